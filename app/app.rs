@@ -5,41 +5,161 @@ mod components;
 mod core;
 mod pages;
 mod state;
+mod session;
 mod hooks;
+mod cli;
+mod instance;
+mod messages;
+mod telemetry;
+mod http;
+mod crash;
+mod blame;
+mod git;
+mod remote;
 
-use state::AppState;
+use state::{AppState, Bookmark, PanelLayout};
+use session::{BufferBackup, SessionLock};
 use hooks::ConfigLoader;
+use hooks::config_loader::SettingSource;
+use hooks::extensions::check_updates;
+use hooks::keybindings::{KeyChord, KeyPress, Keybindings};
+use hooks::settings_schema::{self, SettingKind, SettingSchema, SETTINGS_SCHEMA};
+use cli::{CliArgs, OpenTarget};
+use instance::InstanceServer;
+use messages::{UiMessage, UiSender};
+use telemetry::Telemetry;
+use blame::{relative_age, BlameCache};
+use git::{branch_label, GitAction};
 
 use mikoui::{
-    set_theme, FontManager, ThemeColors, ThemeMode, Widget, 
-    dwm_windows,
+    refresh_system_reduced_motion, set_accessibility_config, set_animation_config, set_theme, AccessibilityConfig, AnimationConfig, Easing, FontManager, Profiler, Span, ThemeColors, ThemeMode, Widget, widget_at,
+    available_locales, load_catalogs_from_dir, translate_with, set_file_icon_theme, FileIconTheme, locale, register_catalog, set_locale, t, Catalog, DEFAULT_LOCALE,
+    enter_window, layout_direction, mirror_x, relative_luminance, set_surface_opacity, surface_opacity,
+    copy_changed_to_buffer, copy_to_buffer, dwm_windows, jump_list, url_scheme, WindowMaterial,
 };
-use components::{ActivityBar, TitleBar, MenuBar, WindowControl, LayoutButton, LeftPanel, RightPanel, BottomPanel, StatusBar, LayoutConfig, CommandPalette};
-use core::{create_editor_menus, handle_menu_action};
-use theme::{kiro::KiroTheme, vscode::VSCodeTheme, xcode::XcodeTheme};
-use mikoeditor::Editor;
+use mikoui::theme::with_alpha;
+use mikoui::components::{AlertDialog, AlertDialogAction, CodiconIcons, ContextMenu, Inspector, MenuItem, Splitter, SplitterSide, Toast};
+use components::{ActivityBar, ActivityBarItem, SidebarView, TitleBar, MenuBar, WindowControl, LayoutButton, LeftPanel, RightPanel, BottomPanel, BottomPanelView, StatusBar, StatusBarItem, LayoutConfig, CommandPalette, CommandItem, PaletteMode, PALETTE_LINE_ID, GoToLine, FindBytes, PerfOverlay};
+use core::{create_editor_menus, handle_menu_action, reveal_in_file_manager, spawn_new_window};
+use theme::{kiro::KiroTheme, vscode::VSCodeTheme, xcode::XcodeTheme, file::ThemeFile};
+use pages::{BookmarksAction, ExtensionRow, ExtensionsAction, ExtensionsPage, KeyboardShortcuts, KeyboardShortcutsAction, SearchAction, ShortcutRow, ThemeEditor, ThemeEditorAction};
+use mikoeditor::{AutoSaveMode, Decoration, Editor, EditorCommand, EditorTab, MacroRecorder, MacroStep, Encoding, Formatter, IndentStyle, IndexHit, IndexQuery, IndexSearch, Indexer, LineEnding, Preview, SaveOptions, TodoComment, TokenType, find_todos, language_display_name, run_command, set_language_configs, LANGUAGES};
 
 #[cfg(target_os = "windows")]
 use components::titlebar::windows_titlebar;
 use softbuffer::{Context, Surface};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
-use std::time::Instant;
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
 use winit::application::ApplicationHandler;
 use winit::event::{ElementState, MouseButton, WindowEvent};
 use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
 use winit::window::{Window, WindowId};
 use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use mikocore::{branches, char_name, code_point_label, diff_lines, named_chars, parse_code_point, file_history, is_archive, search_files, show_file, ArchiveFs, BranchStatus, DiffKind, DirEntry, FileCommit, FileEdit, FileOperation, FileOperations, FileWatcher, RemoteMount, SshHost, Vfs, Walker};
 
+/// Matches a workspace search collects before it stops
+const MAX_SEARCH_RESULTS: usize = 5000;
+/// Files larger than this are skipped when scanning the workspace for to-do comments
+const MAX_TODO_SCAN_SIZE: u64 = 1024 * 1024;
 const WINDOW_WIDTH: f32 = 1200.0;
 const WINDOW_HEIGHT: f32 = 800.0;
 const TITLEBAR_HEIGHT: f32 = 34.0;
 
+/// Command ids from this value up to `BRANCH_PICKER_BASE_ID` select an entry of the Go to Symbol picker
+const SYMBOL_PICKER_BASE_ID: i32 = 1000;
+/// Command ids from this value up to `HISTORY_PICKER_BASE_ID` check out a branch listed by the branch picker
+const BRANCH_PICKER_BASE_ID: i32 = 80_000;
+/// Command ids from this value up to `WORKSPACE_PICKER_BASE_ID` open the diff of a commit listed by File History
+const HISTORY_PICKER_BASE_ID: i32 = 90_000;
+/// Command ids from this value up to `EXTENSION_COMMAND_BASE_ID` open an entry of the Go to File or workspace symbol picker
+const WORKSPACE_PICKER_BASE_ID: i32 = 100_000;
+/// Command ids from this value up to `UNICODE_PICKER_BASE_ID` run a command contributed by an enabled extension, in `Extensions::commands` order
+const EXTENSION_COMMAND_BASE_ID: i32 = 200_000;
+/// Command ids from this value up insert the character whose code point they are past it
+const UNICODE_PICKER_BASE_ID: i32 = 2_000_000;
+/// Most entries listed by the workspace pickers
+const WORKSPACE_PICKER_LIMIT: usize = 5000;
+/// Command ids from this value up to `MACRO_PICKER_BASE_ID` replay the last macro, as often as `MACRO_REPEAT_COUNTS` says
+const MACRO_REPEAT_BASE_ID: i32 = 800;
+const MACRO_REPEAT_COUNTS: [usize; 6] = [2, 3, 5, 10, 25, 100];
+/// Command ids from this value up to `LOCALE_PICKER_BASE_ID` run a saved macro
+const MACRO_PICKER_BASE_ID: i32 = 820;
+/// Commands macros record and replay; the others open pickers or dialogs a replay can't answer
+const MACRO_COMMANDS: [i32; 7] = [6, 39, 41, 55, 56, 170, 171];
+/// Command ids from this value up to `ICON_THEME_PICKER_BASE_ID` select a display language
+const LOCALE_PICKER_BASE_ID: i32 = 900;
+/// Command ids from this value up to `SYMBOL_PICKER_BASE_ID` select a file icon theme
+const ICON_THEME_PICKER_BASE_ID: i32 = 950;
+/// Built-in file icon theme, also used when shared/icons is missing
+const DEFAULT_ICON_THEME: &str = include_str!("../shared/icons/default.json");
+/// Command ids from this value select a language mode, in `LANGUAGES` order
+const LANGUAGE_MODE_PICKER_BASE_ID: i32 = 220;
+/// Picker entry that detects the language mode again
+const AUTO_DETECT_LANGUAGE_ID: i32 = 219;
+/// Entries of the usage data picker
+const USAGE_DATA_TOGGLE_ID: i32 = 251;
+const USAGE_DATA_SHOW_ID: i32 = 252;
+const USAGE_DATA_CLEAR_ID: i32 = 253;
+/// Command ids from this value up to `SETTING_VALUE_PICKER_BASE_ID` select a setting, in `SETTINGS_SCHEMA` order
+const SETTINGS_PICKER_BASE_ID: i32 = 300;
+/// Command ids from this value up to `SSH_HOST_PICKER_BASE_ID` pick a value for the setting chosen above
+const SETTING_VALUE_PICKER_BASE_ID: i32 = 400;
+/// Command ids from this value up to `PROFILE_PICKER_BASE_ID` connect to a saved SSH host, most recent first
+const SSH_HOST_PICKER_BASE_ID: i32 = 600;
+/// Command ids from this value up to `PROFILE_WINDOW_PICKER_BASE_ID` switch to a profile, in `list_profiles` order
+const PROFILE_PICKER_BASE_ID: i32 = 700;
+/// Command ids from this value up to `MACRO_REPEAT_BASE_ID` open a new window with a profile
+const PROFILE_WINDOW_PICKER_BASE_ID: i32 = 750;
+
+/// Tab context menu entries
+const TAB_MENU_CLOSE: usize = 1;
+const TAB_MENU_CLOSE_OTHERS: usize = 2;
+const TAB_MENU_CLOSE_TO_RIGHT: usize = 3;
+const TAB_MENU_CLOSE_SAVED: usize = 4;
+const TAB_MENU_COPY_PATH: usize = 5;
+const TAB_MENU_COPY_RELATIVE_PATH: usize = 6;
+const TAB_MENU_REVEAL_IN_EXPLORER: usize = 7;
+const TAB_MENU_REVEAL_IN_FILE_MANAGER: usize = 8;
+const TAB_MENU_TOGGLE_PIN: usize = 9;
+
+/// Explorer context menu entries
+const EXPLORER_MENU_COPY_PATH: usize = 1;
+const EXPLORER_MENU_COPY_RELATIVE_PATH: usize = 2;
+const EXPLORER_MENU_REVEAL_IN_FILE_MANAGER: usize = 3;
+const EXPLORER_MENU_MOVE_TO_TRASH: usize = 4;
+const EXPLORER_MENU_UNDO: usize = 5;
+
+/// English strings, compiled in so every key has a fallback even without shared/locales
+const EN_CATALOG: &str = include_str!("../shared/locales/en.json");
+
+/// What the alert dialog on screen is asking
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AlertPurpose {
+    DiscardTab,
+    RecoverSession,
+    CrashReport,
+    WorkspaceTrust,
+}
+
+/// Index lookup feeding the palette's files or workspace symbols mode
+struct PaletteSearch {
+    mode: PaletteMode,
+    query: String,
+    search: IndexSearch,
+    /// Set until the first results replace the previous query's targets
+    fresh: bool,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum AppTheme {
     Kiro,
     VSCode,
     Xcode,
+    HighContrast,
 }
 
 impl AppTheme {
@@ -51,6 +171,7 @@ impl AppTheme {
             (AppTheme::VSCode, ThemeMode::Light) => VSCodeTheme::light(),
             (AppTheme::Xcode, ThemeMode::Dark) => XcodeTheme::dark(),
             (AppTheme::Xcode, ThemeMode::Light) => XcodeTheme::light(),
+            (AppTheme::HighContrast, _) => ThemeColors::high_contrast(),
         }
     }
     
@@ -59,6 +180,7 @@ impl AppTheme {
             AppTheme::Kiro => "Kiro",
             AppTheme::VSCode => "VSCode",
             AppTheme::Xcode => "Xcode",
+            AppTheme::HighContrast => "High Contrast",
         }
     }
 }
@@ -74,8 +196,61 @@ struct App {
     bottom_panel: Option<BottomPanel>,
     status_bar: Option<StatusBar>,
     command_palette: Option<CommandPalette>,
+    go_to_line: Option<GoToLine>,
+    find_bytes: Option<FindBytes>,
+    theme_editor: Option<ThemeEditor>,
+    keyboard_shortcuts: Option<KeyboardShortcuts>,
+    extensions_page: Option<ExtensionsPage>,
+    checking_extensions: bool, // An extension update check is running in the background
+    alert_dialog: Option<AlertDialog>,
+    alert_purpose: AlertPurpose,
+    tab_menu: ContextMenu,
+    explorer_menu: ContextMenu, // Acts on the rows selected in the explorer
+    tab_menu_target: usize, // Tab the context menu was opened on
+    perf_overlay: PerfOverlay,
+    inspector: Inspector, // Developer: Toggle Widget Inspector
+    profiler: Profiler,
+    symbol_targets: Vec<(usize, usize)>,
+    workspace_targets: Vec<(PathBuf, usize, usize)>, // File, line and column of each workspace picker entry
+    palette_search: Option<PaletteSearch>,
+    file_operations: FileOperations, // Explorer deletes and renames, for Undo Last File Operation
+    vfs: Arc<dyn Vfs>, // What the explorer, tabs, search and the file watcher read workspace files through
+    archives: Arc<ArchiveFs>, // The same file system as `vfs`, to mount archives on
+    file_watcher: FileWatcher, // Files open in tabs, reloaded when they change on disk
+    settings_watcher: FileWatcher, // settings.yml files and keybindings.json, reapplied when they change
+    keybindings: Keybindings,
+    toast: Toast,
+    session_lock: SessionLock, // Left behind by a crash, so the next start can offer recovery
+    recovered_backups: Option<Vec<BufferBackup>>, // Set after an unclean shutdown until the recovery dialog is answered
+    crash_report: Option<PathBuf>, // Report of the last session's panic, offered after the recovery dialog
+    backup_versions: Vec<(usize, u64)>, // Tab ids and buffer versions in the last backup
+    outline_source: Option<(usize, u64, Option<String>)>, // Tab id, buffer version and language the outline was built from
+    todos_source: Option<Vec<(usize, u64)>>, // Tab ids and buffer versions the TODOs page was built from
+    workspace_todos: Vec<(PathBuf, Vec<TodoComment>)>, // To-do comments of the workspace files, as of the last scan
+    bookmark_tabs: HashSet<usize>, // Tabs given their saved bookmarks; from then on the tab's own are saved
+    indexer: Option<Indexer>, // Index of the opened folder for Go to File and workspace symbols
+    locale_options: Vec<String>, // Locales listed by the display language picker
+    icon_theme: String, // Active file icon theme
+    icon_theme_options: Vec<String>, // Themes listed by the file icon theme picker
+    setting_target: Option<&'static SettingSchema>, // Setting whose values the value picker lists
+    macros: MacroRecorder, // Keyboard macro being recorded and the last one, for replay
+    macro_options: Vec<String>, // Saved macros listed by the Run Saved Macro picker
+    blame: BlameCache, // git blame of the files inline blame was shown in
+    inline_blame: bool, // git.show_inline_blame, flipped for the session by Toggle Inline Blame
+    blame_source: Option<(usize, usize, u64, bool)>, // Tab id, cursor line, buffer version and modified flag inline blame was shown for
+    history_options: Option<(PathBuf, Vec<FileCommit>)>, // File and commits listed by the File History picker
+    git_status: Option<BranchStatus>, // Branch of the workspace repository, shown in the status bar
+    git_busy: bool, // A pull, push or other git action is running; one at a time
+    branch_options: Vec<String>, // Branches listed by the branch picker
+    profile_options: Vec<String>, // Profiles listed by the profile pickers
+    remote: Option<RemoteMount>, // Folder opened over SSH instead of a local one, see `open_remote`
+    ssh_host: Option<SshHost>, // Host picked to connect to, until the folder to open is typed
+    uploads: HashMap<PathBuf, bool>, // Local copies of remote files being uploaded; true when saved again meanwhile
     editor: Option<Editor>,
     layout_config: LayoutConfig,
+    left_splitter: Splitter,
+    right_splitter: Splitter,
+    bottom_splitter: Splitter,
     widgets: Vec<Box<dyn Widget>>,
     mouse_pos: (f32, f32),
     font_manager: FontManager,
@@ -83,10 +258,18 @@ struct App {
     theme_colors: ThemeColors,
     theme_mode: ThemeMode,
     current_theme: AppTheme,
+    /// Palette loaded from a theme file or edited in the Theme Editor, with its name
+    custom_theme: Option<(String, ThemeColors)>,
     is_dragging: bool,
     drag_start_pos: Option<(f32, f32)>,
     is_window_maximized: bool,
     app_state: AppState,
+    launch: Option<CliArgs>, // Command-line arguments, handled once the window exists
+    wait_tabs: Vec<usize>, // Ids of the tabs opened with --wait; the app exits once all are closed
+    instance: Option<InstanceServer>, // Receives deep links and files forwarded by later launches
+    ui_sender: UiSender, // Cloned into background threads so they can ask for UI changes
+    ui_messages: mpsc::Receiver<UiMessage>, // Requests from background threads, drained every event loop cycle
+    telemetry: Telemetry, // Opt-in usage counters
     ime_enabled: bool,
     modifiers: winit::keyboard::ModifiersState,
     config_loader: ConfigLoader,
@@ -95,13 +278,27 @@ struct App {
 }
 
 impl App {
-    fn new() -> Self {
+    fn new(launch: CliArgs, instance: Option<InstanceServer>, (ui_sender, ui_messages): (UiSender, mpsc::Receiver<UiMessage>)) -> Self {
         // Load application state (creates default if first run)
-        let app_state = AppState::load();
+        let mut app_state = AppState::load();
+        // A profile named on the command line replaces the last session's, panel layout included
+        if let Some(ref profile) = launch.profile {
+            app_state.switch_profile(profile);
+        }
+        let (session_lock, unclean_shutdown) = SessionLock::acquire();
+        let recovered_backups = unclean_shutdown.then(session::load_backups);
+        let crash_report = crash::take_pending_report();
+        let telemetry = Telemetry::load(app_state.telemetry_enabled);
         
         // Restore workspace directory if it was saved
+        let archives = Arc::new(ArchiveFs::default());
         if let Some(ref workspace_path) = app_state.workspace_path {
-            if workspace_path.exists() {
+            if is_archive(workspace_path) && workspace_path.is_file() {
+                // An archive opened as the workspace is browsed in place; there's no folder to change to
+                if let Err(e) = archives.mount(workspace_path) {
+                    eprintln!("Cannot open archive {}: {}", workspace_path.display(), e);
+                }
+            } else if workspace_path.exists() {
                 if let Err(e) = std::env::set_current_dir(workspace_path) {
                     eprintln!("Failed to change to workspace directory: {}", e);
                 } else {
@@ -117,12 +314,14 @@ impl App {
         let theme_colors = current_theme.get_colors(theme_mode);
         set_theme(theme_colors);
         
-        // Initialize font manager with system fonts
-        let font_manager = FontManager::new();
+        // Initialize font manager with system fonts; `editor.ui_font_family` picks the UI font
+        let mut font_manager = FontManager::new();
         
-        // Load Inter Variable font as primary font
-        // const INTER_FONT_DATA: &[u8] = include_bytes!("fonts/InterVariable.ttf");
-        // font_manager.set_primary_font(INTER_FONT_DATA);
+        // Bundled Inter Variable, selectable as "Inter"
+        const INTER_FONT_DATA: &[u8] = include_bytes!("fonts/InterVariable.ttf");
+        if let Err(e) = font_manager.register_font(INTER_FONT_DATA, Some("Inter")) {
+            eprintln!("Failed to register bundled Inter font: {}", e);
+        }
         
         // Load layout config from state
         let mut layout_config = LayoutConfig::default();
@@ -133,7 +332,21 @@ impl App {
         layout_config.bottom_panel_visible = app_state.bottom_panel_visible;
         layout_config.bottom_panel_height = app_state.bottom_panel_height;
         
-        Self {
+        let (left_splitter, right_splitter, bottom_splitter) = layout_config.create_splitters();
+        
+        // Load workspace configs for the restored workspace
+        let mut config_loader = ConfigLoader::new();
+        config_loader.set_profile(&app_state.profile);
+        let trusted = app_state.workspace_path.as_ref().is_none_or(|path| app_state.workspace_trust(path) == Some(true));
+        config_loader.set_workspace_trusted(trusted);
+        if let Some(ref workspace_path) = app_state.workspace_path {
+            if workspace_path.exists() {
+                config_loader.set_workspace(workspace_path.clone());
+            }
+        }
+        
+        let vfs: Arc<dyn Vfs> = archives.clone();
+        let mut app = Self {
             window: None,
             surface: None,
             titlebar: None,
@@ -144,8 +357,63 @@ impl App {
             bottom_panel: None,
             status_bar: None,
             command_palette: None,
+            go_to_line: None,
+            find_bytes: None,
+            theme_editor: None,
+            keyboard_shortcuts: None,
+            extensions_page: None,
+            checking_extensions: false,
+            alert_dialog: None,
+            alert_purpose: AlertPurpose::DiscardTab,
+            tab_menu: ContextMenu::new(0.0, 0.0, Vec::new()),
+            explorer_menu: ContextMenu::new(0.0, 0.0, Vec::new()),
+            tab_menu_target: 0,
+            perf_overlay: PerfOverlay::new(WINDOW_WIDTH, TITLEBAR_HEIGHT),
+            inspector: Inspector::new(),
+            profiler: Profiler::new(),
+            symbol_targets: Vec::new(),
+            workspace_targets: Vec::new(),
+            palette_search: None,
+            file_operations: FileOperations::new(),
+            file_watcher: FileWatcher::new(Duration::from_secs(1)).vfs(vfs.clone()),
+            vfs,
+            archives,
+            settings_watcher: FileWatcher::new(Duration::from_secs(1)),
+            keybindings: Keybindings::new(
+                CommandPalette::create_default_commands().iter().map(|command| (command.id, command.shortcut.as_deref())),
+            ),
+            toast: Toast::new(),
+            session_lock,
+            recovered_backups,
+            crash_report,
+            backup_versions: Vec::new(),
+            outline_source: None,
+            todos_source: None,
+            workspace_todos: Vec::new(),
+            bookmark_tabs: HashSet::new(),
+            indexer: None,
+            locale_options: Vec::new(),
+            icon_theme: "default".to_string(),
+            icon_theme_options: Vec::new(),
+            setting_target: None,
+            macros: MacroRecorder::default(),
+            macro_options: Vec::new(),
+            blame: BlameCache::default(),
+            inline_blame: false,
+            blame_source: None,
+            history_options: None,
+            git_status: None,
+            git_busy: false,
+            branch_options: Vec::new(),
+            profile_options: Vec::new(),
+            remote: None,
+            ssh_host: None,
+            uploads: HashMap::new(),
             editor: None,
             layout_config,
+            left_splitter,
+            right_splitter,
+            bottom_splitter,
             widgets: Vec::new(),
             mouse_pos: (0.0, 0.0),
             font_manager,
@@ -153,16 +421,34 @@ impl App {
             theme_colors,
             theme_mode,
             current_theme,
+            custom_theme: None,
             is_dragging: false,
             drag_start_pos: None,
             is_window_maximized: app_state.window_maximized,
             app_state,
+            launch: Some(launch),
+            wait_tabs: Vec::new(),
+            instance,
+            ui_sender,
+            ui_messages,
+            telemetry,
             ime_enabled: false,
             modifiers: winit::keyboard::ModifiersState::empty(),
-            config_loader: ConfigLoader::new(),
+            config_loader,
             #[cfg(target_os = "windows")]
             window_hwnd: None,
-        }
+        };
+        app.load_catalogs();
+        app.load_settings_theme();
+        app.apply_icon_theme_settings();
+        app.apply_animation_settings();
+        app.apply_accessibility_settings();
+        app.apply_locale_settings();
+        app.apply_font_settings();
+        app.start_indexer();
+        let features = app.usage_features();
+        app.telemetry.start_session(unclean_shutdown, features);
+        app
     }
     
     fn toggle_theme_mode(&mut self) {
@@ -170,17 +456,30 @@ impl App {
             ThemeMode::Dark => ThemeMode::Light,
             ThemeMode::Light => ThemeMode::Dark,
         };
+        self.custom_theme = None;
         self.apply_theme();
     }
     
     fn set_theme(&mut self, theme: AppTheme) {
         self.current_theme = theme;
+        self.custom_theme = None;
         self.apply_theme();
     }
     
+    fn theme_name(&self) -> &str {
+        match self.custom_theme {
+            Some((ref name, _)) => name,
+            None => self.current_theme.name(),
+        }
+    }
+    
     fn apply_theme(&mut self) {
-        self.theme_colors = self.current_theme.get_colors(self.theme_mode);
+        self.theme_colors = match self.custom_theme {
+            Some((_, colors)) => colors,
+            None => self.current_theme.get_colors(self.theme_mode),
+        };
         set_theme(self.theme_colors);
+        self.apply_window_material();
         
         let size = self.window.as_ref().map(|w| w.inner_size());
         if let Some(size) = size {
@@ -191,6 +490,113 @@ impl App {
         }
     }
     
+    /// Use `editor.theme` from the settings when it is `high-contrast` or names a file in the themes directory
+    fn load_settings_theme(&mut self) {
+        let Some(name) = self.config_loader.get_settings().map(|settings| settings.editor.theme.clone()) else {
+            return;
+        };
+        if name == "high-contrast" {
+            self.set_theme(AppTheme::HighContrast);
+            return;
+        }
+        let Some(content) = self.config_loader.load_theme(&name) else {
+            return;
+        };
+        match ThemeFile::parse(&content).map_err(|e| e.to_string()).and_then(|file| self.apply_theme_file(&file)) {
+            Ok(()) => println!("Loaded theme '{}' from file", name),
+            Err(e) => eprintln!("Failed to load theme '{}': {}", name, e),
+        }
+    }
+    
+    /// Use `editor.icon_theme` from the settings
+    fn apply_icon_theme_settings(&mut self) {
+        let name = self.config_loader.get_settings()
+            .map(|settings| settings.editor.icon_theme.clone())
+            .unwrap_or_else(|| "default".to_string());
+        self.apply_icon_theme(&name);
+    }
+    
+    /// Switch file icons to a theme from shared/icons; `default` falls back to the built-in copy
+    fn apply_icon_theme(&mut self, name: &str) {
+        let icons_dir = self.config_loader.get_icon_themes_dir();
+        let path = icons_dir.join(format!("{}.json", name));
+        let result = if path.exists() {
+            FileIconTheme::load(&path)
+        } else if name == "default" {
+            FileIconTheme::parse(DEFAULT_ICON_THEME, &icons_dir)
+        } else {
+            Err(format!("no icon theme named '{}'", name).into())
+        };
+        match result {
+            Ok(theme) => {
+                set_file_icon_theme(theme);
+                self.icon_theme = name.to_string();
+            }
+            Err(e) => eprintln!("Failed to load icon theme '{}': {}", name, e),
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    fn apply_theme_file(&mut self, file: &ThemeFile) -> Result<(), String> {
+        let mode = file.theme_mode();
+        let colors = file.to_colors(self.current_theme.get_colors(mode))?;
+        self.theme_mode = mode;
+        self.custom_theme = Some((file.name.clone(), colors));
+        self.apply_theme();
+        Ok(())
+    }
+    
+    /// Apply a palette without rebuilding the UI (Theme Editor live preview)
+    fn preview_theme(&mut self, name: &str, colors: ThemeColors) {
+        self.custom_theme = Some((name.to_string(), colors));
+        self.theme_colors = colors;
+        set_theme(colors);
+    }
+    
+    fn show_theme_editor(&mut self) {
+        if let Some(ref mut keyboard_shortcuts) = self.keyboard_shortcuts {
+            keyboard_shortcuts.hide();
+        }
+        if let Some(ref mut extensions_page) = self.extensions_page {
+            extensions_page.hide();
+        }
+        let name = self.theme_name().to_string();
+        let colors = self.theme_colors;
+        if let Some(ref mut theme_editor) = self.theme_editor {
+            theme_editor.show(&name, colors);
+        }
+    }
+    
+    /// Write the Theme Editor palette to the themes directory or a chosen file
+    fn save_edited_theme(&mut self, export: bool) {
+        let Some(ref theme_editor) = self.theme_editor else {
+            return;
+        };
+        let file = ThemeFile::from_colors(theme_editor.name(), self.theme_mode, &theme_editor.colors());
+        let path = if export {
+            mikoui::file_dialogs::save_file_dialog("Export Color Theme", &file.file_name(), &[("Theme Files", "*.yml")])
+        } else {
+            Some(self.config_loader.get_themes_dir().join(file.file_name()))
+        };
+        let Some(path) = path else {
+            return;
+        };
+        
+        let status = match file.save(&path) {
+            Ok(()) => {
+                self.custom_theme = Some((file.name.clone(), theme_editor.colors()));
+                format!("Saved to {}", path.display())
+            }
+            Err(e) => format!("Failed to save theme: {}", e),
+        };
+        self.log_output(&status);
+        if let Some(ref mut theme_editor) = self.theme_editor {
+            theme_editor.set_status(status);
+        }
+    }
+    
     fn build_ui(&mut self, width: f32, _height: f32) {
         self.widgets.clear();
         
@@ -224,6 +630,7 @@ impl App {
             .with_menubar(menubar_width);
         titlebar.set_maximized(self.is_window_maximized);
         self.titlebar = Some(titlebar);
+        self.sync_menubar_collapse();
         
         // Enable Windows 11 Snap Layouts
         #[cfg(target_os = "windows")]
@@ -233,38 +640,80 @@ impl App {
         }
         
         // Create command palette
-        let command_palette = CommandPalette::new(width, _height);
+        let mut command_palette = CommandPalette::new(width, _height);
+        command_palette.set_shortcut_overrides(self.shortcut_overrides());
+        command_palette.set_command_boosts(self.app_state.command_boosts());
+        command_palette.set_extension_commands(self.extension_command_items());
         self.command_palette = Some(command_palette);
+        self.go_to_line = Some(GoToLine::new(width, TITLEBAR_HEIGHT));
+        self.find_bytes = Some(FindBytes::new(width, TITLEBAR_HEIGHT));
+        self.perf_overlay.set_screen_width(width);
+        match self.alert_dialog {
+            Some(ref mut alert_dialog) => alert_dialog.set_screen_size(width, _height),
+            None => self.alert_dialog = Some(Self::create_alert_dialog(self.alert_purpose, width, _height)),
+        }
+        
+        // RTL locales mirror the horizontal layout: activity bar and side bar on the right
+        let rtl = layout_direction().is_rtl();
         
         // Create activity bar
-        let activitybar = ActivityBar::new(0.0, TITLEBAR_HEIGHT, _height - TITLEBAR_HEIGHT);
+        let mut activitybar = ActivityBar::new(0.0, TITLEBAR_HEIGHT, _height - TITLEBAR_HEIGHT);
         let activity_bar_width = activitybar.width();
+        activitybar.set_x(mirror_x(0.0, activity_bar_width, 0.0, width));
         self.activitybar = Some(activitybar);
         
         // Create layout panels
         let status_bar_height = 24.0;
         let content_top = TITLEBAR_HEIGHT;
-        let content_left = activity_bar_width;
-        let content_width = width - content_left;
+        let content_left = if rtl { 0.0 } else { activity_bar_width };
+        let content_width = width - activity_bar_width;
         let content_height = _height - content_top - status_bar_height;  // Account for status bar
         
+        // Splitters own the panel sizes and stay alive across rebuilds, so drags survive them
+        self.layout_config.left_panel_width = self.left_splitter.size();
+        self.layout_config.right_panel_width = self.right_splitter.size();
+        self.layout_config.bottom_panel_height = self.bottom_splitter.size();
+        let (left_side, right_side) = if rtl {
+            (SplitterSide::End, SplitterSide::Start)
+        } else {
+            (SplitterSide::Start, SplitterSide::End)
+        };
+        self.left_splitter.set_side(left_side);
+        self.left_splitter.set_bounds(mirror_x(content_left, 0.0, content_left, content_width), content_top, content_height);
+        self.left_splitter.set_collapsed(!self.layout_config.left_panel_visible);
+        self.right_splitter.set_side(right_side);
+        self.right_splitter.set_bounds(mirror_x(content_left + content_width, 0.0, content_left, content_width), content_top, content_height);
+        self.right_splitter.set_collapsed(!self.layout_config.right_panel_visible);
+        self.bottom_splitter.set_bounds(_height - status_bar_height, content_left, content_width);
+        self.bottom_splitter.set_collapsed(!self.layout_config.bottom_panel_visible);
+        
         // Left panel
         if self.layout_config.left_panel_visible {
-            let mut left_panel = if let Some(ref workspace_path) = self.app_state.workspace_path {
+            let left_x = mirror_x(content_left, self.layout_config.left_panel_width, content_left, content_width);
+            let mut left_panel = if let Some(ref mount) = self.remote {
+                // A remote folder keeps what was listed so far
+                let mut left_panel = LeftPanel::new(left_x, content_top, self.layout_config.left_panel_width, content_height);
+                let previous = self.left_panel.as_mut().map(|panel| panel.explorer_mut());
+                let expanded = previous.as_ref().map(|explorer| explorer.get_expanded_paths()).unwrap_or_default();
+                let listings = previous.and_then(|explorer| explorer.take_remote()).unwrap_or_default();
+                left_panel.explorer_mut().set_remote(mount.local_root.clone(), listings);
+                left_panel.explorer_mut().restore_expanded_state(&expanded);
+                left_panel
+            } else if let Some(ref workspace_path) = self.app_state.workspace_path {
                 // Load with saved workspace path
                 println!("Creating left panel with workspace path: {}", workspace_path.display());
                 LeftPanel::new_with_path(
-                    content_left,
+                    left_x,
                     content_top,
                     self.layout_config.left_panel_width,
                     content_height,
-                    workspace_path.clone(),
+                    self.workspace_walker(workspace_path.clone()),
                 )
             } else {
                 // No workspace - show empty explorer
                 println!("Creating left panel without workspace path");
                 LeftPanel::new(
-                    content_left,
+                    left_x,
                     content_top,
                     self.layout_config.left_panel_width,
                     content_height,
@@ -275,6 +724,18 @@ impl App {
             if !self.app_state.expanded_folders.is_empty() {
                 left_panel.explorer_mut().restore_expanded_state(&self.app_state.expanded_folders);
             }
+            // Keep the filter when the layout is rebuilt
+            if let Some(filter) = self.left_panel.as_ref().map(|panel| panel.filter_text().to_string()) {
+                left_panel.set_filter(&filter);
+            }
+            // And the search page with its results
+            if let Some(ref mut previous) = self.left_panel {
+                left_panel.keep_search_from(previous);
+            }
+            left_panel.search_mut().set_root(self.app_state.workspace_path.clone());
+            left_panel.todos_mut().set_root(self.app_state.workspace_path.clone());
+            left_panel.bookmarks_mut().set_root(self.app_state.workspace_path.clone());
+            self.todos_source = None;
             
             self.layout_config.left_panel_width = left_panel.width();
             self.left_panel = Some(left_panel);
@@ -284,13 +745,33 @@ impl App {
         
         // Right panel
         if self.layout_config.right_panel_visible {
-            let right_x = width - self.layout_config.right_panel_width;
-            let right_panel = RightPanel::new(
-                right_x,
-                content_top,
+            let right_x = mirror_x(
+                content_left + content_width - self.layout_config.right_panel_width,
                 self.layout_config.right_panel_width,
-                content_height,
+                content_left,
+                content_width,
             );
+            // Keep the existing panel so the outline and its toggle survive relayouts
+            let right_panel = match self.right_panel.take() {
+                Some(mut right_panel) => {
+                    right_panel.set_bounds(
+                        right_x,
+                        content_top,
+                        self.layout_config.right_panel_width,
+                        content_height,
+                    );
+                    right_panel
+                }
+                None => {
+                    self.outline_source = None;
+                    RightPanel::new(
+                        right_x,
+                        content_top,
+                        self.layout_config.right_panel_width,
+                        content_height,
+                    )
+                }
+            };
             self.layout_config.right_panel_width = right_panel.width();
             self.right_panel = Some(right_panel);
         } else {
@@ -300,12 +781,29 @@ impl App {
         // Bottom panel (above status bar)
         if self.layout_config.bottom_panel_visible {
             let bottom_y = _height - self.layout_config.bottom_panel_height - status_bar_height;
-            let bottom_panel = BottomPanel::new(
-                content_left,
-                bottom_y,
-                content_width,
-                self.layout_config.bottom_panel_height,
-            );
+            let shell_allowed = self.workspace_trusted();
+            // Keep the existing panel so the terminal session and output survive relayouts
+            let bottom_panel = match self.bottom_panel.take() {
+                Some(mut bottom_panel) => {
+                    bottom_panel.set_bounds(
+                        content_left,
+                        bottom_y,
+                        content_width,
+                        self.layout_config.bottom_panel_height,
+                    );
+                    bottom_panel
+                }
+                None => {
+                    let mut bottom_panel = BottomPanel::new(
+                        content_left,
+                        bottom_y,
+                        content_width,
+                        self.layout_config.bottom_panel_height,
+                    );
+                    bottom_panel.set_shell_allowed(shell_allowed);
+                    bottom_panel
+                }
+            };
             self.layout_config.bottom_panel_height = bottom_panel.height();
             self.bottom_panel = Some(bottom_panel);
         } else {
@@ -327,6 +825,7 @@ impl App {
         } else {
             0.0
         };
+        let editor_x = mirror_x(editor_x, editor_width, content_left, content_width);
         let editor_height = if self.layout_config.bottom_panel_visible {
             content_height - self.layout_config.bottom_panel_height
         } else {
@@ -337,183 +836,4038 @@ impl App {
         let status_bar_y = _height - status_bar_height;
         let status_bar = StatusBar::new(0.0, status_bar_y, width);
         self.status_bar = Some(status_bar);
+        self.toast.set_anchor(width - 16.0, status_bar_y - 12.0);
         
         // Editor height already accounts for status bar through content_height
-        let editor = Editor::new(editor_x, content_top, editor_width, editor_height);
+        // Keep the existing editor so open tabs survive relayouts
+        let editor = match self.editor.take() {
+            Some(mut editor) => {
+                editor.set_bounds(editor_x, content_top, editor_width, editor_height);
+                editor
+            }
+            None => {
+                let mut editor = Editor::new(editor_x, content_top, editor_width, editor_height);
+                editor.set_vfs(self.vfs.clone());
+                editor
+            }
+        };
         self.editor = Some(editor);
+        self.apply_editor_settings();
+        
+        // The Theme Editor page covers the editor area; keep it open across relayouts
+        let theme_editor = match self.theme_editor.take() {
+            Some(mut theme_editor) => {
+                theme_editor.set_bounds(editor_x, content_top, editor_width, editor_height);
+                theme_editor
+            }
+            None => ThemeEditor::new(editor_x, content_top, editor_width, editor_height),
+        };
+        self.theme_editor = Some(theme_editor);
+        
+        let keyboard_shortcuts = match self.keyboard_shortcuts.take() {
+            Some(mut keyboard_shortcuts) => {
+                keyboard_shortcuts.set_bounds(editor_x, content_top, editor_width, editor_height);
+                keyboard_shortcuts
+            }
+            None => KeyboardShortcuts::new(editor_x, content_top, editor_width, editor_height),
+        };
+        self.keyboard_shortcuts = Some(keyboard_shortcuts);
+        
+        let extensions_page = match self.extensions_page.take() {
+            Some(mut extensions_page) => {
+                extensions_page.set_bounds(editor_x, content_top, editor_width, editor_height);
+                extensions_page
+            }
+            None => {
+                let mut extensions_page = ExtensionsPage::new(editor_x, content_top, editor_width, editor_height);
+                extensions_page.set_install_dir(self.config_loader.get_extensions_dir().display().to_string());
+                extensions_page
+            }
+        };
+        self.extensions_page = Some(extensions_page);
     }
     
-    fn handle_button_click(&mut self, _x: f32, _y: f32) {
-        // No demo buttons - add your custom button handling here
+    /// Push `animations` from the settings into the global animation config
+    fn apply_animation_settings(&mut self) {
+        let config = self.config_loader.get_settings()
+            .map(|settings| AnimationConfig {
+                enabled: settings.animations.enabled,
+                speed: settings.animations.speed.max(0.0),
+                respect_reduced_motion: settings.animations.respect_reduced_motion,
+                smooth_scrolling: settings.animations.smooth_scrolling,
+                scroll_duration: settings.animations.scroll_duration_ms as f32 / 1000.0,
+                scroll_easing: Easing::from_id(&settings.animations.scroll_easing).unwrap_or_else(|| {
+                    eprintln!("Unknown scroll easing '{}', using ease_out", settings.animations.scroll_easing);
+                    Easing::default()
+                }),
+            })
+            .unwrap_or_default();
+        refresh_system_reduced_motion();
+        set_animation_config(config);
     }
     
-    fn handle_menu_action(&mut self, item_id: i32) {
-        use mikoui::file_dialogs;
+    /// Push `accessibility` from the settings into the global accessibility config
+    fn apply_accessibility_settings(&mut self) {
+        let config = self.config_loader.get_settings()
+            .map(|settings| AccessibilityConfig {
+                text_scale: settings.accessibility.text_scale,
+                min_hit_target: settings.accessibility.min_hit_target,
+            })
+            .unwrap_or_default();
+        set_accessibility_config(config);
+        // Text measured at the old scale is laid out again on the next frame
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// `window.material` from the settings
+    fn window_material(&self) -> WindowMaterial {
+        let Some(settings) = self.config_loader.get_settings() else {
+            return WindowMaterial::None;
+        };
+        WindowMaterial::from_id(&settings.window.material).unwrap_or_else(|| {
+            eprintln!("Unknown window material '{}', using none", settings.window.material);
+            WindowMaterial::None
+        })
+    }
+    
+    /// Put the window material behind the window and make panel backgrounds translucent to match
+    fn apply_window_material(&mut self) {
+        let Some(window) = self.window.clone() else {
+            return;
+        };
+        let mut material = self.window_material();
+        let hwnd = window.window_handle().ok().and_then(|handle| match handle.as_raw() {
+            RawWindowHandle::Win32(win32_handle) => Some(win32_handle.hwnd.get()),
+            _ => None,
+        });
+        let backdrop = matches!(material, WindowMaterial::Acrylic | WindowMaterial::Mica);
+        let applied = hwnd.is_some_and(|hwnd| {
+            dwm_windows::set_dark_mode(hwnd, relative_luminance(self.theme_colors.background) < 0.5);
+            dwm_windows::set_window_material(hwnd, material)
+        });
+        if backdrop && !applied {
+            println!("Window material {:?} is not supported here, using transparency", material);
+            material = WindowMaterial::Transparent;
+        }
         
-        match item_id {
-            4 => {
-                // Open Folder
-                println!("Opening folder dialog...");
-                match file_dialogs::open_folder_dialog("Open Folder") {
-                    Some(path) => {
-                        println!("Folder selected: {:?}", path);
-                        
-                        // Update app state with new workspace path
-                        self.app_state.workspace_path = Some(path.clone());
-                        
-                        // Load workspace configs (.rabital folder)
-                        self.config_loader.set_workspace(path.clone());
-                        
-                        // Log loaded configs
-                        if let Some(settings) = self.config_loader.get_settings() {
-                            println!("Loaded editor settings: theme={}", settings.editor.theme);
-                        }
-                        if let Some(tasks) = self.config_loader.get_tasks() {
-                            println!("Loaded {} tasks", tasks.tasks.len());
-                        }
-                        
-                        // Change current directory
-                        if let Err(e) = std::env::set_current_dir(&path) {
-                            eprintln!("Failed to change directory: {}", e);
-                        } else {
-                            println!("Changed directory to: {}", path.display());
-                        }
-                        
-                        // Update window title
-                        if let Some(window) = &self.window {
-                            let new_title = self.get_window_title();
-                            window.set_title(&new_title);
-                        }
-                        
-                        // Rebuild UI to load the new folder
-                        let window_size = self.window.as_ref().map(|w| w.inner_size());
-                        if let Some(size) = window_size {
-                            self.build_ui(size.width as f32, size.height as f32);
-                            if let Some(window) = &self.window {
-                                window.request_redraw();
-                            }
-                        }
-                        
-                        // Save state immediately
-                        if let Err(e) = self.app_state.save() {
-                            eprintln!("Failed to save state: {}", e);
-                        } else {
-                            println!("State saved successfully");
-                        }
-                    }
-                    None => {
-                        println!("Folder dialog cancelled or failed");
-                    }
-                }
-            }
-            _ => {
-                // Delegate to the standalone handler for other menu items
-                handle_menu_action(item_id);
+        window.set_transparent(material != WindowMaterial::None);
+        let opacity = match material {
+            WindowMaterial::None => 1.0,
+            _ => self.config_loader.get_settings().map_or(1.0, |settings| settings.window.opacity.clamp(0.0, 1.0)),
+        };
+        set_surface_opacity((opacity * 255.0).round() as u8);
+        window.request_redraw();
+    }
+    
+    /// Register shared and workspace fonts, then select the UI and editor families from the settings
+    fn apply_font_settings(&mut self) {
+        let mut font_dirs = vec![self.config_loader.get_fonts_dir()];
+        font_dirs.extend(self.config_loader.get_workspace_fonts_dir());
+        for dir in font_dirs {
+            let families = self.font_manager.register_fonts_from_dir(&dir);
+            if !families.is_empty() {
+                println!("Registered fonts from {}: {:?}", dir.display(), families);
             }
         }
+        
+        let (ui_family, mono_family) = self.config_loader.get_settings()
+            .map(|settings| (settings.editor.ui_font_family.clone(), settings.editor.font_family.clone()))
+            .unwrap_or_default();
+        let revision = self.font_manager.revision();
+        self.font_manager.set_ui_font_family(&ui_family);
+        self.font_manager.set_monospace_font_family(&mono_family);
+        if self.font_manager.revision() != revision {
+            self.refresh_fonts();
+        }
     }
     
-    fn get_clicked_menu_item_id(&self) -> Option<i32> {
-        if let Some(ref menubar) = self.menubar {
-            // Use Any trait to access MenuBar internals
-            use std::any::Any;
-            let menubar_any = menubar.as_any();
-            if let Some(mb) = menubar_any.downcast_ref::<MenuBar>() {
-                // Access private fields through reflection isn't possible in Rust
-                // We need to add a public method to MenuBar instead
-                return mb.get_clicked_item_id();
-            }
+    /// Fold the menus behind the hamburger button when the titlebar has no room for them
+    fn sync_menubar_collapse(&mut self) {
+        if let (Some(menubar), Some(titlebar)) = (self.menubar.as_mut(), self.titlebar.as_ref()) {
+            menubar.set_collapsed(titlebar.is_menubar_collapsed());
         }
-        None
     }
     
-    fn get_window_title(&self) -> String {
-        // Try to get folder name from workspace path first
-        if let Some(ref workspace_path) = self.app_state.workspace_path {
-            if let Some(folder_name) = workspace_path.file_name() {
-                if let Some(name) = folder_name.to_str() {
-                    return format!("{} - Rabital", name);
-                }
+    /// Re-measure text laid out up front after the fonts change; everything else picks them up on the next frame
+    fn refresh_fonts(&mut self) {
+        if let Some(ref mut menubar) = self.menubar {
+            let menubar_width = menubar.total_width(&mut self.font_manager);
+            if let Some(ref mut titlebar) = self.titlebar {
+                titlebar.set_menubar_width(menubar_width);
             }
+            self.sync_menubar_collapse();
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Register the built-in English strings and every catalog in shared/locales
+    fn load_catalogs(&mut self) {
+        match Catalog::from_json(EN_CATALOG) {
+            Ok(catalog) => register_catalog(DEFAULT_LOCALE, catalog),
+            Err(e) => eprintln!("Failed to parse built-in catalog: {}", e),
         }
+        let locales = load_catalogs_from_dir(&self.config_loader.get_locales_dir());
+        println!("Loaded locales: {:?}", locales);
+    }
+    
+    /// Switch to `editor.locale` from the settings
+    fn apply_locale_settings(&mut self) {
+        let locale = self.config_loader.get_settings()
+            .map(|settings| settings.editor.locale.clone())
+            .unwrap_or_else(|| DEFAULT_LOCALE.to_string());
+        self.change_locale(&locale);
+    }
+    
+    /// Set the display language and rebuild the labels that were translated up front
+    fn change_locale(&mut self, new_locale: &str) {
+        let previous_direction = layout_direction();
+        set_locale(new_locale);
         
-        // Fallback to current directory
-        if let Ok(current_dir) = std::env::current_dir() {
-            if let Some(folder_name) = current_dir.file_name() {
-                if let Some(name) = folder_name.to_str() {
-                    return format!("{} - Rabital", name);
-                }
+        if let Some(ref mut menubar) = self.menubar {
+            menubar.set_menus(create_editor_menus());
+            let menubar_width = menubar.total_width(&mut self.font_manager);
+            if let Some(ref mut titlebar) = self.titlebar {
+                titlebar.set_menubar_width(menubar_width);
             }
+            self.sync_menubar_collapse();
+        }
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.reload_commands();
+        }
+        self.keybindings_changed();
+        if let Some(size) = self.window.as_ref().map(|w| w.inner_size()) {
+            if self.alert_dialog.is_some() {
+                self.alert_dialog = Some(Self::create_alert_dialog(self.alert_purpose, size.width as f32, size.height as f32));
+            }
+            // Switching between LTR and RTL locales mirrors the panels
+            if layout_direction() != previous_direction {
+                self.build_ui(size.width as f32, size.height as f32);
+            }
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
         }
-        
-        // Default to "Untitled"
-        "Untitled - Rabital".to_string()
     }
     
-    fn save_state(&mut self) {
-        // Update state with current values
-        if let Some(window) = &self.window {
-            let size = window.inner_size();
-            self.app_state.window_width = size.width;
-            self.app_state.window_height = size.height;
-            
-            if let Ok(pos) = window.outer_position() {
-                self.app_state.window_x = pos.x;
-                self.app_state.window_y = pos.y;
+    fn create_alert_dialog(purpose: AlertPurpose, width: f32, height: f32) -> AlertDialog {
+        match purpose {
+            AlertPurpose::DiscardTab => AlertDialog::new(width, height)
+                .confirm(t!("dialog.discard"), true)
+                .cancel(t!("dialog.keep_editing")),
+            AlertPurpose::RecoverSession => AlertDialog::new(width, height)
+                .confirm(t!("dialog.recover"), false)
+                .cancel(t!("dialog.recover_skip")),
+            AlertPurpose::CrashReport => AlertDialog::new(width, height)
+                .confirm(t!("dialog.open_crash_report"), false)
+                .cancel(t!("dialog.dismiss")),
+            AlertPurpose::WorkspaceTrust => AlertDialog::new(width, height)
+                .confirm(t!("dialog.trust"), false)
+                .cancel(t!("dialog.restricted_mode")),
+        }
+    }
+    
+    /// Show the dialog asking `purpose`, with the buttons that go with it
+    fn show_alert(&mut self, purpose: AlertPurpose, title: &str, description: &str) {
+        if purpose != self.alert_purpose {
+            self.alert_purpose = purpose;
+            if let Some(size) = self.window.as_ref().map(|w| w.inner_size()) {
+                self.alert_dialog = Some(Self::create_alert_dialog(purpose, size.width as f32, size.height as f32));
             }
         }
+        if let Some(ref mut alert_dialog) = self.alert_dialog {
+            alert_dialog.show(title, description, &mut self.font_manager);
+        }
+    }
+    
+    /// Show the display language quick pick
+    fn show_locale_picker(&mut self) {
+        let current = locale();
+        self.locale_options = available_locales();
+        let items = self.locale_options
+            .iter()
+            .enumerate()
+            .map(|(i, code)| {
+                let item = CommandItem::new(LOCALE_PICKER_BASE_ID as u32 + i as u32, code.clone())
+                    .with_icon(CodiconIcons::GLOBE);
+                if *code == current {
+                    item.with_description(t!("picker.current"))
+                } else {
+                    item
+                }
+            })
+            .collect();
         
-        self.app_state.window_maximized = self.is_window_maximized;
-        self.app_state.left_panel_visible = self.layout_config.left_panel_visible;
-        self.app_state.left_panel_width = self.layout_config.left_panel_width;
-        self.app_state.right_panel_visible = self.layout_config.right_panel_visible;
-        self.app_state.right_panel_width = self.layout_config.right_panel_width;
-        self.app_state.bottom_panel_visible = self.layout_config.bottom_panel_visible;
-        self.app_state.bottom_panel_height = self.layout_config.bottom_panel_height;
-        
-        // Save current workspace path
-        if let Ok(current_dir) = std::env::current_dir() {
-            self.app_state.workspace_path = Some(current_dir);
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker(t!("picker.select_language"), items);
+        }
+    }
+    
+    /// Show the file icon theme quick pick
+    fn show_icon_theme_picker(&mut self) {
+        let mut options = self.config_loader.list_icon_themes();
+        if !options.iter().any(|name| name == "default") {
+            options.insert(0, "default".to_string());
         }
+        self.icon_theme_options = options;
+        let items = self.icon_theme_options
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let item = CommandItem::new(ICON_THEME_PICKER_BASE_ID as u32 + i as u32, name.clone())
+                    .with_icon(CodiconIcons::FILE_MEDIA);
+                if *name == self.icon_theme {
+                    item.with_description(t!("picker.current"))
+                } else {
+                    item
+                }
+            })
+            .collect();
         
-        // Save expanded folders from explorer
-        if let Some(ref left_panel) = self.left_panel {
-            self.app_state.expanded_folders = left_panel.explorer().get_expanded_paths();
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker(t!("picker.select_icon_theme"), items);
         }
+    }
+    
+    /// Show the language mode quick pick for the active file
+    fn show_language_mode_picker(&mut self) {
+        let current = self.editor.as_ref().and_then(|editor| editor.active_language());
+        let mut items = vec![
+            CommandItem::new(AUTO_DETECT_LANGUAGE_ID as u32, t!("picker.auto_detect_language"))
+                .with_icon(CodiconIcons::SEARCH),
+        ];
+        items.extend(LANGUAGES.iter().enumerate().map(|(i, (id, name))| {
+            let description = if current.as_deref() == Some(*id) {
+                format!("{} · {}", id, t!("picker.current"))
+            } else {
+                id.to_string()
+            };
+            CommandItem::new(LANGUAGE_MODE_PICKER_BASE_ID as u32 + i as u32, *name)
+                .with_icon(CodiconIcons::FILE_CODE)
+                .with_description(description)
+        }));
         
-        // Save to file
-        if let Err(e) = self.app_state.save() {
-            eprintln!("Failed to save state: {}", e);
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker(t!("picker.select_language_mode"), items);
         }
     }
     
-    #[cfg(target_os = "windows")]
-    fn load_window_icon(&self) -> Option<winit::window::Icon> {
-        // Load icon from embedded bytes
-        const ICON_DATA: &[u8] = include_bytes!("assets/icon.ico");
+    /// Show the usage data settings: opt in or out, and look at or drop what was collected
+    fn show_usage_data_picker(&mut self) {
+        let (toggle, state) = if self.telemetry.is_enabled() {
+            (t!("picker.usage_data_disable"), t!("picker.usage_data_on"))
+        } else {
+            (t!("picker.usage_data_enable"), t!("picker.usage_data_off"))
+        };
+        let items = vec![
+            CommandItem::new(USAGE_DATA_TOGGLE_ID as u32, toggle)
+                .with_icon(CodiconIcons::SETTINGS_GEAR)
+                .with_description(state),
+            CommandItem::new(USAGE_DATA_SHOW_ID as u32, t!("picker.usage_data_show"))
+                .with_icon(CodiconIcons::JSON),
+            CommandItem::new(USAGE_DATA_CLEAR_ID as u32, t!("picker.usage_data_clear"))
+                .with_icon(CodiconIcons::TRASH),
+        ];
         
-        // Try to parse and load the icon
-        match Self::parse_ico(ICON_DATA) {
-            Ok(icon) => {
-                println!("Icon loaded successfully");
-                Some(icon)
-            }
-            Err(e) => {
-                eprintln!("Failed to load icon: {}", e);
-                // Try fallback: create a simple colored icon
-                Self::create_fallback_icon().ok()
-            }
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker(t!("picker.usage_data"), items);
         }
     }
     
-    #[cfg(target_os = "windows")]
-    fn parse_ico(data: &[u8]) -> Result<winit::window::Icon, Box<dyn std::error::Error>> {
-        // Try to load as ICO using image crate
-        let img = image::load_from_memory_with_format(data, image::ImageFormat::Ico)?;
+    /// List every setting with its current value, grouped by section; generated from the schema
+    fn show_settings_picker(&mut self) {
+        let items = SETTINGS_SCHEMA
+            .iter()
+            .filter(|schema| !schema.key.contains('*'))
+            .enumerate()
+            .map(|(i, schema)| {
+                let (section, name) = schema.key.split_once('.').unwrap_or(("", schema.key));
+                let value = self.config_loader.setting_value(schema.key)
+                    .map_or_else(|| schema.default.to_string(), |value| settings_schema::format_value(&value));
+                let icon = match schema.kind {
+                    SettingKind::Bool => CodiconIcons::SYMBOL_BOOLEAN,
+                    SettingKind::Integer { .. } | SettingKind::Number { .. } => CodiconIcons::SYMBOL_NUMERIC,
+                    SettingKind::Text => CodiconIcons::SYMBOL_STRING,
+                    SettingKind::Choice(_) => CodiconIcons::SYMBOL_ENUM,
+                    SettingKind::List | SettingKind::Columns => CodiconIcons::SYMBOL_ARRAY,
+                };
+                let description = match self.config_loader.setting_source(schema.key) {
+                    SettingSource::Workspace => t!("picker.setting_from_workspace", description = schema.description),
+                    _ => schema.description.to_string(),
+                };
+                CommandItem::new(SETTINGS_PICKER_BASE_ID as u32 + i as u32, format!("{}: {}", name, value))
+                    .with_icon(icon)
+                    .with_description(description)
+                    .with_category(section)
+            })
+            .collect();
         
-        // Convert to RGBA8
-        let rgba = img.to_rgba8();
-        let (width, height) = rgba.dimensions();
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker(t!("picker.settings"), items);
+        }
+    }
+    
+    /// Change a setting picked in the Settings picker: switches flip, choices get a picker of
+    /// their own, and everything else opens the settings file at the setting's line
+    fn edit_setting(&mut self, schema: &'static SettingSchema) {
+        match schema.kind {
+            SettingKind::Bool => {
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                let enabled = self.config_loader.setting_value(schema.key)
+                    .and_then(|value| value.as_bool())
+                    .unwrap_or(schema.default == "true");
+                self.write_setting(schema.key, serde_yaml::Value::Bool(!enabled));
+            }
+            SettingKind::Choice(choices) => {
+                let current = self.config_loader.setting_value(schema.key).map(|value| settings_schema::format_value(&value));
+                let items = choices
+                    .iter()
+                    .enumerate()
+                    .map(|(i, choice)| {
+                        let item = CommandItem::new(SETTING_VALUE_PICKER_BASE_ID as u32 + i as u32, *choice)
+                            .with_icon(CodiconIcons::SYMBOL_ENUM);
+                        if current.as_deref() == Some(*choice) {
+                            item.with_description(t!("picker.current"))
+                        } else {
+                            item
+                        }
+                    })
+                    .collect();
+                self.setting_target = Some(schema);
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.show_picker(schema.key, items);
+                }
+            }
+            _ => {
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                let path = self.config_loader.settings_file();
+                let content = std::fs::read_to_string(&path).unwrap_or_default();
+                let (line, column) = match settings_schema::locate(&content, schema.key) {
+                    Some(position) => position,
+                    None => {
+                        // Not in the file yet: add it with its default, so there is a line to edit
+                        let default = serde_yaml::from_str(schema.default).unwrap_or(serde_yaml::Value::Null);
+                        self.write_setting(schema.key, default);
+                        let content = std::fs::read_to_string(&path).unwrap_or_default();
+                        settings_schema::locate(&content, schema.key).unwrap_or((1, 1))
+                    }
+                };
+                self.open_at(path, line - 1, column - 1);
+            }
+        }
+    }
+    
+    /// Save a setting through the Settings picker and apply the reloaded settings
+    fn write_setting(&mut self, key: &str, value: serde_yaml::Value) {
+        match self.config_loader.set_setting(key, &value) {
+            Ok(path) => {
+                self.settings_watcher.acknowledge(&path);
+                self.log_output(&format!("Set {} to {} in {}", key, settings_schema::format_value(&value), path.display()));
+                self.apply_settings();
+                self.report_settings_issues();
+            }
+            Err(e) => {
+                self.log_output(&format!("Failed to save setting {}: {}", key, e));
+                self.toast.show(t!("toast.setting_save_failed", key = key), Some(CodiconIcons::WARNING));
+            }
+        }
+    }
+    
+    /// Apply everything settings.yml controls, after it was loaded or changed
+    fn apply_settings(&mut self) {
+        self.load_settings_theme();
+        self.apply_icon_theme_settings();
+        self.apply_animation_settings();
+        self.apply_accessibility_settings();
+        self.apply_window_material();
+        self.apply_locale_settings();
+        self.apply_font_settings();
+        self.apply_editor_settings();
+    }
+    
+    /// Panel layout as shown, to keep with the profile in use
+    fn panel_layout(&self) -> PanelLayout {
+        PanelLayout {
+            left_panel_visible: self.layout_config.left_panel_visible,
+            left_panel_width: self.layout_config.left_panel_width,
+            right_panel_visible: self.layout_config.right_panel_visible,
+            right_panel_width: self.layout_config.right_panel_width,
+            bottom_panel_visible: self.layout_config.bottom_panel_visible,
+            bottom_panel_height: self.layout_config.bottom_panel_height,
+        }
+    }
+    
+    /// Show the profiles, the one in use marked; for a new window when `new_window` is set
+    fn show_profile_picker(&mut self, new_window: bool) {
+        let current = self.config_loader.profile().to_string();
+        let base = if new_window { PROFILE_WINDOW_PICKER_BASE_ID } else { PROFILE_PICKER_BASE_ID };
+        self.profile_options = self.config_loader.list_profiles();
+        self.profile_options.truncate((PROFILE_WINDOW_PICKER_BASE_ID - PROFILE_PICKER_BASE_ID) as usize);
+        let icon = if new_window { CodiconIcons::WINDOW } else { CodiconIcons::SETTINGS_GEAR };
+        let mut items: Vec<CommandItem> = self.profile_options
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let item = CommandItem::new(base as u32 + i as u32, name.clone()).with_icon(icon);
+                if *name == current {
+                    item.with_description(t!("picker.current"))
+                } else {
+                    item
+                }
+            })
+            .collect();
+        if !new_window {
+            items.push(CommandItem::new(278, t!("profiles.create")).with_icon(CodiconIcons::ADD));
+        }
+        let placeholder = if new_window { t!("picker.new_window_profile") } else { t!("picker.select_profile") };
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker(placeholder, items);
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Use another profile in this window: its settings, keybindings, extensions and panel layout
+    fn switch_profile(&mut self, name: &str) {
+        if name == self.config_loader.profile() {
+            return;
+        }
+        self.app_state.set_panel_layout(self.panel_layout());
+        let layout = self.app_state.switch_profile(name);
+        self.layout_config.left_panel_visible = layout.left_panel_visible;
+        self.layout_config.left_panel_width = layout.left_panel_width;
+        self.layout_config.right_panel_visible = layout.right_panel_visible;
+        self.layout_config.right_panel_width = layout.right_panel_width;
+        self.layout_config.bottom_panel_visible = layout.bottom_panel_visible;
+        self.layout_config.bottom_panel_height = layout.bottom_panel_height;
+        (self.left_splitter, self.right_splitter, self.bottom_splitter) = self.layout_config.create_splitters();
+        
+        self.config_loader.set_profile(name);
+        self.log_output(&format!("Switched to profile {}", name));
+        self.toast.show(t!("toast.profile_switched", name = name), Some(CodiconIcons::SETTINGS_GEAR));
+        self.apply_settings();
+        self.load_keybindings();
+        self.extensions_changed();
+        // After the switch toast so a broken file in the profile isn't hidden by it
+        self.report_settings_issues();
+        if let Some(size) = self.window.as_ref().map(|w| w.inner_size()) {
+            self.build_ui(size.width as f32, size.height as f32);
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Log values the settings schema rejected, with their file, line and column
+    fn report_settings_issues(&mut self) {
+        let issues: Vec<String> = self.config_loader.settings_issues()
+            .iter()
+            .map(|(path, issue)| format!("{}:{}:{}: {}: {}", path.display(), issue.line, issue.column, issue.key, issue.message))
+            .collect();
+        if issues.is_empty() {
+            return;
+        }
+        for issue in &issues {
+            self.log_output(issue);
+        }
+        self.toast.show(t!("toast.settings_invalid", count = issues.len()), Some(CodiconIcons::WARNING));
+    }
+    
+    /// Merge keybindings.json over the default shortcuts, logging entries it had to skip
+    fn load_keybindings(&mut self) {
+        let path = self.config_loader.keybindings_file();
+        let mut problems = self.keybindings.load(&path);
+        problems.extend(self.keybindings.conflicts().iter().map(|conflict| {
+            format!("{}: {} is bound to {}", path.display(), conflict.keys, self.command_titles(&conflict.commands))
+        }));
+        self.keybindings_changed();
+        if problems.is_empty() {
+            return;
+        }
+        for problem in &problems {
+            self.log_output(problem);
+        }
+        self.toast.show(t!("toast.keybindings_invalid", count = problems.len()), Some(CodiconIcons::WARNING));
+    }
+    
+    /// Show the current keybindings in the command palette and on the Keyboard Shortcuts page
+    fn keybindings_changed(&mut self) {
+        let overrides = self.shortcut_overrides();
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.set_shortcut_overrides(overrides);
+        }
+        let rows = self.shortcut_rows();
+        if let Some(ref mut keyboard_shortcuts) = self.keyboard_shortcuts {
+            keyboard_shortcuts.set_rows(rows);
+        }
+    }
+    
+    /// Palette shortcuts of the commands keybindings.json rebinds
+    fn shortcut_overrides(&self) -> HashMap<u32, Option<String>> {
+        CommandPalette::create_default_commands()
+            .iter()
+            .filter(|command| self.keybindings.is_user_defined(command.id))
+            .map(|command| {
+                let keys: Vec<String> = self.keybindings.keys_for(command.id).iter().map(|keys| keys.to_string()).collect();
+                (command.id, (!keys.is_empty()).then(|| keys.join(", ")))
+            })
+            .collect()
+    }
+    
+    fn shortcut_rows(&self) -> Vec<ShortcutRow> {
+        let conflicts = self.keybindings.conflicts();
+        CommandPalette::create_default_commands()
+            .into_iter()
+            .map(|command| {
+                let others: Vec<u32> = conflicts
+                    .iter()
+                    .filter(|conflict| conflict.commands.contains(&command.id))
+                    .flat_map(|conflict| conflict.commands.iter().copied())
+                    .filter(|&other| other != command.id)
+                    .collect();
+                ShortcutRow {
+                    command: command.id,
+                    keys: self.keybindings.keys_for(command.id).into_iter().cloned().collect(),
+                    user_defined: self.keybindings.is_user_defined(command.id),
+                    conflicts: others.iter().map(|&other| self.command_titles(&[other])).collect(),
+                    title: command.label,
+                }
+            })
+            .collect()
+    }
+    
+    /// Palette labels of `commands`, comma separated
+    fn command_titles(&self, commands: &[u32]) -> String {
+        let registered = CommandPalette::create_default_commands();
+        commands
+            .iter()
+            .map(|&id| registered.iter().find(|command| command.id == id).map_or_else(|| id.to_string(), |command| command.label.clone()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+    
+    /// Apply a change made on the Keyboard Shortcuts page and write keybindings.json
+    fn handle_keyboard_shortcuts_action(&mut self, action: Option<KeyboardShortcutsAction>) {
+        let command = match action {
+            Some(KeyboardShortcutsAction::Set(command, keys)) => {
+                self.keybindings.set(command, vec![keys]);
+                command
+            }
+            Some(KeyboardShortcutsAction::Remove(command)) => {
+                self.keybindings.set(command, Vec::new());
+                command
+            }
+            Some(KeyboardShortcutsAction::Reset(command)) => {
+                self.keybindings.reset(command);
+                command
+            }
+            None => return,
+        };
+        
+        let path = self.config_loader.keybindings_file();
+        let titles = CommandPalette::create_default_commands();
+        let saved = self.keybindings.save(&path, |id| {
+            titles.iter().find(|command| command.id == id).map(|command| command.label.clone())
+        });
+        match saved {
+            Ok(()) => {
+                self.settings_watcher.acknowledge(&path);
+                self.log_output(&format!("Saved keybindings to {}", path.display()));
+            }
+            Err(e) => {
+                self.log_output(&format!("Failed to save keybindings to {}: {}", path.display(), e));
+                self.toast.show(t!("toast.keybindings_save_failed"), Some(CodiconIcons::WARNING));
+            }
+        }
+        self.keybindings_changed();
+        
+        if let Some(conflict) = self.keybindings.conflicts().into_iter().find(|conflict| conflict.commands.contains(&command)) {
+            let others: Vec<u32> = conflict.commands.iter().copied().filter(|&other| other != command).collect();
+            let message = t!("toast.keybinding_conflict", keys = conflict.keys, commands = self.command_titles(&others));
+            self.toast.show(message, Some(CodiconIcons::WARNING));
+        }
+    }
+    
+    /// Palette entries of the commands the enabled extensions contribute
+    fn extension_command_items(&self) -> Vec<CommandItem> {
+        self.config_loader
+            .extensions()
+            .commands()
+            .iter()
+            .enumerate()
+            .map(|(i, (extension, command))| {
+                CommandItem::new(EXTENSION_COMMAND_BASE_ID as u32 + i as u32, command.title.clone())
+                    .with_icon(CodiconIcons::EXTENSIONS)
+                    .with_description(extension.manifest.name.clone())
+                    .with_category(t!("category.extensions"))
+            })
+            .collect()
+    }
+    
+    fn extension_rows(&self) -> Vec<ExtensionRow> {
+        let extensions = self.config_loader.extensions();
+        let commands = extensions.commands();
+        extensions
+            .all()
+            .iter()
+            .map(|extension| {
+                let manifest = &extension.manifest;
+                // An enabled extension's commands are numbered in a run, from its first one
+                let first = commands.iter().position(|(owner, _)| owner.manifest.id == manifest.id);
+                ExtensionRow {
+                    id: manifest.id.clone(),
+                    name: manifest.name.clone(),
+                    version: manifest.version.clone(),
+                    publisher: manifest.publisher.clone(),
+                    description: manifest.description.clone(),
+                    enabled: extension.enabled,
+                    update: extension.update.clone(),
+                    commands: manifest.contributes.commands
+                        .iter()
+                        .enumerate()
+                        .map(|(i, command)| (first.map(|first| EXTENSION_COMMAND_BASE_ID as u32 + (first + i) as u32), command.title.clone()))
+                        .collect(),
+                    themes: extension.theme_names(),
+                }
+            })
+            .collect()
+    }
+    
+    /// Show the extensions' current state in the command palette and on the Extensions page
+    fn extensions_changed(&mut self) {
+        let items = self.extension_command_items();
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.set_extension_commands(items);
+        }
+        let rows = self.extension_rows();
+        let checking = self.checking_extensions;
+        if let Some(ref mut extensions_page) = self.extensions_page {
+            extensions_page.set_rows(rows);
+            extensions_page.set_checking(checking);
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Apply a choice made on the Extensions page
+    fn handle_extensions_action(&mut self, action: Option<ExtensionsAction>) {
+        match action {
+            Some(ExtensionsAction::SetEnabled(id, enabled)) => {
+                let name = self.config_loader.extensions().get(&id).map(|extension| extension.manifest.name.clone()).unwrap_or_default();
+                match self.config_loader.extensions_mut().set_enabled(&id, enabled) {
+                    Ok(()) => {
+                        let message = if enabled { t!("toast.extension_enabled", name = name) } else { t!("toast.extension_disabled", name = name) };
+                        self.toast.show(message, Some(CodiconIcons::EXTENSIONS));
+                    }
+                    Err(e) => {
+                        self.log_output(&format!("Failed to save {}: {}", self.config_loader.extensions_state_file().display(), e));
+                        self.toast.show(t!("toast.extensions_save_failed"), Some(CodiconIcons::WARNING));
+                    }
+                }
+                self.extensions_changed();
+            }
+            Some(ExtensionsAction::CheckForUpdates) => self.check_extension_updates(),
+            Some(ExtensionsAction::RunCommand(id)) => self.handle_menu_action(id as i32),
+            Some(ExtensionsAction::UseTheme(name)) => self.write_setting("editor.theme", serde_yaml::Value::String(name)),
+            None => {}
+        }
+    }
+    
+    /// Fetch the published manifest of every extension with an `update_url`, off the UI thread
+    fn check_extension_updates(&mut self) {
+        if self.checking_extensions {
+            return;
+        }
+        let sources = self.config_loader.extensions().update_sources();
+        let ui = self.ui_sender.clone();
+        std::thread::spawn(move || {
+            let (updates, errors) = check_updates(&sources);
+            for error in &errors {
+                ui.send(UiMessage::Log(format!("Failed to check for extension updates: {}", error)));
+            }
+            let (key, args) = match (updates.len(), errors.len()) {
+                (0, 0) => ("toast.extensions_up_to_date", Vec::new()),
+                (0, failed) => ("toast.extension_update_failed", vec![("count", failed.to_string())]),
+                (count, _) => ("toast.extension_updates", vec![("count", count.to_string())]),
+            };
+            ui.send(UiMessage::SetBadge { item: ActivityBarItem::Extensions, count: updates.len() });
+            ui.send(UiMessage::ExtensionUpdates(updates));
+            ui.send(UiMessage::ShowToast { key, args, icon: Some(CodiconIcons::EXTENSIONS) });
+            ui.send(UiMessage::RefreshPanel(ActivityBarItem::Extensions));
+        });
+        self.checking_extensions = true;
+        self.extensions_changed();
+    }
+    
+    /// Apply what background threads asked for since the last event loop cycle
+    fn drain_ui_messages(&mut self) {
+        let messages: Vec<UiMessage> = self.ui_messages.try_iter().collect();
+        if messages.is_empty() {
+            return;
+        }
+        for message in messages {
+            match message {
+                UiMessage::ShowToast { key, args, icon } => self.toast.show(translate_with(key, &args), icon),
+                UiMessage::Log(line) => self.log_output(&line),
+                UiMessage::SetBadge { item, count } => {
+                    if let Some(ref mut activitybar) = self.activitybar {
+                        activitybar.set_badge(item, count);
+                    }
+                }
+                UiMessage::RefreshPanel(ActivityBarItem::Explorer) => {
+                    if let Some(ref mut left_panel) = self.left_panel {
+                        left_panel.explorer_mut().refresh();
+                    }
+                }
+                UiMessage::RefreshPanel(ActivityBarItem::Extensions) => self.extensions_changed(),
+                UiMessage::RefreshPanel(_) => {}
+                UiMessage::Open(links) => self.open_forwarded(links),
+                UiMessage::ExtensionUpdates(updates) => {
+                    self.checking_extensions = false;
+                    self.config_loader.extensions_mut().set_updates(&updates);
+                }
+                UiMessage::Blame { path, modified, lines } => {
+                    self.blame.insert(path, modified, lines);
+                    self.blame_source = None;
+                }
+                UiMessage::FileHistory { path, commits: Ok(commits) } => self.show_history_picker(path, commits),
+                UiMessage::FileHistory { path, commits: Err(e) } => {
+                    self.log_output(&format!("\x1b[31mCouldn't list the history of {}: {}\x1b[0m", path.display(), e));
+                    self.toast.show(t!("toast.no_history"), Some(CodiconIcons::WARNING));
+                }
+                UiMessage::GitActionDone { action, result } => self.git_action_done(action, result),
+                UiMessage::RemoteConnected { host, result } => match result {
+                    Ok(root) => self.open_remote(host, root),
+                    Err(e) => {
+                        self.log_output(&format!("\x1b[31mCouldn't connect to {}: {}\x1b[0m", host.destination(), e));
+                        self.toast.show(t!("toast.remote_connect_failed", host = host.destination()), Some(CodiconIcons::WARNING));
+                    }
+                },
+                UiMessage::RemoteListing { dir, result } => self.remote_listing(dir, result),
+                UiMessage::RemoteDownloaded { path, result } => self.remote_downloaded(path, result),
+                UiMessage::RemoteUploaded { path, result } => self.remote_uploaded(path, result),
+                UiMessage::GitStatus(status) => self.git_status = status,
+                UiMessage::WorkspaceTodos(todos) => {
+                    self.workspace_todos = todos;
+                    self.todos_source = None;
+                }
+            }
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Opt in or out of usage data; the choice is kept in the app state, not in settings.yml
+    fn set_telemetry_enabled(&mut self, enabled: bool) {
+        self.telemetry.set_enabled(enabled);
+        self.app_state.telemetry_enabled = enabled;
+        if enabled {
+            let features = self.usage_features();
+            self.telemetry.start_session(false, features);
+            self.telemetry.flush();
+        }
+        self.save_state();
+        let message = if enabled { t!("toast.usage_data_enabled") } else { t!("toast.usage_data_disabled") };
+        self.toast.show(message, Some(CodiconIcons::SETTINGS_GEAR));
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Settings reported with the usage data; only fixed choices, never names or paths
+    fn usage_features(&self) -> BTreeMap<String, String> {
+        let mut features = BTreeMap::new();
+        let Some(settings) = self.config_loader.get_settings() else {
+            return features;
+        };
+        let mut add = |name: &str, value: String| {
+            features.insert(name.to_string(), value);
+        };
+        add("editor.auto_save", settings.editor.auto_save.clone());
+        add("editor.word_wrap", settings.editor.word_wrap.to_string());
+        add("editor.show_minimap", settings.editor.show_minimap.to_string());
+        add("editor.format_on_save", settings.editor.format_on_save.to_string());
+        add("editor.restore_tabs", settings.editor.restore_tabs.clone());
+        add("editor.locale", settings.editor.locale.clone());
+        add("animations.enabled", settings.animations.enabled.to_string());
+        add("window.material", settings.window.material.clone());
+        features
+    }
+    
+    /// Push file format defaults from the loaded settings into the editor
+    fn apply_editor_settings(&mut self) {
+        set_language_configs(self.config_loader.get_languages().clone());
+        let Some(settings) = self.config_loader.get_settings() else {
+            return;
+        };
+        
+        let encoding = Encoding::from_id(&settings.editor.encoding).unwrap_or_else(|| {
+            eprintln!("Unknown encoding '{}', using UTF-8", settings.editor.encoding);
+            Encoding::default()
+        });
+        let line_ending = LineEnding::from_id(&settings.editor.eol).unwrap_or_default();
+        
+        let render_whitespace = settings.editor.render_whitespace;
+        let highlight_trailing_whitespace = settings.editor.highlight_trailing_whitespace;
+        let sticky_scroll = settings.editor.sticky_scroll;
+        let inlay_hints = settings.editor.inlay_hints;
+        self.inline_blame = settings.git.show_inline_blame;
+        self.blame_source = None;
+        self.todos_source = None;
+        
+        let indent_style = |(insert_spaces, tab_size): (bool, u32)| IndentStyle {
+            use_spaces: insert_spaces,
+            width: tab_size.max(1) as usize,
+        };
+        let default_indent = indent_style(settings.indent_for(None));
+        let language_indents = settings.languages.keys()
+            .map(|lang| (lang.clone(), indent_style(settings.indent_for(Some(lang)))))
+            .collect();
+        let detect_indentation = settings.editor.detect_indentation;
+        let todo_keywords = settings.todos.keywords.clone();
+        let rulers = |columns: &[u32]| columns.iter().map(|&column| column as usize).collect::<Vec<_>>();
+        let default_rulers = rulers(settings.rulers_for(None));
+        let language_rulers = settings.languages.keys()
+            .map(|lang| (lang.clone(), rulers(settings.rulers_for(Some(lang)))))
+            .collect();
+        let auto_save_delay = Duration::from_millis(settings.editor.auto_save_delay as u64);
+        let auto_save = AutoSaveMode::from_id(&settings.editor.auto_save, auto_save_delay).unwrap_or_else(|| {
+            eprintln!("Unknown auto save mode '{}', using off", settings.editor.auto_save);
+            AutoSaveMode::Off
+        });
+        
+        if let Some(ref mut editor) = self.editor {
+            editor.set_defaults(encoding, line_ending);
+            editor.set_indent_defaults(default_indent, language_indents, detect_indentation);
+            editor.set_render_whitespace(render_whitespace);
+            editor.set_rulers(default_rulers, language_rulers);
+            editor.set_todo_keywords(todo_keywords);
+            editor.set_highlight_trailing_whitespace(highlight_trailing_whitespace);
+            editor.set_sticky_scroll(sticky_scroll);
+            editor.set_inlay_hints(inlay_hints);
+            editor.set_auto_save_mode(auto_save);
+        }
+    }
+    
+    /// Save the modified files for auto save
+    fn auto_save_files(&mut self) {
+        let Some(ref mut editor) = self.editor else {
+            return;
+        };
+        let saving: Vec<PathBuf> = editor.tab_manager().tabs().iter()
+            .filter(|tab| tab.is_modified())
+            .filter_map(|tab| tab.buffer.file_path().cloned())
+            .collect();
+        // Cleanups would fight typing that only paused, so the delay mode skips them
+        let cleanups = !matches!(editor.auto_save_mode(), AutoSaveMode::AfterDelay(_));
+        let settings = self.config_loader.get_settings();
+        let failed = editor.save_modified_files(|language| match settings {
+            Some(settings) if cleanups => SaveOptions {
+                trim_trailing_whitespace: settings.trim_trailing_whitespace_for(language),
+                insert_final_newline: settings.insert_final_newline_for(language),
+            },
+            _ => SaveOptions::default(),
+        });
+        for path in saving.iter().filter(|path| !failed.iter().any(|(failed, _)| failed == *path)) {
+            self.file_saved(path);
+        }
+        for (path, e) in failed {
+            self.log_output(&format!("\x1b[31mAuto save failed for {}: {}\x1b[0m", path.display(), e));
+        }
+        if !saving.is_empty() {
+            if let Some(window) = &self.window {
+                window.request_redraw();
+            }
+        }
+    }
+    
+    /// Save-time cleanups for the active document's language
+    fn save_options(&self) -> SaveOptions {
+        let Some(settings) = self.config_loader.get_settings() else {
+            return SaveOptions::default();
+        };
+        let language = self.editor.as_ref().and_then(|editor| editor.active_language());
+        
+        SaveOptions {
+            trim_trailing_whitespace: settings.trim_trailing_whitespace_for(language.as_deref()),
+            insert_final_newline: settings.insert_final_newline_for(language.as_deref()),
+        }
+    }
+    
+    /// Run the active language's formatter; on save a missing formatter is not an error
+    fn format_document(&mut self, on_save: bool) {
+        let language = self.editor.as_ref().and_then(|editor| editor.active_language());
+        let formatter = match self.config_loader.get_settings() {
+            Some(settings) => settings.formatter_for(language.as_deref()),
+            None => language.as_deref().and_then(Formatter::default_for),
+        };
+        let Some(formatter) = formatter else {
+            if !on_save {
+                self.log_output(&format!("No formatter configured for {}", language.as_deref().unwrap_or("this file")));
+            }
+            return;
+        };
+        // Saving still works in Restricted Mode, unformatted
+        if !self.workspace_trusted() {
+            if !on_save {
+                self.require_trust();
+            }
+            return;
+        }
+        
+        let result = self.editor.as_mut().map(|editor| editor.format_active(&formatter));
+        if let Some(Err(e)) = result {
+            self.log_output(&format!("\x1b[31mFormatting failed: {}\x1b[0m", e));
+        }
+    }
+    
+    /// Show the encoding quick pick (reopen or save with encoding)
+    fn show_encoding_picker(&mut self) {
+        let current = self.editor.as_ref()
+            .and_then(|editor| editor.get_document_info())
+            .map(|(encoding, _)| encoding);
+        
+        let mut items = Vec::new();
+        for (i, encoding) in Encoding::ALL.iter().enumerate() {
+            let mut item = CommandItem::new(160 + i as u32, t!("picker.reopen_with_encoding", encoding = encoding.label()))
+                .with_icon(CodiconIcons::REFRESH)
+                .with_category(t!("category.reopen_with_encoding"));
+            if current == Some(*encoding) {
+                item = item.with_description(t!("picker.current"));
+            }
+            items.push(item);
+        }
+        for (i, encoding) in Encoding::ALL.iter().enumerate() {
+            items.push(
+                CommandItem::new(165 + i as u32, t!("picker.save_with_encoding", encoding = encoding.label()))
+                    .with_icon(CodiconIcons::SAVE)
+                    .with_category(t!("category.save_with_encoding")),
+            );
+        }
+        
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker(t!("picker.select_action"), items);
+        }
+    }
+    
+    /// Show the indentation actions quick pick
+    fn show_indentation_picker(&mut self) {
+        let items = vec![
+            CommandItem::new(174, t!("picker.indent_using_spaces"))
+                .with_icon(CodiconIcons::WHITESPACE)
+                .with_category("Indentation"),
+            CommandItem::new(175, t!("picker.indent_using_tabs"))
+                .with_icon(CodiconIcons::WHITESPACE)
+                .with_category("Indentation"),
+            CommandItem::new(176, t!("picker.detect_indentation"))
+                .with_icon(CodiconIcons::SEARCH)
+                .with_category("Indentation"),
+            CommandItem::new(177, t!("picker.reindent_lines"))
+                .with_icon(CodiconIcons::LIST_FLAT)
+                .with_category("Indentation"),
+        ];
+        
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker(t!("picker.select_action"), items);
+        }
+    }
+    
+    /// Show the tab size quick pick; ids 181-188 pick spaces, 191-198 pick tabs
+    fn show_tab_size_picker(&mut self, use_spaces: bool) {
+        let current = self.editor.as_ref().and_then(|editor| editor.active_indent());
+        let base = if use_spaces { 180 } else { 190 };
+        
+        let items = (1..=8)
+            .map(|width| {
+                let item = CommandItem::new(base + width as u32, width.to_string())
+                    .with_category(t!("category.tab_size"));
+                if current == Some(IndentStyle { use_spaces, width }) {
+                    item.with_description(t!("picker.current"))
+                } else {
+                    item
+                }
+            })
+            .collect();
+        
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker(t!("picker.select_tab_size"), items);
+        }
+    }
+    
+    /// Insert Unicode Character: every named character by name, or any typed as a code point
+    fn show_unicode_picker(&mut self) {
+        /// The character and its name, with the code point to the right, which also matches the query
+        fn unicode_item(c: char, name: String) -> CommandItem {
+            let glyph = if c.is_control() || c.is_whitespace() { ' ' } else { c };
+            CommandItem::new(UNICODE_PICKER_BASE_ID as u32 + c as u32, format!("{}  {}", glyph, name))
+                .with_shortcut(code_point_label(c))
+                .with_category(code_point_label(c))
+        }
+        
+        let items = named_chars().iter().map(|(c, name)| unicode_item(*c, name.clone())).collect();
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker_with_query_item(t!("picker.insert_unicode"), items, |query| {
+                parse_code_point(query).map(|c| unicode_item(c, char_name(c).unwrap_or_else(|| t!("unicode.unnamed"))))
+            });
+        }
+    }
+    
+    /// Show the bottom panel with the given view selected
+    fn show_bottom_panel_view(&mut self, view: BottomPanelView) {
+        if !self.layout_config.bottom_panel_visible {
+            self.layout_config.bottom_panel_visible = true;
+            if let Some(size) = self.window.as_ref().map(|w| w.inner_size()) {
+                self.build_ui(size.width as f32, size.height as f32);
+            }
+        }
+        if let Some(ref mut bottom_panel) = self.bottom_panel {
+            bottom_panel.set_active_view(view);
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Type the selection, or the cursor line without one, into the terminal and run it
+    fn run_selection_in_terminal(&mut self) {
+        if !self.require_trust() {
+            return;
+        }
+        let text = self.editor.as_ref()
+            .and_then(|editor| editor.tab_manager().get_active_tab())
+            .filter(|tab| tab.hex.is_none())
+            .map(|tab| if tab.has_selection() { tab.get_selected_text() } else { tab.buffer.line(tab.cursor_line).unwrap_or_default() });
+        let Some(text) = text.filter(|text| !text.trim().is_empty()) else {
+            return;
+        };
+        // The shell reads Enter as a carriage return
+        let input = text.trim_end_matches(['\r', '\n']).replace("\r\n", "\n").replace('\n', "\r");
+        self.send_to_terminal(&format!("{}\r", input));
+    }
+    
+    /// Run the active file in the terminal with its language's interpreter, saving it first
+    fn run_active_file_in_terminal(&mut self) {
+        if !self.require_trust() {
+            return;
+        }
+        let active = self.editor.as_ref()
+            .and_then(|editor| editor.tab_manager().get_active_tab())
+            .filter(|tab| tab.hex.is_none())
+            .map(|tab| (tab.buffer.file_path().cloned(), tab.buffer.language().unwrap_or("text").to_string(), tab.is_modified()));
+        let Some((Some(path), language, modified)) = active else {
+            self.toast.show(t!("toast.run_save_first"), Some(CodiconIcons::SAVE));
+            return;
+        };
+        let Some(command) = run_command(&language, &path) else {
+            self.toast.show(t!("toast.no_interpreter", language = language_display_name(&language)), Some(CodiconIcons::INFO));
+            return;
+        };
+        if modified {
+            // Save
+            self.handle_menu_action(6);
+        }
+        self.send_to_terminal(&format!("{}\r", command));
+    }
+    
+    /// Reveal the terminal and type `input` into its shell
+    fn send_to_terminal(&mut self, input: &str) {
+        self.show_bottom_panel_view(BottomPanelView::Terminal);
+        let sent = self.bottom_panel.as_mut().is_some_and(|panel| panel.send_to_terminal(input));
+        if !sent {
+            self.toast.show(t!("toast.terminal_unavailable"), Some(CodiconIcons::WARNING));
+        }
+    }
+    
+    /// Hand text dragged out of the editor to the terminal or sidebar input under the mouse
+    ///
+    /// Line breaks become spaces in the terminal, so a dropped snippet is typed but never run.
+    fn drop_text(&mut self, text: &str) {
+        let (x, y) = self.mouse_pos;
+        let over_terminal = self.layout_config.bottom_panel_visible
+            && self.bottom_panel.as_ref().is_some_and(|panel| panel.is_over_terminal(x, y));
+        if over_terminal {
+            let line: String = text.lines().collect::<Vec<_>>().join(" ");
+            self.send_to_terminal(&line);
+        } else if self.layout_config.left_panel_visible {
+            if let Some(ref mut left_panel) = self.left_panel {
+                left_panel.drop_text(x, y, text, &mut self.font_manager);
+            }
+        }
+    }
+    
+    /// Show where dragged editor text would land at `point`; `None` clears every preview
+    fn preview_text_drop(&mut self, point: Option<(f32, f32)>) {
+        if let Some(ref mut left_panel) = self.left_panel {
+            left_panel.preview_text_drop(point.filter(|_| self.layout_config.left_panel_visible), &mut self.font_manager);
+        }
+        if let Some(ref mut bottom_panel) = self.bottom_panel {
+            bottom_panel.preview_text_drop(point.filter(|_| self.layout_config.bottom_panel_visible));
+        }
+    }
+    
+    /// Print a message and append it to the Output view when the panel is open
+    fn log_output(&mut self, message: &str) {
+        println!("{}", message);
+        crash::log(message);
+        if let Some(ref mut bottom_panel) = self.bottom_panel {
+            bottom_panel.append_output(message);
+            bottom_panel.append_output("\n");
+        }
+    }
+    
+    /// Show the "Reopen Editor With..." quick pick
+    fn show_reopen_with_picker(&mut self) {
+        let is_hex = self.editor.as_ref().is_some_and(|editor| editor.is_active_hex());
+        let items = vec![
+            CommandItem::new(201, t!("picker.text_editor"))
+                .with_icon(CodiconIcons::FILE_CODE)
+                .with_description(if is_hex { String::new() } else { t!("picker.active") }),
+            CommandItem::new(202, t!("picker.hex_editor"))
+                .with_icon(CodiconIcons::FILE_BINARY)
+                .with_description(if is_hex { t!("picker.active") } else { String::new() }),
+        ];
+        
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker(t!("picker.select_editor"), items);
+        }
+    }
+    
+    /// Show the active file rendered in a preview tab, or say why it can't be
+    fn open_active_preview(&mut self) {
+        let Some(ref mut editor) = self.editor else {
+            return;
+        };
+        let Some(path) = editor.tab_manager().get_active_tab().and_then(|tab| tab.buffer.file_path().cloned()) else {
+            return;
+        };
+        let name = path.file_name().unwrap_or_default().to_string_lossy().into_owned();
+        if !Preview::supports(&path) {
+            self.toast.show(t!("toast.no_preview", name = name), Some(CodiconIcons::INFO));
+            return;
+        }
+        if let Err(e) = editor.open_preview(path) {
+            self.log_output(&format!("\x1b[31mCouldn't preview {}: {}\x1b[0m", name, e));
+            self.toast.show(t!("toast.preview_failed", name = name), Some(CodiconIcons::WARNING));
+        }
+    }
+    
+    /// Show the Go to Line input for the active document
+    /// Panel splitters paired with whether their panel is shown
+    fn splitters_mut(&mut self) -> [(bool, &mut Splitter); 3] {
+        [
+            (self.layout_config.left_panel_visible, &mut self.left_splitter),
+            (self.layout_config.right_panel_visible, &mut self.right_splitter),
+            (self.layout_config.bottom_panel_visible, &mut self.bottom_splitter),
+        ]
+    }
+    
+    /// Start a splitter drag, or reset its size on double-click; returns true if a handle was hit
+    fn press_splitters(&mut self, x: f32, y: f32) -> bool {
+        let Some(splitter) = self.splitters_mut()
+            .into_iter()
+            .find(|(visible, splitter)| *visible && splitter.contains(x, y))
+            .map(|(_, splitter)| splitter)
+        else {
+            return false;
+        };
+        
+        splitter.handle_mouse_down(x, y);
+        if !splitter.is_dragging() {
+            // Double-click restored the default size
+            self.apply_splitter_sizes();
+        }
+        true
+    }
+    
+    /// Feed pointer motion to the splitters, relayout on resize and show the resize cursor
+    fn update_splitters(&mut self, x: f32, y: f32) {
+        let mut changed = false;
+        let mut cursor = None;
+        for (visible, splitter) in self.splitters_mut() {
+            if visible || splitter.is_dragging() {
+                changed |= splitter.handle_mouse_move(x, y);
+                cursor = cursor.or(splitter.cursor());
+            }
+        }
+        
+        if changed {
+            self.apply_splitter_sizes();
+        }
+        if let (Some(cursor), Some(window)) = (cursor, &self.window) {
+            window.set_cursor(cursor);
+        }
+    }
+    
+    /// Copy splitter sizes into the layout; a collapsed splitter hides its panel
+    fn apply_splitter_sizes(&mut self) {
+        self.layout_config.left_panel_visible = !self.left_splitter.is_collapsed();
+        self.layout_config.left_panel_width = self.left_splitter.size();
+        self.layout_config.right_panel_visible = !self.right_splitter.is_collapsed();
+        self.layout_config.right_panel_width = self.right_splitter.size();
+        self.layout_config.bottom_panel_visible = !self.bottom_splitter.is_collapsed();
+        self.layout_config.bottom_panel_height = self.bottom_splitter.size();
+        
+        if let Some(window) = &self.window {
+            let size = window.inner_size();
+            self.build_ui(size.width as f32, size.height as f32);
+        }
+    }
+    
+    /// Apply the button chosen in the unsaved-changes dialog
+    fn handle_alert_dialog_action(&mut self, action: Option<AlertDialogAction>) {
+        match (self.alert_purpose, action) {
+            (AlertPurpose::DiscardTab, Some(AlertDialogAction::Confirm)) => {
+                if let Some(ref mut editor) = self.editor {
+                    editor.close_active_tab();
+                    println!("Closed active tab, discarding changes");
+                }
+            }
+            (AlertPurpose::RecoverSession, Some(AlertDialogAction::Confirm)) => {
+                self.recover_session();
+                self.offer_crash_report();
+            }
+            (AlertPurpose::RecoverSession, Some(AlertDialogAction::Cancel)) => {
+                // Backups are written again from here on, replacing the old ones
+                self.recovered_backups = None;
+                self.offer_crash_report();
+            }
+            (AlertPurpose::CrashReport, Some(action)) => {
+                if let Some(path) = self.crash_report.take() {
+                    self.log_output(&format!("Crash report saved at {}", path.display()));
+                    if action == AlertDialogAction::Confirm {
+                        self.open_file(path);
+                    }
+                }
+                self.offer_workspace_trust(false);
+            }
+            (AlertPurpose::WorkspaceTrust, Some(action)) => {
+                if let Some(path) = self.app_state.workspace_path.clone() {
+                    let trusted = action == AlertDialogAction::Confirm;
+                    self.app_state.set_workspace_trust(path, trusted);
+                    self.apply_workspace_trust();
+                    if trusted {
+                        self.blame_source = None;
+                        self.refresh_git_status();
+                    } else {
+                        self.git_status = None;
+                        self.toast.show(t!("toast.restricted_mode"), Some(CodiconIcons::WARNING));
+                    }
+                }
+            }
+            _ => {}
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// After an unclean shutdown, offer to bring back unsaved tabs and reset the layout
+    fn offer_session_recovery(&mut self) {
+        let Some(backups) = self.recovered_backups.as_ref() else {
+            self.offer_crash_report();
+            return;
+        };
+        let message = if backups.is_empty() {
+            t!("dialog.recover_layout_message")
+        } else {
+            t!("dialog.recover_message", count = backups.len())
+        };
+        self.show_alert(AlertPurpose::RecoverSession, &t!("dialog.recover_title"), &message);
+    }
+    
+    /// After a panic, offer to open the crash report the panic hook wrote
+    fn offer_crash_report(&mut self) {
+        let Some(path) = self.crash_report.as_ref() else {
+            self.offer_workspace_trust(false);
+            return;
+        };
+        let message = t!("dialog.crash_report_message", path = path.display());
+        self.show_alert(AlertPurpose::CrashReport, &t!("dialog.crash_report_title"), &message);
+    }
+    
+    /// Ask whether to trust the opened folder, unless it was answered before or `again` asks anew
+    fn offer_workspace_trust(&mut self, again: bool) {
+        let Some(path) = self.app_state.workspace_path.as_ref() else {
+            return;
+        };
+        if !again && self.app_state.workspace_trust(path).is_some() {
+            return;
+        }
+        let message = t!("dialog.trust_message", path = path.display());
+        self.show_alert(AlertPurpose::WorkspaceTrust, &t!("dialog.trust_title"), &message);
+    }
+    
+    /// False in Restricted Mode: the opened folder wasn't trusted, or the prompt wasn't answered yet
+    ///
+    /// Formatters, git, the terminal's shell and the workspace's settings run or name programs
+    /// the folder can configure, so they wait for trust. Without a folder everything is allowed.
+    fn workspace_trusted(&self) -> bool {
+        self.app_state.workspace_path.as_ref().is_none_or(|path| self.app_state.workspace_trust(path) == Some(true))
+    }
+    
+    /// Start or hold back what Restricted Mode covers: the terminal's shell and the workspace's own settings
+    fn apply_workspace_trust(&mut self) {
+        let trusted = self.workspace_trusted();
+        if let Some(ref mut bottom_panel) = self.bottom_panel {
+            bottom_panel.set_shell_allowed(trusted);
+        }
+        if self.config_loader.set_workspace_trusted(trusted) {
+            self.apply_settings();
+        }
+    }
+    
+    /// True when trusted; in Restricted Mode tell why nothing happens instead
+    fn require_trust(&mut self) -> bool {
+        let trusted = self.workspace_trusted();
+        if !trusted {
+            self.toast.show(t!("toast.restricted_mode"), Some(CodiconIcons::WARNING));
+        }
+        trusted
+    }
+    
+    /// Reopen the backed-up tabs as unsaved edits and put the panels back to their defaults
+    fn recover_session(&mut self) {
+        let backups = self.recovered_backups.take().unwrap_or_default();
+        if let Some(ref mut editor) = self.editor {
+            for backup in &backups {
+                if let Err(e) = editor.restore_text(backup.path.as_deref(), &backup.title, &backup.text) {
+                    eprintln!("Failed to restore {}: {}", backup.title, e);
+                }
+            }
+        }
+        self.log_output(&format!("Recovered {} unsaved file(s)", backups.len()));
+        
+        // A crash may have left the saved layout broken
+        let defaults = AppState::default();
+        self.layout_config = LayoutConfig::default();
+        self.layout_config.left_panel_visible = defaults.left_panel_visible;
+        self.layout_config.left_panel_width = defaults.left_panel_width;
+        self.layout_config.right_panel_visible = defaults.right_panel_visible;
+        self.layout_config.right_panel_width = defaults.right_panel_width;
+        self.layout_config.bottom_panel_visible = defaults.bottom_panel_visible;
+        self.layout_config.bottom_panel_height = defaults.bottom_panel_height;
+        (self.left_splitter, self.right_splitter, self.bottom_splitter) = self.layout_config.create_splitters();
+        self.is_window_maximized = defaults.window_maximized;
+        self.app_state.expanded_folders.clear();
+        if let Some(window) = &self.window {
+            window.set_maximized(false);
+            let size = window.inner_size();
+            self.build_ui(size.width as f32, size.height as f32);
+        }
+        self.save_state();
+    }
+    
+    /// Rebuild the outline when the active document changed and track the cursor in it
+    fn refresh_outline(&mut self) {
+        let (Some(right_panel), Some(editor)) = (self.right_panel.as_mut(), self.editor.as_ref()) else {
+            return;
+        };
+        let tab = editor.tab_manager().get_active_tab();
+        let source = tab.map(|tab| (tab.id, tab.buffer.version(), tab.buffer.language().map(str::to_string)));
+        if source != self.outline_source {
+            right_panel.outline_mut().set_symbols(editor.document_symbols());
+            self.outline_source = source;
+        }
+        if let Some(tab) = tab {
+            right_panel.outline_mut().set_cursor_line(tab.cursor_line);
+        }
+    }
+    
+    /// Rebuild the TODOs page while it shows and an open document changed
+    ///
+    /// Open documents are listed as edited; other files as the last workspace scan read them.
+    fn refresh_todos(&mut self) {
+        let (Some(left_panel), Some(editor)) = (self.left_panel.as_mut(), self.editor.as_mut()) else {
+            return;
+        };
+        if !left_panel.is_todos_visible() {
+            return;
+        }
+        editor.refresh_todos();
+        let tabs = editor.tab_manager().tabs();
+        let source: Vec<(usize, u64)> = tabs.iter().map(|tab| (tab.id, tab.buffer.version())).collect();
+        if self.todos_source.as_ref() == Some(&source) {
+            return;
+        }
+        let open: Vec<(PathBuf, Vec<TodoComment>)> = tabs.iter()
+            .filter(|tab| !tab.is_hex())
+            .filter_map(|tab| Some((tab.buffer.file_path()?.clone(), tab.todos().to_vec())))
+            .collect();
+        let scanned: Vec<(PathBuf, Vec<TodoComment>)> = self.workspace_todos.iter()
+            .filter(|(path, _)| !open.iter().any(|(open, _)| open == path))
+            .cloned()
+            .collect();
+        let mut files = open;
+        files.extend(scanned);
+        left_panel.todos_mut().set_files(files);
+        self.todos_source = Some(source);
+    }
+    
+    /// Show the TODOs page in the left panel, scanning the workspace first when settings ask for it
+    fn show_todos(&mut self) {
+        self.show_left_panel();
+        if let Some(ref mut left_panel) = self.left_panel {
+            left_panel.set_view(SidebarView::Todos);
+        }
+        let scan = self.config_loader.get_settings().is_some_and(|settings| settings.todos.scan_workspace);
+        if scan {
+            self.scan_workspace_todos();
+        }
+        self.todos_source = None;
+    }
+    
+    /// Read the indexed workspace files for to-do comments off the UI thread
+    fn scan_workspace_todos(&mut self) {
+        let (Some(indexer), Some(editor)) = (self.indexer.as_ref(), self.editor.as_ref()) else {
+            return;
+        };
+        let paths: Vec<PathBuf> = indexer.index().files().into_iter().map(Path::to_path_buf).collect();
+        let keywords = editor.todo_keywords().to_vec();
+        let (ui, vfs) = (self.ui_sender.clone(), self.vfs.clone());
+        std::thread::spawn(move || {
+            let todos = paths
+                .into_iter()
+                .filter_map(|path| {
+                    if vfs.metadata(&path).ok()?.len > MAX_TODO_SCAN_SIZE {
+                        return None;
+                    }
+                    let text = String::from_utf8(vfs.read(&path).ok()?).ok()?;
+                    let todos = find_todos(text.lines(), &keywords);
+                    (!todos.is_empty()).then_some((path, todos))
+                })
+                .collect();
+            ui.send(UiMessage::WorkspaceTodos(todos));
+        });
+    }
+    
+    /// Folder the bookmarks are kept under; files opened without one share an empty path
+    fn bookmark_workspace(&self) -> PathBuf {
+        self.app_state.workspace_path.clone().unwrap_or_default()
+    }
+    
+    /// Give newly opened tabs their saved bookmarks, save the ones toggled since, and refresh the Bookmarks page
+    fn sync_bookmarks(&mut self) {
+        let workspace = self.bookmark_workspace();
+        let Some(ref mut editor) = self.editor else {
+            return;
+        };
+        for tab in editor.tab_manager_mut().tabs_mut() {
+            // A tab reopened as text from hex gets the saved ones again; an untitled tab keeps its own once saved
+            if tab.is_hex() {
+                self.bookmark_tabs.remove(&tab.id);
+                continue;
+            }
+            let loaded = !self.bookmark_tabs.insert(tab.id);
+            let Some(path) = tab.buffer.file_path().cloned() else {
+                continue;
+            };
+            if loaded {
+                self.app_state.set_file_bookmarks(&workspace, &path, &tab.bookmarks());
+            } else {
+                tab.set_bookmarks(&self.app_state.file_bookmarks(&workspace, &path));
+            }
+        }
+        let open: HashSet<usize> = editor.tab_manager().tabs().iter().map(|tab| tab.id).collect();
+        self.bookmark_tabs.retain(|id| open.contains(id));
+        
+        if let Some(ref mut left_panel) = self.left_panel {
+            let bookmarks = self.app_state.bookmarks(&workspace);
+            if left_panel.is_bookmarks_visible() && left_panel.bookmarks().bookmarks() != bookmarks {
+                left_panel.bookmarks_mut().set_bookmarks(bookmarks.to_vec());
+            }
+        }
+    }
+    
+    /// Bookmark the cursor line, or remove its bookmark
+    fn toggle_bookmark(&mut self) {
+        if let Some(tab) = self.editor.as_mut().and_then(|editor| editor.tab_manager_mut().get_active_tab_mut()) {
+            let line = tab.cursor_line;
+            tab.toggle_bookmark(line);
+        }
+    }
+    
+    /// Move to the next or previous bookmark of the workspace, going on into other files
+    fn go_to_bookmark(&mut self, forward: bool) {
+        self.sync_bookmarks();
+        let workspace = self.bookmark_workspace();
+        let position = self.editor.as_ref()
+            .and_then(|editor| editor.tab_manager().get_active_tab())
+            .and_then(|tab| Some((tab.buffer.file_path()?.clone(), tab.cursor_line)));
+        let (file, line) = position.map_or((None, 0), |(path, line)| (Some(path), line));
+        match self.app_state.next_bookmark(&workspace, file.as_deref(), line, forward).cloned() {
+            Some(bookmark) => self.open_at(bookmark.path, bookmark.line, 0),
+            None => self.toast.show(t!("toast.no_bookmarks"), Some(CodiconIcons::INFO)),
+        }
+    }
+    
+    /// Delete a bookmark from the Bookmarks page, and from its tab when the file is open
+    fn remove_bookmark(&mut self, bookmark: &Bookmark) {
+        let workspace = self.bookmark_workspace();
+        let mut lines = self.app_state.file_bookmarks(&workspace, &bookmark.path);
+        lines.retain(|(line, _)| *line != bookmark.line);
+        self.app_state.set_file_bookmarks(&workspace, &bookmark.path, &lines);
+        let tab = self.editor.as_mut().and_then(|editor| {
+            editor.tab_manager_mut().tabs_mut().iter_mut().find(|tab| tab.buffer.file_path() == Some(&bookmark.path))
+        });
+        if let Some(tab) = tab {
+            tab.set_bookmarks(&lines);
+        }
+    }
+    
+    /// Show the Bookmarks page in the left panel
+    fn show_bookmarks(&mut self) {
+        self.show_left_panel();
+        if let Some(ref mut left_panel) = self.left_panel {
+            left_panel.set_view(SidebarView::Bookmarks);
+        }
+        self.sync_bookmarks();
+    }
+    
+    /// Show who last changed the cursor line after its end, while inline blame is on
+    /// Lines of a tab with unsaved changes no longer match the file git blamed, so those go without.
+    fn refresh_inline_blame(&mut self) {
+        let show = self.inline_blame && self.workspace_trusted();
+        let Some(editor) = self.editor.as_mut() else {
+            return;
+        };
+        let source = editor
+            .tab_manager()
+            .get_active_tab()
+            .filter(|_| show)
+            .map(|tab| (tab.id, tab.cursor_line, tab.buffer.version(), tab.is_modified()));
+        if source == self.blame_source {
+            return;
+        }
+        self.blame_source = source;
+        for tab in editor.tab_manager_mut().tabs_mut() {
+            tab.remove_decorations("blame");
+        }
+        let Some(tab) = editor.tab_manager_mut().get_active_tab_mut().filter(|_| show) else {
+            return;
+        };
+        let Some(path) = tab.buffer.file_path().filter(|_| !tab.is_modified() && !tab.is_hex()).cloned() else {
+            return;
+        };
+        let line = tab.cursor_line;
+        let Some(blame) = self.blame.line(&path, line, &self.ui_sender) else {
+            return;
+        };
+        let text = if blame.is_committed() {
+            t!("blame.inline", author = blame.author, age = relative_age(blame.time), summary = blame.summary)
+        } else {
+            t!("blame.uncommitted")
+        };
+        let start = tab.buffer.line_to_char(line);
+        let decoration = Decoration::new(start..start + tab.line_length(line)).after_line(text, with_alpha(self.theme_colors.muted_foreground, 160));
+        tab.add_decoration("blame", decoration);
+    }
+    
+    /// List the commits that changed the active file, once git has found them
+    fn show_file_history(&mut self) {
+        if !self.require_trust() {
+            return;
+        }
+        let path = self.editor.as_ref()
+            .and_then(|editor| editor.tab_manager().get_active_tab())
+            .and_then(|tab| tab.buffer.file_path().cloned());
+        let Some(path) = path else {
+            self.log_output("File History: save the file first");
+            return;
+        };
+        let ui = self.ui_sender.clone();
+        std::thread::spawn(move || {
+            let commits = file_history(&path).map_err(|e| e.to_string());
+            ui.send(UiMessage::FileHistory { path, commits });
+        });
+    }
+    
+    fn show_history_picker(&mut self, path: PathBuf, commits: Vec<FileCommit>) {
+        if commits.is_empty() {
+            self.toast.show(t!("toast.no_history"), Some(CodiconIcons::HISTORY));
+            return;
+        }
+        let items = commits
+            .iter()
+            .enumerate()
+            .map(|(i, commit)| {
+                let hash = commit.commit.get(..7).unwrap_or(&commit.commit);
+                CommandItem::new(HISTORY_PICKER_BASE_ID as u32 + i as u32, commit.summary.clone())
+                    .with_icon(CodiconIcons::SOURCE_CONTROL)
+                    .with_description(format!("{} · {} · {}", hash, commit.author, relative_age(commit.time)))
+            })
+            .collect();
+        let name = path.file_name().map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        self.history_options = Some((path, commits));
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker(t!("picker.file_history", name = name), items);
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Show what the `index`th commit of the File History picker changed in its file
+    fn open_commit_diff(&mut self, index: usize) {
+        let Some((file, commits)) = self.history_options.as_ref() else {
+            return;
+        };
+        let Some(commit) = commits.get(index) else {
+            return;
+        };
+        // The commit before it in the file's history has the name the file had in its parent
+        let old_path = commits.get(index + 1).map_or(commit.path.as_str(), |older| older.path.as_str());
+        // A commit that added the file has nothing before it
+        let old_text = show_file(file, &format!("{}^", commit.commit), old_path).unwrap_or_default();
+        let new_text = match show_file(file, &commit.commit, &commit.path) {
+            Ok(text) => text,
+            // Deleted in this commit
+            Err(_) => String::new(),
+        };
+        let name = Path::new(&commit.path).file_name().map_or_else(|| commit.path.clone(), |name| name.to_string_lossy().into_owned());
+        let title = format!("{} ({})", name, commit.commit.get(..7).unwrap_or(&commit.commit));
+        self.open_text_diff(&old_text, &new_text, title);
+    }
+    
+    /// Folder git commands run in: the workspace, or the working directory without one
+    fn git_dir(&self) -> Option<PathBuf> {
+        self.app_state.workspace_path.clone().or_else(|| std::env::current_dir().ok())
+    }
+    
+    /// Look up the workspace's branch for the status bar, off the UI thread
+    fn refresh_git_status(&mut self) {
+        if !self.workspace_trusted() {
+            self.git_status = None;
+            return;
+        }
+        if let Some(dir) = self.git_dir() {
+            git::refresh_status(dir, &self.ui_sender);
+        }
+    }
+    
+    /// Run a git action in the workspace, reporting it through a toast and the Output panel
+    fn run_git_action(&mut self, action: GitAction) {
+        if !self.require_trust() {
+            return;
+        }
+        if self.git_busy {
+            self.toast.show(t!("git.busy"), Some(CodiconIcons::SYNC));
+            return;
+        }
+        let Some(dir) = self.git_dir().filter(|_| self.git_status.is_some()) else {
+            self.toast.show(t!("git.no_repository"), Some(CodiconIcons::SOURCE_CONTROL));
+            return;
+        };
+        self.git_busy = true;
+        self.log_output(&format!("> {}", action.command_line()));
+        self.toast.show(t!(action.progress_key()), Some(CodiconIcons::SYNC));
+        git::run(action, dir, &self.ui_sender);
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    fn git_action_done(&mut self, action: GitAction, result: Result<String, String>) {
+        self.git_busy = false;
+        match result {
+            Ok(output) => {
+                for line in output.lines().filter(|line| !line.trim().is_empty()) {
+                    self.log_output(line);
+                }
+                self.toast.show(t!(action.done_key()), Some(CodiconIcons::SOURCE_CONTROL));
+            }
+            Err(e) => {
+                self.log_output(&format!("\x1b[31m{} failed: {}\x1b[0m", action.command_line(), e));
+                self.toast.show(t!("git.failed", command = action.command_line()), Some(CodiconIcons::WARNING));
+            }
+        }
+    }
+    
+    /// Local branches of the workspace repository, to check one out or create a new one
+    fn show_branch_picker(&mut self) {
+        if !self.require_trust() {
+            return;
+        }
+        let Some(dir) = self.git_dir().filter(|_| self.git_status.is_some()) else {
+            self.toast.show(t!("git.no_repository"), Some(CodiconIcons::SOURCE_CONTROL));
+            return;
+        };
+        let current = self.git_status.as_ref().map(|status| status.head.clone());
+        self.branch_options = branches(&dir).unwrap_or_default();
+        let mut items = vec![CommandItem::new(270, t!("git.create_branch")).with_icon(CodiconIcons::ADD)];
+        items.extend(self.branch_options.iter().enumerate().map(|(i, branch)| {
+            let item = CommandItem::new(BRANCH_PICKER_BASE_ID as u32 + i as u32, branch.clone()).with_icon(CodiconIcons::GIT_BRANCH);
+            if current.as_deref() == Some(branch.as_str()) {
+                item.with_description(t!("git.current_branch"))
+            } else {
+                item
+            }
+        }));
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker(t!("picker.checkout"), items);
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Write the unsaved tabs to the backup file when they changed since the last heartbeat
+    fn backup_modified_tabs(&mut self) {
+        // Keep the crashed session's backups until the recovery dialog is answered
+        if self.recovered_backups.is_some() {
+            return;
+        }
+        let Some(ref editor) = self.editor else {
+            return;
+        };
+        let modified: Vec<&EditorTab> = editor.tab_manager().tabs().iter().filter(|tab| tab.hex.is_none() && tab.is_modified()).collect();
+        let versions: Vec<(usize, u64)> = modified.iter().map(|tab| (tab.id, tab.buffer.version())).collect();
+        if versions == self.backup_versions {
+            return;
+        }
+        let backups: Vec<BufferBackup> = modified
+            .iter()
+            .map(|tab| BufferBackup {
+                path: tab.buffer.file_path().cloned(),
+                title: tab.title.clone(),
+                text: tab.buffer.to_string(),
+            })
+            .collect();
+        match session::save_backups(&backups) {
+            Ok(()) => self.backup_versions = versions,
+            Err(e) => eprintln!("Failed to back up unsaved tabs: {}", e),
+        }
+    }
+    
+    /// Clean exit: drop the backups and the session lock
+    fn end_session(&mut self) {
+        self.telemetry.flush();
+        // An unanswered recovery dialog is offered again next time
+        if self.recovered_backups.is_some() {
+            return;
+        }
+        if let Err(e) = session::save_backups(&[]) {
+            eprintln!("Failed to remove backups: {}", e);
+        }
+        self.session_lock.release();
+    }
+    
+    /// Reopen the last session's tabs: all of them, only the pinned ones, or none, per `editor.restore_tabs`
+    fn restore_tabs(&mut self) {
+        let mode = self.config_loader.get_settings().map_or("all".to_string(), |settings| settings.editor.restore_tabs.clone());
+        let files = match mode.as_str() {
+            "none" => return,
+            "pinned" => self.app_state.pinned_files.clone(),
+            _ => self.app_state.open_files.clone(),
+        };
+        let Some(ref mut editor) = self.editor else {
+            return;
+        };
+        
+        let mut restored = 0;
+        for path in files.into_iter().filter(|path| path.is_file()) {
+            let pinned = self.app_state.pinned_files.contains(&path);
+            match editor.open_file(path) {
+                Ok(()) => {
+                    restored += 1;
+                    let tabs = editor.tab_manager_mut();
+                    if pinned {
+                        tabs.set_pinned(tabs.active_index(), true);
+                    }
+                }
+                Err(e) => eprintln!("Failed to restore tab: {}", e),
+            }
+        }
+        if restored == 0 {
+            return;
+        }
+        
+        // The restored files replace the welcome tab
+        let tabs = editor.tab_manager_mut();
+        tabs.close_tabs_where(|_, tab| tab.buffer.file_path().is_none() && !tab.is_modified());
+        let active = self.app_state.active_file.as_ref()
+            .and_then(|active| tabs.tabs().iter().position(|tab| tab.buffer.file_path() == Some(active)));
+        if let Some(index) = active {
+            tabs.set_active_tab(index);
+        }
+        println!("Restored {} tab(s)", restored);
+    }
+    
+    /// Close the active tab; unsaved changes need confirmation first
+    fn request_close_active_tab(&mut self) {
+        let modified_title = self.editor.as_ref()
+            .and_then(|editor| editor.tab_manager().get_active_tab())
+            .filter(|tab| tab.is_modified())
+            .map(|tab| tab.title.clone());
+        if let Some(title) = modified_title {
+            self.show_alert(AlertPurpose::DiscardTab, &t!("dialog.discard_title"), &t!("dialog.discard_message", title = title));
+        } else if let Some(ref mut editor) = self.editor {
+            editor.close_active_tab();
+            println!("Closed active tab");
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Bring back the most recently closed file tab, scrolled and selected as it was
+    fn reopen_closed_tab(&mut self) {
+        let Some(ref mut editor) = self.editor else {
+            return;
+        };
+        match editor.tab_manager_mut().reopen_closed_tab() {
+            Some(Ok(_)) => {}
+            Some(Err(e)) => {
+                self.log_output(&format!("\x1b[31mCouldn't reopen the closed tab: {}\x1b[0m", e));
+                self.toast.show(t!("toast.reopen_failed"), Some(CodiconIcons::WARNING));
+            }
+            None => self.toast.show(t!("toast.no_closed_tabs"), Some(CodiconIcons::HISTORY)),
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Open the tab context menu for the tab at `index`, kept inside the window
+    fn show_tab_menu(&mut self, index: usize) {
+        let Some(tab) = self.editor.as_ref().and_then(|editor| editor.tab_manager().get_tab(index)) else {
+            return;
+        };
+        let has_path = tab.buffer.file_path().is_some();
+        let pin_label = if tab.pinned { t!("tab_menu.unpin") } else { t!("tab_menu.pin") };
+        let has_explorer = self.app_state.workspace_path.is_some();
+        let enabled_if = |item: MenuItem, enabled: bool| if enabled { item } else { item.disabled() };
+        
+        self.tab_menu.set_items(vec![
+            MenuItem::new(t!("tab_menu.close"), TAB_MENU_CLOSE).with_shortcut("Ctrl+W"),
+            MenuItem::new(t!("tab_menu.close_others"), TAB_MENU_CLOSE_OTHERS),
+            MenuItem::new(t!("tab_menu.close_to_right"), TAB_MENU_CLOSE_TO_RIGHT),
+            MenuItem::new(t!("tab_menu.close_saved"), TAB_MENU_CLOSE_SAVED),
+            MenuItem::separator(),
+            MenuItem::new(pin_label, TAB_MENU_TOGGLE_PIN),
+            MenuItem::separator(),
+            enabled_if(MenuItem::new(t!("tab_menu.copy_path"), TAB_MENU_COPY_PATH), has_path),
+            enabled_if(MenuItem::new(t!("tab_menu.copy_relative_path"), TAB_MENU_COPY_RELATIVE_PATH), has_path),
+            MenuItem::separator(),
+            enabled_if(MenuItem::new(t!("tab_menu.reveal_in_explorer"), TAB_MENU_REVEAL_IN_EXPLORER), has_path && has_explorer),
+            enabled_if(MenuItem::new(t!("tab_menu.reveal_in_file_manager"), TAB_MENU_REVEAL_IN_FILE_MANAGER), has_path),
+        ]);
+        self.tab_menu_target = index;
+        
+        let (x, y) = self.menu_origin(self.tab_menu.size());
+        self.tab_menu.show(x, y);
+        self.tab_menu.update_hover(self.mouse_pos.0, self.mouse_pos.1);
+    }
+    
+    /// Top-left corner for a context menu opened at the mouse, kept inside the window
+    fn menu_origin(&self, (menu_width, menu_height): (f32, f32)) -> (f32, f32) {
+        let (window_width, window_height) = self.window.as_ref()
+            .map(|window| window.inner_size())
+            .map_or((WINDOW_WIDTH, WINDOW_HEIGHT), |size| (size.width as f32, size.height as f32));
+        let x = self.mouse_pos.0.min(window_width - menu_width).max(0.0);
+        let y = self.mouse_pos.1.min(window_height - menu_height).max(0.0);
+        (x, y)
+    }
+    
+    /// Open the explorer context menu for the selected rows
+    fn show_explorer_menu(&mut self) {
+        let count = self.left_panel.as_ref().map_or(0, |panel| panel.explorer().selected_paths().len());
+        if count == 0 {
+            return;
+        }
+        let trash_label = if count > 1 {
+            t!("explorer_menu.move_items_to_trash", count = count)
+        } else {
+            t!("explorer_menu.move_to_trash")
+        };
+        let enabled_if = |item: MenuItem, enabled: bool| if enabled { item } else { item.disabled() };
+        
+        self.explorer_menu.set_items(vec![
+            MenuItem::new(t!("explorer_menu.copy_path"), EXPLORER_MENU_COPY_PATH),
+            MenuItem::new(t!("explorer_menu.copy_relative_path"), EXPLORER_MENU_COPY_RELATIVE_PATH),
+            MenuItem::separator(),
+            enabled_if(MenuItem::new(t!("explorer_menu.reveal_in_file_manager"), EXPLORER_MENU_REVEAL_IN_FILE_MANAGER), count == 1),
+            MenuItem::separator(),
+            MenuItem::new(trash_label, EXPLORER_MENU_MOVE_TO_TRASH).with_shortcut("Delete"),
+            enabled_if(MenuItem::new(t!("explorer_menu.undo"), EXPLORER_MENU_UNDO).with_shortcut("Ctrl+Z"), self.file_operations.can_undo()),
+        ]);
+        
+        let (x, y) = self.menu_origin(self.explorer_menu.size());
+        self.explorer_menu.show(x, y);
+        self.explorer_menu.update_hover(self.mouse_pos.0, self.mouse_pos.1);
+    }
+    
+    /// Apply an explorer context menu entry to every selected row
+    fn handle_explorer_menu_action(&mut self, id: usize) {
+        let paths = self.left_panel.as_ref().map(|panel| panel.explorer().selected_paths()).unwrap_or_default();
+        match id {
+            EXPLORER_MENU_COPY_PATH | EXPLORER_MENU_COPY_RELATIVE_PATH => {
+                self.copy_paths(&paths, id == EXPLORER_MENU_COPY_RELATIVE_PATH);
+            }
+            EXPLORER_MENU_REVEAL_IN_FILE_MANAGER => {
+                if let Some(path) = paths.first() {
+                    reveal_in_file_manager(path);
+                }
+            }
+            EXPLORER_MENU_MOVE_TO_TRASH => self.trash_explorer_selection(),
+            EXPLORER_MENU_UNDO => self.undo_file_operation(),
+            _ => {}
+        }
+        self.explorer_menu.hide();
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Put paths on the clipboard, one per line; `relative` makes them relative to the workspace
+    fn copy_paths(&self, paths: &[PathBuf], relative: bool) {
+        let workspace = self.app_state.workspace_path.clone().or_else(|| std::env::current_dir().ok());
+        let lines: Vec<String> = paths
+            .iter()
+            .map(|path| {
+                let relative_path = workspace.as_deref().filter(|_| relative).and_then(|root| path.strip_prefix(root).ok());
+                relative_path.unwrap_or(path).to_string_lossy().into_owned()
+            })
+            .collect();
+        if let Ok(mut clipboard) = arboard::Clipboard::new() {
+            let _ = clipboard.set_text(lines.join("\n"));
+        }
+    }
+    
+    /// Apply a tab context menu entry to the tab it was opened on
+    fn handle_tab_menu_action(&mut self, id: usize) {
+        let target = self.tab_menu_target;
+        let Some(ref mut editor) = self.editor else {
+            return;
+        };
+        let path = editor.tab_manager().get_tab(target).and_then(|tab| tab.buffer.file_path().cloned());
+        
+        match id {
+            TAB_MENU_CLOSE => {
+                editor.tab_manager_mut().set_active_tab(target);
+                self.request_close_active_tab();
+            }
+            TAB_MENU_CLOSE_OTHERS | TAB_MENU_CLOSE_TO_RIGHT | TAB_MENU_CLOSE_SAVED => {
+                // Bulk closes never discard changes; modified and pinned tabs stay open
+                let tabs = editor.tab_manager_mut();
+                tabs.set_active_tab(target);
+                let closed = tabs.close_tabs_where(|index, tab| {
+                    let picked = match id {
+                        TAB_MENU_CLOSE_OTHERS => index != target,
+                        TAB_MENU_CLOSE_TO_RIGHT => index > target,
+                        _ => true,
+                    };
+                    picked && !tab.is_modified() && !tab.pinned
+                });
+                println!("Closed {} tab(s)", closed);
+            }
+            TAB_MENU_TOGGLE_PIN => {
+                let tabs = editor.tab_manager_mut();
+                let pinned = tabs.get_tab(target).is_some_and(|tab| tab.pinned);
+                tabs.set_pinned(target, !pinned);
+            }
+            TAB_MENU_COPY_PATH | TAB_MENU_COPY_RELATIVE_PATH => {
+                if let Some(path) = path {
+                    self.copy_paths(&[path], id == TAB_MENU_COPY_RELATIVE_PATH);
+                }
+            }
+            TAB_MENU_REVEAL_IN_EXPLORER => {
+                if let Some(path) = path {
+                    self.reveal_in_explorer(&path);
+                }
+            }
+            TAB_MENU_REVEAL_IN_FILE_MANAGER => {
+                if let Some(path) = path {
+                    reveal_in_file_manager(&path);
+                }
+            }
+            _ => {}
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Send the files selected in the explorer to the trash or recycle bin
+    fn trash_explorer_selection(&mut self) {
+        let paths = self.left_panel.as_ref().map(|panel| panel.explorer().selected_paths()).unwrap_or_default();
+        if paths.is_empty() {
+            return;
+        }
+        if self.remote.is_some() {
+            self.toast.show(t!("toast.remote_file_operations"), Some(CodiconIcons::INFO));
+            return;
+        }
+        let failed = self.file_operations.trash(&paths);
+        for (path, e) in &failed {
+            self.log_output(&format!("\x1b[31mFailed to move {} to the trash: {}\x1b[0m", path.display(), e));
+        }
+        if failed.len() < paths.len() {
+            self.log_output(&format!("Moved {} item(s) to the trash", paths.len() - failed.len()));
+        }
+        if let Some(ref mut left_panel) = self.left_panel {
+            left_panel.explorer_mut().refresh();
+        }
+    }
+    
+    /// Move rows dropped on a folder in the explorer
+    fn move_into_folder(&mut self, paths: &[PathBuf], folder: &Path) {
+        if self.remote.is_some() {
+            self.toast.show(t!("toast.remote_file_operations"), Some(CodiconIcons::INFO));
+            return;
+        }
+        let failed = self.file_operations.move_into(paths, folder);
+        for (path, e) in &failed {
+            self.log_output(&format!("\x1b[31mFailed to move {}: {}\x1b[0m", path.display(), e));
+        }
+        if let Some(ref mut left_panel) = self.left_panel {
+            left_panel.explorer_mut().refresh();
+        }
+    }
+    
+    /// Reverse the latest explorer rename, move or delete
+    fn undo_file_operation(&mut self) {
+        if !self.file_operations.can_undo() {
+            self.log_output("No file operation to undo");
+            return;
+        }
+        match self.file_operations.undo() {
+            Ok(FileOperation::Move(moves)) => {
+                self.log_output(&format!("Moved {} item(s) back", moves.len()));
+            }
+            Ok(FileOperation::Trash(items)) => {
+                self.log_output(&format!("Restored {} item(s) from the trash", items.len()));
+            }
+            Ok(FileOperation::Edit(edits)) => {
+                self.files_rewritten(edits.iter().map(|edit| edit.path.as_path()));
+                self.log_output(&format!("Put back the contents of {} file(s)", edits.len()));
+            }
+            Err(e) => self.log_output(&format!("\x1b[31mCouldn't undo the last file operation: {}\x1b[0m", e)),
+        }
+        if let Some(ref mut left_panel) = self.left_panel {
+            left_panel.explorer_mut().refresh();
+        }
+    }
+    
+    /// Show the search page in the left panel
+    fn show_search(&mut self) {
+        self.show_left_panel();
+        if let Some(ref mut left_panel) = self.left_panel {
+            left_panel.set_view(SidebarView::Search);
+        }
+        if let Some(ref mut activitybar) = self.activitybar {
+            activitybar.set_active_item(ActivityBarItem::Search);
+        }
+    }
+    
+    /// Search the opened folder for the search page's query
+    fn run_search(&mut self) {
+        let Some(root) = self.app_state.workspace_path.clone().filter(|path| self.vfs.is_dir(path)) else {
+            if let Some(ref mut left_panel) = self.left_panel {
+                left_panel.search_mut().set_message(t!("search.no_folder"));
+            }
+            return;
+        };
+        let walker = self.workspace_walker(root);
+        let Some(search) = self.left_panel.as_mut().map(|panel| panel.search_mut()) else {
+            return;
+        };
+        let query = search.query();
+        if query.text.is_empty() {
+            search.clear_results();
+            return;
+        }
+        let results = search_files(&walker, &query, MAX_SEARCH_RESULTS);
+        let count: usize = results.iter().map(|file| file.matches.len()).sum();
+        search.set_results(results, count >= MAX_SEARCH_RESULTS);
+    }
+    
+    /// Replace the checked matches of the search page, in all files at once
+    fn replace_in_files(&mut self) {
+        let Some(search) = self.left_panel.as_ref().map(|panel| panel.search()) else {
+            return;
+        };
+        let (query, replacement, checked) = (search.query(), search.replacement().to_string(), search.checked_matches());
+        if checked.is_empty() {
+            return;
+        }
+        // Unsaved edits would be lost when the tab reloads, or overwrite the replacement when saved
+        let unsaved = self.editor.as_ref().and_then(|editor| {
+            let tabs = editor.tab_manager().tabs();
+            checked.iter().find(|(path, _)| tabs.iter().any(|tab| tab.is_modified() && tab.buffer.file_path() == Some(path)))
+        });
+        if let Some((path, _)) = unsaved {
+            let message = t!("search.unsaved", name = path.file_name().unwrap_or_default().to_string_lossy());
+            self.set_search_message(message);
+            return;
+        }
+        
+        let mut edits = Vec::new();
+        for (path, matches) in &checked {
+            // The matches must still be where the search found them
+            let before = self.vfs.read(path).ok().and_then(|bytes| String::from_utf8(bytes).ok()).unwrap_or_default();
+            let found = query.find(&before);
+            if !matches.iter().all(|m| found.contains(&m.range)) {
+                self.set_search_message(t!("search.stale", name = path.file_name().unwrap_or_default().to_string_lossy()));
+                return;
+            }
+            let ranges: Vec<_> = matches.iter().map(|m| m.range.clone()).collect();
+            let after = mikocore::replace_ranges(&before, &ranges, &replacement);
+            edits.push(FileEdit { path: path.clone(), before, after });
+        }
+        
+        let count: usize = checked.iter().map(|(_, matches)| matches.len()).sum();
+        match self.file_operations.edit(edits) {
+            Ok(()) => {
+                self.files_rewritten(checked.iter().map(|(path, _)| path.as_path()));
+                self.log_output(&format!("Replaced {} occurrence(s) in {} file(s); Undo Last File Operation puts them back", count, checked.len()));
+                self.run_search();
+                self.set_search_message(t!("search.replaced", count = count, files = checked.len()));
+            }
+            Err(e) => {
+                self.log_output(&format!("\x1b[31mReplace in files failed, no file was changed: {}\x1b[0m", e));
+                self.set_search_message(t!("search.replace_failed"));
+            }
+        }
+    }
+    
+    fn set_search_message(&mut self, message: String) {
+        if let Some(ref mut left_panel) = self.left_panel {
+            left_panel.search_mut().set_message(message);
+        }
+    }
+    
+    /// Files changed by Replace in Files or its undo: reload their tabs and keep the watcher and index quiet
+    fn files_rewritten<'a>(&mut self, paths: impl Iterator<Item = &'a Path>) {
+        for path in paths {
+            if let Some(ref mut editor) = self.editor {
+                if let Err(e) = editor.reload_file(path) {
+                    eprintln!("Failed to reload {}: {}", path.display(), e);
+                }
+            }
+            if let Some(ref indexer) = self.indexer {
+                indexer.file_changed(path);
+            }
+            self.file_watcher.acknowledge(path);
+        }
+    }
+    
+    fn handle_search_action(&mut self, action: SearchAction) {
+        match action {
+            SearchAction::Search => self.run_search(),
+            SearchAction::ReplaceAll => self.replace_in_files(),
+            SearchAction::Open(path, line, column) => self.open_at(path, line, column),
+        }
+    }
+    
+    /// Rename the identifier under the cursor across the opened folder
+    /// Until a language server can answer rename requests, this is a whole-word, case-sensitive
+    /// Replace in Files: the search page previews every occurrence before anything changes.
+    fn rename_symbol(&mut self) {
+        let word = self.editor.as_ref()
+            .and_then(|editor| editor.tab_manager().get_active_tab())
+            .and_then(|tab| tab.word_at_cursor());
+        let Some(word) = word else {
+            self.log_output("Rename Symbol: put the cursor on an identifier first");
+            return;
+        };
+        self.show_search();
+        if let Some(ref mut left_panel) = self.left_panel {
+            left_panel.search_mut().set_query(&word, true, true);
+        }
+        self.run_search();
+    }
+
+    /// Show the definition of the identifier under the cursor in a peek view below its line
+    /// Without a language server, the definition is a symbol of that exact name: in the
+    /// document itself first, then anywhere in the indexed folder.
+    fn peek_definition(&mut self) {
+        let Some(editor) = self.editor.as_ref() else {
+            return;
+        };
+        let Some(tab) = editor.tab_manager().get_active_tab() else {
+            return;
+        };
+        let Some(word) = tab.word_at_cursor() else {
+            self.log_output("Peek Definition: put the cursor on an identifier first");
+            return;
+        };
+        let local = tab.buffer.file_path().cloned().and_then(|path| {
+            editor.document_symbols().into_iter().find(|symbol| symbol.name == word).map(|symbol| (path, symbol.line))
+        });
+        let target = local.or_else(|| {
+            let indexer = self.indexer.as_ref()?;
+            let index = indexer.index();
+            let symbol = index.find_symbols(&word, WORKSPACE_PICKER_LIMIT).into_iter().find(|symbol| symbol.name == word)?;
+            Some((symbol.path.clone(), symbol.line))
+        });
+        let Some((path, line)) = target else {
+            self.toast.show(t!("toast.no_definition", name = word), Some(CodiconIcons::INFO));
+            return;
+        };
+        let result = self.editor.as_mut().map_or(Ok(()), |editor| editor.peek_file(path.clone(), line, true));
+        if let Err(e) = result {
+            self.log_output(&format!("\x1b[31mCouldn't peek {}: {}\x1b[0m", path.display(), e));
+        }
+    }
+
+    fn show_left_panel(&mut self) {
+        if !self.layout_config.left_panel_visible {
+            self.layout_config.left_panel_visible = true;
+            if let Some(size) = self.window.as_ref().map(|window| window.inner_size()) {
+                self.build_ui(size.width as f32, size.height as f32);
+            }
+        }
+    }
+    
+    /// Show the explorer, expand the folders leading to `path` and scroll to it
+    fn reveal_in_explorer(&mut self, path: &Path) {
+        self.show_left_panel();
+        let revealed = self.left_panel.as_mut().is_some_and(|panel| panel.reveal(path));
+        if !revealed {
+            println!("{} is not in the opened folder", path.display());
+        }
+    }
+    
+    fn show_go_to_line(&mut self) {
+        let line_count = self.editor.as_ref().map_or(0, |editor| editor.line_count());
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.hide();
+        }
+        if let Some(ref mut go_to_line) = self.go_to_line {
+            go_to_line.show(line_count);
+        }
+    }
+    
+    /// Open the palette in `mode` and give it the mode's items
+    fn show_palette(&mut self, mode: PaletteMode) {
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_mode(mode);
+        }
+        self.fill_palette_mode();
+    }
+    
+    /// Provide the symbols or line count of the mode the palette's search text switched to,
+    /// or start looking up the query of the files and workspace symbols modes
+    fn fill_palette_mode(&mut self) {
+        self.start_palette_search();
+        let Some(mode) = self.command_palette.as_mut().and_then(|palette| palette.take_mode_request()) else {
+            return;
+        };
+        let (items, hint) = match mode {
+            PaletteMode::Symbols => self.symbol_items(),
+            PaletteMode::Line => {
+                let line_count = self.editor
+                    .as_ref()
+                    .filter(|editor| editor.tab_manager().get_active_tab().is_some())
+                    .map(|editor| editor.line_count());
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.set_line_count(line_count);
+                }
+                return;
+            }
+            PaletteMode::Files | PaletteMode::WorkspaceSymbols | PaletteMode::Commands | PaletteMode::Help => return,
+        };
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.set_mode_items(mode, items, hint);
+        }
+    }
+    
+    /// Count a command run from the palette's `>` mode towards its ranking
+    fn record_palette_use(&mut self) {
+        let Some(command) = self.command_palette.as_mut().and_then(|palette| palette.take_used_command()) else {
+            return;
+        };
+        self.app_state.record_command_use(command);
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.set_command_boosts(self.app_state.command_boosts());
+        }
+    }
+    
+    /// Go to Symbol entries for the active document, for the palette's `@` mode
+    fn symbol_items(&mut self) -> (Vec<CommandItem>, Option<String>) {
+        let symbols = self.editor.as_ref()
+            .map(|editor| editor.document_symbols())
+            .unwrap_or_default();
+        
+        self.symbol_targets = symbols.iter().map(|symbol| (symbol.line, symbol.column)).collect();
+        let items = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, symbol)| {
+                let (icon, category) = match symbol.kind {
+                    TokenType::Function => (CodiconIcons::SYMBOL_FUNCTION, t!("category.function")),
+                    _ => (CodiconIcons::SYMBOL_CLASS, t!("category.type")),
+                };
+                CommandItem::new(SYMBOL_PICKER_BASE_ID as u32 + i as u32, format!("{}{}", "  ".repeat(symbol.depth), symbol.name))
+                    .with_icon(icon)
+                    .with_description(t!("picker.line", line = symbol.line + 1))
+                    .with_category(category)
+            })
+            .collect();
+        
+        let hint = self.symbol_targets.is_empty().then(|| t!("picker.no_symbols"));
+        (items, hint)
+    }
+    
+    /// Walker over `root` with the explorer's exclusion settings, shared by the explorer and the indexer
+    fn workspace_walker(&self, root: PathBuf) -> Walker {
+        let walker = Walker::new(root).vfs(self.vfs.clone());
+        match self.config_loader.get_settings() {
+            Some(settings) => walker
+                .show_hidden(settings.explorer.show_hidden_files)
+                .use_ignore_files(settings.explorer.use_gitignore)
+                .exclude(&settings.explorer.exclude_patterns),
+            None => walker,
+        }
+    }
+    
+    /// Index the opened folder in the background, replacing the index of the previous one
+    fn start_indexer(&mut self) {
+        self.indexer = None;
+        let Some(root) = self.app_state.workspace_path.clone().filter(|path| path.is_dir()) else {
+            return;
+        };
+        let use_cache = self.config_loader.get_settings().is_none_or(|settings| settings.editor.index_cache);
+        let cache = use_cache.then(AppState::index_cache_path);
+        self.indexer = Some(Indexer::start(self.workspace_walker(root), cache));
+    }
+    
+    /// Look up the palette's new files or workspace symbols query, cancelling the previous lookup
+    fn start_palette_search(&mut self) {
+        let Some(command_palette) = self.command_palette.as_mut() else {
+            return;
+        };
+        let Some((mode, query)) = command_palette.take_query_request() else {
+            return;
+        };
+        self.palette_search = None;
+        let index_query = match mode {
+            PaletteMode::Files => IndexQuery::Files(query.clone()),
+            PaletteMode::WorkspaceSymbols => IndexQuery::Symbols(query.clone()),
+            _ => return,
+        };
+        let Some(ref indexer) = self.indexer else {
+            command_palette.set_mode_hint(mode, Some(t!("palette.no_folder")));
+            command_palette.push_results(mode, &query, Vec::new(), true);
+            return;
+        };
+        command_palette.set_mode_hint(mode, (!indexer.is_ready()).then(|| t!("picker.indexing")));
+        let search = indexer.search(index_query, WORKSPACE_PICKER_LIMIT);
+        self.palette_search = Some(PaletteSearch { mode, query, search, fresh: true });
+    }
+    
+    /// Hand the palette the results found since the last call; true if the palette changed
+    fn poll_palette_search(&mut self) -> bool {
+        if !self.command_palette.as_ref().is_some_and(|palette| palette.is_visible()) {
+            self.palette_search = None;
+            return false;
+        }
+        let Some(search) = self.palette_search.as_mut() else {
+            return false;
+        };
+        let hits = search.search.poll();
+        let done = search.search.is_done();
+        if hits.is_empty() && !done {
+            return false;
+        }
+        if std::mem::take(&mut search.fresh) {
+            self.workspace_targets.clear();
+        }
+        let (mode, query) = (search.mode, search.query.clone());
+        if done {
+            self.palette_search = None;
+        }
+        
+        let root = self.indexer.as_ref().map(|indexer| indexer.index().root().to_path_buf()).unwrap_or_default();
+        let icons = mikoui::file_icon_theme();
+        let items = hits
+            .into_iter()
+            .map(|hit| {
+                let id = WORKSPACE_PICKER_BASE_ID as u32 + self.workspace_targets.len() as u32;
+                match hit {
+                    IndexHit::File(path) => {
+                        let name = path.file_name().map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+                        let icon = icons.file_icon(&name, None);
+                        let folder = path
+                            .parent()
+                            .and_then(|parent| parent.strip_prefix(&root).ok())
+                            .map_or_else(String::new, |folder| folder.to_string_lossy().into_owned());
+                        self.workspace_targets.push((path, 0, 0));
+                        CommandItem::new(id, name).with_file_icon(icon).with_description(folder)
+                    }
+                    IndexHit::Symbol(symbol) => {
+                        let (icon, category) = match symbol.kind {
+                            TokenType::Function => (CodiconIcons::SYMBOL_FUNCTION, t!("category.function")),
+                            _ => (CodiconIcons::SYMBOL_CLASS, t!("category.type")),
+                        };
+                        let file = symbol.path.strip_prefix(&root).unwrap_or(&symbol.path);
+                        let item = CommandItem::new(id, symbol.name.clone())
+                            .with_icon(icon)
+                            .with_description(format!("{}:{}", file.display(), symbol.line + 1))
+                            .with_category(category);
+                        self.workspace_targets.push((symbol.path, symbol.line, symbol.column));
+                        item
+                    }
+                }
+            })
+            .collect();
+        
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.push_results(mode, &query, items, done);
+            if done && query.is_empty() && self.workspace_targets.is_empty() && mode == PaletteMode::WorkspaceSymbols {
+                command_palette.set_mode_hint(mode, Some(t!("picker.no_workspace_symbols")));
+            }
+        }
+        true
+    }
+    
+    /// Focus the file's tab, opening it if needed, and move the cursor to a position
+    fn open_at(&mut self, path: PathBuf, line: usize, column: usize) {
+        let Some(ref mut editor) = self.editor else {
+            return;
+        };
+        let tabs = editor.tab_manager_mut();
+        match tabs.tabs().iter().position(|tab| tab.buffer.file_path() == Some(&path)) {
+            Some(index) => tabs.set_active_tab(index),
+            None => {
+                if !self.open_file(path) {
+                    return;
+                }
+            }
+        }
+        if let Some(ref mut editor) = self.editor {
+            editor.go_to_position(line, column);
+        }
+    }
+    
+    /// Show the end of line sequence quick pick
+    fn show_line_ending_picker(&mut self) {
+        let current = self.editor.as_ref()
+            .and_then(|editor| editor.get_document_info())
+            .map(|(_, line_ending)| line_ending);
+        
+        let items = [(170, LineEnding::Lf), (171, LineEnding::CrLf)]
+            .into_iter()
+            .map(|(id, line_ending)| {
+                let item = CommandItem::new(id, line_ending.label())
+                    .with_icon(CodiconIcons::LIST_FLAT)
+                    .with_category(t!("category.end_of_line"));
+                if current == Some(line_ending) {
+                    item.with_description(t!("picker.current"))
+                } else {
+                    item
+                }
+            })
+            .collect();
+        
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker(t!("picker.select_eol"), items);
+        }
+    }
+    
+    /// Make `path` the workspace: load its settings, index it and show it in the explorer
+    fn open_workspace(&mut self, path: PathBuf) {
+        // Update app state with new workspace path; open tabs take the new folder's bookmarks
+        self.app_state.workspace_path = Some(path.clone());
+        self.bookmark_tabs.clear();
+        if self.app_state.add_recent_workspace(path.clone()) {
+            self.update_jump_list();
+        }
+        
+        // Load workspace configs (.rabital folder), its settings only once it is trusted
+        self.apply_workspace_trust();
+        self.config_loader.set_workspace(path.clone());
+        self.start_indexer();
+        self.refresh_git_status();
+        self.offer_workspace_trust(false);
+        
+        // Log loaded configs
+        if let Some(settings) = self.config_loader.get_settings() {
+            println!("Loaded editor settings: theme={}", settings.editor.theme);
+        }
+        if let Some(tasks) = self.config_loader.get_tasks() {
+            println!("Loaded {} tasks", tasks.tasks.len());
+        }
+        self.apply_settings();
+        self.report_settings_issues();
+        
+        // Change current directory; an archive has none to change to
+        if !self.archives.is_mounted(&path) {
+            if let Err(e) = std::env::set_current_dir(&path) {
+                eprintln!("Failed to change directory: {}", e);
+            } else {
+                println!("Changed directory to: {}", path.display());
+            }
+        }
+        
+        // Update window title
+        if let Some(window) = &self.window {
+            let new_title = self.get_window_title();
+            window.set_title(&new_title);
+        }
+        
+        // Rebuild UI to load the new folder
+        let window_size = self.window.as_ref().map(|w| w.inner_size());
+        if let Some(size) = window_size {
+            self.build_ui(size.width as f32, size.height as f32);
+            if let Some(window) = &self.window {
+                window.request_redraw();
+            }
+        }
+        
+        // Save state immediately
+        if let Err(e) = self.app_state.save() {
+            eprintln!("Failed to save state: {}", e);
+        } else {
+            println!("State saved successfully");
+        }
+    }
+    
+    /// List the archive at `path` so it reads as a read-only folder; false with a toast when it can't be read
+    fn mount_archive(&mut self, path: &Path) -> bool {
+        match self.archives.mount(path) {
+            Ok(()) => true,
+            Err(e) => {
+                let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+                self.log_output(&format!("\x1b[31mCouldn't open {}: {}\x1b[0m", name, e));
+                self.toast.show(t!("toast.archive_open_failed", name = name), Some(CodiconIcons::WARNING));
+                false
+            }
+        }
+    }
+    
+    /// Make an archive the workspace, browsed in place and read-only
+    fn open_archive(&mut self, path: PathBuf) {
+        if self.mount_archive(&path) {
+            self.open_workspace(path);
+        }
+    }
+    
+    /// Show an archive picked in the explorer as an expanded folder in its place
+    fn expand_archive(&mut self, path: PathBuf) {
+        if !self.archives.is_mounted(&path) && !self.mount_archive(&path) {
+            return;
+        }
+        if let Some(ref mut left_panel) = self.left_panel {
+            let explorer = left_panel.explorer_mut();
+            let mut expanded = explorer.get_expanded_paths();
+            expanded.push(path.to_string_lossy().to_string());
+            explorer.refresh();
+            explorer.restore_expanded_state(&expanded);
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Show the saved SSH hosts, most recently used first, with an entry to add another
+    fn show_ssh_host_picker(&mut self) {
+        let mut items: Vec<CommandItem> = self.app_state.ssh_hosts
+            .iter()
+            .take((PROFILE_PICKER_BASE_ID - SSH_HOST_PICKER_BASE_ID) as usize)
+            .enumerate()
+            .map(|(i, spec)| {
+                let item = CommandItem::new(SSH_HOST_PICKER_BASE_ID as u32 + i as u32, spec.clone()).with_icon(CodiconIcons::GLOBE);
+                let connected = self.remote.as_ref().is_some_and(|mount| mount.host.to_string() == *spec);
+                if connected {
+                    item.with_description(t!("picker.connected"))
+                } else {
+                    item
+                }
+            })
+            .collect();
+        items.push(CommandItem::new(282, t!("remote.add_host")).with_icon(CodiconIcons::ADD));
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_picker(t!("picker.ssh_host"), items);
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Ask which folder of `host` to open
+    fn ask_remote_folder(&mut self, host: SshHost) {
+        let placeholder = t!("picker.remote_folder", host = host.destination());
+        self.ssh_host = Some(host);
+        if let Some(ref mut command_palette) = self.command_palette {
+            command_palette.show_prompt(placeholder, 284);
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Show a folder of `host` in the explorer, with its files kept under the state folder while they're edited
+    ///
+    /// The local workspace stays open for search, git and the terminal.
+    fn open_remote(&mut self, host: SshHost, root: String) {
+        self.app_state.add_ssh_host(host.to_string());
+        let mount = RemoteMount::new(host, root, &AppState::remote_cache_path());
+        self.log_output(&format!("Opened {} on {}", mount.remote_root, mount.host.destination()));
+        self.toast.show(t!("toast.remote_connected", host = mount.host.destination()), Some(CodiconIcons::GLOBE));
+        self.remote = Some(mount);
+        // Listings of a folder opened before don't carry over
+        if let Some(ref mut left_panel) = self.left_panel {
+            left_panel.explorer_mut().take_remote();
+        }
+        self.remote_changed();
+    }
+    
+    /// Go back to the local workspace in the explorer
+    fn close_remote(&mut self) {
+        let Some(mount) = self.remote.take() else {
+            self.toast.show(t!("toast.remote_not_connected"), Some(CodiconIcons::INFO));
+            return;
+        };
+        self.ssh_host = None;
+        if let Some(ref mut left_panel) = self.left_panel {
+            left_panel.explorer_mut().take_remote();
+        }
+        self.toast.show(t!("toast.remote_closed", host = mount.host.destination()), Some(CodiconIcons::GLOBE));
+        self.remote_changed();
+    }
+    
+    fn remote_changed(&mut self) {
+        self.show_left_panel();
+        if let Some(window) = &self.window {
+            let new_title = self.get_window_title();
+            window.set_title(&new_title);
+        }
+        if let Some(size) = self.window.as_ref().map(|w| w.inner_size()) {
+            self.build_ui(size.width as f32, size.height as f32);
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Fetch the remote folders the explorer shows without a listing, in the background
+    fn request_remote_listings(&mut self) {
+        let Some(ref mount) = self.remote else {
+            return;
+        };
+        let dirs = self.left_panel.as_mut().map(|panel| panel.explorer_mut().take_listing_requests()).unwrap_or_default();
+        for dir in dirs {
+            remote::list(mount, dir, &self.ui_sender);
+        }
+    }
+    
+    fn remote_listing(&mut self, dir: PathBuf, result: Result<Vec<DirEntry>, String>) {
+        let Some(remote_path) = self.remote.as_ref().and_then(|mount| mount.remote_path(&dir)) else {
+            return;
+        };
+        match result {
+            Ok(entries) => {
+                if let Some(ref mut left_panel) = self.left_panel {
+                    left_panel.explorer_mut().set_listing(dir, entries);
+                }
+            }
+            Err(e) => {
+                self.log_output(&format!("\x1b[31mCouldn't list {}: {}\x1b[0m", remote_path, e));
+                self.toast.show(t!("toast.remote_list_failed"), Some(CodiconIcons::WARNING));
+            }
+        }
+    }
+    
+    /// Open a file picked in the explorer; a remote one is downloaded first, unless it's open already
+    fn open_explorer_file(&mut self, path: PathBuf) {
+        // Archives on disk expand into folders instead
+        if is_archive(&path) && self.remote.is_none() {
+            self.expand_archive(path);
+            return;
+        }
+        let is_open = self.editor.as_ref().is_some_and(|editor| {
+            editor.tab_manager().tabs().iter().any(|tab| tab.source_path() == Some(path.as_path()))
+        });
+        match self.remote.as_ref().filter(|mount| mount.contains(&path)) {
+            Some(mount) if !is_open => remote::download(mount, path, &self.ui_sender),
+            _ => {
+                self.open_file(path);
+            }
+        }
+    }
+    
+    fn remote_downloaded(&mut self, path: PathBuf, result: Result<(), String>) {
+        match result {
+            // Local copies stay out of the recent files, as they go stale once disconnected
+            Ok(()) => {
+                if let Some(ref mut editor) = self.editor {
+                    let opened = if Preview::is_image(&path) { editor.open_preview(path.clone()) } else { editor.open_file(path.clone()) };
+                    if let Err(e) = opened {
+                        eprintln!("Failed to open {}: {}", path.display(), e);
+                    }
+                }
+            }
+            Err(e) => {
+                let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+                self.log_output(&format!("\x1b[31mCouldn't download {}: {}\x1b[0m", name, e));
+                self.toast.show(t!("toast.remote_download_failed", name = name), Some(CodiconIcons::WARNING));
+            }
+        }
+    }
+    
+    /// Copy a saved local copy back to its host; saves made meanwhile are sent once it's done, so the last one wins
+    fn upload_remote_file(&mut self, path: &Path) {
+        let Some(mount) = self.remote.as_ref().filter(|mount| mount.contains(path)) else {
+            return;
+        };
+        match self.uploads.get_mut(path) {
+            Some(again) => *again = true,
+            None => {
+                self.uploads.insert(path.to_path_buf(), false);
+                remote::upload(mount, path.to_path_buf(), &self.ui_sender);
+            }
+        }
+    }
+    
+    fn remote_uploaded(&mut self, path: PathBuf, result: Result<(), String>) {
+        let again = self.uploads.remove(&path).unwrap_or(false);
+        let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        match result {
+            Ok(()) => self.log_output(&format!("Uploaded {}", name)),
+            Err(e) => {
+                self.log_output(&format!("\x1b[31mCouldn't upload {}: {}\x1b[0m", name, e));
+                self.toast.show(t!("toast.remote_upload_failed", name = name), Some(CodiconIcons::WARNING));
+            }
+        }
+        if again {
+            self.upload_remote_file(&path);
+        }
+    }
+    
+    /// Let the index, the file watcher and a remote host know a tab was written to `path`
+    fn file_saved(&mut self, path: &Path) {
+        if let Some(ref indexer) = self.indexer {
+            indexer.file_changed(path);
+        }
+        self.file_watcher.acknowledge(path);
+        self.upload_remote_file(path);
+    }
+    
+    /// Open a file in a tab, images as previews, and remember it for the recent files
+    fn open_file(&mut self, path: PathBuf) -> bool {
+        let Some(ref mut editor) = self.editor else {
+            return false;
+        };
+        let opened = if Preview::is_image(&path) { editor.open_preview(path.clone()) } else { editor.open_file(path.clone()) };
+        if let Err(e) = opened {
+            eprintln!("Failed to open {}: {}", path.display(), e);
+            return false;
+        }
+        if self.app_state.add_recent_file(path) {
+            self.update_jump_list();
+        }
+        true
+    }
+    
+    /// Show the recent folders and files in the taskbar jump list
+    fn update_jump_list(&self) {
+        let categories = [
+            (t!("jump_list.recent_folders"), self.app_state.recent_workspaces.clone()),
+            (t!("jump_list.recent_files"), self.app_state.recent_files.clone()),
+        ];
+        jump_list::set_jump_list(&categories);
+    }
+    
+    /// Open what the command line asks for, e.g. from a terminal or a jump list entry
+    fn open_launch(&mut self, launch: CliArgs) {
+        let mut opened = Vec::new();
+        for target in launch.open {
+            let path = cli::absolute(&target.path);
+            if path.is_dir() {
+                self.open_workspace(path);
+                continue;
+            }
+            if is_archive(&path) && path.is_file() {
+                self.open_archive(path);
+                continue;
+            }
+            if !path.is_file() {
+                eprintln!("Cannot open {}: no such file or folder", path.display());
+                continue;
+            }
+            let line = target.line.map_or(0, |line| line.saturating_sub(1));
+            let column = target.column.map_or(0, |column| column.saturating_sub(1));
+            self.open_at(path.clone(), line, column);
+            opened.extend(self.tab_id_for(&path));
+        }
+        if let Some((old, new)) = launch.diff {
+            opened.extend(self.open_diff(&cli::absolute(&old), &cli::absolute(&new)));
+        }
+        if launch.wait {
+            self.wait_tabs = opened;
+        }
+    }
+    
+    /// Open the links another launch handed over, and bring the window to the front
+    fn open_forwarded(&mut self, links: Vec<String>) {
+        match CliArgs::parse(links.into_iter().map(Into::into)) {
+            Ok(launch) => self.open_launch(CliArgs { wait: false, ..launch }),
+            Err(message) => eprintln!("Ignoring forwarded request: {}", message),
+        }
+        if let Some(window) = &self.window {
+            window.focus_window();
+            window.request_redraw();
+        }
+    }
+    
+    /// Id of the tab showing `path`
+    fn tab_id_for(&self, path: &Path) -> Option<usize> {
+        let tabs = self.editor.as_ref()?.tab_manager().tabs();
+        tabs.iter().find(|tab| tab.buffer.file_path().map(PathBuf::as_path) == Some(path)).map(|tab| tab.id)
+    }
+    
+    fn is_tab_open(&self, id: usize) -> bool {
+        self.editor.as_ref().is_some_and(|editor| editor.tab_manager().tabs().iter().any(|tab| tab.id == id))
+    }
+    
+    /// Show the line differences between two files in a new tab; returns the tab's id
+    fn open_diff(&mut self, old: &Path, new: &Path) -> Option<usize> {
+        let read = |path: &Path| {
+            std::fs::read_to_string(path).map_err(|e| eprintln!("Cannot diff {}: {}", path.display(), e)).ok()
+        };
+        let (old_text, new_text) = (read(old)?, read(new)?);
+        let name = |path: &Path| path.file_name().map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().into_owned());
+        let title = format!("{} \u{2194} {}", name(old), name(new));
+        self.open_text_diff(&old_text, &new_text, title)
+    }
+    
+    /// Show the line differences between two texts in a new tab; returns the tab's id
+    fn open_text_diff(&mut self, old_text: &str, new_text: &str, title: String) -> Option<usize> {
+        let colors = &self.theme_colors;
+        let mut text = String::new();
+        let mut decorations = Vec::new();
+        let mut offset = 0;
+        for (kind, line) in diff_lines(old_text, new_text) {
+            let (prefix, style) = match kind {
+                DiffKind::Equal => ("  ", None),
+                DiffKind::Added => ("+ ", Some((CodiconIcons::ADD, colors.syntax.string))),
+                DiffKind::Removed => ("- ", Some((CodiconIcons::REMOVE, colors.destructive))),
+            };
+            let len = prefix.len() + line.chars().count();
+            if let Some((icon, color)) = style {
+                decorations.push(Decoration::new(offset..offset + len).background(with_alpha(color, 40)).gutter_icon(icon, color));
+            }
+            text.push_str(prefix);
+            text.push_str(line);
+            text.push('\n');
+            offset += len + 1;
+        }
+        
+        let editor = self.editor.as_mut()?;
+        let tabs = editor.tab_manager_mut();
+        tabs.add_tab_with_text(&text, title);
+        let tab = tabs.get_active_tab_mut()?;
+        tab.set_decorations("diff", decorations);
+        Some(tab.id)
+    }
+    
+    fn handle_button_click(&mut self, _x: f32, _y: f32) {
+        // No demo buttons - add your custom button handling here
+    }
+    
+    fn handle_menu_action(&mut self, item_id: i32) {
+        use mikoui::file_dialogs;
+        
+        if MACRO_COMMANDS.contains(&item_id) {
+            self.macros.record(MacroStep::Command { command: item_id as u32 });
+        }
+        // Ids from `MACRO_REPEAT_BASE_ID` up pick macros, locales, symbols or files rather than commands
+        if item_id < MACRO_REPEAT_BASE_ID {
+            self.telemetry.record_command(item_id as u32);
+        }
+        
+        match item_id {
+            4 => {
+                // Open Folder
+                println!("Opening folder dialog...");
+                match file_dialogs::open_folder_dialog("Open Folder") {
+                    Some(path) => {
+                        println!("Folder selected: {:?}", path);
+                        self.open_workspace(path);
+                    }
+                    None => {
+                        println!("Folder dialog cancelled or failed");
+                    }
+                }
+            }
+            6 => {
+                // Save
+                let language = self.editor.as_ref().and_then(|editor| editor.active_language());
+                let format_on_save = self.config_loader
+                    .get_settings()
+                    .is_some_and(|settings| settings.format_on_save_for(language.as_deref()));
+                if format_on_save {
+                    self.format_document(true);
+                }
+                let options = self.save_options();
+                let result = self.editor.as_mut().map(|editor| editor.save_active_file(&options));
+                if let Some(Ok(_)) = result {
+                    let saved = self.editor.as_ref().and_then(|editor| editor.tab_manager().get_active_tab()?.buffer.file_path().cloned());
+                    if let Some(path) = saved {
+                        self.file_saved(&path);
+                    }
+                }
+                match result {
+                    Some(Ok(_)) => self.log_output("File saved"),
+                    Some(Err(e)) => self.log_output(&format!("\x1b[31mFailed to save file: {}\x1b[0m", e)),
+                    None => {}
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            37 => {
+                // Toggle Line Comment
+                self.run_edit(EditorCommand::ToggleLineComment);
+            }
+            38 => {
+                // Toggle Block Comment
+                self.run_edit(EditorCommand::ToggleBlockComment);
+            }
+            39 => {
+                // Format Document
+                self.format_document(false);
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            41 => {
+                // Trim Trailing Whitespace
+                if let Some(ref mut editor) = self.editor {
+                    editor.trim_trailing_whitespace();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            9 => {
+                // Auto Save: toggle between off and saving after the configured delay
+                let delay = self.config_loader.get_settings().map_or(1000, |settings| settings.editor.auto_save_delay);
+                if let Some(ref mut editor) = self.editor {
+                    let mode = match editor.auto_save_mode() {
+                        AutoSaveMode::Off => AutoSaveMode::AfterDelay(Duration::from_millis(delay as u64)),
+                        _ => AutoSaveMode::Off,
+                    };
+                    editor.set_auto_save_mode(mode);
+                    let state = if mode == AutoSaveMode::Off { "off" } else { "on" };
+                    self.log_output(&format!("Auto save {}", state));
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            78 => {
+                // Toggle Render Whitespace
+                if let Some(ref mut editor) = self.editor {
+                    editor.toggle_render_whitespace();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            260 => {
+                // Toggle Inlay Hints
+                if let Some(ref mut editor) = self.editor {
+                    editor.toggle_inlay_hints();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            261 => {
+                // Peek Definition
+                self.peek_definition();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            262 => {
+                // Toggle Inline Blame
+                self.inline_blame = !self.inline_blame;
+                self.blame_source = None;
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            263 => {
+                // File History
+                self.show_file_history();
+            }
+            264 => {
+                // Checkout to...
+                self.show_branch_picker();
+            }
+            265 => self.run_git_action(GitAction::Pull),
+            266 => {
+                let set_upstream = self.git_status.as_ref().is_some_and(|status| status.upstream.is_none());
+                self.run_git_action(GitAction::Push { set_upstream });
+            }
+            267 => self.run_git_action(GitAction::Fetch),
+            268 => self.run_git_action(GitAction::Stash),
+            269 => self.run_git_action(GitAction::StashPop),
+            270 => {
+                // Create new branch... from the branch picker
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.show_prompt(t!("picker.new_branch"), 271);
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            276 => {
+                // Manage Workspace Trust
+                if self.app_state.workspace_path.is_some() {
+                    self.offer_workspace_trust(true);
+                } else {
+                    self.toast.show(t!("toast.trust_no_folder"), Some(CodiconIcons::INFO));
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            277 => self.show_profile_picker(false),
+            278 => {
+                // New Profile... from the profile picker
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.show_prompt(t!("picker.new_profile"), 279);
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            279 => {
+                // Profile name typed into the prompt
+                let name = self.command_palette.as_ref().and_then(|palette| palette.prompt_text()).map(str::to_string);
+                if let Some(name) = name.filter(|name| !name.is_empty()) {
+                    match self.config_loader.create_profile(&name) {
+                        Ok(()) => self.switch_profile(name.trim()),
+                        Err(e) => {
+                            self.log_output(&format!("\x1b[31mFailed to create profile: {}\x1b[0m", e));
+                            self.toast.show(t!("toast.profile_create_failed"), Some(CodiconIcons::WARNING));
+                        }
+                    }
+                }
+            }
+            280 => self.show_profile_picker(true),
+            281 => self.show_ssh_host_picker(),
+            282 => {
+                // Add New SSH Host... from the host picker
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.show_prompt(t!("picker.new_ssh_host"), 283);
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            283 => {
+                // Host typed into the prompt
+                let spec = self.command_palette.as_ref().and_then(|palette| palette.prompt_text()).map(str::to_string);
+                match spec.filter(|spec| !spec.is_empty()).map(|spec| SshHost::parse(&spec)) {
+                    Some(Ok(host)) => self.ask_remote_folder(host),
+                    Some(Err(e)) => {
+                        self.log_output(&format!("\x1b[31m{}\x1b[0m", e));
+                        self.toast.show(t!("toast.ssh_host_invalid"), Some(CodiconIcons::WARNING));
+                    }
+                    None => {}
+                }
+            }
+            284 => {
+                // Remote folder typed into the prompt
+                let dir = self.command_palette.as_ref().and_then(|palette| palette.prompt_text()).unwrap_or_default().to_string();
+                if let Some(host) = self.ssh_host.take() {
+                    self.toast.show(t!("toast.remote_connecting", host = host.destination()), Some(CodiconIcons::GLOBE));
+                    remote::connect(host, dir, &self.ui_sender);
+                }
+            }
+            285 => self.close_remote(),
+            287 => {
+                // Show TODOs
+                self.show_todos();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            288 => self.toggle_bookmark(),
+            289 => self.go_to_bookmark(true),
+            290 => self.go_to_bookmark(false),
+            291 => {
+                // Label Bookmark..., for the cursor line
+                let line = self.editor.as_ref().and_then(|editor| editor.tab_manager().get_active_tab()).map(|tab| tab.cursor_line);
+                if let (Some(line), Some(command_palette)) = (line, self.command_palette.as_mut()) {
+                    command_palette.show_prompt(t!("picker.bookmark_label", line = line + 1), 292);
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            292 => {
+                // Label typed into the prompt
+                let label = self.command_palette.as_ref().and_then(|palette| palette.prompt_text()).unwrap_or_default().to_string();
+                if let Some(tab) = self.editor.as_mut().and_then(|editor| editor.tab_manager_mut().get_active_tab_mut()) {
+                    let line = tab.cursor_line;
+                    tab.set_bookmark_label(line, &label);
+                }
+            }
+            293 => {
+                // Show Bookmarks
+                self.show_bookmarks();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            294 => {
+                // Insert Unicode Character...
+                self.show_unicode_picker();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            295 => {
+                // Open Preview of the active image or Markdown file
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                self.open_active_preview();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            286 => {
+                // Open Archive
+                let filters = [("Archives", "*.zip;*.jar;*.tar;*.tar.gz;*.tgz")];
+                if let Some(path) = file_dialogs::open_file_dialog("Open Archive", &filters) {
+                    self.open_archive(path);
+                }
+            }
+            274 => {
+                // Run Selected Text in Active Terminal
+                self.run_selection_in_terminal();
+            }
+            275 => {
+                // Run Active File in Active Terminal
+                self.run_active_file_in_terminal();
+            }
+            272 | 273 => {
+                // Next / Previous Conflict
+                let found = self.editor.as_mut().is_some_and(|editor| {
+                    if item_id == 272 { editor.next_conflict() } else { editor.previous_conflict() }
+                });
+                if !found {
+                    self.toast.show(t!("toast.no_conflicts"), Some(CodiconIcons::INFO));
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            271 => {
+                // Branch name typed into the prompt
+                let name = self.command_palette.as_ref().and_then(|palette| palette.prompt_text()).map(str::to_string);
+                if let Some(name) = name.filter(|name| !name.is_empty()) {
+                    self.run_git_action(GitAction::CreateBranch(name));
+                }
+            }
+            160..=169 => {
+                // Reopen (160-164) or save (165-169) with encoding
+                let index = (item_id - 160) as usize % Encoding::ALL.len();
+                let encoding = Encoding::ALL[index];
+                let options = self.save_options();
+                if let Some(ref mut editor) = self.editor {
+                    let result = if item_id < 165 {
+                        editor.reopen_active_with_encoding(encoding)
+                    } else {
+                        editor.set_active_encoding(encoding);
+                        editor.save_active_file(&options)
+                    };
+                    if let Err(e) = result {
+                        eprintln!("Failed to apply encoding {}: {}", encoding.label(), e);
+                    }
+                }
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            170 | 171 => {
+                // Change end of line sequence
+                let line_ending = if item_id == 170 { LineEnding::Lf } else { LineEnding::CrLf };
+                if let Some(ref mut editor) = self.editor {
+                    editor.set_active_line_ending(line_ending);
+                }
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            172 => {
+                self.show_encoding_picker();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            173 => {
+                self.show_line_ending_picker();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            215 => {
+                // Change Language Mode
+                self.show_language_mode_picker();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            id if id == AUTO_DETECT_LANGUAGE_ID
+                || (LANGUAGE_MODE_PICKER_BASE_ID..LANGUAGE_MODE_PICKER_BASE_ID + LANGUAGES.len() as i32).contains(&id) =>
+            {
+                // Language mode picked; re-highlights the active tab
+                let language = LANGUAGES
+                    .get((id - LANGUAGE_MODE_PICKER_BASE_ID) as usize)
+                    .filter(|_| id != AUTO_DETECT_LANGUAGE_ID)
+                    .map(|(language, _)| *language);
+                if let Some(ref mut editor) = self.editor {
+                    editor.set_active_language(language);
+                }
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            68 => {
+                // Output
+                self.show_bottom_panel_view(BottomPanelView::Output);
+            }
+            69 => {
+                // Terminal
+                self.show_bottom_panel_view(BottomPanelView::Terminal);
+            }
+            200 => {
+                // Reopen Editor With...
+                self.show_reopen_with_picker();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            201 | 202 => {
+                // Reopen as text or hex
+                if let Some(ref mut editor) = self.editor {
+                    let result = if item_id == 201 {
+                        editor.reopen_active_as_text()
+                    } else {
+                        editor.reopen_active_as_hex()
+                    };
+                    if let Err(e) = result {
+                        eprintln!("Failed to reopen editor: {}", e);
+                    }
+                }
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            203 => {
+                // Find bytes in the hex editor
+                if self.editor.as_ref().is_some_and(|editor| editor.is_active_hex()) {
+                    if let Some(ref mut command_palette) = self.command_palette {
+                        command_palette.hide();
+                    }
+                    if let Some(ref mut find_bytes) = self.find_bytes {
+                        find_bytes.show();
+                    }
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            35 | 91 => {
+                // Go to Line
+                self.show_go_to_line();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            92 => {
+                // Go to Bracket
+                if let Some(ref mut editor) = self.editor {
+                    editor.go_to_bracket();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            60 => {
+                // Command Palette
+                self.show_palette(PaletteMode::Commands);
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            84 => {
+                // Go to File
+                self.show_palette(PaletteMode::Files);
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            62 => {
+                // Show Explorer, with the keyboard in the file tree
+                self.show_left_panel();
+                if let Some(ref mut left_panel) = self.left_panel {
+                    left_panel.set_view(SidebarView::Explorer);
+                    left_panel.focus_explorer();
+                }
+                if let Some(ref mut activitybar) = self.activitybar {
+                    activitybar.set_active_item(ActivityBarItem::Explorer);
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            63 => {
+                // Show Search, seeded with the selected text
+                let selected = self.editor.as_ref()
+                    .and_then(|editor| editor.tab_manager().get_active_tab())
+                    .map(|tab| tab.get_selected_text())
+                    .filter(|text| !text.is_empty() && !text.contains('\n'));
+                self.show_search();
+                if let Some(ref mut left_panel) = self.left_panel {
+                    let search = left_panel.search_mut();
+                    if let Some(text) = selected {
+                        let query = search.query();
+                        search.set_query(&text, query.match_case, query.whole_word);
+                    }
+                    search.focus_query();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            207 => {
+                // Show Outline
+                if !self.layout_config.right_panel_visible {
+                    self.layout_config.right_panel_visible = true;
+                    if let Some(size) = self.window.as_ref().map(|window| window.inner_size()) {
+                        self.build_ui(size.width as f32, size.height as f32);
+                    }
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            208 => {
+                // Toggle Outline Follow Cursor
+                if let Some(ref mut right_panel) = self.right_panel {
+                    let follow = !right_panel.outline().follow_cursor();
+                    right_panel.outline_mut().set_follow_cursor(follow);
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            206 => {
+                // Rename Symbol
+                self.rename_symbol();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            94 => {
+                // Reveal Active File in Explorer View
+                let path = self.editor.as_ref().and_then(|editor| editor.tab_manager().get_active_tab()?.buffer.file_path().cloned());
+                if let Some(path) = path {
+                    self.reveal_in_explorer(&path);
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            95 => {
+                // Move Selected Files to Trash
+                self.trash_explorer_selection();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            96 => {
+                // Undo Last File Operation
+                self.undo_file_operation();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            93 => {
+                // Go to Symbol in Workspace
+                self.show_palette(PaletteMode::WorkspaceSymbols);
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            36 | 85 => {
+                // Go to Symbol
+                self.show_palette(PaletteMode::Symbols);
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            id if id == PALETTE_LINE_ID as i32 => {
+                // Line picked in the palette's `:` mode
+                let target = self.command_palette.as_ref().and_then(|palette| palette.line_target());
+                if let (Some((line, column)), Some(editor)) = (target, self.editor.as_mut()) {
+                    editor.go_to_position(line - 1, column - 1);
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            id if (LOCALE_PICKER_BASE_ID..ICON_THEME_PICKER_BASE_ID).contains(&id) => {
+                // Display language picked
+                if let Some(code) = self.locale_options.get((id - LOCALE_PICKER_BASE_ID) as usize).cloned() {
+                    self.change_locale(&code);
+                }
+            }
+            id if (ICON_THEME_PICKER_BASE_ID..SYMBOL_PICKER_BASE_ID).contains(&id) => {
+                // File icon theme picked
+                if let Some(name) = self.icon_theme_options.get((id - ICON_THEME_PICKER_BASE_ID) as usize).cloned() {
+                    self.apply_icon_theme(&name);
+                }
+            }
+            id if id >= UNICODE_PICKER_BASE_ID => {
+                // Character picked in Insert Unicode Character, inserted as is
+                if let Some(c) = char::from_u32((id - UNICODE_PICKER_BASE_ID) as u32) {
+                    self.run_edit(EditorCommand::Paste(c.to_string()));
+                }
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+            }
+            id if id >= EXTENSION_COMMAND_BASE_ID => {
+                // Extension command; its steps replay like a saved macro
+                let steps = self.config_loader
+                    .extensions()
+                    .commands()
+                    .get((id - EXTENSION_COMMAND_BASE_ID) as usize)
+                    .map(|(_, command)| command.steps.clone());
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                if let Some(steps) = steps {
+                    self.play_macro(&steps, 1);
+                }
+            }
+            id if id >= WORKSPACE_PICKER_BASE_ID => {
+                // File or workspace symbol picked
+                if let Some((path, line, column)) = self.workspace_targets.get((id - WORKSPACE_PICKER_BASE_ID) as usize).cloned() {
+                    self.open_at(path, line, column);
+                }
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            id if id >= HISTORY_PICKER_BASE_ID => {
+                // Commit picked in File History
+                self.open_commit_diff((id - HISTORY_PICKER_BASE_ID) as usize);
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            id if id >= BRANCH_PICKER_BASE_ID => {
+                // Branch picked
+                if let Some(branch) = self.branch_options.get((id - BRANCH_PICKER_BASE_ID) as usize).cloned() {
+                    self.run_git_action(GitAction::Checkout(branch));
+                }
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+            }
+            id if id >= SYMBOL_PICKER_BASE_ID => {
+                // Symbol picked
+                if let Some(&(line, column)) = self.symbol_targets.get((id - SYMBOL_PICKER_BASE_ID) as usize) {
+                    if let Some(ref mut editor) = self.editor {
+                        editor.go_to_position(line, column);
+                    }
+                }
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            55 | 56 => {
+                // Add Cursor Above / Below: grow the column selection
+                if let Some(ref mut editor) = self.editor {
+                    editor.extend_column_selection(if item_id == 55 { -1 } else { 1 }, 0);
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            174 | 175 => {
+                // Indent Using Spaces / Tabs: pick a tab size next
+                self.show_tab_size_picker(item_id == 174);
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            176 | 177 => {
+                // Detect indentation / reindent lines
+                if let Some(ref mut editor) = self.editor {
+                    if item_id == 176 {
+                        editor.detect_active_indent();
+                    } else {
+                        editor.reindent_lines();
+                    }
+                }
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            181..=188 | 191..=198 => {
+                // Tab size picked
+                let indent = if item_id < 190 {
+                    IndentStyle::spaces((item_id - 180) as usize)
+                } else {
+                    IndentStyle::tabs((item_id - 190) as usize)
+                };
+                if let Some(ref mut editor) = self.editor {
+                    editor.set_active_indent(indent);
+                }
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            210 => {
+                // Preferences: Open Theme Editor
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                self.show_theme_editor();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            211 => {
+                // Preferences: Load Color Theme from File
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                if let Some(path) = file_dialogs::open_file_dialog("Load Color Theme", &[("Theme Files", "*.yml;*.yaml")]) {
+                    let result = ThemeFile::load(&path).map_err(|e| e.to_string())
+                        .and_then(|file| self.apply_theme_file(&file));
+                    match result {
+                        Ok(()) => self.log_output(&format!("Loaded theme from {}", path.display())),
+                        Err(e) => self.log_output(&format!("\x1b[31mFailed to load theme: {}\x1b[0m", e)),
+                    }
+                }
+            }
+            216 | 217 => {
+                // View: Pin Editor / Unpin Editor
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                if let Some(ref mut editor) = self.editor {
+                    let tabs = editor.tab_manager_mut();
+                    tabs.set_pinned(tabs.active_index(), item_id == 216);
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            259 => {
+                // View: Reopen Closed Editor
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                self.reopen_closed_tab();
+            }
+            212 => {
+                // Developer: Toggle Performance Overlay
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                self.perf_overlay.toggle();
+                self.profiler.reset();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            254 => {
+                // Developer: Toggle Widget Inspector
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                self.inspector.toggle();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            213 => {
+                // Preferences: Toggle High Contrast Theme
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                let theme = if self.current_theme == AppTheme::HighContrast && self.custom_theme.is_none() {
+                    AppTheme::Kiro
+                } else {
+                    AppTheme::HighContrast
+                };
+                self.set_theme(theme);
+            }
+            214 => {
+                // Preferences: Configure Display Language
+                self.show_locale_picker();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            218 => {
+                // Preferences: File Icon Theme
+                self.show_icon_theme_picker();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            150 | 151 => {
+                // Terminal command navigation (shell integration marks)
+                if let Some(ref mut bottom_panel) = self.bottom_panel {
+                    let moved = if item_id == 150 {
+                        bottom_panel.previous_command()
+                    } else {
+                        bottom_panel.next_command()
+                    };
+                    if moved {
+                        if let Some(window) = &self.window {
+                            window.request_redraw();
+                        }
+                    }
+                }
+            }
+            97 => {
+                // Toggle Macro Recording
+                self.toggle_macro_recording();
+            }
+            98 => {
+                // Play Last Macro
+                let steps = self.macros.last().to_vec();
+                self.play_macro(&steps, 1);
+            }
+            99 => {
+                // Play Last Macro Multiple Times...
+                let items = MACRO_REPEAT_COUNTS
+                    .iter()
+                    .enumerate()
+                    .map(|(i, count)| CommandItem::new((MACRO_REPEAT_BASE_ID + i as i32) as u32, t!("picker.times", count = count)).with_icon(CodiconIcons::REFRESH))
+                    .collect();
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.show_picker(t!("picker.macro_repeat_count"), items);
+                }
+            }
+            204 => {
+                // Save Last Macro
+                self.save_last_macro();
+            }
+            205 => {
+                // Run Saved Macro...
+                self.macro_options = self.config_loader.get_macros().keys().cloned().collect();
+                let items = self.macro_options
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| CommandItem::new((MACRO_PICKER_BASE_ID + i as i32) as u32, name.clone()).with_icon(CodiconIcons::PLAY))
+                    .collect();
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.show_picker(t!("picker.select_macro"), items);
+                }
+            }
+            id if (MACRO_REPEAT_BASE_ID..MACRO_PICKER_BASE_ID).contains(&id) => {
+                // Repeat count picked
+                if let Some(&count) = MACRO_REPEAT_COUNTS.get((id - MACRO_REPEAT_BASE_ID) as usize) {
+                    let steps = self.macros.last().to_vec();
+                    self.play_macro(&steps, count);
+                }
+            }
+            id if (MACRO_PICKER_BASE_ID..LOCALE_PICKER_BASE_ID).contains(&id) => {
+                // Saved macro picked; it becomes the last macro, so Ctrl+Alt+P repeats it
+                let name = self.macro_options.get((id - MACRO_PICKER_BASE_ID) as usize);
+                if let Some(steps) = name.and_then(|name| self.config_loader.get_macros().get(name)).cloned() {
+                    self.macros.set_last(steps.clone());
+                    self.play_macro(&steps, 1);
+                }
+            }
+            255 => {
+                // Preferences: Open Settings
+                self.show_settings_picker();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            66 => {
+                // View: Show Extensions
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                if let Some(ref mut theme_editor) = self.theme_editor {
+                    theme_editor.hide();
+                }
+                if let Some(ref mut keyboard_shortcuts) = self.keyboard_shortcuts {
+                    keyboard_shortcuts.hide();
+                }
+                if let Some(ref mut activitybar) = self.activitybar {
+                    activitybar.set_active_item(ActivityBarItem::Extensions);
+                }
+                self.extensions_changed();
+                if let Some(ref mut extensions_page) = self.extensions_page {
+                    extensions_page.show();
+                }
+            }
+            258 => {
+                // Extensions: Check for Updates
+                self.check_extension_updates();
+            }
+            256 => {
+                // Preferences: Keyboard Shortcuts
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                if let Some(ref mut theme_editor) = self.theme_editor {
+                    theme_editor.hide();
+                }
+                if let Some(ref mut extensions_page) = self.extensions_page {
+                    extensions_page.hide();
+                }
+                self.keybindings_changed();
+                if let Some(ref mut keyboard_shortcuts) = self.keyboard_shortcuts {
+                    keyboard_shortcuts.show();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            id if (SETTINGS_PICKER_BASE_ID..SETTING_VALUE_PICKER_BASE_ID).contains(&id) => {
+                if let Some(schema) = SETTINGS_SCHEMA.iter().filter(|schema| !schema.key.contains('*')).nth((id - SETTINGS_PICKER_BASE_ID) as usize) {
+                    self.edit_setting(schema);
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            id if (PROFILE_PICKER_BASE_ID..PROFILE_WINDOW_PICKER_BASE_ID).contains(&id) => {
+                // Profile picked
+                if let Some(name) = self.profile_options.get((id - PROFILE_PICKER_BASE_ID) as usize).cloned() {
+                    self.switch_profile(&name);
+                }
+            }
+            id if (PROFILE_WINDOW_PICKER_BASE_ID..MACRO_REPEAT_BASE_ID).contains(&id) => {
+                // Profile picked for a new window
+                if let Some(name) = self.profile_options.get((id - PROFILE_WINDOW_PICKER_BASE_ID) as usize) {
+                    spawn_new_window(Some(name.as_str()));
+                }
+            }
+            id if (SSH_HOST_PICKER_BASE_ID..PROFILE_PICKER_BASE_ID).contains(&id) => {
+                // Saved SSH host picked
+                let spec = self.app_state.ssh_hosts.get((id - SSH_HOST_PICKER_BASE_ID) as usize).cloned();
+                if let Some(host) = spec.and_then(|spec| SshHost::parse(&spec).ok()) {
+                    self.ask_remote_folder(host);
+                }
+            }
+            id if (SETTING_VALUE_PICKER_BASE_ID..SSH_HOST_PICKER_BASE_ID).contains(&id) => {
+                if let Some(ref mut command_palette) = self.command_palette {
+                    command_palette.hide();
+                }
+                let target = self.setting_target.take();
+                if let Some((schema, choice)) = target.and_then(|schema| match schema.kind {
+                    SettingKind::Choice(choices) => choices.get((id - SETTING_VALUE_PICKER_BASE_ID) as usize).map(|choice| (schema, *choice)),
+                    _ => None,
+                }) {
+                    self.write_setting(schema.key, serde_yaml::Value::from(choice));
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            250 => {
+                // Preferences: Usage Data
+                self.show_usage_data_picker();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            USAGE_DATA_TOGGLE_ID => {
+                let enabled = !self.telemetry.is_enabled();
+                self.set_telemetry_enabled(enabled);
+            }
+            USAGE_DATA_SHOW_ID => {
+                // The buffer is plain JSON, so it opens in a tab like any other file
+                let path = self.telemetry.flush();
+                if path.exists() {
+                    self.open_file(path);
+                } else {
+                    self.toast.show(t!("toast.usage_data_empty"), None);
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            USAGE_DATA_CLEAR_ID => {
+                self.telemetry.clear();
+                self.toast.show(t!("toast.usage_data_cleared"), Some(CodiconIcons::TRASH));
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            14 => {
+                // Exit, cleanly so the next start doesn't offer crash recovery
+                self.save_state();
+                self.end_session();
+                std::process::exit(0);
+            }
+            _ => {
+                // Delegate to the standalone handler for other menu items
+                handle_menu_action(item_id);
+            }
+        }
+    }
+    
+    fn get_clicked_menu_item_id(&self) -> Option<i32> {
+        if let Some(ref menubar) = self.menubar {
+            // Use Any trait to access MenuBar internals
+            use std::any::Any;
+            let menubar_any = menubar.as_any();
+            if let Some(mb) = menubar_any.downcast_ref::<MenuBar>() {
+                // Access private fields through reflection isn't possible in Rust
+                // We need to add a public method to MenuBar instead
+                return mb.get_clicked_item_id();
+            }
+        }
+        None
+    }
+    
+    fn get_window_title(&self) -> String {
+        if let Some(ref mount) = self.remote {
+            return format!("{} [SSH: {}] - Rabital", mount.name(), mount.host.destination());
+        }
+        
+        // Try to get folder name from workspace path first
+        if let Some(ref workspace_path) = self.app_state.workspace_path {
+            if let Some(folder_name) = workspace_path.file_name() {
+                if let Some(name) = folder_name.to_str() {
+                    return format!("{} - Rabital", name);
+                }
+            }
+        }
+        
+        // Fallback to current directory
+        if let Ok(current_dir) = std::env::current_dir() {
+            if let Some(folder_name) = current_dir.file_name() {
+                if let Some(name) = folder_name.to_str() {
+                    return format!("{} - Rabital", name);
+                }
+            }
+        }
+        
+        // Default to "Untitled"
+        "Untitled - Rabital".to_string()
+    }
+    
+    fn save_state(&mut self) {
+        // Update state with current values
+        if let Some(window) = &self.window {
+            let size = window.inner_size();
+            self.app_state.window_width = size.width;
+            self.app_state.window_height = size.height;
+            
+            if let Ok(pos) = window.outer_position() {
+                self.app_state.window_x = pos.x;
+                self.app_state.window_y = pos.y;
+            }
+        }
+        
+        self.app_state.window_maximized = self.is_window_maximized;
+        self.app_state.set_panel_layout(self.panel_layout());
+        
+        // Save current workspace path, unless it's an archive, which never became the working directory
+        let in_archive = self.app_state.workspace_path.as_ref().is_some_and(|path| self.archives.is_mounted(path));
+        if let Some(current_dir) = std::env::current_dir().ok().filter(|_| !in_archive) {
+            self.app_state.workspace_path = Some(current_dir);
+        }
+        
+        // Save expanded folders from explorer
+        if let Some(ref left_panel) = self.left_panel {
+            self.app_state.expanded_folders = left_panel.explorer().get_expanded_paths();
+        }
+        
+        // Save open tabs; `editor.restore_tabs` decides at startup which of them come back
+        if let Some(ref editor) = self.editor {
+            let tabs = editor.tab_manager();
+            let path_of = |tab: &EditorTab| tab.buffer.file_path().cloned();
+            self.app_state.open_files = tabs.tabs().iter().filter_map(path_of).collect();
+            self.app_state.pinned_files = tabs.tabs().iter().filter(|tab| tab.pinned).filter_map(path_of).collect();
+            self.app_state.active_file = tabs.get_active_tab().and_then(path_of);
+        }
+        
+        // Save to file
+        if let Err(e) = self.app_state.save() {
+            eprintln!("Failed to save state: {}", e);
+        }
+    }
+    
+    #[cfg(target_os = "windows")]
+    fn load_window_icon(&self) -> Option<winit::window::Icon> {
+        // Load icon from embedded bytes
+        const ICON_DATA: &[u8] = include_bytes!("assets/icon.ico");
+        
+        // Try to parse and load the icon
+        match Self::parse_ico(ICON_DATA) {
+            Ok(icon) => {
+                println!("Icon loaded successfully");
+                Some(icon)
+            }
+            Err(e) => {
+                eprintln!("Failed to load icon: {}", e);
+                // Try fallback: create a simple colored icon
+                Self::create_fallback_icon().ok()
+            }
+        }
+    }
+    
+    #[cfg(target_os = "windows")]
+    fn parse_ico(data: &[u8]) -> Result<winit::window::Icon, Box<dyn std::error::Error>> {
+        // Try to load as ICO using image crate
+        let img = image::load_from_memory_with_format(data, image::ImageFormat::Ico)?;
+        
+        // Convert to RGBA8
+        let rgba = img.to_rgba8();
+        let (width, height) = rgba.dimensions();
         
         println!("Icon dimensions: {}x{}", width, height);
         
@@ -544,6 +4898,13 @@ impl App {
     }
     
     fn render(&mut self) {
+        self.sync_bookmarks();
+        self.refresh_outline();
+        self.refresh_todos();
+        self.refresh_inline_blame();
+        if self.inspector.is_enabled() {
+            self.update_inspector();
+        }
         if let (Some(window), Some(surface)) = (&self.window, &mut self.surface) {
             let size = window.inner_size();
             let (width, height) = (size.width, size.height);
@@ -551,6 +4912,9 @@ impl App {
             if width == 0 || height == 0 {
                 return;
             }
+            self.profiler.begin_frame();
+            // Components resolve colors against this window's theme override, if it has one
+            let _window_theme = enter_window(window.id());
             
             let width_nz = NonZeroU32::new(width).unwrap();
             let height_nz = NonZeroU32::new(height).unwrap();
@@ -560,60 +4924,92 @@ impl App {
                 skia_safe::surfaces::raster_n32_premul((width as i32, height as i32)).unwrap();
             let canvas = skia_surface.canvas();
             
-            canvas.clear(self.theme_colors.background);
-            
             let elapsed = self.start_time.elapsed().as_secs_f32();
             
-            // Update titlebar with command palette state
+            // Advance every animation before drawing so the phases can be timed separately
+            let update_span = Span::begin("update");
             let command_palette_open = self.command_palette.as_ref().map_or(false, |cp| cp.is_visible());
             if let Some(ref mut titlebar) = self.titlebar {
                 titlebar.set_command_palette_open(command_palette_open);
                 titlebar.update_animation(elapsed);
-                titlebar.draw(canvas, &mut self.font_manager);
             }
-            
-            // Update menubar animation but draw it in two passes
             if let Some(ref mut menubar) = self.menubar {
                 menubar.update_animation(elapsed);
             }
+            if let Some(ref mut activitybar) = self.activitybar {
+                activitybar.update_animation(elapsed);
+            }
+            if let Some(ref mut left_panel) = self.left_panel {
+                left_panel.update_animation(elapsed);
+            }
+            if let Some(ref mut right_panel) = self.right_panel {
+                right_panel.update_animation(elapsed);
+            }
+            if let Some(ref mut bottom_panel) = self.bottom_panel {
+                bottom_panel.update_animation(elapsed);
+            }
+            for widget in &mut self.widgets {
+                widget.update_animation(elapsed);
+            }
+            if let Some(ref mut editor) = self.editor {
+                editor.update_animation(elapsed);
+            }
+            if let Some(ref mut theme_editor) = self.theme_editor {
+                theme_editor.update_animation(elapsed);
+            }
+            if let Some(ref mut keyboard_shortcuts) = self.keyboard_shortcuts {
+                keyboard_shortcuts.update_animation(elapsed);
+            }
+            if let Some(ref mut extensions_page) = self.extensions_page {
+                extensions_page.update_animation(elapsed);
+            }
+            if let Some(ref mut command_palette) = self.command_palette {
+                command_palette.update_animation(elapsed);
+            }
+            if let Some(ref mut alert_dialog) = self.alert_dialog {
+                alert_dialog.update_animation(elapsed);
+            }
+            self.tab_menu.update_animation(elapsed);
+            self.explorer_menu.update_animation(elapsed);
+            self.toast.update_animation(elapsed);
+            self.profiler.record(update_span);
+            
+            let draw_span = Span::begin("draw");
+            // Panels draw their own translucent backgrounds over the window material
+            canvas.clear(if surface_opacity() < 255 { skia_safe::Color::TRANSPARENT } else { self.theme_colors.background });
+            
+            if let Some(ref titlebar) = self.titlebar {
+                titlebar.draw(canvas, &mut self.font_manager);
+            }
             
             // Draw menubar items (without dropdown)
             if let Some(ref menubar) = self.menubar {
                 menubar.draw_menubar_only(canvas, &mut self.font_manager);
             }
             
-            // Update and draw activity bar
-            if let Some(ref mut activitybar) = self.activitybar {
-                activitybar.update_animation(elapsed);
+            if let Some(ref activitybar) = self.activitybar {
                 activitybar.draw(canvas, &mut self.font_manager);
             }
             
-            // Update and draw layout panels
-            if let Some(ref mut left_panel) = self.left_panel {
-                left_panel.update_animation(elapsed);
+            // Draw layout panels
+            if let Some(ref left_panel) = self.left_panel {
                 left_panel.draw(canvas, &mut self.font_manager);
             }
             
-            if let Some(ref mut right_panel) = self.right_panel {
-                right_panel.update_animation(elapsed);
+            if let Some(ref right_panel) = self.right_panel {
                 right_panel.draw(canvas, &mut self.font_manager);
             }
             
-            if let Some(ref mut bottom_panel) = self.bottom_panel {
-                bottom_panel.update_animation(elapsed);
+            if let Some(ref bottom_panel) = self.bottom_panel {
                 bottom_panel.draw(canvas, &mut self.font_manager);
             }
             
-            // Update and draw widgets
-            for widget in &mut self.widgets {
-                widget.update_animation(elapsed);
+            for widget in &self.widgets {
                 widget.draw(canvas, &mut self.font_manager);
             }
             
-            // Update and draw editor in main area
+            // Draw editor in main area
             if let Some(ref mut editor) = self.editor {
-                editor.update_animation(elapsed);
-                
                 // Create UI font (normal, for tab bar)
                 let ui_font = self.font_manager.create_font("", 13.0, 400);
                 
@@ -646,6 +5042,40 @@ impl App {
                     if let Some((language, line, col)) = editor.get_editor_info() {
                         status_bar.update_editor_info(language, line, col);
                     }
+                    if let Some((encoding, line_ending)) = editor.get_document_info() {
+                        status_bar.update_document_info(encoding.label(), line_ending.label());
+                    }
+                    if let Some(indent) = editor.active_indent() {
+                        status_bar.update_indentation(&indent.label());
+                    }
+                    status_bar.update_character(editor.char_at_cursor());
+                    status_bar.update_pending_saves(editor.pending_auto_saves());
+                }
+            }
+            
+            if let Some(ref mut status_bar) = self.status_bar {
+                status_bar.update_branch(self.git_status.as_ref().map(branch_label), self.git_busy);
+            }
+            
+            if let Some(ref theme_editor) = self.theme_editor {
+                theme_editor.draw(canvas, &mut self.font_manager);
+            }
+            if let Some(ref keyboard_shortcuts) = self.keyboard_shortcuts {
+                keyboard_shortcuts.draw(canvas, &mut self.font_manager);
+            }
+            if let Some(ref extensions_page) = self.extensions_page {
+                extensions_page.draw(canvas, &mut self.font_manager);
+            }
+            
+            // Splitter handles highlight on top of the panel edges
+            let splitters = [
+                (self.layout_config.left_panel_visible, &self.left_splitter),
+                (self.layout_config.right_panel_visible, &self.right_splitter),
+                (self.layout_config.bottom_panel_visible, &self.bottom_splitter),
+            ];
+            for (visible, splitter) in splitters {
+                if visible || splitter.is_dragging() {
+                    splitter.draw(canvas, &mut self.font_manager);
                 }
             }
             
@@ -654,34 +5084,62 @@ impl App {
                 status_bar.draw(canvas, &mut self.font_manager);
             }
             
+            // Dragged editor text follows the mouse over every panel
+            if let Some(ref editor) = self.editor {
+                let ui_font = self.font_manager.create_font("", 13.0, 400);
+                editor.draw_text_drag(canvas, &ui_font);
+            }
+            
             // Draw menubar dropdown on top of everything
             if let Some(ref menubar) = self.menubar {
                 menubar.draw_dropdown_only(canvas, &mut self.font_manager);
             }
             
             // Draw command palette on top of everything (if visible)
-            if let Some(ref mut command_palette) = self.command_palette {
-                command_palette.update_animation(elapsed);
+            if let Some(ref command_palette) = self.command_palette {
                 command_palette.draw(canvas, &mut self.font_manager);
             }
             
+            if let Some(ref go_to_line) = self.go_to_line {
+                go_to_line.draw(canvas, &mut self.font_manager);
+            }
+            
+            if let Some(ref find_bytes) = self.find_bytes {
+                find_bytes.draw(canvas, &mut self.font_manager);
+            }
+            
+            self.tab_menu.draw(canvas, &mut self.font_manager);
+            self.explorer_menu.draw(canvas, &mut self.font_manager);
+            self.toast.draw(canvas, &mut self.font_manager);
+            
+            // Confirmation dialog is modal, so it goes above every other overlay
+            if let Some(ref alert_dialog) = self.alert_dialog {
+                alert_dialog.draw(canvas, &mut self.font_manager);
+            }
+            
+            self.inspector.draw(canvas, &mut self.font_manager, height as f32);
+            self.perf_overlay.draw(canvas, &mut self.font_manager, &self.profiler);
+            self.profiler.record(draw_span);
+            
+            let copy_span = Span::begin("buffer copy");
             let image = skia_surface.image_snapshot();
             if let Some(pixels) = image.peek_pixels() {
                 let mut buffer = surface.buffer_mut().unwrap();
                 let src = pixels.bytes().unwrap();
-                
-                for y in 0..height as usize {
-                    for x in 0..width as usize {
-                        let idx = (y * width as usize + x) * 4;
-                        let b = src[idx] as u32;
-                        let g = src[idx + 1] as u32;
-                        let r = src[idx + 2] as u32;
-                        let a = src[idx + 3] as u32;
-                        buffer[y * width as usize + x] = (a << 24) | (r << 16) | (g << 8) | b;
-                    }
+                if buffer.age() == 1 {
+                    // The buffer still holds the frame on screen: upload only what changed
+                    let damage = copy_changed_to_buffer(src, &mut buffer, width as usize);
+                    buffer.present_with_damage(&damage).unwrap();
+                } else {
+                    copy_to_buffer(src, &mut buffer);
+                    buffer.present().unwrap();
                 }
-                
-                buffer.present().unwrap();
+            }
+            self.profiler.record(copy_span);
+            self.profiler.end_frame();
+            if self.perf_overlay.is_visible() {
+                let widget_count = self.widget_count();
+                self.perf_overlay.update(&self.profiler, widget_count);
             }
             
             // Request another frame if animation is in progress or resizing
@@ -691,44 +5149,296 @@ impl App {
         }
     }
     
+    /// Reload unmodified tabs, and image and Markdown previews, whose files changed on disk since the last poll
+    fn reload_changed_files(&mut self) {
+        let Some(ref mut editor) = self.editor else {
+            return;
+        };
+        let open: Vec<PathBuf> = editor.tab_manager().tabs().iter().filter_map(|tab| tab.source_path().map(Path::to_path_buf)).collect();
+        self.file_watcher.set_paths(open.iter().map(|path| path.as_path()));
+        
+        let mut reloaded = Vec::new();
+        for path in self.file_watcher.poll() {
+            match editor.reload_file(&path) {
+                Ok(true) => reloaded.push(path),
+                Ok(false) => {}
+                Err(e) => eprintln!("Failed to reload {}: {}", path.display(), e),
+            }
+        }
+        let message = match reloaded.as_slice() {
+            [] => return,
+            [path] => t!("toast.reloaded", name = path.file_name().unwrap_or_default().to_string_lossy()),
+            paths => t!("toast.reloaded_many", count = paths.len()),
+        };
+        for path in &reloaded {
+            self.log_output(&format!("Reloaded {}", path.display()));
+        }
+        self.toast.show(message, Some(CodiconIcons::REFRESH));
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Reapply the settings or keybindings when one of their files changed on disk
+    fn reload_changed_settings(&mut self) {
+        let keybindings_file = self.config_loader.keybindings_file();
+        let mut files = self.config_loader.settings_files();
+        files.push(keybindings_file.clone());
+        self.settings_watcher.set_paths(files.iter().map(|path| path.as_path()));
+        let (keybindings, changed): (Vec<PathBuf>, Vec<PathBuf>) = self.settings_watcher
+            .poll()
+            .into_iter()
+            .partition(|path| *path == keybindings_file);
+        if !keybindings.is_empty() {
+            self.log_output(&format!("Keybindings changed: {}", keybindings_file.display()));
+            self.load_keybindings();
+        }
+        if changed.is_empty() {
+            return;
+        }
+        for path in &changed {
+            self.log_output(&format!("Settings changed: {}", path.display()));
+        }
+        self.config_loader.reload_settings();
+        self.apply_settings();
+        self.toast.show(t!("toast.settings_reloaded"), Some(CodiconIcons::REFRESH));
+        // Shown after the reload toast so a broken file isn't reported as a clean reload
+        self.report_settings_issues();
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Hand the inspector the window's widgets in draw order, so the topmost one wins the hover
+    fn update_inspector(&mut self) {
+        let mut roots: Vec<&dyn Widget> = Vec::new();
+        if let Some(ref titlebar) = self.titlebar {
+            roots.push(titlebar);
+        }
+        if let Some(ref menubar) = self.menubar {
+            roots.push(menubar);
+        }
+        if let Some(ref activitybar) = self.activitybar {
+            roots.push(activitybar);
+        }
+        if let Some(ref left_panel) = self.left_panel {
+            roots.push(left_panel);
+        }
+        if let Some(ref right_panel) = self.right_panel {
+            roots.push(right_panel);
+        }
+        if let Some(ref bottom_panel) = self.bottom_panel {
+            roots.push(bottom_panel);
+        }
+        roots.extend(self.widgets.iter().map(|widget| widget.as_ref()));
+        if let Some(keyboard_shortcuts) = self.keyboard_shortcuts.as_ref().filter(|page| page.is_visible()) {
+            roots.push(keyboard_shortcuts);
+        }
+        if let Some(extensions_page) = self.extensions_page.as_ref().filter(|page| page.is_visible()) {
+            roots.push(extensions_page);
+        }
+        if let Some(ref status_bar) = self.status_bar {
+            roots.push(status_bar);
+        }
+        if let Some(command_palette) = self.command_palette.as_ref().filter(|cp| cp.is_visible()) {
+            roots.push(command_palette);
+        }
+        if let Some(go_to_line) = self.go_to_line.as_ref().filter(|g| g.is_visible()) {
+            roots.push(go_to_line);
+        }
+        if let Some(find_bytes) = self.find_bytes.as_ref().filter(|f| f.is_visible()) {
+            roots.push(find_bytes);
+        }
+        if let Some(alert_dialog) = self.alert_dialog.as_ref().filter(|d| d.is_visible()) {
+            roots.push(alert_dialog);
+        }
+        self.inspector.update(&roots, self.mouse_pos.0, self.mouse_pos.1);
+    }
+    
+    /// Top-level widgets and components currently in the tree, for the performance overlay
+    fn widget_count(&self) -> usize {
+        let components = [
+            self.titlebar.is_some(),
+            self.menubar.is_some(),
+            self.activitybar.is_some(),
+            self.left_panel.is_some(),
+            self.right_panel.is_some(),
+            self.bottom_panel.is_some(),
+            self.status_bar.is_some(),
+            self.command_palette.is_some(),
+            self.go_to_line.is_some(),
+            self.find_bytes.is_some(),
+            self.theme_editor.is_some(),
+            self.keyboard_shortcuts.is_some(),
+            self.extensions_page.is_some(),
+            self.alert_dialog.is_some(),
+            self.editor.is_some(),
+        ];
+        self.widgets.len() + components.iter().filter(|present| **present).count()
+    }
+    
     fn update_control_flow(&self, event_loop: &ActiveEventLoop) {
         if self.needs_continuous_redraw() {
             event_loop.set_control_flow(ControlFlow::Poll);
         } else {
-            event_loop.set_control_flow(ControlFlow::Wait);
+            // Wake up for the session heartbeat, to poll the files open in tabs and for auto save
+            let mut wake_at = self.session_lock.next_beat();
+            if !self.file_watcher.is_empty() {
+                wake_at = wake_at.min(self.file_watcher.next_poll());
+            }
+            if !self.settings_watcher.is_empty() {
+                wake_at = wake_at.min(self.settings_watcher.next_poll());
+            }
+            if let Some(deadline) = self.editor.as_ref().and_then(|editor| editor.auto_save_deadline()) {
+                wake_at = wake_at.min(deadline);
+            }
+            event_loop.set_control_flow(ControlFlow::WaitUntil(wake_at));
         }
     }
     
     fn needs_continuous_redraw(&self) -> bool {
-        // Check if command palette is animating
+        // The performance overlay graphs every frame; the toast fades
+        if self.perf_overlay.is_visible() || self.toast.is_visible() {
+            return true;
+        }
+        
+        // Check if command palette is animating, or waiting for results
         if let Some(ref command_palette) = self.command_palette {
-            if command_palette.is_animating() {
+            if command_palette.is_animating() || command_palette.is_loading() {
                 return true;
             }
         }
         
         // Check if any panel is resizing
+        if self.left_splitter.is_dragging() || self.right_splitter.is_dragging() || self.bottom_splitter.is_dragging() {
+            return true;
+        }
         if let Some(ref left_panel) = self.left_panel {
-            if left_panel.is_resizing() || left_panel.is_scrollbar_dragging() {
+            if left_panel.is_scrollbar_dragging() || left_panel.is_animating() {
                 return true;
             }
         }
-        if let Some(ref right_panel) = self.right_panel {
-            if right_panel.is_resizing() {
-                return true;
-            }
+        if self.editor.as_ref().is_some_and(|editor| editor.is_animating()) {
+            return true;
         }
-        if let Some(ref bottom_panel) = self.bottom_panel {
-            if bottom_panel.is_resizing() {
-                return true;
-            }
+        if self.bottom_panel.as_ref().is_some_and(|panel| panel.is_animating()) {
+            return true;
         }
         
         false
     }
     
+    /// Run an editing command from the keyboard, recording it while a macro is being recorded
+    fn run_edit(&mut self, command: EditorCommand) {
+        self.execute_edit(&command);
+        self.macros.record(MacroStep::Edit(command));
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Run an editing command; copied or cut text goes to the clipboard
+    fn execute_edit(&mut self, command: &EditorCommand) {
+        if let Some(text) = self.editor.as_mut().and_then(|editor| editor.execute(command)) {
+            if let Ok(mut clipboard) = arboard::Clipboard::new() {
+                let _ = clipboard.set_text(text);
+            }
+        }
+    }
+    
+    /// Start recording a macro, or stop and keep it as the last macro
+    fn toggle_macro_recording(&mut self) {
+        if self.macros.is_recording() {
+            let steps = self.macros.stop();
+            self.toast.show(t!("toast.macro_recorded", count = steps), Some(CodiconIcons::RECORD_KEYS));
+        } else {
+            self.macros.start();
+            self.toast.show(t!("toast.macro_recording"), Some(CodiconIcons::RECORD));
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Replay macro steps `times` times over
+    fn play_macro(&mut self, steps: &[MacroStep], times: usize) {
+        if self.macros.is_recording() {
+            self.log_output("Stop recording (Ctrl+Alt+R) before playing a macro");
+            return;
+        }
+        if steps.is_empty() {
+            self.log_output("No macro recorded yet; Ctrl+Alt+R starts recording");
+            return;
+        }
+        for _ in 0..times {
+            for step in steps {
+                match step {
+                    MacroStep::Edit(command) => self.execute_edit(command),
+                    // Saved macros are hand-editable, so only commands a recording could hold run
+                    MacroStep::Command { command } if MACRO_COMMANDS.contains(&(*command as i32)) => self.handle_menu_action(*command as i32),
+                    MacroStep::Command { command } => eprintln!("Macro step skipped: command {} can't be replayed", command),
+                }
+            }
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
+    /// Save the last macro under the first free `macro-N` name; it can be renamed in macros.yml
+    fn save_last_macro(&mut self) {
+        let steps = self.macros.last().to_vec();
+        if steps.is_empty() {
+            self.log_output("No macro recorded yet; Ctrl+Alt+R starts recording");
+            return;
+        }
+        let saved = self.config_loader.get_macros();
+        let name = (1..).map(|n| format!("macro-{}", n)).find(|name| !saved.contains_key(name)).unwrap_or_default();
+        match self.config_loader.save_macro(&name, steps) {
+            Ok(path) => {
+                self.log_output(&format!("Saved macro '{}' to {}", name, path.display()));
+                self.toast.show(t!("toast.macro_saved", name = name), Some(CodiconIcons::SAVE));
+            }
+            Err(e) => self.log_output(&format!("\x1b[31mFailed to save macro: {}\x1b[0m", e)),
+        }
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+    
     fn insert_text(&mut self, text: &str, command_palette_visible: bool) {
-        if command_palette_visible {
+        if let Some(find_bytes) = self.find_bytes.as_mut().filter(|f| f.is_visible()) {
+            for c in text.chars() {
+                find_bytes.add_char(c);
+            }
+        } else if let Some(go_to_line) = self.go_to_line.as_mut().filter(|g| g.is_visible()) {
+            for c in text.chars() {
+                go_to_line.add_char(c);
+            }
+        } else if let Some(keyboard_shortcuts) = self.keyboard_shortcuts.as_mut().filter(|page| page.is_visible() && !command_palette_visible) {
+            for c in text.chars() {
+                if !c.is_control() {
+                    keyboard_shortcuts.handle_char(c);
+                }
+            }
+        } else if let Some(extensions_page) = self.extensions_page.as_mut().filter(|page| page.is_visible() && !command_palette_visible) {
+            for c in text.chars() {
+                if !c.is_control() {
+                    extensions_page.handle_char(c);
+                }
+            }
+        } else if let Some(theme_editor) = self.theme_editor.as_mut().filter(|t| t.is_visible() && !command_palette_visible) {
+            let mut changed = false;
+            for c in text.chars() {
+                if !c.is_control() {
+                    changed |= theme_editor.handle_char(c);
+                }
+            }
+            if changed {
+                let (name, colors) = (theme_editor.name().to_string(), theme_editor.colors());
+                self.preview_theme(&name, colors);
+            }
+        } else if command_palette_visible {
             if let Some(ref mut command_palette) = self.command_palette {
                 for c in text.chars() {
                     if !c.is_control() {
@@ -736,21 +5446,29 @@ impl App {
                     }
                 }
             }
-        } else {
-            if let Some(ref mut editor) = self.editor {
-                for c in text.chars() {
-                    if !c.is_control() || c == '\t' {
-                        if c == '\t' {
-                            editor.insert_char(' ');
-                            editor.insert_char(' ');
-                            editor.insert_char(' ');
-                            editor.insert_char(' ');
-                        } else {
-                            editor.insert_char(c);
-                        }
-                    }
+            self.fill_palette_mode();
+        } else if let Some(left_panel) = self.left_panel.as_mut().filter(|panel| panel.is_search_focused()) {
+            for c in text.chars() {
+                if !c.is_control() {
+                    left_panel.search_mut().handle_char(c);
                 }
             }
+        } else if let Some(left_panel) = self.left_panel.as_mut().filter(|panel| panel.is_filter_focused()) {
+            for c in text.chars() {
+                if !c.is_control() {
+                    left_panel.filter_char(c);
+                }
+            }
+        } else if let Some(left_panel) = self.left_panel.as_mut().filter(|panel| panel.is_explorer_focused()) {
+            for c in text.chars() {
+                left_panel.explorer_char(c);
+            }
+        } else {
+            // Tab arrives here too but is handled as a key in handle_special_key
+            let typed: String = text.chars().filter(|c| !c.is_control()).collect();
+            if !typed.is_empty() {
+                self.run_edit(EditorCommand::Type(typed));
+            }
         }
         
         if let Some(window) = &self.window {
@@ -762,62 +5480,55 @@ impl App {
         use winit::keyboard::KeyCode;
         
         match code {
-            KeyCode::KeyA => {
-                // Select All
+            KeyCode::Space => {
+                // Trigger Suggest
                 if let Some(ref mut editor) = self.editor {
-                    editor.select_all();
+                    editor.trigger_completion();
                     if let Some(window) = &self.window {
                         window.request_redraw();
                     }
                 }
                 true
             }
+            KeyCode::KeyA => {
+                // Select All
+                self.run_edit(EditorCommand::SelectAll);
+                true
+            }
             KeyCode::KeyC => {
-                // Copy
-                if let Some(ref editor) = self.editor {
-                    if let Some(tab) = editor.tab_manager().get_active_tab() {
-                        let text = tab.get_selected_text();
-                        if !text.is_empty() {
-                            if let Ok(mut clipboard) = arboard::Clipboard::new() {
-                                let _ = clipboard.set_text(text);
-                            }
-                        }
+                // Copy (output selection first, then editor)
+                if let Some(text) = self.bottom_panel.as_ref().and_then(|p| p.selected_text()) {
+                    if let Ok(mut clipboard) = arboard::Clipboard::new() {
+                        let _ = clipboard.set_text(text);
                     }
+                } else {
+                    self.run_edit(EditorCommand::Copy);
                 }
                 true
             }
+            KeyCode::KeyX if self.modifiers.shift_key() => {
+                // Show Extensions (Ctrl+Shift+X)
+                self.handle_menu_action(66);
+                true
+            }
             KeyCode::KeyX => {
                 // Cut
-                if let Some(ref mut editor) = self.editor {
-                    if let Some(tab) = editor.tab_manager_mut().get_active_tab_mut() {
-                        let text = tab.get_selected_text();
-                        if !text.is_empty() {
-                            if let Ok(mut clipboard) = arboard::Clipboard::new() {
-                                let _ = clipboard.set_text(text);
-                            }
-                            tab.delete_selection();
-                            if let Some(window) = &self.window {
-                                window.request_redraw();
-                            }
-                        }
-                    }
-                }
+                self.run_edit(EditorCommand::Cut);
                 true
             }
             KeyCode::KeyV => {
-                // Paste
-                if let Ok(mut clipboard) = arboard::Clipboard::new() {
-                    if let Ok(text) = clipboard.get_text() {
-                        if let Some(ref mut editor) = self.editor {
-                            editor.insert_text(&text);
-                            if let Some(window) = &self.window {
-                                window.request_redraw();
-                            }
-                        }
-                    }
+                // Paste; a macro keeps the pasted text rather than the clipboard at replay time
+                let text = arboard::Clipboard::new().and_then(|mut clipboard| clipboard.get_text());
+                if let Ok(text) = text {
+                    self.run_edit(EditorCommand::Paste(text));
                 }
                 true
             }
+            KeyCode::KeyS => {
+                // Save (Ctrl+S)
+                self.handle_menu_action(6);
+                true
+            }
             KeyCode::KeyN => {
                 // New Tab (Ctrl+N)
                 if let Some(ref mut editor) = self.editor {
@@ -829,6 +5540,72 @@ impl App {
                 }
                 true
             }
+            KeyCode::KeyO if self.modifiers.shift_key() => {
+                // Go to Symbol (Ctrl+Shift+O)
+                self.handle_menu_action(85);
+                true
+            }
+            KeyCode::KeyE if self.modifiers.shift_key() => {
+                // Show Explorer (Ctrl+Shift+E)
+                self.handle_menu_action(62);
+                true
+            }
+            KeyCode::KeyF if self.modifiers.shift_key() => {
+                // Show Search (Ctrl+Shift+F)
+                self.handle_menu_action(63);
+                true
+            }
+            KeyCode::KeyU if self.modifiers.shift_key() => {
+                // Show Output (Ctrl+Shift+U)
+                self.handle_menu_action(68);
+                true
+            }
+            KeyCode::KeyF if self.editor.as_ref().is_some_and(|editor| editor.is_active_hex()) => {
+                // Find bytes (Ctrl+F in the hex editor)
+                self.handle_menu_action(203);
+                true
+            }
+            KeyCode::KeyR if self.modifiers.alt_key() => {
+                // Toggle Macro Recording (Ctrl+Alt+R)
+                self.handle_menu_action(97);
+                true
+            }
+            KeyCode::KeyP if self.modifiers.alt_key() && self.modifiers.shift_key() => {
+                // Pin or unpin the active editor (Ctrl+Alt+Shift+P)
+                let pinned = self.editor.as_ref().and_then(|editor| editor.tab_manager().get_active_tab()).is_some_and(|tab| tab.pinned);
+                self.handle_menu_action(if pinned { 217 } else { 216 });
+                true
+            }
+            KeyCode::KeyP if self.modifiers.alt_key() => {
+                // Play Last Macro (Ctrl+Alt+P)
+                self.handle_menu_action(98);
+                true
+            }
+            KeyCode::KeyP if self.modifiers.shift_key() => {
+                // Command Palette (Ctrl+Shift+P)
+                self.handle_menu_action(60);
+                true
+            }
+            KeyCode::KeyP => {
+                // Go to File (Ctrl+P)
+                self.handle_menu_action(84);
+                true
+            }
+            KeyCode::KeyT if self.modifiers.shift_key() => {
+                // Reopen Closed Editor (Ctrl+Shift+T)
+                self.handle_menu_action(259);
+                true
+            }
+            KeyCode::KeyT => {
+                // Go to Symbol in Workspace (Ctrl+T)
+                self.handle_menu_action(93);
+                true
+            }
+            KeyCode::KeyG => {
+                // Go to Line (Ctrl+G)
+                self.handle_menu_action(91);
+                true
+            }
             KeyCode::KeyO => {
                 // Open File (Ctrl+O)
                 use mikoui::file_dialogs;
@@ -836,15 +5613,8 @@ impl App {
                 match file_dialogs::open_file_dialog("Open File", &[("All Files", "*.*")]) {
                     Some(path) => {
                         println!("File selected: {:?}", path);
-                        if let Some(ref mut editor) = self.editor {
-                            match editor.open_file(path.clone()) {
-                                Ok(_) => {
-                                    println!("File opened successfully");
-                                }
-                                Err(e) => {
-                                    eprintln!("Failed to open file: {}", e);
-                                }
-                            }
+                        if self.open_file(path) {
+                            println!("File opened successfully");
                         }
                         if let Some(window) = &self.window {
                             window.request_redraw();
@@ -858,13 +5628,12 @@ impl App {
             }
             KeyCode::KeyW => {
                 // Close Tab (Ctrl+W)
-                if let Some(ref mut editor) = self.editor {
-                    editor.close_active_tab();
-                    println!("Closed active tab");
-                    if let Some(window) = &self.window {
-                        window.request_redraw();
-                    }
-                }
+                self.request_close_active_tab();
+                true
+            }
+            KeyCode::KeyZ if self.left_panel.as_ref().is_some_and(|panel| panel.is_explorer_focused()) => {
+                // Undo Last File Operation (Ctrl+Z in the explorer)
+                self.handle_menu_action(96);
                 true
             }
             KeyCode::KeyZ => {
@@ -877,6 +5646,29 @@ impl App {
                 println!("Redo not yet implemented");
                 true
             }
+            KeyCode::ArrowUp | KeyCode::ArrowDown | KeyCode::ArrowLeft | KeyCode::ArrowRight
+                if self.modifiers.alt_key() =>
+            {
+                // Column (box) selection (Ctrl+Alt+Arrow)
+                let (line_delta, column_delta) = match code {
+                    KeyCode::ArrowUp => (-1, 0),
+                    KeyCode::ArrowDown => (1, 0),
+                    KeyCode::ArrowLeft => (0, -1),
+                    _ => (0, 1),
+                };
+                self.run_edit(EditorCommand::ColumnSelect { lines: line_delta, columns: column_delta });
+                true
+            }
+            KeyCode::ArrowUp if self.bottom_panel.is_some() => {
+                // Go to Previous Command (Ctrl+Up)
+                self.handle_menu_action(150);
+                true
+            }
+            KeyCode::ArrowDown if self.bottom_panel.is_some() => {
+                // Go to Next Command (Ctrl+Down)
+                self.handle_menu_action(151);
+                true
+            }
             KeyCode::Tab => {
                 // Next Tab (Ctrl+Tab)
                 if let Some(ref mut editor) = self.editor {
@@ -894,7 +5686,110 @@ impl App {
     fn handle_special_key(&mut self, code: winit::keyboard::KeyCode, command_palette_visible: bool) {
         use winit::keyboard::KeyCode;
         
-        if command_palette_visible {
+        let go_to_line_visible = self.go_to_line.as_ref().is_some_and(|g| g.is_visible());
+        let find_bytes_visible = self.find_bytes.as_ref().is_some_and(|f| f.is_visible());
+        let theme_editor_visible = self.theme_editor.as_ref().is_some_and(|t| t.is_visible());
+        let keyboard_shortcuts_visible = self.keyboard_shortcuts.as_ref().is_some_and(|page| page.is_visible());
+        let extensions_visible = self.extensions_page.as_ref().is_some_and(|page| page.is_visible());
+        
+        if find_bytes_visible {
+            let key_str = match code {
+                KeyCode::Escape => "Escape",
+                KeyCode::Enter => "Enter",
+                KeyCode::Backspace => "Backspace",
+                _ => "",
+            };
+            
+            let pattern = self.find_bytes.as_mut().and_then(|f| f.handle_key_input(key_str));
+            if let Some(pattern) = pattern {
+                let found = self.editor.as_mut().and_then(|editor| editor.find_bytes(&pattern));
+                if let Some(ref mut find_bytes) = self.find_bytes {
+                    match found {
+                        Some(offset) => find_bytes.set_status(t!("find_bytes.found", offset = format!("{:X}", offset))),
+                        None => find_bytes.set_status(t!("find_bytes.no_match")),
+                    }
+                }
+            }
+        } else if go_to_line_visible {
+            let key_str = match code {
+                KeyCode::Escape => "Escape",
+                KeyCode::Enter => "Enter",
+                KeyCode::Backspace => "Backspace",
+                _ => "",
+            };
+            
+            let target = self.go_to_line.as_mut().and_then(|g| g.handle_key_input(key_str));
+            if let (Some((line, column)), Some(editor)) = (target, self.editor.as_mut()) {
+                editor.go_to_position(line - 1, column - 1);
+            }
+        } else if keyboard_shortcuts_visible && !command_palette_visible {
+            if let Some(ref mut keyboard_shortcuts) = self.keyboard_shortcuts {
+                let action = match code {
+                    KeyCode::Escape => {
+                        keyboard_shortcuts.handle_escape();
+                        None
+                    }
+                    KeyCode::ArrowUp => {
+                        keyboard_shortcuts.move_selection(-1);
+                        None
+                    }
+                    KeyCode::ArrowDown => {
+                        keyboard_shortcuts.move_selection(1);
+                        None
+                    }
+                    KeyCode::Enter => {
+                        keyboard_shortcuts.record_selected();
+                        None
+                    }
+                    KeyCode::Backspace => {
+                        keyboard_shortcuts.handle_backspace();
+                        None
+                    }
+                    KeyCode::Delete => keyboard_shortcuts.remove_selected(),
+                    _ => return,
+                };
+                self.handle_keyboard_shortcuts_action(action);
+            }
+        } else if extensions_visible && !command_palette_visible {
+            if let Some(ref mut extensions_page) = self.extensions_page {
+                let action = match code {
+                    KeyCode::Escape => {
+                        extensions_page.handle_escape();
+                        None
+                    }
+                    KeyCode::ArrowUp => {
+                        extensions_page.move_selection(-1);
+                        None
+                    }
+                    KeyCode::ArrowDown => {
+                        extensions_page.move_selection(1);
+                        None
+                    }
+                    KeyCode::Enter => extensions_page.toggle_selected(),
+                    KeyCode::Backspace => {
+                        extensions_page.handle_backspace();
+                        None
+                    }
+                    _ => return,
+                };
+                self.handle_extensions_action(action);
+            }
+        } else if theme_editor_visible && !command_palette_visible {
+            if let Some(ref mut theme_editor) = self.theme_editor {
+                match code {
+                    KeyCode::Escape => theme_editor.hide(),
+                    KeyCode::ArrowUp => theme_editor.move_selection(-1),
+                    KeyCode::ArrowDown => theme_editor.move_selection(1),
+                    KeyCode::Backspace => {
+                        if theme_editor.handle_backspace() {
+                            let (name, colors) = (theme_editor.name().to_string(), theme_editor.colors());
+                            self.preview_theme(&name, colors);
+                        }
+                    }
+                    _ => return,
+                }
+            }
+        } else if command_palette_visible {
             if let Some(ref mut command_palette) = self.command_palette {
                 let key_str = match code {
                     KeyCode::Escape => "Escape",
@@ -907,28 +5802,94 @@ impl App {
                 
                 if !key_str.is_empty() {
                     if let Some(command_id) = command_palette.handle_key_input(key_str) {
+                        self.record_palette_use();
                         self.handle_menu_action(command_id as i32);
                     }
                 }
             }
+            self.fill_palette_mode();
+        } else if let Some(left_panel) = self.left_panel.as_mut().filter(|panel| panel.is_search_focused()) {
+            let search = left_panel.search_mut();
+            let action = match code {
+                KeyCode::Backspace => {
+                    search.handle_backspace();
+                    None
+                }
+                KeyCode::Enter => search.handle_key("Enter"),
+                KeyCode::Tab => search.handle_key("Tab"),
+                KeyCode::Escape => search.handle_key("Escape"),
+                _ => None,
+            };
+            if let Some(action) = action {
+                self.handle_search_action(action);
+            }
+        } else if let Some(left_panel) = self.left_panel.as_mut().filter(|panel| panel.is_filter_focused()) {
+            match code {
+                KeyCode::Escape => left_panel.clear_filter(),
+                // Move on to the filtered tree
+                KeyCode::Enter | KeyCode::ArrowDown => left_panel.focus_explorer(),
+                KeyCode::Backspace => left_panel.filter_backspace(),
+                _ => {}
+            }
+        } else if let Some(left_panel) = self.left_panel.as_mut().filter(|panel| panel.is_explorer_focused()) {
+            let key_str = match code {
+                KeyCode::Escape => "Escape",
+                KeyCode::Enter => "Enter",
+                KeyCode::ArrowUp => "ArrowUp",
+                KeyCode::ArrowDown => "ArrowDown",
+                KeyCode::ArrowLeft => "ArrowLeft",
+                KeyCode::ArrowRight => "ArrowRight",
+                KeyCode::PageUp => "PageUp",
+                KeyCode::PageDown => "PageDown",
+                KeyCode::Home => "Home",
+                KeyCode::End => "End",
+                _ => "",
+            };
+            if code == KeyCode::Delete {
+                self.handle_menu_action(95);
+            } else if key_str == "Escape" {
+                left_panel.blur();
+            } else if left_panel.explorer_key(key_str) {
+                if let Some(path) = left_panel.take_clicked_file() {
+                    self.open_explorer_file(path);
+                }
+            }
         } else {
-            if let Some(ref mut editor) = self.editor {
-                match code {
-                    KeyCode::ArrowLeft => editor.move_cursor_left(),
-                    KeyCode::ArrowRight => editor.move_cursor_right(),
-                    KeyCode::ArrowUp => editor.move_cursor_up(),
-                    KeyCode::ArrowDown => editor.move_cursor_down(),
-                    KeyCode::Backspace => editor.delete_char(),
-                    KeyCode::Enter => editor.insert_newline(),
-                    KeyCode::Tab => {
-                        editor.insert_char(' ');
-                        editor.insert_char(' ');
-                        editor.insert_char(' ');
-                        editor.insert_char(' ');
-                    }
-                    _ => return,
+            let key_str = match code {
+                KeyCode::Escape => "Escape",
+                KeyCode::Enter => "Enter",
+                KeyCode::Tab => "Tab",
+                KeyCode::ArrowUp => "ArrowUp",
+                KeyCode::ArrowDown => "ArrowDown",
+                _ => "",
+            };
+            // Escape closes the completion popup or a hover card before it clears the selection
+            let handled = self.editor.as_mut().is_some_and(|editor| {
+                (key_str == "Escape" && editor.cancel_text_drag())
+                    || editor.handle_completion_key(key_str)
+                    || (key_str == "Escape" && (editor.dismiss_hover() || editor.close_peek()))
+            });
+            if handled {
+                self.preview_text_drop(None);
+                if let Some(window) = &self.window {
+                    window.request_redraw();
                 }
+                return;
             }
+            
+            let command = match code {
+                KeyCode::ArrowLeft => EditorCommand::CursorLeft,
+                KeyCode::ArrowRight => EditorCommand::CursorRight,
+                KeyCode::ArrowUp => EditorCommand::CursorUp,
+                KeyCode::ArrowDown => EditorCommand::CursorDown,
+                KeyCode::Backspace => EditorCommand::DeleteLeft,
+                KeyCode::Enter => EditorCommand::Newline,
+                KeyCode::Escape => EditorCommand::ClearSelection,
+                KeyCode::Tab if self.modifiers.shift_key() => EditorCommand::Outdent,
+                KeyCode::Tab => EditorCommand::Indent,
+                _ => return,
+            };
+            self.run_edit(command);
         }
         
         if let Some(window) = &self.window {
@@ -950,7 +5911,8 @@ impl ApplicationHandler for App {
                     WINDOW_HEIGHT as i32,
                 ))
                 .with_decorations(false)
-                .with_resizable(true);
+                .with_resizable(true)
+                .with_transparent(self.window_material() != WindowMaterial::None);
             
             // Set window icon
             #[cfg(target_os = "windows")]
@@ -981,21 +5943,93 @@ impl ApplicationHandler for App {
             
             self.window = Some(window.clone());
             self.surface = Some(surface);
+            self.apply_window_material();
             
             let size = window.inner_size();
             self.build_ui(size.width as f32, size.height as f32);
+            self.restore_tabs();
+            self.refresh_git_status();
+            self.report_settings_issues();
+            self.load_keybindings();
+            self.offer_session_recovery();
+            
+            if let Some(workspace) = self.app_state.workspace_path.clone() {
+                self.app_state.add_recent_workspace(workspace);
+            }
+            if let Some(launch) = self.launch.take() {
+                self.open_launch(launch);
+            }
+            url_scheme::register_url_scheme(cli::URL_SCHEME, "Rabital");
+            self.update_jump_list();
+        }
+    }
+    
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        self.reload_changed_files();
+        self.reload_changed_settings();
+        if self.editor.as_mut().is_some_and(|editor| editor.poll_auto_save()) {
+            self.auto_save_files();
         }
+        if self.session_lock.beat() {
+            self.backup_modified_tabs();
+            let telemetry = self.config_loader.get_settings().map(|settings| settings.telemetry.clone()).unwrap_or_default();
+            let interval = Duration::from_secs(telemetry.upload_interval_hours as u64 * 3600);
+            self.telemetry.poll_upload(&telemetry.endpoint, interval);
+            self.telemetry.flush();
+        }
+        if self.poll_palette_search() {
+            if let Some(window) = &self.window {
+                window.request_redraw();
+            }
+        }
+        self.drain_ui_messages();
+        self.request_remote_listings();
+        if !self.wait_tabs.is_empty() && !self.wait_tabs.iter().any(|id| self.is_tab_open(*id)) {
+            // Everything opened with --wait is closed: hand control back to the caller
+            self.save_state();
+            event_loop.exit();
+            return;
+        }
+        self.update_control_flow(event_loop);
+    }
+    
+    fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
+        if let Some(instance) = &self.instance {
+            instance.stop();
+        }
+        self.end_session();
     }
     
     fn window_event(&mut self, event_loop: &ActiveEventLoop, _id: WindowId, event: WindowEvent) {
+        if let WindowEvent::RedrawRequested = event {
+            self.render();
+            return;
+        }
+        
+        let span = Span::begin("events");
+        self.handle_window_event(event_loop, event);
+        self.profiler.record(span);
+    }
+}
+
+impl App {
+    /// Dispatch one window event; `window_event` times this as the "events" phase
+    fn handle_window_event(&mut self, event_loop: &ActiveEventLoop, event: WindowEvent) {
         match event {
             WindowEvent::CloseRequested => {
                 // Save state before closing
                 self.save_state();
                 event_loop.exit();
             }
-            WindowEvent::RedrawRequested => {
-                self.render();
+            WindowEvent::Focused(true) => {
+                // Branches may have changed in a terminal while the window was in the background
+                self.refresh_git_status();
+            }
+            WindowEvent::Focused(false) => {
+                self.keybindings.clear_pending();
+                if let Some(ref mut editor) = self.editor {
+                    editor.window_focus_lost();
+                }
             }
             WindowEvent::Resized(size) => {
                 if size.width > 0 && size.height > 0 {
@@ -1023,6 +6057,7 @@ impl ApplicationHandler for App {
                     if let Some(ref mut titlebar) = self.titlebar {
                         titlebar.update_size(size.width as f32);
                     }
+                    self.sync_menubar_collapse();
                     
                     // Request redraw
                     if let Some(window) = &self.window {
@@ -1032,6 +6067,11 @@ impl ApplicationHandler for App {
             }
             WindowEvent::CursorMoved { position, .. } => {
                 self.mouse_pos = (position.x as f32, position.y as f32);
+                if self.inspector.is_enabled() {
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                }
                 
                 // Check if menu is open - if so, only update menu hover
                 let menu_is_open = self.menubar.as_ref().map_or(false, |m| m.is_menu_open());
@@ -1039,6 +6079,30 @@ impl ApplicationHandler for App {
                 // Check if command palette is open
                 let command_palette_open = self.command_palette.as_ref().map_or(false, |cp| cp.is_visible());
                 
+                if let Some(alert_dialog) = self.alert_dialog.as_mut().filter(|d| d.is_visible()) {
+                    alert_dialog.update_hover(self.mouse_pos.0, self.mouse_pos.1);
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                
+                // An open context menu keeps the hover to itself
+                if self.explorer_menu.is_visible() {
+                    self.explorer_menu.update_hover(self.mouse_pos.0, self.mouse_pos.1);
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                if self.tab_menu.is_visible() {
+                    self.tab_menu.update_hover(self.mouse_pos.0, self.mouse_pos.1);
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                
                 if let Some(ref mut titlebar) = self.titlebar {
                     titlebar.update_hover(self.mouse_pos.0, self.mouse_pos.1);
                 }
@@ -1058,17 +6122,34 @@ impl ApplicationHandler for App {
                         activitybar.update_hover(self.mouse_pos.0, self.mouse_pos.1);
                     }
                     
-                    if let Some(ref mut editor) = self.editor {
-                        editor.update_hover(self.mouse_pos.0, self.mouse_pos.1);
+                    if let Some(ref mut status_bar) = self.status_bar {
+                        status_bar.update_hover(self.mouse_pos.0, self.mouse_pos.1);
+                    }
+                    
+                    if let Some(theme_editor) = self.theme_editor.as_mut().filter(|t| t.is_visible()) {
+                        if theme_editor.handle_mouse_move(self.mouse_pos.0, self.mouse_pos.1) {
+                            let (name, colors) = (theme_editor.name().to_string(), theme_editor.colors());
+                            self.preview_theme(&name, colors);
+                        }
+                    } else if let Some(keyboard_shortcuts) = self.keyboard_shortcuts.as_mut().filter(|page| page.is_visible()) {
+                        keyboard_shortcuts.update_hover(self.mouse_pos.0, self.mouse_pos.1);
+                        window.request_redraw();
+                    } else if let Some(extensions_page) = self.extensions_page.as_mut().filter(|page| page.is_visible()) {
+                        extensions_page.update_hover(self.mouse_pos.0, self.mouse_pos.1);
+                        window.request_redraw();
+                    } else if let Some(ref mut editor) = self.editor {
+                        let mono_font = self.font_manager.create_mono_font(14.0, 400);
+                        editor.update_hover(self.mouse_pos.0, self.mouse_pos.1, &mono_font);
                         
                         // Handle mouse drag for text selection
-                        let mono_font = self.font_manager.create_font("", 14.0, 400);
                         editor.handle_mouse_drag(self.mouse_pos.0, self.mouse_pos.1, &mono_font);
                         
                         // Change cursor to text cursor when over editor content
                         if let Some(window) = &self.window {
                             use winit::window::CursorIcon;
-                            if editor.is_over_editor_content(self.mouse_pos.0, self.mouse_pos.1) {
+                            if editor.is_dragging_text() {
+                                window.set_cursor(if self.modifiers.control_key() { CursorIcon::Copy } else { CursorIcon::Grabbing });
+                            } else if editor.is_over_editor_content(self.mouse_pos.0, self.mouse_pos.1) {
                                 window.set_cursor(CursorIcon::Text);
                             } else {
                                 window.set_cursor(CursorIcon::Default);
@@ -1076,64 +6157,34 @@ impl ApplicationHandler for App {
                         }
                     }
                     
-                    // Update panel hover states and handle resizing
+                    // Update panel hover states
                     if let Some(ref mut left_panel) = self.left_panel {
-                        if left_panel.is_resizing() {
-                            left_panel.resize_to(self.mouse_pos.0);
-                            self.layout_config.left_panel_width = left_panel.width();
-                            // Rebuild UI to update layout
-                            if let Some(window) = &self.window {
-                                let size = window.inner_size();
-                                self.build_ui(size.width as f32, size.height as f32);
-                            }
-                        } else if left_panel.is_scrollbar_dragging() {
+                        if left_panel.is_scrollbar_dragging() {
                             // Handle scrollbar drag
-                            left_panel.handle_mouse_drag(self.mouse_pos.1);
+                            left_panel.handle_mouse_drag(self.mouse_pos.0, self.mouse_pos.1);
                         } else {
                             left_panel.update_hover(self.mouse_pos.0, self.mouse_pos.1);
+                            left_panel.drag_rows_to(self.mouse_pos.0, self.mouse_pos.1);
                         }
                     }
-                } else {
-                    // Menu is open - clear hover states on other elements
-                    if let Some(ref mut left_panel) = self.left_panel {
-                        // Still handle resizing even when menu is open
-                        if left_panel.is_resizing() {
-                            left_panel.resize_to(self.mouse_pos.0);
-                            self.layout_config.left_panel_width = left_panel.width();
-                            if let Some(window) = &self.window {
-                                let size = window.inner_size();
-                                self.build_ui(size.width as f32, size.height as f32);
-                            }
-                        }
+                    
+                    // Inputs and the terminal show where dragged editor text would land
+                    if self.editor.as_ref().is_some_and(|editor| editor.is_dragging_text()) {
+                        self.preview_text_drop(Some(self.mouse_pos));
                     }
                 }
                 
                 if let Some(ref mut right_panel) = self.right_panel {
-                    if right_panel.is_resizing() {
-                        if let Some(window) = &self.window {
-                            let size = window.inner_size();
-                            right_panel.resize_to(self.mouse_pos.0, size.width as f32);
-                            self.layout_config.right_panel_width = right_panel.width();
-                            self.build_ui(size.width as f32, size.height as f32);
-                        }
-                    } else {
-                        right_panel.update_hover(self.mouse_pos.0, self.mouse_pos.1);
-                    }
+                    right_panel.update_hover(self.mouse_pos.0, self.mouse_pos.1);
                 }
                 
                 if let Some(ref mut bottom_panel) = self.bottom_panel {
-                    if bottom_panel.is_resizing() {
-                        if let Some(window) = &self.window {
-                            let size = window.inner_size();
-                            bottom_panel.resize_to(self.mouse_pos.1, size.height as f32);
-                            self.layout_config.bottom_panel_height = bottom_panel.height();
-                            self.build_ui(size.width as f32, size.height as f32);
-                        }
-                    } else {
-                        bottom_panel.update_hover(self.mouse_pos.0, self.mouse_pos.1);
-                    }
+                    bottom_panel.update_hover(self.mouse_pos.0, self.mouse_pos.1);
                 }
                 
+                // Panel splitters keep resizing even when a menu is open
+                self.update_splitters(self.mouse_pos.0, self.mouse_pos.1);
+                
                 for widget in &mut self.widgets {
                     widget.update_hover(self.mouse_pos.0, self.mouse_pos.1);
                 }
@@ -1163,15 +6214,51 @@ impl ApplicationHandler for App {
                 button: MouseButton::Left,
                 ..
             } => {
+                // While inspecting, a click logs the widget under the cursor instead of pressing it
+                if self.inspector.is_enabled() {
+                    if let Some(description) = self.inspector.describe_hovered() {
+                        self.log_output(&description);
+                        self.toast.show(t!("toast.inspector_logged"), Some(CodiconIcons::INSPECT));
+                    }
+                    return;
+                }
+                
+                // A confirmation dialog blocks everything behind it
+                if self.alert_dialog.as_ref().is_some_and(|d| d.is_visible()) {
+                    let action = self.alert_dialog.as_mut().and_then(|d| d.handle_click(self.mouse_pos.0, self.mouse_pos.1));
+                    self.handle_alert_dialog_action(action);
+                    return;
+                }
+                
+                // Any click closes a context menu, running the entry under the cursor
+                if self.explorer_menu.is_visible() {
+                    match self.explorer_menu.handle_click() {
+                        Some(id) => self.handle_explorer_menu_action(id),
+                        None => self.explorer_menu.hide(),
+                    }
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                if self.tab_menu.is_visible() {
+                    match self.tab_menu.handle_click() {
+                        Some(id) => self.handle_tab_menu_action(id),
+                        None => self.tab_menu.hide(),
+                    }
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                
                 // Check titlebar controls first
                 if let Some(ref mut titlebar) = self.titlebar {
                     // Check search bar click (entire search bar opens command palette)
                     if titlebar.is_search_bar_clicked(self.mouse_pos.0, self.mouse_pos.1) {
-                        if let Some(ref mut command_palette) = self.command_palette {
-                            command_palette.show();
-                            if let Some(window) = &self.window {
-                                window.request_redraw();
-                            }
+                        self.show_palette(PaletteMode::Files);
+                        if let Some(window) = &self.window {
+                            window.request_redraw();
                         }
                         return;
                     }
@@ -1229,14 +6316,38 @@ impl ApplicationHandler for App {
                     }
                 }
                 
+                // Clicking outside the Find Bytes input closes it
+                if let Some(find_bytes) = self.find_bytes.as_mut().filter(|f| f.is_visible()) {
+                    if !find_bytes.contains(self.mouse_pos.0, self.mouse_pos.1) {
+                        find_bytes.hide();
+                        if let Some(window) = &self.window {
+                            window.request_redraw();
+                        }
+                    }
+                    return;
+                }
+                
+                // Clicking outside the Go to Line input closes it
+                if let Some(go_to_line) = self.go_to_line.as_mut().filter(|g| g.is_visible()) {
+                    if !go_to_line.contains(self.mouse_pos.0, self.mouse_pos.1) {
+                        go_to_line.hide();
+                        if let Some(window) = &self.window {
+                            window.request_redraw();
+                        }
+                    }
+                    return;
+                }
+                
                 // Check command palette first (if visible, it's on top)
                 if let Some(ref mut command_palette) = self.command_palette {
                     if command_palette.is_visible() {
                         if command_palette.contains(self.mouse_pos.0, self.mouse_pos.1) {
                             command_palette.on_click();
-                            if let Some(command_id) = command_palette.get_selected_command() {
+                            if let Some(command_id) = command_palette.accept_selected() {
+                                self.record_palette_use();
                                 self.handle_menu_action(command_id as i32);
                             }
+                            self.fill_palette_mode();
                             if let Some(window) = &self.window {
                                 window.request_redraw();
                             }
@@ -1252,6 +6363,24 @@ impl ApplicationHandler for App {
                     }
                 }
                 
+                // Check status bar encoding / line ending indicators
+                let status_item = self.status_bar.as_ref()
+                    .and_then(|status_bar| status_bar.item_at(self.mouse_pos.0, self.mouse_pos.1));
+                if let Some(item) = status_item {
+                    match item {
+                        StatusBarItem::Branch => self.show_branch_picker(),
+                        StatusBarItem::Language => self.show_language_mode_picker(),
+                        StatusBarItem::Character => self.show_unicode_picker(),
+                        StatusBarItem::Indentation => self.show_indentation_picker(),
+                        StatusBarItem::Encoding => self.show_encoding_picker(),
+                        StatusBarItem::LineEnding => self.show_line_ending_picker(),
+                    }
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                
                 // Check menubar
                 let (menubar_clicked, clicked_item_id) = {
                     let mut clicked = false;
@@ -1282,72 +6411,163 @@ impl ApplicationHandler for App {
                 }
                 
                 // Check activity bar
-                if let Some(ref mut activitybar) = self.activitybar {
-                    if activitybar.contains(self.mouse_pos.0, self.mouse_pos.1) {
-                        activitybar.on_click();
-                        if let Some(window) = &self.window {
-                            window.request_redraw();
+                let (x, y) = self.mouse_pos;
+                if let Some(activitybar) = self.activitybar.as_mut().filter(|bar| bar.contains(x, y)) {
+                    activitybar.on_click();
+                    // The explorer and search items switch the left panel's page; extensions opens its page
+                    match activitybar.get_active_item() {
+                        Some(ActivityBarItem::Explorer) => self.handle_menu_action(62),
+                        Some(ActivityBarItem::Search) => self.handle_menu_action(63),
+                        Some(ActivityBarItem::Extensions) => self.handle_menu_action(66),
+                        _ => {}
+                    }
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                
+                // The Keyboard Shortcuts, Extensions and Theme Editor pages cover the editor area
+                if let Some(keyboard_shortcuts) = self.keyboard_shortcuts.as_mut().filter(|page| page.contains(self.mouse_pos.0, self.mouse_pos.1)) {
+                    let action = keyboard_shortcuts.handle_mouse_down(self.mouse_pos.0, self.mouse_pos.1);
+                    self.handle_keyboard_shortcuts_action(action);
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                if let Some(extensions_page) = self.extensions_page.as_mut().filter(|page| page.contains(self.mouse_pos.0, self.mouse_pos.1)) {
+                    let action = extensions_page.handle_mouse_down(self.mouse_pos.0, self.mouse_pos.1);
+                    self.handle_extensions_action(action);
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                if let Some(theme_editor) = self.theme_editor.as_mut().filter(|t| t.contains(self.mouse_pos.0, self.mouse_pos.1)) {
+                    match theme_editor.handle_mouse_down(self.mouse_pos.0, self.mouse_pos.1) {
+                        Some(ThemeEditorAction::ColorsChanged) => {
+                            let (name, colors) = (theme_editor.name().to_string(), theme_editor.colors());
+                            self.preview_theme(&name, colors);
                         }
-                        return;
+                        Some(ThemeEditorAction::Save) => self.save_edited_theme(false),
+                        Some(ThemeEditorAction::Export) => self.save_edited_theme(true),
+                        None => {}
+                    }
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
                     }
+                    return;
                 }
                 
                 // Check editor tabs
                 if let Some(ref mut editor) = self.editor {
                     // Create a temporary font for click handling
-                    let mono_font = self.font_manager.create_font("", 14.0, 400);
-                    if editor.handle_click(self.mouse_pos.0, self.mouse_pos.1, &mono_font) {
+                    let mono_font = self.font_manager.create_mono_font(14.0, 400);
+                    
+                    // Alt+click starts a column (box) selection
+                    if self.modifiers.alt_key()
+                        && editor.is_over_editor_content(self.mouse_pos.0, self.mouse_pos.1)
+                        && editor.start_column_selection(self.mouse_pos.0, self.mouse_pos.1, &mono_font)
+                    {
                         if let Some(window) = &self.window {
                             window.request_redraw();
                         }
                         return;
                     }
-                }
-                
-                // Check panel resize handles
-                if let Some(ref mut left_panel) = self.left_panel {
-                    if left_panel.is_over_resize_handle(self.mouse_pos.0, self.mouse_pos.1) {
-                        left_panel.start_resize();
-                        self.update_control_flow(event_loop);
+                    
+                    if editor.handle_click(self.mouse_pos.0, self.mouse_pos.1, &mono_font) {
                         if let Some(window) = &self.window {
                             window.request_redraw();
                         }
                         return;
                     }
-                    
+                }
+                
+                // Panel splitters sit on the panel edges, so they take the press first
+                if self.press_splitters(self.mouse_pos.0, self.mouse_pos.1) {
+                    self.update_control_flow(event_loop);
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                
+                // The search page handles its own presses
+                let search_press = self.left_panel.as_mut()
+                    .filter(|panel| panel.is_search_visible() && panel.contains(x, y))
+                    .map(|panel| panel.search_mut().handle_mouse_down(x, y));
+                if let Some(action) = search_press {
+                    if let Some(action) = action {
+                        self.handle_search_action(action);
+                    }
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                
+                // TODOs rows fold their file or jump to their comment
+                let todos_press = self.left_panel.as_mut()
+                    .filter(|panel| panel.is_todos_visible() && panel.contains(x, y))
+                    .map(|panel| panel.todos_mut().handle_mouse_down(x, y));
+                if let Some(target) = todos_press {
+                    if let Some((path, line, column)) = target {
+                        self.open_at(path, line, column);
+                    }
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                
+                // Bookmark rows jump to their line, or delete the bookmark from their button
+                let bookmarks_press = self.left_panel.as_mut()
+                    .filter(|panel| panel.is_bookmarks_visible() && panel.contains(x, y))
+                    .map(|panel| panel.bookmarks_mut().handle_mouse_down(x, y));
+                if let Some(action) = bookmarks_press {
+                    match action {
+                        Some(BookmarksAction::Open(path, line)) => self.open_at(path, line, 0),
+                        Some(BookmarksAction::Remove(bookmark)) => self.remove_bookmark(&bookmark),
+                        None => {}
+                    }
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                
+                // Outline rows jump to their symbol
+                let outline_press = self.right_panel.as_mut()
+                    .filter(|panel| panel.contains(x, y))
+                    .map(|panel| panel.handle_mouse_down(x, y));
+                if let Some(target) = outline_press {
+                    if let (Some((line, column)), Some(editor)) = (target, self.editor.as_mut()) {
+                        editor.go_to_position(line, column);
+                    }
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                
+                if let Some(ref mut left_panel) = self.left_panel {
+                    // Clicking anywhere else gives the keyboard back to the editor
+                    if !left_panel.contains(self.mouse_pos.0, self.mouse_pos.1) {
+                        left_panel.blur();
+                    }
                     // Check if click is inside left panel (but not on resize handle)
                     if left_panel.contains(self.mouse_pos.0, self.mouse_pos.1) {
                         // Handle scrollbar or regular click
-                        left_panel.handle_mouse_press(self.mouse_pos.0, self.mouse_pos.1);
-                        if !left_panel.is_scrollbar_dragging() {
+                        // Ctrl+click and Shift+click pick more rows instead of opening them
+                        let toggle = self.modifiers.control_key() || self.modifiers.super_key();
+                        let range = self.modifiers.shift_key();
+                        let handled = left_panel.handle_mouse_press(self.mouse_pos.0, self.mouse_pos.1)
+                            || ((toggle || range) && left_panel.extend_selection(range));
+                        if !handled {
+                            // Files open on release, so the press can start a drag
                             left_panel.on_click();
-                            
-                            // Check if a file was clicked and open it
-                            if let Some(file_path) = left_panel.take_clicked_file() {
-                                println!("Opening file: {}", file_path.display());
-                                if let Some(ref mut editor) = self.editor {
-                                    match editor.open_file(file_path.clone()) {
-                                        Ok(_) => {
-                                            println!("File opened successfully");
-                                        }
-                                        Err(e) => {
-                                            eprintln!("Failed to open file: {}", e);
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                        if let Some(window) = &self.window {
-                            window.request_redraw();
                         }
-                        return;
-                    }
-                }
-                
-                if let Some(ref mut right_panel) = self.right_panel {
-                    if right_panel.is_over_resize_handle(self.mouse_pos.0, self.mouse_pos.1) {
-                        right_panel.start_resize();
-                        self.update_control_flow(event_loop);
                         if let Some(window) = &self.window {
                             window.request_redraw();
                         }
@@ -1356,9 +6576,7 @@ impl ApplicationHandler for App {
                 }
                 
                 if let Some(ref mut bottom_panel) = self.bottom_panel {
-                    if bottom_panel.is_over_resize_handle(self.mouse_pos.0, self.mouse_pos.1) {
-                        bottom_panel.start_resize();
-                        self.update_control_flow(event_loop);
+                    if bottom_panel.handle_mouse_press(self.mouse_pos.0, self.mouse_pos.1) {
                         if let Some(window) = &self.window {
                             window.request_redraw();
                         }
@@ -1381,10 +6599,8 @@ impl ApplicationHandler for App {
                 // Handle button clicks
                 self.handle_button_click(self.mouse_pos.0, self.mouse_pos.1);
                 
-                for widget in &mut self.widgets {
-                    if widget.contains(self.mouse_pos.0, self.mouse_pos.1) {
-                        widget.on_click();
-                    }
+                if let Some(index) = widget_at(&self.widgets, self.mouse_pos.0, self.mouse_pos.1) {
+                    self.widgets[index].on_click();
                 }
                 
                 if let Some(window) = &self.window {
@@ -1400,21 +6616,41 @@ impl ApplicationHandler for App {
                 self.drag_start_pos = None;
                 
                 // Stop panel resizing
-                if let Some(ref mut left_panel) = self.left_panel {
-                    left_panel.stop_resize();
-                    left_panel.handle_mouse_release();
+                self.left_splitter.handle_mouse_up();
+                self.right_splitter.handle_mouse_up();
+                self.bottom_splitter.handle_mouse_up();
+                // Rows are opened, or moved if they were dragged onto a folder, when the button comes up
+                let (dropped, clicked_file) = match self.left_panel.as_mut() {
+                    Some(left_panel) => (left_panel.handle_mouse_release(), left_panel.take_clicked_file()),
+                    None => (None, None),
+                };
+                if let Some((paths, folder)) = dropped {
+                    self.move_into_folder(&paths, &folder);
                 }
-                if let Some(ref mut right_panel) = self.right_panel {
-                    right_panel.stop_resize();
+                if let Some(file_path) = clicked_file {
+                    self.open_explorer_file(file_path);
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
                 }
                 if let Some(ref mut bottom_panel) = self.bottom_panel {
-                    bottom_panel.stop_resize();
+                    bottom_panel.handle_mouse_release();
+                }
+                
+                // Dragged text that didn't land in the editor goes to the terminal or input under the mouse
+                let copy = self.modifiers.control_key();
+                if let Some(text) = self.editor.as_mut().and_then(|editor| editor.finish_text_drag(copy)) {
+                    self.drop_text(&text);
                 }
+                self.preview_text_drop(None);
                 
                 // Stop text selection
                 if let Some(ref mut editor) = self.editor {
                     editor.handle_mouse_release();
                 }
+                if let Some(ref mut theme_editor) = self.theme_editor {
+                    theme_editor.handle_mouse_up();
+                }
                 
                 // Update control flow - switch back to Wait if nothing is active
                 self.update_control_flow(event_loop);
@@ -1424,6 +6660,33 @@ impl ApplicationHandler for App {
                 button: MouseButton::Right,
                 ..
             } => {
+                if self.alert_dialog.as_ref().is_some_and(|d| d.is_visible()) {
+                    return;
+                }
+                
+                // Right-clicking a tab opens its context menu
+                if let Some(index) = self.editor.as_ref().and_then(|editor| editor.tab_at(self.mouse_pos.0, self.mouse_pos.1)) {
+                    self.show_tab_menu(index);
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                self.tab_menu.hide();
+                
+                // Right-clicking explorer rows opens the menu for the selection
+                let on_row = self.left_panel.as_mut()
+                    .filter(|panel| panel.contains(self.mouse_pos.0, self.mouse_pos.1))
+                    .is_some_and(|panel| panel.context_select());
+                if on_row {
+                    self.show_explorer_menu();
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                self.explorer_menu.hide();
+                
                 if let Some(ref titlebar) = self.titlebar {
                     if titlebar.is_draggable_area(self.mouse_pos.0, self.mouse_pos.1) {
                         #[cfg(target_os = "windows")]
@@ -1454,8 +6717,76 @@ impl ApplicationHandler for App {
                 if event.state == ElementState::Pressed {
                     let command_palette_visible = self.command_palette.as_ref().map_or(false, |cp| cp.is_visible());
                     
+                    // The confirmation dialog takes every key while it is open
+                    if self.alert_dialog.as_ref().is_some_and(|d| d.is_visible()) {
+                        let key_str = match event.physical_key {
+                            PhysicalKey::Code(KeyCode::Escape) => "Escape",
+                            PhysicalKey::Code(KeyCode::Enter) => "Enter",
+                            _ => "",
+                        };
+                        let action = self.alert_dialog.as_mut().and_then(|d| d.handle_key_input(key_str));
+                        self.handle_alert_dialog_action(action);
+                        return;
+                    }
+                    
+                    // Escape closes a context menu; other keys go on to the editor
+                    if self.tab_menu.is_visible() || self.explorer_menu.is_visible() {
+                        self.tab_menu.hide();
+                        self.explorer_menu.hide();
+                        if let Some(window) = &self.window {
+                            window.request_redraw();
+                        }
+                        if event.physical_key == PhysicalKey::Code(KeyCode::Escape) {
+                            return;
+                        }
+                    }
+                    
                     // Check for Ctrl key combinations
                     if let PhysicalKey::Code(code) = event.physical_key {
+                        let chord = KeyChord::from_key(code, self.modifiers);
+                        
+                        // The Keyboard Shortcuts page records every key while it listens, shortcuts included
+                        if let Some(keyboard_shortcuts) = self.keyboard_shortcuts.as_mut().filter(|page| page.is_visible() && page.is_recording()) {
+                            let action = chord.and_then(|chord| keyboard_shortcuts.handle_chord(chord));
+                            self.handle_keyboard_shortcuts_action(action);
+                            if let Some(window) = &self.window {
+                                window.request_redraw();
+                            }
+                            return;
+                        }
+                        
+                        // Keybindings from keybindings.json and multi-key sequences come before the built-in keys
+                        if let Some(chord) = chord.filter(|_| !command_palette_visible) {
+                            match self.keybindings.press(chord) {
+                                KeyPress::Command(command) => {
+                                    self.handle_menu_action(command as i32);
+                                    return;
+                                }
+                                KeyPress::Pending => {
+                                    let keys = self.keybindings.pending_keys();
+                                    self.toast.show(t!("toast.keybinding_pending", keys = keys), Some(CodiconIcons::RECORD_KEYS));
+                                    if let Some(window) = &self.window {
+                                        window.request_redraw();
+                                    }
+                                    return;
+                                }
+                                KeyPress::Suppressed => return,
+                                KeyPress::Unhandled => {}
+                            }
+                        }
+                        
+                        // Format Document (Shift+Alt+F)
+                        if code == KeyCode::KeyF && self.modifiers.shift_key() && self.modifiers.alt_key() && !command_palette_visible {
+                            self.handle_menu_action(39);
+                            return;
+                        }
+                        
+                        // Rename Symbol (F2)
+                        if code == KeyCode::F2 && !command_palette_visible {
+                            self.handle_menu_action(206);
+                            return;
+                        }
+                        
                         // Handle Ctrl+Key shortcuts
                         if self.modifiers.contains(ModifiersState::CONTROL) {
                             if self.handle_ctrl_shortcut(code) {
@@ -1529,7 +6860,26 @@ impl ApplicationHandler for App {
                 // Check if scrolling over left panel (explorer)
                 if let Some(ref mut left_panel) = self.left_panel {
                     if left_panel.contains(self.mouse_pos.0, self.mouse_pos.1) {
-                        left_panel.explorer_mut().scroll(scroll_delta);
+                        left_panel.scroll(scroll_delta);
+                        if let Some(window) = &self.window {
+                            window.request_redraw();
+                        }
+                        return;
+                    }
+                }
+                
+                // Check if scrolling over the outline
+                if let Some(right_panel) = self.right_panel.as_mut().filter(|panel| panel.contains(self.mouse_pos.0, self.mouse_pos.1)) {
+                    right_panel.scroll(scroll_delta);
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                
+                // Check if scrolling over the bottom panel
+                if let Some(ref mut bottom_panel) = self.bottom_panel {
+                    if bottom_panel.contains(self.mouse_pos.0, self.mouse_pos.1) && bottom_panel.scroll(scroll_delta) {
                         if let Some(window) = &self.window {
                             window.request_redraw();
                         }
@@ -1537,10 +6887,36 @@ impl ApplicationHandler for App {
                     }
                 }
                 
+                if let Some(keyboard_shortcuts) = self.keyboard_shortcuts.as_mut().filter(|page| page.contains(self.mouse_pos.0, self.mouse_pos.1)) {
+                    keyboard_shortcuts.scroll(scroll_delta);
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                if let Some(extensions_page) = self.extensions_page.as_mut().filter(|page| page.contains(self.mouse_pos.0, self.mouse_pos.1)) {
+                    extensions_page.scroll(scroll_delta);
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                
+                if let Some(theme_editor) = self.theme_editor.as_mut().filter(|t| t.contains(self.mouse_pos.0, self.mouse_pos.1)) {
+                    theme_editor.scroll(scroll_delta);
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+                
                 // Check if scrolling over editor
                 if let Some(ref mut editor) = self.editor {
                     if editor.contains(self.mouse_pos.0, self.mouse_pos.1) {
-                        editor.scroll(scroll_delta);
+                        let (x, y) = self.mouse_pos;
+                        if !editor.scroll_hover(x, y, scroll_delta) && !editor.scroll_peek(x, y, scroll_delta) {
+                            editor.scroll(scroll_delta);
+                        }
                         if let Some(window) = &self.window {
                             window.request_redraw();
                         }
@@ -1564,9 +6940,37 @@ impl ApplicationHandler for App {
 }
 
 fn main() {
+    crash::install_panic_hook();
+    
+    let launch = match CliArgs::from_env() {
+        Ok(launch) if launch.help => {
+            println!("{}", cli::USAGE);
+            return;
+        }
+        Ok(launch) => launch,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(2);
+        }
+    };
+    
+    // Hand the files to the running window when asked to; a deep link always asks, unless a profile is named
+    if launch.reuse_window && !launch.wait && launch.diff.is_none() && launch.profile.is_none() && !launch.open.is_empty() {
+        let links: Vec<String> = launch.open.iter()
+            .map(|target| OpenTarget { path: cli::absolute(&target.path), ..target.clone() }.to_url())
+            .collect();
+        if instance::forward(&links) {
+            return;
+        }
+    }
+    
     let event_loop = EventLoop::new().unwrap();
     event_loop.set_control_flow(ControlFlow::Wait);
+    let ui = messages::channel(event_loop.create_proxy());
+    let instance = InstanceServer::start(ui.0.clone())
+        .map_err(|e| eprintln!("Failed to listen for forwarded files: {}", e))
+        .ok();
     
-    let mut app = App::new();
+    let mut app = App::new(launch, instance, ui);
     event_loop.run_app(&mut app).unwrap();
 }
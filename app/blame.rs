@@ -0,0 +1,59 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use mikocore::BlameLine;
+use mikoui::t;
+
+use crate::messages::{UiMessage, UiSender};
+
+/// `git blame` of files, fetched off the UI thread the first time a line is asked for
+///
+/// An entry is kept for as long as its file's modification time stays the same, so saving
+/// a file blames it again.
+#[derive(Default)]
+pub struct BlameCache {
+    files: HashMap<PathBuf, (Option<SystemTime>, Vec<BlameLine>)>,
+    pending: HashSet<PathBuf>,
+}
+
+impl BlameCache {
+    /// Who last changed a line of `path`; None while git is asked, or when it doesn't track the file
+    pub fn line(&mut self, path: &Path, line: usize, ui: &UiSender) -> Option<&BlameLine> {
+        let modified = modified(path);
+        let fresh = self.files.get(path).is_some_and(|(at, _)| *at == modified);
+        if !fresh && self.pending.insert(path.to_path_buf()) {
+            let (ui, path) = (ui.clone(), path.to_path_buf());
+            std::thread::spawn(move || {
+                // Files outside a repository have no blame, which is worth remembering too
+                let lines = mikocore::blame(&path).unwrap_or_default();
+                ui.send(UiMessage::Blame { path, modified, lines });
+            });
+        }
+        self.files.get(path).filter(|(at, _)| *at == modified)?.1.get(line)
+    }
+
+    /// Keep what a fetch started by `line` found
+    pub fn insert(&mut self, path: PathBuf, modified: Option<SystemTime>, lines: Vec<BlameLine>) {
+        self.pending.remove(&path);
+        self.files.insert(path, (modified, lines));
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+/// How long ago a time in seconds since the Unix epoch was, e.g. "3 days ago"
+pub fn relative_age(time: i64) -> String {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs() as i64);
+    const DAY: i64 = 86_400;
+    match (now - time).max(0) {
+        seconds if seconds < 60 => t!("age.now"),
+        seconds if seconds < 3600 => t!("age.minutes", count = seconds / 60),
+        seconds if seconds < DAY => t!("age.hours", count = seconds / 3600),
+        seconds if seconds < 30 * DAY => t!("age.days", count = seconds / DAY),
+        seconds if seconds < 365 * DAY => t!("age.months", count = seconds / (30 * DAY)),
+        seconds => t!("age.years", count = seconds / (365 * DAY)),
+    }
+}
@@ -0,0 +1,224 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// Scheme of deep links, e.g. `rabital://open?file=src/main.rs&line=42`
+pub const URL_SCHEME: &str = "rabital";
+
+/// A file or folder to open, optionally at a 1-based line and column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenTarget {
+    pub path: PathBuf,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl OpenTarget {
+    /// Parse `path`, `path:line` or `path:line:column`
+    /// A path that exists as written wins over a `:line` suffix, so names with colons still open.
+    fn parse(arg: OsString) -> Self {
+        let path = PathBuf::from(&arg);
+        let Some(text) = arg.to_str().filter(|_| !path.exists()) else {
+            return Self { path, line: None, column: None };
+        };
+        // Peel off up to two numbers from the end: the last one found is the line
+        let mut rest = text;
+        let mut numbers = Vec::new();
+        while numbers.len() < 2 {
+            let Some((head, number)) = rest.rsplit_once(':').filter(|(head, _)| !head.is_empty()) else {
+                break;
+            };
+            let Ok(number) = number.parse::<usize>() else {
+                break;
+            };
+            numbers.push(number);
+            rest = head;
+        }
+        let (line, column) = match numbers[..] {
+            [column, line] => (Some(line), Some(column)),
+            [line] => (Some(line), None),
+            _ => (None, None),
+        };
+        Self { path: PathBuf::from(rest), line, column }
+    }
+    
+    /// Parse a deep link: `rabital://open?file=<path>&line=<n>&column=<n>` or `rabital://open?folder=<path>`
+    /// Values are percent-encoded.
+    pub fn from_url(url: &str) -> Result<Self, String> {
+        let invalid = || format!("Invalid link {}", url);
+        let rest = url
+            .split_once("://")
+            .filter(|(scheme, _)| scheme.eq_ignore_ascii_case(URL_SCHEME))
+            .map(|(_, rest)| rest)
+            .ok_or_else(invalid)?;
+        let (action, query) = rest.split_once('?').unwrap_or((rest, ""));
+        if action.trim_end_matches('/') != "open" {
+            return Err(format!("Unknown link action {} in {}", action, url));
+        }
+        let mut target = Self { path: PathBuf::new(), line: None, column: None };
+        for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            let value = percent_decode(value).ok_or_else(invalid)?;
+            match key {
+                "file" | "folder" => target.path = PathBuf::from(value),
+                "line" => target.line = Some(value.parse().map_err(|_| invalid())?),
+                "column" => target.column = Some(value.parse().map_err(|_| invalid())?),
+                _ => {}
+            }
+        }
+        if target.path.as_os_str().is_empty() {
+            return Err(format!("Link {} names no file or folder", url));
+        }
+        Ok(target)
+    }
+    
+    /// The deep link opening this target; `path` should be absolute
+    pub fn to_url(&self) -> String {
+        let mut url = format!("{}://open?file={}", URL_SCHEME, percent_encode(&self.path.to_string_lossy()));
+        if let Some(line) = self.line {
+            url.push_str(&format!("&line={}", line));
+        }
+        if let Some(column) = self.column {
+            url.push_str(&format!("&column={}", column));
+        }
+        url
+    }
+}
+
+fn percent_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~/\\:".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// None for a malformed escape or text that isn't UTF-8 once decoded
+fn percent_decode(text: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            // from_str_radix alone would take a sign, as in "%+1"
+            let hex = std::str::from_utf8(tail.get(..2)?).ok().filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// Command-line arguments of the app
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CliArgs {
+    pub open: Vec<OpenTarget>,
+    /// `--diff <old> <new>`
+    pub diff: Option<(PathBuf, PathBuf)>,
+    /// `--wait`: keep running until the opened files are closed, e.g. as git's editor
+    pub wait: bool,
+    /// `--reuse-window` or a deep link: hand the files to the running instance if there is one
+    pub reuse_window: bool,
+    /// `--profile <name>`: use this profile instead of the last session's
+    pub profile: Option<String>,
+    pub help: bool,
+}
+
+pub const USAGE: &str = "\
+Usage: rabital [options] [paths...]
+
+  <folder>                 Open a folder as the workspace
+  <file>[:line[:column]]   Open a file, optionally at a position
+  rabital://open?file=<path>&line=<n>&column=<n>
+                           Open a file or folder (folder=<path>) from a link
+  --diff <old> <new>       Compare two files
+  -r, --reuse-window       Open in the running window instead of a new one
+  --profile <name>         Use a profile's settings, keybindings and extensions
+  --wait                   Wait for the opened files to be closed before exiting
+  -h, --help               Show this help";
+
+impl CliArgs {
+    /// Parse the arguments of this process
+    pub fn from_env() -> Result<Self, String> {
+        Self::parse(std::env::args_os().skip(1))
+    }
+    
+    pub fn parse(args: impl IntoIterator<Item = OsString>) -> Result<Self, String> {
+        let mut cli = Self::default();
+        let mut args = args.into_iter();
+        let mut options_done = false;
+        while let Some(arg) = args.next() {
+            match arg.to_str().filter(|_| !options_done) {
+                Some("--") => options_done = true,
+                Some("--wait" | "-w") => cli.wait = true,
+                Some("--reuse-window" | "-r") => cli.reuse_window = true,
+                Some("--diff" | "-d") => {
+                    let (Some(old), Some(new)) = (args.next(), args.next()) else {
+                        return Err("--diff needs two files".to_string());
+                    };
+                    cli.diff = Some((PathBuf::from(old), PathBuf::from(new)));
+                }
+                Some("--profile") => {
+                    let Some(name) = args.next().and_then(|name| name.into_string().ok()) else {
+                        return Err("--profile needs a name".to_string());
+                    };
+                    cli.profile = Some(name);
+                }
+                Some("--help" | "-h") => cli.help = true,
+                Some(option) if option.starts_with('-') && option.len() > 1 => {
+                    return Err(format!("Unknown option {}\n\n{}", option, USAGE));
+                }
+                Some(link) if link.to_ascii_lowercase().starts_with(&format!("{}://", URL_SCHEME)) => {
+                    cli.open.push(OpenTarget::from_url(link)?);
+                    cli.reuse_window = true;
+                }
+                _ => cli.open.push(OpenTarget::parse(arg)),
+            }
+        }
+        Ok(cli)
+    }
+}
+
+/// `path` made absolute, so it still names the same file when the workspace changes
+pub fn absolute(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| std::env::current_dir().map_or_else(|_| path.to_path_buf(), |dir| dir.join(path)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deep_links_name_a_path_and_decode_their_values() {
+        let target = OpenTarget::from_url("rabital://open?file=C%3A%5Cdocs%5Cna%C3%AFve%20notes.md&line=42&column=7").unwrap();
+        assert_eq!(target.path, PathBuf::from("C:\\docs\\na\u{ef}ve notes.md"));
+        assert_eq!((target.line, target.column), (Some(42), Some(7)));
+        let folder = OpenTarget::from_url("RABITAL://open/?folder=/srv/site&unknown=1").unwrap();
+        assert_eq!((folder.path, folder.line), (PathBuf::from("/srv/site"), None));
+        assert_eq!(OpenTarget::from_url(&target.to_url()), Ok(target));
+
+        // No query, or a position without anything to open
+        assert!(OpenTarget::from_url("rabital://open").unwrap_err().contains("names no file"));
+        assert!(OpenTarget::from_url("rabital://open?line=3").unwrap_err().contains("names no file"));
+        assert!(OpenTarget::from_url("rabital://open?file=a.rs&line=x").is_err());
+        assert!(OpenTarget::from_url("rabital://close?file=a.rs").unwrap_err().starts_with("Unknown link action"));
+        assert!(OpenTarget::from_url("https://open?file=a.rs").is_err());
+    }
+
+    #[test]
+    fn percent_decoding_rejects_broken_escapes_and_invalid_utf8() {
+        assert_eq!(percent_decode("a%20b%2Fc%2f").as_deref(), Some("a b/c/"));
+        assert_eq!(percent_decode("%E2%86%92").as_deref(), Some("\u{2192}"));
+        assert_eq!(percent_decode("100%"), None);
+        assert_eq!(percent_decode("%4"), None);
+        assert_eq!(percent_decode("%zz"), None);
+        assert_eq!(percent_decode("%+1"), None);
+        assert_eq!(percent_decode("%FF%FE"), None);
+        assert_eq!(percent_decode("%C3"), None);
+        assert!(OpenTarget::from_url("rabital://open?file=%FF.rs").unwrap_err().starts_with("Invalid link"));
+    }
+}
@@ -1,9 +1,10 @@
-use mikoui::{CodiconIcons, Icon, IconSize, Widget, FontManager};
+use mikoui::{animation_rate, surface, CodiconIcons, Icon, IconSize, Widget, FontManager};
 use skia_safe::{Canvas, Color, Paint, Rect};
 
 const ACTIVITY_BAR_WIDTH: f32 = 48.0;
 const ICON_SIZE: f32 = 24.0;
 const ITEM_HEIGHT: f32 = 48.0;
+const BADGE_HEIGHT: f32 = 16.0;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ActivityBarItem {
@@ -37,6 +38,7 @@ pub struct ActivityBar {
     active_item: Option<usize>,
     hover_item: Option<usize>,
     hover_progress: Vec<f32>,
+    badges: Vec<usize>, // Count shown over each item's icon, 0 for none
 }
 
 impl ActivityBar {
@@ -50,6 +52,7 @@ impl ActivityBar {
         ];
         
         let hover_progress = vec![0.0; items.len()];
+        let badges = vec![0; items.len()];
         
         Self {
             x,
@@ -60,6 +63,7 @@ impl ActivityBar {
             active_item: Some(0), // Explorer active by default
             hover_item: None,
             hover_progress,
+            badges,
         }
     }
     
@@ -67,6 +71,11 @@ impl ActivityBar {
         self.width
     }
     
+    /// Move the bar horizontally, e.g. to the right edge in RTL layouts
+    pub fn set_x(&mut self, x: f32) {
+        self.x = x;
+    }
+    
     fn item_rect(&self, index: usize) -> Rect {
         let y = self.y + (index as f32 * ITEM_HEIGHT);
         Rect::from_xywh(self.x, y, self.width, ITEM_HEIGHT)
@@ -75,16 +84,51 @@ impl ActivityBar {
     pub fn get_active_item(&self) -> Option<ActivityBarItem> {
         self.active_item.and_then(|i| self.items.get(i).copied())
     }
+    
+    /// Mark an item active, e.g. when its page is opened with a shortcut
+    pub fn set_active_item(&mut self, item: ActivityBarItem) {
+        self.active_item = self.items.iter().position(|i| *i == item);
+    }
+    
+    /// Show a count over an item's icon, e.g. pending updates; 0 hides it
+    pub fn set_badge(&mut self, item: ActivityBarItem, count: usize) {
+        if let Some(i) = self.items.iter().position(|i| *i == item) {
+            self.badges[i] = count;
+        }
+    }
+    
+    fn draw_badge(&self, canvas: &Canvas, font_manager: &mut FontManager, item_rect: Rect, count: usize) {
+        let theme = mikoui::current_theme();
+        let text = if count > 99 { "99+".to_string() } else { count.to_string() };
+        let font = font_manager.create_font(&text, 9.0, 600);
+        let text_width = font.measure_str(&text, None).0;
+        let width = (text_width + 8.0).max(BADGE_HEIGHT);
+        
+        // Over the top right corner of the icon
+        let right = item_rect.left + (self.width + ICON_SIZE) / 2.0 + 4.0;
+        let top = item_rect.top + (ITEM_HEIGHT - ICON_SIZE) / 2.0 + ICON_SIZE - BADGE_HEIGHT + 4.0;
+        let badge_rect = Rect::from_xywh(right - width, top, width, BADGE_HEIGHT);
+        
+        let mut bg_paint = Paint::default();
+        bg_paint.set_anti_alias(true);
+        bg_paint.set_color(theme.primary);
+        canvas.draw_round_rect(badge_rect, BADGE_HEIGHT / 2.0, BADGE_HEIGHT / 2.0, &bg_paint);
+        
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        text_paint.set_color(theme.primary_foreground);
+        canvas.draw_str(&text, (badge_rect.center_x() - text_width / 2.0, badge_rect.bottom - 4.5), &font, &text_paint);
+    }
 }
 
 impl Widget for ActivityBar {
-    fn draw(&self, canvas: &Canvas, _font_manager: &mut FontManager) {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut FontManager) {
         let theme = mikoui::current_theme();
         
         // Background
         let mut bg_paint = Paint::default();
         bg_paint.set_anti_alias(true);
-        bg_paint.set_color(theme.card);
+        bg_paint.set_color(surface(theme.card));
         
         let rect = Rect::from_xywh(self.x, self.y, self.width, self.height);
         canvas.draw_rect(rect, &bg_paint);
@@ -142,7 +186,11 @@ impl Widget for ActivityBar {
                 IconSize::Medium,
                 icon_color,
             );
-            icon.draw(canvas, _font_manager);
+            icon.draw(canvas, font_manager);
+            
+            if self.badges[i] > 0 {
+                self.draw_badge(canvas, font_manager, item_rect, self.badges[i]);
+            }
         }
         
         // Right border
@@ -185,7 +233,7 @@ impl Widget for ActivityBar {
         for i in 0..self.hover_progress.len() {
             let target = if self.hover_item == Some(i) { 1.0 } else { 0.0 };
             if (self.hover_progress[i] - target).abs() > 0.01 {
-                self.hover_progress[i] += (target - self.hover_progress[i]) * animation_speed;
+                self.hover_progress[i] += (target - self.hover_progress[i]) * animation_rate(animation_speed);
             } else {
                 self.hover_progress[i] = target;
             }
@@ -199,6 +247,10 @@ impl Widget for ActivityBar {
         }
     }
     
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+    
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1,7 +1,14 @@
-use mikoui::{Widget, FontManager};
-use mikoui::theme::current_theme;
-use mikoui::components::{Icon, IconSize, CodiconIcons};
-use skia_safe::{Canvas, Paint, Rect, Color};
+use mikoui::{animation_rate, fuzzy_match, t, FileIcon, Widget, FontManager};
+use mikoui::theme::{current_theme, Theme};
+use mikoui::components::{Icon, IconSize, CodiconIcons, Skeleton};
+use skia_safe::{Canvas, Font, Paint, Rect, Color};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use super::parse_line_target;
+
+/// Command id of the palette's `:` entry; read the position with `CommandPalette::line_target`
+pub const PALETTE_LINE_ID: u32 = 257;
 
 /// Command item in the palette
 #[derive(Debug, Clone)]
@@ -9,7 +16,7 @@ pub struct CommandItem {
     pub id: u32,
     pub label: String,
     pub description: Option<String>,
-    pub icon: Option<&'static str>,
+    pub icon: Option<FileIcon>,
     pub shortcut: Option<String>,
     pub category: String,
 }
@@ -22,7 +29,7 @@ impl CommandItem {
             description: None,
             icon: None,
             shortcut: None,
-            category: "General".to_string(),
+            category: t!("category.general"),
         }
     }
     
@@ -32,6 +39,12 @@ impl CommandItem {
     }
     
     pub fn with_icon(mut self, icon: &'static str) -> Self {
+        self.icon = Some(FileIcon::codicon(icon));
+        self
+    }
+    
+    /// Icon from the file icon theme, e.g. for quick open results
+    pub fn with_file_icon(mut self, icon: FileIcon) -> Self {
         self.icon = Some(icon);
         self
     }
@@ -47,6 +60,87 @@ impl CommandItem {
     }
 }
 
+/// What the palette lists, chosen by the first character of the search text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteMode {
+    /// No prefix: files of the opened folder, by name
+    Files,
+    /// `>`: registered commands
+    Commands,
+    /// `@`: symbols of the active file
+    Symbols,
+    /// `#`: symbols defined anywhere in the opened folder
+    WorkspaceSymbols,
+    /// `:`: a line, and optionally a column, of the active file
+    Line,
+    /// `?`: the other modes and their prefixes
+    Help,
+}
+
+impl PaletteMode {
+    pub const ALL: [PaletteMode; 6] = [
+        PaletteMode::Files,
+        PaletteMode::Commands,
+        PaletteMode::Symbols,
+        PaletteMode::WorkspaceSymbols,
+        PaletteMode::Line,
+        PaletteMode::Help,
+    ];
+    
+    pub fn prefix(self) -> &'static str {
+        match self {
+            PaletteMode::Files => "",
+            PaletteMode::Commands => ">",
+            PaletteMode::Symbols => "@",
+            PaletteMode::WorkspaceSymbols => "#",
+            PaletteMode::Line => ":",
+            PaletteMode::Help => "?",
+        }
+    }
+    
+    /// Split search text into its mode and the query after the prefix
+    pub fn parse(text: &str) -> (PaletteMode, &str) {
+        Self::ALL
+            .into_iter()
+            .filter(|mode| !mode.prefix().is_empty())
+            .find_map(|mode| text.strip_prefix(mode.prefix()).map(|query| (mode, query.trim_start())))
+            .unwrap_or((PaletteMode::Files, text))
+    }
+    
+    /// Whether the app provides the entries, through `CommandPalette::set_mode_items`
+    fn is_provided(self) -> bool {
+        matches!(self, PaletteMode::Symbols | PaletteMode::Line)
+    }
+    
+    /// Whether the app looks up every query in the background, see `CommandPalette::take_query_request`
+    pub fn is_async(self) -> bool {
+        matches!(self, PaletteMode::Files | PaletteMode::WorkspaceSymbols)
+    }
+    
+    fn placeholder(self) -> String {
+        match self {
+            PaletteMode::Files => t!("palette.files"),
+            PaletteMode::Commands => t!("palette.placeholder"),
+            PaletteMode::Symbols => t!("picker.go_to_symbol"),
+            PaletteMode::WorkspaceSymbols => t!("picker.go_to_workspace_symbol"),
+            PaletteMode::Line => t!("palette.line"),
+            PaletteMode::Help => t!("palette.help"),
+        }
+    }
+    
+    /// Entry describing the mode in the `?` list
+    fn help_label(self) -> String {
+        match self {
+            PaletteMode::Files => t!("palette.help_files"),
+            PaletteMode::Commands => t!("palette.help_commands"),
+            PaletteMode::Symbols => t!("palette.help_symbols"),
+            PaletteMode::WorkspaceSymbols => t!("palette.help_workspace_symbols"),
+            PaletteMode::Line => t!("palette.help_line"),
+            PaletteMode::Help => t!("palette.help_help"),
+        }
+    }
+}
+
 /// Command Palette overlay
 pub struct CommandPalette {
     x: f32,
@@ -56,12 +150,32 @@ pub struct CommandPalette {
     visible: bool,
     search_text: String,
     commands: Vec<CommandItem>,
-    filtered_commands: Vec<usize>, // Indices into commands
+    filtered_commands: Vec<usize>, // Indices into commands, best match first
+    highlights: Vec<Vec<usize>>, // Matched label chars of each filtered command
     selected_index: usize,
     hover_index: Option<usize>,
     scroll_offset: f32,
     animation_progress: f32, // 0.0 to 1.0 for fade in/out
     target_visible: bool,
+    picker_placeholder: Option<String>, // Set while showing a quick pick instead of commands
+    prompt: Option<u32>, // Id of the one entry a text prompt makes of the typed text
+    prompt_text: Option<String>, // Text of the last accepted prompt entry
+    query_item: Option<fn(&str) -> Option<CommandItem>>, // Makes a quick pick entry of the typed text, listed first
+    picker_len: usize, // Items of the quick pick before the query item
+    shortcut_overrides: HashMap<u32, Option<String>>, // Shortcuts from keybindings.json, by command
+    extension_commands: Vec<CommandItem>, // Contributed by the enabled extensions
+    mode: Option<PaletteMode>, // Mode the items were loaded for; None once they need reloading
+    mode_request: Option<PaletteMode>, // Mode waiting for the app to provide its items
+    mode_hint: Option<String>, // Provider's placeholder, e.g. while indexing
+    line_count: Option<usize>, // Lines of the active file, for the `:` mode
+    line_target: Option<(usize, usize)>,
+    command_boosts: HashMap<u32, i32>, // Ranking boost of recently and frequently run commands
+    used_command: Option<u32>, // Command run from the `>` mode, waiting to be counted
+    query_request: Option<(PaletteMode, String)>, // Query waiting for the app to start looking it up
+    requested_query: Option<String>, // Query the async mode's results are for
+    loading: bool, // More results of `requested_query` may still arrive
+    replace_results: bool, // The next results replace the previous query's
+    pulse: f32, // Seconds of animation, for the loading row
 }
 
 impl CommandPalette {
@@ -87,93 +201,362 @@ impl CommandPalette {
             search_text: String::new(),
             commands,
             filtered_commands,
+            highlights: Vec::new(),
             selected_index: 0,
             hover_index: None,
             scroll_offset: 0.0,
             animation_progress: 0.0,
             target_visible: false,
+            picker_placeholder: None,
+            prompt: None,
+            prompt_text: None,
+            query_item: None,
+            picker_len: 0,
+            shortcut_overrides: HashMap::new(),
+            extension_commands: Vec::new(),
+            mode: None,
+            mode_request: None,
+            mode_hint: None,
+            line_count: None,
+            line_target: None,
+            command_boosts: HashMap::new(),
+            used_command: None,
+            query_request: None,
+            requested_query: None,
+            loading: false,
+            replace_results: false,
+            pulse: 0.0,
         }
     }
     
-    fn create_default_commands() -> Vec<CommandItem> {
+    /// Every registered command, with the shortcut it has by default
+    pub fn create_default_commands() -> Vec<CommandItem> {
         vec![
             // File commands
-            CommandItem::new(1, "File: New File")
+            CommandItem::new(1, t!("command.file.new_file"))
                 .with_icon(CodiconIcons::FILE)
                 .with_shortcut("Ctrl+N")
-                .with_category("File"),
-            CommandItem::new(2, "File: New Window")
+                .with_category(t!("category.file")),
+            CommandItem::new(2, t!("command.file.new_window"))
                 .with_icon(CodiconIcons::WINDOW)
                 .with_shortcut("Ctrl+Shift+N")
-                .with_category("File"),
-            CommandItem::new(3, "File: Open File")
+                .with_category(t!("category.file")),
+            CommandItem::new(3, t!("command.file.open_file"))
                 .with_icon(CodiconIcons::FOLDER_OPENED)
                 .with_shortcut("Ctrl+O")
-                .with_category("File"),
-            CommandItem::new(4, "File: Open Folder")
+                .with_category(t!("category.file")),
+            CommandItem::new(4, t!("command.file.open_folder"))
                 .with_icon(CodiconIcons::FOLDER_OPENED)
                 .with_shortcut("Ctrl+K Ctrl+O")
-                .with_category("File"),
-            CommandItem::new(6, "File: Save")
+                .with_category(t!("category.file")),
+            CommandItem::new(286, t!("command.file.open_archive"))
+                .with_icon(CodiconIcons::FOLDER_OPENED)
+                .with_category(t!("category.file")),
+            CommandItem::new(6, t!("command.file.save"))
                 .with_icon(CodiconIcons::SAVE)
                 .with_shortcut("Ctrl+S")
-                .with_category("File"),
-            CommandItem::new(7, "File: Save As")
+                .with_category(t!("category.file")),
+            CommandItem::new(7, t!("command.file.save_as"))
                 .with_icon(CodiconIcons::SAVE_AS)
                 .with_shortcut("Ctrl+Shift+S")
-                .with_category("File"),
+                .with_category(t!("category.file")),
             
             // View commands
-            CommandItem::new(62, "View: Show Explorer")
+            CommandItem::new(62, t!("command.view.show_explorer"))
                 .with_icon(CodiconIcons::FILES)
                 .with_shortcut("Ctrl+Shift+E")
-                .with_category("View"),
-            CommandItem::new(63, "View: Show Search")
+                .with_category(t!("category.view")),
+            CommandItem::new(63, t!("command.view.show_search"))
                 .with_icon(CodiconIcons::SEARCH)
                 .with_shortcut("Ctrl+Shift+F")
-                .with_category("View"),
-            CommandItem::new(64, "View: Show Source Control")
+                .with_category(t!("category.view")),
+            CommandItem::new(207, t!("command.view.show_outline"))
+                .with_icon(CodiconIcons::LIST_TREE)
+                .with_category(t!("category.view")),
+            CommandItem::new(208, t!("command.view.toggle_outline_follow_cursor"))
+                .with_icon(CodiconIcons::LOCATION)
+                .with_category(t!("category.view")),
+            CommandItem::new(287, t!("command.view.show_todos"))
+                .with_icon(CodiconIcons::CHECKLIST)
+                .with_category(t!("category.view")),
+            CommandItem::new(66, t!("command.view.show_extensions"))
+                .with_icon(CodiconIcons::EXTENSIONS)
+                .with_shortcut("Ctrl+Shift+X")
+                .with_category(t!("category.view")),
+            CommandItem::new(258, t!("command.extensions.check_for_updates"))
+                .with_icon(CodiconIcons::REFRESH)
+                .with_category(t!("category.extensions")),
+            CommandItem::new(64, t!("command.view.show_source_control"))
                 .with_icon(CodiconIcons::SOURCE_CONTROL)
                 .with_shortcut("Ctrl+Shift+G")
-                .with_category("View"),
-            CommandItem::new(69, "View: Toggle Terminal")
+                .with_category(t!("category.view")),
+            CommandItem::new(69, t!("command.view.toggle_terminal"))
                 .with_icon(CodiconIcons::TERMINAL)
                 .with_shortcut("Ctrl+`")
-                .with_category("View"),
-            CommandItem::new(76, "View: Toggle Full Screen")
+                .with_category(t!("category.view")),
+            CommandItem::new(76, t!("command.view.toggle_full_screen"))
                 .with_icon(CodiconIcons::SCREEN_FULL)
                 .with_shortcut("F11")
-                .with_category("View"),
+                .with_category(t!("category.view")),
+            CommandItem::new(78, t!("command.view.toggle_render_whitespace"))
+                .with_icon(CodiconIcons::WHITESPACE)
+                .with_category(t!("category.view")),
+            CommandItem::new(260, t!("command.view.toggle_inlay_hints"))
+                .with_icon(CodiconIcons::SYMBOL_PARAMETER)
+                .with_category(t!("category.view")),
+            CommandItem::new(262, t!("command.git.toggle_inline_blame"))
+                .with_icon(CodiconIcons::GIT_COMMIT)
+                .with_category(t!("category.git")),
+            CommandItem::new(263, t!("command.git.file_history"))
+                .with_icon(CodiconIcons::HISTORY)
+                .with_category(t!("category.git")),
+            CommandItem::new(264, t!("command.git.checkout"))
+                .with_icon(CodiconIcons::GIT_BRANCH)
+                .with_category(t!("category.git")),
+            CommandItem::new(265, t!("command.git.pull"))
+                .with_icon(CodiconIcons::ARROW_DOWN)
+                .with_category(t!("category.git")),
+            CommandItem::new(266, t!("command.git.push"))
+                .with_icon(CodiconIcons::ARROW_UP)
+                .with_category(t!("category.git")),
+            CommandItem::new(267, t!("command.git.fetch"))
+                .with_icon(CodiconIcons::SYNC)
+                .with_category(t!("category.git")),
+            CommandItem::new(268, t!("command.git.stash"))
+                .with_icon(CodiconIcons::SAVE)
+                .with_category(t!("category.git")),
+            CommandItem::new(269, t!("command.git.stash_pop"))
+                .with_icon(CodiconIcons::DISCARD)
+                .with_category(t!("category.git")),
+            CommandItem::new(272, t!("command.git.next_conflict"))
+                .with_icon(CodiconIcons::ARROW_DOWN)
+                .with_category(t!("category.git")),
+            CommandItem::new(273, t!("command.git.previous_conflict"))
+                .with_icon(CodiconIcons::ARROW_UP)
+                .with_category(t!("category.git")),
+            CommandItem::new(216, t!("command.view.pin_editor"))
+                .with_icon(CodiconIcons::PIN)
+                .with_shortcut("Ctrl+Alt+Shift+P")
+                .with_category(t!("category.view")),
+            CommandItem::new(217, t!("command.view.unpin_editor"))
+                .with_icon(CodiconIcons::PINNED)
+                .with_shortcut("Ctrl+Alt+Shift+P")
+                .with_category(t!("category.view")),
+            CommandItem::new(259, t!("command.view.reopen_closed_editor"))
+                .with_icon(CodiconIcons::HISTORY)
+                .with_shortcut("Ctrl+Shift+T")
+                .with_category(t!("category.view")),
             
             // Edit commands
-            CommandItem::new(29, "Edit: Find")
+            CommandItem::new(29, t!("command.edit.find"))
                 .with_icon(CodiconIcons::SEARCH)
                 .with_shortcut("Ctrl+F")
-                .with_category("Edit"),
-            CommandItem::new(32, "Edit: Replace")
+                .with_category(t!("category.edit")),
+            CommandItem::new(32, t!("command.edit.replace"))
                 .with_icon(CodiconIcons::REPLACE)
                 .with_shortcut("Ctrl+H")
-                .with_category("Edit"),
-            CommandItem::new(39, "Edit: Format Document")
+                .with_category(t!("category.edit")),
+            CommandItem::new(39, t!("command.edit.format_document"))
                 .with_icon(CodiconIcons::SYMBOL_RULER)
                 .with_shortcut("Shift+Alt+F")
-                .with_category("Edit"),
+                .with_category(t!("category.edit")),
+            CommandItem::new(37, t!("command.edit.toggle_line_comment"))
+                .with_icon(CodiconIcons::COMMENT)
+                .with_shortcut("Ctrl+/")
+                .with_category(t!("category.edit")),
+            CommandItem::new(38, t!("command.edit.toggle_block_comment"))
+                .with_icon(CodiconIcons::COMMENT)
+                .with_shortcut("Shift+Alt+A")
+                .with_category(t!("category.edit")),
+            CommandItem::new(41, t!("command.edit.trim_trailing_whitespace"))
+                .with_icon(CodiconIcons::WHITESPACE)
+                .with_category(t!("category.edit")),
+            CommandItem::new(174, t!("command.edit.indent_using_spaces"))
+                .with_icon(CodiconIcons::WHITESPACE)
+                .with_category(t!("category.edit")),
+            CommandItem::new(175, t!("command.edit.indent_using_tabs"))
+                .with_icon(CodiconIcons::WHITESPACE)
+                .with_category(t!("category.edit")),
+            CommandItem::new(176, t!("command.edit.detect_indentation_from_content"))
+                .with_icon(CodiconIcons::SEARCH)
+                .with_category(t!("category.edit")),
+            CommandItem::new(177, t!("command.edit.reindent_lines"))
+                .with_icon(CodiconIcons::LIST_FLAT)
+                .with_category(t!("category.edit")),
+            CommandItem::new(206, t!("command.edit.rename_symbol"))
+                .with_icon(CodiconIcons::EDIT)
+                .with_shortcut("F2")
+                .with_category(t!("category.edit")),
+            CommandItem::new(97, t!("command.edit.toggle_macro_recording"))
+                .with_icon(CodiconIcons::RECORD)
+                .with_shortcut("Ctrl+Alt+R")
+                .with_category(t!("category.edit")),
+            CommandItem::new(98, t!("command.edit.play_last_macro"))
+                .with_icon(CodiconIcons::PLAY)
+                .with_shortcut("Ctrl+Alt+P")
+                .with_category(t!("category.edit")),
+            CommandItem::new(99, t!("command.edit.play_last_macro_times"))
+                .with_icon(CodiconIcons::REFRESH)
+                .with_category(t!("category.edit")),
+            CommandItem::new(204, t!("command.edit.save_last_macro"))
+                .with_icon(CodiconIcons::SAVE)
+                .with_category(t!("category.edit")),
+            CommandItem::new(205, t!("command.edit.run_saved_macro"))
+                .with_icon(CodiconIcons::RECORD_KEYS)
+                .with_category(t!("category.edit")),
+            CommandItem::new(294, t!("command.edit.insert_unicode_character"))
+                .with_icon(CodiconIcons::SYMBOL_STRING)
+                .with_category(t!("category.edit")),
             
             // Go commands
-            CommandItem::new(84, "Go: Go to File")
+            CommandItem::new(84, t!("command.go.go_to_file"))
                 .with_icon(CodiconIcons::GO_TO_FILE)
                 .with_shortcut("Ctrl+P")
-                .with_category("Go"),
-            CommandItem::new(91, "Go: Go to Line")
+                .with_category(t!("category.go")),
+            CommandItem::new(91, t!("command.go.go_to_line"))
                 .with_icon(CodiconIcons::ARROW_RIGHT)
                 .with_shortcut("Ctrl+G")
-                .with_category("Go"),
+                .with_category(t!("category.go")),
+            CommandItem::new(92, t!("command.go.go_to_bracket"))
+                .with_icon(CodiconIcons::BRACKET)
+                .with_shortcut("Ctrl+Shift+\\")
+                .with_category(t!("category.go")),
+            CommandItem::new(85, t!("command.go.go_to_symbol_in_editor"))
+                .with_icon(CodiconIcons::SYMBOL_FUNCTION)
+                .with_shortcut("Ctrl+Shift+O")
+                .with_category(t!("category.go")),
+            CommandItem::new(93, t!("command.go.go_to_symbol_in_workspace"))
+                .with_icon(CodiconIcons::SYMBOL_CLASS)
+                .with_shortcut("Ctrl+T")
+                .with_category(t!("category.go")),
+            CommandItem::new(261, t!("command.go.peek_definition"))
+                .with_icon(CodiconIcons::EYE)
+                .with_shortcut("Alt+F12")
+                .with_category(t!("category.go")),
+            
+            // Bookmark commands
+            CommandItem::new(288, t!("command.bookmarks.toggle"))
+                .with_icon(CodiconIcons::BOOKMARK)
+                .with_shortcut("Ctrl+K Ctrl+K")
+                .with_category(t!("category.bookmarks")),
+            CommandItem::new(289, t!("command.bookmarks.next"))
+                .with_icon(CodiconIcons::ARROW_DOWN)
+                .with_shortcut("Ctrl+K Ctrl+N")
+                .with_category(t!("category.bookmarks")),
+            CommandItem::new(290, t!("command.bookmarks.previous"))
+                .with_icon(CodiconIcons::ARROW_UP)
+                .with_shortcut("Ctrl+K Ctrl+P")
+                .with_category(t!("category.bookmarks")),
+            CommandItem::new(291, t!("command.bookmarks.label"))
+                .with_icon(CodiconIcons::EDIT)
+                .with_category(t!("category.bookmarks")),
+            CommandItem::new(293, t!("command.bookmarks.show"))
+                .with_icon(CodiconIcons::BOOKMARK)
+                .with_category(t!("category.bookmarks")),
             
             // Terminal commands
-            CommandItem::new(120, "Terminal: New Terminal")
+            CommandItem::new(120, t!("command.terminal.new_terminal"))
                 .with_icon(CodiconIcons::TERMINAL)
                 .with_shortcut("Ctrl+Shift+`")
-                .with_category("Terminal"),
+                .with_category(t!("category.terminal")),
+            CommandItem::new(274, t!("command.terminal.run_selected_text"))
+                .with_icon(CodiconIcons::PLAY)
+                .with_category(t!("category.terminal")),
+            CommandItem::new(275, t!("command.terminal.run_active_file"))
+                .with_icon(CodiconIcons::PLAY)
+                .with_category(t!("category.terminal")),
+            CommandItem::new(150, t!("command.terminal.go_to_previous_command"))
+                .with_icon(CodiconIcons::ARROW_UP)
+                .with_shortcut("Ctrl+Up")
+                .with_category(t!("category.terminal")),
+            CommandItem::new(151, t!("command.terminal.go_to_next_command"))
+                .with_icon(CodiconIcons::ARROW_DOWN)
+                .with_shortcut("Ctrl+Down")
+                .with_category(t!("category.terminal")),
+            
+            // File format commands
+            CommandItem::new(172, t!("command.file.change_file_encoding"))
+                .with_icon(CodiconIcons::FILE_CODE)
+                .with_category(t!("category.file")),
+            CommandItem::new(173, t!("command.file.change_end_of_line_sequence"))
+                .with_icon(CodiconIcons::LIST_FLAT)
+                .with_category(t!("category.file")),
+            CommandItem::new(215, t!("command.file.change_language_mode"))
+                .with_icon(CodiconIcons::FILE_CODE)
+                .with_category(t!("category.file")),
+            CommandItem::new(200, t!("command.file.reopen_editor_with"))
+                .with_icon(CodiconIcons::FILE_BINARY)
+                .with_category(t!("category.file")),
+            CommandItem::new(295, t!("command.file.open_preview"))
+                .with_icon(CodiconIcons::MARKDOWN)
+                .with_category(t!("category.file")),
+            CommandItem::new(94, t!("command.file.reveal_active_file"))
+                .with_icon(CodiconIcons::FILES)
+                .with_category(t!("category.file")),
+            CommandItem::new(95, t!("command.file.move_to_trash"))
+                .with_icon(CodiconIcons::TRASH)
+                .with_category(t!("category.file")),
+            CommandItem::new(96, t!("command.file.undo_file_operation"))
+                .with_icon(CodiconIcons::DISCARD)
+                .with_category(t!("category.file")),
+            CommandItem::new(203, t!("command.hex.find_bytes"))
+                .with_icon(CodiconIcons::SEARCH)
+                .with_shortcut("Ctrl+F")
+                .with_category(t!("category.hex")),
+            
+            // Preferences commands
+            CommandItem::new(210, t!("command.preferences.open_theme_editor"))
+                .with_icon(CodiconIcons::SYMBOL_COLOR)
+                .with_category(t!("category.preferences")),
+            CommandItem::new(211, t!("command.preferences.load_color_theme_from_file"))
+                .with_icon(CodiconIcons::PAINTCAN)
+                .with_category(t!("category.preferences")),
+            CommandItem::new(213, t!("command.preferences.toggle_high_contrast_theme"))
+                .with_icon(CodiconIcons::INSPECT)
+                .with_category(t!("category.preferences")),
+            CommandItem::new(214, t!("command.preferences.configure_display_language"))
+                .with_icon(CodiconIcons::GLOBE)
+                .with_category(t!("category.preferences")),
+            CommandItem::new(218, t!("command.preferences.file_icon_theme"))
+                .with_icon(CodiconIcons::FILE_MEDIA)
+                .with_category(t!("category.preferences")),
+            CommandItem::new(255, t!("command.preferences.open_settings"))
+                .with_icon(CodiconIcons::SETTINGS_GEAR)
+                .with_category(t!("category.preferences")),
+            CommandItem::new(256, t!("command.preferences.keyboard_shortcuts"))
+                .with_icon(CodiconIcons::RECORD_KEYS)
+                .with_shortcut("Ctrl+K Ctrl+S")
+                .with_category(t!("category.preferences")),
+            CommandItem::new(250, t!("command.preferences.usage_data"))
+                .with_icon(CodiconIcons::GRAPH)
+                .with_category(t!("category.preferences")),
+            CommandItem::new(276, t!("command.preferences.manage_workspace_trust"))
+                .with_icon(CodiconIcons::SETTINGS_GEAR)
+                .with_category(t!("category.preferences")),
+            
+            // Profile commands
+            CommandItem::new(277, t!("command.profiles.switch"))
+                .with_icon(CodiconIcons::SETTINGS_GEAR)
+                .with_category(t!("category.profiles")),
+            CommandItem::new(280, t!("command.profiles.new_window"))
+                .with_icon(CodiconIcons::WINDOW)
+                .with_category(t!("category.profiles")),
+            
+            // Remote commands
+            CommandItem::new(281, t!("command.remote.connect"))
+                .with_icon(CodiconIcons::GLOBE)
+                .with_category(t!("category.remote")),
+            CommandItem::new(285, t!("command.remote.close"))
+                .with_icon(CodiconIcons::GLOBE)
+                .with_category(t!("category.remote")),
+            
+            // Developer commands
+            CommandItem::new(212, t!("command.developer.toggle_performance_overlay"))
+                .with_icon(CodiconIcons::INSPECT)
+                .with_category(t!("category.developer")),
+            CommandItem::new(254, t!("command.developer.toggle_widget_inspector"))
+                .with_icon(CodiconIcons::INSPECT)
+                .with_category(t!("category.developer")),
         ]
     }
     
@@ -187,6 +570,10 @@ impl CommandPalette {
     }
     
     pub fn show(&mut self) {
+        // Outside a quick pick, load the mode's items afresh
+        if self.picker_placeholder.is_none() {
+            self.mode = None;
+        }
         self.target_visible = true;
         self.search_text.clear();
         self.selected_index = 0;
@@ -195,9 +582,245 @@ impl CommandPalette {
         self.update_filter();
     }
     
+    /// Show the palette with the search text set to the prefix of `mode`
+    pub fn show_mode(&mut self, mode: PaletteMode) {
+        self.picker_placeholder = None;
+        self.show();
+        self.search_text = mode.prefix().to_string();
+        self.update_filter();
+    }
+    
     pub fn hide(&mut self) {
         self.target_visible = false;
         self.search_text.clear();
+        // Results still on their way are for a query that is gone
+        self.query_request = None;
+        self.requested_query = None;
+        self.loading = false;
+        
+        // Load the mode's items again after a quick pick
+        if self.picker_placeholder.take().is_some() {
+            self.mode = None;
+        }
+        self.prompt = None;
+        self.query_item = None;
+    }
+    
+    /// Rebuild the command list, e.g. after switching the display language
+    pub fn reload_commands(&mut self) {
+        self.picker_placeholder = None;
+        self.mode = None;
+        self.update_filter();
+    }
+    
+    /// Show these shortcuts instead of the defaults; `None` for a command left without keys
+    pub fn set_shortcut_overrides(&mut self, overrides: HashMap<u32, Option<String>>) {
+        self.shortcut_overrides = overrides;
+        if self.picker_placeholder.is_none() {
+            self.reload_commands();
+        }
+    }
+    
+    /// Commands of the enabled extensions, listed after the built-in ones
+    pub fn set_extension_commands(&mut self, commands: Vec<CommandItem>) {
+        self.extension_commands = commands;
+        if self.picker_placeholder.is_none() {
+            self.reload_commands();
+        }
+    }
+    
+    /// Mode the search text switched to whose items the app provides, once
+    pub fn take_mode_request(&mut self) -> Option<PaletteMode> {
+        self.mode_request.take()
+    }
+    
+    /// Items of the files or symbols mode, with a placeholder replacing the mode's own
+    pub fn set_mode_items(&mut self, mode: PaletteMode, items: Vec<CommandItem>, hint: Option<String>) {
+        if self.picker_placeholder.is_some() || self.mode != Some(mode) {
+            return;
+        }
+        self.commands = items;
+        self.mode_hint = hint;
+        self.update_filter();
+    }
+    
+    /// Query of the files or workspace symbols mode to look up, once per change of the search text
+    /// Results go to `push_results`; the app drops the previous lookup, whose results would be stale.
+    pub fn take_query_request(&mut self) -> Option<(PaletteMode, String)> {
+        self.query_request.take()
+    }
+    
+    /// Add results found for `query`; results of any other query are ignored
+    /// The first results replace those of the previous query, which stay listed until then.
+    pub fn push_results(&mut self, mode: PaletteMode, query: &str, items: Vec<CommandItem>, done: bool) {
+        if self.picker_placeholder.is_some() || self.mode != Some(mode) || self.requested_query.as_deref() != Some(query) {
+            return;
+        }
+        let replace = self.replace_results && (done || !items.is_empty());
+        let selected = if replace { None } else { self.get_selected_command() };
+        if replace {
+            self.commands.clear();
+            self.replace_results = false;
+        }
+        self.commands.extend(items);
+        self.loading = !done;
+        self.rank();
+        // Keep the selection on the same entry while results stream in
+        self.selected_index = selected
+            .and_then(|id| self.filtered_commands.iter().position(|&i| self.commands[i].id == id))
+            .unwrap_or(0);
+        if replace {
+            self.scroll_offset = 0.0;
+        }
+        self.ensure_selected_visible();
+    }
+    
+    /// Placeholder replacing the mode's own, e.g. while the folder is being indexed
+    pub fn set_mode_hint(&mut self, mode: PaletteMode, hint: Option<String>) {
+        if self.picker_placeholder.is_none() && self.mode == Some(mode) {
+            self.mode_hint = hint;
+        }
+    }
+    
+    /// True while results of an async mode are still arriving
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+    
+    /// Lines of the active file for the `:` mode, `None` without one
+    pub fn set_line_count(&mut self, line_count: Option<usize>) {
+        self.line_count = line_count;
+        if self.picker_placeholder.is_none() && self.mode == Some(PaletteMode::Line) {
+            self.mode_hint = Some(match line_count {
+                Some(count) => t!("go_to_line.prompt", count = count),
+                None => t!("palette.no_editor"),
+            });
+            self.update_filter();
+        }
+    }
+    
+    /// Text typed into the last prompt when its entry was accepted, for the prompt's id
+    pub fn prompt_text(&self) -> Option<&str> {
+        self.prompt_text.as_deref()
+    }
+    
+    /// One-based `(line, column)` of the last `:` entry, for `PALETTE_LINE_ID`
+    pub fn line_target(&self) -> Option<(usize, usize)> {
+        self.line_target
+    }
+    
+    /// Rank recently and frequently run commands higher in the `>` mode
+    pub fn set_command_boosts(&mut self, boosts: HashMap<u32, i32>) {
+        self.command_boosts = boosts;
+    }
+    
+    /// Command last run from the `>` mode, once, to count towards its ranking
+    pub fn take_used_command(&mut self) -> Option<u32> {
+        self.used_command.take()
+    }
+    
+    /// Search text after the mode prefix; the whole text in a quick pick
+    fn query(&self) -> &str {
+        if self.picker_placeholder.is_some() {
+            &self.search_text
+        } else {
+            PaletteMode::parse(&self.search_text).1
+        }
+    }
+    
+    /// Replace the items with the ones of `mode`, asking the app for the ones it provides
+    fn enter_mode(&mut self, mode: PaletteMode) {
+        self.mode = Some(mode);
+        self.mode_hint = None;
+        self.query_request = None;
+        self.requested_query = None;
+        self.loading = false;
+        self.replace_results = false;
+        self.commands = match mode {
+            PaletteMode::Commands => self.command_list(),
+            PaletteMode::Help => PaletteMode::ALL
+                .iter()
+                .enumerate()
+                .map(|(i, mode)| {
+                    let item = CommandItem::new(i as u32, mode.help_label()).with_icon(CodiconIcons::QUESTION);
+                    match mode.prefix() {
+                        "" => item,
+                        prefix => item.with_shortcut(prefix),
+                    }
+                })
+                .collect(),
+            PaletteMode::Files | PaletteMode::Symbols | PaletteMode::WorkspaceSymbols | PaletteMode::Line => Vec::new(),
+        };
+        self.mode_request = mode.is_provided().then_some(mode);
+    }
+    
+    /// The single `:` entry for the typed position, if it parses
+    fn line_items(&mut self) -> Vec<CommandItem> {
+        self.line_target = self.line_count.and_then(|count| parse_line_target(self.query(), count));
+        let Some((line, column)) = self.line_target else {
+            return Vec::new();
+        };
+        let label = if column > 1 {
+            t!("go_to_line.line_column", line = line, column = column)
+        } else {
+            t!("go_to_line.line", line = line)
+        };
+        vec![CommandItem::new(PALETTE_LINE_ID, label).with_icon(CodiconIcons::ARROW_RIGHT)]
+    }
+    
+    /// Run the selected entry: hides the palette and returns its id, or switches mode for a `?` entry
+    pub fn accept_selected(&mut self) -> Option<u32> {
+        let command_id = self.get_selected_command()?;
+        if self.picker_placeholder.is_none() && self.mode == Some(PaletteMode::Help) {
+            let mode = PaletteMode::ALL.get(command_id as usize)?;
+            self.search_text = mode.prefix().to_string();
+            self.update_filter();
+            return None;
+        }
+        if self.picker_placeholder.is_none() && self.mode == Some(PaletteMode::Commands) {
+            self.used_command = Some(command_id);
+        }
+        if self.prompt == Some(command_id) {
+            self.prompt_text = Some(self.query().trim().to_string());
+        }
+        self.hide();
+        Some(command_id)
+    }
+    
+    fn command_list(&self) -> Vec<CommandItem> {
+        let mut commands = Self::create_default_commands();
+        commands.extend(self.extension_commands.iter().cloned());
+        for command in &mut commands {
+            if let Some(shortcut) = self.shortcut_overrides.get(&command.id) {
+                command.shortcut = shortcut.clone();
+            }
+        }
+        commands
+    }
+    
+    /// Show the palette as a quick pick over `items` instead of the command list
+    pub fn show_picker(&mut self, placeholder: impl Into<String>, items: Vec<CommandItem>) {
+        self.commands = items;
+        self.picker_placeholder = Some(placeholder.into());
+        self.prompt = None;
+        self.query_item = None;
+        self.show();
+    }
+    
+    /// Quick pick that also lists first the entry `query_item` makes of the typed text, if any
+    /// E.g. a character typed as a code point, next to the characters whose name matches.
+    pub fn show_picker_with_query_item(&mut self, placeholder: impl Into<String>, items: Vec<CommandItem>, query_item: fn(&str) -> Option<CommandItem>) {
+        self.picker_len = items.len();
+        self.show_picker(placeholder, items);
+        self.query_item = Some(query_item);
+    }
+    
+    /// Ask for a line of text, e.g. a branch name; the typed text becomes one entry, accepted as `id`
+    pub fn show_prompt(&mut self, placeholder: impl Into<String>, id: u32) {
+        self.commands = Vec::new();
+        self.picker_placeholder = Some(placeholder.into());
+        self.prompt = Some(id);
+        self.show();
     }
     
     pub fn toggle(&mut self) {
@@ -219,16 +842,7 @@ impl CommandPalette {
                 self.hide();
                 None
             }
-            "Enter" => {
-                if !self.filtered_commands.is_empty() && self.selected_index < self.filtered_commands.len() {
-                    let cmd_index = self.filtered_commands[self.selected_index];
-                    let command_id = self.commands[cmd_index].id;
-                    self.hide();
-                    Some(command_id)
-                } else {
-                    None
-                }
-            }
+            "Enter" => self.accept_selected(),
             "ArrowUp" => {
                 if self.selected_index > 0 {
                     self.selected_index -= 1;
@@ -270,25 +884,98 @@ impl CommandPalette {
     }
     
     fn update_filter(&mut self) {
-        if self.search_text.is_empty() {
-            self.filtered_commands = (0..self.commands.len()).collect();
+        if self.picker_placeholder.is_none() {
+            let (mode, query) = PaletteMode::parse(&self.search_text);
+            let query = query.to_string();
+            if self.mode != Some(mode) {
+                self.enter_mode(mode);
+            }
+            if mode == PaletteMode::Line {
+                self.commands = self.line_items();
+            }
+            if mode.is_async() && self.requested_query.as_ref() != Some(&query) {
+                self.requested_query = Some(query.clone());
+                self.query_request = Some((mode, query));
+                self.loading = true;
+                self.replace_results = true;
+            }
+        } else if let Some(id) = self.prompt {
+            let text = self.query().trim();
+            self.commands = if text.is_empty() {
+                Vec::new()
+            } else {
+                vec![CommandItem::new(id, text).with_icon(CodiconIcons::ADD)]
+            };
+        } else if let Some(query_item) = self.query_item {
+            let item = query_item(self.query().trim());
+            self.commands.truncate(self.picker_len);
+            self.commands.extend(item);
+        }
+        
+        self.rank();
+        if self.commands.len() > self.picker_len && self.query_item.is_some() {
+            // The query item comes first, whether or not the ranking matched it
+            let index = self.picker_len;
+            if let Some(position) = self.filtered_commands.iter().position(|&i| i == index) {
+                self.filtered_commands.remove(position);
+                if position < self.highlights.len() {
+                    self.highlights.remove(position);
+                }
+            }
+            self.filtered_commands.insert(0, index);
+            if !self.highlights.is_empty() {
+                self.highlights.insert(0, Vec::new());
+            }
+        }
+        self.selected_index = 0;
+        self.scroll_offset = 0.0;
+    }
+    
+    /// Order the items matching the query by score into `filtered_commands`
+    fn rank(&mut self) {
+        let query = self.query().to_string();
+        let no_boosts = HashMap::new();
+        let boosts = match self.mode {
+            Some(PaletteMode::Commands) if self.picker_placeholder.is_none() => &self.command_boosts,
+            _ => &no_boosts,
+        };
+        if query.is_empty() || self.mode == Some(PaletteMode::Line) || self.prompt.is_some() {
+            // Recently and frequently run commands first, the rest in registration order
+            let mut filtered: Vec<usize> = (0..self.commands.len()).collect();
+            filtered.sort_by_key(|&i| Reverse(boosts.get(&self.commands[i].id).copied().unwrap_or(0)));
+            self.filtered_commands = filtered;
+            self.highlights.clear();
         } else {
-            let search_lower = self.search_text.to_lowercase();
-            self.filtered_commands = self.commands
+            let mut matches: Vec<(i32, usize, Vec<usize>)> = self.commands
                 .iter()
                 .enumerate()
-                .filter(|(_, cmd)| {
-                    cmd.label.to_lowercase().contains(&search_lower) ||
-                    cmd.category.to_lowercase().contains(&search_lower) ||
-                    cmd.description.as_ref().map_or(false, |d| d.to_lowercase().contains(&search_lower))
+                .filter_map(|(i, cmd)| {
+                    let boost = boosts.get(&cmd.id).copied().unwrap_or(0);
+                    if let Some(found) = fuzzy_match(&query, &cmd.label) {
+                        return Some((found.score + boost, i, found.positions));
+                    }
+                    // The description before the label (a file's folder and name) and the category
+                    // still match, at half the score and without highlights
+                    [cmd.description.as_ref().map(|d| format!("{}/{}", d, cmd.label)), Some(cmd.category.clone())]
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|text| fuzzy_match(&query, &text))
+                        .map(|found| found.score / 2)
+                        .max()
+                        .map(|score| (score + boost, i, Vec::new()))
                 })
-                .map(|(i, _)| i)
                 .collect();
+            // Best score first, then the shorter label, then registration order
+            matches.sort_by(|a, b| {
+                b.0.cmp(&a.0)
+                    .then_with(|| self.commands[a.1].label.len().cmp(&self.commands[b.1].label.len()))
+                    .then(a.1.cmp(&b.1))
+            });
+            (self.filtered_commands, self.highlights) = matches
+                .into_iter()
+                .map(|(_, index, positions)| (index, positions))
+                .unzip();
         }
-        
-        // Reset selection
-        self.selected_index = 0;
-        self.scroll_offset = 0.0;
     }
     
     fn ensure_selected_visible(&mut self) {
@@ -311,6 +998,24 @@ impl CommandPalette {
         }
     }
     
+    /// Draw `label` with the chars at `highlights` in the match color
+    fn draw_label(canvas: &Canvas, font: &Font, label: &str, highlights: &[usize], origin: (f32, f32), paint: &Paint, match_paint: &Paint) {
+        let mut x = origin.0;
+        let mut run = String::new();
+        let mut run_matched = false;
+        for (i, c) in label.chars().enumerate() {
+            let matched = highlights.binary_search(&i).is_ok();
+            if matched != run_matched && !run.is_empty() {
+                canvas.draw_str(&run, (x, origin.1), font, if run_matched { match_paint } else { paint });
+                x += font.measure_str(&run, None).0;
+                run.clear();
+            }
+            run_matched = matched;
+            run.push(c);
+        }
+        canvas.draw_str(&run, (x, origin.1), font, if run_matched { match_paint } else { paint });
+    }
+    
     pub fn scroll(&mut self, delta: f32) {
         let max_scroll = (self.filtered_commands.len() as f32 * Self::ITEM_HEIGHT)
             - (Self::MAX_VISIBLE_ITEMS as f32 * Self::ITEM_HEIGHT);
@@ -402,16 +1107,8 @@ impl Widget for CommandPalette {
         let text_x = input_padding + 32.0;
         let text_y = 32.0;
         
-        if self.search_text.is_empty() {
-            let placeholder = "Type a command or search...";
-            let font = font_manager.create_font(placeholder, 13.0, 400);
-            let mut text_paint = Paint::default();
-            let muted = theme.muted_foreground;
-            let text_alpha = (muted.a() as f32 * alpha_multiplier) as u8;
-            text_paint.set_color(Color::from_argb(text_alpha, muted.r(), muted.g(), muted.b()));
-            text_paint.set_anti_alias(true);
-            canvas.draw_str(placeholder, (text_x, text_y), &font, &text_paint);
-        } else {
+        let mut placeholder_x = text_x;
+        if !self.search_text.is_empty() {
             let font = font_manager.create_font(&self.search_text, 13.0, 400);
             let mut text_paint = Paint::default();
             let fg = theme.foreground;
@@ -419,6 +1116,22 @@ impl Widget for CommandPalette {
             text_paint.set_color(Color::from_argb(text_alpha, fg.r(), fg.g(), fg.b()));
             text_paint.set_anti_alias(true);
             canvas.draw_str(&self.search_text, (text_x, text_y), &font, &text_paint);
+            placeholder_x += font.measure_str(&self.search_text, None).0 + 4.0;
+        }
+        
+        // Placeholder of the quick pick, or of the mode after its prefix
+        if self.query().is_empty() {
+            let placeholder = match (&self.picker_placeholder, &self.mode_hint) {
+                (Some(placeholder), _) | (None, Some(placeholder)) => placeholder.clone(),
+                (None, None) => PaletteMode::parse(&self.search_text).0.placeholder(),
+            };
+            let font = font_manager.create_font(&placeholder, 13.0, 400);
+            let mut text_paint = Paint::default();
+            let muted = theme.muted_foreground;
+            let text_alpha = (muted.a() as f32 * alpha_multiplier) as u8;
+            text_paint.set_color(Color::from_argb(text_alpha, muted.r(), muted.g(), muted.b()));
+            text_paint.set_anti_alias(true);
+            canvas.draw_str(&placeholder, (placeholder_x, text_y), &font, &text_paint);
         }
         
         // Draw separator
@@ -480,17 +1193,18 @@ impl Widget for CommandPalette {
             
             // Draw icon with alpha
             if let Some(icon) = command.icon {
-                let fg = theme.foreground;
+                let fg = icon.color.unwrap_or(theme.foreground);
                 let icon_alpha = (fg.a() as f32 * alpha_multiplier) as u8;
                 let icon_color = Color::from_argb(icon_alpha, fg.r(), fg.g(), fg.b());
                 
                 let icon_widget = Icon::new(
                     16.0,
                     item_y + 14.0,
-                    icon,
+                    icon.svg,
                     IconSize::Small,
                     icon_color,
-                );
+                )
+                .full_color(icon.full_color);
                 icon_widget.draw(canvas, font_manager);
             }
             
@@ -503,7 +1217,11 @@ impl Widget for CommandPalette {
             let text_alpha = (fg.a() as f32 * alpha_multiplier) as u8;
             text_paint.set_color(Color::from_argb(text_alpha, fg.r(), fg.g(), fg.b()));
             text_paint.set_anti_alias(true);
-            canvas.draw_str(&command.label, (label_x, label_y), &font, &text_paint);
+            let mut match_paint = text_paint.clone();
+            let info = Theme::INFO;
+            match_paint.set_color(Color::from_argb((info.a() as f32 * alpha_multiplier) as u8, info.r(), info.g(), info.b()));
+            let highlights = self.highlights.get(i).map_or(&[][..], Vec::as_slice);
+            Self::draw_label(canvas, &font, &command.label, highlights, (label_x, label_y), &text_paint, &match_paint);
             
             // Draw shortcut - VSCode style with background
             if let Some(ref shortcut) = command.shortcut {
@@ -540,6 +1258,19 @@ impl Widget for CommandPalette {
             }
         }
         
+        // Pulsing row below the results while more may arrive
+        if self.loading {
+            let item_y = items_start_y + (self.filtered_commands.len() as f32 * Self::ITEM_HEIGHT) - self.scroll_offset;
+            if item_y < items_start_y + visible_height {
+                let mut icon = Skeleton::new_circle(16.0, item_y + 14.0, 16.0);
+                let mut label = Skeleton::new(44.0, item_y + 16.0, self.width * 0.45, 12.0).border_radius(3.0);
+                icon.update_animation(self.pulse);
+                label.update_animation(self.pulse);
+                icon.draw(canvas, font_manager);
+                label.draw(canvas, font_manager);
+            }
+        }
+        
         canvas.restore();
         
         canvas.restore(); // Restore from scale/translate
@@ -573,12 +1304,14 @@ impl Widget for CommandPalette {
         }
     }
     
-    fn update_animation(&mut self, _elapsed: f32) {
+    fn update_animation(&mut self, elapsed: f32) {
+        self.pulse = elapsed;
+        
         // Update animation progress
         let target = if self.target_visible { 1.0 } else { 0.0 };
         
         if (self.animation_progress - target).abs() > 0.01 {
-            let delta = (target - self.animation_progress) * Self::ANIMATION_SPEED;
+            let delta = (target - self.animation_progress) * animation_rate(Self::ANIMATION_SPEED);
             self.animation_progress += delta;
         } else {
             self.animation_progress = target;
@@ -594,6 +1327,10 @@ impl Widget for CommandPalette {
         }
     }
     
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+    
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -0,0 +1,189 @@
+use mikoui::{t, Widget, FontManager};
+use mikoui::theme::current_theme;
+use mikoeditor::HexBuffer;
+use skia_safe::{Canvas, Paint, Rect};
+
+/// Small inline input for searching bytes in the hex editor
+/// Accepts hex bytes (`DE AD BE EF`) or quoted text (`"PNG"`)
+pub struct FindBytes {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    visible: bool,
+    text: String,
+    status: Option<String>,
+}
+
+impl FindBytes {
+    const WIDTH: f32 = 420.0;
+    const HEIGHT: f32 = 64.0;
+
+    pub fn new(screen_width: f32, top: f32) -> Self {
+        Self {
+            x: (screen_width - Self::WIDTH) / 2.0,
+            y: top + 8.0,
+            width: Self::WIDTH,
+            height: Self::HEIGHT,
+            visible: false,
+            text: String::new(),
+            status: None,
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show the input, keeping the previous search text
+    pub fn show(&mut self) {
+        self.visible = true;
+        self.status = None;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn add_char(&mut self, c: char) {
+        if !c.is_control() {
+            self.text.push(c);
+            self.status = None;
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        self.text.pop();
+        self.status = None;
+    }
+
+    /// Result of the last search, shown under the input
+    pub fn set_status(&mut self, status: impl Into<String>) {
+        self.status = Some(status.into());
+    }
+
+    /// Handle a special key; returns the pattern to search for on Enter
+    pub fn handle_key_input(&mut self, key: &str) -> Option<Vec<u8>> {
+        match key {
+            "Escape" => {
+                self.hide();
+                None
+            }
+            "Enter" => {
+                let pattern = HexBuffer::parse_pattern(&self.text);
+                if pattern.is_none() {
+                    self.set_status(t!("find_bytes.invalid"));
+                }
+                pattern
+            }
+            "Backspace" => {
+                self.backspace();
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn hint(&self) -> String {
+        if let Some(ref status) = self.status {
+            return status.clone();
+        }
+        match HexBuffer::parse_pattern(&self.text) {
+            Some(pattern) => t!("find_bytes.ready", count = pattern.len()),
+            None => t!("find_bytes.prompt"),
+        }
+    }
+}
+
+impl Widget for FindBytes {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut FontManager) {
+        if !self.visible {
+            return;
+        }
+
+        let theme = current_theme();
+        let rect = Rect::from_xywh(self.x, self.y, self.width, self.height);
+
+        // Shadow
+        let mut shadow_paint = Paint::default();
+        shadow_paint.set_color(skia_safe::Color::from_argb(80, 0, 0, 0));
+        shadow_paint.set_anti_alias(true);
+        if let Some(blur) = skia_safe::MaskFilter::blur(skia_safe::BlurStyle::Normal, 12.0, false) {
+            shadow_paint.set_mask_filter(blur);
+        }
+        canvas.draw_round_rect(rect.with_offset((2.0, 2.0)), 6.0, 6.0, &shadow_paint);
+
+        // Background and border
+        let mut bg_paint = Paint::default();
+        bg_paint.set_color(theme.card);
+        bg_paint.set_anti_alias(true);
+        canvas.draw_round_rect(rect, 6.0, 6.0, &bg_paint);
+
+        let mut border_paint = Paint::default();
+        border_paint.set_color(theme.border);
+        border_paint.set_style(skia_safe::PaintStyle::Stroke);
+        border_paint.set_stroke_width(1.0);
+        border_paint.set_anti_alias(true);
+        canvas.draw_round_rect(rect, 6.0, 6.0, &border_paint);
+
+        // Input field
+        let input_rect = Rect::from_xywh(self.x + 8.0, self.y + 8.0, self.width - 16.0, 26.0);
+        let mut input_paint = Paint::default();
+        input_paint.set_color(theme.background);
+        input_paint.set_anti_alias(true);
+        canvas.draw_round_rect(input_rect, 4.0, 4.0, &input_paint);
+
+        let mut focus_paint = Paint::default();
+        focus_paint.set_color(theme.primary);
+        focus_paint.set_style(skia_safe::PaintStyle::Stroke);
+        focus_paint.set_stroke_width(1.0);
+        focus_paint.set_anti_alias(true);
+        canvas.draw_round_rect(input_rect, 4.0, 4.0, &focus_paint);
+
+        let font = font_manager.create_mono_font(13.0, 400);
+        let mut text_paint = Paint::default();
+        text_paint.set_color(theme.foreground);
+        text_paint.set_anti_alias(true);
+        canvas.draw_str(&self.text, (input_rect.left + 8.0, input_rect.top + 18.0), &font, &text_paint);
+
+        // Caret after the text
+        let caret_x = input_rect.left + 8.0 + font.measure_str(&self.text, None).0 + 1.0;
+        canvas.draw_rect(Rect::from_xywh(caret_x, input_rect.top + 6.0, 1.0, 14.0), &text_paint);
+
+        // Hint or search result
+        let hint = self.hint();
+        let hint_font = font_manager.create_font(&hint, 12.0, 400);
+        let mut hint_paint = Paint::default();
+        hint_paint.set_color(theme.muted_foreground);
+        hint_paint.set_anti_alias(true);
+        canvas.draw_str(&hint, (self.x + 16.0, self.y + 54.0), &hint_font, &hint_paint);
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        self.visible && x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    fn update_hover(&mut self, _x: f32, _y: f32) {
+        // No hover states
+    }
+
+    fn update_animation(&mut self, _elapsed: f32) {
+        // No animations
+    }
+
+    fn on_click(&mut self) {
+        // Clicks inside keep the input open
+    }
+
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -1,26 +1,51 @@
-use mikoui::{Widget, FontManager};
-use mikoui::theme::current_theme;
+use mikoui::{t, Widget, FontManager, LogView, Orientation, Scrollbar, ScrollbarStyle};
+use mikoui::theme::{current_theme, surface};
 use skia_safe::{Canvas, Color, Paint, Rect};
 use mikoterminal::{Terminal, TerminalConfig, TerminalRenderer};
 
-const RESIZE_HANDLE_HEIGHT: f32 = 4.0;
-const MIN_HEIGHT: f32 = 100.0;
-const MAX_HEIGHT: f32 = 500.0;
+const OVERVIEW_RULER_WIDTH: f32 = 10.0;
+const VIEW_TAB_WIDTH: f32 = 80.0;
+const HEADER_HEIGHT: f32 = 32.0;
+
+/// Views hosted in the bottom panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BottomPanelView {
+    Terminal,
+    Output,
+}
+
+impl BottomPanelView {
+    const ALL: [BottomPanelView; 2] = [BottomPanelView::Terminal, BottomPanelView::Output];
+    
+    fn label(&self) -> &'static str {
+        match self {
+            BottomPanelView::Terminal => "Terminal",
+            BottomPanelView::Output => "Output",
+        }
+    }
+}
 
 pub struct BottomPanel {
     x: f32,
     y: f32,
     width: f32,
     height: f32,
-    is_resizing: bool,
-    hover_resize: bool,
     terminal: Option<Terminal>,
     terminal_renderer: TerminalRenderer,
+    terminal_scrollbar: Scrollbar,
+    output: LogView,
+    active_view: BottomPanelView,
+    hovered_view: Option<BottomPanelView>,
+    /// Text dragged from the editor is over the terminal
+    drop_hover: bool,
+    /// False in Restricted Mode, where no shell is started in the folder
+    shell_allowed: bool,
 }
 
 impl BottomPanel {
     pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
         let terminal_renderer = TerminalRenderer::new(14.0);
+        let output = LogView::new(x + 8.0, y + HEADER_HEIGHT, width - 16.0, height - HEADER_HEIGHT - 4.0);
         
         // Don't start terminal immediately - it will be started on first update
         // This avoids issues with PTY creation on the main thread
@@ -29,18 +54,30 @@ impl BottomPanel {
             x,
             y,
             width,
-            height: height.clamp(MIN_HEIGHT, MAX_HEIGHT),
-            is_resizing: false,
-            hover_resize: false,
+            height,
             terminal: None,
+            shell_allowed: false,
             terminal_renderer,
+            terminal_scrollbar: Scrollbar::new(Orientation::Vertical).style(ScrollbarStyle::Classic),
+            output,
+            active_view: BottomPanelView::Terminal,
+            hovered_view: None,
+            drop_hover: false,
+        }
+    }
+    
+    /// Allow the shell to start, or end it when the folder goes into Restricted Mode
+    pub fn set_shell_allowed(&mut self, allowed: bool) {
+        self.shell_allowed = allowed;
+        if !allowed {
+            self.terminal = None;
         }
     }
     
     /// Initialize terminal (call this after panel is created)
     pub fn init_terminal(&mut self) {
-        if self.terminal.is_some() {
-            return; // Already initialized
+        if self.terminal.is_some() || !self.shell_allowed {
+            return; // Already initialized, or waiting for the folder to be trusted
         }
         
         // Create terminal with config
@@ -48,9 +85,13 @@ impl BottomPanel {
         config.font_size = 14.0;
         
         // Calculate rows and cols based on panel size
-        let (cell_width, cell_height) = self.terminal_renderer.cell_size();
-        config.cols = ((self.width - 32.0) / cell_width).max(20.0) as u16;
-        config.rows = ((self.height - 48.0) / cell_height).max(5.0) as u16;
+        let (rows, cols) = self.terminal_size();
+        config.rows = rows;
+        config.cols = cols;
+        
+        // Start the shell in the workspace root
+        config.cwd = std::env::current_dir().ok();
+        config.env.push(("TERM_PROGRAM".to_string(), "rabital".to_string()));
         
         let mut terminal = Terminal::new(config);
         
@@ -67,49 +108,211 @@ impl BottomPanel {
         }
     }
     
-    pub fn height(&self) -> f32 {
-        self.height
+    /// Terminal rows and cols that fit in the panel
+    fn terminal_size(&self) -> (u16, u16) {
+        let (cell_width, cell_height) = self.terminal_renderer.cell_size();
+        let cols = ((self.width - 32.0 - OVERVIEW_RULER_WIDTH) / cell_width).max(20.0) as u16;
+        let rows = ((self.height - 48.0) / cell_height).max(5.0) as u16;
+        (rows, cols)
     }
     
-    pub fn set_position(&mut self, y: f32) {
-        self.y = y;
+    /// Resize the terminal to match the panel size
+    fn sync_terminal_size(&mut self) {
+        let (rows, cols) = self.terminal_size();
+        if let Some(ref mut terminal) = self.terminal {
+            if terminal.size() != (rows, cols) {
+                if let Err(e) = terminal.resize(rows, cols) {
+                    eprintln!("Failed to resize terminal: {}", e);
+                }
+            }
+        }
     }
     
-    pub fn set_width(&mut self, width: f32) {
-        self.width = width;
+    /// Terminal scrollback area beside the text, shared by the scrollbar and failed-command marks
+    fn terminal_ruler_rect(&self) -> Rect {
+        Rect::from_xywh(
+            self.x + self.width - OVERVIEW_RULER_WIDTH - 4.0,
+            self.y + 40.0,
+            OVERVIEW_RULER_WIDTH,
+            (self.height - 48.0).max(0.0),
+        )
+    }
+    
+    /// Mirror the terminal's scrollback position into the scrollbar, one line per cell height
+    fn sync_terminal_scrollbar(&mut self) {
+        let Some(ref terminal) = self.terminal else {
+            return;
+        };
+        let (_, cell_height) = self.terminal_renderer.cell_size();
+        let first_line = terminal.first_line();
+        let ruler_rect = self.terminal_ruler_rect();
+        self.terminal_scrollbar.set_bounds(ruler_rect.left, ruler_rect.top, ruler_rect.width(), ruler_rect.height());
+        self.terminal_scrollbar.set_metrics(
+            (terminal.total_lines() - first_line) as f32 * cell_height,
+            terminal.visible_lines().len() as f32 * cell_height,
+        );
+        self.terminal_scrollbar.set_offset((terminal.first_visible_line() - first_line) as f32 * cell_height);
+    }
+    
+    /// Scroll the terminal to where the scrollbar was dragged or clicked
+    fn apply_terminal_scrollbar(&mut self) {
+        let (_, cell_height) = self.terminal_renderer.cell_size();
+        let offset = self.terminal_scrollbar.offset();
+        if let Some(ref mut terminal) = self.terminal {
+            let line = terminal.first_line() + (offset / cell_height).round() as usize;
+            terminal.scroll_to_line(line);
+        }
+    }
+    
+    /// Whether the terminal scrollbar is still easing its hover state
+    pub fn is_animating(&self) -> bool {
+        self.terminal_scrollbar.is_animating()
+    }
+    
+    /// Scroll the terminal to the previous shell command
+    pub fn previous_command(&mut self) -> bool {
+        self.terminal.as_mut().is_some_and(|t| t.previous_command())
+    }
+    
+    /// Scroll the terminal to the next shell command
+    pub fn next_command(&mut self) -> bool {
+        self.terminal.as_mut().is_some_and(|t| t.next_command())
+    }
+    
+    /// Type `text` into the terminal's shell, starting it first if needed; false when it couldn't start
+    pub fn send_to_terminal(&mut self, text: &str) -> bool {
+        self.init_terminal();
+        let Some(ref mut terminal) = self.terminal else {
+            return false;
+        };
+        if let Err(e) = terminal.send_input(text) {
+            eprintln!("Failed to write to terminal: {}", e);
+            return false;
+        }
+        true
+    }
+    
+    /// The terminal is showing and (x, y) is over its text, below the view tabs
+    pub fn is_over_terminal(&self, x: f32, y: f32) -> bool {
+        self.active_view == BottomPanelView::Terminal && self.contains(x, y) && y > self.y + HEADER_HEIGHT
+    }
+    
+    /// Outline the terminal while dragged text is over it; `None` clears it
+    pub fn preview_text_drop(&mut self, point: Option<(f32, f32)>) {
+        self.drop_hover = point.is_some_and(|(x, y)| self.is_over_terminal(x, y));
+    }
+    
+    pub fn set_active_view(&mut self, view: BottomPanelView) {
+        self.active_view = view;
+    }
+    
+    /// Append text (ANSI colors allowed) to the Output view
+    pub fn append_output(&mut self, text: &str) {
+        self.output.append(text);
     }
     
-    pub fn resize_handle_rect(&self) -> Rect {
+    fn view_tab_rect(&self, view: BottomPanelView) -> Rect {
+        let index = BottomPanelView::ALL.iter().position(|v| *v == view).unwrap_or(0);
         Rect::from_xywh(
-            self.x,
-            self.y - RESIZE_HANDLE_HEIGHT / 2.0,
-            self.width,
-            RESIZE_HANDLE_HEIGHT,
+            self.x + 8.0 + index as f32 * VIEW_TAB_WIDTH,
+            self.y + 4.0,
+            VIEW_TAB_WIDTH,
+            HEADER_HEIGHT - 8.0,
         )
     }
     
-    pub fn is_over_resize_handle(&self, x: f32, y: f32) -> bool {
-        let handle = self.resize_handle_rect();
-        x >= handle.left && x <= handle.right && y >= handle.top && y <= handle.bottom
+    fn view_at(&self, x: f32, y: f32) -> Option<BottomPanelView> {
+        BottomPanelView::ALL
+            .into_iter()
+            .find(|view| self.view_tab_rect(*view).contains(skia_safe::Point::new(x, y)))
     }
     
-    pub fn start_resize(&mut self) {
-        self.is_resizing = true;
+    /// Handle a left click; returns true if the panel consumed it
+    pub fn handle_mouse_press(&mut self, x: f32, y: f32) -> bool {
+        if !self.contains(x, y) {
+            self.output.clear_selection();
+            return false;
+        }
+        
+        if let Some(view) = self.view_at(x, y) {
+            self.active_view = view;
+        } else if self.active_view == BottomPanelView::Terminal && self.terminal_scrollbar.handle_mouse_down(x, y) {
+            self.apply_terminal_scrollbar();
+        } else if self.active_view == BottomPanelView::Output {
+            self.output.start_selection(x, y);
+        }
+        true
+    }
+    
+    pub fn handle_mouse_release(&mut self) {
+        self.output.end_selection();
+        self.terminal_scrollbar.handle_mouse_up();
+    }
+    
+    /// Scroll the active view; returns false if it doesn't scroll
+    pub fn scroll(&mut self, delta: f32) -> bool {
+        match self.active_view {
+            BottomPanelView::Output => {
+                self.output.scroll(delta);
+                true
+            }
+            BottomPanelView::Terminal => {
+                let Some(ref mut terminal) = self.terminal else {
+                    return false;
+                };
+                let (_, cell_height) = self.terminal_renderer.cell_size();
+                let rows = (delta / cell_height).round() as isize;
+                let rows = if rows == 0 && delta != 0.0 { delta.signum() as isize } else { rows };
+                let line = (terminal.first_visible_line() as isize + rows).max(terminal.first_line() as isize);
+                terminal.scroll_to_line(line as usize);
+                self.sync_terminal_scrollbar();
+                true
+            }
+        }
+    }
+    
+    /// Text selected in the Output view
+    pub fn selected_text(&self) -> Option<String> {
+        if self.active_view == BottomPanelView::Output && self.output.has_selection() {
+            Some(self.output.selected_text())
+        } else {
+            None
+        }
+    }
+    
+    pub fn height(&self) -> f32 {
+        self.height
     }
     
-    pub fn stop_resize(&mut self) {
-        self.is_resizing = false;
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+        self.sync_layout();
+    }
+    
+    pub fn set_position(&mut self, y: f32) {
+        self.y = y;
+        self.sync_layout();
     }
     
-    pub fn resize_to(&mut self, y: f32, window_height: f32) {
-        let new_height = (window_height - y).clamp(MIN_HEIGHT, MAX_HEIGHT);
-        self.height = new_height;
-        self.y = window_height - self.height;
+    pub fn set_width(&mut self, width: f32) {
+        self.width = width;
+        self.sync_layout();
     }
     
-    pub fn is_resizing(&self) -> bool {
-        self.is_resizing
+    /// Fit the terminal and output view to the panel bounds
+    fn sync_layout(&mut self) {
+        self.output.set_bounds(
+            self.x + 8.0,
+            self.y + HEADER_HEIGHT,
+            self.width - 16.0,
+            self.height - HEADER_HEIGHT - 4.0,
+        );
+        self.sync_terminal_size();
     }
+    
 }
 
 impl Widget for BottomPanel {
@@ -118,7 +321,7 @@ impl Widget for BottomPanel {
         
         // Background
         let mut bg_paint = Paint::default();
-        bg_paint.set_color(Color::from_rgb(12, 12, 12)); // Dark terminal background
+        bg_paint.set_color(surface(Color::from_rgb(12, 12, 12))); // Dark terminal background
         bg_paint.set_anti_alias(true);
         
         let panel_rect = Rect::from_xywh(self.x, self.y, self.width, self.height);
@@ -137,42 +340,75 @@ impl Widget for BottomPanel {
             &border_paint,
         );
         
-        // Resize handle
-        if self.hover_resize || self.is_resizing {
-            let mut handle_paint = Paint::default();
-            let alpha = if self.is_resizing { 100 } else { 50 };
-            handle_paint.set_color(Color::from_argb(alpha, 100, 150, 255));
-            handle_paint.set_anti_alias(true);
+        // Header with one tab per view
+        for view in BottomPanelView::ALL {
+            let tab_rect = self.view_tab_rect(view);
+            let active = view == self.active_view;
+            
+            let text = view.label();
+            let font = font_manager.create_font(text, 12.0, 600);
+            let mut text_paint = Paint::default();
+            text_paint.set_color(if active || self.hovered_view == Some(view) {
+                theme.foreground
+            } else {
+                theme.muted_foreground
+            });
+            text_paint.set_anti_alias(true);
+            
+            canvas.draw_str(
+                text,
+                (tab_rect.left + 8.0, self.y + 24.0),
+                &font,
+                &text_paint,
+            );
             
-            let handle_rect = self.resize_handle_rect();
-            canvas.draw_rect(handle_rect, &handle_paint);
+            if active {
+                let mut underline_paint = Paint::default();
+                underline_paint.set_color(theme.foreground);
+                let text_width = font.measure_str(text, None).0;
+                canvas.draw_rect(
+                    Rect::from_xywh(tab_rect.left + 8.0, tab_rect.bottom - 1.0, text_width, 1.0),
+                    &underline_paint,
+                );
+            }
         }
         
-        // Header
-        let text = "Terminal";
-        let font = font_manager.create_font(text, 12.0, 600);
-        let mut text_paint = Paint::default();
-        text_paint.set_color(theme.foreground);
-        text_paint.set_anti_alias(true);
-        
-        canvas.draw_str(
-            text,
-            (self.x + 16.0, self.y + 24.0),
-            &font,
-            &text_paint,
-        );
-        
-        // Render terminal or show message
-        if let Some(ref terminal) = self.terminal {
+        // Render the active view
+        if self.active_view == BottomPanelView::Output {
+            self.output.draw(canvas, font_manager);
+        } else if let Some(ref terminal) = self.terminal {
             self.terminal_renderer.render(
                 terminal,
                 canvas,
                 self.x + 16.0,
                 self.y + 40.0,
             );
+            self.terminal_renderer.render_command_marks(
+                terminal,
+                canvas,
+                self.x + 16.0,
+                self.y + 40.0,
+            );
+            
+            // Scrollbar along the right edge, with failed commands marked on its track
+            self.terminal_scrollbar.paint(canvas);
+            self.terminal_renderer.render_failed_marks(terminal, canvas, self.terminal_ruler_rect());
+            
+            if self.drop_hover {
+                let mut drop_paint = Paint::default();
+                drop_paint.set_anti_alias(true);
+                drop_paint.set_style(skia_safe::PaintStyle::Stroke);
+                drop_paint.set_stroke_width(2.0);
+                drop_paint.set_color(theme.primary);
+                canvas.draw_rect(
+                    Rect::from_xywh(self.x + 4.0, self.y + HEADER_HEIGHT, self.width - 8.0, self.height - HEADER_HEIGHT - 4.0),
+                    &drop_paint,
+                );
+            }
         } else {
             // Show initialization message
-            let msg = "Terminal initializing...";
+            let msg = if self.shell_allowed { t!("terminal.initializing") } else { t!("terminal.restricted") };
+            let msg = msg.as_str();
             let font = font_manager.create_font(msg, 12.0, 400);
             let mut msg_paint = Paint::default();
             msg_paint.set_color(theme.muted_foreground);
@@ -192,10 +428,14 @@ impl Widget for BottomPanel {
     }
     
     fn update_hover(&mut self, x: f32, y: f32) {
-        self.hover_resize = self.is_over_resize_handle(x, y);
+        self.hovered_view = self.view_at(x, y);
+        self.output.update_hover(x, y);
+        if self.terminal_scrollbar.handle_mouse_move(x, y) {
+            self.apply_terminal_scrollbar();
+        }
     }
     
-    fn update_animation(&mut self, _elapsed: f32) {
+    fn update_animation(&mut self, elapsed: f32) {
         // Initialize terminal on first update if not already done
         if self.terminal.is_none() {
             self.init_terminal();
@@ -205,12 +445,18 @@ impl Widget for BottomPanel {
         if let Some(ref mut terminal) = self.terminal {
             let _ = terminal.update();
         }
+        self.sync_terminal_scrollbar();
+        self.terminal_scrollbar.update_animation(elapsed);
     }
     
     fn on_click(&mut self) {
         // Handle clicks if needed
     }
     
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+    
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
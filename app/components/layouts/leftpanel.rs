@@ -1,66 +1,98 @@
-use mikoui::{Widget, FontManager};
-use mikoui::theme::current_theme;
-use skia_safe::{Canvas, Color, Paint, Rect};
-use crate::pages::Explorer;
+use mikoui::{t, Widget, FontManager};
+use mikoui::components::Input;
+use mikoui::theme::{current_theme, surface, Size};
+use skia_safe::{Canvas, Paint, Rect};
+use std::path::Path;
+use crate::pages::{BookmarkView, Explorer, SearchView, TodoView};
 
-const RESIZE_HANDLE_WIDTH: f32 = 4.0;
-const MIN_WIDTH: f32 = 200.0;
-const MAX_WIDTH: f32 = 600.0;
 const HEADER_HEIGHT: f32 = 32.0;
+/// Filter box row below the header
+const FILTER_HEIGHT: f32 = 40.0;
+const FILTER_PADDING: f32 = 8.0;
+
+/// Page shown in the panel, picked from the activity bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SidebarView {
+    Explorer,
+    Search,
+    Todos,
+    Bookmarks,
+}
 
 pub struct LeftPanel {
     x: f32,
     y: f32,
     width: f32,
     height: f32,
-    is_resizing: bool,
-    hover_resize: bool,
     explorer: Explorer,
+    // Narrows the tree to matching names
+    filter_input: Input,
+    filter_hovered: bool,
+    view: SidebarView,
+    search: SearchView,
+    todos: TodoView,
+    bookmarks: BookmarkView,
 }
 
 impl LeftPanel {
     pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
-        let clamped_width = width.clamp(MIN_WIDTH, MAX_WIDTH);
         let explorer = Explorer::new(
             x,
-            y + HEADER_HEIGHT,
-            clamped_width,
-            height - HEADER_HEIGHT,
+            y + HEADER_HEIGHT + FILTER_HEIGHT,
+            width,
+            height - HEADER_HEIGHT - FILTER_HEIGHT,
         );
         
         Self {
             x,
             y,
-            width: clamped_width,
+            width,
             height,
-            is_resizing: false,
-            hover_resize: false,
             explorer,
+            filter_input: Self::filter_input(x, y, width),
+            filter_hovered: false,
+            view: SidebarView::Explorer,
+            search: SearchView::new(x, y + HEADER_HEIGHT, width, height - HEADER_HEIGHT),
+            todos: TodoView::new(x, y + HEADER_HEIGHT, width, height - HEADER_HEIGHT),
+            bookmarks: BookmarkView::new(x, y + HEADER_HEIGHT, width, height - HEADER_HEIGHT),
         }
     }
     
-    pub fn new_with_path(x: f32, y: f32, width: f32, height: f32, root_path: std::path::PathBuf) -> Self {
-        println!("LeftPanel::new_with_path called with: {}", root_path.display());
-        let clamped_width = width.clamp(MIN_WIDTH, MAX_WIDTH);
+    pub fn new_with_path(x: f32, y: f32, width: f32, height: f32, walker: mikocore::Walker) -> Self {
+        println!("LeftPanel::new_with_path called with: {}", walker.root().display());
         let explorer = crate::pages::Explorer::new_with_path(
             x,
-            y + HEADER_HEIGHT,
-            clamped_width,
-            height - HEADER_HEIGHT,
-            root_path,
+            y + HEADER_HEIGHT + FILTER_HEIGHT,
+            width,
+            height - HEADER_HEIGHT - FILTER_HEIGHT,
+            walker,
         );
         
         Self {
             x,
             y,
-            width: clamped_width,
+            width,
             height,
-            is_resizing: false,
-            hover_resize: false,
             explorer,
+            filter_input: Self::filter_input(x, y, width),
+            filter_hovered: false,
+            view: SidebarView::Explorer,
+            search: SearchView::new(x, y + HEADER_HEIGHT, width, height - HEADER_HEIGHT),
+            todos: TodoView::new(x, y + HEADER_HEIGHT, width, height - HEADER_HEIGHT),
+            bookmarks: BookmarkView::new(x, y + HEADER_HEIGHT, width, height - HEADER_HEIGHT),
         }
     }
     
+    fn filter_input(x: f32, y: f32, width: f32) -> Input {
+        Input::new(
+            x + FILTER_PADDING,
+            y + HEADER_HEIGHT + (FILTER_HEIGHT - Size::Sm.height()) / 2.0,
+            width - FILTER_PADDING * 2.0,
+            t!("explorer.filter_placeholder"),
+        )
+        .size(Size::Sm)
+    }
+    
     pub fn width(&self) -> f32 {
         self.width
     }
@@ -69,47 +101,79 @@ impl LeftPanel {
         self.height = height;
         self.explorer.set_bounds(
             self.x,
-            self.y + HEADER_HEIGHT,
+            self.y + HEADER_HEIGHT + FILTER_HEIGHT,
             self.width,
-            height - HEADER_HEIGHT,
+            height - HEADER_HEIGHT - FILTER_HEIGHT,
         );
+        self.search.set_bounds(self.x, self.y + HEADER_HEIGHT, self.width, height - HEADER_HEIGHT);
+        self.todos.set_bounds(self.x, self.y + HEADER_HEIGHT, self.width, height - HEADER_HEIGHT);
+        self.bookmarks.set_bounds(self.x, self.y + HEADER_HEIGHT, self.width, height - HEADER_HEIGHT);
     }
     
-    pub fn resize_handle_rect(&self) -> Rect {
-        Rect::from_xywh(
-            self.x + self.width - RESIZE_HANDLE_WIDTH / 2.0,
-            self.y,
-            RESIZE_HANDLE_WIDTH,
-            self.height,
-        )
+    pub fn set_view(&mut self, view: SidebarView) {
+        self.view = view;
+        self.blur();
     }
     
-    pub fn is_over_resize_handle(&self, x: f32, y: f32) -> bool {
-        let handle = self.resize_handle_rect();
-        x >= handle.left && x <= handle.right && y >= handle.top && y <= handle.bottom
+    pub fn is_search_visible(&self) -> bool {
+        self.view == SidebarView::Search
     }
     
-    pub fn start_resize(&mut self) {
-        self.is_resizing = true;
+    pub fn search(&self) -> &SearchView {
+        &self.search
     }
     
-    pub fn stop_resize(&mut self) {
-        self.is_resizing = false;
+    pub fn search_mut(&mut self) -> &mut SearchView {
+        &mut self.search
     }
     
-    pub fn resize_to(&mut self, x: f32) {
-        let new_width = (x - self.x).clamp(MIN_WIDTH, MAX_WIDTH);
-        self.width = new_width;
-        self.explorer.set_bounds(
-            self.x,
-            self.y + HEADER_HEIGHT,
-            new_width,
-            self.height - HEADER_HEIGHT,
-        );
+    pub fn is_todos_visible(&self) -> bool {
+        self.view == SidebarView::Todos
+    }
+    
+    pub fn todos(&self) -> &TodoView {
+        &self.todos
+    }
+    
+    pub fn todos_mut(&mut self) -> &mut TodoView {
+        &mut self.todos
+    }
+    
+    pub fn is_bookmarks_visible(&self) -> bool {
+        self.view == SidebarView::Bookmarks
+    }
+    
+    pub fn bookmarks(&self) -> &BookmarkView {
+        &self.bookmarks
+    }
+    
+    pub fn bookmarks_mut(&mut self) -> &mut BookmarkView {
+        &mut self.bookmarks
+    }
+    
+    pub fn is_search_focused(&self) -> bool {
+        self.is_search_visible() && self.search.is_focused()
     }
     
-    pub fn is_resizing(&self) -> bool {
-        self.is_resizing
+    /// Carry the page, the search results, the to-do list and the bookmarks over from the panel this one replaces
+    pub fn keep_search_from(&mut self, previous: &mut LeftPanel) {
+        self.view = previous.view;
+        std::mem::swap(&mut self.search, &mut previous.search);
+        std::mem::swap(&mut self.todos, &mut previous.todos);
+        std::mem::swap(&mut self.bookmarks, &mut previous.bookmarks);
+        self.search.set_bounds(self.x, self.y + HEADER_HEIGHT, self.width, self.height - HEADER_HEIGHT);
+        self.todos.set_bounds(self.x, self.y + HEADER_HEIGHT, self.width, self.height - HEADER_HEIGHT);
+        self.bookmarks.set_bounds(self.x, self.y + HEADER_HEIGHT, self.width, self.height - HEADER_HEIGHT);
+    }
+    
+    /// Scroll the page under the pointer
+    pub fn scroll(&mut self, delta: f32) {
+        match self.view {
+            SidebarView::Explorer => self.explorer.scroll(delta),
+            SidebarView::Search => self.search.scroll(delta),
+            SidebarView::Todos => self.todos.scroll(delta),
+            SidebarView::Bookmarks => self.bookmarks.scroll(delta),
+        }
     }
     
     pub fn explorer(&self) -> &Explorer {
@@ -120,28 +184,141 @@ impl LeftPanel {
         &mut self.explorer
     }
     
-    pub fn handle_mouse_press(&mut self, x: f32, y: f32) {
-        // Check if clicking on scrollbar
-        if self.explorer.is_over_scrollbar(x, y) {
-            self.explorer.start_scrollbar_drag(y);
-        }
+    /// Press on the explorer scrollbar; returns true if it took the press
+    pub fn handle_mouse_press(&mut self, x: f32, y: f32) -> bool {
+        self.view == SidebarView::Explorer && self.explorer.handle_scrollbar_press(x, y)
+    }
+    
+    pub fn handle_mouse_drag(&mut self, x: f32, y: f32) {
+        self.explorer.handle_scrollbar_drag(x, y);
     }
     
-    pub fn handle_mouse_drag(&mut self, y: f32) {
-        self.explorer.handle_scrollbar_drag(y);
+    /// Drag the pressed rows along; past a few pixels they drop into the folder under the pointer
+    pub fn drag_rows_to(&mut self, x: f32, y: f32) {
+        self.explorer.drag_to(x, y);
     }
     
-    pub fn handle_mouse_release(&mut self) {
+    /// Stop scrollbar and row drags; returns the rows dropped on a folder, if any
+    pub fn handle_mouse_release(&mut self) -> Option<(Vec<std::path::PathBuf>, std::path::PathBuf)> {
         self.explorer.stop_scrollbar_drag();
+        self.explorer.end_drag()
+    }
+    
+    /// Right-click in the tree; returns false when no row is under the mouse
+    pub fn context_select(&mut self) -> bool {
+        self.view == SidebarView::Explorer && !self.filter_hovered && self.explorer.context_select()
     }
     
     pub fn is_scrollbar_dragging(&self) -> bool {
         self.explorer.is_scrollbar_dragging()
     }
     
+    pub fn is_animating(&self) -> bool {
+        self.explorer.is_animating()
+    }
+    
     pub fn take_clicked_file(&mut self) -> Option<std::path::PathBuf> {
         self.explorer.take_clicked_file()
     }
+    
+    pub fn filter_text(&self) -> &str {
+        self.filter_input.text()
+    }
+    
+    pub fn is_filter_focused(&self) -> bool {
+        self.filter_input.is_focused()
+    }
+    
+    pub fn is_explorer_focused(&self) -> bool {
+        self.explorer.is_focused()
+    }
+    
+    /// Give the keyboard to the file tree
+    pub fn focus_explorer(&mut self) {
+        self.filter_input.set_focused(false);
+        self.explorer.set_focused(true);
+    }
+    
+    /// Give the keyboard back to the editor
+    pub fn blur(&mut self) {
+        self.filter_input.set_focused(false);
+        self.explorer.set_focused(false);
+        self.search.blur();
+    }
+    
+    /// Tree navigation keys; returns false for keys the tree doesn't use
+    pub fn explorer_key(&mut self, key: &str) -> bool {
+        self.explorer.handle_key(key)
+    }
+    
+    /// Ctrl+click or Shift+click in the tree; returns false when no row is under the mouse
+    pub fn extend_selection(&mut self, range: bool) -> bool {
+        if self.view != SidebarView::Explorer || self.filter_hovered || !self.explorer.extend_selection(range) {
+            return false;
+        }
+        self.filter_input.set_focused(false);
+        true
+    }
+    
+    /// Typeahead: jump to the row starting with the typed chars
+    pub fn explorer_char(&mut self, c: char) {
+        self.explorer.handle_char(c);
+    }
+    
+    /// Replace the filter text, e.g. to keep it when the panel is rebuilt
+    pub fn set_filter(&mut self, text: &str) {
+        self.filter_input.set_text(text.to_string());
+        self.explorer.set_filter(text);
+    }
+    
+    pub fn filter_char(&mut self, c: char) {
+        self.filter_input.handle_char(c);
+        self.explorer.set_filter(self.filter_input.text());
+    }
+    
+    pub fn filter_backspace(&mut self) {
+        self.filter_input.handle_backspace();
+        self.explorer.set_filter(self.filter_input.text());
+    }
+    
+    /// Empty the filter and give the keyboard back
+    pub fn clear_filter(&mut self) {
+        self.filter_input.set_focused(false);
+        self.set_filter("");
+    }
+    
+    /// Show where text dragged over the filter or search inputs would land; `None` clears it
+    pub fn preview_text_drop(&mut self, point: Option<(f32, f32)>, font_manager: &mut FontManager) {
+        let filter_shown = self.view == SidebarView::Explorer && self.explorer.has_root();
+        let search_shown = self.is_search_visible();
+        self.filter_input.preview_drop(point.filter(|_| filter_shown), font_manager);
+        self.search.preview_text_drop(point.filter(|_| search_shown), font_manager);
+    }
+    
+    /// Insert dragged text into the input under the point; false when there is none
+    pub fn drop_text(&mut self, x: f32, y: f32, text: &str, font_manager: &mut FontManager) -> bool {
+        if self.is_search_visible() {
+            return self.search.drop_text(x, y, text, font_manager);
+        }
+        if self.view != SidebarView::Explorer
+            || !self.explorer.has_root()
+            || !self.filter_input.drop_text(x, y, text, font_manager)
+        {
+            return false;
+        }
+        self.explorer.set_focused(false);
+        self.explorer.set_filter(self.filter_input.text());
+        true
+    }
+    
+    /// Expand the folders leading to `path` and scroll to it; clears a filter that hides it
+    pub fn reveal(&mut self, path: &Path) -> bool {
+        let revealed = self.explorer.reveal(path);
+        if revealed && !self.explorer.is_filtering() {
+            self.filter_input.set_text(String::new());
+        }
+        revealed
+    }
 }
 
 impl Widget for LeftPanel {
@@ -150,7 +327,7 @@ impl Widget for LeftPanel {
         
         // Background
         let mut bg_paint = Paint::default();
-        bg_paint.set_color(theme.card);
+        bg_paint.set_color(surface(theme.card));
         bg_paint.set_anti_alias(true);
         
         let panel_rect = Rect::from_xywh(self.x, self.y, self.width, self.height);
@@ -169,19 +346,13 @@ impl Widget for LeftPanel {
             &border_paint,
         );
         
-        // Resize handle (visual indicator when hovering)
-        if self.hover_resize || self.is_resizing {
-            let mut handle_paint = Paint::default();
-            let alpha = if self.is_resizing { 100 } else { 50 };
-            handle_paint.set_color(Color::from_argb(alpha, 100, 150, 255));
-            handle_paint.set_anti_alias(true);
-            
-            let handle_rect = self.resize_handle_rect();
-            canvas.draw_rect(handle_rect, &handle_paint);
-        }
-        
-        // Header - show "EXPLORER" label
-        let text = "EXPLORER";
+        // Header - show the page label
+        let text = match self.view {
+            SidebarView::Explorer => "EXPLORER",
+            SidebarView::Search => "SEARCH",
+            SidebarView::Todos => "TODOS",
+            SidebarView::Bookmarks => "BOOKMARKS",
+        };
         let font = font_manager.create_font(text, 11.0, 600);
         let mut text_paint = Paint::default();
         text_paint.set_color(theme.muted_foreground);
@@ -194,6 +365,19 @@ impl Widget for LeftPanel {
             &text_paint,
         );
         
+        if self.view == SidebarView::Search {
+            self.search.draw(canvas, font_manager);
+            return;
+        }
+        if self.view == SidebarView::Todos {
+            self.todos.draw(canvas, font_manager);
+            return;
+        }
+        if self.view == SidebarView::Bookmarks {
+            self.bookmarks.draw(canvas, font_manager);
+            return;
+        }
+        
         // Show current folder path if available
         if self.explorer.has_root() {
             let folder_name = self.explorer.get_root_name();
@@ -214,6 +398,10 @@ impl Widget for LeftPanel {
             );
         }
         
+        if self.explorer.has_root() {
+            self.filter_input.draw(canvas, font_manager);
+        }
+        
         // Draw explorer
         self.explorer.draw(canvas, font_manager);
     }
@@ -223,21 +411,44 @@ impl Widget for LeftPanel {
     }
     
     fn update_hover(&mut self, x: f32, y: f32) {
-        self.hover_resize = self.is_over_resize_handle(x, y);
-        
-        // Update explorer hover if not resizing
-        if !self.hover_resize {
-            self.explorer.update_hover(x, y);
+        if self.view == SidebarView::Search {
+            self.search.update_hover(x, y);
+            return;
+        }
+        if self.view == SidebarView::Todos {
+            self.todos.update_hover(x, y);
+            return;
         }
+        if self.view == SidebarView::Bookmarks {
+            self.bookmarks.update_hover(x, y);
+            return;
+        }
+        self.filter_hovered = self.explorer.has_root() && self.filter_input.hit_test(x, y);
+        self.filter_input.update_hover(x, y);
+        self.explorer.update_hover(x, y);
     }
     
     fn update_animation(&mut self, _elapsed: f32) {
+        self.filter_input.update_animation(_elapsed);
+        self.search.update_animation(_elapsed);
         self.explorer.update_animation(_elapsed);
     }
     
     fn on_click(&mut self) {
-        // Forward click to explorer
-        self.explorer.on_click();
+        if self.view != SidebarView::Explorer {
+            return;
+        }
+        self.filter_input.set_focused(self.filter_hovered);
+        if self.filter_hovered {
+            self.explorer.set_focused(false);
+        } else {
+            // Forward click to explorer
+            self.explorer.on_click();
+        }
+    }
+    
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
     }
     
     fn as_any(&self) -> &dyn std::any::Any {
@@ -3,10 +3,19 @@ pub mod rightpanel;
 pub mod bottompanel;
 pub mod statusbar;
 
-pub use leftpanel::LeftPanel;
+pub use leftpanel::{LeftPanel, SidebarView};
 pub use rightpanel::RightPanel;
-pub use bottompanel::BottomPanel;
-pub use statusbar::StatusBar;
+pub use bottompanel::{BottomPanel, BottomPanelView};
+pub use statusbar::{StatusBar, StatusBarItem};
+
+use mikoui::components::{Orientation, Splitter, SplitterSide};
+
+const SIDE_PANEL_MIN_WIDTH: f32 = 200.0;
+const SIDE_PANEL_MAX_WIDTH: f32 = 600.0;
+const SIDE_PANEL_COLLAPSE_WIDTH: f32 = 120.0;
+const BOTTOM_PANEL_MIN_HEIGHT: f32 = 100.0;
+const BOTTOM_PANEL_MAX_HEIGHT: f32 = 500.0;
+const BOTTOM_PANEL_COLLAPSE_HEIGHT: f32 = 60.0;
 
 /// Layout configuration
 #[derive(Debug, Clone)]
@@ -31,3 +40,26 @@ impl Default for LayoutConfig {
         }
     }
 }
+
+impl LayoutConfig {
+    /// Splitters for the left, right and bottom panels, seeded with the configured sizes
+    pub fn create_splitters(&self) -> (Splitter, Splitter, Splitter) {
+        let defaults = LayoutConfig::default();
+        let side_panel = |side, width: f32, default_width: f32| {
+            Splitter::new(Orientation::Horizontal, side, width)
+                .min_size(SIDE_PANEL_MIN_WIDTH)
+                .max_size(SIDE_PANEL_MAX_WIDTH)
+                .default_size(default_width)
+                .collapsible(SIDE_PANEL_COLLAPSE_WIDTH)
+        };
+        
+        let left = side_panel(SplitterSide::Start, self.left_panel_width, defaults.left_panel_width);
+        let right = side_panel(SplitterSide::End, self.right_panel_width, defaults.right_panel_width);
+        let bottom = Splitter::new(Orientation::Vertical, SplitterSide::End, self.bottom_panel_height)
+            .min_size(BOTTOM_PANEL_MIN_HEIGHT)
+            .max_size(BOTTOM_PANEL_MAX_HEIGHT)
+            .default_size(defaults.bottom_panel_height)
+            .collapsible(BOTTOM_PANEL_COLLAPSE_HEIGHT);
+        (left, right, bottom)
+    }
+}
@@ -1,18 +1,20 @@
 use mikoui::{Widget, FontManager};
-use mikoui::theme::current_theme;
-use skia_safe::{Canvas, Color, Paint, Rect};
+use mikoui::components::{CodiconIcons, Icon, IconSize};
+use mikoui::theme::{current_theme, surface, with_alpha};
+use skia_safe::{Canvas, Paint, Point, Rect};
+use crate::pages::Outline;
 
-const RESIZE_HANDLE_WIDTH: f32 = 4.0;
-const MIN_WIDTH: f32 = 200.0;
-const MAX_WIDTH: f32 = 600.0;
+const HEADER_HEIGHT: f32 = 32.0;
+const BUTTON_SIZE: f32 = 22.0;
 
 pub struct RightPanel {
     x: f32,
     y: f32,
     width: f32,
     height: f32,
-    is_resizing: bool,
-    hover_resize: bool,
+    outline: Outline,
+    // Follow Cursor toggle in the header
+    follow_hovered: bool,
 }
 
 impl RightPanel {
@@ -20,10 +22,10 @@ impl RightPanel {
         Self {
             x,
             y,
-            width: width.clamp(MIN_WIDTH, MAX_WIDTH),
+            width,
             height,
-            is_resizing: false,
-            hover_resize: false,
+            outline: Outline::new(x, y + HEADER_HEIGHT, width, height - HEADER_HEIGHT),
+            follow_hovered: false,
         }
     }
     
@@ -33,42 +35,48 @@ impl RightPanel {
     
     pub fn set_position(&mut self, x: f32) {
         self.x = x;
+        self.layout();
     }
     
     pub fn set_height(&mut self, height: f32) {
         self.height = height;
+        self.layout();
     }
     
-    pub fn resize_handle_rect(&self) -> Rect {
-        Rect::from_xywh(
-            self.x - RESIZE_HANDLE_WIDTH / 2.0,
-            self.y,
-            RESIZE_HANDLE_WIDTH,
-            self.height,
-        )
+    /// Move and resize, keeping the outline
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        (self.x, self.y, self.width, self.height) = (x, y, width, height);
+        self.layout();
     }
     
-    pub fn is_over_resize_handle(&self, x: f32, y: f32) -> bool {
-        let handle = self.resize_handle_rect();
-        x >= handle.left && x <= handle.right && y >= handle.top && y <= handle.bottom
+    fn layout(&mut self) {
+        self.outline.set_bounds(self.x, self.y + HEADER_HEIGHT, self.width, self.height - HEADER_HEIGHT);
     }
     
-    pub fn start_resize(&mut self) {
-        self.is_resizing = true;
+    pub fn outline(&self) -> &Outline {
+        &self.outline
     }
     
-    pub fn stop_resize(&mut self) {
-        self.is_resizing = false;
+    pub fn outline_mut(&mut self) -> &mut Outline {
+        &mut self.outline
     }
     
-    pub fn resize_to(&mut self, x: f32, window_width: f32) {
-        let new_width = (window_width - x).clamp(MIN_WIDTH, MAX_WIDTH);
-        self.width = new_width;
-        self.x = window_width - self.width;
+    fn follow_button_rect(&self) -> Rect {
+        Rect::from_xywh(self.x + self.width - BUTTON_SIZE - 8.0, self.y + (HEADER_HEIGHT - BUTTON_SIZE) / 2.0, BUTTON_SIZE, BUTTON_SIZE)
     }
     
-    pub fn is_resizing(&self) -> bool {
-        self.is_resizing
+    /// Press in the panel; returns the line and column of a symbol that was clicked
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> Option<(usize, usize)> {
+        if self.follow_button_rect().contains(Point::new(x, y)) {
+            let follow = !self.outline.follow_cursor();
+            self.outline.set_follow_cursor(follow);
+            return None;
+        }
+        self.outline.handle_mouse_down(x, y)
+    }
+    
+    pub fn scroll(&mut self, delta: f32) {
+        self.outline.scroll(delta);
     }
 }
 
@@ -78,7 +86,7 @@ impl Widget for RightPanel {
         
         // Background
         let mut bg_paint = Paint::default();
-        bg_paint.set_color(theme.card);
+        bg_paint.set_color(surface(theme.card));
         bg_paint.set_anti_alias(true);
         
         let panel_rect = Rect::from_xywh(self.x, self.y, self.width, self.height);
@@ -97,30 +105,33 @@ impl Widget for RightPanel {
             &border_paint,
         );
         
-        // Resize handle
-        if self.hover_resize || self.is_resizing {
-            let mut handle_paint = Paint::default();
-            let alpha = if self.is_resizing { 100 } else { 50 };
-            handle_paint.set_color(Color::from_argb(alpha, 100, 150, 255));
-            handle_paint.set_anti_alias(true);
-            
-            let handle_rect = self.resize_handle_rect();
-            canvas.draw_rect(handle_rect, &handle_paint);
-        }
-        
-        // Placeholder content
-        let text = "Right Panel";
-        let font = font_manager.create_font(text, 14.0, 400);
+        // Header with the Follow Cursor toggle
+        let text = "OUTLINE";
+        let font = font_manager.create_font(text, 11.0, 600);
         let mut text_paint = Paint::default();
         text_paint.set_color(theme.muted_foreground);
         text_paint.set_anti_alias(true);
         
         canvas.draw_str(
             text,
-            (self.x + 16.0, self.y + 32.0),
+            (self.x + 16.0, self.y + 20.0),
             &font,
             &text_paint,
         );
+        
+        let button = self.follow_button_rect();
+        let follow = self.outline.follow_cursor();
+        if follow || self.follow_hovered {
+            let mut button_paint = Paint::default();
+            button_paint.set_anti_alias(true);
+            button_paint.set_color(if follow { with_alpha(theme.primary, 60) } else { theme.muted });
+            canvas.draw_round_rect(button, 4.0, 4.0, &button_paint);
+        }
+        let offset = (BUTTON_SIZE - IconSize::Small.as_f32()) / 2.0;
+        Icon::new(button.left + offset, button.top + offset, CodiconIcons::LOCATION, IconSize::Small, theme.foreground)
+            .draw(canvas, font_manager);
+        
+        self.outline.draw(canvas, font_manager);
     }
     
     fn contains(&self, x: f32, y: f32) -> bool {
@@ -128,7 +139,8 @@ impl Widget for RightPanel {
     }
     
     fn update_hover(&mut self, x: f32, y: f32) {
-        self.hover_resize = self.is_over_resize_handle(x, y);
+        self.follow_hovered = self.follow_button_rect().contains(Point::new(x, y));
+        self.outline.update_hover(x, y);
     }
     
     fn update_animation(&mut self, _elapsed: f32) {
@@ -139,6 +151,10 @@ impl Widget for RightPanel {
         // Handle clicks if needed
     }
     
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+    
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1,5 +1,20 @@
-use mikoui::{current_theme, Widget};
-use skia_safe::{Canvas, Font, Paint, Rect};
+use mikoui::{current_theme, t, with_alpha, Widget};
+use mikoui::components::{CodiconIcons, Icon, IconSize};
+use mikocore::{char_category, char_name, code_point_label};
+use skia_safe::{Canvas, Font, Paint, PaintStyle, Rect};
+
+/// Clickable status bar entries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusBarItem {
+    /// Git branch of the opened folder, shown only inside a repository
+    Branch,
+    Language,
+    /// Code point of the character at the cursor, shown only when there is one
+    Character,
+    Indentation,
+    Encoding,
+    LineEnding,
+}
 
 pub struct StatusBar {
     x: f32,
@@ -9,10 +24,25 @@ pub struct StatusBar {
     language: String,
     cursor_line: usize,
     cursor_column: usize,
+    indentation: String,
+    encoding: String,
+    line_ending: String,
+    pending_saves: usize, // Modified files auto save hasn't written yet
+    branch: Option<String>, // Git branch with its ahead and behind counts; None outside a repository
+    git_busy: bool, // A pull, push or other git command is running
+    character: Option<char>, // Character right of the cursor; None at the end of a line
+    hovered_item: Option<StatusBarItem>,
 }
 
 impl StatusBar {
     const HEIGHT: f32 = 24.0;
+    const BRANCH_SLOT_WIDTH: f32 = 160.0;
+    const LANGUAGE_SLOT_WIDTH: f32 = 110.0;
+    const CURSOR_SLOT_WIDTH: f32 = 120.0;
+    const CHARACTER_SLOT_WIDTH: f32 = 70.0;
+    const INDENTATION_SLOT_WIDTH: f32 = 90.0;
+    const ENCODING_SLOT_WIDTH: f32 = 110.0;
+    const LINE_ENDING_SLOT_WIDTH: f32 = 50.0;
     
     pub fn new(x: f32, y: f32, width: f32) -> Self {
         Self {
@@ -23,6 +53,14 @@ impl StatusBar {
             language: "Text".to_string(),
             cursor_line: 1,
             cursor_column: 1,
+            indentation: "Spaces: 4".to_string(),
+            encoding: "UTF-8".to_string(),
+            line_ending: "LF".to_string(),
+            pending_saves: 0,
+            branch: None,
+            git_busy: false,
+            character: None,
+            hovered_item: None,
         }
     }
     
@@ -41,6 +79,138 @@ impl StatusBar {
         self.cursor_line = cursor_line;
         self.cursor_column = cursor_column;
     }
+    
+    pub fn update_document_info(&mut self, encoding: &str, line_ending: &str) {
+        self.encoding = encoding.to_string();
+        self.line_ending = line_ending.to_string();
+    }
+    
+    pub fn update_indentation(&mut self, indentation: &str) {
+        self.indentation = indentation.to_string();
+    }
+    
+    /// Files waiting for auto save; 0 hides the indicator
+    pub fn update_pending_saves(&mut self, pending: usize) {
+        self.pending_saves = pending;
+    }
+    
+    /// Character at the cursor, described by the code point item and its hover card
+    pub fn update_character(&mut self, character: Option<char>) {
+        self.character = character;
+    }
+    
+    /// Git branch label, e.g. `main 2↓ 1↑`, and whether a git command is running; None hides it
+    pub fn update_branch(&mut self, branch: Option<String>, busy: bool) {
+        self.branch = branch;
+        self.git_busy = busy;
+    }
+    
+    /// Left edge of the language, right of the branch when there is one
+    fn language_x(&self) -> f32 {
+        self.x + if self.branch.is_some() { Self::BRANCH_SLOT_WIDTH } else { 0.0 }
+    }
+    
+    /// Bounds of a clickable item; the branch and language sit at the left edge, the rest left of the cursor position
+    fn item_rect(&self, item: StatusBarItem) -> Rect {
+        let line_ending_x = self.x + self.width - 10.0 - Self::CURSOR_SLOT_WIDTH - Self::LINE_ENDING_SLOT_WIDTH;
+        let encoding_x = line_ending_x - Self::ENCODING_SLOT_WIDTH;
+        match item {
+            StatusBarItem::Branch => Rect::from_xywh(self.x, self.y, Self::BRANCH_SLOT_WIDTH, self.height),
+            StatusBarItem::Language => Rect::from_xywh(self.language_x(), self.y, Self::LANGUAGE_SLOT_WIDTH, self.height),
+            StatusBarItem::LineEnding => {
+                Rect::from_xywh(line_ending_x, self.y, Self::LINE_ENDING_SLOT_WIDTH, self.height)
+            }
+            StatusBarItem::Encoding => {
+                Rect::from_xywh(encoding_x, self.y, Self::ENCODING_SLOT_WIDTH, self.height)
+            }
+            StatusBarItem::Indentation => Rect::from_xywh(
+                encoding_x - Self::INDENTATION_SLOT_WIDTH,
+                self.y,
+                Self::INDENTATION_SLOT_WIDTH,
+                self.height,
+            ),
+            StatusBarItem::Character => Rect::from_xywh(
+                encoding_x - Self::INDENTATION_SLOT_WIDTH - Self::CHARACTER_SLOT_WIDTH,
+                self.y,
+                Self::CHARACTER_SLOT_WIDTH,
+                self.height,
+            ),
+        }
+    }
+    
+    /// Get the clickable item at a position
+    pub fn item_at(&self, x: f32, y: f32) -> Option<StatusBarItem> {
+        [StatusBarItem::Branch, StatusBarItem::Language, StatusBarItem::Character, StatusBarItem::Indentation, StatusBarItem::Encoding, StatusBarItem::LineEnding]
+            .into_iter()
+            .filter(|item| *item != StatusBarItem::Branch || self.branch.is_some())
+            .filter(|item| *item != StatusBarItem::Character || self.character.is_some())
+            .find(|item| self.item_rect(*item).contains(skia_safe::Point::new(x, y)))
+    }
+    
+    fn draw_item(&self, canvas: &Canvas, font: &Font, text_paint: &Paint, item: StatusBarItem, label: &str) {
+        let rect = self.item_rect(item);
+        
+        if self.hovered_item == Some(item) {
+            let mut hover_paint = Paint::default();
+            hover_paint.set_color(with_alpha(current_theme().primary_foreground, 30));
+            hover_paint.set_anti_alias(true);
+            canvas.draw_rect(rect, &hover_paint);
+        }
+        
+        let label_width = font.measure_str(label, None).0;
+        canvas.draw_str(
+            label,
+            (rect.left + (rect.width() - label_width) / 2.0, self.y + 16.0),
+            font,
+            text_paint,
+        );
+    }
+    
+    /// Card above the code point item with the character, its name and its category
+    fn draw_character_card(&self, canvas: &Canvas, font_manager: &mut mikoui::FontManager, c: char) {
+        const PADDING: f32 = 10.0;
+        const LINE_HEIGHT: f32 = 18.0;
+        let theme = current_theme();
+        let glyph = if c.is_control() || c.is_whitespace() { String::new() } else { c.to_string() };
+        let title = format!("{}  {}", glyph, code_point_label(c)).trim_start().to_string();
+        let name = char_name(c).unwrap_or_else(|| t!("unicode.unnamed"));
+        let category = char_category(c);
+        
+        // The title's font falls back to one with the glyph, e.g. for emoji
+        let title_font = font_manager.create_font(&title, 15.0, 600);
+        let font = font_manager.create_font(&name, 12.0, 400);
+        let width = [
+            title_font.measure_str(&title, None).0,
+            font.measure_str(&name, None).0,
+            font.measure_str(category, None).0,
+        ]
+        .into_iter()
+        .fold(0.0, f32::max)
+            + PADDING * 2.0;
+        let height = LINE_HEIGHT * 3.0 + PADDING * 2.0 - 4.0;
+        let item = self.item_rect(StatusBarItem::Character);
+        let x = item.left.min(self.x + self.width - width - 4.0).max(self.x + 4.0);
+        let rect = Rect::from_xywh(x, self.y - height - 4.0, width, height);
+        
+        let mut background = Paint::default();
+        background.set_anti_alias(true);
+        background.set_color(theme.popover);
+        canvas.draw_round_rect(rect, 6.0, 6.0, &background);
+        let mut border = Paint::default();
+        border.set_anti_alias(true);
+        border.set_style(PaintStyle::Stroke);
+        border.set_color(theme.border);
+        canvas.draw_round_rect(rect, 6.0, 6.0, &border);
+        
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        text_paint.set_color(theme.popover_foreground);
+        let baseline = rect.top + PADDING + 12.0;
+        canvas.draw_str(&title, (rect.left + PADDING, baseline), &title_font, &text_paint);
+        canvas.draw_str(&name, (rect.left + PADDING, baseline + LINE_HEIGHT), &font, &text_paint);
+        text_paint.set_color(theme.muted_foreground);
+        canvas.draw_str(category, (rect.left + PADDING, baseline + LINE_HEIGHT * 2.0), &font, &text_paint);
+    }
 }
 
 impl Widget for StatusBar {
@@ -64,13 +234,35 @@ impl Widget for StatusBar {
         text_paint.set_color(theme.primary_foreground);
         text_paint.set_anti_alias(true);
         
-        // Language indicator (left side)
-        canvas.draw_str(
-            &self.language,
-            (self.x + 10.0, self.y + 16.0),
-            &font,
-            &text_paint,
-        );
+        // Git branch (left edge, click to switch), with a sync icon while a git command runs
+        if let Some(ref branch) = self.branch {
+            let rect = self.item_rect(StatusBarItem::Branch);
+            if self.hovered_item == Some(StatusBarItem::Branch) {
+                let mut hover_paint = Paint::default();
+                hover_paint.set_color(with_alpha(theme.primary_foreground, 30));
+                canvas.draw_rect(rect, &hover_paint);
+            }
+            let icon = if self.git_busy { CodiconIcons::SYNC } else { CodiconIcons::GIT_BRANCH };
+            let size = IconSize::Small.as_f32();
+            Icon::new(rect.left + 10.0, self.y + (self.height - size) / 2.0, icon, IconSize::Small, theme.primary_foreground).paint(canvas);
+            canvas.save();
+            canvas.clip_rect(rect, None, true);
+            canvas.draw_str(branch, (rect.left + 14.0 + size, self.y + 16.0), &font, &text_paint);
+            canvas.restore();
+        }
+        
+        // Language mode (left side, click to change)
+        self.draw_item(canvas, &font, &text_paint, StatusBarItem::Language, &self.language);
+        
+        // Auto save indicator, next to the language
+        if self.pending_saves > 0 {
+            let pending = if self.pending_saves == 1 {
+                "\u{25CF} Auto Save pending".to_string()
+            } else {
+                format!("\u{25CF} Auto Save pending ({})", self.pending_saves)
+            };
+            canvas.draw_str(&pending, (self.language_x() + Self::LANGUAGE_SLOT_WIDTH + 10.0, self.y + 16.0), &font, &text_paint);
+        }
         
         // Cursor position (right side)
         let cursor_info = format!("Ln {}, Col {}", self.cursor_line, self.cursor_column);
@@ -81,14 +273,27 @@ impl Widget for StatusBar {
             &font,
             &text_paint,
         );
+        
+        // Indentation, encoding and line ending (click to change)
+        self.draw_item(canvas, &font, &text_paint, StatusBarItem::Indentation, &self.indentation);
+        self.draw_item(canvas, &font, &text_paint, StatusBarItem::Encoding, &self.encoding);
+        self.draw_item(canvas, &font, &text_paint, StatusBarItem::LineEnding, &self.line_ending);
+        
+        // Code point of the character at the cursor (hover for its name, click to insert one)
+        if let Some(c) = self.character {
+            self.draw_item(canvas, &font, &text_paint, StatusBarItem::Character, &code_point_label(c));
+            if self.hovered_item == Some(StatusBarItem::Character) {
+                self.draw_character_card(canvas, font_manager, c);
+            }
+        }
     }
     
-    fn update_hover(&mut self, _x: f32, _y: f32) {
-        // Status bar doesn't have hover states
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hovered_item = self.item_at(x, y);
     }
     
     fn on_click(&mut self) {
-        // Status bar doesn't handle clicks
+        // Clicks are resolved by the app through item_at
     }
     
     fn contains(&self, x: f32, y: f32) -> bool {
@@ -99,6 +304,10 @@ impl Widget for StatusBar {
         // No animations
     }
     
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+    
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
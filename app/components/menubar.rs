@@ -1,7 +1,7 @@
 use skia_safe::{Canvas, Color, Paint, Rect, Image, Data};
-use mikoui::components::{MenuItem, Widget};
-use mikoui::core::FontManager;
-use mikoui::theme::current_theme;
+use mikoui::components::{CodiconIcons, Icon, IconSize, MenuItem, Widget};
+use mikoui::core::{animation_rate, FontManager};
+use mikoui::theme::{current_theme, surface};
 
 // Embed the app logo
 const APP_LOGO: &[u8] = include_bytes!("../assets/logo.png");
@@ -33,14 +33,20 @@ pub struct MenuBar {
     hover_progress: Vec<f32>,
     item_hover_progress: Vec<f32>,
     app_logo: std::cell::RefCell<Option<std::sync::Arc<Image>>>,
+    // Menus folded behind a hamburger button when the titlebar is too narrow
+    collapsed: bool,
+    hover_hamburger: bool,
+    // Top-level menus listed by the hamburger button, opened as menu index `menus.len()`
+    overflow_items: Vec<MenuItem>,
 }
 
 impl MenuBar {
     pub fn new(x: f32, y: f32, width: f32, menus: Vec<MenuBarItem>) -> Self {
-        let max_items = menus.iter().map(|m| m.items.len()).max().unwrap_or(0);
+        let max_items = menus.iter().map(|m| m.items.len()).max().unwrap_or(0).max(menus.len());
         let hover_progress = vec![0.0; menus.len()];
         let item_hover_progress = vec![0.0; max_items];
         let menu_widths = vec![0.0; menus.len()];
+        let overflow_items = Self::overflow_items(&menus);
         
         Self {
             x,
@@ -55,6 +61,36 @@ impl MenuBar {
             hover_progress,
             item_hover_progress,
             app_logo: std::cell::RefCell::new(None),
+            collapsed: false,
+            hover_hamburger: false,
+            overflow_items,
+        }
+    }
+    
+    fn overflow_items(menus: &[MenuBarItem]) -> Vec<MenuItem> {
+        menus.iter().enumerate().map(|(i, menu)| MenuItem::new(menu.label.clone(), i)).collect()
+    }
+    
+    /// Replace the menus, e.g. after switching the display language
+    pub fn set_menus(&mut self, menus: Vec<MenuBarItem>) {
+        let max_items = menus.iter().map(|m| m.items.len()).max().unwrap_or(0).max(menus.len());
+        self.hover_progress = vec![0.0; menus.len()];
+        self.item_hover_progress = vec![0.0; max_items];
+        self.menu_widths = vec![0.0; menus.len()];
+        self.overflow_items = Self::overflow_items(&menus);
+        self.menus = menus;
+        self.active_menu = None;
+        self.hover_menu = None;
+        self.hover_item = None;
+    }
+    
+    /// Fold the menus behind a hamburger button, for narrow windows
+    pub fn set_collapsed(&mut self, collapsed: bool) {
+        if self.collapsed != collapsed {
+            self.collapsed = collapsed;
+            self.active_menu = None;
+            self.hover_menu = None;
+            self.hover_item = None;
         }
     }
     
@@ -94,6 +130,22 @@ impl MenuBar {
 
     const LOGO_SIZE: f32 = 16.0;
     const LOGO_PADDING: f32 = 8.0;
+    const HAMBURGER_WIDTH: f32 = 32.0;
+    /// Width of the logo and hamburger button while collapsed
+    pub const COLLAPSED_WIDTH: f32 = Self::LOGO_SIZE + Self::LOGO_PADDING * 2.0 + Self::HAMBURGER_WIDTH;
+
+    fn hamburger_rect(&self) -> Rect {
+        Rect::from_xywh(self.x + Self::LOGO_SIZE + Self::LOGO_PADDING * 2.0, self.y, Self::HAMBURGER_WIDTH, self.height)
+    }
+
+    /// Items of menu `menu_index`; one past the last menu is the hamburger button's list of menus
+    fn menu_items(&self, menu_index: usize) -> &[MenuItem] {
+        match self.menus.get(menu_index) {
+            Some(menu) => &menu.items,
+            None if self.collapsed && menu_index == self.menus.len() => &self.overflow_items,
+            None => &[],
+        }
+    }
 
     fn calculate_menu_width(&self, menu_label: &str, font_manager: &mut FontManager) -> f32 {
         let font = font_manager.create_font(menu_label, 11.0, 400);
@@ -103,6 +155,10 @@ impl MenuBar {
     }
 
     fn menu_item_rect(&self, index: usize, font_manager: &mut FontManager) -> Rect {
+        // Every dropdown opens under the hamburger button while collapsed
+        if self.collapsed {
+            return self.hamburger_rect();
+        }
         // Start after the logo
         let mut x = self.x + Self::LOGO_SIZE + Self::LOGO_PADDING * 2.0;
         for i in 0..index {
@@ -129,10 +185,13 @@ impl MenuBar {
     pub fn update_hover_with_font(&mut self, x: f32, y: f32, font_manager: &mut FontManager) {
         // Check menubar items with proper width calculation
         self.hover_menu = None;
+        self.hover_hamburger = false;
         
         // Skip hover if over logo area
         let logo_end_x = self.x + Self::LOGO_SIZE + Self::LOGO_PADDING * 2.0;
-        if x >= self.x && x < logo_end_x && y >= self.y && y <= self.y + self.height {
+        if self.collapsed {
+            self.hover_hamburger = self.hamburger_rect().contains(skia_safe::Point::new(x, y));
+        } else if x >= self.x && x < logo_end_x && y >= self.y && y <= self.y + self.height {
             // Over logo, no menu hover
         } else {
             for i in 0..self.menus.len() {
@@ -147,34 +206,33 @@ impl MenuBar {
         // Check dropdown items
         self.hover_item = None;
         if let Some(menu_index) = self.active_menu {
-            if menu_index < self.menus.len() {
-                for i in 0..self.menus[menu_index].items.len() {
-                    let item_rect = self.dropdown_item_rect(menu_index, i, font_manager);
-                    if x >= item_rect.left && x <= item_rect.right && y >= item_rect.top && y <= item_rect.bottom {
-                        if !self.menus[menu_index].items[i].separator {
-                            self.hover_item = Some(i);
-                        }
-                        break;
+            for i in 0..self.menu_items(menu_index).len() {
+                let item_rect = self.dropdown_item_rect(menu_index, i, font_manager);
+                if x >= item_rect.left && x <= item_rect.right && y >= item_rect.top && y <= item_rect.bottom {
+                    if !self.menu_items(menu_index)[i].separator {
+                        self.hover_item = Some(i);
                     }
+                    break;
                 }
             }
         }
     }
 
     fn dropdown_rect(&self, menu_index: usize, font_manager: &mut FontManager) -> Rect {
-        if menu_index >= self.menus.len() {
+        let items = self.menu_items(menu_index);
+        if items.is_empty() {
             return Rect::default();
         }
 
         let menu_rect = self.menu_item_rect(menu_index, font_manager);
         let item_height = 32.0;
-        let height = self.menus[menu_index].items.iter().map(|item| {
+        let height = items.iter().map(|item| {
             if item.separator { 9.0 } else { item_height }
         }).sum::<f32>();
 
         // Calculate the maximum width needed for all items
         let mut max_width: f32 = 180.0; // Minimum width
-        for item in &self.menus[menu_index].items {
+        for item in items {
             if !item.separator {
                 // Measure label width
                 let label_font = font_manager.create_font(&item.label, 12.0, 400);
@@ -207,16 +265,17 @@ impl MenuBar {
         let item_height = 32.0;
         let separator_height = 9.0;
 
+        let items = self.menu_items(menu_index);
         let mut y = dropdown.top;
-        for i in 0..item_index {
-            if self.menus[menu_index].items[i].separator {
+        for item in &items[..item_index] {
+            if item.separator {
                 y += separator_height;
             } else {
                 y += item_height;
             }
         }
 
-        let height = if self.menus[menu_index].items[item_index].separator {
+        let height = if items[item_index].separator {
             separator_height
         } else {
             item_height
@@ -242,6 +301,27 @@ impl MenuBar {
             canvas.draw_image_rect(logo.as_ref(), None, dest_rect, &paint);
         }
 
+        // Hamburger button in place of the menus
+        if self.collapsed {
+            let rect = self.hamburger_rect();
+            if self.hover_hamburger || self.active_menu.is_some() {
+                let mut hover_paint = Paint::default();
+                hover_paint.set_color(Color::from_argb(80, colors.muted.r(), colors.muted.g(), colors.muted.b()));
+                hover_paint.set_anti_alias(true);
+                canvas.draw_rect(rect, &hover_paint);
+            }
+            let icon_size = IconSize::Small.as_f32();
+            Icon::new(
+                rect.left + (rect.width() - icon_size) / 2.0,
+                rect.top + (rect.height() - icon_size) / 2.0,
+                CodiconIcons::MENU,
+                IconSize::Small,
+                colors.foreground,
+            )
+            .draw(canvas, font_manager);
+            return;
+        }
+
         // Draw menu items
         for (i, menu) in self.menus.iter().enumerate() {
             let menu_rect = self.menu_item_rect(i, font_manager);
@@ -276,7 +356,7 @@ impl MenuBar {
 
         // Draw active dropdown
         if let Some(menu_index) = self.active_menu {
-            if menu_index < self.menus.len() {
+            if !self.menu_items(menu_index).is_empty() {
                 let dropdown_rect = self.dropdown_rect(menu_index, font_manager);
 
                 // Draw shadow with blur effect
@@ -300,55 +380,11 @@ impl MenuBar {
                 }
                 canvas.draw_round_rect(shadow_rect, 6.0, 6.0, &shadow_paint);
 
-                // Mica Effect: Multi-layer translucent background
-                let card_color = colors.card;
-                
-                // Layer 1: Base translucent layer (Mica base)
-                let mut base_layer = Paint::default();
-                base_layer.set_color(Color::from_argb(
-                    200, // 78% opacity for Mica effect
-                    card_color.r(),
-                    card_color.g(),
-                    card_color.b(),
-                ));
-                base_layer.set_anti_alias(true);
-                canvas.draw_round_rect(dropdown_rect, 6.0, 6.0, &base_layer);
-                
-                // Layer 2: Subtle tint overlay for depth
-                let mut tint_layer = Paint::default();
-                let tint_alpha = 15; // Very subtle tint
-                tint_layer.set_color(Color::from_argb(
-                    tint_alpha,
-                    card_color.r().saturating_add(10),
-                    card_color.g().saturating_add(10),
-                    card_color.b().saturating_add(10),
-                ));
-                tint_layer.set_anti_alias(true);
-                canvas.draw_round_rect(dropdown_rect, 6.0, 6.0, &tint_layer);
-                
-                // Layer 3: Noise texture for Mica material feel
-                // Create a subtle noise pattern
-                canvas.save();
-                let rrect = skia_safe::RRect::new_rect_xy(dropdown_rect, 6.0, 6.0);
-                canvas.clip_rrect(rrect, None, Some(true));
-                
-                let mut noise_paint = Paint::default();
-                noise_paint.set_anti_alias(true);
-                
-                // Draw subtle noise dots
-                for y in (dropdown_rect.top as i32..dropdown_rect.bottom as i32).step_by(3) {
-                    for x in (dropdown_rect.left as i32..dropdown_rect.right as i32).step_by(3) {
-                        // Pseudo-random noise based on position
-                        let noise_val = ((x * 7 + y * 13) % 255) as u8;
-                        if noise_val > 200 { // Only draw ~20% of pixels for subtle effect
-                            let alpha = (noise_val as f32 / 255.0 * 8.0) as u8; // Very low opacity
-                            noise_paint.set_color(Color::from_argb(alpha, 255, 255, 255));
-                            canvas.draw_circle((x as f32, y as f32), 0.5, &noise_paint);
-                        }
-                    }
-                }
-                
-                canvas.restore();
+                // Translucent over the window material when one is active
+                let mut background = Paint::default();
+                background.set_color(surface(colors.card));
+                background.set_anti_alias(true);
+                canvas.draw_round_rect(dropdown_rect, 6.0, 6.0, &background);
 
                 // Draw border
                 let mut dropdown_border = Paint::default();
@@ -359,7 +395,7 @@ impl MenuBar {
                 canvas.draw_round_rect(dropdown_rect, 6.0, 6.0, &dropdown_border);
 
                 // Draw menu items
-                for (i, item) in self.menus[menu_index].items.iter().enumerate() {
+                for (i, item) in self.menu_items(menu_index).iter().enumerate() {
                     let item_rect = self.dropdown_item_rect(menu_index, i, font_manager);
 
                     if item.separator {
@@ -420,6 +456,18 @@ impl MenuBar {
                             text_paint.set_anti_alias(true);
                             canvas.draw_str(shortcut, (shortcut_x, text_y), &font, &text_paint);
                         }
+
+                        // Entries of the hamburger list open a menu
+                        if menu_index == self.menus.len() {
+                            Icon::new(
+                                item_rect.right - 12.0 - IconSize::Small.as_f32(),
+                                item_rect.top + (item_rect.height() - IconSize::Small.as_f32()) / 2.0,
+                                CodiconIcons::CHEVRON_RIGHT,
+                                IconSize::Small,
+                                colors.muted_foreground,
+                            )
+                            .draw(canvas, font_manager);
+                        }
                     }
                 }
             }
@@ -436,7 +484,11 @@ impl Widget for MenuBar {
 
     fn contains(&self, x: f32, y: f32) -> bool {
         // Check menubar - only the area where menu items are
-        if y >= self.y && y <= self.y + self.height {
+        if self.collapsed {
+            if self.hamburger_rect().contains(skia_safe::Point::new(x, y)) {
+                return true;
+            }
+        } else if y >= self.y && y <= self.y + self.height {
             // Check if within any menu item bounds (approximate)
             let mut current_x = self.x;
             for menu in &self.menus {
@@ -505,7 +557,7 @@ impl Widget for MenuBar {
                 0.0
             };
             if (self.hover_progress[i] - target).abs() > 0.01 {
-                self.hover_progress[i] += (target - self.hover_progress[i]) * animation_speed;
+                self.hover_progress[i] += (target - self.hover_progress[i]) * animation_rate(animation_speed);
             } else {
                 self.hover_progress[i] = target;
             }
@@ -515,7 +567,7 @@ impl Widget for MenuBar {
         for i in 0..self.item_hover_progress.len() {
             let target = if self.hover_item == Some(i) { 1.0 } else { 0.0 };
             if (self.item_hover_progress[i] - target).abs() > 0.01 {
-                self.item_hover_progress[i] += (target - self.item_hover_progress[i]) * animation_speed;
+                self.item_hover_progress[i] += (target - self.item_hover_progress[i]) * animation_rate(animation_speed);
             } else {
                 self.item_hover_progress[i] = target;
             }
@@ -547,6 +599,10 @@ impl Widget for MenuBar {
         }
     }
 
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -560,6 +616,24 @@ impl Widget for MenuBar {
 impl MenuBar {
     /// Handle click and return the clicked item ID if a menu item was clicked
     pub fn handle_click(&mut self) -> Option<i32> {
+        // The hamburger button toggles its list of menus
+        if self.hover_hamburger {
+            self.active_menu = match self.active_menu {
+                Some(_) => None,
+                None => Some(self.menus.len()),
+            };
+            return None;
+        }
+        
+        // Picking from that list opens the menu in its place
+        if self.active_menu == Some(self.menus.len()) {
+            if let Some(menu_index) = self.hover_item {
+                self.active_menu = Some(menu_index);
+                self.hover_item = None;
+            }
+            return None;
+        }
+        
         // Check if clicking on menubar item
         if let Some(menu_index) = self.hover_menu {
             if self.active_menu == Some(menu_index) {
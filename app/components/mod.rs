@@ -3,9 +3,15 @@ pub mod titlebar;
 pub mod menubar;
 pub mod layouts;
 pub mod command;
+mod gotoline;
+mod findbytes;
+mod perfoverlay;
 
 pub use activitybar::{ActivityBar, ActivityBarItem};
 pub use titlebar::{TitleBar, WindowControl, LayoutButton};
 pub use menubar::{MenuBar, MenuBarItem};
-pub use layouts::{LeftPanel, RightPanel, BottomPanel, StatusBar, LayoutConfig};
-pub use command::{CommandPalette, CommandItem};
+pub use layouts::{LeftPanel, SidebarView, RightPanel, BottomPanel, BottomPanelView, StatusBar, StatusBarItem, LayoutConfig};
+pub use command::{CommandPalette, CommandItem, PaletteMode, PALETTE_LINE_ID};
+pub use gotoline::{GoToLine, parse_line_target};
+pub use findbytes::FindBytes;
+pub use perfoverlay::PerfOverlay;
@@ -0,0 +1,134 @@
+use mikoui::{FontManager, Profiler, Sparkline, Theme, Widget};
+use mikoui::theme::{current_theme, with_alpha};
+use skia_safe::{Canvas, Paint, Rect};
+
+/// Debug HUD with FPS, a frame time graph and per-phase timings
+pub struct PerfOverlay {
+    x: f32,
+    y: f32,
+    visible: bool,
+    frame_graph: Sparkline,
+    widget_count: usize,
+}
+
+impl PerfOverlay {
+    const WIDTH: f32 = 220.0;
+    const PADDING: f32 = 10.0;
+    const LINE_HEIGHT: f32 = 16.0;
+    const GRAPH_HEIGHT: f32 = 40.0;
+
+    pub fn new(screen_width: f32, top: f32) -> Self {
+        let x = screen_width - Self::WIDTH - 12.0;
+        let y = top + 12.0;
+        Self {
+            x,
+            y,
+            visible: false,
+            frame_graph: Sparkline::new(
+                x + Self::PADDING,
+                y + Self::PADDING + Self::LINE_HEIGHT * 2.0,
+                Self::WIDTH - Self::PADDING * 2.0,
+                Self::GRAPH_HEIGHT,
+            )
+            .max_points(120)
+            .color(Theme::SUCCESS),
+            widget_count: 0,
+        }
+    }
+
+    /// Keep the overlay pinned to the right edge after a resize
+    pub fn set_screen_width(&mut self, screen_width: f32) {
+        self.x = screen_width - Self::WIDTH - 12.0;
+        self.frame_graph.set_bounds(
+            self.x + Self::PADDING,
+            self.y + Self::PADDING + Self::LINE_HEIGHT * 2.0,
+            Self::WIDTH - Self::PADDING * 2.0,
+            Self::GRAPH_HEIGHT,
+        );
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+        self.frame_graph.set_values(&[]);
+    }
+
+    /// Feed the latest frame into the graph; call once per frame while visible
+    pub fn update(&mut self, profiler: &Profiler, widget_count: usize) {
+        if let Some(frame) = profiler.last_frame() {
+            self.frame_graph.push(frame.frame_time.as_secs_f32() * 1000.0);
+        }
+        self.widget_count = widget_count;
+    }
+
+    pub fn draw(&self, canvas: &Canvas, font_manager: &mut FontManager, profiler: &Profiler) {
+        if !self.visible {
+            return;
+        }
+
+        let colors = current_theme();
+        let phases = profiler.span_names();
+        let height = Self::PADDING * 2.0
+            + Self::LINE_HEIGHT * 2.0
+            + Self::GRAPH_HEIGHT
+            + Self::LINE_HEIGHT * (phases.len() + 1) as f32
+            + 4.0;
+        let rect = Rect::from_xywh(self.x, self.y, Self::WIDTH, height);
+
+        let mut bg_paint = Paint::default();
+        bg_paint.set_anti_alias(true);
+        bg_paint.set_color(with_alpha(colors.popover, 230));
+        canvas.draw_round_rect(rect, Theme::RADIUS_MD, Theme::RADIUS_MD, &bg_paint);
+
+        let mut border_paint = Paint::default();
+        border_paint.set_anti_alias(true);
+        border_paint.set_style(skia_safe::PaintStyle::Stroke);
+        border_paint.set_stroke_width(1.0);
+        border_paint.set_color(colors.border);
+        canvas.draw_round_rect(rect.with_inset((0.5, 0.5)), Theme::RADIUS_MD, Theme::RADIUS_MD, &border_paint);
+
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        let left = self.x + Self::PADDING;
+        let right = self.x + Self::WIDTH - Self::PADDING;
+        let mut baseline = self.y + Self::PADDING + 12.0;
+
+        // Headline: FPS and the last frame time
+        let frame_ms = profiler.last_frame().map(|frame| frame.frame_time.as_secs_f32() * 1000.0).unwrap_or(0.0);
+        let fps = format!("{:.0} FPS", profiler.fps());
+        let font = font_manager.create_font(&fps, 13.0, 600);
+        text_paint.set_color(colors.foreground);
+        canvas.draw_str(&fps, (left, baseline), &font, &text_paint);
+        let frame = format!("{:.2} ms", frame_ms);
+        let font = font_manager.create_font(&frame, 12.0, 400);
+        let (frame_width, _) = font.measure_str(&frame, None);
+        text_paint.set_color(colors.muted_foreground);
+        canvas.draw_str(&frame, (right - frame_width, baseline), &font, &text_paint);
+
+        baseline += Self::LINE_HEIGHT;
+        let caption = "Frame time";
+        let font = font_manager.create_font(caption, 11.0, 400);
+        canvas.draw_str(caption, (left, baseline), &font, &text_paint);
+
+        self.frame_graph.draw(canvas, font_manager);
+        baseline += Self::GRAPH_HEIGHT + Self::LINE_HEIGHT + 4.0;
+
+        // Average per phase, then the widget count
+        let rows = phases
+            .iter()
+            .map(|name| (name.to_string(), format!("{:.2} ms", profiler.average_ms(name))))
+            .chain(std::iter::once(("widgets".to_string(), self.widget_count.to_string())));
+        for (label, value) in rows {
+            let font = font_manager.create_font(&label, 12.0, 400);
+            text_paint.set_color(colors.muted_foreground);
+            canvas.draw_str(&label, (left, baseline), &font, &text_paint);
+            let (value_width, _) = font.measure_str(&value, None);
+            text_paint.set_color(colors.foreground);
+            canvas.draw_str(&value, (right - value_width, baseline), &font, &text_paint);
+            baseline += Self::LINE_HEIGHT;
+        }
+    }
+}
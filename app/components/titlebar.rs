@@ -1,7 +1,8 @@
 use skia_safe::{Canvas, Color, Paint, Rect};
-use mikoui::core::FontManager;
+use mikoui::core::{animation_rate, FontManager};
 use mikoui::components::{Widget, Icon, IconSize, CodiconIcons};
-use mikoui::theme::current_theme;
+use mikoui::theme::{current_theme, surface};
+use super::MenuBar;
 
 #[cfg(target_os = "windows")]
 use windows::Win32::{
@@ -12,6 +13,15 @@ use windows::Win32::{
     },
 };
 
+const LAYOUT_BUTTONS_WIDTH: f32 = 100.0;
+/// Back and forward buttons left of the search field, with their gaps
+const NAV_WIDTH: f32 = 60.0;
+const MAX_SEARCH_WIDTH: f32 = 400.0;
+/// Narrower than this the search field turns into an icon button
+const MIN_SEARCH_WIDTH: f32 = 160.0;
+const COMPACT_SEARCH_WIDTH: f32 = 28.0;
+const SEARCH_HEIGHT: f32 = 26.0;
+
 /// Window control button types
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum WindowControl {
@@ -254,14 +264,14 @@ impl Widget for WindowControlButton {
         
         let target_hover = if self.hover { 1.0 } else { 0.0 };
         if (self.hover_progress - target_hover).abs() > 0.01 {
-            self.hover_progress += (target_hover - self.hover_progress) * animation_speed;
+            self.hover_progress += (target_hover - self.hover_progress) * animation_rate(animation_speed);
         } else {
             self.hover_progress = target_hover;
         }
         
         let target_active = if self.active { 1.0 } else { 0.0 };
         if (self.active_progress - target_active).abs() > 0.01 {
-            self.active_progress += (target_active - self.active_progress) * (animation_speed * 2.0);
+            self.active_progress += (target_active - self.active_progress) * animation_rate(animation_speed * 2.0);
         } else {
             self.active_progress = target_active;
         }
@@ -275,6 +285,10 @@ impl Widget for WindowControlButton {
         self.active = true;
     }
     
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+    
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -303,6 +317,12 @@ pub struct TitleBar {
     search_icon_hover: bool,
     search_icon_hover_progress: f32,
     command_palette_open: bool,
+    // Fitted to the width by `layout`; the window controls always keep their place
+    menubar_collapsed: bool,
+    nav_visible: bool,
+    search_rect: Option<Rect>,
+    search_compact: bool,
+    layout_buttons_x: Option<f32>,
 }
 
 impl TitleBar {
@@ -314,7 +334,7 @@ impl TitleBar {
         let maximize_x = close_x - button_width;
         let minimize_x = maximize_x - button_width;
         
-        Self {
+        let mut titlebar = Self {
             x,
             y,
             width,
@@ -332,7 +352,14 @@ impl TitleBar {
             search_icon_hover: false,
             search_icon_hover_progress: 0.0,
             command_palette_open: false,
-        }
+            menubar_collapsed: false,
+            nav_visible: false,
+            search_rect: None,
+            search_compact: false,
+            layout_buttons_x: None,
+        };
+        titlebar.layout();
+        titlebar
     }
     
     pub fn set_project_name(&mut self, name: &str) {
@@ -342,11 +369,60 @@ impl TitleBar {
     pub fn with_menubar(mut self, menubar_width: f32) -> Self {
         self.show_menubar = true;
         self.menubar_width = menubar_width;
+        self.layout();
         self
     }
     
+    /// Update the reserved menubar width, e.g. after the menu labels were translated
+    pub fn set_menubar_width(&mut self, menubar_width: f32) {
+        self.menubar_width = menubar_width;
+        self.layout();
+    }
+    
+    /// Width the menubar takes, which is less than its full width while collapsed
     pub fn menubar_width(&self) -> f32 {
-        self.menubar_width
+        if !self.show_menubar {
+            0.0
+        } else if self.menubar_collapsed {
+            MenuBar::COLLAPSED_WIDTH
+        } else {
+            self.menubar_width
+        }
+    }
+    
+    /// True when the menus don't fit and should fold behind the hamburger button
+    pub fn is_menubar_collapsed(&self) -> bool {
+        self.menubar_collapsed
+    }
+    
+    /// Fit the menubar, search field and layout buttons between the window controls
+    /// The layout buttons go last; before that the menubar collapses and the search field shrinks to an icon.
+    fn layout(&mut self) {
+        let right_end = self.minimize_btn.x - 16.0;
+        let full_width = self.x + self.menubar_width + 16.0 + NAV_WIDTH + MIN_SEARCH_WIDTH + LAYOUT_BUTTONS_WIDTH;
+        self.menubar_collapsed = self.show_menubar && full_width > right_end;
+        
+        let left_start = self.x + self.menubar_width() + 16.0;
+        let layout_visible = left_start + LAYOUT_BUTTONS_WIDTH <= right_end;
+        self.layout_buttons_x = layout_visible.then_some(right_end - LAYOUT_BUTTONS_WIDTH + 8.0);
+        let search_end = if layout_visible { right_end - LAYOUT_BUTTONS_WIDTH } else { right_end };
+        
+        let available = search_end - left_start;
+        let top = self.y + (self.height - SEARCH_HEIGHT) / 2.0;
+        self.nav_visible = available >= NAV_WIDTH + MIN_SEARCH_WIDTH;
+        self.search_compact = !self.nav_visible && available < MIN_SEARCH_WIDTH;
+        self.search_rect = if self.nav_visible {
+            // Centered in the free space, but never over the back and forward buttons
+            let width = (available - NAV_WIDTH).min(MAX_SEARCH_WIDTH);
+            let x = (left_start + (available - width) / 2.0).max(left_start + NAV_WIDTH);
+            Some(Rect::from_xywh(x, top, width, SEARCH_HEIGHT))
+        } else if !self.search_compact {
+            Some(Rect::from_xywh(left_start, top, available, SEARCH_HEIGHT))
+        } else if available >= COMPACT_SEARCH_WIDTH {
+            Some(Rect::from_xywh(search_end - COMPACT_SEARCH_WIDTH, top, COMPACT_SEARCH_WIDTH, SEARCH_HEIGHT))
+        } else {
+            None
+        };
     }
     
     pub fn set_maximized(&mut self, maximized: bool) {
@@ -372,31 +448,17 @@ impl TitleBar {
     }
     
     pub fn is_search_bar_clicked(&self, x: f32, y: f32) -> bool {
-        let (search_x, search_y, search_w, search_h) = self.get_search_bar_bounds();
-        x >= search_x && x <= search_x + search_w && y >= search_y && y <= search_y + search_h
-    }
-    
-    fn get_search_bar_bounds(&self) -> (f32, f32, f32, f32) {
-        let left_start = self.x + self.menubar_width + 16.0;
-        let right_end = self.minimize_btn.x - 16.0;
-        let layout_buttons_width = 100.0;
-        let layout_start_pos = right_end - layout_buttons_width;
-        let available_width = layout_start_pos - left_start;
-        
-        let max_search_width = 400.0;
-        let search_height = 26.0;
-        let search_center_x = left_start + available_width / 2.0;
-        let search_start = search_center_x - max_search_width / 2.0;
-        let center_y = self.y + self.height / 2.0;
-        
-        (search_start, center_y - search_height / 2.0, max_search_width, search_height)
+        self.search_rect.is_some_and(|rect| x >= rect.left && x <= rect.right && y >= rect.top && y <= rect.bottom)
     }
     
-    fn get_search_icon_bounds(&self) -> (f32, f32, f32, f32) {
-        let (search_x, search_y, _search_w, search_h) = self.get_search_bar_bounds();
+    fn get_search_icon_bounds(&self, search_rect: Rect) -> (f32, f32, f32, f32) {
         let icon_size = 16.0;
-        let icon_x = search_x + 8.0;
-        let icon_y = search_y + (search_h - icon_size) / 2.0;
+        let icon_x = if self.search_compact {
+            search_rect.left + (search_rect.width() - icon_size) / 2.0
+        } else {
+            search_rect.left + 8.0
+        };
+        let icon_y = search_rect.top + (search_rect.height() - icon_size) / 2.0;
         (icon_x, icon_y, icon_size, icon_size)
     }
     
@@ -411,6 +473,7 @@ impl TitleBar {
         self.close_btn.x = close_x;
         self.maximize_btn.x = maximize_x;
         self.minimize_btn.x = minimize_x;
+        self.layout();
     }
     
     /// Check if a point is in the draggable area (not on buttons or menubar)
@@ -429,7 +492,7 @@ impl TitleBar {
         
         // Exclude menubar area if enabled (only the left portion where menu items are)
         // Add some padding to make it easier to drag
-        if self.show_menubar && x >= self.x && x <= self.x + self.menubar_width {
+        if self.show_menubar && x >= self.x && x <= self.x + self.menubar_width() {
             return false;
         }
         
@@ -443,7 +506,7 @@ impl TitleBar {
             return false;
         }
         
-        self.contains(x, y) && x >= self.x && x <= self.x + self.menubar_width
+        self.contains(x, y) && x >= self.x && x <= self.x + self.menubar_width()
     }
     
     /// Get the bounds of the maximize button (for Windows 11 snap layouts)
@@ -480,11 +543,9 @@ impl TitleBar {
     
     /// Get which layout button was clicked, if any
     pub fn get_clicked_layout_button(&self, x: f32, y: f32) -> Option<LayoutButton> {
-        let right_end = self.minimize_btn.x - 16.0;
-        let layout_buttons_width = 100.0;
+        let layout_start = self.layout_buttons_x?;
         let layout_button_size = 28.0;
         let layout_button_gap = 4.0;
-        let layout_start = right_end - layout_buttons_width + 8.0;
         let center_y = self.y + self.height / 2.0;
         
         // Check each layout button
@@ -519,173 +580,134 @@ impl Widget for TitleBar {
         // Background
         let mut bg_paint = Paint::default();
         bg_paint.set_anti_alias(true);
-        bg_paint.set_color(theme.card);
+        bg_paint.set_color(surface(theme.card));
         
         let rect = Rect::from_xywh(self.x, self.y, self.width, self.height);
         canvas.draw_rect(rect, &bg_paint);
         
-        // Calculate available space
-        let left_start = self.x + self.menubar_width + 16.0;
-        let right_end = self.minimize_btn.x - 16.0;
         let center_y = self.y + self.height / 2.0;
         
-        // Layout toggle buttons width (positioned on the right)
-        let layout_buttons_width = 100.0;
-        let layout_start_pos = right_end - layout_buttons_width;
-        
-        // Center section: Navigation buttons + Search bar
-        let nav_button_size = 24.0;
-        let nav_button_gap = 4.0;
-        let gap_between_nav_and_search = 8.0;
-        let max_search_width = 400.0;
-        let search_height = 26.0;
-        
-        // Calculate available width for centering
-        let available_width = layout_start_pos - left_start;
-        
-        // Center the search bar in the available space
-        let search_center_x = left_start + available_width / 2.0;
-        let search_start = search_center_x - max_search_width / 2.0;
-        
-        // Position navigation buttons to the left of the search bar
-        let forward_x = search_start - gap_between_nav_and_search - nav_button_size;
-        let back_x = forward_x - nav_button_gap - nav_button_size;
-        
-        // Draw back button background
-        // let back_rect = Rect::from_xywh(back_x, center_y - nav_button_size / 2.0, nav_button_size, nav_button_size);
-        // let mut nav_paint = Paint::default();
-        // nav_paint.set_anti_alias(true);
-        // nav_paint.set_color(theme.muted);
-        // canvas.draw_round_rect(back_rect, 4.0, 4.0, &nav_paint);
-        
-        // Draw back icon
-        let back_icon = Icon::new(
-            back_x + 0.0,
-            center_y - 8.0,
-            CodiconIcons::CHEVRON_LEFT,
-            IconSize::Small,
-            theme.muted_foreground,
-        );
-        back_icon.draw(canvas, font_manager);
-        
-        // Draw forward button background
-        // let forward_rect = Rect::from_xywh(forward_x, center_y - nav_button_size / 2.0, nav_button_size, nav_button_size);
-        // canvas.draw_round_rect(forward_rect, 4.0, 4.0, &nav_paint);
-        
-        // Draw forward icon
-        let forward_icon = Icon::new(
-            forward_x + 0.0,
-            center_y - 8.0,
-            CodiconIcons::CHEVRON_RIGHT,
-            IconSize::Small,
-            theme.muted_foreground,
-        );
-        forward_icon.draw(canvas, font_manager);
-        
-        // Search bar - already calculated above
-        let search_width = max_search_width;
-        let search_rect = Rect::from_xywh(
-            search_start,
-            center_y - search_height / 2.0,
-            search_width,
-            search_height,
-        );
-        
-        // Calculate opacity based on command palette state
-        let search_opacity = if self.command_palette_open { 0.0 } else { 1.0 };
-        
-        // Draw hover background on entire search bar
-        if self.search_icon_hover_progress > 0.0 {
-            let hover_alpha = (30.0 * self.search_icon_hover_progress * search_opacity) as u8;
-            let mut hover_paint = Paint::default();
-            hover_paint.set_anti_alias(true);
-            let muted = theme.muted;
-            hover_paint.set_color(Color::from_argb(hover_alpha, muted.r(), muted.g(), muted.b()));
-            canvas.draw_round_rect(search_rect, 4.0, 4.0, &hover_paint);
+        if let Some(search_rect) = self.search_rect {
+            // Navigation buttons to the left of the search bar
+            if self.nav_visible {
+                let nav_button_size = 24.0;
+                let nav_button_gap = 4.0;
+                let gap_between_nav_and_search = 8.0;
+                let forward_x = search_rect.left - gap_between_nav_and_search - nav_button_size;
+                let back_x = forward_x - nav_button_gap - nav_button_size;
+                
+                let back_icon = Icon::new(
+                    back_x,
+                    center_y - 8.0,
+                    CodiconIcons::CHEVRON_LEFT,
+                    IconSize::Small,
+                    theme.muted_foreground,
+                );
+                back_icon.draw(canvas, font_manager);
+                
+                let forward_icon = Icon::new(
+                    forward_x,
+                    center_y - 8.0,
+                    CodiconIcons::CHEVRON_RIGHT,
+                    IconSize::Small,
+                    theme.muted_foreground,
+                );
+                forward_icon.draw(canvas, font_manager);
+            }
+            
+            // Calculate opacity based on command palette state
+            let search_opacity = if self.command_palette_open { 0.0 } else { 1.0 };
+            
+            // Draw hover background on entire search bar
+            if self.search_icon_hover_progress > 0.0 {
+                let hover_alpha = (30.0 * self.search_icon_hover_progress * search_opacity) as u8;
+                let mut hover_paint = Paint::default();
+                hover_paint.set_anti_alias(true);
+                let muted = theme.muted;
+                hover_paint.set_color(Color::from_argb(hover_alpha, muted.r(), muted.g(), muted.b()));
+                canvas.draw_round_rect(search_rect, 4.0, 4.0, &hover_paint);
+            }
+            
+            // A narrow titlebar only has room for the search icon
+            if !self.search_compact {
+                // Search bar background
+                let mut search_bg = Paint::default();
+                search_bg.set_anti_alias(true);
+                let input_color = theme.input;
+                let bg_alpha = (input_color.a() as f32 * search_opacity) as u8;
+                search_bg.set_color(Color::from_argb(bg_alpha, input_color.r(), input_color.g(), input_color.b()));
+                canvas.draw_round_rect(search_rect, 4.0, 4.0, &search_bg);
+                
+                // Search bar border
+                let mut search_border = Paint::default();
+                search_border.set_anti_alias(true);
+                let border_color = theme.border;
+                let border_alpha = (border_color.a() as f32 * search_opacity) as u8;
+                search_border.set_color(Color::from_argb(border_alpha, border_color.r(), border_color.g(), border_color.b()));
+                search_border.set_style(skia_safe::PaintStyle::Stroke);
+                search_border.set_stroke_width(1.0);
+                canvas.draw_round_rect(search_rect, 4.0, 4.0, &search_border);
+            }
+            
+            // Draw search icon inside the search bar
+            let (icon_x, icon_y, _icon_w, _icon_h) = self.get_search_icon_bounds(search_rect);
+            let muted_fg = theme.muted_foreground;
+            let icon_alpha = (muted_fg.a() as f32 * search_opacity) as u8;
+            let icon_color = Color::from_argb(icon_alpha, muted_fg.r(), muted_fg.g(), muted_fg.b());
+            let search_icon = Icon::new(
+                icon_x,
+                icon_y,
+                CodiconIcons::SEARCH,
+                IconSize::Small,
+                icon_color,
+            );
+            search_icon.draw(canvas, font_manager);
+            
+            // Project name, cut off where the shrunken search bar ends
+            if !self.search_compact {
+                let search_font = font_manager.create_font(&self.project_name, 12.0, 400);
+                let mut search_text_paint = Paint::default();
+                search_text_paint.set_anti_alias(true);
+                let fg_color = theme.foreground;
+                let text_alpha = (fg_color.a() as f32 * search_opacity) as u8;
+                search_text_paint.set_color(Color::from_argb(text_alpha, fg_color.r(), fg_color.g(), fg_color.b()));
+                canvas.save();
+                canvas.clip_rect(search_rect.with_inset((8.0, 0.0)), None, Some(true));
+                canvas.draw_str(
+                    &self.project_name,
+                    (search_rect.left + 36.0, center_y + 4.0),
+                    &search_font,
+                    &search_text_paint,
+                );
+                canvas.restore();
+            }
         }
         
-        // Search bar background
-        let mut search_bg = Paint::default();
-        search_bg.set_anti_alias(true);
-        let input_color = theme.input;
-        let bg_alpha = (input_color.a() as f32 * search_opacity) as u8;
-        search_bg.set_color(Color::from_argb(bg_alpha, input_color.r(), input_color.g(), input_color.b()));
-        canvas.draw_round_rect(search_rect, 4.0, 4.0, &search_bg);
-        
-        // Search bar border
-        let mut search_border = Paint::default();
-        search_border.set_anti_alias(true);
-        let border_color = theme.border;
-        let border_alpha = (border_color.a() as f32 * search_opacity) as u8;
-        search_border.set_color(Color::from_argb(border_alpha, border_color.r(), border_color.g(), border_color.b()));
-        search_border.set_style(skia_safe::PaintStyle::Stroke);
-        search_border.set_stroke_width(1.0);
-        canvas.draw_round_rect(search_rect, 4.0, 4.0, &search_border);
-        
-        // Draw search icon inside the search bar
-        let (icon_x, icon_y, _icon_w, _icon_h) = self.get_search_icon_bounds();
-        let muted_fg = theme.muted_foreground;
-        let icon_alpha = (muted_fg.a() as f32 * search_opacity) as u8;
-        let icon_color = Color::from_argb(icon_alpha, muted_fg.r(), muted_fg.g(), muted_fg.b());
-        let search_icon = Icon::new(
-            icon_x,
-            icon_y,
-            CodiconIcons::SEARCH,
-            IconSize::Small,
-            icon_color,
-        );
-        search_icon.draw(canvas, font_manager);
-        
-        // Project name and search text
-        let search_font = font_manager.create_font(&self.project_name, 12.0, 400);
-        let mut search_text_paint = Paint::default();
-        search_text_paint.set_anti_alias(true);
-        let fg_color = theme.foreground;
-        let text_alpha = (fg_color.a() as f32 * search_opacity) as u8;
-        search_text_paint.set_color(Color::from_argb(text_alpha, fg_color.r(), fg_color.g(), fg_color.b()));
-        canvas.draw_str(
-            &self.project_name,
-            (search_start + 36.0, center_y + 4.0),
-            &search_font,
-            &search_text_paint,
-        );
-        
         // Layout toggle buttons - positioned on the right
-        let layout_button_size = 28.0;
-        let layout_button_gap = 4.0;
-        let layout_start = right_end - layout_buttons_width + 8.0;
-        
-        // Layout button icons: sidebar-left, sidebar-right, panel-bottom
-        let layout_icons = [
-            CodiconIcons::LAYOUT_SIDEBAR_LEFT,
-            CodiconIcons::LAYOUT_PANEL,
-            CodiconIcons::LAYOUT_SIDEBAR_RIGHT,
-        ];
-        
-        for (i, icon) in layout_icons.iter().enumerate() {
-            let button_x = layout_start + (i as f32 * (layout_button_size + layout_button_gap));
-            let button_rect = Rect::from_xywh(
-                button_x,
-                center_y - layout_button_size / 2.0,
-                layout_button_size,
-                layout_button_size,
-            );
+        if let Some(layout_start) = self.layout_buttons_x {
+            let layout_button_size = 28.0;
+            let layout_button_gap = 4.0;
             
-            // Button background
-            // let mut layout_paint = Paint::default();
-            // layout_paint.set_anti_alias(true);
-            // layout_paint.set_color(theme.muted);
-            // canvas.draw_round_rect(button_rect, 4.0, 4.0, &layout_paint);
+            // Layout button icons: sidebar-left, sidebar-right, panel-bottom
+            let layout_icons = [
+                CodiconIcons::LAYOUT_SIDEBAR_LEFT,
+                CodiconIcons::LAYOUT_PANEL,
+                CodiconIcons::LAYOUT_SIDEBAR_RIGHT,
+            ];
             
-            // Button icon
-            let layout_icon = Icon::new(
-                button_x + 6.0,
-                center_y - 8.0,
-                icon,
-                IconSize::Small,
-                theme.muted_foreground,
-            );
-            layout_icon.draw(canvas, font_manager);
+            for (i, icon) in layout_icons.iter().enumerate() {
+                let button_x = layout_start + (i as f32 * (layout_button_size + layout_button_gap));
+                
+                // Button icon
+                let layout_icon = Icon::new(
+                    button_x + 6.0,
+                    center_y - 8.0,
+                    icon,
+                    IconSize::Small,
+                    theme.muted_foreground,
+                );
+                layout_icon.draw(canvas, font_manager);
+            }
         }
         
         // Draw window control buttons
@@ -729,7 +751,7 @@ impl Widget for TitleBar {
         let target = if self.search_icon_hover { 1.0 } else { 0.0 };
         let animation_speed = 0.2;
         if (self.search_icon_hover_progress - target).abs() > 0.01 {
-            self.search_icon_hover_progress += (target - self.search_icon_hover_progress) * animation_speed;
+            self.search_icon_hover_progress += (target - self.search_icon_hover_progress) * animation_rate(animation_speed);
         } else {
             self.search_icon_hover_progress = target;
         }
@@ -739,6 +761,14 @@ impl Widget for TitleBar {
         // Handled by get_clicked_control
     }
     
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+    
+    fn children(&self) -> Vec<&dyn Widget> {
+        vec![&self.minimize_btn as &dyn Widget, &self.maximize_btn, &self.close_btn]
+    }
+    
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1,15 +1,18 @@
-use mikoui::MenuItem;
+use mikoui::{t, MenuItem};
 use crate::components::MenuBarItem;
+use std::path::Path;
 use std::process::Command;
 
-/// Spawn a new window instance
-fn spawn_new_window() {
+/// Spawn a new window instance, with the given profile instead of the last session's
+pub fn spawn_new_window(profile: Option<&str>) {
     // Get the current executable path
     if let Ok(exe_path) = std::env::current_exe() {
         // Spawn a new process with the same executable
-        match Command::new(exe_path)
-            .spawn()
-        {
+        let mut command = Command::new(exe_path);
+        if let Some(profile) = profile {
+            command.arg("--profile").arg(profile);
+        }
+        match command.spawn() {
             Ok(_) => println!("New window spawned successfully"),
             Err(e) => eprintln!("Failed to spawn new window: {}", e),
         }
@@ -18,163 +21,193 @@ fn spawn_new_window() {
     }
 }
 
+/// Show a file in the system file manager, selected where the platform supports it
+pub fn reveal_in_file_manager(path: &Path) {
+    #[cfg(target_os = "windows")]
+    let result = Command::new("explorer").arg(format!("/select,{}", path.display())).spawn();
+    #[cfg(target_os = "macos")]
+    let result = Command::new("open").arg("-R").arg(path).spawn();
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let result = Command::new("xdg-open").arg(path.parent().unwrap_or(path)).spawn();
+    
+    if let Err(e) = result {
+        eprintln!("Failed to open file manager: {}", e);
+    }
+}
+
 /// Create the default editor menu structure
 pub fn create_editor_menus() -> Vec<MenuBarItem> {
     vec![
-        MenuBarItem::new("File", vec![
-            MenuItem::new("New File", 1).with_shortcut("Ctrl+N"),
-            MenuItem::new("New Window", 2).with_shortcut("Ctrl+Shift+N"),
-            MenuItem::new("Open File...", 3).with_shortcut("Ctrl+O"),
-            MenuItem::new("Open Folder...", 4).with_shortcut("Ctrl+K Ctrl+O"),
-            MenuItem::new("Open Recent", 5),
+        MenuBarItem::new(t!("menu.file.title"), vec![
+            MenuItem::new(t!("menu.file.new_file"), 1).with_shortcut("Ctrl+N"),
+            MenuItem::new(t!("menu.file.new_window"), 2).with_shortcut("Ctrl+Shift+N"),
+            MenuItem::new(t!("menu.file.open_file"), 3).with_shortcut("Ctrl+O"),
+            MenuItem::new(t!("menu.file.open_folder"), 4).with_shortcut("Ctrl+K Ctrl+O"),
+            MenuItem::new(t!("menu.file.open_archive"), 286),
+            MenuItem::new(t!("menu.file.open_recent"), 5),
             MenuItem::separator(),
-            MenuItem::new("Save", 6).with_shortcut("Ctrl+S"),
-            MenuItem::new("Save As...", 7).with_shortcut("Ctrl+Shift+S"),
-            MenuItem::new("Save All", 8).with_shortcut("Ctrl+K S"),
+            MenuItem::new(t!("menu.file.save"), 6).with_shortcut("Ctrl+S"),
+            MenuItem::new(t!("menu.file.save_as"), 7).with_shortcut("Ctrl+Shift+S"),
+            MenuItem::new(t!("menu.file.save_all"), 8).with_shortcut("Ctrl+K S"),
             MenuItem::separator(),
-            MenuItem::new("Auto Save", 9),
+            MenuItem::new(t!("menu.file.auto_save"), 9),
             MenuItem::separator(),
-            MenuItem::new("Close", 10).with_shortcut("Ctrl+W"),
-            MenuItem::new("Close All", 11).with_shortcut("Ctrl+K Ctrl+W"),
-            MenuItem::new("Revert File", 12),
+            MenuItem::new(t!("menu.file.close"), 10).with_shortcut("Ctrl+W"),
+            MenuItem::new(t!("menu.file.close_all"), 11).with_shortcut("Ctrl+K Ctrl+W"),
+            MenuItem::new(t!("menu.file.revert_file"), 12),
             MenuItem::separator(),
-            MenuItem::new("Preferences", 13).with_shortcut("Ctrl+,"),
+            MenuItem::new(t!("menu.file.preferences"), 13).with_shortcut("Ctrl+,"),
             MenuItem::separator(),
-            MenuItem::new("Exit", 14).with_shortcut("Alt+F4"),
+            MenuItem::new(t!("menu.file.exit"), 14).with_shortcut("Alt+F4"),
         ]),
-        MenuBarItem::new("Edit", vec![
-            MenuItem::new("Undo", 20).with_shortcut("Ctrl+Z"),
-            MenuItem::new("Redo", 21).with_shortcut("Ctrl+Y"),
+        MenuBarItem::new(t!("menu.edit.title"), vec![
+            MenuItem::new(t!("menu.edit.undo"), 20).with_shortcut("Ctrl+Z"),
+            MenuItem::new(t!("menu.edit.redo"), 21).with_shortcut("Ctrl+Y"),
             MenuItem::separator(),
-            MenuItem::new("Cut", 22).with_shortcut("Ctrl+X"),
-            MenuItem::new("Copy", 23).with_shortcut("Ctrl+C"),
-            MenuItem::new("Paste", 24).with_shortcut("Ctrl+V"),
-            MenuItem::new("Delete", 25).with_shortcut("Del"),
+            MenuItem::new(t!("menu.edit.cut"), 22).with_shortcut("Ctrl+X"),
+            MenuItem::new(t!("menu.edit.copy"), 23).with_shortcut("Ctrl+C"),
+            MenuItem::new(t!("menu.edit.paste"), 24).with_shortcut("Ctrl+V"),
+            MenuItem::new(t!("menu.edit.delete"), 25).with_shortcut("Del"),
             MenuItem::separator(),
-            MenuItem::new("Select All", 26).with_shortcut("Ctrl+A"),
-            MenuItem::new("Expand Selection", 27).with_shortcut("Shift+Alt+Right"),
-            MenuItem::new("Shrink Selection", 28).with_shortcut("Shift+Alt+Left"),
+            MenuItem::new(t!("menu.edit.select_all"), 26).with_shortcut("Ctrl+A"),
+            MenuItem::new(t!("menu.edit.expand_selection"), 27).with_shortcut("Shift+Alt+Right"),
+            MenuItem::new(t!("menu.edit.shrink_selection"), 28).with_shortcut("Shift+Alt+Left"),
             MenuItem::separator(),
-            MenuItem::new("Find", 29).with_shortcut("Ctrl+F"),
-            MenuItem::new("Find Next", 30).with_shortcut("F3"),
-            MenuItem::new("Find Previous", 31).with_shortcut("Shift+F3"),
-            MenuItem::new("Replace", 32).with_shortcut("Ctrl+H"),
+            MenuItem::new(t!("menu.edit.find"), 29).with_shortcut("Ctrl+F"),
+            MenuItem::new(t!("menu.edit.find_next"), 30).with_shortcut("F3"),
+            MenuItem::new(t!("menu.edit.find_previous"), 31).with_shortcut("Shift+F3"),
+            MenuItem::new(t!("menu.edit.replace"), 32).with_shortcut("Ctrl+H"),
             MenuItem::separator(),
-            MenuItem::new("Find in Files", 33).with_shortcut("Ctrl+Shift+F"),
-            MenuItem::new("Replace in Files", 34).with_shortcut("Ctrl+Shift+H"),
+            MenuItem::new(t!("menu.edit.find_in_files"), 33).with_shortcut("Ctrl+Shift+F"),
+            MenuItem::new(t!("menu.edit.replace_in_files"), 34).with_shortcut("Ctrl+Shift+H"),
             MenuItem::separator(),
-            MenuItem::new("Go To Line...", 35).with_shortcut("Ctrl+G"),
-            MenuItem::new("Go To Symbol...", 36).with_shortcut("Ctrl+Shift+O"),
+            MenuItem::new(t!("menu.edit.go_to_line"), 35).with_shortcut("Ctrl+G"),
+            MenuItem::new(t!("menu.edit.go_to_symbol"), 36).with_shortcut("Ctrl+Shift+O"),
             MenuItem::separator(),
-            MenuItem::new("Toggle Line Comment", 37).with_shortcut("Ctrl+/"),
-            MenuItem::new("Toggle Block Comment", 38).with_shortcut("Shift+Alt+A"),
+            MenuItem::new(t!("menu.edit.toggle_line_comment"), 37).with_shortcut("Ctrl+/"),
+            MenuItem::new(t!("menu.edit.toggle_block_comment"), 38).with_shortcut("Shift+Alt+A"),
             MenuItem::separator(),
-            MenuItem::new("Format Document", 39).with_shortcut("Shift+Alt+F"),
-            MenuItem::new("Format Selection", 40).with_shortcut("Ctrl+K Ctrl+F"),
-            MenuItem::new("Trim Trailing Whitespace", 41),
+            MenuItem::new(t!("menu.edit.format_document"), 39).with_shortcut("Shift+Alt+F"),
+            MenuItem::new(t!("menu.edit.format_selection"), 40).with_shortcut("Ctrl+K Ctrl+F"),
+            MenuItem::new(t!("menu.edit.trim_trailing_whitespace"), 41),
         ]),
-        MenuBarItem::new("Selection", vec![
-            MenuItem::new("Select Line", 50).with_shortcut("Ctrl+L"),
-            MenuItem::new("Select Word", 51).with_shortcut("Ctrl+D"),
-            MenuItem::new("Expand Selection", 52).with_shortcut("Shift+Alt+Right"),
-            MenuItem::new("Shrink Selection", 53).with_shortcut("Shift+Alt+Left"),
-            MenuItem::separator(),
-            MenuItem::new("Select All Occurrences", 54).with_shortcut("Ctrl+Shift+L"),
-            MenuItem::new("Add Cursor Above", 55).with_shortcut("Ctrl+Alt+Up"),
-            MenuItem::new("Add Cursor Below", 56).with_shortcut("Ctrl+Alt+Down"),
-            MenuItem::new("Add Next Occurrence", 57).with_shortcut("Ctrl+D"),
-            MenuItem::new("Undo Last Cursor", 58).with_shortcut("Ctrl+U"),
+        MenuBarItem::new(t!("menu.selection.title"), vec![
+            MenuItem::new(t!("menu.selection.select_line"), 50).with_shortcut("Ctrl+L"),
+            MenuItem::new(t!("menu.selection.select_word"), 51).with_shortcut("Ctrl+D"),
+            MenuItem::new(t!("menu.selection.expand_selection"), 52).with_shortcut("Shift+Alt+Right"),
+            MenuItem::new(t!("menu.selection.shrink_selection"), 53).with_shortcut("Shift+Alt+Left"),
+            MenuItem::separator(),
+            MenuItem::new(t!("menu.selection.select_all_occurrences"), 54).with_shortcut("Ctrl+Shift+L"),
+            MenuItem::new(t!("menu.selection.add_cursor_above"), 55).with_shortcut("Ctrl+Alt+Up"),
+            MenuItem::new(t!("menu.selection.add_cursor_below"), 56).with_shortcut("Ctrl+Alt+Down"),
+            MenuItem::new(t!("menu.selection.add_next_occurrence"), 57).with_shortcut("Ctrl+D"),
+            MenuItem::new(t!("menu.selection.undo_last_cursor"), 58).with_shortcut("Ctrl+U"),
         ]),
-        MenuBarItem::new("View", vec![
-            MenuItem::new("Command Palette", 60).with_shortcut("Ctrl+Shift+P"),
-            MenuItem::new("Open View...", 61).with_shortcut("Ctrl+Q"),
-            MenuItem::separator(),
-            MenuItem::new("Explorer", 62).with_shortcut("Ctrl+Shift+E"),
-            MenuItem::new("Search", 63).with_shortcut("Ctrl+Shift+F"),
-            MenuItem::new("Source Control", 64).with_shortcut("Ctrl+Shift+G"),
-            MenuItem::new("Run and Debug", 65).with_shortcut("Ctrl+Shift+D"),
-            MenuItem::new("Extensions", 66).with_shortcut("Ctrl+Shift+X"),
-            MenuItem::separator(),
-            MenuItem::new("Problems", 67).with_shortcut("Ctrl+Shift+M"),
-            MenuItem::new("Output", 68).with_shortcut("Ctrl+Shift+U"),
-            MenuItem::new("Terminal", 69).with_shortcut("Ctrl+`"),
-            MenuItem::separator(),
-            MenuItem::new("Show Tabs", 70),
-            MenuItem::new("Show Status Bar", 71),
-            MenuItem::new("Toggle Minimap", 72),
-            MenuItem::separator(),
-            MenuItem::new("Zoom In", 73).with_shortcut("Ctrl++"),
-            MenuItem::new("Zoom Out", 74).with_shortcut("Ctrl+-"),
-            MenuItem::new("Reset Zoom", 75).with_shortcut("Ctrl+0"),
-            MenuItem::separator(),
-            MenuItem::new("Toggle Full Screen", 76).with_shortcut("F11"),
-            MenuItem::new("Toggle Zen Mode", 77).with_shortcut("Ctrl+K Z"),
+        MenuBarItem::new(t!("menu.view.title"), vec![
+            MenuItem::new(t!("menu.view.command_palette"), 60).with_shortcut("Ctrl+Shift+P"),
+            MenuItem::new(t!("menu.view.open_view"), 61).with_shortcut("Ctrl+Q"),
+            MenuItem::separator(),
+            MenuItem::new(t!("menu.view.explorer"), 62).with_shortcut("Ctrl+Shift+E"),
+            MenuItem::new(t!("menu.view.search"), 63).with_shortcut("Ctrl+Shift+F"),
+            MenuItem::new(t!("menu.view.source_control"), 64).with_shortcut("Ctrl+Shift+G"),
+            MenuItem::new(t!("menu.view.run_and_debug"), 65).with_shortcut("Ctrl+Shift+D"),
+            MenuItem::new(t!("menu.view.extensions"), 66).with_shortcut("Ctrl+Shift+X"),
+            MenuItem::new(t!("menu.view.todos"), 287),
+            MenuItem::new(t!("menu.view.bookmarks"), 293),
+            MenuItem::separator(),
+            MenuItem::new(t!("menu.view.problems"), 67).with_shortcut("Ctrl+Shift+M"),
+            MenuItem::new(t!("menu.view.output"), 68).with_shortcut("Ctrl+Shift+U"),
+            MenuItem::new(t!("menu.view.terminal"), 69).with_shortcut("Ctrl+`"),
+            MenuItem::separator(),
+            MenuItem::new(t!("menu.view.show_tabs"), 70),
+            MenuItem::new(t!("menu.view.show_status_bar"), 71),
+            MenuItem::new(t!("menu.view.toggle_minimap"), 72),
+            MenuItem::new(t!("menu.view.render_whitespace"), 78),
+            MenuItem::new(t!("menu.view.inlay_hints"), 260),
+            MenuItem::new(t!("menu.view.inline_blame"), 262),
+            MenuItem::separator(),
+            MenuItem::new(t!("menu.view.zoom_in"), 73).with_shortcut("Ctrl++"),
+            MenuItem::new(t!("menu.view.zoom_out"), 74).with_shortcut("Ctrl+-"),
+            MenuItem::new(t!("menu.view.reset_zoom"), 75).with_shortcut("Ctrl+0"),
+            MenuItem::separator(),
+            MenuItem::new(t!("menu.view.toggle_full_screen"), 76).with_shortcut("F11"),
+            MenuItem::new(t!("menu.view.toggle_zen_mode"), 77).with_shortcut("Ctrl+K Z"),
         ]),
-        MenuBarItem::new("Go", vec![
-            MenuItem::new("Back", 80).with_shortcut("Alt+Left"),
-            MenuItem::new("Forward", 81).with_shortcut("Alt+Right"),
-            MenuItem::new("Last Edit Location", 82).with_shortcut("Ctrl+K Ctrl+Q"),
-            MenuItem::separator(),
-            MenuItem::new("Switch Editor", 83).with_shortcut("Ctrl+Tab"),
-            MenuItem::new("Go to File...", 84).with_shortcut("Ctrl+P"),
-            MenuItem::new("Go to Symbol...", 85).with_shortcut("Ctrl+Shift+O"),
-            MenuItem::separator(),
-            MenuItem::new("Go to Definition", 86).with_shortcut("F12"),
-            MenuItem::new("Go to Declaration", 87),
-            MenuItem::new("Go to Type Definition", 88),
-            MenuItem::new("Go to Implementation", 89).with_shortcut("Ctrl+F12"),
-            MenuItem::new("Go to References", 90).with_shortcut("Shift+F12"),
-            MenuItem::separator(),
-            MenuItem::new("Go to Line...", 91).with_shortcut("Ctrl+G"),
-            MenuItem::new("Go to Bracket", 92).with_shortcut("Ctrl+Shift+\\"),
+        MenuBarItem::new(t!("menu.go.title"), vec![
+            MenuItem::new(t!("menu.go.back"), 80).with_shortcut("Alt+Left"),
+            MenuItem::new(t!("menu.go.forward"), 81).with_shortcut("Alt+Right"),
+            MenuItem::new(t!("menu.go.last_edit_location"), 82).with_shortcut("Ctrl+K Ctrl+Q"),
+            MenuItem::separator(),
+            MenuItem::new(t!("menu.go.switch_editor"), 83).with_shortcut("Ctrl+Tab"),
+            MenuItem::new(t!("menu.go.go_to_file"), 84).with_shortcut("Ctrl+P"),
+            MenuItem::new(t!("menu.go.go_to_symbol"), 85).with_shortcut("Ctrl+Shift+O"),
+            MenuItem::separator(),
+            MenuItem::new(t!("menu.go.go_to_definition"), 86).with_shortcut("F12"),
+            MenuItem::new(t!("menu.go.peek_definition"), 261).with_shortcut("Alt+F12"),
+            MenuItem::new(t!("menu.go.go_to_declaration"), 87),
+            MenuItem::new(t!("menu.go.go_to_type_definition"), 88),
+            MenuItem::new(t!("menu.go.go_to_implementation"), 89).with_shortcut("Ctrl+F12"),
+            MenuItem::new(t!("menu.go.go_to_references"), 90).with_shortcut("Shift+F12"),
+            MenuItem::separator(),
+            MenuItem::new(t!("menu.go.go_to_line"), 91).with_shortcut("Ctrl+G"),
+            MenuItem::new(t!("menu.go.go_to_bracket"), 92).with_shortcut("Ctrl+Shift+\\"),
+            MenuItem::separator(),
+            MenuItem::new(t!("menu.go.toggle_bookmark"), 288).with_shortcut("Ctrl+K Ctrl+K"),
+            MenuItem::new(t!("menu.go.next_bookmark"), 289).with_shortcut("Ctrl+K Ctrl+N"),
+            MenuItem::new(t!("menu.go.previous_bookmark"), 290).with_shortcut("Ctrl+K Ctrl+P"),
         ]),
-        MenuBarItem::new("Run", vec![
-            MenuItem::new("Start Debugging", 100).with_shortcut("F5"),
-            MenuItem::new("Run Without Debugging", 101).with_shortcut("Ctrl+F5"),
-            MenuItem::new("Stop Debugging", 102).with_shortcut("Shift+F5"),
-            MenuItem::new("Restart Debugging", 103).with_shortcut("Ctrl+Shift+F5"),
-            MenuItem::separator(),
-            MenuItem::new("Step Over", 104).with_shortcut("F10"),
-            MenuItem::new("Step Into", 105).with_shortcut("F11"),
-            MenuItem::new("Step Out", 106).with_shortcut("Shift+F11"),
-            MenuItem::new("Continue", 107).with_shortcut("F5"),
-            MenuItem::separator(),
-            MenuItem::new("Toggle Breakpoint", 108).with_shortcut("F9"),
-            MenuItem::new("New Breakpoint", 109),
-            MenuItem::separator(),
-            MenuItem::new("Open Configurations", 110),
-            MenuItem::new("Add Configuration...", 111),
+        MenuBarItem::new(t!("menu.run.title"), vec![
+            MenuItem::new(t!("menu.run.start_debugging"), 100).with_shortcut("F5"),
+            MenuItem::new(t!("menu.run.run_without_debugging"), 101).with_shortcut("Ctrl+F5"),
+            MenuItem::new(t!("menu.run.stop_debugging"), 102).with_shortcut("Shift+F5"),
+            MenuItem::new(t!("menu.run.restart_debugging"), 103).with_shortcut("Ctrl+Shift+F5"),
+            MenuItem::separator(),
+            MenuItem::new(t!("menu.run.step_over"), 104).with_shortcut("F10"),
+            MenuItem::new(t!("menu.run.step_into"), 105).with_shortcut("F11"),
+            MenuItem::new(t!("menu.run.step_out"), 106).with_shortcut("Shift+F11"),
+            MenuItem::new(t!("menu.run.continue"), 107).with_shortcut("F5"),
+            MenuItem::separator(),
+            MenuItem::new(t!("menu.run.toggle_breakpoint"), 108).with_shortcut("F9"),
+            MenuItem::new(t!("menu.run.new_breakpoint"), 109),
+            MenuItem::separator(),
+            MenuItem::new(t!("menu.run.open_configurations"), 110),
+            MenuItem::new(t!("menu.run.add_configuration"), 111),
         ]),
-        MenuBarItem::new("Terminal", vec![
-            MenuItem::new("New Terminal", 120).with_shortcut("Ctrl+Shift+`"),
-            MenuItem::new("Split Terminal", 121).with_shortcut("Ctrl+Shift+5"),
+        MenuBarItem::new(t!("menu.terminal.title"), vec![
+            MenuItem::new(t!("menu.terminal.new_terminal"), 120).with_shortcut("Ctrl+Shift+`"),
+            MenuItem::new(t!("menu.terminal.split_terminal"), 121).with_shortcut("Ctrl+Shift+5"),
+            MenuItem::separator(),
+            MenuItem::new(t!("menu.terminal.run_task"), 122).with_shortcut("Ctrl+Shift+B"),
+            MenuItem::new(t!("menu.terminal.run_build_task"), 123),
+            MenuItem::new(t!("menu.terminal.run_active_file"), 275),
+            MenuItem::new(t!("menu.terminal.run_selected_text"), 274),
             MenuItem::separator(),
-            MenuItem::new("Run Task...", 122).with_shortcut("Ctrl+Shift+B"),
-            MenuItem::new("Run Build Task", 123),
+            MenuItem::new(t!("menu.terminal.show_running_tasks"), 124),
+            MenuItem::new(t!("menu.terminal.restart_running_task"), 125),
+            MenuItem::new(t!("menu.terminal.terminate_task"), 126),
             MenuItem::separator(),
-            MenuItem::new("Show Running Tasks", 124),
-            MenuItem::new("Restart Running Task", 125),
-            MenuItem::new("Terminate Task", 126),
+            MenuItem::new(t!("menu.terminal.configure_tasks"), 127),
+            MenuItem::new(t!("menu.terminal.configure_default_build_task"), 128),
             MenuItem::separator(),
-            MenuItem::new("Configure Tasks...", 127),
-            MenuItem::new("Configure Default Build Task", 128),
+            MenuItem::new(t!("menu.terminal.go_to_previous_command"), 150).with_shortcut("Ctrl+Up"),
+            MenuItem::new(t!("menu.terminal.go_to_next_command"), 151).with_shortcut("Ctrl+Down"),
         ]),
-        MenuBarItem::new("Help", vec![
-            MenuItem::new("Welcome", 130),
-            MenuItem::new("Show All Commands", 131).with_shortcut("Ctrl+Shift+P"),
-            MenuItem::new("Documentation", 132),
-            MenuItem::new("Release Notes", 133),
+        MenuBarItem::new(t!("menu.help.title"), vec![
+            MenuItem::new(t!("menu.help.welcome"), 130),
+            MenuItem::new(t!("menu.help.show_all_commands"), 131).with_shortcut("Ctrl+Shift+P"),
+            MenuItem::new(t!("menu.help.documentation"), 132),
+            MenuItem::new(t!("menu.help.release_notes"), 133),
             MenuItem::separator(),
-            MenuItem::new("Keyboard Shortcuts Reference", 134).with_shortcut("Ctrl+K Ctrl+R"),
-            MenuItem::new("Video Tutorials", 135),
-            MenuItem::new("Tips and Tricks", 136),
+            MenuItem::new(t!("menu.help.keyboard_shortcuts_reference"), 134).with_shortcut("Ctrl+K Ctrl+R"),
+            MenuItem::new(t!("menu.help.video_tutorials"), 135),
+            MenuItem::new(t!("menu.help.tips_and_tricks"), 136),
             MenuItem::separator(),
-            MenuItem::new("Join Us on Twitter", 137),
-            MenuItem::new("Report Issue", 138),
+            MenuItem::new(t!("menu.help.join_us_on_twitter"), 137),
+            MenuItem::new(t!("menu.help.report_issue"), 138),
             MenuItem::separator(),
-            MenuItem::new("Check for Updates...", 139),
+            MenuItem::new(t!("menu.help.check_for_updates"), 139),
             MenuItem::separator(),
-            MenuItem::new("About", 140),
+            MenuItem::new(t!("menu.help.about"), 140),
         ]),
     ]
 }
@@ -191,7 +224,7 @@ pub fn handle_menu_action(item_id: i32) {
         }
         2 => {
             // New Window - spawn a new instance
-            spawn_new_window();
+            spawn_new_window(None);
         }
         3 => {
             // Open File
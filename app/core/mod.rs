@@ -1,3 +1,3 @@
 pub mod menuitems;
 
-pub use menuitems::{create_editor_menus, handle_menu_action};
+pub use menuitems::{create_editor_menus, handle_menu_action, reveal_in_file_manager, spawn_new_window};
@@ -0,0 +1,92 @@
+use std::backtrace::Backtrace;
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::fs;
+use std::panic::PanicHookInfo;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::state::AppState;
+
+/// Output lines kept for the next crash report
+const LOG_LINES: usize = 200;
+
+static RECENT_LOG: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+/// Remember a line of output, so a crash report shows what happened right before the panic
+pub fn log(line: &str) {
+    let Ok(mut recent) = RECENT_LOG.lock() else {
+        return;
+    };
+    if recent.len() == LOG_LINES {
+        recent.pop_front();
+    }
+    recent.push_back(line.to_string());
+}
+
+/// Write a crash report when the app panics, then let the default hook print the panic as usual
+///
+/// The report waits at `AppState::crash_report_path()` until the next start picks it up with
+/// `take_pending_report`.
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let path = AppState::crash_report_path();
+        match fs::write(&path, report(info)) {
+            Ok(()) => eprintln!("Crash report written to {}", path.display()),
+            Err(e) => eprintln!("Failed to write crash report: {}", e),
+        }
+        default_hook(info);
+    }));
+}
+
+fn report(info: &PanicHookInfo) -> String {
+    let message = info
+        .payload()
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| info.payload().downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".to_string());
+    let location = info.location().map_or("unknown location".to_string(), |location| location.to_string());
+    let thread = std::thread::current();
+    
+    let mut report = String::new();
+    let _ = writeln!(report, "Rabital {} crash report", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(report, "Time: {} (seconds since the epoch)", now_secs());
+    let _ = writeln!(report, "Platform: {} {}", std::env::consts::OS, std::env::consts::ARCH);
+    let _ = writeln!(report, "Thread: {}", thread.name().unwrap_or("unnamed"));
+    let _ = writeln!(report);
+    let _ = writeln!(report, "Panicked at {}:", location);
+    let _ = writeln!(report, "{}", message);
+    let _ = writeln!(report);
+    let _ = writeln!(report, "Backtrace:");
+    let _ = writeln!(report, "{}", Backtrace::force_capture());
+    let _ = writeln!(report, "Recent output:");
+    // A panic while logging leaves the lock poisoned; the lines are still fine to read
+    let recent = RECENT_LOG.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    for line in recent.iter() {
+        let _ = writeln!(report, "{}", line);
+    }
+    report
+}
+
+/// Move the report of the last session's crash aside, so it's offered once; returns its new path
+pub fn take_pending_report() -> Option<PathBuf> {
+    let pending = AppState::crash_report_path();
+    if !pending.exists() {
+        return None;
+    }
+    let archived = pending.with_file_name(format!("crash-{}.txt", now_secs()));
+    match fs::rename(&pending, &archived) {
+        Ok(()) => Some(archived),
+        Err(e) => {
+            eprintln!("Failed to move crash report: {}", e);
+            Some(pending)
+        }
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+}
@@ -0,0 +1,92 @@
+use std::path::PathBuf;
+
+use mikocore::BranchStatus;
+
+use crate::messages::{UiMessage, UiSender};
+
+/// A git command run from the palette or the branch picker, off the UI thread
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitAction {
+    Pull,
+    /// Push, setting `origin` as the upstream of a branch that has none yet
+    Push { set_upstream: bool },
+    Fetch,
+    Stash,
+    StashPop,
+    Checkout(String),
+    CreateBranch(String),
+}
+
+impl GitAction {
+    fn args(&self) -> Vec<&str> {
+        match self {
+            GitAction::Pull => vec!["pull"],
+            GitAction::Push { set_upstream: false } => vec!["push"],
+            GitAction::Push { set_upstream: true } => vec!["push", "--set-upstream", "origin", "HEAD"],
+            GitAction::Fetch => vec!["fetch", "--all", "--prune"],
+            GitAction::Stash => vec!["stash", "push"],
+            GitAction::StashPop => vec!["stash", "pop"],
+            GitAction::Checkout(branch) => vec!["checkout", branch],
+            GitAction::CreateBranch(branch) => vec!["checkout", "-b", branch],
+        }
+    }
+
+    /// Locale key of the toast shown while the command runs
+    pub fn progress_key(&self) -> &'static str {
+        match self {
+            GitAction::Pull => "git.pulling",
+            GitAction::Push { .. } => "git.pushing",
+            GitAction::Fetch => "git.fetching",
+            GitAction::Stash => "git.stashing",
+            GitAction::StashPop => "git.popping_stash",
+            GitAction::Checkout(_) | GitAction::CreateBranch(_) => "git.checking_out",
+        }
+    }
+
+    /// Locale key of the toast shown once the command succeeded
+    pub fn done_key(&self) -> &'static str {
+        match self {
+            GitAction::Pull => "git.pulled",
+            GitAction::Push { .. } => "git.pushed",
+            GitAction::Fetch => "git.fetched",
+            GitAction::Stash => "git.stashed",
+            GitAction::StashPop => "git.popped_stash",
+            GitAction::Checkout(_) | GitAction::CreateBranch(_) => "git.checked_out",
+        }
+    }
+
+    /// The command line as typed in a terminal, for the Output panel
+    pub fn command_line(&self) -> String {
+        format!("git {}", self.args().join(" "))
+    }
+}
+
+/// Run `action` in `dir`, then tell the UI how it went and where the branch stands now
+pub fn run(action: GitAction, dir: PathBuf, ui: &UiSender) {
+    let ui = ui.clone();
+    std::thread::spawn(move || {
+        let result = mikocore::git(&dir, &action.args()).map_err(|e| e.to_string());
+        ui.send(UiMessage::GitActionDone { action, result });
+        ui.send(UiMessage::GitStatus(mikocore::branch_status(&dir).ok()));
+    });
+}
+
+/// Look up the branch of the repository `dir` is in; None reaches the UI outside a repository
+pub fn refresh_status(dir: PathBuf, ui: &UiSender) {
+    let ui = ui.clone();
+    std::thread::spawn(move || {
+        ui.send(UiMessage::GitStatus(mikocore::branch_status(&dir).ok()));
+    });
+}
+
+/// Status bar text of a branch, e.g. `main 2↓ 1↑` when behind by 2 and ahead by 1
+pub fn branch_label(status: &BranchStatus) -> String {
+    let mut label = status.head.clone();
+    if status.behind > 0 {
+        label.push_str(&format!(" {}\u{2193}", status.behind));
+    }
+    if status.ahead > 0 {
+        label.push_str(&format!(" {}\u{2191}", status.ahead));
+    }
+    label
+}
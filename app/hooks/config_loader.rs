@@ -1,14 +1,31 @@
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::fs;
 use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use mikoeditor::{Formatter, LanguageConfig, MacroStep, DEFAULT_TODO_KEYWORDS};
+
+use super::extensions::Extensions;
+use super::settings_schema::{self, SettingIssue};
+
+/// Profile that keeps its files directly in shared/config
+pub const DEFAULT_PROFILE: &str = "Default";
 
 /// Configuration loader that auto-detects and parses .rabital config files
 pub struct ConfigLoader {
     workspace_path: Option<PathBuf>,
     app_dir: PathBuf,
     settings: Option<EditorSettings>,
+    user_settings: Value, // shared/config/setting.yml as validated, `Null` without one
+    workspace_settings: Value, // .rabital/settings.yml of the workspace, merged over the user's
+    workspace_trusted: bool, // Outside Restricted Mode; the workspace's settings are ignored until then
+    settings_issues: Vec<(PathBuf, SettingIssue)>, // Values the schema rejected at the last load
     tasks: Option<TasksConfig>,
     debug: Option<DebugConfig>,
+    macros: BTreeMap<String, Vec<MacroStep>>, // Saved keyboard macros by name
+    languages: BTreeMap<String, LanguageConfig>, // Comment tokens, brackets and word chars by language id
+    extensions: Extensions, // Installed in shared/extensions
+    profile: String, // Named set of settings, keybindings and enabled extensions in use
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,14 +42,56 @@ pub struct EditorSettings {
     pub git: GitConfig,
     #[serde(default)]
     pub search: SearchConfig,
+    #[serde(default)]
+    pub todos: TodosConfig,
+    #[serde(default)]
+    pub animations: AnimationsConfig,
+    #[serde(default)]
+    pub accessibility: AccessibilityConfig,
+    #[serde(default)]
+    pub window: WindowConfig,
+    #[serde(default)]
+    pub telemetry: TelemetryConfig,
+}
+
+/// Where a setting's value comes from, lowest precedence first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SettingSource {
+    Default,
+    /// shared/config/setting.yml
+    User,
+    /// .rabital/settings.yml of the open workspace
+    Workspace,
+}
+
+/// Lay `overrides` over `base`: mappings merge key by key, anything else replaces
+fn merge_settings(base: &mut Value, overrides: &Value) {
+    match (base, overrides) {
+        (_, Value::Null) => {}
+        (Value::Mapping(base), Value::Mapping(overrides)) => {
+            for (key, value) in overrides {
+                match base.get_mut(key) {
+                    Some(existing) => merge_settings(existing, value),
+                    None => {
+                        base.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (base, overrides) => *base = overrides.clone(),
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EditorConfig {
     #[serde(default = "default_theme")]
     pub theme: String,
+    /// Monospace family for the editor and terminal
     #[serde(default = "default_font_family")]
     pub font_family: String,
+    /// Family for menus, panels and dialogs; empty uses the platform default
+    #[serde(default)]
+    pub ui_font_family: String,
     #[serde(default = "default_font_size")]
     pub font_size: u32,
     #[serde(default = "default_line_height")]
@@ -41,8 +100,10 @@ pub struct EditorConfig {
     pub tab_size: u32,
     #[serde(default = "default_true")]
     pub insert_spaces: bool,
-    #[serde(default)]
-    pub auto_save: bool,
+    /// `off`, `after_delay` or `on_focus_change`; `true`/`false` from older configs still work
+    #[serde(default = "default_auto_save", deserialize_with = "deserialize_auto_save")]
+    pub auto_save: String,
+    /// Milliseconds after the last edit for `after_delay`
     #[serde(default = "default_auto_save_delay")]
     pub auto_save_delay: u32,
     #[serde(default)]
@@ -59,14 +120,71 @@ pub struct EditorConfig {
     pub trim_trailing_whitespace: bool,
     #[serde(default)]
     pub insert_final_newline: bool,
+    #[serde(default = "default_encoding")]
+    pub encoding: String,
+    #[serde(default = "default_eol")]
+    pub eol: String,
+    #[serde(default)]
+    pub render_whitespace: bool,
+    /// Columns vertical ruler lines are drawn at, e.g. `[80, 120]`
+    #[serde(default)]
+    pub rulers: Vec<u32>,
+    #[serde(default = "default_true")]
+    pub highlight_trailing_whitespace: bool,
+    /// Pin the headers of the functions and blocks scrolled into at the top of the editor
+    #[serde(default = "default_true")]
+    pub sticky_scroll: bool,
+    /// Show inline hints, such as parameter names and inferred types, between the characters
+    #[serde(default = "default_true")]
+    pub inlay_hints: bool,
+    #[serde(default = "default_true")]
+    pub detect_indentation: bool,
+    /// Display language; catalogs live in shared/locales
+    #[serde(default = "default_locale")]
+    pub locale: String,
+    /// File icon theme; `default` or the name of a JSON file in shared/icons
+    #[serde(default = "default_icon_theme")]
+    pub icon_theme: String,
+    /// Tabs reopened at startup: `all`, `pinned` or `none`
+    #[serde(default = "default_restore_tabs")]
+    pub restore_tabs: String,
+    /// Keep the workspace index on disk so reopening a folder only re-reads changed files
+    #[serde(default = "default_true")]
+    pub index_cache: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LanguageConfig {
-    #[serde(default = "default_tab_size")]
-    pub tab_size: u32,
+    /// Overrides `editor.tab_size` when set
     #[serde(default)]
-    pub format_on_save: bool,
+    pub tab_size: Option<u32>,
+    /// Overrides `editor.insert_spaces` when set
+    #[serde(default)]
+    pub insert_spaces: Option<bool>,
+    /// Overrides `editor.format_on_save` when set
+    #[serde(default)]
+    pub format_on_save: Option<bool>,
+    /// Replaces the built-in formatter; an empty command turns formatting off
+    #[serde(default)]
+    pub formatter: Option<FormatterConfig>,
+    /// Overrides `editor.trim_trailing_whitespace` when set
+    #[serde(default)]
+    pub trim_trailing_whitespace: Option<bool>,
+    /// Overrides `editor.insert_final_newline` when set
+    #[serde(default)]
+    pub insert_final_newline: Option<bool>,
+    /// Replaces `editor.rulers` when set; `[]` hides them
+    #[serde(default)]
+    pub rulers: Option<Vec<u32>>,
+}
+
+/// External formatter: reads the document on stdin, writes the result to stdout
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormatterConfig {
+    pub command: String,
+    /// `${file}` expands to the document's path
+    #[serde(default)]
+    pub args: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -75,6 +193,9 @@ pub struct ExplorerConfig {
     pub exclude_patterns: Vec<String>,
     #[serde(default)]
     pub show_hidden_files: bool,
+    /// Also skip what .gitignore, .ignore and .git/info/exclude list
+    #[serde(default = "default_true")]
+    pub use_gitignore: bool,
     #[serde(default = "default_true")]
     pub sort_folders_first: bool,
 }
@@ -113,6 +234,69 @@ pub struct SearchConfig {
     pub exclude_patterns: Vec<String>,
 }
 
+/// Comments listed on the TODOs page and highlighted in the editor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodosConfig {
+    /// Words that start a to-do comment, matched case-sensitively
+    #[serde(default = "default_todo_keywords")]
+    pub keywords: Vec<String>,
+    /// Also list the comments of workspace files that aren't open, read when the page is shown
+    #[serde(default)]
+    pub scan_workspace: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnimationsConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Multiplier for every UI animation; 2.0 settles twice as fast
+    #[serde(default = "default_animation_speed")]
+    pub speed: f32,
+    /// Turn animations off when the OS asks for reduced motion
+    #[serde(default = "default_true")]
+    pub respect_reduced_motion: bool,
+    /// Glide to new scroll positions in the editor and scrolling lists
+    #[serde(default = "default_true")]
+    pub smooth_scrolling: bool,
+    #[serde(default = "default_scroll_duration_ms")]
+    pub scroll_duration_ms: u32,
+    /// `ease_out`, `ease_in_out` or `linear`
+    #[serde(default = "default_scroll_easing")]
+    pub scroll_easing: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessibilityConfig {
+    /// Multiplier for UI text, independent of the display scale; editor text follows `editor.font_size`
+    #[serde(default = "default_text_scale")]
+    pub text_scale: f32,
+    /// Smallest width and height, in logical pixels, buttons and checkboxes react within
+    #[serde(default = "default_min_hit_target")]
+    pub min_hit_target: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowConfig {
+    /// What shows through the window: `none`, `transparent`, `acrylic` or `mica`
+    /// Acrylic and Mica need Windows 11; elsewhere they fall back to `transparent`.
+    #[serde(default = "default_window_material")]
+    pub material: String,
+    /// Opacity of panel backgrounds while a material is active, `0.0..=1.0`
+    #[serde(default = "default_window_opacity")]
+    pub opacity: f32,
+}
+
+/// Where opted-in usage data goes; whether it is collected at all is chosen in the app
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelemetryConfig {
+    /// `https://` URL, or `http://` on this machine, receiving the counters as JSON; empty keeps them local
+    #[serde(default)]
+    pub endpoint: String,
+    /// Hours between uploads
+    #[serde(default = "default_upload_interval_hours")]
+    pub upload_interval_hours: u32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TasksConfig {
     pub version: String,
@@ -155,21 +339,126 @@ fn default_font_size() -> u32 { 14 }
 fn default_line_height() -> f32 { 1.5 }
 fn default_tab_size() -> u32 { 4 }
 fn default_true() -> bool { true }
+fn default_auto_save() -> String { "off".to_string() }
 fn default_auto_save_delay() -> u32 { 1000 }
+fn default_encoding() -> String { "utf8".to_string() }
+fn default_eol() -> String { "auto".to_string() }
+fn default_locale() -> String { "en".to_string() }
+fn default_icon_theme() -> String { "default".to_string() }
+fn default_restore_tabs() -> String { "all".to_string() }
 fn default_shell() -> String { "powershell.exe".to_string() }
 fn default_terminal_font_size() -> u32 { 13 }
 fn default_scrollback() -> u32 { 10000 }
+fn default_todo_keywords() -> Vec<String> { DEFAULT_TODO_KEYWORDS.iter().map(|keyword| keyword.to_string()).collect() }
+fn default_animation_speed() -> f32 { 1.0 }
+fn default_scroll_duration_ms() -> u32 { 150 }
+fn default_scroll_easing() -> String { "ease_out".to_string() }
+fn default_text_scale() -> f32 { 1.0 }
+fn default_min_hit_target() -> f32 { 24.0 }
+fn default_window_material() -> String { "none".to_string() }
+fn default_window_opacity() -> f32 { 0.8 }
+fn default_upload_interval_hours() -> u32 { 24 }
+
+/// Auto save was a bool before it had modes; `true` meant saving after a delay
+fn deserialize_auto_save<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum AutoSave {
+        Enabled(bool),
+        Mode(String),
+    }
+    Ok(match AutoSave::deserialize(deserializer)? {
+        AutoSave::Enabled(true) => "after_delay".to_string(),
+        AutoSave::Enabled(false) => default_auto_save(),
+        AutoSave::Mode(mode) => mode,
+    })
+}
+
+impl EditorSettings {
+    /// Indentation for a language as (insert_spaces, tab_size)
+    pub fn indent_for(&self, language: Option<&str>) -> (bool, u32) {
+        let config = language.and_then(|lang| self.languages.get(lang));
+        (
+            config.and_then(|c| c.insert_spaces).unwrap_or(self.editor.insert_spaces),
+            config.and_then(|c| c.tab_size).unwrap_or(self.editor.tab_size),
+        )
+    }
+    
+    /// Whether to trim trailing whitespace on save for a language
+    pub fn trim_trailing_whitespace_for(&self, language: Option<&str>) -> bool {
+        language
+            .and_then(|lang| self.languages.get(lang))
+            .and_then(|config| config.trim_trailing_whitespace)
+            .unwrap_or(self.editor.trim_trailing_whitespace)
+    }
+    
+    /// Whether to format documents of a language on save
+    pub fn format_on_save_for(&self, language: Option<&str>) -> bool {
+        language
+            .and_then(|lang| self.languages.get(lang))
+            .and_then(|config| config.format_on_save)
+            .unwrap_or(self.editor.format_on_save)
+    }
+    
+    /// Formatter for a language: the configured one, else the built-in default (rustfmt, black, prettier)
+    pub fn formatter_for(&self, language: Option<&str>) -> Option<Formatter> {
+        let language = language?;
+        match self.languages.get(language).and_then(|config| config.formatter.as_ref()) {
+            Some(config) if config.command.is_empty() => None,
+            Some(config) => Some(Formatter {
+                command: config.command.clone(),
+                args: config.args.clone(),
+            }),
+            None => Formatter::default_for(language),
+        }
+    }
+    
+    /// Whether to insert a final newline on save for a language
+    pub fn insert_final_newline_for(&self, language: Option<&str>) -> bool {
+        language
+            .and_then(|lang| self.languages.get(lang))
+            .and_then(|config| config.insert_final_newline)
+            .unwrap_or(self.editor.insert_final_newline)
+    }
+    
+    /// Ruler columns for a language
+    pub fn rulers_for(&self, language: Option<&str>) -> &[u32] {
+        language
+            .and_then(|lang| self.languages.get(lang))
+            .and_then(|config| config.rulers.as_deref())
+            .unwrap_or(&self.editor.rulers)
+    }
+}
+
+impl Default for EditorSettings {
+    fn default() -> Self {
+        Self {
+            editor: EditorConfig::default(),
+            languages: std::collections::HashMap::new(),
+            explorer: ExplorerConfig::default(),
+            terminal: TerminalConfig::default(),
+            git: GitConfig::default(),
+            search: SearchConfig::default(),
+            todos: TodosConfig::default(),
+            animations: AnimationsConfig::default(),
+            accessibility: AccessibilityConfig::default(),
+            window: WindowConfig::default(),
+            telemetry: TelemetryConfig::default(),
+        }
+    }
+}
 
 impl Default for EditorConfig {
     fn default() -> Self {
         Self {
             theme: default_theme(),
             font_family: default_font_family(),
+            ui_font_family: String::new(),
             font_size: default_font_size(),
             line_height: default_line_height(),
             tab_size: default_tab_size(),
             insert_spaces: true,
-            auto_save: false,
+            auto_save: default_auto_save(),
             auto_save_delay: default_auto_save_delay(),
             word_wrap: false,
             show_line_numbers: true,
@@ -178,6 +467,18 @@ impl Default for EditorConfig {
             format_on_save: false,
             trim_trailing_whitespace: false,
             insert_final_newline: false,
+            encoding: default_encoding(),
+            eol: default_eol(),
+            render_whitespace: false,
+            rulers: Vec::new(),
+            highlight_trailing_whitespace: true,
+            sticky_scroll: true,
+            inlay_hints: true,
+            detect_indentation: true,
+            locale: default_locale(),
+            icon_theme: default_icon_theme(),
+            restore_tabs: default_restore_tabs(),
+            index_cache: true,
         }
     }
 }
@@ -192,6 +493,7 @@ impl Default for ExplorerConfig {
                 ".git/**".to_string(),
             ],
             show_hidden_files: false,
+            use_gitignore: true,
             sort_folders_first: true,
         }
     }
@@ -233,6 +535,55 @@ impl Default for SearchConfig {
     }
 }
 
+impl Default for TodosConfig {
+    fn default() -> Self {
+        Self {
+            keywords: default_todo_keywords(),
+            scan_workspace: false,
+        }
+    }
+}
+
+impl Default for AnimationsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            speed: default_animation_speed(),
+            respect_reduced_motion: true,
+            smooth_scrolling: true,
+            scroll_duration_ms: default_scroll_duration_ms(),
+            scroll_easing: default_scroll_easing(),
+        }
+    }
+}
+
+impl Default for AccessibilityConfig {
+    fn default() -> Self {
+        Self {
+            text_scale: default_text_scale(),
+            min_hit_target: default_min_hit_target(),
+        }
+    }
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            material: default_window_material(),
+            opacity: default_window_opacity(),
+        }
+    }
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: String::new(),
+            upload_interval_hours: default_upload_interval_hours(),
+        }
+    }
+}
+
 impl ConfigLoader {
     /// Create a new config loader
     pub fn new() -> Self {
@@ -241,13 +592,36 @@ impl ConfigLoader {
             .and_then(|p| p.parent().map(|p| p.to_path_buf()))
             .unwrap_or_else(|| PathBuf::from("."));
         
-        Self {
+        let mut loader = Self {
             workspace_path: None,
             app_dir,
             settings: None,
+            user_settings: Value::Null,
+            workspace_settings: Value::Null,
+            workspace_trusted: false,
+            settings_issues: Vec::new(),
             tasks: None,
             debug: None,
+            macros: BTreeMap::new(),
+            languages: BTreeMap::new(),
+            extensions: Extensions::default(),
+            profile: DEFAULT_PROFILE.to_string(),
+        };
+        loader.load_macros();
+        loader.load_languages();
+        loader.extensions = Extensions::load(&loader.get_extensions_dir(), &loader.extensions_state_file());
+        loader
+    }
+    
+    /// Apply the workspace's own settings or not, as they can name formatters and other commands to run;
+    /// true when that changed and the settings were reloaded
+    pub fn set_workspace_trusted(&mut self, trusted: bool) -> bool {
+        if self.workspace_trusted == trusted {
+            return false;
         }
+        self.workspace_trusted = trusted;
+        self.reload_settings();
+        true
     }
     
     /// Set the workspace path and auto-load configs
@@ -271,17 +645,42 @@ impl ConfigLoader {
         self.get_shared_dir().join("config")
     }
     
+    /// Get the UI string catalogs directory
+    pub fn get_locales_dir(&self) -> PathBuf {
+        self.get_shared_dir().join("locales")
+    }
+    
+    /// File icon themes, one JSON file each
+    pub fn get_icon_themes_dir(&self) -> PathBuf {
+        self.get_shared_dir().join("icons")
+    }
+    
+    /// Installed extensions, one folder each with an extension.json
+    pub fn get_extensions_dir(&self) -> PathBuf {
+        self.get_shared_dir().join("extensions")
+    }
+    
+    /// Font files registered at startup
+    pub fn get_fonts_dir(&self) -> PathBuf {
+        self.get_shared_dir().join("fonts")
+    }
+    
+    /// Fonts shipped with a workspace in .rabital/fonts
+    pub fn get_workspace_fonts_dir(&self) -> Option<PathBuf> {
+        self.workspace_path.as_ref().map(|workspace| workspace.join(".rabital").join("fonts"))
+    }
+    
     /// Load all configuration files
     fn load_configs(&mut self) {
+        self.load_macros();
+        self.load_languages();
+        self.reload_settings();
         if let Some(ref workspace) = self.workspace_path {
             let rabital_dir = workspace.join(".rabital");
             
             if rabital_dir.exists() {
                 println!("Found .rabital directory at: {}", rabital_dir.display());
                 
-                // Load settings.yml
-                self.load_settings(&rabital_dir);
-                
                 // Load tasks.yml
                 self.load_tasks(&rabital_dir);
                 
@@ -289,72 +688,199 @@ impl ConfigLoader {
                 self.load_debug(&rabital_dir);
             } else {
                 println!("No .rabital directory found, using defaults");
-                self.load_global_settings();
             }
         }
     }
     
-    /// Load settings from .rabital/settings.yml or global config
-    fn load_settings(&mut self, rabital_dir: &Path) {
-        let settings_path = rabital_dir.join("settings.yml");
+    /// Load the user's settings, then the workspace's .rabital/settings.yml over them once it is trusted
+    ///
+    /// Runs again whenever either file changes. Precedence is default < user < workspace, per setting: sections and `languages`
+    /// entries merge key by key, while a list such as `exclude_patterns` replaces the
+    /// user's list as a whole.
+    pub fn reload_settings(&mut self) {
+        self.settings_issues.clear();
+        self.user_settings = self.read_settings_layer(&self.user_settings_file());
+        self.workspace_settings = match self.workspace_settings_file().filter(|_| self.workspace_trusted) {
+            Some(path) => self.read_settings_layer(&path),
+            None => Value::Null,
+        };
         
-        if settings_path.exists() {
-            match fs::read_to_string(&settings_path) {
-                Ok(content) => {
-                    match serde_yaml::from_str::<EditorSettings>(&content) {
-                        Ok(settings) => {
-                            println!("Loaded settings from: {}", settings_path.display());
-                            self.settings = Some(settings);
-                        }
-                        Err(e) => {
-                            eprintln!("Failed to parse settings.yml: {}", e);
-                            self.load_global_settings();
-                        }
-                    }
+        let mut merged = self.user_settings.clone();
+        merge_settings(&mut merged, &self.workspace_settings);
+        if merged.is_null() {
+            merged = Value::Mapping(Default::default());
+        }
+        self.settings = Some(serde_yaml::from_value(merged).unwrap_or_else(|e| {
+            eprintln!("Failed to apply settings: {}", e);
+            EditorSettings::default()
+        }));
+    }
+    
+    /// One settings file, checked against the schema; `Null` when it is missing or unusable
+    fn read_settings_layer(&mut self, path: &Path) -> Value {
+        if !path.exists() {
+            return Value::Null;
+        }
+        match fs::read_to_string(path) {
+            Ok(content) => match self.parse_settings(path, &content) {
+                Ok(settings) => {
+                    println!("Loaded settings from: {}", path.display());
+                    settings
                 }
                 Err(e) => {
-                    eprintln!("Failed to read settings.yml: {}", e);
-                    self.load_global_settings();
+                    eprintln!("Failed to parse {}", e);
+                    Value::Null
                 }
+            },
+            Err(e) => {
+                eprintln!("Failed to read {}: {}", path.display(), e);
+                Value::Null
             }
+        }
+    }
+    
+    /// Parse a settings file and check it against the schema
+    ///
+    /// Values the schema rejects are reported in `settings_issues` and left out, so they
+    /// fall back to the next layer; only YAML that can't be parsed at all fails the whole file.
+    fn parse_settings(&mut self, path: &Path, content: &str) -> Result<Value, String> {
+        let mut value: Value = serde_yaml::from_str(content).map_err(|e| match e.location() {
+            Some(location) => format!("{}:{}:{}: {}", path.display(), location.line(), location.column(), e),
+            None => format!("{}: {}", path.display(), e),
+        })?;
+        for issue in settings_schema::validate(&mut value, content) {
+            eprintln!("{}:{}:{}: {}: {}", path.display(), issue.line, issue.column, issue.key, issue.message);
+            self.settings_issues.push((path.to_path_buf(), issue));
+        }
+        Ok(value)
+    }
+    
+    /// Problems found in the settings files at the last load, with the file each is in
+    pub fn settings_issues(&self) -> &[(PathBuf, SettingIssue)] {
+        &self.settings_issues
+    }
+    
+    /// The user's settings in the current profile, shared by every workspace
+    pub fn user_settings_file(&self) -> PathBuf {
+        self.profile_dir(&self.profile).join("setting.yml")
+    }
+    
+    /// The user's keybindings in the current profile, merged over the commands' default shortcuts
+    pub fn keybindings_file(&self) -> PathBuf {
+        self.profile_dir(&self.profile).join("keybindings.json")
+    }
+    
+    /// Extensions the user turned off in the current profile
+    pub fn extensions_state_file(&self) -> PathBuf {
+        self.profile_dir(&self.profile).join("extensions.json")
+    }
+    
+    /// Folder of a profile's files: shared/config for the default one, shared/config/profiles/<name> for the others
+    fn profile_dir(&self, name: &str) -> PathBuf {
+        if name == DEFAULT_PROFILE {
+            self.get_config_dir()
         } else {
-            self.load_global_settings();
+            self.get_config_dir().join("profiles").join(name)
         }
     }
     
-    /// Load global settings from shared/config/setting.yml
-    fn load_global_settings(&mut self) {
-        let global_settings_path = self.get_config_dir().join("setting.yml");
-        
-        if global_settings_path.exists() {
-            match fs::read_to_string(&global_settings_path) {
-                Ok(content) => {
-                    match serde_yaml::from_str::<EditorSettings>(&content) {
-                        Ok(settings) => {
-                            println!("Loaded global settings from: {}", global_settings_path.display());
-                            self.settings = Some(settings);
-                            return;
-                        }
-                        Err(e) => {
-                            eprintln!("Failed to parse global setting.yml: {}", e);
-                        }
-                    }
-                }
-                Err(e) => {
-                    eprintln!("Failed to read global setting.yml: {}", e);
-                }
+    /// Name of the profile in use
+    pub fn profile(&self) -> &str {
+        &self.profile
+    }
+    
+    /// The default profile, then the others by name
+    pub fn list_profiles(&self) -> Vec<String> {
+        let mut profiles: Vec<String> = fs::read_dir(self.get_config_dir().join("profiles"))
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+            .filter(|name| name != DEFAULT_PROFILE)
+            .collect();
+        profiles.sort();
+        profiles.insert(0, DEFAULT_PROFILE.to_string());
+        profiles
+    }
+    
+    /// Use a profile's settings, keybindings and extension choices from now on
+    ///
+    /// A profile without a folder yet starts out with the defaults.
+    pub fn set_profile(&mut self, name: &str) {
+        self.profile = name.to_string();
+        self.extensions = Extensions::load(&self.get_extensions_dir(), &self.extensions_state_file());
+        self.reload_settings();
+    }
+    
+    /// Create a profile that starts as a copy of the current one's files
+    pub fn create_profile(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let name = name.trim();
+        if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\', ':']) {
+            return Err(format!("{} is not a valid profile name", name).into());
+        }
+        let dir = self.profile_dir(name);
+        if name == DEFAULT_PROFILE || dir.exists() {
+            return Err(format!("Profile {} already exists", name).into());
+        }
+        fs::create_dir_all(&dir)?;
+        for file in [self.user_settings_file(), self.keybindings_file(), self.extensions_state_file()] {
+            if let Some(file_name) = file.file_name().filter(|_| file.exists()) {
+                fs::copy(&file, dir.join(file_name))?;
             }
         }
-        
-        // Use default settings
-        self.settings = Some(EditorSettings {
-            editor: EditorConfig::default(),
-            languages: std::collections::HashMap::new(),
-            explorer: ExplorerConfig::default(),
-            terminal: TerminalConfig::default(),
-            git: GitConfig::default(),
-            search: SearchConfig::default(),
-        });
+        Ok(())
+    }
+    
+    /// The open workspace's settings, whether or not the file exists yet
+    pub fn workspace_settings_file(&self) -> Option<PathBuf> {
+        self.workspace_path.as_ref().map(|workspace| workspace.join(".rabital").join("settings.yml"))
+    }
+    
+    /// Files the settings are read from, to watch for changes
+    pub fn settings_files(&self) -> Vec<PathBuf> {
+        std::iter::once(self.user_settings_file()).chain(self.workspace_settings_file()).collect()
+    }
+    
+    /// File the Settings picker writes to: the workspace's when it has one and is trusted, else the user's
+    pub fn settings_file(&self) -> PathBuf {
+        self.workspace_settings_file()
+            .filter(|path| self.workspace_trusted && path.exists())
+            .unwrap_or_else(|| self.user_settings_file())
+    }
+    
+    /// Which layer the current value of a dotted setting comes from
+    pub fn setting_source(&self, key: &str) -> SettingSource {
+        if settings_schema::lookup(&self.workspace_settings, key).is_some() {
+            SettingSource::Workspace
+        } else if settings_schema::lookup(&self.user_settings, key).is_some() {
+            SettingSource::User
+        } else {
+            SettingSource::Default
+        }
+    }
+    
+    /// Current value of a dotted setting, defaults included
+    pub fn setting_value(&self, key: &str) -> Option<Value> {
+        let settings = serde_yaml::to_value(self.settings.as_ref()?).ok()?;
+        settings_schema::lookup(&settings, key).cloned()
+    }
+    
+    /// Write one setting into the settings file, keeping its comments, then reload; returns the file written
+    pub fn set_setting(&mut self, key: &str, value: &Value) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let path = self.settings_file();
+        let content = fs::read_to_string(&path).unwrap_or_default();
+        let literal = match value {
+            // Double-quoted like the rest of setting.yml; JSON string escapes are valid YAML
+            Value::String(text) => serde_json::to_string(text)?,
+            _ => serde_yaml::to_string(value)?.trim_end().to_string(),
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, settings_schema::set_value(&content, key, &literal))?;
+        self.reload_settings();
+        Ok(path)
     }
     
     /// Load tasks from .rabital/tasks.yml
@@ -405,6 +931,76 @@ impl ConfigLoader {
         }
     }
     
+    /// Load saved macros: shared/config/macros.yml, then the workspace's .rabital/macros.yml on top
+    fn load_macros(&mut self) {
+        let mut files = vec![self.get_config_dir().join("macros.yml")];
+        if let Some(ref workspace) = self.workspace_path {
+            files.push(workspace.join(".rabital").join("macros.yml"));
+        }
+        self.macros.clear();
+        for path in files.iter().filter(|path| path.exists()) {
+            let macros = fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| serde_yaml::from_str::<BTreeMap<String, Vec<MacroStep>>>(&content).map_err(|e| e.to_string()));
+            match macros {
+                Ok(macros) => self.macros.extend(macros),
+                Err(e) => eprintln!("Failed to load {}: {}", path.display(), e),
+            }
+        }
+    }
+    
+    /// Saved keyboard macros by name
+    pub fn get_macros(&self) -> &BTreeMap<String, Vec<MacroStep>> {
+        &self.macros
+    }
+    
+    /// Load language configs: shared/config/languages.yml, then the workspace's .rabital/languages.yml,
+    /// whose entries override single fields of the shared ones
+    fn load_languages(&mut self) {
+        let mut files = vec![self.get_config_dir().join("languages.yml")];
+        if let Some(ref workspace) = self.workspace_path {
+            files.push(workspace.join(".rabital").join("languages.yml"));
+        }
+        self.languages.clear();
+        for path in files.iter().filter(|path| path.exists()) {
+            let languages = fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| serde_yaml::from_str::<BTreeMap<String, LanguageConfig>>(&content).map_err(|e| e.to_string()));
+            match languages {
+                Ok(languages) => {
+                    for (language, config) in languages {
+                        self.languages.entry(language).or_default().merge(config);
+                    }
+                }
+                Err(e) => eprintln!("Failed to load {}: {}", path.display(), e),
+            }
+        }
+    }
+    
+    /// Language configs from languages.yml files by language id, to merge over the built-in ones
+    pub fn get_languages(&self) -> &BTreeMap<String, LanguageConfig> {
+        &self.languages
+    }
+    
+    /// Save a macro in the workspace's .rabital folder when it has one, else globally; returns the file written
+    pub fn save_macro(&mut self, name: &str, steps: Vec<MacroStep>) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let rabital_dir = self.workspace_path.as_ref().map(|workspace| workspace.join(".rabital")).filter(|dir| dir.is_dir());
+        let path = rabital_dir.unwrap_or_else(|| self.get_config_dir()).join("macros.yml");
+        
+        // Keep the other macros in that file; the merged list may come from two files
+        let mut saved: BTreeMap<String, Vec<MacroStep>> = match fs::read_to_string(&path) {
+            Ok(content) => serde_yaml::from_str(&content)?,
+            Err(_) => BTreeMap::new(),
+        };
+        saved.insert(name.to_string(), steps.clone());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, serde_yaml::to_string(&saved)?)?;
+        self.macros.insert(name.to_string(), steps);
+        Ok(path)
+    }
+    
     /// Get the loaded settings
     pub fn get_settings(&self) -> Option<&EditorSettings> {
         self.settings.as_ref()
@@ -420,7 +1016,15 @@ impl ConfigLoader {
         self.debug.as_ref()
     }
     
-    /// List available themes from shared/themes directory
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+    
+    pub fn extensions_mut(&mut self) -> &mut Extensions {
+        &mut self.extensions
+    }
+    
+    /// List available themes from shared/themes directory and the enabled extensions
     pub fn list_themes(&self) -> Vec<String> {
         let themes_dir = self.get_themes_dir();
         let mut themes = Vec::new();
@@ -434,19 +1038,33 @@ impl ConfigLoader {
                 }
             }
         }
+        themes.extend(self.extensions.theme_names());
         
+        themes.sort();
+        themes.dedup();
+        themes
+    }
+    
+    /// List file icon themes from the shared/icons directory
+    pub fn list_icon_themes(&self) -> Vec<String> {
+        let mut themes: Vec<String> = fs::read_dir(self.get_icon_themes_dir())
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| entry.file_name().to_str()?.strip_suffix(".json").map(str::to_string))
+            .collect();
         themes.sort();
         themes
     }
     
-    /// Load a theme by name from shared/themes
+    /// Load a theme by name from shared/themes, else from an enabled extension
     pub fn load_theme(&self, theme_name: &str) -> Option<String> {
         let theme_path = self.get_themes_dir().join(format!("{}.yml", theme_name));
         
         if theme_path.exists() {
             fs::read_to_string(&theme_path).ok()
         } else {
-            None
+            fs::read_to_string(self.extensions.theme_path(theme_name)?).ok()
         }
     }
 }
@@ -0,0 +1,308 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use mikoeditor::MacroStep;
+
+/// Manifest file every extension folder holds
+pub const MANIFEST_FILE: &str = "extension.json";
+
+/// An extension's `extension.json`
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExtensionManifest {
+    /// Unique name, `publisher.name` by convention; the folder's name when left out
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub publisher: String,
+    #[serde(default)]
+    pub description: String,
+    /// Where the latest extension.json is published: an `http://` URL or a file path
+    #[serde(default)]
+    pub update_url: Option<String>,
+    #[serde(default)]
+    pub contributes: Contributions,
+}
+
+/// What an extension adds while it is enabled
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Contributions {
+    #[serde(default)]
+    pub commands: Vec<ExtensionCommand>,
+    /// Theme files relative to the extension folder, selected by file name like those in shared/themes
+    #[serde(default)]
+    pub themes: Vec<PathBuf>,
+}
+
+/// Palette command that replays macro steps, written like those of macros.yml
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExtensionCommand {
+    pub title: String,
+    pub steps: Vec<MacroStep>,
+}
+
+/// An installed extension
+#[derive(Debug, Clone)]
+pub struct Extension {
+    pub manifest: ExtensionManifest,
+    pub dir: PathBuf,
+    pub enabled: bool,
+    /// Newer version found by the last update check
+    pub update: Option<String>,
+}
+
+impl Extension {
+    /// Names of the contributed themes, as `editor.theme` takes them
+    pub fn theme_names(&self) -> Vec<String> {
+        self.manifest
+            .contributes
+            .themes
+            .iter()
+            .filter_map(|path| path.file_stem()?.to_str().map(str::to_string))
+            .collect()
+    }
+
+    fn theme_path(&self, name: &str) -> Option<PathBuf> {
+        self.manifest
+            .contributes
+            .themes
+            .iter()
+            .find(|path| path.file_stem().and_then(|stem| stem.to_str()) == Some(name))
+            .map(|path| self.dir.join(path))
+    }
+}
+
+/// extensions.json: which extensions are turned off
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ExtensionsState {
+    #[serde(default)]
+    disabled: BTreeSet<String>,
+}
+
+/// Extension to look for an update of, with the version installed
+#[derive(Debug, Clone)]
+pub struct UpdateSource {
+    pub id: String,
+    pub version: String,
+    pub url: String,
+}
+
+/// Extensions installed in shared/extensions, one folder each
+///
+/// Extensions are declarative: they contribute palette commands made of macro steps and
+/// color themes. Turning one off keeps it installed but drops its contributions; the
+/// disabled ids are kept in shared/config/extensions.json.
+#[derive(Debug, Default)]
+pub struct Extensions {
+    extensions: Vec<Extension>,
+    state_file: PathBuf,
+}
+
+impl Extensions {
+    /// Read every `*/extension.json` under `dir`; unusable manifests are logged and skipped
+    pub fn load(dir: &Path, state_file: &Path) -> Self {
+        let state: ExtensionsState = fs::read_to_string(state_file)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).map_err(|e| eprintln!("Failed to parse {}: {}", state_file.display(), e)).ok())
+            .unwrap_or_default();
+
+        let mut extensions: Vec<Extension> = fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.join(MANIFEST_FILE).is_file())
+            .filter_map(|path| match read_manifest(&path) {
+                Ok(manifest) => Some(Extension {
+                    enabled: !state.disabled.contains(&manifest.id),
+                    manifest,
+                    dir: path,
+                    update: None,
+                }),
+                Err(e) => {
+                    eprintln!("Failed to load extension {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .collect();
+        extensions.sort_by_key(|extension| extension.manifest.name.to_lowercase());
+        Self {
+            extensions,
+            state_file: state_file.to_path_buf(),
+        }
+    }
+
+    pub fn all(&self) -> &[Extension] {
+        &self.extensions
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Extension> {
+        self.extensions.iter().find(|extension| extension.manifest.id == id)
+    }
+
+    pub fn enabled(&self) -> impl Iterator<Item = &Extension> {
+        self.extensions.iter().filter(|extension| extension.enabled)
+    }
+
+    /// Turn an extension on or off and save the choice
+    pub fn set_enabled(&mut self, id: &str, enabled: bool) -> Result<(), Box<dyn std::error::Error>> {
+        let extension = self.extensions
+            .iter_mut()
+            .find(|extension| extension.manifest.id == id)
+            .ok_or_else(|| format!("no extension '{}'", id))?;
+        extension.enabled = enabled;
+
+        let state = ExtensionsState {
+            disabled: self.extensions.iter().filter(|e| !e.enabled).map(|e| e.manifest.id.clone()).collect(),
+        };
+        if let Some(parent) = self.state_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.state_file, serde_json::to_string_pretty(&state)?)?;
+        Ok(())
+    }
+
+    /// Commands of the enabled extensions, in a stable order for numbering them
+    pub fn commands(&self) -> Vec<(&Extension, &ExtensionCommand)> {
+        self.enabled()
+            .flat_map(|extension| extension.manifest.contributes.commands.iter().map(move |command| (extension, command)))
+            .collect()
+    }
+
+    /// File of a theme an enabled extension contributes
+    pub fn theme_path(&self, name: &str) -> Option<PathBuf> {
+        self.enabled().find_map(|extension| extension.theme_path(name))
+    }
+
+    pub fn theme_names(&self) -> Vec<String> {
+        self.enabled().flat_map(Extension::theme_names).collect()
+    }
+
+    /// Extensions that say where their updates are published
+    pub fn update_sources(&self) -> Vec<UpdateSource> {
+        self.extensions
+            .iter()
+            .filter_map(|extension| {
+                Some(UpdateSource {
+                    id: extension.manifest.id.clone(),
+                    version: extension.manifest.version.clone(),
+                    url: extension.manifest.update_url.clone()?,
+                })
+            })
+            .collect()
+    }
+
+    /// Record the result of `check_updates`; extensions missing from it are up to date
+    pub fn set_updates(&mut self, updates: &HashMap<String, String>) {
+        for extension in &mut self.extensions {
+            extension.update = updates.get(&extension.manifest.id).cloned();
+        }
+    }
+}
+
+fn read_manifest(dir: &Path) -> Result<ExtensionManifest, String> {
+    let content = fs::read_to_string(dir.join(MANIFEST_FILE)).map_err(|e| e.to_string())?;
+    let mut manifest: ExtensionManifest = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+    if manifest.id.is_empty() {
+        manifest.id = dir.file_name().and_then(|name| name.to_str()).unwrap_or_default().to_string();
+    }
+    Ok(manifest)
+}
+
+/// Fetch each source's published manifest; returns the newer versions by extension id, and the sources that failed
+///
+/// Blocks on the network, so run it off the UI thread.
+pub fn check_updates(sources: &[UpdateSource]) -> (HashMap<String, String>, Vec<String>) {
+    let mut updates = HashMap::new();
+    let mut errors = Vec::new();
+    for source in sources {
+        let published = if source.url.starts_with("http://") {
+            crate::http::get(&source.url)
+        } else {
+            fs::read_to_string(&source.url).map_err(|e| e.to_string())
+        };
+        match published.and_then(|content| serde_json::from_str::<ExtensionManifest>(&content).map_err(|e| e.to_string())) {
+            Ok(manifest) if compare_versions(&manifest.version, &source.version) == Ordering::Greater => {
+                updates.insert(source.id.clone(), manifest.version);
+            }
+            Ok(_) => {}
+            Err(e) => errors.push(format!("{}: {}", source.url, e)),
+        }
+    }
+    (updates, errors)
+}
+
+/// Compare dotted versions number by number, so `1.10.0` is newer than `1.9.2`
+///
+/// Missing parts count as 0 and a suffix after `-` is ignored.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let parts = |version: &str| -> Vec<u64> {
+        let version = version.trim().trim_start_matches('v');
+        let release = version.split('-').next().unwrap_or_default();
+        release.split('.').map(|part| part.parse().unwrap_or(0)).collect()
+    };
+    let (a, b) = (parts(a), parts(b));
+    (0..a.len().max(b.len()))
+        .map(|i| a.get(i).unwrap_or(&0).cmp(b.get(i).unwrap_or(&0)))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_compare_numerically() {
+        assert_eq!(compare_versions("1.10.0", "1.9.2"), Ordering::Greater);
+        assert_eq!(compare_versions("1.2", "1.2.0"), Ordering::Equal);
+        assert_eq!(compare_versions("v2.0.0", "1.99"), Ordering::Greater);
+        assert_eq!(compare_versions("0.3.0-beta", "0.3.1"), Ordering::Less);
+    }
+
+    #[test]
+    fn disabled_extensions_contribute_nothing() {
+        let root = std::env::temp_dir().join(format!("rabital-extensions-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (folder, name) in [("ocean", "Ocean"), ("snippets", "Snippets")] {
+            fs::create_dir_all(root.join("extensions").join(folder)).unwrap();
+            let manifest = format!(
+                r#"{{"name": "{}", "version": "1.0.0", "update_url": "{}",
+                    "contributes": {{"themes": ["themes/{}.yml"], "commands": [{{"title": "Insert {}", "steps": ["newline", {{"command": 41}}]}}]}}}}"#,
+                name,
+                root.join("published.json").display(),
+                folder,
+                name
+            );
+            fs::write(root.join("extensions").join(folder).join(MANIFEST_FILE), manifest).unwrap();
+        }
+        fs::write(root.join("published.json"), r#"{"name": "Ocean", "version": "1.1.0"}"#).unwrap();
+        let state_file = root.join("config").join("extensions.json");
+
+        let mut extensions = Extensions::load(&root.join("extensions"), &state_file);
+        assert_eq!(extensions.all().len(), 2);
+        assert_eq!(extensions.get("ocean").unwrap().manifest.name, "Ocean");
+        assert_eq!(extensions.commands().len(), 2);
+        assert_eq!(extensions.commands()[0].1.steps[1], MacroStep::Command { command: 41 });
+        assert_eq!(extensions.theme_path("ocean"), Some(root.join("extensions/ocean/themes/ocean.yml")));
+
+        extensions.set_enabled("ocean", false).unwrap();
+        assert_eq!(extensions.theme_path("ocean"), None);
+        assert_eq!(extensions.theme_names(), vec!["snippets"]);
+        assert_eq!(extensions.commands().len(), 1);
+
+        // The choice survives a reload
+        let mut extensions = Extensions::load(&root.join("extensions"), &state_file);
+        assert!(!extensions.get("ocean").unwrap().enabled);
+
+        let (updates, errors) = check_updates(&extensions.update_sources());
+        assert!(errors.is_empty());
+        assert_eq!(updates.len(), 2);
+        extensions.set_updates(&updates);
+        assert_eq!(extensions.get("snippets").unwrap().update.as_deref(), Some("1.1.0"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
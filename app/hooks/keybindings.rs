@@ -0,0 +1,490 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use winit::keyboard::{KeyCode, ModifiersState};
+
+/// One key press with its modifiers, written like `Ctrl+Shift+P`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool,
+    /// Key name as written in keybindings.json: `P`, `F2`, `Up`, `` ` ``...
+    pub key: String,
+}
+
+impl KeyChord {
+    /// Parse `Ctrl+Alt+P`; modifiers may come in any order and any case
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut chord = Self {
+            ctrl: false,
+            alt: false,
+            shift: false,
+            meta: false,
+            key: String::new(),
+        };
+        let parts: Vec<&str> = text.trim().split('+').map(str::trim).collect();
+        let (key, modifiers) = parts.split_last().ok_or_else(|| format!("empty key in '{}'", text))?;
+        for modifier in modifiers {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => chord.ctrl = true,
+                "alt" | "option" => chord.alt = true,
+                "shift" => chord.shift = true,
+                "meta" | "cmd" | "super" | "win" => chord.meta = true,
+                other => return Err(format!("unknown modifier '{}' in '{}'", other, text)),
+            }
+        }
+        chord.key = KEY_NAMES
+            .iter()
+            .map(|(_, name)| *name)
+            .find(|name| name.eq_ignore_ascii_case(key))
+            .ok_or_else(|| format!("unknown key '{}' in '{}'", key, text))?
+            .to_string();
+        Ok(chord)
+    }
+
+    /// The chord for a key press, or None for a modifier on its own
+    pub fn from_key(code: KeyCode, modifiers: ModifiersState) -> Option<Self> {
+        let key = KEY_NAMES.iter().find(|(key_code, _)| *key_code == code)?.1;
+        Some(Self {
+            ctrl: modifiers.control_key(),
+            alt: modifiers.alt_key(),
+            shift: modifiers.shift_key(),
+            meta: modifiers.super_key(),
+            key: key.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (held, name) in [(self.ctrl, "Ctrl"), (self.alt, "Alt"), (self.shift, "Shift"), (self.meta, "Meta")] {
+            if held {
+                write!(f, "{}+", name)?;
+            }
+        }
+        f.write_str(&self.key)
+    }
+}
+
+/// Chords pressed one after another, written like `Ctrl+K Ctrl+O`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeySequence(pub Vec<KeyChord>);
+
+impl KeySequence {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let chords = text.split_whitespace().map(KeyChord::parse).collect::<Result<Vec<_>, _>>()?;
+        if chords.is_empty() {
+            return Err("empty key".to_string());
+        }
+        Ok(Self(chords))
+    }
+
+    pub fn starts_with(&self, prefix: &[KeyChord]) -> bool {
+        self.0.starts_with(prefix)
+    }
+
+    /// Whether pressing one sequence always runs into the other: same keys, or one a prefix
+    pub fn overlaps(&self, other: &KeySequence) -> bool {
+        self.0.starts_with(&other.0) || other.0.starts_with(&self.0)
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, chord) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", chord)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeybindingSource {
+    /// Shortcut the command is registered with
+    Default,
+    /// Set in keybindings.json
+    User,
+}
+
+#[derive(Debug, Clone)]
+pub struct Keybinding {
+    pub command: u32,
+    pub keys: KeySequence,
+    pub source: KeybindingSource,
+}
+
+/// What a key press resolved to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyPress {
+    /// Run this command
+    Command(u32),
+    /// The first chords of a sequence; wait for the next key
+    Pending,
+    /// Swallow the key: a sequence broke off, or its default command was rebound elsewhere
+    Suppressed,
+    /// Not a keybinding; the built-in key handling takes it
+    Unhandled,
+}
+
+/// A key bound to more than one command
+#[derive(Debug, Clone)]
+pub struct KeybindingConflict {
+    pub keys: KeySequence,
+    pub commands: Vec<u32>,
+}
+
+/// One entry of keybindings.json; a missing or empty `key` unbinds the command
+#[derive(Debug, Serialize, Deserialize)]
+struct KeybindingEntry {
+    command: u32,
+    #[serde(default)]
+    key: Option<String>,
+    /// Name of the command, written for whoever edits the file; ignored when loading
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+}
+
+/// The registered commands' shortcuts with the user's keybindings.json merged over them
+///
+/// A command listed in the file loses all of its default keys and gets the ones from the
+/// file instead, so rebinding a command frees its old shortcut. Single-chord defaults stay
+/// with the app's built-in key handling, which knows their context (Ctrl+F in the hex
+/// editor, Ctrl+Alt+Shift+P pinning or unpinning); `press` dispatches user keybindings and
+/// multi-chord sequences, and suppresses a default whose command was moved to another key.
+pub struct Keybindings {
+    commands: Vec<u32>,
+    defaults: Vec<Keybinding>,
+    /// Keys from keybindings.json per command; empty when the command was unbound
+    user: BTreeMap<u32, Vec<KeySequence>>,
+    bindings: Vec<Keybinding>,
+    pending: Vec<KeyChord>,
+}
+
+impl Keybindings {
+    /// `commands` are every registered command with its default shortcut, if any
+    pub fn new<'a>(commands: impl IntoIterator<Item = (u32, Option<&'a str>)>) -> Self {
+        let mut ids = Vec::new();
+        let mut defaults = Vec::new();
+        for (command, shortcut) in commands {
+            ids.push(command);
+            if let Some(keys) = shortcut.and_then(|shortcut| KeySequence::parse(shortcut).ok()) {
+                defaults.push(Keybinding { command, keys, source: KeybindingSource::Default });
+            }
+        }
+        let mut keybindings = Self {
+            commands: ids,
+            defaults,
+            user: BTreeMap::new(),
+            bindings: Vec::new(),
+            pending: Vec::new(),
+        };
+        keybindings.merge();
+        keybindings
+    }
+
+    /// Replace the user keybindings with `path`'s; a missing file means none
+    ///
+    /// Returns the problems found. Entries with an unknown command or key are skipped;
+    /// a file that isn't valid JSON leaves no user keybindings at all.
+    pub fn load(&mut self, path: &Path) -> Vec<String> {
+        self.user.clear();
+        let mut issues = Vec::new();
+        match std::fs::read_to_string(path) {
+            Ok(content) if !content.trim().is_empty() => {
+                match serde_json::from_str::<Vec<KeybindingEntry>>(&content) {
+                    Ok(entries) => {
+                        for entry in entries {
+                            if let Err(e) = self.add_entry(&entry) {
+                                issues.push(format!("{}: {}", path.display(), e));
+                            }
+                        }
+                    }
+                    Err(e) => issues.push(format!("{}: {}", path.display(), e)),
+                }
+            }
+            Ok(_) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => issues.push(format!("{}: {}", path.display(), e)),
+        }
+        self.merge();
+        issues
+    }
+
+    fn add_entry(&mut self, entry: &KeybindingEntry) -> Result<(), String> {
+        if !self.commands.contains(&entry.command) {
+            return Err(format!("unknown command {}", entry.command));
+        }
+        let keys = match entry.key.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(key) => Some(KeySequence::parse(key)?),
+        };
+        let bound = self.user.entry(entry.command).or_default();
+        bound.extend(keys);
+        Ok(())
+    }
+
+    /// Write the user keybindings; `title` names each command for the reader of the file
+    pub fn save(&self, path: &Path, title: impl Fn(u32) -> Option<String>) -> std::io::Result<()> {
+        let mut entries = Vec::new();
+        for (&command, keys) in &self.user {
+            if keys.is_empty() {
+                entries.push(KeybindingEntry { command, key: None, title: title(command) });
+            }
+            for keys in keys {
+                entries.push(KeybindingEntry { command, key: Some(keys.to_string()), title: title(command) });
+            }
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&entries).map_err(std::io::Error::other)?;
+        std::fs::write(path, json + "\n")
+    }
+
+    /// Bind `command` to exactly `keys` (nothing, when empty), replacing its defaults
+    pub fn set(&mut self, command: u32, keys: Vec<KeySequence>) {
+        self.user.insert(command, keys);
+        self.merge();
+    }
+
+    /// Drop the user keybinding of `command`, back to its default shortcut
+    pub fn reset(&mut self, command: u32) {
+        self.user.remove(&command);
+        self.merge();
+    }
+
+    fn merge(&mut self) {
+        self.bindings = self.defaults
+            .iter()
+            .filter(|binding| !self.user.contains_key(&binding.command))
+            .cloned()
+            .collect();
+        for (&command, keys) in &self.user {
+            self.bindings.extend(keys.iter().map(|keys| Keybinding {
+                command,
+                keys: keys.clone(),
+                source: KeybindingSource::User,
+            }));
+        }
+        self.pending.clear();
+    }
+
+    pub fn is_user_defined(&self, command: u32) -> bool {
+        self.user.contains_key(&command)
+    }
+
+    /// Keys bound to `command` after merging, in the order they were defined
+    pub fn keys_for(&self, command: u32) -> Vec<&KeySequence> {
+        self.bindings.iter().filter(|binding| binding.command == command).map(|binding| &binding.keys).collect()
+    }
+
+    /// Commands whose keys are `keys` or overlap them as a prefix
+    pub fn bound_to(&self, keys: &KeySequence) -> Vec<u32> {
+        let mut commands = Vec::new();
+        for binding in self.bindings.iter().filter(|binding| binding.keys.overlaps(keys)) {
+            if !commands.contains(&binding.command) {
+                commands.push(binding.command);
+            }
+        }
+        commands
+    }
+
+    /// Keys bound to several commands, where at least one of the bindings is the user's
+    ///
+    /// Defaults that share a key (Ctrl+F for Find and Find Bytes) depend on context
+    /// and aren't reported.
+    pub fn conflicts(&self) -> Vec<KeybindingConflict> {
+        let mut conflicts: Vec<KeybindingConflict> = Vec::new();
+        for binding in self.bindings.iter().filter(|binding| binding.source == KeybindingSource::User) {
+            let commands = self.bound_to(&binding.keys);
+            if commands.len() < 2 || conflicts.iter().any(|conflict| conflict.keys.overlaps(&binding.keys)) {
+                continue;
+            }
+            conflicts.push(KeybindingConflict { keys: binding.keys.clone(), commands });
+        }
+        conflicts
+    }
+
+    /// Whether the first chords of a sequence were pressed and the next key completes it
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    pub fn pending_keys(&self) -> KeySequence {
+        KeySequence(self.pending.clone())
+    }
+
+    /// Resolve a key press against the keybindings, keeping track of sequences
+    pub fn press(&mut self, chord: KeyChord) -> KeyPress {
+        let was_pending = self.is_pending();
+        let mut pressed = std::mem::take(&mut self.pending);
+        pressed.push(chord);
+
+        // Later bindings win, so the user's come before the defaults they shadow
+        let mut exact = None;
+        let mut longer = false;
+        for binding in self.bindings.iter().rev().filter(|binding| self.dispatches(binding)) {
+            if binding.keys.0 == pressed {
+                exact = exact.or(Some(binding.command));
+            } else if binding.keys.starts_with(&pressed) {
+                longer = true;
+            }
+        }
+
+        if longer {
+            self.pending = pressed;
+            return KeyPress::Pending;
+        }
+        if let Some(command) = exact {
+            return KeyPress::Command(command);
+        }
+        if was_pending || self.is_displaced(&pressed) {
+            return KeyPress::Suppressed;
+        }
+        KeyPress::Unhandled
+    }
+
+    /// Cancel a half-typed sequence, e.g. when the window loses focus
+    pub fn clear_pending(&mut self) {
+        self.pending.clear();
+    }
+
+    fn dispatches(&self, binding: &Keybinding) -> bool {
+        binding.source == KeybindingSource::User || binding.keys.0.len() > 1
+    }
+
+    /// A default single-chord key whose command now lives elsewhere, and nothing took it over
+    fn is_displaced(&self, pressed: &[KeyChord]) -> bool {
+        let [chord] = pressed else {
+            return false;
+        };
+        let pressed = std::slice::from_ref(chord);
+        self.defaults.iter().any(|binding| binding.keys.0 == pressed && self.user.contains_key(&binding.command))
+            && !self.bindings.iter().any(|binding| binding.keys.0 == pressed)
+    }
+}
+
+/// Keys that can be bound, with the name keybindings.json uses for them
+const KEY_NAMES: &[(KeyCode, &str)] = &[
+    (KeyCode::KeyA, "A"), (KeyCode::KeyB, "B"), (KeyCode::KeyC, "C"), (KeyCode::KeyD, "D"),
+    (KeyCode::KeyE, "E"), (KeyCode::KeyF, "F"), (KeyCode::KeyG, "G"), (KeyCode::KeyH, "H"),
+    (KeyCode::KeyI, "I"), (KeyCode::KeyJ, "J"), (KeyCode::KeyK, "K"), (KeyCode::KeyL, "L"),
+    (KeyCode::KeyM, "M"), (KeyCode::KeyN, "N"), (KeyCode::KeyO, "O"), (KeyCode::KeyP, "P"),
+    (KeyCode::KeyQ, "Q"), (KeyCode::KeyR, "R"), (KeyCode::KeyS, "S"), (KeyCode::KeyT, "T"),
+    (KeyCode::KeyU, "U"), (KeyCode::KeyV, "V"), (KeyCode::KeyW, "W"), (KeyCode::KeyX, "X"),
+    (KeyCode::KeyY, "Y"), (KeyCode::KeyZ, "Z"),
+    (KeyCode::Digit0, "0"), (KeyCode::Digit1, "1"), (KeyCode::Digit2, "2"), (KeyCode::Digit3, "3"),
+    (KeyCode::Digit4, "4"), (KeyCode::Digit5, "5"), (KeyCode::Digit6, "6"), (KeyCode::Digit7, "7"),
+    (KeyCode::Digit8, "8"), (KeyCode::Digit9, "9"),
+    (KeyCode::F1, "F1"), (KeyCode::F2, "F2"), (KeyCode::F3, "F3"), (KeyCode::F4, "F4"),
+    (KeyCode::F5, "F5"), (KeyCode::F6, "F6"), (KeyCode::F7, "F7"), (KeyCode::F8, "F8"),
+    (KeyCode::F9, "F9"), (KeyCode::F10, "F10"), (KeyCode::F11, "F11"), (KeyCode::F12, "F12"),
+    (KeyCode::ArrowUp, "Up"), (KeyCode::ArrowDown, "Down"), (KeyCode::ArrowLeft, "Left"), (KeyCode::ArrowRight, "Right"),
+    (KeyCode::Home, "Home"), (KeyCode::End, "End"), (KeyCode::PageUp, "PageUp"), (KeyCode::PageDown, "PageDown"),
+    (KeyCode::Insert, "Insert"), (KeyCode::Delete, "Delete"), (KeyCode::Backspace, "Backspace"),
+    (KeyCode::Enter, "Enter"), (KeyCode::Escape, "Escape"), (KeyCode::Tab, "Tab"), (KeyCode::Space, "Space"),
+    (KeyCode::Backquote, "`"), (KeyCode::Minus, "-"), (KeyCode::Equal, "="), (KeyCode::BracketLeft, "["),
+    (KeyCode::BracketRight, "]"), (KeyCode::Backslash, "\\"), (KeyCode::Semicolon, ";"), (KeyCode::Quote, "'"),
+    (KeyCode::Comma, ","), (KeyCode::Period, "."), (KeyCode::Slash, "/"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(text: &str) -> KeySequence {
+        KeySequence::parse(text).unwrap()
+    }
+
+    fn chord(text: &str) -> KeyChord {
+        KeyChord::parse(text).unwrap()
+    }
+
+    fn keybindings() -> Keybindings {
+        Keybindings::new([
+            (4, Some("Ctrl+K Ctrl+O")),
+            (29, Some("Ctrl+F")),
+            (84, Some("Ctrl+P")),
+            (203, Some("Ctrl+F")),
+            (212, None),
+        ])
+    }
+
+    #[test]
+    fn parse_and_format() {
+        assert_eq!(keys("shift+ctrl+`").to_string(), "Ctrl+Shift+`");
+        assert_eq!(keys("Ctrl+K  ctrl+o").to_string(), "Ctrl+K Ctrl+O");
+        assert_eq!(chord("Alt+Shift+F"), chord("Shift+Alt+F"));
+        assert!(KeySequence::parse("Ctrl+Hyper+P").is_err());
+        assert!(KeySequence::parse("Ctrl+").is_err());
+        assert!(KeySequence::parse("  ").is_err());
+    }
+
+    #[test]
+    fn user_keys_replace_defaults() {
+        let mut keybindings = keybindings();
+        // Single-chord defaults are left to the built-in handlers
+        assert_eq!(keybindings.press(chord("Ctrl+P")), KeyPress::Unhandled);
+
+        keybindings.set(84, vec![keys("Ctrl+Alt+O")]);
+        assert_eq!(keybindings.press(chord("Ctrl+Alt+O")), KeyPress::Command(84));
+        assert_eq!(keybindings.press(chord("Ctrl+P")), KeyPress::Suppressed);
+
+        keybindings.set(212, vec![keys("Ctrl+P")]);
+        assert_eq!(keybindings.press(chord("Ctrl+P")), KeyPress::Command(212));
+
+        keybindings.reset(84);
+        keybindings.reset(212);
+        assert_eq!(keybindings.press(chord("Ctrl+P")), KeyPress::Unhandled);
+    }
+
+    #[test]
+    fn sequences_wait_for_the_next_chord() {
+        let mut keybindings = keybindings();
+        assert_eq!(keybindings.press(chord("Ctrl+K")), KeyPress::Pending);
+        assert_eq!(keybindings.press(chord("Ctrl+O")), KeyPress::Command(4));
+        assert_eq!(keybindings.press(chord("Ctrl+K")), KeyPress::Pending);
+        assert_eq!(keybindings.press(chord("A")), KeyPress::Suppressed);
+        assert!(!keybindings.is_pending());
+    }
+
+    #[test]
+    fn conflicts_involve_user_keys() {
+        let mut keybindings = keybindings();
+        assert!(keybindings.conflicts().is_empty());
+
+        keybindings.set(212, vec![keys("Ctrl+K")]);
+        let conflicts = keybindings.conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].commands, [4, 212]);
+        assert_eq!(keybindings.bound_to(&keys("Ctrl+F")), [29, 203]);
+    }
+
+    #[test]
+    fn load_and_save_round_trip() {
+        let path = std::env::temp_dir().join(format!("keybindings-{}.json", std::process::id()));
+        std::fs::write(&path, r#"[
+            { "command": 84, "key": "Ctrl+Alt+O" },
+            { "command": 29, "key": null },
+            { "command": 999, "key": "Ctrl+Q" },
+            { "command": 212, "key": "Ctrl+Nope" }
+        ]"#).unwrap();
+        let mut keybindings = keybindings();
+        assert_eq!(keybindings.load(&path).len(), 2);
+        assert_eq!(keybindings.keys_for(84), [&keys("Ctrl+Alt+O")]);
+        assert!(keybindings.keys_for(29).is_empty());
+
+        keybindings.save(&path, |_| None).unwrap();
+        let mut reloaded = Keybindings::new([(29, Some("Ctrl+F")), (84, Some("Ctrl+P")), (212, None)]);
+        assert!(reloaded.load(&path).is_empty());
+        assert_eq!(reloaded.keys_for(84), [&keys("Ctrl+Alt+O")]);
+        assert!(reloaded.is_user_defined(29) && reloaded.keys_for(29).is_empty());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,3 +1,6 @@
 pub mod config_loader;
+pub mod extensions;
+pub mod keybindings;
+pub mod settings_schema;
 
 pub use config_loader::ConfigLoader;
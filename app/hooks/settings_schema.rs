@@ -0,0 +1,352 @@
+use serde_yaml::{Mapping, Value};
+
+/// What a setting accepts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingKind {
+    Bool,
+    Integer { min: i64, max: i64 },
+    Number { min: f64, max: f64 },
+    Text,
+    /// One of a fixed set of strings
+    Choice(&'static [&'static str]),
+    /// A list of strings, e.g. glob patterns
+    List,
+    /// A list of text columns, e.g. ruler positions
+    Columns,
+}
+
+/// One entry of settings.yml: its dotted key, type, default and what it does
+///
+/// `*` in a key matches any name, e.g. `languages.*.tab_size`.
+#[derive(Debug, Clone, Copy)]
+pub struct SettingSchema {
+    pub key: &'static str,
+    pub kind: SettingKind,
+    /// Default as a YAML literal, the same value the `default_x()` functions return
+    pub default: &'static str,
+    pub description: &'static str,
+}
+
+const fn setting(key: &'static str, kind: SettingKind, default: &'static str, description: &'static str) -> SettingSchema {
+    SettingSchema { key, kind, default, description }
+}
+
+use SettingKind::*;
+
+/// Every setting read from settings.yml, in the order the Settings picker lists them
+pub const SETTINGS_SCHEMA: &[SettingSchema] = &[
+    setting("editor.theme", Text, "dark", "Color theme; the name of a file in shared/themes"),
+    setting("editor.font_family", Text, "Cascadia Code", "Monospace family for the editor and terminal"),
+    setting("editor.ui_font_family", Text, "''", "Family for menus, panels and dialogs; empty uses the platform default"),
+    setting("editor.font_size", Integer { min: 6, max: 72 }, "14", "Editor font size in points"),
+    setting("editor.line_height", Number { min: 0.8, max: 3.0 }, "1.5", "Line height as a multiple of the font size"),
+    setting("editor.tab_size", Integer { min: 1, max: 16 }, "4", "Columns per indentation level"),
+    setting("editor.insert_spaces", Bool, "true", "Indent with spaces instead of tabs"),
+    setting("editor.auto_save", Choice(&["off", "after_delay", "on_focus_change"]), "off", "When edited files save themselves"),
+    setting("editor.auto_save_delay", Integer { min: 0, max: 600_000 }, "1000", "Milliseconds after the last edit for after_delay"),
+    setting("editor.word_wrap", Bool, "false", "Wrap long lines at the window edge"),
+    setting("editor.show_line_numbers", Bool, "true", "Show line numbers in the gutter"),
+    setting("editor.show_minimap", Bool, "false", "Show the minimap"),
+    setting("editor.highlight_current_line", Bool, "true", "Highlight the line with the cursor"),
+    setting("editor.format_on_save", Bool, "false", "Format documents when saving"),
+    setting("editor.trim_trailing_whitespace", Bool, "false", "Remove whitespace at line ends when saving"),
+    setting("editor.insert_final_newline", Bool, "false", "End files with a newline when saving"),
+    setting("editor.encoding", Choice(&["utf8", "utf8bom", "utf16le", "utf16be", "iso88591"]), "utf8", "Encoding of new files"),
+    setting("editor.eol", Choice(&["auto", "lf", "crlf"]), "auto", "Line ending of new files"),
+    setting("editor.render_whitespace", Bool, "false", "Draw spaces and tabs"),
+    setting("editor.rulers", Columns, "[]", "Columns to draw vertical ruler lines at, e.g. [80, 120]"),
+    setting("editor.highlight_trailing_whitespace", Bool, "true", "Mark whitespace at line ends"),
+    setting("editor.sticky_scroll", Bool, "true", "Pin the headers of enclosing functions and blocks at the top"),
+    setting("editor.inlay_hints", Bool, "true", "Show parameter name and type hints between the characters"),
+    setting("editor.detect_indentation", Bool, "true", "Take indentation from the file's contents"),
+    setting("editor.locale", Text, "en", "Display language; catalogs live in shared/locales"),
+    setting("editor.icon_theme", Text, "default", "File icon theme; default or a JSON file in shared/icons"),
+    setting("editor.restore_tabs", Choice(&["all", "pinned", "none"]), "all", "Tabs reopened at startup"),
+    setting("editor.index_cache", Bool, "true", "Keep the workspace index on disk between sessions"),
+    setting("languages.*.tab_size", Integer { min: 1, max: 16 }, "4", "Overrides editor.tab_size"),
+    setting("languages.*.insert_spaces", Bool, "true", "Overrides editor.insert_spaces"),
+    setting("languages.*.format_on_save", Bool, "false", "Overrides editor.format_on_save"),
+    setting("languages.*.formatter.command", Text, "''", "Formatter reading stdin and writing stdout; empty turns formatting off"),
+    setting("languages.*.formatter.args", List, "[]", "Formatter arguments; ${file} expands to the document's path"),
+    setting("languages.*.trim_trailing_whitespace", Bool, "false", "Overrides editor.trim_trailing_whitespace"),
+    setting("languages.*.insert_final_newline", Bool, "false", "Overrides editor.insert_final_newline"),
+    setting("languages.*.rulers", Columns, "[]", "Replaces editor.rulers"),
+    setting("explorer.exclude_patterns", List, "[]", "Glob patterns hidden from the explorer"),
+    setting("explorer.show_hidden_files", Bool, "false", "Show dot files"),
+    setting("explorer.use_gitignore", Bool, "true", "Also hide what .gitignore, .ignore and .git/info/exclude list"),
+    setting("explorer.sort_folders_first", Bool, "true", "List folders before files"),
+    setting("terminal.shell", Text, "powershell.exe", "Shell started in new terminals"),
+    setting("terminal.font_size", Integer { min: 6, max: 72 }, "13", "Terminal font size in points"),
+    setting("terminal.cursor_blink", Bool, "true", "Blink the terminal cursor"),
+    setting("terminal.scrollback", Integer { min: 0, max: 1_000_000 }, "10000", "Lines kept above the terminal screen"),
+    setting("git.auto_fetch", Bool, "false", "Fetch from remotes in the background"),
+    setting("git.show_inline_blame", Bool, "false", "Show who last changed the current line"),
+    setting("git.show_gutter_indicators", Bool, "true", "Mark added, changed and removed lines in the gutter"),
+    setting("search.case_sensitive", Bool, "false", "Match case in Find in Files"),
+    setting("search.whole_word", Bool, "false", "Match whole words in Find in Files"),
+    setting("search.use_regex", Bool, "false", "Treat Find in Files queries as regular expressions"),
+    setting("search.exclude_patterns", List, "[]", "Glob patterns Find in Files skips"),
+    setting("todos.keywords", List, "[TODO, FIXME, HACK]", "Words that start a comment listed on the TODOs page"),
+    setting("todos.scan_workspace", Bool, "false", "Also list the comments of workspace files that aren't open"),
+    setting("animations.enabled", Bool, "true", "Animate the UI"),
+    setting("animations.speed", Number { min: 0.1, max: 10.0 }, "1.0", "Multiplier for every UI animation; 2.0 settles twice as fast"),
+    setting("animations.respect_reduced_motion", Bool, "true", "Turn animations off when the OS asks for reduced motion"),
+    setting("animations.smooth_scrolling", Bool, "true", "Glide to new scroll positions in the editor and scrolling lists"),
+    setting("animations.scroll_duration_ms", Integer { min: 0, max: 2000 }, "150", "Milliseconds a smooth scroll takes"),
+    setting("animations.scroll_easing", Choice(&["ease_out", "ease_in_out", "linear"]), "ease_out", "How smooth scrolls speed up and slow down"),
+    setting("accessibility.text_scale", Number { min: 0.5, max: 3.0 }, "1.0", "Multiplier for UI text, independent of the display scale"),
+    setting("accessibility.min_hit_target", Number { min: 0.0, max: 64.0 }, "24", "Smallest size in logical pixels buttons and checkboxes react within"),
+    setting("window.material", Choice(&["none", "transparent", "acrylic", "mica"]), "none", "What shows through the window"),
+    setting("window.opacity", Number { min: 0.0, max: 1.0 }, "0.8", "Opacity of panel backgrounds while a material is active"),
+    setting("telemetry.endpoint", Text, "''", "https:// address (or http:// on this machine) receiving opted-in usage data; empty keeps it local"),
+    setting("telemetry.upload_interval_hours", Integer { min: 1, max: 8760 }, "24", "Hours between usage data uploads"),
+];
+
+/// Schema entry for a dotted key, matching `*` segments against any name
+pub fn find(key: &str) -> Option<&'static SettingSchema> {
+    SETTINGS_SCHEMA.iter().find(|schema| segments_match(schema.key, key, false))
+}
+
+/// Whether `pattern` matches `key`, or with `prefix` whether it continues below `key`
+fn segments_match(pattern: &str, key: &str, prefix: bool) -> bool {
+    let mut pattern = pattern.split('.');
+    for segment in key.split('.') {
+        match pattern.next() {
+            Some(expected) if expected == "*" || expected == segment => {}
+            _ => return false,
+        }
+    }
+    pattern.next().is_some() == prefix
+}
+
+/// A value in a settings file that doesn't fit the schema
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingIssue {
+    /// 1-based position of the key in the file, when it could be found
+    pub line: usize,
+    pub column: usize,
+    pub key: String,
+    pub message: String,
+}
+
+/// Check a parsed settings file against the schema, removing what doesn't fit
+///
+/// Removed values fall back to their defaults, so one typo doesn't discard the whole
+/// file. `content` is the file's text, used to point each issue at its line.
+pub fn validate(value: &mut Value, content: &str) -> Vec<SettingIssue> {
+    let mut issues = Vec::new();
+    if let Value::Mapping(mapping) = value {
+        validate_mapping(mapping, "", content, &mut issues);
+    } else if !value.is_null() {
+        issues.push(SettingIssue {
+            line: 1,
+            column: 1,
+            key: String::new(),
+            message: "expected sections like `editor:` at the top level".to_string(),
+        });
+        *value = Value::Null;
+    }
+    issues
+}
+
+fn validate_mapping(mapping: &mut Mapping, parent: &str, content: &str, issues: &mut Vec<SettingIssue>) {
+    let mut invalid = Vec::new();
+    for (name, value) in mapping.iter_mut() {
+        let Some(name) = name.as_str() else {
+            invalid.push(name.clone());
+            continue;
+        };
+        let key = if parent.is_empty() { name.to_string() } else { format!("{}.{}", parent, name) };
+        let problem = if let Some(schema) = find(&key) {
+            check(schema, value).err()
+        } else if SETTINGS_SCHEMA.iter().any(|schema| segments_match(schema.key, &key, true)) {
+            match value {
+                Value::Mapping(children) => {
+                    validate_mapping(children, &key, content, issues);
+                    None
+                }
+                Value::Null => None,
+                _ => Some("expected a section of settings".to_string()),
+            }
+        } else {
+            Some("unknown setting".to_string())
+        };
+        if let Some(message) = problem {
+            let (line, column) = locate(content, &key).unwrap_or((1, 1));
+            issues.push(SettingIssue { line, column, key, message });
+            invalid.push(Value::String(name.to_string()));
+        }
+    }
+    for name in invalid {
+        mapping.remove(&name);
+    }
+}
+
+fn check(schema: &SettingSchema, value: &Value) -> Result<(), String> {
+    match (schema.kind, value) {
+        (Bool, Value::Bool(_)) | (Text, Value::String(_)) => Ok(()),
+        (Integer { min, max }, Value::Number(number)) if number.is_i64() || number.is_u64() => {
+            match number.as_i64() {
+                Some(n) if (min..=max).contains(&n) => Ok(()),
+                _ => Err(format!("expected a whole number from {} to {}", min, max)),
+            }
+        }
+        (Number { min, max }, Value::Number(number)) => match number.as_f64() {
+            Some(n) if (min..=max).contains(&n) => Ok(()),
+            _ => Err(format!("expected a number from {} to {}", min, max)),
+        },
+        (Choice(choices), Value::String(choice)) if choices.contains(&choice.as_str()) => Ok(()),
+        // Auto save was a bool before it had modes; `deserialize_auto_save` still takes one
+        (Choice(_), Value::Bool(_)) if schema.key == "editor.auto_save" => Ok(()),
+        (List, Value::Sequence(items)) if items.iter().all(Value::is_string) => Ok(()),
+        (Columns, Value::Sequence(items)) if items.iter().all(|item| item.as_u64().is_some_and(|n| n <= u32::MAX as u64)) => Ok(()),
+        (Bool, _) => Err("expected true or false".to_string()),
+        (Integer { min, max }, _) => Err(format!("expected a whole number from {} to {}", min, max)),
+        (Number { min, max }, _) => Err(format!("expected a number from {} to {}", min, max)),
+        (Text, _) => Err("expected text".to_string()),
+        (Choice(choices), _) => Err(format!("expected one of: {}", choices.join(", "))),
+        (List, _) => Err("expected a list of text, e.g. [\"*.log\"]".to_string()),
+        (Columns, _) => Err("expected a list of column numbers, e.g. [80, 120]".to_string()),
+    }
+}
+
+/// Value of a dotted key in a settings tree
+pub fn lookup<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    key.split('.').try_fold(value, |value, name| value.get(name))
+}
+
+/// Show a value the way it would be written in settings.yml
+pub fn format_value(value: &Value) -> String {
+    match value {
+        Value::String(text) if text.is_empty() => "''".to_string(),
+        Value::String(text) => text.clone(),
+        _ => serde_yaml::to_string(value).map_or_else(|_| String::new(), |yaml| yaml.trim_end().to_string()),
+    }
+}
+
+/// 1-based line and column of a dotted key in block-style YAML, following indentation
+pub fn locate(content: &str, key: &str) -> Option<(usize, usize)> {
+    let wanted: Vec<&str> = key.split('.').collect();
+    let mut path: Vec<(usize, String)> = Vec::new(); // (indent, name) of the keys enclosing the line
+    for (index, line) in content.lines().enumerate() {
+        let Some((indent, name)) = key_on_line(line) else {
+            continue;
+        };
+        while path.last().is_some_and(|(parent_indent, _)| *parent_indent >= indent) {
+            path.pop();
+        }
+        path.push((indent, name.to_string()));
+        if path.len() == wanted.len() && path.iter().zip(&wanted).all(|((_, a), b)| a == b) {
+            return Some((index + 1, indent + 1));
+        }
+    }
+    None
+}
+
+/// Indentation and name of a `name:` line, skipping comments, blank lines and list items
+fn key_on_line(line: &str) -> Option<(usize, &str)> {
+    let trimmed = line.trim_start();
+    if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('-') {
+        return None;
+    }
+    let (name, _) = trimmed.split_once(':')?;
+    let name = name.trim().trim_matches(|c| c == '"' || c == '\'');
+    (!name.is_empty() && !name.contains(' ')).then_some((line.len() - trimmed.len(), name))
+}
+
+/// Set `section.name` to a YAML literal, keeping the rest of the file and its comments as they are
+pub fn set_value(content: &str, key: &str, literal: &str) -> String {
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    if let Some((line, column)) = locate(content, key) {
+        let text = &lines[line - 1];
+        let name_end = text[column - 1..].find(':').map_or(text.len(), |at| column - 1 + at + 1);
+        let comment = text[name_end..].find(" #").map(|at| text[name_end + at..].to_string()).unwrap_or_default();
+        lines[line - 1] = format!("{} {}{}", &text[..name_end], literal, comment);
+    } else {
+        let (section, name) = key.rsplit_once('.').unwrap_or(("", key));
+        match locate(content, section).filter(|_| !section.contains('.')) {
+            Some((line, column)) => {
+                // After the last line that belongs to the section, at its children's indentation
+                let mut end = line;
+                let mut indent = column - 1 + 2;
+                for (index, text) in lines.iter().enumerate().skip(line) {
+                    let trimmed = text.trim_start();
+                    if trimmed.is_empty() || trimmed.starts_with('#') {
+                        continue;
+                    }
+                    let text_indent = text.len() - trimmed.len();
+                    if text_indent < column {
+                        break;
+                    }
+                    if end == line {
+                        indent = text_indent;
+                    }
+                    end = index + 1;
+                }
+                lines.insert(end, format!("{}{}: {}", " ".repeat(indent), name, literal));
+            }
+            None => {
+                if lines.last().is_some_and(|last| !last.trim().is_empty()) {
+                    lines.push(String::new());
+                }
+                lines.push(format!("{}:", section));
+                lines.push(format!("  {}: {}", name, literal));
+            }
+        }
+    }
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    updated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issues_point_at_the_offending_line() {
+        let content = "editor:\n  font_size: huge\n  tab_size: 2\n  auto_save: true\nwindow:\n  material: glass\n  wobble: 3\n";
+        let mut value: Value = serde_yaml::from_str(content).unwrap();
+        let issues = validate(&mut value, content);
+        let found: Vec<_> = issues.iter().map(|issue| (issue.key.as_str(), issue.line, issue.column)).collect();
+        assert_eq!(found, [("editor.font_size", 2, 3), ("window.material", 6, 3), ("window.wobble", 7, 3)]);
+        assert_eq!(issues[1].message, "expected one of: none, transparent, acrylic, mica");
+
+        // Bad values are dropped, good ones stay
+        assert!(lookup(&value, "editor.font_size").is_none());
+        assert_eq!(lookup(&value, "editor.tab_size"), Some(&Value::from(2)));
+    }
+
+    #[test]
+    fn language_sections_use_wildcards() {
+        let content = "languages:\n  rust:\n    tab_size: 0\n    formatter:\n      command: rustfmt\n      args: [--edition, 2021]\n";
+        let mut value: Value = serde_yaml::from_str(content).unwrap();
+        let issues = validate(&mut value, content);
+        assert_eq!(issues.len(), 2);
+        assert_eq!((issues[0].key.as_str(), issues[0].line), ("languages.rust.tab_size", 3));
+        assert_eq!((issues[1].key.as_str(), issues[1].line), ("languages.rust.formatter.args", 6));
+    }
+
+    #[test]
+    fn rulers_take_column_numbers() {
+        let content = "editor:\n  rulers: [80, 120]\nlanguages:\n  python:\n    rulers: [\"79\"]\n";
+        let mut value: Value = serde_yaml::from_str(content).unwrap();
+        let issues = validate(&mut value, content);
+        assert_eq!(issues.len(), 1);
+        assert_eq!((issues[0].key.as_str(), issues[0].line), ("languages.python.rulers", 5));
+        assert_eq!(issues[0].message, "expected a list of column numbers, e.g. [80, 120]");
+    }
+
+    #[test]
+    fn set_value_keeps_comments() {
+        let content = "# My settings\neditor:\n  font_size: 14 # points\n  tab_size: 4\n\ngit:\n  auto_fetch: true\n";
+        let updated = set_value(content, "editor.font_size", "16");
+        assert_eq!(updated, content.replace("14 # points", "16 # points"));
+
+        let updated = set_value(content, "editor.word_wrap", "true");
+        assert!(updated.contains("  tab_size: 4\n  word_wrap: true\n\ngit:"));
+
+        let updated = set_value(content, "window.material", "mica");
+        assert!(updated.ends_with("  auto_fetch: true\n\nwindow:\n  material: mica\n"));
+    }
+}
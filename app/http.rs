@@ -0,0 +1,65 @@
+use std::net::IpAddr;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use ureq::http::Uri;
+use ureq::Agent;
+
+/// How long a request may take, from connecting to the end of the answer
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// One agent for every request, so connections and TLS sessions are reused
+static AGENT: LazyLock<Agent> = LazyLock::new(|| Agent::config_builder().timeout_global(Some(TIMEOUT)).build().into());
+
+/// POST a JSON body to an endpoint and check for a 2xx answer
+///
+/// The body may hold user data, so it only goes out over `https://`, or `http://` to this machine.
+pub fn post_json(endpoint: &str, body: &str) -> Result<(), String> {
+    let uri: Uri = endpoint.parse().map_err(|_| format!("'{}' is not a URL", endpoint))?;
+    match uri.scheme_str() {
+        Some("https") => {}
+        Some("http") if uri.host().is_some_and(is_loopback) => {}
+        Some("http") => return Err(format!("'{}' must use https:// unless it is on this machine", endpoint)),
+        _ => return Err(format!("unsupported endpoint '{}', expected https://", endpoint)),
+    }
+    AGENT
+        .post(endpoint)
+        .header("Content-Type", "application/json")
+        .send(body)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// GET the body of an `http://` or `https://` URL, failing on anything but a 2xx answer
+pub fn get(url: &str) -> Result<String, String> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(format!("unsupported URL '{}', expected http:// or https://", url));
+    }
+    AGENT
+        .get(url)
+        .call()
+        .and_then(|mut response| response.body_mut().read_to_string())
+        .map_err(|e| e.to_string())
+}
+
+/// `localhost` or a loopback address, IPv6 ones in brackets as they appear in a URL
+fn is_loopback(host: &str) -> bool {
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    host.eq_ignore_ascii_case("localhost") || host.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usage_data_only_leaves_the_machine_encrypted() {
+        let error = post_json("http://192.0.2.1:8080/usage", "{}").unwrap_err();
+        assert!(error.contains("must use https://"), "{}", error);
+        assert!(post_json("ftp://localhost/usage", "{}").unwrap_err().starts_with("unsupported"));
+        assert!(get("file:///extension.json").unwrap_err().starts_with("unsupported"));
+
+        assert!(is_loopback("localhost") && is_loopback("127.0.0.1") && is_loopback("[::1]"));
+        assert!(!is_loopback("192.0.2.1") && !is_loopback("example.com"));
+    }
+}
@@ -0,0 +1,108 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::messages::{UiMessage, UiSender};
+use crate::state::AppState;
+
+/// How long a later launch tries to reach the running instance, and how long a request may take
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+/// How long a later launch waits for the running instance to confirm it took the links
+const ACK_TIMEOUT: Duration = Duration::from_secs(2);
+/// Line the instance answers with once the links reached its event loop
+const ACK: &str = "ok";
+
+/// Receives the deep links later launches forward to this instance
+///
+/// The listener takes a local port and writes it, with a random 128-bit token, to a file next to
+/// the session lock that only the user can read. Requests that don't start with the token are
+/// dropped, so other local programs, other users and web pages can't drive the app.
+pub struct InstanceServer {
+    port: u16,
+}
+
+impl InstanceServer {
+    /// Start listening; each request reaches the event loop as a `UiMessage::Open`
+    pub fn start(ui: UiSender) -> std::io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        let port = listener.local_addr()?.port();
+        let token = new_token()?;
+        write_private(&AppState::instance_path(), &format!("{}\n{}\n", port, token))?;
+        
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                // A client that never finishes its request mustn't block the ones after it
+                let _ = stream.set_read_timeout(Some(CONNECT_TIMEOUT));
+                let mut lines = BufReader::new(&stream).lines().map_while(Result::ok);
+                if lines.next().as_deref() != Some(token.as_str()) {
+                    continue;
+                }
+                if !ui.send(UiMessage::Open(lines.collect())) {
+                    break;
+                }
+                let _ = writeln!(stream, "{}", ACK);
+            }
+        });
+        Ok(Self { port })
+    }
+    
+    /// Remove the port file, unless a newer instance has replaced it
+    pub fn stop(&self) {
+        let path = AppState::instance_path();
+        if read_instance().is_some_and(|(port, _)| port == self.port) {
+            if let Err(e) = fs::remove_file(&path) {
+                eprintln!("Failed to remove {}: {}", path.display(), e);
+            }
+        }
+    }
+}
+
+/// 128 bits from the operating system's random source, as hex
+fn new_token() -> io::Result<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes).map_err(|e| io::Error::other(format!("no random token: {}", e)))?;
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+/// Write a file only its owner can read; on Windows the profile folder's ACL already keeps others out
+fn write_private(path: &Path, content: &str) -> io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(path)?;
+    // The mode only applies to new files; one left by an older version may still be readable
+    #[cfg(unix)]
+    file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+    file.write_all(content.as_bytes())
+}
+
+fn read_instance() -> Option<(u16, String)> {
+    let content = fs::read_to_string(AppState::instance_path()).ok()?;
+    let mut lines = content.lines();
+    let port = lines.next()?.trim().parse().ok()?;
+    Some((port, lines.next()?.trim().to_string()))
+}
+
+/// Hand links to the running instance; false when none confirms it took them
+///
+/// A port file left by a crash may name a port another program now listens on, and that one
+/// won't answer, so the caller opens the links itself.
+pub fn forward(links: &[String]) -> bool {
+    let Some((port, token)) = read_instance() else {
+        return false;
+    };
+    let address = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+    let Ok(mut stream) = TcpStream::connect_timeout(&address, CONNECT_TIMEOUT) else {
+        return false;
+    };
+    let request = format!("{}\n{}\n", token, links.join("\n"));
+    if stream.write_all(request.as_bytes()).is_err() || stream.shutdown(Shutdown::Write).is_err() {
+        return false;
+    }
+    let _ = stream.set_read_timeout(Some(ACK_TIMEOUT));
+    let mut answer = String::new();
+    BufReader::new(stream).read_line(&mut answer).is_ok() && answer.trim_end() == ACK
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::SystemTime;
+use winit::event_loop::EventLoopProxy;
+
+use mikocore::{BlameLine, BranchStatus, DirEntry, FileCommit, SshHost};
+use mikoeditor::TodoComment;
+
+use crate::components::ActivityBarItem;
+use crate::git::GitAction;
+
+/// Something a background thread asks the UI to do
+///
+/// Worker threads can't touch the widgets, so they describe the change and the event loop
+/// applies it on the UI thread.
+#[derive(Debug)]
+pub enum UiMessage {
+    /// Toast with the text of a locale key, translated on the UI thread where the catalogs live
+    ShowToast { key: &'static str, args: Vec<(&'static str, String)>, icon: Option<&'static str> },
+    /// Line for the Output panel
+    Log(String),
+    /// Number shown on an activity bar item; 0 removes it
+    SetBadge { item: ActivityBarItem, count: usize },
+    /// Reload what the page behind an activity bar item shows
+    RefreshPanel(ActivityBarItem),
+    /// Files or deep links to open, as a later launch forwards them
+    Open(Vec<String>),
+    /// Newer versions found by an extension update check, by extension id
+    ExtensionUpdates(HashMap<String, String>),
+    /// `git blame` of a file as it was at `modified`; empty when git doesn't track it
+    Blame { path: PathBuf, modified: Option<SystemTime>, lines: Vec<BlameLine> },
+    /// Commits that changed a file, for the File History picker, or why git couldn't list them
+    FileHistory { path: PathBuf, commits: Result<Vec<FileCommit>, String> },
+    /// What a git command printed, or its error message
+    GitActionDone { action: GitAction, result: Result<String, String> },
+    /// Branch of the workspace repository; None outside one
+    GitStatus(Option<BranchStatus>),
+    /// Absolute path of the remote folder asked for, or why the host couldn't be reached
+    RemoteConnected { host: SshHost, result: Result<String, String> },
+    /// Entries of the remote folder whose local copy is `dir`
+    RemoteListing { dir: PathBuf, result: Result<Vec<DirEntry>, String> },
+    /// A remote file was copied to `path`, ready to open
+    RemoteDownloaded { path: PathBuf, result: Result<(), String> },
+    /// The file at `path` was copied back to the host
+    RemoteUploaded { path: PathBuf, result: Result<(), String> },
+    /// To-do comments of the workspace files that have any
+    WorkspaceTodos(Vec<(PathBuf, Vec<TodoComment>)>),
+}
+
+/// Sending half of the UI channel; clone one for every background thread
+#[derive(Clone)]
+pub struct UiSender {
+    sender: Sender<UiMessage>,
+    proxy: EventLoopProxy<()>,
+}
+
+impl UiSender {
+    /// Queue a message and wake the event loop to drain it; false once the app has closed
+    pub fn send(&self, message: UiMessage) -> bool {
+        self.sender.send(message).is_ok() && self.proxy.send_event(()).is_ok()
+    }
+}
+
+/// Channel from background threads to the event loop behind `proxy`
+pub fn channel(proxy: EventLoopProxy<()>) -> (UiSender, Receiver<UiMessage>) {
+    let (sender, receiver) = mpsc::channel();
+    (UiSender { sender, proxy }, receiver)
+}
@@ -0,0 +1,200 @@
+use mikoui::{file_icon_theme, t, Widget, FontManager};
+use mikoui::theme::current_theme;
+use mikoui::components::{CodiconIcons, Icon, IconSize};
+use skia_safe::{Canvas, Color, Paint, Rect};
+use std::path::{Path, PathBuf};
+use crate::state::Bookmark;
+
+const SUMMARY_HEIGHT: f32 = 24.0;
+const ROW_HEIGHT: f32 = 22.0;
+const PADDING: f32 = 8.0;
+/// Square hit area of a row's delete button, at its right end
+const DELETE_SIZE: f32 = 18.0;
+/// Label tint, the light blue of the editor's gutter bookmarks
+const LABEL_COLOR: Color = Color::from_rgb(96, 165, 250);
+
+/// What a press on the bookmarks page asks for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BookmarksAction {
+    /// Show the bookmarked line
+    Open(PathBuf, usize),
+    Remove(Bookmark),
+}
+
+/// The workspace's bookmarks, one row each with a button that deletes it
+pub struct BookmarkView {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    // Paths are shown relative to it
+    root: Option<PathBuf>,
+    bookmarks: Vec<Bookmark>,
+    hover_row: Option<usize>,
+    scroll_offset: f32,
+}
+
+impl BookmarkView {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            root: None,
+            bookmarks: Vec::new(),
+            hover_row: None,
+            scroll_offset: 0.0,
+        }
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+        self.scroll(0.0);
+    }
+
+    /// Folder the paths are shown relative to
+    pub fn set_root(&mut self, root: Option<PathBuf>) {
+        self.root = root;
+    }
+
+    pub fn bookmarks(&self) -> &[Bookmark] {
+        &self.bookmarks
+    }
+
+    pub fn set_bookmarks(&mut self, bookmarks: Vec<Bookmark>) {
+        self.bookmarks = bookmarks;
+        self.hover_row = self.hover_row.filter(|&row| row < self.bookmarks.len());
+        self.scroll(0.0);
+    }
+
+    fn list_rect(&self) -> Rect {
+        let top = self.y + SUMMARY_HEIGHT;
+        Rect::from_xywh(self.x, top, self.width, (self.y + self.height - top).max(0.0))
+    }
+
+    fn row_at(&self, x: f32, y: f32) -> Option<usize> {
+        let list = self.list_rect();
+        if x < list.left || x > list.right || y < list.top || y > list.bottom {
+            return None;
+        }
+        let row = ((y - list.top + self.scroll_offset) / ROW_HEIGHT) as usize;
+        (row < self.bookmarks.len()).then_some(row)
+    }
+
+    fn delete_rect(&self, row_top: f32) -> Rect {
+        Rect::from_xywh(self.x + self.width - PADDING - DELETE_SIZE, row_top + (ROW_HEIGHT - DELETE_SIZE) / 2.0, DELETE_SIZE, DELETE_SIZE)
+    }
+
+    pub fn scroll(&mut self, delta: f32) {
+        let total = self.bookmarks.len() as f32 * ROW_HEIGHT;
+        let max_scroll = (total - self.list_rect().height()).max(0.0);
+        self.scroll_offset = (self.scroll_offset + delta).clamp(0.0, max_scroll);
+    }
+
+    /// The bookmark pressed on, to open, or to remove when its delete button was pressed
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> Option<BookmarksAction> {
+        let row = self.row_at(x, y)?;
+        let bookmark = &self.bookmarks[row];
+        let row_top = self.list_rect().top + row as f32 * ROW_HEIGHT - self.scroll_offset;
+        if self.delete_rect(row_top).contains(skia_safe::Point::new(x, y)) {
+            return Some(BookmarksAction::Remove(bookmark.clone()));
+        }
+        Some(BookmarksAction::Open(bookmark.path.clone(), bookmark.line))
+    }
+
+    fn display_path(&self, path: &Path) -> (String, String) {
+        let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        let parent = path.parent().unwrap_or(Path::new(""));
+        let folder = self.root.as_deref().and_then(|root| parent.strip_prefix(root).ok()).unwrap_or(parent);
+        (name, folder.to_string_lossy().replace('\\', "/"))
+    }
+}
+
+impl Widget for BookmarkView {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut FontManager) {
+        let theme = current_theme();
+        let mut muted_paint = Paint::default();
+        muted_paint.set_anti_alias(true);
+        muted_paint.set_color(theme.muted_foreground);
+
+        let summary = if self.bookmarks.is_empty() { t!("bookmarks.none") } else { t!("bookmarks.count", count = self.bookmarks.len()) };
+        let font = font_manager.create_font(&summary, 12.0, 400);
+        canvas.draw_str(&summary, (self.x + PADDING + 4.0, self.y + 16.0), &font, &muted_paint);
+
+        let list = self.list_rect();
+        canvas.save();
+        canvas.clip_rect(list, None, None);
+        let icons = file_icon_theme();
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        text_paint.set_color(theme.foreground);
+        let mut label_paint = Paint::default();
+        label_paint.set_anti_alias(true);
+        label_paint.set_color(LABEL_COLOR);
+        for (index, bookmark) in self.bookmarks.iter().enumerate() {
+            let y = list.top + index as f32 * ROW_HEIGHT - self.scroll_offset;
+            if y + ROW_HEIGHT < list.top || y > list.bottom {
+                continue;
+            }
+            if self.hover_row == Some(index) {
+                let mut hover_paint = Paint::default();
+                hover_paint.set_color(theme.muted);
+                canvas.draw_rect(Rect::from_xywh(self.x, y, self.width, ROW_HEIGHT), &hover_paint);
+            }
+            let baseline = y + 15.0;
+            let x = self.x + PADDING;
+            Icon::new(x, y + 3.0, CodiconIcons::BOOKMARK, IconSize::Small, LABEL_COLOR).draw(canvas, font_manager);
+
+            // The label leads when there is one, then the file and line
+            let mut text_x = x + 20.0;
+            if !bookmark.label.is_empty() {
+                let font = font_manager.create_font(&bookmark.label, 13.0, 500);
+                canvas.draw_str(&bookmark.label, (text_x, baseline), &font, &label_paint);
+                text_x += font.measure_str(&bookmark.label, Some(&label_paint)).0 + 8.0;
+            }
+            let (name, folder) = self.display_path(&bookmark.path);
+            icons.file_icon(&name, None).to_icon(text_x, y + 3.0, IconSize::Small, theme.foreground).draw(canvas, font_manager);
+            let location = format!("{}:{}", name, bookmark.line + 1);
+            let font = font_manager.create_font(&location, 13.0, 400);
+            let name_x = text_x + 20.0;
+            canvas.draw_str(&location, (name_x, baseline), &font, &text_paint);
+            let name_width = font.measure_str(&location, Some(&text_paint)).0;
+            let folder_font = font_manager.create_font(&folder, 12.0, 400);
+            canvas.draw_str(&folder, (name_x + name_width + 6.0, baseline), &folder_font, &muted_paint);
+
+            // Delete button, over the end of long rows while hovered
+            if self.hover_row == Some(index) {
+                let button = self.delete_rect(y);
+                let mut button_paint = Paint::default();
+                button_paint.set_color(theme.muted);
+                canvas.draw_rect(Rect::from_xywh(button.left - PADDING, y, button.width() + PADDING * 2.0, ROW_HEIGHT), &button_paint);
+                Icon::new(button.left + 1.0, button.top + 1.0, CodiconIcons::CLOSE, IconSize::Small, theme.foreground).draw(canvas, font_manager);
+            }
+        }
+        canvas.restore();
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hover_row = self.row_at(x, y);
+    }
+
+    fn update_animation(&mut self, _elapsed: f32) {}
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -1,9 +1,16 @@
-use mikoui::{Widget, FontManager};
-use mikoui::theme::current_theme;
-use mikoui::components::{Icon, IconSize, CodiconIcons};
-use skia_safe::{Canvas, Color, Paint, Rect};
+use mikoui::{file_icon_theme, t, FileIcon, FileIconTheme, Widget, FontManager};
+use mikoui::theme::{current_theme, with_alpha};
+use mikoui::components::{Icon, IconSize, CodiconIcons, Orientation, Scrollbar, Typeahead};
+use skia_safe::{Canvas, Paint, Rect};
+use mikocore::{DirEntry, WalkEntry, Walker};
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use std::fs;
+
+/// Entries read when matching a filter, so huge folders can't stall typing
+const MAX_FILTER_ENTRIES: usize = 50_000;
+
+/// Pointer travel before a press on a row turns into a drag
+const DRAG_THRESHOLD: f32 = 4.0;
 
 /// File tree item
 #[derive(Debug, Clone)]
@@ -17,50 +24,60 @@ pub struct FileItem {
 }
 
 impl FileItem {
-    pub fn new(path: PathBuf, depth: usize) -> Self {
-        let name = path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("")
-            .to_string();
-        
-        let is_dir = path.is_dir();
-        
+    fn from_entry(entry: WalkEntry) -> Self {
+        let name = entry.path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
         Self {
             name,
-            path,
-            is_dir,
+            path: entry.path,
+            is_dir: entry.is_dir,
             is_expanded: false,
-            depth,
+            depth: entry.depth,
             children: Vec::new(),
         }
     }
     
-    pub fn load_children(&mut self) {
+    /// List the folder's entries once, skipping what the walker skips; a remote folder lists what was fetched so far
+    pub fn load_children(&mut self, walker: &Walker, remote: Option<&RemoteListings>) {
         if !self.is_dir || !self.children.is_empty() {
             return;
         }
-        
-        if let Ok(entries) = fs::read_dir(&self.path) {
-            let mut items: Vec<FileItem> = entries
-                .filter_map(|e| e.ok())
-                .map(|e| FileItem::new(e.path(), self.depth + 1))
-                .collect();
-            
-            // Sort: directories first, then alphabetically
-            items.sort_by(|a, b| {
-                match (a.is_dir, b.is_dir) {
-                    (true, false) => std::cmp::Ordering::Less,
-                    (false, true) => std::cmp::Ordering::Greater,
-                    _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-                }
-            });
-            
-            self.children = items;
-        }
+        let entries = match remote {
+            Some(remote) => remote.read_dir(&self.path),
+            None => walker.read_dir(&self.path),
+        };
+        self.children = entries.into_iter().map(FileItem::from_entry).collect();
+    }
+}
+
+/// Folders of a remote workspace listed so far, by the path of their local copy
+///
+/// Listing a remote folder takes a round trip, so the app fetches them in the background
+/// and hands them in with `Explorer::set_listing`; the tree shows the last listing meanwhile.
+#[derive(Debug, Default)]
+pub struct RemoteListings {
+    listed: HashMap<PathBuf, Vec<WalkEntry>>,
+    // Listed since the last refresh
+    fresh: HashSet<PathBuf>,
+    // Asked for and not back yet
+    requested: HashSet<PathBuf>,
+}
+
+impl RemoteListings {
+    fn read_dir(&self, dir: &Path) -> Vec<WalkEntry> {
+        self.listed.get(dir).cloned().unwrap_or_default()
     }
 }
 
+/// Rows pressed on and maybe being dragged onto a folder
+struct RowDrag {
+    paths: Vec<PathBuf>,
+    start: (f32, f32),
+    // Past the threshold, so releasing drops instead of clicking
+    moved: bool,
+    // Folder the rows would move into
+    target: Option<PathBuf>,
+}
+
 /// File Explorer
 pub struct Explorer {
     x: f32,
@@ -68,18 +85,36 @@ pub struct Explorer {
     width: f32,
     height: f32,
     root_path: PathBuf,
+    // Hidden files, ignore files and exclude globs from the explorer settings
+    walker: Walker,
+    // Set while the root is the local copy of a remote folder, which is listed from here instead
+    remote: Option<RemoteListings>,
     items: Vec<FileItem>,
-    scroll_offset: f32,
     hover_index: Option<usize>,
     expanded_paths: Vec<String>,
-    // Scrollbar state
-    scrollbar_width: f32,
-    scrollbar_hover: bool,
-    scrollbar_dragging: bool,
-    drag_start_y: f32,
-    drag_start_offset: f32,
+    // Owns the scroll offset
+    scrollbar: Scrollbar,
     // File opening
     clicked_file: Option<PathBuf>,
+    // Highlighted by Reveal in Explorer until the next click
+    revealed_path: Option<PathBuf>,
+    // Lowercase name filter; empty shows the whole tree
+    filter: String,
+    // Matches and the folders leading to them while filtering
+    filter_shown: HashSet<PathBuf>,
+    // Expanded folders to restore once the filter is cleared
+    expanded_before_filter: Option<Vec<String>>,
+    // Keyboard navigation: the row arrow keys move from, and whether keys come here
+    selected: Option<PathBuf>,
+    focused: bool,
+    typeahead: Typeahead,
+    // More rows picked with Ctrl+click or Shift+click, besides `selected`
+    multi_selected: HashSet<PathBuf>,
+    // Moving rows into a folder by dragging
+    drag: Option<RowDrag>,
+    // Row pressed on; opened or toggled once released without dragging
+    pending_click: Option<usize>,
+    mouse_pos: (f32, f32),
 }
 
 impl Explorer {
@@ -90,20 +125,30 @@ impl Explorer {
             width,
             height,
             root_path: PathBuf::new(),
+            walker: Walker::new(PathBuf::new()),
+            remote: None,
             items: Vec::new(),
-            scroll_offset: 0.0,
             hover_index: None,
             expanded_paths: Vec::new(),
-            scrollbar_width: 8.0,
-            scrollbar_hover: false,
-            scrollbar_dragging: false,
-            drag_start_y: 0.0,
-            drag_start_offset: 0.0,
+            scrollbar: Scrollbar::new(Orientation::Vertical),
             clicked_file: None,
+            revealed_path: None,
+            filter: String::new(),
+            filter_shown: HashSet::new(),
+            expanded_before_filter: None,
+            selected: None,
+            focused: false,
+            typeahead: Typeahead::new(),
+            multi_selected: HashSet::new(),
+            drag: None,
+            pending_click: None,
+            mouse_pos: (0.0, 0.0),
         }
     }
     
-    pub fn new_with_path(x: f32, y: f32, width: f32, height: f32, root_path: PathBuf) -> Self {
+    /// Show the walker's root folder
+    pub fn new_with_path(x: f32, y: f32, width: f32, height: f32, walker: Walker) -> Self {
+        let root_path = walker.root().to_path_buf();
         println!("Explorer::new_with_path called with: {}", root_path.display());
         println!("Path exists: {}", root_path.exists());
         println!("Path is_dir: {}", root_path.is_dir());
@@ -114,16 +159,24 @@ impl Explorer {
             width,
             height,
             root_path: root_path.clone(),
+            walker,
+            remote: None,
             items: Vec::new(),
-            scroll_offset: 0.0,
             hover_index: None,
             expanded_paths: Vec::new(),
-            scrollbar_width: 8.0,
-            scrollbar_hover: false,
-            scrollbar_dragging: false,
-            drag_start_y: 0.0,
-            drag_start_offset: 0.0,
+            scrollbar: Scrollbar::new(Orientation::Vertical),
             clicked_file: None,
+            revealed_path: None,
+            filter: String::new(),
+            filter_shown: HashSet::new(),
+            expanded_before_filter: None,
+            selected: None,
+            focused: false,
+            typeahead: Typeahead::new(),
+            multi_selected: HashSet::new(),
+            drag: None,
+            pending_click: None,
+            mouse_pos: (0.0, 0.0),
         };
         
         explorer.load_root();
@@ -131,10 +184,31 @@ impl Explorer {
         explorer
     }
     
-    pub fn set_root_path(&mut self, path: PathBuf) {
-        self.root_path = path;
+    /// Show another folder, or the same one with new exclusion settings
+    pub fn set_walker(&mut self, walker: Walker) {
+        self.remote = None;
+        self.set_root(walker);
+    }
+    
+    /// Show a remote folder through its local copy at `root`, keeping what was listed before
+    pub fn set_remote(&mut self, root: PathBuf, listings: RemoteListings) {
+        self.remote = Some(listings);
+        self.set_root(Walker::new(root));
+    }
+    
+    /// The remote listings, to hand to the explorer that replaces this one
+    pub fn take_remote(&mut self) -> Option<RemoteListings> {
+        self.remote.take()
+    }
+    
+    fn set_root(&mut self, walker: Walker) {
+        self.root_path = walker.root().to_path_buf();
+        self.walker = walker;
         self.items.clear();
         self.expanded_paths.clear();
+        self.filter.clear();
+        self.filter_shown.clear();
+        self.expanded_before_filter = None;
         self.load_root();
     }
     
@@ -153,18 +227,18 @@ impl Explorer {
     /// Expand all folders
     pub fn expand_all(&mut self) {
         self.expanded_paths.clear();
-        Self::expand_all_recursive(&mut self.items, &mut self.expanded_paths);
+        Self::expand_all_recursive(&mut self.items, &mut self.expanded_paths, &self.walker, self.remote.as_ref());
     }
     
-    fn expand_all_recursive(items: &mut [FileItem], expanded_paths: &mut Vec<String>) {
+    fn expand_all_recursive(items: &mut [FileItem], expanded_paths: &mut Vec<String>, walker: &Walker, remote: Option<&RemoteListings>) {
         for item in items {
             if item.is_dir {
                 item.is_expanded = true;
                 if item.children.is_empty() {
-                    item.load_children();
+                    item.load_children(walker, remote);
                 }
                 expanded_paths.push(item.path.to_string_lossy().to_string());
-                Self::expand_all_recursive(&mut item.children, expanded_paths);
+                Self::expand_all_recursive(&mut item.children, expanded_paths, walker, remote);
             }
         }
     }
@@ -184,8 +258,11 @@ impl Explorer {
         }
     }
     
-    /// Get list of expanded folder paths
+    /// Get list of expanded folder paths; while filtering, the ones from before the filter
     pub fn get_expanded_paths(&self) -> Vec<String> {
+        if let Some(expanded) = &self.expanded_before_filter {
+            return expanded.clone();
+        }
         let mut paths = Vec::new();
         Self::collect_expanded_paths(&self.items, &mut paths);
         paths
@@ -203,19 +280,19 @@ impl Explorer {
     /// Restore expanded state from paths
     pub fn restore_expanded_state(&mut self, paths: &[String]) {
         self.expanded_paths = paths.to_vec();
-        Self::restore_expanded_recursive(&mut self.items, paths);
+        Self::restore_expanded_recursive(&mut self.items, paths, &self.walker, self.remote.as_ref());
     }
     
-    fn restore_expanded_recursive(items: &mut [FileItem], paths: &[String]) {
+    fn restore_expanded_recursive(items: &mut [FileItem], paths: &[String], walker: &Walker, remote: Option<&RemoteListings>) {
         for item in items {
             if item.is_dir {
                 let path_str = item.path.to_string_lossy().to_string();
                 if paths.contains(&path_str) {
                     item.is_expanded = true;
                     if item.children.is_empty() {
-                        item.load_children();
+                        item.load_children(walker, remote);
                     }
-                    Self::restore_expanded_recursive(&mut item.children, paths);
+                    Self::restore_expanded_recursive(&mut item.children, paths, walker, remote);
                 }
             }
         }
@@ -229,115 +306,127 @@ impl Explorer {
         
         println!("Explorer: Loading root from: {}", self.root_path.display());
         
-        if !self.root_path.exists() {
+        if let Some(ref remote) = self.remote {
+            self.items = remote.read_dir(&self.root_path).into_iter().map(FileItem::from_entry).collect();
+            return;
+        }
+        if self.walker.file_system().metadata(&self.root_path).is_err() {
             eprintln!("Explorer: Root path does not exist: {}", self.root_path.display());
             return;
         }
         
         // Load root directory contents directly without showing the root folder itself
-        if let Ok(entries) = fs::read_dir(&self.root_path) {
-            let mut items: Vec<FileItem> = entries
-                .filter_map(|e| e.ok())
-                .map(|e| FileItem::new(e.path(), 0)) // Start at depth 0
-                .collect();
-            
-            // Sort: directories first, then alphabetically
-            items.sort_by(|a, b| {
-                match (a.is_dir, b.is_dir) {
-                    (true, false) => std::cmp::Ordering::Less,
-                    (false, true) => std::cmp::Ordering::Greater,
-                    _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-                }
-            });
-            
-            self.items = items;
-            println!("Explorer: Loaded {} items", self.items.len());
-        } else {
-            eprintln!("Explorer: Failed to read directory: {}", self.root_path.display());
-        }
+        self.items = self.walker.read_dir(&self.root_path).into_iter().map(FileItem::from_entry).collect();
+        println!("Explorer: Loaded {} items", self.items.len());
     }
     
     fn get_visible_items(&self) -> Vec<&FileItem> {
         let mut visible = Vec::new();
         
-        fn collect_visible<'a>(item: &'a FileItem, visible: &mut Vec<&'a FileItem>) {
+        fn collect_visible<'a>(item: &'a FileItem, visible: &mut Vec<&'a FileItem>, shown: Option<&HashSet<PathBuf>>) {
+            if shown.is_some_and(|shown| !shown.contains(&item.path)) {
+                return;
+            }
             visible.push(item);
             if item.is_expanded {
                 for child in &item.children {
-                    collect_visible(child, visible);
+                    collect_visible(child, visible, shown);
                 }
             }
         }
         
         // Collect all root-level items and their expanded children
+        let shown = self.is_filtering().then_some(&self.filter_shown);
         for item in &self.items {
-            collect_visible(item, &mut visible);
+            collect_visible(item, &mut visible, shown);
         }
         
         visible
     }
     
-    fn get_item_icon(&self, item: &FileItem) -> &'static str {
+    fn get_item_icon(item: &FileItem, icons: &FileIconTheme) -> FileIcon {
         if item.is_dir {
-            if item.is_expanded {
-                CodiconIcons::FOLDER_OPENED
-            } else {
-                CodiconIcons::FOLDER
-            }
+            icons.folder_icon(&item.name, item.is_expanded)
         } else {
-            // Determine icon based on file extension
-            if let Some(ext) = item.path.extension().and_then(|e| e.to_str()) {
-                match ext {
-                    "rs" => CodiconIcons::FILE_CODE,
-                    "toml" | "yml" | "yaml" | "json" => CodiconIcons::SETTINGS_GEAR,
-                    "md" => CodiconIcons::BOOK,
-                    "txt" => CodiconIcons::FILE_TEXT,
-                    "png" | "jpg" | "jpeg" | "gif" | "svg" => CodiconIcons::FILE_MEDIA,
-                    _ => CodiconIcons::FILE,
-                }
-            } else {
-                CodiconIcons::FILE
-            }
+            icons.file_icon(&item.name, None)
         }
     }
     
     pub fn toggle_item(&mut self, index: usize) {
-        let visible = self.get_visible_items();
-        if index >= visible.len() {
+        let Some(path) = self.get_visible_items().get(index).map(|item| item.path.clone()) else {
             return;
-        }
-        
-        // Find and toggle the item at the given visible index
-        let mut current_index = 0;
-        for item in &mut self.items {
-            if Self::toggle_at_index(item, index, &mut current_index) {
-                return;
+        };
+        if let Some(item) = Self::find_item_mut(&mut self.items, &path) {
+            item.is_expanded = !item.is_expanded;
+            if item.is_expanded {
+                item.load_children(&self.walker, self.remote.as_ref());
             }
         }
     }
     
-    fn toggle_at_index(item: &mut FileItem, target_index: usize, current_index: &mut usize) -> bool {
-        // Check if this is the target item
-        if *current_index == target_index {
-            item.is_expanded = !item.is_expanded;
-            if item.is_expanded && item.children.is_empty() {
-                item.load_children();
+    fn find_item_mut<'a>(items: &'a mut [FileItem], path: &Path) -> Option<&'a mut FileItem> {
+        for item in items {
+            if item.path == path {
+                return Some(item);
+            }
+            if item.is_dir && path.starts_with(&item.path) {
+                return Self::find_item_mut(&mut item.children, path);
             }
-            return true;
         }
+        None
+    }
+    
+    pub fn is_filtering(&self) -> bool {
+        !self.filter.is_empty()
+    }
+    
+    /// Show only entries whose name contains `filter`, ignoring case, and the folders leading to them
+    /// Folders holding matches are expanded; clearing the filter brings back the previous expansion.
+    pub fn set_filter(&mut self, filter: &str) {
+        let filter = filter.trim().to_lowercase();
+        if filter == self.filter {
+            return;
+        }
+        if !self.is_filtering() {
+            self.expanded_before_filter = Some(self.get_expanded_paths());
+        }
+        self.filter = filter;
+        self.filter_shown.clear();
+        self.hover_index = None;
+        self.revealed_path = None;
+        self.scrollbar.set_offset(0.0);
+        Self::collapse_all_recursive(&mut self.items);
         
-        *current_index += 1;
+        if !self.is_filtering() {
+            let expanded = self.expanded_before_filter.take().unwrap_or_default();
+            self.restore_expanded_state(&expanded);
+            return;
+        }
         
-        // If this item is expanded, check its children
-        if item.is_expanded {
-            for child in &mut item.children {
-                if Self::toggle_at_index(child, target_index, current_index) {
-                    return true;
+        let mut folders = HashSet::new();
+        // A remote folder can only be searched as far as it was listed
+        let entries: Box<dyn Iterator<Item = WalkEntry> + '_> = match self.remote {
+            Some(ref remote) => Box::new(remote.listed.values().flatten().cloned()),
+            None => Box::new(self.walker.iter()),
+        };
+        for entry in entries.take(MAX_FILTER_ENTRIES) {
+            let name = entry.path.file_name().map(|name| name.to_string_lossy().to_lowercase()).unwrap_or_default();
+            if !name.contains(&self.filter) {
+                continue;
+            }
+            let mut parent = entry.path.parent();
+            self.filter_shown.insert(entry.path.clone());
+            while let Some(folder) = parent.filter(|folder| *folder != self.root_path) {
+                // Folders already seen have their own parents in the set
+                if !folders.insert(folder.to_path_buf()) {
+                    break;
                 }
+                self.filter_shown.insert(folder.to_path_buf());
+                parent = folder.parent();
             }
         }
-        
-        false
+        let folders: Vec<String> = folders.iter().map(|folder| folder.to_string_lossy().to_string()).collect();
+        Self::restore_expanded_recursive(&mut self.items, &folders, &self.walker, self.remote.as_ref());
     }
     
     pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
@@ -347,82 +436,370 @@ impl Explorer {
         self.height = height;
     }
     
-    fn get_scrollbar_rect(&self) -> Rect {
+    /// Keep the scrollbar in step with the bounds and the number of visible rows
+    fn sync_scrollbar(&mut self) {
         let item_height = 28.0;
-        let visible_items = self.get_visible_items();
-        let total_height = visible_items.len() as f32 * item_height;
+        let total_height = self.get_visible_items().len() as f32 * item_height;
+        self.scrollbar.attach_to(Rect::from_xywh(self.x, self.y, self.width, self.height));
+        self.scrollbar.set_metrics(total_height, self.height);
+    }
+    
+    pub fn is_over_scrollbar(&self, x: f32, y: f32) -> bool {
+        self.scrollbar.is_scrollable() && self.scrollbar.contains(x, y)
+    }
+    
+    /// Grab the thumb or page through the track; returns true if the scrollbar was hit
+    pub fn handle_scrollbar_press(&mut self, x: f32, y: f32) -> bool {
+        self.sync_scrollbar();
+        self.scrollbar.handle_mouse_down(x, y)
+    }
+    
+    pub fn stop_scrollbar_drag(&mut self) {
+        self.scrollbar.handle_mouse_up();
+    }
+    
+    pub fn handle_scrollbar_drag(&mut self, x: f32, y: f32) {
+        self.scrollbar.handle_mouse_move(x, y);
+    }
+    
+    pub fn is_scrollbar_dragging(&self) -> bool {
+        self.scrollbar.is_dragging()
+    }
+    
+    pub fn is_animating(&self) -> bool {
+        self.scrollbar.is_animating()
+    }
+    
+    pub fn scroll(&mut self, delta: f32) {
+        self.sync_scrollbar();
+        self.scrollbar.set_offset(self.scrollbar.offset() + delta);
+    }
+    
+    /// Expand the folders leading to `path`, scroll it into view and highlight it
+    /// Returns false if the path is outside the opened folder
+    pub fn reveal(&mut self, path: &Path) -> bool {
+        let Ok(relative) = path.strip_prefix(&self.root_path) else {
+            return false;
+        };
+        // A filtered-out file can't be shown, so reveal the whole tree
+        if self.is_filtering() && !self.filter_shown.contains(path) {
+            self.set_filter("");
+        }
         
-        if total_height <= self.height {
-            return Rect::from_xywh(0.0, 0.0, 0.0, 0.0); // No scrollbar needed
+        let mut items = &mut self.items;
+        let mut current = self.root_path.clone();
+        for component in relative.parent().into_iter().flat_map(Path::components) {
+            current.push(component);
+            let Some(folder) = items.iter_mut().find(|item| item.is_dir && item.path == current) else {
+                return false;
+            };
+            folder.is_expanded = true;
+            if folder.children.is_empty() {
+                folder.load_children(&self.walker, self.remote.as_ref());
+            }
+            items = &mut folder.children;
         }
         
-        let scrollbar_height = (self.height / total_height * self.height).max(30.0);
-        let max_scroll = total_height - self.height;
-        let scroll_ratio = if max_scroll > 0.0 {
-            self.scroll_offset / max_scroll
-        } else {
-            0.0
+        let Some(index) = self.get_visible_items().iter().position(|item| item.path == path) else {
+            return false;
         };
-        let scrollbar_y = self.y + (self.height - scrollbar_height) * scroll_ratio;
-        
-        Rect::from_xywh(
-            self.x + self.width - self.scrollbar_width - 2.0,
-            scrollbar_y,
-            self.scrollbar_width,
-            scrollbar_height,
-        )
+        self.scroll_into_view(index);
+        self.revealed_path = Some(path.to_path_buf());
+        self.selected = Some(path.to_path_buf());
+        true
     }
     
-    pub fn is_over_scrollbar(&self, x: f32, y: f32) -> bool {
-        let rect = self.get_scrollbar_rect();
-        rect.width() > 0.0 && x >= rect.left && x <= rect.right && y >= rect.top && y <= rect.bottom
+    fn scroll_into_view(&mut self, index: usize) {
+        let item_height = 28.0;
+        self.sync_scrollbar();
+        let top = index as f32 * item_height;
+        let offset = self.scrollbar.offset();
+        if top < offset {
+            self.scrollbar.set_offset(top);
+        } else if top + item_height > offset + self.height {
+            self.scrollbar.set_offset(top + item_height - self.height);
+        }
     }
     
-    pub fn start_scrollbar_drag(&mut self, y: f32) {
-        self.scrollbar_dragging = true;
-        self.drag_start_y = y;
-        self.drag_start_offset = self.scroll_offset;
+    pub fn is_focused(&self) -> bool {
+        self.focused
     }
     
-    pub fn stop_scrollbar_drag(&mut self) {
-        self.scrollbar_dragging = false;
+    /// Take or give up the keyboard; taking it selects the first row if nothing is selected
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused && self.has_root();
+        self.typeahead.reset();
+        if self.focused && self.selected_index().is_none() {
+            self.selected = self.get_visible_items().first().map(|item| item.path.clone());
+        }
+    }
+    
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_deref()?;
+        self.get_visible_items().iter().position(|item| item.path == selected)
     }
     
-    pub fn handle_scrollbar_drag(&mut self, y: f32) {
-        if !self.scrollbar_dragging {
+    fn select_index(&mut self, index: usize) {
+        let Some(path) = self.get_visible_items().get(index).map(|item| item.path.clone()) else {
             return;
+        };
+        self.selected = Some(path);
+        self.multi_selected.clear();
+        self.revealed_path = None;
+        self.scroll_into_view(index);
+    }
+    
+    /// Ctrl+click adds or removes the hovered row, Shift+click selects the rows from the cursor to it
+    /// Returns false when no row is hovered.
+    pub fn extend_selection(&mut self, range: bool) -> bool {
+        let Some(index) = self.hover_index else {
+            return false;
+        };
+        let visible = self.get_visible_items();
+        let Some(path) = visible.get(index).map(|item| item.path.clone()) else {
+            return false;
+        };
+        let cursor = self.selected.as_deref().and_then(|selected| visible.iter().position(|item| item.path == selected));
+        if range {
+            let (start, end) = match cursor {
+                Some(cursor) => (cursor.min(index), cursor.max(index)),
+                None => (index, index),
+            };
+            let rows: HashSet<PathBuf> = visible[start..=end].iter().map(|item| item.path.clone()).collect();
+            drop(visible);
+            self.multi_selected = rows;
+            // The cursor stays put so the next Shift+click measures from it again
+            if cursor.is_none() {
+                self.selected = Some(path);
+            }
+        } else {
+            drop(visible);
+            if let Some(selected) = self.selected.take() {
+                self.multi_selected.insert(selected);
+            }
+            if !self.multi_selected.remove(&path) {
+                self.multi_selected.insert(path.clone());
+            }
+            self.selected = Some(path);
         }
-        
-        let item_height = 28.0;
-        let visible_items = self.get_visible_items();
-        let total_height = visible_items.len() as f32 * item_height;
-        let max_scroll = (total_height - self.height).max(0.0);
-        
-        if max_scroll <= 0.0 {
+        self.revealed_path = None;
+        self.focused = true;
+        self.typeahead.reset();
+        true
+    }
+    
+    /// Selected rows in tree order, for commands like delete
+    pub fn selected_paths(&self) -> Vec<PathBuf> {
+        self.get_visible_items()
+            .into_iter()
+            .filter(|item| self.is_selected(&item.path))
+            .map(|item| item.path.clone())
+            .collect()
+    }
+    
+    fn is_selected(&self, path: &Path) -> bool {
+        if self.multi_selected.is_empty() {
+            self.selected.as_deref() == Some(path)
+        } else {
+            self.multi_selected.contains(path)
+        }
+    }
+    
+    /// Read the tree again after files changed on disk, keeping open folders and the filter
+    ///
+    /// A remote tree keeps showing the last listings until the new ones come back.
+    pub fn refresh(&mut self) {
+        if let Some(ref mut remote) = self.remote {
+            remote.fresh.clear();
+            remote.requested.clear();
             return;
         }
-        
-        let delta_y = y - self.drag_start_y;
-        let scroll_ratio = delta_y / self.height;
-        let delta_scroll = scroll_ratio * total_height;
-        
-        self.scroll_offset = (self.drag_start_offset + delta_scroll).clamp(0.0, max_scroll);
+        let expanded = self.get_expanded_paths();
+        let filter = self.filter.clone();
+        self.filter.clear();
+        self.filter_shown.clear();
+        self.expanded_before_filter = None;
+        self.load_root();
+        self.restore_expanded_state(&expanded);
+        if !filter.is_empty() {
+            self.set_filter(&filter);
+        }
+        self.multi_selected.retain(|path| path.exists());
+        if self.selected.as_deref().is_some_and(|path| !path.exists()) {
+            self.selected = None;
+        }
+        self.hover_index = None;
     }
     
-    pub fn is_scrollbar_dragging(&self) -> bool {
-        self.scrollbar_dragging
+    /// Remote folders shown without a listing since the last refresh: the root and the expanded ones
+    ///
+    /// Each is handed out once until `set_listing` answers, or the next refresh.
+    pub fn take_listing_requests(&mut self) -> Vec<PathBuf> {
+        let Some(ref mut remote) = self.remote else {
+            return Vec::new();
+        };
+        let mut expanded = Vec::new();
+        Self::collect_expanded_paths(&self.items, &mut expanded);
+        std::iter::once(self.root_path.clone())
+            .chain(expanded.into_iter().map(PathBuf::from))
+            .filter(|dir| !remote.fresh.contains(dir) && remote.requested.insert(dir.clone()))
+            .collect()
     }
     
-    pub fn scroll(&mut self, delta: f32) {
-        let item_height = 28.0;
-        let visible_items = self.get_visible_items();
-        let total_items = visible_items.len().max(1);
-        let total_height = total_items as f32 * item_height;
-        let visible_height = self.height - 40.0; // Account for header
-        let max_scroll = (total_height - visible_height).max(0.0);
+    /// Show the entries of a remote folder, as fetched in the background
+    pub fn set_listing(&mut self, dir: PathBuf, entries: Vec<DirEntry>) {
+        let depth = dir.strip_prefix(&self.root_path).map_or(0, |relative| relative.components().count());
+        let expanded = self.get_expanded_paths();
+        let Some(ref mut remote) = self.remote else {
+            return;
+        };
+        let entries = entries
+            .into_iter()
+            .map(|entry| WalkEntry { path: dir.join(&entry.name), is_dir: entry.is_dir, depth })
+            .collect();
+        remote.requested.remove(&dir);
+        remote.fresh.insert(dir.clone());
+        remote.listed.insert(dir.clone(), entries);
+        if dir == self.root_path {
+            self.load_root();
+        } else if let Some(folder) = Self::find_item_mut(&mut self.items, &dir) {
+            folder.children.clear();
+        }
+        self.restore_expanded_state(&expanded);
+    }
+    
+    /// Right-click: a row outside the selection becomes the only selected row
+    /// Returns false when no row is under the mouse.
+    pub fn context_select(&mut self) -> bool {
+        let Some(path) = self.hover_index.and_then(|index| self.get_visible_items().get(index).map(|item| item.path.clone())) else {
+            return false;
+        };
+        if !self.is_selected(&path) {
+            self.selected = Some(path);
+            self.multi_selected.clear();
+        }
+        self.revealed_path = None;
+        self.focused = true;
+        true
+    }
+    
+    /// Follow the pointer while rows are pressed; past the threshold they drag onto the folder below it
+    pub fn drag_to(&mut self, x: f32, y: f32) {
+        let Some(drag) = self.drag.as_ref() else {
+            return;
+        };
+        let (dx, dy) = (x - drag.start.0, y - drag.start.1);
+        if !drag.moved && dx.abs().max(dy.abs()) < DRAG_THRESHOLD {
+            return;
+        }
+        self.update_hover(x, y);
+        // Onto a folder, into the folder holding a file, or into the root below the last row
+        let target = if !self.contains(x, y) {
+            None
+        } else if let Some(item) = self.hover_index.and_then(|index| self.get_visible_items().get(index).copied()) {
+            if item.is_dir { Some(item.path.clone()) } else { item.path.parent().map(Path::to_path_buf) }
+        } else {
+            Some(self.root_path.clone())
+        };
+        let Some(drag) = self.drag.as_mut() else {
+            return;
+        };
+        drag.moved = true;
+        // A folder can't move into itself
+        drag.target = target.filter(|target| !drag.paths.iter().any(|path| target.starts_with(path)));
+    }
+    
+    /// Release after a press on a row: the dragged paths and the folder they were dropped on, if any
+    pub fn end_drag(&mut self) -> Option<(Vec<PathBuf>, PathBuf)> {
+        let drag = self.drag.take()?;
+        let pending = self.pending_click.take();
+        if drag.moved {
+            return drag.target.map(|target| (drag.paths, target));
+        }
+        if let Some(index) = pending {
+            self.click_row(index);
+        }
+        None
+    }
+    
+    /// Select a row, toggling a folder or opening a file
+    fn click_row(&mut self, index: usize) {
+        let Some((path, is_dir)) = self.get_visible_items().get(index).map(|item| (item.path.clone(), item.is_dir)) else {
+            return;
+        };
+        self.selected = Some(path.clone());
+        self.multi_selected.clear();
+        if is_dir {
+            self.toggle_item(index);
+        } else {
+            println!("File clicked: {}", path.display());
+            self.clicked_file = Some(path);
+        }
+    }
+    
+    /// Arrow keys move the selection, Right/Left expand and collapse folders, Enter opens
+    /// Returns false for keys the explorer doesn't use.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        let visible = self.get_visible_items();
+        let count = visible.len();
+        if count == 0 {
+            return false;
+        }
+        let current = self.selected_index();
+        let item = current.map(|index| (index, visible[index].path.clone(), visible[index].is_dir, visible[index].is_expanded));
+        // The row right below an open folder is its first child, if it has any
+        let first_child = current.filter(|&index| visible.get(index + 1).is_some_and(|next| next.depth > visible[index].depth)).map(|index| index + 1);
+        drop(visible);
+        let page = ((self.height / 28.0) as usize).max(1);
         
-        // Apply scroll delta with smooth clamping
-        self.scroll_offset = (self.scroll_offset + delta).clamp(0.0, max_scroll);
+        match key {
+            "ArrowDown" => self.select_index(current.map_or(0, |index| (index + 1).min(count - 1))),
+            "ArrowUp" => self.select_index(current.map_or(0, |index| index.saturating_sub(1))),
+            "PageDown" => self.select_index(current.map_or(0, |index| (index + page).min(count - 1))),
+            "PageUp" => self.select_index(current.map_or(0, |index| index.saturating_sub(page))),
+            "Home" => self.select_index(0),
+            "End" => self.select_index(count - 1),
+            "ArrowRight" => match item {
+                Some((index, _, true, false)) => self.toggle_item(index),
+                Some((_, _, true, true)) => {
+                    if let Some(child) = first_child {
+                        self.select_index(child);
+                    }
+                }
+                _ => {}
+            },
+            "ArrowLeft" => match item {
+                Some((index, _, true, true)) => self.toggle_item(index),
+                Some((_, path, _, _)) => {
+                    let parent = path.parent().filter(|parent| *parent != self.root_path);
+                    let index = parent.and_then(|parent| self.get_visible_items().iter().position(|item| item.path == parent));
+                    if let Some(index) = index {
+                        self.select_index(index);
+                    }
+                }
+                None => {}
+            },
+            "Enter" => match item {
+                Some((index, _, true, _)) => self.toggle_item(index),
+                Some((_, path, false, _)) => self.clicked_file = Some(path),
+                None => {}
+            },
+            _ => return false,
+        }
+        self.typeahead.reset();
+        true
+    }
+    
+    /// Jump to the next row whose name starts with the typed chars
+    pub fn handle_char(&mut self, c: char) {
+        if c.is_control() {
+            return;
+        }
+        let names: Vec<String> = self.get_visible_items().iter().map(|item| item.name.clone()).collect();
+        let current = self.selected_index();
+        if let Some(index) = self.typeahead.type_char(c, current, &names) {
+            self.select_index(index);
+        }
     }
     
     /// Get the clicked file path (if any) and clear it
@@ -442,14 +819,14 @@ impl Widget for Explorer {
         
         // Show welcome message if no folder is open
         if !self.has_root() {
-            let welcome_text = "No folder opened";
-            let font = font_manager.create_font(welcome_text, 14.0, 400);
+            let welcome_text = t!("explorer.no_folder");
+            let font = font_manager.create_font(&welcome_text, 14.0, 400);
             let mut text_paint = Paint::default();
             text_paint.set_color(theme.muted_foreground);
             text_paint.set_anti_alias(true);
             
             canvas.draw_str(
-                welcome_text,
+                &welcome_text,
                 (self.x + 16.0, self.y + 40.0),
                 &font,
                 &text_paint,
@@ -461,9 +838,19 @@ impl Widget for Explorer {
         let indent_size = 16.0;
         
         let visible_items = self.get_visible_items();
+        let icons = file_icon_theme();
+        
+        if visible_items.is_empty() && self.is_filtering() {
+            let text = t!("explorer.no_matches");
+            let font = font_manager.create_font(&text, 13.0, 400);
+            let mut text_paint = Paint::default();
+            text_paint.set_color(theme.muted_foreground);
+            text_paint.set_anti_alias(true);
+            canvas.draw_str(&text, (self.x + 16.0, self.y + 20.0), &font, &text_paint);
+        }
         
         for (i, item) in visible_items.iter().enumerate() {
-            let y = self.y + (i as f32 * item_height) - self.scroll_offset;
+            let y = self.y + (i as f32 * item_height) - self.scrollbar.offset();
             
             // Skip if not visible
             if y + item_height < self.y || y > self.y + self.height {
@@ -472,6 +859,38 @@ impl Widget for Explorer {
             
             let x = self.x + (item.depth as f32 * indent_size);
             
+            // Keyboard selection; dimmer while the keyboard is elsewhere
+            if self.is_selected(&item.path) {
+                let mut selected_paint = Paint::default();
+                selected_paint.set_color(with_alpha(theme.primary, if self.focused { 60 } else { 25 }));
+                selected_paint.set_anti_alias(true);
+                let row = Rect::from_xywh(self.x, y, self.width, item_height);
+                canvas.draw_rect(row, &selected_paint);
+                if self.focused {
+                    selected_paint.set_color(theme.ring);
+                    selected_paint.set_style(skia_safe::PaintStyle::Stroke);
+                    selected_paint.set_stroke_width(1.0);
+                    canvas.draw_rect(row.with_inset((0.5, 0.5)), &selected_paint);
+                }
+            }
+            
+            // Folder the dragged rows would drop into
+            let drop_target = self.drag.as_ref().filter(|drag| drag.moved).and_then(|drag| drag.target.as_deref());
+            if item.is_dir && drop_target == Some(item.path.as_path()) {
+                let mut drop_paint = Paint::default();
+                drop_paint.set_color(with_alpha(theme.primary, 40));
+                drop_paint.set_anti_alias(true);
+                canvas.draw_rect(Rect::from_xywh(self.x, y, self.width, item_height), &drop_paint);
+            }
+            
+            // Revealed item highlight
+            if self.revealed_path.as_deref() == Some(item.path.as_path()) {
+                let mut revealed_paint = Paint::default();
+                revealed_paint.set_color(theme.accent);
+                revealed_paint.set_anti_alias(true);
+                canvas.draw_rect(Rect::from_xywh(self.x, y, self.width, item_height), &revealed_paint);
+            }
+            
             // Hover background
             if self.hover_index == Some(i) {
                 let mut hover_paint = Paint::default();
@@ -507,13 +926,7 @@ impl Widget for Explorer {
             
             // File/folder icon
             let icon_x = x + if item.is_dir { 18.0 } else { 4.0 };
-            let file_icon = Icon::new(
-                icon_x,
-                y + 6.0,
-                self.get_item_icon(item),
-                IconSize::Small,
-                theme.foreground,
-            );
+            let file_icon = Self::get_item_icon(item, &icons).to_icon(icon_x, y + 6.0, IconSize::Small, theme.foreground);
             file_icon.draw(canvas, font_manager);
             
             // File name
@@ -523,6 +936,23 @@ impl Widget for Explorer {
             text_paint.set_color(theme.foreground);
             text_paint.set_anti_alias(true);
             
+            // Mark the part of the name the filter matched
+            if self.is_filtering() {
+                let lowercase = item.name.to_lowercase();
+                // Lowercasing can change byte lengths, so only highlight when the offsets still line up
+                if let Some(start) = lowercase.find(&self.filter).filter(|_| lowercase.len() == item.name.len()) {
+                    let end = start + self.filter.len();
+                    if item.name.is_char_boundary(start) && item.name.is_char_boundary(end) {
+                        let before = font.measure_str(&item.name[..start], Some(&text_paint)).0;
+                        let matched = font.measure_str(&item.name[start..end], Some(&text_paint)).0;
+                        let mut match_paint = Paint::default();
+                        match_paint.set_color(with_alpha(theme.primary, 70));
+                        match_paint.set_anti_alias(true);
+                        canvas.draw_rect(Rect::from_xywh(text_x + before, y + 5.0, matched, item_height - 10.0), &match_paint);
+                    }
+                }
+            }
+            
             canvas.draw_str(
                 &item.name,
                 (text_x, y + 18.0),
@@ -531,27 +961,7 @@ impl Widget for Explorer {
             );
         }
         
-        // Draw scrollbar if needed
-        let scrollbar_rect = self.get_scrollbar_rect();
-        if scrollbar_rect.width() > 0.0 {
-            let mut scrollbar_paint = Paint::default();
-            let alpha = if self.scrollbar_dragging {
-                180
-            } else if self.scrollbar_hover {
-                120
-            } else {
-                80
-            };
-            scrollbar_paint.set_color(Color::from_argb(alpha, 200, 200, 200));
-            scrollbar_paint.set_anti_alias(true);
-            
-            canvas.draw_round_rect(
-                scrollbar_rect,
-                4.0,
-                4.0,
-                &scrollbar_paint,
-            );
-        }
+        self.scrollbar.draw(canvas, font_manager);
     }
     
     fn contains(&self, x: f32, y: f32) -> bool {
@@ -559,22 +969,23 @@ impl Widget for Explorer {
     }
     
     fn update_hover(&mut self, x: f32, y: f32) {
+        self.mouse_pos = (x, y);
         if !self.contains(x, y) {
             self.hover_index = None;
-            self.scrollbar_hover = false;
+            self.scrollbar.update_hover(x, y);
             return;
         }
         
         // Check if hovering over scrollbar
-        self.scrollbar_hover = self.is_over_scrollbar(x, y);
+        self.scrollbar.update_hover(x, y);
         
-        if self.scrollbar_hover {
+        if self.scrollbar.is_hovered() {
             self.hover_index = None;
             return;
         }
         
         let item_height = 28.0;
-        let relative_y = y - self.y + self.scroll_offset;
+        let relative_y = y - self.y + self.scrollbar.offset();
         let index = (relative_y / item_height) as usize;
         
         let visible_count = self.get_visible_items().len();
@@ -585,35 +996,42 @@ impl Widget for Explorer {
         }
     }
     
-    fn update_animation(&mut self, _elapsed: f32) {
-        // No animation for now
+    fn update_animation(&mut self, elapsed: f32) {
+        self.sync_scrollbar();
+        self.scrollbar.update_animation(elapsed);
     }
     
     fn on_click(&mut self) {
         // Don't handle clicks if on scrollbar
-        if self.scrollbar_hover {
+        if self.scrollbar.is_hovered() {
             return;
         }
         
-        if let Some(index) = self.hover_index {
-            let visible = self.get_visible_items();
-            if let Some(item) = visible.get(index) {
-                let is_dir = item.is_dir;
-                let path = item.path.clone();
-                
-                // Drop the borrow before mutating self
-                drop(visible);
-                
-                if is_dir {
-                    // Toggle directory
-                    self.toggle_item(index);
-                } else {
-                    // Open file
-                    println!("File clicked: {}", path.display());
-                    self.clicked_file = Some(path);
-                }
-            }
+        self.revealed_path = None;
+        let Some(index) = self.hover_index else {
+            return;
+        };
+        let Some(path) = self.get_visible_items().get(index).map(|item| item.path.clone()) else {
+            return;
+        };
+        
+        // Clicking a row gives the explorer the keyboard
+        self.focused = true;
+        self.typeahead.reset();
+        
+        // Pressing a selected row keeps the selection so all of it can be dragged
+        if !self.is_selected(&path) {
+            self.selected = Some(path);
+            self.multi_selected.clear();
         }
+        // Opening or toggling waits for the release, so a press can start a drag instead
+        self.pending_click = Some(index);
+        self.drag = Some(RowDrag {
+            paths: self.selected_paths(),
+            start: self.mouse_pos,
+            moved: false,
+            target: None,
+        });
     }
     
     fn as_any(&self) -> &dyn std::any::Any {
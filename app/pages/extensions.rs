@@ -0,0 +1,508 @@
+use mikoui::{t, Widget, FontManager, Size, Theme, Variant};
+use mikoui::theme::{current_theme, with_alpha};
+use mikoui::components::{Button, CodiconIcons, Icon, IconSize, Input};
+use skia_safe::{Canvas, Paint, Rect};
+
+const HEADER_HEIGHT: f32 = 56.0;
+const SEARCH_HEIGHT: f32 = 44.0;
+const ROW_HEIGHT: f32 = 56.0;
+const ITEM_HEIGHT: f32 = 28.0;
+const PADDING: f32 = 16.0;
+const LIST_COLUMN: f32 = 0.45;
+const TOGGLE_WIDTH: f32 = 72.0;
+const UPDATES_BUTTON_WIDTH: f32 = 150.0;
+
+/// One installed extension as the Extensions page lists it
+#[derive(Debug, Clone)]
+pub struct ExtensionRow {
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    pub publisher: String,
+    pub description: String,
+    pub enabled: bool,
+    /// Newer version found by the last update check
+    pub update: Option<String>,
+    /// Contributed commands, with their palette id while the extension is enabled
+    pub commands: Vec<(Option<u32>, String)>,
+    pub themes: Vec<String>,
+}
+
+/// What the app should do after the page handled an event
+pub enum ExtensionsAction {
+    SetEnabled(String, bool),
+    CheckForUpdates,
+    /// Run a contributed command by its palette id
+    RunCommand(u32),
+    /// Switch to a contributed color theme
+    UseTheme(String),
+}
+
+/// Page listing the installed extensions, turning them on and off and showing what they contribute
+pub struct ExtensionsPage {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    visible: bool,
+    rows: Vec<ExtensionRow>,
+    /// Indices into `rows` matching the search
+    filtered: Vec<usize>,
+    selected: usize,
+    hover_row: Option<usize>,
+    hover_item: Option<usize>,
+    scroll_offset: f32,
+    search_input: Input,
+    updates_button: Button,
+    checking: bool,
+    /// Folder extensions are installed in, for the empty page
+    install_dir: String,
+}
+
+impl ExtensionsPage {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        let mut page = Self {
+            x,
+            y,
+            width,
+            height,
+            visible: false,
+            rows: Vec::new(),
+            filtered: Vec::new(),
+            selected: 0,
+            hover_row: None,
+            hover_item: None,
+            scroll_offset: 0.0,
+            search_input: Input::new(0.0, 0.0, width, t!("extensions.search")).size(Size::Sm),
+            updates_button: Button::new(0.0, 0.0, UPDATES_BUTTON_WIDTH, t!("extensions.check_for_updates"))
+                .size(Size::Sm)
+                .variant(Variant::Outline),
+            checking: false,
+            install_dir: String::new(),
+        };
+        page.layout();
+        page
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+        self.layout();
+        self.scroll(0.0);
+    }
+
+    fn layout(&mut self) {
+        self.search_input.set_bounds(self.x + PADDING, self.y + HEADER_HEIGHT, self.width * LIST_COLUMN - PADDING);
+        let button_y = self.y + (HEADER_HEIGHT - 8.0 - self.updates_button.height()) / 2.0;
+        self.updates_button.set_position(self.x + self.width - PADDING - UPDATES_BUTTON_WIDTH, button_y);
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn show(&mut self) {
+        self.visible = true;
+        self.search_input.set_focused(true);
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn set_install_dir(&mut self, dir: impl Into<String>) {
+        self.install_dir = dir.into();
+    }
+
+    /// Grey out the update button while a check runs
+    pub fn set_checking(&mut self, checking: bool) {
+        self.checking = checking;
+        self.updates_button.set_disabled(checking);
+    }
+
+    /// Replace the listed extensions, keeping the selection on the same one
+    pub fn set_rows(&mut self, rows: Vec<ExtensionRow>) {
+        let selected = self.selected_row().map(|row| row.id.clone());
+        self.rows = rows;
+        self.update_filter();
+        if let Some(index) = selected.and_then(|id| self.filtered.iter().position(|&row| self.rows[row].id == id)) {
+            self.selected = index;
+        }
+    }
+
+    fn selected_row(&self) -> Option<&ExtensionRow> {
+        self.filtered.get(self.selected).map(|&index| &self.rows[index])
+    }
+
+    /// Match the search against names, publishers and descriptions
+    fn update_filter(&mut self) {
+        let query = self.search_input.text().trim().to_lowercase();
+        self.filtered = self.rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| {
+                query.is_empty()
+                    || [&row.name, &row.publisher, &row.description].iter().any(|text| text.to_lowercase().contains(&query))
+            })
+            .map(|(index, _)| index)
+            .collect();
+        self.selected = self.selected.min(self.filtered.len().saturating_sub(1));
+        self.scroll(0.0);
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        self.search_input.handle_char(c);
+        self.selected = 0;
+        self.update_filter();
+    }
+
+    pub fn handle_backspace(&mut self) {
+        self.search_input.handle_backspace();
+        self.update_filter();
+    }
+
+    /// Escape clears the search first, then closes the page
+    pub fn handle_escape(&mut self) {
+        if self.search_input.text().is_empty() {
+            self.hide();
+        } else {
+            self.search_input.clear();
+            self.update_filter();
+        }
+    }
+
+    /// Turn the selected extension on or off
+    pub fn toggle_selected(&self) -> Option<ExtensionsAction> {
+        self.selected_row().map(|row| ExtensionsAction::SetEnabled(row.id.clone(), !row.enabled))
+    }
+
+    pub fn move_selection(&mut self, delta: isize) {
+        if self.filtered.is_empty() {
+            return;
+        }
+        self.selected = self.selected.saturating_add_signed(delta).min(self.filtered.len() - 1);
+
+        // Keep the selected row in view
+        let list_height = self.list_rect().height();
+        let row_top = self.selected as f32 * ROW_HEIGHT;
+        if row_top < self.scroll_offset {
+            self.scroll_offset = row_top;
+        } else if row_top + ROW_HEIGHT > self.scroll_offset + list_height {
+            self.scroll_offset = row_top + ROW_HEIGHT - list_height;
+        }
+    }
+
+    fn list_rect(&self) -> Rect {
+        let top = self.y + HEADER_HEIGHT + SEARCH_HEIGHT;
+        let width = self.width * LIST_COLUMN - PADDING;
+        Rect::from_xywh(self.x + PADDING, top, width, (self.y + self.height - PADDING - top).max(0.0))
+    }
+
+    fn details_rect(&self) -> Rect {
+        let left = self.x + self.width * LIST_COLUMN + PADDING;
+        let top = self.y + HEADER_HEIGHT;
+        Rect::from_ltrb(left, top, (self.x + self.width - PADDING).max(left), (self.y + self.height - PADDING).max(top))
+    }
+
+    fn row_rect(&self, index: usize) -> Rect {
+        let list = self.list_rect();
+        Rect::from_xywh(list.left, list.top + index as f32 * ROW_HEIGHT - self.scroll_offset, list.width(), ROW_HEIGHT)
+    }
+
+    fn toggle_rect(&self, index: usize) -> Rect {
+        let row = self.row_rect(index);
+        Rect::from_xywh(row.right - TOGGLE_WIDTH - 8.0, row.top + 8.0, TOGGLE_WIDTH, 22.0)
+    }
+
+    fn row_at(&self, x: f32, y: f32) -> Option<usize> {
+        let list = self.list_rect();
+        if x < list.left || x > list.right || y < list.top || y > list.bottom {
+            return None;
+        }
+        let row = ((y - list.top + self.scroll_offset) / ROW_HEIGHT) as usize;
+        (row < self.filtered.len()).then_some(row)
+    }
+
+    /// Top of the first contributed command in the details, after the heading and description
+    fn items_top(&self) -> f32 {
+        self.details_rect().top + 96.0
+    }
+
+    /// Rows of the selected extension's commands then themes, each list under its heading
+    fn item_rects(&self) -> Vec<Rect> {
+        let Some(row) = self.selected_row() else {
+            return Vec::new();
+        };
+        let details = self.details_rect();
+        let mut top = self.items_top();
+        let mut rects = Vec::new();
+        for count in [row.commands.len(), row.themes.len()].into_iter().filter(|&count| count > 0) {
+            top += ITEM_HEIGHT;
+            for _ in 0..count {
+                rects.push(Rect::from_xywh(details.left, top, details.width(), ITEM_HEIGHT));
+                top += ITEM_HEIGHT;
+            }
+            top += ITEM_HEIGHT / 2.0;
+        }
+        rects
+    }
+
+    fn item_at(&self, x: f32, y: f32) -> Option<usize> {
+        self.item_rects().iter().position(|rect| rect.contains(skia_safe::Point::new(x, y)))
+    }
+
+    pub fn scroll(&mut self, delta: f32) {
+        let total = self.filtered.len() as f32 * ROW_HEIGHT;
+        let max_scroll = (total - self.list_rect().height()).max(0.0);
+        self.scroll_offset = (self.scroll_offset + delta).clamp(0.0, max_scroll);
+    }
+
+    /// Click a row to select it, its button to turn it on or off, a contribution to use it
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> Option<ExtensionsAction> {
+        if self.updates_button.hit_test(x, y) {
+            self.updates_button.on_click();
+            return (!self.checking).then_some(ExtensionsAction::CheckForUpdates);
+        }
+        if let Some(row) = self.row_at(x, y) {
+            self.selected = row;
+            return self.toggle_rect(row).contains(skia_safe::Point::new(x, y)).then(|| self.toggle_selected()).flatten();
+        }
+        let item = self.item_at(x, y)?;
+        let row = self.selected_row()?;
+        if !row.enabled {
+            return None;
+        }
+        match row.commands.get(item) {
+            Some((id, _)) => id.map(ExtensionsAction::RunCommand),
+            None => row.themes.get(item - row.commands.len()).map(|name| ExtensionsAction::UseTheme(name.clone())),
+        }
+    }
+
+    fn draw_list(&self, canvas: &Canvas, font_manager: &mut FontManager) {
+        let theme = current_theme();
+        let list = self.list_rect();
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+
+        if self.rows.is_empty() {
+            let message = t!("extensions.none_installed", dir = self.install_dir);
+            let font = font_manager.create_font(&message, Theme::TEXT_XS, 400);
+            text_paint.set_color(theme.muted_foreground);
+            canvas.draw_str(&message, (list.left + 8.0, list.top + 20.0), &font, &text_paint);
+            return;
+        }
+
+        canvas.save();
+        canvas.clip_rect(list, None, true);
+        for (index, &row_index) in self.filtered.iter().enumerate() {
+            let row = self.row_rect(index);
+            if row.bottom < list.top || row.top > list.bottom {
+                continue;
+            }
+            let extension = &self.rows[row_index];
+            let selected = index == self.selected;
+
+            if selected || self.hover_row == Some(index) {
+                let mut row_paint = Paint::default();
+                row_paint.set_anti_alias(true);
+                row_paint.set_color(if selected { theme.accent } else { with_alpha(theme.accent, 128) });
+                canvas.draw_round_rect(row, Theme::RADIUS_SM, Theme::RADIUS_SM, &row_paint);
+            }
+
+            let name_font = font_manager.create_font(&extension.name, 13.0, 600);
+            text_paint.set_color(match (extension.enabled, selected) {
+                (false, _) => theme.muted_foreground,
+                (true, true) => theme.accent_foreground,
+                (true, false) => theme.foreground,
+            });
+            canvas.draw_str(&extension.name, (row.left + 8.0, row.top + 22.0), &name_font, &text_paint);
+            let name_width = name_font.measure_str(&extension.name, None).0;
+
+            let small_font = font_manager.create_font(&extension.version, Theme::TEXT_XS, 400);
+            text_paint.set_color(theme.muted_foreground);
+            canvas.draw_str(&extension.version, (row.left + 16.0 + name_width, row.top + 22.0), &small_font, &text_paint);
+            if let Some(version) = &extension.update {
+                let update = t!("extensions.update_available", version = version);
+                let version_width = small_font.measure_str(&extension.version, None).0;
+                text_paint.set_color(Theme::INFO);
+                canvas.draw_str(&update, (row.left + 28.0 + name_width + version_width, row.top + 22.0), &small_font, &text_paint);
+            }
+
+            let description = if extension.enabled { extension.description.clone() } else { t!("extensions.disabled") };
+            let description_font = font_manager.create_font(&description, Theme::TEXT_XS, 400);
+            text_paint.set_color(theme.muted_foreground);
+            canvas.draw_str(&description, (row.left + 8.0, row.top + 42.0), &description_font, &text_paint);
+
+            // Enable / Disable
+            let toggle = self.toggle_rect(index);
+            let label = if extension.enabled { t!("extensions.disable") } else { t!("extensions.enable") };
+            let mut chip_paint = Paint::default();
+            chip_paint.set_anti_alias(true);
+            chip_paint.set_color(if extension.enabled { theme.secondary } else { theme.primary });
+            canvas.draw_round_rect(toggle, Theme::RADIUS_SM, Theme::RADIUS_SM, &chip_paint);
+            let label_font = font_manager.create_font(&label, Theme::TEXT_XS, 500);
+            let label_width = label_font.measure_str(&label, None).0;
+            text_paint.set_color(if extension.enabled { theme.secondary_foreground } else { theme.primary_foreground });
+            canvas.draw_str(&label, (toggle.center_x() - label_width / 2.0, toggle.top + 15.0), &label_font, &text_paint);
+        }
+        canvas.restore();
+    }
+
+    fn draw_details(&self, canvas: &Canvas, font_manager: &mut FontManager) {
+        let Some(row) = self.selected_row() else {
+            return;
+        };
+        let theme = current_theme();
+        let details = self.details_rect();
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+
+        let name_font = font_manager.create_font(&row.name, Theme::TEXT_LG, 600);
+        text_paint.set_color(theme.foreground);
+        canvas.draw_str(&row.name, (details.left, details.top + 24.0), &name_font, &text_paint);
+
+        let about = match (row.publisher.is_empty(), &row.update) {
+            (true, None) => format!("{} · {}", row.id, row.version),
+            (false, None) => format!("{} · {} · {}", row.publisher, row.id, row.version),
+            (_, Some(update)) => format!("{} · {} → {}", row.id, row.version, update),
+        };
+        let small_font = font_manager.create_font(&about, Theme::TEXT_XS, 400);
+        text_paint.set_color(theme.muted_foreground);
+        canvas.draw_str(&about, (details.left, details.top + 46.0), &small_font, &text_paint);
+        let description_font = font_manager.create_font(&row.description, 13.0, 400);
+        text_paint.set_color(theme.foreground);
+        canvas.draw_str(&row.description, (details.left, details.top + 70.0), &description_font, &text_paint);
+
+        let rects = self.item_rects();
+        let mut headings = Vec::new();
+        if !row.commands.is_empty() {
+            headings.push((t!("extensions.commands", count = row.commands.len()), rects[0].top - ITEM_HEIGHT));
+        }
+        if !row.themes.is_empty() {
+            headings.push((t!("extensions.themes", count = row.themes.len()), rects[row.commands.len()].top - ITEM_HEIGHT));
+        }
+        if headings.is_empty() {
+            let message = t!("extensions.no_contributions");
+            text_paint.set_color(theme.muted_foreground);
+            canvas.draw_str(&message, (details.left, self.items_top() + 20.0), &small_font, &text_paint);
+        }
+        for (heading, top) in headings {
+            let heading_font = font_manager.create_font(&heading, Theme::TEXT_XS, 600);
+            text_paint.set_color(theme.muted_foreground);
+            canvas.draw_str(&heading, (details.left, top + 19.0), &heading_font, &text_paint);
+        }
+
+        let labels = row.commands
+            .iter()
+            .map(|(_, title)| (CodiconIcons::PLAY, title))
+            .chain(row.themes.iter().map(|name| (CodiconIcons::SYMBOL_COLOR, name)));
+        for (index, ((icon, label), rect)) in labels.zip(&rects).enumerate() {
+            if row.enabled && self.hover_item == Some(index) {
+                let mut hover_paint = Paint::default();
+                hover_paint.set_anti_alias(true);
+                hover_paint.set_color(with_alpha(theme.accent, 128));
+                canvas.draw_round_rect(*rect, Theme::RADIUS_SM, Theme::RADIUS_SM, &hover_paint);
+            }
+            let color = if row.enabled { theme.foreground } else { theme.muted_foreground };
+            Icon::new(rect.left + 6.0, rect.top + 6.0, icon, IconSize::Small, color).draw(canvas, font_manager);
+            let font = font_manager.create_font(label, 13.0, 400);
+            text_paint.set_color(color);
+            canvas.draw_str(label, (rect.left + 28.0, rect.top + 19.0), &font, &text_paint);
+        }
+        if !row.enabled && !(row.commands.is_empty() && row.themes.is_empty()) {
+            let hint = t!("extensions.enable_to_use");
+            let top = rects.last().map_or(self.items_top(), |rect| rect.bottom);
+            text_paint.set_color(theme.muted_foreground);
+            canvas.draw_str(&hint, (details.left, top + 24.0), &small_font, &text_paint);
+        }
+    }
+}
+
+impl Widget for ExtensionsPage {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut FontManager) {
+        if !self.visible {
+            return;
+        }
+
+        let theme = current_theme();
+
+        let mut bg_paint = Paint::default();
+        bg_paint.set_color(theme.background);
+        canvas.draw_rect(Rect::from_xywh(self.x, self.y, self.width, self.height), &bg_paint);
+
+        // Header
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        let title = t!("extensions.title");
+        let title_font = font_manager.create_font(&title, Theme::TEXT_LG, 600);
+        text_paint.set_color(theme.foreground);
+        canvas.draw_str(&title, (self.x + PADDING, self.y + 32.0), &title_font, &text_paint);
+
+        let enabled = self.rows.iter().filter(|row| row.enabled).count();
+        let updates = self.rows.iter().filter(|row| row.update.is_some()).count();
+        let hint = if self.checking {
+            t!("extensions.checking")
+        } else if updates > 0 {
+            t!("extensions.summary_updates", installed = self.rows.len(), enabled = enabled, updates = updates)
+        } else {
+            t!("extensions.summary", installed = self.rows.len(), enabled = enabled)
+        };
+        let hint_font = font_manager.create_font(&hint, Theme::TEXT_XS, 400);
+        text_paint.set_color(theme.muted_foreground);
+        let title_width = title_font.measure_str(&title, None).0;
+        canvas.draw_str(&hint, (self.x + PADDING * 2.0 + title_width, self.y + 31.0), &hint_font, &text_paint);
+        self.updates_button.draw(canvas, font_manager);
+
+        let mut border_paint = Paint::default();
+        border_paint.set_color(theme.border);
+        canvas.draw_rect(
+            Rect::from_xywh(self.x, self.y + HEADER_HEIGHT - 8.0, self.width, 1.0),
+            &border_paint,
+        );
+        let divider_x = self.x + self.width * LIST_COLUMN + PADDING / 2.0;
+        canvas.draw_rect(
+            Rect::from_xywh(divider_x, self.y + HEADER_HEIGHT, 1.0, (self.height - HEADER_HEIGHT - PADDING).max(0.0)),
+            &border_paint,
+        );
+
+        self.search_input.draw(canvas, font_manager);
+        self.draw_list(canvas, font_manager);
+        self.draw_details(canvas, font_manager);
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        self.visible && x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hover_row = self.row_at(x, y);
+        self.hover_item = self.item_at(x, y);
+        self.search_input.update_hover(x, y);
+        self.updates_button.update_hover(x, y);
+    }
+
+    fn update_animation(&mut self, elapsed: f32) {
+        self.search_input.update_animation(elapsed);
+        self.updates_button.update_animation(elapsed);
+    }
+
+    fn on_click(&mut self) {}
+
+    fn bounds(&self) -> Option<Rect> {
+        self.visible.then(|| Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        vec![&self.search_input as &dyn Widget, &self.updates_button]
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -1,3 +1,17 @@
+pub mod bookmarks;
 pub mod explorer;
+pub mod extensions;
+pub mod outline;
+pub mod search;
+pub mod shortcuts;
+pub mod themeeditor;
+pub mod todos;
 
-pub use explorer::Explorer;
+pub use bookmarks::{BookmarkView, BookmarksAction};
+pub use explorer::{Explorer, RemoteListings};
+pub use extensions::{ExtensionRow, ExtensionsAction, ExtensionsPage};
+pub use outline::Outline;
+pub use search::{SearchAction, SearchView};
+pub use shortcuts::{KeyboardShortcuts, KeyboardShortcutsAction, ShortcutRow};
+pub use themeeditor::{ThemeEditor, ThemeEditorAction};
+pub use todos::TodoView;
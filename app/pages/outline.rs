@@ -0,0 +1,175 @@
+use mikoui::{t, Widget, FontManager};
+use mikoui::theme::{current_theme, with_alpha};
+use mikoui::components::{CodiconIcons, Icon, IconSize};
+use mikoeditor::{DocumentSymbol, TokenType};
+use skia_safe::{Canvas, Paint, Rect};
+
+const ROW_HEIGHT: f32 = 24.0;
+const INDENT: f32 = 14.0;
+const PADDING: f32 = 12.0;
+
+/// Functions, types and headings of the active document, nested like the code
+pub struct Outline {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    symbols: Vec<DocumentSymbol>,
+    // Innermost symbol around the cursor
+    active: Option<usize>,
+    // Scroll the active symbol into view as the cursor moves
+    follow_cursor: bool,
+    hover_row: Option<usize>,
+    scroll_offset: f32,
+}
+
+impl Outline {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            symbols: Vec::new(),
+            active: None,
+            follow_cursor: true,
+            hover_row: None,
+            scroll_offset: 0.0,
+        }
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+        self.scroll(0.0);
+    }
+
+    pub fn set_symbols(&mut self, symbols: Vec<DocumentSymbol>) {
+        self.symbols = symbols;
+        self.active = None;
+        self.hover_row = None;
+        self.scroll(0.0);
+    }
+
+    pub fn follow_cursor(&self) -> bool {
+        self.follow_cursor
+    }
+
+    pub fn set_follow_cursor(&mut self, follow_cursor: bool) {
+        self.follow_cursor = follow_cursor;
+        self.reveal_active();
+    }
+
+    /// Highlight the symbol around the cursor; when following, scroll to it
+    pub fn set_cursor_line(&mut self, line: usize) {
+        let active = DocumentSymbol::enclosing(&self.symbols, line);
+        if active != self.active {
+            self.active = active;
+            self.reveal_active();
+        }
+    }
+
+    fn reveal_active(&mut self) {
+        let Some(active) = self.active.filter(|_| self.follow_cursor) else {
+            return;
+        };
+        let top = active as f32 * ROW_HEIGHT;
+        if top < self.scroll_offset {
+            self.scroll_offset = top;
+        } else if top + ROW_HEIGHT > self.scroll_offset + self.height {
+            self.scroll_offset = top + ROW_HEIGHT - self.height;
+        }
+    }
+
+    pub fn scroll(&mut self, delta: f32) {
+        let total = self.symbols.len() as f32 * ROW_HEIGHT;
+        let max_scroll = (total - self.height).max(0.0);
+        self.scroll_offset = (self.scroll_offset + delta).clamp(0.0, max_scroll);
+    }
+
+    fn row_at(&self, x: f32, y: f32) -> Option<usize> {
+        if !self.contains(x, y) {
+            return None;
+        }
+        let row = ((y - self.y + self.scroll_offset) / ROW_HEIGHT) as usize;
+        (row < self.symbols.len()).then_some(row)
+    }
+
+    /// Line and column of the symbol pressed on
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> Option<(usize, usize)> {
+        let symbol = &self.symbols[self.row_at(x, y)?];
+        Some((symbol.line, symbol.column))
+    }
+
+    fn icon(kind: TokenType) -> &'static str {
+        match kind {
+            TokenType::Function => CodiconIcons::SYMBOL_FUNCTION,
+            TokenType::Keyword => CodiconIcons::MARKDOWN,
+            _ => CodiconIcons::SYMBOL_CLASS,
+        }
+    }
+}
+
+impl Widget for Outline {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut FontManager) {
+        let theme = current_theme();
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+
+        if self.symbols.is_empty() {
+            let text = t!("outline.no_symbols");
+            let font = font_manager.create_font(&text, 13.0, 400);
+            text_paint.set_color(theme.muted_foreground);
+            canvas.draw_str(&text, (self.x + PADDING, self.y + 20.0), &font, &text_paint);
+            return;
+        }
+
+        canvas.save();
+        canvas.clip_rect(Rect::from_xywh(self.x, self.y, self.width, self.height), None, None);
+        text_paint.set_color(theme.foreground);
+        for (i, symbol) in self.symbols.iter().enumerate() {
+            let y = self.y + i as f32 * ROW_HEIGHT - self.scroll_offset;
+            if y + ROW_HEIGHT < self.y || y > self.y + self.height {
+                continue;
+            }
+            let row = Rect::from_xywh(self.x, y, self.width, ROW_HEIGHT);
+            if self.active == Some(i) {
+                let mut active_paint = Paint::default();
+                active_paint.set_color(with_alpha(theme.primary, 40));
+                canvas.draw_rect(row, &active_paint);
+            } else if self.hover_row == Some(i) {
+                let mut hover_paint = Paint::default();
+                hover_paint.set_color(theme.muted);
+                canvas.draw_rect(row, &hover_paint);
+            }
+
+            let x = self.x + PADDING + symbol.depth as f32 * INDENT;
+            Icon::new(x, y + 4.0, Self::icon(symbol.kind), IconSize::Small, theme.muted_foreground).draw(canvas, font_manager);
+            let font = font_manager.create_font(&symbol.name, 13.0, 400);
+            canvas.draw_str(&symbol.name, (x + 22.0, y + 17.0), &font, &text_paint);
+        }
+        canvas.restore();
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hover_row = self.row_at(x, y);
+    }
+
+    fn update_animation(&mut self, _elapsed: f32) {}
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,524 @@
+use mikoui::{file_icon_theme, t, Widget, FontManager, Size};
+use mikoui::theme::{current_theme, with_alpha};
+use mikoui::components::{CodiconIcons, Icon, IconSize, Input};
+use mikocore::{FileMatches, SearchQuery, TextMatch};
+use skia_safe::{Canvas, Font, Paint, Rect};
+use std::path::{Path, PathBuf};
+
+const INPUT_ROW_HEIGHT: f32 = 40.0;
+const SUMMARY_HEIGHT: f32 = 24.0;
+const ROW_HEIGHT: f32 = 22.0;
+const PADDING: f32 = 8.0;
+const BUTTON_SIZE: f32 = 24.0;
+const CHECKBOX_SIZE: f32 = 12.0;
+/// Match rows sit this far right of their file row
+const MATCH_INDENT: f32 = 20.0;
+/// Characters of context kept before a match in its preview
+const PREVIEW_CONTEXT: usize = 24;
+
+/// What the app should do after the search view handled an event
+pub enum SearchAction {
+    /// Run the query over the workspace again
+    Search,
+    /// Replace the checked matches
+    ReplaceAll,
+    /// Show a match in the editor, at a 0-based line and column
+    Open(PathBuf, usize, usize),
+}
+
+/// Toggle and action buttons next to the inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SearchButton {
+    MatchCase,
+    WholeWord,
+    ReplaceAll,
+}
+
+/// Matches of one file, each one checked (to be replaced) unless unticked
+struct FileResult {
+    path: PathBuf,
+    matches: Vec<TextMatch>,
+    checked: Vec<bool>,
+    expanded: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    File(usize),
+    /// File and match index
+    Match(usize, usize),
+}
+
+/// Search panel: finds text across the workspace and previews a replacement, grouped by file
+pub struct SearchView {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    // Result paths are shown relative to it
+    root: Option<PathBuf>,
+    query_input: Input,
+    replace_input: Input,
+    match_case: bool,
+    whole_word: bool,
+    results: Vec<FileResult>,
+    // Line under the inputs: the result count, or what the last replace did
+    message: Option<String>,
+    hover_row: Option<usize>,
+    hover_button: Option<SearchButton>,
+    scroll_offset: f32,
+}
+
+impl SearchView {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        let mut view = Self {
+            x,
+            y,
+            width,
+            height,
+            root: None,
+            query_input: Input::new(0.0, 0.0, width, t!("search.placeholder")).size(Size::Sm),
+            replace_input: Input::new(0.0, 0.0, width, t!("search.replace_placeholder")).size(Size::Sm),
+            match_case: false,
+            whole_word: false,
+            results: Vec::new(),
+            message: None,
+            hover_row: None,
+            hover_button: None,
+            scroll_offset: 0.0,
+        };
+        view.layout();
+        view
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+        self.layout();
+        self.scroll(0.0);
+    }
+
+    fn layout(&mut self) {
+        let input_y = (INPUT_ROW_HEIGHT - Size::Sm.height()) / 2.0;
+        let width = self.width - PADDING * 2.0;
+        self.query_input.set_bounds(self.x + PADDING, self.y + input_y, width - BUTTON_SIZE * 2.0 - 4.0);
+        self.replace_input.set_bounds(self.x + PADDING, self.y + INPUT_ROW_HEIGHT + input_y, width - BUTTON_SIZE - 4.0);
+    }
+
+    fn button_rect(&self, button: SearchButton) -> Rect {
+        let right = self.x + self.width - PADDING;
+        let (x, row) = match button {
+            SearchButton::MatchCase => (right - BUTTON_SIZE * 2.0, 0.0),
+            SearchButton::WholeWord => (right - BUTTON_SIZE, 0.0),
+            SearchButton::ReplaceAll => (right - BUTTON_SIZE, 1.0),
+        };
+        let y = self.y + row * INPUT_ROW_HEIGHT + (INPUT_ROW_HEIGHT - BUTTON_SIZE) / 2.0;
+        Rect::from_xywh(x, y, BUTTON_SIZE, BUTTON_SIZE)
+    }
+
+    fn list_rect(&self) -> Rect {
+        let top = self.y + INPUT_ROW_HEIGHT * 2.0 + SUMMARY_HEIGHT;
+        Rect::from_xywh(self.x, top, self.width, (self.y + self.height - top).max(0.0))
+    }
+
+    /// Folder the results are shown relative to
+    pub fn set_root(&mut self, root: Option<PathBuf>) {
+        self.root = root;
+    }
+
+    pub fn query(&self) -> SearchQuery {
+        SearchQuery::new(self.query_input.text())
+            .match_case(self.match_case)
+            .whole_word(self.whole_word)
+    }
+
+    pub fn replacement(&self) -> &str {
+        self.replace_input.text()
+    }
+
+    /// Fill in the query, e.g. the word to rename, and give the keyboard to the replace input
+    pub fn set_query(&mut self, text: &str, match_case: bool, whole_word: bool) {
+        self.query_input.set_text(text.to_string());
+        self.match_case = match_case;
+        self.whole_word = whole_word;
+        self.query_input.set_focused(false);
+        self.replace_input.set_focused(true);
+        self.replace_input.select_all();
+    }
+
+    pub fn focus_query(&mut self) {
+        self.replace_input.set_focused(false);
+        self.query_input.set_focused(true);
+        self.query_input.select_all();
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.query_input.is_focused() || self.replace_input.is_focused()
+    }
+
+    pub fn blur(&mut self) {
+        self.query_input.set_focused(false);
+        self.replace_input.set_focused(false);
+    }
+
+    /// Show where text dragged over the query or replace input would land; `None` clears it
+    pub fn preview_text_drop(&mut self, point: Option<(f32, f32)>, font_manager: &mut FontManager) {
+        self.query_input.preview_drop(point, font_manager);
+        self.replace_input.preview_drop(point, font_manager);
+    }
+
+    /// Insert dragged text into the input under the point; false when neither is there
+    pub fn drop_text(&mut self, x: f32, y: f32, text: &str, font_manager: &mut FontManager) -> bool {
+        if self.query_input.drop_text(x, y, text, font_manager) {
+            self.replace_input.set_focused(false);
+            true
+        } else if self.replace_input.drop_text(x, y, text, font_manager) {
+            self.query_input.set_focused(false);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Show new results, all of them checked; `limited` means the search stopped early
+    pub fn set_results(&mut self, results: Vec<FileMatches>, limited: bool) {
+        let count: usize = results.iter().map(|file| file.matches.len()).sum();
+        self.message = Some(match (count, limited) {
+            (0, _) => t!("search.no_results"),
+            (_, false) => t!("search.results", count = count, files = results.len()),
+            (_, true) => t!("search.results_limited", count = count, files = results.len()),
+        });
+        self.results = results
+            .into_iter()
+            .map(|file| FileResult {
+                checked: vec![true; file.matches.len()],
+                path: file.path,
+                matches: file.matches,
+                expanded: true,
+            })
+            .collect();
+        self.scroll_offset = 0.0;
+        self.hover_row = None;
+    }
+
+    pub fn clear_results(&mut self) {
+        self.results.clear();
+        self.message = None;
+        self.hover_row = None;
+    }
+
+    pub fn set_message(&mut self, message: impl Into<String>) {
+        self.message = Some(message.into());
+    }
+
+    /// Files with their checked matches, the ones Replace All rewrites
+    pub fn checked_matches(&self) -> Vec<(PathBuf, Vec<TextMatch>)> {
+        self.results
+            .iter()
+            .filter_map(|file| {
+                let matches: Vec<TextMatch> = file.matches.iter().zip(&file.checked).filter(|(_, checked)| **checked).map(|(found, _)| found.clone()).collect();
+                (!matches.is_empty()).then(|| (file.path.clone(), matches))
+            })
+            .collect()
+    }
+
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = Vec::new();
+        for (i, file) in self.results.iter().enumerate() {
+            rows.push(Row::File(i));
+            if file.expanded {
+                rows.extend((0..file.matches.len()).map(|j| Row::Match(i, j)));
+            }
+        }
+        rows
+    }
+
+    fn row_at(&self, x: f32, y: f32) -> Option<usize> {
+        let list = self.list_rect();
+        if x < list.left || x > list.right || y < list.top || y > list.bottom {
+            return None;
+        }
+        let row = ((y - list.top + self.scroll_offset) / ROW_HEIGHT) as usize;
+        (row < self.rows().len()).then_some(row)
+    }
+
+    fn button_at(&self, x: f32, y: f32) -> Option<SearchButton> {
+        [SearchButton::MatchCase, SearchButton::WholeWord, SearchButton::ReplaceAll]
+            .into_iter()
+            .find(|button| self.button_rect(*button).contains(skia_safe::Point::new(x, y)))
+    }
+
+    pub fn scroll(&mut self, delta: f32) {
+        let total = self.rows().len() as f32 * ROW_HEIGHT;
+        let max_scroll = (total - self.list_rect().height()).max(0.0);
+        self.scroll_offset = (self.scroll_offset + delta).clamp(0.0, max_scroll);
+    }
+
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> Option<SearchAction> {
+        self.query_input.set_focused(self.query_input.hit_test(x, y));
+        self.replace_input.set_focused(self.replace_input.hit_test(x, y));
+
+        match self.button_at(x, y) {
+            Some(SearchButton::MatchCase) => {
+                self.match_case = !self.match_case;
+                return Some(SearchAction::Search);
+            }
+            Some(SearchButton::WholeWord) => {
+                self.whole_word = !self.whole_word;
+                return Some(SearchAction::Search);
+            }
+            Some(SearchButton::ReplaceAll) => return Some(SearchAction::ReplaceAll),
+            None => {}
+        }
+
+        let row = self.rows()[self.row_at(x, y)?];
+        let on_checkbox = |indent: f32| x < self.x + PADDING + indent + CHECKBOX_SIZE + 4.0;
+        match row {
+            Row::File(i) if on_checkbox(0.0) => {
+                let file = &mut self.results[i];
+                let all = file.checked.iter().all(|checked| *checked);
+                file.checked.iter_mut().for_each(|checked| *checked = !all);
+            }
+            Row::File(i) => {
+                self.results[i].expanded = !self.results[i].expanded;
+                self.scroll(0.0);
+            }
+            Row::Match(i, j) if on_checkbox(MATCH_INDENT) => {
+                self.results[i].checked[j] = !self.results[i].checked[j];
+            }
+            Row::Match(i, j) => {
+                let (file, found) = (&self.results[i], &self.results[i].matches[j]);
+                return Some(SearchAction::Open(file.path.clone(), found.line, found.column));
+            }
+        }
+        None
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        if self.query_input.is_focused() {
+            self.query_input.handle_char(c);
+        } else if self.replace_input.is_focused() {
+            self.replace_input.handle_char(c);
+        }
+    }
+
+    pub fn handle_backspace(&mut self) {
+        if self.query_input.is_focused() {
+            self.query_input.handle_backspace();
+        } else if self.replace_input.is_focused() {
+            self.replace_input.handle_backspace();
+        }
+    }
+
+    /// Enter searches, Tab moves between the inputs and Escape hands the keyboard back
+    pub fn handle_key(&mut self, key: &str) -> Option<SearchAction> {
+        match key {
+            "Enter" => return Some(SearchAction::Search),
+            "Tab" => {
+                let query_focused = self.query_input.is_focused();
+                self.query_input.set_focused(!query_focused);
+                self.replace_input.set_focused(query_focused);
+            }
+            "Escape" => self.blur(),
+            _ => {}
+        }
+        None
+    }
+
+    fn display_path(&self, path: &Path) -> (String, String) {
+        let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        let parent = path.parent().unwrap_or(Path::new(""));
+        let folder = self.root.as_deref().and_then(|root| parent.strip_prefix(root).ok()).unwrap_or(parent);
+        (name, folder.to_string_lossy().replace('\\', "/"))
+    }
+
+    fn draw_checkbox(&self, canvas: &Canvas, x: f32, y: f32, checked: bool) {
+        let theme = current_theme();
+        let rect = Rect::from_xywh(x, y + (ROW_HEIGHT - CHECKBOX_SIZE) / 2.0, CHECKBOX_SIZE, CHECKBOX_SIZE);
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        if checked {
+            paint.set_color(theme.primary);
+            canvas.draw_round_rect(rect, 2.0, 2.0, &paint);
+            paint.set_color(theme.primary_foreground);
+            paint.set_style(skia_safe::PaintStyle::Stroke);
+            paint.set_stroke_width(1.5);
+            let (left, top) = (rect.left, rect.top);
+            canvas.draw_line((left + 3.0, top + 6.0), (left + 5.0, top + 8.5), &paint);
+            canvas.draw_line((left + 5.0, top + 8.5), (left + 9.0, top + 3.5), &paint);
+        } else {
+            paint.set_color(theme.border);
+            paint.set_style(skia_safe::PaintStyle::Stroke);
+            paint.set_stroke_width(1.0);
+            canvas.draw_round_rect(rect.with_inset((0.5, 0.5)), 2.0, 2.0, &paint);
+        }
+    }
+
+    /// Line text around the match, the matched text struck out and the replacement after it
+    fn draw_preview(&self, canvas: &Canvas, font: &Font, x: f32, y: f32, found: &TextMatch, checked: bool) {
+        let theme = current_theme();
+        let line = &found.line_text;
+        let (start, end) = (found.line_range.start, found.line_range.end);
+        // Leading whitespace and far away context would push the match out of view
+        let context_start = line[..start].char_indices().rev().nth(PREVIEW_CONTEXT).map_or(0, |(i, _)| i);
+        let before = line[context_start..start].trim_start();
+        let ellipsis = if context_start > 0 { "…" } else { "" };
+        let (matched, after) = (&line[start..end], &line[end..]);
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_color(if checked { theme.foreground } else { theme.muted_foreground });
+        let mut cursor = x;
+        for part in [ellipsis, before] {
+            canvas.draw_str(part, (cursor, y), font, &paint);
+            cursor += font.measure_str(part, Some(&paint)).0;
+        }
+
+        let replacement = self.replacement();
+        let matched_width = font.measure_str(matched, Some(&paint)).0;
+        let mut highlight = Paint::default();
+        highlight.set_anti_alias(true);
+        // `y` is the baseline, 15 below the row top
+        let highlight_top = y - 13.0;
+        if checked && !replacement.is_empty() {
+            highlight.set_color(with_alpha(theme.destructive, 60));
+            canvas.draw_rect(Rect::from_xywh(cursor, highlight_top, matched_width, ROW_HEIGHT - 4.0), &highlight);
+            canvas.draw_str(matched, (cursor, y), font, &paint);
+            let mut strike = paint.clone();
+            strike.set_stroke_width(1.0);
+            canvas.draw_line((cursor, y - 4.0), (cursor + matched_width, y - 4.0), &strike);
+            cursor += matched_width;
+
+            let replacement_width = font.measure_str(replacement, Some(&paint)).0;
+            highlight.set_color(with_alpha(theme.primary, 60));
+            canvas.draw_rect(Rect::from_xywh(cursor, highlight_top, replacement_width, ROW_HEIGHT - 4.0), &highlight);
+            canvas.draw_str(replacement, (cursor, y), font, &paint);
+            cursor += replacement_width;
+        } else {
+            highlight.set_color(with_alpha(theme.primary, 60));
+            canvas.draw_rect(Rect::from_xywh(cursor, highlight_top, matched_width, ROW_HEIGHT - 4.0), &highlight);
+            canvas.draw_str(matched, (cursor, y), font, &paint);
+            cursor += matched_width;
+        }
+        canvas.draw_str(after, (cursor, y), font, &paint);
+    }
+}
+
+impl Widget for SearchView {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut FontManager) {
+        let theme = current_theme();
+        self.query_input.draw(canvas, font_manager);
+        self.replace_input.draw(canvas, font_manager);
+
+        let buttons = [
+            (SearchButton::MatchCase, CodiconIcons::CASE_SENSITIVE, self.match_case),
+            (SearchButton::WholeWord, CodiconIcons::WHOLE_WORD, self.whole_word),
+            (SearchButton::ReplaceAll, CodiconIcons::REPLACE_ALL, false),
+        ];
+        for (button, icon, active) in buttons {
+            let rect = self.button_rect(button);
+            if active || self.hover_button == Some(button) {
+                let mut paint = Paint::default();
+                paint.set_anti_alias(true);
+                paint.set_color(if active { with_alpha(theme.primary, 60) } else { theme.muted });
+                canvas.draw_round_rect(rect, 4.0, 4.0, &paint);
+            }
+            let offset = (BUTTON_SIZE - IconSize::Small.as_f32()) / 2.0;
+            Icon::new(rect.left + offset, rect.top + offset, icon, IconSize::Small, theme.foreground).draw(canvas, font_manager);
+        }
+
+        let mut muted_paint = Paint::default();
+        muted_paint.set_anti_alias(true);
+        muted_paint.set_color(theme.muted_foreground);
+        if let Some(ref message) = self.message {
+            let font = font_manager.create_font(message, 12.0, 400);
+            canvas.draw_str(message, (self.x + PADDING + 4.0, self.y + INPUT_ROW_HEIGHT * 2.0 + 16.0), &font, &muted_paint);
+        }
+
+        let list = self.list_rect();
+        canvas.save();
+        canvas.clip_rect(list, None, None);
+        let icons = file_icon_theme();
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        text_paint.set_color(theme.foreground);
+        for (index, row) in self.rows().into_iter().enumerate() {
+            let y = list.top + index as f32 * ROW_HEIGHT - self.scroll_offset;
+            if y + ROW_HEIGHT < list.top || y > list.bottom {
+                continue;
+            }
+            if self.hover_row == Some(index) {
+                let mut hover_paint = Paint::default();
+                hover_paint.set_color(theme.muted);
+                canvas.draw_rect(Rect::from_xywh(self.x, y, self.width, ROW_HEIGHT), &hover_paint);
+            }
+            let baseline = y + 15.0;
+            match row {
+                Row::File(i) => {
+                    let file = &self.results[i];
+                    let x = self.x + PADDING;
+                    self.draw_checkbox(canvas, x, y, file.checked.iter().any(|checked| *checked));
+                    let chevron = if file.expanded { CodiconIcons::CHEVRON_DOWN } else { CodiconIcons::CHEVRON_RIGHT };
+                    Icon::new(x + CHECKBOX_SIZE + 4.0, y + 3.0, chevron, IconSize::Small, theme.muted_foreground).draw(canvas, font_manager);
+                    let icon_x = x + CHECKBOX_SIZE + 22.0;
+                    let (name, folder) = self.display_path(&file.path);
+                    icons.file_icon(&name, None).to_icon(icon_x, y + 3.0, IconSize::Small, theme.foreground).draw(canvas, font_manager);
+                    let font = font_manager.create_font(&name, 13.0, 500);
+                    let name_x = icon_x + 20.0;
+                    canvas.draw_str(&name, (name_x, baseline), &font, &text_paint);
+                    let name_width = font.measure_str(&name, Some(&text_paint)).0;
+                    let folder_font = font_manager.create_font(&folder, 12.0, 400);
+                    canvas.draw_str(&folder, (name_x + name_width + 6.0, baseline), &folder_font, &muted_paint);
+
+                    // Match count on the right
+                    let count = file.matches.len().to_string();
+                    let count_font = font_manager.create_font(&count, 11.0, 600);
+                    let count_width = count_font.measure_str(&count, Some(&muted_paint)).0;
+                    let badge = Rect::from_xywh(self.x + self.width - PADDING - count_width - 10.0, y + 3.0, count_width + 10.0, ROW_HEIGHT - 6.0);
+                    let mut badge_paint = Paint::default();
+                    badge_paint.set_anti_alias(true);
+                    badge_paint.set_color(theme.secondary);
+                    canvas.draw_round_rect(badge, 8.0, 8.0, &badge_paint);
+                    canvas.draw_str(&count, (badge.left + 5.0, baseline - 1.0), &count_font, &muted_paint);
+                }
+                Row::Match(i, j) => {
+                    let file = &self.results[i];
+                    let x = self.x + PADDING + MATCH_INDENT;
+                    self.draw_checkbox(canvas, x, y, file.checked[j]);
+                    let found = &file.matches[j];
+                    let font = font_manager.create_font(&found.line_text, 12.0, 400);
+                    self.draw_preview(canvas, &font, x + CHECKBOX_SIZE + 8.0, baseline, found, file.checked[j]);
+                }
+            }
+        }
+        canvas.restore();
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.query_input.update_hover(x, y);
+        self.replace_input.update_hover(x, y);
+        self.hover_button = self.button_at(x, y);
+        self.hover_row = self.row_at(x, y);
+    }
+
+    fn update_animation(&mut self, elapsed: f32) {
+        self.query_input.update_animation(elapsed);
+        self.replace_input.update_animation(elapsed);
+    }
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
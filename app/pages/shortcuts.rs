@@ -0,0 +1,567 @@
+use mikoui::{t, Widget, FontManager, Size, Theme};
+use mikoui::theme::{current_theme, with_alpha};
+use mikoui::components::{CodiconIcons, Icon, IconSize, Input};
+use skia_safe::{Canvas, Font, Paint, PaintStyle, Rect};
+
+use crate::hooks::keybindings::{KeyChord, KeySequence};
+
+const HEADER_HEIGHT: f32 = 56.0;
+const SEARCH_HEIGHT: f32 = 44.0;
+const COLUMNS_HEIGHT: f32 = 28.0;
+const ROW_HEIGHT: f32 = 32.0;
+const PADDING: f32 = 16.0;
+const KEYS_COLUMN: f32 = 0.45;
+const SOURCE_COLUMN: f32 = 0.75;
+const RESET_SIZE: f32 = 24.0;
+
+/// One command of the Keyboard Shortcuts page
+#[derive(Debug, Clone)]
+pub struct ShortcutRow {
+    pub command: u32,
+    pub title: String,
+    pub keys: Vec<KeySequence>,
+    /// Keys come from keybindings.json rather than the command's defaults
+    pub user_defined: bool,
+    /// Titles of the other commands bound to one of the keys
+    pub conflicts: Vec<String>,
+}
+
+/// What the app should do after the page handled an event
+pub enum KeyboardShortcutsAction {
+    /// Bind the command to the recorded keys instead of its current ones
+    Set(u32, KeySequence),
+    /// Leave the command without keys
+    Remove(u32),
+    /// Back to the command's default keys
+    Reset(u32),
+}
+
+/// Box that captures key chords while a keybinding is recorded
+///
+/// Sequences are at most two chords (`Ctrl+K Ctrl+S`); a third chord starts over.
+pub struct KeyRecorder {
+    rect: Rect,
+    command: Option<u32>,
+    chords: Vec<KeyChord>,
+}
+
+impl KeyRecorder {
+    const MAX_CHORDS: usize = 2;
+
+    pub fn new() -> Self {
+        Self {
+            rect: Rect::new_empty(),
+            command: None,
+            chords: Vec::new(),
+        }
+    }
+
+    pub fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+    }
+
+    pub fn start(&mut self, command: u32) {
+        self.command = Some(command);
+        self.chords.clear();
+    }
+
+    pub fn stop(&mut self) {
+        self.command = None;
+        self.chords.clear();
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.command.is_some()
+    }
+
+    /// Command the keys are recorded for
+    pub fn command(&self) -> Option<u32> {
+        self.command
+    }
+
+    pub fn record(&mut self, chord: KeyChord) {
+        if self.chords.len() == Self::MAX_CHORDS {
+            self.chords.clear();
+        }
+        self.chords.push(chord);
+    }
+
+    /// The recorded keys, once at least one chord was pressed
+    pub fn keys(&self) -> Option<KeySequence> {
+        (!self.chords.is_empty()).then(|| KeySequence(self.chords.clone()))
+    }
+}
+
+impl Default for KeyRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Widget for KeyRecorder {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut FontManager) {
+        if !self.is_recording() {
+            return;
+        }
+        let theme = current_theme();
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_color(theme.background);
+        canvas.draw_round_rect(self.rect, Theme::RADIUS_SM, Theme::RADIUS_SM, &paint);
+        paint.set_style(PaintStyle::Stroke);
+        paint.set_stroke_width(1.0);
+        paint.set_color(theme.ring);
+        canvas.draw_round_rect(self.rect.with_inset((0.5, 0.5)), Theme::RADIUS_SM, Theme::RADIUS_SM, &paint);
+
+        let (text, color) = match self.keys() {
+            Some(keys) => (keys.to_string(), theme.foreground),
+            None => (t!("shortcuts.press_keys"), theme.muted_foreground),
+        };
+        let font = font_manager.create_font(&text, Theme::TEXT_XS, 400);
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        text_paint.set_color(color);
+        canvas.draw_str(&text, (self.rect.left + 8.0, self.rect.center_y() + 4.0), &font, &text_paint);
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        self.is_recording() && self.rect.contains(skia_safe::Point::new(x, y))
+    }
+
+    fn update_hover(&mut self, _x: f32, _y: f32) {}
+
+    fn update_animation(&mut self, _elapsed: f32) {}
+
+    fn on_click(&mut self) {}
+
+    fn bounds(&self) -> Option<Rect> {
+        self.is_recording().then_some(self.rect)
+    }
+
+    fn debug_state(&self) -> Vec<(&'static str, String)> {
+        vec![("keys", self.keys().map(|keys| keys.to_string()).unwrap_or_default())]
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Page listing every command with its keys, searchable, where keys are recorded anew
+pub struct KeyboardShortcuts {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    visible: bool,
+    rows: Vec<ShortcutRow>,
+    /// Indices into `rows` matching the search
+    filtered: Vec<usize>,
+    selected: usize,
+    hover_row: Option<usize>,
+    scroll_offset: f32,
+    search_input: Input,
+    recorder: KeyRecorder,
+}
+
+impl KeyboardShortcuts {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        let mut page = Self {
+            x,
+            y,
+            width,
+            height,
+            visible: false,
+            rows: Vec::new(),
+            filtered: Vec::new(),
+            selected: 0,
+            hover_row: None,
+            scroll_offset: 0.0,
+            search_input: Input::new(0.0, 0.0, width, t!("shortcuts.search")).size(Size::Sm),
+            recorder: KeyRecorder::new(),
+        };
+        page.layout();
+        page
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+        self.layout();
+        self.scroll(0.0);
+    }
+
+    fn layout(&mut self) {
+        self.search_input.set_bounds(self.x + PADDING, self.y + HEADER_HEIGHT, self.width - PADDING * 2.0);
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn show(&mut self) {
+        self.visible = true;
+        self.search_input.set_focused(true);
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.recorder.stop();
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_recording()
+    }
+
+    /// Replace the listed commands, keeping the selection on the same command
+    pub fn set_rows(&mut self, rows: Vec<ShortcutRow>) {
+        let selected = self.selected_row().map(|row| row.command);
+        self.rows = rows;
+        self.update_filter();
+        if let Some(index) = selected.and_then(|command| {
+            self.filtered.iter().position(|&row| self.rows[row].command == command)
+        }) {
+            self.selected = index;
+        }
+    }
+
+    fn selected_row(&self) -> Option<&ShortcutRow> {
+        self.filtered.get(self.selected).map(|&index| &self.rows[index])
+    }
+
+    /// Match the search against titles and keys, so `ctrl+k` finds what Ctrl+K starts
+    fn update_filter(&mut self) {
+        let query = self.search_input.text().trim().to_lowercase();
+        self.filtered = self.rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| {
+                query.is_empty()
+                    || row.title.to_lowercase().contains(&query)
+                    || row.keys.iter().any(|keys| keys.to_string().to_lowercase().contains(&query))
+            })
+            .map(|(index, _)| index)
+            .collect();
+        self.selected = self.selected.min(self.filtered.len().saturating_sub(1));
+        self.scroll(0.0);
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        self.search_input.handle_char(c);
+        self.selected = 0;
+        self.update_filter();
+    }
+
+    pub fn handle_backspace(&mut self) {
+        self.search_input.handle_backspace();
+        self.update_filter();
+    }
+
+    /// Escape clears the search first, then closes the page
+    pub fn handle_escape(&mut self) {
+        if self.search_input.text().is_empty() {
+            self.hide();
+        } else {
+            self.search_input.clear();
+            self.update_filter();
+        }
+    }
+
+    /// Start recording keys for the selected command
+    pub fn record_selected(&mut self) {
+        if let Some(command) = self.selected_row().map(|row| row.command) {
+            self.recorder.start(command);
+            self.layout_recorder();
+        }
+    }
+
+    /// Remove the selected command's keys, if it has any
+    pub fn remove_selected(&self) -> Option<KeyboardShortcutsAction> {
+        self.selected_row()
+            .filter(|row| !row.keys.is_empty())
+            .map(|row| KeyboardShortcutsAction::Remove(row.command))
+    }
+
+    /// A key pressed while recording: Enter saves, Escape cancels, anything else is recorded
+    pub fn handle_chord(&mut self, chord: KeyChord) -> Option<KeyboardShortcutsAction> {
+        let command = self.recorder.command()?;
+        let plain = !(chord.ctrl || chord.alt || chord.shift || chord.meta);
+        match chord.key.as_str() {
+            "Enter" if plain => {
+                let action = self.recorder.keys().map(|keys| KeyboardShortcutsAction::Set(command, keys));
+                self.recorder.stop();
+                action
+            }
+            "Escape" if plain => {
+                self.recorder.stop();
+                None
+            }
+            _ => {
+                self.recorder.record(chord);
+                None
+            }
+        }
+    }
+
+    /// Titles of the other commands the keys being recorded would collide with
+    fn recording_conflicts(&self) -> Vec<&str> {
+        let (Some(command), Some(keys)) = (self.recorder.command(), self.recorder.keys()) else {
+            return Vec::new();
+        };
+        self.rows
+            .iter()
+            .filter(|row| row.command != command && row.keys.iter().any(|bound| bound.overlaps(&keys)))
+            .map(|row| row.title.as_str())
+            .collect()
+    }
+
+    pub fn move_selection(&mut self, delta: isize) {
+        if self.filtered.is_empty() || self.is_recording() {
+            return;
+        }
+        self.selected = self.selected.saturating_add_signed(delta).min(self.filtered.len() - 1);
+
+        // Keep the selected row in view
+        let list_height = self.list_rect().height();
+        let row_top = self.selected as f32 * ROW_HEIGHT;
+        if row_top < self.scroll_offset {
+            self.scroll_offset = row_top;
+        } else if row_top + ROW_HEIGHT > self.scroll_offset + list_height {
+            self.scroll_offset = row_top + ROW_HEIGHT - list_height;
+        }
+    }
+
+    fn list_rect(&self) -> Rect {
+        let top = self.y + HEADER_HEIGHT + SEARCH_HEIGHT + COLUMNS_HEIGHT;
+        Rect::from_xywh(self.x + PADDING, top, self.width - PADDING * 2.0, (self.y + self.height - PADDING - top).max(0.0))
+    }
+
+    fn row_rect(&self, index: usize) -> Rect {
+        let list = self.list_rect();
+        Rect::from_xywh(list.left, list.top + index as f32 * ROW_HEIGHT - self.scroll_offset, list.width(), ROW_HEIGHT)
+    }
+
+    fn reset_rect(&self, index: usize) -> Rect {
+        let row = self.row_rect(index);
+        Rect::from_xywh(row.right - RESET_SIZE - 4.0, row.top + (ROW_HEIGHT - RESET_SIZE) / 2.0, RESET_SIZE, RESET_SIZE)
+    }
+
+    fn layout_recorder(&mut self) {
+        let row = self.row_rect(self.selected);
+        let left = row.left + row.width() * KEYS_COLUMN - 8.0;
+        let right = row.left + row.width() * SOURCE_COLUMN - 8.0;
+        self.recorder.set_rect(Rect::from_ltrb(left, row.top + 3.0, right, row.bottom - 3.0));
+    }
+
+    fn row_at(&self, x: f32, y: f32) -> Option<usize> {
+        let list = self.list_rect();
+        if x < list.left || x > list.right || y < list.top || y > list.bottom {
+            return None;
+        }
+        let row = ((y - list.top + self.scroll_offset) / ROW_HEIGHT) as usize;
+        (row < self.filtered.len()).then_some(row)
+    }
+
+    pub fn scroll(&mut self, delta: f32) {
+        let total = self.filtered.len() as f32 * ROW_HEIGHT;
+        let max_scroll = (total - self.list_rect().height()).max(0.0);
+        self.scroll_offset = (self.scroll_offset + delta).clamp(0.0, max_scroll);
+        if self.is_recording() {
+            self.layout_recorder();
+        }
+    }
+
+    /// Click a row to select it, click it again to record its keys
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> Option<KeyboardShortcutsAction> {
+        if self.recorder.contains(x, y) {
+            return None;
+        }
+        self.recorder.stop();
+        let row = self.row_at(x, y)?;
+        let command_row = &self.rows[self.filtered[row]];
+        if command_row.user_defined && self.reset_rect(row).contains(skia_safe::Point::new(x, y)) {
+            return Some(KeyboardShortcutsAction::Reset(command_row.command));
+        }
+        if row == self.selected {
+            self.record_selected();
+        }
+        self.selected = row;
+        None
+    }
+
+    /// Keys as chips, one per chord, sequences separated by a gap; returns the right edge
+    fn draw_keys(&self, canvas: &Canvas, font: &Font, keys: &[KeySequence], x: f32, row_top: f32) -> f32 {
+        let theme = current_theme();
+        let mut chip_paint = Paint::default();
+        chip_paint.set_anti_alias(true);
+        chip_paint.set_color(theme.secondary);
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        text_paint.set_color(theme.secondary_foreground);
+
+        let mut left = x;
+        for sequence in keys {
+            for chord in &sequence.0 {
+                let label = chord.to_string();
+                let width = font.measure_str(&label, None).0 + 12.0;
+                let chip = Rect::from_xywh(left, row_top + 6.0, width, ROW_HEIGHT - 12.0);
+                canvas.draw_round_rect(chip, Theme::RADIUS_SM, Theme::RADIUS_SM, &chip_paint);
+                canvas.draw_str(&label, (left + 6.0, row_top + 20.0), font, &text_paint);
+                left += width + 4.0;
+            }
+            left += 8.0;
+        }
+        left
+    }
+}
+
+impl Widget for KeyboardShortcuts {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut FontManager) {
+        if !self.visible {
+            return;
+        }
+
+        let theme = current_theme();
+
+        let mut bg_paint = Paint::default();
+        bg_paint.set_color(theme.background);
+        canvas.draw_rect(Rect::from_xywh(self.x, self.y, self.width, self.height), &bg_paint);
+
+        // Header
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        let title = t!("shortcuts.title");
+        let title_font = font_manager.create_font(&title, Theme::TEXT_LG, 600);
+        text_paint.set_color(theme.foreground);
+        canvas.draw_str(&title, (self.x + PADDING, self.y + 32.0), &title_font, &text_paint);
+
+        let hint = if self.is_recording() { t!("shortcuts.recording_hint") } else { t!("shortcuts.hint") };
+        let hint_font = font_manager.create_font(&hint, Theme::TEXT_XS, 400);
+        text_paint.set_color(theme.muted_foreground);
+        let title_width = title_font.measure_str(&title, None).0;
+        canvas.draw_str(&hint, (self.x + PADDING * 2.0 + title_width, self.y + 31.0), &hint_font, &text_paint);
+
+        let mut border_paint = Paint::default();
+        border_paint.set_color(theme.border);
+        canvas.draw_rect(
+            Rect::from_xywh(self.x, self.y + HEADER_HEIGHT - 8.0, self.width, 1.0),
+            &border_paint,
+        );
+
+        self.search_input.draw(canvas, font_manager);
+
+        // Column titles
+        let list = self.list_rect();
+        let keys_x = list.left + list.width() * KEYS_COLUMN;
+        let source_x = list.left + list.width() * SOURCE_COLUMN;
+        let columns_y = list.top - 9.0;
+        text_paint.set_color(theme.muted_foreground);
+        for (label, x) in [
+            (t!("shortcuts.command"), list.left + 8.0),
+            (t!("shortcuts.keybinding"), keys_x),
+            (t!("shortcuts.source"), source_x),
+        ] {
+            let column_font = font_manager.create_font(&label, Theme::TEXT_XS, 600);
+            canvas.draw_str(&label, (x, columns_y), &column_font, &text_paint);
+        }
+
+        // Command list
+        canvas.save();
+        canvas.clip_rect(list, None, true);
+        let key_font = font_manager.create_font("Ctrl", Theme::TEXT_XS, 400);
+        for (index, &row_index) in self.filtered.iter().enumerate() {
+            let row = self.row_rect(index);
+            if row.bottom < list.top || row.top > list.bottom {
+                continue;
+            }
+            let command_row = &self.rows[row_index];
+
+            if index == self.selected || self.hover_row == Some(index) {
+                let mut row_paint = Paint::default();
+                row_paint.set_anti_alias(true);
+                row_paint.set_color(if index == self.selected {
+                    theme.accent
+                } else {
+                    with_alpha(theme.accent, 128)
+                });
+                canvas.draw_round_rect(row, Theme::RADIUS_SM, Theme::RADIUS_SM, &row_paint);
+            }
+
+            let label_font = font_manager.create_font(&command_row.title, 13.0, 400);
+            text_paint.set_color(if index == self.selected { theme.accent_foreground } else { theme.foreground });
+            canvas.draw_str(&command_row.title, (row.left + 8.0, row.top + 21.0), &label_font, &text_paint);
+
+            if !(self.is_recording() && index == self.selected) {
+                let keys_right = self.draw_keys(canvas, &key_font, &command_row.keys, keys_x, row.top);
+                if !command_row.conflicts.is_empty() {
+                    Icon::new(keys_right, row.top + 8.0, CodiconIcons::WARNING, IconSize::Small, Theme::WARNING)
+                        .draw(canvas, font_manager);
+                }
+            }
+
+            let source = if command_row.user_defined { t!("shortcuts.source_user") } else { t!("shortcuts.source_default") };
+            let source = match command_row.conflicts.as_slice() {
+                [] => source,
+                titles => t!("shortcuts.conflicts", source = source, commands = titles.join(", ")),
+            };
+            text_paint.set_color(if command_row.conflicts.is_empty() { theme.muted_foreground } else { Theme::WARNING });
+            canvas.draw_str(&source, (source_x, row.top + 21.0), &key_font, &text_paint);
+
+            if command_row.user_defined {
+                let reset = self.reset_rect(index);
+                Icon::new(reset.left + 4.0, reset.top + 4.0, CodiconIcons::DISCARD, IconSize::Small, theme.muted_foreground)
+                    .draw(canvas, font_manager);
+            }
+        }
+
+        // The recorder takes the selected row's keys cell, with what it would collide with next to it
+        if self.is_recording() {
+            self.recorder.draw(canvas, font_manager);
+            let conflicts = self.recording_conflicts();
+            if !conflicts.is_empty() {
+                let message = t!("shortcuts.also_bound", commands = conflicts.join(", "));
+                let recorder = self.recorder.bounds().unwrap_or(list);
+                text_paint.set_color(Theme::WARNING);
+                canvas.draw_str(&message, (source_x, recorder.center_y() + 4.0), &key_font, &text_paint);
+            }
+        }
+        canvas.restore();
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        self.visible && x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hover_row = self.row_at(x, y);
+        self.search_input.update_hover(x, y);
+    }
+
+    fn update_animation(&mut self, elapsed: f32) {
+        self.search_input.update_animation(elapsed);
+    }
+
+    fn on_click(&mut self) {}
+
+    fn bounds(&self) -> Option<Rect> {
+        self.visible.then(|| Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        vec![&self.search_input as &dyn Widget, &self.recorder]
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,365 @@
+use mikoui::{t, Widget, FontManager, ThemeColors, Size, Theme, Variant};
+use mikoui::theme::{color_to_hex, current_theme, with_alpha};
+use mikoui::components::{Button, ColorPicker, Input, ERROR_MESSAGE_HEIGHT};
+use skia_safe::{Canvas, Paint, Rect};
+
+const HEADER_HEIGHT: f32 = 56.0;
+const ROW_HEIGHT: f32 = 28.0;
+const SIDE_WIDTH: f32 = 260.0;
+const PADDING: f32 = 16.0;
+
+/// What the app should do after the theme editor handled an event
+pub enum ThemeEditorAction {
+    /// The edited palette changed and should be applied live
+    ColorsChanged,
+    /// Save into the themes directory
+    Save,
+    /// Export to a file chosen by the user
+    Export,
+}
+
+/// Page listing every theme color with a swatch, edited through a ColorPicker
+pub struct ThemeEditor {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    visible: bool,
+    colors: ThemeColors,
+    selected: usize,
+    hover_row: Option<usize>,
+    scroll_offset: f32,
+    picker: ColorPicker,
+    name_input: Input,
+    save_button: Button,
+    export_button: Button,
+    status: Option<String>,
+    status_y: f32,
+}
+
+impl ThemeEditor {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        let colors = current_theme();
+        let mut editor = Self {
+            x,
+            y,
+            width,
+            height,
+            visible: false,
+            colors,
+            selected: 0,
+            hover_row: None,
+            scroll_offset: 0.0,
+            picker: ColorPicker::new(0.0, 0.0, SIDE_WIDTH, colors.background),
+            name_input: Input::new(0.0, 0.0, SIDE_WIDTH, t!("theme_editor.name"))
+                .size(Size::Sm)
+                .with_validator(|text| {
+                    if text.trim().is_empty() {
+                        Err(t!("theme_editor.name_required"))
+                    } else {
+                        Ok(())
+                    }
+                }),
+            save_button: Button::new(0.0, 0.0, (SIDE_WIDTH - Theme::SPACE_2) / 2.0, t!("theme_editor.save")).size(Size::Sm),
+            export_button: Button::new(0.0, 0.0, (SIDE_WIDTH - Theme::SPACE_2) / 2.0, t!("theme_editor.export"))
+                .size(Size::Sm)
+                .variant(Variant::Outline),
+            status: None,
+            status_y: 0.0,
+        };
+        editor.layout();
+        editor
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+        self.layout();
+        self.scroll(0.0);
+    }
+
+    fn side_x(&self) -> f32 {
+        self.x + self.width - SIDE_WIDTH - PADDING
+    }
+
+    fn layout(&mut self) {
+        let side_x = self.side_x();
+        let mut y = self.y + HEADER_HEIGHT + 24.0;
+        self.picker.set_position(side_x, y);
+        y += self.picker.height() + ERROR_MESSAGE_HEIGHT + Theme::SPACE_2;
+        self.name_input.set_bounds(side_x, y, SIDE_WIDTH);
+        y += self.name_input.height() + ERROR_MESSAGE_HEIGHT + Theme::SPACE_2;
+        self.save_button.set_position(side_x, y);
+        self.export_button.set_position(side_x + (SIDE_WIDTH + Theme::SPACE_2) / 2.0, y);
+        self.status_y = y + self.save_button.height() + 20.0;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Open the editor on a copy of `colors`
+    pub fn show(&mut self, name: &str, colors: ThemeColors) {
+        self.visible = true;
+        self.colors = colors;
+        self.status = None;
+        self.name_input.set_text(name.to_string());
+        self.select(self.selected);
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.picker.handle_mouse_up();
+    }
+
+    pub fn colors(&self) -> ThemeColors {
+        self.colors
+    }
+
+    pub fn name(&self) -> &str {
+        self.name_input.text().trim()
+    }
+
+    /// Message shown under the buttons, e.g. where the theme was saved
+    pub fn set_status(&mut self, status: impl Into<String>) {
+        self.status = Some(status.into());
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = index.min(ThemeColors::FIELD_NAMES.len() - 1);
+        if let Some(color) = self.colors.get(ThemeColors::FIELD_NAMES[self.selected]) {
+            self.picker.set_color(color);
+        }
+    }
+
+    /// Copy the picker color into the selected token; returns true if it changed
+    fn apply_picker(&mut self) -> bool {
+        let field = ThemeColors::FIELD_NAMES[self.selected];
+        let color = self.picker.color();
+        if self.colors.get(field) == Some(color) {
+            return false;
+        }
+        self.colors.set(field, color);
+        self.status = None;
+        true
+    }
+
+    fn list_rect(&self) -> Rect {
+        let left = self.x + PADDING;
+        let top = self.y + HEADER_HEIGHT;
+        Rect::from_xywh(left, top, self.side_x() - left - PADDING, self.height - HEADER_HEIGHT - PADDING)
+    }
+
+    fn row_at(&self, x: f32, y: f32) -> Option<usize> {
+        let list = self.list_rect();
+        if x < list.left || x > list.right || y < list.top || y > list.bottom {
+            return None;
+        }
+        let row = ((y - list.top + self.scroll_offset) / ROW_HEIGHT) as usize;
+        (row < ThemeColors::FIELD_NAMES.len()).then_some(row)
+    }
+
+    pub fn scroll(&mut self, delta: f32) {
+        let total = ThemeColors::FIELD_NAMES.len() as f32 * ROW_HEIGHT;
+        let max_scroll = (total - self.list_rect().height()).max(0.0);
+        self.scroll_offset = (self.scroll_offset + delta).clamp(0.0, max_scroll);
+    }
+
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> Option<ThemeEditorAction> {
+        self.name_input.set_focused(self.name_input.hit_test(x, y));
+
+        if let Some(row) = self.row_at(x, y) {
+            self.select(row);
+            return None;
+        }
+        if self.picker.contains(x, y) {
+            self.picker.handle_mouse_down(x, y);
+            return self.apply_picker().then_some(ThemeEditorAction::ColorsChanged);
+        }
+        self.picker.handle_mouse_down(x, y);
+
+        if self.save_button.hit_test(x, y) {
+            self.save_button.on_click();
+            return self.name_input.validate().then_some(ThemeEditorAction::Save);
+        }
+        if self.export_button.hit_test(x, y) {
+            self.export_button.on_click();
+            return self.name_input.validate().then_some(ThemeEditorAction::Export);
+        }
+        None
+    }
+
+    /// Returns true if a channel drag changed the palette
+    pub fn handle_mouse_move(&mut self, x: f32, y: f32) -> bool {
+        self.update_hover(x, y);
+        self.picker.handle_mouse_move(x, y) && self.apply_picker()
+    }
+
+    pub fn handle_mouse_up(&mut self) {
+        self.picker.handle_mouse_up();
+    }
+
+    /// Type into the name or hex input; returns true if the palette changed
+    pub fn handle_char(&mut self, c: char) -> bool {
+        if self.name_input.is_focused() {
+            self.name_input.handle_char(c);
+            return false;
+        }
+        self.picker.handle_char(c) && self.apply_picker()
+    }
+
+    pub fn handle_backspace(&mut self) -> bool {
+        if self.name_input.is_focused() {
+            self.name_input.handle_backspace();
+            return false;
+        }
+        self.picker.handle_backspace() && self.apply_picker()
+    }
+
+    /// Arrow keys move the selection through the color list
+    pub fn move_selection(&mut self, delta: isize) {
+        let last = ThemeColors::FIELD_NAMES.len() - 1;
+        self.select(self.selected.saturating_add_signed(delta).min(last));
+
+        // Keep the selected row in view
+        let list_height = self.list_rect().height();
+        let row_top = self.selected as f32 * ROW_HEIGHT;
+        if row_top < self.scroll_offset {
+            self.scroll_offset = row_top;
+        } else if row_top + ROW_HEIGHT > self.scroll_offset + list_height {
+            self.scroll_offset = row_top + ROW_HEIGHT - list_height;
+        }
+    }
+}
+
+impl Widget for ThemeEditor {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut FontManager) {
+        if !self.visible {
+            return;
+        }
+
+        let theme = current_theme();
+
+        let mut bg_paint = Paint::default();
+        bg_paint.set_color(theme.background);
+        canvas.draw_rect(Rect::from_xywh(self.x, self.y, self.width, self.height), &bg_paint);
+
+        // Header
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        let title = t!("theme_editor.title");
+        let title_font = font_manager.create_font(&title, Theme::TEXT_LG, 600);
+        text_paint.set_color(theme.foreground);
+        canvas.draw_str(&title, (self.x + PADDING, self.y + 32.0), &title_font, &text_paint);
+
+        let hint = t!("theme_editor.hint");
+        let hint_font = font_manager.create_font(&hint, Theme::TEXT_XS, 400);
+        text_paint.set_color(theme.muted_foreground);
+        let title_width = title_font.measure_str(&title, None).0;
+        canvas.draw_str(&hint, (self.x + PADDING * 2.0 + title_width, self.y + 31.0), &hint_font, &text_paint);
+
+        let mut border_paint = Paint::default();
+        border_paint.set_color(theme.border);
+        canvas.draw_rect(
+            Rect::from_xywh(self.x, self.y + HEADER_HEIGHT - 8.0, self.width, 1.0),
+            &border_paint,
+        );
+
+        // Color list
+        let list = self.list_rect();
+        canvas.save();
+        canvas.clip_rect(list, None, true);
+        let mono_font = font_manager.create_mono_font(Theme::TEXT_XS, 400);
+        for (index, field) in ThemeColors::FIELD_NAMES.iter().enumerate() {
+            let row_y = list.top + index as f32 * ROW_HEIGHT - self.scroll_offset;
+            if row_y + ROW_HEIGHT < list.top || row_y > list.bottom {
+                continue;
+            }
+            let Some(color) = self.colors.get(field) else {
+                continue;
+            };
+
+            let row = Rect::from_xywh(list.left, row_y, list.width(), ROW_HEIGHT);
+            if index == self.selected || self.hover_row == Some(index) {
+                let mut row_paint = Paint::default();
+                row_paint.set_anti_alias(true);
+                row_paint.set_color(if index == self.selected {
+                    theme.accent
+                } else {
+                    with_alpha(theme.accent, 128)
+                });
+                canvas.draw_round_rect(row, Theme::RADIUS_SM, Theme::RADIUS_SM, &row_paint);
+            }
+
+            // Swatch
+            let swatch = Rect::from_xywh(list.left + 8.0, row_y + 6.0, 16.0, 16.0);
+            let mut swatch_paint = Paint::default();
+            swatch_paint.set_anti_alias(true);
+            swatch_paint.set_color(color);
+            canvas.draw_round_rect(swatch, 3.0, 3.0, &swatch_paint);
+            let mut swatch_border = Paint::default();
+            swatch_border.set_anti_alias(true);
+            swatch_border.set_style(skia_safe::PaintStyle::Stroke);
+            swatch_border.set_color(theme.border);
+            canvas.draw_round_rect(swatch, 3.0, 3.0, &swatch_border);
+
+            let label_font = font_manager.create_font(field, 13.0, 400);
+            text_paint.set_color(if index == self.selected { theme.accent_foreground } else { theme.foreground });
+            canvas.draw_str(field, (list.left + 34.0, row_y + 18.0), &label_font, &text_paint);
+
+            let hex = color_to_hex(color);
+            let hex_width = mono_font.measure_str(&hex, None).0;
+            text_paint.set_color(theme.muted_foreground);
+            canvas.draw_str(&hex, (list.right - hex_width - 8.0, row_y + 18.0), &mono_font, &text_paint);
+        }
+        canvas.restore();
+
+        // Side column: picker for the selected token, name and actions
+        let side_x = self.side_x();
+        let field = ThemeColors::FIELD_NAMES[self.selected];
+        let field_font = font_manager.create_font(field, Theme::TEXT_SM, 600);
+        text_paint.set_color(theme.foreground);
+        canvas.draw_str(field, (side_x, self.y + HEADER_HEIGHT + 12.0), &field_font, &text_paint);
+
+        self.picker.draw(canvas, font_manager);
+        self.name_input.draw(canvas, font_manager);
+        self.save_button.draw(canvas, font_manager);
+        self.export_button.draw(canvas, font_manager);
+
+        if let Some(ref status) = self.status {
+            let status_font = font_manager.create_font(status, Theme::TEXT_XS, 400);
+            text_paint.set_color(theme.muted_foreground);
+            canvas.draw_str(status, (side_x, self.status_y), &status_font, &text_paint);
+        }
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        self.visible && x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hover_row = self.row_at(x, y);
+        self.name_input.update_hover(x, y);
+        self.save_button.update_hover(x, y);
+        self.export_button.update_hover(x, y);
+    }
+
+    fn update_animation(&mut self, elapsed: f32) {
+        self.picker.update_animation(elapsed);
+        self.name_input.update_animation(elapsed);
+        self.save_button.update_animation(elapsed);
+        self.export_button.update_animation(elapsed);
+    }
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,230 @@
+use mikoui::{file_icon_theme, t, Widget, FontManager};
+use mikoui::theme::{current_theme, with_alpha};
+use mikoui::components::{CodiconIcons, Icon, IconSize};
+use mikoeditor::TodoComment;
+use skia_safe::{Canvas, Color, Paint, Rect};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+const SUMMARY_HEIGHT: f32 = 24.0;
+const ROW_HEIGHT: f32 = 22.0;
+const PADDING: f32 = 8.0;
+/// Comment rows sit this far right of their file row
+const ITEM_INDENT: f32 = 20.0;
+/// Keyword tint, the same amber the editor highlights them with
+const KEYWORD_COLOR: Color = Color::from_rgb(234, 179, 8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    File(usize),
+    /// File and comment index
+    Item(usize, usize),
+}
+
+/// TODO, FIXME and similar comments of the open documents and, when scanned, the workspace,
+/// grouped by file
+pub struct TodoView {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    // Paths are shown relative to it
+    root: Option<PathBuf>,
+    files: Vec<(PathBuf, Vec<TodoComment>)>,
+    // Files folded by a click on their row, kept across refreshes
+    collapsed: HashSet<PathBuf>,
+    hover_row: Option<usize>,
+    scroll_offset: f32,
+}
+
+impl TodoView {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            root: None,
+            files: Vec::new(),
+            collapsed: HashSet::new(),
+            hover_row: None,
+            scroll_offset: 0.0,
+        }
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+        self.scroll(0.0);
+    }
+
+    /// Folder the paths are shown relative to
+    pub fn set_root(&mut self, root: Option<PathBuf>) {
+        self.root = root;
+    }
+
+    /// Show the comments of each file; files without any are left out
+    pub fn set_files(&mut self, mut files: Vec<(PathBuf, Vec<TodoComment>)>) {
+        files.retain(|(_, todos)| !todos.is_empty());
+        files.sort_by(|a, b| a.0.cmp(&b.0));
+        self.files = files;
+        self.hover_row = None;
+        self.scroll(0.0);
+    }
+
+    fn list_rect(&self) -> Rect {
+        let top = self.y + SUMMARY_HEIGHT;
+        Rect::from_xywh(self.x, top, self.width, (self.y + self.height - top).max(0.0))
+    }
+
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = Vec::new();
+        for (i, (path, todos)) in self.files.iter().enumerate() {
+            rows.push(Row::File(i));
+            if !self.collapsed.contains(path) {
+                rows.extend((0..todos.len()).map(|j| Row::Item(i, j)));
+            }
+        }
+        rows
+    }
+
+    fn row_at(&self, x: f32, y: f32) -> Option<usize> {
+        let list = self.list_rect();
+        if x < list.left || x > list.right || y < list.top || y > list.bottom {
+            return None;
+        }
+        let row = ((y - list.top + self.scroll_offset) / ROW_HEIGHT) as usize;
+        (row < self.rows().len()).then_some(row)
+    }
+
+    pub fn scroll(&mut self, delta: f32) {
+        let total = self.rows().len() as f32 * ROW_HEIGHT;
+        let max_scroll = (total - self.list_rect().height()).max(0.0);
+        self.scroll_offset = (self.scroll_offset + delta).clamp(0.0, max_scroll);
+    }
+
+    /// Fold or unfold a file, or return the file, line and column of a comment pressed on
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> Option<(PathBuf, usize, usize)> {
+        match self.rows()[self.row_at(x, y)?] {
+            Row::File(i) => {
+                let path = &self.files[i].0;
+                if !self.collapsed.remove(path) {
+                    self.collapsed.insert(path.clone());
+                }
+                self.scroll(0.0);
+                None
+            }
+            Row::Item(i, j) => {
+                let (path, todos) = &self.files[i];
+                Some((path.clone(), todos[j].line, todos[j].column))
+            }
+        }
+    }
+
+    fn display_path(&self, path: &Path) -> (String, String) {
+        let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        let parent = path.parent().unwrap_or(Path::new(""));
+        let folder = self.root.as_deref().and_then(|root| parent.strip_prefix(root).ok()).unwrap_or(parent);
+        (name, folder.to_string_lossy().replace('\\', "/"))
+    }
+}
+
+impl Widget for TodoView {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut FontManager) {
+        let theme = current_theme();
+        let mut muted_paint = Paint::default();
+        muted_paint.set_anti_alias(true);
+        muted_paint.set_color(theme.muted_foreground);
+
+        let count: usize = self.files.iter().map(|(_, todos)| todos.len()).sum();
+        let summary = if count == 0 { t!("todos.none") } else { t!("todos.count", count = count, files = self.files.len()) };
+        let font = font_manager.create_font(&summary, 12.0, 400);
+        canvas.draw_str(&summary, (self.x + PADDING + 4.0, self.y + 16.0), &font, &muted_paint);
+
+        let list = self.list_rect();
+        canvas.save();
+        canvas.clip_rect(list, None, None);
+        let icons = file_icon_theme();
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        text_paint.set_color(theme.foreground);
+        let mut keyword_paint = Paint::default();
+        keyword_paint.set_anti_alias(true);
+        keyword_paint.set_color(KEYWORD_COLOR);
+        for (index, row) in self.rows().into_iter().enumerate() {
+            let y = list.top + index as f32 * ROW_HEIGHT - self.scroll_offset;
+            if y + ROW_HEIGHT < list.top || y > list.bottom {
+                continue;
+            }
+            if self.hover_row == Some(index) {
+                let mut hover_paint = Paint::default();
+                hover_paint.set_color(theme.muted);
+                canvas.draw_rect(Rect::from_xywh(self.x, y, self.width, ROW_HEIGHT), &hover_paint);
+            }
+            let baseline = y + 15.0;
+            match row {
+                Row::File(i) => {
+                    let (path, todos) = &self.files[i];
+                    let x = self.x + PADDING;
+                    let chevron = if self.collapsed.contains(path) { CodiconIcons::CHEVRON_RIGHT } else { CodiconIcons::CHEVRON_DOWN };
+                    Icon::new(x, y + 3.0, chevron, IconSize::Small, theme.muted_foreground).draw(canvas, font_manager);
+                    let icon_x = x + 18.0;
+                    let (name, folder) = self.display_path(path);
+                    icons.file_icon(&name, None).to_icon(icon_x, y + 3.0, IconSize::Small, theme.foreground).draw(canvas, font_manager);
+                    let font = font_manager.create_font(&name, 13.0, 500);
+                    let name_x = icon_x + 20.0;
+                    canvas.draw_str(&name, (name_x, baseline), &font, &text_paint);
+                    let name_width = font.measure_str(&name, Some(&text_paint)).0;
+                    let folder_font = font_manager.create_font(&folder, 12.0, 400);
+                    canvas.draw_str(&folder, (name_x + name_width + 6.0, baseline), &folder_font, &muted_paint);
+
+                    // Comment count on the right
+                    let count = todos.len().to_string();
+                    let count_font = font_manager.create_font(&count, 11.0, 600);
+                    let count_width = count_font.measure_str(&count, Some(&muted_paint)).0;
+                    let badge = Rect::from_xywh(self.x + self.width - PADDING - count_width - 10.0, y + 3.0, count_width + 10.0, ROW_HEIGHT - 6.0);
+                    let mut badge_paint = Paint::default();
+                    badge_paint.set_anti_alias(true);
+                    badge_paint.set_color(with_alpha(KEYWORD_COLOR, 50));
+                    canvas.draw_round_rect(badge, 8.0, 8.0, &badge_paint);
+                    canvas.draw_str(&count, (badge.left + 5.0, baseline - 1.0), &count_font, &text_paint);
+                }
+                Row::Item(i, j) => {
+                    let todo = &self.files[i].1[j];
+                    let x = self.x + PADDING + ITEM_INDENT;
+                    let keyword_font = font_manager.create_font(&todo.keyword, 12.0, 600);
+                    canvas.draw_str(&todo.keyword, (x, baseline), &keyword_font, &keyword_paint);
+                    let text_x = x + keyword_font.measure_str(&todo.keyword, Some(&keyword_paint)).0 + 6.0;
+                    let font = font_manager.create_font(&todo.text, 12.0, 400);
+                    canvas.draw_str(&todo.text, (text_x, baseline), &font, &text_paint);
+                    let text_width = font.measure_str(&todo.text, Some(&text_paint)).0;
+                    let line = format!(":{}", todo.line + 1);
+                    canvas.draw_str(&line, (text_x + text_width + 6.0, baseline), &font, &muted_paint);
+                }
+            }
+        }
+        canvas.restore();
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hover_row = self.row_at(x, y);
+    }
+
+    fn update_animation(&mut self, _elapsed: f32) {}
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
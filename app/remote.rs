@@ -0,0 +1,56 @@
+use std::path::PathBuf;
+
+use mikocore::{RemoteMount, SshHost};
+
+use crate::messages::{UiMessage, UiSender};
+
+/// Find the absolute path of `dir` on `host`, which also tells whether it can be reached
+pub fn connect(host: SshHost, dir: String, ui: &UiSender) {
+    let ui = ui.clone();
+    std::thread::spawn(move || {
+        let result = mikocore::resolve_dir(&host, &dir).map_err(|e| e.to_string());
+        ui.send(UiMessage::RemoteConnected { host, result });
+    });
+}
+
+/// List the remote folder whose local copy is `dir`
+pub fn list(mount: &RemoteMount, dir: PathBuf, ui: &UiSender) {
+    let (ui, mount) = (ui.clone(), mount.clone());
+    std::thread::spawn(move || {
+        let result = match mount.remote_path(&dir) {
+            Some(remote) => mikocore::list_dir(&mount.host, &remote).map_err(|e| e.to_string()),
+            None => Err(format!("{} is not in the remote folder", dir.display())),
+        };
+        ui.send(UiMessage::RemoteListing { dir, result });
+    });
+}
+
+/// Copy a remote file over its local copy at `path`, for the UI to open once it's there
+pub fn download(mount: &RemoteMount, path: PathBuf, ui: &UiSender) {
+    let (ui, mount) = (ui.clone(), mount.clone());
+    std::thread::spawn(move || {
+        let result = (|| {
+            let remote = mount.remote_path(&path).ok_or("not in the remote folder")?;
+            let contents = mikocore::read_file(&mount.host, &remote)?;
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&path, contents)?;
+            Ok::<(), Box<dyn std::error::Error>>(())
+        })();
+        ui.send(UiMessage::RemoteDownloaded { path, result: result.map_err(|e| e.to_string()) });
+    });
+}
+
+/// Copy the local copy at `path` over its remote file, as saved
+pub fn upload(mount: &RemoteMount, path: PathBuf, ui: &UiSender) {
+    let (ui, mount) = (ui.clone(), mount.clone());
+    std::thread::spawn(move || {
+        let result = (|| {
+            let remote = mount.remote_path(&path).ok_or("not in the remote folder")?;
+            mikocore::write_file(&mount.host, &remote, &std::fs::read(&path)?)?;
+            Ok::<(), Box<dyn std::error::Error>>(())
+        })();
+        ui.send(UiMessage::RemoteUploaded { path, result: result.map_err(|e| e.to_string()) });
+    });
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::state::AppState;
+
+/// How often a running session refreshes its lock file
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+/// A lock not refreshed for this long was left behind by a session that didn't exit cleanly
+const STALE_AFTER: Duration = Duration::from_secs(15);
+
+/// Lock file held while the app runs, removed again on a clean exit
+///
+/// A lock found at startup means the last session crashed or was killed. The heartbeat keeps
+/// the lock of a session that is still running from looking stale.
+pub struct SessionLock {
+    path: PathBuf,
+    last_beat: Instant,
+}
+
+impl SessionLock {
+    /// Take the lock; the flag is true when a previous session didn't exit cleanly
+    pub fn acquire() -> (Self, bool) {
+        let path = AppState::session_lock_path();
+        let unclean = match fs::read_to_string(&path) {
+            Ok(content) => {
+                let beat = content.lines().nth(1).and_then(|secs| secs.trim().parse::<u64>().ok()).unwrap_or(0);
+                let age = now_secs().saturating_sub(beat);
+                if age < STALE_AFTER.as_secs() {
+                    println!("Another session seems to be running; taking over its lock");
+                    false
+                } else {
+                    true
+                }
+            }
+            Err(_) => false,
+        };
+        let lock = Self {
+            path,
+            last_beat: Instant::now(),
+        };
+        lock.write();
+        (lock, unclean)
+    }
+    
+    /// Refresh the lock when the heartbeat is due; returns true when it was
+    pub fn beat(&mut self) -> bool {
+        if Instant::now() < self.next_beat() {
+            return false;
+        }
+        self.last_beat = Instant::now();
+        self.write();
+        true
+    }
+    
+    pub fn next_beat(&self) -> Instant {
+        self.last_beat + HEARTBEAT_INTERVAL
+    }
+    
+    /// Remove the lock on a clean exit
+    pub fn release(&self) {
+        if let Err(e) = fs::remove_file(&self.path) {
+            eprintln!("Failed to remove session lock: {}", e);
+        }
+    }
+    
+    /// `pid` and `seconds since the epoch`, one per line
+    fn write(&self) {
+        if let Err(e) = fs::write(&self.path, format!("{}\n{}\n", std::process::id(), now_secs())) {
+            eprintln!("Failed to write session lock: {}", e);
+        }
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Unsaved text of a tab, written with the heartbeat so a crash doesn't lose it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BufferBackup {
+    pub path: Option<PathBuf>, // None for untitled tabs
+    pub title: String,
+    pub text: String,
+}
+
+/// Replace the stored backups; an empty list removes the file
+pub fn save_backups(backups: &[BufferBackup]) -> Result<(), Box<dyn std::error::Error>> {
+    let path = AppState::backups_path();
+    if backups.is_empty() {
+        if path.exists() {
+            fs::remove_file(&path)?;
+        }
+        return Ok(());
+    }
+    // Write aside and rename, so a crash mid-write keeps the previous backups
+    let temp = path.with_extension("tmp");
+    fs::write(&temp, bincode::serialize(backups)?)?;
+    fs::rename(&temp, &path)?;
+    Ok(())
+}
+
+/// Backups left by the last session
+pub fn load_backups() -> Vec<BufferBackup> {
+    let Ok(bytes) = fs::read(AppState::backups_path()) else {
+        return Vec::new();
+    };
+    bincode::deserialize(&bytes).unwrap_or_else(|e| {
+        eprintln!("Failed to read buffer backups: {}", e);
+        Vec::new()
+    })
+}
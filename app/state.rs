@@ -1,8 +1,42 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::fs;
 use std::io::{Read, Write};
 
+/// Length of the recent folder and file lists
+const MAX_RECENT: usize = 10;
+/// Commands whose palette usage is remembered
+const MAX_COMMAND_USAGE: usize = 50;
+
+/// How often a command was run from the command palette
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommandUsage {
+    pub command: u32,
+    pub count: u32,
+}
+
+/// A bookmarked line of a file, kept with its workspace
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub path: PathBuf,
+    /// Zero-based
+    pub line: usize,
+    /// Name given with Label Bookmark; empty when it has none
+    pub label: String,
+}
+
+/// Which side panels are shown and how big they are; each profile keeps its own
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PanelLayout {
+    pub left_panel_visible: bool,
+    pub left_panel_width: f32,
+    pub right_panel_visible: bool,
+    pub right_panel_width: f32,
+    pub bottom_panel_visible: bool,
+    pub bottom_panel_height: f32,
+}
+
 /// Application state that persists between sessions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppState {
@@ -20,6 +54,18 @@ pub struct AppState {
     pub bottom_panel_visible: bool,
     pub bottom_panel_height: f32,
     pub expanded_folders: Vec<String>,
+    pub open_files: Vec<PathBuf>, // Tabs of the last session, in tab order
+    pub pinned_files: Vec<PathBuf>,
+    pub active_file: Option<PathBuf>,
+    pub recent_workspaces: Vec<PathBuf>, // Most recently opened first
+    pub recent_files: Vec<PathBuf>,
+    pub telemetry_enabled: bool, // Opted in to anonymous usage data, see `Telemetry`
+    pub command_usage: Vec<CommandUsage>, // Palette commands, most recently run first
+    pub workspace_trust: HashMap<PathBuf, bool>, // Folders answered in the trust prompt; false keeps them in Restricted Mode
+    pub profile: String, // Profile of the last session, see `ConfigLoader::set_profile`
+    pub profile_layouts: HashMap<String, PanelLayout>, // Panel layout of the profiles not in use
+    pub ssh_hosts: Vec<String>, // Hosts connected to, as `SshHost` specs, most recently used first
+    pub bookmarks: HashMap<PathBuf, Vec<Bookmark>>, // Bookmarks of each workspace folder, sorted by file and line
 }
 
 impl Default for AppState {
@@ -39,6 +85,18 @@ impl Default for AppState {
             bottom_panel_visible: false,
             bottom_panel_height: 200.0,
             expanded_folders: Vec::new(),
+            open_files: Vec::new(),
+            pinned_files: Vec::new(),
+            active_file: None,
+            recent_workspaces: Vec::new(),
+            recent_files: Vec::new(),
+            telemetry_enabled: false,
+            command_usage: Vec::new(),
+            workspace_trust: HashMap::new(),
+            profile: "Default".to_string(),
+            profile_layouts: HashMap::new(),
+            ssh_hosts: Vec::new(),
+            bookmarks: HashMap::new(),
         }
     }
 }
@@ -55,6 +113,41 @@ impl AppState {
         PathBuf::from("currentstate.rbx")
     }
     
+    /// Where the workspace index is cached between sessions, next to the state file
+    pub fn index_cache_path() -> PathBuf {
+        Self::state_file_path().with_file_name("workspaceindex.rbx")
+    }
+    
+    /// Lock file held while a session runs, see `SessionLock`
+    pub fn session_lock_path() -> PathBuf {
+        Self::state_file_path().with_file_name("session.lock")
+    }
+    
+    /// Port and token of the running instance, see `InstanceServer`
+    pub fn instance_path() -> PathBuf {
+        Self::state_file_path().with_file_name("instance.lock")
+    }
+    
+    /// Unsaved tab contents kept for recovery after a crash
+    pub fn backups_path() -> PathBuf {
+        Self::state_file_path().with_file_name("backups.rbx")
+    }
+    
+    /// Report written by the panic hook, waiting to be offered at the next start
+    pub fn crash_report_path() -> PathBuf {
+        Self::state_file_path().with_file_name("crash.txt")
+    }
+    
+    /// Local copies of the files of remote folders, in a folder per host
+    pub fn remote_cache_path() -> PathBuf {
+        Self::state_file_path().with_file_name("remote")
+    }
+    
+    /// Usage counters waiting for upload, kept as JSON so they can be inspected
+    pub fn metrics_path() -> PathBuf {
+        Self::state_file_path().with_file_name("metrics.json")
+    }
+    
     /// Load state from file
     pub fn load() -> Self {
         let path = Self::state_file_path();
@@ -113,6 +206,131 @@ impl AppState {
         Ok(())
     }
     
+    /// Move a folder to the front of the recent list; returns false when it already was
+    pub fn add_recent_workspace(&mut self, path: PathBuf) -> bool {
+        push_recent(&mut self.recent_workspaces, path)
+    }
+    
+    /// Move a file to the front of the recent list; returns false when it already was
+    pub fn add_recent_file(&mut self, path: PathBuf) -> bool {
+        push_recent(&mut self.recent_files, path)
+    }
+    
+    /// Move an SSH host to the front of the saved hosts
+    pub fn add_ssh_host(&mut self, spec: String) -> bool {
+        push_recent(&mut self.ssh_hosts, spec)
+    }
+    
+    /// Count a run of `command` from the palette and move it to the front
+    pub fn record_command_use(&mut self, command: u32) {
+        let count = match self.command_usage.iter().position(|usage| usage.command == command) {
+            Some(index) => self.command_usage.remove(index).count,
+            None => 0,
+        };
+        self.command_usage.insert(0, CommandUsage { command, count: count.saturating_add(1) });
+        self.command_usage.truncate(MAX_COMMAND_USAGE);
+    }
+    
+    /// Palette ranking boost of each used command: up to 40 for frequency, up to 20 for recency
+    pub fn command_boosts(&self) -> HashMap<u32, i32> {
+        self.command_usage
+            .iter()
+            .enumerate()
+            .map(|(recency, usage)| {
+                let frequency = 4 * usage.count.min(10) as i32;
+                let recency = 20 - 2 * recency.min(10) as i32;
+                (usage.command, frequency + recency)
+            })
+            .collect()
+    }
+    
+    /// Whether `path` was trusted in the trust prompt; None until it is answered
+    pub fn workspace_trust(&self, path: &Path) -> Option<bool> {
+        self.workspace_trust.get(path).copied()
+    }
+    
+    pub fn set_workspace_trust(&mut self, path: PathBuf, trusted: bool) {
+        self.workspace_trust.insert(path, trusted);
+    }
+    
+    /// Bookmarks of a workspace folder, sorted by file and line
+    pub fn bookmarks(&self, workspace: &Path) -> &[Bookmark] {
+        self.bookmarks.get(workspace).map_or(&[], Vec::as_slice)
+    }
+    
+    /// Replace the bookmarks of `file` with `lines` and their labels; returns false when they were already those
+    pub fn set_file_bookmarks(&mut self, workspace: &Path, file: &Path, lines: &[(usize, String)]) -> bool {
+        let current = self.bookmarks(workspace).iter().filter(|bookmark| bookmark.path == file);
+        if current.map(|bookmark| (bookmark.line, bookmark.label.as_str())).eq(lines.iter().map(|(line, label)| (*line, label.as_str()))) {
+            return false;
+        }
+        let bookmarks = self.bookmarks.entry(workspace.to_path_buf()).or_default();
+        bookmarks.retain(|bookmark| bookmark.path != file);
+        bookmarks.extend(lines.iter().map(|(line, label)| Bookmark { path: file.to_path_buf(), line: *line, label: label.clone() }));
+        bookmarks.sort_by(|a, b| (&a.path, a.line).cmp(&(&b.path, b.line)));
+        if bookmarks.is_empty() {
+            self.bookmarks.remove(workspace);
+        }
+        true
+    }
+    
+    /// Lines of `file` bookmarked in a workspace, with their labels
+    pub fn file_bookmarks(&self, workspace: &Path, file: &Path) -> Vec<(usize, String)> {
+        self.bookmarks(workspace)
+            .iter()
+            .filter(|bookmark| bookmark.path == file)
+            .map(|bookmark| (bookmark.line, bookmark.label.clone()))
+            .collect()
+    }
+    
+    /// The bookmark after line `line` of `file`, or before it going backwards, wrapping around past the last file
+    ///
+    /// Without a file it is the first bookmark, or the last going backwards.
+    pub fn next_bookmark(&self, workspace: &Path, file: Option<&Path>, line: usize, forward: bool) -> Option<&Bookmark> {
+        let bookmarks = self.bookmarks(workspace);
+        let found = match (file, forward) {
+            (Some(file), true) => bookmarks.iter().find(|bookmark| (bookmark.path.as_path(), bookmark.line) > (file, line)),
+            (Some(file), false) => bookmarks.iter().rev().find(|bookmark| (bookmark.path.as_path(), bookmark.line) < (file, line)),
+            (None, _) => None,
+        };
+        found.or(if forward { bookmarks.first() } else { bookmarks.last() })
+    }
+    
+    /// Panel layout of the profile in use
+    pub fn panel_layout(&self) -> PanelLayout {
+        PanelLayout {
+            left_panel_visible: self.left_panel_visible,
+            left_panel_width: self.left_panel_width,
+            right_panel_visible: self.right_panel_visible,
+            right_panel_width: self.right_panel_width,
+            bottom_panel_visible: self.bottom_panel_visible,
+            bottom_panel_height: self.bottom_panel_height,
+        }
+    }
+    
+    pub fn set_panel_layout(&mut self, layout: PanelLayout) {
+        self.left_panel_visible = layout.left_panel_visible;
+        self.left_panel_width = layout.left_panel_width;
+        self.right_panel_visible = layout.right_panel_visible;
+        self.right_panel_width = layout.right_panel_width;
+        self.bottom_panel_visible = layout.bottom_panel_visible;
+        self.bottom_panel_height = layout.bottom_panel_height;
+    }
+    
+    /// Put the current panel layout away with its profile and bring back `name`'s
+    ///
+    /// A profile used for the first time keeps the layout as it is.
+    pub fn switch_profile(&mut self, name: &str) -> PanelLayout {
+        if self.profile != name {
+            let layout = self.panel_layout();
+            self.profile_layouts.insert(std::mem::replace(&mut self.profile, name.to_string()), layout);
+            if let Some(layout) = self.profile_layouts.remove(name) {
+                self.set_panel_layout(layout);
+            }
+        }
+        self.panel_layout()
+    }
+    
     /// Check if a folder is expanded
     pub fn is_folder_expanded(&self, path: &str) -> bool {
         self.expanded_folders.contains(&path.to_string())
@@ -138,3 +356,96 @@ impl AppState {
         self.expanded_folders.clear();
     }
 }
+
+fn push_recent<T: PartialEq>(list: &mut Vec<T>, item: T) -> bool {
+    if list.first() == Some(&item) {
+        return false;
+    }
+    list.retain(|recent| recent != &item);
+    list.insert(0, item);
+    list.truncate(MAX_RECENT);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_usage_ranks_recent_and_frequent_first() {
+        let mut state = AppState::default();
+        for command in [6, 6, 6, 84, 63] {
+            state.record_command_use(command);
+        }
+        let commands: Vec<u32> = state.command_usage.iter().map(|usage| usage.command).collect();
+        assert_eq!(commands, vec![63, 84, 6]);
+        assert_eq!(state.command_usage[2].count, 3);
+
+        // Three runs outweigh being run last
+        let boosts = state.command_boosts();
+        assert!(boosts[&6] > boosts[&84]);
+        assert!(boosts[&63] > boosts[&84]);
+        assert!(!boosts.contains_key(&1));
+    }
+
+    #[test]
+    fn workspace_trust_is_kept_per_folder() {
+        let mut state = AppState::default();
+        let (trusted, restricted) = (PathBuf::from("/work/app"), PathBuf::from("/downloads/repo"));
+        state.set_workspace_trust(trusted.clone(), true);
+        state.set_workspace_trust(restricted.clone(), false);
+        assert_eq!(state.workspace_trust(&trusted), Some(true));
+        assert_eq!(state.workspace_trust(&restricted), Some(false));
+        assert_eq!(state.workspace_trust(Path::new("/work/app/src")), None);
+
+        let reloaded: AppState = bincode::deserialize(&bincode::serialize(&state).unwrap()).unwrap();
+        assert_eq!(reloaded.workspace_trust(&trusted), Some(true));
+    }
+
+    #[test]
+    fn profiles_keep_their_panel_layout() {
+        let mut state = AppState::default();
+        let default_layout = state.panel_layout();
+
+        // A new profile starts from the layout in use
+        assert_eq!(state.switch_profile("Writing"), default_layout);
+        state.left_panel_visible = false;
+        state.bottom_panel_height = 320.0;
+
+        let restored = state.switch_profile("Default");
+        assert_eq!(restored, default_layout);
+        assert_eq!(state.profile, "Default");
+
+        let writing = state.switch_profile("Writing");
+        assert!(!writing.left_panel_visible);
+        assert_eq!(writing.bottom_panel_height, 320.0);
+        assert_eq!(state.profile_layouts.keys().collect::<Vec<_>>(), vec!["Default"]);
+    }
+
+    #[test]
+    fn bookmarks_wrap_around_files() {
+        let mut state = AppState::default();
+        let workspace = Path::new("/work/app");
+        let (main, lib) = (Path::new("/work/app/main.rs"), Path::new("/work/app/lib.rs"));
+        assert!(state.set_file_bookmarks(workspace, main, &[(4, String::new()), (20, "exit".to_string())]));
+        assert!(state.set_file_bookmarks(workspace, lib, &[(9, String::new())]));
+        assert!(!state.set_file_bookmarks(workspace, lib, &[(9, String::new())]));
+        assert!(!state.set_file_bookmarks(Path::new("/other"), lib, &[]));
+        assert_eq!(state.file_bookmarks(workspace, main), vec![(4, String::new()), (20, "exit".to_string())]);
+
+        let next = |file: &Path, line, forward| {
+            let bookmark = state.next_bookmark(workspace, Some(file), line, forward).unwrap();
+            (bookmark.path.clone(), bookmark.line)
+        };
+        // lib.rs sorts before main.rs
+        assert_eq!(next(lib, 9, true), (main.to_path_buf(), 4));
+        assert_eq!(next(main, 4, true), (main.to_path_buf(), 20));
+        assert_eq!(next(main, 20, true), (lib.to_path_buf(), 9));
+        assert_eq!(next(lib, 9, false), (main.to_path_buf(), 20));
+        assert!(state.next_bookmark(Path::new("/other"), None, 0, true).is_none());
+
+        assert!(state.set_file_bookmarks(workspace, main, &[]));
+        assert!(state.set_file_bookmarks(workspace, lib, &[]));
+        assert!(state.bookmarks.is_empty());
+    }
+}
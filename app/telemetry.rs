@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::http::post_json;
+use crate::state::AppState;
+
+/// Anonymous usage counters, exactly what an upload sends
+///
+/// Only counts and setting values go in here: no paths, file contents, names or machine ids.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageReport {
+    pub sessions: u64,
+    pub crashed_sessions: u64, // Sessions followed by a start that found the session lock left behind
+    pub commands: BTreeMap<u32, u64>, // Invocations by command id
+    pub features: BTreeMap<String, String>, // Settings like `editor.auto_save`, as of the last start
+}
+
+impl UsageReport {
+    fn is_empty(&self) -> bool {
+        self.sessions == 0 && self.crashed_sessions == 0 && self.commands.is_empty()
+    }
+    
+    /// Add the counts of a report whose upload failed; newer feature values win
+    fn merge(&mut self, older: UsageReport) {
+        self.sessions += older.sessions;
+        self.crashed_sessions += older.crashed_sessions;
+        for (command, count) in older.commands {
+            *self.commands.entry(command).or_default() += count;
+        }
+        for (feature, value) in older.features {
+            self.features.entry(feature).or_insert(value);
+        }
+    }
+}
+
+/// Contents of the metrics file
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Buffer {
+    last_upload: u64, // Seconds since the epoch
+    report: UsageReport,
+}
+
+/// Opt-in usage metrics, buffered in a JSON file next to the state file and uploaded on a schedule
+///
+/// Nothing is recorded while disabled, and disabling removes what was buffered. The file is
+/// plain JSON so users can read what would be sent.
+pub struct Telemetry {
+    enabled: bool,
+    buffer: Buffer,
+    dirty: bool, // Counts changed since the last write
+    upload: Option<(UsageReport, Receiver<Result<(), String>>)>, // Report in flight, restored when the upload fails
+}
+
+impl Telemetry {
+    pub fn load(enabled: bool) -> Self {
+        let buffer = if enabled {
+            fs::read_to_string(AppState::metrics_path())
+                .ok()
+                .and_then(|content| serde_json::from_str(&content).ok())
+                .unwrap_or_default()
+        } else {
+            Buffer::default()
+        };
+        Self {
+            enabled,
+            buffer,
+            dirty: false,
+            upload: None,
+        }
+    }
+    
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+    
+    /// Opt in or out; opting out drops the buffered counts and their file
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.clear();
+        }
+    }
+    
+    /// Drop the buffered counts, keeping the upload schedule
+    pub fn clear(&mut self) {
+        self.buffer.report = UsageReport::default();
+        self.upload = None;
+        self.dirty = false;
+        let path = AppState::metrics_path();
+        if path.exists() {
+            if let Err(e) = fs::remove_file(&path) {
+                eprintln!("Failed to remove {}: {}", path.display(), e);
+            }
+        }
+    }
+    
+    /// Count a start; `crashed` is true when the previous session didn't exit cleanly
+    pub fn start_session(&mut self, crashed: bool, features: BTreeMap<String, String>) {
+        if !self.enabled {
+            return;
+        }
+        let report = &mut self.buffer.report;
+        report.sessions += 1;
+        if crashed {
+            report.crashed_sessions += 1;
+        }
+        report.features = features;
+        self.dirty = true;
+    }
+    
+    pub fn record_command(&mut self, command: u32) {
+        if !self.enabled {
+            return;
+        }
+        *self.buffer.report.commands.entry(command).or_default() += 1;
+        self.dirty = true;
+    }
+    
+    /// Write the counts to disk when they changed; returns the file for `Show Collected Data`
+    pub fn flush(&mut self) -> std::path::PathBuf {
+        let path = AppState::metrics_path();
+        if self.enabled && self.dirty {
+            match serde_json::to_string_pretty(&self.buffer) {
+                Ok(json) => match fs::write(&path, json) {
+                    Ok(()) => self.dirty = false,
+                    Err(e) => eprintln!("Failed to write {}: {}", path.display(), e),
+                },
+                Err(e) => eprintln!("Failed to serialize usage data: {}", e),
+            }
+        }
+        path
+    }
+    
+    /// Finish a running upload and start the next once `interval` passed since the last one
+    pub fn poll_upload(&mut self, endpoint: &str, interval: Duration) {
+        if let Some((_, result)) = &self.upload {
+            match result.try_recv() {
+                Ok(Ok(())) => {
+                    self.upload = None;
+                }
+                Ok(Err(e)) => {
+                    eprintln!("Failed to upload usage data: {}", e);
+                    if let Some((report, _)) = self.upload.take() {
+                        self.buffer.report.merge(report);
+                    }
+                }
+                Err(mpsc::TryRecvError::Empty) => return,
+                Err(mpsc::TryRecvError::Disconnected) => self.upload = None,
+            }
+        }
+        
+        let now = now_secs();
+        let due = now.saturating_sub(self.buffer.last_upload) >= interval.as_secs();
+        if !self.enabled || endpoint.is_empty() || !due || self.buffer.report.is_empty() {
+            return;
+        }
+        // A failed upload waits for the next interval too, so an unreachable server isn't hammered
+        self.buffer.last_upload = now;
+        let report = std::mem::take(&mut self.buffer.report);
+        self.buffer.report.features = report.features.clone();
+        self.dirty = true;
+        
+        let body = match serde_json::to_string(&report) {
+            Ok(body) => body,
+            Err(e) => {
+                eprintln!("Failed to serialize usage data: {}", e);
+                return;
+            }
+        };
+        let endpoint = endpoint.to_string();
+        let (sender, result) = mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = sender.send(post_json(&endpoint, &body));
+        });
+        self.upload = Some((report, result));
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+}
@@ -0,0 +1,110 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::fs;
+use serde::{Deserialize, Serialize};
+use mikoui::{color_to_hex, parse_hex_color, SyntaxColors, ThemeColors, ThemeMode};
+
+/// Theme file stored as YAML in `shared/themes/<name>.yml`
+///
+/// ```yaml
+/// name: Sunset
+/// mode: dark
+/// colors:
+///   background: "#121212"
+///   primary: "#F97316"
+/// syntax:
+///   keyword: "#F472B6"
+///   comment: "#6B7280"
+/// ```
+/// Colors missing from the file fall back to the built-in palette for `mode`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThemeFile {
+    pub name: String,
+    #[serde(default = "default_mode")]
+    pub mode: String,
+    #[serde(default)]
+    pub colors: BTreeMap<String, String>,
+    /// Code highlighting colors by `SyntaxColors` field name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub syntax: BTreeMap<String, String>,
+}
+
+fn default_mode() -> String { "dark".to_string() }
+
+impl ThemeFile {
+    /// Capture every color of a palette
+    pub fn from_colors(name: &str, mode: ThemeMode, colors: &ThemeColors) -> Self {
+        let syntax = SyntaxColors::FIELD_NAMES
+            .iter()
+            .filter_map(|field| Some((field.to_string(), color_to_hex(colors.syntax.get(field)?))))
+            .collect();
+        let colors = ThemeColors::FIELD_NAMES
+            .iter()
+            .filter_map(|field| Some((field.to_string(), color_to_hex(colors.get(field)?))))
+            .collect();
+        Self {
+            name: name.to_string(),
+            mode: match mode {
+                ThemeMode::Dark => "dark",
+                ThemeMode::Light => "light",
+            }
+            .to_string(),
+            colors,
+            syntax,
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(serde_yaml::from_str(content)?)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_yaml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn theme_mode(&self) -> ThemeMode {
+        if self.mode.eq_ignore_ascii_case("light") {
+            ThemeMode::Light
+        } else {
+            ThemeMode::Dark
+        }
+    }
+
+    /// Resolve the palette on top of `base`; unknown keys and bad colors are errors
+    pub fn to_colors(&self, base: ThemeColors) -> Result<ThemeColors, String> {
+        let mut colors = base;
+        for (field, value) in &self.colors {
+            let color = parse_hex_color(value)
+                .ok_or_else(|| format!("Invalid color '{}' for '{}'", value, field))?;
+            if !colors.set(field, color) {
+                return Err(format!("Unknown theme color '{}'", field));
+            }
+        }
+        for (field, value) in &self.syntax {
+            let color = parse_hex_color(value)
+                .ok_or_else(|| format!("Invalid color '{}' for 'syntax.{}'", value, field))?;
+            if !colors.syntax.set(field, color) {
+                return Err(format!("Unknown syntax color '{}'", field));
+            }
+        }
+        Ok(colors)
+    }
+
+    /// File name used when saving into the themes directory
+    pub fn file_name(&self) -> String {
+        let slug: String = self.name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+            .collect();
+        let slug = slug.trim_matches('-');
+        format!("{}.yml", if slug.is_empty() { "custom" } else { slug })
+    }
+}
@@ -1,5 +1,5 @@
 use skia_safe::Color;
-use mikoui::ThemeColors;
+use mikoui::{SyntaxColors, ThemeColors};
 
 /// Kiro theme - Modern, clean design with subtle accents
 pub struct KiroTheme;
@@ -26,6 +26,20 @@ impl KiroTheme {
             border: Color::from_argb(255, 38, 38, 38),
             input: Color::from_argb(255, 38, 38, 38),
             ring: Color::from_argb(255, 99, 102, 241),
+            syntax: SyntaxColors {
+                keyword: Color::from_rgb(167, 139, 250),    // Violet-400
+                function: Color::from_rgb(125, 211, 252),   // Sky-300
+                type_name: Color::from_rgb(94, 234, 212),   // Teal-300
+                string: Color::from_rgb(134, 239, 172),     // Green-300
+                number: Color::from_rgb(251, 191, 36),      // Amber-400
+                comment: Color::from_rgb(115, 115, 115),    // Neutral-500
+                operator: Color::from_rgb(163, 163, 163),   // Neutral-400
+                punctuation: Color::from_rgb(163, 163, 163), // Neutral-400
+                variable: Color::from_rgb(230, 230, 230),   // #E6E6E6
+                property: Color::from_rgb(165, 180, 252),   // Indigo-300
+                parameter: Color::from_rgb(253, 186, 116),  // Orange-300
+                constant: Color::from_rgb(249, 168, 212),   // Pink-300
+            },
         }
     }
 
@@ -50,6 +64,20 @@ impl KiroTheme {
             border: Color::from_argb(255, 229, 229, 229),
             input: Color::from_argb(255, 229, 229, 229),
             ring: Color::from_argb(255, 79, 70, 229),
+            syntax: SyntaxColors {
+                keyword: Color::from_rgb(109, 40, 217),     // Violet-700
+                function: Color::from_rgb(3, 105, 161),     // Sky-700
+                type_name: Color::from_rgb(15, 118, 110),   // Teal-700
+                string: Color::from_rgb(21, 128, 61),       // Green-700
+                number: Color::from_rgb(180, 83, 9),        // Amber-700
+                comment: Color::from_rgb(115, 115, 115),    // Neutral-500
+                operator: Color::from_rgb(82, 82, 82),      // Neutral-600
+                punctuation: Color::from_rgb(82, 82, 82),   // Neutral-600
+                variable: Color::from_rgb(24, 24, 24),      // #181818
+                property: Color::from_rgb(67, 56, 202),     // Indigo-700
+                parameter: Color::from_rgb(194, 65, 12),    // Orange-700
+                constant: Color::from_rgb(190, 24, 93),     // Pink-700
+            },
         }
     }
 }
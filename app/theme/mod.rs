@@ -1,3 +1,4 @@
 pub mod kiro;
 pub mod vscode;
 pub mod xcode;
+pub mod file;
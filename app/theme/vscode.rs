@@ -1,5 +1,5 @@
 use skia_safe::Color;
-use mikoui::ThemeColors;
+use mikoui::{SyntaxColors, ThemeColors};
 
 /// VSCode theme - Familiar editor colors
 pub struct VSCodeTheme;
@@ -26,6 +26,7 @@ impl VSCodeTheme {
             border: Color::from_argb(255, 51, 51, 51),
             input: Color::from_argb(255, 51, 51, 51),
             ring: Color::from_argb(255, 0, 122, 204),
+            syntax: SyntaxColors::dark(),
         }
     }
 
@@ -50,6 +51,7 @@ impl VSCodeTheme {
             border: Color::from_argb(255, 229, 229, 229),
             input: Color::from_argb(255, 229, 229, 229),
             ring: Color::from_argb(255, 0, 122, 204),
+            syntax: SyntaxColors::light(),
         }
     }
 }
@@ -1,5 +1,5 @@
 use skia_safe::Color;
-use mikoui::ThemeColors;
+use mikoui::{SyntaxColors, ThemeColors};
 
 /// Xcode theme - Apple's development environment style
 pub struct XcodeTheme;
@@ -26,6 +26,20 @@ impl XcodeTheme {
             border: Color::from_argb(255, 58, 59, 62),
             input: Color::from_argb(255, 58, 59, 62),
             ring: Color::from_argb(255, 10, 132, 255),
+            syntax: SyntaxColors {
+                keyword: Color::from_rgb(252, 95, 163),     // #FC5FA3
+                function: Color::from_rgb(103, 183, 164),   // #67B7A4
+                type_name: Color::from_rgb(93, 216, 255),   // #5DD8FF
+                string: Color::from_rgb(252, 106, 93),      // #FC6A5D
+                number: Color::from_rgb(208, 191, 105),     // #D0BF69
+                comment: Color::from_rgb(108, 121, 134),    // #6C7986
+                operator: Color::from_rgb(223, 223, 224),   // #DFDFE0
+                punctuation: Color::from_rgb(223, 223, 224), // #DFDFE0
+                variable: Color::from_rgb(223, 223, 224),   // #DFDFE0
+                property: Color::from_rgb(161, 103, 230),   // #A167E6
+                parameter: Color::from_rgb(223, 223, 224),  // #DFDFE0
+                constant: Color::from_rgb(253, 143, 63),    // #FD8F3F
+            },
         }
     }
 
@@ -50,6 +64,20 @@ impl XcodeTheme {
             border: Color::from_argb(255, 229, 229, 234),
             input: Color::from_argb(255, 229, 229, 234),
             ring: Color::from_argb(255, 0, 122, 255),
+            syntax: SyntaxColors {
+                keyword: Color::from_rgb(155, 35, 147),     // #9B2393
+                function: Color::from_rgb(50, 109, 116),    // #326D74
+                type_name: Color::from_rgb(11, 79, 121),    // #0B4F79
+                string: Color::from_rgb(196, 26, 22),       // #C41A16
+                number: Color::from_rgb(28, 0, 207),        // #1C00CF
+                comment: Color::from_rgb(93, 108, 121),     // #5D6C79
+                operator: Color::from_rgb(38, 38, 38),      // #262626
+                punctuation: Color::from_rgb(38, 38, 38),   // #262626
+                variable: Color::from_rgb(38, 38, 38),      // #262626
+                property: Color::from_rgb(108, 54, 169),    // #6C36A9
+                parameter: Color::from_rgb(38, 38, 38),     // #262626
+                constant: Color::from_rgb(100, 56, 32),     // #643820
+            },
         }
     }
 }
@@ -0,0 +1,580 @@
+::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 0),
+        (1, 36),
+        (0, 70),
+        (0, 74),
+        (0, 112),
+        (0, 263),
+        (0, 8),
+        (0, 51),
+        (0, 4),
+        (0, 69),
+        (0, 168),
+        (0, 39),
+        (0, 0),
+        (0, 119),
+        (3, 113),
+        (0, 0),
+        (0, 161),
+        (1, 127),
+        (2, 346),
+        (0, 43),
+        (0, 30),
+        (0, 1),
+        (0, 7),
+        (1, 41),
+        (0, 151),
+        (0, 16),
+        (0, 1),
+        (0, 0),
+        (0, 6),
+        (0, 201),
+        (0, 303),
+        (0, 117),
+        (0, 306),
+        (0, 14),
+        (0, 173),
+        (0, 7),
+        (0, 43),
+        (0, 388),
+        (0, 17),
+        (0, 5),
+        (0, 12),
+        (0, 147),
+        (0, 128),
+        (0, 163),
+        (0, 19),
+        (1, 15),
+        (0, 3),
+        (0, 251),
+        (0, 0),
+        (0, 164),
+        (0, 10),
+        (0, 106),
+        (0, 182),
+        (0, 31),
+        (0, 2),
+        (2, 207),
+        (0, 48),
+        (1, 381),
+        (11, 188),
+        (0, 18),
+        (0, 25),
+        (2, 111),
+        (13, 415),
+        (0, 91),
+        (0, 8),
+        (8, 241),
+        (1, 395),
+        (1, 287),
+        (0, 125),
+        (1, 446),
+        (0, 356),
+        (1, 111),
+        (0, 51),
+        (0, 9),
+        (0, 10),
+        (0, 342),
+        (0, 154),
+        (1, 221),
+        (0, 330),
+        (0, 0),
+        (0, 38),
+        (23, 433),
+        (2, 397),
+        (0, 35),
+        (8, 116),
+        (0, 0),
+        (0, 2),
+        (20, 293),
+        (2, 7),
+        (0, 11),
+        (5, 102),
+        (0, 0),
+        (13, 398),
+        (0, 96),
+        (60, 194),
+        (1, 364),
+    ],
+    entries: &[
+        (b"ACKNOWLEDGE", '\u{0006}'),
+        (b"HENTAIGANA LETTER E-1", '\u{1B001}'),
+        (b"VS188", '\u{E01AB}'),
+        (b"ZWNBSP", '\u{FEFF}'),
+        (b"VS190", '\u{E01AD}'),
+        (b"ZWSP", '\u{200B}'),
+        (b"VS211", '\u{E01C2}'),
+        (b"VS106", '\u{E0159}'),
+        (b"LINE TABULATION SET", '\u{008A}'),
+        (b"START OF TEXT", '\u{0002}'),
+        (b"VS222", '\u{E01CD}'),
+        (b"VS230", '\u{E01D5}'),
+        (b"PRIVATE USE TWO", '\u{0092}'),
+        (b"ENQUIRY", '\u{0005}'),
+        (b"IND", '\u{0084}'),
+        (b"STRING TERMINATOR", '\u{009C}'),
+        (b"VS102", '\u{E0155}'),
+        (b"VS210", '\u{E01C1}'),
+        (b"VS88", '\u{E0147}'),
+        (b"VS50", '\u{E0121}'),
+        (b"VS133", '\u{E0174}'),
+        (b"VS96", '\u{E014F}'),
+        (b"VS45", '\u{E011C}'),
+        (b"VS29", '\u{E010C}'),
+        (b"EOM", '\u{0019}'),
+        (b"VT", '\u{000B}'),
+        (b"VS58", '\u{E0129}'),
+        (b"NNBSP", '\u{202F}'),
+        (b"SS3", '\u{008F}'),
+        (b"UNIT SEPARATOR", '\u{001F}'),
+        (b"VS122", '\u{E0169}'),
+        (b"REVERSE INDEX", '\u{008D}'),
+        (b"VS229", '\u{E01D4}'),
+        (b"VS232", '\u{E01D7}'),
+        (b"VS9", '\u{FE08}'),
+        (b"SSA", '\u{0086}'),
+        (b"VS125", '\u{E016C}'),
+        (b"VS243", '\u{E01E2}'),
+        (b"APC", '\u{009F}'),
+        (b"EOT", '\u{0004}'),
+        (b"VS221", '\u{E01CC}'),
+        (b"VS231", '\u{E01D6}'),
+        (b"VS80", '\u{E013F}'),
+        (b"VS67", '\u{E0132}'),
+        (b"YI SYLLABLE ITERATION MARK", '\u{A015}'),
+        (b"CUNEIFORM SIGN NU11 TENU", '\u{122D4}'),
+        (b"VS99", '\u{E0152}'),
+        (b"VS110", '\u{E015D}'),
+        (b"VS128", '\u{E016F}'),
+        (b"INDEX", '\u{0084}'),
+        (b"VS104", '\u{E0157}'),
+        (b"VS113", '\u{E0160}'),
+        (b"VS62", '\u{E012D}'),
+        (b"VS98", '\u{E0151}'),
+        (b"RIGHTWARDS TRIANGLE-HEADED ARROW WITH DOUBLE VERTICAL STROKE", '\u{2B7C}'),
+        (b"ESCAPE", '\u{001B}'),
+        (b"HANGUL JONGSEONG YESIEUNG-KIYEOK", '\u{11EC}'),
+        (b"VS244", '\u{E01E3}'),
+        (b"LRO", '\u{202D}'),
+        (b"LRE", '\u{202A}'),
+        (b"VS182", '\u{E01A5}'),
+        (b"VS65", '\u{E0130}'),
+        (b"VS248", '\u{E01E7}'),
+        (b"VS78", '\u{E013D}'),
+        (b"HANGUL JONGSEONG YESIEUNG-SSANGKIYEOK", '\u{11ED}'),
+        (b"MEDEFAIDRIN CAPITAL LETTER NG", '\u{16E57}'),
+        (b"LAO LETTER FO FON", '\u{0E9D}'),
+        (b"EPA", '\u{0097}'),
+        (b"SHY", '\u{00AD}'),
+        (b"VS213", '\u{E01C4}'),
+        (b"VS30", '\u{E010D}'),
+        (b"VS100", '\u{E0153}'),
+        (b"VS84", '\u{E0143}'),
+        (b"VERTICAL TABULATION SET", '\u{008A}'),
+        (b"VS225", '\u{E01D0}'),
+        (b"VS218", '\u{E01C9}'),
+        (b"VS32", '\u{E010F}'),
+        (b"SUBSTITUTE", '\u{001A}'),
+        (b"NEXT LINE", '\u{0085}'),
+        (b"VS86", '\u{E0145}'),
+        (b"HT", '\u{0009}'),
+        (b"VS121", '\u{E0168}'),
+        (b"VS143", '\u{E017E}'),
+        (b"VS141", '\u{E017C}'),
+        (b"VS220", '\u{E01CB}'),
+        (b"NL", '\u{000A}'),
+        (b"RLM", '\u{200F}'),
+        (b"PLU", '\u{008C}'),
+        (b"VS69", '\u{E0134}'),
+        (b"SS2", '\u{008E}'),
+        (b"VS216", '\u{E01C7}'),
+        (b"VS2", '\u{FE01}'),
+        (b"VS163", '\u{E0192}'),
+        (b"PDI", '\u{2069}'),
+        (b"MW", '\u{0095}'),
+        (b"VS148", '\u{E0183}'),
+        (b"CSI", '\u{009B}'),
+        (b"VS24", '\u{E0107}'),
+        (b"RLO", '\u{202E}'),
+        (b"VS111", '\u{E015E}'),
+        (b"SINGLE-SHIFT-3", '\u{008F}'),
+        (b"VS36", '\u{E0113}'),
+        (b"KANNADA LETTER LLLA", '\u{0CDE}'),
+        (b"VS116", '\u{E0163}'),
+        (b"VS73", '\u{E0138}'),
+        (b"VS103", '\u{E0156}'),
+        (b"VS214", '\u{E01C5}'),
+        (b"NO BREAK HERE", '\u{0083}'),
+        (b"VS76", '\u{E013B}'),
+        (b"ZWJ", '\u{200D}'),
+        (b"VS8", '\u{FE07}'),
+        (b"VS150", '\u{E0185}'),
+        (b"ARABIC SMALL HIGH LIGATURE ALEF WITH YEH BARREE", '\u{0616}'),
+        (b"VS173", '\u{E019C}'),
+        (b"VS169", '\u{E0198}'),
+        (b"CR", '\u{000D}'),
+        (b"HOP", '\u{0081}'),
+        (b"BOM", '\u{FEFF}'),
+        (b"VS131", '\u{E0172}'),
+        (b"NBSP", '\u{00A0}'),
+        (b"CHARACTER TABULATION SET", '\u{0088}'),
+        (b"VS254", '\u{E01ED}'),
+        (b"VS172", '\u{E019B}'),
+        (b"VS252", '\u{E01EB}'),
+        (b"LF", '\u{000A}'),
+        (b"CUNEIFORM SIGN KALAM", '\u{12327}'),
+        (b"DELETE", '\u{007F}'),
+        (b"VS198", '\u{E01B5}'),
+        (b"VS209", '\u{E01C0}'),
+        (b"REVERSE LINE FEED", '\u{008D}'),
+        (b"VS175", '\u{E019E}'),
+        (b"CANCEL", '\u{0018}'),
+        (b"VS6", '\u{FE05}'),
+        (b"VS194", '\u{E01B1}'),
+        (b"LRI", '\u{2066}'),
+        (b"VS183", '\u{E01A6}'),
+        (b"VS112", '\u{E015F}'),
+        (b"VS145", '\u{E0180}'),
+        (b"VS164", '\u{E0193}'),
+        (b"CANCEL CHARACTER", '\u{0094}'),
+        (b"VS17", '\u{E0100}'),
+        (b"VS142", '\u{E017D}'),
+        (b"LATIN CAPITAL LETTER GHA", '\u{01A2}'),
+        (b"MEDEFAIDRIN SMALL LETTER H", '\u{16E76}'),
+        (b"VS119", '\u{E0166}'),
+        (b"ALM", '\u{061C}'),
+        (b"SINGLE SHIFT TWO", '\u{008E}'),
+        (b"MENDE KIKAKUI SYLLABLE M172 MBO", '\u{1E899}'),
+        (b"PAD", '\u{0080}'),
+        (b"MEDEFAIDRIN CAPITAL LETTER H", '\u{16E56}'),
+        (b"PRESENTATION FORM FOR VERTICAL RIGHT WHITE LENTICULAR BRACKET", '\u{FE18}'),
+        (b"STX", '\u{0002}'),
+        (b"VS3", '\u{FE02}'),
+        (b"VS132", '\u{E0173}'),
+        (b"VS249", '\u{E01E8}'),
+        (b"PM", '\u{009E}'),
+        (b"FVS3", '\u{180D}'),
+        (b"VS201", '\u{E01B8}'),
+        (b"TIBETAN MARK BKA- SHOG GI MGO RGYAN", '\u{0FD0}'),
+        (b"VS140", '\u{E017B}'),
+        (b"FORM FEED", '\u{000C}'),
+        (b"INFORMATION SEPARATOR THREE", '\u{001D}'),
+        (b"VS137", '\u{E0178}'),
+        (b"VS138", '\u{E0179}'),
+        (b"GROUP SEPARATOR", '\u{001D}'),
+        (b"VS139", '\u{E017A}'),
+        (b"RS", '\u{001E}'),
+        (b"INFORMATION SEPARATOR FOUR", '\u{001C}'),
+        (b"FS", '\u{001C}'),
+        (b"NULL", '\u{0000}'),
+        (b"VS166", '\u{E0195}'),
+        (b"VS120", '\u{E0167}'),
+        (b"SI", '\u{000F}'),
+        (b"MEDEFAIDRIN SMALL LETTER NG", '\u{16E77}'),
+        (b"VS54", '\u{E0125}'),
+        (b"VS66", '\u{E0131}'),
+        (b"VS46", '\u{E011D}'),
+        (b"RI", '\u{008D}'),
+        (b"VS20", '\u{E0103}'),
+        (b"VS77", '\u{E013C}'),
+        (b"HORIZONTAL TABULATION WITH JUSTIFICATION", '\u{0089}'),
+        (b"VS12", '\u{FE0B}'),
+        (b"VS115", '\u{E0162}'),
+        (b"SINGLE SHIFT THREE", '\u{008F}'),
+        (b"VS223", '\u{E01CE}'),
+        (b"SOS", '\u{0098}'),
+        (b"PRIVATE USE-2", '\u{0092}'),
+        (b"BYZANTINE MUSICAL SYMBOL FTHORA SKLIRON CHROMA VASIS", '\u{1D0C5}'),
+        (b"FVS1", '\u{180B}'),
+        (b"VS68", '\u{E0133}'),
+        (b"VS15", '\u{FE0E}'),
+        (b"VS123", '\u{E016A}'),
+        (b"ST", '\u{009C}'),
+        (b"BEL", '\u{0007}'),
+        (b"VS154", '\u{E0189}'),
+        (b"VS144", '\u{E017F}'),
+        (b"DEVICE CONTROL THREE", '\u{0013}'),
+        (b"FF", '\u{000C}'),
+        (b"VS49", '\u{E0120}'),
+        (b"VS197", '\u{E01B4}'),
+        (b"DC2", '\u{0012}'),
+        (b"DC4", '\u{0014}'),
+        (b"VS75", '\u{E013A}'),
+        (b"VS56", '\u{E0127}'),
+        (b"DCS", '\u{0090}'),
+        (b"VS185", '\u{E01A8}'),
+        (b"VS204", '\u{E01BB}'),
+        (b"VS42", '\u{E0119}'),
+        (b"PLD", '\u{008B}'),
+        (b"MESSAGE WAITING", '\u{0095}'),
+        (b"VS186", '\u{E01A9}'),
+        (b"ZWNJ", '\u{200C}'),
+        (b"VS196", '\u{E01B3}'),
+        (b"APPLICATION PROGRAM COMMAND", '\u{009F}'),
+        (b"VS161", '\u{E0190}'),
+        (b"VS31", '\u{E010E}'),
+        (b"VS235", '\u{E01DA}'),
+        (b"VS193", '\u{E01B0}'),
+        (b"VS151", '\u{E0186}'),
+        (b"DEVICE CONTROL TWO", '\u{0012}'),
+        (b"VS156", '\u{E018B}'),
+        (b"BYTE ORDER MARK", '\u{FEFF}'),
+        (b"VS89", '\u{E0148}'),
+        (b"LINE TABULATION", '\u{000B}'),
+        (b"VS25", '\u{E0108}'),
+        (b"VS74", '\u{E0139}'),
+        (b"ESC", '\u{001B}'),
+        (b"DC3", '\u{0013}'),
+        (b"HANGUL JONGSEONG YESIEUNG-KHIEUKH", '\u{11EF}'),
+        (b"VS153", '\u{E0188}'),
+        (b"PARTIAL LINE FORWARD", '\u{008B}'),
+        (b"OSC", '\u{009D}'),
+        (b"VS90", '\u{E0149}'),
+        (b"VS28", '\u{E010B}'),
+        (b"VS157", '\u{E018C}'),
+        (b"INFORMATION SEPARATOR TWO", '\u{001E}'),
+        (b"SHIFT IN", '\u{000F}'),
+        (b"VS177", '\u{E01A0}'),
+        (b"VS180", '\u{E01A3}'),
+        (b"VS158", '\u{E018D}'),
+        (b"DEVICE CONTROL ONE", '\u{0011}'),
+        (b"NEGATIVE ACKNOWLEDGE", '\u{0015}'),
+        (b"VS34", '\u{E0111}'),
+        (b"VS219", '\u{E01CA}'),
+        (b"VS228", '\u{E01D3}'),
+        (b"VS108", '\u{E015B}'),
+        (b"CHARACTER TABULATION", '\u{0009}'),
+        (b"MMSP", '\u{205F}'),
+        (b"NUL", '\u{0000}'),
+        (b"VS124", '\u{E016B}'),
+        (b"VS107", '\u{E015A}'),
+        (b"MVS", '\u{180E}'),
+        (b"DLE", '\u{0010}'),
+        (b"END OF TRANSMISSION BLOCK", '\u{0017}'),
+        (b"VS227", '\u{E01D2}'),
+        (b"VS134", '\u{E0175}'),
+        (b"INFORMATION SEPARATOR ONE", '\u{001F}'),
+        (b"VS240", '\u{E01DF}'),
+        (b"VS41", '\u{E0118}'),
+        (b"FILE SEPARATOR", '\u{001C}'),
+        (b"VS178", '\u{E01A1}'),
+        (b"SUNDANESE LETTER ARCHAIC I", '\u{1BBD}'),
+        (b"CHARACTER TABULATION WITH JUSTIFICATION", '\u{0089}'),
+        (b"VS160", '\u{E018F}'),
+        (b"VS224", '\u{E01CF}'),
+        (b"PADDING CHARACTER", '\u{0080}'),
+        (b"VS57", '\u{E0128}'),
+        (b"TAB", '\u{0009}'),
+        (b"VS217", '\u{E01C8}'),
+        (b"VS199", '\u{E01B6}'),
+        (b"HANGUL JONGSEONG SSANGYESIEUNG", '\u{11EE}'),
+        (b"VS192", '\u{E01AF}'),
+        (b"VS105", '\u{E0158}'),
+        (b"VS101", '\u{E0154}'),
+        (b"ETX", '\u{0003}'),
+        (b"BACKSPACE", '\u{0008}'),
+        (b"VS250", '\u{E01E9}'),
+        (b"RECORD SEPARATOR", '\u{001E}'),
+        (b"VS55", '\u{E0126}'),
+        (b"MICR DASH SYMBOL", '\u{2449}'),
+        (b"VS146", '\u{E0181}'),
+        (b"START OF GUARDED AREA", '\u{0096}'),
+        (b"GS", '\u{001D}'),
+        (b"VS85", '\u{E0144}'),
+        (b"ALERT", '\u{0007}'),
+        (b"VS176", '\u{E019F}'),
+        (b"VS16", '\u{FE0F}'),
+        (b"BPH", '\u{0082}'),
+        (b"MYANMAR LETTER KHAMTI LLA", '\u{AA6E}'),
+        (b"VS63", '\u{E012E}'),
+        (b"LAO LETTER LO", '\u{0EA5}'),
+        (b"VS53", '\u{E0124}'),
+        (b"PARTIAL LINE BACKWARD", '\u{008C}'),
+        (b"DEVICE CONTROL FOUR", '\u{0014}'),
+        (b"ETB", '\u{0017}'),
+        (b"RLE", '\u{202B}'),
+        (b"SOH", '\u{0001}'),
+        (b"PDF", '\u{202C}'),
+        (b"VS13", '\u{FE0C}'),
+        (b"VS114", '\u{E0161}'),
+        (b"CUNEIFORM SIGN NU11 OVER NU11 BUR OVER BUR", '\u{122D5}'),
+        (b"VS130", '\u{E0171}'),
+        (b"CARRIAGE RETURN", '\u{000D}'),
+        (b"WJ", '\u{2060}'),
+        (b"SYRIAC SUBLINEAR COLON SKEWED LEFT", '\u{0709}'),
+        (b"CONTROL SEQUENCE INTRODUCER", '\u{009B}'),
+        (b"VTS", '\u{008A}'),
+        (b"END OF GUARDED AREA", '\u{0097}'),
+        (b"VS165", '\u{E0194}'),
+        (b"VS236", '\u{E01DB}'),
+        (b"SCI", '\u{009A}'),
+        (b"VS23", '\u{E0106}'),
+        (b"VS170", '\u{E0199}'),
+        (b"FVS4", '\u{180F}'),
+        (b"VS162", '\u{E0191}'),
+        (b"END OF TEXT", '\u{0003}'),
+        (b"NEW LINE", '\u{000A}'),
+        (b"VS159", '\u{E018E}'),
+        (b"VS117", '\u{E0164}'),
+        (b"VS94", '\u{E014D}'),
+        (b"VS203", '\u{E01BA}'),
+        (b"LATIN SMALL LETTER GHA", '\u{01A3}'),
+        (b"VS179", '\u{E01A2}'),
+        (b"VS239", '\u{E01DE}'),
+        (b"VS5", '\u{FE04}'),
+        (b"CGJ", '\u{034F}'),
+        (b"VS247", '\u{E01E6}'),
+        (b"VS171", '\u{E019A}'),
+        (b"HTS", '\u{0088}'),
+        (b"HORIZONTAL TABULATION SET", '\u{0088}'),
+        (b"PU2", '\u{0092}'),
+        (b"SUB", '\u{001A}'),
+        (b"VS37", '\u{E0114}'),
+        (b"DC1", '\u{0011}'),
+        (b"START OF HEADING", '\u{0001}'),
+        (b"DATA LINK ESCAPE", '\u{0010}'),
+        (b"VS251", '\u{E01EA}'),
+        (b"VS19", '\u{E0102}'),
+        (b"VS61", '\u{E012C}'),
+        (b"VERTICAL TABULATION", '\u{000B}'),
+        (b"VS64", '\u{E012F}'),
+        (b"CAN", '\u{0018}'),
+        (b"VS60", '\u{E012B}'),
+        (b"RLI", '\u{2067}'),
+        (b"PRIVATE USE-1", '\u{0091}'),
+        (b"VS174", '\u{E019D}'),
+        (b"VS22", '\u{E0105}'),
+        (b"END OF PROTECTED AREA", '\u{0097}'),
+        (b"START OF STRING", '\u{0098}'),
+        (b"VS26", '\u{E0109}'),
+        (b"FSI", '\u{2068}'),
+        (b"BREAK PERMITTED HERE", '\u{0082}'),
+        (b"VS168", '\u{E0197}'),
+        (b"VS184", '\u{E01A7}'),
+        (b"SYN", '\u{0016}'),
+        (b"VS237", '\u{E01DC}'),
+        (b"VS4", '\u{FE03}'),
+        (b"ENQ", '\u{0005}'),
+        (b"VS253", '\u{E01EC}'),
+        (b"VS97", '\u{E0150}'),
+        (b"MENDE KIKAKUI SYLLABLE M174 MBOO", '\u{1E89A}'),
+        (b"END OF SELECTED AREA", '\u{0087}'),
+        (b"SINGLE GRAPHIC CHARACTER INTRODUCER", '\u{0099}'),
+        (b"BS", '\u{0008}'),
+        (b"VS215", '\u{E01C6}'),
+        (b"LAO LETTER RO", '\u{0EA3}'),
+        (b"VS72", '\u{E0137}'),
+        (b"VS155", '\u{E018A}'),
+        (b"VS245", '\u{E01E4}'),
+        (b"PRIVACY MESSAGE", '\u{009E}'),
+        (b"HIGH OCTET PRESET", '\u{0081}'),
+        (b"VS83", '\u{E0142}'),
+        (b"SP", '\u{0020}'),
+        (b"OPERATING SYSTEM COMMAND", '\u{009D}'),
+        (b"VS43", '\u{E011A}'),
+        (b"VS191", '\u{E01AE}'),
+        (b"STS", '\u{0093}'),
+        (b"HORIZONTAL TABULATION", '\u{0009}'),
+        (b"PARTIAL LINE DOWN", '\u{008B}'),
+        (b"VS47", '\u{E011E}'),
+        (b"VS51", '\u{E0122}'),
+        (b"SINGLE-SHIFT-2", '\u{008E}'),
+        (b"VS207", '\u{E01BE}'),
+        (b"DEL", '\u{007F}'),
+        (b"START OF PROTECTED AREA", '\u{0096}'),
+        (b"LEFTWARDS TRIANGLE-HEADED ARROW WITH DOUBLE VERTICAL STROKE", '\u{2B7A}'),
+        (b"LOCKING-SHIFT ZERO", '\u{000F}'),
+        (b"VS226", '\u{E01D1}'),
+        (b"HTJ", '\u{0089}'),
+        (b"VS39", '\u{E0116}'),
+        (b"SET TRANSMIT STATE", '\u{0093}'),
+        (b"SGC", '\u{0099}'),
+        (b"VS187", '\u{E01AA}'),
+        (b"VS200", '\u{E01B7}'),
+        (b"VS109", '\u{E015C}'),
+        (b"EM", '\u{0019}'),
+        (b"SINGLE CHARACTER INTRODUCER", '\u{009A}'),
+        (b"ACK", '\u{0006}'),
+        (b"VS35", '\u{E0112}'),
+        (b"VS59", '\u{E012A}'),
+        (b"SO", '\u{000E}'),
+        (b"NEL", '\u{0085}'),
+        (b"PARTIAL LINE UP", '\u{008C}'),
+        (b"END OF MEDIUM", '\u{0019}'),
+        (b"VS255", '\u{E01EE}'),
+        (b"VS27", '\u{E010A}'),
+        (b"VS118", '\u{E0165}'),
+        (b"VS205", '\u{E01BC}'),
+        (b"VS129", '\u{E0170}'),
+        (b"VS70", '\u{E0135}'),
+        (b"CCH", '\u{0094}'),
+        (b"VS234", '\u{E01D9}'),
+        (b"SPA", '\u{0096}'),
+        (b"VS189", '\u{E01AC}'),
+        (b"SHIFT OUT", '\u{000E}'),
+        (b"START OF SELECTED AREA", '\u{0086}'),
+        (b"EOL", '\u{000A}'),
+        (b"PU1", '\u{0091}'),
+        (b"VS71", '\u{E0136}'),
+        (b"VS79", '\u{E013E}'),
+        (b"PRIVATE USE ONE", '\u{0091}'),
+        (b"VS11", '\u{FE0A}'),
+        (b"NBH", '\u{0083}'),
+        (b"VS92", '\u{E014B}'),
+        (b"VS127", '\u{E016E}'),
+        (b"VS212", '\u{E01C3}'),
+        (b"VS7", '\u{FE06}'),
+        (b"VS208", '\u{E01BF}'),
+        (b"VS93", '\u{E014C}'),
+        (b"NAK", '\u{0015}'),
+        (b"WEIERSTRASS ELLIPTIC FUNCTION", '\u{2118}'),
+        (b"VS181", '\u{E01A4}'),
+        (b"VS91", '\u{E014A}'),
+        (b"DEVICE CONTROL STRING", '\u{0090}'),
+        (b"VS38", '\u{E0115}'),
+        (b"VS167", '\u{E0196}'),
+        (b"LINE FEED", '\u{000A}'),
+        (b"VS40", '\u{E0117}'),
+        (b"VS44", '\u{E011B}'),
+        (b"SYNCHRONOUS IDLE", '\u{0016}'),
+        (b"US", '\u{001F}'),
+        (b"ESA", '\u{0087}'),
+        (b"VS126", '\u{E016D}'),
+        (b"VS81", '\u{E0140}'),
+        (b"VS246", '\u{E01E5}'),
+        (b"VS136", '\u{E0177}'),
+        (b"VS152", '\u{E0187}'),
+        (b"VS241", '\u{E01E0}'),
+        (b"VS14", '\u{FE0D}'),
+        (b"VS242", '\u{E01E1}'),
+        (b"LAO LETTER FO FAY", '\u{0E9F}'),
+        (b"VS233", '\u{E01D8}'),
+        (b"VS48", '\u{E011F}'),
+        (b"VS21", '\u{E0104}'),
+        (b"VS206", '\u{E01BD}'),
+        (b"VS195", '\u{E01B2}'),
+        (b"LOCKING-SHIFT ONE", '\u{000E}'),
+        (b"LRM", '\u{200E}'),
+        (b"END OF TRANSMISSION", '\u{0004}'),
+        (b"VS238", '\u{E01DD}'),
+        (b"VS87", '\u{E0146}'),
+        (b"VS95", '\u{E014E}'),
+        (b"VS147", '\u{E0182}'),
+        (b"VS10", '\u{FE09}'),
+        (b"VS82", '\u{E0141}'),
+        (b"FVS2", '\u{180C}'),
+        (b"VS202", '\u{E01B9}'),
+        (b"MICR ON US SYMBOL", '\u{2448}'),
+        (b"BAMUM LETTER PHASE-A MAEMGBIEE", '\u{1680B}'),
+        (b"VS149", '\u{E0184}'),
+        (b"VS1", '\u{FE00}'),
+        (b"VS18", '\u{E0101}'),
+        (b"VS135", '\u{E0176}'),
+        (b"VS52", '\u{E0123}'),
+        (b"VS33", '\u{E0110}'),
+        (b"END OF LINE", '\u{000A}'),
+        (b"VS256", '\u{E01EF}'),
+    ],
+}
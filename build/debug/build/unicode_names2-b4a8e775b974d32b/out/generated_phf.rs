@@ -0,0 +1,6237 @@
+// autogenerated by generator.rs
+pub static NAME2CODE_N: u64 = 16693546314645435226;
+pub static NAME2CODE_DISP: &'static [(u16, u16)] = &[
+    (34243, 10530), (34243, 19653), (34243, 21656), (34243, 31634), (34243, 10530),
+    (34243, 34011), (34243, 28978), (34243, 19456), (34243, 10530), (34243, 18143),
+    (34243, 1890), (34243, 10530), (34243, 10530), (34243, 37040), (34243, 37006),
+    (34243, 18436), (34243, 1890), (34243, 10530), (34243, 19653), (34243, 18186),
+    (34243, 19653), (34243, 10530), (34243, 21656), (34243, 37488), (34243, 10530),
+    (34243, 34011), (34243, 19456), (34243, 1890), (34243, 28978), (34243, 31104),
+    (34243, 10530), (34243, 27434), (34243, 34011), (34243, 1538), (34243, 10530),
+    (34243, 19456), (34243, 37488), (34243, 19456), (34243, 18186), (34243, 18436),
+    (34243, 10771), (34243, 10530), (34243, 34011), (34243, 28978), (34243, 10530),
+    (34243, 10530), (34243, 19653), (34243, 19653), (34243, 34011), (34243, 28978),
+    (34243, 10530), (34243, 28978), (34243, 1890), (34243, 1890), (34243, 182),
+    (34243, 32341), (34243, 10530), (34243, 1890), (34243, 21325), (34243, 34011),
+    (34243, 32341), (34243, 28860), (34243, 16792), (34243, 13502), (34243, 10530),
+    (34243, 29560), (34243, 38344), (34243, 34011), (34243, 9223), (34243, 10530),
+    (34243, 29560), (34243, 10530), (34243, 16792), (34243, 19653), (34243, 28978),
+    (34243, 29560), (34243, 10530), (34243, 10530), (34243, 7528), (34243, 18436),
+    (34243, 28978), (34243, 30845), (34243, 19653), (34243, 28978), (34243, 1890),
+    (34243, 28978), (34243, 13476), (34243, 37040), (34243, 19653), (34243, 10530),
+    (34243, 34011), (34243, 21922), (34243, 19456), (34243, 34011), (34243, 1912),
+    (34243, 19653), (34243, 19456), (34243, 1890), (34243, 37040), (34243, 34072),
+    (34243, 34011), (34243, 32341), (34243, 10530), (34243, 1890), (34243, 19456),
+    (34243, 10530), (34243, 28978), (34243, 10530), (34243, 10530), (34243, 16300),
+    (34243, 19456), (34243, 18143), (34243, 10020), (34243, 32385), (34243, 32385),
+    (34243, 29560), (34243, 34011), (34243, 10530), (34243, 1890), (34243, 1890),
+    (34243, 37040), (34243, 28978), (34243, 19653), (34243, 34011), (34243, 34011),
+    (34243, 19653), (34243, 28978), (34243, 1890), (34243, 37040), (34243, 28978),
+    (34243, 39544), (34243, 20247), (34243, 5947), (34243, 34011), (34243, 39538),
+    (34243, 10530), (34243, 19428), (34243, 18143), (34243, 19653), (34243, 19456),
+    (34243, 29560), (34243, 10530), (34243, 34011), (34243, 22364), (34243, 19653),
+    (34243, 1890), (34243, 1890), (34243, 29560), (34243, 19456), (34243, 10530),
+    (34243, 18186), (34243, 21325), (34243, 1538), (34243, 21656), (34243, 10530),
+    (34243, 1890), (34243, 13476), (34243, 10530), (34243, 28978), (34243, 10530),
+    (34243, 10530), (34243, 38333), (34243, 18436), (34243, 1890), (34243, 1890),
+    (34243, 20247), (34243, 10530), (34243, 10530), (34243, 28978), (34243, 1538),
+    (34243, 10530), (34243, 28573), (34243, 39538), (34243, 10530), (34243, 16792),
+    (34243, 12049), (34243, 28978), (34243, 20247), (34243, 19653), (34243, 19456),
+    (34243, 10530), (34243, 32341), (34243, 32341), (34243, 10530), (34243, 29560),
+    (34243, 30845), (34243, 10530), (34243, 10530), (34243, 13476), (34243, 1890),
+    (34243, 18436), (34243, 32385), (34243, 21656), (34243, 19653), (34243, 10530),
+    (34243, 34011), (34243, 15198), (34243, 25259), (34243, 27434), (34243, 27434),
+    (34243, 10530), (34243, 10530), (34243, 22364), (34243, 19428), (34243, 27434),
+    (34243, 10530), (34243, 32341), (34243, 1890), (34243, 19456), (34243, 10530),
+    (34243, 37040), (34243, 1890), (34243, 1890), (34243, 39544), (34243, 1890),
+    (34243, 10530), (34243, 38344), (34243, 6069), (34243, 19653), (34243, 5598),
+    (34243, 10530), (34243, 27434), (34243, 33967), (34243, 30845), (34243, 19653),
+    (34243, 34011), (34243, 1890), (34243, 34011), (34243, 19456), (34243, 10530),
+    (34243, 31634), (34243, 18436), (34243, 1890), (34243, 18143), (34243, 19428),
+    (34243, 5295), (34243, 1538), (34243, 39544), (34243, 29459), (34243, 29459),
+    (34243, 10530), (34243, 34011), (34243, 1890), (34243, 28978), (34243, 32385),
+    (34243, 22364), (34243, 30845), (34243, 5598), (34243, 32385), (34243, 32341),
+    (34243, 37040), (34243, 32341), (34243, 18186), (34243, 30845), (34243, 10530),
+    (34243, 29459), (34243, 37488), (34243, 18186), (34243, 3132), (34243, 5947),
+    (34243, 16792), (34243, 21656), (34243, 20899), (34243, 13476), (34243, 21922),
+    (34243, 29560), (34243, 34011), (34243, 10530), (34243, 1890), (34243, 10530),
+    (34243, 27434), (34243, 1890), (34243, 1538), (34243, 1890), (34243, 27434),
+    (34243, 16792), (34243, 19653), (34243, 10530), (34243, 10530), (34243, 1890),
+    (34243, 19653), (34243, 34011), (34243, 1890), (34243, 10530), (34243, 19456),
+    (34243, 15198), (34243, 37040), (34243, 1890), (34243, 18143), (34243, 10530),
+    (34243, 29459), (34243, 34011), (34243, 10530), (34243, 10530), (34243, 28978),
+    (34243, 16792), (34243, 28978), (34243, 19456), (34243, 19456), (34243, 13476),
+    (34243, 6073), (34243, 18186), (34243, 13476), (34243, 18436), (34243, 28978),
+    (34243, 34011), (34243, 1890), (34243, 32341), (34243, 19456), (34243, 10530),
+    (34243, 23418), (34243, 10530), (34243, 38344), (34243, 26873), (34243, 13476),
+    (34243, 29459), (34243, 34011), (34243, 13476), (34243, 38333), (34243, 13476),
+    (34243, 19653), (34243, 34011), (34243, 10530), (34243, 34011), (34243, 37040),
+    (34243, 1538), (34243, 18143), (34243, 34011), (34243, 32341), (34243, 19456),
+    (34243, 10530), (34243, 37488), (34243, 28978), (34243, 18436), (34243, 5836),
+    (34243, 1890), (34243, 32341), (34243, 13502), (34243, 10530), (34243, 36970),
+    (34243, 1890), (34243, 10530), (34243, 1890), (34243, 1890), (34243, 32255),
+    (34243, 13502), (34243, 22364), (34243, 18143), (34243, 16792), (34243, 1890),
+    (34243, 37040), (34243, 36970), (34243, 18186), (34243, 19456), (34243, 36970),
+    (34243, 10530), (34243, 182), (34243, 1890), (34243, 10530), (34243, 10530),
+    (34243, 13476), (34243, 19653), (34243, 28573), (34243, 10530), (34243, 10530),
+    (34243, 19456), (34243, 1890), (34243, 19456), (34243, 10530), (34243, 38333),
+    (34243, 1890), (34243, 27434), (34243, 13502), (34243, 1890), (34243, 29560),
+    (34243, 26873), (34243, 19653), (34243, 32341), (34243, 1890), (34243, 37488),
+    (34243, 19456), (34243, 18186), (34243, 10530), (34243, 37006), (34243, 15198),
+    (34243, 10530), (34243, 13476), (34243, 19456), (34243, 10530), (34243, 10530),
+    (34243, 32385), (34243, 19456), (34243, 19653), (34243, 10530), (34243, 10530),
+    (34243, 19456), (34243, 10530), (34243, 34011), (34243, 31104), (34243, 36970),
+    (34243, 10530), (34243, 1912), (34243, 28978), (34243, 13502), (34243, 18991),
+    (34243, 1890), (34243, 28978), (34243, 10530), (34243, 32385), (34243, 1890),
+    (34243, 1890), (34243, 34011), (34243, 21325), (34243, 34011), (34243, 19653),
+    (34243, 10530), (34243, 1890), (34243, 10530), (34243, 22364), (34243, 1890),
+    (34243, 38344), (34243, 9932), (34243, 12049), (34243, 20247), (34243, 27434),
+    (34243, 38878), (34243, 32385), (34243, 5598), (34243, 13502), (34243, 1538),
+    (34243, 19456), (34243, 13476), (34243, 34011), (34243, 36970), (34243, 28978),
+    (34243, 39828), (34243, 28978), (34243, 10530), (34243, 24232), (34243, 10530),
+    (34243, 28978), (34243, 10530), (34243, 20247), (34243, 18186), (34243, 28514),
+    (34243, 10530), (34243, 10265), (34243, 1890), (34243, 1890), (34243, 16792),
+    (34243, 37040), (34243, 1890), (34243, 37488), (34243, 18991), (34243, 10530),
+    (34243, 1890), (34243, 1538), (34243, 5598), (34243, 18186), (34243, 21656),
+    (34243, 18143), (34243, 34011), (34243, 10530), (34243, 19456), (34243, 19456),
+    (34243, 29459), (34243, 19653), (34243, 21656), (34243, 13476), (34243, 16792),
+    (34243, 28978), (34243, 1890), (34243, 21656), (34243, 1890), (34243, 28978),
+    (34243, 19653), (34243, 30845), (34243, 34011), (34243, 10530), (34243, 16792),
+    (34243, 27035), (34243, 37040), (34243, 10530), (34243, 10530), (34243, 37040),
+    (34243, 20247), (34243, 13476), (34243, 10530), (34243, 20247), (34243, 28860),
+    (34243, 26863), (34243, 32385), (34243, 20247), (34243, 28978), (34243, 21325),
+    (34243, 10530), (34243, 16792), (34243, 10530), (34243, 34011), (34243, 1890),
+    (34243, 19653), (34243, 27434), (34243, 18436), (34243, 34011), (34243, 13502),
+    (34243, 10530), (34243, 13502), (34243, 19456), (34243, 24215), (34243, 10530),
+    (34243, 34011), (34243, 10530), (34243, 19653), (34243, 1890), (34243, 24099),
+    (34243, 1890), (34243, 1890), (34243, 1890), (34243, 19456), (34243, 30845),
+    (34243, 1890), (34243, 28978), (34243, 36970), (34243, 5212), (34243, 30845),
+    (34243, 23710), (34243, 1538), (34243, 13476), (34243, 20247), (34243, 19428),
+    (34243, 28860), (34243, 10530), (34243, 10530), (34243, 16792), (34243, 10530),
+    (34243, 28573), (34243, 28978), (34243, 10530), (34243, 32255), (34243, 26840),
+    (34243, 10530), (34243, 1890), (34243, 28978), (34243, 28978), (34243, 19456),
+    (34243, 10530), (34243, 28978), (34243, 28978), (34243, 18436), (34243, 38333),
+    (34243, 37040), (34243, 30845), (34243, 18143), (34243, 34011), (34243, 19653),
+    (34243, 10530), (34243, 37488), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 1890), (34243, 10530), (34243, 20899), (34243, 2700), (34243, 1890),
+    (34243, 19456), (34243, 29459), (34243, 34011), (34243, 1890), (34243, 38344),
+    (34243, 30845), (34243, 1890), (34243, 20247), (34243, 19653), (34243, 26840),
+    (34243, 36970), (34243, 1890), (34243, 29459), (34243, 30845), (34243, 10530),
+    (34243, 10530), (34243, 32341), (34243, 10530), (34243, 32341), (34243, 21656),
+    (34243, 1890), (34243, 5598), (34243, 13476), (34243, 10530), (34243, 32341),
+    (34243, 10530), (34243, 32385), (34243, 19456), (34243, 19653), (34243, 28978),
+    (34243, 19653), (34243, 13476), (34243, 12049), (34243, 1890), (34243, 33967),
+    (34243, 1538), (34243, 28978), (34243, 10530), (34243, 10530), (34243, 19653),
+    (34243, 19456), (34243, 28860), (34243, 22364), (34243, 10530), (34243, 18186),
+    (34243, 21656), (34243, 10530), (34243, 28978), (34243, 29560), (34243, 1890),
+    (34243, 23710), (34243, 18436), (34243, 19653), (34243, 16137), (34243, 10530),
+    (34243, 19428), (34243, 10530), (34243, 29560), (34243, 37040), (34243, 10530),
+    (34243, 19456), (34243, 27434), (34243, 18143), (34243, 10530), (34243, 21922),
+    (34243, 13502), (34243, 13476), (34243, 28860), (34243, 10530), (34243, 32341),
+    (34243, 19653), (34243, 10530), (34243, 28978), (34243, 1890), (34243, 32385),
+    (34243, 13476), (34243, 10530), (34243, 34011), (34243, 10530), (34243, 10530),
+    (34243, 19653), (34243, 17741), (34243, 10530), (34243, 34011), (34243, 1890),
+    (34243, 30845), (34243, 19456), (34243, 13476), (34243, 13476), (34243, 10530),
+    (34243, 19456), (34243, 37040), (34243, 19456), (34243, 32341), (34243, 10530),
+    (34243, 37006), (34243, 19456), (34243, 10530), (34243, 1890), (34243, 30845),
+    (34243, 32341), (34243, 21325), (34243, 32385), (34243, 28978), (34243, 37040),
+    (34243, 26840), (34243, 26840), (34243, 36970), (34243, 10530), (34243, 28978),
+    (34243, 20247), (34243, 19653), (34243, 32385), (34243, 31634), (34243, 10530),
+    (34243, 28978), (34243, 28978), (34243, 10530), (34243, 10530), (34243, 28978),
+    (34243, 26873), (34243, 34011), (34243, 10530), (34243, 28978), (34243, 19456),
+    (34243, 29459), (34243, 34011), (34243, 10530), (34243, 32385), (34243, 19653),
+    (34243, 34011), (34243, 13476), (34243, 18436), (34243, 10530), (34243, 19653),
+    (34243, 15198), (34243, 10530), (34243, 10530), (34243, 29459), (34243, 36970),
+    (34243, 32385), (34243, 10530), (34243, 19456), (34243, 10530), (34243, 37040),
+    (34243, 34011), (34243, 18186), (34243, 32341), (34243, 29459), (34243, 18186),
+    (34243, 19653), (34243, 10530), (34243, 5295), (34243, 28978), (34243, 10530),
+    (34243, 1890), (34243, 29560), (34243, 28978), (34243, 21656), (34243, 19428),
+    (34243, 34011), (34243, 28978), (34243, 28978), (34243, 10530), (34243, 10530),
+    (34243, 18143), (34243, 39544), (34243, 10771), (34243, 1890), (34243, 37040),
+    (34243, 18186), (34243, 2700), (34243, 36970), (34243, 27434), (34243, 19456),
+    (34243, 30845), (34243, 1890), (34243, 36970), (34243, 10530), (34243, 26840),
+    (34243, 28978), (34243, 19456), (34243, 19653), (34243, 32341), (34243, 10530),
+    (34243, 1538), (34243, 1890), (34243, 1890), (34243, 28860), (34243, 18186),
+    (34243, 25259), (34243, 10530), (34243, 10530), (34243, 32385), (34243, 32341),
+    (34243, 10530), (34243, 5836), (34243, 1890), (34243, 19456), (34243, 19653),
+    (34243, 37006), (34243, 34011), (34243, 1538), (34243, 29560), (34243, 1538),
+    (34243, 10530), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 21656),
+    (34243, 21325), (34243, 18186), (34243, 1890), (34243, 10530), (34243, 30845),
+    (34243, 36603), (34243, 19456), (34243, 10530), (34243, 37040), (34243, 1890),
+    (34243, 18102), (34243, 34011), (34243, 35192), (34243, 18143), (34243, 19456),
+    (34243, 1890), (34243, 27434), (34243, 1890), (34243, 21656), (34243, 10530),
+    (34243, 30845), (34243, 28978), (34243, 36603), (34243, 32385), (34243, 28978),
+    (34243, 18186), (34243, 10530), (34243, 10530), (34243, 29560), (34243, 10530),
+    (34243, 19653), (34243, 28573), (34243, 19456), (34243, 10530), (34243, 18186),
+    (34243, 16792), (34243, 35284), (34243, 18143), (34243, 19456), (34243, 16792),
+    (34243, 10530), (34243, 1890), (34243, 10530), (34243, 32385), (34243, 36970),
+    (34243, 19653), (34243, 1890), (34243, 28978), (34243, 1890), (34243, 39538),
+    (34243, 21922), (34243, 34011), (34243, 8111), (34243, 27434), (34243, 1890),
+    (34243, 37040), (34243, 13502), (34243, 21656), (34243, 1538), (34243, 21922),
+    (34243, 10530), (34243, 13476), (34243, 19456), (34243, 29560), (34243, 30845),
+    (34243, 21922), (34243, 10530), (34243, 10530), (34243, 19653), (34243, 13476),
+    (34243, 19456), (34243, 32385), (34243, 18436), (34243, 28978), (34243, 10530),
+    (34243, 21922), (34243, 5598), (34243, 28978), (34243, 28860), (34243, 32341),
+    (34243, 13476), (34243, 1890), (34243, 10530), (34243, 16792), (34243, 28573),
+    (34243, 28978), (34243, 32385), (34243, 1890), (34243, 19456), (34243, 34011),
+    (34243, 28860), (34243, 28978), (34243, 28573), (34243, 34011), (34243, 19456),
+    (34243, 32385), (34243, 33967), (34243, 29560), (34243, 10530), (34243, 28514),
+    (34243, 10530), (34243, 21656), (34243, 10530), (34243, 34011), (34243, 37040),
+    (34243, 32385), (34243, 1890), (34243, 34011), (34243, 19456), (34243, 19456),
+    (34243, 2700), (34243, 19456), (34243, 1890), (34243, 32341), (34243, 19653),
+    (34243, 10530), (34243, 38344), (34243, 20247), (34243, 10530), (34243, 37040),
+    (34243, 27434), (34243, 34011), (34243, 10530), (34243, 10530), (34243, 34011),
+    (34243, 37006), (34243, 20247), (34243, 32385), (34243, 32341), (34243, 18186),
+    (34243, 18436), (34243, 33967), (34243, 21656), (34243, 36970), (34243, 19653),
+    (34243, 34011), (34243, 19653), (34243, 32385), (34243, 182), (34243, 10530),
+    (34243, 1890), (34243, 28573), (34243, 28978), (34243, 10530), (34243, 28978),
+    (34243, 34011), (34243, 18186), (34243, 37488), (34243, 19653), (34243, 34011),
+    (34243, 30845), (34243, 34011), (34243, 10530), (34243, 19653), (34243, 37040),
+    (34243, 28978), (34243, 18102), (34243, 36603), (34243, 1890), (34243, 18436),
+    (34243, 18143), (34243, 37040), (34243, 19428), (34243, 39828), (34243, 19456),
+    (34243, 16792), (34243, 30845), (34243, 29560), (34243, 18436), (34243, 32341),
+    (34243, 38344), (34243, 1890), (34243, 39544), (34243, 19456), (34243, 28978),
+    (34243, 38344), (34243, 19653), (34243, 13476), (34243, 19653), (34243, 21325),
+    (34243, 10530), (34243, 10530), (34243, 19456), (34243, 10530), (34243, 16792),
+    (34243, 28978), (34243, 32385), (34243, 27434), (34243, 19456), (34243, 19653),
+    (34243, 10530), (34243, 28978), (34243, 10530), (34243, 1538), (34243, 19653),
+    (34243, 37040), (34243, 10530), (34243, 28978), (34243, 19653), (34243, 10771),
+    (34243, 10530), (34243, 28860), (34243, 38333), (34243, 5598), (34243, 19653),
+    (34243, 28978), (34243, 10530), (34243, 28978), (34243, 19653), (34243, 28978),
+    (34243, 34011), (34243, 29560), (34243, 5836), (34243, 29560), (34243, 5598),
+    (34243, 10530), (34243, 22364), (34243, 1890), (34243, 18186), (34243, 37040),
+    (34243, 37040), (34243, 38344), (34243, 1890), (34243, 5598), (34243, 10530),
+    (34243, 15198), (34243, 34011), (34243, 10530), (34243, 1890), (34243, 37488),
+    (34243, 10530), (34243, 21656), (34243, 20899), (34243, 19428), (34243, 28978),
+    (34243, 31433), (34243, 28978), (34243, 28978), (34243, 10530), (34243, 10530),
+    (34243, 36970), (34243, 5598), (34243, 10530), (34243, 29560), (34243, 28978),
+    (34243, 21656), (34243, 10530), (34243, 10530), (34243, 1890), (34243, 39538),
+    (34243, 37040), (34243, 10530), (34243, 20247), (34243, 10530), (34243, 36970),
+    (34243, 28978), (34243, 17543), (34243, 29560), (34243, 19456), (34243, 16792),
+    (34243, 18186), (34243, 32385), (34243, 10530), (34243, 15198), (34243, 20359),
+    (34243, 10530), (34243, 34011), (34243, 10530), (34243, 37040), (34243, 29560),
+    (34243, 28978), (34243, 10530), (34243, 1890), (34243, 27434), (34243, 1890),
+    (34243, 1538), (34243, 10530), (34243, 18143), (34243, 26840), (34243, 28978),
+    (34243, 38344), (34243, 10530), (34243, 36970), (34243, 32341), (34243, 32341),
+    (34243, 2700), (34243, 20247), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 28860), (34243, 21325), (34243, 38333), (34243, 37040), (34243, 10530),
+    (34243, 1890), (34243, 20247), (34243, 1890), (34243, 10530), (34243, 28978),
+    (34243, 1890), (34243, 39828), (34243, 10530), (34243, 29560), (34243, 39544),
+    (34243, 39538), (34243, 10530), (34243, 27434), (34243, 38344), (34243, 8111),
+    (34243, 10530), (34243, 28978), (34243, 6073), (34243, 28978), (34243, 1890),
+    (34243, 10530), (34243, 1538), (34243, 1538), (34243, 36970), (34243, 19653),
+    (34243, 27434), (34243, 10530), (34243, 18186), (34243, 34011), (34243, 10530),
+    (34243, 1538), (34243, 32385), (34243, 37488), (34243, 10530), (34243, 10530),
+    (34243, 37488), (34243, 32341), (34243, 1538), (34243, 38344), (34243, 19653),
+    (34243, 19456), (34243, 19456), (34243, 19456), (34243, 10530), (34243, 1814),
+    (34243, 1890), (34243, 10530), (34243, 36970), (34243, 1538), (34243, 26840),
+    (34243, 36970), (34243, 34011), (34243, 16792), (34243, 10530), (34243, 10530),
+    (34243, 32341), (34243, 1890), (34243, 16137), (34243, 1890), (34243, 21325),
+    (34243, 21656), (34243, 19456), (34243, 29560), (34243, 18991), (34243, 18436),
+    (34243, 34011), (34243, 18991), (34243, 1538), (34243, 1538), (34243, 10530),
+    (34243, 13502), (34243, 30845), (34243, 27434), (34243, 10530), (34243, 1890),
+    (34243, 19428), (34243, 10530), (34243, 5598), (34243, 10530), (34243, 22364),
+    (34243, 32385), (34243, 28860), (34243, 5947), (34243, 28573), (34243, 13476),
+    (34243, 34011), (34243, 24215), (34243, 28978), (34243, 35192), (34243, 28514),
+    (34243, 19456), (34243, 1890), (34243, 19653), (34243, 1890), (34243, 27035),
+    (34243, 16792), (34243, 1538), (34243, 19653), (34243, 19456), (34243, 10530),
+    (34243, 28978), (34243, 1890), (34243, 35792), (34243, 5598), (34243, 32341),
+    (34243, 37006), (34243, 1890), (34243, 13502), (34243, 10530), (34243, 30845),
+    (34243, 19456), (34243, 19456), (34243, 30845), (34243, 18186), (34243, 39538),
+    (34243, 2700), (34243, 28978), (34243, 18186), (34243, 10530), (34243, 10530),
+    (34243, 1890), (34243, 19456), (34243, 16792), (34243, 10530), (34243, 37488),
+    (34243, 21656), (34243, 18186), (34243, 38333), (34243, 32341), (34243, 19456),
+    (34243, 1890), (34243, 10530), (34243, 23950), (34243, 28860), (34243, 28978),
+    (34243, 182), (34243, 10530), (34243, 28978), (34243, 16792), (34243, 29560),
+    (34243, 34011), (34243, 1890), (34243, 32385), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 28978), (34243, 19653), (34243, 1890), (34243, 1890),
+    (34243, 18143), (34243, 34011), (34243, 1538), (34243, 21656), (34243, 13476),
+    (34243, 19456), (34243, 19456), (34243, 1890), (34243, 10530), (34243, 13502),
+    (34243, 28978), (34243, 10530), (34243, 27434), (34243, 34011), (34243, 8394),
+    (34243, 36970), (34243, 29560), (34243, 1890), (34243, 5947), (34243, 38344),
+    (34243, 10530), (34243, 10530), (34243, 19456), (34243, 1890), (34243, 10530),
+    (34243, 10530), (34243, 19456), (34243, 18143), (34243, 18186), (34243, 32341),
+    (34243, 10530), (34243, 10530), (34243, 10530), (34243, 1890), (34243, 27434),
+    (34243, 29459), (34243, 1814), (34243, 18436), (34243, 1890), (34243, 19456),
+    (34243, 29560), (34243, 10771), (34243, 30845), (34243, 19428), (34243, 13476),
+    (34243, 10530), (34243, 37488), (34243, 38344), (34243, 19456), (34243, 10530),
+    (34243, 27434), (34243, 19653), (34243, 30845), (34243, 28978), (34243, 18186),
+    (34243, 13476), (34243, 32385), (34243, 10530), (34243, 19456), (34243, 19456),
+    (34243, 1890), (34243, 28978), (34243, 9932), (34243, 10530), (34243, 26873),
+    (34243, 25259), (34243, 1890), (34243, 10530), (34243, 1890), (34243, 1890),
+    (34243, 10530), (34243, 32341), (34243, 28860), (34243, 10530), (34243, 21325),
+    (34243, 28978), (34243, 20247), (34243, 28978), (34243, 10530), (34243, 19653),
+    (34243, 28978), (34243, 19456), (34243, 1890), (34243, 13476), (34243, 26840),
+    (34243, 39538), (34243, 32255), (34243, 10530), (34243, 1538), (34243, 13476),
+    (34243, 21656), (34243, 1538), (34243, 26840), (34243, 21325), (34243, 32341),
+    (34243, 34072), (34243, 38344), (34243, 1890), (34243, 8111), (34243, 1890),
+    (34243, 35284), (34243, 10530), (34243, 10530), (34243, 19456), (34243, 1890),
+    (34243, 10530), (34243, 27434), (34243, 38344), (34243, 33967), (34243, 28978),
+    (34243, 11703), (34243, 7587), (34243, 37040), (34243, 13476), (34243, 1814),
+    (34243, 25259), (34243, 10530), (34243, 10530), (34243, 36970), (34243, 29459),
+    (34243, 10530), (34243, 24215), (34243, 19456), (34243, 10530), (34243, 32255),
+    (34243, 1538), (34243, 10530), (34243, 39544), (34243, 7461), (34243, 21325),
+    (34243, 19456), (34243, 30845), (34243, 28573), (34243, 7528), (34243, 10530),
+    (34243, 10530), (34243, 13476), (34243, 10530), (34243, 28978), (34243, 10530),
+    (34243, 10530), (34243, 12049), (34243, 1890), (34243, 1890), (34243, 34011),
+    (34243, 34011), (34243, 30845), (34243, 10530), (34243, 19456), (34243, 39538),
+    (34243, 29560), (34243, 18143), (34243, 19456), (34243, 30152), (34243, 19456),
+    (34243, 19653), (34243, 18143), (34243, 1538), (34243, 10530), (34243, 13476),
+    (34243, 16792), (34243, 37040), (34243, 1890), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 29560), (34243, 10771), (34243, 1538), (34243, 10530),
+    (34243, 19456), (34243, 19456), (34243, 10530), (34243, 19456), (34243, 13476),
+    (34243, 13476), (34243, 10530), (34243, 27434), (34243, 5598), (34243, 10530),
+    (34243, 26840), (34243, 1890), (34243, 37040), (34243, 13476), (34243, 10530),
+    (34243, 18186), (34243, 10530), (34243, 18436), (34243, 1538), (34243, 13476),
+    (34243, 5836), (34243, 19653), (34243, 19456), (34243, 18143), (34243, 19456),
+    (34243, 24215), (34243, 32341), (34243, 10530), (34243, 18186), (34243, 32341),
+    (34243, 27434), (34243, 1538), (34243, 16137), (34243, 1890), (34243, 32341),
+    (34243, 18186), (34243, 25259), (34243, 19653), (34243, 28978), (34243, 10530),
+    (34243, 1890), (34243, 21325), (34243, 30845), (34243, 10530), (34243, 28978),
+    (34243, 10530), (34243, 37040), (34243, 1675), (34243, 28978), (34243, 1538),
+    (34243, 13476), (34243, 26873), (34243, 34011), (34243, 1538), (34243, 34011),
+    (34243, 28978), (34243, 1890), (34243, 21325), (34243, 5152), (34243, 18143),
+    (34243, 19456), (34243, 13476), (34243, 19653), (34243, 28978), (34243, 27434),
+    (34243, 32385), (34243, 1538), (34243, 19456), (34243, 10530), (34243, 16300),
+    (34243, 10530), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 29560),
+    (34243, 10530), (34243, 15198), (34243, 1890), (34243, 10530), (34243, 37040),
+    (34243, 37040), (34243, 10530), (34243, 29560), (34243, 16137), (34243, 38333),
+    (34243, 10530), (34243, 32255), (34243, 37040), (34243, 20247), (34243, 19456),
+    (34243, 34011), (34243, 10530), (34243, 1890), (34243, 10530), (34243, 10530),
+    (34243, 26873), (34243, 37006), (34243, 18186), (34243, 29560), (34243, 24232),
+    (34243, 15198), (34243, 10530), (34243, 1890), (34243, 21669), (34243, 16792),
+    (34243, 37040), (34243, 13476), (34243, 33967), (34243, 1890), (34243, 10020),
+    (34243, 28978), (34243, 21325), (34243, 36970), (34243, 22364), (34243, 1538),
+    (34243, 38344), (34243, 10530), (34243, 19456), (34243, 28978), (34243, 1890),
+    (34243, 10530), (34243, 21922), (34243, 10530), (34243, 27035), (34243, 34011),
+    (34243, 10530), (34243, 32385), (34243, 26863), (34243, 10530), (34243, 18436),
+    (34243, 10530), (34243, 37488), (34243, 28978), (34243, 21656), (34243, 29560),
+    (34243, 38344), (34243, 10530), (34243, 10530), (34243, 1890), (34243, 10530),
+    (34243, 24215), (34243, 34011), (34243, 1890), (34243, 28978), (34243, 19653),
+    (34243, 28978), (34243, 1538), (34243, 21656), (34243, 10530), (34243, 10530),
+    (34243, 19653), (34243, 21325), (34243, 28860), (34243, 10530), (34243, 7587),
+    (34243, 10530), (34243, 10530), (34243, 1890), (34243, 18436), (34243, 18186),
+    (34243, 33967), (34243, 10530), (34243, 19653), (34243, 10265), (34243, 19456),
+    (34243, 5947), (34243, 13476), (34243, 1890), (34243, 19456), (34243, 1890),
+    (34243, 1890), (34243, 19456), (34243, 10530), (34243, 10530), (34243, 9932),
+    (34243, 29459), (34243, 10530), (34243, 38333), (34243, 10530), (34243, 10530),
+    (34243, 27434), (34243, 10530), (34243, 18186), (34243, 37006), (34243, 31634),
+    (34243, 13476), (34243, 32341), (34243, 28978), (34243, 32341), (34243, 31634),
+    (34243, 19456), (34243, 37040), (34243, 18143), (34243, 15505), (34243, 10530),
+    (34243, 10530), (34243, 5295), (34243, 21325), (34243, 1890), (34243, 28860),
+    (34243, 10530), (34243, 37040), (34243, 1890), (34243, 18186), (34243, 13502),
+    (34243, 32385), (34243, 34011), (34243, 32535), (34243, 13476), (34243, 5522),
+    (34243, 34011), (34243, 10530), (34243, 34011), (34243, 13476), (34243, 10530),
+    (34243, 10530), (34243, 18186), (34243, 18186), (34243, 38344), (34243, 21656),
+    (34243, 1890), (34243, 1890), (34243, 37040), (34243, 1890), (34243, 17741),
+    (34243, 38878), (34243, 19456), (34243, 37040), (34243, 32255), (34243, 5947),
+    (34243, 10530), (34243, 10530), (34243, 10530), (34243, 34011), (34243, 28978),
+    (34243, 18436), (34243, 32385), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 19653), (34243, 10265), (34243, 10530), (34243, 32341), (34243, 1890),
+    (34243, 32385), (34243, 13476), (34243, 10530), (34243, 10530), (34243, 13476),
+    (34243, 32385), (34243, 10530), (34243, 1890), (34243, 10530), (34243, 13476),
+    (34243, 10530), (34243, 10530), (34243, 32341), (34243, 19456), (34243, 35844),
+    (34243, 19456), (34243, 1890), (34243, 10530), (34243, 21656), (34243, 13502),
+    (34243, 12049), (34243, 28860), (34243, 15198), (34243, 10530), (34243, 19456),
+    (34243, 18143), (34243, 5598), (34243, 19456), (34243, 28978), (34243, 37488),
+    (34243, 37006), (34243, 39544), (34243, 10530), (34243, 13476), (34243, 27434),
+    (34243, 13502), (34243, 28978), (34243, 28978), (34243, 38333), (34243, 33967),
+    (34243, 19456), (34243, 21325), (34243, 34549), (34243, 10530), (34243, 5947),
+    (34243, 28978), (34243, 21325), (34243, 39544), (34243, 37040), (34243, 28978),
+    (34243, 1890), (34243, 5598), (34243, 18186), (34243, 37488), (34243, 19653),
+    (34243, 10530), (34243, 13502), (34243, 5598), (34243, 36970), (34243, 28978),
+    (34243, 19653), (34243, 1890), (34243, 30845), (34243, 1538), (34243, 36970),
+    (34243, 10530), (34243, 18143), (34243, 8394), (34243, 10530), (34243, 21656),
+    (34243, 2700), (34243, 21656), (34243, 19456), (34243, 32341), (34243, 10020),
+    (34243, 10530), (34243, 21325), (34243, 13476), (34243, 34011), (34243, 18436),
+    (34243, 28978), (34243, 1538), (34243, 1890), (34243, 1890), (34243, 10530),
+    (34243, 19653), (34243, 18436), (34243, 16137), (34243, 32341), (34243, 28860),
+    (34243, 39538), (34243, 34011), (34243, 28860), (34243, 10530), (34243, 18436),
+    (34243, 1538), (34243, 13476), (34243, 20247), (34243, 19456), (34243, 13476),
+    (34243, 1890), (34243, 10530), (34243, 18186), (34243, 32385), (34243, 35792),
+    (34243, 24232), (34243, 16792), (34243, 36970), (34243, 20247), (34243, 24215),
+    (34243, 28978), (34243, 20247), (34243, 5598), (34243, 24232), (34243, 37040),
+    (34243, 13476), (34243, 37040), (34243, 34011), (34243, 39538), (34243, 34011),
+    (34243, 32385), (34243, 20247), (34243, 18186), (34243, 29459), (34243, 16137),
+    (34243, 32385), (34243, 19653), (34243, 28978), (34243, 13476), (34243, 37040),
+    (34243, 19653), (34243, 19653), (34243, 33967), (34243, 10530), (34243, 5836),
+    (34243, 21656), (34243, 34011), (34243, 27434), (34243, 10530), (34243, 1538),
+    (34243, 34011), (34243, 10530), (34243, 13476), (34243, 19456), (34243, 1538),
+    (34243, 37488), (34243, 10530), (34243, 10530), (34243, 28978), (34243, 39544),
+    (34243, 7587), (34243, 12049), (34243, 20014), (34243, 38333), (34243, 5598),
+    (34243, 1538), (34243, 18436), (34243, 1890), (34243, 13476), (34243, 10530),
+    (34243, 10530), (34243, 10530), (34243, 19456), (34243, 13476), (34243, 10530),
+    (34243, 19653), (34243, 30845), (34243, 10530), (34243, 20247), (34243, 32385),
+    (34243, 10530), (34243, 36603), (34243, 28514), (34243, 1890), (34243, 39828),
+    (34243, 22364), (34243, 33967), (34243, 34011), (34243, 10530), (34243, 10530),
+    (34243, 13502), (34243, 36970), (34243, 21922), (34243, 33967), (34243, 19653),
+    (34243, 18186), (34243, 13476), (34243, 19456), (34243, 36970), (34243, 31634),
+    (34243, 1890), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 38333),
+    (34243, 21656), (34243, 32385), (34243, 32255), (34243, 28573), (34243, 21325),
+    (34243, 1890), (34243, 16137), (34243, 29560), (34243, 10530), (34243, 37040),
+    (34243, 13502), (34243, 32341), (34243, 13502), (34243, 21325), (34243, 29560),
+    (34243, 19456), (34243, 10530), (34243, 10530), (34243, 1890), (34243, 1890),
+    (34243, 37006), (34243, 34011), (34243, 19456), (34243, 10530), (34243, 28978),
+    (34243, 12049), (34243, 13502), (34243, 21922), (34243, 28978), (34243, 12049),
+    (34243, 39828), (34243, 1890), (34243, 32385), (34243, 19456), (34243, 19653),
+    (34243, 37040), (34243, 18436), (34243, 10530), (34243, 18186), (34243, 34011),
+    (34243, 19456), (34243, 19653), (34243, 21922), (34243, 27434), (34243, 13476),
+    (34243, 19653), (34243, 13476), (34243, 19653), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 21656), (34243, 28978), (34243, 37040), (34243, 10530),
+    (34243, 28860), (34243, 21656), (34243, 13476), (34243, 32341), (34243, 19653),
+    (34243, 32385), (34243, 31433), (34243, 26863), (34243, 39828), (34243, 16792),
+    (34243, 1538), (34243, 29560), (34243, 18143), (34243, 5947), (34243, 19456),
+    (34243, 10530), (34243, 28573), (34243, 10530), (34243, 15198), (34243, 21656),
+    (34243, 32341), (34243, 13502), (34243, 34011), (34243, 19456), (34243, 1890),
+    (34243, 19456), (34243, 34011), (34243, 10530), (34243, 5598), (34243, 29560),
+    (34243, 28978), (34243, 15405), (34243, 19456), (34243, 38344), (34243, 18436),
+    (34243, 27434), (34243, 1538), (34243, 7528), (34243, 32385), (34243, 19456),
+    (34243, 1890), (34243, 10530), (34243, 34011), (34243, 10530), (34243, 1890),
+    (34243, 1890), (34243, 10530), (34243, 37040), (34243, 34011), (34243, 26863),
+    (34243, 19456), (34243, 19653), (34243, 10530), (34243, 10530), (34243, 36603),
+    (34243, 10530), (34243, 10530), (34243, 1890), (34243, 28978), (34243, 36551),
+    (34243, 32385), (34243, 10530), (34243, 1538), (34243, 37040), (34243, 30845),
+    (34243, 20247), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 28978), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 13502), (34243, 10530), (34243, 29459), (34243, 15171), (34243, 10530),
+    (34243, 18143), (34243, 1890), (34243, 25259), (34243, 29459), (34243, 36603),
+    (34243, 26840), (34243, 31634), (34243, 29560), (34243, 1890), (34243, 36970),
+    (34243, 19456), (34243, 19456), (34243, 27434), (34243, 39538), (34243, 19456),
+    (34243, 5348), (34243, 28860), (34243, 1538), (34243, 13502), (34243, 24099),
+    (34243, 19456), (34243, 34011), (34243, 10530), (34243, 34011), (34243, 38344),
+    (34243, 1890), (34243, 30845), (34243, 33967), (34243, 1890), (34243, 32341),
+    (34243, 27434), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 27434), (34243, 1538), (34243, 16792), (34243, 10530),
+    (34243, 13476), (34243, 27434), (34243, 29459), (34243, 32341), (34243, 10530),
+    (34243, 32341), (34243, 1890), (34243, 32385), (34243, 19456), (34243, 5836),
+    (34243, 19456), (34243, 18436), (34243, 23710), (34243, 1538), (34243, 19653),
+    (34243, 18143), (34243, 27434), (34243, 38344), (34243, 32385), (34243, 18436),
+    (34243, 21656), (34243, 1890), (34243, 10530), (34243, 28978), (34243, 34894),
+    (34243, 18186), (34243, 18186), (34243, 38344), (34243, 10530), (34243, 34011),
+    (34243, 10530), (34243, 19456), (34243, 19456), (34243, 32385), (34243, 10530),
+    (34243, 36970), (34243, 182), (34243, 10530), (34243, 24215), (34243, 10530),
+    (34243, 10530), (34243, 10530), (34243, 20247), (34243, 19653), (34243, 4867),
+    (34243, 1890), (34243, 10530), (34243, 15198), (34243, 28573), (34243, 25259),
+    (34243, 1890), (34243, 19456), (34243, 10530), (34243, 28978), (34243, 18186),
+    (34243, 30845), (34243, 37488), (34243, 34011), (34243, 10265), (34243, 21922),
+    (34243, 10530), (34243, 10530), (34243, 32385), (34243, 19653), (34243, 6069),
+    (34243, 1538), (34243, 28978), (34243, 21656), (34243, 37040), (34243, 1890),
+    (34243, 19456), (34243, 1538), (34243, 10530), (34243, 30845), (34243, 21656),
+    (34243, 1890), (34243, 1890), (34243, 1890), (34243, 10530), (34243, 37488),
+    (34243, 28978), (34243, 10618), (34243, 1890), (34243, 32385), (34243, 28573),
+    (34243, 19456), (34243, 10771), (34243, 20247), (34243, 26873), (34243, 35192),
+    (34243, 21325), (34243, 32385), (34243, 39544), (34243, 12049), (34243, 32341),
+    (34243, 10530), (34243, 26863), (34243, 32341), (34243, 28978), (34243, 40),
+    (34243, 27434), (34243, 28978), (34243, 28978), (34243, 34011), (34243, 13476),
+    (34243, 10530), (34243, 1890), (34243, 1890), (34243, 39544), (34243, 10530),
+    (34243, 32341), (34243, 30845), (34243, 26863), (34243, 5947), (34243, 32385),
+    (34243, 1890), (34243, 8111), (34243, 22364), (34243, 18186), (34243, 19653),
+    (34243, 38344), (34243, 18436), (34243, 10530), (34243, 25259), (34243, 18436),
+    (34243, 1890), (34243, 1538), (34243, 27434), (34243, 10530), (34243, 19456),
+    (34243, 5598), (34243, 21656), (34243, 10530), (34243, 16137), (34243, 29459),
+    (34243, 26873), (34243, 10530), (34243, 1890), (34243, 19653), (34243, 1890),
+    (34243, 1890), (34243, 10530), (34243, 19456), (34243, 33967), (34243, 19653),
+    (34243, 10530), (34243, 37040), (34243, 34011), (34243, 18551), (34243, 27434),
+    (34243, 32385), (34243, 21325), (34243, 27434), (34243, 36970), (34243, 19456),
+    (34243, 19653), (34243, 19456), (34243, 10530), (34243, 18143), (34243, 19456),
+    (34243, 16792), (34243, 5598), (34243, 18991), (34243, 13476), (34243, 28978),
+    (34243, 28978), (34243, 16137), (34243, 35792), (34243, 10530), (34243, 10530),
+    (34243, 37040), (34243, 1538), (34243, 10530), (34243, 10530), (34243, 1538),
+    (34243, 10530), (34243, 28978), (34243, 33967), (34243, 29459), (34243, 1890),
+    (34243, 32385), (34243, 21325), (34243, 10530), (34243, 13502), (34243, 30845),
+    (34243, 10530), (34243, 10530), (34243, 37040), (34243, 19653), (34243, 1538),
+    (34243, 10530), (34243, 25259), (34243, 10530), (34243, 22364), (34243, 10530),
+    (34243, 1890), (34243, 34011), (34243, 19456), (34243, 10530), (34243, 19653),
+    (34243, 32255), (34243, 21325), (34243, 10530), (34243, 10530), (34243, 36970),
+    (34243, 18143), (34243, 36970), (34243, 35792), (34243, 21922), (34243, 13502),
+    (34243, 1890), (34243, 1890), (34243, 18186), (34243, 39538), (34243, 28860),
+    (34243, 10530), (34243, 10530), (34243, 32341), (34243, 19456), (34243, 37040),
+    (34243, 27434), (34243, 10530), (34243, 1890), (34243, 19653), (34243, 10530),
+    (34243, 28978), (34243, 10530), (34243, 27035), (34243, 27434), (34243, 5598),
+    (34243, 28978), (34243, 17813), (34243, 19653), (34243, 34011), (34243, 34011),
+    (34243, 1890), (34243, 23950), (34243, 32255), (34243, 28978), (34243, 12049),
+    (34243, 19456), (34243, 10530), (34243, 34011), (34243, 34011), (34243, 19653),
+    (34243, 10530), (34243, 29560), (34243, 10530), (34243, 1890), (34243, 19653),
+    (34243, 19456), (34243, 37040), (34243, 34011), (34243, 27434), (34243, 19456),
+    (34243, 13502), (34243, 10530), (34243, 19653), (34243, 19456), (34243, 27434),
+    (34243, 1890), (34243, 10530), (34243, 34011), (34243, 12049), (34243, 13476),
+    (34243, 1890), (34243, 28978), (34243, 10530), (34243, 32385), (34243, 28978),
+    (34243, 10530), (34243, 32385), (34243, 10530), (34243, 10530), (34243, 29560),
+    (34243, 19653), (34243, 37006), (34243, 18436), (34243, 15198), (34243, 32341),
+    (34243, 32341), (34243, 32385), (34243, 10530), (34243, 25259), (34243, 37488),
+    (34243, 10530), (34243, 13476), (34243, 37040), (34243, 24232), (34243, 10530),
+    (34243, 20247), (34243, 32341), (34243, 11703), (34243, 32255), (34243, 19653),
+    (34243, 18143), (34243, 34011), (34243, 32385), (34243, 12049), (34243, 19456),
+    (34243, 28978), (34243, 10530), (34243, 32341), (34243, 10530), (34243, 16792),
+    (34243, 16300), (34243, 19653), (34243, 19456), (34243, 18186), (34243, 35844),
+    (34243, 26840), (34243, 10530), (34243, 19653), (34243, 6073), (34243, 21656),
+    (34243, 25259), (34243, 21656), (34243, 10530), (34243, 19456), (34243, 5598),
+    (34243, 18436), (34243, 31433), (34243, 28978), (34243, 10530), (34243, 30845),
+    (34243, 28978), (34243, 34894), (34243, 19456), (34243, 28978), (34243, 19456),
+    (34243, 34011), (34243, 32341), (34243, 10530), (34243, 1890), (34243, 5836),
+    (34243, 25039), (34243, 10530), (34243, 28978), (34243, 5598), (34243, 13502),
+    (34243, 22364), (34243, 10530), (34243, 18186), (34243, 10530), (34243, 1890),
+    (34243, 13476), (34243, 10530), (34243, 10530), (34243, 28978), (34243, 10530),
+    (34243, 37488), (34243, 34011), (34243, 1890), (34243, 38333), (34243, 20247),
+    (34243, 27434), (34243, 30845), (34243, 1890), (34243, 28978), (34243, 10530),
+    (34243, 10530), (34243, 34011), (34243, 18436), (34243, 10530), (34243, 1538),
+    (34243, 29560), (34243, 1538), (34243, 4867), (34243, 39544), (34243, 1538),
+    (34243, 19653), (34243, 10530), (34243, 1890), (34243, 1890), (34243, 1890),
+    (34243, 1538), (34243, 31634), (34243, 28978), (34243, 32341), (34243, 1890),
+    (34243, 37006), (34243, 15405), (34243, 17956), (34243, 1890), (34243, 28978),
+    (34243, 13476), (34243, 30845), (34243, 27434), (34243, 20247), (34243, 37040),
+    (34243, 34011), (34243, 32385), (34243, 18436), (34243, 26873), (34243, 27434),
+    (34243, 10530), (34243, 1538), (34243, 34011), (34243, 18991), (34243, 10530),
+    (34243, 5598), (34243, 24215), (34243, 21656), (34243, 1890), (34243, 10771),
+    (34243, 18436), (34243, 1890), (34243, 13476), (34243, 18186), (34243, 28514),
+    (34243, 34011), (34243, 37488), (34243, 31634), (34243, 11703), (34243, 37040),
+    (34243, 18991), (34243, 21325), (34243, 29459), (34243, 182), (34243, 17956),
+    (34243, 1890), (34243, 10530), (34243, 39544), (34243, 1890), (34243, 32341),
+    (34243, 10530), (34243, 1890), (34243, 1890), (34243, 28978), (34243, 27434),
+    (34243, 10530), (34243, 10530), (34243, 36603), (34243, 19456), (34243, 28978),
+    (34243, 36551), (34243, 10530), (34243, 36970), (34243, 19456), (34243, 35192),
+    (34243, 34011), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 34011),
+    (34243, 39828), (34243, 18143), (34243, 26840), (34243, 33967), (34243, 10530),
+    (34243, 18186), (34243, 39544), (34243, 19428), (34243, 10530), (34243, 15405),
+    (34243, 1890), (34243, 20014), (34243, 1890), (34243, 10530), (34243, 5295),
+    (34243, 28978), (34243, 10530), (34243, 37040), (34243, 28573), (34243, 25259),
+    (34243, 1890), (34243, 1890), (34243, 32341), (34243, 19653), (34243, 19653),
+    (34243, 19653), (34243, 1890), (34243, 28978), (34243, 7528), (34243, 1890),
+    (34243, 37006), (34243, 1912), (34243, 10530), (34243, 37040), (34243, 18436),
+    (34243, 1814), (34243, 18436), (34243, 15198), (34243, 10530), (34243, 21325),
+    (34243, 5789), (34243, 10530), (34243, 5947), (34243, 30845), (34243, 19456),
+    (34243, 10530), (34243, 15198), (34243, 1538), (34243, 30845), (34243, 10530),
+    (34243, 28978), (34243, 38344), (34243, 34011), (34243, 18186), (34243, 28978),
+    (34243, 20247), (34243, 19456), (34243, 31433), (34243, 19653), (34243, 10530),
+    (34243, 10530), (34243, 1890), (34243, 37040), (34243, 39538), (34243, 10530),
+    (34243, 34011), (34243, 19456), (34243, 1890), (34243, 12049), (34243, 19456),
+    (34243, 9781), (34243, 27434), (34243, 10530), (34243, 1890), (34243, 1890),
+    (34243, 1890), (34243, 10530), (34243, 31634), (34243, 25259), (34243, 32385),
+    (34243, 18186), (34243, 34011), (34243, 2700), (34243, 10530), (34243, 10530),
+    (34243, 37040), (34243, 28978), (34243, 19456), (34243, 35792), (34243, 10530),
+    (34243, 10530), (34243, 28978), (34243, 10530), (34243, 27434), (34243, 10530),
+    (34243, 32341), (34243, 36551), (34243, 37006), (34243, 19456), (34243, 19653),
+    (34243, 1890), (34243, 21656), (34243, 13476), (34243, 32341), (34243, 10530),
+    (34243, 10530), (34243, 13476), (34243, 39544), (34243, 19456), (34243, 37006),
+    (34243, 1890), (34243, 35192), (34243, 5789), (34243, 18186), (34243, 10530),
+    (34243, 34011), (34243, 19456), (34243, 19456), (34243, 27434), (34243, 19456),
+    (34243, 37488), (34243, 28978), (34243, 1890), (34243, 13502), (34243, 39828),
+    (34243, 10530), (34243, 34011), (34243, 19456), (34243, 30845), (34243, 10530),
+    (34243, 10530), (34243, 10530), (34243, 28978), (34243, 10530), (34243, 10530),
+    (34243, 28573), (34243, 38878), (34243, 38344), (34243, 28978), (34243, 32341),
+    (34243, 27434), (34243, 34011), (34243, 30845), (34243, 18143), (34243, 10530),
+    (34243, 28978), (34243, 34011), (34243, 19456), (34243, 36970), (34243, 39544),
+    (34243, 5598), (34243, 13476), (34243, 21325), (34243, 37488), (34243, 10530),
+    (34243, 19456), (34243, 27434), (34243, 30364), (34243, 19428), (34243, 13476),
+    (34243, 29560), (34243, 26840), (34243, 5789), (34243, 21325), (34243, 10530),
+    (34243, 10530), (34243, 28978), (34243, 29893), (34243, 7587), (34243, 19456),
+    (34243, 13476), (34243, 34011), (34243, 34011), (34243, 18436), (34243, 19456),
+    (34243, 19653), (34243, 10530), (34243, 19456), (34243, 1890), (34243, 31634),
+    (34243, 28978), (34243, 19653), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 32385), (34243, 37040), (34243, 13476), (34243, 5947),
+    (34243, 37040), (34243, 18436), (34243, 1890), (34243, 18186), (34243, 24232),
+    (34243, 5598), (34243, 1890), (34243, 16792), (34243, 10530), (34243, 1890),
+    (34243, 13476), (34243, 16792), (34243, 28978), (34243, 10530), (34243, 30845),
+    (34243, 29560), (34243, 1890), (34243, 19456), (34243, 19653), (34243, 20247),
+    (34243, 34011), (34243, 33967), (34243, 32341), (34243, 19653), (34243, 29560),
+    (34243, 37040), (34243, 30845), (34243, 10530), (34243, 28860), (34243, 38344),
+    (34243, 10530), (34243, 30845), (34243, 1890), (34243, 28978), (34243, 10530),
+    (34243, 28860), (34243, 14304), (34243, 31433), (34243, 32385), (34243, 12049),
+    (34243, 182), (34243, 19456), (34243, 21656), (34243, 37040), (34243, 21922),
+    (34243, 19456), (34243, 32341), (34243, 36351), (34243, 28978), (34243, 34011),
+    (34243, 10530), (34243, 18186), (34243, 10530), (34243, 19653), (34243, 32385),
+    (34243, 32385), (34243, 31634), (34243, 1890), (34243, 21922), (34243, 19456),
+    (34243, 28978), (34243, 36970), (34243, 34011), (34243, 19653), (34243, 37040),
+    (34243, 7587), (34243, 18143), (34243, 1890), (34243, 19456), (34243, 1890),
+    (34243, 10530), (34243, 10530), (34243, 34011), (34243, 13476), (34243, 32341),
+    (34243, 10530), (34243, 18991), (34243, 10530), (34243, 28978), (34243, 1538),
+    (34243, 26840), (34243, 10530), (34243, 1890), (34243, 32385), (34243, 13476),
+    (34243, 19428), (34243, 32341), (34243, 19456), (34243, 19653), (34243, 27434),
+    (34243, 10530), (34243, 26840), (34243, 30845), (34243, 10530), (34243, 39538),
+    (34243, 1890), (34243, 10530), (34243, 38344), (34243, 10530), (34243, 19428),
+    (34243, 32341), (34243, 28978), (34243, 5598), (34243, 1890), (34243, 18991),
+    (34243, 25259), (34243, 10530), (34243, 10530), (34243, 20247), (34243, 29459),
+    (34243, 32385), (34243, 1538), (34243, 39544), (34243, 38344), (34243, 1890),
+    (34243, 1890), (34243, 18551), (34243, 37040), (34243, 34011), (34243, 28860),
+    (34243, 10530), (34243, 27434), (34243, 1538), (34243, 1890), (34243, 19456),
+    (34243, 10530), (34243, 13476), (34243, 36970), (34243, 28978), (34243, 1890),
+    (34243, 13476), (34243, 21656), (34243, 13502), (34243, 13476), (34243, 27434),
+    (34243, 19456), (34243, 28860), (34243, 21656), (34243, 19428), (34243, 13476),
+    (34243, 1890), (34243, 10530), (34243, 34011), (34243, 28978), (34243, 1890),
+    (34243, 21325), (34243, 39544), (34243, 26840), (34243, 19428), (34243, 10530),
+    (34243, 13502), (34243, 37006), (34243, 8394), (34243, 16137), (34243, 10530),
+    (34243, 28573), (34243, 28978), (34243, 27434), (34243, 1890), (34243, 28978),
+    (34243, 28978), (34243, 1890), (34243, 35792), (34243, 29560), (34243, 1538),
+    (34243, 10530), (34243, 28860), (34243, 21656), (34243, 19653), (34243, 34011),
+    (34243, 21481), (34243, 32385), (34243, 37040), (34243, 26840), (34243, 34011),
+    (34243, 28978), (34243, 13476), (34243, 37488), (34243, 21656), (34243, 10530),
+    (34243, 10530), (34243, 10530), (34243, 10530), (34243, 30845), (34243, 5836),
+    (34243, 27434), (34243, 29459), (34243, 5598), (34243, 9932), (34243, 1890),
+    (34243, 10530), (34243, 36970), (34243, 28860), (34243, 32385), (34243, 19456),
+    (34243, 21656), (34243, 32341), (34243, 19456), (34243, 19456), (34243, 10530),
+    (34243, 10530), (34243, 1890), (34243, 13476), (34243, 13476), (34243, 19456),
+    (34243, 37040), (34243, 10530), (34243, 21325), (34243, 32341), (34243, 29459),
+    (34243, 10530), (34243, 26863), (34243, 1890), (34243, 1538), (34243, 32255),
+    (34243, 182), (34243, 34011), (34243, 32535), (34243, 5947), (34243, 24232),
+    (34243, 20247), (34243, 10530), (34243, 10530), (34243, 19456), (34243, 5789),
+    (34243, 13476), (34243, 4867), (34243, 21656), (34243, 19456), (34243, 10530),
+    (34243, 1890), (34243, 34011), (34243, 19456), (34243, 1890), (34243, 10530),
+    (34243, 39538), (34243, 34011), (34243, 29560), (34243, 1538), (34243, 29560),
+    (34243, 32385), (34243, 10530), (34243, 18186), (34243, 1890), (34243, 19456),
+    (34243, 18436), (34243, 37040), (34243, 28978), (34243, 18186), (34243, 13476),
+    (34243, 10530), (34243, 32341), (34243, 1890), (34243, 22364), (34243, 29459),
+    (34243, 18436), (34243, 18186), (34243, 10530), (34243, 19456), (34243, 34011),
+    (34243, 32385), (34243, 18186), (34243, 37488), (34243, 19456), (34243, 15198),
+    (34243, 19456), (34243, 32385), (34243, 32341), (34243, 36154), (34243, 29560),
+    (34243, 10530), (34243, 10530), (34243, 25259), (34243, 5212), (34243, 29459),
+    (34243, 36603), (34243, 28978), (34243, 39538), (34243, 28573), (34243, 32385),
+    (34243, 1890), (34243, 35792), (34243, 1538), (34243, 19456), (34243, 10530),
+    (34243, 10530), (34243, 18436), (34243, 36603), (34243, 19653), (34243, 10530),
+    (34243, 19653), (34243, 10530), (34243, 20014), (34243, 34011), (34243, 19456),
+    (34243, 19653), (34243, 24232), (34243, 21325), (34243, 37488), (34243, 10530),
+    (34243, 32341), (34243, 28978), (34243, 29560), (34243, 15198), (34243, 29560),
+    (34243, 10530), (34243, 18143), (34243, 10530), (34243, 10530), (34243, 19653),
+    (34243, 1890), (34243, 25259), (34243, 32341), (34243, 19456), (34243, 28978),
+    (34243, 27434), (34243, 10530), (34243, 37006), (34243, 33941), (34243, 10530),
+    (34243, 19456), (34243, 10530), (34243, 19653), (34243, 19456), (34243, 10530),
+    (34243, 10530), (34243, 18186), (34243, 30845), (34243, 19456), (34243, 19456),
+    (34243, 20247), (34243, 1890), (34243, 10530), (34243, 38344), (34243, 38344),
+    (34243, 31634), (34243, 19456), (34243, 19456), (34243, 15171), (34243, 28978),
+    (34243, 10530), (34243, 10265), (34243, 1538), (34243, 28978), (34243, 1890),
+    (34243, 38344), (34243, 19653), (34243, 1890), (34243, 37488), (34243, 33967),
+    (34243, 10530), (34243, 28978), (34243, 10530), (34243, 10530), (34243, 37040),
+    (34243, 19653), (34243, 37488), (34243, 19653), (34243, 34011), (34243, 38344),
+    (34243, 36970), (34243, 19653), (34243, 28978), (34243, 34011), (34243, 34011),
+    (34243, 18143), (34243, 32255), (34243, 21656), (34243, 19653), (34243, 13476),
+    (34243, 31634), (34243, 10530), (34243, 28978), (34243, 27434), (34243, 26873),
+    (34243, 28978), (34243, 19456), (34243, 10530), (34243, 37006), (34243, 3132),
+    (34243, 19456), (34243, 28978), (34243, 36970), (34243, 28978), (34243, 1538),
+    (34243, 18186), (34243, 36970), (34243, 30845), (34243, 1890), (34243, 5598),
+    (34243, 182), (34243, 10530), (34243, 17741), (34243, 27434), (34243, 13476),
+    (34243, 27035), (34243, 10530), (34243, 13476), (34243, 10530), (34243, 21325),
+    (34243, 1890), (34243, 1890), (34243, 28978), (34243, 18436), (34243, 21656),
+    (34243, 10530), (34243, 28978), (34243, 28978), (34243, 28978), (34243, 28978),
+    (34243, 18186), (34243, 1890), (34243, 29560), (34243, 28978), (34243, 13502),
+    (34243, 27434), (34243, 34011), (34243, 28978), (34243, 36970), (34243, 13476),
+    (34243, 20899), (34243, 39544), (34243, 34011), (34243, 37006), (34243, 29560),
+    (34243, 10530), (34243, 27434), (34243, 20899), (34243, 1890), (34243, 5522),
+    (34243, 1890), (34243, 28978), (34243, 10530), (34243, 37488), (34243, 1538),
+    (34243, 5598), (34243, 27434), (34243, 10530), (34243, 20247), (34243, 7587),
+    (34243, 29560), (34243, 39828), (34243, 27434), (34243, 10530), (34243, 24099),
+    (34243, 37040), (34243, 10530), (34243, 37488), (34243, 28978), (34243, 10530),
+    (34243, 1890), (34243, 19139), (34243, 19456), (34243, 29560), (34243, 28978),
+    (34243, 28860), (34243, 19653), (34243, 29560), (34243, 10020), (34243, 1890),
+    (34243, 13476), (34243, 28860), (34243, 10530), (34243, 19456), (34243, 28978),
+    (34243, 39538), (34243, 18300), (34243, 24215), (34243, 10530), (34243, 10530),
+    (34243, 21325), (34243, 30845), (34243, 5789), (34243, 32341), (34243, 22364),
+    (34243, 37040), (34243, 30845), (34243, 29459), (34243, 10530), (34243, 20014),
+    (34243, 16137), (34243, 1538), (34243, 37488), (34243, 10530), (34243, 1890),
+    (34243, 12049), (34243, 13476), (34243, 10530), (34243, 35192), (34243, 29459),
+    (34243, 31104), (34243, 29459), (34243, 10530), (34243, 13476), (34243, 38344),
+    (34243, 10530), (34243, 28978), (34243, 10530), (34243, 1890), (34243, 19653),
+    (34243, 29560), (34243, 10530), (34243, 10530), (34243, 15198), (34243, 28978),
+    (34243, 39544), (34243, 19456), (34243, 10530), (34243, 10530), (34243, 16792),
+    (34243, 1890), (34243, 30845), (34243, 31634), (34243, 10530), (34243, 13476),
+    (34243, 16792), (34243, 28978), (34243, 29459), (34243, 32385), (34243, 21656),
+    (34243, 5295), (34243, 10530), (34243, 21922), (34243, 18186), (34243, 10265),
+    (34243, 16792), (34243, 10530), (34243, 32385), (34243, 10530), (34243, 16792),
+    (34243, 1890), (34243, 28978), (34243, 19456), (34243, 36970), (34243, 37040),
+    (34243, 10530), (34243, 3132), (34243, 19653), (34243, 1890), (34243, 1890),
+    (34243, 38344), (34243, 7528), (34243, 13476), (34243, 26863), (34243, 20247),
+    (34243, 19456), (34243, 10530), (34243, 31634), (34243, 5836), (34243, 21325),
+    (34243, 10530), (34243, 1890), (34243, 13476), (34243, 19653), (34243, 38344),
+    (34243, 13476), (34243, 10530), (34243, 27434), (34243, 10530), (34243, 10530),
+    (34243, 1890), (34243, 32385), (34243, 39544), (34243, 1890), (34243, 39544),
+    (34243, 32341), (34243, 32385), (34243, 1890), (34243, 10530), (34243, 32385),
+    (34243, 28978), (34243, 18186), (34243, 30845), (34243, 31104), (34243, 10530),
+    (34243, 28978), (34243, 10530), (34243, 21656), (34243, 6069), (34243, 10530),
+    (34243, 19653), (34243, 5212), (34243, 18436), (34243, 21325), (34243, 1538),
+    (34243, 9223), (34243, 20247), (34243, 28978), (34243, 37006), (34243, 19456),
+    (34243, 28978), (34243, 19456), (34243, 5598), (34243, 8628), (34243, 34011),
+    (34243, 38344), (34243, 1890), (34243, 28978), (34243, 37040), (34243, 34011),
+    (34243, 19456), (34243, 35792), (34243, 39544), (34243, 39544), (34243, 19456),
+    (34243, 10530), (34243, 36970), (34243, 1890), (34243, 36970), (34243, 10530),
+    (34243, 10530), (34243, 39538), (34243, 10530), (34243, 10530), (34243, 39544),
+    (34243, 33941), (34243, 18143), (34243, 10530), (34243, 28978), (34243, 38181),
+    (34243, 19456), (34243, 28978), (34243, 24215), (34243, 24232), (34243, 13502),
+    (34243, 10530), (34243, 32341), (34243, 36603), (34243, 37040), (34243, 10530),
+    (34243, 34894), (34243, 28978), (34243, 34011), (34243, 29560), (34243, 1538),
+    (34243, 1890), (34243, 10530), (34243, 1890), (34243, 30845), (34243, 36603),
+    (34243, 15171), (34243, 20899), (34243, 28978), (34243, 5598), (34243, 18186),
+    (34243, 31634), (34243, 39828), (34243, 28978), (34243, 39538), (34243, 36603),
+    (34243, 1890), (34243, 13502), (34243, 38628), (34243, 18436), (34243, 10530),
+    (34243, 21656), (34243, 26840), (34243, 17813), (34243, 32341), (34243, 19653),
+    (34243, 28978), (34243, 5598), (34243, 32341), (34243, 1538), (34243, 19456),
+    (34243, 37040), (34243, 10530), (34243, 10530), (34243, 21656), (34243, 10530),
+    (34243, 19653), (34243, 28978), (34243, 19456), (34243, 28978), (34243, 10530),
+    (34243, 18143), (34243, 18436), (34243, 37488), (34243, 28978), (34243, 21325),
+    (34243, 39538), (34243, 29459), (34243, 32341), (34243, 18436), (34243, 34072),
+    (34243, 10530), (34243, 1890), (34243, 34011), (34243, 32385), (34243, 10530),
+    (34243, 18186), (34243, 10265), (34243, 34011), (34243, 28978), (34243, 1890),
+    (34243, 21325), (34243, 10530), (34243, 31634), (34243, 10530), (34243, 13476),
+    (34243, 16137), (34243, 34011), (34243, 10530), (34243, 18436), (34243, 18186),
+    (34243, 28978), (34243, 34011), (34243, 39514), (34243, 4867), (34243, 16792),
+    (34243, 10530), (34243, 28978), (34243, 10530), (34243, 19653), (34243, 10530),
+    (34243, 10530), (34243, 10530), (34243, 1814), (34243, 10530), (34243, 38344),
+    (34243, 18436), (34243, 10530), (34243, 19653), (34243, 1538), (34243, 13476),
+    (34243, 10530), (34243, 2700), (34243, 1890), (34243, 15198), (34243, 16792),
+    (34243, 32341), (34243, 1538), (34243, 32341), (34243, 10530), (34243, 10530),
+    (34243, 28860), (34243, 1890), (34243, 16792), (34243, 25259), (34243, 21922),
+    (34243, 10530), (34243, 28978), (34243, 28978), (34243, 21325), (34243, 1890),
+    (34243, 19653), (34243, 32341), (34243, 20247), (34243, 5598), (34243, 36970),
+    (34243, 10530), (34243, 32341), (34243, 1890), (34243, 24099), (34243, 30226),
+    (34243, 13476), (34243, 1890), (34243, 15198), (34243, 19456), (34243, 31634),
+    (34243, 10530), (34243, 19456), (34243, 21325), (34243, 28860), (34243, 10265),
+    (34243, 1538), (34243, 10530), (34243, 28978), (34243, 10530), (34243, 28978),
+    (34243, 16792), (34243, 1538), (34243, 37040), (34243, 18991), (34243, 37040),
+    (34243, 18186), (34243, 34549), (34243, 34011), (34243, 38333), (34243, 10530),
+    (34243, 34011), (34243, 18186), (34243, 29560), (34243, 30845), (34243, 28514),
+    (34243, 19456), (34243, 36970), (34243, 38813), (34243, 29459), (34243, 10530),
+    (34243, 34011), (34243, 10530), (34243, 19653), (34243, 36551), (34243, 5598),
+    (34243, 19456), (34243, 26873), (34243, 39538), (34243, 10530), (34243, 34011),
+    (34243, 18186), (34243, 1538), (34243, 24099), (34243, 19653), (34243, 12049),
+    (34243, 10530), (34243, 16137), (34243, 13502), (34243, 36970), (34243, 38344),
+    (34243, 21325), (34243, 5598), (34243, 36970), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 10530), (34243, 20247), (34243, 34011), (34243, 27434),
+    (34243, 19456), (34243, 10530), (34243, 10530), (34243, 34011), (34243, 15198),
+    (34243, 10020), (34243, 1890), (34243, 19456), (34243, 28978), (34243, 29560),
+    (34243, 19653), (34243, 28860), (34243, 1890), (34243, 28978), (34243, 37040),
+    (34243, 28978), (34243, 1538), (34243, 28978), (34243, 28978), (34243, 29459),
+    (34243, 39544), (34243, 6073), (34243, 38344), (34243, 35844), (34243, 29459),
+    (34243, 28978), (34243, 21325), (34243, 10530), (34243, 28978), (34243, 16792),
+    (34243, 27434), (34243, 13476), (34243, 1890), (34243, 36719), (34243, 32341),
+    (34243, 25039), (34243, 39544), (34243, 34011), (34243, 36970), (34243, 27434),
+    (34243, 28978), (34243, 5212), (34243, 28978), (34243, 13476), (34243, 10530),
+    (34243, 40), (34243, 28978), (34243, 10530), (34243, 26840), (34243, 18186),
+    (34243, 18143), (34243, 10530), (34243, 10530), (34243, 21656), (34243, 1538),
+    (34243, 10530), (34243, 10530), (34243, 1890), (34243, 25259), (34243, 21656),
+    (34243, 10530), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 1538),
+    (34243, 21656), (34243, 37006), (34243, 34011), (34243, 2885), (34243, 17956),
+    (34243, 34011), (34243, 10530), (34243, 19428), (34243, 18102), (34243, 29560),
+    (34243, 16792), (34243, 37006), (34243, 32255), (34243, 10530), (34243, 39538),
+    (34243, 39538), (34243, 19653), (34243, 13502), (34243, 10530), (34243, 31634),
+    (34243, 29459), (34243, 32385), (34243, 27434), (34243, 21656), (34243, 21922),
+    (34243, 10530), (34243, 37040), (34243, 1890), (34243, 34011), (34243, 1538),
+    (34243, 19428), (34243, 27434), (34243, 10771), (34243, 10530), (34243, 13502),
+    (34243, 1890), (34243, 27434), (34243, 10530), (34243, 10020), (34243, 35192),
+    (34243, 1538), (34243, 32385), (34243, 20247), (34243, 10530), (34243, 13476),
+    (34243, 28978), (34243, 36970), (34243, 28514), (34243, 38344), (34243, 182),
+    (34243, 26873), (34243, 10530), (34243, 10530), (34243, 15198), (34243, 32385),
+    (34243, 5104), (34243, 10530), (34243, 32341), (34243, 10530), (34243, 10530),
+    (34243, 39544), (34243, 24215), (34243, 13476), (34243, 18436), (34243, 28978),
+    (34243, 28978), (34243, 13502), (34243, 28978), (34243, 39544), (34243, 30845),
+    (34243, 10530), (34243, 7528), (34243, 18991), (34243, 31634), (34243, 37040),
+    (34243, 4867), (34243, 1538), (34243, 27434), (34243, 19456), (34243, 19653),
+    (34243, 37488), (34243, 29560), (34243, 10530), (34243, 18186), (34243, 13502),
+    (34243, 10530), (34243, 20247), (34243, 10530), (34243, 37040), (34243, 35284),
+    (34243, 36970), (34243, 16792), (34243, 853), (34243, 1890), (34243, 38333),
+    (34243, 10530), (34243, 20899), (34243, 13502), (34243, 37006), (34243, 28573),
+    (34243, 10530), (34243, 10530), (34243, 37040), (34243, 2700), (34243, 20247),
+    (34243, 32552), (34243, 13476), (34243, 10530), (34243, 24232), (34243, 19653),
+    (34243, 29560), (34243, 10530), (34243, 19456), (34243, 10530), (34243, 18186),
+    (34243, 9781), (34243, 31433), (34243, 18186), (34243, 21656), (34243, 18436),
+    (34243, 24215), (34243, 1890), (34243, 10530), (34243, 20247), (34243, 23710),
+    (34243, 34011), (34243, 5598), (34243, 6073), (34243, 10530), (34243, 19653),
+    (34243, 30845), (34243, 21922), (34243, 10530), (34243, 32341), (34243, 13502),
+    (34243, 32385), (34243, 21656), (34243, 1890), (34243, 9932), (34243, 37006),
+    (34243, 16792), (34243, 28978), (34243, 1538), (34243, 21325), (34243, 26873),
+    (34243, 1675), (34243, 19653), (34243, 18143), (34243, 10530), (34243, 1890),
+    (34243, 10530), (34243, 29459), (34243, 18551), (34243, 18143), (34243, 38333),
+    (34243, 19456), (34243, 18186), (34243, 1890), (34243, 22364), (34243, 29560),
+    (34243, 34011), (34243, 34011), (34243, 28978), (34243, 21656), (34243, 19456),
+    (34243, 21656), (34243, 1890), (34243, 18436), (34243, 10530), (34243, 21925),
+    (34243, 19653), (34243, 10530), (34243, 37040), (34243, 12049), (34243, 19456),
+    (34243, 10530), (34243, 5598), (34243, 28978), (34243, 17741), (34243, 30845),
+    (34243, 18143), (34243, 1538), (34243, 10530), (34243, 19428), (34243, 1538),
+    (34243, 10530), (34243, 1890), (34243, 28978), (34243, 38344), (34243, 28978),
+    (34243, 28573), (34243, 18186), (34243, 10530), (34243, 30845), (34243, 19456),
+    (34243, 19428), (34243, 31433), (34243, 26863), (34243, 23950), (34243, 36970),
+    (34243, 10530), (34243, 34011), (34243, 37006), (34243, 1890), (34243, 28978),
+    (34243, 17813), (34243, 24232), (34243, 18143), (34243, 13476), (34243, 19653),
+    (34243, 10530), (34243, 31433), (34243, 15405), (34243, 10530), (34243, 28860),
+    (34243, 1814), (34243, 1890), (34243, 37040), (34243, 31634), (34243, 30845),
+    (34243, 27434), (34243, 10530), (34243, 16300), (34243, 13476), (34243, 24099),
+    (34243, 21922), (34243, 1890), (34243, 28978), (34243, 19456), (34243, 15198),
+    (34243, 38344), (34243, 20247), (34243, 19456), (34243, 37488), (34243, 13476),
+    (34243, 2700), (34243, 28978), (34243, 30845), (34243, 13502), (34243, 28860),
+    (34243, 28978), (34243, 29560), (34243, 10530), (34243, 31433), (34243, 10530),
+    (34243, 25259), (34243, 19456), (34243, 37040), (34243, 10530), (34243, 19456),
+    (34243, 21325), (34243, 6073), (34243, 28978), (34243, 12049), (34243, 16792),
+    (34243, 28978), (34243, 18143), (34243, 24232), (34243, 15198), (34243, 19653),
+    (34243, 37040), (34243, 36970), (34243, 36970), (34243, 1890), (34243, 19456),
+    (34243, 1890), (34243, 28978), (34243, 10530), (34243, 32385), (34243, 10530),
+    (34243, 33967), (34243, 21656), (34243, 1890), (34243, 37006), (34243, 10530),
+    (34243, 10530), (34243, 36970), (34243, 36970), (34243, 19456), (34243, 1538),
+    (34243, 29459), (34243, 27434), (34243, 29560), (34243, 28978), (34243, 5947),
+    (34243, 10530), (34243, 1538), (34243, 1890), (34243, 1538), (34243, 1538),
+    (34243, 11703), (34243, 35792), (34243, 10530), (34243, 18436), (34243, 30364),
+    (34243, 1538), (34243, 10530), (34243, 39828), (34243, 10530), (34243, 21656),
+    (34243, 10530), (34243, 19653), (34243, 21325), (34243, 19653), (34243, 5598),
+    (34243, 33967), (34243, 21656), (34243, 21656), (34243, 18436), (34243, 32385),
+    (34243, 35192), (34243, 35792), (34243, 1538), (34243, 13476), (34243, 1890),
+    (34243, 32341), (34243, 1890), (34243, 30845), (34243, 28573), (34243, 182),
+    (34243, 5598), (34243, 19456), (34243, 36970), (34243, 26873), (34243, 10530),
+    (34243, 32255), (34243, 10530), (34243, 30845), (34243, 34011), (34243, 28573),
+    (34243, 1890), (34243, 13476), (34243, 10530), (34243, 10530), (34243, 32341),
+    (34243, 1890), (34243, 10530), (34243, 28860), (34243, 18102), (34243, 28978),
+    (34243, 10530), (34243, 37040), (34243, 28573), (34243, 19653), (34243, 1890),
+    (34243, 34011), (34243, 1890), (34243, 21325), (34243, 13476), (34243, 20247),
+    (34243, 13502), (34243, 34011), (34243, 32255), (34243, 10530), (34243, 10530),
+    (34243, 26840), (34243, 18186), (34243, 1890), (34243, 10530), (34243, 32341),
+    (34243, 11703), (34243, 37006), (34243, 27434), (34243, 38333), (34243, 1890),
+    (34243, 1890), (34243, 27035), (34243, 32341), (34243, 28573), (34243, 182),
+    (34243, 39538), (34243, 12049), (34243, 13476), (34243, 27434), (34243, 21922),
+    (34243, 27434), (34243, 28978), (34243, 36970), (34243, 18436), (34243, 26873),
+    (34243, 8111), (34243, 19653), (34243, 10530), (34243, 13476), (34243, 29560),
+    (34243, 39538), (34243, 10530), (34243, 28978), (34243, 1890), (34243, 10530),
+    (34243, 19456), (34243, 19456), (34243, 1890), (34243, 182), (34243, 37040),
+    (34243, 39538), (34243, 37006), (34243, 19653), (34243, 34011), (34243, 34011),
+    (34243, 25259), (34243, 38344), (34243, 32385), (34243, 32341), (34243, 13476),
+    (34243, 36970), (34243, 19653), (34243, 37040), (34243, 15198), (34243, 16792),
+    (34243, 1890), (34243, 13476), (34243, 10530), (34243, 18102), (34243, 30845),
+    (34243, 18143), (34243, 37040), (34243, 10020), (34243, 29459), (34243, 10530),
+    (34243, 37006), (34243, 29459), (34243, 5598), (34243, 38813), (34243, 30845),
+    (34243, 13476), (34243, 27035), (34243, 34011), (34243, 36154), (34243, 32385),
+    (34243, 10530), (34243, 26840), (34243, 5789), (34243, 1890), (34243, 13502),
+    (34243, 10530), (34243, 28978), (34243, 10530), (34243, 18436), (34243, 13502),
+    (34243, 34011), (34243, 10530), (34243, 34011), (34243, 19456), (34243, 10530),
+    (34243, 18436), (34243, 13502), (34243, 20359), (34243, 39538), (34243, 32535),
+    (34243, 33967), (34243, 1538), (34243, 18186), (34243, 29560), (34243, 19456),
+    (34243, 1890), (34243, 2700), (34243, 36970), (34243, 10530), (34243, 37040),
+    (34243, 19456), (34243, 18143), (34243, 10530), (34243, 34011), (34243, 24232),
+    (34243, 29560), (34243, 12049), (34243, 10530), (34243, 21656), (34243, 32341),
+    (34243, 18186), (34243, 28978), (34243, 37040), (34243, 23950), (34243, 30845),
+    (34243, 10530), (34243, 19653), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 30845), (34243, 10530), (34243, 32385), (34243, 10530), (34243, 34011),
+    (34243, 32385), (34243, 19456), (34243, 10530), (34243, 18102), (34243, 34011),
+    (34243, 27035), (34243, 10530), (34243, 21325), (34243, 10530), (34243, 32385),
+    (34243, 34011), (34243, 19653), (34243, 32385), (34243, 32385), (34243, 1890),
+    (34243, 1890), (34243, 10530), (34243, 16792), (34243, 23710), (34243, 10530),
+    (34243, 39544), (34243, 16792), (34243, 15171), (34243, 28860), (34243, 1890),
+    (34243, 13476), (34243, 39828), (34243, 18102), (34243, 18436), (34243, 18991),
+    (34243, 36603), (34243, 36970), (34243, 24232), (34243, 18186), (34243, 10530),
+    (34243, 16792), (34243, 5789), (34243, 13476), (34243, 10530), (34243, 10530),
+    (34243, 36551), (34243, 10530), (34243, 21325), (34243, 29459), (34243, 20247),
+    (34243, 37040), (34243, 29560), (34243, 21656), (34243, 18436), (34243, 19456),
+    (34243, 25039), (34243, 5598), (34243, 10530), (34243, 18186), (34243, 1890),
+    (34243, 25259), (34243, 37040), (34243, 11759), (34243, 32385), (34243, 8111),
+    (34243, 10530), (34243, 10530), (34243, 39828), (34243, 32341), (34243, 29560),
+    (34243, 10530), (34243, 29560), (34243, 10530), (34243, 32552), (34243, 28860),
+    (34243, 10530), (34243, 27434), (34243, 16792), (34243, 10530), (34243, 1890),
+    (34243, 19456), (34243, 10530), (34243, 10530), (34243, 32341), (34243, 18186),
+    (34243, 39538), (34243, 19456), (34243, 10530), (34243, 28978), (34243, 35192),
+    (34243, 28978), (34243, 5295), (34243, 32385), (34243, 28978), (34243, 37006),
+    (34243, 32341), (34243, 30845), (34243, 26873), (34243, 19456), (34243, 13502),
+    (34243, 36551), (34243, 19456), (34243, 32341), (34243, 10530), (34243, 1890),
+    (34243, 13476), (34243, 39538), (34243, 1890), (34243, 1912), (34243, 32341),
+    (34243, 32341), (34243, 27035), (34243, 28978), (34243, 21922), (34243, 23710),
+    (34243, 18186), (34243, 21922), (34243, 10530), (34243, 16792), (34243, 28978),
+    (34243, 12049), (34243, 10530), (34243, 37006), (34243, 24232), (34243, 29560),
+    (34243, 19456), (34243, 27434), (34243, 32341), (34243, 1538), (34243, 1890),
+    (34243, 24099), (34243, 32341), (34243, 18143), (34243, 10530), (34243, 37488),
+    (34243, 12049), (34243, 32341), (34243, 36970), (34243, 37040), (34243, 35844),
+    (34243, 13502), (34243, 10530), (34243, 1890), (34243, 1890), (34243, 10530),
+    (34243, 30845), (34243, 10530), (34243, 1538), (34243, 15198), (34243, 9223),
+    (34243, 36970), (34243, 1538), (34243, 36970), (34243, 29560), (34243, 31104),
+    (34243, 29560), (34243, 10530), (34243, 30226), (34243, 25259), (34243, 5947),
+    (34243, 37488), (34243, 25259), (34243, 28978), (34243, 34011), (34243, 29459),
+    (34243, 36970), (34243, 29560), (34243, 10530), (34243, 1890), (34243, 19456),
+    (34243, 28978), (34243, 1890), (34243, 10530), (34243, 10530), (34243, 34011),
+    (34243, 37040), (34243, 1890), (34243, 28978), (34243, 27434), (34243, 30845),
+    (34243, 13476), (34243, 32341), (34243, 18102), (34243, 12049), (34243, 19456),
+    (34243, 28978), (34243, 1890), (34243, 8111), (34243, 5598), (34243, 27434),
+    (34243, 37488), (34243, 1890), (34243, 32385), (34243, 1538), (34243, 34011),
+    (34243, 28978), (34243, 18436), (34243, 10530), (34243, 32341), (34243, 13502),
+    (34243, 33967), (34243, 8394), (34243, 10530), (34243, 15198), (34243, 19653),
+    (34243, 28860), (34243, 39828), (34243, 10530), (34243, 10530), (34243, 28978),
+    (34243, 10530), (34243, 18186), (34243, 26840), (34243, 29560), (34243, 37488),
+    (34243, 10530), (34243, 19653), (34243, 15198), (34243, 28514), (34243, 37040),
+    (34243, 19653), (34243, 37040), (34243, 28978), (34243, 10530), (34243, 1890),
+    (34243, 19456), (34243, 32385), (34243, 29459), (34243, 19653), (34243, 29560),
+    (34243, 26840), (34243, 19653), (34243, 19456), (34243, 13476), (34243, 31634),
+    (34243, 13476), (34243, 33967), (34243, 10530), (34243, 10530), (34243, 33967),
+    (34243, 10530), (34243, 21656), (34243, 10530), (34243, 18143), (34243, 182),
+    (34243, 18102), (34243, 24215), (34243, 28978), (34243, 1538), (34243, 28978),
+    (34243, 39538), (34243, 5836), (34243, 19456), (34243, 13476), (34243, 27035),
+    (34243, 20247), (34243, 18186), (34243, 29560), (34243, 1890), (34243, 37488),
+    (34243, 10530), (34243, 10530), (34243, 31634), (34243, 29560), (34243, 10530),
+    (34243, 32385), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 27434),
+    (34243, 1890), (34243, 28978), (34243, 29560), (34243, 13476), (34243, 39538),
+    (34243, 28978), (34243, 10530), (34243, 36970), (34243, 32341), (34243, 32255),
+    (34243, 6073), (34243, 21325), (34243, 38333), (34243, 1538), (34243, 10530),
+    (34243, 36970), (34243, 28860), (34243, 39544), (34243, 23950), (34243, 10530),
+    (34243, 19139), (34243, 16792), (34243, 15171), (34243, 10530), (34243, 20247),
+    (34243, 1890), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 1890),
+    (34243, 10530), (34243, 1538), (34243, 27434), (34243, 34011), (34243, 32341),
+    (34243, 28978), (34243, 36970), (34243, 1538), (34243, 19428), (34243, 28978),
+    (34243, 28978), (34243, 16792), (34243, 28539), (34243, 10020), (34243, 34011),
+    (34243, 1890), (34243, 13476), (34243, 25259), (34243, 38344), (34243, 28978),
+    (34243, 8111), (34243, 10530), (34243, 28978), (34243, 16137), (34243, 1890),
+    (34243, 39538), (34243, 37006), (34243, 34011), (34243, 31563), (34243, 37040),
+    (34243, 19653), (34243, 18436), (34243, 1890), (34243, 4867), (34243, 1538),
+    (34243, 29459), (34243, 18102), (34243, 10530), (34243, 19653), (34243, 16792),
+    (34243, 34011), (34243, 10530), (34243, 34011), (34243, 34011), (34243, 38344),
+    (34243, 16137), (34243, 25259), (34243, 1890), (34243, 8230), (34243, 21325),
+    (34243, 18436), (34243, 21656), (34243, 1890), (34243, 34011), (34243, 19653),
+    (34243, 10530), (34243, 6073), (34243, 10530), (34243, 29560), (34243, 34011),
+    (34243, 9932), (34243, 18186), (34243, 7418), (34243, 10530), (34243, 26873),
+    (34243, 19456), (34243, 28978), (34243, 20899), (34243, 1538), (34243, 1890),
+    (34243, 32341), (34243, 36970), (34243, 5598), (34243, 10530), (34243, 33967),
+    (34243, 1890), (34243, 21325), (34243, 1890), (34243, 28978), (34243, 28978),
+    (34243, 34011), (34243, 5789), (34243, 27434), (34243, 34011), (34243, 1890),
+    (34243, 37006), (34243, 23418), (34243, 34011), (34243, 1890), (34243, 28573),
+    (34243, 24215), (34243, 34011), (34243, 7528), (34243, 1538), (34243, 29459),
+    (34243, 10530), (34243, 29459), (34243, 19653), (34243, 28978), (34243, 32385),
+    (34243, 10530), (34243, 5598), (34243, 10530), (34243, 21325), (34243, 10530),
+    (34243, 37488), (34243, 28978), (34243, 10530), (34243, 37040), (34243, 28978),
+    (34243, 10530), (34243, 1814), (34243, 36719), (34243, 1890), (34243, 10530),
+    (34243, 10530), (34243, 24215), (34243, 10530), (34243, 24215), (34243, 28978),
+    (34243, 37768), (34243, 10530), (34243, 28978), (34243, 37488), (34243, 1890),
+    (34243, 28978), (34243, 21656), (34243, 32341), (34243, 28978), (34243, 17741),
+    (34243, 19456), (34243, 19456), (34243, 29560), (34243, 10530), (34243, 27434),
+    (34243, 28978), (34243, 35792), (34243, 18186), (34243, 37488), (34243, 38344),
+    (34243, 18186), (34243, 10530), (34243, 21325), (34243, 28978), (34243, 1675),
+    (34243, 33577), (34243, 18186), (34243, 10530), (34243, 10530), (34243, 5598),
+    (34243, 13502), (34243, 12049), (34243, 19653), (34243, 26873), (34243, 1890),
+    (34243, 28978), (34243, 13476), (34243, 25259), (34243, 10530), (34243, 32952),
+    (34243, 32385), (34243, 28978), (34243, 19456), (34243, 20014), (34243, 19456),
+    (34243, 23950), (34243, 10530), (34243, 34011), (34243, 10530), (34243, 10530),
+    (34243, 37006), (34243, 18186), (34243, 182), (34243, 10530), (34243, 18186),
+    (34243, 20247), (34243, 28978), (34243, 32385), (34243, 25098), (34243, 27434),
+    (34243, 33967), (34243, 18143), (34243, 34011), (34243, 36970), (34243, 19456),
+    (34243, 29560), (34243, 6069), (34243, 19653), (34243, 21325), (34243, 13502),
+    (34243, 28573), (34243, 27434), (34243, 19456), (34243, 34011), (34243, 10530),
+    (34243, 10530), (34243, 182), (34243, 28573), (34243, 1890), (34243, 10530),
+    (34243, 10530), (34243, 1890), (34243, 28978), (34243, 33967), (34243, 10530),
+    (34243, 12049), (34243, 37040), (34243, 10530), (34243, 32341), (34243, 10530),
+    (34243, 28978), (34243, 19456), (34243, 1890), (34243, 39538), (34243, 19139),
+    (34243, 18186), (34243, 13476), (34243, 10530), (34243, 34011), (34243, 19653),
+    (34243, 16792), (34243, 10530), (34243, 10530), (34243, 16792), (34243, 38333),
+    (34243, 34011), (34243, 16792), (34243, 13502), (34243, 33967), (34243, 33967),
+    (34243, 21325), (34243, 14304), (34243, 10530), (34243, 1890), (34243, 2700),
+    (34243, 27036), (34243, 19653), (34243, 26863), (34243, 39544), (34243, 36970),
+    (34243, 28978), (34243, 10530), (34243, 19653), (34243, 25259), (34243, 10530),
+    (34243, 32952), (34243, 34894), (34243, 32385), (34243, 36603), (34243, 32385),
+    (34243, 18186), (34243, 25259), (34243, 19456), (34243, 19456), (34243, 13476),
+    (34243, 37040), (34243, 10530), (34243, 13476), (34243, 37040), (34243, 18436),
+    (34243, 10530), (34243, 10530), (34243, 5295), (34243, 18186), (34243, 29560),
+    (34243, 1890), (34243, 10530), (34243, 5598), (34243, 16792), (34243, 28514),
+    (34243, 2700), (34243, 18551), (34243, 10530), (34243, 21922), (34243, 29560),
+    (34243, 10530), (34243, 10530), (34243, 18186), (34243, 10530), (34243, 16792),
+    (34243, 5522), (34243, 18143), (34243, 32341), (34243, 10530), (34243, 16137),
+    (34243, 28573), (34243, 10530), (34243, 22364), (34243, 34011), (34243, 1890),
+    (34243, 16137), (34243, 19456), (34243, 13476), (34243, 28978), (34243, 1890),
+    (34243, 1890), (34243, 10530), (34243, 32341), (34243, 32341), (34243, 21922),
+    (34243, 19653), (34243, 19653), (34243, 29560), (34243, 1890), (34243, 32552),
+    (34243, 32385), (34243, 29459), (34243, 29560), (34243, 32385), (34243, 10530),
+    (34243, 10530), (34243, 33941), (34243, 10530), (34243, 1890), (34243, 25259),
+    (34243, 1890), (34243, 1890), (34243, 19456), (34243, 37040), (34243, 10530),
+    (34243, 34011), (34243, 39544), (34243, 1538), (34243, 1538), (34243, 13502),
+    (34243, 19456), (34243, 34011), (34243, 18143), (34243, 18186), (34243, 27434),
+    (34243, 10530), (34243, 19653), (34243, 32385), (34243, 36551), (34243, 38344),
+    (34243, 25259), (34243, 28978), (34243, 19456), (34243, 32535), (34243, 10530),
+    (34243, 21922), (34243, 32341), (34243, 34011), (34243, 28978), (34243, 32255),
+    (34243, 33967), (34243, 5836), (34243, 10530), (34243, 8111), (34243, 34011),
+    (34243, 10530), (34243, 34011), (34243, 21922), (34243, 34011), (34243, 19456),
+    (34243, 1890), (34243, 10530), (34243, 15198), (34243, 21922), (34243, 27434),
+    (34243, 27434), (34243, 26840), (34243, 28978), (34243, 37040), (34243, 10530),
+    (34243, 19456), (34243, 1538), (34243, 10530), (34243, 19456), (34243, 5295),
+    (34243, 182), (34243, 34894), (34243, 32341), (34243, 13476), (34243, 1890),
+    (34243, 1890), (34243, 32341), (34243, 28860), (34243, 19456), (34243, 13476),
+    (34243, 10530), (34243, 19456), (34243, 11703), (34243, 34011), (34243, 10530),
+    (34243, 29459), (34243, 5598), (34243, 32255), (34243, 16792), (34243, 10530),
+    (34243, 22364), (34243, 34011), (34243, 20247), (34243, 10530), (34243, 13502),
+    (34243, 19456), (34243, 28978), (34243, 18186), (34243, 15505), (34243, 10530),
+    (34243, 20247), (34243, 18143), (34243, 1890), (34243, 25259), (34243, 1538),
+    (34243, 19456), (34243, 32341), (34243, 13476), (34243, 30845), (34243, 19653),
+    (34243, 19456), (34243, 5295), (34243, 10530), (34243, 19428), (34243, 19653),
+    (34243, 19653), (34243, 21922), (34243, 26840), (34243, 10020), (34243, 39514),
+    (34243, 16300), (34243, 21669), (34243, 28978), (34243, 18436), (34243, 37040),
+    (34243, 32385), (34243, 10530), (34243, 29459), (34243, 1890), (34243, 9781),
+    (34243, 1538), (34243, 19428), (34243, 182), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 19653), (34243, 19456), (34243, 10530), (34243, 13476),
+    (34243, 1890), (34243, 36970), (34243, 18186), (34243, 34011), (34243, 21656),
+    (34243, 1890), (34243, 20899), (34243, 28978), (34243, 39828), (34243, 29459),
+    (34243, 19456), (34243, 37488), (34243, 29560), (34243, 7587), (34243, 5598),
+    (34243, 19653), (34243, 1538), (34243, 1890), (34243, 28860), (34243, 32341),
+    (34243, 37040), (34243, 34011), (34243, 28978), (34243, 19653), (34243, 10530),
+    (34243, 18186), (34243, 27434), (34243, 19456), (34243, 28978), (34243, 31634),
+    (34243, 6073), (34243, 28978), (34243, 34011), (34243, 12049), (34243, 30845),
+    (34243, 10530), (34243, 10530), (34243, 35192), (34243, 30845), (34243, 34011),
+    (34243, 1890), (34243, 10530), (34243, 1890), (34243, 34011), (34243, 29560),
+    (34243, 38344), (34243, 28978), (34243, 10530), (34243, 10530), (34243, 1890),
+    (34243, 1890), (34243, 14304), (34243, 10530), (34243, 18436), (34243, 38813),
+    (34243, 33967), (34243, 13502), (34243, 1538), (34243, 7587), (34243, 37040),
+    (34243, 19456), (34243, 38344), (34243, 10530), (34243, 28141), (34243, 38344),
+    (34243, 29560), (34243, 28978), (34243, 34011), (34243, 33967), (34243, 12049),
+    (34243, 10530), (34243, 26863), (34243, 10530), (34243, 38333), (34243, 28978),
+    (34243, 10530), (34243, 37006), (34243, 28978), (34243, 13476), (34243, 24232),
+    (34243, 1890), (34243, 18186), (34243, 16792), (34243, 29560), (34243, 19456),
+    (34243, 28860), (34243, 34011), (34243, 1890), (34243, 19456), (34243, 19456),
+    (34243, 19653), (34243, 19456), (34243, 5598), (34243, 1890), (34243, 18186),
+    (34243, 10530), (34243, 1890), (34243, 20247), (34243, 31563), (34243, 30845),
+    (34243, 16792), (34243, 19653), (34243, 32385), (34243, 10530), (34243, 21922),
+    (34243, 1890), (34243, 16137), (34243, 18186), (34243, 2347), (34243, 32341),
+    (34243, 20247), (34243, 30845), (34243, 10530), (34243, 10530), (34243, 1538),
+    (34243, 32385), (34243, 10530), (34243, 37006), (34243, 1890), (34243, 19653),
+    (34243, 18436), (34243, 11703), (34243, 32385), (34243, 19456), (34243, 28978),
+    (34243, 34011), (34243, 10530), (34243, 10530), (34243, 32385), (34243, 32341),
+    (34243, 32535), (34243, 1890), (34243, 10530), (34243, 21656), (34243, 34894),
+    (34243, 1890), (34243, 28978), (34243, 1890), (34243, 10530), (34243, 36970),
+    (34243, 10530), (34243, 19456), (34243, 32255), (34243, 29459), (34243, 20247),
+    (34243, 32385), (34243, 34011), (34243, 30152), (34243, 38333), (34243, 10530),
+    (34243, 13476), (34243, 24232), (34243, 28978), (34243, 1538), (34243, 32341),
+    (34243, 30845), (34243, 39544), (34243, 5947), (34243, 10530), (34243, 10530),
+    (34243, 19653), (34243, 10530), (34243, 28978), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 10530), (34243, 1890), (34243, 37040), (34243, 37040),
+    (34243, 26863), (34243, 39538), (34243, 36603), (34243, 32385), (34243, 37488),
+    (34243, 15171), (34243, 1538), (34243, 28978), (34243, 19653), (34243, 16792),
+    (34243, 18186), (34243, 2700), (34243, 7528), (34243, 1890), (34243, 37488),
+    (34243, 39544), (34243, 5947), (34243, 10530), (34243, 10530), (34243, 36970),
+    (34243, 10530), (34243, 39544), (34243, 1538), (34243, 5598), (34243, 37040),
+    (34243, 13476), (34243, 29560), (34243, 1890), (34243, 10530), (34243, 23710),
+    (34243, 10530), (34243, 18186), (34243, 24232), (34243, 10530), (34243, 10771),
+    (34243, 26840), (34243, 24099), (34243, 19456), (34243, 18143), (34243, 39538),
+    (34243, 21669), (34243, 1890), (34243, 10530), (34243, 1890), (34243, 10530),
+    (34243, 34011), (34243, 28978), (34243, 10530), (34243, 19428), (34243, 32385),
+    (34243, 31634), (34243, 37040), (34243, 10530), (34243, 29560), (34243, 18186),
+    (34243, 27434), (34243, 38333), (34243, 18436), (34243, 1890), (34243, 1890),
+    (34243, 39544), (34243, 19653), (34243, 13502), (34243, 1890), (34243, 11703),
+    (34243, 19653), (34243, 13502), (34243, 37040), (34243, 36970), (34243, 34072),
+    (34243, 39538), (34243, 32535), (34243, 27434), (34243, 28978), (34243, 21325),
+    (34243, 21325), (34243, 28978), (34243, 22364), (34243, 10530), (34243, 32341),
+    (34243, 10530), (34243, 28978), (34243, 28978), (34243, 36551), (34243, 5947),
+    (34243, 10771), (34243, 1814), (34243, 5598), (34243, 28978), (34243, 10530),
+    (34243, 1538), (34243, 28978), (34243, 34011), (34243, 39544), (34243, 19456),
+    (34243, 32385), (34243, 28573), (34243, 18186), (34243, 18143), (34243, 19456),
+    (34243, 27434), (34243, 10530), (34243, 10530), (34243, 13476), (34243, 10530),
+    (34243, 32341), (34243, 20247), (34243, 13476), (34243, 1538), (34243, 18143),
+    (34243, 28978), (34243, 10530), (34243, 9781), (34243, 13476), (34243, 37006),
+    (34243, 32255), (34243, 28978), (34243, 34011), (34243, 10530), (34243, 10530),
+    (34243, 1890), (34243, 37040), (34243, 19653), (34243, 10530), (34243, 182),
+    (34243, 22364), (34243, 19653), (34243, 10530), (34243, 27434), (34243, 10530),
+    (34243, 3186), (34243, 10530), (34243, 1890), (34243, 28978), (34243, 10530),
+    (34243, 21656), (34243, 16792), (34243, 10530), (34243, 1890), (34243, 39538),
+    (34243, 1890), (34243, 10530), (34243, 22842), (34243, 20247), (34243, 10530),
+    (34243, 15198), (34243, 19428), (34243, 29560), (34243, 36970), (34243, 28978),
+    (34243, 33967), (34243, 19456), (34243, 1538), (34243, 28978), (34243, 1890),
+    (34243, 28539), (34243, 19653), (34243, 1890), (34243, 13476), (34243, 5295),
+    (34243, 19653), (34243, 15198), (34243, 10530), (34243, 3988), (34243, 34011),
+    (34243, 37040), (34243, 19653), (34243, 32341), (34243, 19653), (34243, 19456),
+    (34243, 37040), (34243, 5598), (34243, 18186), (34243, 10530), (34243, 1890),
+    (34243, 15171), (34243, 10530), (34243, 10530), (34243, 16792), (34243, 15198),
+    (34243, 12049), (34243, 28978), (34243, 19456), (34243, 21325), (34243, 36970),
+    (34243, 10530), (34243, 1890), (34243, 10530), (34243, 1814), (34243, 15198),
+    (34243, 30845), (34243, 1890), (34243, 21325), (34243, 10771), (34243, 1538),
+    (34243, 19653), (34243, 39828), (34243, 1890), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 19456), (34243, 10530), (34243, 30845), (34243, 28271),
+    (34243, 16137), (34243, 32255), (34243, 10771), (34243, 29459), (34243, 34011),
+    (34243, 10771), (34243, 10530), (34243, 19456), (34243, 39544), (34243, 36970),
+    (34243, 24232), (34243, 19456), (34243, 5598), (34243, 10530), (34243, 1890),
+    (34243, 26863), (34243, 1890), (34243, 20247), (34243, 32952), (34243, 28978),
+    (34243, 19456), (34243, 10530), (34243, 1538), (34243, 19428), (34243, 10530),
+    (34243, 28978), (34243, 10530), (34243, 10530), (34243, 19653), (34243, 1538),
+    (34243, 36970), (34243, 5598), (34243, 27434), (34243, 18186), (34243, 27434),
+    (34243, 1890), (34243, 21656), (34243, 37040), (34243, 1890), (34243, 18436),
+    (34243, 28978), (34243, 39544), (34243, 5947), (34243, 3988), (34243, 10530),
+    (34243, 26840), (34243, 1538), (34243, 27434), (34243, 23950), (34243, 10530),
+    (34243, 1890), (34243, 32255), (34243, 28978), (34243, 10530), (34243, 28573),
+    (34243, 5598), (34243, 13476), (34243, 5836), (34243, 1346), (34243, 19653),
+    (34243, 10530), (34243, 28573), (34243, 19021), (34243, 19456), (34243, 29560),
+    (34243, 20247), (34243, 18186), (34243, 37006), (34243, 10771), (34243, 10530),
+    (34243, 10530), (34243, 19428), (34243, 29560), (34243, 35792), (34243, 27035),
+    (34243, 10530), (34243, 28978), (34243, 10530), (34243, 10530), (34243, 1890),
+    (34243, 182), (34243, 1890), (34243, 19653), (34243, 1890), (34243, 3132), (34243, 34011),
+    (34243, 1890), (34243, 32341), (34243, 1890), (34243, 20247), (34243, 18102),
+    (34243, 15198), (34243, 8111), (34243, 35284), (34243, 34011), (34243, 26873),
+    (34243, 1538), (34243, 32385), (34243, 10530), (34243, 1890), (34243, 39514),
+    (34243, 13502), (34243, 10530), (34243, 21656), (34243, 34549), (34243, 1890),
+    (34243, 10530), (34243, 5598), (34243, 10530), (34243, 10530), (34243, 19456),
+    (34243, 16792), (34243, 10530), (34243, 10530), (34243, 19456), (34243, 27434),
+    (34243, 16792), (34243, 24232), (34243, 5598), (34243, 38344), (34243, 19456),
+    (34243, 26873), (34243, 18436), (34243, 27434), (34243, 8111), (34243, 32385),
+    (34243, 19653), (34243, 21656), (34243, 10530), (34243, 35123), (34243, 1538),
+    (34243, 1538), (34243, 10530), (34243, 32341), (34243, 35792), (34243, 4867),
+    (34243, 2885), (34243, 19428), (34243, 26840), (34243, 19428), (34243, 10530),
+    (34243, 10530), (34243, 10265), (34243, 1890), (34243, 5836), (34243, 12049),
+    (34243, 15832), (34243, 10530), (34243, 28573), (34243, 36603), (34243, 21656),
+    (34243, 10530), (34243, 10530), (34243, 34011), (34243, 29560), (34243, 19653),
+    (34243, 28514), (34243, 19653), (34243, 37040), (34243, 10530), (34243, 19456),
+    (34243, 37040), (34243, 20247), (34243, 34011), (34243, 16137), (34243, 32385),
+    (34243, 11703), (34243, 5947), (34243, 31634), (34243, 32255), (34243, 25259),
+    (34243, 33967), (34243, 10530), (34243, 29560), (34243, 31433), (34243, 10265),
+    (34243, 18186), (34243, 13476), (34243, 29459), (34243, 29560), (34243, 38333),
+    (34243, 10265), (34243, 19456), (34243, 37488), (34243, 18143), (34243, 32341),
+    (34243, 38333), (34243, 23418), (34243, 15198), (34243, 31634), (34243, 35284),
+    (34243, 1890), (34243, 39828), (34243, 1890), (34243, 1890), (34243, 10530),
+    (34243, 1890), (34243, 34011), (34243, 32341), (34243, 38333), (34243, 182),
+    (34243, 10530), (34243, 21325), (34243, 29459), (34243, 18436), (34243, 30845),
+    (34243, 10020), (34243, 19456), (34243, 28978), (34243, 10530), (34243, 37488),
+    (34243, 10530), (34243, 1890), (34243, 30845), (34243, 19456), (34243, 13502),
+    (34243, 18436), (34243, 19653), (34243, 19456), (34243, 32341), (34243, 36154),
+    (34243, 1890), (34243, 32341), (34243, 10530), (34243, 21656), (34243, 10530),
+    (34243, 19456), (34243, 37040), (34243, 7528), (34243, 37488), (34243, 15198),
+    (34243, 10530), (34243, 10530), (34243, 10020), (34243, 19653), (34243, 10530),
+    (34243, 22364), (34243, 10530), (34243, 30845), (34243, 19456), (34243, 1890),
+    (34243, 10530), (34243, 18143), (34243, 29560), (34243, 10530), (34243, 36970),
+    (34243, 30845), (34243, 21656), (34243, 5348), (34243, 19653), (34243, 29152),
+    (34243, 5789), (34243, 28573), (34243, 13502), (34243, 37006), (34243, 18143),
+    (34243, 34011), (34243, 32341), (34243, 16137), (34243, 32385), (34243, 19456),
+    (34243, 26863), (34243, 8111), (34243, 34011), (34243, 34011), (34243, 28978),
+    (34243, 10530), (34243, 5836), (34243, 1890), (34243, 10530), (34243, 39538),
+    (34243, 37040), (34243, 16137), (34243, 20247), (34243, 21656), (34243, 19456),
+    (34243, 27434), (34243, 32385), (34243, 10771), (34243, 8111), (34243, 32385),
+    (34243, 29459), (34243, 10530), (34243, 18586), (34243, 19456), (34243, 19456),
+    (34243, 1890), (34243, 1538), (34243, 19456), (34243, 13476), (34243, 28978),
+    (34243, 32385), (34243, 29459), (34243, 10530), (34243, 20014), (34243, 5789),
+    (34243, 10530), (34243, 21669), (34243, 1890), (34243, 39538), (34243, 10530),
+    (34243, 10530), (34243, 37488), (34243, 5598), (34243, 1890), (34243, 31433),
+    (34243, 10530), (34243, 10530), (34243, 10530), (34243, 28573), (34243, 20247),
+    (34243, 25259), (34243, 19456), (34243, 32385), (34243, 28573), (34243, 13476),
+    (34243, 10530), (34243, 27434), (34243, 29560), (34243, 10530), (34243, 18143),
+    (34243, 31104), (34243, 19456), (34243, 36551), (34243, 5836), (34243, 10530),
+    (34243, 8394), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 19653), (34243, 10530), (34243, 19653), (34243, 16792), (34243, 13502),
+    (34243, 37040), (34243, 20247), (34243, 7528), (34243, 10530), (34243, 37006),
+    (34243, 32385), (34243, 1890), (34243, 30845), (34243, 10530), (34243, 10530),
+    (34243, 37488), (34243, 10530), (34243, 6069), (34243, 19428), (34243, 30845),
+    (34243, 18102), (34243, 11703), (34243, 36970), (34243, 1890), (34243, 37040),
+    (34243, 36970), (34243, 18186), (34243, 21922), (34243, 13476), (34243, 1890),
+    (34243, 28978), (34243, 38344), (34243, 21922), (34243, 28978), (34243, 13476),
+    (34243, 32341), (34243, 10530), (34243, 37040), (34243, 19456), (34243, 1538),
+    (34243, 9781), (34243, 9781), (34243, 10530), (34243, 24232), (34243, 5598),
+    (34243, 10530), (34243, 35192), (34243, 29560), (34243, 27434), (34243, 27035),
+    (34243, 31563), (34243, 38344), (34243, 1538), (34243, 19456), (34243, 27434),
+    (34243, 24215), (34243, 5836), (34243, 18102), (34243, 28978), (34243, 25259),
+    (34243, 10265), (34243, 5295), (34243, 10530), (34243, 8111), (34243, 10020),
+    (34243, 1890), (34243, 39538), (34243, 19456), (34243, 32385), (34243, 10530),
+    (34243, 18186), (34243, 28978), (34243, 12049), (34243, 32385), (34243, 19139),
+    (34243, 10530), (34243, 34011), (34243, 10530), (34243, 7528), (34243, 28978),
+    (34243, 19456), (34243, 10530), (34243, 19653), (34243, 36970), (34243, 19653),
+    (34243, 19653), (34243, 28978), (34243, 32385), (34243, 38344), (34243, 16792),
+    (34243, 8844), (34243, 5836), (34243, 32341), (34243, 28978), (34243, 10530),
+    (34243, 34072), (34243, 10530), (34243, 18186), (34243, 1890), (34243, 10530),
+    (34243, 36970), (34243, 32385), (34243, 24232), (34243, 28978), (34243, 7528),
+    (34243, 21656), (34243, 31563), (34243, 13476), (34243, 10530), (34243, 19456),
+    (34243, 10530), (34243, 31433), (34243, 13502), (34243, 34011), (34243, 37040),
+    (34243, 10530), (34243, 26863), (34243, 1890), (34243, 16792), (34243, 38333),
+    (34243, 13476), (34243, 28978), (34243, 37040), (34243, 4867), (34243, 8111),
+    (34243, 28978), (34243, 2700), (34243, 1890), (34243, 37040), (34243, 37040),
+    (34243, 20247), (34243, 37006), (34243, 1890), (34243, 34011), (34243, 37040),
+    (34243, 28978), (34243, 10530), (34243, 21325), (34243, 11703), (34243, 27035),
+    (34243, 27434), (34243, 10530), (34243, 10530), (34243, 36603), (34243, 34011),
+    (34243, 30845), (34243, 20247), (34243, 20247), (34243, 21656), (34243, 38344),
+    (34243, 39828), (34243, 28978), (34243, 9781), (34243, 10530), (34243, 19653),
+    (34243, 28978), (34243, 5598), (34243, 10530), (34243, 35792), (34243, 13476),
+    (34243, 28978), (34243, 20247), (34243, 34072), (34243, 28978), (34243, 28978),
+    (34243, 37006), (34243, 18436), (34243, 19456), (34243, 3132), (34243, 34011),
+    (34243, 10530), (34243, 25259), (34243, 13476), (34243, 13476), (34243, 31433),
+    (34243, 18143), (34243, 19653), (34243, 31634), (34243, 19456), (34243, 10530),
+    (34243, 32341), (34243, 12049), (34243, 8111), (34243, 1890), (34243, 5598),
+    (34243, 10265), (34243, 18186), (34243, 36970), (34243, 31634), (34243, 16792),
+    (34243, 10530), (34243, 10530), (34243, 19456), (34243, 10530), (34243, 20247),
+    (34243, 39538), (34243, 10530), (34243, 21656), (34243, 10530), (34243, 19456),
+    (34243, 10530), (34243, 1538), (34243, 10530), (34243, 10530), (34243, 37488),
+    (34243, 39544), (34243, 10530), (34243, 32341), (34243, 1675), (34243, 22720),
+    (34243, 37040), (34243, 21325), (34243, 28978), (34243, 21656), (34243, 25259),
+    (34243, 19653), (34243, 17813), (34243, 30845), (34243, 28978), (34243, 1538),
+    (34243, 19456), (34243, 10530), (34243, 37040), (34243, 32952), (34243, 19456),
+    (34243, 10530), (34243, 19653), (34243, 1814), (34243, 18102), (34243, 19653),
+    (34243, 1538), (34243, 37040), (34243, 38344), (34243, 28978), (34243, 10530),
+    (34243, 15171), (34243, 19653), (34243, 32385), (34243, 1814), (34243, 11703),
+    (34243, 8111), (34243, 4867), (34243, 25259), (34243, 11703), (34243, 1890),
+    (34243, 20247), (34243, 19456), (34243, 28978), (34243, 34011), (34243, 32341),
+    (34243, 1890), (34243, 24232), (34243, 20247), (34243, 18143), (34243, 10020),
+    (34243, 10530), (34243, 21922), (34243, 10530), (34243, 5836), (34243, 19456),
+    (34243, 19653), (34243, 38333), (34243, 32341), (34243, 19653), (34243, 1538),
+    (34243, 10530), (34243, 39538), (34243, 25039), (34243, 4867), (34243, 18186),
+    (34243, 1890), (34243, 28860), (34243, 21656), (34243, 10530), (34243, 10530),
+    (34243, 1890), (34243, 10530), (34243, 28860), (34243, 1814), (34243, 19456),
+    (34243, 5598), (34243, 10530), (34243, 15198), (34243, 28978), (34243, 10530),
+    (34243, 19653), (34243, 1538), (34243, 36719), (34243, 12049), (34243, 38333),
+    (34243, 10530), (34243, 21656), (34243, 7461), (34243, 1890), (34243, 10530),
+    (34243, 13502), (34243, 10530), (34243, 19456), (34243, 31786), (34243, 10530),
+    (34243, 1890), (34243, 10530), (34243, 1890), (34243, 18186), (34243, 38344),
+    (34243, 37006), (34243, 1538), (34243, 19456), (34243, 38333), (34243, 5947),
+    (34243, 1912), (34243, 25259), (34243, 7461), (34243, 19653), (34243, 34011),
+    (34243, 10530), (34243, 18436), (34243, 36970), (34243, 10530), (34243, 37040),
+    (34243, 19456), (34243, 28978), (34243, 182), (34243, 10530), (34243, 32341),
+    (34243, 10530), (34243, 19653), (34243, 28978), (34243, 21325), (34243, 10530),
+    (34243, 27434), (34243, 28978), (34243, 10530), (34243, 10530), (34243, 26840),
+    (34243, 28573), (34243, 36970), (34243, 1890), (34243, 10530), (34243, 24232),
+    (34243, 30845), (34243, 39544), (34243, 10530), (34243, 36551), (34243, 1814),
+    (34243, 10530), (34243, 1538), (34243, 28978), (34243, 24232), (34243, 28978),
+    (34243, 32385), (34243, 13502), (34243, 18186), (34243, 28978), (34243, 28860),
+    (34243, 36970), (34243, 29560), (34243, 10530), (34243, 5598), (34243, 21656),
+    (34243, 1890), (34243, 27434), (34243, 37006), (34243, 1890), (34243, 21325),
+    (34243, 28978), (34243, 27434), (34243, 24099), (34243, 5598), (34243, 15198),
+    (34243, 4205), (34243, 28978), (34243, 23710), (34243, 28978), (34243, 30845),
+    (34243, 5598), (34243, 10530), (34243, 18436), (34243, 28978), (34243, 10530),
+    (34243, 1890), (34243, 182), (34243, 32385), (34243, 9749), (34243, 29560),
+    (34243, 28978), (34243, 18186), (34243, 35284), (34243, 32385), (34243, 18143),
+    (34243, 10530), (34243, 26840), (34243, 21325), (34243, 19653), (34243, 13476),
+    (34243, 32341), (34243, 10020), (34243, 10530), (34243, 20247), (34243, 18186),
+    (34243, 37040), (34243, 7528), (34243, 38344), (34243, 19456), (34243, 29560),
+    (34243, 22364), (34243, 1890), (34243, 9932), (34243, 29459), (34243, 27035),
+    (34243, 28978), (34243, 19653), (34243, 1890), (34243, 19456), (34243, 34011),
+    (34243, 10530), (34243, 25259), (34243, 37006), (34243, 25039), (34243, 27434),
+    (34243, 26840), (34243, 1538), (34243, 21325), (34243, 27434), (34243, 39544),
+    (34243, 18436), (34243, 25259), (34243, 16137), (34243, 1890), (34243, 27434),
+    (34243, 1890), (34243, 30845), (34243, 1538), (34243, 28978), (34243, 35792),
+    (34243, 24099), (34243, 9932), (34243, 1890), (34243, 1890), (34243, 13502),
+    (34243, 16792), (34243, 1890), (34243, 28514), (34243, 28978), (34243, 20899),
+    (34243, 13476), (34243, 19653), (34243, 10530), (34243, 19653), (34243, 37006),
+    (34243, 17813), (34243, 10530), (34243, 28978), (34243, 28978), (34243, 13476),
+    (34243, 1890), (34243, 10530), (34243, 33967), (34243, 29560), (34243, 35844),
+    (34243, 29560), (34243, 10530), (34243, 1890), (34243, 18991), (34243, 30845),
+    (34243, 10530), (34243, 10530), (34243, 1890), (34243, 37040), (34243, 31634),
+    (34243, 10530), (34243, 19456), (34243, 37488), (34243, 10530), (34243, 21656),
+    (34243, 12049), (34243, 19653), (34243, 21325), (34243, 37040), (34243, 37040),
+    (34243, 39538), (34243, 28539), (34243, 15198), (34243, 5836), (34243, 10530),
+    (34243, 35792), (34243, 34072), (34243, 19456), (34243, 32341), (34243, 37040),
+    (34243, 12049), (34243, 18436), (34243, 12049), (34243, 19653), (34243, 18436),
+    (34243, 21656), (34243, 21656), (34243, 37488), (34243, 5348), (34243, 19653),
+    (34243, 7528), (34243, 10530), (34243, 32341), (34243, 18186), (34243, 13476),
+    (34243, 1890), (34243, 5947), (34243, 24232), (34243, 10530), (34243, 30845),
+    (34243, 21325), (34243, 11703), (34243, 28978), (34243, 21656), (34243, 39538),
+    (34243, 34011), (34243, 22364), (34243, 12049), (34243, 32385), (34243, 21325),
+    (34243, 37488), (34243, 1538), (34243, 34011), (34243, 1538), (34243, 1890),
+    (34243, 19456), (34243, 18186), (34243, 1890), (34243, 38344), (34243, 10530),
+    (34243, 12049), (34243, 28978), (34243, 16792), (34243, 1890), (34243, 31634),
+    (34243, 1890), (34243, 18436), (34243, 10530), (34243, 10530), (34243, 13502),
+    (34243, 25259), (34243, 33967), (34243, 30845), (34243, 19456), (34243, 10530),
+    (34243, 18300), (34243, 10771), (34243, 34011), (34243, 32385), (34243, 32341),
+    (34243, 15505), (34243, 30845), (34243, 10530), (34243, 1890), (34243, 7587),
+    (34243, 38344), (34243, 1814), (34243, 3132), (34243, 27434), (34243, 1890),
+    (34243, 6073), (34243, 1890), (34243, 19456), (34243, 37040), (34243, 1538),
+    (34243, 10530), (34243, 10530), (34243, 32341), (34243, 34011), (34243, 32385),
+    (34243, 28978), (34243, 31634), (34243, 10530), (34243, 15198), (34243, 28978),
+    (34243, 15171), (34243, 10530), (34243, 32385), (34243, 35284), (34243, 10020),
+    (34243, 32385), (34243, 25259), (34243, 2766), (34243, 34011), (34243, 15198),
+    (34243, 1890), (34243, 1538), (34243, 26873), (34243, 21325), (34243, 32341),
+    (34243, 24232), (34243, 5212), (34243, 13476), (34243, 32385), (34243, 37040),
+    (34243, 20899), (34243, 33967), (34243, 32385), (34243, 10530), (34243, 1890),
+    (34243, 13476), (34243, 10530), (34243, 34011), (34243, 26840), (34243, 20247),
+    (34243, 37488), (34243, 10530), (34243, 19653), (34243, 19653), (34243, 32385),
+    (34243, 10530), (34243, 10530), (34243, 1890), (34243, 28978), (34243, 10530),
+    (34243, 28978), (34243, 4102), (34243, 20247), (34243, 10530), (34243, 28978),
+    (34243, 34011), (34243, 10530), (34243, 2407), (34243, 37040), (34243, 10530),
+    (34243, 18186), (34243, 13502), (34243, 1890), (34243, 32385), (34243, 34011),
+    (34243, 5598), (34243, 10530), (34243, 27036), (34243, 8111), (34243, 21925),
+    (34243, 37040), (34243, 31634), (34243, 15198), (34243, 37040), (34243, 19653),
+    (34243, 29560), (34243, 10771), (34243, 17741), (34243, 2700), (34243, 28978),
+    (34243, 19653), (34243, 16300), (34243, 27434), (34243, 35844), (34243, 13476),
+    (34243, 29560), (34243, 19456), (34243, 10530), (34243, 34011), (34243, 37040),
+    (34243, 10530), (34243, 16792), (34243, 27434), (34243, 7528), (34243, 10530),
+    (34243, 32341), (34243, 5598), (34243, 19653), (34243, 36970), (34243, 10530),
+    (34243, 10530), (34243, 10530), (34243, 28978), (34243, 10530), (34243, 10530),
+    (34243, 31634), (34243, 19428), (34243, 10530), (34243, 24215), (34243, 21325),
+    (34243, 32341), (34243, 32063), (34243, 10530), (34243, 7571), (34243, 1890),
+    (34243, 21325), (34243, 1890), (34243, 32385), (34243, 15171), (34243, 16137),
+    (34243, 30364), (34243, 20247), (34243, 18436), (34243, 39538), (34243, 28573),
+    (34243, 21922), (34243, 5836), (34243, 21656), (34243, 1538), (34243, 10530),
+    (34243, 28978), (34243, 30845), (34243, 19653), (34243, 20247), (34243, 13476),
+    (34243, 39544), (34243, 1890), (34243, 16792), (34243, 1890), (34243, 10530),
+    (34243, 25259), (34243, 28978), (34243, 19653), (34243, 10530), (34243, 19653),
+    (34243, 18143), (34243, 29560), (34243, 28860), (34243, 32385), (34243, 2700),
+    (34243, 29459), (34243, 24215), (34243, 10530), (34243, 30845), (34243, 32385),
+    (34243, 13476), (34243, 6073), (34243, 10530), (34243, 18186), (34243, 10530),
+    (34243, 1890), (34243, 19653), (34243, 10530), (34243, 28978), (34243, 32385),
+    (34243, 1538), (34243, 19456), (34243, 10530), (34243, 10265), (34243, 19653),
+    (34243, 28978), (34243, 26840), (34243, 1890), (34243, 32385), (34243, 21656),
+    (34243, 1890), (34243, 32385), (34243, 37040), (34243, 37040), (34243, 5947),
+    (34243, 30364), (34243, 853), (34243, 20247), (34243, 8111), (34243, 1890),
+    (34243, 35844), (34243, 36603), (34243, 10530), (34243, 16137), (34243, 34011),
+    (34243, 10530), (34243, 1890), (34243, 5598), (34243, 10530), (34243, 30845),
+    (34243, 1538), (34243, 18143), (34243, 10530), (34243, 1890), (34243, 39828),
+    (34243, 19456), (34243, 35792), (34243, 30152), (34243, 39544), (34243, 35792),
+    (34243, 34011), (34243, 35792), (34243, 21922), (34243, 32385), (34243, 10530),
+    (34243, 19653), (34243, 37488), (34243, 32385), (34243, 32341), (34243, 28860),
+    (34243, 36970), (34243, 27434), (34243, 10530), (34243, 32385), (34243, 29560),
+    (34243, 32385), (34243, 10530), (34243, 38333), (34243, 1890), (34243, 33967),
+    (34243, 19428), (34243, 13476), (34243, 32385), (34243, 35192), (34243, 37006),
+    (34243, 13139), (34243, 1890), (34243, 37040), (34243, 10265), (34243, 32535),
+    (34243, 2592), (34243, 19653), (34243, 38344), (34243, 13476), (34243, 32385),
+    (34243, 1890), (34243, 15505), (34243, 5789), (34243, 9223), (34243, 30845),
+    (34243, 25259), (34243, 10618), (34243, 1890), (34243, 13476), (34243, 34072),
+    (34243, 10530), (34243, 31634), (34243, 19653), (34243, 10530), (34243, 39544),
+    (34243, 10530), (34243, 22720), (34243, 10530), (34243, 18436), (34243, 5348),
+    (34243, 19456), (34243, 28978), (34243, 28860), (34243, 28860), (34243, 28978),
+    (34243, 21656), (34243, 15198), (34243, 10530), (34243, 1538), (34243, 21922),
+    (34243, 21325), (34243, 1912), (34243, 28978), (34243, 16792), (34243, 37488),
+    (34243, 34011), (34243, 39538), (34243, 35844), (34243, 10530), (34243, 19456),
+    (34243, 29560), (34243, 19456), (34243, 29459), (34243, 26873), (34243, 29560),
+    (34243, 38344), (34243, 29302), (34243, 37488), (34243, 28978), (34243, 26863),
+    (34243, 32952), (34243, 29459), (34243, 26863), (34243, 19456), (34243, 11703),
+    (34243, 28978), (34243, 19456), (34243, 37006), (34243, 35123), (34243, 38344),
+    (34243, 5836), (34243, 21922), (34243, 13502), (34243, 34683), (34243, 19456),
+    (34243, 10530), (34243, 38333), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 10265), (34243, 5212), (34243, 19428), (34243, 14304), (34243, 28978),
+    (34243, 37040), (34243, 30845), (34243, 19456), (34243, 10530), (34243, 10530),
+    (34243, 19456), (34243, 20247), (34243, 5947), (34243, 1890), (34243, 10530),
+    (34243, 21656), (34243, 19456), (34243, 32255), (34243, 18991), (34243, 1538),
+    (34243, 15319), (34243, 11590), (34243, 28978), (34243, 28860), (34243, 21656),
+    (34243, 19456), (34243, 29560), (34243, 21656), (34243, 20247), (34243, 10530),
+    (34243, 33967), (34243, 10530), (34243, 19456), (34243, 16792), (34243, 37040),
+    (34243, 38333), (34243, 10530), (34243, 21481), (34243, 1538), (34243, 20247),
+    (34243, 10530), (34243, 28978), (34243, 34011), (34243, 21656), (34243, 39538),
+    (34243, 33967), (34243, 18991), (34243, 28978), (34243, 5348), (34243, 15171),
+    (34243, 39538), (34243, 21922), (34243, 10530), (34243, 13502), (34243, 19456),
+    (34243, 1890), (34243, 15198), (34243, 10530), (34243, 18143), (34243, 30845),
+    (34243, 1890), (34243, 13502), (34243, 30845), (34243, 30845), (34243, 35792),
+    (34243, 19653), (34243, 27036), (34243, 8844), (34243, 29560), (34243, 27434),
+    (34243, 13476), (34243, 38333), (34243, 10530), (34243, 28978), (34243, 1538),
+    (34243, 18186), (34243, 39828), (34243, 5295), (34243, 13476), (34243, 18436),
+    (34243, 13476), (34243, 10530), (34243, 32341), (34243, 10530), (34243, 28978),
+    (34243, 16792), (34243, 5212), (34243, 34011), (34243, 19653), (34243, 34011),
+    (34243, 10771), (34243, 19456), (34243, 32385), (34243, 10530), (34243, 13476),
+    (34243, 19653), (34243, 10530), (34243, 20899), (34243, 10265), (34243, 13476),
+    (34243, 30845), (34243, 182), (34243, 5598), (34243, 26840), (34243, 10530),
+    (34243, 19456), (34243, 19653), (34243, 36970), (34243, 5598), (34243, 10530),
+    (34243, 38878), (34243, 21656), (34243, 32341), (34243, 38333), (34243, 1538),
+    (34243, 18436), (34243, 18436), (34243, 18143), (34243, 36970), (34243, 28514),
+    (34243, 32341), (34243, 10530), (34243, 21325), (34243, 40), (34243, 1890),
+    (34243, 29560), (34243, 27434), (34243, 28978), (34243, 28978), (34243, 4867),
+    (34243, 32385), (34243, 18436), (34243, 182), (34243, 13476), (34243, 32341),
+    (34243, 36603), (34243, 28978), (34243, 10530), (34243, 10530), (34243, 34011),
+    (34243, 1890), (34243, 10530), (34243, 1814), (34243, 37040), (34243, 10020),
+    (34243, 10530), (34243, 10530), (34243, 1538), (34243, 32341), (34243, 5789),
+    (34243, 10530), (34243, 35192), (34243, 34011), (34243, 16792), (34243, 10530),
+    (34243, 28978), (34243, 5598), (34243, 1538), (34243, 28978), (34243, 24232),
+    (34243, 36970), (34243, 10530), (34243, 10530), (34243, 28860), (34243, 38344),
+    (34243, 13476), (34243, 19456), (34243, 37040), (34243, 28978), (34243, 28978),
+    (34243, 1538), (34243, 28978), (34243, 13476), (34243, 2700), (34243, 25039),
+    (34243, 35192), (34243, 37006), (34243, 19653), (34243, 20014), (34243, 19653),
+    (34243, 1538), (34243, 1890), (34243, 21922), (34243, 35792), (34243, 27434),
+    (34243, 32385), (34243, 1890), (34243, 20247), (34243, 32385), (34243, 10530),
+    (34243, 10530), (34243, 15198), (34243, 18551), (34243, 27035), (34243, 30845),
+    (34243, 24232), (34243, 19456), (34243, 18551), (34243, 1890), (34243, 10530),
+    (34243, 19653), (34243, 34011), (34243, 17813), (34243, 18102), (34243, 16792),
+    (34243, 19456), (34243, 29560), (34243, 27434), (34243, 37040), (34243, 27434),
+    (34243, 10530), (34243, 10530), (34243, 37488), (34243, 16792), (34243, 32385),
+    (34243, 10530), (34243, 30845), (34243, 34011), (34243, 19456), (34243, 10530),
+    (34243, 19456), (34243, 19456), (34243, 16611), (34243, 27035), (34243, 29560),
+    (34243, 19456), (34243, 22364), (34243, 24232), (34243, 27434), (34243, 13502),
+    (34243, 39828), (34243, 13476), (34243, 19653), (34243, 32341), (34243, 10530),
+    (34243, 18436), (34243, 10530), (34243, 29560), (34243, 29459), (34243, 1890),
+    (34243, 32341), (34243, 10530), (34243, 9932), (34243, 18186), (34243, 18186),
+    (34243, 8111), (34243, 28978), (34243, 21656), (34243, 28978), (34243, 21656),
+    (34243, 37488), (34243, 1890), (34243, 27035), (34243, 28978), (34243, 29459),
+    (34243, 21325), (34243, 10530), (34243, 1890), (34243, 34011), (34243, 19456),
+    (34243, 1890), (34243, 10530), (34243, 6069), (34243, 36970), (34243, 36551),
+    (34243, 19456), (34243, 4867), (34243, 11009), (34243, 35844), (34243, 35792),
+    (34243, 15893), (34243, 19428), (34243, 22842), (34243, 18186), (34243, 28978),
+    (34243, 24215), (34243, 34011), (34243, 30845), (34243, 12049), (34243, 27434),
+    (34243, 25259), (34243, 10530), (34243, 28860), (34243, 9781), (34243, 32341),
+    (34243, 28978), (34243, 10530), (34243, 18186), (34243, 29459), (34243, 20247),
+    (34243, 10530), (34243, 10530), (34243, 38333), (34243, 18186), (34243, 37040),
+    (34243, 28978), (34243, 34011), (34243, 18186), (34243, 34011), (34243, 10530),
+    (34243, 37006), (34243, 4377), (34243, 13476), (34243, 21669), (34243, 37040),
+    (34243, 30845), (34243, 13502), (34243, 27434), (34243, 10530), (34243, 1538),
+    (34243, 18991), (34243, 36551), (34243, 10530), (34243, 21669), (34243, 28860),
+    (34243, 19456), (34243, 25259), (34243, 16300), (34243, 19653), (34243, 1890),
+    (34243, 10530), (34243, 27035), (34243, 28978), (34243, 13502), (34243, 5598),
+    (34243, 18143), (34243, 10530), (34243, 20247), (34243, 25098), (34243, 10530),
+    (34243, 16792), (34243, 29459), (34243, 1890), (34243, 10530), (34243, 1890),
+    (34243, 10530), (34243, 18102), (34243, 39544), (34243, 10530), (34243, 36154),
+    (34243, 10618), (34243, 28978), (34243, 18186), (34243, 20247), (34243, 10530),
+    (34243, 10530), (34243, 39544), (34243, 2592), (34243, 29560), (34243, 16137),
+    (34243, 32255), (34243, 13502), (34243, 37040), (34243, 24232), (34243, 13476),
+    (34243, 1890), (34243, 1538), (34243, 13476), (34243, 18991), (34243, 32341),
+    (34243, 13502), (34243, 32385), (34243, 28978), (34243, 36970), (34243, 19456),
+    (34243, 21325), (34243, 1890), (34243, 1890), (34243, 37040), (34243, 31104),
+    (34243, 32385), (34243, 13502), (34243, 10530), (34243, 19456), (34243, 10265),
+    (34243, 25039), (34243, 28978), (34243, 18143), (34243, 18436), (34243, 21922),
+    (34243, 18551), (34243, 32385), (34243, 28978), (34243, 19456), (34243, 28978),
+    (34243, 34011), (34243, 21922), (34243, 18186), (34243, 18143), (34243, 38344),
+    (34243, 10530), (34243, 34011), (34243, 37040), (34243, 32952), (34243, 1890),
+    (34243, 19456), (34243, 1890), (34243, 19653), (34243, 33967), (34243, 13502),
+    (34243, 19653), (34243, 23950), (34243, 10530), (34243, 10020), (34243, 29459),
+    (34243, 16792), (34243, 28978), (34243, 26873), (34243, 32341), (34243, 18436),
+    (34243, 32255), (34243, 1890), (34243, 34889), (34243, 1890), (34243, 37488),
+    (34243, 32385), (34243, 18436), (34243, 28978), (34243, 15198), (34243, 2347),
+    (34243, 37040), (34243, 30845), (34243, 13502), (34243, 2242), (34243, 28860),
+    (34243, 35844), (34243, 13476), (34243, 30845), (34243, 31563), (34243, 37040),
+    (34243, 10530), (34243, 5295), (34243, 38333), (34243, 5598), (34243, 10530),
+    (34243, 10530), (34243, 30845), (34243, 30845), (34243, 16792), (34243, 39544),
+    (34243, 36970), (34243, 19653), (34243, 23950), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 36154), (34243, 28978), (34243, 28978), (34243, 34011),
+    (34243, 1890), (34243, 28573), (34243, 28514), (34243, 21325), (34243, 18186),
+    (34243, 20247), (34243, 10530), (34243, 1890), (34243, 19456), (34243, 37488),
+    (34243, 19653), (34243, 16792), (34243, 19456), (34243, 1890), (34243, 10530),
+    (34243, 10530), (34243, 10530), (34243, 28978), (34243, 19653), (34243, 19653),
+    (34243, 20247), (34243, 25259), (34243, 28978), (34243, 10530), (34243, 27434),
+    (34243, 36154), (34243, 29560), (34243, 1538), (34243, 10530), (34243, 10530),
+    (34243, 33967), (34243, 10530), (34243, 28539), (34243, 8394), (34243, 13476),
+    (34243, 10530), (34243, 10530), (34243, 23212), (34243, 36970), (34243, 1538),
+    (34243, 37488), (34243, 28514), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 34011), (34243, 5947), (34243, 10530), (34243, 26840), (34243, 18436),
+    (34243, 30845), (34243, 28978), (34243, 10530), (34243, 1538), (34243, 18991),
+    (34243, 26840), (34243, 10530), (34243, 27434), (34243, 10530), (34243, 10530),
+    (34243, 5598), (34243, 1890), (34243, 5212), (34243, 30845), (34243, 25039),
+    (34243, 1890), (34243, 10530), (34243, 18991), (34243, 18436), (34243, 39828),
+    (34243, 32385), (34243, 6069), (34243, 9932), (34243, 29459), (34243, 13476),
+    (34243, 28300), (34243, 8111), (34243, 20247), (34243, 37040), (34243, 10530),
+    (34243, 34011), (34243, 21481), (34243, 10530), (34243, 36154), (34243, 19971),
+    (34243, 1890), (34243, 39544), (34243, 32341), (34243, 10530), (34243, 29459),
+    (34243, 18143), (34243, 32341), (34243, 29560), (34243, 28978), (34243, 19456),
+    (34243, 13502), (34243, 20247), (34243, 28978), (34243, 18186), (34243, 32063),
+    (34243, 13476), (34243, 18143), (34243, 32063), (34243, 8844), (34243, 6069),
+    (34243, 28978), (34243, 34011), (34243, 10530), (34243, 1538), (34243, 28978),
+    (34243, 1890), (34243, 10530), (34243, 19653), (34243, 28978), (34243, 34011),
+    (34243, 17741), (34243, 10530), (34243, 36970), (34243, 39514), (34243, 1538),
+    (34243, 10530), (34243, 5836), (34243, 36970), (34243, 32341), (34243, 19456),
+    (34243, 21656), (34243, 10530), (34243, 10530), (34243, 13476), (34243, 10265),
+    (34243, 13476), (34243, 13502), (34243, 5598), (34243, 1890), (34243, 5789),
+    (34243, 3186), (34243, 29459), (34243, 13476), (34243, 1538), (34243, 28978),
+    (34243, 1890), (34243, 18102), (34243, 30845), (34243, 10530), (34243, 19653),
+    (34243, 16792), (34243, 34894), (34243, 10530), (34243, 13502), (34243, 21656),
+    (34243, 21325), (34243, 19653), (34243, 35792), (34243, 20247), (34243, 10530),
+    (34243, 30845), (34243, 19653), (34243, 10530), (34243, 10530), (34243, 28978),
+    (34243, 1538), (34243, 10530), (34243, 28978), (34243, 10530), (34243, 1890),
+    (34243, 21325), (34243, 19653), (34243, 21669), (34243, 10530), (34243, 39544),
+    (34243, 5598), (34243, 34011), (34243, 29459), (34243, 38333), (34243, 16137),
+    (34243, 29459), (34243, 19456), (34243, 32385), (34243, 36970), (34243, 37488),
+    (34243, 39538), (34243, 19456), (34243, 18436), (34243, 34011), (34243, 4867),
+    (34243, 32385), (34243, 10530), (34243, 8394), (34243, 27434), (34243, 29560),
+    (34243, 38813), (34243, 26863), (34243, 19456), (34243, 27035), (34243, 13476),
+    (34243, 38344), (34243, 27434), (34243, 39538), (34243, 29560), (34243, 39544),
+    (34243, 19428), (34243, 15198), (34243, 34011), (34243, 30226), (34243, 1890),
+    (34243, 34072), (34243, 1890), (34243, 10530), (34243, 20247), (34243, 32063),
+    (34243, 18186), (34243, 27434), (34243, 16137), (34243, 10530), (34243, 1890),
+    (34243, 10530), (34243, 1890), (34243, 38813), (34243, 35844), (34243, 32255),
+    (34243, 27434), (34243, 10530), (34243, 24232), (34243, 28835), (34243, 13476),
+    (34243, 28978), (34243, 1814), (34243, 28978), (34243, 36970), (34243, 34011),
+    (34243, 28978), (34243, 27434), (34243, 12049), (34243, 1890), (34243, 13502),
+    (34243, 28978), (34243, 1538), (34243, 32255), (34243, 5836), (34243, 32341),
+    (34243, 13502), (34243, 27434), (34243, 10530), (34243, 18143), (34243, 10530),
+    (34243, 10530), (34243, 4867), (34243, 10530), (34243, 32341), (34243, 1890),
+    (34243, 8111), (34243, 18143), (34243, 18186), (34243, 2700), (34243, 18186),
+    (34243, 10530), (34243, 32385), (34243, 10530), (34243, 29459), (34243, 19653),
+    (34243, 13476), (34243, 1890), (34243, 21325), (34243, 10530), (34243, 29560),
+    (34243, 37006), (34243, 30152), (34243, 16792), (34243, 10530), (34243, 37488),
+    (34243, 28978), (34243, 27035), (34243, 28860), (34243, 35284), (34243, 37040),
+    (34243, 1538), (34243, 1890), (34243, 20247), (34243, 21922), (34243, 18102),
+    (34243, 21669), (34243, 27434), (34243, 1912), (34243, 13476), (34243, 10530),
+    (34243, 1538), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 37040),
+    (34243, 38344), (34243, 15505), (34243, 37040), (34243, 30152), (34243, 21656),
+    (34243, 27434), (34243, 32385), (34243, 37040), (34243, 19653), (34243, 24215),
+    (34243, 10530), (34243, 10530), (34243, 19456), (34243, 28514), (34243, 10530),
+    (34243, 5947), (34243, 6069), (34243, 28978), (34243, 32341), (34243, 38344),
+    (34243, 34011), (34243, 19456), (34243, 27434), (34243, 1538), (34243, 21325),
+    (34243, 10530), (34243, 32535), (34243, 29560), (34243, 29560), (34243, 6069),
+    (34243, 6069), (34243, 13502), (34243, 10530), (34243, 18143), (34243, 10530),
+    (34243, 10530), (34243, 19456), (34243, 24232), (34243, 1890), (34243, 5598),
+    (34243, 28978), (34243, 35844), (34243, 10530), (34243, 34011), (34243, 18186),
+    (34243, 36603), (34243, 32341), (34243, 5152), (34243, 18436), (34243, 37040),
+    (34243, 18102), (34243, 15198), (34243, 15198), (34243, 1538), (34243, 22364),
+    (34243, 29459), (34243, 28978), (34243, 10530), (34243, 5598), (34243, 32385),
+    (34243, 1890), (34243, 39544), (34243, 18991), (34243, 21710), (34243, 27434),
+    (34243, 38333), (34243, 1890), (34243, 37006), (34243, 29560), (34243, 37040),
+    (34243, 1538), (34243, 19653), (34243, 29560), (34243, 39538), (34243, 10020),
+    (34243, 27035), (34243, 10530), (34243, 18436), (34243, 28978), (34243, 21922),
+    (34243, 27035), (34243, 39544), (34243, 37006), (34243, 18186), (34243, 19456),
+    (34243, 32341), (34243, 16792), (34243, 10530), (34243, 1890), (34243, 1538),
+    (34243, 12049), (34243, 34894), (34243, 28978), (34243, 5947), (34243, 13476),
+    (34243, 32952), (34243, 1890), (34243, 35284), (34243, 29526), (34243, 27434),
+    (34243, 11703), (34243, 35792), (34243, 1890), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 19456), (34243, 27035), (34243, 23710), (34243, 1538),
+    (34243, 10530), (34243, 5295), (34243, 21656), (34243, 28300), (34243, 16137),
+    (34243, 28860), (34243, 19456), (34243, 28539), (34243, 19456), (34243, 32385),
+    (34243, 32385), (34243, 15198), (34243, 29560), (34243, 10530), (34243, 20247),
+    (34243, 10530), (34243, 18143), (34243, 19456), (34243, 39538), (34243, 22364),
+    (34243, 1890), (34243, 38333), (34243, 10530), (34243, 9781), (34243, 10020),
+    (34243, 32385), (34243, 38344), (34243, 10530), (34243, 34011), (34243, 1890),
+    (34243, 21325), (34243, 37040), (34243, 5598), (34243, 19653), (34243, 24215),
+    (34243, 28860), (34243, 34683), (34243, 5522), (34243, 34011), (34243, 10020),
+    (34243, 19653), (34243, 32385), (34243, 1890), (34243, 10530), (34243, 27035),
+    (34243, 18436), (34243, 10530), (34243, 37488), (34243, 27036), (34243, 16792),
+    (34243, 18991), (34243, 5295), (34243, 7528), (34243, 182), (34243, 18551), (34243, 1890),
+    (34243, 18143), (34243, 1890), (34243, 16792), (34243, 23710), (34243, 32255),
+    (34243, 36970), (34243, 32952), (34243, 13476), (34243, 5947), (34243, 28860),
+    (34243, 35792), (34243, 30845), (34243, 37006), (34243, 37006), (34243, 10530),
+    (34243, 32385), (34243, 37488), (34243, 29459), (34243, 1890), (34243, 10530),
+    (34243, 1890), (34243, 10530), (34243, 28573), (34243, 19653), (34243, 31104),
+    (34243, 10530), (34243, 39544), (34243, 39828), (34243, 26840), (34243, 37040),
+    (34243, 1890), (34243, 21325), (34243, 31104), (34243, 19456), (34243, 28978),
+    (34243, 27434), (34243, 32385), (34243, 16300), (34243, 38333), (34243, 4377),
+    (34243, 10530), (34243, 10530), (34243, 10530), (34243, 28978), (34243, 33967),
+    (34243, 16137), (34243, 13476), (34243, 34011), (34243, 10530), (34243, 1890),
+    (34243, 21325), (34243, 10530), (34243, 12049), (34243, 32341), (34243, 27434),
+    (34243, 19456), (34243, 16137), (34243, 35792), (34243, 1890), (34243, 10530),
+    (34243, 10530), (34243, 26863), (34243, 6069), (34243, 34011), (34243, 7528),
+    (34243, 19971), (34243, 21656), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 34011), (34243, 19428), (34243, 36970), (34243, 21325), (34243, 1890),
+    (34243, 5598), (34243, 27434), (34243, 19653), (34243, 32341), (34243, 18436),
+    (34243, 35123), (34243, 10530), (34243, 1890), (34243, 35792), (34243, 34011),
+    (34243, 13502), (34243, 10530), (34243, 13476), (34243, 13476), (34243, 19456),
+    (34243, 13502), (34243, 30845), (34243, 36970), (34243, 34011), (34243, 28573),
+    (34243, 1890), (34243, 12049), (34243, 25259), (34243, 1890), (34243, 19456),
+    (34243, 22364), (34243, 19653), (34243, 28978), (34243, 10530), (34243, 32385),
+    (34243, 38813), (34243, 10530), (34243, 10530), (34243, 28978), (34243, 15505),
+    (34243, 17741), (34243, 23418), (34243, 1890), (34243, 36970), (34243, 28978),
+    (34243, 16137), (34243, 34011), (34243, 31786), (34243, 32341), (34243, 19456),
+    (34243, 34011), (34243, 13476), (34243, 32255), (34243, 1890), (34243, 34011),
+    (34243, 32341), (34243, 10530), (34243, 26840), (34243, 37040), (34243, 32255),
+    (34243, 27434), (34243, 27434), (34243, 23710), (34243, 10530), (34243, 20247),
+    (34243, 13476), (34243, 10530), (34243, 5212), (34243, 19456), (34243, 36154),
+    (34243, 5598), (34243, 21818), (34243, 16748), (34243, 1814), (34243, 5212),
+    (34243, 1675), (34243, 28978), (34243, 19653), (34243, 25039), (34243, 32341),
+    (34243, 29560), (34243, 29560), (34243, 10530), (34243, 19456), (34243, 21922),
+    (34243, 10530), (34243, 21669), (34243, 10530), (34243, 26840), (34243, 21656),
+    (34243, 28978), (34243, 4102), (34243, 19653), (34243, 16792), (34243, 13476),
+    (34243, 31104), (34243, 34011), (34243, 37006), (34243, 30845), (34243, 18143),
+    (34243, 10530), (34243, 35792), (34243, 31433), (34243, 5598), (34243, 19653),
+    (34243, 10530), (34243, 4413), (34243, 10265), (34243, 31634), (34243, 5836),
+    (34243, 32341), (34243, 10530), (34243, 10530), (34243, 28978), (34243, 8111),
+    (34243, 38344), (34243, 28978), (34243, 28978), (34243, 28978), (34243, 36351),
+    (34243, 34011), (34243, 19021), (34243, 32341), (34243, 5598), (34243, 19653),
+    (34243, 19653), (34243, 19428), (34243, 34011), (34243, 18143), (34243, 28978),
+    (34243, 10530), (34243, 13476), (34243, 29459), (34243, 16137), (34243, 10530),
+    (34243, 29560), (34243, 19653), (34243, 10530), (34243, 12049), (34243, 10530),
+    (34243, 21922), (34243, 10530), (34243, 28978), (34243, 10530), (34243, 16792),
+    (34243, 32385), (34243, 19456), (34243, 1890), (34243, 39544), (34243, 10530),
+    (34243, 34011), (34243, 29560), (34243, 10530), (34243, 1890), (34243, 1538),
+    (34243, 39544), (34243, 19456), (34243, 1890), (34243, 4867), (34243, 32255),
+    (34243, 39544), (34243, 37006), (34243, 27434), (34243, 1890), (34243, 36970),
+    (34243, 21922), (34243, 182), (34243, 19456), (34243, 37488), (34243, 21656),
+    (34243, 4867), (34243, 6771), (34243, 19653), (34243, 10530), (34243, 28978),
+    (34243, 34011), (34243, 5947), (34243, 18186), (34243, 18436), (34243, 1890),
+    (34243, 32385), (34243, 10530), (34243, 19653), (34243, 32341), (34243, 27434),
+    (34243, 5947), (34243, 32341), (34243, 39538), (34243, 10530), (34243, 28514),
+    (34243, 23710), (34243, 36970), (34243, 38344), (34243, 38344), (34243, 10530),
+    (34243, 10530), (34243, 32385), (34243, 19456), (34243, 7528), (34243, 24215),
+    (34243, 32341), (34243, 38333), (34243, 5836), (34243, 37006), (34243, 10530),
+    (34243, 21656), (34243, 19139), (34243, 1538), (34243, 10530), (34243, 3031),
+    (34243, 19653), (34243, 36970), (34243, 25259), (34243, 1538), (34243, 19456),
+    (34243, 37040), (34243, 19653), (34243, 32385), (34243, 32385), (34243, 1890),
+    (34243, 15171), (34243, 10530), (34243, 39544), (34243, 1890), (34243, 19456),
+    (34243, 10530), (34243, 1538), (34243, 1890), (34243, 10530), (34243, 19456),
+    (34243, 1538), (34243, 32385), (34243, 34011), (34243, 22364), (34243, 1890),
+    (34243, 37040), (34243, 19456), (34243, 28978), (34243, 38333), (34243, 1890),
+    (34243, 9932), (34243, 1890), (34243, 22842), (34243, 24215), (34243, 10530),
+    (34243, 20247), (34243, 1890), (34243, 16786), (34243, 7528), (34243, 37040),
+    (34243, 7587), (34243, 16137), (34243, 40), (34243, 10530), (34243, 31104), (34243, 1890),
+    (34243, 10530), (34243, 10265), (34243, 21325), (34243, 10530), (34243, 31634),
+    (34243, 20247), (34243, 11703), (34243, 34549), (34243, 29560), (34243, 10530),
+    (34243, 28978), (34243, 15171), (34243, 35844), (34243, 28978), (34243, 10020),
+    (34243, 1890), (34243, 10530), (34243, 21325), (34243, 21922), (34243, 35123),
+    (34243, 23950), (34243, 18186), (34243, 15198), (34243, 7175), (34243, 32385),
+    (34243, 1538), (34243, 1890), (34243, 28978), (34243, 19456), (34243, 10530),
+    (34243, 39544), (34243, 24215), (34243, 37040), (34243, 34894), (34243, 7418),
+    (34243, 21244), (34243, 19456), (34243, 37006), (34243, 20247), (34243, 1890),
+    (34243, 8230), (34243, 1890), (34243, 10530), (34243, 38344), (34243, 18186),
+    (34243, 10530), (34243, 19653), (34243, 29528), (34243, 10530), (34243, 182),
+    (34243, 10530), (34243, 13476), (34243, 12049), (34243, 24215), (34243, 10530),
+    (34243, 10530), (34243, 30364), (34243, 18143), (34243, 21656), (34243, 1890),
+    (34243, 13502), (34243, 22364), (34243, 34894), (34243, 38411), (34243, 10530),
+    (34243, 10530), (34243, 37040), (34243, 18186), (34243, 10530), (34243, 38344),
+    (34243, 31634), (34243, 28539), (34243, 10530), (34243, 27434), (34243, 28573),
+    (34243, 30845), (34243, 34894), (34243, 37006), (34243, 39538), (34243, 15198),
+    (34243, 13476), (34243, 18300), (34243, 10530), (34243, 21325), (34243, 24215),
+    (34243, 1538), (34243, 22364), (34243, 32063), (34243, 1346), (34243, 19653),
+    (34243, 10530), (34243, 182), (34243, 36970), (34243, 37488), (34243, 19653),
+    (34243, 34011), (34243, 18186), (34243, 38813), (34243, 19653), (34243, 15198),
+    (34243, 1890), (34243, 10530), (34243, 10530), (34243, 1814), (34243, 1538),
+    (34243, 36551), (34243, 18186), (34243, 32341), (34243, 1538), (34243, 7528),
+    (34243, 20247), (34243, 10530), (34243, 7571), (34243, 18436), (34243, 22364),
+    (34243, 22720), (34243, 10530), (34243, 39544), (34243, 39242), (34243, 7571),
+    (34243, 10530), (34243, 37040), (34243, 20014), (34243, 33967), (34243, 38344),
+    (34243, 37040), (34243, 31634), (34243, 1890), (34243, 17543), (34243, 18186),
+    (34243, 21325), (34243, 13476), (34243, 26840), (34243, 32385), (34243, 1890),
+    (34243, 15505), (34243, 37630), (34243, 13476), (34243, 13476), (34243, 31634),
+    (34243, 21325), (34243, 10530), (34243, 10020), (34243, 10265), (34243, 19428),
+    (34243, 37488), (34243, 19428), (34243, 34011), (34243, 28978), (34243, 10530),
+    (34243, 13476), (34243, 36970), (34243, 27434), (34243, 28573), (34243, 24232),
+    (34243, 17408), (34243, 21656), (34243, 13476), (34243, 36154), (34243, 38344),
+    (34243, 1890), (34243, 36970), (34243, 30152), (34243, 28860), (34243, 21656),
+    (34243, 35192), (34243, 31104), (34243, 10530), (34243, 19456), (34243, 1538),
+    (34243, 33967), (34243, 39514), (34243, 32385), (34243, 32535), (34243, 10530),
+    (34243, 1538), (34243, 26840), (34243, 10530), (34243, 3186), (34243, 21325),
+    (34243, 19653), (34243, 20247), (34243, 19653), (34243, 1890), (34243, 1890),
+    (34243, 39544), (34243, 19456), (34243, 28978), (34243, 10530), (34243, 5598),
+    (34243, 21656), (34243, 32385), (34243, 25259), (34243, 34248), (34243, 29459),
+    (34243, 18186), (34243, 20014), (34243, 13476), (34243, 33967), (34243, 19653),
+    (34243, 25098), (34243, 20247), (34243, 4867), (34243, 20247), (34243, 5947),
+    (34243, 15171), (34243, 10530), (34243, 32341), (34243, 21325), (34243, 10530),
+    (34243, 34072), (34243, 5789), (34243, 10530), (34243, 34011), (34243, 21656),
+    (34243, 30152), (34243, 1814), (34243, 13476), (34243, 21656), (34243, 18436),
+    (34243, 31433), (34243, 10530), (34243, 19456), (34243, 8111), (34243, 21656),
+    (34243, 18436), (34243, 18186), (34243, 1890), (34243, 39538), (34243, 12049),
+    (34243, 28978), (34243, 10771), (34243, 13476), (34243, 35192), (34243, 38344),
+    (34243, 19456), (34243, 5836), (34243, 27036), (34243, 37006), (34243, 24232),
+    (34243, 28978), (34243, 37488), (34243, 19653), (34243, 27035), (34243, 13502),
+    (34243, 37040), (34243, 21325), (34243, 1890), (34243, 10530), (34243, 9781),
+    (34243, 32255), (34243, 10530), (34243, 1890), (34243, 32385), (34243, 31634),
+    (34243, 10530), (34243, 39544), (34243, 8394), (34243, 10530), (34243, 31104),
+    (34243, 10530), (34243, 26863), (34243, 20247), (34243, 32385), (34243, 28514),
+    (34243, 21656), (34243, 19456), (34243, 12049), (34243, 7587), (34243, 1890),
+    (34243, 10530), (34243, 10530), (34243, 5947), (34243, 6073), (34243, 10530),
+    (34243, 28978), (34243, 10530), (34243, 25098), (34243, 10530), (34243, 28978),
+    (34243, 36970), (34243, 19653), (34243, 19456), (34243, 1890), (34243, 19456),
+    (34243, 18186), (34243, 19456), (34243, 10530), (34243, 10020), (34243, 10530),
+    (34243, 38878), (34243, 18143), (34243, 37040), (34243, 10530), (34243, 19653),
+    (34243, 19456), (34243, 37040), (34243, 22364), (34243, 34011), (34243, 18186),
+    (34243, 30845), (34243, 19428), (34243, 26873), (34243, 24215), (34243, 32385),
+    (34243, 32255), (34243, 27434), (34243, 2347), (34243, 29560), (34243, 15198),
+    (34243, 10530), (34243, 28978), (34243, 10530), (34243, 27434), (34243, 16300),
+    (34243, 37040), (34243, 7461), (34243, 28978), (34243, 1890), (34243, 27434),
+    (34243, 32341), (34243, 34011), (34243, 10530), (34243, 20247), (34243, 28978),
+    (34243, 21325), (34243, 21656), (34243, 31634), (34243, 10530), (34243, 36970),
+    (34243, 32341), (34243, 19653), (34243, 19428), (34243, 10530), (34243, 30845),
+    (34243, 34011), (34243, 39514), (34243, 28573), (34243, 39828), (34243, 10530),
+    (34243, 10530), (34243, 1890), (34243, 10530), (34243, 182), (34243, 21656),
+    (34243, 16792), (34243, 35344), (34243, 182), (34243, 34072), (34243, 34011),
+    (34243, 18551), (34243, 13502), (34243, 30845), (34243, 38344), (34243, 19653),
+    (34243, 32341), (34243, 37006), (34243, 38878), (34243, 10530), (34243, 32341),
+    (34243, 35192), (34243, 8111), (34243, 21656), (34243, 13476), (34243, 34011),
+    (34243, 16792), (34243, 1890), (34243, 31104), (34243, 25259), (34243, 26863),
+    (34243, 28860), (34243, 13502), (34243, 16792), (34243, 37040), (34243, 32255),
+    (34243, 19456), (34243, 24232), (34243, 10530), (34243, 25098), (34243, 19653),
+    (34243, 28978), (34243, 10530), (34243, 10265), (34243, 5789), (34243, 26873),
+    (34243, 32255), (34243, 28978), (34243, 23950), (34243, 27434), (34243, 23212),
+    (34243, 3132), (34243, 1890), (34243, 8230), (34243, 36154), (34243, 39538),
+    (34243, 1538), (34243, 13502), (34243, 5598), (34243, 19584), (34243, 10265),
+    (34243, 37488), (34243, 10530), (34243, 27570), (34243, 28978), (34243, 19653),
+    (34243, 18436), (34243, 6453), (34243, 10530), (34243, 10530), (34243, 5104),
+    (34243, 10530), (34243, 19456), (34243, 34011), (34243, 31634), (34243, 10530),
+    (34243, 28978), (34243, 18436), (34243, 34549), (34243, 5598), (34243, 24215),
+    (34243, 21669), (34243, 10530), (34243, 36970), (34243, 30845), (34243, 36603),
+    (34243, 10530), (34243, 39544), (34243, 39037), (34243, 30845), (34243, 29560),
+    (34243, 19653), (34243, 8111), (34243, 34011), (34243, 18186), (34243, 38333),
+    (34243, 22364), (34243, 39544), (34243, 34011), (34243, 24099), (34243, 10530),
+    (34243, 28978), (34243, 15505), (34243, 1890), (34243, 29417), (34243, 34011),
+    (34243, 29560), (34243, 20247), (34243, 28514), (34243, 27680), (34243, 13502),
+    (34243, 18991), (34243, 24232), (34243, 28978), (34243, 17726), (34243, 32385),
+    (34243, 37040), (34243, 9355), (34243, 26840), (34243, 13476), (34243, 5598),
+    (34243, 10530), (34243, 18143), (34243, 32255), (34243, 39544), (34243, 10530),
+    (34243, 28860), (34243, 24232), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 32341), (34243, 32952), (34243, 21656), (34243, 13476),
+    (34243, 4867), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 1890),
+    (34243, 26863), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 21325),
+    (34243, 25259), (34243, 31104), (34243, 16792), (34243, 23950), (34243, 29459),
+    (34243, 39544), (34243, 19456), (34243, 20247), (34243, 33967), (34243, 38344),
+    (34243, 13502), (34243, 19653), (34243, 34011), (34243, 20247), (34243, 13476),
+    (34243, 28860), (34243, 28978), (34243, 18436), (34243, 39544), (34243, 16300),
+    (34243, 28978), (34243, 18551), (34243, 32341), (34243, 853), (34243, 13502),
+    (34243, 29459), (34243, 28978), (34243, 28978), (34243, 32385), (34243, 34011),
+    (34243, 17543), (34243, 7461), (34243, 5836), (34243, 182), (34243, 28978),
+    (34243, 39544), (34243, 27434), (34243, 34072), (34243, 10530), (34243, 34011),
+    (34243, 30845), (34243, 35123), (34243, 35792), (34243, 24232), (34243, 10530),
+    (34243, 30845), (34243, 28978), (34243, 34011), (34243, 13502), (34243, 32341),
+    (34243, 36970), (34243, 21656), (34243, 18436), (34243, 10530), (34243, 34011),
+    (34243, 13502), (34243, 21656), (34243, 13476), (34243, 28573), (34243, 10530),
+    (34243, 20247), (34243, 32341), (34243, 5598), (34243, 10020), (34243, 36970),
+    (34243, 19971), (34243, 19456), (34243, 5152), (34243, 20247), (34243, 13476),
+    (34243, 18186), (34243, 1538), (34243, 10530), (34243, 15198), (34243, 10530),
+    (34243, 10530), (34243, 36603), (34243, 38344), (34243, 28514), (34243, 9932),
+    (34243, 19653), (34243, 28978), (34243, 26863), (34243, 18186), (34243, 28978),
+    (34243, 30845), (34243, 37488), (34243, 13476), (34243, 10530), (34243, 15505),
+    (34243, 18186), (34243, 34011), (34243, 22364), (34243, 13476), (34243, 10530),
+    (34243, 20247), (34243, 13476), (34243, 18436), (34243, 34011), (34243, 34011),
+    (34243, 6069), (34243, 1890), (34243, 27434), (34243, 24232), (34243, 24215),
+    (34243, 24232), (34243, 21656), (34243, 19456), (34243, 19653), (34243, 28978),
+    (34243, 13476), (34243, 10530), (34243, 38813), (34243, 5522), (34243, 37488),
+    (34243, 7587), (34243, 9932), (34243, 1538), (34243, 25259), (34243, 15319),
+    (34243, 17956), (34243, 5836), (34243, 19653), (34243, 13502), (34243, 10530),
+    (34243, 1538), (34243, 26840), (34243, 28978), (34243, 10530), (34243, 36970),
+    (34243, 28978), (34243, 13476), (34243, 21656), (34243, 37040), (34243, 27434),
+    (34243, 21656), (34243, 21922), (34243, 10530), (34243, 29459), (34243, 2766),
+    (34243, 10530), (34243, 35284), (34243, 28514), (34243, 22720), (34243, 25098),
+    (34243, 32385), (34243, 12049), (34243, 39828), (34243, 10530), (34243, 32341),
+    (34243, 21656), (34243, 34011), (34243, 10530), (34243, 1890), (34243, 32385),
+    (34243, 30845), (34243, 4867), (34243, 1890), (34243, 21656), (34243, 13476),
+    (34243, 29560), (34243, 30364), (34243, 1890), (34243, 20247), (34243, 19456),
+    (34243, 32385), (34243, 38813), (34243, 3132), (34243, 32385), (34243, 10530),
+    (34243, 32385), (34243, 28978), (34243, 21325), (34243, 10530), (34243, 1890),
+    (34243, 1890), (34243, 7587), (34243, 1890), (34243, 19456), (34243, 1538),
+    (34243, 28514), (34243, 28978), (34243, 19653), (34243, 17956), (34243, 28978),
+    (34243, 13476), (34243, 2766), (34243, 31634), (34243, 34011), (34243, 1538),
+    (34243, 27434), (34243, 35792), (34243, 32255), (34243, 15198), (34243, 1538),
+    (34243, 6069), (34243, 28573), (34243, 9223), (34243, 12049), (34243, 32552),
+    (34243, 18186), (34243, 24232), (34243, 31634), (34243, 19456), (34243, 19428),
+    (34243, 1890), (34243, 8844), (34243, 19456), (34243, 27434), (34243, 1890),
+    (34243, 18186), (34243, 1890), (34243, 1538), (34243, 21325), (34243, 19428),
+    (34243, 32341), (34243, 19653), (34243, 12049), (34243, 35192), (34243, 19456),
+    (34243, 1890), (34243, 1890), (34243, 1675), (34243, 21656), (34243, 18186), (34243, 182),
+    (34243, 24232), (34243, 34011), (34243, 10530), (34243, 10530), (34243, 20247),
+    (34243, 23418), (34243, 18436), (34243, 36551), (34243, 22364), (34243, 18436),
+    (34243, 14304), (34243, 5598), (34243, 10530), (34243, 1890), (34243, 38181),
+    (34243, 4867), (34243, 19456), (34243, 10530), (34243, 10530), (34243, 26863),
+    (34243, 19653), (34243, 27434), (34243, 29560), (34243, 25259), (34243, 1538),
+    (34243, 34072), (34243, 32255), (34243, 37040), (34243, 29560), (34243, 28978),
+    (34243, 18143), (34243, 6453), (34243, 28860), (34243, 5522), (34243, 28573),
+    (34243, 30845), (34243, 1890), (34243, 29459), (34243, 5947), (34243, 1890),
+    (34243, 19456), (34243, 10530), (34243, 19456), (34243, 3132), (34243, 31634),
+    (34243, 29560), (34243, 9932), (34243, 36970), (34243, 34011), (34243, 37040),
+    (34243, 29459), (34243, 18551), (34243, 10530), (34243, 27434), (34243, 10530),
+    (34243, 29459), (34243, 10530), (34243, 20359), (34243, 10530), (34243, 27434),
+    (34243, 10530), (34243, 10530), (34243, 13476), (34243, 20247), (34243, 10530),
+    (34243, 28978), (34243, 32255), (34243, 27434), (34243, 27434), (34243, 1890),
+    (34243, 182), (34243, 29560), (34243, 19653), (34243, 19456), (34243, 10530),
+    (34243, 11703), (34243, 29459), (34243, 21922), (34243, 29459), (34243, 18143),
+    (34243, 19653), (34243, 32341), (34243, 1890), (34243, 5598), (34243, 13476),
+    (34243, 31563), (34243, 10530), (34243, 38344), (34243, 10530), (34243, 24215),
+    (34243, 10530), (34243, 1346), (34243, 23950), (34243, 19653), (34243, 21325),
+    (34243, 10530), (34243, 5598), (34243, 18143), (34243, 24215), (34243, 32255),
+    (34243, 28573), (34243, 19428), (34243, 36351), (34243, 19456), (34243, 32341),
+    (34243, 4867), (34243, 5522), (34243, 10530), (34243, 1890), (34243, 13476),
+    (34243, 28978), (34243, 29560), (34243, 5947), (34243, 10530), (34243, 13476),
+    (34243, 18186), (34243, 26873), (34243, 2242), (34243, 15405), (34243, 10530),
+    (34243, 21656), (34243, 10530), (34243, 25259), (34243, 19456), (34243, 34325),
+    (34243, 1538), (34243, 1890), (34243, 23950), (34243, 27434), (34243, 19428),
+    (34243, 28573), (34243, 21922), (34243, 10530), (34243, 10530), (34243, 36970),
+    (34243, 19456), (34243, 35792), (34243, 20247), (34243, 18186), (34243, 19456),
+    (34243, 16792), (34243, 10530), (34243, 32341), (34243, 39544), (34243, 10530),
+    (34243, 1890), (34243, 36719), (34243, 28978), (34243, 21669), (34243, 16300),
+    (34243, 1890), (34243, 32385), (34243, 34011), (34243, 16137), (34243, 38344),
+    (34243, 30845), (34243, 36970), (34243, 34549), (34243, 36154), (34243, 23418),
+    (34243, 30845), (34243, 32385), (34243, 10530), (34243, 5212), (34243, 19653),
+    (34243, 6073), (34243, 26873), (34243, 29417), (34243, 29560), (34243, 28978),
+    (34243, 29459), (34243, 1890), (34243, 39828), (34243, 10530), (34243, 13476),
+    (34243, 10530), (34243, 36603), (34243, 10530), (34243, 21325), (34243, 1890),
+    (34243, 34894), (34243, 32255), (34243, 32552), (34243, 16792), (34243, 1890),
+    (34243, 24215), (34243, 16792), (34243, 31104), (34243, 13476), (34243, 10530),
+    (34243, 12049), (34243, 28978), (34243, 12049), (34243, 10530), (34243, 6073),
+    (34243, 12049), (34243, 16792), (34243, 36970), (34243, 20482), (34243, 12607),
+    (34243, 16300), (34243, 16792), (34243, 29459), (34243, 28978), (34243, 13502),
+    (34243, 36058), (34243, 1814), (34243, 18186), (34243, 28978), (34243, 28978),
+    (34243, 1890), (34243, 31634), (34243, 28514), (34243, 18991), (34243, 39828),
+    (34243, 24232), (34243, 1538), (34243, 18143), (34243, 34011), (34243, 29152),
+    (34243, 38344), (34243, 32341), (34243, 10530), (34243, 1890), (34243, 29560),
+    (34243, 27434), (34243, 34072), (34243, 32341), (34243, 1890), (34243, 1890),
+    (34243, 26840), (34243, 27434), (34243, 10771), (34243, 1538), (34243, 7571),
+    (34243, 18436), (34243, 12049), (34243, 36551), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 10530), (34243, 19456), (34243, 18436), (34243, 15198),
+    (34243, 7418), (34243, 10530), (34243, 37488), (34243, 1538), (34243, 29893),
+    (34243, 21922), (34243, 34011), (34243, 39828), (34243, 10530), (34243, 22364),
+    (34243, 10530), (34243, 24476), (34243, 1890), (34243, 1890), (34243, 1890),
+    (34243, 35192), (34243, 20247), (34243, 32385), (34243, 15505), (34243, 21922),
+    (34243, 37006), (34243, 10530), (34243, 23710), (34243, 21481), (34243, 20899),
+    (34243, 32952), (34243, 34011), (34243, 18143), (34243, 1890), (34243, 32385),
+    (34243, 15198), (34243, 7461), (34243, 20899), (34243, 10530), (34243, 2700),
+    (34243, 30845), (34243, 32385), (34243, 19971), (34243, 32063), (34243, 10530),
+    (34243, 26863), (34243, 13502), (34243, 34011), (34243, 25039), (34243, 28978),
+    (34243, 38344), (34243, 18436), (34243, 10530), (34243, 36603), (34243, 1890),
+    (34243, 37006), (34243, 32341), (34243, 36970), (34243, 36970), (34243, 28978),
+    (34243, 8111), (34243, 5947), (34243, 29560), (34243, 9355), (34243, 24090),
+    (34243, 34011), (34243, 21922), (34243, 28978), (34243, 10530), (34243, 13476),
+    (34243, 19653), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 29459),
+    (34243, 29560), (34243, 37006), (34243, 10530), (34243, 37488), (34243, 1538),
+    (34243, 10530), (34243, 10530), (34243, 10530), (34243, 10530), (34243, 21325),
+    (34243, 39828), (34243, 30845), (34243, 34011), (34243, 20482), (34243, 28978),
+    (34243, 10530), (34243, 10530), (34243, 26015), (34243, 13476), (34243, 1538),
+    (34243, 9781), (34243, 10530), (34243, 38344), (34243, 5598), (34243, 1890),
+    (34243, 16137), (34243, 31634), (34243, 29459), (34243, 1890), (34243, 21656),
+    (34243, 21656), (34243, 18143), (34243, 19456), (34243, 14837), (34243, 13476),
+    (34243, 10530), (34243, 1890), (34243, 39544), (34243, 19456), (34243, 37488),
+    (34243, 28978), (34243, 28860), (34243, 23950), (34243, 13476), (34243, 32341),
+    (34243, 37040), (34243, 15171), (34243, 37040), (34243, 37768), (34243, 35123),
+    (34243, 5836), (34243, 28978), (34243, 1890), (34243, 24232), (34243, 34549),
+    (34243, 25039), (34243, 37488), (34243, 39828), (34243, 38344), (34243, 37978),
+    (34243, 24215), (34243, 36970), (34243, 1538), (34243, 10530), (34243, 37630),
+    (34243, 28514), (34243, 16137), (34243, 28573), (34243, 29459), (34243, 33967),
+    (34243, 38333), (34243, 28978), (34243, 27434), (34243, 39828), (34243, 18143),
+    (34243, 10530), (34243, 14304), (34243, 28978), (34243, 34683), (34243, 38344),
+    (34243, 36154), (34243, 2885), (34243, 32341), (34243, 17813), (34243, 27434),
+    (34243, 19653), (34243, 32341), (34243, 38344), (34243, 13476), (34243, 19971),
+    (34243, 2700), (34243, 34011), (34243, 16300), (34243, 34011), (34243, 37488),
+    (34243, 38344), (34243, 29893), (34243, 34011), (34243, 14837), (34243, 40),
+    (34243, 32341), (34243, 18551), (34243, 5598), (34243, 37040), (34243, 10530),
+    (34243, 5598), (34243, 39828), (34243, 38344), (34243, 13476), (34243, 8111),
+    (34243, 10530), (34243, 13476), (34243, 27434), (34243, 32341), (34243, 21656),
+    (34243, 22364), (34243, 29860), (34243, 34011), (34243, 10530), (34243, 26840),
+    (34243, 21481), (34243, 21922), (34243, 18991), (34243, 34011), (34243, 10530),
+    (34243, 27434), (34243, 19456), (34243, 28978), (34243, 8844), (34243, 22842),
+    (34243, 10530), (34243, 38344), (34243, 34011), (34243, 19428), (34243, 13476),
+    (34243, 27434), (34243, 10530), (34243, 35123), (34243, 3132), (34243, 37488),
+    (34243, 31563), (34243, 10530), (34243, 16792), (34243, 34011), (34243, 28978),
+    (34243, 10530), (34243, 1482), (34243, 10530), (34243, 19456), (34243, 37006),
+    (34243, 32385), (34243, 8844), (34243, 34549), (34243, 6771), (34243, 30845),
+    (34243, 38333), (34243, 1890), (34243, 21922), (34243, 20784), (34243, 39544),
+    (34243, 10530), (34243, 18102), (34243, 31634), (34243, 16792), (34243, 10530),
+    (34243, 32341), (34243, 36476), (34243, 10020), (34243, 24099), (34243, 5789),
+    (34243, 21656), (34243, 19653), (34243, 39828), (34243, 27035), (34243, 10530),
+    (34243, 1912), (34243, 10530), (34243, 21656), (34243, 22720), (34243, 25259),
+    (34243, 5212), (34243, 19456), (34243, 1890), (34243, 22364), (34243, 18186),
+    (34243, 38344), (34243, 36970), (34243, 34011), (34243, 20247), (34243, 36154),
+    (34243, 19456), (34243, 24232), (34243, 31634), (34243, 38878), (34243, 20899),
+    (34243, 19456), (34243, 15405), (34243, 28573), (34243, 37006), (34243, 39538),
+    (34243, 31563), (34243, 15198), (34243, 18186), (34243, 39828), (34243, 1890),
+    (34243, 35844), (34243, 32255), (34243, 37040), (34243, 10530), (34243, 5836),
+    (34243, 1814), (34243, 24232), (34243, 19456), (34243, 31104), (34243, 21332),
+    (34243, 29560), (34243, 10530), (34243, 29459), (34243, 34011), (34243, 21656),
+    (34243, 32535), (34243, 12049), (34243, 28978), (34243, 29560), (34243, 29459),
+    (34243, 10530), (34243, 10530), (34243, 24232), (34243, 9223), (34243, 10530),
+    (34243, 10530), (34243, 38411), (34243, 19456), (34243, 37040), (34243, 1538),
+    (34243, 1890), (34243, 37488), (34243, 32535), (34243, 10530), (34243, 19653),
+    (34243, 19456), (34243, 26840), (34243, 30845), (34243, 21325), (34243, 35844),
+    (34243, 39544), (34243, 20991), (34243, 1890), (34243, 28978), (34243, 13476),
+    (34243, 30461), (34243, 37040), (34243, 13476), (34243, 21325), (34243, 21656),
+    (34243, 39037), (34243, 26840), (34243, 10530), (34243, 6073), (34243, 36970),
+    (34243, 13476), (34243, 21922), (34243, 34011), (34243, 36551), (34243, 10020),
+    (34243, 10530), (34243, 38878), (34243, 1890), (34243, 21656), (34243, 4867),
+    (34243, 1826), (34243, 32385), (34243, 16792), (34243, 21634), (34243, 35123),
+    (34243, 28978), (34243, 10530), (34243, 18186), (34243, 30845), (34243, 28222),
+    (34243, 24232), (34243, 3988), (34243, 19456), (34243, 27680), (34243, 6073),
+    (34243, 10530), (34243, 37040), (34243, 18143), (34243, 182), (34243, 32385),
+    (34243, 32255), (34243, 3186), (34243, 18991), (34243, 28514), (34243, 34072),
+    (34243, 23418), (34243, 36970), (34243, 1890), (34243, 21656), (34243, 32341),
+    (34243, 19021), (34243, 10530), (34243, 36970), (34243, 2242), (34243, 34011),
+    (34243, 37040), (34243, 29893), (34243, 36970), (34243, 29560), (34243, 1890),
+    (34243, 10530), (34243, 1890), (34243, 28978), (34243, 36483), (34243, 25259),
+    (34243, 29560), (34243, 26873), (34243, 1890), (34243, 15832), (34243, 10530),
+    (34243, 35344), (34243, 28514), (34243, 19653), (34243, 38344), (34243, 16840),
+    (34243, 18102), (34243, 20899), (34243, 36970), (34243, 28978), (34243, 8111),
+    (34243, 28978), (34243, 19456), (34243, 5598), (34243, 13476), (34243, 10530),
+    (34243, 32255), (34243, 1538), (34243, 37006), (34243, 1890), (34243, 29860),
+    (34243, 10530), (34243, 19456), (34243, 5043), (34243, 10771), (34243, 29560),
+    (34243, 21656), (34243, 182), (34243, 21710), (34243, 28978), (34243, 1890),
+    (34243, 29302), (34243, 10530), (34243, 18436), (34243, 10265), (34243, 28978),
+    (34243, 21656), (34243, 21656), (34243, 26840), (34243, 27434), (34243, 20247),
+    (34243, 24232), (34243, 19456), (34243, 1890), (34243, 1538), (34243, 29459),
+    (34243, 19653), (34243, 10530), (34243, 19653), (34243, 26863), (34243, 1675),
+    (34243, 32255), (34243, 13476), (34243, 19456), (34243, 21656), (34243, 22093),
+    (34243, 20247), (34243, 28978), (34243, 37040), (34243, 13476), (34243, 21325),
+    (34243, 10265), (34243, 10530), (34243, 18551), (34243, 36154), (34243, 15198),
+    (34243, 39544), (34243, 21922), (34243, 10530), (34243, 32255), (34243, 1890),
+    (34243, 24099), (34243, 25259), (34243, 32341), (34243, 34011), (34243, 10530),
+    (34243, 36603), (34243, 28978), (34243, 19971), (34243, 29152), (34243, 2407),
+    (34243, 5295), (34243, 36603), (34243, 13476), (34243, 4205), (34243, 10530),
+    (34243, 15198), (34243, 9932), (34243, 182), (34243, 24215), (34243, 16137),
+    (34243, 28573), (34243, 10530), (34243, 23950), (34243, 30303), (34243, 5789),
+    (34243, 5348), (34243, 38628), (34243, 39457), (34243, 18436), (34243, 32385),
+    (34243, 10530), (34243, 28978), (34243, 10530), (34243, 18186), (34243, 28860),
+    (34243, 29459), (34243, 18186), (34243, 24099), (34243, 1890), (34243, 23710),
+    (34243, 38344), (34243, 21325), (34243, 10530), (34243, 34011), (34243, 38181),
+    (34243, 12049), (34243, 16300), (34243, 23212), (34243, 1814), (34243, 38344),
+    (34243, 10530), (34243, 11703), (34243, 18186), (34243, 29560), (34243, 18551),
+    (34243, 21922), (34243, 19456), (34243, 17956), (34243, 33577), (34243, 10530),
+    (34243, 15198), (34243, 19456), (34243, 15405), (34243, 1912), (34243, 17741),
+    (34243, 3132), (34243, 13476), (34243, 12049), (34243, 17956), (34243, 5318),
+    (34243, 28573), (34243, 26873), (34243, 10530), (34243, 1890), (34243, 37040),
+    (34243, 32341), (34243, 27128), (34243, 1890), (34243, 3132), (34243, 21922),
+    (34243, 6073), (34243, 37040), (34243, 21925), (34243, 34011), (34243, 1890),
+    (34243, 10530), (34243, 1890), (34243, 16300), (34243, 30845), (34243, 30226),
+    (34243, 34011), (34243, 10530), (34243, 13476), (34243, 18436), (34243, 10530),
+    (34243, 29560), (34243, 18102), (34243, 28860), (34243, 34011), (34243, 29560),
+    (34243, 1890), (34243, 37488), (34243, 10530), (34243, 32385), (34243, 26840),
+    (34243, 5836), (34243, 10530), (34243, 19456), (34243, 29560), (34243, 29459),
+    (34243, 36483), (34243, 10530), (34243, 10530), (34243, 27434), (34243, 35792),
+    (34243, 32385), (34243, 18186), (34243, 28978), (34243, 34683), (34243, 10530),
+    (34243, 15198), (34243, 13476), (34243, 37040), (34243, 10265), (34243, 35123),
+    (34243, 10530), (34243, 5947), (34243, 20482), (34243, 15198), (34243, 10530),
+    (34243, 19653), (34243, 28978), (34243, 2700), (34243, 16792), (34243, 21656),
+    (34243, 19653), (34243, 18586), (34243, 32919), (34243, 1890), (34243, 1538),
+    (34243, 21325), (34243, 31104), (34243, 19428), (34243, 7587), (34243, 32385),
+    (34243, 1538), (34243, 26873), (34243, 1890), (34243, 10530), (34243, 25259),
+    (34243, 28860), (34243, 19653), (34243, 35844), (34243, 10771), (34243, 28978),
+    (34243, 13502), (34243, 10530), (34243, 1890), (34243, 32385), (34243, 1538),
+    (34243, 33967), (34243, 35844), (34243, 19653), (34243, 34011), (34243, 27434),
+    (34243, 34011), (34243, 12259), (34243, 36551), (34243, 10530), (34243, 36351),
+    (34243, 10530), (34243, 18586), (34243, 1538), (34243, 10771), (34243, 36970),
+    (34243, 5318), (34243, 13476), (34243, 10530), (34243, 13476), (34243, 19456),
+    (34243, 18143), (34243, 10530), (34243, 10530), (34243, 30845), (34243, 27434),
+    (34243, 29893), (34243, 33967), (34243, 37488), (34243, 25259), (34243, 32341),
+    (34243, 37006), (34243, 10530), (34243, 1890), (34243, 21325), (34243, 31634),
+    (34243, 7587), (34243, 20247), (34243, 6069), (34243, 10530), (34243, 27434),
+    (34243, 6554), (34243, 28978), (34243, 19456), (34243, 25701), (34243, 10530),
+    (34243, 27434), (34243, 24232), (34243, 10530), (34243, 7571), (34243, 12049),
+    (34243, 16137), (34243, 18186), (34243, 27035), (34243, 34072), (34243, 10530),
+    (34243, 24232), (34243, 38813), (34243, 10530), (34243, 10530), (34243, 38344),
+    (34243, 32385), (34243, 9932), (34243, 5947), (34243, 10530), (34243, 1890),
+    (34243, 28573), (34243, 13502), (34243, 35844), (34243, 19653), (34243, 32341),
+    (34243, 16137), (34243, 19653), (34243, 1890), (34243, 10530), (34243, 19456),
+    (34243, 28978), (34243, 39745), (34243, 35792), (34243, 10530), (34243, 21656),
+    (34243, 10020), (34243, 29459), (34243, 10530), (34243, 21922), (34243, 7175),
+    (34243, 10530), (34243, 34011), (34243, 29417), (34243, 29893), (34243, 182),
+    (34243, 29302), (34243, 27434), (34243, 24099), (34243, 21656), (34243, 28860),
+    (34243, 29459), (34243, 19456), (34243, 21922), (34243, 23418), (34243, 28860),
+    (34243, 8111), (34243, 18143), (34243, 21325), (34243, 18436), (34243, 34011),
+    (34243, 3132), (34243, 32385), (34243, 5947), (34243, 28573), (34243, 36970),
+    (34243, 32063), (34243, 10530), (34243, 27434), (34243, 10530), (34243, 36985),
+    (34243, 28978), (34243, 29459), (34243, 13476), (34243, 32341), (34243, 39514),
+    (34243, 10530), (34243, 34011), (34243, 21669), (34243, 32385), (34243, 30845),
+    (34243, 35844), (34243, 15171), (34243, 9932), (34243, 10530), (34243, 19653),
+    (34243, 10530), (34243, 13502), (34243, 29560), (34243, 19428), (34243, 13476),
+    (34243, 32341), (34243, 10530), (34243, 10530), (34243, 9932), (34243, 5522),
+    (34243, 10530), (34243, 36603), (34243, 23710), (34243, 19456), (34243, 29560),
+    (34243, 21656), (34243, 15405), (34243, 10265), (34243, 13669), (34243, 19456),
+    (34243, 5789), (34243, 9932), (34243, 29560), (34243, 20247), (34243, 12259),
+    (34243, 5836), (34243, 29560), (34243, 15405), (34243, 1890), (34243, 34011),
+    (34243, 26840), (34243, 29860), (34243, 9749), (34243, 1346), (34243, 37006),
+    (34243, 28978), (34243, 21656), (34243, 34011), (34243, 28573), (34243, 1538),
+    (34243, 1890), (34243, 18143), (34243, 36970), (34243, 35844), (34243, 19653),
+    (34243, 13476), (34243, 38344), (34243, 29560), (34243, 1890), (34243, 34072),
+    (34243, 6453), (34243, 29459), (34243, 27434), (34243, 30303), (34243, 19428),
+    (34243, 16300), (34243, 1912), (34243, 1890), (34243, 1890), (34243, 27434),
+    (34243, 3132), (34243, 18186), (34243, 10530), (34243, 10530), (34243, 21325),
+    (34243, 28978), (34243, 38333), (34243, 36970), (34243, 13476), (34243, 9222),
+    (34243, 27434), (34243, 1890), (34243, 13476), (34243, 21481), (34243, 7587),
+    (34243, 5598), (34243, 8518), (34243, 1890), (34243, 22364), (34243, 36970),
+    (34243, 13476), (34243, 1912), (34243, 10530), (34243, 19428), (34243, 29152),
+    (34243, 19456), (34243, 20014), (34243, 26873), (34243, 13502), (34243, 18436),
+    (34243, 2885), (34243, 36970), (34243, 26873), (34243, 5104), (34243, 25232),
+    (34243, 24099), (34243, 26863), (34243, 19456), (34243, 7528), (34243, 19456),
+    (34243, 32385), (34243, 30845), (34243, 18186), (34243, 1890), (34243, 10530),
+    (34243, 32341), (34243, 19456), (34243, 16965), (34243, 25098), (34243, 15198),
+    (34243, 10530), (34243, 35344), (34243, 29459), (34243, 39544), (34243, 39800),
+    (34243, 29459), (34243, 32341), (34243, 10530), (34243, 19971), (34243, 1912),
+    (34243, 32385), (34243, 10530), (34243, 1538), (34243, 26863), (34243, 32341),
+    (34243, 28573), (34243, 8628), (34243, 38878), (34243, 37040), (34243, 1890),
+    (34243, 10530), (34243, 13502), (34243, 5836), (34243, 37006), (34243, 32385),
+    (34243, 18143), (34243, 35344), (34243, 10530), (34243, 10530), (34243, 33967),
+    (34243, 10530), (34243, 21669), (34243, 36154), (34243, 10530), (34243, 27036),
+    (34243, 5212), (34243, 10530), (34243, 23950), (34243, 32341), (34243, 34011),
+    (34243, 34011), (34243, 33941), (34243, 18436), (34243, 30152), (34243, 37006),
+    (34243, 1538), (34243, 39037), (34243, 25259), (34243, 12049), (34243, 1538),
+    (34243, 25098), (34243, 28978), (34243, 19456), (34243, 37488), (34243, 1890),
+    (34243, 10530), (34243, 34894), (34243, 32552), (34243, 3186), (34243, 18186),
+    (34243, 18143), (34243, 16137), (34243, 37488), (34243, 23418), (34243, 36603),
+    (34243, 13476), (34243, 19428), (34243, 27434), (34243, 10530), (34243, 18186),
+    (34243, 10265), (34243, 19456), (34243, 15505), (34243, 19428), (34243, 34011),
+    (34243, 32261), (34243, 30845), (34243, 18143), (34243, 36970), (34243, 20359),
+    (34243, 26840), (34243, 10530), (34243, 10530), (34243, 27617), (34243, 7175),
+    (34243, 20014), (34243, 10530), (34243, 38344), (34243, 33967), (34243, 35123),
+    (34243, 22494), (34243, 10530), (34243, 31634), (34243, 31104), (34243, 19456),
+    (34243, 10530), (34243, 34889), (34243, 32385), (34243, 31433), (34243, 25098),
+    (34243, 10530), (34243, 1538), (34243, 25259), (34243, 10530), (34243, 32385),
+    (34243, 21656), (34243, 10265), (34243, 19139), (34243, 13476), (34243, 18300),
+    (34243, 5152), (34243, 38086), (34243, 10530), (34243, 18186), (34243, 10530),
+    (34243, 2242), (34243, 21922), (34243, 18436), (34243, 1890), (34243, 26015),
+    (34243, 26840), (34243, 19456), (34243, 10530), (34243, 15405), (34243, 32535),
+    (34243, 1538), (34243, 19428), (34243, 18143), (34243, 1890), (34243, 22842),
+    (34243, 21656), (34243, 21325), (34243, 1890), (34243, 32341), (34243, 9355),
+    (34243, 18290), (34243, 10530), (34243, 10530), (34243, 19456), (34243, 21325),
+    (34243, 32341), (34243, 16300), (34243, 10530), (34243, 17726), (34243, 10530),
+    (34243, 28978), (34243, 15505), (34243, 1538), (34243, 1675), (34243, 10530),
+    (34243, 1890), (34243, 39544), (34243, 32385), (34243, 10530), (34243, 19456),
+    (34243, 28978), (34243, 2242), (34243, 11009), (34243, 24274), (34243, 3186),
+    (34243, 37488), (34243, 28978), (34243, 25259), (34243, 2885), (34243, 30845),
+    (34243, 33967), (34243, 10530), (34243, 19021), (34243, 19653), (34243, 1890),
+    (34243, 19653), (34243, 10530), (34243, 10530), (34243, 28978), (34243, 33967),
+    (34243, 10530), (34243, 28978), (34243, 10530), (34243, 7528), (34243, 28860),
+    (34243, 1890), (34243, 19021), (34243, 29560), (34243, 8111), (34243, 5522),
+    (34243, 28978), (34243, 27434), (34243, 24820), (34243, 10265), (34243, 28978),
+    (34243, 10530), (34243, 7461), (34243, 18143), (34243, 12049), (34243, 22364),
+    (34243, 28978), (34243, 19456), (34243, 34011), (34243, 28141), (34243, 38344),
+    (34243, 19456), (34243, 20784), (34243, 12049), (34243, 16792), (34243, 37040),
+    (34243, 10530), (34243, 1538), (34243, 35792), (34243, 34011), (34243, 32341),
+    (34243, 28978), (34243, 29560), (34243, 36970), (34243, 19456), (34243, 10530),
+    (34243, 1675), (34243, 32385), (34243, 33967), (34243, 29560), (34243, 10530),
+    (34243, 19584), (34243, 29417), (34243, 34011), (34243, 10530), (34243, 1890),
+    (34243, 27434), (34243, 18551), (34243, 39544), (34243, 5598), (34243, 25259),
+    (34243, 22740), (34243, 25259), (34243, 24292), (34243, 25259), (34243, 12049),
+    (34243, 20247), (34243, 32341), (34243, 1675), (34243, 28978), (34243, 30845),
+    (34243, 28978), (34243, 10530), (34243, 1538), (34243, 32341), (34243, 1890),
+    (34243, 10265), (34243, 36058), (34243, 10530), (34243, 19653), (34243, 18143),
+    (34243, 10530), (34243, 20247), (34243, 10530), (34243, 10530), (34243, 32385),
+    (34243, 36970), (34243, 19456), (34243, 21325), (34243, 29528), (34243, 1890),
+    (34243, 24090), (34243, 39538), (34243, 1890), (34243, 29560), (34243, 10530),
+    (34243, 10530), (34243, 9781), (34243, 28860), (34243, 31563), (34243, 37488),
+    (34243, 28860), (34243, 10530), (34243, 10530), (34243, 19653), (34243, 22720),
+    (34243, 1890), (34243, 28573), (34243, 10530), (34243, 30845), (34243, 5318),
+    (34243, 1890), (34243, 27036), (34243, 6069), (34243, 26873), (34243, 10530),
+    (34243, 37040), (34243, 37040), (34243, 38641), (34243, 10771), (34243, 34894),
+    (34243, 27035), (34243, 37006), (34243, 17543), (34243, 31634), (34243, 182),
+    (34243, 5789), (34243, 38320), (34243, 37040), (34243, 20359), (34243, 1890),
+    (34243, 10530), (34243, 32255), (34243, 19456), (34243, 34011), (34243, 36970),
+    (34243, 1890), (34243, 32385), (34243, 38411), (34243, 5947), (34243, 5598),
+    (34243, 36970), (34243, 10530), (34243, 29459), (34243, 13502), (34243, 33941),
+    (34243, 13502), (34243, 10530), (34243, 27434), (34243, 18102), (34243, 37006),
+    (34243, 37006), (34243, 38344), (34243, 18991), (34243, 1538), (34243, 26840),
+    (34243, 5947), (34243, 30152), (34243, 18991), (34243, 26840), (34243, 28978),
+    (34243, 9781), (34243, 36970), (34243, 38333), (34243, 12621), (34243, 28573),
+    (34243, 10530), (34243, 20359), (34243, 13476), (34243, 34011), (34243, 20247),
+    (34243, 31634), (34243, 34549), (34243, 29459), (34243, 28573), (34243, 19139),
+    (34243, 19456), (34243, 16300), (34243, 9222), (34243, 19456), (34243, 10530),
+    (34243, 1890), (34243, 27434), (34243, 1890), (34243, 19456), (34243, 19653),
+    (34243, 1890), (34243, 38333), (34243, 12049), (34243, 7528), (34243, 29152),
+    (34243, 35792), (34243, 10530), (34243, 19456), (34243, 10530), (34243, 5789),
+    (34243, 20247), (34243, 18186), (34243, 21325), (34243, 34011), (34243, 1890),
+    (34243, 34820), (34243, 1538), (34243, 31104), (34243, 16300), (34243, 39457),
+    (34243, 18436), (34243, 1890), (34243, 10530), (34243, 16137), (34243, 32341),
+    (34243, 12049), (34243, 29459), (34243, 32552), (34243, 32341), (34243, 23950),
+    (34243, 22364), (34243, 19456), (34243, 10530), (34243, 35792), (34243, 1890),
+    (34243, 2700), (34243, 23710), (34243, 31433), (34243, 32385), (34243, 32385),
+    (34243, 16792), (34243, 10530), (34243, 38628), (34243, 32341), (34243, 21325),
+    (34243, 7571), (34243, 1538), (34243, 39544), (34243, 10530), (34243, 32385),
+    (34243, 20014), (34243, 10771), (34243, 21325), (34243, 35192), (34243, 39538),
+    (34243, 5836), (34243, 1538), (34243, 36603), (34243, 21656), (34243, 11703),
+    (34243, 39544), (34243, 27434), (34243, 39183), (34243, 29526), (34243, 10530),
+    (34243, 10530), (34243, 29152), (34243, 5036), (34243, 1890), (34243, 28860),
+    (34243, 10530), (34243, 21325), (34243, 13476), (34243, 10530), (34243, 29560),
+    (34243, 34011), (34243, 10530), (34243, 19653), (34243, 12835), (34243, 16965),
+    (34243, 38813), (34243, 36970), (34243, 24232), (34243, 35192), (34243, 10530),
+    (34243, 15505), (34243, 19456), (34243, 10802), (34243, 18143), (34243, 11703),
+    (34243, 24099), (34243, 19428), (34243, 1890), (34243, 7659), (34243, 19456),
+    (34243, 26692), (34243, 17741), (34243, 10530), (34243, 29560), (34243, 38333),
+    (34243, 28835), (34243, 34011), (34243, 1890), (34243, 10530), (34243, 19456),
+    (34243, 39544), (34243, 32341), (34243, 9107), (34243, 27434), (34243, 19139),
+    (34243, 28978), (34243, 28860), (34243, 1538), (34243, 10530), (34243, 13476),
+    (34243, 31433), (34243, 28573), (34243, 38344), (34243, 10020), (34243, 32341),
+    (34243, 5598), (34243, 19653), (34243, 10530), (34243, 10530), (34243, 1890),
+    (34243, 8394), (34243, 5836), (34243, 27434), (34243, 35792), (34243, 36603),
+    (34243, 6073), (34243, 10530), (34243, 2885), (34243, 18143), (34243, 37040),
+    (34243, 7528), (34243, 1890), (34243, 28978), (34243, 19428), (34243, 35792),
+    (34243, 6073), (34243, 10530), (34243, 11590), (34243, 10530), (34243, 32255),
+    (34243, 10530), (34243, 6661), (34243, 13476), (34243, 19456), (34243, 8230),
+    (34243, 38813), (34243, 10265), (34243, 36551), (34243, 5104), (34243, 24099),
+    (34243, 19428), (34243, 1346), (34243, 29152), (34243, 5598), (34243, 4867),
+    (34243, 37006), (34243, 10530), (34243, 19456), (34243, 37488), (34243, 24099),
+    (34243, 39544), (34243, 18102), (34243, 10530), (34243, 28141), (34243, 10530),
+    (34243, 32385), (34243, 15198), (34243, 10530), (34243, 31634), (34243, 23212),
+    (34243, 3132), (34243, 10530), (34243, 36603), (34243, 9222), (34243, 20784),
+    (34243, 30845), (34243, 32341), (34243, 32063), (34243, 31563), (34243, 39538),
+    (34243, 11703), (34243, 19983), (34243, 28573), (34243, 34011), (34243, 30085),
+    (34243, 5187), (34243, 34011), (34243, 10020), (34243, 37040), (34243, 16792),
+    (34243, 25259), (34243, 10530), (34243, 39828), (34243, 10530), (34243, 6069),
+    (34243, 10530), (34243, 37040), (34243, 10530), (34243, 32341), (34243, 20247),
+    (34243, 37006), (34243, 10530), (34243, 21922), (34243, 1538), (34243, 32385),
+    (34243, 10530), (34243, 21922), (34243, 1890), (34243, 23523), (34243, 33967),
+    (34243, 10530), (34243, 37040), (34243, 5947), (34243, 3186), (34243, 38344),
+    (34243, 10530), (34243, 32952), (34243, 13476), (34243, 28978), (34243, 39828),
+    (34243, 1788), (34243, 5947), (34243, 13502), (34243, 19971), (34243, 39544),
+    (34243, 29560), (34243, 40), (34243, 34011), (34243, 10530), (34243, 19456),
+    (34243, 28978), (34243, 27128), (34243, 23710), (34243, 2766), (34243, 10530),
+    (34243, 10530), (34243, 32341), (34243, 37488), (34243, 1890), (34243, 10530),
+    (34243, 21922), (34243, 28978), (34243, 18186), (34243, 32341), (34243, 17956),
+    (34243, 32255), (34243, 5212), (34243, 1538), (34243, 37488), (34243, 10530),
+    (34243, 13476), (34243, 21997), (34243, 24232), (34243, 5789), (34243, 19021),
+    (34243, 27434), (34243, 18102), (34243, 19456), (34243, 31433), (34243, 10530),
+    (34243, 16792), (34243, 23950), (34243, 32385), (34243, 18143), (34243, 32535),
+    (34243, 5212), (34243, 30845), (34243, 19456), (34243, 10530), (34243, 37006),
+    (34243, 13502), (34243, 32385), (34243, 28978), (34243, 36603), (34243, 5598),
+    (34243, 3517), (34243, 18102), (34243, 5348), (34243, 26873), (34243, 27434),
+    (34243, 34011), (34243, 21634), (34243, 10530), (34243, 32385), (34243, 5295),
+    (34243, 5836), (34243, 33967), (34243, 36603), (34243, 182), (34243, 31634),
+    (34243, 24232), (34243, 10530), (34243, 36970), (34243, 28978), (34243, 7461),
+    (34243, 1538), (34243, 19456), (34243, 24232), (34243, 28573), (34243, 8394),
+    (34243, 7587), (34243, 38344), (34243, 1538), (34243, 27035), (34243, 34011),
+    (34243, 1538), (34243, 35844), (34243, 25259), (34243, 18991), (34243, 19456),
+    (34243, 12259), (34243, 21325), (34243, 31563), (34243, 10530), (34243, 13476),
+    (34243, 1884), (34243, 36970), (34243, 38878), (34243, 26015), (34243, 19653),
+    (34243, 18186), (34243, 5836), (34243, 34072), (34243, 26863), (34243, 2133),
+    (34243, 5947), (34243, 18436), (34243, 18436), (34243, 40), (34243, 10530),
+    (34243, 27434), (34243, 14304), (34243, 28978), (34243, 36351), (34243, 1890),
+    (34243, 34011), (34243, 28978), (34243, 39538), (34243, 10530), (34243, 26873),
+    (34243, 37040), (34243, 27035), (34243, 30845), (34243, 35284), (34243, 21773),
+    (34243, 29560), (34243, 5598), (34243, 18186), (34243, 27434), (34243, 36062),
+    (34243, 10530), (34243, 38813), (34243, 29560), (34243, 1890), (34243, 19653),
+    (34243, 10530), (34243, 28978), (34243, 17956), (34243, 28141), (34243, 24232),
+    (34243, 10530), (34243, 18436), (34243, 36970), (34243, 28978), (34243, 39544),
+    (34243, 35792), (34243, 28978), (34243, 20482), (34243, 7461), (34243, 37488),
+    (34243, 31433), (34243, 18143), (34243, 29560), (34243, 18186), (34243, 16300),
+    (34243, 12049), (34243, 9036), (34243, 37040), (34243, 39544), (34243, 32341),
+    (34243, 19456), (34243, 18186), (34243, 29302), (34243, 10530), (34243, 25621),
+    (34243, 19456), (34243, 19653), (34243, 18436), (34243, 10530), (34243, 38086),
+    (34243, 22720), (34243, 7528), (34243, 37040), (34243, 30845), (34243, 32255),
+    (34243, 38344), (34243, 28978), (34243, 3186), (34243, 24215), (34243, 19428),
+    (34243, 1538), (34243, 14304), (34243, 34011), (34243, 29560), (34243, 13392),
+    (34243, 31634), (34243, 34011), (34243, 19456), (34243, 35792), (34243, 12259),
+    (34243, 13502), (34243, 34011), (34243, 39828), (34243, 32341), (34243, 10530),
+    (34243, 32341), (34243, 38333), (34243, 1538), (34243, 35284), (34243, 7587),
+    (34243, 182), (34243, 1890), (34243, 19456), (34243, 27434), (34243, 32535),
+    (34243, 12049), (34243, 19456), (34243, 1890), (34243, 38344), (34243, 38813),
+    (34243, 27434), (34243, 37040), (34243, 10530), (34243, 28063), (34243, 32562),
+    (34243, 19653), (34243, 28978), (34243, 1890), (34243, 10530), (34243, 38333),
+    (34243, 10530), (34243, 32952), (34243, 6453), (34243, 10530), (34243, 10530),
+    (34243, 38333), (34243, 39183), (34243, 17813), (34243, 3988), (34243, 39828),
+    (34243, 28860), (34243, 5789), (34243, 32385), (34243, 15405), (34243, 5598),
+    (34243, 5598), (34243, 38344), (34243, 15405), (34243, 1890), (34243, 10530),
+    (34243, 1538), (34243, 10530), (34243, 29560), (34243, 31634), (34243, 10530),
+    (34243, 10530), (34243, 22494), (34243, 1890), (34243, 28978), (34243, 28573),
+    (34243, 10530), (34243, 28978), (34243, 29893), (34243, 29560), (34243, 10530),
+    (34243, 27434), (34243, 26840), (34243, 37040), (34243, 30845), (34243, 19456),
+    (34243, 16792), (34243, 39242), (34243, 28978), (34243, 27434), (34243, 13476),
+    (34243, 32535), (34243, 19021), (34243, 15893), (34243, 1890), (34243, 32255),
+    (34243, 38813), (34243, 29560), (34243, 30303), (34243, 9355), (34243, 11398),
+    (34243, 23950), (34243, 8111), (34243, 9932), (34243, 20247), (34243, 35192),
+    (34243, 1890), (34243, 35192), (34243, 34894), (34243, 1890), (34243, 10530),
+    (34243, 1890), (34243, 10530), (34243, 4655), (34243, 36970), (34243, 28514),
+    (34243, 34549), (34243, 10530), (34243, 35284), (34243, 22720), (34243, 19653),
+    (34243, 1538), (34243, 39538), (34243, 23418), (34243, 38344), (34243, 29560),
+    (34243, 28573), (34243, 37630), (34243, 32952), (34243, 36603), (34243, 9355),
+    (34243, 38181), (34243, 1890), (34243, 1890), (34243, 12150), (34243, 1378),
+    (34243, 27434), (34243, 28978), (34243, 26961), (34243, 34011), (34243, 19653),
+    (34243, 34011), (34243, 15198), (34243, 19456), (34243, 1890), (34243, 5348),
+    (34243, 1890), (34243, 29560), (34243, 29560), (34243, 24232), (34243, 28514),
+    (34243, 39538), (34243, 15198), (34243, 10530), (34243, 1890), (34243, 27617),
+    (34243, 22842), (34243, 28141), (34243, 15505), (34243, 10530), (34243, 5187),
+    (34243, 8111), (34243, 10530), (34243, 19653), (34243, 21669), (34243, 37488),
+    (34243, 34011), (34243, 18602), (34243, 10530), (34243, 5947), (34243, 1890),
+    (34243, 24090), (34243, 5152), (34243, 5295), (34243, 11331), (34243, 5348),
+    (34243, 19456), (34243, 10265), (34243, 38344), (34243, 3132), (34243, 18436),
+    (34243, 32341), (34243, 14304), (34243, 1814), (34243, 36970), (34243, 18436),
+    (34243, 30845), (34243, 10530), (34243, 8111), (34243, 1890), (34243, 10530),
+    (34243, 32341), (34243, 21922), (34243, 39538), (34243, 10530), (34243, 37488),
+    (34243, 5152), (34243, 16300), (34243, 32385), (34243, 19653), (34243, 34011),
+    (34243, 8111), (34243, 10184), (34243, 25259), (34243, 39544), (34243, 27434),
+    (34243, 9223), (34243, 1890), (34243, 26015), (34243, 10530), (34243, 15198),
+    (34243, 693), (34243, 1538), (34243, 10530), (34243, 29302), (34243, 13476),
+    (34243, 10530), (34243, 7692), (34243, 5598), (34243, 13476), (34243, 1675),
+    (34243, 28978), (34243, 39457), (34243, 1538), (34243, 5522), (34243, 36970),
+    (34243, 6073), (34243, 5187), (34243, 28978), (34243, 26873), (34243, 10530),
+    (34243, 16748), (34243, 5522), (34243, 18102), (34243, 1890), (34243, 36603),
+    (34243, 10265), (34243, 32341), (34243, 10530), (34243, 32341), (34243, 10265),
+    (34243, 39514), (34243, 9781), (34243, 10530), (34243, 10530), (34243, 7587),
+    (34243, 32385), (34243, 16965), (34243, 39514), (34243, 29526), (34243, 1890),
+    (34243, 29459), (34243, 37488), (34243, 28978), (34243, 29302), (34243, 11703),
+    (34243, 26873), (34243, 13502), (34243, 9090), (34243, 19653), (34243, 15171),
+    (34243, 27434), (34243, 19456), (34243, 21481), (34243, 16611), (34243, 22364),
+    (34243, 10530), (34243, 39538), (34243, 34011), (34243, 13476), (34243, 16137),
+    (34243, 1890), (34243, 28222), (34243, 17813), (34243, 7571), (34243, 2583),
+    (34243, 19653), (34243, 5522), (34243, 5104), (34243, 36970), (34243, 25701),
+    (34243, 1890), (34243, 19653), (34243, 34011), (34243, 39544), (34243, 38333),
+    (34243, 31433), (34243, 27434), (34243, 10771), (34243, 21656), (34243, 22364),
+    (34243, 29459), (34243, 10530), (34243, 37488), (34243, 32562), (34243, 32952),
+    (34243, 26863), (34243, 34011), (34243, 25039), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 27035), (34243, 32341), (34243, 39544), (34243, 32341),
+    (34243, 17543), (34243, 10530), (34243, 36970), (34243, 28141), (34243, 28295),
+    (34243, 32865), (34243, 10530), (34243, 32255), (34243, 39644), (34243, 37040),
+    (34243, 16137), (34243, 19456), (34243, 5598), (34243, 18186), (34243, 5947),
+    (34243, 13476), (34243, 20247), (34243, 31104), (34243, 35123), (34243, 32385),
+    (34243, 35284), (34243, 34011), (34243, 5598), (34243, 2885), (34243, 32341),
+    (34243, 1890), (34243, 10530), (34243, 10530), (34243, 29860), (34243, 13476),
+    (34243, 24232), (34243, 10530), (34243, 32385), (34243, 21669), (34243, 10530),
+    (34243, 18551), (34243, 1890), (34243, 25259), (34243, 34549), (34243, 20247),
+    (34243, 35792), (34243, 31634), (34243, 10530), (34243, 4595), (34243, 13502),
+    (34243, 28514), (34243, 1538), (34243, 9223), (34243, 5598), (34243, 20359),
+    (34243, 28978), (34243, 29459), (34243, 5947), (34243, 21922), (34243, 32341),
+    (34243, 19456), (34243, 30152), (34243, 22364), (34243, 10530), (34243, 8629),
+    (34243, 17741), (34243, 20899), (34243, 5598), (34243, 24215), (34243, 1890),
+    (34243, 15171), (34243, 25259), (34243, 19653), (34243, 10530), (34243, 24232),
+    (34243, 23950), (34243, 3031), (34243, 19428), (34243, 29037), (34243, 10530),
+    (34243, 10530), (34243, 10530), (34243, 16792), (34243, 19653), (34243, 7528),
+    (34243, 36970), (34243, 36603), (34243, 182), (34243, 18186), (34243, 5789),
+    (34243, 21656), (34243, 18436), (34243, 29560), (34243, 30845), (34243, 10530),
+    (34243, 12607), (34243, 5010), (34243, 18186), (34243, 9223), (34243, 20482),
+    (34243, 34011), (34243, 31433), (34243, 34011), (34243, 37630), (34243, 32063),
+    (34243, 28978), (34243, 20247), (34243, 34011), (34243, 10530), (34243, 27434),
+    (34243, 10530), (34243, 37040), (34243, 22842), (34243, 31634), (34243, 4867),
+    (34243, 32341), (34243, 10530), (34243, 37040), (34243, 182), (34243, 18143),
+    (34243, 9781), (34243, 37040), (34243, 10265), (34243, 1538), (34243, 19456),
+    (34243, 10530), (34243, 28978), (34243, 27680), (34243, 1890), (34243, 38344),
+    (34243, 35344), (34243, 29560), (34243, 26840), (34243, 28978), (34243, 26840),
+    (34243, 24090), (34243, 16748), (34243, 12049), (34243, 32952), (34243, 10530),
+    (34243, 1538), (34243, 5598), (34243, 7528), (34243, 10771), (34243, 32341),
+    (34243, 10530), (34243, 34072), (34243, 39544), (34243, 10530), (34243, 10184),
+    (34243, 19653), (34243, 31433), (34243, 1346), (34243, 18290), (34243, 28860),
+    (34243, 16137), (34243, 2492), (34243, 20899), (34243, 2290), (34243, 33967), (34243, 40),
+    (34243, 1348), (34243, 29560), (34243, 38344), (34243, 19653), (34243, 182),
+    (34243, 8402), (34243, 693), (34243, 19456), (34243, 10530), (34243, 1890),
+    (34243, 28978), (34243, 1912), (34243, 36603), (34243, 24215), (34243, 11009),
+    (34243, 29860), (34243, 10530), (34243, 38813), (34243, 32385), (34243, 28978),
+    (34243, 36970), (34243, 39544), (34243, 32385), (34243, 23418), (34243, 21669),
+    (34243, 10530), (34243, 22842), (34243, 38960), (34243, 7587), (34243, 7418),
+    (34243, 34011), (34243, 19653), (34243, 36042), (34243, 18186), (34243, 32341),
+    (34243, 20014), (34243, 38344), (34243, 10530), (34243, 1890), (34243, 32385),
+    (34243, 10530), (34243, 28978), (34243, 32385), (34243, 32385), (34243, 10530),
+    (34243, 5947), (34243, 1890), (34243, 30845), (34243, 28978), (34243, 38344),
+    (34243, 19456), (34243, 10530), (34243, 35284), (34243, 35844), (34243, 35344),
+    (34243, 2700), (34243, 12049), (34243, 19653), (34243, 28860), (34243, 1890),
+    (34243, 26840), (34243, 38344), (34243, 26840), (34243, 27035), (34243, 10530),
+    (34243, 31634), (34243, 27680), (34243, 13476), (34243, 34011), (34243, 39544),
+    (34243, 29560), (34243, 29560), (34243, 21656), (34243, 5212), (34243, 15171),
+    (34243, 29560), (34243, 5598), (34243, 28514), (34243, 5187), (34243, 29560),
+    (34243, 20359), (34243, 37040), (34243, 10530), (34243, 13476), (34243, 10530),
+    (34243, 13476), (34243, 18102), (34243, 18143), (34243, 10530), (34243, 28573),
+    (34243, 19653), (34243, 31563), (34243, 20247), (34243, 8111), (34243, 18436),
+    (34243, 10530), (34243, 25039), (34243, 35192), (34243, 5212), (34243, 38181),
+    (34243, 10265), (34243, 5187), (34243, 10530), (34243, 15198), (34243, 16792),
+    (34243, 36970), (34243, 37488), (34243, 15832), (34243, 5836), (34243, 39544),
+    (34243, 32341), (34243, 22740), (34243, 10530), (34243, 1890), (34243, 19456),
+    (34243, 19456), (34243, 34011), (34243, 6069), (34243, 37040), (34243, 18143),
+    (34243, 9781), (34243, 29459), (34243, 22919), (34243, 10530), (34243, 7944),
+    (34243, 10530), (34243, 2347), (34243, 693), (34243, 32552), (34243, 25259),
+    (34243, 18186), (34243, 21325), (34243, 16792), (34243, 19653), (34243, 10530),
+    (34243, 34011), (34243, 4722), (34243, 32385), (34243, 37006), (34243, 13476),
+    (34243, 32385), (34243, 18186), (34243, 36970), (34243, 1538), (34243, 38749),
+    (34243, 1538), (34243, 16792), (34243, 19428), (34243, 18436), (34243, 1538),
+    (34243, 37040), (34243, 30845), (34243, 29560), (34243, 38344), (34243, 9932),
+    (34243, 34072), (34243, 13139), (34243, 24099), (34243, 10108), (34243, 32341),
+    (34243, 10530), (34243, 13502), (34243, 33967), (34243, 8111), (34243, 18102),
+    (34243, 1788), (34243, 37040), (34243, 16786), (34243, 10530), (34243, 22720),
+    (34243, 29560), (34243, 34011), (34243, 24215), (34243, 1890), (34243, 10530),
+    (34243, 12259), (34243, 39544), (34243, 36351), (34243, 18991), (34243, 38344),
+    (34243, 39644), (34243, 37040), (34243, 10530), (34243, 10530), (34243, 5348),
+    (34243, 35123), (34243, 39828), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 22364), (34243, 2885), (34243, 30845), (34243, 15198),
+    (34243, 13476), (34243, 18436), (34243, 7528), (34243, 38344), (34243, 28573),
+    (34243, 10530), (34243, 19456), (34243, 34325), (34243, 32341), (34243, 5598),
+    (34243, 24232), (34243, 10530), (34243, 37006), (34243, 32552), (34243, 39457),
+    (34243, 37040), (34243, 1538), (34243, 5036), (34243, 21922), (34243, 25039),
+    (34243, 32341), (34243, 15171), (34243, 38344), (34243, 5104), (34243, 1884),
+    (34243, 1538), (34243, 37040), (34243, 26840), (34243, 37006), (34243, 10530),
+    (34243, 17813), (34243, 15198), (34243, 10530), (34243, 10530), (34243, 35192),
+    (34243, 10530), (34243, 29526), (34243, 16369), (34243, 25039), (34243, 28573),
+    (34243, 16137), (34243, 10530), (34243, 6069), (34243, 32385), (34243, 34894),
+    (34243, 5598), (34243, 21925), (34243, 20482), (34243, 31104), (34243, 2125),
+    (34243, 10530), (34243, 1890), (34243, 36970), (34243, 7571), (34243, 10530),
+    (34243, 32385), (34243, 21325), (34243, 34011), (34243, 33967), (34243, 38344),
+    (34243, 36154), (34243, 10530), (34243, 28978), (34243, 5947), (34243, 1538),
+    (34243, 10530), (34243, 19653), (34243, 34011), (34243, 1890), (34243, 10530),
+    (34243, 20247), (34243, 5522), (34243, 15171), (34243, 29417), (34243, 13476),
+    (34243, 7571), (34243, 30845), (34243, 23950), (34243, 10530), (34243, 18551),
+    (34243, 10530), (34243, 5598), (34243, 15505), (34243, 25730), (34243, 1890),
+    (34243, 10530), (34243, 34072), (34243, 20014), (34243, 24090), (34243, 33609),
+    (34243, 8111), (34243, 23770), (34243, 10530), (34243, 1890), (34243, 39544),
+    (34243, 35041), (34243, 1538), (34243, 5043), (34243, 37040), (34243, 10530),
+    (34243, 10530), (34243, 28860), (34243, 31634), (34243, 12049), (34243, 18186),
+    (34243, 10530), (34243, 15198), (34243, 26840), (34243, 6069), (34243, 28978),
+    (34243, 10530), (34243, 3132), (34243, 39544), (34243, 27434), (34243, 32385),
+    (34243, 19785), (34243, 28978), (34243, 182), (34243, 19456), (34243, 10530),
+    (34243, 1912), (34243, 32341), (34243, 36970), (34243, 36719), (34243, 12049),
+    (34243, 29152), (34243, 10530), (34243, 5152), (34243, 10530), (34243, 20899),
+    (34243, 15198), (34243, 31347), (34243, 1538), (34243, 1890), (34243, 35123),
+    (34243, 15198), (34243, 29459), (34243, 23523), (34243, 1890), (34243, 10485),
+    (34243, 13476), (34243, 37488), (34243, 34011), (34243, 33941), (34243, 21481),
+    (34243, 20784), (34243, 1890), (34243, 1675), (34243, 20247), (34243, 10530),
+    (34243, 22438), (34243, 28978), (34243, 1890), (34243, 3132), (34243, 10530),
+    (34243, 10530), (34243, 1348), (34243, 13476), (34243, 38344), (34243, 21997),
+    (34243, 10530), (34243, 18143), (34243, 25098), (34243, 1890), (34243, 28978),
+    (34243, 12049), (34243, 5295), (34243, 1348), (34243, 18186), (34243, 16137),
+    (34243, 1538), (34243, 31786), (34243, 21656), (34243, 10020), (34243, 32535),
+    (34243, 36970), (34243, 31634), (34243, 37006), (34243, 28978), (34243, 34011),
+    (34243, 34011), (34243, 6073), (34243, 10265), (34243, 31104), (34243, 10530),
+    (34243, 7418), (34243, 1538), (34243, 32255), (34243, 8111), (34243, 20014),
+    (34243, 1378), (34243, 13476), (34243, 5212), (34243, 32952), (34243, 1538),
+    (34243, 26840), (34243, 30226), (34243, 28978), (34243, 10530), (34243, 39538),
+    (34243, 10530), (34243, 13502), (34243, 28573), (34243, 20784), (34243, 26840),
+    (34243, 1538), (34243, 1890), (34243, 10530), (34243, 37488), (34243, 36970),
+    (34243, 28573), (34243, 21656), (34243, 16792), (34243, 1890), (34243, 10530),
+    (34243, 39457), (34243, 37006), (34243, 11398), (34243, 1890), (34243, 37488),
+    (34243, 20359), (34243, 10530), (34243, 39828), (34243, 10530), (34243, 16792),
+    (34243, 39538), (34243, 10530), (34243, 864), (34243, 10530), (34243, 18436),
+    (34243, 27434), (34243, 27035), (34243, 26863), (34243, 28573), (34243, 27434),
+    (34243, 10530), (34243, 19653), (34243, 13899), (34243, 16300), (34243, 10530),
+    (34243, 27434), (34243, 30845), (34243, 7587), (34243, 18018), (34243, 5789),
+    (34243, 21634), (34243, 8111), (34243, 1890), (34243, 34011), (34243, 19456),
+    (34243, 13139), (34243, 1346), (34243, 21325), (34243, 17813), (34243, 21710),
+    (34243, 30364), (34243, 30845), (34243, 23950), (34243, 1538), (34243, 10530),
+    (34243, 10530), (34243, 28514), (34243, 10530), (34243, 19456), (34243, 5212),
+    (34243, 19456), (34243, 13476), (34243, 27434), (34243, 11703), (34243, 20247),
+    (34243, 34011), (34243, 24099), (34243, 10530), (34243, 26840), (34243, 11009),
+    (34243, 10618), (34243, 28978), (34243, 34011), (34243, 10530), (34243, 19456),
+    (34243, 10530), (34243, 28978), (34243, 16300), (34243, 39544), (34243, 11601),
+    (34243, 1890), (34243, 7129), (34243, 37978), (34243, 27607), (34243, 10530),
+    (34243, 19653), (34243, 5010), (34243, 19456), (34243, 21925), (34243, 26840),
+    (34243, 13943), (34243, 32341), (34243, 28514), (34243, 19456), (34243, 10265),
+    (34243, 10530), (34243, 11703), (34243, 18436), (34243, 22364), (34243, 24232),
+    (34243, 10530), (34243, 2242), (34243, 18436), (34243, 9355), (34243, 35192),
+    (34243, 39544), (34243, 12049), (34243, 34683), (34243, 13476), (34243, 35123),
+    (34243, 1838), (34243, 1538), (34243, 182), (34243, 26840), (34243, 24232),
+    (34243, 35844), (34243, 27607), (34243, 39538), (34243, 22720), (34243, 28539),
+    (34243, 16207), (34243, 28573), (34243, 1890), (34243, 19456), (34243, 19428),
+    (34243, 21656), (34243, 29560), (34243, 11590), (34243, 36970), (34243, 37040),
+    (34243, 19456), (34243, 26114), (34243, 21325), (34243, 20784), (34243, 10530),
+    (34243, 10771), (34243, 30845), (34243, 10530), (34243, 6771), (34243, 35792),
+    (34243, 29459), (34243, 1890), (34243, 19021), (34243, 1890), (34243, 21481),
+    (34243, 1890), (34243, 12049), (34243, 28978), (34243, 10530), (34243, 182),
+    (34243, 19584), (34243, 21656), (34243, 30845), (34243, 1884), (34243, 1890),
+    (34243, 27434), (34243, 7461), (34243, 21325), (34243, 30059), (34243, 1890),
+    (34243, 20720), (34243, 39800), (34243, 29560), (34243, 32341), (34243, 35611),
+    (34243, 32063), (34243, 21656), (34243, 36154), (34243, 693), (34243, 29560),
+    (34243, 23418), (34243, 36551), (34243, 1890), (34243, 37040), (34243, 12049),
+    (34243, 10530), (34243, 27434), (34243, 31634), (34243, 28063), (34243, 4867),
+    (34243, 22842), (34243, 5598), (34243, 38333), (34243, 31634), (34243, 34072),
+    (34243, 10530), (34243, 20482), (34243, 12150), (34243, 20784), (34243, 21656),
+    (34243, 28978), (34243, 32255), (34243, 20247), (34243, 27680), (34243, 1538),
+    (34243, 20359), (34243, 29893), (34243, 11703), (34243, 27434), (34243, 24232),
+    (34243, 34011), (34243, 18143), (34243, 38333), (34243, 39544), (34243, 1890),
+    (34243, 10530), (34243, 32261), (34243, 25259), (34243, 1538), (34243, 34011),
+    (34243, 16792), (34243, 26863), (34243, 32535), (34243, 37006), (34243, 4214),
+    (34243, 16792), (34243, 5598), (34243, 18102), (34243, 10530), (34243, 3988),
+    (34243, 9749), (34243, 19456), (34243, 13502), (34243, 19428), (34243, 36603),
+    (34243, 21656), (34243, 13476), (34243, 30152), (34243, 29560), (34243, 16300),
+    (34243, 10530), (34243, 10530), (34243, 19139), (34243, 6771), (34243, 13476),
+    (34243, 10530), (34243, 35344), (34243, 37488), (34243, 13502), (34243, 34072),
+    (34243, 1675), (34243, 25098), (34243, 36058), (34243, 10530), (34243, 1912),
+    (34243, 10530), (34243, 34011), (34243, 9781), (34243, 32255), (34243, 31634),
+    (34243, 21325), (34243, 18436), (34243, 10530), (34243, 2583), (34243, 19584),
+    (34243, 10530), (34243, 31786), (34243, 15171), (34243, 34011), (34243, 28978),
+    (34243, 13476), (34243, 21325), (34243, 24089), (34243, 31551), (34243, 1890),
+    (34243, 10530), (34243, 38411), (34243, 27680), (34243, 1912), (34243, 35123),
+    (34243, 1890), (34243, 18436), (34243, 1890), (34243, 25286), (34243, 22364),
+    (34243, 10530), (34243, 34011), (34243, 24099), (34243, 40), (34243, 20720),
+    (34243, 37040), (34243, 15527), (34243, 32341), (34243, 10530), (34243, 29560),
+    (34243, 1675), (34243, 25259), (34243, 15198), (34243, 20247), (34243, 5598),
+    (34243, 6771), (34243, 8844), (34243, 5598), (34243, 36970), (34243, 10530),
+    (34243, 1890), (34243, 29893), (34243, 23705), (34243, 18300), (34243, 18143),
+    (34243, 13476), (34243, 17741), (34243, 34011), (34243, 16792), (34243, 39544),
+    (34243, 21656), (34243, 10530), (34243, 26840), (34243, 38333), (34243, 7929),
+    (34243, 3500), (34243, 10530), (34243, 13476), (34243, 21325), (34243, 5348),
+    (34243, 2583), (34243, 37040), (34243, 37040), (34243, 31965), (34243, 21325),
+    (34243, 4867), (34243, 7587), (34243, 10530), (34243, 10530), (34243, 10530),
+    (34243, 10530), (34243, 10530), (34243, 1346), (34243, 38333), (34243, 1890),
+    (34243, 39242), (34243, 10530), (34243, 26863), (34243, 13212), (34243, 25259),
+    (34243, 28978), (34243, 5836), (34243, 1538), (34243, 9781), (34243, 182), (34243, 34894),
+    (34243, 20014), (34243, 34011), (34243, 12049), (34243, 1912), (34243, 10530),
+    (34243, 28978), (34243, 10530), (34243, 16792), (34243, 21656), (34243, 7659),
+    (34243, 13476), (34243, 2766), (34243, 4367), (34243, 5187), (34243, 16840),
+    (34243, 24476), (34243, 16406), (34243, 36970), (34243, 18143), (34243, 24215),
+    (34243, 36154), (34243, 5043), (34243, 18143), (34243, 32341), (34243, 7587),
+    (34243, 37040), (34243, 10530), (34243, 32341), (34243, 1890), (34243, 18186),
+    (34243, 5522), (34243, 2407), (34243, 20359), (34243, 29526), (34243, 19456),
+    (34243, 39828), (34243, 10020), (34243, 19653), (34243, 21656), (34243, 13476),
+    (34243, 38749), (34243, 13502), (34243, 24292), (34243, 37006), (34243, 38333),
+    (34243, 32341), (34243, 21669), (34243, 28978), (34243, 8230), (34243, 1838),
+    (34243, 6073), (34243, 34011), (34243, 29302), (34243, 2885), (34243, 22364),
+    (34243, 10530), (34243, 27434), (34243, 19584), (34243, 34011), (34243, 38641),
+    (34243, 13502), (34243, 10530), (34243, 19653), (34243, 25730), (34243, 29152),
+    (34243, 1890), (34243, 16810), (34243, 28514), (34243, 9932), (34243, 28978),
+    (34243, 1814), (34243, 27570), (34243, 20247), (34243, 1814), (34243, 34011),
+    (34243, 30226), (34243, 10530), (34243, 19653), (34243, 18186), (34243, 1890),
+    (34243, 35123), (34243, 7587), (34243, 23950), (34243, 27570), (34243, 24099),
+    (34243, 29309), (34243, 32341), (34243, 16137), (34243, 5947), (34243, 20784),
+    (34243, 27434), (34243, 18102), (34243, 14837), (34243, 28978), (34243, 32385),
+    (34243, 5836), (34243, 1538), (34243, 32341), (34243, 28222), (34243, 19653),
+    (34243, 18186), (34243, 23950), (34243, 16792), (34243, 30364), (34243, 28978),
+    (34243, 1890), (34243, 13476), (34243, 22842), (34243, 24099), (34243, 10530),
+    (34243, 15832), (34243, 18186), (34243, 10530), (34243, 19653), (34243, 7418),
+    (34243, 10530), (34243, 36571), (34243, 10530), (34243, 1538), (34243, 853),
+    (34243, 5598), (34243, 30152), (34243, 39544), (34243, 28978), (34243, 37040),
+    (34243, 19653), (34243, 27607), (34243, 33941), (34243, 4102), (34243, 19653),
+    (34243, 18186), (34243, 19456), (34243, 18186), (34243, 11247), (34243, 32341),
+    (34243, 10530), (34243, 36603), (34243, 5295), (34243, 36515), (34243, 28978),
+    (34243, 39828), (34243, 27182), (34243, 37040), (34243, 33967), (34243, 5152),
+    (34243, 27035), (34243, 28063), (34243, 10530), (34243, 1890), (34243, 28514),
+    (34243, 28978), (34243, 10530), (34243, 17856), (34243, 1890), (34243, 21325),
+    (34243, 28056), (34243, 4214), (34243, 11703), (34243, 21922), (34243, 24232),
+    (34243, 19456), (34243, 26114), (34243, 10530), (34243, 37006), (34243, 21656),
+    (34243, 31634), (34243, 24090), (34243, 32341), (34243, 33967), (34243, 28514),
+    (34243, 10530), (34243, 28978), (34243, 34011), (34243, 10530), (34243, 19653),
+    (34243, 27904), (34243, 18436), (34243, 37040), (34243, 39544), (34243, 32385),
+    (34243, 10530), (34243, 29459), (34243, 11590), (34243, 4655), (34243, 34011),
+    (34243, 13060), (34243, 853), (34243, 26863), (34243, 29459), (34243, 34011),
+    (34243, 20247), (34243, 10530), (34243, 37040), (34243, 38333), (34243, 10530),
+    (34243, 27035), (34243, 21922), (34243, 10530), (34243, 10530), (34243, 38086),
+    (34243, 13139), (34243, 6069), (34243, 10530), (34243, 24215), (34243, 6073),
+    (34243, 7129), (34243, 17741), (34243, 21656), (34243, 18436), (34243, 21922),
+    (34243, 20247), (34243, 18436), (34243, 20482), (34243, 20899), (34243, 34011),
+    (34243, 27434), (34243, 10530), (34243, 32341), (34243, 3670), (34243, 27035),
+    (34243, 17813), (34243, 22740), (34243, 5191), (34243, 28978), (34243, 13476),
+    (34243, 8111), (34243, 16300), (34243, 18143), (34243, 24099), (34243, 15832),
+    (34243, 8111), (34243, 5836), (34243, 38333), (34243, 29560), (34243, 21325),
+    (34243, 32341), (34243, 10265), (34243, 34549), (34243, 28978), (34243, 28978),
+    (34243, 13476), (34243, 34011), (34243, 1538), (34243, 28860), (34243, 10530),
+    (34243, 21325), (34243, 28978), (34243, 27434), (34243, 28978), (34243, 19456),
+    (34243, 18436), (34243, 5152), (34243, 9932), (34243, 13502), (34243, 32063),
+    (34243, 19653), (34243, 1912), (34243, 30845), (34243, 28860), (34243, 2700),
+    (34243, 39544), (34243, 16497), (34243, 21922), (34243, 18300), (34243, 34011),
+    (34243, 8165), (34243, 29417), (34243, 30845), (34243, 13476), (34243, 18143),
+    (34243, 24215), (34243, 1538), (34243, 18300), (34243, 28271), (34243, 9932),
+    (34243, 10530), (34243, 23418), (34243, 16792), (34243, 1538), (34243, 21656),
+    (34243, 4377), (34243, 22364), (34243, 11398), (34243, 28978), (34243, 28978),
+    (34243, 14304), (34243, 29528), (34243, 5152), (34243, 12340), (34243, 21925),
+    (34243, 19456), (34243, 15171), (34243, 10530), (34243, 13139), (34243, 24232),
+    (34243, 27434), (34243, 19021), (34243, 2885), (34243, 28978), (34243, 32552),
+    (34243, 24090), (34243, 39828), (34243, 27035), (34243, 19428), (34243, 38813),
+    (34243, 4102), (34243, 24215), (34243, 28860), (34243, 28978), (34243, 37007),
+    (34243, 19456), (34243, 39457), (34243, 1538), (34243, 37488), (34243, 14304),
+    (34243, 10530), (34243, 26830), (34243, 34011), (34243, 18586), (34243, 1675),
+    (34243, 20482), (34243, 27617), (34243, 39544), (34243, 39544), (34243, 10530),
+    (34243, 32952), (34243, 32385), (34243, 7461), (34243, 16792), (34243, 1538),
+    (34243, 10530), (34243, 20247), (34243, 26840), (34243, 10265), (34243, 31634),
+    (34243, 32255), (34243, 31634), (34243, 18186), (34243, 32063), (34243, 13476),
+    (34243, 1538), (34243, 10530), (34243, 18102), (34243, 29560), (34243, 23941),
+    (34243, 5789), (34243, 11703), (34243, 16300), (34243, 30152), (34243, 10530),
+    (34243, 16163), (34243, 21325), (34243, 38641), (34243, 32385), (34243, 32341),
+    (34243, 39544), (34243, 39538), (34243, 35344), (34243, 15832), (34243, 28573),
+    (34243, 19139), (34243, 21997), (34243, 24493), (34243, 10530), (34243, 2347),
+    (34243, 12185), (34243, 1890), (34243, 32535), (34243, 21325), (34243, 34011),
+    (34243, 20482), (34243, 29459), (34243, 39183), (34243, 30461), (34243, 1538),
+    (34243, 39544), (34243, 36602), (34243, 6073), (34243, 7461), (34243, 16300),
+    (34243, 5043), (34243, 8111), (34243, 28514), (34243, 19456), (34243, 28539),
+    (34243, 10530), (34243, 18436), (34243, 36058), (34243, 1890), (34243, 25259),
+    (34243, 26530), (34243, 34011), (34243, 1884), (34243, 18102), (34243, 8628),
+    (34243, 28141), (34243, 10530), (34243, 34011), (34243, 32341), (34243, 34011),
+    (34243, 2290), (34243, 21669), (34243, 10530), (34243, 21656), (34243, 20247),
+    (34243, 27607), (34243, 27607), (34243, 1538), (34243, 1890), (34243, 10530),
+    (34243, 12049), (34243, 1037), (34243, 1538), (34243, 12607), (34243, 25259),
+    (34243, 24215), (34243, 18143), (34243, 22612), (34243, 23839), (34243, 1826),
+    (34243, 38628), (34243, 12049), (34243, 1890), (34243, 10530), (34243, 18143),
+    (34243, 1814), (34243, 10530), (34243, 5043), (34243, 15198), (34243, 19428),
+    (34243, 30205), (34243, 7418), (34243, 18143), (34243, 10530), (34243, 14938),
+    (34243, 38086), (34243, 28978), (34243, 15832), (34243, 5295), (34243, 28978),
+    (34243, 31104), (34243, 19456), (34243, 15832), (34243, 9223), (34243, 10530),
+    (34243, 10530), (34243, 24215), (34243, 25098), (34243, 15505), (34243, 4867),
+    (34243, 34894), (34243, 32952), (34243, 5295), (34243, 29459), (34243, 4205),
+    (34243, 10530), (34243, 14837), (34243, 16137), (34243, 9932), (34243, 18143),
+    (34243, 19653), (34243, 32385), (34243, 19653), (34243, 22438), (34243, 21325),
+    (34243, 1890), (34243, 5836), (34243, 17570), (34243, 34549), (34243, 34894),
+    (34243, 39544), (34243, 37978), (34243, 19653), (34243, 10530), (34243, 28514),
+    (34243, 21481), (34243, 12049), (34243, 3855), (34243, 10530), (34243, 25621),
+    (34243, 13476), (34243, 32385), (34243, 5348), (34243, 35792), (34243, 10530),
+    (34243, 27434), (34243, 26015), (34243, 2891), (34243, 13502), (34243, 10530),
+    (34243, 23523), (34243, 18436), (34243, 32341), (34243, 19653), (34243, 10530),
+    (34243, 27035), (34243, 19456), (34243, 22842), (34243, 9223), (34243, 17654),
+    (34243, 31634), (34243, 24232), (34243, 20014), (34243, 19584), (34243, 32385),
+    (34243, 4722), (34243, 12049), (34243, 10530), (34243, 3517), (34243, 28860),
+    (34243, 13587), (34243, 38344), (34243, 24099), (34243, 1788), (34243, 14334),
+    (34243, 35792), (34243, 32261), (34243, 28978), (34243, 16792), (34243, 10530),
+    (34243, 2242), (34243, 2347), (34243, 18143), (34243, 32385), (34243, 15198),
+    (34243, 20720), (34243, 29459), (34243, 10530), (34243, 5805), (34243, 13476),
+    (34243, 5152), (34243, 28860), (34243, 37040), (34243, 21325), (34243, 28094),
+    (34243, 10530), (34243, 30356), (34243, 10530), (34243, 28978), (34243, 28063),
+    (34243, 1538), (34243, 12256), (34243, 29459), (34243, 26863), (34243, 10530),
+    (34243, 18290), (34243, 13502), (34243, 10530), (34243, 19456), (34243, 8111),
+    (34243, 20247), (34243, 21669), (34243, 29560), (34243, 32385), (34243, 13476),
+    (34243, 27434), (34243, 18740), (34243, 34011), (34243, 30152), (34243, 27036),
+    (34243, 28514), (34243, 1912), (34243, 19139), (34243, 19456), (34243, 23418),
+    (34243, 19456), (34243, 19139), (34243, 29459), (34243, 34011), (34243, 22720),
+    (34243, 34011), (34243, 27123), (34243, 13476), (34243, 24215), (34243, 3132),
+    (34243, 13476), (34243, 22740), (34243, 6554), (34243, 29893), (34243, 21656),
+    (34243, 32385), (34243, 20720), (34243, 6069), (34243, 1890), (34243, 30205),
+    (34243, 1890), (34243, 36551), (34243, 10530), (34243, 36970), (34243, 1826),
+    (34243, 36551), (34243, 9036), (34243, 18186), (34243, 5187), (34243, 8111),
+    (34243, 28978), (34243, 39639), (34243, 35792), (34243, 2885), (34243, 16792),
+    (34243, 32385), (34243, 10530), (34243, 29860), (34243, 28978), (34243, 13502),
+    (34243, 1538), (34243, 27123), (34243, 10530), (34243, 10530), (34243, 15171),
+    (34243, 27617), (34243, 39544), (34243, 16792), (34243, 1470), (34243, 10530),
+    (34243, 5152), (34243, 1884), (34243, 19653), (34243, 1890), (34243, 3024),
+    (34243, 33967), (34243, 13476), (34243, 39514), (34243, 10790), (34243, 32341),
+    (34243, 36719), (34243, 19456), (34243, 28978), (34243, 23952), (34243, 20564),
+    (34243, 37006), (34243, 10530), (34243, 18102), (34243, 4867), (34243, 4531),
+    (34243, 19653), (34243, 20247), (34243, 881), (34243, 27035), (34243, 10530),
+    (34243, 36603), (34243, 12987), (34243, 29526), (34243, 26987), (34243, 15832),
+    (34243, 5789), (34243, 18143), (34243, 18143), (34243, 29860), (34243, 39514),
+    (34243, 32341), (34243, 27741), (34243, 10530), (34243, 6453), (34243, 10530),
+    (34243, 12259), (34243, 38628), (34243, 32341), (34243, 3900), (34243, 18436),
+    (34243, 23354), (34243, 10530), (34243, 18300), (34243, 6621), (34243, 14304),
+    (34243, 7418), (34243, 34549), (34243, 36970), (34243, 6069), (34243, 32952),
+    (34243, 3132), (34243, 23710), (34243, 19653), (34243, 31550), (34243, 24476),
+    (34243, 39544), (34243, 13476), (34243, 10530), (34243, 21325), (34243, 24099),
+    (34243, 34011), (34243, 25259), (34243, 37040), (34243, 28860), (34243, 19653),
+    (34243, 10530), (34243, 39745), (34243, 5533), (34243, 21656), (34243, 3132),
+    (34243, 5295), (34243, 26863), (34243, 7461), (34243, 20482), (34243, 1890),
+    (34243, 26873), (34243, 19653), (34243, 30845), (34243, 39828), (34243, 18186),
+    (34243, 32535), (34243, 24215), (34243, 39544), (34243, 15405), (34243, 10530),
+    (34243, 31359), (34243, 27434), (34243, 5348), (34243, 31563), (34243, 3959),
+    (34243, 37006), (34243, 28978), (34243, 2242), (34243, 23950), (34243, 15198),
+    (34243, 7571), (34243, 32255), (34243, 16792), (34243, 18692), (34243, 28978),
+    (34243, 2885), (34243, 18186), (34243, 26840), (34243, 16792), (34243, 32385),
+    (34243, 28978), (34243, 32385), (34243, 182), (34243, 1538), (34243, 28063),
+    (34243, 11703), (34243, 9749), (34243, 39443), (34243, 5789), (34243, 28860),
+    (34243, 23710), (34243, 17956), (34243, 13502), (34243, 19584), (34243, 20482),
+    (34243, 10530), (34243, 35792), (34243, 21656), (34243, 12007), (34243, 12259),
+    (34243, 29863), (34243, 21922), (34243, 30845), (34243, 20014), (34243, 19456),
+    (34243, 3031), (34243, 32385), (34243, 40), (34243, 34072), (34243, 4214), (34243, 1538),
+    (34243, 18991), (34243, 5947), (34243, 18991), (34243, 34549), (34243, 10530),
+    (34243, 2700), (34243, 13297), (34243, 28978), (34243, 28573), (34243, 21325),
+    (34243, 24292), (34243, 19653), (34243, 22919), (34243, 31634), (34243, 10530),
+    (34243, 29560), (34243, 28978), (34243, 21656), (34243, 19456), (34243, 4205),
+    (34243, 13476), (34243, 33586), (34243, 1538), (34243, 24215), (34243, 38086),
+    (34243, 32341), (34243, 9911), (34243, 32255), (34243, 10530), (34243, 30226),
+    (34243, 5187), (34243, 23523), (34243, 10530), (34243, 32952), (34243, 10530),
+    (34243, 29560), (34243, 36970), (34243, 16792), (34243, 31507), (34243, 10530),
+    (34243, 10530), (34243, 28094), (34243, 36719), (34243, 20235), (34243, 12776),
+    (34243, 32385), (34243, 31507), (34243, 32385), (34243, 3132), (34243, 10530),
+    (34243, 16497), (34243, 22842), (34243, 33967), (34243, 10530), (34243, 693),
+    (34243, 34011), (34243, 15198), (34243, 21656), (34243, 23408), (34243, 19785),
+    (34243, 29459), (34243, 5805), (34243, 2027), (34243, 26863), (34243, 19456),
+    (34243, 10020), (34243, 19456), (34243, 1890), (34243, 24215), (34243, 21244),
+    (34243, 36603), (34243, 23950), (34243, 2407), (34243, 14938), (34243, 25039),
+    (34243, 24232), (34243, 10530), (34243, 9355), (34243, 18102), (34243, 18143),
+    (34243, 20235), (34243, 22740), (34243, 10530), (34243, 7571), (34243, 15171),
+    (34243, 19653), (34243, 30364), (34243, 10618), (34243, 23770), (34243, 33967),
+    (34243, 11590), (34243, 1912), (34243, 27434), (34243, 10530), (34243, 32385),
+    (34243, 5043), (34243, 18102), (34243, 13502), (34243, 8572), (34243, 6771),
+    (34243, 37717), (34243, 18143), (34243, 1890), (34243, 26114), (34243, 38749),
+    (34243, 10530), (34243, 10020), (34243, 20784), (34243, 39514), (34243, 28978),
+    (34243, 10530), (34243, 35792), (34243, 28573), (34243, 1890), (34243, 36515),
+    (34243, 1912), (34243, 10530), (34243, 32255), (34243, 24476), (34243, 38344),
+    (34243, 30152), (34243, 18602), (34243, 39538), (34243, 22842), (34243, 13899),
+    (34243, 19456), (34243, 20247), (34243, 3186), (34243, 5152), (34243, 5598),
+    (34243, 16137), (34243, 10530), (34243, 26186), (34243, 39514), (34243, 10530),
+    (34243, 37006), (34243, 1890), (34243, 38813), (34243, 29560), (34243, 13476),
+    (34243, 1890), (34243, 10530), (34243, 22494), (34243, 15198), (34243, 15319),
+    (34243, 22740), (34243, 34072), (34243, 32341), (34243, 12049), (34243, 10530),
+    (34243, 5212), (34243, 19456), (34243, 36970), (34243, 16137), (34243, 28978),
+    (34243, 5533), (34243, 36571), (34243, 10530), (34243, 39183), (34243, 34011),
+    (34243, 8111), (34243, 33941), (34243, 1346), (34243, 18197), (34243, 10530),
+    (34243, 12994), (34243, 10530), (34243, 31551), (34243, 1890), (34243, 13476),
+    (34243, 33967), (34243, 26114), (34243, 5598), (34243, 5104), (34243, 5212),
+    (34243, 33967), (34243, 4214), (34243, 1675), (34243, 34011), (34243, 28514),
+    (34243, 28573), (34243, 1538), (34243, 37488), (34243, 35284), (34243, 26863),
+    (34243, 5598), (34243, 10530), (34243, 2885), (34243, 26840), (34243, 36571),
+    (34243, 32385), (34243, 6453), (34243, 7587), (34243, 10530), (34243, 13476),
+    (34243, 18186), (34243, 21325), (34243, 21710), (34243, 17956), (34243, 17813),
+    (34243, 29302), (34243, 6453), (34243, 21656), (34243, 28978), (34243, 34894),
+    (34243, 32385), (34243, 28978), (34243, 20899), (34243, 38333), (34243, 13476),
+    (34243, 3186), (34243, 20844), (34243, 32341), (34243, 19460), (34243, 21325),
+    (34243, 14938), (34243, 1538), (34243, 33967), (34243, 4867), (34243, 8394),
+    (34243, 8230), (34243, 19653), (34243, 19720), (34243, 16586), (34243, 3988),
+    (34243, 36970), (34243, 22842), (34243, 19021), (34243, 32385), (34243, 1814),
+    (34243, 21634), (34243, 13476), (34243, 12259), (34243, 39544), (34243, 16300),
+    (34243, 37852), (34243, 1890), (34243, 1890), (34243, 2700), (34243, 15405),
+    (34243, 1890), (34243, 20506), (34243, 30845), (34243, 18143), (34243, 19653),
+    (34243, 10530), (34243, 39514), (34243, 1890), (34243, 5152), (34243, 32968),
+    (34243, 9036), (34243, 37488), (34243, 5789), (34243, 29860), (34243, 10530),
+    (34243, 1675), (34243, 21481), (34243, 2242), (34243, 29893), (34243, 31433),
+    (34243, 22720), (34243, 27376), (34243, 1890), (34243, 35792), (34243, 19653),
+    (34243, 9912), (34243, 10434), (34243, 22720), (34243, 1538), (34243, 9036),
+    (34243, 25259), (34243, 10530), (34243, 17861), (34243, 3517), (34243, 29560),
+    (34243, 36058), (34243, 5598), (34243, 10530), (34243, 33967), (34243, 3702),
+    (34243, 30845), (34243, 7579), (34243, 21669), (34243, 10530), (34243, 34011),
+    (34243, 12049), (34243, 28978), (34243, 34011), (34243, 30732), (34243, 19456),
+    (34243, 25259), (34243, 34011), (34243, 32385), (34243, 12049), (34243, 1890),
+    (34243, 24232), (34243, 10530), (34243, 20482), (34243, 10530), (34243, 7528),
+    (34243, 19456), (34243, 25039), (34243, 17956), (34243, 28573), (34243, 1890),
+    (34243, 28222), (34243, 23848), (34243, 6073), (34243, 30789), (34243, 5598),
+    (34243, 28978), (34243, 31634), (34243, 33956), (34243, 18143), (34243, 19653),
+    (34243, 21325), (34243, 30845), (34243, 1538), (34243, 24089), (34243, 24274),
+    (34243, 21325), (34243, 18143), (34243, 11894), (34243, 1890), (34243, 38628),
+    (34243, 37040), (34243, 182), (34243, 1890), (34243, 1348), (34243, 1037), (34243, 5212),
+    (34243, 10530), (34243, 19653), (34243, 27434), (34243, 39183), (34243, 10530),
+    (34243, 25098), (34243, 32341), (34243, 10530), (34243, 10530), (34243, 34011),
+    (34243, 5836), (34243, 25470), (34243, 19584), (34243, 18186), (34243, 5836),
+    (34243, 1538), (34243, 10485), (34243, 10530), (34243, 18436), (34243, 20014),
+    (34243, 9781), (34243, 30845), (34243, 29302), (34243, 21669), (34243, 37040),
+    (34243, 10530), (34243, 28094), (34243, 36970), (34243, 9781), (34243, 10530),
+    (34243, 2700), (34243, 18290), (34243, 21325), (34243, 8111), (34243, 10265),
+    (34243, 22831), (34243, 34894), (34243, 28860), (34243, 13455), (34243, 5522),
+    (34243, 32063), (34243, 12049), (34243, 39670), (34243, 28860), (34243, 4205),
+    (34243, 3171), (34243, 19456), (34243, 38681), (34243, 10485), (34243, 1890),
+    (34243, 1890), (34243, 16611), (34243, 10530), (34243, 36970), (34243, 16792),
+    (34243, 32341), (34243, 38926), (34243, 32255), (34243, 10530), (34243, 18436),
+    (34243, 10771), (34243, 12203), (34243, 26830), (34243, 30303), (34243, 15405),
+    (34243, 9932), (34243, 17741), (34243, 10265), (34243, 13502), (34243, 32341),
+    (34243, 18551), (34243, 15198), (34243, 20216), (34243, 1890), (34243, 18991),
+    (34243, 34894), (34243, 32385), (34243, 38813), (34243, 5533), (34243, 32261),
+    (34243, 10530), (34243, 2242), (34243, 23406), (34243, 24215), (34243, 35192),
+    (34243, 13476), (34243, 21925), (34243, 34011), (34243, 21997), (34243, 28860),
+    (34243, 15171), (34243, 34248), (34243, 25039), (34243, 28978), (34243, 32341),
+    (34243, 10530), (34243, 17225), (34243, 19456), (34243, 8772), (34243, 32385),
+    (34243, 1890), (34243, 38344), (34243, 18551), (34243, 18143), (34243, 34011),
+    (34243, 32919), (34243, 2226), (34243, 18186), (34243, 17457), (34243, 28573),
+    (34243, 32341), (34243, 10530), (34243, 36483), (34243, 32385), (34243, 10530),
+    (34243, 28978), (34243, 13502), (34243, 32341), (34243, 19456), (34243, 12790),
+    (34243, 20247), (34243, 18991), (34243, 1890), (34243, 1538), (34243, 32552),
+    (34243, 28935), (34243, 10530), (34243, 18186), (34243, 25730), (34243, 20359),
+    (34243, 6771), (34243, 37040), (34243, 37759), (34243, 18692), (34243, 22438),
+    (34243, 5523), (34243, 10530), (34243, 9781), (34243, 32385), (34243, 19971),
+    (34243, 6073), (34243, 16792), (34243, 10530), (34243, 37488), (34243, 39514),
+    (34243, 1538), (34243, 8844), (34243, 19785), (34243, 15832), (34243, 34011),
+    (34243, 32255), (34243, 8111), (34243, 34011), (34243, 1890), (34243, 30205),
+    (34243, 37006), (34243, 21922), (34243, 31433), (34243, 16792), (34243, 1633),
+    (34243, 10530), (34243, 26530), (34243, 29459), (34243, 36970), (34243, 31965),
+    (34243, 35192), (34243, 18102), (34243, 24232), (34243, 24292), (34243, 1890),
+    (34243, 5295), (34243, 18102), (34243, 18197), (34243, 5036), (34243, 29560),
+    (34243, 15171), (34243, 27619), (34243, 25110), (34243, 38333), (34243, 35951),
+    (34243, 10530), (34243, 446), (34243, 182), (34243, 27904), (34243, 7571), (34243, 28978),
+    (34243, 19456), (34243, 13476), (34243, 7129), (34243, 19456), (34243, 3988),
+    (34243, 28573), (34243, 21481), (34243, 28514), (34243, 534), (34243, 31347),
+    (34243, 17856), (34243, 30364), (34243, 28141), (34243, 14837), (34243, 22919),
+    (34243, 17577), (34243, 12049), (34243, 1884), (34243, 10020), (34243, 5598),
+    (34243, 10530), (34243, 27035), (34243, 32919), (34243, 21656), (34243, 16754),
+    (34243, 30364), (34243, 32385), (34243, 10530), (34243, 30607), (34243, 13476),
+    (34243, 18436), (34243, 28222), (34243, 34549), (34243, 25259), (34243, 18102),
+    (34243, 27434), (34243, 5789), (34243, 10530), (34243, 10530), (34243, 1538),
+    (34243, 34303), (34243, 32255), (34243, 20899), (34243, 31634), (34243, 9223),
+    (34243, 10530), (34243, 16406), (34243, 5152), (34243, 21325), (34243, 3959),
+    (34243, 32385), (34243, 5598), (34243, 29459), (34243, 13502), (34243, 38126),
+    (34243, 27123), (34243, 29560), (34243, 32255), (34243, 10530), (34243, 5789),
+    (34243, 35123), (34243, 39544), (34243, 16207), (34243, 2133), (34243, 38333),
+    (34243, 15171), (34243, 34011), (34243, 10530), (34243, 28514), (34243, 24215),
+    (34243, 9355), (34243, 13476), (34243, 18186), (34243, 1538), (34243, 40), (34243, 19653),
+    (34243, 28141), (34243, 34011), (34243, 29302), (34243, 16136), (34243, 29037),
+    (34243, 23932), (34243, 39544), (34243, 26987), (34243, 10530), (34243, 33577),
+    (34243, 23950), (34243, 6069), (34243, 39544), (34243, 772), (34243, 22364),
+    (34243, 38344), (34243, 10530), (34243, 1538), (34243, 10530), (34243, 26071),
+    (34243, 10530), (34243, 26073), (34243, 38333), (34243, 6453), (34243, 9781),
+    (34243, 34894), (34243, 5789), (34243, 8230), (34243, 21922), (34243, 4916),
+    (34243, 18991), (34243, 24099), (34243, 16207), (34243, 16369), (34243, 10802),
+    (34243, 25259), (34243, 10530), (34243, 19971), (34243, 12994), (34243, 10530),
+    (34243, 4377), (34243, 39538), (34243, 10530), (34243, 30152), (34243, 10530),
+    (34243, 1890), (34243, 10530), (34243, 1890), (34243, 1814), (34243, 32255),
+    (34243, 10530), (34243, 29302), (34243, 21656), (34243, 23418), (34243, 30364),
+    (34243, 7528), (34243, 32227), (34243, 39784), (34243, 12994), (34243, 30007),
+    (34243, 21877), (34243, 23408), (34243, 25737), (34243, 34011), (34243, 22093),
+    (34243, 10530), (34243, 22364), (34243, 32341), (34243, 32385), (34243, 32385),
+    (34243, 10530), (34243, 10530), (34243, 19653), (34243, 1538), (34243, 13476),
+    (34243, 37006), (34243, 28094), (34243, 26873), (34243, 16786), (34243, 24099),
+    (34243, 26873), (34243, 1890), (34243, 9355), (34243, 1890), (34243, 29560),
+    (34243, 36970), (34243, 9781), (34243, 18186), (34243, 39538), (34243, 20784),
+    (34243, 28978), (34243, 2347), (34243, 33939), (34243, 19456), (34243, 28056),
+    (34243, 19456), (34243, 37006), (34243, 36551), (34243, 19653), (34243, 8111),
+    (34243, 11283), (34243, 10530), (34243, 10530), (34243, 24215), (34243, 27434),
+    (34243, 36719), (34243, 28860), (34243, 13476), (34243, 1890), (34243, 14698),
+    (34243, 34072), (34243, 24215), (34243, 1890), (34243, 30226), (34243, 10530),
+    (34243, 35992), (34243, 26873), (34243, 2492), (34243, 32552), (34243, 20014),
+    (34243, 1890), (34243, 13476), (34243, 34229), (34243, 32341), (34243, 37040),
+    (34243, 15198), (34243, 36970), (34243, 28978), (34243, 10530), (34243, 34072),
+    (34243, 37040), (34243, 7528), (34243, 32341), (34243, 34353), (34243, 27797),
+    (34243, 16611), (34243, 5522), (34243, 10530), (34243, 28978), (34243, 2347),
+    (34243, 32341), (34243, 30356), (34243, 32341), (34243, 7659), (34243, 15832),
+    (34243, 24222), (34243, 19456), (34243, 16792), (34243, 34011), (34243, 21814),
+    (34243, 28978), (34243, 21818), (34243, 10530), (34243, 11703), (34243, 19653),
+    (34243, 19456), (34243, 36970), (34243, 24232), (34243, 5212), (34243, 19456),
+    (34243, 10020), (34243, 10530), (34243, 34011), (34243, 8230), (34243, 10530),
+    (34243, 9932), (34243, 31550), (34243, 19456), (34243, 1890), (34243, 37040),
+    (34243, 31359), (34243, 32385), (34243, 37861), (34243, 10530), (34243, 19456),
+    (34243, 1890), (34243, 39514), (34243, 10530), (34243, 1890), (34243, 1346),
+    (34243, 1890), (34243, 15736), (34243, 32385), (34243, 35908), (34243, 18436),
+    (34243, 28978), (34243, 39183), (34243, 5522), (34243, 20482), (34243, 28431),
+    (34243, 13476), (34243, 29560), (34243, 22364), (34243, 22098), (34243, 20899),
+    (34243, 19456), (34243, 16113), (34243, 18551), (34243, 10530), (34243, 16792),
+    (34243, 10771), (34243, 11703), (34243, 28978), (34243, 25259), (34243, 19653),
+    (34243, 10530), (34243, 34011), (34243, 21325), (34243, 7461), (34243, 12835),
+    (34243, 16792), (34243, 23064), (34243, 1063), (34243, 3186), (34243, 9036),
+    (34243, 17543), (34243, 19456), (34243, 10530), (34243, 35381), (34243, 39828),
+    (34243, 28860), (34243, 6450), (34243, 18436), (34243, 10530), (34243, 13669),
+    (34243, 182), (34243, 3031), (34243, 29459), (34243, 11703), (34243, 5598), (34243, 2492),
+    (34243, 24476), (34243, 18436), (34243, 37040), (34243, 23846), (34243, 29459),
+    (34243, 32617), (34243, 7528), (34243, 16792), (34243, 15832), (34243, 24493),
+    (34243, 23705), (34243, 1538), (34243, 39544), (34243, 28978), (34243, 1890),
+    (34243, 19456), (34243, 7461), (34243, 1538), (34243, 26873), (34243, 17726),
+    (34243, 23523), (34243, 21656), (34243, 15319), (34243, 28351), (34243, 11601),
+    (34243, 33670), (34243, 31104), (34243, 7129), (34243, 11590), (34243, 4595),
+    (34243, 1346), (34243, 27035), (34243, 36970), (34243, 15332), (34243, 28978),
+    (34243, 27617), (34243, 17383), (34243, 1912), (34243, 32865), (34243, 12049),
+    (34243, 34072), (34243, 27193), (34243, 7571), (34243, 31563), (34243, 38865),
+    (34243, 8628), (34243, 3186), (34243, 19456), (34243, 26863), (34243, 35445),
+    (34243, 34391), (34243, 864), (34243, 9093), (34243, 32341), (34243, 29560),
+    (34243, 19456), (34243, 34301), (34243, 16137), (34243, 25098), (34243, 32385),
+    (34243, 10530), (34243, 30845), (34243, 31634), (34243, 29860), (34243, 34011),
+    (34243, 1890), (34243, 27376), (34243, 19653), (34243, 11247), (34243, 26863),
+    (34243, 5836), (34243, 21325), (34243, 5789), (34243, 34409), (34243, 38094),
+    (34243, 10530), (34243, 20482), (34243, 29893), (34243, 1890), (34243, 17956),
+    (34243, 19456), (34243, 32385), (34243, 35460), (34243, 1890), (34243, 27035),
+    (34243, 30152), (34243, 10530), (34243, 18057), (34243, 10020), (34243, 10530),
+    (34243, 27149), (34243, 10530), (34243, 19456), (34243, 28978), (34243, 32385),
+    (34243, 31104), (34243, 182), (34243, 29893), (34243, 18197), (34243, 20247),
+    (34243, 21997), (34243, 10530), (34243, 27434), (34243, 10530), (34243, 24493),
+    (34243, 38333), (34243, 2492), (34243, 10946), (34243, 12049), (34243, 21634),
+    (34243, 36970), (34243, 15464), (34243, 10530), (34243, 32385), (34243, 38292),
+    (34243, 10530), (34243, 30845), (34243, 5295), (34243, 35123), (34243, 17791),
+    (34243, 28056), (34243, 29417), (34243, 7528), (34243, 12034), (34243, 10530),
+    (34243, 10530), (34243, 23950), (34243, 36603), (34243, 17225), (34243, 37040),
+    (34243, 19653), (34243, 13476), (34243, 12185), (34243, 10530), (34243, 32952),
+    (34243, 31563), (34243, 3132), (34243, 10530), (34243, 28860), (34243, 5598),
+    (34243, 39828), (34243, 10530), (34243, 18186), (34243, 27434), (34243, 28063),
+    (34243, 10530), (34243, 7175), (34243, 26873), (34243, 1890), (34243, 18102),
+    (34243, 19456), (34243, 27035), (34243, 8376), (34243, 36154), (34243, 33967),
+    (34243, 39538), (34243, 18013), (34243, 9107), (34243, 36970), (34243, 28978),
+    (34243, 32255), (34243, 29302), (34243, 10530), (34243, 21773), (34243, 28978),
+    (34243, 10530), (34243, 31784), (34243, 10530), (34243, 38411), (34243, 1470),
+    (34243, 18436), (34243, 33777), (34243, 12049), (34243, 1346), (34243, 28978),
+    (34243, 28978), (34243, 18414), (34243, 10530), (34243, 1890), (34243, 13476),
+    (34243, 37847), (34243, 20482), (34243, 1890), (34243, 39538), (34243, 16927),
+    (34243, 34011), (34243, 22424), (34243, 19653), (34243, 18102), (34243, 18436),
+    (34243, 12621), (34243, 5187), (34243, 1538), (34243, 18102), (34243, 5075),
+    (34243, 9036), (34243, 29417), (34243, 9865), (34243, 13502), (34243, 28978),
+    (34243, 1890), (34243, 1538), (34243, 27149), (34243, 1890), (34243, 6655),
+    (34243, 22919), (34243, 27434), (34243, 9749), (34243, 22842), (34243, 14837),
+    (34243, 37488), (34243, 19456), (34243, 3132), (34243, 21669), (34243, 10530),
+    (34243, 3524), (34243, 10530), (34243, 2592), (34243, 32385), (34243, 29560),
+    (34243, 39183), (34243, 20550), (34243, 19653), (34243, 28222), (34243, 32385),
+    (34243, 32385), (34243, 5013), (34243, 30633), (34243, 10530), (34243, 32341),
+    (34243, 1675), (34243, 38344), (34243, 28300), (34243, 23338), (34243, 13502),
+    (34243, 10530), (34243, 30364), (34243, 32968), (34243, 19456), (34243, 22720),
+    (34243, 13899), (34243, 5598), (34243, 10530), (34243, 10530), (34243, 31005),
+    (34243, 39544), (34243, 36475), (34243, 39538), (34243, 10530), (34243, 23212),
+    (34243, 15439), (34243, 32085), (34243, 25259), (34243, 23633), (34243, 36970),
+    (34243, 6060), (34243, 1890), (34243, 35344), (34243, 1890), (34243, 21325),
+    (34243, 22912), (34243, 27680), (34243, 19428), (34243, 28978), (34243, 13124),
+    (34243, 28539), (34243, 15578), (34243, 10530), (34243, 35792), (34243, 18186),
+    (34243, 29893), (34243, 28978), (34243, 22775), (34243, 1912), (34243, 13732),
+    (34243, 19653), (34243, 34011), (34243, 28978), (34243, 8394), (34243, 1890),
+    (34243, 15198), (34243, 12396), (34243, 22919), (34243, 27617), (34243, 28222),
+    (34243, 5152), (34243, 11703), (34243, 18186), (34243, 1890), (34243, 21656),
+    (34243, 19456), (34243, 37006), (34243, 26840), (34243, 10530), (34243, 39514),
+    (34243, 19456), (34243, 39646), (34243, 25701), (34243, 7528), (34243, 881),
+    (34243, 5295), (34243, 33586), (34243, 1538), (34243, 13587), (34243, 10530),
+    (34243, 1538), (34243, 32911), (34243, 12607), (34243, 26961), (34243, 37040),
+    (34243, 20784), (34243, 37856), (34243, 32385), (34243, 36571), (34243, 1890),
+    (34243, 29459), (34243, 10530), (34243, 34011), (34243, 37040), (34243, 21656),
+    (34243, 10530), (34243, 16668), (34243, 1538), (34243, 1890), (34243, 1826),
+    (34243, 10530), (34243, 1538), (34243, 10530), (34243, 10530), (34243, 18300),
+    (34243, 32952), (34243, 13476), (34243, 10530), (34243, 9222), (34243, 10530),
+    (34243, 5043), (34243, 1814), (34243, 28300), (34243, 9745), (34243, 29893),
+    (34243, 10530), (34243, 27193), (34243, 32385), (34243, 13502), (34243, 10530),
+    (34243, 18436), (34243, 28978), (34243, 28214), (34243, 27680), (34243, 32952),
+    (34243, 18920), (34243, 10530), (34243, 9932), (34243, 36023), (34243, 6073),
+    (34243, 19428), (34243, 13392), (34243, 7528), (34243, 16300), (34243, 10530),
+    (34243, 10530), (34243, 10530), (34243, 29459), (34243, 1538), (34243, 3500),
+    (34243, 38344), (34243, 12337), (34243, 10530), (34243, 21325), (34243, 13476),
+    (34243, 5789), (34243, 23710), (34243, 35611), (34243, 10530), (34243, 30845),
+    (34243, 17225), (34243, 28978), (34243, 30845), (34243, 1670), (34243, 13476),
+    (34243, 12465), (34243, 20985), (34243, 1814), (34243, 28978), (34243, 10530),
+    (34243, 29417), (34243, 7528), (34243, 10530), (34243, 10434), (34243, 9912),
+    (34243, 6054), (34243, 13476), (34243, 28978), (34243, 23975), (34243, 38333),
+    (34243, 2027), (34243, 36154), (34243, 19653), (34243, 19456), (34243, 20247),
+    (34243, 8070), (34243, 1890), (34243, 10530), (34243, 9355), (34243, 2133),
+    (34243, 32341), (34243, 5836), (34243, 17832), (34243, 35832), (34243, 3132),
+    (34243, 19456), (34243, 37488), (34243, 17741), (34243, 7528), (34243, 21656),
+    (34243, 32552), (34243, 21656), (34243, 7066), (34243, 5598), (34243, 29583),
+    (34243, 10530), (34243, 10530), (34243, 4102), (34243, 28056), (34243, 13502),
+    (34243, 1890), (34243, 6663),];
+pub static NAME2CODE_CODE: &'static [char] = &[
+    '𝦉', '⟳', '🎳', '𓰝', '𛲈', 'ᙻ', '⪆', '𘢮', '𑐁', '𑘤', '🥡',
+    '𑁨', '𓎈', 'ꑻ', '𝢮', '🖱', '𝓌', '⑼', '🕤', '⇚', '🮽', '𝂶',
+    '𐩇', '🛃', '𑥖', '𑀕', '🏬', 'ヘ', '𝥶', '𓞎', '\u{616}', '𑯃', '𓴁',
+    '瀛', '𐫰', 'ꪋ', '𝦚', '🏮', '𔐁', '🦈', 'Ƃ', '🯗', '勺', '𓋢', 'Ꙃ',
+    '𜺧', 'Ԟ', '𝞉', '𝣔', '𓲹', '𓺕', '𓒃', '𔉶', '𔉲', 'ಶ', '𓟹',
+    '𐴁', '𑀅', 'ϋ', '𔘪', '\u{11444}', '𓵾', '𑼳', '𞤼', '𓍝', '𝚪', 'ڮ',
+    '𐕏', '𓑠', '𓋥', '➣', '༫', '𑘚', '⹐', '𒐨', '𔖁', '𝂒', '\u{11830}',
+    '𓺞', '🧆', '𓹬', '𓜉', 'ᶧ', '𒂗', '𐀣', '𘰵', '︑', '⭛', '𓌪',
+    '𖬒', '䷗', 'ꏝ', 'ᾩ', '𑘐', 'ꪇ', 'ᖳ', '𓳣', '賂', '𓆥', '\u{e0117}',
+    '⊚', '\u{2dee}', '𝣾', '蜨', '🭅', '𒾐', 'ꄼ', '𝢟', '𓋳', '﨤', '虜',
+    '𑰎', '𐇢', '𝔸', 'ᕸ', '𑼮', 'ᙄ', '⚝', '𓰋', 'ᙂ', 'ݝ', '𝐀', '菌',
+    '𓒩', 'ꝍ', '𘥍', '𒈧', '𓬴', '𖡲', '🯒', '𜲏', 'Ṛ', '𓣩', '𓸘',
+    '🖏', '𝈒', 'ﻈ', 'ꣽ', '𑗌', '𓈦', '🐔', '𔆁', '🩂', '𓣮', '𒃠',
+    'ᴱ', '𒊺', '𘬓', 'ꁃ', 'ᜢ', '𑼘', '🎹', '𓩺', '𓒪', '𜾣', '⺤', '㎉',
+    '𘨖', 'ᵢ', 'Ⰶ', '𒁃', '𜹻', 'ᬣ', '𐘦', '𐜦', 'ᔻ', 'ꖑ', '𔆙', '𛅷',
+    '🎁', '𖹫', '\u{a94f}', '🚺', 'ʢ', '🅒', '𔒡', '˃', 'ㄐ', 'ㄈ', 'ꪱ',
+    '𔆞', 'ㄗ', '⤣', 'ㄖ', 'ꑉ', 'Ꮤ', '\u{323}', 'ᨲ', '𓲀', '\u{1cf23}',
+    '\u{898}', '\u{fa3}', 'Ꭷ', '🥮', '𓦊', '𒀐', '𐃓', '𐾴', '🥌', 'Ȥ', '𐌙',
+    'ⴖ', '𐰮', 'ᒑ', '𑅫', '🟢', 'ꊏ', '𓕩', 'ﰈ', '𖤭', '𐖧', 'ꏳ',
+    '\u{1da54}', 'હ', 'ᾰ', '𓌄', '🌓', '\u{e002a}', '𒔐', '𔋅', '\u{1da6c}',
+    '𐓲', '𓷟', '㤺', '𝚫', 'ꆶ', '⋋', 'ᲅ', '䷯', '𔍭', '𜵑', '𓝧', '█',
+    'ꆤ', '🝬', '\u{f9d}', 'ﴹ', '𐋵', '築', '⮤', '𛀗', 'ๅ', '𒌅', '𝋯',
+    '➯', 'Ӓ', '↧', 'ꇱ', '\u{202f}', 'Ӎ', '\u{11d36}', '⪻', '🚗', '🯨', '𒃁',
+    'ﴅ', 'ܮ', '༆', '𑇳', 'ﻄ', '⸮', 'ń', '𖹇', '𐄗', 'ﶨ', 'ឫ', 'Ჺ',
+    '𔊂', 'ⶽ', '𒇘', '嶲', '𐩪', '🙠', '𘯯', '\u{1ab2}', '𓺅', '𓺁', '𓳀',
+    '𔗊', '𔕐', '𘯠', '𑋎', '𓃍', '⺿', '𑘆', '𝗫', '𜵴', 'ҩ', 'ԩ', 'ꓑ',
+    '᧘', '𛃿', 'ⓡ', '𑲋', 'ꔥ', 'လ', 'ⴣ', 'ꉒ', '𓜩', 'ƙ', '𑆯', '𑁙',
+    'ᵥ', '𘭎', '𞡬', '𓡭', '༈', '﨔', '\u{aa7c}', '㉋', '𖤱', '𒓦', 'ㅀ',
+    'ᖇ', '🔨', 'ഺ', 'ά', 'ꨦ', '⩢', '🌛', '🭼', 'Ꟈ', '﷾', '𓒥', '🗉',
+    '\u{113c9}', '𐇙', 'ꎄ', '\u{e01c9}', '𝟀', 'ﴼ', 'ਜ', 'Ẵ', '🩤', '\u{e013f}',
+    '\u{1bab}', '⠉', '𑃰', '𞗚', '𓦰', '𐀆', 'ᯊ', '𝒈', '𖪉', '𐤁', '𛰐',
+    '｛', '𒊚', '𘱖', 'ᣫ', '\u{11a96}', '𐑼', '🠤', '𓿸', '\u{e01ed}', '𓳇',
+    '\u{d4d}', '𞹵', '𞹛', 'ᡟ', 'ﴇ', '𓷞', '𒔷', '⭢', '𝋀', '𑐆',
+    '\u{fe01}', 'ꋹ', '𓀥', '𔑤', '𐫍', '𔌇', '𔍏', 'ᅠ', '⣊', '𔖬',
+    '\u{1bef}', '𒒩', 'Ꟶ', '𞠻', '⟡', '𖭑', '𞁦', '꒲', '∛', 'ୡ',
+    '\u{116ab}', '𝑽', '\u{2029}', 'ꡞ', '🔺', '܋', 'ﱣ', '𔌅', '𛉩', '┠',
+    '𛆇', 'ꈘ', 'Ꞽ', '｜', '𑻲', '𓝄', '𓈌', 'X', 'ꛨ', '𒊴', 'Ɲ', '╶',
+    '\u{a950}', '\u{f77}', '🧹', '𐊼', '𐿭', '⪧', '𜸃', '𐂨', '𐂬', '𦌾',
+    'ӝ', '𐃚', '𐃖', '\u{a9b8}', 'ɲ', '𓔚', 'ᬰ', 'ᛴ', '𓷤', '𓷠', 'ꈿ',
+    '𝓓', 'ꡔ', '\u{ecc}', '\u{1daa2}', '𔏐', '𝄺', '𓔑', '𔋎', '𖼜', '𘦶',
+    'ᇓ', 'ᶈ', 'ꢱ', '𔆄', '⩞', '᠖', 'Ⴟ', 'ꪣ', '𑁵', '𒃇', '𓲅', '𓛢',
+    '⧍', '﹏', 'ꧏ', '𝣸', '𓥇', '㊑', '🪞', '𔍲', '҂', '𝖏', 'ॡ', '𐤤',
+    '𞠚', '\u{11d31}', '𑐡', '𒈃', '愈', '𒆩', '𔊳', 'ሪ', '𒔴', '𓻰', 'Ű',
+    'ᣀ', 'ݯ', '🞝', '𓭑', '𒓪', '𒓫', ' ', 'ꃸ', '🄠', '🙱', '𔉟',
+    '\u{1b6b}', '𒐷', '𜱹', '𐫒', '\u{1d1ac}', '𐇨', '𔓆', '𑦱', '𔂴',
+    '\u{346}', '𓕓', '⟣', '🥨', '⳺', '辶', '𘬱', '𝌡', '𓰟', '令', '᭡',
+    '𓇙', '𐄩', '𛋤', '𜰚', '⪳', '𓍕', '𓋧', 'မ', '𝗤', '𑧌', '𝗬',
+    'ઝ', '𑆚', 'ᖁ', '💶', 'ﮂ', '𝁽', 'ꊀ', '𑨹', '𒔖', '𓣉', '𞤤', 'ꍢ',
+    '𓱦', '𑄙', '㉒', '\u{305}', '𒄌', '\u{1d18a}', 'Ӯ', 'ᯂ', '\u{8cb}', 'ﬤ',
+    'Ṁ', '⻓', '𒃹', '🭯', '𐎍', '⫼', '𔑡', '𐄪', '𜱐', '𜺋', '𖩸',
+    'げ', '𘤉', '◉', '\u{7a8}', '\u{e0187}', 'Ð', '𝃪', 'ꤠ', 'ﶀ', 'ጹ', '𝧇',
+    '𘣨', '𑱔', '𔔿', '𓔏', '𓧦', '\u{31e}', '᱕', '𞠬', '\u{89e}', 'ᒹ',
+    '𓏷', 'ﺶ', '𐎙', '᮱', '𝧛', '𘧾', '𒈔', '𑪛', '𐰁', '␚', 'ꂎ', '㌴',
+    '𔌞', '⁷', '𘧣', '𐓁', '𔌕', '΅', 'ꏔ', 'ᯝ', 'ꁱ', '🂌', '🝾', '➇',
+    '𑠔', '𘥌', '⁛', 'ꔭ', '𑰬', '፸', 'ꯆ', 'ᠭ', '𘨪', '′', '𝑊', '⇕',
+    '𝓫', 'ꥱ', '☐', '𝓿', '𐵃', '꩓', '🛍', 'Ẁ', 'ᴕ', '𔘱', '𓭚', '⎬',
+    'ᢉ', '➋', 'ᗾ', 'ﻓ', '𛄌', '𛆜', '𖽞', '𞄄', 'ь', '𝕆', 'ꆷ', '𐕝',
+    '/', '\u{17cd}', '𔄰', '𐐎', '𒀪', '𐎏', '𒒞', '𛆑', '𓑁', 'ྈ', '𓓘',
+    'ᮇ', 'ヨ', '𐳼', '𔅄', '𔁢', '𝢐', '𔃨', '🏍', '𝄘', '\u{e004d}', '🖸',
+    '𘩣', '𖹡', '𐻄', '𐏈', 'ﰵ', '𑛃', '\u{a9b9}', '𓔅', '𑱥', '𑘦', 'お',
+    '\u{8e0}', 'ꁐ', 'ꁑ', '掩', '撝', '🦺', '𘩡', '𛀪', '𓔎', 'ᜋ', '𔊪',
+    '꒪', '〹', '⿂', '🂋', '🂇', '𖬷', 'ⱟ', '𐿮', '𑃣', 'ﳢ', 'ꛛ', '﮾',
+    '𒆼', '𓙥', '┇', '𐩅', '𔈰', '𔈴', '🀧', '𘯈', '𓔌', '\u{aa31}', '𔔗',
+    '𘤭', '𘰫', '⨅', '𐁙', '𑧅', '\u{193b}', '𓜊', '𑼖', '𖧋', '⬴', '𜲨',
+    'ꋕ', '⽂', '𜹈', '𒓝', 'വ', '𓓎', '𘯂', 'ꀙ', 'Ṙ', '\u{1193c}', 'ᜬ',
+    'ሲ', '𔓡', '𛆽', '𖼨', 'ᑫ', '𘬥', '並', '⅓', 'Ǵ', '𝠀', '𐌁', '𓈫',
+    '📄', '𝙖', '𘲿', 'ቯ', 'জ', '♴', '\u{1dd6}', '𓷮', '௺', '𝥑', '𓾎',
+    '𐼈', '𐳖', '⍰', '⎎', '🪯', '𜷹', '㏓', '𐇲', '\u{fe2b}', '𒆀', 'ꬁ',
+    '↱', '𑜦', 'ܒ', '\u{11636}', '𖤼', '🨙', 'ዅ', '𑱽', 'せ', '𔒭', '᥀',
+    'ៅ', 'ꥆ', '𐳯', '𐳮', 'Ï', 'ᤴ', 'ޚ', '𐭽', '↜', '🈟', 'ㇵ', 'ꚧ',
+    '𞲯', '隆', '𔀽', '𘪎', 'ﱬ', '⡾', '💀', '➷', 'Ꮓ', '𓎳', '𝋤', 'ᢥ',
+    '𓹚', '𓧔', '𓌲', '𖧃', '𐋴', '⢚', '𑄑', 'ㅆ', '🐘', '𓆖', '᧱',
+    '𑁲', '𖥴', '\u{b62}', 'ᛝ', 'ڑ', '𑰧', 'ꤝ', '⣧', 'ዎ', 'ნ', '𝒑',
+    '𐧊', 'Ⅻ', '𖨧', '𐘾', '𐊆', '░', '𘢰', '𐎮', '𞋠', '\u{1ac8}', '𐤂',
+    'ᩓ', 'ᤑ', '😥', 'ﴰ', '䷪', '\u{309}', '⏪', 'ぁ', '𝝬', '𞅄', 'ꛍ',
+    '𝇜', '𖨷', 'ቊ', '𓬿', '𔒜', '🈐', '◲', '𓝠', '🟣', 'ӽ', '𓟛',
+    '𝆖', '𓨲', '𓇵', '〟', '𝘇', '↺', '𜶺', '𒂳', '∍', 'ጛ', '𞢙',
+    '𔈀', 'ᩐ', '⯖', 'ᅼ', '𑊄', '𝧣', '𑩟', '𐃳', '𑶀', '𖦅', '𓠰',
+    '⏯', '⼗', '𓠽', '𓖶', '🤊', '🧱', '𔃆', '𔃂', '𒄺', 'ກ', '𝋆',
+    '𓥰', '⡄', '𑘞', 'я', 'ɕ', '⾀', '🨡', 'ꋥ', '𓶘', '𘢈', '𔔋', '⌂',
+    '𓀎', '𓜥', 'ည', '⊄', 'ὧ', 'ﲘ', 'ҏ', '😷', '𑴎', 'ś', '𞄁', 'ꀩ',
+    '🬷', '𛋄', '𞣉', 'ꮡ', '𓤨', '𐭂', '𛰆', '🩏', '𔎒', 'ꢢ', '᥍',
+    'ဘ', '⼱', '𝈽', 'ഃ', 'ꙇ', '೦', '⧎', '⡼', 'ჵ', '\u{13433}', 'ꏓ',
+    '𔏸', '˨', '𒒘', '𓽜', 'Ά', '𘰜', '\u{1dc5}', '😇', '𝀧', '︹', 'ಏ',
+    '𐑱', 'ⅉ', '⫏', 'ﯮ', '\u{e0120}', '㈂', 'ẋ', 'ꗭ', '𒉼', '\u{8d8}', '୭',
+    '𓰰', '𑶢', '𒊍', 'ᙪ', '𦖨', '≽', '𜺉', '𑎝', '𘩉', '𝄼', '𝚼',
+    '⎅', '𜳯', 'ᔶ', '𘣃', '𝦙', '𝦏', '𑲏', 'ᓫ', '𓟤', '𛇹', '\u{e0026}',
+    'ඬ', '෫', '𝼈', '𝛄', '𞥁', '𓬉', '╛', '𓶁', '裏', 'ㅎ', 'ః', '𖼳',
+    '\u{200b}', '𒀭', '𜱋', '𒓆', '𝥨', '𛰇', 'ࢰ', '𝔣', 'ᱸ', 'ঈ', '𓞉',
+    '⼷', '𒂟', '𑱢', '𞡙', '\u{1da26}', '𝃁', '𓌣', '⎱', 'ష', 'ﴋ', '𓻁',
+    '£', '𞠟', '⣶', 'ᖚ', '𝣢', '𖼧', '\u{e013c}', 'ﱓ', '𔂇', '𐣴', '𖹝',
+    '𔑪', 'ࣂ', '\u{1343d}', '𓆔', 'ս', '𐽕', 'ዼ', '🭨', '𓦠', '𐃣', '𐄺',
+    'Ⅸ', 'ퟂ', '𐰂', '𐲩', '𓸄', '𓸀', '📔', '𝛶', '𝖌', '𒇡', '𝁠',
+    '𐹽', 'ࡽ', '𓶒', '𐮁', '𑂧', '𒍃', '𔂀', '𐺏', '℃', 'ᘖ', 'ᩰ',
+    '\u{1103b}', '𑨥', 'ᮮ', '𐧖', '𓶟', 'ᔰ', '𖢹', '\u{1a6a}', '\u{736}', '🤞',
+    '𑅱', '❢', 'ﺯ', 'ɇ', '𓠆', 'ჱ', '🡪', 'ꇨ', '⯁', '𐺎', '𞡨', '⮫',
+    '🛟', '𘤨', 'ඪ', '𞱵', '𜶣', '㈀', '𞠞', 'ꑨ', '\u{e35}', '𐵎', 'ﭺ',
+    '𐺝', '𐖑', '𝇎', '𒌽', '𓛞', 'ણ', '༚', '꒫', '僧', '\u{e007c}', '⻧',
+    '𞹢', '𜴡', 'ꄖ', '𝁫', '㋣', '𝤸', 'ⅼ', '𝑰', 'ꅞ', '𜾉', '⽢', '𒆦',
+    '𘠑', '🇼', '𔍕', '⊂', '𒍪', '𛋻', '🇰', '🇴', '፮', 'ᯣ', 'ᘴ', '૭',
+    '𔓨', '🜹', '𐡠', 'દ', '𐅃', 'ꩥ', '𒔜', 'ᮚ', '\u{1e4ee}', '𝅑', 'ᝋ',
+    '𔎘', '𞋕', '𓔧', 'ᆽ', '𝅬', '\u{1cf35}', '𛋴', '𒂸', '𜺏', '𛇔',
+    '𑌪', 'ﲀ', '㌐', '𐃱', '𑩗', 'ۥ', 'Ἓ', '𐕯', '𜳍', '৬', '⦍', 'ꓼ',
+    '\u{11ca1}', '∁', '\u{311}', 'Ꭰ', '𖄶', '𛇖', '\u{e01c0}', 'ﻶ', '⽋', 'ⓟ',
+    '𝥔', 'ᅶ', 'ꇥ', '𓳨', 'ꯄ', '🛷', '🞐', '𞲄', '𔈺', '३', '\u{10d26}',
+    '⼇', 'ꡬ', 'ﮄ', '𖢉', '\u{e0177}', '𝈂', '𐌄', '𒀃', '￨', '𑐠', '︓',
+    '𓧀', '𐑍', '𐮂', '𓴘', '\u{fa1}', '⚠', '𓟖', '🔻', '㉹', '𑻭', '𞤐',
+    '０', '𐵓', 'ﱎ', '𖤜', '🅚', 'ꯛ', '𐵿', 'ꕙ', '𓇠', '\u{1cf43}', '𞀵',
+    'み', '⏈', 'ﰉ', '\u{113be}', '𐒤', '𐲋', '𝔘', '𑫟', '𐞠', '\u{1e8d2}',
+    'ꄩ', '۔', '𓇼', '𑂲', '𐹾', '𐒴', '𔐎', 'ᾫ', '𜾎', '\u{aa2c}', 'ウ',
+    'Ẫ', '◥', '𔖈', 'ᇤ', '𓻊', 'ب', '𘣂', '🭔', '𑐝', 'ﺔ', '⇪', '𔊀',
+    '🥝', '𓪔', '\u{7eb}', 'ϸ', 'ᯞ', '𝇚', 'ꪭ', '𔍇', '𞢥', '㇚', '𓴥',
+    'ᣈ', '𖢯', 'g', '𛂱', 'ﯨ', '𑯴', '\u{1ac4}', '൏', '𜰈', 'ｋ', '౧',
+    '𓲶', 'వ', '🌉', '荣', '菧', 'ᄣ', '𐜐', '𐜔', '𔍁', '👯', '\u{f8f}',
+    '捻', '𔍊', '𖾛', '𛈞', '๑', '𓠝', 'ꅖ', '𓛋', '𒿕', '𔇞', '𖭒',
+    'ᰌ', '\u{d3e}', 'ᥓ', '🧶', '\u{10f4c}', '𓨼', '𐭊', '𐺔', '𖠷', 'ቍ',
+    '𝇟', '錄', 'ḭ', '\u{1d169}', '𐐊', 'ꏂ', '⎉', '𔂦', '𑃚', '🍑', '𐕍',
+    '𞠌', '𛆤', '𖫉', 'ᡕ', 'ᭁ', '⥒', '𓚞', '𖣛', '𐫜', '\u{e0142}', '𐱄',
+    '𑖄', '𣪍', 'ຎ', '𝙪', '𖵠', '𐩴', '𑂪', '࿕', '𜶲', '𐪐', '𔍮',
+    'ી', '🪗', '𓇔', '𔍥', '𐳇', '𓢟', '𓆴', 'ﲞ', '֏', '⏵', 'ꇿ', 'ቹ',
+    '𔓺', 'ḡ', 'ꑥ', 'ಗ', '𔃯', '\u{13451}', 'ܘ', '𘫆', 'ᨉ', '⨢', 'ꁇ',
+    '慎', 'ᝪ', '𓊮', '⋃', '𐪔', '\u{1e8d5}', 'ꚪ', '🞪', 'ﺐ', '𘠡', 'Ⰰ',
+    '𑥄', 'Ⓗ', '🡾', 'ꄵ', '⽽', '磌', '🥾', 'ຯ', '𝡞', '\u{20d2}',
+    '\u{11c98}', '♡', '𒔿', 'ﰦ', '⓸', 'ﵚ', '\u{1da3b}', 'ꡛ', 'ꖂ', '𐊜', '⒓',
+    '⪫', '𘮒', '𑨕', '𛁒', '𓶮', 'ﳶ', 'ꯠ', '♊', '𖽟', 'ꚬ', '𔊟', '𜴹',
+    'ꃘ', '𐭚', 'ﶂ', '𝂹', '⑈', '𝢥', '🅖', '🛄', '𓰔', '𑫠', '㈩',
+    '𑀴', 'ꟶ', '𒃮', 'ြ', '𘨸', '\u{11a8c}', '🄫', '𐁆', '𛇯', '𓦕', '𝌕',
+    'ⶎ', 'մ', 'ꖴ', '𑇮', '𖫐', '\u{11f41}', '𓦲', 'ﳹ', 'Ⱄ', '⠥', '⤿',
+    '𖡼', '𘲊', '𑪾', '冬', '⧮', '⓰', '𔔦', '𔃺', '⤁', '\u{fe25}', '⟵',
+    '𑰑', '𓿝', 'ꏩ', '𓽽', 'ι', '🔊', '⬿', '𝚇', 'ꔹ', '𘦍', '𓴅',
+    '鋗', '♅', '∉', 'ܜ', '𔕵', '⁵', '𑂟', 'ि', '𒈈', '𓓈', '𐅐', '𓎰',
+    '𔍙', '𑄔', '\u{aab8}', '𓗙', '𐦮', '𐋨', 'ឩ', '𑯲', 'ៜ', '⢧', '⌗',
+    '𒄩', 'ꡥ', 'ḋ', '𜺗', 'Ǿ', '𓅽', '𔆷', '𑁱', 'ᰨ', '𘬆', '⁕', '𑢶',
+    '𓀆', '᯾', '𐕊', '𝂤', '𐔹', 'ᯢ', 'ꨕ', '\u{105e}', '𐂰', '𖥖', 'ﳴ',
+    '𑤉', 'ᱮ', 'ᨓ', 'Ⓐ', '𔀃', '⡨', '𖫃', '𐮫', '☥', '𩖶', 'Ɔ', '𔑚',
+    ',', '᪩', '㏥', '↼', '𒑀', '𑱝', '꠳', '𜸋', 'ꦥ', '𐩣', '𓐍', '\u{734}',
+    '𐰢', 'ᚼ', '𑩦', '𒒷', '╊', '𒌤', '𓇥', '𐰚', 'ᣣ', 'ﴱ', '𔋾', 'ࠐ',
+    '𓻖', '𝘼', 'Ꞡ', '\u{981}', 'ⵢ', '⾮', '꒠', '𖢧', 'ꛚ', '_', '𑼋', '⯉',
+    '勇', 'ꁤ', 'ퟞ', 'ᙎ', '𦾱', '⟫', '𔃐', '𓥲', 'ᯁ', '蝫', '𒀨', '᎐',
+    '⩵', '◱', '𓏌', '脃', '𐲐', 'רּ', 'ᖯ', '𐲉', '𔔭', '䷇', '𛀥', '𝞒',
+    '𝆹', 'ꃓ', 'Ꜣ', 'Ⴧ', '㊥', '𝙌', '⚸', '𑪱', '𓌅', '𓫁', '\u{1a69}',
+    '𔉵', '﹈', '𔊍', '𖄔', '𓄻', '⋥', '𐞀', '𑠉', '𓆦', 'ၢ', '𔔾',
+    '🤖', '𐀪', 'ｖ', '㍙', '𘮼', 'ﾺ', '𓯾', 'ᭊ', 'ﯯ', 'ꓬ', '𒌖', '@',
+    'ㅐ', 'ߴ', '𓞿', 'ꤤ', '𞡌', '𝨺', '𓞶', '𘮄', '𑼣', '୰', 'Ň', '𔇩',
+    'ᙠ', 'ᠷ', '𜰑', '🥷', '𑛖', 'ﳻ', '⻩', '↦', '𝈳', 'ᐷ', '🧍', '𝇗',
+    '⢕', '𔔳', 'ᾏ', '𐃏', 'ꖱ', '𒀋', 'ꚃ', 'Ģ', '𓜅', '𐍣', '𝢼', '𛱏',
+    '𞊡', '𐍔', '𞊭', '𘬐', '🔩', 'ᭇ', '𝙫', '𓱽', 'ኦ', 'ꤙ', '⒀', 'ӯ',
+    '\u{e0153}', '𞋓', '𜷗', 'ዘ', 'ᖹ', '𔍬', 'ㆪ', 'ἔ', '𒑴', 'Ꭾ',
+    '\u{1e012}', '𘬘', '𜴼', 'Ւ', '<', '㣇', '😊', '༰', '𓣛', '🍘', '𑖁',
+    '𑤱', '≘', 'ꢒ', '𝝲', '⫟', 'ᯌ', 'Ꭲ', 'Ʃ', 'ﲧ', '𔓵', 'ᴝ', 'ᛅ',
+    '\u{488}', '\u{e0025}', 'ℸ', '𓖆', '𘯾', '⧱', '𖬁', '𓂘', '𓨶', '𓧜',
+    '𒄆', '𒅖', '𘱷', '⪌', '✒', '\u{113c0}', '\u{1bf1}', '𑱺', '𘢦', '𐣣',
+    '⍂', '𔓜', '𝆃', '⥔', '𐦬', 'ᶻ', '𓬗', '𛈾', '📦', 'ຝ', '𑇥',
+    '𑆆', '𑎜', '𘭣', 'റ', '🩇', '𒌠', '𒂤', '𛅴', '🛔', 'ꛅ', '𐿲',
+    '𛇀', '𞲲', '🚝', 'ⷅ', '㍈', '𐩭', '𓜾', '𓜺', 'ꋯ', '𜰏', '𖨇',
+    '𞢑', '𘥱', '𓎝', 'ઉ', 'ᗶ', '𐎽', '𔄒', '✂', '𜷫', '༤', '𔄛',
+    '𑠎', '𐂺', '𖭾', '𐂕', '𐫵', '𓖪', '᧭', '\u{1e016}', '⼳', 'ῳ', '𞲴',
+    '⦷', '🤜', 'ꔚ', 'ﰘ', '♉', '𓿐', '𒎕', 'ኵ', 'ꏸ', 'ꗝ', '䷚', '🉢',
+    '🙙', '𓐧', '𓡲', '⮂', '𐋆', 'Ɱ', 'ಐ', 'ⱱ', 'ꯩ', '➢', '𖪘', 'ꉍ',
+    'վ', '𖬙', '𓄌', 'Ʒ', '☔', '\u{1ded}', 'ᬦ', '𓙧', '𓪭', '𐬜', '㌍',
+    '𝢈', 'Ố', 'ꔠ', '𓪹', 'ꮅ', '𒆽', 'ಃ', 'ꮄ', '⠮', '𖪛', '𞸵', '𔉥',
+    '𖭩', '︻', 'ꀞ', 'ꀡ', '𝡅', '⧃', 'ｱ', '㍨', '⺭', 'چ', '志', '彫',
+    '䷨', '🥎', 'ᘕ', 'ҙ', '𜱳', '𜱞', '𓟴', 'ス', 'ﭚ', '𑼾', '𑐶', '༡',
+    '𘨮', '𘠦', 'د', 'Q', '𖠄', 'ꔱ', 'ꇜ', '𑄐', '𘭶', '\u{11039}', 'ꙃ',
+    '𑐳', '⥽', 'ႏ', '\u{651}', 'ᕊ', '𑂉', '𞄪', '𑖭', '𘭻', '⾆', '𓐄',
+    '㋲', 'ᬖ', 'W', 'ỵ', '𘨜', '\u{110cd}', '𞁂', 'ユ', '\u{89b}', '𒋜', '𓅹',
+    '𐡈', '🔂', '𑙑', '𐍅', 'ᓙ', '𔕤', '\u{206b}', '𜵵', 'V', '𞠰', '𝘹',
+    '𘨄', '𘨀', '𝘵', 'ﱜ', '\u{1bf2}', '𓿀', '𜲄', '𓫞', '➖', 'ᨮ', '𝛼',
+    '\u{5bf}', '\u{e01ce}', '𛇪', 'ꉏ', '𘦵', '𞲀', '⩣', 'ᐫ', '🖤', '𒊊',
+    'ㅛ', 'ᙬ', '𒌳', '𔊹', '𒁟', '𜺐', '🏢', '㎋', '𓮉', '𛈧', '𓮁',
+    'ﶼ', '𓮘', 'Ἴ', '𓮊', '\u{e0192}', '↩', '🄝', '𓗹', '𛂽', '\u{1cf44}',
+    '🜐', '𞄖', '\u{85a}', 'ꌣ', '⥴', '𛃴', '🧖', '𘫙', '𓧱', '🮭', '𝈌',
+    'ꄰ', '\u{1060}', '𐫤', '⏠', '𐬐', '𒇠', '⽑', '🔤', 'ﮢ', 'ۻ', '🢫',
+    '📊', '﨣', '𝛫', '𒅨', '𝑢', '𜶾', '𖪢', '𞁬', '𒈛', 'ꐵ', '\u{359}',
+    '𐰱', '𐧁', '𒋑', 'ć', '𝙷', '𑂌', '𓄂', '𑈘', 'ﹾ', '𛱌', '𓒹',
+    '𑽊', '⊟', 'ӥ', '𔋑', '\u{e012e}', 'ᜟ', '𖢑', '𔋚', '𘡜', '🡩', 'Ꝃ',
+    '𓡶', 'ꥥ', '𒋀', '𑽓', '𜱊', 'ج', '📻', '𞢷', 'ᖛ', '𞁅', 'ấ', '𒒋',
+    'ۂ', '😝', '\u{10d6b}', '𓋴', '䷏', 'ﮟ', 'ꥡ', 'ꇛ', '𔌄', '\u{a9bd}', '⡞',
+    '𑐛', 'ẙ', '𓺿', 'ퟓ', '🐙', '𑣑', '⌟', '𓧭', 'ᯫ', '𝃳', 'ޡ', '🙥',
+    'එ', 'ṕ', 'ᶯ', 'ꔗ', '𞺅', '𘣧', 'レ', '𒄫', 'ꭣ', '𐓒', '\u{739}',
+    '䷁', 'മ', '寧', '𓷑', '𖥸', 'ퟛ', 'ŏ', '𜷰', '𒀱', '𓣟', 'ꅦ', '𐴔',
+    '𐦔', '𔈌', '𑩀', 'ᚕ', '\u{1cf09}', '𜴥', '𘭭', '\u{1d176}', 'ᥗ', '𝄌',
+    'ᴹ', '𓋱', '𑨔', '𖼐', '𖣐', '꜁', '🌳', '𓝟', '𞡷', '⍒', '\u{1abf}',
+    '𑧎', '𝚢', '𓡣', '\u{e01be}', '𓻲', 'ꔏ', '┗', '♃', '\u{16b36}', '↴',
+    '𝁬', '\u{10d24}', '𖪬', '𑧒', '🃒', '🖫', '⪩', '𓄀', '᪡', '𝢊', 'ኁ',
+    '\u{e012f}', '𐵘', '𐹣', '𓏳', '𛁡', '𑢽', '𔁬', '⹕', 'ǝ', '𒀁', '𒉐',
+    '𔉚', '𑏌', '꠨', '⫛', 'ᢄ', 'ᄯ', '🏲', '⪶', '𐦨', '𐠲', 'ǌ', '⫕',
+    'ꡣ', '𔁡', 'ᅱ', '⦮', '𓮑', 'འ', 'ꇺ', '𘧶', '🎨', '𐽙', 'Ḵ', '𓇎',
+    '𛀨', '❥', '\u{202b}', '𝑝', 'ꀁ', '⒚', '𝘈', 'ꬢ', '𝗊', '𓖍', '𜾁',
+    '𝗆', '𓻣', '⏾', '𓩄', '\u{1e5ee}', '╦', '𝀒', '﵈', '𓅳', '𓲣', '𓧩',
+    'ꃤ', '𞠊', 'ᾦ', 'ず', '💼', 'ሟ', '鼏', '𓰞', '𝪈', 'ʼ', '𒍈', '᳄',
+    '𛆫', '𑣲', 'ᚎ', '𑫭', 'ꩬ', 'ʷ', '𓰚', '𐇥', '𒁄', '𝝇', 'ꀎ', '⩃',
+    '𒔻', '𝌤', '𓀌', '𖽹', '🌽', 'ᙑ', '𞡧', '𞺉', '🧒', '漢', '𐓫',
+    'ቃ', '𓰑', 'ᐸ', '𝀡', '𓾲', '𓋫', 'ے', '𜲉', '𓿖', '𛰎', '🖾', 'ᰛ',
+    '𑑇', '𑤡', '𖄅', '\u{10378}', '🎬', '⚳', 'ձ', 'ꯏ', '𓐈', '𓓉', 'ꭕ',
+    '僚', 'ﶏ', '𘮷', '𔓊', '\u{e0138}', 'ᨨ', 'ᬳ', 'ঢ', '㺬', '𖨊', '𝠇',
+    '⒐', '𐭭', '\u{1e948}', '𔗄', 'ᗸ', '🭍', '𔑩', '𔑥', '🍦', '𓆀', '𐧼',
+    '𛀡', '🨴', 'ቘ', '😟', '🩎', '𓙕', '꠸', '𝄛', '𐇕', '🖚', '𓑡',
+    'Ⴢ', 'ᮐ', '𝀪', '𐖔', '𔅰', 'ṿ', '𓷪', '𔓚', '🛏', '𝞦', '𛀸', 'ɔ',
+    '\u{a82}', '⛋', '𓱶', '𘳈', '𐲁', '᠕', 'ꁄ', '𘦅', 'ᐽ', '🎻', '𓸨',
+    '𘫮', '𔍧', '𓽴', '\u{64b}', 'ძ', '𐤥', '𓦨', '𔉁', '𓧟', '𐋪', 'Ĉ',
+    '\u{6db}', '🧞', '𓼭', '𐕋', '\u{1112a}', 'ઍ', '𑂭', '𓎣', '𛋓', '𑼇',
+    '𐌣', 'ⲕ', '🧴', 'ﱴ', '\u{a8e5}', 'ߊ', 'ᅡ', 'ᅦ', 'ꘔ', 'ⶵ', '𞺰',
+    '🄜', '𞡜', 'Ⳡ', '𓕛', '𒋐', 'ፏ', '؛', '𝠨', 'ㅡ', '⎀', '\u{dd3}',
+    'ﯗ', '𐀠', '𒎆', '𝜠', '𔆹', '᭑', 'ᐋ', 'ꌟ', 'ᴨ', '⫦', '𓛣', 'ꓒ',
+    '𑀜', 'ݠ', '𓙺', 'ƹ', '𛄀', '𖬓', '𑆂', '𐑻', '𒁙', '𐑨', '𖢨', '﹪',
+    'ꭄ', 'ᚠ', 'ᖖ', '㉜', '𓖏', '𐠼', 'ᦿ', '𑐴', '⏺', '𓾷', 'б', '𛉇',
+    '௦', '𓄖', 'Ġ', 'ↈ', 'ᭆ', '𜽬', '\u{e0123}', '⎯', '𓽈', '𐔙', 'ꣂ',
+    '𞢃', '🮃', 'ꁳ', '𐧨', '𐔟', '\u{a51}', 'ㅥ', '𐔀', '𘯊', '𝗈', '𑼚',
+    'ଥ', '𓥶', 'ꂾ', '𓩲', 'Ύ', '蓼', 'ﬄ', '𖥊', '𓩿', 'ၶ', '𔓀', '𞋂',
+    '𘬳', '𛁵', '𔎙', '𝼧', 'ᨾ', '🍨', '𑀤', '𓒚', '𛉎', 'Ք', 'ᑿ', 'ㅕ',
+    'Ȳ', '𐑜', '℧', '𝐛', '𐡦', '\u{1cf3c}', '𝐧', 'ﮊ', 'ꬄ', '𜷕', '〙',
+    '𝜬', '⮯', '\u{e0112}', '\u{1daa5}', 'ﾥ', '⨿', '𛈣', 'ꗶ', '⻰', '𔍼',
+    '᧤', '𑤴', 'ッ', '⯫', '\u{1dde}', 'ઙ', '𔗕', '𓎱', '𛀴', '𛱡', '苦',
+    '𝝹', '𓆕', '⛤', '𐀘', 'ꝑ', '᪫', 'ᗗ', '\u{c4c}', '\u{1cf02}', '𛄡',
+    '𐤗', '∣', '᥎', '𔒱', '∳', '𜺟', '՝', '𓛯', 'ꬷ', '𑊖', '參', '𖬞',
+    '\u{11a01}', '𒎈', '𝗍', 'ы', 'ട', '𝦨', 'ଧ', '㍘', '𔃎', '𔀰', 'ಬ',
+    '🬹', '𑦧', '𝠙', '𑜔', '⭫', 'ꏖ', '𑶌', '𑂆', '\u{825}', '𖧚', '৻',
+    '🩦', 'ல', '\u{1e8d3}', '𝞆', '🎽', '➚', '𝧙', '𔊦', '𒇹', '𓐖', '𐓰',
+    '𓎼', '𐐻', '\u{1da20}', '℄', '⩜', '䀈', '⼉', 'ፃ', '𓴼', 'ჶ', '𓉥',
+    'ꍣ', '𝦩', 'Ḝ', 'ꡜ', '𒀬', '𒊃', '𝆄', '\u{1b35}', '𘭀', '𘭄', '𔉧',
+    '𔐲', '\u{113bf}', 'ꩮ', '⿏', '𓁄', 'ﭔ', '𝼉', 'Ꞙ', 'ਦ', '𓚹', '𓓋',
+    '🁴', 'ᣐ', '𑂬', 'Ռ', 'ꎬ', 'ᤶ', '⫌', '𓜘', '𞲒', '𐚾', '\u{103d}',
+    '〻', 'ㆁ', '𔕅', '𘩎', '𘭍', '𓜑', '꩝', 'ᶎ', '𑁕', '𓽐', '𔇃',
+    '🏹', '᱖', '𝞮', 'γ', '𐕘', '𔀏', '𐲗', '𛂤', '𖺌', '㏪', 'ᆏ', '⺪',
+    '\u{110b5}', '𔃣', '𐽅', '𓽒', 'ꌉ', '𑘻', 'ᒨ', '꒵', '𓘇', '🪂', '𐎡',
+    '𐛼', '🤷', '𓤘', '𓻂', 'ظ', '𐔅', '𔇠', '𓦷', '\u{1da14}', '𛂌', 'ᢀ',
+    '𓿃', '𑛞', 'ﳍ', '浪', '𓞓', 'ﻫ', '𒑘', 'ဎ', '𓨙', 'ㆃ', 'ꅝ', '௬',
+    'ࢆ', '𖪦', '㋗', '𐛽', 'ᔝ', '𜱝', '𘱶', '🮪', '𑐃', '𛇋', '𑠥',
+    '𐔺', '😫', 'ɉ', '𓀼', '割', '𛉵', '𒉿', '\u{afc}', 'ⶼ', '𑌛', '𝄠',
+    'Ꙇ', '𝕋', '\u{116b5}', '𓥭', '𝠲', 'ᨦ', '𖮉', '𔁘', '🌀', '𔀓', '𐹨',
+    'ﭥ', 'ⲷ', '北', 'ຟ', 'ꊟ', 'ꒋ', '𜶰', 'ꎌ', '𓞽', 'ꡌ', '不', '𘤵',
+    '𓛽', 'ᙌ', '𞢵', '𖾙', '𞋘', '𔂤', '𞁤', 'ࣇ', '𓡁', 'ᰎ', '᪒',
+    '𘢿', '𓞍', '𓱺', '🂩', '܊', '𑚤', '⢭', '\u{e0174}', '叟', 'ᅇ', '𐿳',
+    '𑿮', '🨍', '𝐌', '𓙹', '꒨', 'ꌊ', '𔇰', 'ﶆ', '𐴟', '𓍗', '𝠂',
+    '𓝽', '\u{e0148}', 'μ', '𑅛', '🟪', '𘰀', 'ꊚ', '✸', 'ఝ', 'ሡ', '𐦟',
+    'ᵪ', '\u{1107f}', 'ሥ', '𔘗', '\u{115dd}', 'ₚ', 'ᘂ', 'ﱳ', '𐌡', 'ꗖ', '⡀',
+    'ꢜ', '𓻼', '⨎', '⥤', '𖨬', '𝔈', '\u{a678}', '𞲢', '🨣', 'ᒫ', 'ꩈ',
+    'ꉎ', 'ꉑ', '𓳝', 'Җ', '𔂊', '𐽃', '𑤭', '𛱸', '🀱', '𒔄', '𖩢',
+    '𐋸', 'έ', '\u{e0031}', 'ꈗ', 'ધ', '𐇹', 'ꛃ', '𑑑', 'ꮮ', '🁹', '𓞸',
+    '𑖊', '⾞', '\u{f87}', '\u{206d}', '𑄍', 'ﳝ', '𐕎', '𔉗', 'ࠔ', '𐞇', 'ᇶ',
+    '𘬲', '𔂉', '㎿', '\u{1dc8}', 'ᗘ', '𓸔', 'ꤱ', 'Ⲥ', '⑃', '𓡜', '𛋩',
+    '𓾼', '𝗧', 'ቂ', '↞', 'ǅ', '𝗗', 'ࡣ', '𝗟', 'ᑵ', '⾛', '𑵯', '㍩',
+    '𞗱', '𓻇', 'হ', '𜶌', '𝜟', '⅝', '🤢', 'ﰒ', '\u{362}', '㉉', '👍',
+    'ࡐ', 'ﭪ', '𑨐', '𐘭', 'ݲ', 'ﹰ', '🗔', 'ᾤ', '𓉁', 'ꕳ', '🝲', '𛱒',
+    '𖩺', '𜸥', '𐞂', '￮', '𝦛', '𐬃', '𐛌', '𖵤', '𜰨', '⸡', 'ꍟ',
+    'ጞ', '𜹸', '🡫', '𓖨', '𐮌', 'ꭥ', '📀', '𓧧', '𓧣', 'ꆚ', '🁃',
+    'ﭫ', '𑤌', '𓳷', 'ܧ', '⸫', '\u{a948}', '𛈘', '㍴', '\u{1e015}', '═', 'ꧠ',
+    '⛂', '\u{a92b}', '𑌢', '𝂲', '\u{1da2d}', '🍐', '𐚑', 'ꂤ', '𐩬', '𜷎',
+    '𞁏', 'ୀ', '𞢭', '𝢏', '𓉖', '␀', '𑀈', 'ｙ', 'ꚙ', '⼴', '𝣮', '𐆈',
+    '𐖙', '𒉵', '𑎺', 'ᚴ', '𑚩', 'ⱋ', '꩒', 'Ⴏ', '㎤', '𖭧', 'ࡷ', '𔐞',
+    'ꃢ', '⸠', '╄', 'ᰫ', '\u{a92c}', '𐼋', '𐢫', '⇽', '𒔨', 'ᢡ', '⩑',
+    '𒈱', '𖦫', '𑊤', 'ਧ', '\u{e010a}', '𞁞', '𑒡', '⥂', 'ࢿ', 'ﹽ', 'ͼ',
+    'ꆭ', '𜶋', '𝕧', '𔗥', '𔗩', '𓂮', '⨄', '𓂶', '𐤯', 'ᢳ', 'ꆆ',
+    '𜴋', 'ꃼ', '𓝖', '⬗', '𛰉', '𝙬', '\u{1172a}', '𐩧', '𖫨', '☓', '𔑕',
+    '\u{e014e}', '𑫷', '𔓱', '⭜', '𜱲', '\u{1cde}', '𐃡', 'ᵩ', '𜶘', '𝖸',
+    '༕', 'ၐ', '\u{1b39}', 'ɀ', '𝛹', '𒓥', '⥈', '𑍁', '⒴', '𓦎', 'ᢿ',
+    '᥅', '𓦁', '𓾫', '𖧫', 'ᯕ', '𓦑', 'ꦐ', '浸', '𑆞', 'ജ', 'ଢ଼', '𐢪',
+    'ළ', 'ꊛ', '\u{1ba9}', '𓉻', '𓻗', '寮', '𒿎', 'ใ', '𝍸', 'ﴙ', '\u{1b01}',
+    '𖡞', 'း', '🣁', 'ㆶ', '㊼', '𓠎', '𖄆', '⏿', '𝌊', '𖢰', '𓲿',
+    '𛋞', '𓆇', '𞀼', 'ꄷ', 'כ', '𒋎', 'ꞥ', '𓡪', 'ꘕ', '⸈', '𐢁', 'ᠣ',
+    'ᄸ', 'ᇀ', '噴', '𔊋', 'စ', '𝋍', '👚', '๕', '𝥝', '\u{1da23}', '𖵁',
+    '⹚', '𑢥', '⽧', '𒆹', '卽', '梎', 'ទ', '↖', '𐳁', '㇘', '㋞', '𛂿',
+    'ፇ', '⎙', 'ᒎ', '𛄲', '𞠛', 'ഠ', '㎎', '᳷', '𑤨', '𔈘', '逸', '𝡙',
+    '𜳊', '🪖', 'ﱊ', 'ㆵ', '📘', '𝍉', 'ꎿ', 'є', 'ꈀ', 'ﵢ', '𐖘', '𓽶',
+    '𓌼', '𓏀', 'ꎉ', 'ሐ', 'ᎀ', 'ꥭ', '𐍄', '𐪕', '\u{2061}', 'ㇹ', 'ሣ',
+    '⟝', '𐄙', 'ᓾ', 'ࢲ', '𑨞', '፱', 'ꀻ', '↤', '𑋕', '𘭷', 'ꃃ', 'ᯑ',
+    '𝒬', 'ꃄ', '𔂿', 'ឡ', '\u{e01ae}', '𘢧', '\u{1a6c}', '𓝷', '☾', 'ƕ', 'ꇬ',
+    'Ⱶ', '𞸒', '⧛', 'ꉾ', '𖧇', '𓻢', '𓉍', 'あ', '𔆋', '\u{1112d}', 'ﶕ',
+    'ￛ', 'Ζ', 'ⵜ', '𐐁', '\u{11129}', '㊝', '𔂓', '𘦎', '🎱', '𑄘', '𔆆',
+    'ﭓ', '𖵜', '𖡔', '\u{11a55}', '꙳', 'Ⰱ', '\u{e01e0}', '𓺑', '𑵭',
+    '\u{13454}', '𞡯', '𘫹', '𛄛', '꓿', '\u{8e2}', '𖩩', '𜸸', '⠣', 'ᡯ',
+    '𝂯', '⍬', 'ᩅ', 'ᙘ', '𐡑', '𜷏', '┍', 'ᅜ', '𔕰', 'ረ', '𑧤', 'ꋢ',
+    '𐑩', '⍳', '𑜼', '𝡳', '𓊯', 'ᨹ', '𜺄', 'ヤ', '𐮆', '🠩', '🔃',
+    '⾷', '𝡎', '𘧱', 'ക', 'ߌ', '☇', '𒆾', '𓋇', '𜵶', '𔐇', '𓪉', '𑆭',
+    'ᝎ', '𖨂', '𓣳', '🚈', '🬄', '𘩲', '𐅀', '‾', '␠', '𝓴', '𓜄',
+    '㍃', '𖬠', '⁌', '⿸', '𐫡', '²', '\u{e015c}', 'ʠ', '𘣯', '𘣳', '\u{114b0}',
+    '🤃', '𐦳', '𘢩', '𘢭', 'ᚌ', '🐸', '𝃗', '⮠', '➁', '🬎', '𐭿',
+    '🗭', '⟠', '\u{a67b}', 'ꊽ', '𐒽', '\u{8ca}', '𘠨', '𑒾', '𐊇', '𛈜',
+    'Ὲ', '˜', '𒄗', '⺑', '𓃂', '𓵖', '𐔐', '𔅁', 'ᒥ', '𓨎', '𛊰', 'ڬ',
+    'ኗ', '𝦬', '䷝', '𑪈', 'ਐ', 'ਔ', '𑧄', '𓟮', 'Ꙡ', 'ぽ', '𝠿', '𝐤',
+    '𐘓', '𓜱', '㊬', '🦖', '௳', '🞚', 'ᱺ', '⼒', 'ᓿ', 'ᔴ', '🆂', '🖔',
+    '🢻', '𒅼', '⏷', '⊼', '🕭', '༠', '𐿩', '𘬧', 'ຏ', 'ꐓ', '𛇍', 'ᲀ',
+    '𜶠', '⇳', '𒁲', '𞣎', '𝡑', '꜌', '𞸂', '𐄽', '𑀃', '𑀀', '\u{20e4}',
+    '𖣓', '😨', 'ᬢ', '🕗', '⒂', '⛥', '𓌾', '𐀅', 'Ọ', 'ɫ', '𑩆', '𓤹',
+    '🙍', '𑀓', 'ꄥ', '𑱻', '𤜵', 'Ì', '𔕛', '🜀', 'ᙈ', 'ᄡ', '𐠞', '🤱',
+    '𘭏', 'ি', '🦘', '𐃥', 'ㄕ', '𐫴', '✋', '⼭', '𑂣', '𑣤', '😽', 'ᛩ',
+    '\u{11d97}', '珞', '𓩥', '𐰟', '⧐', '𓈱', '𒓄', '𔇨', '㉴', '𒄀', '८',
+    'ᒓ', '𒑍', '𘡕', '𓓮', 'ᅲ', '𖹙', '㍧', 'ᒳ', '𜴈', '𘫟', '𓹙',
+    '𑱱', '𝐋', '𐵈', '￤', '𓓩', 'Ứ', '\u{f93}', '𐤭', '𓩮', '𔆔', '𜳕',
+    '𞣇', '🎰', 'ḓ', '𒎏', '⦶', 'ৌ', 'ࢍ', '𒋯', '㇃', 'ᚅ', '𑪚', '♬',
+    '著', '⩥', '𐑷', '\u{116b4}', '𛅰', '⎝', '𐲚', '⯪', '🀍', '𞊧', '𞊢',
+    '⤽', '⹖', '㌒', 'ᵫ', '𛰧', '㉖', 'ﻭ', '𓌖', 'ꝃ', '`', '𐢃', 'ଶ',
+    'ㄳ', 'ﰟ', 'ȑ', '𜴨', '𓚨', '〠', '𓊾', '☎', '𑫌', '𒈆', '🁜', '㊽',
+    '𓽓', '🇷', '🇻', '🇿', '𑫮', '𛀲', 'ስ', '調', '𐠡', '𝌨', 'ꪂ',
+    '\u{1171d}', '𛅻', '𓤓', 'e', 'ꤡ', '𜸛', '𔔹', '⯌', 'ၮ', '𓫲', '𓂁',
+    '𓂅', 'ﺚ', '🀲', '𔇦', 'ự', '\u{1da2f}', '𑆄', '𐀞', '𓐝', 'ᯄ', '𘢥',
+    '𐋩', '⎃', '𐙔', '𔅜', 'Ѝ', '\u{6da}', 'ፊ', '𝕽', '𝖁', 'ء', '𘥹',
+    '𔋍', '𝕱', '𐘞', '𘠾', 'ᮖ', '𝕣', '𝕟', 'ᙾ', 'Ÿ', '𝕓', '🔶',
+    '𜺣', '\u{1de6}', '𔘏', '⭤', '⭉', '𖵃', '⇗', '𑚝', '♼', '\u{e0028}',
+    'ꌁ', 'ಭ', 'ꒇ', '꭫', '𐫚', '𓁘', '𐐄', '𖠉', '𝦻', '⩧', '𐑉', '𐒀',
+    'ῐ', '᱅', 'ᆙ', '𝘘', '𓵝', 'ạ', '𞡝', '𐗬', '༻', '𖧀', '𐺕', '𘣰',
+    'ܫ', '΄', '𑎧', '𛂡', '𓍈', '𓙬', '𓕺', 'ᖔ', '◆', '𓉏', '𖨖', '🡓',
+    '𓣧', 'ﰿ', '\u{5b3}', '𓶙', '𒉤', '꒴', '𓝜', '𘳀', 'ꏵ', '𛆃', '🦏',
+    '𑫶', '𜱢', 'ቭ', '𐂵', 'Ḳ', '\u{11a33}', '𑝅', '𜷞', '⫬', 'Ǹ', 'ఏ',
+    '𐤦', '𒍕', '౬', '𓰵', '🪠', '冀', '𑀉', '𘯶', '🗥', 'ʘ', 'ꫠ', '⢢',
+    '✈', 'ἕ', 'ﳎ', 'ঞ', '𘦖', '𒑏', '𛱟', '𔕇', '𑶨', '𐒷', 'ᖒ', 'Ы',
+    '𑀮', '\u{1ac7}', '𐙅', '𞲝', 'ᕜ', '꣐', 'ㅰ', '恵', '♄', 'ᇷ', '卑',
+    'ज', '𓗯', '𘫓', '𐌐', 'ꏕ', 'ⳉ', '𓹁', '𞴠', '⻜', '𒆱', '﹒', '𓅖',
+    '𞋛', '🜥', '𓥜', '🔌', '㊭', 'എ', '𐒜', '𝕪', '}', '\u{a48}', '\u{1da46}',
+    'ӄ', '\u{1b42}', '𐘌', '𑚋', '𔂌', 'ᗟ', '⋏', '🍴', '𔓽', '𓘾', 'ᦼ',
+    '𑊺', '𓘹', '⏐', 'ॹ', 'l', '\u{11442}', '𛆍', '当', '𑅲', 'ভ', 'ﾸ',
+    'ퟯ', '𔈄', '彩', '\u{11d32}', '𔊽', '𓚔', '𓚐', '\u{2003}', 'ഴ', '⿉',
+    '𧲨', 'ừ', '𐿊', '𓭋', '𒐼', 'ࠊ', '\u{1abb}', 'Ԧ', 'ŕ', '\u{114b8}', '𓆽',
+    '𓇁', '𓽧', 'ﵻ', '𜾡', '𑘖', '𔊿', '⊐', '🝦', '𜰋', '🏵', '𐊤',
+    '𜸣', '𐼰', '𐌸', '⠼', '𝛪', '罺', 'ၻ', 'ꗁ', 'ど', 'ʚ', '\u{1da4a}',
+    'ᱨ', '𓁺', 'ꆇ', 'ꄽ', '𔖏', 'ᥭ', '𑜡', 'ᤇ', '𓡙', '႖', '𑐰', '⢜',
+    '🦚', 'ﰻ', '𜲠', '\u{1cda}', '𐗞', '𒾘', '𓮣', '𐑏', 'Ӆ', '𐕡', 'ꁰ',
+    'ᴭ', '🟁', '𓰖', '𝁊', '෬', 'Շ', 'ퟀ', '𓮬', 'ꨍ', '𔍸', '𓩔', '𞗿',
+    'ꤶ', 'Δ', '\u{1da1f}', 'ᰕ', '𖨠', '𓔄', '𜽫', '𒃩', 'Ɨ', '\u{822}',
+    '\u{b82}', '𖩙', 'ᵕ', '꩙', '൯', '𝅜', '𞟡', '𓁐', '𜴁', '𓁙', 'ṡ',
+    '𐚐', 'ࡿ', '𘡝', '᪙', '𘮅', '⌕', '𝗼', '⇮', '𞢮', '൴', '𐀤', '𐪂',
+    '𓌱', '𓄏', '𓼖', '🡖', '𐡤', '𔏺', '🗛', '➃', '\u{489}', 'ꕨ', 'ສ',
+    '𐪆', '𞥟', '🜳', 'ꂍ', '🨲', '𒍹', 'Ⲡ', '𑣇', '꣎', '▛', '𝧺', 'Ἣ',
+    '⬁', '𖨮', '𖧠', '𖩥', '\u{1da67}', 'Ԛ', '𘬒', '𐍙', '𖬖', '\u{fba}',
+    '𘨑', 'Ḃ', '🭥', '𓦋', '𖣋', '🜞', 'Ꜭ', '𞤽', '𓦆', '𓗔', '𓝌',
+    '𑊆', '🙊', '𓹹', '🏇', 'ᗁ', '𒃤', '𔓯', '𓾅', '\u{b63}', 'Ե', '𐳊',
+    '𘤻', 'Ẍ', '𝚆', '𓮢', '𝟆', 'ﭩ', 'ⱂ', '𝕎', 'ꝭ', '𔇤', '➄', 'ퟧ',
+    '𝠓', '꒾', '🜘', '𖵀', '\u{1103d}', '🧝', '𘰂', '᧾', '𒾔', '𝅄', 'Ⅿ',
+    '↬', '嬾', '▮', '𞢳', '𓆹', '𒿡', '烙', 'Ⱙ', '𚿲', '𔋪', '𐣼',
+    '🆕', 'ﬨ', '㏟', '𐑪', '🜾', '𓮭', '敬', '⩽', '𔀸', '𓭏', 'ᰣ', 'Ს',
+    '𔔎', '﹞', 'Ⱘ', 'ꨖ', 'וּ', 'ᕔ', '¤', '⸧', 'ㅱ', '𝡌', '😣', '⛵',
+    '⟃', '𞺨', 'ᾆ', '𓏱', '⏅', '\u{5b8}', '⯡', '⮽', '𓴯', '𔄪', '𞡰',
+    '𖹴', '📟', '𧃒', '\u{afa}', '𓋍', '𛆖', 'ﾽ', '⠠', '𓴢', '🜔', '𓟝',
+    '𐾻', '℉', '🭶', '⛪', '犯', '𝌈', '荓', '𔆫', 'ᕿ', '𔏣', '𞓜', 'ꭠ',
+    'ƿ', 'Ԉ', '᪪', '𓣬', '𘢋', 'Ƥ', 'ᤰ', '⼏', '𜹮', '具', 'ᬌ', '𓫋',
+    '𓈏', 'ӱ', '\u{1da0e}', 'ꎃ', 'ง', 'ఇ', '𑚍', '\u{e01ad}', '⣢', '\u{f74}',
+    'ޱ', '𔏮', '𐋯', '꤆', 'ぇ', '𔄺', '\u{1e026}', '𓮳', 'ම', 'Β', '𝀐',
+    'ዏ', '𔒻', '殻', '𔓃', 'ፎ', '⟖', '𓞵', '𐋁', '🉠', '㋑', '\u{8f8}',
+    'ዝ', '𘬞', '𐞛', '𑪹', 'ꏭ', '㍯', '𐛰', '𑰤', '𞥐', '𜹼', 'ꉬ', 'ᵡ',
+    '𐇟', '🔷', '𥮫', '𘮴', '𘮰', '🀷', 'ꏁ', '𓕞', '🟈', 'ھ', '兔',
+    '⺇', 'ᄑ', 'ﳄ', '𓘿', '𓈳', '念', '𘬕', '𝠮', 'െ', 'Ă', '𐑚', 'ᰝ',
+    '𑦣', '𘨍', '🬮', '𐳘', '🔫', '𖧏', '\u{fb0}', '𑰯', '𓘉', '𐜰',
+    '\u{11173}', '՞', '𞢏', '䗗', '｡', 'ﴺ', 'ﴐ', '𓚜', 'Ή', '𓼿', '𓊣',
+    '🖼', '𝌔', '∀', '𐇸', '𓼴', 'ஊ', '\u{1da32}', 'ۋ', 'ᠫ', '𞹎', '𖾘',
+    '⏗', '𞸊', '㏐', '𜴬', '◈', '𞁫', '𒔡', '𒃀', '🖥', '🟀', '𝄕',
+    '𓹡', '𓸗', 'ꣷ', '⟢', 'L', '𑒘', '𒿆', '\u{10a05}', '\u{cc6}', '𝣵', '⪇',
+    '裸', '⪬', '𞋧', '𐡾', '𛀶', 'ᶱ', '㆘', 'ᑂ', '𝦖', '𑄾', '𜴔',
+    '\u{11131}', '𓥽', '🢣', '𐨰', '։', '𝩱', '𐭫', '▫', '⇉', '𘰘', '𝁁',
+    'ꋙ', 'ᙸ', '⒌', '𜹒', '𞤀', '𓻉', '𐡕', '𜲌', 'ፅ', 'ȷ', 'ﺬ', 'ꠉ',
+    '𑪷', '𓶪', '🮐', '𖤹', '𓨃', '𓨇', '𐆇', '𑼡', '𓜈', '𐛙', '𜹶',
+    '𑊩', '⃀', 'ﻖ', '⸊', 'ᡴ', '𑃧', 'ᶏ', '㈎', '⁉', '🍀', '⁺', '𒂺',
+    'ㅵ', 'ຍ', '𓿙', 'שּ', '𓿑', '𓿕', 'ቮ', '𓔫', '𓛮', '𝗵', '𓀂', 'ꠇ',
+    '𖨒', 'ᥜ', 'ᏹ', '🗯', '\u{fe04}', '𘢁', '⪈', '⌳', '￣', '🮂', '𓳋',
+    'ᨴ', '𔄎', 'ꝷ', '⠃', '\u{a67c}', '𓶽', '🭝', '㉃', 'ꗐ', 'ᙝ', '𞊪',
+    '𑰓', '𓭎', '\u{16b31}', '𞊓', '𔗎', '𓴎', 'ư', '𐾉', '𔌯', 'ᦒ', '𑋷',
+    '⩱', '∱', '⣉', '𔏱', '🬀', '𐎩', 'Ꙫ', '𓵴', '。', '𜶴', '𝩺', '𖦣',
+    '◔', '\u{1b3b}', 'ꡤ', 'ḿ', '𑁍', '𜸷', 'ἃ', '🁷', '𝒒', '𔉬', '₾',
+    'ൠ', '𔄨', '𘬎', 'ଭ', 'ꉣ', '⢹', '𐗚', '𓍚', '𓌩', '𐞈', '⪥', '𐙵',
+    '𓩰', '𞢈', '.', '𖼯', '⛮', 'ꖜ', '࠳', '𓲃', '\u{2df4}', '𑨗', '🆃',
+    '韛', '𜴞', 'ꤸ', '鉶', '🅷', '𘮦', 'Ｑ', '𘥵', '𘯏', 'ڛ', 'খ', '𜳧',
+    '𓾐', 'ᇳ', 'ℬ', 'ᒉ', '𞴫', '𘮯', '𐍲', '鳽', '𜴵', '𑆨', 'ꢡ',
+    '𘨁', '𘯀', 'ᢇ', '杖', 'ꎦ', '╜', 'ꂌ', '𒅷', '🮈', '𘰯', '⯍', '𝡐',
+    '𑅗', '🎢', '𐛭', 'ꏟ', '綾', 'ၒ', '𘦴', '\u{11d35}', '𝤁', '🨳', '𔐸',
+    '𝤜', '𔑀', '𒁑', '𝼍', 'を', 'ᢕ', '𓄋', 'ꈖ', 'ꅤ', 'ᚫ', 'Ҽ', '𔍗',
+    'ᨥ', '⊒', '𑊝', '𐜜', '𑣚', '惘', '廒', '𒁷', 'Đ', '🅅', '𓻏', '𖥅',
+    '🨊', '𖦧', '𔂍', '𛊏', 'ꈤ', '𓓶', '𓧽', '𖤘', '𝁙', '𘭞',
+    '\u{1da1a}', '𓀪', 'ᑯ', '𞄥', '🨌', '𘡏', '\u{ebc}', '𒒾', '省', '࿉',
+    '⽼', '⇩', 'ℕ', '⢻', '◀', '🂹', '⠕', '𑁮', 'ﾎ', 'ᱵ', '\u{e004a}',
+    '𣊸', '✄', 'ᓶ', '᭟', '\u{e017f}', '☛', '𖩉', '㌌', '𛈐', 'ꮖ', '𐳗',
+    '🛠', 'ᙏ', '𓪢', 'ゎ', '𒇆', '🕄', 'ꄹ', 'ത', 'ꉮ', '\u{1df4}', 'ᐜ',
+    '𑱨', '🔚', 'ꋟ', 'β', '𐦶', 'ѩ', '⣸', '💊', '▖', 'ᦨ', 'ཀ', '𓫫',
+    '🢶', '𐊚', '𓛊', '𒓗', 'ꧣ', '𓞢', '𓞦', 'ꂟ', '𑑙', '𐧈', '𝀟',
+    '𑋌', 'ﱟ', 'ͱ', '𑱞', '\u{10f4d}', '🍅', 'ㆍ', '\u{112e3}', '𖵡', '𓚱',
+    '𝂦', 'ご', '𒋮', '𓚒', '𐪜', '🁭', '𘣮', '𓨈', '፶', 'ꑕ', '𐗔',
+    '𝈱', '🙿', '𜵇', 'ᴁ', '茝', '𐐬', '⽅', 'ɱ', '𜺍', '𐪗', 'ﻘ', 'Ⴁ',
+    '𓵂', '𐝁', '𐧾', '⍷', '𑐌', '〿', '𝗐', '𑿊', 'ㇽ', '◠', '⟸', '⧿',
+    '𛇗', '𛇓', '𓫜', 'ᇨ', '芝', '𐒨', 'Ἠ', '≕', '𔐈', '⏡', '🗓',
+    '𐆖', '𒆚', '𒒍', '𐣠', '𖄋', '🭹', '𐓜', '\u{eb4}', 'ꕜ', 'ϟ', '𝐔',
+    '𓉋', 'ﰺ', '😐', '⭓', '𐔦', 'Ç', '𔒪', 'ώ', '⇛', '𐼼', '\u{339}',
+    '𝖖', '꒘', '𓭌', '檨', '𝛬', '𖵭', '🢸', '𒉓', '𓞌', '𦞵', '𑯊',
+    '𣍟', 'ᅳ', '𐐽', '𐌶', '𔈦', '𓼼', '🙵', '𐭪', '𛁿', '沿', '✞',
+    '𒊼', '𝙓', '𝒴', '⺜', 'ฆ', '𑄹', '𑨒', '𔙄', '𑿝', '𞥀', '🃍',
+    'Ѐ', '𑐚', '\u{61c}', 'ਟ', 'ﴞ', '𔓠', '𓏗', '𓺴', 'ᕽ', '𘰠', '𓺸',
+    'Ἤ', '𘳅', 'ボ', '㉊', 'ꝣ', '𓂔', '𛉢', '〦', '𖽛', '𐴵', '𐾳', '𔑜',
+    'ꈞ', '𝙜', 'ষ', '𘲆', '﮿', '⾿', 'ﮠ', '𘫷', '𝧉', '⻚', 'ꔻ', 'ჭ',
+    '𛂠', '𞲖', '❋', '𒋅', '𓤟', '𓐀', '𘰿', '𘰻', '⥕', '略', '𝥀',
+    '𞸑', '𞓦', '𖄸', '庳', '↔', 'ఊ', 'ѭ', '𐨵', '🨶', '⭧', '╼', '𝘨',
+    '𓤼', 'Ꝿ', '\u{1abe}', '\u{34c}', '行', 'ꇇ', '\u{1886}', 'ゆ', '🭌', '🃮',
+    'ꜽ', '𜶁', '🭿', 'ꏷ', 'ꏶ', '\u{e01d0}', '⤄', '𒿉', 'ꅒ', '𒿑', '𓲛',
+    '𔇾', 'ﰣ', 'ㆠ', '𝇔', '🝗', '𑿭', '𓼬', '𐅵', '🛥', '㊳', '⓴',
+    '𝠆', 'ꐾ', '㎆', '牢', '\u{31c}', 'Ω', '\u{11a3b}', '𔋰', 'ı', '𐳱', '𜽼',
+    '𐋢', 'Ꝇ', 'Ꝝ', 'ቛ', '𐺐', '𓫐', '𓿱', '𓈠', 'ꐱ', '⺞', '𔕡', 'ﳓ',
+    '𑋍', '𑗗', '𒓜', '⿓', 'ށ', '≻', '\u{30a}', '趼', '𖄏', '🭎', 'ƨ',
+    '𓍪', '難', '𐑗', 'ⵡ', '𒅉', '▿', '㌸', '𓭩', 'Д', '┢', '𔎤', '練',
+    '\u{16126}', '䷳', '\u{8dc}', 'ꇞ', '⨍', '𒔇', '𑜊', '𐲂', 'ߜ', '𓍙', '𓹖',
+    '𓶰', '𐰳', '𐲯', '𞺶', '६', '𛰓', '𑿞', '𝚗', '🭀', '\u{a71}', '𒈕',
+    '睊', '𐙣', '𝤰', 'ﲃ', '⚅', '𛇬', '揅', '⣷', 'ʮ', '⎸', '⌏',
+    '\u{11c3d}', 'ꄒ', 'ﾋ', '𞠑', '𜽙', 'ῑ', '\u{e01a4}', 'Ổ', '𒌷', '𛄘',
+    '𔐷', 'ꠌ', '𒂴', '𝗀', 'ࡏ', 'ᕣ', '朗', 'ퟔ', '𝑶', '⊦', '𝑾', '𝇇',
+    'ܕ', 'Ꮑ', 'ࡲ', '𝞓', '𝅋', '⍪', '𖨳', '𜱻', 'ꊒ', 'ʭ', '\u{e01a0}',
+    '𛰲', '⏙', '𐧅', '𝣣', '𔄫', '⼖', '𑎖', '𔈏', '🌟', '\u{1e01e}', '𔎲',
+    '𑈨', 'ₗ', '🢰', '𘩁', '𔕽', 'ᒼ', '🖷', '𓇑', '♯', '𔕦', 'ᩎ',
+    '🜌', '𐒏', 'ѵ', '𐎋', '𐅞', '𓦌', 'ࢨ', '🏟', '๚', '𑀐', '𝀌',
+    '𑣛', 'ཉ', '𐊌', '𐠈', '𓞄', 'ꡚ', '⯯', '㌢', '𓂽', '菱', 'ꋝ',
+    '\u{f7a}', 'ಎ', '٣', 'ꆣ', '𓸬', 'ㅇ', '寳', 'ﱤ', '𛰚', '𖪔', '⸔',
+    '𐒣', '𑁜', 'ᓤ', '⦺', '𒃙', '𐨧', '𓣄', 'ऩ', '🦢', '𓐨', 'ꑞ',
+    '𓆘', '𔕨', '𐳏', 'ṥ', '␢', '𖵹', '┸', 'ᄏ', '𖩼', '🬇', '𞋨', 'ࢧ',
+    '𓁀', '𔐖', '𑴉', 'Ꞹ', '⚷', '𔗐', '𜺳', '𝈣', '𖫇', '𖢟', '𔖣',
+    'Ⓑ', '𓝗', '𖠊', '𓦻', 'Ꮌ', '𝀤', 'ꕌ', '𝙛', '𓰘', 'ڝ', '𛈓', '𐱆',
+    '𐚬', '⪣', '𝜭', '𝆹𝅥𝅮', 'ᄖ', '꒤', '𓉘', 'ꋺ', '𜾝', 'ⱄ', 'ࣀ', '𔋢',
+    '꒻', 'ಾ', '瑇', '﹗', '\u{20e7}', '𞸹', '𓽳', '𓢧', '᙭', 'Ӏ', 'ᆎ',
+    '𒁊', 'ꤲ', 'ﹻ', '𓹈', '\u{e007f}', 'ؠ', 'ꁖ', '🝫', '𐐺', '𔌲', '爵',
+    '犕', '𖡂', 'ꤻ', 'ܠ', 'Უ', 'ﬕ', '𔑨', '誠', '𛱙', '⑁', '𝂝',
+    '\u{1def}', 'ꝅ', '𜿀', '𔌚', '𞁐', '🤳', '꜑', '𓴿', 'ꚻ', '𑙕', '𓑪',
+    '𓋽', '"', 'ꪻ', '摾', '⻘', 'ꈕ', 'ຳ', 'ਖ', '\u{ae2}', '𓳃', '𘫼', 'Ȑ',
+    'ꎀ', 'ꇩ', 'ꇦ', '𞠴', '−', '𓎕', '𓎚', '🉅', '૨', '⊘', '⚛', '𐅸',
+    '🭐', '۳', '𐡿', 'व', '𐮍', '𐲢', '𐄍', '♓', '🠖', '𝘂', '𑊐',
+    '𒿇', '𔏀', 'ኰ', '⡖', 'ۉ', '𝄐', '𑠠', '𖩡', '𐇔', 'ꉝ', '𓄯', '𥚚',
+    '𝝳', '𓀵', 'ਛ', 'Ⲙ', '⻑', 'ㄷ', 'ඍ', 'ᛉ', '𓇜', '🪈', 'โ', '𓎐',
+    'ɚ', 'ឮ', 'ᐊ', 'Ꮉ', '🫎', '𒒇', 'ᖅ', '\u{feff}', 'ಓ', 'Ꙗ', 'ⵯ', 'ኔ',
+    'ꬒ', '⿶', 'Ⴅ', '\u{e0074}', 'ᴄ', 'ᰐ', '𑇱', '👰', '𐤢', '\u{1a79}', 'ਸ਼',
+    '𜾠', 'ఛ', 'ꏆ', '𔁴', 'ѝ', '𝖞', '๖', 'Ṫ', '𐀀', '𓌈', '𘧎', '𐴒',
+    '⪮', 'ꏮ', '🗚', '𑒉', 'Զ', '㈿', '𝞝', '𜴢', '𓯰', '𓯴', 'ᨻ', 'ᰉ',
+    '𒔺', '𔑰', '⺻', '🟊', '𝢣', 'ﯸ', 'ꔣ', 'ꇮ', '߈', '✚', '𓩌', '𐡯',
+    '𑼜', '𝤽', '𝅒', 'ꪜ', '🎒', 'ӎ', '𓯲', '𓯶', 'ｲ', '≹', '𓓠', 'ꀅ',
+    '𓬳', '𞁧', '⼩', 'ꞛ', '\u{1171f}', '匆', '\u{20e5}', '⡣', '\u{11c94}', 'ꀃ',
+    '𜾂', '\u{11c9e}', '㈣', '𞺈', '𛋒', '𑋁', '𝚝', 'ꏫ', 'ꔼ', '𑖔', '𔗢',
+    '𐇗', '܂', '\u{31d}', 'Ʋ', 'ጠ', '\u{1cf01}', '𓼺', '🠘', '\u{a8e6}', '奢',
+    '𑵿', '⌎', '❲', '𜳈', '\u{e0141}', '𔔅', '\u{e01d9}', '🖦', '𓱌', '２',
+    'Ț', '頻', '🀑', 'ﾜ', 'ﲷ', '⾌', 'ϊ', '㋈', '㆓', '𒈞', '⸿', '𑙥',
+    'ം', '𓆛', 'ᐂ', '𒓕', '城', '𓅒', 'ᢲ', '𓼂', 'ₓ', 'ނ', '🤪', '𝘭',
+    'ᣛ', '𓫸', '𓫴', 'ꦔ', 'ꅋ', '𓄓', '𓼏', 'ᱬ', '\u{eb9}', '㊅', '𓥬',
+    '瘐', '𓚮', '𓳜', '⻲', '𒔯', '𝤱', '𝼜', '𓤣', '𑽕', 'ᨆ', '🢝',
+    '𑅣', 'ꊌ', '𘤌', '௹', '𘤄', '🜵', '﵀', '∫', '𐬈', 'Ϩ', '\u{a92d}',
+    '𓋙', '꩑', '𔀐', '𔀔', '𖺂', 'ↅ', '𑯷', '\u{8d4}', 'ꮦ', '𘪓', '𓨯',
+    '⽹', 'ꃝ', '𐍪', '⟊', '𓧁', 'ᓷ', '𜸯', '𘯆', 'ⲋ', 'Ꮛ', '𛲕', '𖧾',
+    '🕣', 'ﭽ', '𓄳', '𝙨', '𐂦', '𞠝', '𐂟', 'Ἒ', '╺', '𝞙', '𘤐',
+    '\u{1d16d}', '𑂩', '🤭', 'ꛦ', '𒃜', '𔒰', '🚓', '🤟', '𓸽', '፺', 'ė',
+    '⇢', 'ậ', 'ꨯ', '𛂳', '𝜦', '𒒕', '𞠤', 'ፉ', '𓄟', '𖪮', 'ಷ', '𒔋',
+    '𞠄', 'Ѻ', '𞠏', '𐚶', 'ᨡ', 'ᓁ', '𔇌', '𓤍', '𝋓', '𔉦', '❪', '𑊈',
+    '𑒫', '🙾', '↡', 'ૠ', '〴', '𓏖', '𑗇', '\u{5a6}', '\u{11300}', '𔗟',
+    '𔈲', '𔈶', '𜺃', 'ᯪ', '🨀', '㌟', 'ᯧ', '③', 'ᇟ', '𘯷', '⁘', 'ख',
+    'ᮡ', '▗', '𒂑', 'ሁ', '𞴍', '᧬', '𐄬', 'ꩧ', 'ᘌ', '𒓰', '‗', 'ᗰ',
+    'ｮ', '🜱', '𑴡', 'ι', '𝧋', '𓁹', '🂱', '𖬆', '𛱴', '🅥', '全',
+    '𐴉', '𛊢', '𛊦', '𐪀', '🖡', 'ퟣ', '𐊒', '𑏕', 'ⱼ', '𞋙', '𑯌',
+    '໓', 'ꓩ', 'ふ', '𐍊', '𐐓', '𛱨', '𝆡', 'は', '\u{e011b}', '🆭', 'ຫ',
+    '𝧹', 'ᦚ', 'ᔌ', '🭷', '𝃕', '𖄇', '𔗨', '🞥', '𝠹', '♁', 'ڟ', '🖴',
+    '🆝', '𐎦', '𜰡', '𝈰', '𓕜', '𓘆', '𜸲', '𓾥', '⤦', '𝑈', '𒑫',
+    'अ', '𑿫', 'इ', 'ຩ', '⻟', '𑚸', '𓊧', '𖣟', '𛀓', '海', 'ﻴ', '𐵆',
+    '𝦃', '⋍', '𓩯', '𒿮', 'ᢤ', '𜹦', '𖹓', '\u{8f9}', '𓅊', 'ᕃ', '👢',
+    '𝥚', '𓨂', 'ꀣ', '♜', '𓠄', '🂓', '𓪒', '𓪖', '𝓘', '､', '理',
+    '𓋟', '𓶗', '𖭞', '𔐍', 'ﳼ', '𝀘', 'ﰌ', 'ᾒ', '𑣀', '𐖅', 'ꨘ',
+    '🛰', '𑒨', 'ㅷ', '\u{116b2}', 'ㆷ', '𝝁', 'ᩂ', '◜', 'Ⱑ', '🔅', '𑀇',
+    '𔕞', 'Ⴇ', '𒑈', '𑶇', '𐦢', '𓙒', '↋', '𑿰', '𛀔', '𝣄', 'ﻻ',
+    '𞺹', '𓺋', '𝐂', '𘠓', '⹊', '\u{e0178}', '𞲤', '𘱰', '𓓹', '𐆋', 'ꟊ',
+    'ﴀ', '𓶌', '😁', '⹓', '𖠝', '𓑯', '𞡭', 'ꨟ', 'ފ', '𥥼', '🁕', '𓮃',
+    '𜰄', '𐭬', '𒇭', 'ᒒ', '𝆴', '𛂑', 'ႇ', '𓎫', 'ﰨ', '📴', '𔃈',
+    '𒿨', 'ࡩ', 'ꎣ', '𑠕', '🤌', '⤨', '𖄚', '𘨡', '\u{aac1}', 'ｹ', '🆖',
+    '⟟', 'ｷ', '🭒', '𐪑', '𞠆', '𑫦', '\u{e01a2}', '𘳒', '𝈇', '\u{2df0}',
+    '𝧔', '⨌', '㇑', '喳', '飢', '🎗', 'ￚ', '𔆵', '鱀', '𞲭', 'ζ', '𝐵',
+    '𜶓', '⎺', '𖡫', '𝠗', '🅐', '🕋', '𝓛', '𐛟', '⮎', '\u{600}', '￬',
+    '👎', 'ぐ', '𛃵', 'ꄫ', '𐓚', '𒆬', 'Y', 'ˊ', 'ᵮ', 'ఠ', '𛁃', 'ᩁ',
+    '🠁', 'ᄰ', '↽', '🇹', '🨪', '𝘽', '𖠞', '𔔢', '𑫁', '🛅', '𐭻',
+    'በ', '𖪝', '㉶', '𔙁', 'Ḁ', '𑗄', '𐨣', '𝙼', '𒃅', '🩹', '𖽚',
+    'ﱉ', 'ﭬ', '梅', '𑽍', 'ʟ', '𒒟', '𑣅', 'ꋜ', '𓎥', '麗', 'Ŧ', '╥', 'I',
+    '𐐢', '𒁓', '𔐵', '𝁡', '𓽀', 'ゖ', '𒾫', 'ខ', '𓰫', '𓢁', '𝣒',
+    'ӡ', '𞲊', 'ꐥ', '𓟒', '𐪘', '𔗮', '😏', '𝍳', '𖹄', '𔁺', '⮒', 'Ⴙ',
+    '虜', '蚈', '𩒖', 'ﳔ', '𛃡', '🌿', '𝥬', '𞡋', '𓪄', '𐓶', '𓣸',
+    '〧', '𐦿', '𒁎', '𑙩', '㈐', '𓧷', '𐞧', '𞢽', '😉', '🄑', '𓱵',
+    '𞀶', 'ᕘ', 'ዑ', '𐾶', '﹫', 'Ǫ', '㋔', '⮵', '𝥺', '\u{e0151}', '𓜢',
+    '\u{334}', '‖', 'ᮞ', '𔅷', '𝓼', 'ὤ', '𑌰', '\u{11c33}', '𓎾', '🏀',
+    '𑦽', '𑩹', 'ﲾ', 'ᄻ', '⽾', 'ޟ', '𒌰', '𑆑', '𐡮', '𓢝', 'Ძ',
+    '\u{a675}', '𓷴', '𐄌', '𝤪', '⤘', '𞲁', '🜷', '樂', '🄙', '𘯨', '𑀷',
+    'ꂝ', '☑', '◑', '𒉴', '⧻', '🕥', '𛀑', '𓢔', 'ᄝ', 'ᠪ', '𓢘', '𒎐',
+    '鐕', '𔀴', '𝂞', 'ሸ', 'ꂡ', '🖗', 'ꅺ', '𑜚', '￢', 'ǚ', '𜸩',
+    '\u{1e006}', 'ﾂ', '🉆', '🌰', '📵', '𖦎', '𒂄', '⭄', '𘣭', '𛰏', '𛇶',
+    '𐓐', '∐', '𒄎', '𪘀', '𒆂', '𐠬', '𝼃', '𐢜', 'ࡍ', 'ｰ', '𐍀',
+    '𔁶', '𓕗', 'ꉡ', '𝧶', 'ꛑ', 'উ', '⟛', '𑑌', '𓍁', '🕜', '𘠃',
+    '𓨗', 'ꆖ', 'จ', '𝟋', '𔆬', '𖦨', '⩁', '𝗚', '𞱺', 'ጡ', '🦻',
+    '\u{109d}', 'ꀖ', 'ጥ', '㊲', '🄌', 'П', '𒊁', '𘣩', '𒔌', 'ퟹ', '𑜖',
+    '轢', '𓏸', 'ᶳ', 'ד', '𐨛', 'ോ', 'ℾ', 'ꉤ', 'ﵩ', '𓗊', '⊅', '𐀨',
+    '𝗷', '𑯸', '🃠', '𐛏', '🬤', '𔋔', '療', '暈', '＃', '𓣺', '𒌵',
+    '𓐪', '🄿', 'ꔁ', 'ࢬ', '𑦳', 'Ä', '𓘜', 'ꗷ', '⎖', 'ﺡ', '𓥢',
+    '\u{e012b}', '\u{1033}', '𝐪', 'փ', '𝐲', '\u{e007a}', '臘', '\u{e0072}', '𓕏',
+    '⼋', '\u{e006e}', '𒋴', '𔖀', '𖤔', 'ꭊ', 'Ꮂ', 'ٯ', '著', '🀨', '╝',
+    'ᐢ', '\u{fab}', '𑫪', '🫰', 'ꡫ', '\u{202a}', '𒒙', '𝑞', 'ⶆ', '𓃟', 'ᱴ',
+    'ףּ', 'ן', '🝁', '᱾', 'Ḏ', '𐔥', 'ﴻ', '𘧫', '𓝴', '𐙩', '🍗', '⭨',
+    'ᘵ', 'ꬡ', 'Ɩ', 'ᰁ', 'ڡ', '🔬', '𔀯', '︙', 'Ƈ', '🠛', '𒌒', '㋂',
+    '𝼖', '𝄴', '𛊊', '⻄', '㈾', '𒿏', '᚛', '🐲', 'ꂇ', '𐝎', '𛉜', 'ꪼ',
+    '⿅', '𓲊', 'ꓮ', '\u{5a0}', '𔈮', 'ሚ', 'ꖐ', '⧲', '🁌', '⏶', 'Ị',
+    '\u{11a8b}', '😛', '𘤂', '◝', 'ચ', '𔈥', '𝩸', '𘬷', '𐖈', '𐎨', '𔐦',
+    '𖹤', '䳸', '𝆦', '\u{1cf16}', '𓱚', '𐞗', 'ꞇ', '𒋖', 'ㄔ', 'ᩭ', 'ဩ',
+    '𖭅', '𐵯', '𖼤', '𝗰', '♰', 'Ô', '⢱', '𒁁', '℅', '𐴞', '♤', '⬷',
+    'מּ', 'ྊ', 'ꆱ', '𞊤', '\u{1da33}', '𝟱', '\u{ac8}', '𓑃', '⬕', '🙚',
+    '𓡢', '𓡦', '𓤸', 'ⴍ', '贈', '\u{10f48}', '𔒋', 'ꈬ', 'Ҿ', 'フ', '≦',
+    '⇿', 'ἢ', '𓕁', '𘩏', '𑪵', '𜰿', '𓋐', '𐊯', '𘣍', '𒆞', '\u{1d17a}',
+    '\u{11181}', '𘲴', '🮜', '⛚', '𝌸', '𜸭', '𛆐', '𑗍', '𒎔', '𑒇',
+    '𓴨', '🛪', '◒', '𐃟', '𓕇', 'Ⴗ', 'Ꮪ', 'ꋌ', '𒍦', 'ㆀ', '𐱇', '𓮔',
+    '𓍐', '\u{11a3d}', '︰', 'ӭ', '𓩘', '𞄇', 'ꪚ', '𓪧', '\u{1cf1d}', '𝑐',
+    '᭻', 'ⓛ', '𜾓', '𝑠', '࿀', 'ȏ', 'ⓧ', '姘', 'ꉰ', '⡔', '𐔚', '⚮',
+    '𔔩', '悔', '𞲉', '𛂞', 'ᚭ', '𑖟', 'ꓯ', '𖦾', '᭴', '𝞸', '𔂠',
+    '𓄨', '𐔗', '𓅕', 'ᢾ', 'き', '𖤪', '🛇', '🚎', 'ᕈ', 'ㄡ', 'ⵓ', '⌛',
+    '𓖵', '𐴖', '𝦗', '𓖹', '𑇚', '⚊', 'ᗹ', '𝄈', 'ꪑ', '\u{e005a}', '𐼳',
+    '\u{e0053}', '𖪎', '𓖺', '𝔰', '\u{180d}', 'ᖎ', '⒊', 'ถ', '🠱', '𑂈',
+    '𓞲', '𓜶', 'Ḫ', '𐙟', '𔘈', '㉓', 'ң', '𘲜', '𝛈', 'ᢚ', '𓘽', '✡',
+    'ઃ', '𒐑', '𜸊', '𐵡', 'ៈ', 'ㅒ', '🟤', '𘯵', '𜴺', '\u{11a92}', '𜵒',
+    '𖫢', '🝄', '𖼅', '𓹟', '𓝫', '㊄', 'ﺟ', 'ᜐ', '\u{11038}', '𛋪',
+    '\u{11043}', 'ㄸ', '१', '𝍓', '𜹖', 'ꭱ', '🆌', '𛁉', '🮳', '🪏', 'ﻎ',
+    '灰', '𓭲', '㌷', '𒃝', '𓔊', '⨦', '𐘬', '／', 'ｓ', '𝛚', '⺳', '𝃒',
+    'ĸ', 'Ἐ', 'Ⲯ', '翺', '𐧒', '𡚨', 'm', 'a', '𖠹', '🬐', '𔋬', 'ﾣ',
+    '𐐏', '𐪅', '㏇', '𐦷', '🔪', 'ݙ', '𝚨', '\u{5a8}', '𑂝', '𐤕', '𝉅',
+    '𐳰', '\u{b3c}', '𔄥', 'ト', '𑗒', '𓡴', '𓖧', '𝘠', '🚆', 'ሶ', '𓦒',
+    'ག', 'ᨵ', '𑩞', '𘣓', '𜽹', '𘰦', '𓞂', '𒆸', 'Ҍ', '◸', '\u{1da9c}',
+    '𐭉', '𜱗', 'ᭌ', '崙', '𘳆', 'ᅘ', 'ꕹ', '𝞴', '𐂻', '𐰊', '🝪',
+    '𔆾', '𒔽', '𜵜', '燎', '𓤢', '𓁓', '♏', '𐫟', '𒉟', 'ᑴ', 'ꄻ',
+    '𝅘𝅥', '𐆜', 'ꆄ', '᭺', '⤚', 'ⵌ', '㏑', 'ㅋ', '𜽣', '𐅱', 'ꔌ', '𔁩',
+    '𓡫', '頋', '𑂎', 'ᢊ', 'ᣱ', '𔁪', '↚', 'ﴈ', '𘧺', 'ᙿ', '🃝', 'Ŵ',
+    '⧕', '𝕴', '☟', '𝞊', '𝛆', '𖭃', '𓍘', '𐖌', '𑆜', '𔎎', '𞴂',
+    'ꖉ', '𘠈', 'ퟙ', '🥸', '𐮀', 'ஷ', '𝣚', '𘨢', '⼿', '𓺼', 'ミ', '繅',
+    'ꋼ', '𝡰', '≇', 'z', '\u{aab3}', '𛇐', '𐃩', '🄊', 'ᾮ', '🂳', '𓾓',
+    'ꕗ', 'ꩶ', '⋢', 'J', '\u{1cf2c}', '𐊛', 'ᦐ', '⨏', 'ي', '𔈪', 'ꠣ', '㋠',
+    '𓖜', '𓇚', '𝧸', '𓄅', '𝙹', '፰', '୪', '𘠀', 'ꯑ', '𔈡', 'ڐ', '𘫴',
+    '𝒎', '𓁌', '𘮂', '𔊢', '𓭕', '㌭', '𝟸', '𝓂', '➸', 'ౙ', '𐇰',
+    '𓌑', '🧭', '𞁇', '👂', '𐛐', 'ᆈ', 'ᴫ', '𛆵', 'づ', '𓩤', '𔘖',
+    '𔘒', '𓺉', 'ꢌ', 'ﻏ', '𓭝', 'ⲏ', 'ꢆ', '⾩', '𓱋', '𒔾', 'ಹ', 'ᚯ',
+    '🨟', '\u{818}', '𐔋', '𑴪', '𐮪', 'ẚ', '𐂁', 'ݑ', '𛱚', '𘲌', 'ˬ',
+    'ꖋ', '𐆊', '𞊐', '🢇', '𑴥', '𖩜', '☒', '\u{11c36}', '𝝤', '𐠫', 'ଌ',
+    '𘪡', '👷', '\u{306}', 'ㆿ', '൦', '⧝', 'ﮱ', '࠲', '𖧣', '𓷾', '𝈿',
+    '𑒬', 'ꈦ', '𝋪', '༦', 'ᣢ', '𝚾', '\u{1cf0b}', '𥃳', '眞', '⪏', '⚑',
+    '瞋', '硎', '𛆒', 'ﱍ', '𓮵', '𐜂', '𘡶', '𝂕', 'ꈽ', '೭', '🬑',
+    'ꀧ', '\u{1d171}', '!', '🭚', '𓵧', '𓵣', 'ὑ', '𑵓', '慠', '𖵒', '𖠶',
+    '𖄪', '𔀙', '𑠣', '𔅤', '∓', 'ਮ', '𐇵', '𔄻', '𐝑', '\u{a67d}', 'Ė',
+    '𓷢', '🁝', '𑲩', '㈬', '𑁒', '𑇃', '𖼹', 'ᨎ', '𓛴', '𝚂', '𞢡',
+    '᪈', '𐑿', 'ᇙ', '🆩', '𐀐', 'ˣ', 'ﴨ', '𒐊', '𐊪', '㉸', '𐊺', '𘩹',
+    '𑈫', '╇', 'ꚇ', '𔏏', '𝕼', '舁', 'ꡧ', 'ﵬ', '🁦', '\u{a8e0}', '𐭟',
+    '𝈍', '𑁋', '\u{354}', '𑵨', '\u{aab7}', '🂅', 'ᡱ', '𒋉', '🬱', '𝧼',
+    '⦝', 'ꓞ', '𘥦', '𐛜', 'ﶶ', '𞋑', '³', '𑨤', '𓃦', '\u{1da09}', '𝚠',
+    '𞓵', '╭', '🦝', '🨥', '🂵', 'ጄ', 'ȩ', 'ￋ', 'ﱲ', 'ಖ', '𐑓', '𔘕',
+    'ᬘ', '𐡵', '⥘', '✃', '真', 'ຣ', '𑪗', 'ᴠ', 'ч', 'ɴ', '﹠', '\u{1c37}',
+    '෯', '𘪸', 'Е', '゛', '𛇦', '𛄏', '\u{11ca6}', '📱', '𓒺', 'ꌑ', 'ޙ',
+    'Š', '㍼', '꧍', '🜑', '㈲', '𐤧', '𑙄', 'ﵶ', 'ᙺ', '🢄', '𓐗', '𜾴',
+    '𑲁', '𔆪', '𖥓', '⌘', '𛁎', 'О', '𓊖', '𑄤', '🔝', '𘱜', '🎋',
+    '𑿓', 'ꓚ', '≺', '⏆', '𒾞', 'ጨ', '𝧎', 'ጪ', '𝌖', '𜶨', 'ⶪ', 'ﭻ',
+    'ǡ', 'ㆺ', '𖺅', '𒉶', '𐍕', 'ژ', 'ꢨ', '𘱗', '𐓊', 'ዛ', '🬲', '𐙂',
+    '周', 'ꃉ', 'ꃊ', '\u{fe0b}', '～', '𑎅', '𖤬', '🇲', '┾', '𓹶', '🥙',
+    '˓', 'Ⅽ', 'Ȯ', '𞡔', '𓢽', '𒅿', '𖫠', '𝒋', '𐎔', 'Ҏ', '穀', 'ᣆ',
+    '𝔢', '𐰯', '𓰕', '𑠍', '⅕', 'ꔬ', '𝓇', '𒌴', 'ˎ', '𓬫', '␉', 'ᩄ',
+    '𒐎', '𓦡', '𓚪', '𓦩', 'ᅬ', '🥍', '𑜸', 'Ⓤ', '𓢴', '𝔁', '𖬍',
+    '𥪧', '\u{113bd}', '𖬌', '⩇', '𔐰', 'င', '𓌬', '𛁴', 'ẕ', 'ᄲ', '𓦪',
+    '\u{13449}', 'ꨡ', '𔅻', 'ǣ', '📥', '𐑀', '⍈', '𒒒', '🥭', '𔁫', 'ጋ',
+    '𔅼', '𓵷', 'ເ', '𐌭', 'ვ', '𓎆', '𐅊', '⪝', '𜵃', '⥍', 'ࢻ', '𔅲',
+    '⦉', '𓙃', '🂴', '🩍', '𑵺', 'ᑚ', '𜽸', '𒔳', '𐳽', '⒅', '𝜸',
+    '㇙', 'Ⰵ', 'ꀕ', '𞗰', 'ﯹ', 'Æ', '𖼑', '⛀', '🠉', 'ᾝ', '𝚅', 'ࠚ',
+    'ꈁ', '\u{11d44}', 'ᨊ', '吝', '\u{1da6a}', '𒋱', '𑆌', '𖤁', '𔖨', '𓵍',
+    'Ｚ', 'ᗅ', 'ᓲ', 'Ｎ', '𐑌', 'ౚ', 'ᒚ', 'ᤃ', '\u{e01e8}', '꛴', '🆢',
+    '⨥', '𝠺', '𝖶', '𑋘', '𖧎', '⁻', '𜴅', '𑢭', '𑿚', 'ꬲ', '𞢪',
+    'ⷝ', 'ⷚ', 'ⶰ', 'ၰ', '𐼄', 'ᴗ', '𒌕', 'ꉙ', 'ӛ', '\u{1e2ec}', '𝝮',
+    'ꔦ', '𝆟', 'ࢋ', '凜', '𝝥', '\u{1dff}', '𓊄', 'ㇺ', '𓻨', '𓼎', '𓨦',
+    '𓇛', '⋖', '𑖃', '🚂', '㉾', '𑗐', '꜉', '𜹃', '𐛫', '𔑲', '\u{1cf3a}',
+    '𑼕', '\u{317}', '𐜙', '𑐥', '🭢', 'ᄦ', '𒊮', '𝕛', '𓒈', '𔇔', '𐏉',
+    '𖫛', '\u{a69e}', '𝚈', '🠈', 'ᝂ', 'ࢫ', 'ﳀ', '⋑', '𐤰', '𛄠', 'ከ',
+    '⻇', '𞹱', '𝈲', '𒒜', '𐧳', '𔒎', 'ὣ', '𔋡', '𔈉', 'ܬ', '𐕵', '፯',
+    'ಌ', '𔘫', '🝶', '🥉', '𐨀', 'ꄪ', '𒾥', '\u{1a5a}', '\u{1343f}', 'ᤫ',
+    '〽', 'ꋽ', '⢿', '\u{e0027}', '𓯵', '𛱛', '𞊕', '𞊑', '𓉔', '𖡠', 'м',
+    'ㆈ', '⎔', 'ᅾ', '\u{103a}', 'ᥚ', '🙔', '𑂥', '𓋃', '𓾬', 'ឬ', '\u{30f}',
+    '𓆐', '\u{e0037}', '𓹠', '﨏', '𞢂', 'ﰴ', '🐃', '𐆒', '𐬆', '𑿍', '™',
+    'ߪ', '𑄅', '𑤟', '卑', '𝒅', '𑄆', '┎', '𒂍', '𐄥', '𖫖', 'ౝ',
+    '𓏘', '᪂', '𔒁', 'ᴺ', 'ˆ', '\u{d00}', 'Ѳ', 'ᒌ', 'ꥄ', '𒄴', 'ꤑ', '₮',
+    '\u{11839}', 'Ჰ', 'ፖ', '𘲀', 'ㄥ', 'ው', '𞡑', '𓚻', '𓚿', '𐂓', 'Ɥ',
+    '㏖', '𔖸', '𛂀', '𓗫', '𓋗', '𛱃', 'ᾣ', 'ㇴ', '𑆓', '𓵃', '𓵇',
+    '𐖚', '🬌', '٪', '𑙖', '㇖', 'ﾴ', '⛏', '🚍', '𐡶', '𓠢', '𞱲', '𛉊',
+    '⠅', '𓽪', '🢱', 'Ļ', '𖠻', '⼧', 'ෝ', 'ꌄ', '𐓞', '🖎', '𝥕', 'Ë',
+    '\u{1cf14}', '𝜲', '🩃', '𓫇', '𜷨', '𐞯', '𐡹', '㍊', '𑵪', '🏋',
+    '𐓟', '㮝', '\u{821}', '\u{367}', '🐳', '𑠬', 'ᇢ', 'ৰ', '𖧹', 'ڿ', 'ㄪ',
+    '┵', '⸐', '𒍿', '𓏅', '≠', '𑃜', '𒉾', '𓏵', '🃥', '🁲', '⍴', '០',
+    'ڕ', '🔯', '𒐂', '𖹱', 'ᬙ', '𛈌', '\u{fe03}', '𓎍', '㏗', '𞸌', '𘩐',
+    '𝓉', 'ᇗ', 'ả', '𓔳', '𓤅', '⇡', '⳩', '𑽉', '\u{7ad}', '😀', 'ﾰ',
+    '𔗠', '啓', '♙', '𜸬', 'Ꮘ', '𓻟', '𞄊', 'ࠨ', '𔐒', '\u{a8ed}', '𑿙',
+    '𝄎', 'ꗱ', '𓕿', 'ꢴ', '𐭇', '㊡', '𛱀', '𝒿', '𐑅', '𔊗', 'ᱳ',
+    '𒉲', '𝝭', '𛀉', 'ᒗ', '\u{2df8}', 'ᗠ', '𘭔', '𓟲', '𓟶', '𑠃', '𝣹',
+    'ዊ', '🚸', '⠐', 'ཞ', 'ህ', '🪓', 'Ꮰ', '𑩿', 'ꧩ', '〇', '🪘', 'ᑧ',
+    'ࢉ', '𞡾', '𘯹', '𒀜', 'ꙝ', '𑇎', '更', 'ﮦ', '𓹭', '𓆶', 'ﶈ', '‰',
+    '𞲚', '𐎎', 'ᦵ', '𑤢', '𘯺', '𓗼', 'ރ', '⩌', '𔑴', '𝜴', '🪜', 'д',
+    'ᐿ', '⊔', '𐗀', '\u{1133e}', '𐗪', '𐗤', '\u{1cf26}', 'ᶬ', '𓻶', '𓐇',
+    '⺟', '𝒞', 'ඐ', 'ﲔ', '𐚣', '𓍄', 'ⰼ', '𑴛', 'ణ', '៥', 'ᇍ', '⮚',
+    '𓛍', '\u{b56}', 'ﰜ', 'ࢷ', '𑆋', '🄮', '𓑥', 'ⵛ', '𓏦', '𖪲', 'Ｋ',
+    '𛂜', '𞠜', '𒄒', '𐅻', '◙', '𑆔', '𑐋', '𓮐', '𞡿', 'ꦇ', 'ꃫ',
+    '𖧽', 'ꒀ', '𒐟', 'ꑾ', '𘥜', '🗃', '၊', '𓛀', '𐚅', '𘬸', 'ㆅ', '೩',
+    '⩯', '🪱', 'ↁ', '⿴', '𓁃', '𝍕', '⾼', '𑲍', 'ۓ', '\u{16120}', 'ㄎ',
+    'ꠑ', '⦤', '𑠛', '𐄲', '🠗', 'ｦ', '𒇂', '𖣱', '𝤄', 'Ꭳ', 'ᢈ', '⠈',
+    'ࢎ', 'ꪫ', '\u{e01bf}', '𓡈', '❣', '𓣠', '𔆛', '𖦞', '𓓼', '𝄚',
+    '\u{a8e1}', '♨', 'ﹴ', '⽊', 'ﺵ', 'ɏ', '𝚱', '𓿤', '𘱞', 'ꌐ', '𑵤',
+    '\u{1082}', '𑙁', '𞗐', '⬬', '𘪠', '᜶', '𖣲', '𝦠', 'ᦌ', '𝚤',
+    '\u{115bd}', '䏙', '𝆗', '墬', '𛊆', '𐡋', '🜗', '🛜', '𝈗', 'ಈ',
+    '𓹓', '𓥺', '𔅆', '𝌳', '\u{a9b6}', '𐂳', 'ꟸ', '𔓟', 'ﵔ', 'ᾱ', '𒎂',
+    'ᨽ', '𓸺', 'ᵳ', '𓤕', '𜵯', '᳂', 'ﭕ', '𘩊', '𞹷', '\u{3000}', '𒍇',
+    '⫹', '𖄙', '⮧', 'ꘒ', '܁', 'ꢮ', 'ᛋ', '𐇭', '⼍', '𔅑', '𓰥', '𓱨',
+    '📭', '𓆠', '變', 'ꨙ', '𔊌', 'Ⲓ', '🅸', '𝗝', '𐞴', '𐞓', 'ꨧ',
+    '𜱩', '𐂧', '㊫', '︼', '𔋏', '𑻧', '𖡌', '\u{11c3f}', '᱙', '🗟', '─',
+    '𐃯', '𐨢', '𒌯', '𘧸', '𓒁', 'ﺴ', '🨦', '𝟃', 'ⰹ', '𑦴', '𔎷',
+    '⬀', 'Խ', '𝓽', 'ᣧ', '\u{abe8}', 'ⓜ', '🔉', '𝆐', '𛄎', '♭', '꘣',
+    'ꑶ', '🥪', '𓒊', '𓄣', '𔏎', '𝟣', 'Ʀ', '酪', '𖥛', 'ょ', 'ꨂ', '💜',
+    '𔕯', '神', 'ㄉ', 'Ꟛ', '↿', 'ↆ', '𓡄', '𒁞', 'ច', '੦', '𔀱', 'ቱ',
+    '𓭅', '䪲', 'ꦖ', '❝', '𝪋', '𝑘', '⇁', '⼨', 'ᤘ', '缾', 'ᘸ', '𝢺',
+    '𒈼', '𐔼', 'ஜ', '𔕮', '𓍑', 'ꕅ', 'ᒿ', '🧧', '𓤌', '𒋻', '𜶐',
+    '𔌋', 'ﯜ', 'ﻐ', '流', '𐝢', 'ꍠ', 'ߗ', 'Ҩ', '𝡹', '𞠎', '🏒', '𒊥',
+    '\u{e01ee}', '𓹮', '↟', '♞', '𞋄', 'ﺈ', '\u{114c2}', '᮴', '𝍭', 'ⅿ',
+    '♸', 'ﷆ', '𒂒', '𓦺', '𝙒', '㋮', '𑨲', '𝙆', 'ᯏ', '𝘾', '異', 'ш',
+    '𘤳', '🃭', '𓠒', 'ড়', '🏌', 'ꂱ', '𔄸', '𔄠', '𝀴', '\u{e0039}', 'ኑ',
+    '𓫗', '𓫧', '搢', '𝤭', '𝑍', '𔌀', '𔕷', 'ყ', '𛉪', '⥧', '🫠',
+    '꧘', '㇢', 'ᅙ', 'ㅧ', '𞹉', '\u{1e017}', '᪠', '𘮣', '\u{300}', '📁', '🕙',
+    'ᠨ', 'つ', '𖵗', 'ᦾ', '࿔', '𐊢', '⬆', '᭜', '𝈘', '🎥', '𐃛', 'ﴯ',
+    'ᬲ', 'ⲁ', '❴', '⊎', '𛉫', '𘪆', '𓮕', 'ㄠ', 'ᴈ', '𑱘', '˸', 'ඤ',
+    'ဨ', '𒊠', '𜵠', 'ꨜ', '🏎', '𐧎', '𓑻', 'ᏽ', '𓹃', '𘦳', 'Ȉ', '𐺰',
+    '\u{e005d}', '𖦉', '𓼱', '𒒵', 'ᐬ', '⩆', '𖽮', 'ￜ', '⒃', '𓰜', 'ബ',
+    'Ỵ', '㌘', '𑲱', '𒿙', '𛆬', 'ႋ', '𑙫', '\u{335}', '🜓', '༔', '㎜',
+    '𜳒', 'ᢛ', 'ó', 'ᵯ', '﹅', '┌', '␦', '𐭕', '﵊', '𑄟', 'ⅲ', '𔗋',
+    '🐿', '𐊮', '🄩', '𛇟', '⛛', '𘡎', 'ꁝ', '⽇', '🄾', '𖫭', 'ݟ', '𒒅',
+    'ŉ', 'ኞ', 'ꝛ', '☳', '𒾾', 'ꦉ', '\u{35c}', '\u{1d168}', '㈁', '𝟕', '𘪭',
+    'ᮦ', 'ꋑ', 'ǂ', '🕈', '\u{2cef}', 'ⲍ', 'ꁔ', 'Ⲱ', 'ꬰ', '𐔣', '𓊠',
+    '𔑎', 'ᶘ', '🀵', '\u{ccc}', '↮', '𓷶', '𑨛', '🯹', '𐑑', '𘯙', 'ⶾ',
+    '𖪞', '𖦰', 'Ꝓ', 'ባ', '𖪫', '👐', '𒍗', '𓫄', '𑵷', '꧞', '𘬅',
+    '𔀥', '𔀩', '𝒙', '𝟯', '𓯊', 'Ǔ', '𘮫', '\u{344}', '𓝺', 'ນ', '𓉅',
+    '𘪥', '𓩝', '⾏', 'ퟦ', '\u{1da35}', '𝤨', '𔔺', 'ϩ', '𘰨', '𓉾', 'ᗺ',
+    '𑈥', '𑵣', 'ڤ', 'Ū', '⎣', '𐛊', '𓴕', 'ʂ', '⢇', '𑰋', '𛁸', '𖽵',
+    '𓷀', '𘣾', '𔌬', '𐠪', '㉙', '㎩', '𝣊', '𖵪', 'ḗ', '𐘍', '𞓘',
+    '𝔉', '𔌛', '𖤯', '𖾝', 'Ǘ', 'ꭻ', '\u{1a5c}', '𑫒', '\u{e0165}', '꩖',
+    '𛲐', 'Ԭ', '𞤒', '⫎', '🕨', '𐀔', '𐺇', '⡱', '🜺', 'ダ', '𐢔', '𔇲',
+    'ᒬ', '𖽩', '\u{a8ee}', '𝓲', '𞸤', '𝓪', '◪', '𝔂', 'ᮑ', '𝓺',
+    '\u{11ca0}', 'Ⱌ', 'ᰟ', '௸', '𐔵', '⍭', '𞗠', '𛊋', '⠀', '𑲴', '𜹍',
+    '🉃', '⊷', '𛉓', 'Ꝭ', '⤫', 'ᖾ', '🛡', '꒚', '𐛴', '𘩦', '例', '𒓀',
+    '🌜', '勉', '\u{11443}', '𝙶', '𖭕', '⤡', 'ᵰ', '𒌥', '⿲', '𞸡', '𒍚',
+    '𑶧', '🔣', '𝪊', '⦔', '𔅒', 'ꯦ', 'ȯ', '𔒧', '𑄷', '𐲤', '⺦', '𘲹',
+    '࿁', 'ᇛ', '╍', '𜱉', '𝦫', '\u{1ace}', '𓿥', 'ੜ', 'ن', 'ꓢ', '𛉗',
+    '𛆝', '𑈢', 'ㅘ', '𒈶', 'ќ', '👻', '𖫒', 'ﲆ', '🙹', '𓢑', '\u{1cf39}',
+    '𓢙', '🛬', 'ᑩ', '𐬹', 'ꂁ', '𘦲', '𝇏', '𓡂', '𓕈', '𝢵', '𓁼',
+    '𝕯', '𓯗', '𐰲', '𓻬', '𓅝', '𓅮', 'ߣ', 'з', 'ꔔ', '𘲾', '\u{1103a}',
+    '勤', '𛆡', '𛂻', 'Յ', '𝟊', '𐧫', '𖭜', 'ᱥ', '𑴅', '䷧', '𐎊', '𘤾',
+    'ﮗ', '𐘸', '◂', '㉱', 'ٹ', 'ꘖ', '𒾱', 'ᠡ', '㎚', '𜰓', '𘬦', 'ᠤ',
+    '≯', '\u{1da4f}', 'ꭘ', '律', '📯', 'ಣ', '𐖻', '𐖟', '𐙸', 'ᴋ', '🎓',
+    'Ꮻ', '𖦖', '𓛝', '𐴄', '꣖', '▔', '𑠫', '𘰎', '路', 'ȹ', '𓈰', '╿',
+    '戮', '弢', '⥹', '𞁟', 'ᦈ', '掠', '𒈐', '\u{30c}', 'ࡓ', '🝯', '𖧡',
+    'ꮝ', '🥫', '𓩣', '𓚀', '𓐤', '\u{1cf33}', '𝠱', '𐊸', '𓆤', '𒐛',
+    '\u{1daaf}', '𒆑', 'ᅔ', '𑋆', '🙀', '㊉', '𓠊', '拾', '𜱽', '𜸏', '𓒰',
+    '𒀉', '𐀶', '〒', '⡻', '\u{1b37}', '𜹬', '𑒂', '𝆢', 'ᡜ', '⡚', 'ꢐ',
+    'ၽ', 'u', '𔂔', '♐', '𐵢', '𑊥', '𝞅', '\u{2dfe}', '𓍾', '𛋳', '𖧁',
+    '𘮜', '𓖯', '𑁢', '𓵹', '🕛', '𒌹', '𖢭', 'ᴚ', '⓯', '⤀', 'ጙ',
+    '𑴭', '𛰡', '࿘', 'ﾛ', 'ꢸ', 'ᐝ', '🧗', '𓱧', 'ꇶ', '১', '𑱜', 'ꡊ',
+    '\u{2de2}', '柳', '☵', '夆', 'Σ', '𐫗', '🢴', '𐌝', 'ꕆ', '𖩐',
+    '\u{1103f}', '𓡽', '𔐚', '𐂃', 'ᛦ', 'ꋷ', 'ꋴ', 'ꘑ', '𓄴', 'ዜ', '🨚',
+    '\u{a41}', '𓒙', '𝦌', '꒑', 'ᇸ', '鬒', '𐿵', 'ᜭ', '𑗎', '🏸', 'ꍈ',
+    '🗅', 'ﺳ', '\u{82d}', 'ڹ', '𔃘', '𓓧', '𓓣', 'Ϗ', 'ᕋ', '⚖', '𓫳',
+    '𖢺', '𐳭', '𓵻', '𔃅', '🯍', 'ᅁ', '➞', '\u{10f4a}', '🏛', '𓤪',
+    '\u{1a62}', '𓔻', '➝', '𐶅', '\u{e01b7}', '᯼', '𝁀', 'ㅓ', '⡶', 'ᆛ', 'ᑭ',
+    'そ', '𘧃', '˾', '𑣜', 'ℽ', 'ҟ', 'ᣎ', '𝠉', 'ഊ', '𐛛', '𓴤', '𘠊',
+    'Ὗ', '𓱕', '\u{8f1}', '𓬂', '𐲕', '𑆊', '𛇃', '𐹢', '🥈', '𘢅', '෩',
+    '𐜛', '𓄥', 'ᆕ', '𓲫', 'ဣ', '🂲', '➥', '𓅦', '𛁨', '𑨠', '𑅝',
+    '𓖉', '𔌏', 'ὗ', '\u{1cf15}', '𜹟', '𜲡', '∕', '𓘠', '𝝔', '𔂆', '𛰻',
+    '\u{d42}', '𐜎', 'ል', '𑴦', 'Գ', '𝦒', '蟡', '𑿋', '💓', '𔅵', 'ន',
+    '⿈', '𓦞', '⬩', '𦓚', '𓾂', '🯘', '𜳠', '☲', '𓣅', '🏚', '𑐯',
+    'ꐈ', '𓝑', '𝣅', '𜰾', 'ᵠ', '𑼈', 'ꚿ', 'ዒ', '𑼄', '𐒓', '𓲩', '፹',
+    'ꬶ', '𑅢', '𐬝', '⣓', 'Ǉ', '𛂪', '𔉯', '⋙', '裡', '𒍛', '𝔶', '𔓻',
+    'わ', 'ત', '𒋾', '𝔍', 'ﬃ', 'ᬝ', '𖺁', '𑗚', '\u{1086}', 'ᵋ', '⧡',
+    '🁒', '⤋', '𖧑', '𞲦', '𓌤', '𓾔', '🤲', '𒐺', '𓗒', 'ȶ', '𝧡',
+    '𔘼', '𓨚', '⦛', '𓖿', 'ృ', 'ݺ', '𐽿', '⠝', '𞋀', '\u{613}', '\u{e016d}',
+    '𑀒', '𘥩', '𑴬', '𜶈', '𑛐', '\u{e005f}', 'ऽ', '⦱', '𓊆', '\u{2cf0}',
+    '𑈩', '𓳌', 'ﱃ', 'ܡ', '\u{11c31}', '𒒨', 'ಅ', '𜺆', '𒇌', 'ᕹ', '🎜',
+    '\u{6ec}', '⯞', '𐰓', '⅁', 'ೡ', 'じ', '⻖', '🌢', '𓥧', '⫯', '𞹒',
+    '𐶁', 'ꂐ', '𝡒', '♲', '𑇔', '𔇒', 'ጿ', 'Ằ', '𓾠', '𒓲', '𖦗', '꒬',
+    'ᒇ', 'ᧈ', 'ယ', '㌲', '𖫜', 'ꗩ', 'ﲱ', 'ꎛ', '౺', '౨', '𑨦',
+    '\u{114b4}', 'ฃ', '𔒕', '𔒑', '⁒', '╘', 'ꢰ', '𞟲', '𖦓', '𞟪', '🠯',
+    '🜒', '𑩶', 'ȥ', '𘫔', '◕', 'ꡆ', '𓪓', '🯮', '𝜪', '𑢲', '𔂱',
+    '𔂵', '🖍', '𓞭', '🂷', '\u{e01a3}', '𐞅', '🜄', '⾫', '⾣', 'ꮽ', 'ꇓ',
+    'ꉂ', 'ჾ', '⫃', '𝃅', '𑘌', '𒌃', 'ꑘ', '𓌂', 'ഔ', '𐎵', '𔑼', '𖠇',
+    '𝧯', '𓃡', '𑴍', '\u{5a9}', '𓽰', '𓢃', 'ﱙ', '𐧇', '𑢧', 'ꎔ', '🢧',
+    'ᙲ', 'т', '𘣛', '௰', 'ឦ', 'ࡻ', 'שּׂ', '𑛇', '𓘈', 'ᑇ', '🫣', '𓴻',
+    '𐼐', '𒆘', 'ﵥ', 'ᬏ', '𐲎', '\u{650}', '𝝦', 'ꐍ', '𛃜', '𔇙', '𔌘',
+    '\u{1e027}', '\u{1b6d}', '𜳳', '💎', '𛋷', 'ϴ', '𛊡', '🅈', '𓣖', '💽',
+    '𑜹', '𐝒', 'ﺆ', '𐵸', '\u{1922}', '🧤', '𐮮', '𓟌', 'Ꞓ', '🖅', '𛂖',
+    '𓰣', 'ᅹ', '𑪁', '𔉴', 'ᅉ', '繁', '🜴', '祝', '𜽔', 'ᤞ', 'ګ', 'Ҵ',
+    '⤇', 'ࢪ', '𐎅', '𜵖', '⪂', '𐇯', '㈏', '𒌍', '肋', '𓌝', '𒈠', 'Ɠ',
+    '🅝', '𐐖', '𐔒', '⿀', '🬏', '𑴩', 'ꖶ', 'ᙕ', '⥥', '𓏔', '𝁘', '⭖',
+    '𐆍', 'ℌ', '𒌋', '🁱', 'ࢠ', '𝙩', '𐫫', '🙂', '⨖', 'ン', '⇰', 'ㇻ',
+    '\u{fe29}', '⹅', 'ꩱ', '🅛', '𐡀', 'ᖺ', 'ѡ', '𓜙', '𓭖', '𓭒', '🃋',
+    '𒋵', '𜳥', '᮰', '𝜩', 'ж', '𔊉', '𐦉', '𖬡', '\u{1cdc}', '𘦐', 'ॳ',
+    '╬', '𝁞', '🌙', '４', '𝁖', '𐙴', 'f', '𛈟', '𐙰', 'ő', '𓨁', 'ව',
+    'ᚰ', '𐩻', '𜳝', 'ぼ', 'ᱎ', 'Ꙁ', 'ᓕ', 'ꐗ', 'Ꮈ', 'ⲫ', '𔍄', '👩',
+    'ﺛ', '࿒', '𔅧', '𓽲', 'ጭ', 'ꗆ', '🞘', '𔓅', 'ጩ', '𖬽', '𒿔', '𝔯',
+    '᧫', 'ᓵ', '⣕', '⑺', 'օ', '𓆻', '𘬗', 'ع', '𑍄', '𔁜', 'ꅈ', '⇾',
+    'ϝ', 'ꗼ', '🤑', 'ﬓ', '憯', 'ᦞ', 'ന', '𐙏', '𐹰', '𖫆', '¼', '𐫔',
+    '𖦶', '壷', '𞲃', '\u{5ba}', '𓂨', '𓚛', '𓪿', '𡴋', '𔋽', '𐚟', '꜋',
+    '𓡎', '⒠', 'ⰺ', 'ف', 'ꥪ', 'ᰗ', 'ᚾ', '🤆', '🗆', '㋎', '𑊙', 'ᕁ',
+    '🖜', '💋', 'Ꝅ', '𝡈', 'Ᏽ', '𒾟', '⇟', '𘲳', '💥', '𑽎', 'ᒞ',
+    '𘲼', 'ܙ', 'ꆘ', '𓒻', '🧰', 'ꀚ', '𝩿', 'ር', 'ᆤ', 'ꑚ', '⫔', '𓊭',
+    '𓊩', '⋳', '𑘓', '\u{1b71}', 'ꪶ', '𔅃', '𑢫', '𜹜', 'ព', '\u{13438}',
+    'ꃜ', '𐚳', '☸', '𛇣', '𞓤', '㆝', '⅛', '\u{1cd2}', 'ꦒ', '\u{1cf2b}',
+    '🗧', '𓕽', '𐘤', '꒐', '㆒', '᪤', '᛭', 'ⱛ', '\u{e0172}', '𝅅', '⻉',
+    '⯿', '𘮕', '𞴼', '𘠠', '\u{1cf04}', '㍥', 'ⷜ', 'ᵦ', 'ᔁ', '柺', '𘨯',
+    '𐳂', 'ᬱ', '🀛', '࠹', '𖢳', '\u{13431}', '𑫢', '㈺', '𓰓', 'ꄛ', '﵁',
+    '𓯤', 'ᘫ', 'የ', 'ﻡ', '👃', '𞢊', 'ꅣ', '𘮞', '𝄭', 'ﵵ', '𐁁', 'ሃ',
+    '𝐓', 'ꅭ', '🖣', '㏾', '⡴', '🁟', '⽃', 'ಽ', 'ﴬ', '\u{eb7}', 'ꪖ',
+    '𓸋', '𓬩', 'ᆫ', 'פּ', '𜳌', '𐚘', '𐚔', '𛆰', 'ʋ', '𔁐', '𘤍',
+    '\u{1752}', '𖥒', '➔', '🌚', '𜵄', '𐋤', '𝥍', '\u{e47}', 'Ⲇ', 'Ը', '𒀆',
+    'ﭠ', '𓭠', '𓭤', 'ˇ', '📛', '𐽗', '𒒓', '\u{1b6c}', '𑴓', 'ꃖ', 'ⰰ',
+    'ꀍ', '𞸱', '𞲐', '𑽑', '汎', '𥃲', 'ᜮ', 'ꞅ', '⭟', '𐕲', '𓸧', 'ǥ',
+    '𛰂', 'ර', '𘣞', 'ハ', '𓨾', '𓨺', '࿈', '╷', '𖨤', 'ਾ', 'ᶩ', '𑼤',
+    'ᗤ', '\u{e01b8}', '𒇪', 'ຂ', '𓎊', 'য়', 'ہ', 'Å', '𐖛', 'ᛜ', '🞍',
+    'ߩ', 'ē', '℆', 'ဋ', '㎈', 'फ', '\u{1cf1c}', '𛆢', '𔑝', '༨', '𝋒', 'ႈ',
+    'ꂒ', 'ય', '⬞', 'ꗥ', 'ⴉ', 'ㅭ', '𓒀', '𓻮', '𐦾', '𐘽', 'ᛇ', '𔗬',
+    '懞', '𔑶', '𓞕', '\u{13436}', 'ﶤ', '≄', 'Ꞟ', '𔃞', '𔃚', '𘳇', '⣯',
+    '𓫤', '🗩', '𓥷', '｢', 'ῷ', 'ﺀ', '⇌', '🉣', '𝣰', '𝼔', 'ꦴ', 'ޞ',
+    'ᖊ', 'ᖋ', '❘', '𑰙', 'ﳌ', '⬉', '\u{11729}', '𞱿', 'ꦃ', '𓹘', '㎸',
+    'Ꞔ', 'Ǆ', '𝜘', 'ꥫ', 'ᘞ', '\u{749}', '𓐯', '𑰔', '๓', '𐗰', '帽',
+    '𛀁', '𓺧', '౹', '𒈽', '⬈', '૧', '淹', '𔘉', '\u{8d7}', 'ᤷ', 'ュ',
+    '𞤧', '🨓', '𔊊', '𝓹', '𛋁', '🄔', 'ꡉ', 'ꋳ', '𐝣', 'ᖦ', '𑣋',
+    'ビ', '🖛', '🢢', '🪽', '𔊅', '꫟', '𓃃', 'ၕ', '᠆', '𘮊', '𑗙', 'ꑠ',
+    '𝛓', '\u{b55}', 'סּ', 'ഉ', 'ۼ', '𓳺', '𝧓', '𔈨', '𛋎', '𘮁', '𔇭',
+    'ᛧ', '𘨫', '🎮', '𔀫', '𑆱', '1', '🀂', 'ㅶ', 'ﬡ', '𝃤', '𐆙', '𓹔',
+    'ꖳ', '𐁈', '𓱷', '𔏟', '𘮶', '𘮲', '⸄', '𔑾', '𐱅', '⛌', 'ᗽ',
+    '\u{fb8}', '𐧩', 'ꑤ', '𒈉', '㋶', 'Ụ', '🛤', '𓗆', '𒎍', '𓸡', '📜',
+    '\u{10f85}', '🐮', '≆', '⅞', '𘭜', '⍿', '𐞒', '𐀇', 'ᙰ', '𔍫', '𔒮',
+    'ꭸ', '\u{119d6}', '𜾮', '⋗', '𘦱', '𐭰', '𞹔', '𐵮', '𔔖', 'キ',
+    '\u{e0067}', '𞺙', '𞱻', '𘫕', '𝁚', '🆀', '👖', '𑻸', '😃', 'Ⲿ',
+    '𖨜', '𓫛', 'ō', '𝛀', '𓖽', '𔍠', '𑱰', 'Н', 'ﯿ', 'ᗂ', '𡧈', '🗮',
+    '⸨', '𝁒', '㇅', '𞢩', 'ଗ', 'ሳ', '𔊧', 'ᒍ', '𘫂', '𞢼', 'ʑ', '𘠴',
+    '冷', 'ඏ', '›', 'ᦆ', '𖣕', '力', '𒀧', '𔓕', '𓢺', '⅙', '栟', '𔍿',
+    'ⴃ', 'ᜡ', 'ꏞ', 'Ϯ', '𔒼', '𐧧', '𒆃', '𜸶', '🥧', '🨺', '𖠟', 'ﻃ',
+    '䷦', '𓂟', '𑙓', '𑎭', '𞡇', 'ꪠ', '🐟', '🈠', 'ꬕ', '⚙', '𒔣', 'ݣ',
+    '\u{1cf3f}', 'ꕦ', '꜓', '\u{e0129}', '𓳈', '🮶', '𖫁', '𒾤', 'Լ', 'ﻆ',
+    'ꉲ', 'ᑈ', '\u{1ced}', '𓥵', 'ꍦ', '軔', '𞸜', '❯', '𓁨', '𛀻', 'ڎ',
+    '𒍴', 'ꀋ', '𖬜', '𑰠', '⿋', '𓠱', 'ᣪ', 'ڙ', 'ഄ', 'ꚮ', '𓴈', 'ㅬ',
+    '⍀', '\u{119db}', 'କ', 'ࡡ', '𞡟', '𓠡', '𐀚', 'ᒠ', 'आ', '⌿', '𐹵',
+    '👴', '🞰', 'Ú', '𑎹', '🐧', 'ᓻ', '𖬅', '𑖺', 'ꔸ', '𖬄', '🜈', '⏍',
+    '𒈦', '𔋘', 'Ǯ', '𛊾', '𓭼', '𖩧', 'ꌼ', '🈯', '𖨞', '⺃', '𖦺',
+    '𓥣', '𑤩', '∈', '🁅', '𓨢', '𝗕', '𓸂', 'ꥲ', 'ꎰ', '\u{1cf4}', '𔑹',
+    '𐢬', 'ẵ', '𓹋', '𛇳', '𖬣', '𝙏', '𑂔', '𑿎', '𐅚', '᧪', 'ầ',
+    '𜷜', '𒌶', 'ˢ', '𓍮', '𔈖', '\u{484}', 'ࡥ', '꜊', '𝀭', '⚟', 'ꪮ',
+    '𛲟', '⍇', '𐂉', '𓊓', '\u{1da00}', 'ᗍ', 'ᄇ', '𖫦', '𝈟', '𝈢', '𑤅',
+    '𘭺', '𘭾', '᭙', '\u{7ec}', '𜷃', '🅆', 'T', '◿', '𓢫', '𑘣', '𓱪',
+    'ϗ', '\u{1da4b}', '⣀', '➌', '𐬋', '𝃨', '܀', '𓽆', '『', '𓼳', '𓛜',
+    '𖵞', '𓍅', '𝘺', '驪', '𝘲', '\u{fbc}', '𐚂', '𝒄', '𓥝', '𛃞', '🡷',
+    '⾦', '𑯒', '龍', '⏂', '㏞', 'ꝩ', 'ち', '9', 'ජ', '\u{1c2d}', '𓗄', 'ꅠ',
+    '𓦢', 'ᬞ', 'ꏻ', '𝔚', '𛈫', '𝔒', '𑁈', 'ݪ', '≏', '𑂇', '𒅔', '𓮀',
+    'Ⱪ', 'Წ', '𪃎', '㭉', '𐼟', '𛁳', '\u{11127}', '\u{abc}', '𓦫', '𔐉',
+    '𓩚', '𐘝', 'ﵕ', '\u{111c0}', '⟼', 'ꏤ', '𑲂', '𘫜', '꩟', '⁏', 'ﻣ',
+    '㌃', '倂', '嬈', '🈤', 'ᠴ', '𛁁', '𓂗', 'さ', 'ㇶ', 'ﷀ', '𜸀', '㊮',
+    '𖬻', '都', 'ሂ', '𜴤', '䯎', '𔘘', '𐂮', '➴', '⦩', '𝀸', '⟲', '𐼣',
+    'ﮜ', 'ṗ', '\u{e0044}', '𑠜', '𐏎', 'ᐟ', '抱', 'ଜ', '暑', 'Ṽ', '⼵',
+    '╟', 'ᲊ', 'ꁹ', '೬', 'ɽ', '𝢷', '𘰈', '𐀰', '🖐', '𝇪', 'Ḇ',
+    '\u{1ab5}', '𓒠', '\u{b57}', '𓦚', '\u{89c}', '𘢜', '𖡀', '🮦', '𞀻', '࠻',
+    'ₛ', '𒎋', '𐔰', '❂', '𓥪', '𓵤', '㋜', 'ƣ', '戛', '⧰', 'ⱆ', '⽎',
+    '𑬅', '𛰢', '𛂊', 'ᩏ', 'ᩍ', '𞋥', 'ॺ', '𔗂', '𘪄', '𓡘', 'ਠ', 'ꍌ',
+    'ஐ', '〉', '\u{16f4f}', '𝟾', 'れ', '⛟', '𓈤', '⪃', 'ᳺ', 'ㄘ', '𐙝',
+    '𜷈', 'ꓵ', 'ί', '߂', '𑵡', '𝂳', '🜣', '𑋑', '㎟', '𝥋', '𑊱', '⥏',
+    '𐰖', '𓸸', '𐍦', '𐽸', '᭝', '😕', '🛫', 'ត', '𐨡', '𞁛', 'Ὓ',
+    '𓳞', '🬪', '⥸', '꒟', '🛴', '𞸍', '𐦯', 'ﺽ', '⸛', '𓶠', '𖨢',
+    '𔖩', '𑼏', 'ᐶ', '𓶭', 'Ⳋ', 'ό', 'ᔼ', '꓃', '𘭩', '𓳕', '𝝄', 'Ⴀ',
+    '𖭳', '🃴', '⇬', 'ꛆ', '𒒎', '𔋼', '𑊠', 'ℷ', '🆍', '➲', '␙', '𓍼',
+    '𒄇', '𐔽', '𐙁', 'ﰪ', '𒇺', '⎋', 'ｪ', '⬐', '𔉛', '𖬟', 'ᴖ', 'ꟃ',
+    '𐦁', '🨫', 'ᖶ', '𐩒', 'ⲩ', '煉', '聯', '𐣤', '𑩄', '𘧬', 'ߓ', '𞺆',
+    '𓩎', '𞄘', 'ᨑ', '\u{e018b}', '𞄟', '𔐑', '𘩚', '𓴞', '✓', '𓙟', '𖭡',
+    '𐝦', '⨶', '𑪽', '\u{1ba8}', '𘧀', '𝠛', '𘥟', '𛉔', 'Ⴃ', 'ﺣ', '꘧',
+    'ꓡ', 'ᄂ', '𐵤', '෧', '𓮛', '𐎂', '🔽', 'ᓣ', '᱐', '⿇', '⁙', '𘰝',
+    '៰', 'ࠂ', '⎽', '𝈮', '𐜬', '⼌', '𐣿', '𓰐', '𐴓', 'ጮ', '🧡', 'ᄀ',
+    '𐭮', 'ꉺ', '𛀚', '𛃖', '𜹉', '\u{2de5}', 'ڌ', '𑇲', 'Ꙉ', '\u{1da03}',
+    '\u{6e8}', '\u{1cf0f}', '⭝', '\u{483}', '𓭊', '↶', '𞄷', '𐙱', 'ꊯ', '𖨴',
+    '𝣉', '𞸫', 'ᆚ', '𒅆', '𒐓', '💂', 'ꅵ', '𑛗', '𒉻', 'ѫ', 'ៗ', '➏',
+    '𒿋', '𝧌', '懲', '𐃺', 'ᓠ', '𔒬', 'ꍔ', '𛊓', '𐀟', 'ꐑ', 'ⓓ',
+    '🕁', 'ﰏ', 'ூ', '喝', 'ᛖ', '𛲔', '𑶋', 'ퟨ', '𔅩', 'ᐘ', '𔇢', '𑨰',
+    '𔎮', '𐾸', 'ꅪ', '𝂢', '𔎣', '\u{33e}', 'ਗ਼', '𝁝', '༢', '𓑲', '𑰀',
+    '㊹', '𒋺', 'च', '⡟', '𖥋', '𝦦', '🮞', '🃗', '㇐', '𓣝', 'ｏ', '🮘',
+    '📕', 'ᬮ', '𓩬', '𘪒', '𔎡', 'Ҁ', '𖧛', '𝙸', '𓓂', '\u{1143a}', 'ছ',
+    '𓎷', '𓕷', 'ޖ', '𔖐', '\u{1e028}', '𞡢', '𓅾', '𖦳', '𑐓', '𓷲', '𐃊',
+    '𝜆', '𐵏', '⏇', '𓖁', 'ᙉ', '𔗻', 'ԛ', '🨧', '𘭙', '𘡗', 'ⴠ', '🅽',
+    '𝣱', '𝢲', '\u{1da08}', '𘮢', '尢', 'ꊃ', 'ᔳ', '𝘐', '𜸿', 'ඒ', '🢒',
+    '\u{1d189}', '໙', 'Ვ', '㎍', '𔊆', '𖪥', '㎲', 'ﴳ', '𖪳', '𑀑', '𑄦',
+    '𓮓', 'ґ', '🐕', '\u{20f0}', '⨆', '𘫥', 'ἒ', '𝃱', 'ꃇ', '𜳛', '𔀀',
+    '𑰡', 'ᖱ', '𜷬', '🈪', '𚿾', 'ꃰ', '𒅞', '\u{10377}', '❮', '𓛱', 'Დ',
+    '𐔾', '꧵', '𓘑', '\u{ece}', '𖵋', 'ᄨ', '㆔', '𐅨', '𐂗', '🖀', '𓐌',
+    '\u{1d177}', '𖡸', '𒎒', '𐧆', '𒐿', 'デ', '☁', '⡎', '\u{1b38}', 'ヅ',
+    '𓼵', '⻋', '𛃅', '╠', '𓅀', '\u{e01e1}', '𓱃', '𞄽', 'პ', '𞋃', 'щ',
+    '𞱱', '𑆒', '⧣', '𝢒', '𓀓', '𐝏', 'ﴤ', '𛊯', '𓤷', '𓧆', '㌜',
+    '𔂪', '𖬇', '𓧏', 'ⴗ', 'ᐗ', '來', 'ᐎ', '⨊', 'ᓄ', '⏁', '𐙛', 'అ',
+    'ꦵ', 'ಒ', '𝤣', '硫', '᱃', '𐄤', 'ቬ', '🫒', 'إ', '𔔇', 'ߡ', 'ꑒ',
+    'ඡ', 'ꙭ', 'ꄈ', '𐤫', '⾟', '𖹸', '𓷉', '𖹽', '𐽰', '𓁊', '𘦉',
+    '🗀', 'ኧ', '𔒩', '⦻', 'ᓞ', 'ం', '𞗪', '⛁', 'ᬇ', 'ꏬ', '𑯗', '𐌴',
+    '𓃧', '𝂅', '↝', '𔎼', '𐞝', '⸦', '𓰺', 'ﻊ', '🚡', '𞺻', '𛅾',
+    '⿕', '𖪙', 'ᆒ', 'ꛁ', '𐪒', '𔕚', '🮅', '⹋', '🤣', '𝞰', 'ᚏ', '🤼',
+    'ᶜ', '𝅫', '𓀸', '𖹺', '𒋷', '𔅱', 'ꢅ', '𓱻', 'ಥ', '＄', '𓁠',
+    '𑈉', '𓍽', '𖥾', 'ၪ', 'ᢞ', 'ഫ', '𓕧', '𓀡', '𓵈', '🀓', '𑁔',
+    '𖼿', '𒍽', '\u{35d}', '𝕥', '∝', '𓾰', '𐎑', '\u{1c2f}', '𖤟', '𤠔',
+    '🈳', 'ꆃ', 'ⰷ', '𐦜', '𝠩', 'ᄵ', '𓴸', '𓜀', 'ㅤ', 'ᰵ', 'ܩ', 'ⵝ',
+    '𓕬', '琢', 'ꞣ', 'ﾐ', 'ﾄ', '𝞥', 'ៀ', 'Ἵ', '𝠘', '𓷿', 'Ꮕ', 'Ꝫ',
+    '𑘜', 'ﶎ', 'Ͻ', '𘭅', '𔂘', '𑈻', '𑱐', 'ṅ', '𐑆', '⥛', '𘡟', 'ᣥ',
+    '𝞇', '𝇧', 'ﴡ', '𑎈', 'ɼ', '𑛓', '𔊼', 'ℼ', '𖵄', '𑈟', 'ゼ', 'ザ',
+    'ﱭ', '𓘄', '𐛲', '𞄎', 'ᥡ', '𑿈', 'ၝ', 'ꂶ', '𝛊', '\u{11c30}', '𓽙',
+    '𓇡', '𔑦', '\u{e002c}', '𓰢', '𐐅', '\u{115b2}', '𒑗', '𓴙', '⊈', '𔀲',
+    '👥', '\u{11a8e}', '𔀿', '𒐝', '🢁', '⣗', '𒆡', '𑂑', '𛱳', 'ᡘ', '𐵑',
+    '𒄧', '▀', 'ݱ', '𑐢', '\u{e0001}', 'ᔅ', '𖩯', '𖹮', '🖳', '𓦸', '◡',
+    '𞡚', '𖢪', '𒔊', '𑜈', 'ꠍ', '𐢩', 'ꐊ', '𞴪', '𘱔', '🃧', 'ප',
+    'ꌃ', 'ᛔ', '𛉽', 'ᑣ', '𘭼', '𝒉', '𑎥', '𑎷', '𞤥', 'ଈ', '𛀊',
+    '🬰', '𑅡', 'ᚨ', '🦹', '𖥢', 'ພ', '𖬪', '𝡋', '⒭', 'ꁪ', '𛋃',
+    '\u{1a56}', '𑐦', '拓', '\u{180c}', '𒄏', '𒇣', '廓', '㏀', '𐂛', 'ᾔ', '⡗',
+    '⮻', '\u{180e}', '𓣣', 'ᴶ', '\u{596}', '𞋪', '🍣', '⽍', 'ৎ', '𝤋', 'ጒ',
+    'š', '爐', '𞟣', '𓿓', 'ᕖ', '𛂏', '𒋥', '۾', '𑆕', 'ᴊ', '🈢', 'ᓭ',
+    '႑', 'ቆ', '𓟸', '𖼥', '㏎', '𝙙', '𛃙', '𜸴', '🄪', 'ᚖ', '\u{658}',
+    '𓕯', 'ፌ', 'ﭗ', '👇', '\u{657}', '⎶', '𐆄', 'ᒜ', '𓙲', '\u{345}', '🬩',
+    'ᆐ', 'ջ', '𓵟', '𓵛', 'ꯒ', 'ꔛ', '🫩', 'ސ', '𒋗', 'ꍀ', '🁊', 'ʙ',
+    '𝤏', '𑙧', '𓁂', 'ₘ', 'ᡐ', '♟', '𐘪', '𔂬', '𓰪', '𓰮', '𓁭',
+    '⼻', '᰻', '𔔛', 'ℯ', '𑗂', '𝧍', '𓐙', 'ᶗ', '𓵒', '𐝠', '窱', '⮷',
+    '𝇆', '🦭', '𖢌', 'イ', '𐒙', 'ᡥ', '🫢', '𓟔', '𔘔', 'ꑆ', '𖽔',
+    'ꎘ', '節', '𓜻', '\u{135d}', '𐊿', 'Ὰ', '𛋸', '𖽾', '🮋', '𝣨', '🪭',
+    '𖣙', 'ᙯ', 'ꛜ', '𐚢', '𝦮', '\u{e0190}', '\u{820}', '𒀟', '𖽜', '౩',
+    '𔉒', 'ࡉ', '屮', '𓏋', '𘬵', '𛄐', '⫄', 'Ḅ', '型', '𓂦', '\u{1612d}',
+    '\u{33c}', 'ᣂ', '𛋠', '㈋', '𛋨', '▾', '𝗨', '𖭷', '𝂇', '﹋', '𝗘',
+    '𓜏', '𘤰', '𝗜', 'ꭑ', 'ᝯ', '\u{614}', '𑊚', '𔘺', '៹', '𝨷', '🅶',
+    '𘣢', '𐩾', '𝢂', 'Ｒ', '\u{5b5}', 'ጵ', '𝄜', 'Ꮒ', '𔀨', '𓱘', '瀹',
+    '𛆅', '\u{e01a8}', '⌇', '𑿁', 'ᬉ', '🥥', 'ອ', 'ହ', 'ﻚ', '🥻', 'j',
+    '𓺺', '𞺌', '𞲙', 'Ỽ', 'ڼ', '𛁼', '𐛾', '𞠢', '䷢', '◚', 'ฉ',
+    '\u{11133}', '𒑎', '𝛟', '𓻴', '𝦶', '🂠', '𓉎', '𐽒', '𑫣', 'ꭡ', '⧘',
+    '🝰', 'ᗒ', '𑜎', '\u{e0189}', 'ꅾ', '\u{11835}', '♝', '𛊅', '𓱔', '᱘',
+    '𑒮', '𒇖', '𘮗', '𔌑', '𐐤', '𓸿', '𐆌', '㏝', 'ꝿ', 'ᨇ', '𓱝',
+    'ᾈ', '𐨦', 'ꥵ', '❓', '𒄯', '𑘮', 'ᨖ', 'ɢ', '𑌠', '┧', 'ᰂ', '𐣨',
+    '𐃃', '᱗', '⯎', '𝃂', 'ᑋ', '𐃅', '𓌊', '𓔔', 'ꐭ', 'ﻞ', '🕺', 'ঐ',
+    '⼃', 'ᣜ', '꘏', 'ႎ', '𓫠', '𜲿', '𑀌', '𝼩', 'ꌝ', '𑊶', '𑧉',
+    '\u{e0109}', 'ᢃ', 'ꌭ', '✲', 'ᬩ', '\u{20da}', 'ꄇ', '𝼚', '𘦏', '𛱗',
+    '🏴', '𓂱', '𖤡', '᐀', '𓂵', '𓄕', '𐍈', '𞺃', '𑨮', '𒒢', 'જ',
+    '᮷', 'ꉕ', 'ꭗ', 'ꉓ', '𝜮', '𑈅', 'ղ', '𝦹', 'Һ', '𑤏', 'ⷀ', 'ಕ',
+    'ꣀ', 'ㄅ', 'ﶋ', '𓾾', 'Ჳ', '⯰', 'ҫ', '𝠽', 'ꭂ', '⣄', '𖽶', '\u{a8ea}',
+    '᠇', 'ᙶ', 'য', '𓓸', '𐡸', '㉛', '𐘖', 'ﱗ', '𐀭', '𔐴', '\u{e018a}',
+    '𓷒', 'ᛏ', '⨼', '⽪', '𜱼', '𝖵', '𑰘', '𚿱', '𝡪', '𔘝', '𓂊',
+    '𓂎', '𓯘', '𑽌', 'ﻱ', '𑢰', '𐲡', '\u{a951}', 'ड', '𓦍', 'ມ', 'Ể',
+    '𞱹', '𓴉', '𐂐', '𐣵', '🛞', 'প', '𐕶', '𛋛', '覆', 'ꇀ', '🦯',
+    '𓵞', '𝡫', '🥇', '𑣝', '𔉀', '\u{e01cc}', '𐒆', '\u{10f4e}', '𝤿', 'ԋ',
+    'ツ', '𑛀', '𝧊', '\u{8d6}', 'テ', '𛋖', '𝘞', '𝘚', '𜲸', '𘬮', '𓈑',
+    'ȵ', '⎡', 'ᴜ', '𞗨', '𓵌', '𑚮', '𒅗', 'ಔ', '𝡬', 'び', '🦮', '𝢡',
+    '𐤪', '𐵲', 'ێ', 'ﵹ', '𒿭', 'ᕏ', '𓒌', '𖽭', '𖪇', '𓟿', '𓺯',
+    '🕹', '\u{1035}', '𝘆', '𖽓', '𘦬', 'ꔯ', '𔊒', 'ᗉ', '\u{1037}', '䁆',
+    '⌮', '𒅲', '߅', '𒐥', '𔋸', '⸃', '𐰆', 'ᒧ', '𓈆', '𑱾', '🬫', '𖤢',
+    'ꬿ', 'ᗩ', '𑘃', '⽺', '🢪', '𓧘', 'Ϝ', '𔈼', '𒐋', '𖠣', 'ὅ', '𛱠',
+    '𓈔', 'ⷐ', '🍕', '𛊴', '\u{30d}', '😓', '𘡱', 'ᄹ', '᭞', 'ⴡ', '👛',
+    '⥿', '𖪜', '𓔘', '᭥', '離', '𔌡', '🭘', '𔌩', 'ᗡ', '𔔆', '𝚬', 'ጀ',
+    '瑩', '𓷱', 'Ტ', '𐦐', '🩱', '\u{9be}', '𘬝', '⡝', 'ⵎ', '𝍠', '𓃪',
+    '𘱚', '𝞡', '𑣓', '𝔬', 'ﴓ', '🆛', '𝼌', '⊏', '𔈗', '⦦', '𞓥',
+    '𒄔', 'Ḋ', '⦘', '𝘓', '𝈴', '𓌮', '𓫒', '㋰', '𔆃', 'ꡁ', 'ᣨ', '⛈',
+    '𓰎', '䷕', '𞸁', '𓰁', '𓰅', 'ᶴ', '𝥼', '🧾', '𝅎', 'ꥣ', '\u{11d3d}',
+    '𐧔', '𛀕', 'ꙿ', 'Ȱ', 'ˏ', '🚉', 'ꒈ', 'ㆡ', '⊗', '𐻂', '\u{ac7}',
+    '\u{1ce0}', '𐕔', 'ꧻ', '⢏', 'ԙ', 'ꄝ', 'ᖟ', '🜩', '𜵧', '𝌎', '🯳',
+    '𝌐', '𓘟', '╋', '₱', 'Ꝣ', '𝓳', '⧟', '🩑', 'ﵺ', '𞲏', '𑶂', '׳',
+    '𑎀', '𐩐', '𜾙', '𑎄', '\u{e017c}', '🍳', 'ﶟ', '😰', 'ਇ', '⸆', '🕷',
+    'ᶊ', 'ኾ', '𑓖', 'ᔋ', '𓷙', '𝗱', '🈶', '\u{8fa}', 'ワ', 'ᇚ', '𖤩',
+    '𘠹', '∹', '𘠱', 'ඝ', '𐊗', '𓉷', '𘩯', '𓷃', 'ʓ', '𘢪', '𓵗',
+    '𐇶', '\u{302c}', '🏑', 'ᘀ', '𓐠', 'ᜩ', '𓕥', '𓬧', 'Ꮃ', '🝠', '༧',
+    '𝈾', '𓒜', 'ℶ', '𖣹', 'ꀠ', '⭊', '𝐝', '𐗯', '⏼', '𐃞', '𓈾',
+    '𔁄', '𑖞', '𔇷', '\u{8eb}', 'ᡶ', '𑌃', '𑊦', '🅵', '𘡉', '𝤐', '🝏',
+    'ᆸ', '㌯', 'ᄓ', '߿', '꧅', '𒁴', 'ꛔ', '𛅺', '𑙨', '𝟹', 'ᚧ', '𔃸',
+    'ࠅ', '𔃰', 'Ṗ', '𑨧', '𞠐', '\u{1ba2}', 'Ꞇ', '𐕴', '𐲭', '𓰧', '𓜪',
+    'ą', '🟅', '𓈋', '𐂈', 'Ṹ', '𜰷', '𛰩', 'ꛠ', '㉅', '𓜁', '\u{f79}',
+    '\u{fb1}', '🧎', '𜵟', 'ഓ', '𔏘', 'ㆾ', '🖘', '☶', '𓴟', '𔘶', '𔘲',
+    'ᆣ', '\u{a4b}', 'ꦄ', '🥴', '੨', '⠏', '╲', '𔔁', 'ﰂ', '\u{1de7}', '⡒',
+    '🆪', 'ﻠ', '🨄', '\u{1136c}', 'ᜅ', '𐔊', '𖭭', 'ꗒ', 'ᘅ', '𒍔', '𝛽',
+    '𖹟', '𜹲', '𐆗', 'ٻ', 'ﵘ', '𖺄', '𖹍', '𔌵', '𔒃', '𞤣', 'ᱢ',
+    '🭤', 'ퟵ', '𝣥', '𖽳', '𒌄', 'З', '𐲨', '🯤', 'ᠧ', '𜶆', 'Ḙ', '𘥺',
+    '╏', '▊', '𝢫', '🎚', '懲', '𖪓', '𐍯', 'ܝ', '𐹺', 'ⳕ', 'ቶ', '㍿',
+    '႟', 'ॴ', 'ऄ', '𘭠', 'ꗡ', '⎴', '𝓠', 'ﻒ', 'ﻔ', '𓘭', '𝉀', '𝔻',
+    'ၺ', '𓞤', '𘱪', 'Ꜩ', 'ﺹ', '\u{829}', '㇕', 'ᘹ', 'Þ', '𔕸', 'ྉ', 'ힱ',
+    'ꔇ', '㊱', '𒋊', '𝅃', '⦠', '\u{1da02}', '𒉺', '꭛', '𑧀', '𖵕', '𑪢',
+    '\u{f8d}', '㉰', '𝐳', '𓡊', '🇨', '🇬', '𑅵', 'ᎅ', '𖪋', '𓌃', '𓺔',
+    'ぅ', '𓤒', '🧉', '𔍌', '𐔷', '𝧠', 'ẽ', 'ꇄ', '⼄', 'ѳ', '𜾾', '𓵩',
+    '𐗮', '𝍣', '𒆮', '𓔣', '𓜽', 'ਙ', '𘣤', '🠇', '𑅜', '㉏', '𐞩',
+    'ꜵ', 'Ყ', '𔅝', '𑧋', '🗡', '𖣘', '𝖂', '𝌝', '𝧭', '𝈙', '𞤁',
+    'Ῑ', '𖥽', 'ᴍ', '𛀟', '𐝉', 'ፁ', '𘢨', '𓠸', 'Ё', '𘢤', 'თ', '🈷',
+    '𐒇', '𔁻', '꒓', '𛀩', '𐙎', '𝂻', '\u{e0152}', 'ﴦ', '𝝗', 'ヹ', 'ᕥ',
+    '🡆', '🁼', '𓦬', '\u{e0196}', '⨻', 'ḝ', 'ᆃ', '𝧥', 'ᨈ', '𐆂', '🭮',
+    'ꓕ', '𓠻', '𓃏', '᯽', '🃓', '𑖇', '𝄩', '𘨅', '𒂣', '⏨', '𐲧',
+    '𑩧', '𐒐', '🎲', '⦕', '▣', 'Ȇ', '🧫', '𐨬', '𑫱', '౾', '𖪸', 'ਨ',
+    '㈆', 'ﺋ', 'ᵆ', '𑑉', 'ꎟ', '𛁧', 'ﻅ', '√', 'ﴄ', '𜺨', 'ᗱ', '𓍒',
+    '𐅰', 'ᑉ', 'ᗯ', '⪷', 'ᅥ', '\u{59e}', '𒆪', '🯧', 'ꛬ', '🡗', '𐣮',
+    'ቇ', '𑖓', '𞋎', 'ሓ', '𐵙', '𓗥', 'α', 'ኍ', 'ﲋ', '𖬧', '層', '⫶',
+    'ጳ', '𘦕', '𝒹', 'ꆟ', '🞅', '⨘', 'ಋ', '🮇', '⥜', '𜽲', 'ㆊ', 'ⴁ',
+    'ዥ', 'ȋ', '𞡀', '𑴏', 'ᕳ', '𐌲', '𑖡', '贈', '乁', '𓗮', '᧓', 'ᝮ',
+    '𖤰', '⤻', '𔒹', '≧', '𔍎', '𐴳', '𐎥', '𔓣', '𔑭', '𖩏', 'Ґ',
+    '𑍈', '𝙾', '😞', '𞗓', '𐖝', '嶺', '𖤣', '𒈬', 'ᅮ', '𜲰', '\u{338}',
+    '\u{e0131}', '𘯼', '肭', '΅', 'ᆵ', '𓻳', '𝀀', '𝤼', 'ﴣ', '𞄸', '꒮',
+    'ﯳ', '🐝', '⨓', '\u{65e}', '⤱', 'Ḍ', 'ɬ', '𔑵', '𘨩', 'ꍻ', '𒉬',
+    '𒃖', '𔊾', 'ᤀ', '𓡥', 'ぃ', '📸', '₦', '🙰', '𓩻', '𝖔', 'ㄭ', '⋴',
+    '\u{10f49}', 'ց', '𖠸', '𓟜', '㈤', '𓶖', '𓺏', '𓠈', '𔊱', '\u{1cf24}',
+    '🈙', '𞤦', '🮮', 'ꭃ', '𖩻', '𝌃', '𑼐', '𐳟', '🮒', '𖧗', '𐲏',
+    '𔂫', '𖩄', '𑣭', '⏘', '𛂫', 'ზ', '𑚛', 'ጓ', '𘢚', '🆓', 'ﭳ',
+    '𘩛', '𘩟', '🫗', '\u{816}', '𒂂', '𞺄', '𒄮', '＝', '𓭇', 'ⷑ', '𝌣',
+    '䳭', '🚽', '𖠐', '𓳛', '𔊖', '𓕠', '𑐞', '𝣡', 'ఒ', 'ᔂ', '🎟',
+    '𒃸', '⁶', '⋷', 'ꗈ', '𓇶', '𓓳', '𒿊', 'ඥ', '𐠴', 'ﳿ', 'ꗘ', '𞴁',
+    '〼', 'ⵗ', 'ਈ', '𖣍', '᪃', '𖵇', '༺', '𒍆', '𖤏', 'Ⅱ', '▏', '𛅹',
+    '⟿', '⓪', '𖾚', 'ꗣ', '𜸢', '꧴', 'ᙳ', '𐚌', '𔇓', 'ݻ', '╯', '𓟄',
+    '🔐', '㉐', 'ℭ', '𒌸', '𘨻', '⧺', 'బ', '\u{a672}', 'ᆅ', '🝮', '𑐍',
+    '𓀧', '⒞', 'ᗲ', '𒅯', '𓐆', '𒋶', 'ⰾ', '⿍', 'ꄊ', '🮠', '𓍡',
+    '\u{eca}', 'ﻷ', '𔉪', '🬯', '\u{e0102}', '𘦿', '𑪡', 'ᥒ', '\u{1da29}', 'ᗫ',
+    'Ꮷ', '𝢆', '🄵', '𒁿', '𓗞', '﨧', '\u{1193e}', '🯭', '𐪏', '𘨘', '𔈷',
+    '𞟺', '𝋲', '𓀢', '𑇫', 'ꬥ', '𒇏', '飼', 'Ȧ', '⤃', '⬹', '𒓹', '𘧟',
+    '⭅', '🕚', 'ꢛ', '𒒛', '𓞈', 'ኺ', '𖦡', '𒂁', 'Ť', 'ﰸ', '⩈', '𛈅',
+    '🃁', '꜏', '𐤟', '𠭣', '𑊹', 'ᅚ', '𞠸', '𝀗', '⸕', 'Ʝ', 'ᔒ', '🪔',
+    '𓙇', 'ᱚ', '𘡚', '林', 'ǜ', '┯', '𑜍', '𞸋', '📡', 'ዞ', '𓈃', 'ᛠ',
+    '🦡', '՛', '\u{1dfd}', 'ⶊ', '𐚭', 'ᮒ', '🦬', '𝢭', '𐹡', 'ꎺ', '🅨',
+    'ᝫ', 'ꫴ', '爛', '𝄣', '˞', '★', '𐒲', '𐰉', '𖡝', '⫅', '𐾽', '𛆕',
+    '𝂙', '𢌱', '𓻒', '🌻', '\u{1b34}', '𖨑', '𒊽', '🚴', '꧋', '\u{11cab}',
+    '𝖾', '\u{e01ba}', 'ස', '☨', 'ꚓ', 'Ḗ', '𛂯', '𘱝', '⺒', '⍺', '🠨',
+    '𖭮', '𝜀', 'ꫦ', 'ẗ', '𑥑', '𓖚', 'ሷ', 'ত', '𔘸', '𑅇', '𓣤',
+    '𑯀', 'ᇰ', 'ﾀ', 'ﾃ', '𔏤', '𐿈', '𝀃', '𒋚', '\u{1da21}', '䂖', '𐲮',
+    'ᩡ', 'ᩮ', '◍', '𔉾', '𐢯', '𓠐', '𑥔', '𓫎', '嵃', '𓈒', 'ⶬ', '⛷',
+    '◵', '𝧝', '𝔫', '𒊢', '𑿏', '⋓', '𝈁', '𝗾', '𔊄', '𐦣', 'ꜩ',
+    'ᇑ', '꜕', '𐬵', '𘥑', '੶', 'w', '𐪟', '↣', '\u{115b5}', '\u{11a54}', 'ሿ',
+    'ࡔ', '諾', '𓆺', '𔌨', 'ዢ', '𐍚', '⯴', '㏭', 'ⳤ', '᧑', '𝛷', '𑊘',
+    '﹝', '\u{312}', 'ᅖ', '了', '𖼙', '𛰽', '視', '␈', '𖫤', '𘮵', '𐚺',
+    '𞓡', '𝦞', '𓒞', 'ꨴ', '𒉣', 'ႌ', '𔈕', 'ꍑ', '🉁', '𞊦', '𑼪',
+    '𐼉', '𑤵', '𐐕', 'Ƀ', '𜳣', '㋯', '𖢱', '𘨊', '᱆', '🏄', '🬟',
+    '𐇣', '´', 'ක', '𖼍', '🍔', '𒃏', '𘥒', '→', '𐚧', 'ᑌ', '⒉', '𓫟',
+    '𝤂', '🛨', '≍', '𔅇', '𒍬', '⹁', '㌂', '𘮖', '𑖋', '𛃏', '𛉸',
+    '𒇄', '𐙾', 'ꂳ', '𑒝', '⣔', '𞁢', '🮑', '𝒩', 'ን', '𔍩', 'ǉ', 'ﴝ',
+    'ࣅ', '𒌗', '𐾰', 'ꏘ', '𒄛', 'ሜ', 'ឪ', 'む', '⣹', '𑖖', '𝄮', '🯎',
+    '𓽻', '𓀯', 'ዖ', 'ஃ', '𛱺', '𐦲', '𛈹', '𓁷', '𛈱', '𐔂', '⢳',
+    '➧', 'Ṧ', '⎷', '𐣽', 'ጯ', 'А', '≅', '敏', '🩴', 'ᓓ', '𐔈', '\u{aa2d}',
+    '𤲒', 'ᑗ', 'ﺒ', '𓇳', '𐂡', '║', '𓈈', '≢', 'ኒ', '𓲡', 'भ', 'Ө',
+    '🛐', 'ዾ', 'ᰈ', '㈭', '𓩆', '𓭡', '𐤔', '🛂', '裗', 'ᄤ', '𓮜',
+    '𒍻', '᭢', '𔓔', 'ﰲ', '蓮', '𔎰', '⢈', '𐀒', '𝋨', 'ꭏ', '數',
+    '\u{1dfa}', '𔔴', '𑦭', 'ڜ', '𑣖', '𓿘', 'ﳡ', '႔', '𛆊', '⛄', '🀣',
+    '𖪾', 'ᔖ', 'ص', 'ᰒ', '\u{111b6}', '𐆎', '𓖊', '𓴒', '𝕀', '؎', '𒋹',
+    '𑈀', '𓜒', '𖦟', 'Ȋ', '﹆', 'ꈇ', '🌶', '𔏶', '\u{e0145}', 'ᘙ', 'ц',
+    '𒉒', 'ᘗ', '𔀎', '𘠉', '𐀼', '𓪱', 'ἑ', '𐛵', '𓤠', '𓤤', 'ଞ',
+    'ⓢ', '🄉', 'ᾯ', '𝇅', '𝁸', '\u{6df}', '⛼', '𝁶', '𖠡', '𓋁', 'ꔮ',
+    '𓾁', '狼', '勞', 'ෆ', '𐨙', '𐧯', 'ள', '𜲇', '𔎴', '𝠡', '𒂋',
+    '𜲝', '𑘇', '\u{1143e}', 'シ', 'ᔈ', '𐅽', '𝌌', '𝣇', 'ꥼ', 'ᛓ',
+    '\u{ac4}', '𓞨', 'ꆽ', 'ꉶ', '🪝', 'ꉸ', '𞺳', 'Қ', '⡩', '📖', '𓖥',
+    '⧂', '𓶊', '🨤', '𐤏', '𞣃', '\u{1e01f}', '🆄', 'C', '🐗', 'ၛ', '🅴',
+    'S', '𘮡', '𘮥', '⡏', '𐞸', '𝦯', '𑀪', '⦯', '𒉷', '𝠑', '𝦺', '🥐',
+    'ꡲ', '⸌', '𘮮', '𑢣', 'ࡃ', 'ᘿ', 'ｎ', '𑖎', '𘪴', 'ⱇ', '𐒩',
+    '\u{948}', '𝂿', '🜻', '𖄖', '⟉', '𝝌', '𒇼', 'ϻ', 'ꋉ', 'ꄾ', '𓗾',
+    '\u{11d90}', '𛲖', '\u{8d5}', 'ᕅ', '\u{e010f}', '𞟹', '🔑', 'ᆡ', 'Ƽ', '𑅪',
+    '𖪽', 'ﶬ', 'ꥯ', '𑧇', '\u{1e021}', 'ꗞ', 'ｐ', '𐧿', '𔍵', '𜺰', '⍝',
+    '●', '𛃁', '𖥰', '𓻃', 'ቋ', '𓗱', '嬨', '♈', '𐌾', '𛊩', 'Ꮺ', '𞗺',
+    '\u{116b6}', '᱑', '𓯅', '𞤷', '𓏊', 'ሏ', '𓧺', '諾', 'ᘁ', '𑘈', '᭼',
+    '蘆', 'ꖃ', '𓬊', '𝡺', '⮓', '⌡', '𒇽', '𑱛', '🗣', '𐼕', 'ⰲ', '𑠁',
+    '㏸', '⺣', '𞅎', 'ⱍ', '🀝', '🧇', '\u{e004b}', '\u{e0047}', '𢌱', '𔄢',
+    '\u{10f50}', '𖽒', 'Ⱛ', '𓨨', 'ᜀ', '⼬', '💪', '⸎', '㉗', '𓀛', '₯',
+    '𞅉', '㈱', 'ᭉ', '𘥤', '🦄', '𔌦', '𝌏', 'ÿ', 'ᐴ', '𐎃', '𐩯', '𓿫',
+    '𓿯', 'Ꟙ', '𓭟', '𞱳', '𓣈', 'Ꚕ', '🞟', '𓙉', '𓪯', 'ꎼ', 'ᚹ',
+    '🦨', '𓿦', '🥵', '⇜', 'ᤏ', '𓲭', '⼑', '𓉤', 'ㄦ', '𔕣', 'ꅹ',
+    '\u{11d3a}', '𓊶', '﹇', '𒓚', 'ꌤ', 'ﶡ', '𓚰', 'ꁻ', 'ꆔ', '𓺲', '⬛',
+    'リ', '𓨥', 'ラ', '𔏆', '\u{16b35}', '𓤏', '𝟳', '𝟛', '𛄕', '𑇂', '𝝩',
+    '𑼓', 'ö', '𞴉', 'ப', '𖼏', '𑿗', '𒂹', 'ᔓ', '𐲊', '\u{ecb}', 'ᛁ',
+    '𞄫', '𓐛', '⊉', '𐎇', '𐠯', '𓇂', '𓎧', '\u{2009}', '𘩥', 'Ⱥ', '🎇',
+    '⭡', '𑩸', '𑘪', '㴳', 'ᄭ', '㇛', '꣕', 'ዣ', '§', '𑆳', 'Ⅾ', '𞡤',
+    '꥟', '𔒍', '𔄝', '𞓞', 'Ꙝ', '⢌', '𞓝', '𐊘', 'ഘ', '𖪨', 'ᙁ', '꒕',
+    '\u{e0110}', 'ｊ', 'ჼ', 'ᑲ', '𛆲', '≋', 'ᦀ', '𔑊', '⩸', '🡨', '𜶟',
+    'Ǚ', 'ꌯ', 'ᶰ', 'ᶃ', '𓶕', '\u{e01ab}', '𑧜', '⨚', '\u{6dd}', 'ꗏ', '𓱓',
+    '𒇔', '⺀', '꒗', '𖣇', 'Ặ', '𝖟', '⑶', 'ጷ', 'ƭ', '│', 'ળ', '𝖇',
+    'ၓ', 'Ჹ', '𓖮', 'ﱸ', '𐕙', '𑌨', 'ᩔ', '\u{11ca3}', '𝩮', '㋘', '𝧐',
+    'ꁧ', '𔎊', '٨', '𖠙', '𘦹', '⌒', '𑀡', 'ꪙ', '𓆸', '𘳂', '𝔹', '𝔽',
+    '🎤', 'ᔎ', '𓂌', '𐀃', '𓊦', 'ꢧ', '𓋛', '𔎉', '𝗖', '𘢎', '𔎅',
+    '𝕗', '✤', '\u{1da04}', '₺', 'х', 'ᾥ', '𔊥', '𓃸', '𐄀', 'ࢂ', '⑴',
+    '᧣', '🎂', '𐌋', '𓷐', '𖪏', '𓷘', '𐒚', '𒐗', '⚆', 'בּ', '𝛝',
+    '𔇋', '🈗', '⢐', 'ዮ', '爵', '𔇺', '𞠋', 'ௌ', 'ࢀ', '𓍳', '𓯞', '🖙',
+    '𓆑', '🞠', '𓠋', '㋡', 'ꉌ', '𐅟', 'ᓬ', '𖄍', '嵼', '₫', '㡢', 'ﭰ',
+    '𐊻', '🧋', '𝔏', '\u{1a74}', '𑼧', '🚙', 'ẟ', 'य़', '𝌁', '\u{8fb}',
+    '𓮅', '❦', '൬', 'ꀶ', '►', '\u{603}', '𔗌', 'ࡢ', '𒉆', '𞴃', '၎', 'Џ',
+    '⠔', '䧦', '🯑', '𖠺', '⋫', '꯶', '𓨱', '𓹕', 'ጲ', '𓽥', '𓫑',
+    '𓿰', '𓈡', '𖤺', '\u{1ab0}', '\u{1cf19}', 'Ń', '𔕠', 'ꕭ', '🝖', '𑽄',
+    'ꀉ', '\u{c48}', '𔋳', '𓳔', '㍗', '⇏', 'ὐ', '𖡧', '២', '𓫚', '𜹾',
+    '𓎴', '𓎯', '𖭲', '🮱', '𞤬', '⬋', '𘠥', '𑈤', '𘠝', '⩟', 'ᠯ',
+    'ꂿ', 'ꝡ', '⫱', '𝤴', '𐜅', '⪸', '𑫈', '𓅥', '𐡌', '𘬩', '𔁒',
+    '𐄯', 'ᦔ', '𖬹', '𓎨', '𓻵', '𝚌', '⒕', '𓩪', '益', '𛇤', '𛇠',
+    '𓃷', '𐫋', '𖠬', '𑖌', '𑙬', '𝗯', '𔋇', '⨑', 'ଯ', '𝣘', '𐌌',
+    '⤟', '\u{730}', '𑴞', '𐆐', 'ꓫ', '🜟', '𑬁', 'Υ', 'ﺎ', 'Å', '𛇢', 'Ꮇ',
+    'ꅱ', '𑻪', '𘨴', '𝘃', '₸', 'ᨚ', '౯', 'ň', 'ਏ', 'ݴ', '𜸎', '𐀕',
+    '᠁', '₢', '𖽰', '𔋩', '\u{e019b}', '𓋂', '⑮', '⇞', '𓊁', 'Ẳ', '𐶏',
+    'ቩ', '𞲋', 'ᢣ', '🍞', 'ᶚ', '𝑫', '𞋶', '྅', '៸', 'ꕠ', '⺯',
+    '\u{5c5}', 'ꗍ', '𓇖', '🄄', '𖡍', '𜵿', '𐼎', 'ꄟ', '𓇣', '\u{8fe}', '㋸',
+    'መ', 'ヾ', '⦌', 'ᒻ', '𘧅', '⤝', '𓎵', '𒄟', 'ჸ', '䷹', 'ꕀ', 'ꄡ',
+    '𓝬', '\u{a02}', '𓖐', 'ፈ', '⦪', '𑈸', '⫢', '𓀳', '𐊧', '𞲧', '𐇪',
+    'ឌ', '｝', '⯘', '𔎚', '𝢻', '𑇜', 'ᴉ', '𓐔', '⋭', '𓿇', '𚿵', 'ᕼ',
+    'ฐ', 'ꐝ', '𓯡', '𖨰', '𑤘', '𒃰', '\u{b3f}', '𝔗', 'ㄓ', '𝝈', '𑣟',
+    'ᄎ', '𓲁', 'ꊦ', '🆋', 'ǘ', 'ꔕ', '𓗁', 'ꑓ', '𓊫', 'ⴶ', 'ꛫ', '婦',
+    '\u{10f83}', '🥚', '𓿳', '𐫇', 'Ⴞ', '\u{11368}', 'ɗ', 'ﳃ', '𘤢', '𑒹',
+    '𜽗', '𐿬', 'Ḉ', '𞋁', 'ᛨ', '𓢭', 'ኹ', '𑊲', 'ꐇ', '🕿', '𞹮', 'ﾖ',
+    'ᦗ', '\u{111b9}', '𜰳', 'Ⓨ', '𑨨', '𖩀', '𓨪', 'ⵔ', 'Ⓜ', 'ꄃ', 'Ʇ',
+    '𞢗', '②', '𓢤', '⡐', '\u{1344a}', '㏳', '\u{11340}', '\u{1e00d}', 'ℎ', '𝡛',
+    'Ӡ', '𓛭', '\u{11728}', '𐂅', '\u{e01d4}', '𐫶', '𓖌', '𞴐', 'ﱇ', '⣺',
+    '𑦢', '𐙲', '𝙔', '𓴷', '\u{f19}', 'ፗ', 'ⲇ', '𝃡', '🞼', 'ᧆ', 'ㄴ',
+    'ᄕ', '𐒈', '𞓟', '掃', 'ꌢ', '⟄', '𞹑', '𝟗', 'ᒪ', '🠃', '☻', '▞',
+    '𑥁', '𘠸', 'ထ', '𖡈', '𓄤', '🝱', '𖬑', '䷭', '𖨘', '𐅑', '𝂪',
+    'ᒟ', '𐂤', '𝁨', '⮉', '𐹻', '📬', '𝢨', '𔑘', '𘥧', '੫', '\u{d43}',
+    '𖦚', 'ꊖ', '𐋱', '↊', 'ぞ', '\u{114bd}', '𖠯', '🢓', '≑', '𑗋', 'ꥢ',
+    'ᱲ', '𑐀', 'ꭩ', 'ፋ', '𝥿', '𓠌', '𖨅', 'ᮍ', '\u{10a03}', 'ﱫ', '🌼',
+    '🮙', 'ᜈ', '𜳄', '𝋊', 'ꜫ', '𔅓', '𐗇', '𜵨', 'Ⱝ', '⥱', '𓎑',
+    '𑐄', '𐙙', '𐎧', '㆛', '🄂', 'Ც', '𐀜', '𞠯', '𔉼', 'ੴ', '⟴',
+    '𘭛', 'N', 'ወ', '𘭖', '𐔌', '𖹗', '❍', '𞊖', '𑊜', '𒒽', '\u{f8e}',
+    '㌧', '꘢', '𝍃', '𛉾', '𑁚', '👋', 'E', 'ჲ', '𒓘', '𑛚', '↕', '㌤',
+    '⏭', '♵', '⣥', '𖡑', '⇻', '\u{2de4}', 'Վ', '\u{f39}', '𛉹', '𓥟', '謁',
+    '𜱪', '𒑃', 'ධ', 'ᱯ', '𓶚', '᥆', 'ꊍ', 'ﻂ', '🄎', 'ﴲ', '洖', '𒊻',
+    '𐒒', '⨧', '▱', '⅐', '𞋤', 'ǰ', '𛂅', '🏽', '▌', '𓾳', '𜺖', '🈲',
+    '𓳥', '🤝', '\u{1cf3d}', '𞀱', '𐬘', '🌴', '𔉉', '𒾵', '𜷁', 'ߧ', ':',
+    '𓩈', '𖼲', '𒇊', '𑯠', '⮩', '🠳', '𝜖', 'ন', '\u{e01e2}', '𝒦', '𓎘',
+    '𔑮', '𘧴', 'ऒ', '𘬌', 'ះ', '𓛿', '𐔢', '⋂', '𝐇', 'Ǻ', '\u{e01bd}',
+    '𓆊', '𞴳', '𝠞', '\u{e0144}', '𔃌', '𓩶', '㌀', '𑫇', '᚜', '𐝓', '𝄆',
+    '𔐬', '🛑', '㉔', '𛉂', 'ሢ', '𝍷', 'ሠ', '🎪', '𔋕', 'ꚁ', '匿', '𓛶',
+    '𓛲', '𓂝', '⣆', '𔅎', '𓐣', '𛂋', '\u{11943}', 'ᩌ', 'ጏ', 'ﺍ', 'ࢼ',
+    '𓥨', '𒾛', '𓤗', '𐼡', '🅼', '⢲', '𓫾', 'ほ', '甾', '𐌀', '🕅',
+    'ꮬ', '𘨂', 'ꮇ', '𐜣', 'ᤸ', '𐋬', '𛈄', '𒆫', 'ᔄ', 'ᥞ', '𓡻', 'ꓥ',
+    'ﲻ', 'ꧨ', 'ᑏ', '𛀽', '𒓋', 'ꏲ', '𛅸', '¢', '𐓻', '𝇙', 'Ҟ', '⢟',
+    '\u{f90}', '𞴰', '𖠰', '🛊', '𑢻', 'ਗ', 'ଉ', '𑃗', '𝝷', '🫳', '𓧳',
+    'ଅ', '⽐', '𓂚', '𝌮', '࠸', '𝀂', '🠴', 'ㆎ', '\u{114b6}', '𔐀', '𘯒',
+    '𐐡', '𓄩', '🨒', 'ዓ', '𝍅', 'ꮻ', 'ꭓ', 'ᰅ', '𓤜', '𔓛', 'ম', '၆',
+    '𝕖', 'Ỉ', 'コ', 'ꌺ', '⢼', '𓟁', 'Ꭼ', '𑅬', '𘪫', '𔁊', 'ே', '𑐘',
+    '𐌉', '𑃡', '㍹', '𖠥', '\u{11a35}', '𘰒', '𘢵', '𘪚', 'ኟ', 'ࡇ', '𘧕',
+    '𝩯', 'ደ', '𞋵', '𖡁', '𑃝', '\u{1932}', 'ꚹ', '𘭈', 'ᐔ', '୯', '𓌓',
+    'ᆇ', '⛕', 'ꁨ', '𒀍', 'ꌹ', '᪨', '𑿕', '𝌑', '⠎', '𝇐', '𑵳', '𒀙',
+    '⢓', 'ꄣ', 'ᣖ', '▯', '𒈨', '–', '\u{81e}', 'ᚉ', '𔅘', '𑖢', 'М', '𞟩',
+    'ਉ', '駂', '㍲', '🐎', 'Ⱊ', 'ᲇ', 'ᔉ', '𝣴', '𐐿', 'ᆿ', 'ᠸ', '𐇓',
+    '👀', 'ဏ', '𝡕', 'ẃ', '𜶿', '𐬕', '𐿡', '𐋺', '㌊', '𔅀', '𔃒',
+    '𖦸', 'ᠰ', '𑠢', 'Ừ', '\u{1de4}', '𘭪', '𒃆', '𘦻', '⻈', '𓳙', '𘮽',
+    'ᬍ', '𘥖', 'ᥴ', 'Ꙋ', '𓨜', '⋄', '𖤧', '𒾻', 'ᤖ', '𞗗', '◩', 'ʵ',
+    '𜹠', '⊢', '⅍', 'ꦭ', '𑵼', 'द', '𓑣', 'Ḓ', '𒑰', '\u{353}', '𑖀',
+    '𑯎', '𐡫', 'ꋁ', 'Ѵ', 'Ḧ', '𓾢', '🨋', '𝟶', '𘨉', '𝐍', '悔',
+    '\u{e014a}', '𒐽', '⚵', '㏶', 'Й', '𑑠', 'ᕛ', '𜸦', 'ᆪ', 'ꂚ', '𑣣',
+    '㋤', '\u{1e4ed}', '𐤇', 'ꇲ', 'ศ', '🠙', '⡥', '༣', '𐩠', '𘨰', '𓾯',
+    '𘰴', '൭', '\u{1e131}', '٦', '𖺒', '𓩊', 'ꊅ', '𖵲', 'Ṓ', 'ڄ', '𝓩',
+    '𔔠', '𓄾', '𝢎', '‵', '🪟', '⾐', 'ɷ', '𒁰', '𝢓', 'ﾙ', '𝀎', '�',
+    '🬃', 'ᆌ', '𐓙', '𑤯', '㈇', 'ꖵ', '𞣋', '𒊈', '𝍎', 'ꚥ', '𓗍',
+    '𖩱', '𑈖', '𝛻', '⾭', 'ⶅ', '𐖸', '𓤁', '\u{1cf37}', 'ﰐ', '🕫', '𛰺',
+    'ﾻ', 'ᕌ', '\u{10a06}', '𑗘', 'ᶦ', '𘱵', '𑇭', 'ﺪ', '𓦤', '𜴝', '␍',
+    '⯚', '𑅚', 'ᔮ', 'ո', '𓠅', '\u{cc0}', '🬡', '𐎚', 'ﱖ', '𔅹', 'φ', 'ꥩ',
+    '‐', '﵆', 'ﵧ', '𝀞', 'ਫ਼', '𝘱', '𐍉', '𒿩', '𒿥', '🐂', '𓏭', 'ೲ',
+    '𝃔', '𝜻', '🀙', '⢣', '𘪷', '𐘊', 'Ӝ', '\u{341}', '𐳔', '\u{5ae}', '𓽱',
+    '𓭣', '𒀫', 'ꗠ', '🖋', '𑊣', 'གྷ', 'ហ', 'ﻝ', 'ᴙ', '𞓪', '𒆳',
+    '\u{320}', 'ᐍ', '𒆲', '𘬶', 'ఙ', '㍸', 'ᚗ', '𓾧', '=', '𓱰', '≒', '𑦸',
+    '𜴳', '𐺌', '𐗨', '𓌸', '𑄝', '𝗣', '⽗', '𓉑', 'ꓜ', 'ﲢ', '𐬇',
+    '𛁦', '𛁢', '\u{e017a}', 'ࡄ', '⽕', 'ἐ', '𐄟', 'ꕂ', '𔄦', '⸶', '𔖢',
+    '𐢀', '😲', '｟', '🇪', '𓣂', '𝒗', '\u{1df3}', '𓉺', '𑵑', 'ᵐ', 'ཎ',
+    'ᵉ', 'ⴷ', '𔔡', '𝒃', 'ꅙ', 'ᒲ', '𓓯', '𝚭', '\u{200a}', '\u{1ce7}',
+    '𞥔', '⛝', '𓌜', '👼', '𑌶', '𓞡', '🭵', '\u{85b}', '𓠪', '𔘥', 'ঃ',
+    '𓗠', '𓗤', 'ힲ', '𝃑', '𒾶', '⇦', 'ᰚ', '”', '𒒑', '㎺', '𐀛', '㍐',
+    '\u{e003c}', '零', 'ᆯ', '𔗭', 'ᛈ', 'ί', 'ථ', '\u{1da34}', '𞸔', '𓈇',
+    'ꏯ', 'ꔑ', '𑁪', '𓪊', '⾖', '𖄹', 'ꦍ', '𓘙', 'ꋦ', '𤎫', '⾻', '🎙',
+    '🔲', '𐰸', '𒔥', '𓲔', '𓘞', '𓧰', 'ꢶ', '𘪯', '𝣶', '𞡫', '🈦',
+    '⩊', '𓭰', '𓁦', '\u{8fc}', '𑣱', 'ꭺ', '㉀', '𝞄', 'ⴑ', '▩', '𑼂',
+    'ᶤ', '𐼥', '𥛅', 'ꭎ', '𖤨', '㌩', 'ᒾ', 'ᘽ', '𐧪', '\u{e01e5}', '🮨',
+    'ᦢ', 'ꆠ', '⋹', '𒓢', '⏩', 'Ꜵ', '𓡟', '𖽥', 'ㆄ', '🕆', 'ꤐ', '𓏛',
+    'הּ', '𘢫', '𘢯', '𞤯', '\u{1a7c}', '㱎', '𜵷', '𘯧', '𘯣', 'ꂦ', 'ꂣ',
+    'ਆ', '𞠔', '𝝿', '\u{aab4}', '𝐰', '𒉫', '⢫', 'ᥳ', '𝐠', '𐾼', '𝐨',
+    '㉘', '\u{310}', '脾', 'ⲡ', 'Ꞩ', '𓥐', '𓷼', 'ၸ', 'ꉭ', '᧦', 'ཡ',
+    '🐱', '▃', 'ᓰ', '𝦁', '𐁍', 'ퟒ', '𓾿', '\u{103e}', '𖹾', 'ឈ', 'ಟ',
+    '𑜲', 'ṳ', '🍭', '𑅕', 'ﶻ', 'Ͽ', '𞺀', '🤸', '⌜', '𓕒', '𑇧', '𔆤',
+    'Ⲵ', '🝈', '🂊', 'ﺱ', '𓍆', 'Ⳍ', '𓕣', '𓍂', '𛇷', '⦥', '𓙂', '⊽',
+    '\u{70f}', 'Ꮏ', '𓼻', '\u{1b00}', '𖡣', '🅺', '\u{944}', '🧮', 'ﻀ', '﯂',
+    '🛧', 'ꌸ', '\u{16b34}', '𒒊', '𓾈', '𑂠', '𐧢', 'ゅ', '𘬨', 'ᘧ', '꓀',
+    'Ⴝ', '𜱔', '\u{ec9}', '𓏨', '𐡷', '𝃚', '\u{faa}', '\u{1a73}', 'ᗑ', '𒿤',
+    '\u{1da52}', 'ӈ', '𞁁', '𐓨', '🍠', '🈰', '\u{1da9d}', '𔖂', 'ƅ', 'ᘣ',
+    '𒑓', '𓽂', '𖹰', '𐦃', '𓦗', '𐘻', 'ᰠ', 'ꊠ', '𒈘', '𐑢', '𝢳',
+    '𔈐', '∯', '∰', '𝐯', '𔔰', '嵐', '𑆣', '\u{e0071}', '🂺', '𜲺', '𝡭',
+    'ꄤ', '\u{e0065}', 'Ў', '𛃥', 'ъ', 'ೱ', '🆟', '𔗁', '𓸕', '𓔰', '識',
+    'ﭱ', '𒌟', 'ᔑ', 'ᚋ', '䷺', 'ꕼ', '𝄑', '𓚭', '𓃥', 'Ƿ', '👑', '𐶎',
+    'ꋰ', 'ꋭ', '留', '﹌', '𘭊', 'ᴣ', '𐙦', '𓚤', '𓚠', '𘬇', '𓋔', '𐿯',
+    '𜲫', '𝩽', 'ᢎ', 'భ', '𑀚', 'ꂗ', '𖤽', '𓽗', '𓘡', '𓘥', '𔓩',
+    '૮', '𓱑', '⊫', '𞄑', '𘧹', 'Ⱟ', '𐃭', '𞤓', '𝖽', '糨', '䈧',
+    '\u{1baa}', '❺', '〵', '𖤳', '𐪉', '℞', '𐃍', '𓂲', '𔋄', 'ᣍ', '𒊟',
+    'ⰴ', '𓠨', '𒐢', '𜰕', '𖣏', '𝗓', '𞹨', '\u{956}', 'ը', '𠕋', 'ਊ',
+    'ᖬ', '𒉸', '🠲', 'ꠀ', '𐹯', '⯻', '𞸭', '𓣐', '𜰊', '𞄍', '𓋘',
+    '𘰑', '⣈', 'ໜ', '𔊁', '⼪', '𘤿', 'ӌ', '\u{1773}', '𑂿', '\u{301}', '𞲘',
+    'ᬑ', '𐬴', '潮', '𐨤', '𓡀', '𑦼', 'ά', 'Ẩ', '𔀣', 'ᚣ', '\u{2001}',
+    '💃', 'ꖈ', '🕓', '𓡰', '\u{eba}', '𒉝', '⡛', '\u{1dfe}', '╮', '\u{a69f}',
+    '𘢷', '㊷', 'ᬬ', '𐞑', '𐼁', 'ⶀ', '瑱', '𜸟', '𐘥', '𝡉', '𓰿',
+    '𓙝', '𑚨', '𘧒', '\u{2df5}', '⫡', '𐑒', '𒆊', 'ힼ', 'ᖏ', '𓋆', '𝛕',
+    '𖡾', '𓽛', 'ꈪ', 'ﮩ', '𛁆', '𜹩', '\u{e0046}', 'ೃ', 'ᐙ', '⭇', '𝖮',
+    '𓍏', '𓍊', 'ţ', 'Ƚ', 'ध', '⿿', '𘬭', '㍇', '𘢒', '\u{1cf30}', '仌',
+    '类', 'ڻ', '𝑟', '𓽝', '𝑧', '𝞫', '𓍯', '𖹯', '🛌', '𓻆', '𒂡', 'ǳ',
+    '𖵣', '𝐹', 'ࡁ', '𛂟', 'ࡆ', 'Ჩ', '⍹', '𝍶', '𓙆', '◎', 'ᤓ', '∧',
+    '𑰭', '𑗅', '\u{112e5}', '⊶', 'Ӧ', '塀', '⹔', '𐙍', '糖', '⏊', '𒃺',
+    'ⱃ', '♚', '𓝡', '゠', '𑛑', '⚬', '🙃', '𞋩', 'ḟ', '𘨷', '𝧀', '⪓',
+    '𞗒', '𐼦', '🗴', '𞡞', '\u{10f82}', '𓰡', 'ᥑ', '𝕢', 'ď', 'Ｈ', '⑰',
+    '𔘃', '𐍳', 'ែ', '𞡣', '𜵹', '𓝼', '🦧', '𓂞', '〲', '🍹', '⩮',
+    '𖵢', '\u{31f}', '𓙣', '↓', 'ꋒ', '\u{a947}', '😭', 'ݥ', '🭰', '𜸳', '祿',
+    '𐢞', '𓹞', '𛀣', '𖥚', '𑢩', '𓯋', '𓜌', '𐚚', '\u{e01d7}', 'ࡵ',
+    '𛆈', '𖨚', '𛆀', '\u{65f}', 'ᰩ', '𐢅', '𓙍', '៘', '㋕', 'ⲽ', '𛊷',
+    '𓽣', 'ｈ', '𓱖', '𑴔', '𑜀', '𐘣', '𓄹', '\u{fbb}', 'ړ', '᭚',
+    '\u{e0114}', 'ル', '𖭝', '𖩵', '𓱟', '𝧦', '⺔', '𐋄', '𜰀', '𝜤', '𓽬',
+    '💒', '𔊲', '䷱', 'ꭽ', 'ឃ', 'ล', '𓴐', '𓴔', '𔉣', '𔏑', '𠘺',
+    '𔎔', '𝈸', '𓻝', '\u{737}', '𑅄', '𓊻', '𐃐', '␧', '𖤞', '𓦙', '𞋱',
+    'Ꚓ', 'ꆬ', '𓷗', '𑨋', '𒉱', '𘦺', '𖩝', '𑃦', '𐞵', '᧷', '𐙆',
+    '𝢅', 'ꭔ', 'ˠ', '⭙', '𖄑', '𜳆', '១', '⹑', '𐃀', '𞺏', 'ྋ', '𐃁',
+    '⡰', '𑛡', '₋', 'ꅛ', '𒆵', '𓁔', '𖽗', '\u{11a38}', '𑴮', '𝑂', '𐓄',
+    '𒒧', 'ڧ', '𜲆', '𔃜', '⟋', '𑿣', 'ၷ', '𓞖', '𖢁', '利', '㋙', '𛂧',
+    'ː', 'ڍ', '𐭞', 'ߟ', '𝍋', '𖧂', '𓁛', '🠬', '𝆧', '𒐐', '🮌', '𑖝',
+    '㎄', 'ﵓ', '𖩇', '൝', '𖬫', 'ꋲ', 'ǁ', '᪬', '𘳎', 'ẁ', '𛋹', 'ᴸ',
+    '𒆙', '𝡟', 'ô', '𐞣', '⏧', '\u{11070}', 'ଣ', '𐛝', '靖', '➑', '🄼',
+    '𝛙', '𐫌', 'ӫ', '𓵁', 'પ', 'ᕐ', 'ﶪ', '𝟪', '𓧃', '🚶', '𘧨', 'ꔽ',
+    '𛆞', '👬', 'Κ', '𒆤', 'ꛡ', '𛋺', '⦐', '♽', 'þ', 'ᾊ', 'ᶨ', '⣭',
+    '𓪠', '﨓', 'ꈑ', '⯟', '𖹩', '𞸶', '𘱀', '⩐', '𖪤', 'ꥷ', '𘫳', '𘫯',
+    '𘮇', 'ﭣ', '𘫦', '𑁖', '𔑅', '\u{e0149}', 'ࠁ', '🜫', '꘦', '⎠', '𝍈',
+    '𓮨', '𝞁', 'ຄ', '︸', 'ᅺ', '𒂧', '𛉻', '𐃤', '𒅀', '♢', '陸', '𔘓',
+    '\u{1a78}', 'ꏹ', '⅒', '\u{102f}', '⑅', '𞴵', '𜰺', '𖥕', '𝟭', 'ꚫ',
+    '𒃈', '𜰹', '𜾞', '🌖', '㎳', '𘦒', '𓬄', '𑶓', '🩳', '\u{859}', '⡵',
+    '㍞', 'ש', '𓽼', '𖾔', '𓪬', '𐠵', '𑣗', '𝐣', '𘡋', '𓊀', '𓗋',
+    '\u{17b9}', '𒅙', '𝐽', 'ⵉ', '↨', '𓰯', '𓼆', '🍼', '\u{348}', '𓸷',
+    '\u{e019c}', '🕔', '𐩸', '𑒑', '⌨', '𓊷', '𓊰', 'Ⰷ', '𐐾', '𖽯', '🧂',
+    'ퟆ', 'ᢱ', 'ȇ', 'ጆ', '▅', 'ё', '🨉', '𖭦', '𐋊', '𒄾', 'ﾲ', '🥶',
+    '𘩢', '🪿', '\u{1d170}', 'ꔴ', '𐰩', '𖼓', '𓬚', '🮺', '𔗞', '⇒', 'ᦊ',
+    '𓦇', '𖵯', 'ꓴ', '\u{e01d2}', 'ꗢ', '𜹇', '⮝', '𒒃', 'ࢇ', 'ᱞ', '𝝆',
+    '𔒿', '㏻', '𓏞', 'ꗌ', '𓂄', 'ﰯ', '𓧤', '𐧂', '𐂏', '𘱫', '歲',
+    '𐭠', 'ꄔ', 'ȝ', 'Ὤ', '㌖', '𒃳', '𓁈', '🁔', '𞴟', 'ʹ', '⡲', '🛈',
+    'Ი', 'ᇡ', '𞤏', '𒾗', '⚶', 'ᖓ', 'ᓴ', 'ற', '🁣', 'ᛸ', '論', '𑆪',
+    '🎷', '𓸾', '𓀩', '𝀍', '𝝃', 'ﭐ', '⌫', '𜰯', '\u{11231}', '㍱', '𑈬',
+    'Ϸ', '𔃿', '𐦂', '⊴', '𔕼', '〝', '㎰', 'ࠑ', '𖪣', '𞸏', 'ಘ', '𐚇',
+    '𓱎', '𝐆', 'ᤉ', 'ᢂ', 'ᕀ', '㍳', '꒷', '𑲆', '𜰟', '⫴', '𝋣', '𔑖',
+    '𖫔', '𐳓', '𐳍', '𨗒', 'ⴄ', '𓊱', 'ꀗ', '𐳤', '𓥘', '𜸵', '𓽸',
+    '𘫧', '🐫', '\u{7ef}', '𘫫', 'Ȏ', '🎦', '𑓘', '𒂚', '𓱉', '꜡', '𘧪',
+    '𝒪', 'ʌ', '🏭', 'ﲳ', 'ﮔ', '⋌', '🦿', '𐗧', 'ꛒ', '𖡰', 'ڞ', 'Ꭵ',
+    '𓁿', '\u{aa32}', '𝃉', '𐂠', '𐌹', '𖣶', '𘲝', '\u{593}', 'שׁ', '㋿',
+    '𝥵', '\u{2de8}', 'ᅄ', '𘭳', 'Ң', 'ꈏ', '𐋶', '𐐷', 'ﮃ', '𓱣', 'ᅷ',
+    'ꏏ', '𓟠', '𓙪', '🀠', '꧔', '𘱘', 'ڽ', '𖺚', 'ꍭ', 'ঽ', '𐊐', '𔕶',
+    '𐧸', '𐵜', '𘨙', 'Ĳ', '\u{11372}', '𔔯', 'კ', 'ᄪ', '𓗐', '⊞', '𖮄',
+    '𐗣', 'Ⓝ', '㊌', '𓌉', '𝼀', '💛', 'ẑ', '🗍', '𝈬', '℡', '\u{a01}',
+    '𝂀', '┳', 'ٺ', 'ꅩ', '𓛪', 'ȡ', '𐲲', '𖩷', '𒇾', '𝍍', '𐄿', '𐁒',
+    '𒑨', 'ꆎ', '𓼇', '\u{11a93}', '༾', 'ꁚ', 'ꉧ', '𝓰', 'ᎏ', '𔐘',
+    '\u{e0171}', '🗸', '\u{e0179}', '竮', '𖽆', 'ꉘ', '𑴋', '🦂', 'ᘈ', '𑫵',
+    'ᐃ', 'ꓹ', '𓝻', '𜺓', '🮛', 'ᓨ', '𒀰', '𒑲', '𖠅', '𒎀', '𒅩',
+    'ꖊ', '🨽', 'ᴴ', '𒌔', '\u{1da41}', '𝈠', '㋹', '⺝', 'ᨠ', '𝙕', 'ᗀ',
+    '⋈', '⯹', '✷', 'ꐉ', 'ኮ', '𔀆', '㏌', '𐖏', '𑦰', '𐊦', '⑵', '𜹚',
+    '𝪉', 'ﵛ', '˫', '𔁌', '𑰆', '⣚', '⤍', '𓧒', '🯄', 'ꊥ', '𓯓', '𝣌',
+    '𛃪', '𛁌', '桒', '𑨯', 'ﳖ', '𓄁', 'θ', '🅿', 'ﬔ', '𔓍', '𒅘',
+    '\u{a8eb}', '𔃷', '💍', '𔂡', '᪕', 'ꚱ', '❕', 'Ｅ', '𝆲', '𝓨', '𘤕',
+    '𜽨', '𜴉', '𒃗', '𖩽', '𝤉', '\u{1daa3}', '𜷄', '𓋠', '𒃐', '𐤎',
+    '𒔩', '𐋏', 'ꅍ', '𑂞', 'ႃ', 'ⱐ', '𓷜', '🞄', '𓑂', '\u{e0198}', 'ꋖ',
+    '\u{a92a}', '𝂊', '𝒮', '𓼈', '𝆞', '\u{5af}', '𝄖', '🆘', '\u{5b4}', 'ᡨ',
+    'F', '𓞱', '𑥘', '𞁑', '𓟅', '𐅇', 'Ѓ', '侀', '\u{d3c}', '𖣀', '\u{356}',
+    'ᨭ', '𞴊', '𐺞', '⫨', '㉈', '𒂢', '𐖮', '\u{ac2}', '⓵', 'ᗝ', '𓳁',
+    '𓅁', '𖫥', '𝂎', '\u{1daab}', '🩩', 'ผ', '𓯀', '𔘽', '⎟', '🍄', 'ꍪ',
+    'ޛ', '𓹗', '𞤄', '₶', 'ặ', '༄', 'ݶ', '𔎀', '𓗝', '𞋐', '┻', '𓻸',
+    '𐗗', '𓖞', '𓜹', '🔆', 'ꘝ', '𓺫', '𓳗', '𔀘', '𐎤', '𓹵', 'ꑫ',
+    '𑤆', 'ꠖ', 'ᔀ', '⩗', '꒦', '⠋', '𐇚', 'ⶈ', '\u{e0035}', 'ସ', '㎥',
+    '𘩂', '𓬭', '￡', '𐚎', '⎞', '𝦽', 'უ', '𜵝', '𐬲', '𘤱', 'ᨐ',
+    '𝪇', '𐰺', '𓣌', '🁸', '𜳐', '𐔶', '𔂟', 'ሖ', '𖣬', '＂', '𓲙',
+    '𛂰', '𓹰', '⨣', 'చ', '☏', '𒒶', 'Ǭ', 'ꣶ', 'ⶄ', '⇂', '𘩈', '𔎳',
+    '％', '𑎪', '☃', '\u{fe24}', '𝝻', '𝈉', 'Ӿ', '𒊷', '᭪', 'ﮝ', '㌓',
+    '㌹', 'ᕲ', '𔕍', '⨫', '𑜅', 'ꜥ', '🬬', '⿽', '𝚸', '𞗬', '𓻓', '𘧜',
+    'ꗲ', 'ꐠ', '🀢', 'ȣ', '𞓕', '𓽤', '𑚊', '𘭨', 'ట', '蠁', 'ᧇ', '𘦑',
+    '𝇈', 'ꙏ', '🠼', '꛳', '\u{e0124}', '𑘟', '𓭪', 'ሆ', '𞋞', '𝕕', '𝡿',
+    '𜸼', '𓵏', '\u{1de0}', '𑶉', '⌸', '\u{1daae}', '🞆', '⟜', '\u{1dc1}', 'ⵇ',
+    'Ր', '𓷅', '\u{111bb}', '₡', '𒁶', '𐖀', 'ꇊ', '𑤤', '⡑', '𓝨', 'ﰽ',
+    'ﷴ', '𓝤', '𐃬', '\u{36a}', '椔', '𑽅', '𐌠', 'િ', '𓚚', '𐰎', '𝛁',
+    'ⳳ', '𓻜', '𓌇', '𐿇', '𐀸', '𓿞', '\u{13453}', '𐼱', '𐹼', 'સ', '𝂵',
+    '㋢', '⸱', '𐊨', '𑰟', 'ⱺ', '𞲮', '諸', '⣻', '\u{11357}', '𐮑', '﮺',
+    '𑗔', '㏱', '𐙶', '𐼵', '𝓊', '𝟤', '𖧭', '𐚊', '㌨', '𐕞', '𑻡',
+    '🣀', '𞊗', '𜲢', '𐬑', '𞟾', '𝛛', '🌾', '꒿', 'ᰡ', '𞗔', '𘣸',
+    '𖠴', 'Ꜹ', '𐼽', '𒅒', '𓬣', '𓞥', '𜸞', 'קּ', '𜾥', '䷖', 'ꚋ',
+    '𝓀', '𐞶', '🌠', '𘰛', '𒁔', '𒂬', 'ږ', '𐍬', '𘫻', '𓄵', '𘭗',
+    '𓟳', '𓟷', '⌬', '𐀎', 'ᡠ', 'Ẉ', '𖾂', '𓆳', '𒉹', '𝌹', 'ᩊ',
+    '𐕛', '𒌧', '𑿪', '൶', '𓱥', '𓝈', '\u{a982}', '𘱲', '𔓪', '⦟', '🦒',
+    '豈', '𐛓', '龜', '𞠿', '巽', 'ㅁ', '𓯟', '𝋮', '𖩳', '𓐎', '𘨶',
+    '🎄', '𑀦', '𑚦', '3', '𛃭', 'ꯊ', 'ꕍ', 'ﴎ', '𔂸', '𘱸', 'ꌂ', '車',
+    '¬', '⧪', '𘧿', '勇', 'ޅ', '𘥉', '祖', '⸻', '𛁭', 'Ἱ', '\u{fb3}', 'ꓣ',
+    '⦁', '榣', 'Ⱦ', '᎙', '\u{17c6}', '𓗴', 'ꉃ', 'ད', '𓲖', '🜂', '𘥋',
+    '𜽕', '𓗚', '𓬲', '⌹', '𓜮', 'ᒄ', '𓬻', '𝌜', '⽠', '𜾸', '𑰞',
+    '𛰨', 'ퟏ', 'ᆳ', '🄖', '🄒', 'ꏽ', 'ꏼ', '𑚖', '𝅇', '𝟙', '𓛉',
+    '𓛅', '𐢮', '𞴮', '🆡', '𑪉', '練', '𝆺𝅥𝅯', '𛈉', 'ἆ', '\u{1ba5}', '💗',
+    'ތ', 'ﮚ', '𘡁', '𓌭', '𐀳', '𐝐', '𑂂', 'Ḭ', '\u{11f3a}', '𘦋', '𘡳',
+    '𛂬', '𔄬', 'ஞ', '🃙', '\u{ff9e}', '🙒', '𝌶', '𝚛', 'ꃿ', '㈖', '𐢓',
+    '𝚋', '𔄧', '𑐪', 'Á', 'ς', 'ၿ', '♧', '𛀈', 'Ⴎ', '၏', 'ॼ', '𓑬',
+    '𝈖', 'Ӣ', '𐧡', 'ǽ', '𓓲', '⧩', '𘯭', '𑽐', '㉡', '\u{e0062}', '𞡂',
+    '⛫', '𞺍', '𒉦', '𐙥', '𐃶', '︔', '最', '\u{17d3}', '𝛨', '𜹴', '𞄨',
+    'ꢤ', '\u{1b36}', '⌱', 'ᆆ', 'ﯴ', '≥', '𜽜', '𐚓', '𓼠', '𐂶', 'ǿ',
+    '꤇', '𞤾', '𑁗', '㈧', '𔍯', '𓞃', '\u{1dc7}', '𞸟', '𞺴', 'ꁿ', '🂪',
+    'ማ', '⸢', 'ఞ', '𖤖', '𞢸', '🦛', '𓉬', '֎', '\u{1de2}', '꞉', '🡳',
+    '┓', '🨻', '𓳚', '𓹂', '⟥', 'Ᏺ', '𓆟', 'Ꭓ', 'プ', 'к', 'ٮ', '𑿠',
+    '𝜢', '𝀢', '𒋟', 'Ⲏ', '〷', 'ꎅ', '∌', '⨞', '𓃁', 'ꊳ', '\u{349}',
+    '𒆺', '⹄', '𓚓', '𑩤', '𛁀', 'ⵄ', 'ᱟ', 'ᒷ', '𒆔', '福', '𒒣', 'ᙴ',
+    '𐌼', '𓵚', 'ꉨ', '𘪃', 'ේ', '𘧷', 'Ờ', '𓇉', 'ꟻ', 'ꡕ', '📿', '亂',
+    '🦔', '᥇', '𑵻', '🍌', '𞋲', '🛣', 'ކ', 'ᅝ', '𐖹', 'ʎ', '𓜚', '𝍂',
+    '⌍', '𓇆', '猪', 'ꀳ', 'ꏉ', '狀', '𜰧', '𓑽', '⾎', '🪥', '𖨦',
+    '\u{fc6}', 'Ƌ', '𓭄', 'ĵ', '⤴', '📇', '𓧪', 'ꛥ', 'ს', '\u{f99}', '🚩',
+    '㋵', 'ꒂ', '\u{f95}', '𓓏', '🁘', '𐗖', '⁓', '𖩓', '🬜', '粒', '৯',
+    'ఆ', 'Ԃ', '𐜆', '𓛦', '〈', '🫟', '\u{5c1}', '𐍥', 'ģ', '𝒊', '𘥃',
+    '①', '𞋢', '𓤀', '𐜩', '𓲍', '𝛰', 'ᗳ', '𑴒', '𐄁', '𑙗', '𝒯',
+    'ӑ', '␥', '﹄', '𔋨', '𔌌', '𒎓', 'Ṷ', 'Ԥ', '𓄦', '\u{e0135}', '𒐏',
+    '𝘿', 'ꈧ', '⎕', '𐲃', '𖺇', '𓠗', 'ﶉ', 'ሌ', '𔆸', 'ⳟ', 'ႚ', '𝗡',
+    '፼', '𝐶', 'ᗥ', '𑎩', 'ᇠ', '🨨', '𜷺', 'ស', '𝗳', '𔌃', 'ꖅ', 'Ⰲ',
+    '年', '𑴝', '\u{11a08}', '㎱', '𑣫', 'ⱉ', '𓁲', 'ꈟ', '𔗉', '𞊜', '⠤',
+    '𘪮', '𑈌', 'ઢ', 'ꩿ', '🍃', '𘣪', '𐊠', '𑀎', '🆊', 'ᬕ', '⾉',
+    '𖧍', '𝞤', '𐁐', 'ઞ', '╳', '𓊙', 'ฟ', '𞤩', '殺', '𜺕', '𒈾',
+    '𝝰', 'ㆸ', '𖼛', 'ឭ', '𐴚', '🌯', 'ѻ', '꭪', '𓹍', '\u{1e945}', '⡦',
+    '𓤈', '𑃑', '↠', '𝛒', '😦', '𑰊', 'ᬯ', '𖼦', '🆫', '𓡸', '➅', '۲',
+    '𑅯', '🮼', '𞄞', '🄍', '𛁑', 'ᆜ', '𔌧', 'ꔵ', '𛰅', 'ᶾ', '🯡',
+    '𓣘', 'ጤ', '𐤬', '𐤨', '\u{1344d}', '𒆍', '⽙', '𓼰', 'ﶧ', '⛸', '形',
+    '꜐', '𛈠', '𑱣', '槪', 'ꢳ', '𐲇', 'Ӥ', '𝁔', '𓘢', '𒔒', '\u{612}',
+    '⼂', '𝠜', '⎩', '𝓦', '₄', 'ﱕ', '\u{a4d}', 'ꯕ', '𐺓', '\u{b42}', '舘',
+    '𓕕', '𑼅', '𐇧', '🙤', 'Ѧ', '𜲖', 'ꭋ', 'ષ', '𐂌', '𐣧', '🔍',
+    '𖫝', '⎹', '𓼒', '𒇢', '〄', '𐶂', '⌆', '𐏃', '𘰔', '𘰐', '🔜',
+    '𝐮', '🦋', 'έ', '✬', '𖪟', '𞴚', '𓽊', '\u{1daa8}', '𘱉', '𝀥', '𒒯',
+    '𐠃', '𓇗', '𐙡', '𐮙', '𝩰', '𝂍', '⣛', 'ଫ', '𒎖', '⟪', '🢅',
+    'ꑷ', '𘦢', '𖥎', 'ڗ', '\u{36c}', 'ⴹ', 'ᥬ', '𞠶', '𔓄', '\u{1071}',
+    '\u{e01d5}', 'В', '𝋄', '異', '𛄜', 'ᘳ', 'Ę', '﵄', 'ງ', 'ᣃ', '𒄬', 'ꧡ',
+    'ಉ', '𑈕', '說', 'ꕺ', '𖾀', 'ﭹ', '⢪', '🯷', '⠹', '𐧰', 'ݍ', '𑙙',
+    '🭙', 'ﳸ', 'ጸ', 'ጽ', '𛊇', '⑩', '𐜇', '𑘋', '𝢹', 'Θ', '\u{e010b}',
+    '༓', '𐦽', '𖠱', '\u{e006a}', '𓢣', '❳', '𔋟', 'ꮈ', '㍮', '𓮠', '᜵',
+    '𘥊', '𝐱', '¾', 'ڸ', '𛈥', '𜴌', 'Ҫ', '𓙯', '𓀅', '𑇬', 'ﱈ', '₎',
+    '𓙢', '𖧮', '𐚠', '𜾿', '🚚', '𐽑', 'ᰏ', '𖥠', '𑾰', '𓒕', '𜾅',
+    '🬕', '𑠇', '𔁋', 'ಯ', '蝹', '𐄏', '𑰐', 'ۊ', '🁈', '␣', '💙', '𘱿',
+    '៵', 'Ხ', 'ɍ', '𝟄', '⨩', '𝙽', '㍕', '𓓒', '𓓖', '𐂣', 'න', '𖦬',
+    '𛱜', '𦳕', '⮢', '𓀻', '𞠷', '𞢚', '王', 'Ꚛ', 'Ɋ', '𓊤', '𘪉',
+    '𓩴', 'ⳝ', 'ꍹ', 'ዳ', '𓹫', '𔅔', 'Ꭺ', '𓙁', '⣃', '𓝹', '𓯳', '𜷐',
+    '𒌪', '𔆏', '𖼭', '⛦', '𓹢', 'ꭒ', '𐡄', '𓏺', '\u{2de7}', 'ᣏ', '𓯼',
+    'ꥁ', 'ｕ', '𐇱', '⨹', '𐧍', 'ꍲ', '𓬢', '𒎊', '🛁', '✇', 'Ἇ', '𑜺',
+    '𖢃', '𑲌', '𖧘', '𓅡', 'π', '\u{1133c}', 'ᚢ', '𓣯', '🐁', '𓅨', '𔄲',
+    '⸸', 'け', '⺽', '𡓤', '⚈', 'ꅶ', 'ࡨ', '鼖', '𑖹', '𝈵', '𖪵', 'ﻺ',
+    '𒈢', '⋘', '𓡛', '𑽇', '𐌱', 'Ṻ', '𓤿', '𝝞', 'ૐ', '💤', 'ඨ',
+    '𓇧', 'Ӫ', '𞢴', '🮊', '糧', '𓟐', '𓀈', '✭', '𐹶', '𓰸', 'ꉐ', '𛋐',
+    '𑊿', '𝦓', 'Ȅ', '𞠒', '📼', '𜴷', 'Ꞵ', '𜶃', '祈', '𛂔', '擄', '𓺽',
+    '࿙', '⺋', '諒', '𓴄', '𞤇', '𓈖', 'ǃ', '𑎃', '𜳡', '𖪈', 'ଵ', '𐞬',
+    '𝠠', 'ᤅ', 'ꞌ', '𓞗', '𓭴', 'ᾉ', 'ꡄ', '➈', '[', '𝆯', '𖢗', '𘤚',
+    '𐴂', 'ᕤ', 'ﯷ', '𝐕', '𓚩', '⛉', '𐆕', '栗', '吏', '꠪', '𐩈', 'ﲁ',
+    '⁴', '𓱛', 'ǐ', '𑦹', 'ﳊ', 'ࢮ', '𘥴', '⠺', '𒁌', '𑄇', '𑫛', '𔊷',
+    '㊀', '𓸟', '🦜', '𜲂', '𐛖', '◷', 'ᠹ', '𓼨', 'Ꙥ', '𝆙', 'ꠓ', '𘡦',
+    '🞽', '𝍧', '𓽄', 'ﾤ', '𔌣', 'ជ', 'ᓍ', '🈝', 'ⱻ', '🮁', '🤡',
+    '𘧩', '𘲖', '𞗟', '☌', '𓅠', '𘲟', 'થ', 'ᡭ', '𓙰', 'ሎ', '\u{1733}',
+    'ҳ', '𞢒', '𘠷', '𔓭', '≔', '𝔤', '𓏼', '𞟨', '෭', '𓷔', '꯬', '🃖',
+    'ᲈ', 'ꋅ', '🜅', '𘧓', '𔒦', '𔙆', '🠣', '𓌺', '𞡲', '𝝴', '𓜓',
+    'ㅑ', '𒀶', '𖹭', '႕', 'ꅨ', '𜶒', '𞁣', '𐦑', '𐂞', 'ퟁ', '𑄺',
+    '𓓤', 'Ⰳ', 'ⅶ', 'ኴ', '𐨱', '꣑', 'ำ', 'ᘓ', 'ۅ', '\u{342}', '𔃀', '𞡓',
+    '𛆶', '𓜨', '𐲘', 'ᴂ', 'ᨿ', '𐓓', 'ﹺ', '𐠀', '𝕬', '𓻔', 'ᚥ', '୶',
+    'ż', '𜲵', '⅗', '嗂', '〢', '𓓪', '𑘠', '𛉀', '𔉸', '𓏯', '𛈗', 'ἤ',
+    '𑰗', '𐨥', '\u{c62}', 'ꏡ', '𒔧', 'ವ', '𑽔', '𞋳', '⪵', 'ꜧ', '𐓥',
+    '꒔', 'ᓹ', 'ᖉ', '𑼿', '🜽', '𖹶', '😢', 'Λ', '𒇜', 'ᮉ', '𓅧', '𛁥',
+    'ꪛ', '𓣚', '🚏', 'ᅈ', 'ﱧ', 'ᜣ', 'น', '𓿋', '\u{e01a5}', 'ℏ', '𝧻',
+    '\u{1b80}', 'Ꟁ', '₷', '𝡆', '㊟', 'ļ', '𓾸', '喝', 'գ', '𘫐', 'ⴼ', 'ﵽ',
+    '𓕙', '﹦', 'ਤ', '𝜫', '𐭤', '\u{1cf18}', '𒕃', '\u{114c0}', 'ꈩ', '\u{fb6}',
+    '𔇹', '⨇', 'ᶌ', 'ꊶ', '﮶', '𑿨', '𝘬', '𓉛', '🖯', 'ꕫ', 'ᆀ', 'Რ',
+    '𓲨', '𔅠', '𞄢', '𝧵', '𝧽', '𓱐', '𘩮', 'ퟎ', '𔒥', '𓂥', '靈',
+    '醴', '𑩼', '𞠓', 'Ẋ', '𝔔', '𘲲', '𝑭', 'ﯝ', '⥐', '𝑱', '🨃',
+    '𖄲', '\u{64c}', 'ᕂ', '𜶮', 'ঋ', '𝡱', '𓮏', '࠱', 'ᅴ', '˝', '屢', '累',
+    '㎭', 'ꔉ', '₧', 'ᘔ', '💅', '𛃄', '𘪨', '𘪬', '𑘥', '゜', '𝕩', '𔂏',
+    '𑗁', '𐨩', '\u{1da45}', '𛀿', '𝄞', '๘', '🙨', '𝡓', 'ࠕ', '𞠠', '🏞',
+    'ꮕ', '𐐰', 'ፘ', '𐛢', '𖄉', 'ﯪ', '𘦀', 'Ⓞ', '𛃍', '善', '\u{115bf}',
+    '·', '𐧮', '🔸', '𛊻', '𖪗', '𝙰', 'ꜰ', 'ꁓ', '𛀘', '𓆪', 'ꆙ', '㍛',
+    '圗', '𓶝', 'ꂅ', 'ꂈ', 'ꐢ', '𖦊', 'ㄧ', '🎞', 'ힴ', '\u{e37}', '𑊅',
+    '⪔', '⿻', 'ﲜ', '𐃆', '𔂃', 'ሹ', '𜶙', '𓫰', 'ᤆ', '⥵', '𔒄', '📹',
+    '𜲶', 'ꏱ', '𞠵', '敏', 'ꞩ', '𐐳', '𓄡', 'ㇷ', '𜾽', '𛇽', '𓙡',
+    'ꚍ', '𓾪', '╀', '𛁊', 'ꊷ', 'ץ', 'ภ', '\u{10d27}', 'ᨔ', '𧏊', '𔂲',
+    '\u{302d}', '𖪶', '𖼄', '꠱', 'অ', 'এ', '𝅏', '𓱏', '𐳾', '🬖',
+    '\u{fe00}', '🡦', '\u{617}', '🐷', '𑐬', '𛇴', '7', 'ꍋ', '℩', '𓇲', '𑐏',
+    'ỗ', '𓪶', '𝍆', '𝑨', '𝝙', 'ꄂ', 'ᑓ', '獺', 'ϫ', '峀', '𝐖', '𔖰',
+    '𓏄', '𓔡', '۴', '𒍣', 'ᎍ', '🧑', 'ꁶ', '⾜', '🏣', '𔊙', '𛊭', '꧙',
+    'ஔ', '𐆅', '𓢋', '┼', 'Ꞁ', 'ꍅ', '𔈂', '🙸', '𓄿', '𖥻', '\u{1cf46}',
+    '𓐡', 'ῡ', 'ඣ', '𛃔', 'ⵚ', '𛋰', '𝞨', '𜱨', 'ᓳ', 'ⷒ', 'ꉠ', '𐨐',
+    'ຌ', '𐀡', '𑀱', 'ᄞ', '𔄊', '\u{1dc4}', '\u{fe2d}', '𓢍', 'ﺢ', '\u{e0167}',
+    'ṋ', '𐡨', '\u{61a}', 'ᨋ', '𐖜', '𓂣', 'ᯮ', '𔁸', '𒆧', '𔁰', '𓴡',
+    '者', '𓴩', 'ꍾ', '𐴸', 'ⶭ', '朡', '𓆿', '🇯', '𒒴', 'ﴶ', 'ﭡ', '≖',
+    '𐂖', '𛃇', 'ফ', '𓹲', 'ด', 'ኚ', '𖼮', '𐖩', '𐲛', '𘥡', 'ࠓ',
+    '😋', '𐳆', '🐐', '𑛘', '𒆯', '𝖙', 'ﲊ', '\u{2068}', '𝖍', '𞴌', 'ܓ',
+    '𐗘', '\u{e01b5}', '𐴱', '᥊', 'も', '\u{f7d}', 'ị', 'ꟁ', '\u{1d243}', '𓤴',
+    '塚', '𒔮', '🅾', '６', 'ꃏ', 'ࣄ', '⍣', '𝆕', '㏼', '𐵅', 'ƈ', '𐺢',
+    '🟉', '𘦓', '\u{11723}', 'ꦋ', '⾅', '𐬤', '真', '♛', '\u{1e011}', '𐘜',
+    'ꓽ', 'ꑋ', '₉', '𓺵', '᱒', '𝤵', '⮥', '🞾', '𔐤', '𒑩', '𐒊', '茣',
+    'ꤣ', '㇄', '\u{11d3f}', '🠿', '𔍃', '𘥣', '湮', '𒉑', '䷂', '𝃛', '𜾆',
+    '𖪱', '𖵑', '𐗏', '𓅉', '𛅤', '⭻', '𓪍', '🗝', '𝕻', '𘤖', '🐤',
+    'ຒ', '𞀷', '𓃼', '𓣰', '𞢯', 'ힾ', '𝓐', '𝌯', '❁', '𔉩', '𣾎',
+    '𓬺', '𖣫', 'ꨒ', '𒄞', '🢺', 'ᭋ', 'ꇉ', '𒓅', '\u{11638}', '𓾺', '𑒟',
+    '🞋', '🫡', '࿇', '᧥', 'ꛘ', '𝓖', 'Μ', 'ᨅ', '𝓭', '𘨓', '𘨗', '𞤴',
+    'ᎆ', '𝦣', '𒌓', 'ન', '👫', '𓕃', '𡷦', '⹌', 'Ꮊ', 'ꔳ', 'կ',
+    '\u{110ba}', 'ॵ', 'ꥸ', '⋻', '𑖜', 'ℴ', '⼞', 'ﭟ', '𝌧', '𔃭', 'ᢒ',
+    '𝡗', 'ꁼ', '𐖨', '🦁', '㈍', '໖', '⨕', '𓊬', '𓊨', '𔕾', 'ᄬ',
+    '\u{1e000}', '𔗓', 'ᙆ', '𐻃', '𘠣', '𒐭', '𒄤', '𓦖', 'ൟ', 'ࡒ', '𓈛',
+    'ᙜ', '𛂂', '㊂', '🁨', 'Კ', '𑎫', 'ꇰ', '𜱆', 'Ɑ', '꧳', '𑜞', '𒉉',
+    '㏮', 'ꕛ', '𛆔', 'ẖ', '𔐝', '\u{11f39}', '𘮔', '፦', '𒋸', '𝚚', 'ㄲ',
+    '𛂄', '𜽺', 'ⲻ', '🥲', '𑁞', 'ʞ', 'Ꜥ', '⁊', 'ክ', '🅜', '\u{73c}',
+    'ꀏ', '𓋒', '𖬿', '𓌻', '꒙', '〨', '𒊀', '𒾭', '𝝪', '𒊇', '𔇕',
+    '𛆳', '🧄', '𝣦', 'Ǜ', 'ᯃ', '𞓙', 'ɶ', '⮁', '꧁', '᠄', 'ቷ', '㎫',
+    '𔇚', '⠍', 'ཋ', '𐄓', '𓸤', '𔒊', '⋡', '𝞯', '𐣲', 'ƾ', '𑫲', '𐚛',
+    '⍉', '•', '𝃯', '𓩳', '𖪃', 'ཛ', '\u{a8e2}', '🨷', 'ꕁ', '➳', '𘡆',
+    '𓍸', '🃩', '𓎅', '𐪇', '🪢', 'ԅ', 'ↄ', '𐖃', '𝅘𝅥𝅲', '𐜤', '\u{2063}',
+    '᰾', 'ᵿ', '𐳺', '🌷', '鹿', '𖣯', '𐒔', '\u{e0076}', '𤋮', 'ꥳ', '𞋆',
+    '𜵽', '𘦛', '𘦟', 'ඇ', '👤', '𘱩', '𘱥', 'ꮂ', '𓐟', '⌓', '𔆱',
+    '𜶖', '𑂦', 'ꡑ', '\u{e4b}', '،', '𝑉', 'ﲓ', '𘱟', '𓭶', '𝀚', '\u{33d}',
+    '𖼔', '𓡖', '𔐐', '♣', 'Ꮯ', 'Ꮮ', '𖢄', '∲', '𑯇', 'Ꮱ', '\u{302e}',
+    '𖺍', '𓑢', 'ज़', '𐖉', 'ꥶ', '𑤛', 'ꮹ', 'ß', '\u{1e8d4}', '𑠟', '𑖑',
+    'ꌾ', '⼊', '𓑶', ';', '𛋦', '𜵐', 'ᆝ', '𑚠', '🖕', '𖩴', '◛', '䷍',
+    '𐭌', 'ฬ', '𓮴', '\u{1df8}', 'Ჱ', '𝤺', 'ﮑ', '𝚄', '🛵', 'ᧂ', '⛆',
+    'ꃛ', '⤜', '⭗', '𘣘', '✴', 'Ϭ', '𐌇', '⟅', '𛈬', '𓵵', '𓵱', '𝠌',
+    'घ', 'ỹ', '𞡳', '𑨜', 'ᐇ', '\u{20d8}', '𐦊', '𔑿', '𑠆', '𓸜', '𝖅',
+    '᮹', '💆', '𐄝', '⟒', '\u{13450}', '⊋', '♗', '𓪕', '𛋲', '🠅', '𛃮',
+    '🚷', '🛺', '𐒉', 'Ꟊ', '၍', '𜱛', '⣬', '𜱸', '再', '𑠭', 'ϭ', '𛁙',
+    '𛁝', 'Ϋ', '」', 'ﳵ', '▵', 'Ⱃ', '淋', '◬', '𐲄', '𜵆', '🏾', 'Ә', 'h',
+    '𒋈', '႙', '𘮏', '𘮋', '৲', '𑼠', '\u{59f}', '⏉', '⥰', '𓳿', '𓳻',
+    '𓡤', '𓱴', '⯣', '𐤷', 'Ď', '⦨', 'ཁ', '𔁚', '𑖅', '𑣨', '𐍢', '𒎗',
+    '𝄀', '🦦', '𖤾', '𐚃', 'ĝ', '𞀽', '🪕', '𓏕', '𘱃', '𘱇', '𒇛',
+    '𓊼', '𞅈', 'ⴴ', 'ひ', '𝍯', '𒇧', '𝑜', '❔', '𛂩', '𒇨', '𔒷',
+    '\u{1d244}', '𛆱', '𖣎', 'ㄚ', '𓈣', '\u{11081}', '𑫴', 'እ', '𐋫', '⠩',
+    '⤲', '𜴖', '\u{1da06}', '୳', 'ﴫ', '㏊', 'Ӷ', '𞡮', '᛬', '𘡞', '\u{7ac}',
+    'ꨝ', '㊾', '❏', '𖵅', '\u{11d34}', 'ᦶ', '𛈯', '⾘', '𓇕', '𛲃', '🯛',
+    'ﶳ', '\u{e0199}', '\u{e019d}', 'ꕑ', '𛃂', '𑆩', 'ꈣ', '𐪎', '🪬', 'ꥅ',
+    'ڔ', '寧', '𖹹', 'Ṉ', '\u{1d17e}', 'ᣚ', 'ꚦ', '🕐', '𔘌', '𐭡', '𔖠',
+    '𓦴', '𘫃', '␡', 'ꂄ', '⩨', '𘱑', '𒒳', '𓊟', '𑨭', 'ւ', '𓉴', '𐛍',
+    '𛈷', '𜱄', '\u{200c}', '𓘦', '𓉢', '𛈼', '𒑁', '裂', '\u{1da4c}', 'ი',
+    '🈞', '۹', '𝣤', '𑌽', '꧆', '𐬊', '🁡', '𐗅', 'ব', 'œ', '😺', '𓠵',
+    '𓞏', '𐣭', 'ꇆ', '𓴜', 'ﲈ', '⯥', '\u{119da}', 'ⰽ', '៷', 'η', '\u{e0122}',
+    '\u{e0126}', '𐵀', '𔉱', 'ㆇ', '𓆨', '𓆷', 'ぬ', '𓜍', '𓊉', '𓞆', '🙻',
+    '🧕', 'i', '𑱧', '㋬', '🡲', 'y', '𜺱', '🍡', '𓑾', '𓑺', '\u{fa2}',
+    '𓄜', '💹', '⳨', '㇜', '𖥫', 'ꇢ', 'ໟ', '\u{a94a}', '𑯄', '⍎', '備',
+    '侮', '𒑂', '༏', '𐪁', '🐓', 'Ჷ', '𔃹', '⤓', 'ꅗ', '\u{aa4c}', '𐑃',
+    'ឝ', '𐿅', '\u{112ea}', '╕', '𔊓', '௭', '𔉔', 'ৠ', 'ᤋ', '𐘺', '🃜',
+    'ȱ', '⼢', '䷫', '⏝', '𜵀', 'ﵳ', '𐓀', '⢎', '𜹳', '🜲', '𓥠', '𘲢',
+    'ﴽ', '𖭄', 'ꮆ', 'ㆉ', '𐺒', '𓒷', '𝈕', '𓺣', '𓫂', '𓲘', '𝦢',
+    'ᗃ', '𛈇', '🍩', '𜳨', 'ꉫ', '𑆵', '𐩃', '𐧕', '✘', '𝡣', '𝐜',
+    '🞁', 'ꪧ', 'Ꟗ', 'რ', '⺕', '𓈹', '𘭸', 'ꦡ', '𝑁', '𓳤', 'ཏ',
+    '\u{36e}', '⬑', '◾', 'Ѥ', 'ᢹ', '𔈚', '𐕇', 'ௐ', '\u{10d6a}', '𐛦', '🁫',
+    '⛶', 'ʳ', '𓋀', '᪉', '𑧂', 'ᵇ', '𘨞', '𓳭', '🠢', '⒔', 'ﶍ',
+    '\u{11236}', '𛁗', '𔉌', '🩺', '\u{206c}', '𜴸', '𓸻', 'ـ', '\u{e0127}', '⊨',
+    '𓖑', '𓟑', '閭', '𝘳', '𝘷', '𔎦', '\u{670}', '𝔪', '𝔞', 'ᴢ', 'ᓋ',
+    '𐡔', '𛂓', 'ᓟ', '╣', '𐺅', '5', '🛆', '₴', '𘰉', '𘦯', 'ጣ', '𒀘',
+    '𞄬', '𖦇', 'ዱ', '㈞', '˽', '৵', '𔕩', 'ⳑ', '𛃟', 'ꓐ', 'ᦃ', 'ꘈ',
+    '▋', '꩗', '𓐓', '⾱', 'ݰ', 'ᐹ', 'ꉷ', '𒃡', '𓦮', '𐙧', '𓎩',
+    '\u{604}', '𔀼', '𘧻', '៳', '貫', 'Ⳗ', '𑅴', '𓉨', '𘭴', 'יִ', '⟨',
+    '𞸗', 'ᓡ', 'ꅌ', '⮔', '𝆳', '𝌴', '𓬁', 'ओ', '𐘯', '⸲', '𐖂', '𐘳',
+    '🅧', 'ʗ', '⩾', 'ۍ', '𔘛', '𔘟', '𐮚', '𓔩', '𐖗', '𝛗', 'ក', '𝢇',
+    '𝌩', '𖫬', '𝤫', 'ꔡ', '𛁍', '🤶', 'ਃ', 'ꦢ', '𓴓', 'ឣ', '𝼑',
+    '\u{e0130}', '𛇝', '㉧', '馧', '𓬽', '𒆭', '𔃶', '𔃲', 'ᕝ', '𐅁', 'ꓛ',
+    '𒃷', '𜳴', 'ቔ', '𑪳', '𖨋', '𜴘', '\u{591}', 'ங', '𔍉', 'ꍐ', 'ꍍ',
+    '𑖂', 'ﮋ', '𓶇', '𑽘', '𐊾', '💟', '卿', '𓫏', 'ᗴ', '𝃧', '㍌',
+    'ᗊ', 'ᄐ', 'Ǩ', 'ݿ', '🮩', '\u{206f}', 'ꮢ', '𘩿', '𐮛', '𜸄', '𝒐',
+    '𒉞', 'Ό', '𓫼', '🪾', '𘥅', '𛱉', '𒔏', 'ⴧ', '➕', '⺹', '🭛', '𓢮',
+    '𘡊', '𐓧', 'గ', '𐬦', 'ꂘ', '𓞹', '𑎮', '𓗛', '𖄬', '\u{1da5a}', '𜴛',
+    '𐰑', '\u{e004e}', '𜹤', '𑘏', '\u{a94c}', '𒐦', '𓘗', '𒓨', 'Ʉ', '䷵',
+    '𞅏', '🨁', '🮕', '𒒤', '🚨', '黹', '𐕸', 'ᝁ', '\u{2007}', '┑', '𖪄',
+    '𐼝', 'ᔇ', 'ፆ', '𖡏', '📫', '𒒱', '𖫗', '𐛑', '𖨯', '𐓳', '\u{8ce}',
+    '𓳒', 'Ὣ', '𔀑', '𓻧', '⏎', '𑶡', '𑩢', '⮲', 'ၦ', '\u{e01c5}', '𔐳',
+    '❷', '𐵄', '\u{1daa4}', 'ꠤ', '𘱣', 'ﯧ', '⧖', '率', '𓪈', 'ꇗ', '🚲',
+    '𔀚', '𔀞', '𐓘', '🎝', '﹊', '𘠤', '𓝸', '𐚷', 'ᒱ', 'ꮣ', '𔁧',
+    '㉻', 'Ρ', 'ꀓ', '👔', '🢑', '🃬', '𑊓', 'Ꮀ', '∙', '𜾵', 'ⱈ', '𔊵',
+    '𜶷', '⽈', '𓶸', 'ꔖ', 'ᴔ', 'ና', '⑊', '𑠤', '𖺈', '\u{35e}', '🙓',
+    '𒍟', '𐱁', '𔉢', '𝦆', 'Ő', 'ⴅ', '\u{742}', 'ཬ', '⺴', '𑫘', 'ꨥ',
+    '𐭔', 'ጬ', 'Ὠ', '𦬼', 'ェ', '𝗒', '𒂶', 'ᑖ', '𓏏', '🦅', 'Ϙ', '𖢂',
+    '⊸', '౭', 'ꃌ', '𛰫', 'ꃶ', '𐓂', 'ᦎ', 'ો', '⼥', '\u{1da51}', '𓃔',
+    'ൊ', '𜰪', '࿅', '𔑣', '𓾽', 'ꪗ', '\u{5c7}', '≌', 'ꎖ', '␑', '𑝄',
+    '\u{951}', '𐔑', '𛃆', '𓃛', '𜷻', '𔘣', '𖨓', '꤄', 'ￃ', '羚', '𐐍',
+    '⟷', '𒅴', 'ⴲ', '✮', 'ῧ', '\u{363}', '\u{364}', '𜵬', '\u{36b}', '𝅍',
+    '𐿴', '𔄈', '𝜝', 'ǫ', '𑛣', '𐌰', '\u{e013a}', '𓋈', '🬔', '⨵', '𝆑',
+    'ᛎ', '🡶', '🝩', '𓛘', '𔎫', 'ꇫ', '᪓', 'ग', 'ꇪ', '𝇓', '𔂐',
+    '\u{111c9}', '🟐', '𜴪', '𔊻', '𑎕', '𐘫', '🎧', '燐', '𑂤', 'ꪉ', 'ڠ',
+    'ࡠ', '包', '𒐴', '𜴗', '𖄁', '𖤲', '𐡎', '🦱', 'ग़', 'ꊉ', '𘢛',
+    '𓞅', 'ბ', '𘪏', '𐌒', 'ͺ', '𓿍', '\u{1bca3}', '博', '𓕲', '𝓋', '𝟷',
+    '𐒥', '𛊛', '𓅻', '𑚁', '♒', '𓍴', 'ᑔ', '㋆', 'ᇻ', '𓇿', '✎', '𓈿',
+    '𔗷', '𝘖', 'չ', '𓪇', '\u{1039}', 'ᢨ', '꠶', 'ס', '𝂐', '𓑷', 'ꎗ',
+    'ᠼ', '𖢚', 'Ὴ', 'ौ', '𒌬', '𘱼', '𝜶', 'ꅄ', 'ਯ', '𝌠', '⌈', '˯',
+    '🫃', 'ힸ', 'ᦂ', '🍏', '\u{e01db}', 'ᠵ', 'ꦚ', 'ꡙ', '𓶯', '𛇺', '𑌙',
+    '𒍭', 'ޑ', '𔀉', '𑨀', '𔀁', '᳅', '𘳓', '⚴', '🤯', '𑌕', '𘱳',
+    '𐂼', '𘲬', '龜', '賈', '𓪲', '𜷡', '🉀', '\u{e0034}', 'ෑ', '𛲄', 'ꮋ',
+    '🎘', '𓎬', '𜹎', '𐼠', '\u{11c96}', '🗫', '🆐', '𑪺', '🮟', '𝡘',
+    '𓦳', '𐹥', '𝤍', '₁', 'ḥ', 'ᅫ', 'Ω', '玲', '𑧣', '\u{1da3e}', '𐢙',
+    '𔓉', '\u{e01e4}', '㋳', '꓆', '🖖', '𑬃', '𔊮', '🧢', 'Ҭ', '⚺', '𓀏',
+    '𒇱', '𔁷', '𘥔', '𘥘', '𓋕', 'ᰇ', '𖤶', '𓧉', '𑁧', '🭱', '𝆠',
+    '𒌈', '𝢔', '\u{1b73}', '𓷏', 'ℝ', '\u{1df0}', '≰', '𒒬', '𑃠', '\u{e0162}',
+    '⭬', 'ꂯ', '\u{111ca}', '🀔', '𓬮', '𓄽', '🭲', '㍤', 'Ǡ', 'ᦸ',
+    '\u{e0146}', '㍔', 'ه', 'ݮ', 'Ⲍ', '🠐', '🛱', '𑘛', '🅋', 'ꅻ', '⬍',
+    '⡽', 'ꗎ', 'ḑ', '𓢰', '𐠐', 'ℹ', '𒄓', 'Ⳙ', 'ቁ', 'ᾡ', '𓃨', '𐕖',
+    '埴', 'Ⴛ', 'ỉ', 'Ზ', 'ﵷ', '𐬠', 'ȳ', 'ᢰ', 'ﭸ', '𓬍', '𒍺', '🥃',
+    '𓬀', '𝆷', 'ᢪ', '┖', '⢗', '⬦', '៴', '𝈓', '⩼', '𒄥', '𒇞',
+    '\u{1d17b}', '𐖦', '即', 'ﾔ', 'Ȟ', '\u{744}', 'ﾕ', '𔎸', '𓁣', '𖬕',
+    '𛊍', 'ӓ', '𓕨', '🧳', '𞠁', '𑼆', '𑣊', '𐂾', '𑆰', '⢺', '𒂐',
+    '𑦾', 'ች', 'ಇ', '🩐', 'ૡ', 'ꝸ', '𛉣', '\u{11a03}', '🫐', 'ꮥ', '𝋎',
+    '𑚒', '𐢚', 'ꮪ', '𑇑', 'ꁏ', 'ʶ', '𒾿', '⿼', 'ಮ', '𓘳', 'ጐ', '𛉒',
+    '\u{e01e7}', '𛁜', '𘣑', '\u{2def}', 'သ', 'ꐒ', 'க', 'ࡈ', '𓠠', 'ᯠ',
+    '𑒭', 'ﺿ', 'ᙃ', '𐔲', '𘭆', '𑋃', '🢭', 'Ϡ', '𝋱', 'Ș', '🐰', '𒈍',
+    '૱', 'ꫮ', '🂨', '෨', 'ᢷ', '𝞂', 'ᥟ', '𑌔', 'ꑵ', 'ฏ', '𐨝', '⇑',
+    '𓣙', '𑙡', '⯱', '𝤑', '𞺦', '𓧲', '泍', '𘯡', '\u{1cd0}', '𘦭', 'ꏪ',
+    'だ', 'で', '𛰾', '🀰', 'Ҹ', 'ꑅ', '﹩', 'ᖠ', '卵', '\u{20e1}', '𖢣',
+    '𑐑', 'ᔢ', 'أ', '𔀺', '歹', 'ቀ', '꓾', '𐭝', '⊁', '𘥀', '𝆺', '𐍭',
+    '🨔', '𑪻', '𝌥', 'ｾ', '𖹲', '⋣', '𘨬', '𓦹', '𒀂', '𐲱', '𓦵',
+    '𘩸', 'ꀑ', '🫤', '❌', 'ª', 'ﻕ', '𓋻', 'ᇫ', '𜱒', '𓡵', '⸉',
+    '\u{e0158}', '\u{11c3b}', 'ꆿ', 'ҁ', '聠', '🈸', '𓹀', '𞋫', '𛀋', '⺁',
+    '𔆀', '𒅺', 'ㆌ', 'ࢁ', '𓿧', '⁋', 'ꎝ', 'ᬄ', '𓈉', '𑣩', 'ﮮ', 'ﭜ',
+    '𒌏', '𝝡', '𐩢', 'ꁁ', '🀖', '𖨩', '𑃢', '𓊸', '≛', '菉', 'ዐ',
+    '𑖣', '⎤', 'ᱜ', '𓃯', 'Ӈ', 'ต', '\u{1ce4}', '䷮', '𖠲', '𐹫', '𓄶',
+    '꘨', '⫂', 'Ề', 'ⷆ', '𖤅', 'ꚵ', '𝃴', 'ϑ', '𑃔', '兀', '\u{e0103}',
+    '\u{e0107}', '𛇼', '𔔽', 'Ṯ', '၇', 'Х', '𑽋', '𘲪', '🯂', 'ꅳ', 'ዬ',
+    '𓇀', '𓇄', '𝩼', '⓮', '⬢', '🨝', '𔏈', '𐵳', 'ᡉ', 'ᘨ', 'ꃭ', '䷉',
+    '𑀧', '꜇', 'ᨤ', '\u{3099}', '𓪩', '𛁄', '𓪡', 'ﱰ', '𒓛', '🁉', '鵧',
+    '\u{1da3c}', 'ⱕ', '𑅀', '𝁵', '🥂', '𐒝', 'ପ', '𐠠', '𑿯', 'ￂ', '𝑅',
+    'ﱢ', '⢷', '⳿', 'ￓ', '𖽫', '𓔖', '𒄷', '⯵', '𔊐', 'ʏ', '𓭨', '💖',
+    '𝚖', 'Ɂ', 'ā', '㇝', '磌', '𔏋', 'ǯ', '𑜗', '𔘍', '𓿟', '𓎤', '𐣩',
+    'ᥝ', '𘢽', '𐛧', '\u{1cf0c}', '𐍗', '𓒬', 'ࠏ', '𑒏', '⦂', '𐬀',
+    '\u{e01eb}', '𘬽', 'ꢓ', 'ㄛ', '⠛', '⅀', '𓻎', '⁈', '𘡇', 'ᐞ', '𔉑',
+    '𔉕', 'Ā', '𝼇', '𒔫', '𐂔', 'ꦈ', '⩓', '𞓲', '𒊲', '𓦜', '𝟉',
+    '𛋧', '𐀢', '\u{e011d}', 'ꊣ', '🥩', '🈜', '𘰗', '𛱲', '\u{1d181}', '⚀',
+    'ਡ', '⮏', '𑒚', 'ာ', 'ꈮ', '㉌', '𑵘', '𘯞', '🙕', 'ꗑ', '❗', 'ꛪ',
+    '鏹', '𒁏', '𓝆', '𔒂', '𑻯', '䷼', '𓗜', '𑂰', '𐣻', 'ቅ', '౻',
+    '𐌵', 'Ձ', '𘨚', '𓥥', '𜶡', 'Ά', 'ꭶ', '𐊏', 'Ⲅ', '🤿', 'ꑊ', '璅',
+    'ꉆ', '➗', '𞸞', '᥄', 'ᦩ', '𔇗', '🠜', '𘡈', '🞙', '🂎', '𞋝',
+    '𜵭', '𓧡', 'Њ', '⛭', 'ⅷ', '𑫗', '\u{e0051}', '⦗', 'ﾝ', '𝅐', 'ế',
+    '𓻺', '\u{e0188}', '\u{1034}', '𝂟', '𑱚', '𑯆', 'ꜹ', '𒔆', '𒌱', 'ᕉ',
+    'ꟗ', '🆑', 'ꢍ', '𐐸', '😖', 'ꆗ', 'ꀛ', '𓐦', 'ߐ', 'ᓔ', '\u{11c93}',
+    '𜱇', '≫', '㍪', 'ሾ', '）', '𔓎', '🥯', '𓚈', '𖤊', '📣', 'ꄸ', 'ꢚ',
+    '\u{2005}', '𓷧', 'ლ', '𔐠', '᪢', '𓢯', '𒉠', 'ߦ', '\u{e0030}', '𐀷',
+    '🏨', 'ø', 'ƫ', '𒒠', '𘱌', 'ﾵ', 'ꫧ', '𓢹', 'ꃪ', 'ﭼ', '\u{e0104}',
+    '\u{e0108}', '𝒟', '\u{e0113}', '𖦕', 'ꯘ', '𒁤', '𖽨', 'შ', '𔘬', '𔘰',
+    '𜶊', '𐘩', '䏕', '⢂', '𓨟', 'ꧬ', '🜯', '𑓁', '𞓳', '𓒎', '🖲',
+    '𝞿', '𝥤', '🏻', '𘯲', '𞱽', '𖵌', '\u{1cf12}', '\u{1a76}', '⮘', '𛉴',
+    '𝟮', 'Έ', '𓫃', 'ଂ', '𐐹', '🯙', '𖩗', '𔊏', '𝋰', 'ⱊ', '𒃚',
+    '𝖦', '𑒲', '𐕄', 'ᛙ', '𐓇', '\u{1193b}', '➹', 'ắ', '𘫪', 'ꄴ', 'ﲌ',
+    'ƍ', '𓔀', '𛂚', 'ꗃ', '𘲎', 'Ῐ', '𩐊', '௶', '𘠞', '𞤑', 'ﾶ',
+    '\u{11d45}', '𓊌', '⩶', '𑀗', '❤', '۵', '𞸪', '⫸', '𒆄', '🤙',
+    '\u{114ba}', '𓦉', '𓵆', '𐃗', '⸁', '𓸲', '𓊘', '𓅇', '𐘕', 'ր', '𛋜',
+    '𞁖', 'ᜂ', '\u{1cf0a}', 'ꎳ', '𓚺', '𓚾', '𔉅', '𘰬', 'ꗴ', '𝤠', '𓚯',
+    '𝟼', '⺄', '𘬖', '➆', 'ꊕ', '𔁙', '𒋍', '𔉎', 'ৈ', '𘨈', 'ჟ', '𘬯',
+    '𘬫', '𝘉', '🐥', 'ū', '𓢱', 'ᐄ', '꯫', 'ᭅ', '⯤', 'ﲦ', '𑙪', '𖪹',
+    '𐕽', '𔐋', '𜸱', '🪸', '𒅛', '⧗', 'ᤎ', '𒃑', '\u{81d}', '𛅽', '𛊺',
+    'ž', 'ᯘ', 'ꬤ', 'ꌞ', '𔁀', '🧠', '𘲉', 'ⷛ', '𐦛', 'ক', '𒌑', 'Ȓ',
+    '𓆋', '𘣽', '𐺚', '٥', '⒍', '率', '𓒍', '𐛀', '𝆵', '⎫', '໒', '𑤞',
+    '𓺬', 'ﾾ', 'ᅽ', '𓺩', '𓧙', '𞸎', '𓧑', '⇍', '🁞', '𓡕', '⤩', 'ꮤ',
+    'ա', 'ꨚ', 'ꮿ', '𔁇', '\u{102e0}', '𓧞', 'ⳛ', '🪻', '𖥹', '⮪', '╾',
+    '𓥗', 'ᙽ', '𓝰', 'ଆ', '𔌠', 'Ｓ', '𝥪', 'ᑮ', '🜋', '𓭬', '𑤀', 'ꬵ',
+    'ꄆ', '𓩙', 'ꄄ', '𞴇', 'ú', 'ⵣ', '𞋍', 'ꋂ', 'ⵏ', '⼀', '𝅁', '𝦀',
+    'ඌ', '🂻', 'ཿ', 'ⶲ', '𐐉', '𑒅', 'ꟿ', '𝜄', '𐬌', '⨬', '𞲇', '𐬎',
+    'ꋵ', '𔑺', 'ﮕ', 'ꕾ', '𝔭', '╵', '𖼒', 'ꅡ', '𘳉', 'ᧁ', '\u{e010e}',
+    '𝠔', '𑂗', '🙄', '𐦞', '𐩖', '⑷', '𐳀', '𖼕', '꜔', '🡬', '𖄓',
+    '芽', '鉼', '𑋲', 'ᠽ', 'ꩢ', 'ꖲ', '𐋍', 'ᎈ', '𜲲', '𑠄', 'ᙖ', '𒐉',
+    '𑼛', 'ꅊ', 'ꯟ', '𓣑', '𘥥', '𚿻', 'ﶞ', '𑒄', '𔔐', '𒇥', 'ၣ',
+    '𝜁', 'ῶ', '𝓶', '𑜳', '𝓮', '𝌱', '𐘎', 'Ꮐ', '𖤉', '⩪', '𐤖',
+    '𝃇', 'τ', '𓔶', '\u{610}', 'ꝉ', '𑠻', '⊥', '\u{1abc}', '𐙌', 'ᝈ', '⡓',
+    '𝧆', 'ᔽ', 'ힹ', '🨱', '𑜙', 'Ὺ', 'ᄺ', 'ꛄ', '𝄹', '𑢤', '𝟿', '𘠲',
+    'శ', '🗄', '\'', '𐙯', '𐡏', '𒆕', '𓨩', 'ᱫ', '⣍', '😚', 'ḻ', '𒊦',
+    'Ⴉ', '˒', 'Ꝁ', '𒀒', '𝌫', 'ᜏ', '𔒫', '🐽', '𓹼', '𓒝', '𑐵', 'ར',
+    '𛃷', '🭣', 'ﾦ', '𝞌', 'ߤ', 'ᣩ', 'ᨺ', 'Ꙧ', '🃰', 'ꖹ', '𒎑', '㍅',
+    '𝤎', '𐑙', '\u{333}', '𒂎', '𐑖', 'ᮾ', '𓟣', '🦊', '𐬅', '𝣝', '𒿯',
+    '𑰾', '\u{20ef}', '𘰼', '𔁎', '᎖', '◭', '易', 'ㇿ', '𛱱', '𞟱', '𒓏',
+    'Ṝ', '🟦', 'ﶫ', '𔆦', '𝛿', '⹂', '𒋂', '𓆡', '𓼍', '𓍀', '\u{d44}',
+    'ᥰ', 'ዂ', '𘢝', '₳', '👭', '磊', 'ꬬ', '˗', 'ꁉ', '𓗳', '𓗷', 'ڦ',
+    '𔍦', '𓤐', '𓤔', '𞋖', '𝟧', '𓤇', '𝀖', '𓄎', 'Ϛ', '𝆹𝅥𝅯', 'շ',
+    '\u{ecd}', '𝝱', '𒊎', '𖹀', '𐬒', '𐼓', '𓠩', '𐃮', 'ᐾ', 'ğ', '🎊',
+    '\u{82a}', '𔁳', '⸴', 'ᒙ', '\u{10efc}', '𓗗', 'ヌ', '𞡃', '🎖', 'à', '𖹊',
+    '𛱷', '𑈭', 'г', '⭀', '𐙈', '𛰋', '𓻹', 'ᅂ', '𐽀', '𒔂', '໕', '🥳',
+    '\u{1073}', '𐠖', '⭦', 'ཤ', '𘱡', 'ቌ', '𐰘', '𐋦', '𓘁', '𑂘', 'ﱦ',
+    '᠒', 'לּ', '咢', '𖩦', '𑶁', '𓘶', '𝁲', '💬', '𓛇', '𓳑', '𜺬',
+    '說', 'ﳒ', '𓿨', 'ᐖ', '𔆐', 'Ḿ', '𑦿', '𓚫', '␏', '⽁', '贛', '𐜒',
+    '῞', '꧱', '𝥟', '╚', '𓚍', '𑘭', '⯦', '𓚢', '\u{e003f}', '\u{1acb}',
+    '⣞', '𜰉', '🠫', '𓴺', 'ᵵ', '𐲬', '𛀹', 'ﳐ', '⾲', '𓾨', '🇽',
+    '𑈚', '𒐯', 'ﵦ', '🇭', '🇱', '⊵', '𞲗', '⥪', '䐋', '⚉', ')', '𓚄',
+    '𐑞', '𘡫', 'ꋗ', '𘡣', '𒄙', '\u{b01}', '𓂃', 'ㄝ', '𝧑', '𓖷', '𓔤',
+    '𖽿', '𐞄', '𓔨', '𓔭', 'ퟐ', '႘', 'ኇ', '𜷑', '𔒯', 'ꠈ', 'ጉ', 'ꌛ',
+    'ㄾ', '⧤', 'ግ', 'ገ', '⥳', 'ⲱ', 'Ḡ', 'ꢠ', 'ᐌ', '𔆽', '🮚', '🌍',
+    '⯀', '\u{e013d}', '𞄗', '𑿖', '⮺', 'ꉼ', '𑣎', '𔃉', 'ؿ', 'ﵙ', '𔁾',
+    '𜹅', '𖣭', '൱', '𖨱', 'Ⰹ', '𐋅', '帨', '𘪤', '𞋜', '𐩦', '⮐',
+    '𞊒', '𐞃', '⻔', '𝄡', 'ꨁ', '\u{1deb}', '\u{11a3c}', '𑼍', '𔁵', '⦢',
+    'Ꮫ', '𘧼', 'ퟘ', '𜷊', 'ꁀ', 'ꗚ', '𜱾', '𓑳', '㎝', '❶', '𒃌', '𑣂',
+    '𖣔', '◳', '𝒶', '🯁', '𐏏', '𒔠', '\u{1611f}', '𐗆', '𓀑', '\u{f78}',
+    'ಫ', '𝘗', 'ᑢ', '溺', '🙛', '𐚆', '𔉿', '𘯽', '𓡿', '𒊘', 'ⴝ',
+    '𐌽', '𜹏', '𜲎', '𖨡', '𐖥', 'ⅈ', '𝒕', '𛆏', 'ὴ', '𘩃', '\u{aa2b}',
+    '🌡', '𐮩', 'ᅢ', '㇀', '⓬', '𞲰', '\u{112e6}', 'ଟ', '೫', '𐰛', '∿',
+    '𔍺', 'ꡭ', '𓯣', '🃛', 'ꙥ', '🃃', '𐡳', '𑈏', '᭣', '煮', 'ᡋ', '𘨹',
+    '𐩕', '𝖲', '𜷲', '𑼎', '𓚇', '㉼', '𓏓', 'ᖰ', 'ꘘ', '𑿔', '\u{e01c4}',
+    '᮶', '✕', '𔍀', '𔍴', '٭', '𑣢', 'ዿ', '𑯉', '𐿶', 'ផ', '𓎽', '𐜟',
+    '𑀢', 'ᡬ', '⌞', 'ƥ', '🙅', '𨗭', '㉽', '𒂽', '𝣍', 'ẓ', '𒍌', 'Ⅶ',
+    'ꝴ', '𓱯', '𓵮', 'う', '𝄤', 'ﾏ', 'ㅔ', '𛅧', '𔐢', '𑣒', '𜱴',
+    '\u{1bf3}', '𝂩', 'ᡍ', 'ꈃ', 'ᚃ', '・', '𔐕', '𞅃', '㌅', '𝼏', '𔖶',
+    '𑄻', '𐖵', '⚌', '𘨠', '𞣏', '𓿻', '\u{11a8f}', '𑫋', '𓽇', '𐬭', '⻊',
+    'Ⴊ', '𝂫', '𓌕', '𛰁', '𓌟', 'ㄬ', '⽰', '𑿑', '𐙀', '🥿', '\u{e0022}',
+    'ድ', '૰', '𐝤', '🤅', '𞱷', '𒾬', '𞓣', '🐇', '㎔', '𓜴', 'ᬊ', 'Ắ',
+    '𖥔', '𐚰', 'ꚣ', 'ꕐ', 'Ở', '𝧄', '𘢙', 'ߘ', '𔊰', '𐀖', 'ﮪ', '᪁',
+    'ﾇ', 'ꃱ', '𓮒', '𛉝', '⍐', '𒈻', 'ᮠ', '𓤃', 'ꭌ', '𞢿', '🕸', '༉',
+    '⧅', '\u{aab0}', '𐹭', '📶', 'ꚺ', 'ꏊ', '⭯', '𞴈', 'ﶢ', '༅', '੩',
+    '𓘴', '𜷇', '𖣤', 'ﵞ', '𖦈', 'Ћ', '𓾶', '𞸕', '𐖄', '💢', 'ћ', '𔊚',
+    '𔐂', '𝁇', '𔋫', '𞁒', 'ꪩ', 'ᖫ', '𐭁', '\u{11c97}', '𑅧', 'ꨑ', '❆',
+    '𓁒', '⟕', '🨇', '𒿛', '𔇖', '𒿓', '𖡚', '𑅨', '𐳙', '𓟇', '𓟃',
+    '๔', 'Ҙ', '像', '𓾻', 'ꋊ', 'ꟽ', '𖤍', '꧸', '𝢝', 'ﺊ', '𝙻', '𐁊',
+    'ᚸ', '㉠', 'ཊ', '\u{1037a}', '𑤪', '\u{1da1e}', 'ᰑ', '꠹', '𖤃', '𑈍',
+    '𝜈', '𛃘', '㌛', '𓍛', '㋨', '𖣪', 'ﺥ', '🅄', '🅀', '𓡍', '🃢',
+    '🃦', '🝒', '𐋂', '𞊥', '𑌡', '⫑', 'ԣ', 'ὸ', 'ང', '𐏐', '𖧶', '🖻',
+    '𔍖', '𐽻', 'ᶍ', '𝋉', '🥊', '𑋹', '⤞', '𓟙', '𘦚', 'ꑼ', 'ؾ', 'ప',
+    '⹒', 'ヒ', '𓸉', 'ꬸ', '\u{a75}', 'ﰼ', '𞤸', '𓍣', '𒈡', '𘯥', '𑫩',
+    '𔖔', '𒌿', '𔖌', '🌇', '𔉨', '𔍟', '𖦒', 'Ю', 'ꋮ', '🯇', 'ꢬ',
+    '𘡛', '𐮇', '𑗉', '𔆒', '𓮹', '𑖉', '🂧', '𓭐', '𒿣', '𒿟', '🧃',
+    '𖧞', 'ⳍ', 'ˡ', 'ΐ', '𔈿', '⥆', '𓶧', '𓮫', '🍱', 'ၜ', '𝈪', '𑩾',
+    '𐿪', 'ಡ', '娧', '𛁫', '𐍱', 'ﱏ', 'ც', '𐀉', '𘭌', 'ﲴ', '\u{1cf00}',
+    '𞲞', 'ᕎ', '𑫞', 'ѯ', '𑫡', 'ਿ', 'ጌ', '𒂠', '\u{318}', 'ᠩ', '𑊳',
+    '𓈊', '\u{abed}', '𐫛', '𝝕', '🧔', 'ṛ', '𝃠', '𐰜', '㇏', '𖢠', 'ぎ',
+    '🗷', '𐔃', '⺵', 'ﱮ', '𒑆', '﹛', '⢩', '\u{200d}', 'ቾ', '▇', '㎪',
+    '𔔬', '𔔨', '𞠲', '𑀰', '﹜', '𐄸', '🚑', '𑌓', '\u{1e133}', '\u{743}',
+    'ᚱ', 'ẫ', '🖩', '\u{e0023}', '\u{17d2}', '𔂾', '⽫', '𔊎', '𘩨', 'ﯽ',
+    '⬺', '𓄬', '𒅾', 'ﶜ', '𔏧', '𑇦', '𘠂', '⚿', '𜹕', '𛆹', '🁏',
+    'ᑡ', '𓸝', '𓅈', '𘱽', 'ᖩ', 'Ⲩ', '𓈍', '𝌽', 'ꂀ', '\u{1e023}', 'Ⱓ',
+    '𣲼', '𒆜', '𓞷', '𓞳', 'ᢝ', '𔄱', '\u{1d188}', '𔂑', 'Č', '\u{2064}',
+    '𞗷', '𑅙', '𑆝', '𔖦', '🚰', '𒅜', 'Ƛ', '⊤', 'ꑣ', '𞸛', '𑿟',
+    '𐂷', '𑯹', '𑂕', '𔃮', '𓼲', 'ᛌ', '𓃘', 'အ', 'Ⰿ', '𘠮', '𝚽',
+    'ꉜ', '˷', 'ዙ', '⤕', '\u{1e4ec}', '⦾', '𖢎', '𘱊', 'ﶹ', '𒍄', '\u{82c}',
+    '𐛔', '⡉', '𞟴', 'ᑅ', '慺', '\u{17d1}', '🌔', '𓭯', 'ῃ', '𓅐',
+    '\u{112e7}', '哶', 'ꎨ', '𓤫', '👡', '𘦨', 'ケ', '𖤒', '𜳖', '𝟵', '𐑐',
+    '\u{102d}', '𘮑', '🪃', '\u{e0156}', 'ਕ', '𘫗', '🙳', 'ﰓ', '᳡', 'ꬾ',
+    'ﺕ', '𑼴', 'ͷ', '\u{1da61}', '𔍨', '𝤗', '\u{17ce}', 'ꌨ', 'ᥱ', 'ꑖ', '𑊁',
+    '\u{7ae}', '𐀝', '𑿿', '𔇽', 'ꔺ', 'ᦖ', 'ﲟ', 'ἶ', '⻙', '⸟', '𓪌',
+    '𐤅', '𑫚', '𓸞', '𓸚', '𘢹', '𛈰', '𐺩', '𛰖', '𓜿', '𔖊', 'ﱔ',
+    '漏', 'ᘻ', 'ञ', '⚚', '㎖', '𐧤', '𓨷', 'ᨱ', '𛇾', '𐧛', 'ꠟ', '𐚜',
+    'ẝ', '𘭟', '⺡', 'ヿ', '\u{350}', '𝞗', '🞇', '𖤎', '𑚡', '𐗠',
+    '\u{11d43}', '𓨹', 'ࢸ', '𓼁', '𘦷', '≱', '𑛉', '𒍎', '🍋', 'ή', '𐭎',
+    '𐴌', 'ꮘ', '\u{2028}', '報', '𓼊', '𐜋', 'Ɣ', 'ﾧ', 'ꏴ', '𐛿', '𓰠',
+    '𓰤', 'ച', 'ῇ', 'আ', '🚐', '\u{8cd}', '𝂭', '𑴆', '𑒧', 'ೝ', '𓽍',
+    'ไ', '𓰭', '𝡝', '\u{64d}', '𐹲', '🙎', '𛈨', 'ꨔ', '᾽', '𖡓', 'ɞ',
+    '𘪦', 'ߙ', '⧧', 'ꁥ', 'ﻪ', 'ꕥ', '⢨', '𔄅', '⢁', '🜍', '𨵷', 'ꬔ',
+    'ⅸ', '⥲', '⥾', '𜷟', '\u{a80b}', 'உ', '𐛸', '𓺮', '𖬋', '𔒗',
+    '\u{1c33}', '┕', 'ㅙ', 'ꫛ', '🖃', '𔐗', '𐐝', '㊠', '𓸆', '𖺎', '𖦽',
+    '𓊔', '𘠐', 'ﳜ', '𞴅', '𓎿', '𑖒', '𒉩', '𔂋', '𐡍', 'ᵀ', 'ﰳ',
+    '㉎', '𐐟', 'ꅑ', 'Ⅳ', '𔆿', 'ն', '🖊', 'ᖍ', '𜷳', '≵', '𜽤', '𝐟',
+    '𘬃', '𐧓', '𛇎', '𔃙', '𘤞', '𛇇', 'ꆸ', '𞤖', '𓤧', '𓯏', 'ﲪ',
+    '歷', '𑫸', '🯀', 'ꡰ', 'D', '𜾢', '⮕', '𐾁', '𝖣', '𝌋', '㏃', 'ｅ',
+    'ʐ', '⛇', '𑋢', '㉦', 'Ս', '𐽂', '\u{115af}', 'דּ', '𓴑', '៛', '◊', 'ǋ',
+    '༟', '┱', '🢜', '𜾹', '𜶎', '🫛', '𛆣', '囹', 'ย', 'Ѽ', 'ႊ', '㏛',
+    '𐅮', '𓾘', '🟘', '𘫺', 'ຨ', ';', '㍑', '𒃂', '🀻', '⠓', '\u{afd}',
+    '𖦠', '⛠', '࢈', '𓐑', '❈', '𖧷', '𑈹', '𑆴', '㏣', '🎆', '𑰢', 'Ŋ',
+    'ಙ', '𐀗', '⁎', '⊧', '𐑂', '\u{1d180}', 'ቓ', '𖣮', '𘯋', '𝠄', '婢',
+    '𝝘', 'ḕ', '𓸁', '📞', '𖩎', 'ᵎ', '𐡆', 'ㅯ', '𓯆', '𛈢', '𖥺',
+    '𝇕', '𒎅', '𓀚', '🄨', '𐫂', '\u{16127}', '𑊴', 'ᘊ', '𝌍', 'ꒃ',
+    '\u{1b6f}', '𐄔', 'ퟋ', '𓻋', 'ꆥ', '𒋿', 'ꆧ', '𒈗', '𔘻', '◃',
+    '\u{e0058}', '🞑', 'ඛ', '\u{e004c}', '\u{e0048}', '𖧵', '🧬', '𝝐', '𛁏',
+    'ᦪ', 'ण', '𝍔', '\u{5b0}', 'ꦅ', 'ᙓ', '𘩖', '𓝵', '৫', 'ꀯ', '𞤡', 'Ŏ',
+    '𓅩', '𑿘', '𜾖', 'ꠝ', '䷄', 'ޠ', '𒄹', 'Პ', '𝜾', '𔘀', '𑇯',
+    '\u{302b}', '𑂚', '𓰒', 'ꮉ', '𑒥', '𔔘', '🦎', '\u{1bed}', '𛊎', 'ⴽ',
+    '\u{592}', '᧖', '𒂔', 'ᵶ', '𒌢', '𝣀', '𞺡', '𓼸', '𘤡', '\u{115b3}',
+    '𑽖', '\u{1dcc}', '𖽈', '᳆', '𘪀', '𐘏', '𘪈', '𝆘', 'ꍊ', 'ዌ', '➍',
+    '𓲦', '⭌', '𛅦', '𔈔', '⤪', 'ဖ', '🝡', '𓴍', '𑋰', '𐼖', '𜴮',
+    'ꉈ', 'ꋄ', '𘡽', '𝋳', '⅏', '🠽', '𐰴', 'ు', '𘰃', 'ܢ', 'ꅲ', '𔃕',
+    '𓐚', '㮝', '𥳐', 'ଠ', 'ꠏ', 'ꫪ', 'ᔃ', '𔐾', '\u{2de9}', '\u{ad}', '𛂐',
+    'ޣ', '𒅊', '𓆲', '𒇸', '𑪟', '⌊', 'ﺇ', '㔕', '𑚇', '𘯛', '𑣍', 'ല',
+    'ѷ', 'ਣ', 'ᩣ', 'נ', 'ꍄ', '⏱', 'Ⴄ', '𒿖', 'ဇ', '𘡖', '🄆', '𓘘',
+    '㋌', 'Ⴔ', '𑿢', '🏝', '𓔷', '𐞉', '🫴', '🜇', 'ᳳ', '𓃇', 'ꌫ', 'ꌪ',
+    'ｧ', '\u{11a5a}', '\u{1da57}', '𞢦', '𝜇', '𔒵', '🅕', 'ᑰ', 'Ὧ', '𑓅',
+    'ᚷ', '𓨘', 'ꛞ', 'ﯣ', '⡡', '𓱭', '𘲶', '𑴜', '𔁯', '𑎘', 'ꮗ', '𝖆',
+    'ﵰ', 'Օ', '🌭', 'ﴧ', 'ဟ', '\u{1a75}', '𓨌', '⛔', '𐍟', '𓗻', '𓚝',
+    '𑶘', '𑊀', '𞁓', '🔾', '𑊂', '𑻷', '䷻', '𒔹', '၉', '𘭱', '𝔾',
+    '⼦', '𑜑', '⦸', '𔃏', '᧻', '𓅑', '𝆩', '\u{11446}', '𝗠', 'ꩡ',
+    '\u{a67a}', '𒆷', '𞢤', '꡵', '⤠', '𝔵', '𞁀', '𓭿', '𞟳', '𓶺', 'ე',
+    '𒾼', '㎵', '𓶱', '𖣧', 'ㅉ', '𛁣', '⤎', 'ᴦ', 'ꝰ', 'ﾩ', '꛷', '꜀',
+    '𓼡', '𓸳', '𐪊', '𝩹', '\u{942}', '𓻽', '𜲬', 'న', 'ԟ', '\u{11237}',
+    'ⵕ', 'א', 'ὰ', '🥀', '𘰶', '𑎉', '𓰈', '𐌮', '⪟', '𓰄', '⻃', '𞢣',
+    'ᯒ', '⭽', '𓙌', '𝥡', '🮆', '𓁇', 'ᦧ', '𖽂', '𖬂', '🟕', '𐎿',
+    '𞢓', 'ꆺ', '𛉨', '⺌', '𐚗', '𑣮', '꒸', '𖢀', '𐌑', '🜨', '𐮭',
+    '೨', 'ﲰ', '🚅', 'Ѿ', 'ഖ', '🢩', '𓩗', 'બ', '💁', '𑊽', '𖩊', 'ꀵ',
+    '𑤍', 'ᗨ', '𑴧', '𖦋', '𑵐', '𘫾', '𜴦', '𒑔', '𓸦', '🜕', '𓖒',
+    'Ѕ', '𓖇', '𓖟', 'ὄ', 'Պ', '𜴰', '⼓', '𐜗', '﮽', '🯯', '𝃦', '𘣎',
+    'ꃯ', '⁔', 'ᣰ', '𝡔', '🜁', '𛅒', 'ꃽ', '𓋪', '\u{e0100}', '᎒', '𔑢',
+    'ꩪ', '𘡵', '𓸯', '🬦', 'ꯜ', 'ᇯ', 'ꗳ', 'ꕚ', '𓶲', 'ك', 'ᯖ', '𓥌',
+    '⊙', 'Ⴡ', '𖣩', '𞁗', '💝', '𝘑', '𘢺', '🥼', '𓃫', '\u{fe23}', 'ﾬ',
+    '🮔', '𓀋', 'ҽ', '𐄢', '𘭃', 'ⲹ', '\u{601}', '𑈧', 'ꛓ', '𐪄', '⟍',
+    '⭥', '𐒗', '𝧗', '𐒅', '𐌓', '𝧿', 'ꬴ', 'ይ', '⋸', '🍎', '➟', '🔡',
+    'ǆ', '𝞋', '𐗳', '𐤓', '\u{119d5}', '舄', '𞡍', '𓭛', 'ꇠ', '⻤', 'ﾊ',
+    '\u{e0186}', '拼', 'ﾌ', 'ꯉ', 'ং', '懶', 'ㄯ', '𝕝', '𝕙', '🨼', '𔈒',
+    '𞹶', '𘨳', '᭤', '㍭', '𓨆', '🡑', '𓳩', '𖣾', '𒁦', '𛀆', '𓯍',
+    'K', '\u{cbc}', 'ꂜ', '𘦈', '⥎', 'გ', '𑼝', '𒆒', 'ꊱ', '𐀊', '\u{1cf10}',
+    'Ᏸ', 'ꗟ', '⦇', '┿', '𐖶', '𔗏', '𑎋', 'に', '⒥', 'ṱ', '𐀫', '𞲆',
+    '⽮', '𔀒', '𓂿', '𓂺', '𓯫', '𒀵', '＊', '🨬', 'ꮞ', '𝘶', '𐊃',
+    '𓂂', '𑘡', '𒆠', '𛉋', 'ǻ', '\u{e016b}', 'ꞁ', 'ꚠ', '𛄟', '\u{1030}',
+    '☈', '𐔉', '𖮌', '𐧱', '\u{1bad}', 'ള', '\u{afb}', '𑯝', 'ɋ', '𓤡', 'ɮ',
+    'ㅾ', '𑤷', '𓱮', '𝁈', '⡈', '𓭷', '𓩇', 'ﲎ', '𓎟', 'ச', '𐑲',
+    '𓷊', 'ĭ', '𒇀', '\u{6ed}', '𐘴', '𓩞', '𜹡', '🈣', '𒀡', '\u{316}', 'Ἕ',
+    '𖼈', '⥝', '𖡿', 'ӟ', '𒔦', '𒇿', '𞹬', '𔕆', '𘲮', '🍢', '𑃶',
+    'మ', '㌕', 'ᬿ', 'Ⓡ', '\u{f91}', 'Ⓩ', '𓱿', '𔎱', '𝌇', '𓛩', '𞺵',
+    'ƞ', 'Ἦ', '🬊', '𓃆', 'ﰚ', '🕑', 'ᝢ', 'ᾴ', '𓠫', '𐩳', '𖺐', '》',
+    'ꖙ', '𖭰', '▪', 'ꕎ', 'ꗦ', '𔍈', '🫑', '𜶇', '𖤫', '･', 'Ⅷ', '🪰',
+    '𒍠', '👟', 'ﾫ', '𔃱', '𝙉', 'Ⱳ', '𔒆', '\u{1df6}', 'һ', 'ͳ', '𜾄',
+    '𝞞', '𒍲', 'ਜ਼', 'ƻ', '𞤙', '𓒣', '␟', '𐓃', 'ꊫ', 'ꝇ', '∟', 'ḯ',
+    '𒍰', '\u{826}', 'র', '𐕷', '▲', '☭', '𝝏', '𑂫', '𔔂', '𐄇', 'ཐ',
+    'ⱶ', '𓭁', 'ꪄ', '🨹', '𓁳', '\u{1d1ab}', '．', '𞴎', '𐫃', '⚄',
+    '\u{946}', '𐫘', '🐦', 'ˋ', '𖣆', '\u{74a}', 'ề', '𐧵', '𓷍', '𘨟', '𓷆',
+    '᭽', '𝡥', 'ዽ', 'ߨ', '𐢏', '𐩥', '⅜', '𓨴', '𓨰', 'ꑈ', '𔉽',
+    '\u{11cb3}', '𑇇', '𞁄', '𐺙', '𔉰', '🃇', '𝑥', '𓷋', 'ꖕ', '𓳊',
+    '𓱱', '🬗', 'ᒡ', '𐼘', '𑎣', 'ᇲ', '𐒎', '𐄑', 'ࡘ', '𐬏', '𑊔',
+    '⁀', '⪅', '𛀒', 'ᎎ', '𘭕', '⏜', '𘠵', '𓥩', '𘭡', '𝞖', '𐆘', 'ﳮ',
+    '💉', 'ꢽ', '𐊑', '÷', '𓅂', '🔙', '𖨝', '\u{11134}', '\u{1e947}', 'ʇ',
+    '꯹', 'ꯪ', '𘧐', '😈', '𓀠', '𓥯', '𑣠', '𓀜', 'ガ', 'ὂ', 'į', '𖽇',
+    '⊡', 'ꨣ', '୷', '\u{111bc}', 'ꄍ', '𑠧', '𜺌', '𓐐', '𐅂', 'ﱷ', '𐣾',
+    '𖄝', '꧓', '𖮆', 'ﹹ', '𐫏', '𒇮', '𝠎', '𘪽', '𔖫', '🞊', 'ჩ',
+    'ᦹ', 'ᒣ', 'ꂠ', '🠂', '\u{2de0}', '🂣', 'Ƣ', 'ల', '𘠔', 'ꗛ', '💦',
+    '𑎴', 'ⲝ', '🗕', 'Ꭴ', '🮧', 'Ȩ', '⟂', 'ｘ', '࠵', 'Ὄ', '\u{1d179}',
+    'ᶷ', '\u{ac5}', '𒐵', '🂦', '🝃', '𓸼', '🍷', 'ẩ', '𓛾', '𓛺', 'ŭ',
+    '𐕆', 'ⴆ', '𐴗', 'ꡖ', '\u{a6f1}', '𑇒', 'ɸ', '𖵬', '\u{1ab4}', 'ꍁ', 'ᥙ',
+    '📐', '𓺪', '㉭', '𑢠', '𑧆', '🃘', 'ጢ', 'ᇇ', '𔏉', 'ﰀ', '𓙠',
+    '𔏅', 'ꀄ', 'ዶ', '𐿢', '𒂱', 'క', 'ऎ', '𖫧', 'ᩤ', '𑋝', '⩺', '𓄑',
+    '𓟟', 'ᘇ', '🚪', 'ا', 'ལ', '₠', '𛀱', '𔏊', '⤳', '𜶳', '𖥘', '𔋐',
+    'ꂴ', '𝚒', '𝆌', '𘮎', '🗠', '𜶧', '🞣', '⪉', '\u{5a7}', '⣡', '⮬',
+    '𖢇', '𔇪', '𜽰', '\u{e0161}', '𔂶', 'ပ', '𖽠', '௧', '⿊', '𐞙', 'ꖣ',
+    '𓒸', 'ꍴ', '♔', '⬎', '🮤', '𓰂', '𔔤', '🎕', '𔍔', 'Ო', '𜶄', '㇒',
+    'ᵌ', '\u{f9f}', '\u{1a5d}', 'ᓽ', '𘱤', 'ᶿ', '𔕌', '🦴', 'ⷍ', '𖹚', 'ᳮ',
+    'Ꮭ', '㏍', '𝃐', 'ຈ', '𝞈', '⎾', '๛', 'ᰘ', '㎾', '\u{11c32}', '𞓠',
+    '⩉', '𒉏', '𔓞', 'ആ', '🙖', '\u{e01ac}', '𓤖', 'ꆯ', '\u{10f46}', '𔇉',
+    '𝙵', '💠', '𜶢', '𘥬', 'Ꭹ', '𒐬', 'ꕊ', '𔖄', '࠼', 'ꈠ', '𝋅', 'ټ',
+    'ẹ', '𓵨', '𘧚', '𘧖', '🔿', '𘧞', 'Ỏ', '𜳔', 'ᢙ', '𓀤', '𓔾',
+    'ᆱ', '◣', '𘨣', '𐺡', '𑵸', 'ᜃ', 'ퟴ', '𐲀', '𖡨', '\u{17bd}', 'ꓨ',
+    'ᕗ', '🦐', '𝥽', 'ズ', 'æ', '𐦕', '𓿮', '㏩', '౮', 'Ჸ', '𓟢', '𔖪',
+    '𓫯', 'ꭢ', '𝌀', '𖺘', '𔈎', '𐔤', '𒆌', 'ᥧ', '➦', 'ἧ', 'ᥣ', 'ㄵ',
+    '⎜', '𑼬', '𓥅', 'ꩍ', '𐐔', '🧘', '𒑥', '𑨟', '𝠋', '𔀻', '𘧳',
+    '\u{1a77}', 'ᖜ', '𓭀', '⼕', '𝇋', '֍', '𓮯', '𐦠', 'ᥩ', '𝞵', '𓄺',
+    '𝞑', '𒉙', '𝌦', '𑅘', 'ꓟ', 'Ὦ', '𒓓', '𑇈', '𓥂', '𝂠', '𔎆',
+    'ߚ', '𓳴', '𑼨', '𝕸', '𝂮', 'ᯚ', '𘮱', '𓘍', '𓻫', 'ݡ', '𛱎', '𓑦',
+    'Ꮞ', '𒌐', '〉', 'ᱠ', '🃏', '𛀎', '𐗦', '⎲', '𝥲', 'ހ', '⑥', 'ꐪ',
+    '𓦽', '𜸮', '♾', '\u{1343a}', '౽', '𝥘', '𝙇', '൳', '𔒟', 'ﳁ', '𖾅',
+    '🙷', '෪', 'ẳ', '𓁥', '瀞', '\u{954}', '💔', '𑓐', 'ῠ', '\u{89a}', '𓟈',
+    '𛀠', '𖵓', '𔌁', '𑫂', '\u{1e136}', '𘤔', 'ꞵ', '𛂙', '𘰾', 'ฝ', '🀟',
+    '𒌘', '𝓒', '𐼢', '\u{11c3a}', '𝖭', '𝖩', '𓖢', '𒿰', '𓳫', 'ᑾ',
+    '𛆎', 'ᑶ', '𞗴', 'ꐄ', '漢', '𘢴', '\u{e016c}', '𝟓', 'ﰝ', '\u{e0170}',
+    '\u{a677}', '\u{1ce3}', 'ᎊ', '𞴣', '𐿋', '\u{2df6}', '\u{8e8}', '𐅍', '𔊞',
+    '𓱞', '𝋁', 'ઈ', '🜛', '\u{1c31}', '𝍖', '𝥓', '🐜', '⩒', '🟫', '৭',
+    '𖵝', '𞲡', 'ⴺ', 'ڭ', '𑠌', '𜵋', '\u{11a53}', '𓶤', '𑼢', '𒍉', '🍮',
+    'ꪪ', '𝟒', '𓊳', 'ᐨ', '𔅍', '𐨭', '𐵻', '𜲅', '𓻀', '᾿', 'ノ',
+    '𞡉', '㉺', '\u{c3c}', '⳾', 'ᜑ', '𔏖', '𝇩', '𑚏', '𘭁', '♆', '𑊏',
+    '⚋', '𑌋', '\u{e011e}', '𐡧', '༮', '𐘉', 'ኽ', 'ἀ', '𘱋', 'ບ', 'រ',
+    '𞓢', '⦞', 'ꩠ', '🕖', '⌃', '🔔', '𔗿', '䷶', '𝔊', '𔉭', '⇈', '𒊏',
+    '\u{e007e}', '𞠥', 'ﶚ', 'ᤪ', '〱', 'ᕢ', '𖦵', '⛣', '⎻', '𑒐', 'ꪡ',
+    '⤌', 'ឳ', '𐡐', '㍬', '𑎨', '𓕻', '💲', '𝒷', '𐋡', '𝗔', '⋱',
+    '𖣸', '🡥', 'Ȿ', '𐓴', '𘳔', '🖪', '♮', '𝞃', '𘫄', '﵂', '🠧',
+    'ⷖ', 'ﻇ', '𐍜', '\u{1cdb}', '𔀠', '꧖', '𔗫', '𐪈', '𝓣', '㌈', '咞',
+    '𔕬', '🥑', '\u{1bca0}', '𑄣', 'ᘰ', 'ȅ', '𞄠', '𛄒', 'ϙ', 'ゕ', 'ﬀ',
+    '🚁', 'ꛩ', '⣟', 'ꆊ', '𓝮', 'ꁸ', '𑃛', 'ↂ', 'Ⳬ', '𓉝', '⦅', 'ﺺ',
+    'Ὼ', 'Ꙣ', '\u{a671}', '✔', '𒔚', '𐵊', '᭒', '𐩨', '𐬟', '🢬', '拔',
+    '𓈞', '\u{1da47}', '惡', '⯮', '⬝', 'ට', 'ˤ', '𑄋', '𐦖', 'ላ', '𐿆',
+    '𐿂', '𖼀', '𓮶', '𓃕', 'ꕽ', 'ᄱ', 'ꧼ', 'ು', '𓣿', 'ߑ', '𑁩', '𓣲',
+    '𓣶', '⋇', '𐐗', '𘪳', '🌸', '賁', '🞉', '𖥭', 'ᔸ', '\u{fe09}', '🄀',
+    '🫱', '🂡', '𐄠', 'Ꚅ', 'ﯰ', 'ꄁ', '𓳐', '𓧅', 'ῆ', '＞', '𑐩', 'Ꮁ',
+    '﮲', 'ゥ', '𛊗', '𡬘', '೪', '∶', '᱇', '⋵', '𝁂', 'δ', '\u{1da0d}',
+    '\u{487}', 'ᓼ', 'Ἔ', '𐜲', '𒂖', '𐩓', '𛰍', '😆', '禎', '𐬣',
+    '\u{1ab6}', '𐵼', '🪒', '𝧢', '\u{360}', '🆁', '𔈈', 'ᩗ', 'ⷁ', '🐅',
+    '𐮉', 'Т', '¹', '💺', 'ໃ', 'ꐔ', '𑅒', '𓯐', '㇉', '𛃲', '𑽃', '𓷰',
+    '𜷛', '𞢆', '⽟', '𓂯', '𞤺', 'ჺ', '𘪁', '\u{5c2}', 'ﴗ', '𑌝', '𓉲',
+    '𒁈', 'ㄿ', '𛇿', 'ⓕ', '𖤈', 'Ϻ', '\u{11001}', 'ﯙ', '𑱳', '𘬾', '𐖬',
+    '𔘆', '🥓', '𓭘', '殮', '𓷽', '𔙀', 'ﴠ', '🦗', '𑛛', '᮳', '𔇬',
+    '𔏭', '𑎁', '🗗', '🙽', '𓍇', '𔈳', '𓌿', '𓍃', '𠠄', '𝁥', '𝥫',
+    '𒋢', '𞹾', '𓤻', '🠰', 'ꇝ', '𑂸', '\u{11a37}', '𓩉', '𞲍', '\u{a806}',
+    '𘯔', '𓏾', '𔉄', '𛋗', '𔘡', '𜵎', '𔁛', '𘬡', '𒈖', 'Ȁ', '𝢕',
+    '\u{20ea}', '㎁', '▬', 'ǲ', '𛊫', '\u{9c4}', '⇱', 'ᲁ', '𓈟', 'ࡂ', 'ꔧ',
+    '𔅌', '𑀔', '𓓑', 'ଡ', '𐋰', '𤉣', '𐩲', 'ᬒ', '🟓', '𓽉', 'ꪓ', 'ה',
+    '\u{1059}', '摩', '\u{618}', '𑁦', '𛲑', '🮬', 'ˁ', '𔗳', '𔉋', 'ﺸ', 'ꇴ',
+    '🭭', '💿', 'Α', '𐫢', '🭪', '廊', '𜷢', '𖭂', '𔂜', '𝥷', 'ꓷ',
+    '𜳫', '𛃤', '𑦵', '🏕', '⍮', '𒋁', '\u{11f36}', '𓬦', '𒎃', 'ᓗ', '𔌙',
+    '𝛘', '𖡜', '𘩇', '𜽐', '濫', '卉', 'њ', '𓎢', '𞓰', '￠', '𜰙',
+    '𒐍', '𐤑', '邏', '𐼧', '𔆜', 'ᬾ', '𓚥', '𝥎', '漣', '𐍰', '𘡾',
+    '𖄊', '𒒂', 'ᔦ', 'ᔭ', '𐵂', '𖨥', '𓘨', '𑢨', '㌞', 'Ⲻ', 'ꑟ', '┺',
+    '𝋠', 'ᣳ', '⍦', '\u{11046}', 'ꠠ', '鼻', '𓺐', '𨜮', '𑛜', '⣘', 'ﱑ',
+    '𑘝', 'ᆓ', 'ｳ', '𖧳', 'ᬜ', '𑚜', 'ꅔ', '𓽮', '𘮪', '˦', 'v', 'ォ',
+    '絣', '🏆', '𞲠', '⠱', '＼', '𒀷', '𔍓', '𛂒', '🪁', '₇', '⮴',
+    '\u{e010d}', '𒀏', 'ဝ', '𞸅', '𔋌', '𝥮', '⧌', 'ꍝ', 'ꍗ', '𝦭', '𑩵',
+    '\u{180b}', '𓑭', '𐭋', 'Ϧ', '\u{a926}', '\u{aaed}', 'ᬤ', 'ꃋ', 'ﵑ', '𑴗',
+    '🖰', 'ꂰ', '𞴦', 'ꂷ', 'ꃂ', '\u{11371}', '𐄷', 'ݸ', 'ꦜ', 'ଽ', '\u{1074}',
+    '&', 'ꅰ', '𐐦', 'ॸ', '𐭐', 'ӊ', '⸗', '𒐾', 'Ō', '🩥', 'ধ', 'ᗚ',
+    '𐙺', '𓙱', '𓆄', 'ز', '⸷', 'Ԯ', '𞲌', '𝍬', '𔍝', 'र', '𜾩', '𘢘',
+    '𓀷', '༲', '𞺁', '𛀂', 'ﲤ', 'ൿ', '𞡊', 'ᘐ', '𓔛', '𔎩', '⑽', '𘠧',
+    '🎏', '\u{16129}', 'ࠉ', '⡊', '𓇷', '𓆂', '𓭫', '𓍎', '➽', '𑃟', '⬳',
+    'Ṥ', '᪦', '𐊱', '𝘊', '𘥛', '🥤', '𛰬', 'ᵣ', '𓩵', 'ḷ', '𘰟', 'ꪈ',
+    '𓖓', 'ﰫ', '𓦀', 'ﳘ', 'ⴻ', 'ꔓ', 'ꫳ', '𖨍', 'ᵂ', '𔇳', 'ﭑ', 'ᶕ',
+    'ђ', 'ꦊ', '𓢊', '⼺', '篆', 'ᑜ', '𖦄', '𓅅', '᠀', '𜽘', '𝈩', '⺈',
+    '🎛', '暴', 'ﳙ', '𛱅', '𖢐', '൮', '𘫘', '⒗', '𓝦', '𓳳', '𐞞',
+    '𔐼', '\u{1daa6}', '𔇼', '絛', '𖤗', '𓕢', '⢙', '⼽', '𔘁', '⤑', '𐘠',
+    '𓶅', '𒄜', 'ぱ', '𐭢', '\u{11f5a}', '𝡤', 'ᄄ', '𝇉', '⋬', 'い', '𜹞',
+    'ꄎ', 'ⷌ', '𖹒', '𔀮', '㈥', '𓞁', '𑯂', 'ˈ', '𓘲', '⊿', '𛆌', '꛵',
+    '︷', 'ቼ', 'ऊ', '露', '🯆', '🝢', '🝞', '⌤', '𓀕', '𐍁', 'ᆠ', 'ᆍ',
+    'ᢴ', 'Ẓ', '𖬉', '㌻', '𓔂', '𔇫', '喫', '🡁', '𞁭', '⸍', 'ꔅ', '𐛯',
+    '𝠃', 'ﳂ', '\u{1c2c}', 'Τ', 'ｍ', 'ᰧ', '🍲', '𥐝', 'ɘ', 'ｑ', '𒇝',
+    '𒃦', 'ⴋ', '𓺂', '𒆨', '𝈻', '⎍', '𑐎', 'R', '𑄗', '⣴', 'ㄋ', 'ᡒ',
+    '𛃹', '𛃽', '𘩗', '⪜', '𓓽', '𒅪', 'ﭘ', '𐝡', '𑥆', '🠑', '𜺘',
+    '𔖴', 'ᅣ', 'ᅨ', '䷈', '꣔', '⟱', '𒒼', '𐞊', '𑨡', '：', '𔔶', '𓧌',
+    'ὶ', '𓷖', '𓃹', '𓣀', '𘫈', 'ﶛ', '𐗟', '𝂔', '🞦', 'ꀔ', '𑍐',
+    'ꙣ', '𝀏', '𐭘', '\u{fa0}', '🩁', '𝖧', 'ა', '𑫫', '𔑫', '⤧', '𝍑',
+    '✣', '𖽻', 'ů', '𖽝', '𖽕', 'ⰵ', '𓁖', '𖽪', '\u{1713}', 'ꯣ', '𝧃',
+    '𓋼', '܈', 'ꎭ', '𞓶', '𓨬', '𒃶', '\u{93c}', 'ਵ', '𜴒', 'ꁈ', 'ﻋ',
+    '㊃', '𐵠', 'ꌠ', '⍧', 'ꤿ', '︳', '𝕜', '𘩭', '㆖', '㈊', '𓢀', 'ṑ',
+    '𜺈', '𓟭', '𐍆', 'ê', '𓍲', 'ⶻ', 'ろ', '𛁮', '𑱿', 'ꀆ', '𘳏', 'ტ',
+    '\u{1da28}', '𓔈', 'Ӄ', '😱', 'ᔺ', '𛊨', 'ꯈ', '𒓖', '⪐', '𐹸', '┙',
+    '𓢕', '㉨', '؍', '𓎹', '☼', '𖥧', '∵', '🭏', '❇', '🍈', '𒐸', 'ꗂ',
+    'غ', '𒅇', '𒂞', 'ﳗ', '𝄟', 'ꖾ', '𝠟', '╴', 'ꙛ', '\u{e011f}', '𘣗',
+    'ݨ', '𓂀', 'b', '塚', '㌆', '𓘷', 'ꈯ', 'Ⳏ', '𑄢', '𔖑', '𖹖', '📓',
+    '𐴈', '𔁕', '⿺', '𘫶', '°', '🐾', '⤢', '\u{324}', '𑍂', '\u{fb2}', '𒅐',
+    'Ṅ', '𓮥', '𓮩', 'ॎ', '𓂷', '慈', 'ꬻ', '\u{e005e}', '𓮻', '\u{fe0f}',
+    '𓼢', 'ㅢ', '𓮪', '𔃑', 'ʦ', 'Ⴤ', 'ഡ', '⫁', 'ꯃ', '︵', 'ʆ', 'Ӂ', '⁂',
+    '𝂏', 'Ꮼ', '𝟡', '𓶄', '𝀉', '🭑', '𝥠', '𞺮', '𝞚', 'ﰩ', '⿰',
+    '🁬', '𓇤', '𓩛', '―', 'ᚒ', '𖢔', '𖦼', '𓔓', '𑪸', '𓷻', 'Ɤ',
+    '𜶻', 'ഥ', '𛆓', '𒐻', '𝍇', '𐀋', '🂫', '𓼅', '᪆', '🞶', '\u{1da07}',
+    '⇔', '\u{e019f}', '⼟', 'ﾁ', 'ꨆ', '𑱑', 'ঠ', '𝄶', '𓸎', '⣪', 'ཟ',
+    'پ', '\u{1de1}', '𜷆', '𓣭', '𐦫', '𞴋', '𔌤', '𝙳', '𐽵', '˹', 'Ͱ',
+    '𑤓', 'ꂵ', '🡼', '𞹲', '\u{1b3d}', '⒇', '𑩲', '𔏦', 'ꮨ', '𑽙', 'ꎡ',
+    '𝆶', '𑱓', '🍝', 'Ὑ', 'ꦮ', '𐞷', '⌀', '⯇', '𐅬', '🈩', '⠧', '𓚑',
+    'ᆔ', '𓚉', '𓚅', '⭃', '𒃣', '𘰲', '𒀀', '𝤚', '𒔅', '𑽆', 'ዡ',
+    '𛆸', '𞗑', '˼', '𑊟', '𒓍', '𓄫', '⣐', '᠃', '𝐻', 'ⵅ', '𝦝', '🚋',
+    '༑', '⬟', '𖥯', '𒈫', '𝁄', '𔗡', '\u{1e2ed}', '𓛌', 'ṉ', '🡭', '᧳',
+    '\u{6ea}', 'ᯙ', '𔅟', '㈽', '᭶', '٧', '🁿', 'ᅋ', '🪡', 'Ⳟ', 'ᵺ', '𐳪',
+    '⥁', '𖼉', '꩕', '🫥', '𘩵', '⤾', '𑛟', '𐋎', '≷', '⠸', 'ṇ',
+    '\u{11cb0}', '⪡', '𓈴', 'ᰦ', '𐑋', '𒋼', 'ﱚ', '＆', '𖬈', '🎸', '⡢',
+    '𞺑', '𛉃', 'ꍶ', '頩', '㏂', 'ꍷ', '𖥝', '˛', '𐳦', '𐳠', '𐳧', '𖤀',
+    '𓽹', '𐭣', '𛊸', 'ΰ', '𤾡', '𜾲', '𐏍', 'ꦾ', '૩', '☩', '𒂀',
+    '𐘋', '𑦪', 'ꉁ', 'Ψ', 'ᾛ', '𓮾', '𐊰', 'ጂ', '𖥃', '𐂂', 'ጅ', 'Հ',
+    '𐙘', '𝚀', '𒎎', 'ཙ', '🡯', '\u{17ba}', 'И', '\u{11ca7}', '🤚', 'ꍨ',
+    'ꌓ', '\u{110b6}', '➒', 'り', '⺮', '𐤮', '𐺖', '⣮', '𝋑', 'ⴐ', '𜰖',
+    '⫥', '𘦙', 'ᓥ', '𓗓', '𐗌', 'ㅖ', '𘱙', '؈', '🠶', '𘱕', '⚕',
+    '\u{e003d}', '\u{1da58}', '𑆇', 'ﲲ', 'ხ', '⢆', 'ᖴ', '𑪜', '㇥', '🗊',
+    'ꘞ', '｀', '𝆮', '𝁩', '𐵟', '༁', '᭔', '⣠', '𓢿', '𐦱', '\u{16af1}',
+    '𑤥', '䷸', 'ꎻ', '𓇫', '𐛋', '🅩', '𛊵', '⁄', 'ꔐ', '𝔴', 'ぴ', '𐳩',
+    '𑚧', '𝓍', '⎗', '𔋥', '５', '+', '⻣', '⊩', '𝓷', '\u{dca}', 'ぜ', '𑤫',
+    'ᡲ', '𝡍', '𓖱', '𝓻', '㉤', '꠰', 'ῂ', '𘯖', 'ꨇ', 'ꆏ', '⁖', '🌏',
+    '𓓝', '𛀵', '᭨', '≶', 'ナ', 'ネ', '《', '𔋊', '൰', '⏛', '⾹', '𞻰',
+    'ﻩ', '𒋡', '𖼃', '𘲄', '🐣', 'ໂ', '🤺', '𓓐', '𝡯', 'ᴌ', '🎀',
+    '𓹷', '🮿', '𑃒', 'ᕴ', '𛄊', '𔐪', '\u{a8e7}', '𞓷', 'ꋾ', '🍒', '䷛',
+    '⣇', '\u{eb6}', '𑈡', '𔃔', '𝂣', '𐺜', 'ݜ', '𑍞', 'ꉵ', '㌮', '𛇆',
+    'ݫ', '𘡪', 'ᡈ', '廉', '܅', '浩', '𔅏', '⛅', '⨡', '𓙔', 'ꚢ', '🅰',
+    '𐲪', '⩝', '𜲐', 'ኩ', 'ޕ', '𝕿', 'ᶢ', '𛇏', '𓥒', '𓈼', '𓈸',
+    '\u{8ee}', '✉', '≭', '𓴲', '꒞', '\u{1da3f}', '๐', '𓲟', 'Ķ', '🥱', '𛂵',
+    '𖢲', '𓮝', 'ᮈ', '𑛒', '𔓑', '𣢧', '㊦', '𑅥', '𝧞', '𓊗', '🐢',
+    'ො', '𐼏', '🗻', '𒂪', '௵', '𑃀', '𐚸', 'ⰻ', '𞥕', '𓏚', '➾',
+    '𜸺', '𖠕', 'ᚬ', 'ﾪ', 'ᛃ', '𑫆', '⿆', 'Ǧ', '𓈄', '႒', 'ඔ', 'ꂭ',
+    'ԓ', '🟋', '🞭', '𐡻', '練', '華', 'ꈳ', '𓳄', '𜾍', '𐐈', '\u{1daac}',
+    '𓯉', '𖪭', '𓯁', '𓢵', '𞄅', 'ώ', '𘬟', '𑦯', '⦴', 'ބ', '𜽚',
+    '🢠', 'ꄞ', '𑊑', '🖽', '𓠹', '𓠾', '𝁭', '𔖥', '𝄲', '𓻌', '⌥',
+    'ġ', 'ᢐ', '⏟', 'ꇧ', '𒀦', 'ዹ', '𓔴', '𔐅', '𘦥', '𘤋', '˰', '⽥',
+    '⁜', '𘦝', '𘦡', 'ꩨ', 'ﱀ', '⫆', 'ᐳ', '𓹤', '⾓', 'ꍉ', 'ꐚ', 'ﳷ',
+    'ڶ', '𑖤', '內', '\u{1c36}', '𞤳', '⯃', 'Ꞷ', '𓼩', 'Ữ', '𖠤', 'Ჲ',
+    'ᣟ', '𐤒', 'ꬉ', '𝛡', '♻', '🖮', 'ᾭ', '𓣞', '🃑', '𐹠', 'ﶸ', '𝟨',
+    '\u{11132}', 'ռ', '🂬', '𓶈', '⦙', '𒊶', '┉', 'Ø', '🧐', '𔆍', '෮',
+    '𑤃', '𐊂', '🄯', '𓚸', '᪥', '𘢾', '㊇', '𓼣', 'ꌷ', '𝄂', 'ข', 'ꁘ',
+    '𜽟', '㇌', '\u{1939}', '𛂗', '⨃', '🙴', '◺', 'ࢳ', 'ⵤ', '𘰁', 'ᴿ',
+    '及', '𘡸', 'ﳺ', '\u{1343e}', 'ʃ', '🨠', 'ド', '┅', '𔖷', 'ᶫ', '𐠱',
+    'ꍂ', '𒃫', '⑬', '𝌵', '𑜏', '🖒', '𓠴', '𐢈', 'ሼ', '🨆', '𔅛',
+    '𑼟', 'ᗢ', '\u{10a3f}', '𔋿', '𒀝', '𓿔', 'ω', '䦕', '𐶄', 'ᢁ', '𐊉',
+    '🁚', '𝜳', '𑠑', '𜹷', 'ᖻ', '⋒', '𛇘', '⡸', '𑶎', 'Ὂ', '𖫅', '⮜',
+    'ꫫ', '𝆎', 'ᛶ', '𝚓', 'ℙ', '\u{8e7}', 'ℍ', '𖢢', '\u{e0040}', '𔔌',
+    '𔑑', 'אָ', '﹎', 'ᱍ', '𝂺', '🗱', '𐞲', '\u{aabf}', '𐅝', 'ᾂ', '🬝',
+    '⩡', 'ꍸ', '📷', 'ꚝ', '𒈯', '𒔝', '𝖛', '𝖗', '𝖓', '꒼', '\u{1da68}',
+    '„', '␆', 'ቦ', '𖿠', '𓼞', 'ꔀ', 'ᘢ', '𛀦', '🎅', '𖄌', '໗', '𖠆',
+    '᮲', 'Ἥ', '\u{11438}', 'ꧽ', '𒑤', '𓙻', 'ኈ', '𖬊', '𔌹', '𝕚', '𝞻',
+    '𑽗', '⫝̸', '𓵬', '\u{e38}', '既', '⸘', '🜸', '㌡', '┹', 'ᘄ', '善',
+    '𐋲', '𘥶', '⫰', '➰', '𓺳', 'ዤ', 'ﰆ', '᰼', '𘬍', '𞀿', '˅', '⛹',
+    'ᵭ', '𓯕', 'ꧢ', '⒲', '↾', '𘱺', '𓜸', '𔔮', '\u{bcd}', '𔂧', '🙼',
+    '𔓮', '𜾳', 'Ɉ', '𑧟', '𝁪', 'ꗪ', '𐪝', '\u{f73}', '𐊣', 'ỡ', 'ヽ',
+    '𓯚', 'ᳪ', 'ﲙ', 'ሀ', '𓔦', '𐍛', 'ⅴ', '⭮', '👲', '\u{8e6}', '𐭲',
+    '𒀠', 'ⶓ', '𘱹', '𓒔', '\u{740}', '𒀗', '𝙐', '𖠁', '𐗎', '𐀱',
+    '\u{11f01}', '𖄃', '𖾟', 'ᵅ', '𝐒', '㎴', '𒕂', 'ᴤ', '🕞', 'ⷋ', '𑣥',
+    '➘', '𝦡', 'ᣬ', '𝖜', 'ꃗ', 'ᖄ', 'ꃕ', '߉', '𝖈', '⻗', '𓦟', '𓧹',
+    '金', '𓐉', '𐂑', 'ꙑ', 'ﮣ', '🪄', '\u{cca}', '𓼾', '𒓁', '᧞', '𞥃',
+    '\u{a3c}', '䗹', '𐠋', '\u{6eb}', '𓣢', '⼰', '𔓘', '𒓺', '𑆬', '𓤽',
+    '\u{102e}', '\u{f9e}', 'ꤽ', 'ⴂ', '𔗛', '𞤚', 'ꏄ', '𐠸', 'ᴘ', '㍺', '🚢',
+    '🬿', '𓘮', '𜾜', '𐼾', '𜴕', '\u{5a4}', '𘡮', '𔄯', 'ᾓ', '𝒥',
+    '\u{340}', 'ڵ', '𔗚', '𓿠', '𖦥', '🦟', '𐂜', '𝚘', 'ᨷ', 'ᖞ', '𔂽',
+    '𔕕', 'ퟸ', '𖦴', 'ￄ', '𜱦', '𔔙', 'ⱹ', '𖹬', '𞲬', '𓿢', '𞄈',
+    'ᇭ', '𓲢', 'ݳ', 'ᢺ', '\u{1da3d}', '⧔', 'ᶙ', '𐑰', '𑊌', '𝧧', '廊',
+    '𝥐', '𐙤', '𐃵', '𐃴', 'ꝙ', '𓒱', 'נּ', '🬻', 'ꭐ', '𜲃', '𝇛',
+    '\u{5a2}', '㏰', '𖹃', 'ᨫ', '𞴜', '𖡢', '𝘅', '𜳵', 'ꐺ', 'ｺ', 'ꬮ',
+    '𐅆', '𝀵', 'ꎚ', '˕', '𔌝', 'ᶒ', '𛂲', '⌵', '🈛', '𞤌', '𐒋', '⇣',
+    '𝝾', 'ꗹ', '𐧀', '‶', 'ದ', '\u{10a02}', 'ฎ', 'ᥢ', 'ꔫ', '\u{114b5}',
+    '🅉', 'ꈥ', '𒆓', '𔄋', '𑆿', '𓲐', 'Ո', '⏖', '≿', '𓠚', '𓠔', 'ἴ',
+    '𓚂', '\u{711}', '𛂕', '࠺', '𔍅', '𞤛', '𓃀', '𓂻', '𐨑', '𓯮', '𛱶',
+    '𒉰', '🮷', '𐽶', 'ṻ', 'ⴱ', 'Ɒ', 'ꀢ', '𓾉', '𐴝', '𝆺𝅥', '𓍤', 'ꠔ',
+    '𑌜', 'ꇙ', '𓄮', '𓾴', '≐', '㎧', '𘭥', 'Ь', 'ꝺ', 'め', '𔆂',
+    '\u{113ce}', 'ḅ', '≬', '𘲗', '𘲓', '\u{5b2}', '𞣀', '𓡋', 'ꪌ', '𖡬',
+    '\u{1da18}', '𝚙', '𑒤', '𓭞', 'ᦱ', '⇀', '𓚕', '𑌧', '🗵', '𘬜', '㇍',
+    '𐋇', '𔀳', '𛉚', 'ڏ', '🝳', '𖡐', '𜶕', '𓧵', 'ᣑ', '⛬', '喙', '螺',
+    '洛', '𖵫', '𐦩', 'ṯ', '𐖍', '𒐧', '𑇡', 'ⷎ', '⡠', '𔎁', 'ⶁ', 'ꁢ',
+    '\u{a94e}', '⌶', '𓎪', '𒑭', '⺖', '𓨄', '𓢨', '惇', '𓢠', '𓨍', 'ῦ',
+    'ꈱ', '⿎', '◅', '𦞧', '𝅉', '㌰', '𑜂', '𖫣', 'ﲩ', '𐅜', '💧', '𔋣',
+    '𐋃', 'ꈌ', 'ꆡ', '𓸰', '墨', 'ꒅ', '𒇗', '𘰪', '𞴖', '𝙀', 'ⶢ', '𓘌',
+    '𐒢', '𖠀', '𖬔', '﹘', '９', '𑧍', '𛈴', '𐬳', '𘯦', 'ၯ', 'ߠ', '┨',
+    '𐂱', '𖄎', '𖨉', 'ꯢ', '🕂', '𝗮', '𔌂', '𔌆', 'ٱ', 'ඈ', 'ᒝ', 'ส',
+    '♇', '𓠖', '𐞘', 'ᵖ', 'ȿ', '𞗳', '𑈲', '\u{11c35}', '𝀾', 'ꁫ', '\u{acd}',
+    'ể', '𔃢', '𘣫', '𝣧', '𝃊', '𑦫', '𒊧', '𜽡', '🮰', 'ᕠ', 'ꦘ', 'Ֆ',
+    '𔐛', 'ฦ', '𞡘', '𛇰', 'ۯ', '𐜚', '𝀄', 'ג', '📎', '𔃫', '𖽤', 'ɝ',
+    '𐫕', '𓮰', '𓗖', 'ﲄ', 'Ԗ', 'ꂛ', '𑢹', 'ஸ', 'ዕ', '𜺮', '𓓫', '⒄',
+    'ⲣ', '🤒', '⬃', '𒉘', '𖠨', '𓕳', 'ﰎ', '𛂹', '𑖾', '𓕼', 'ꁬ', 'ᗔ',
+    '𔓇', '𛁇', '𒑮', '𔉃', '⠨', '𐤱', '𐤡', '𜲪', '𞗶', 'ꖩ', '𓜰',
+    'ꡓ', '𘡓', '𝡖', 'ꃧ', 'ꃦ', 'ꃩ', '𔂖', '藺', '\u{1df5}', '✳', 'ᮂ',
+    '𑤙', '⽝', '𓪥', '𐔁', '媵', 'ᵙ', 'ᶪ', 'ᘜ', '❵', '🯟', '\u{e0101}',
+    '𝛃', 'ꘆ', '𔕒', 'ጜ', 'ꊐ', '𘩑', '𐡒', 'ᡛ', 'ɡ', '䚾', '𖢜', '🦠',
+    '𔐣', '🧟', '𛆪', 'છ', 'ى', '𑪝', '𓖡', '˟', 'ṽ', 'ᐛ', '𝩶', '␃',
+    '𘰕', 'ݩ', '🙶', '🤋', 'ꢯ', 'ᇅ', '𓱢', '𘯗', '⼫', '𖪻', '𝧫', 'ꃑ',
+    'ľ', '𜾪', '𑒎', '𑰩', 'ƌ', '𓎓', '\u{c3f}', '\u{c46}', '㌾', '𘰚', 'ὁ',
+    '𒑟', 'ᵊ', 'ᐲ', '٤', 'ᓈ', '𑠋', '\u{2000}', '🢳', '⨽', '𑂓', '𓟊',
+    'ᯐ', '\u{e01d6}', '𐎸', '𓒿', 'թ', 'ປ', 'տ', '𓧊', 'ꊻ', 'ꯔ', '𑃤',
+    'ᩆ', 'ະ', '𔎑', '溜', '𖦭', '⒩', '𑌫', '𝟰', '῀', '🚌', '⎚', 'ᤗ',
+    'ꑴ', '𘦂', 'ꀫ', '𜱺', 'ä', '𛃣', '௫', '書', '𝝚', '⎄', '𓄒', '㎠',
+    '˖', '𒓈', '𖩾', '𑄬', '𔋓', '\u{1cf05}', '𑍣', '𜹋', '𔋜', 'ⓞ', 'ⓚ',
+    '𓐥', '𔗑', 'ꑛ', '𔉏', 'ѽ', '𜳑', '₵', '𔇴', 'ṣ', '𛂥', '𝖐', 'ℜ',
+    '\u{59d}', '𞺓', 'ꃹ', 'Ⱐ', '♍', 'ᾚ', '𔐫', '𖵟', 'ⱘ', '𞡁', '⳪',
+    '\u{110c2}', '𖩨', 'ᑆ', '╪', '𑌇', '𓖗', '𔓦', 'ࡧ', '𚿷', 'Ῠ', 'ᜰ',
+    '𝓡', 'ᡗ', '\u{e011a}', '\u{11f37}', '⋕', '⠒', '𝢖', 'ی', '₻', 'ભ', '٫',
+    'ꝳ', '\u{11374}', '𑚉', '𜾺', '𒃲', 'ꝧ', '𞹗', '𔕙', 'Ö', '\u{e0054}',
+    '𜵩', '˥', 'ᄋ', '𑖙', '಄', '𓱍', '𞤊', '⑪', '🂿', 'ר', 'Ԡ', 'ꖒ',
+    '߹', '𓉇', '𑆏', '🛝', 'ǭ', '\u{73d}', '🦓', '𒍀', '𓋎', '᨞', '𑴌',
+    '𔍪', 'ｆ', 'ｚ', 'ꕘ', '⩭', '𘰍', '𔈬', 'ᠶ', '\u{e0147}', '𒇁', 'ꙫ',
+    '🯚', '𓙈', 'Ф', 'ᘃ', '𒈇', '𞸘', '𓍷', '𔈱', 'ᦲ', '𖠜', '⅚', '⽔',
+    '𘮀', 'ｄ', '𝇃', '𜾦', '🭗', '𛆥', '⧨', '𜷵', 'Ꚑ', '𜱈', 'ѧ', '𓊺',
+    'ꙁ', 'ꩦ', '𝖉', '🈘', '𞁜', 'ᙢ', '𓣗', 'ﮁ', 'Ꮝ', '𖼻', 'ߔ', '𑵾',
+    '𔈾', '𖭥', '😡', '𛆺', '𑅮', '൪', '𐙑', '🃂', '𛱕', '𓇐', '𞴧',
+    '⍞', 'ꉥ', 'ᗿ', 'ᵚ', '懶', 'ﶦ', '𒉭', '𛁓', '𐊋', 'ꌔ', '𐊖', '𐼔',
+    '𓿗', '⓱', '𑨺', 'ꌩ', '𖡱', 'ᩲ', '量', '梁', '𝙥', '𓨭', '𓇦', 'บ',
+    '𖦏', '𓎇', 'Ϳ', 'Ⱨ', '𓺱', 'ﺏ', 'ꎎ', '𐮋', '𓭵', 'ⵑ', '\u{6e4}', 'ʔ',
+    '㊺', '\u{eb1}', '𒁫', 'ᐮ', '忹', '⯸', '𝧮', '𓍌', '\u{e01bc}', '𓆣',
+    'ꊝ', '辰', '🔋', '𔎨', '𐔘', 'ఴ', '𖠗', '𓬱', 'í', 'Ẻ', '𒄉', '𝃟',
+    'ᴧ', '𐡪', '𘦸', '𓘣', '忍', '𐐫', 'ꣴ', '𞴑', '\u{73a}', '𒋰', '።',
+    '℣', '𓒓', 'ԍ', '⒖', 'ẍ', '𓝚', '൙', '𓋡', '𒓃', '𔎄', '◽', '𔌢',
+    '❩', 'ᜍ', '↑', '櫓', '𔂛', '𖄗', '𝣂', 'ꦺ', 'ꢲ', '𔗝', 'અ', 'ም',
+    '𜴿', '𓅚', '𐲣', '𓙵', 'Ѷ', '⾕', '𝌘', '𑊾', '\u{11725}', 'ᣕ', '𓘋',
+    '𓦂', '\u{113e1}', '₲', 'ᆢ', '𛉙', '𓟻', '𖽽', '𓀣', '𘧠', '🠞', '𓶾',
+    '𔕜', '𘢱', 'ᤔ', '𖦌', '𒉄', '𧥦', '𔄘', '𐃹', 'ﳨ', '𖦘', '𓂉',
+    'ャ', '𒍞', '𝠖', '𜴄', 'ȟ', '⫐', '㊸', '𐹬', 'ﰄ', '𓗿', '𒈏', '🞔',
+    '👆', '᧹', '᧨', '\u{1cf2d}', '𔃃', '𖧬', '𐄋', 'ョ', '\u{5c4}', '𘤎',
+    '𑛕', '𑆍', '⽿', '💣', '𝼒', 'ț', '෴', '𖤿', '𖪁', '洞', '\u{ce2}',
+    'ⳡ', '𘤃', '𛋱', '㎹', 'ᛀ', 'ᖭ', '𖬦', '⾃', '🀥', '𓽨', '𞊛', 'ⁿ',
+    '𜲋', '𖡻', '\u{e014b}', '𘩄', '𓧻', '🠭', 'ꪽ', '𑒓', 'ᮊ', '𜰘', '𐝌',
+    '╂', 'ᨳ', '𓩭', 'ཚ', 'ꞿ', '▒', 'ꢹ', '🯋', '🏷', '𘭲', '𝋬', 'ퟺ',
+    'ﭦ', 'ꕩ', '𓧖', 'ㅺ', '𝂛', '\u{11d40}', '𑌆', 'স', '🅳', '𓠥', 'ば',
+    'ꞕ', '𔔚', '𔐏', '𜶍', '🦳', '𔇄', '𜾘', '￼', 'ⓔ', '𜷾', '𖤥',
+    'ⰶ', '℻', 'ቕ', '𔗙', '᧚', 'ꤼ', '𔋁', 'Ṩ', '𘧙', 'ᚆ', 'ⴾ', 'ﴵ',
+    '⋽', 'ŧ', '𐚤', 'ꊢ', 'ɾ', '𓲧', 'ڥ', '𓿣', '𘰱', '𓄞', 'ꈋ', 'ⶏ',
+    '𓗇', '𒅡', '𜶏', 'ণ', '\u{202d}', '\u{1d1ad}', '╫', '𓧯', '𔂥', 'ᔊ',
+    'Ʈ', '🌋', 'Ẅ', 'ڋ', '┈', '\u{1b02}', '𐤚', '𞁔', '⥼', '３', '𐢊',
+    '𘲑', '𛂨', 'Ч', 'ﴢ', '܇', '𝃲', '𛉡', '𝟩', '𓭗', '\u{e01c2}', '𓥴',
+    'ḛ', '𝔙', '𐕈', '𘲞', '𘪧', '𑚞', '𓸅', '𒿚', '𒾰', '𒾲', '𝕨',
+    '𐕗', 'ﴸ', '𘧂', '𖣅', '\u{ff9f}', '⠿', '𝅌', '𑚕', '𓊈', 'ꫣ', '𛊔',
+    '𓋦', '☪', '𒁡', '🖑', '𖦦', 'ꮎ', 'ᴞ', '𑘧', 'ꌒ', '≩', 'ꮟ', '𓾤',
+    '𛉏', '𓤎', '𒿗', '𓘊', '𖪖', '𓥿', 'Ũ', '㈜', 'ذ', '᪭', 'ꕋ', 'ꄮ',
+    '𐴙', 'Տ', '𑼗', '𑋔', '𐜘', '𔒛', '\u{1d17f}', '𒓂', '𑣞', 'ꀬ', '𓐬',
+    '𖽧', 'ꋘ', '𖽢', 'ݤ', '𝍤', '𓫱', 'ಧ', '𖽣', '港', '瓊', '🏈', 'ﴉ',
+    '᥌', '🆎', 'ᒰ', '⨱', '𓐂', 'ၤ', '𘪹', '🚼', '𜾐', 'Ｙ', '𔑒', '𐧠',
+    '𘤏', '𓓵', '𓪑', 'ᶓ', '𔁥', '𐄧', '𐀈', '𓋜', '𓓁', 'ﴥ', '𔔜',
+    '𐄂', 'ᣲ', '𒃍', '𜲴', '𖠩', '𐢄', '𜲷', 'ꇑ', 'ꇎ', '⍽', '⅑', '𓭽',
+    'ⲑ', 'ㄫ', '🬴', 'ﹿ', '𒈅', '🕏', '𓷬', '📆', '𒌆', '𖬀', '𑌬',
+    '𝑯', 'ꡋ', '🥛', '𝪀', '🆞', '🌮', 'ꙅ', '𓽋', 'ﮡ', '𖡯', '🯦',
+    '𘣡', '⒡', '𖧒', '⠢', '\u{e01c7}', '𔕱', '𛊬', '𓔗', '𞠗', 'ꦗ',
+    '\u{93a}', '𑠙', '𝩻', 'ᐉ', '𓷌', '💸', '𔉍', 'গ', 'ꞡ', '𝦜', '𛄑',
+    'ᆲ', '⦲', 'ﴑ', 'ႉ', '⩹', '𘧝', '𛊧', 'և', '𔊺', '𔁼', '𐩄', '𓐋',
+    '🙐', '𓼌', '⺩', '𑴤', 'ɯ', '𑜠', '🫆', 'ഞ', '𜵤', '郎', '𑐉', '▼',
+    '𓂩', 'ﻵ', '𝄔', '𐼑', '𘩙', '႐', '𝧂', '⚱', 'ᄔ', 'ꟙ', '\u{11041}',
+    '𓩍', '𔀭', '𑘅', 'ꭨ', 'ꜝ', '𞴸', '藍', '蠟', 'ᘭ', '𞺷', 'ᤈ', '𓎞',
+    '𓹱', 'ತ', '㋒', '𝆍', 'ꛌ', '𐙠', '𐴴', '𐙪', '⍤', '𓩀', '🯰',
+    '𓄠', '𐓋', 'ൾ', 'ꈊ', '€', 'ᵬ', '𓧝', '″', '𞴆', '\u{1da50}', '𘪿',
+    '𘡍', '𓴣', '❃', '𐜱', '𓉯', '𓹾', '፣', '𛄗', '𒈵', 'ଏ', '𔊘',
+    '𖣁', '𑆗', '𜺞', '🧪', 'Ჶ', 'ɺ', '𑋴', '𓁞', 'ᝨ', 'ꄿ', 'ퟍ', 'Ꙛ',
+    'ᚽ', '𜶬', '𛉰', '\u{31b}', '﹀', 'ﭴ', '𛊂', '𘲍', '⨉', 'ᘾ', '𔁨',
+    'ጶ', '㉢', 'ᖨ', '𔐥', '📳', '⮇', '🤴', 'ᔐ', 'ᔕ', '⊲', '𔍂', '𓲵',
+    '𓟽', '𓇏', 'ꆪ', '𜰁', '𜵳', '᭘', '✨', '𒑜', '𜽯', '🐀', '𑖫',
+    '🚱', '𑫅', 'ᄫ', 'ϲ', '𝢀', '⼤', '𖢥', '𒅍', '\u{f71}', '𘪙', '𓣷',
+    '𓣥', '﮷', '᭎', '𓞰', '\u{112e9}', '𝓥', '宅', '𓲼', '𒾮', 'ﹳ', '𔕪',
+    '\u{1163f}', '𑧃', '𐕂', 'ڇ', 'ห', '叫', '𑊷', '𖩘', 'ﶘ', '𔓋', '🨛',
+    '🢡', 'ޗ', '𓍻', 'ഝ', '🂢', '𔖇', '㋭', '🥒', 'ऌ', '𖽘', 'ꘛ', '𓌐',
+    '𓕀', '𓕄', '𐅗', '⣱', '𓙶', '᭵', '𓥎', '𝤟', 'ᾜ', 'ຖ', 'ಚ', '𒉪',
+    '㍠', '⇠', '▥', '☊', 'ꘫ', '𛀇', '諭', 'ꉀ', '📈', 'ᴲ', '\u{1cf36}',
+    'ⓐ', '𜳪', '⩲', '𓵑', 'ϼ', '𐿠', '㏴', '𓛵', '𝥢', '𘫩', 'Ĕ', '㍀',
+    '🮡', '𐚵', '᳓', '♦', 'ଙ', '𓔃', '𞊫', 'ﺖ', '𛃓', '⯬', '𒃴', '𐭱',
+    'ᒋ', '𝧏', '⛎', '𔗖', '㈮', '𒒡', '𞲳', '🪛', '𝍁', 'ᑱ', '𓯧', '⩷',
+    '⍍', '𝦊', '𐂹', '𐽹', '🦤', '𔆠', '𓄍', '𐂊', 'ର', 'Ԩ', 'ᝍ', '𖠋',
+    '␘', '⑆', 'ថ', '𘦦', '𖼩', '𖣂', 'ⱗ', 'ଓ', '$', '◫', 'ઘ', 'ꚕ',
+    '𖬎', '🝓', '𑰥', '𐡢', '🭟', '㋋', '⌚', '𞟼', '🫙', '卿', '𘫛',
+    '盧', '𒇋', '𑴢', 'ᖙ', '𐚡', '𓰇', '໔', '𝐐', '𒋩', 'ⵒ', '𐊍', '┞',
+    'ﳇ', 'ꅢ', 'ꅥ', 'ໆ', '🨸', '𜵡', '🬣', '𒄨', '࠰', '𖹅', '🍶', '𓎀',
+    '𖹛', '𛰛', '🐒', '𓸠', '🃤', '🯬', '𐔏', '┫', '\u{d81}', '𝛦', 'ꜳ',
+    '🧨', '𓦝', '飯', 'ꍬ', '🈁', 'ĩ', 'ꍒ', 'ꦕ', 'ᵁ', '𝣽', '𜰶', 'ী',
+    '𝤥', 'ꙍ', 'ㅏ', '謹', '𝗩', 'ᳩ', 'ꮁ', '𐁚', '\u{89f}', '𔆰', '𒁪',
+    '⯓', '༖', 'ਪ', '\u{8ff}', 'ϒ', '𝑆', '𐠔', '𐠕', '⟬', '⣫', '🬈', '者',
+    '襁', 'ㅨ', '𝁑', '𝘁', '𔗾', '꩘', '🐍', 'ࡾ', '𓼘', 'ꎞ', 'ᵍ', '𓶩',
+    '𓌘', '\u{1ab1}', '𑒻', 'ਲ', '𞸯', 'ꍥ', '𓎒', '𐙇', 'ࢊ', '𓽃',
+    '\u{fb7}', '\u{10d6d}', '𔊤', '✗', 'ଷ', '黎', '𑄖', 'ꠛ', '𑋵', 'Í', '🭊',
+    'ி', '𖺔', '⏲', 'ﬧ', '𖾆', '𒊝', '\u{1d242}', '𐂢', '𛀤', 'ⱸ', 'ᄿ',
+    '𘨔', 'ᜯ', 'ੀ', 'ĕ', '𐇠', '𐦒', '\u{20db}', '𑓑', '𐅩', 'ꃳ', '𒁆',
+    '𝐬', 'ꮚ', '\u{957}', '𔗲', 'ԏ', '𓞟', '⯷', '𞄃', '𐎘', '𞴕', 'ￍ',
+    '𘤶', 'ꕸ', '𓆜', 'ﬦ', '⦳', '𘢣', '⻥', '豕', 'Б', '𝅛', '𐅔', '𐋈',
+    '𝀠', '𝔓', '䷜', '𑄥', '𔋠', 'Ŗ', '\u{1de8}', '⍑', '𘯬', '𑧏', 'ᾃ',
+    '𝙋', '𓱙', '𝙃', '𓍿', 'ヷ', 'ꗽ', '\u{e36}', '𞹋', '𝟇', 'ꖮ', '𞟫',
+    '₊', '𓃉', '੬', '𘡠', 'c', '𓽯', 'ﾷ', 'ﻍ', '㈒', '⼯', '\u{13432}',
+    '𔗜', 'ꕈ', 'ꏿ', '𝡸', '𐿤', '⇥', '𘮃', '𜰛', '𔑌', '𒂈', '꘩',
+    '𐮈', 'ᦋ', '𝟎', 'ⶫ', 'ሰ', '⠪', '𐎒', '𓤭', '࿋', 'ሱ', '𓷄', '⻢',
+    'ᄾ', 'ỳ', 'ෳ', '𓧂', '⦃', '᧵', 'ꞝ', '➡', '﨩', 'ブ', '𓶋', 'ᤜ',
+    '𝂁', '𔒶', 'ଦ', '⌠', '№', 'І', 'ጧ', '𑐜', '𘱏', 'ໄ', '𒁳', '⏤',
+    '𑍃', 'ꖀ', '𒇃', '𓉳', '#', '𓫉', '𜵉', '︴', 'ᓎ', 'ㅂ', '𐚞', 'ѕ',
+    '㎮', '𝀨', 'ᡪ', '⢯', '⥻', '꫰', '🉂', '𘢳', '𜾀', '𝤾', '𖵆', 'ﬖ',
+    '⠳', '𜲳', '🖌', '\u{1bac}', '፧', '⇯', '🖢', '။', 'ꔟ', '\u{1611e}',
+    '𒐘', '♠', '𞗝', '𝣳', '𔈽', 'ﺁ', '⨺', '𝕒', '𔘇', '┪', 'ʛ', '˭',
+    '𘮾', '壟', 'ꮏ', '𑓔', '𘠶', 'ᯤ', '\u{17cb}', 'ᴎ', '變', '𓘩', '𒿐',
+    '\u{962}', '𛈿', '×', '𑁥', '𓗶', '𐞜', '؞', '𑿬', '𞢎', '𛅿', '🬠',
+    '♳', '𝜛', '𝤃', '𓆙', '𔖭', 'ᾟ', '𜰜', '𝣩', '🁗', '𝜙', 'ね',
+    '\u{11ef4}', 'ﯚ', 'ᴒ', '㈄', 'ꝶ', '〜', '⦣', '\u{10d69}', '𒐁', '𝥩', '爫',
+    '𐘲', '𒾓', '\u{330}', 'ℓ', '𓴀', '𑣔', 'ꅎ', '🌕', '\u{16f91}', '𘯤',
+    'ꦓ', 'ᲂ', '𝠤', '\u{5b6}', '⥀', '𝟺', '⎪', '㈔', '✪', '𐫑', '蛢',
+    '\u{e49}', 'ጻ', '𓴆', '㊰', '𒃓', 'ꢖ', '𞺛', '🪤', '𓎖', '𓮙', 'ẉ',
+    'ཅ', 'ℵ', '𔉜', 'ꈻ', '🪧', 'ꊑ', '\u{f94}', '𐗢', '📩', 'ꃴ', 'ꎍ',
+    '𖠈', '⽳', '𖵍', '𔉓', '𐓪', '㊴', '𞋹', '鄑', 'ॶ', 'ꩴ', '𓒐', 'ᳫ',
+    '\u{1da5c}', '⋔', '𒐅', 'ﲣ', '𒿲', '𞠹', '࿄', '𓩷', '𖪚', 'ԝ', '𞊝',
+    '𛉱', 'ㆫ', '🠮', '🩋', '⇭', 'ꎙ', '\u{11c38}', '꣹', 'ꀟ', 'Ն', '𐵍',
+    '⫪', '𑈦', '𐨗', 'ࠌ', '𝌻', '𒅽', '𖣠', '𜾚', '殺', 'ꐆ', 'ꯖ',
+    '𝀛', '🜰', '\u{11cac}', 'ᵃ', 'ۀ', '𑰅', '𜶑', '𓾣', '𖩑', '⛰', '𒀅',
+    '𐛣', '𓪝', '⍟', '𓪏', '꠩', '𓧬', '𓌰', 'ꆮ', 'ҍ', '𘩆', '🁛', '➙',
+    'ⲿ', '𖭁', '𝀈', '𜹘', '⥶', '↗', '⫵', '𒾨', '౪', '𖹔', '𒈲', '𐀲',
+    '𔆡', 'ꢊ', '䷓', 'ᛕ', 'Ⓙ', '𝌓', '𐩩', '𒾑', '☖', '‛', '𛈎', '𓤚',
+    '𐄮', '㋊', '𜰎', '𝔟', '𝛥', '𜱥', '𒅮', '𝢜', '🏗', '\u{1da69}',
+    '📌', '🚀', '𘡻', '𓥼', '\u{1da53}', 'ଚ', 'ﮬ', 'Ħ', '🕡', '𐧄', 'ꂽ',
+    'ꠕ', 'マ', 'ᛒ', '⻒', '⤉', '𐖒', 'ム', '𝄦', '᭐', 'ᥪ', '\u{2deb}', 'ꢕ',
+    '𛁱', '𝒳', '𞀺', '𓬋', '𒋛', '𝗽', '⨭', '𑄸', 'ᢦ', 'ﰛ', 'ඩ',
+    '起', '𔅬', 'ह', '𐺉', '𐓷', '𒈓', 'ᦟ', '𝥹', '𑙒', '𐍃', '𐞎',
+    '𞢉', '𓐜', '𔓢', '🬥', '៙', '𛄋', 'ᕻ', '﹥', '𐕿', '\u{16121}', '𝌒',
+    '𝤧', '⑳', '🈕', '㌣', '𐖯', 'ᢋ', '郱', 'ʪ', 'ფ', '𖢻', 'ோ', '⏽',
+    '𓂴', '🆜', '𛁐', 'ࢹ', '𝠈', '\u{1a1b}', '𐠊', 'ﷶ', '\u{11832}', '𝋈',
+    '🄴', '𓿺', '𛱤', '𒾝', 'Ớ', '䳎', '𑂾', '𓫝', 'ᳵ', 'ㆢ', '𒈁',
+    '໘', '⨟', '𞹯', '𒉢', '𘡑', '𐧲', '\u{332}', '𓻘', '\u{738}', '𓉶', 'ኆ',
+    '\u{7ee}', '𝡃', '𑘂', '𞊣', '𔏡', '搜', '𝐸', '𘩅', '𖫵', '㋐', '𝍡',
+    '\u{733}', '着', '🂮', '𑐫', '𐺁', '𘪂', '\u{901}', 'ὺ', '︕', '𒓠', '𓉹',
+    'ᣊ', '𛁕', 'ꐀ', '𖠌', 'ꈭ', '𓉠', 'ꈫ', '\u{110b4}', 'ꝫ', 'ﶓ', '𓧇',
+    '🐩', '⤮', 'ﷂ', 'ᡮ', '𓒽', '𔅥', '𖭶', '𞁈', 'ᇣ', '\u{32e}', '⌑',
+    '𓓷', '⼸', '\u{a8e8}', '𐧜', '𘢠', '𑂐', '𓥈', 'ⴰ', '𓷭', '𓕫', '⨷',
+    '𜸪', '𞸝', '𐙷', '⬠', '𓓥', 'ᐑ', '𔗴', '𞢁', '𑋀', '𝓏', '𑫃',
+    'ᛰ', 'ﾓ', '𓜃', '𝓔', 'ꖧ', '＜', '\u{11c39}', '˂', 'ᴑ', '𐘟', '\u{e34}',
+    '𖧙', 'ð', '⾗', '𒉨', 'ꕄ', 'ᤄ', '𓟕', '\u{1344f}', '📚', '𓲉', 'ꕷ',
+    'ⲥ', '𘱦', 'ବ', '𓫓', '𓿲', '🟄', '𓓭', '𓓛', '𒄱', '🗈', '𔘩',
+    'ꃆ', '𓺛', '𘫣', '𑖠', '\u{1da0a}', '𐳿', '𜶦', '⍫', '🈫', '༴', '⾄',
+    '④', '🧣', '⫧', '𜸐', '𐑳', 'ﳚ', '㋇', 'ꗕ', '𓤊', '㏯', '𐵛', '𝑚',
+    '𞋟', '𜷅', '𐅹', '⾪', '𝦵', 'ꎆ', 'ᣁ', 'ԭ', 'ᆘ', '𛉖', '𒀾', '🦇',
+    '𒁮', '✍', '🅁', '𛋥', '𓀰', '🍇', 'ᇆ', '㈚', '❄', '\u{f72}', 'ᑳ',
+    '𑴰', '𑿦', 'ⱅ', '𑻵', '𝗋', '𓏐', 'ꊈ', '𝗇', '𝖻', '𓻦', '𑇛',
+    'Ṑ', 'ᄁ', '𜲦', '⅌', '𓅷', '𑫰', '🚫', 'べ', '𒾚', '𜵣', '𛆛',
+    '𐒹', '𐊬', 'ᨪ', 'ﳈ', '糒', '𓭦', '𝩷', '𐙊', 'ᆼ', '𞤻', '𖽲',
+    'ﮏ', 'ﱌ', '𥄳', '𒾴', '꧰', 'ᰙ', '𞤋', 'ᄉ', 'ᴇ', '𘢓', 'ꢩ', 'ﬅ',
+    '𝤳', 'ꖿ', '𔁮', '\u{369}', 'ۑ', '\u{1ddd}', '𘩪', '𓄼', '𪄅', '𔏩',
+    '🨑', '𓒂', '˻', 'ᇕ', 'ᜦ', '𓌌', '𓵽', 'ꆈ', '𒾠', '𐃨', 'ꁜ', '𜵕',
+    '𔎬', 'ޒ', '𐍝', '🡹', '𐇒', '𑥕', '𑗏', '𔅈', 'ꮔ', '𓄇', '𞤍',
+    '𖼠', '⦭', '𒍥', 'ᓑ', '悁', '⠗', '𐗝', '𐑮', '𛉶', '𓄭', '𒁐',
+    '᠊', 'ﷇ', 'µ', '⺱', '𘲯', '𘤼', '憤', '𔎧', 'ᐣ', '𖥙', 'ﰹ', '🍓',
+    '𘣕', '\u{1680}', '⩙', '𘠪', '\u{e0078}', '𝠚', '𓚬', '𖨈', '𞺱', '࿌',
+    '𐵾', '𜲔', 'ᇉ', '🎭', '𔕺', '㍦', '𜲾', '𛊞', '𒄂', '𓒒', '𔕉',
+    '𑈃', '𔂩', '𐕕', '𓟡', '𖡶', 'Ｍ', '𓉮', '🏩', 'ភ', 'ඕ', '𐅦',
+    '𛆯', '✺', '\u{2de6}', '𐠂', '𐭈', 'ጕ', '𐠁', 'Ȝ', 'ᓯ', 'ք', 'ﻥ', '㏔',
+    '𘲨', '২', '⯗', '𓶆', '\u{73f}', '𒋇', '𓉆', '\u{1a17}', 'ⴊ', 'Ꙅ', '𐍡',
+    '𓥔', 'ꂸ', '🐞', '🁖', '𘧲', 'ళ', '𐜈', '𜷂', '𒅏', '𓯄', '句',
+    '𜺢', '꧑', '𑀋', 'ㇱ', '𑖚', 'ᜪ', '𐒂', '𖣚', '𐹤', 'ᛂ', '𒈰',
+    '𑇓', '𓃾', '勵', '𓩩', '᪅', '᥏', '𓴊', '﮹', '𑘀', '𓳉', '𐴕',
+    '𐏌', '𐵐', '寧', '㰘', '𛊠', '𛲙', '𓮲', 'ﵐ', 'ဂ', 'ԯ', 'ゔ', 'ꊺ',
+    'ᚮ', '࠶', '𝃀', '𔃄', '𘠄', '𓀐', 'ӵ', '🕎', 'ꝏ', '𘥳', '𐤴', 'ᦝ',
+    '₿', '𐎣', 'ࢃ', '⧈', 'ࢶ', '†', '𝋇', '𓒦', '𓂛', 'শ', '𐼺', '𐴷',
+    '⨳', 'ᏼ', '𔎐', '𑜃', '𔖍', '0', '𘬻', '⹆', 'ᾇ', 'ڈ', '𖧓', 'Ś',
+    '𒈒', '𞻱', '⦼', 'ᄗ', '𖽐', '🜏', '沈', '𖠿', '\u{1ba4}', '𐙗', '𒂼',
+    '𓾞', '🯌', '𝁮', '\u{347}', '劉', '𝈼', '𐝀', 'ꕏ', 'ῼ', '𓎏', '𑣌',
+    'ᠻ', '徚', '𓙄', '𓟰', '𑑋', '\u{605}', '𑇝', '🇫', '𝍏', '𘡿', '𐧟',
+    '🖁', '𑘢', '🙈', '𖠢', '𜵱', '⮛', '𝄪', '⟀', '⬊', '𐞥', '𝈔',
+    '𓦅', '𝼐', 'ꏀ', 'ꏃ', '𐁋', '𒾯', '𝇥', '〳', '𓕸', 'ꊊ', '\u{1ab7}',
+    '𐿨', '𞡹', 'ᲃ', 'ﱛ', '🚃', '𝜿', '𐞪', '⮰', '𓲚', '㍚', '𐗜',
+    '𓣨', '𝕭', '𖼂', '𜺑', '𔖙', '𓨿', '𒎇', '⪞', '\u{17b4}', '𑄃', '𔎞',
+    '𔄕', '\u{7a7}', '𑫥', '➤', 'Ḑ', '𐛡', '𓲑', '𓄘', '𒌚', '➵', '𑐇',
+    '寃', '𑩜', '🁤', '𖬤', '𐽄', '𓦐', '\u{1143d}', '𖄐', '⚼', '🦶',
+    '𓔒', '𔘊', 'ꭚ', '𝇣', '\u{8cc}', 'ꁣ', '־', 'ᅧ', '𑪠', '\u{1a18}', '𔐜',
+    '𜸔', '𖬾', '೮', '𑵒', '𔘋', '𓄲', 'ꤷ', 'Ὕ', '𔘹', '𒑅', '{', '╎',
+    '𐑛', '📒', '𛂆', 'ǈ', '└', '⪭', '𖧲', '𛂭', 'ጫ', '𑎙', 'ﺃ', '⳦',
+    '𓏃', '𐑥', 'ﰍ', '𖪺', 'ᡦ', '⽀', 'ዠ', '🀞', 'Ⓢ', 'ⷊ', '𝤞', '聰',
+    '欄', '⋪', '𑌯', '𓅿', 'ݔ', '㏵', '𜰠', '𔘾', '𐪍', '落', '㌉', '蘿',
+    '樂', '\u{e01ca}', '𓂏', '𞅇', '𑀄', '𖫚', '⋠', '𝕁', '𖭠', '🟰', '㫤',
+    '𓏇', 'ꅸ', 'ᜤ', '⛩', '𑨿', 'ﱘ', '\u{116b1}', '𐵥', 'ඖ', '⊬', '𖤙',
+    'ᮙ', 'ՙ', 'ᛯ', '🟖', 'ᖝ', '🨭', '𖩰', '㈻', 'ऍ', 'ׯ', '𛀌', 'Ꝩ',
+    '𒅸', '❡', '𘠎', '𓧓', 'ᐡ', '𓀞', '𐑬', '\u{1b6e}', '𐿉', '𓤥', '𓕦',
+    '𝤀', '𛇸', '切', '𞡥', 'ᶔ', '𖵉', '𐂚', '⻎', '𑫏', 'ὓ', '𑧈',
+    '𑙔', '𒍤', '𛊹', 'ર', '\u{1143c}', '啣', 'ﮥ', 'ᰖ', '𓺚', 'ꬑ', '𝗻',
+    '⤈', '\u{1da15}', '𒔘', '𖄒', 'Ί', '∸', '𓼧', '⓳', '𐭼', 'Ⱍ', '🢗',
+    '𔄳', 'ɒ', '𝔄', '𐘔', 'ꑐ', 'Ϫ', 'ኳ', '🪲', 'ൡ', '꧌', '𖵂', 'พ',
+    '𔄼', '𑄕', '\u{dd4}', '𒂦', '𐭒', '🯝', '🨂', '🝭', '𐕒', 'ॻ', '🈻',
+    'ₐ', '𓫙', 'ㆬ', 'ꚯ', 'ㄩ', '🁍', '𓾒', '\u{10a0e}', '𐋀', 'ណ', '𒎉',
+    '꒒', '𓴧', '𒔀', '𓹴', 'ꆉ', '𝥸', '𐖁', '⇋', '𓰬', '𖡕', '⺧', '⧇',
+    '𐹪', 'ꯀ', '⢍', '𖼱', '𓔙', '𓔕', '༱', '𜶼', 'ꅁ', '𓵘', 'Ᶎ',
+    '\u{fffa}', '𓓰', 'ꉽ', 'オ', '🧦', '\u{e0118}', '𓓿', '\u{1e2ef}', '𓹽', 'ﻬ',
+    '🡽', '𐲰', '𐬨', '𝇞', '𐩫', 'ࠄ', '⢤', '🯉', 'ﺷ', '🀡', 'ꃣ',
+    '🦕', '𐗛', '𓢶', 'ౠ', '꜃', '𑯙', '𐚪', 'ꪹ', '𐮃', '㈸', '㬈', '✆',
+    '𝀷', '𢛔', '⏕', '𒉀', '㉟', '𐛨', '🎴', '⋚', '𘦁', '𝓧', 'ဢ',
+    '𓬌', 'ʥ', '𛋢', 'ɹ', '𑂊', '𓬇', 'ﵟ', '\u{e0194}', 'ꛇ', '𓑮', '⚁',
+    '🍥', '𐌅', 'ᑞ', '𝁱', '\u{e0150}', '❟', '𐢨', '⬌', 'ꆲ', '՜', '⑄',
+    '𑆥', '𞡎', '𓝃', '𖣨', 'ሤ', 'ꍮ', '𔀢', '𑤲', 'ɟ', '🌩', '⪽', '⻮',
+    '\u{11833}', 'ﻼ', 'Ľ', '𝘝', '𑎛', 'バ', 'ꈜ', 'ꈨ', '⬲', '𓲪', '◹',
+    '𓫺', '𓝁', 'ଇ', '𐬫', '𝛴', '𘦠', '⪠', '㍁', '𖣢', '𓔯', '𓶷',
+    'ഒ', '𒌜', '𐓮', '﨟', '𐅪', 'ﯦ', 'ㄺ', 'ꎲ', 'ꧺ', '𐺃', '➿', '🎈',
+    '𒌇', '𓈥', '𐄕', 'ጺ', '\u{1da22}', '⬧', '𔌜', '☿', 'ￌ', '۶', '𘤹',
+    '𑘍', 'ຊ', '𐎟', '⋐', '𐒑', '𓓊', '🕢', 'ⓗ', '𒑢', '𐊓', 'ퟳ',
+    '𖥂', 'ꁩ', '\u{1da84}', '𝕍', '𒍨', '𐑕', '𝼋', '𝒛', 'ኄ', '𝤷', '☢',
+    '⫲', 'כּ', '⭹', '𓬶', '𓓅', '𝅊', '𓘔', '⊻', '⧸', '拏', 'ￅ', '𖮈',
+    '㌱', '𜰤', '𜽖', '⧓', '𑻮', '𞤃', 'ᗵ', '𓔝', '𑠸', '𔋭', 'ᴾ',
+    '𝃘', 'ﳳ', '𞁠', '𐗑', '𓚣', '⚞', '𐏓', 'ಊ', '⚫', '𒋳', '𘧥', 'Ⳳ',
+    '𝘥', '𘮘', '🥏', '𐧦', '𛃰', 'ᡢ', '𔋤', '𘡯', '\u{1cf41}', '𓇅', 'ꏜ',
+    'ꐬ', '𔏜', '𛇚', '𝚣', 'ࢱ', '🙑', '🦸', '𓫆', '☠', '𐺦', '\u{1da05}',
+    '𜴽', '𛀧', '𐺧', '𖭽', '𓏁', '⎂', 'ﾮ', '𓽚', '㏦', '𓽘', '𓣍',
+    '㊙', '𞠦', '𔊃', 'ꭈ', '𖨐', '⍾', '𒄋', '𓔁', 'ѹ', '𐩺', 'ꂂ', '㋥',
+    '𓺓', 'ꤾ', '𝖳', 'இ', 'எ', 'அ', '𛀾', 'ｃ', 'ｇ', '\u{1da27}', '⁗',
+    'ㅽ', 'ｗ', 'ꨠ', '𐂀', '𘢶', 'ލ', 'ￊ', 'ᡀ', 'ᪧ', 'ퟃ', 'ᴪ', '𓸵',
+    '𓈨', '𐠮', '𘯄', '\u{2df3}', '𖣜', '𞊨', '🖂', 'ꀿ', '፪', 'ⶉ', '🈬',
+    '𔆧', '𔁝', '\u{1cf25}', '𐅳', '𖢋', '𑂻', 'Ꞥ', '㊧', '𔂹', '𒑧', '㍂',
+    'ዻ', '𐲞', '𐇫', '荒', 'ꄯ', 'ቚ', '𓗣', '𑇰', '𘰢', 'ແ', '𝥴', '🙬',
+    '𓑵', '𐤳', '︐', '🨰', '𝞎', '𐤹', 'ꆌ', '慄', '𓢼', '𝙢', '౼',
+    '𝙚', '𐚙', '𞱶', '𑎞', '𐘷', '\u{2ded}', '𔆎', '𑪰', '𛰄', '𓡨',
+    '𝃥', '㈯', '▆', 'ꯇ', '⫖', 'ꇟ', 'ギ', '𖩔', 'ﵫ', 'Ԫ', '兩', '𒋧',
+    'Ӻ', '🂃', '【', '𐳲', '🗽', 'Ὡ', '\u{1cf42}', '𓶶', '﹃', '𓁁', '𜱙',
+    '⫤', 'ﱝ', '𛈂', '𐑦', 'と', '𒊓', '𝢩', '⚓', 'Ⱉ', '𖡒', 'ﳧ', '৷',
+    '𘢢', '𜷴', '\u{1cdd}', '𓤉', 'ᆹ', '﮵', '𜸝', '𓢗', 'Ɽ', '꜒', '𓺦',
+    '𓺢', 'ᕡ', 'ら', 'ꞯ', '𖬯', 'ꟺ', '𝕵', 'る', '𒂮', '𓑅', '𜵸', '𓕎',
+    '響', '𓅛', 'ⵙ', '𝥁', 'ⴢ', 'ﰃ', '🈓', '𝈄', 'Ｘ', 'ᤱ', '𓛓', '𓲻',
+    '𛄓', 'ꖇ', '𓉩', 'ᚻ', '𝌬', 'ℳ', '𔀕', '\u{11ef3}', 'ഩ', '\u{10eac}',
+    '\u{8e4}', '\u{325}', 'ꘙ', '𔄗', '𔄓', 'Ȣ', '𓄱', '𞹂', '𔄜', '𝃙', '𓏫',
+    '𑅦', '𘱾', 'Ò', 'ꢃ', 'Ṭ', '\u{a927}', '𓜣', 'ꤜ', 'ḃ', 'ễ', '𘨱',
+    '𓳏', '꫱', '𝦰', '\u{35b}', '璘', '𘪕', 'ꦠ', '𓜞', 'ὡ', '𓜭', 'ط',
+    '🃌', 'Ԝ', '🏉', 'ﷱ', 'ে', 'Ñ', '𝘣', '𞹟', '\u{11234}', '␛', 'ሺ',
+    '𓧴', '𑐧', '𘲛', '𓣦', 'ⴸ', '🐪', 'ﱪ', 'ꭼ', '𝁻', '🄦', '⁍',
+    '𘤇', '𓔵', '𓔹', '𓸩', '𒈋', '𐑫', '🠷', '勉', '𝙴', '𐌖', '𞺒',
+    '𑇢', 'ヲ', '𐘇', '\u{e0056}', 'ꈂ', 'ဿ', '⩰', '🯠', '𒑒', 'ӆ', '⸽', 'r',
+    '∮', '\u{e0180}', '𘡲', 'ឿ', 'Ĥ', '𘢬', '𓙳', 'ཀྵ', '𒐤', 'ㆹ', '⁑',
+    'ᚑ', '𞥙', '𓌥', '╡', '𒌁', '\u{105f}', '\u{113c2}', '𒇕', '⒨', '𔉤',
+    '𜰢', '𔂈', '👘', '⵰', '𑑒', '⮼', '⺊', '𔁆', '𔁂', '𑫯', 'Ŕ', '獵',
+    '🢐', '᱁', '𞹻', '𐐱', '難', 'ᳰ', '𓆰', '𓆵', 'ṫ', '🅮', 'ษ', '𠨬',
+    'Ⰸ', '𘰽', '⟭', '⟧', 'ꤞ', '痢', '李', 'Ꮠ', 'ᬨ', '🝐', 'ፑ', '🕼',
+    'ෘ', '🞀', '𓌛', '᧠', '𓜬', '⏏', '𘡡', '\u{33b}', '𜲮', '\u{1b03}',
+    '𐖣', 'ฮ', '🯊', '𐨖', 'ᔪ', '𐍇', '𐳛', 'ꏛ', 'ꄕ', '⡃', '∆', '𒔤',
+    '₈', '𐚍', '⇺', '🚟', '𓥱', '𜲕', '𐐶', '⯾', '⚻', '𝚷', '\u{6d9}',
+    'ꘅ', 'Ᏼ', '𑁊', '꓄', '⭼', '🄞', '𓵊', '𓵎', 'ꉔ', '𔖓', 'ꬂ', 'ℤ',
+    '𓂭', 'ꪵ', '📠', 'ꂙ', '𓝊', '🞩', '𖹁', '𒍂', '𖹌', '𖹉', '🨞',
+    '𐄒', '🢕', '𐼻', '𑇆', '⯩', '﹚', 'ꮰ', '𛀜', 'ퟕ', 'ᝐ', '𜾟', '𒑪',
+    '𐓝', 'ﴷ', '⋦', '🃯', '𑣘', '𓞐', '🦩', '\u{f7b}', '𛊲', '𑰇', 'م',
+    'Ꚁ', '𜺪', '𐰾', 'ᎄ', '\u{5b7}', '𓶍', 'ℑ', 'ꨀ', '𝡇', '⧷', 'す',
+    '𑈣', '𐣯', '𔆕', '⛿', '𔗃', '𝜑', '🀾', '🀺', '𛈝', 'ർ', '\u{11c34}',
+    '𒌩', '⺆', '𐖤', '𓔸', '𔆚', '𐰹', '\u{1a66}', '༼', '𖡤', '𛅱', 'Ⓠ',
+    '𓴗', '👠', '𐜀', '𑅖', '៩', 'Ⓘ', '⌧', '🇶', '🇺', '🇾', 'ꬼ',
+    '🂁', '；', '🇮', 'ꈅ', '𓯷', 'ꚸ', '𓌁', '౸', '﨡', 'ꔆ', '𝨹', '𝑬',
+    'ቡ', '𜲊', '𘦆', '𝑼', '𝑸', '᥈', '𝒀', 'ĉ', '𓯜', '𓔥', 'ᝥ', '𑬈',
+    'ꯌ', 'Η', 'ᢶ', '୦', '𜸅', '𔒝', '𘪝', '𓜕', '𓁆', '𖹦', '☱', '⢮',
+    '𝔮', 'ய', '刺', '𐎲', 'ꜱ', '𘡃', '𘠿', '𘠻', '🧙', '🯵', 'ᨯ', 'ࣈ',
+    '𑼱', '𝣟', 'ᇝ', '𢡄', 'ഋ', 'แ', '𒒸', '🏡', '𔁽', '⪚', 'ڴ', '𓤛',
+    '𐵷', '💰', '𐓭', 'ᤒ', 'ᕇ', 'ヺ', '🁾', 'ύ', '🞜', '𓍜', '㉪', '𑋳',
+    '𜱭', 'Ꞻ', '𐳫', '⍨', '𝄥', '𓴭', 'Ḣ', '𐠍', '🫁', 'Ả', '𔅦',
+    '\u{e01ea}', '𞡻', '🈔', '𓇍', '🔴', 'ᅓ', '\u{1bf0}', 'ᮋ', '𩇟',
+    '\u{e019e}', '\u{326}', '𘯱', 'ꟼ', '祐', 'Ʂ', '𔒓', '𓅭', '𘣈', '𓃋',
+    '𘬙', '𝓃', 'ꩁ', '\u{31a}', '🞏', '𑣉', 'ᝆ', '𑩴', '🙉', 'ꉻ', 'ﯓ',
+    '𓔮', '𝞜', '▟', '🪦', '𐊭', '𐍫', '𛰶', '৶', '𜵓', '🞿', '𓳸',
+    '𓡷', '𔏠', 'ᶄ', '𐽱', '𓵕', '𐽽', '𑒌', 'Ṍ', 'ꈹ', '𞲫', '𖼪',
+    '𞴡', '⏞', 'ꇋ', '𜴧', 'ꇍ', '৺', 'ꗤ', '𔄤', 'ں', '𐃷', '𔕖', '𓲤',
+    'っ', '𜺇', '☫', '𝈅', 'Ṵ', 'ᠳ', '𖄀', '𓑸', 'ﻨ', '𝼝', '𔊇', '◇',
+    'や', 'ਘ', '𞓖', '¸', '廬', '𜱵', 'ᮧ', '𑈞', '𓻭', '𝑵', '𑻤', '🎼',
+    '𓹉', '‡', '𒉥', '𖣡', '𛁋', 'ό', 'ῖ', '𖪯', 'ꎢ', '䵖', 'ꝥ', '𝃞',
+    '𑃸', '𐛬', '⪢', '𔑐', 'Ԅ', '𓯱', 'ꪊ', '𔍛', '𓨮', '𖵱', 'ϓ', '˩',
+    'ꐲ', '৹', '⹍', '𜲈', '𖹪', '🜢', '𐫉', '\u{e0020}', '⒯', 'ȗ', '𞗜',
+    '\u{1be6}', '𛊙', '🦍', '𞹭', '⒣', '\u{59b}', '𐳌', '𑂯', '𓳖', '𓇊',
+    '𒓙', 'ᔆ', '𓶔', '𑢴', '⳧', '𜱘', '𖺊', 'ඦ', '𑯅', '𐐴', '𔄉',
+    '𝤊', 'ᛚ', '𞺋', '𓇪', 'ꖎ', '㋖', '𐲙', '⌝', '𐘈', 'ꗋ', '𖢫',
+    '\u{1cf32}', 'ୟ', '𝞔', '\u{11a8a}', '𖵊', '𑘙', '𘫱', '𓓙', '𒐀', 'ⶸ',
+    'ẻ', '𐝧', '🚿', 'ﵿ', '𔉆', 'ꄳ', 'Ғ', 'ḩ', '⡜', '𖬸', 'ᚓ', '㍉',
+    'ᕵ', '🢆', '𑴃', '蕤', '𒃘', 'ᾑ', '𔕂', 'Ᾰ', '𑍟', '𛱹', '𒆋',
+    '\u{e0168}', 'ϛ', '㌁', '𑐂', '𐚴', '𔃋', '\u{1d167}', '🢃', '𑣙', '𛆭',
+    '芳', '若', '𝥯', '𝢃', '𝆚', '꒝', 'ㅚ', 'ං', '碑', '\u{1cf38}', '𓣜',
+    'ꅆ', 'ႄ', '𔑻', '🜎', '𓜇', '𜰱', '𓅣', '𖠒', '𔇑', '𑧐', '⾙',
+    '𑄒', '𓔬', '⤙', '𓜎', 'ꅜ', '𔕔', 'χ', '𖪐', 'ꘊ', '႓', '☮', '𐖼',
+    '𝍪', '🮉', 'ڢ', '𝚎', 'ᜇ', '𐎰', '◟', '👽', 'ℛ', 'ᘮ', 'ꘃ', '˙',
+    '𘩜', '𛃗', '𑗃', 'ꍤ', '𓪽', '𝌞', '៚', '㌦', 'ᆬ', '𜳷', 'Ⲁ', '🃡',
+    '𜾊', '𑈇', '𐆓', '𐞚', 'ቿ', 'ݵ', '𐨒', 'ꕲ', '𑱲', '△', '𓧨', '烈',
+    'Ⱕ', 'ɠ', 'Ⲃ', '𝛱', '𓿚', '⑉', '𒋭', 'Ԋ', 'ﮅ', 'Р', 'ቢ', '𓕭',
+    '𑎡', '𘬉', '𐗫', '𜸠', '𛁻', '𛁷', 'ꐕ', '𒀹', '𝡾', '𔗘', '🠟',
+    '𜰻', '𔗔', 'ﺫ', '𐏑', '∡', '𔉡', 'ꚉ', '𒁢', '❻', 'ꉉ', '☆', '𛋮',
+    '𓬙', '𜽳', 'ʁ', 'ꆐ', 'ꊿ', 'ﷵ', '𜱬', '𓃒', 'ᘘ', '㌝', 'יּ', '𓍨',
+    'ﺨ', 'ꂻ', '𓞘', '𛰃', '⤹', '🙡', '𘤠', '⎿', '𐇑', 'थ', 'ⴙ', '𝤌',
+    '🀌', '𒄘', '𝝠', '⣂', '𛊚', '⼁', 'ᤐ', 'Ꝋ', '𐁇', 'ꝵ', '𓞮', '💱',
+    'ሞ', 'ᙨ', '𓝭', '𝐴', 'ᶀ', 'ḣ', '﵏', '𑿃', '𞁊', '𑂍', 'ꅧ', '🕧',
+    'ᝅ', '␄', '𛆙', '𐊲', 'ꮍ', 'ꡃ', '𜰵', '𘪛', '𛆚', '﷽', 'ݷ', '𖧢',
+    '𝔠', '🟒', '𜺜', '𓘕', '𐺋', '∏', 'ꈎ', '𔄶', '𓊹', '𐬧', 'ᰍ',
+    '⎌', '𐡴', '⋛', '芑', '𐡃', '\u{1b72}', 'ᢓ', 'ƀ', '꤯', '🥺', 'ᬓ',
+    'ㇰ', '🜤', 'ⰱ', '㉥', '𘩩', '◐', '𑇙', '𣎜', '𘠅', '𛀮', '𖥄',
+    '𝈃', '𘨽', 'ᅅ', 'ꚽ', '𒃯', '𒈀', '𑆘', 'ʴ', 'ۄ', 'ꇡ', 'Ꚇ', '𞴥',
+    '\u{1a7b}', 'ᒁ', '𓝋', 'Ԕ', '❼', '𜲍', 'ᡎ', '𝧨', 'ũ', 'ƽ', '𐍮', 'ꊸ',
+    'Ⰺ', '𓌔', '𐝍', 'ᗣ', '嬾', 'ᐁ', '⦒', '𑱦', 'ി', 'ꉗ', '>', '𔎠',
+    '𐞏', 'ᠦ', '\u{e01cf}', 'ܚ', '契', '𐞢', '\u{6e1}', 'Ꮩ', 'ӷ', '✿', '\u{823}',
+    '⡙', '𝃓', '🫲', '𓑨', '𒒉', '⻞', '𘭮', '𑨖', '⇨', '𝄻', '𛁞', 'Ü',
+    'ǀ', '\u{c4d}', 'ⶮ', '𑯛', '𔑳', 'ꕧ', '𓘬', '𒒐', '\u{a0}', 'Ⱬ', '遼',
+    '⇫', '🗰', '\u{e0115}', '𛀀', '𘮠', '𜳗', '𓵦', '🬭', '𝃋', '⬙', 'ḇ',
+    'Ⴘ', '𖧈', 'ꧯ', '➬', 'ꞈ', 'ꎶ', '🖟', 'ꕪ', '🦌', '𔍣', 'ﹸ',
+    '\u{a42}', '⤵', '㊯', 'ỿ', '𒓞', '㎣', '𓏥', '𛁚', '؆', '𒐔', '𑌲',
+    '𓧛', '𒊸', 'ซ', '𞅅', '৽', '𘮝', '𘦮', '😹', '🍵', 'ឱ', 'ꈰ',
+    '𛋙', '𜵥', '𐊴', '𓓕', '\u{945}', '𛋫', '𛁘', '∷', '𓣋', 'ꤵ', 'സ',
+    'ﳞ', 'ꩂ', '↹', '⇲', '࠽', '𘪺', '𛄅', '𑤁', '慌', '𒊾', '𐗁', '𐊹',
+    '𐫭', 'ᱝ', '✀', '☬', '𒉊', '┰', '\u{7f3}', '𒑠', '𒈌', '𘤪', '␨',
+    '⭁', 'ἠ', '𑂡', '𝞪', '❑', 'ଝ', 'ಂ', '𞋅', 'ꎑ', 'ꐴ', 'ሑ', '𝂜',
+    'ᅑ', '𞸉', 'ꑳ', '𐛥', 'ம', 'ꍛ', '🞷', 'ꪃ', '༎', '𓱒', 'ү', 'ᴼ',
+    '𤰶', '⻨', '𘫌', '𑑓', 'ｼ', '𓼪', '𑤚', '堍', '𑵆', 'Ბ', '𐋻',
+    'ତ', '𖣖', '𜳁', '𘪣', 'Ⅵ', '┥', 'ਭ', 'こ', '𖫟', '𓖦', 'ꩲ', 'ᩈ',
+    '\u{113b8}', 'ю', '𔏕', 'ᇈ', '𑨫', '𘠋', '∦', '𞥘', '🬘', '𒈊', '𝜏',
+    '𒓒', '\u{a9c0}', 'ꑇ', '𛱧', '臨', 'ㅝ', '🧛', '⍯', 'ఢ', '꯳', '⎓',
+    '𖦹', '𔑱', '၃', '𜵘', '𑼦', 'ⅰ', '𝡀', 'Ꞗ', '🟧', '𐭄', '𐴆',
+    'ꮒ', 'ꥬ', '⿃', 'ŵ', 'ퟻ', '𛂎', '𝍵', '\u{598}', '𔆺', '꤉', '\u{d57}',
+    '⨨', 'ꆼ', '𑑡', 'ꆾ', '⓶', 'ꨳ', '🬽', '𐏁', '𐛠', '𐼀', '𑋊', 'ꂋ',
+    'ힰ', '𔑉', '𐂇', 'љ', '𘧘', 'ꛣ', '𘬈', '𝚵', 'A', 'ᵛ', 'Ⰽ', 'ꮱ',
+    '⑀', 'ᶠ', '🩣', '𐌏', 'ᇦ', '\u{9e3}', 'ꮯ', 'ณ', '𓇮', '𝕠', '𒒻',
+    '𝕘', '⠖', '🀶', '⪎', 'ඳ', '\u{65b}', '⨰', '𖾁', '\u{fe0a}', '𐓺', '𔗅',
+    'ᠲ', '𖄫', '𓚊', '🞲', 'ౄ', '𐩼', 'な', '𘧤', '🀉', '𓥄', '𜵮',
+    'ඹ', 'ኛ', '𐼸', 'ぢ', 'ꢷ', '𓴮', '𔈩', '𐓡', '𒍊', '𐑣', 'ᑝ', '𣀊',
+    'ᇱ', 'ᾐ', '勒', '𓳶', '𜾤', 'Ḟ', '𓥍', 'ᨬ', '𞤂', 'ꖷ', '⸜', '𐃫',
+    '𓸍', '𑖐', '੪', '𘣐', '𜽽', '⭲', '𓒢', '𐗒', '🦀', '𛊣', '𐎶',
+    'ᥔ', '𜺔', '㏒', '\u{1d185}', '🦙', '𓄚', '𔖱', '枅', '𑻰', '𓊲', 'ꅀ',
+    '🭦', '𐫯', 'ɿ', '📍', '﵅', '𓸈', '𒌛', '𖭀', '𔎈', 'ꑭ', '𐕑',
+    '𓾡', '𒐆', 'ਥ', '⢛', '\u{a8ec}', '𒍅', '𞢬', 'ꇸ', '⛙', 'ဤ', 'Ņ',
+    '𑠪', 'ꌋ', '𐬉', '𖬛', '\u{1da9f}', '𝋫', '𘩕', 'ﲠ', '𒁾', '𩬰',
+    '\u{8f7}', '𓠣', 'ា', 'ﮯ', '𞓴', '鬒', 'ꘁ', 'ⴟ', '⠻', '𐡰', '㉁',
+    'ᰔ', 'Ӵ', '𓙦', '𞸲', '𞺐', '⣽', 'բ', '🕍', 'Ƹ', '𝓤', 'ꯂ', 'ꭖ',
+    '𑨱', '\u{1da2a}', '𐘵', '🪹', '𐠛', '𐠚', '𐠙', '戀', '𔄄', '🍻', 'Ⱜ',
+    '𒓌', '⥃', '𖹈', 'ઇ', '𐌜', 'Ǟ', '⡘', '𖥇', 'ꤎ', '𝢗', 'ロ', '𛃠',
+    '𜴫', 'ﯻ', '𞠈', '🝆', '𘠛', '⑧', '𝁤', 'ᵷ', 'ꖁ', '𒅋', '𐜁', 'ꆋ',
+    '𐠨', 'ࠀ', '𓴶', '𜵛', 'ଃ', '\u{e011c}', '⚯', '𓢏', 'ꡪ', '𑤄', 'ᚳ',
+    '𖺙', 'ಠ', '𑅆', '𐀯', 'ƚ', 'ざ', '𘠙', '\u{aaec}', 'ằ', '甆', '𖪑',
+    '𔌟', '⬮', '𒉯', '䷾', '𑎵', '⢥', '\u{8f4}', 'ᾎ', '🔼', 'ꇣ', '𛋯',
+    '𛈕', 'ἷ', '𓣇', '𐳑', '︽', '⢝', '𛋈', 'ീ', '𛇒', '˄', '𔏍', '𔔑',
+    'ޓ', 'ẇ', '𜽒', '𐲓', '☋', '𓬰', 'ꐘ', '𖽺', '𐓱', 'අ', '㌺', '𘤧',
+    'd', '🄏', 'ữ', 'ꇕ', '﷏', '꓅', '⯅', '𐕐', 'ⶂ', '\u{119d4}', '𐗱',
+    '𜷦', 'ꠙ', '輦', 'ﱱ', '\u{e0133}', '𐭺', '𔒞', '𝢑', '𐗄', '𒉳', 'ᇩ',
+    '𝞬', '﮸', '𑨙', 'Ỡ', '𝝵', '𓢚', '\u{b43}', '🜦', 'ꩽ', '\u{2dfc}',
+    '𐦼', '𔎌', '￩', '𝕏', '᭛', '🥞', 'ᨙ', '𔗼', '𒉎', '賓', '𒆆', '◮',
+    '⿌', '⮸', '𑤑', '𑩷', '𔈞', '꒳', '\u{1103c}', '𞴶', '𔗀', '𔎃',
+    '\u{1cf13}', '𘩻', '𝗎', 'ꪥ', '𑌿', '𝖺', '⋊', '𖾓', '⁁', 'ŗ', '𔐻',
+    '🝍', '𐑺', '\u{2df9}', '🤗', '\u{1be9}', '𔋱', 'ⶤ', 'ﭧ', '\u{f7e}', '࿗',
+    '𒂯', '\u{aabe}', '𝘻', 'ꯁ', '𘬔', 'ݹ', '𒀳', '𜰦', '𒊵', '𐩁',
+    '\u{941}', '𔁁', '👈', '𘤛', '𜷷', '𝝨', '𒅟', 'ˍ', '𝁯', 'ۦ', '𒄚',
+    '𛇉', '㍆', '𞓨', '𝥥', 'ᙷ', '♪', '꘡', '𓈙', '麻', 'ᎁ', '𑴘', '⧞',
+    '𝘩', '𒊉', '𖨨', '𔁑', 'ꌇ', '\u{b4d}', '𞥋', '✵', '𜺡', '🥁', '𖺖',
+    '𔎯', '𓛔', '𑌞', 'ꠧ', '𛈩', '\u{111cb}', '\u{2dff}', '𓦧', '𓩒', '🗑',
+    '𛰼', '㌙', '𝔇', '🞛', 'ꭞ', '𜸌', '🀏', '୴', 'Ⅰ', '𖦪', '⪕', '𓗈',
+    '\u{e012c}', '\u{e012a}', 'Ż', '𖢝', 'ㅸ', '𛰰', '✛', '𘪋', '❰', '𘩴',
+    '𔌿', '𑧁', '䷅', '𑊡', '𝞀', 'ヵ', '📽', '𓢛', '😔', 'ꊂ', '𓋭',
+    '㈑', '߆', '𞺬', 'ȼ', '✟', '𝞛', '𝜂', '𐛕', 'ꚡ', '𒔰', '𜳹', 'ⶋ',
+    '㉂', '𐄾', 'ꦻ', '𛀝', '𜽩', 'ᨃ', 'ꍺ', 'ᡑ', '𜶪', '𓗕', 'ꜚ', '𝠰',
+    '🔒', '𝇄', '🞫', '𜷪', '𓄷', '𔏪', '᠉', '𝞹', 'ﯭ', '𔆣', '𛃧',
+    '𐊊', '𖨣', '𛊿', '\u{f80}', '𒐞', '⠄', '𐗋', '𐌔', 'ﴊ', '𜲭', '兤',
+    '⣁', '⇷', '𐝔', 'ꥹ', 'ڰ', '𘨲', '𐅓', '𓻤', '𐦡', '🦾', 'Ν', '𛄔',
+    '𖤚', '𓂒', '𜷱', 'Ⱒ', '𘲦', '🢖', 'ꬭ', 'ꣻ', 'ϐ', 'ᙣ', 'ꬩ', '𑜁',
+    '𐀂', '𝁛', '𛰣', '𓧎', '𐴍', 'ធ', 'Ꝥ', '\u{34e}', '␝', 'ઠ', '𔒸',
+    '響', '𓯎', '𔓥', '𓳟', 'ﵣ', '𓥤', 'ᛑ', 'ㆥ', '\u{202e}', '𓷨', 'ű',
+    '⣅', '𔖛', '𐒸', '𓺥', '\u{fa6}', '🜪', '\u{361}', 'ࡰ', '𓠺', '𜰽',
+    '𝢙', 'セ', '𝤓', '𖥱', '𐗂', 'ϯ', 'Ҕ', '𓡩', 'Ⓚ', 'o', 'ˌ', '𒊐',
+    'ﵭ', '\u{111cc}', '𔎗', '𒓶', '⧏', '𔎜', '𛉌', '𔅪', '𖪕', '𑎰', 'ᱣ',
+    '𛱩', '𖺕', 'ܯ', '𔈇', '𓅼', '𑪇', '𒋞', '\u{1cf1e}', '𜳱', '𖡟', '𛈔',
+    '༬', '𔗽', '𞊘', '𓓆', '𐞡', 'о', '\u{1d175}', '𑪃', 'ퟲ', '⋁', '𞢛',
+    'ü', '⛽', 'ἇ', '𖽁', 'ⳗ', '𖣥', 'ሧ', '𐘶', '𐘹', 'ݗ', '𘠁', '𐚼',
+    '𓪼', 'ᱶ', '⁐', '﹣', '𛅥', '𥲀', '𓖝', '\u{d62}', 'ⅾ', 'ꢀ', '𓇸',
+    'Ǐ', '\u{a8e9}', 'ᤳ', '⣦', '𐣡', '𑁇', '🠵', 'ꢦ', '𓗲', '𘩫', '𓘵',
+    '⫗', '㏫', '𞤭', '⎁', 'ﰧ', 'た', '𓫈', '\u{2df2}', '𒂻', '𛁠', 'ł',
+    'ᜎ', '𔗸', '𓛫', '૯', '𒋄', '\u{2df1}', '⺷', 'ᯗ', '𓖎', '𓤆', '𓖲',
+    'ⶡ', '⸬', '𝀊', '𝡜', '🢨', '𞄌', '🅡', 'Გ', '𐵺', '𘢍', '𒈥',
+    '𓖻', '\u{e01df}', '\u{e01e3}', '𑍇', 'ﱻ', 'ꫝ', 'ꪏ', 'ꞻ', '🅦', '\u{1c2e}',
+    '𓉕', '𝌄', 'ⱪ', '᧩', 'ᴩ', 'ࢵ', '\u{1cf31}', '𐛞', '⺅', '𞗡', '𐖎',
+    '𐼂', 'ᡊ', '侻', 'ᑸ', '𐫱', '⽜', '𓎂', '𝡼', 'ፕ', 'ፐ', '⨀', '𑀵',
+    '𒄁', 'ꯤ', '𓻠', '𐁔', '\u{1daad}', 'ꗉ', '𑶔', '𓄙', '♫', '𝒻', 'ꌚ',
+    '\u{115b4}', 'ƃ', '➉', '⭿', '𐋌', '𔑧', '🡠', '\u{e019a}', '𘯌', 'ᅏ',
+    '⍁', '𒃟', 'ꌰ', 'Ꮖ', 'ꌮ', '𒄄', '𓔇', 'Ċ', '⁽', 'ꍵ', '𐳈', 'ᅤ',
+    '𘭒', '𘯓', 'ꘉ', 'ꕯ', 'ၼ', '𐠗', 'ᥖ', 'ᠬ', 'Ⱅ', '𖨗', '𑀳', '𔁉',
+    'Ꞌ', '𒉔', '⦏', '𓉐', '⇄', 'ע', '⣜', 'ᦺ', '𐂪', '🡘', '𓭭', '𒍷',
+    '⎒', 'ଛ', 'Ɐ', '𝂽', '𘫒', '◞', '𝇁', '🝧', '𔀊', 'Ღ', '𑑛', '𞢔',
+    'ꬎ', 'ퟩ', 'ᬧ', '𔖖', '𓱅', '🕬', '𓀟', '𔌫', '𐮐', '┶', 'ꙩ', '𐗉',
+    '🞗', 'ꏅ', '𑯵', 'ꎂ', '𓖳', 'ჰ', '濾', '𐐂', '𐩵', '\u{1d1aa}', '𞡛',
+    '𝀙', 'ꬊ', '⓼', '🉇', '𓾛', '𓖼', '𘪾', '𝢧', '👹', '\u{1ab9}', '𔆲',
+    'ꉦ', '𐡟', '𐫆', '፻', '🖧', '𐇦', 'ᔤ', '\u{11045}', '𓇻', 'ᡂ', '㍏',
+    'ᓃ', '𝼙', '𐄈', '𝛺', '𑚌', '𛉯', '𘥚', 'ᰊ', '🜃', '𑤖', 'ѐ',
+    '🝵', 'Ạ', '𒑦', '𑓙', '\u{e0055}', 'ጚ', 'ꚼ', 'ጼ', '⠘', '𝣎', '𑰕',
+    '𝐑', '𒍼', 'אּ', '𜴏', '汧', '⡅', '𞠇', '🡸', '⸹', '𘱐', 'Ѹ', '𓆾',
+    '𒔗', '𝢉', '\u{952}', '𐠢', '𖦷', '५', '\u{11102}', '꘎', '𓟓', '𘩷',
+    '𝚻', '𓁪', '𓩸', '𓛰', '\u{6dc}', '✱', '𑋱', '𝧚', 'Ӟ', '𓣃',
+    '\u{1e132}', 'ᆉ', 'ҧ', '𝦴', '㍝', 'ﹶ', '🡴', '𓀫', '𓇾', '𐠓', 'Ṕ',
+    'Ӗ', '𖣽', 'ჽ', '𔋙', '𔋞', '℀', 'ꊁ', '\u{1da12}', '𘣅', 'p', '㊜',
+    '𐂿', '𐼿', 'خ', '𓿭', 'ᒐ', '༞', '𓗺', '𘮆', '⡌', '𞺂', '𔍱', '⦆',
+    '𞴬', 'ㅮ', 'ᥐ', 'ᴛ', 'ʜ', 'ᓖ', '\u{1112b}', 'ᔠ', '🬋', '\u{953}', '𛃢',
+    '\u{1cd7}', '𖥮', '᥉', '𐎺', '𞲂', '領', '𜶀', '⡕', '𔕈', '𐫙', 'ᆷ',
+    '𔐺', 'ᦽ', '🗶', '𞟽', '𓕶', 'ꎤ', '🭫', '𝝫', '𓄉', '🌃', 'ᚄ',
+    '𐡉', '𒾹', '𑘘', 'ꚭ', '𔍍', '𑿇', 'ᓱ', '𒐜', '𓃊', '𐘂', 'ꏇ',
+    '𑚪', '𔇆', '𐐚', '𒁗', '𐌺', '𐰕', '𘨼', 'ⱴ', '𞴄', '𑦲', '⥬',
+    'ꣲ', '𓳆', '𞠕', '𓯌', '\u{f82}', '𘧛', '𘧗', '𝃩', '𞢢', '𖭙', '❎',
+    '𝇦', '𘭂', '\u{b44}', 'ꌧ', '𘨤', '𖤴', 'ꕢ', '𓯭', 'ꆑ', 'ݕ', 'Ჾ', '·',
+    'ᘆ', '𘰌', '⠦', 'ἅ', '𖠳', '𖵥', 'ꮺ', 'ꑸ', '𒒈', '𑌈', 'ᝏ',
+    '\u{13455}', '𘥂', 'ஶ', '𑠐', '🠾', '⣒', 'ᢵ', '៖', '⾶', '𞢝', 'ꢙ',
+    'ㄏ', '𝅆', '𛉧', '𓞩', 'ㄒ', '🧷', 'ㄆ', 'Ꮔ', '𔘎', '\u{1612f}', '⮃',
+    '𒌨', '᧧', 'Ꮡ', 'ᅐ', '㍜', 'ਬ', '偺', '🅑', 'ﮍ', '𖣿', '🝀', '𒂇',
+    '𝟠', '𘭬', '𖤇', '𐆃', '𘭧', '𝋂', '辞', '𑀶', 'ｿ', 'ᦻ', '𛲂',
+    '𒔙', '𘦤', 'ᕬ', 'ឞ', '𝗄', 'ᕫ', '𒅂', '꯴', '𘱢', 'ﴟ', 'ퟱ', '𓲄',
+    '𝣲', 'ኤ', '⏓', '⒧', 'ﺂ', '𜺦', '𝡊', '𖤷', '𜺫', 'ဓ', '🀆', '𐑴',
+    '䷟', '𔏝', '𐐌', '𖢡', '㏆', '⹉', 'ꍰ', '𝼆', 'ㄢ', '𓳵', '𐩀', 'ⵆ',
+    '𔗹', '4', '𑚅', 'ꡠ', 'ᑍ', '￭', 'ꐎ', '👮', '𐁕', 'ꏾ', 'ֆ', 'ᖷ',
+    '𓚆', '𝼛', '㈨', '⟌', '𝞶', '\u{1e135}', '𓛎', '𔅨', 'ꗙ', 'Ԇ', '𓘒',
+    'ṭ', '𝠳', '໐', '\u{10d25}', 'Ɗ', '𝖬', '𝖨', '𝖤', '𞴘', '𝘴', '𑚬',
+    'ዦ', '𐢗', '𔑍', '⺛', '𔅭', 'ㅗ', '📪', '𐛇', '\u{321}', '䷰', '㌑',
+    '🎾', '\u{5ad}', '𖪒', 'ㆰ', 'ﰥ', 'ཪ', '𓊊', '𘫠', '𓢪', '𝥱', '⽘',
+    '𝐫', '\u{e0169}', 'ᤦ', 'ﻟ', '𑀂', '𜵍', '🤍', '𝆣', '\u{c4b}', '𝆛',
+    'ᆗ', '紐', '𒀊', '🚮', '𝂈', '𓢩', '\u{1cf2a}', '𑍋', '𓩜', 'ᵾ', '┡',
+    '㎓', '𓪪', '🀫', '𖾇', '𐡝', '𓌧', '𐬾', '﮴', '𐡇', '𓄃', '𐅉',
+    '𓕆', 'Ⲭ', 'ቪ', 'ꀪ', '🥟', '𐲍', '𖧐', 'ɐ', '𠣞', 'ቤ', '𐽓', '⩬',
+    '𐼴', '\u{11d47}', '𓩦', 'º', '𒂜', '㎷', '🟌', 'ᯍ', '瘝', '𘰇', '𓨒',
+    '𓲴', '𝧱', '𓜋', '𝚰', '𐐼', '𝤦', '⻝', '𝆨', '𒉡', '𑏊', 'ڒ',
+    '𘬰', 'с', '𓰲', '\u{110b3}', 'ㄌ', '\u{11f00}', '𔀪', '𓩢', '🁋', '𛋣',
+    'ﰶ', '℔', '𝛳', '𓂢', '🙣', '𔄭', '𘣺', '𝑤', '𐞋', '🥦', '靖',
+    '𑩺', '𛈭', '醙', '\u{1e130}', '𐭥', '𓡚', '\u{6e7}', '𘭓', '𛋬', '𝀜',
+    '𘮨', '㎬', 'ꆳ', '𐵔', '𓩫', '🍁', 'ꗵ', '𓅲', 'ᚘ', '𖄳', '𝒸', '﹍',
+    '𖧯', '⧼', 'Ĵ', 'ﾱ', '𘨺', '𝜌', '𜽴', 'ἓ', '𝆜', 'Ⴌ', '᧢', 'ꁲ',
+    'צּ', '₥', 'ޜ', '𞀲', '𜴴', '𞡴', '🅬', 'ﭢ', '\u{8dd}', '𓜦', 'Ʌ', 'ᤩ',
+    '𓃖', 'ῄ', '𘱴', '👊', '𞺩', '↰', '𖼼', '𜰒', 'ᣒ', '⺗', 'ꝝ', 'ꂥ',
+    '䷴', '〥', '⠆', '𑩘', '𐑽', 'ﭷ', '⠁', '𓙩', '𑯱', 'ઽ', '𑚃', '𝢘',
+    '➱', '𓳮', '🏓', '龜', '𓜜', '⮭', '🕪', '𝐗', 'ﬠ', '喇', '𖹼', '𔊑',
+    '𓺖', '⩦', '𓿏', '🕃', 'ⴎ', '❽', '🔀', '𑱙', '꣼', '𝞾', 'ఔ', '𘣱',
+    '𓿄', '𑘨', '⼆', '🛒', '𓴪', '\u{1cf03}', '㈪', '𐚥', '🮀', '\u{1da17}',
+    '৳', 'ﴖ', 'ƴ', '၅', '🕮', '𛃎', '𛃊', 'ꚳ', '\u{c55}', '㏹', '㉲', '𔂯',
+    'ⶩ', '𐽴', '🭽', 'ᣙ', '⬡', '🚹', '𘣝', '𔅴', '𞲨', '𐌈', '𓢲',
+    '🆆', '𐚿', '𐧻', '𐭹', '🄤', '𔃥', '𑱅', '𔂢', '᪐', '꜆', '╻',
+    '𖤆', 'Ｂ', '𐝋', '𝄝', '🗂', 'ᕮ', '𓻻', 'ﴘ', '𝠶', 'ᥨ', '𝀆',
+    '🖞', '𐲔', '𜰂', '🁪', '𝆺𝅥𝅮', '𒁇', 'ꐰ', '🍽', '𓑤', 'ᙞ', '𓆗',
+    '𐜃', '\u{1daa7}', '𓘸', '売', '\u{1da1c}', '𝞽', '奈', '𓭙', 'ꄧ', 'ꖰ',
+    '⟻', 'Ⲷ', 'ꔿ', '𖼶', '🄅', '𐕃', '𔊠', '\u{1da36}', '𞢺', 'ఋ', '𒀺',
+    '\u{1928}', 'È', '𑑕', '𛇱', '𛇵', '𝍒', 'ᢠ', '𑐐', '𐘙', '𓤺', '捨',
+    '𑱴', '¥', '🆚', '𝧬', 'ﯡ', '𞺗', '𑖪', '𒊔', '𝙺', 'Ⰻ', '𔐧', '༒',
+    '⩍', 'ㄤ', 'ᑹ', '𞄀', '𓰨', '𓾀', '⺐', '⠲', '𖩮', '𓌙', '𘦊', '𑖧',
+    '💚', '\u{fe0e}', '‟', '\u{1036}', '🡅', '𘰧', '൫', '𔘤', '𔘨', 'Ա',
+    '𘭇', '𖠚', '\u{1136a}', 'ᒸ', '𑈄', '𒐒', 'ᎉ', '𓷓', 'ܥ', '𑻥', 'ᛐ',
+    '𓟼', '౿', '🬸', '憎', 'ꅚ', '\u{9d7}', '𔄿', 'ꩩ', '𓰳', '\u{e0182}', 'ᶑ',
+    '阮', '刻', '𓥖', '\u{e018c}', '𘥾', '免', '∠', 'ﺉ', '\u{cc8}', '𐲅', 'ᇁ',
+    '\u{fa8}', '𞴴', 'ᤚ', '૦', '𐬁', '🁇', '𔆘', '𘰆', '𑄄', '𑩰',
+    '\u{11c9b}', '⥫', '𔄷', '𐮊', '⺏', '᭾', '𜿂', '𘤦', '𔊴', '𔆝', '⇓',
+    'ᜆ', '𓚦', 'ሒ', '𓚲', '𒁺', '⸙', 'ᗻ', '⍢', 'ʯ', '𝃆', '𑁘', '꘠',
+    'ﲕ', '𞢘', 'ଔ', '𖣺', '𞢖', '𜽞', 'Ⓛ', '岍', '𞗕', '𖣷', 'Ᾱ',
+    '𓒉', '🕀', '꠫', 'Ⲹ', '𝣐', 'އ', '‽', 'ᾋ', '𑼃', '𐮯', '⤏',
+    '\u{f75}', '隣', '∼', '𔋆', '𑋗', '𝣁', '𒔉', 'Դ', 'ҭ', '𔔱', 'ꅇ',
+    '𝗑', '𝛲', '𝃈', '⯑', 'ᳬ', 'ᢽ', '\u{c40}', '𓒧', 'ࣁ', 'ᦓ', '𐢉',
+    '୵', '𝗿', 'ݓ', '𝁗', 'ἵ', '𔓈', 'ᾖ', '𐇼', '꜖', '𔆴', '𓌗', '𐑡',
+    'ꓧ', '𝗃', '𖬘', '𓏧', 'պ', '𐅠', '𘫰', 'ꠎ', '𑱩', 'ﰡ', '𛂘', '𑚎',
+    'ᶼ', 'է', '⊃', '𝍲', '\u{1a58}', '𔍽', 'ṹ', '⋰', '🌨', 'ᘡ', '\u{e006c}',
+    'ᮔ', '᪀', '𓻥', '𖪀', '𐃄', '𓋓', '🀅', '𐠭', 'ꎊ', '\u{8f6}', '𒌭',
+    'ѥ', 'ﱂ', 'ተ', 'ት', '७', '𒔱', '𘣁', 'ᔘ', 'ힻ', '\u{1daa1}', '】',
+    '𞄣', '𐅕', 'ꁊ', '𞤹', '𓃐', '᧽', 'Ộ', '🮏', '𓧈', '𓧍', 'ट', 'ﷄ',
+    '𓈜', '𓟪', '𓍍', '🝉', '𔉊', 'ธ', '𞊞', '𓙖', '⏹', '𓟺', 'ᘍ',
+    '𞡱', '🫄', '𛋊', '𘣖', '𓽟', 'ᗜ', '𔍳', '𞟭', '𖡎', '𒆣', 'ൺ',
+    '✻', '⪺', '𑿆', '𞸷', 'ⳃ', 'ꡇ', '𖥉', 'ὢ', '𓺒', 'ࡕ', '\u{1e018}',
+    '⟗', '𓼽', '𐖐', '⾯', '𞠭', '𑒋', '🏏', '𓉚', '𜹽', 'ᓌ', '𐃑',
+    '𝩭', '\u{1e029}', '𘣌', '𐒠', 'ᒽ', '見', 'Ẏ', '␖', '🮍', '𑶩', '🎠',
+    '㈓', '𔇿', '𓃴', '𝁿', '܍', '𓧕', '𜵅', 'ߖ', '𓉃', 'เ', '𑌘', '■',
+    'ﮨ', '⑤', '🃫', 'Я', '🃳', 'ꆜ', 'ꆴ', 'ꬣ', '⒵', '᧼', '🛖', 'ꭆ',
+    '𝼄', 'ᔔ', '𓃭', 'ᐤ', 'ᨌ', '\u{5a1}', '𛆋', '𒒏', '𓬾', '𔆳', '𝈐',
+    '𓢖', '𓪋', 'ጟ', '𖠠', '𐀵', '߁', 'ៃ', '꒥', '⠯', '🬵', 'औ', '𓀦',
+    '\u{8e9}', '𑪄', 'ᗙ', 'ष', 'Ї', '🤕', '𖹎', 'ꦪ', '𝀶', '⪒', '𓬑',
+    '𛃼', '𒁚', 'ꩳ', '𐺱', '𛀙', 'ဍ', 'ൻ', 'ᢟ', 'ೞ', 'ꉊ', '𒃒', '߸',
+    '𝃬', '𖹆', '𝤙', '⧆', '⼜', 'ᶆ', '˧', '𓁋', 'ƒ', '𔈣', '࿖',
+    '\u{11439}', '𘩞', '\u{10ae6}', 'ⓣ', '\u{1da40}', 'ᔯ', 'ᔹ', '𔏗', '𔏓', 'Ⅹ',
+    '𝄵', '∺', '𓿆', '𑐊', 'ᐱ', 'ⲗ', 'ז', 'ᵏ', 'ꈸ', '𓨤', 'ꈶ', '𓼕',
+    '⤶', '𛄍', '✜', '𒕁', '𖦔', '¦', 'Ȭ', '𑧞', '⬵', '߄', '𔕎', '…',
+    '௩', 'ᬥ', '𔎵', '῎', '𒂵', '𐃔', 'Ქ', '𜱜', '𖤓', 'ᭈ', '᭗', '𜶅',
+    '←', '𞗘', '𓅄', '𓁗', '𜸰', '🍉', 'Ჽ', '⯝', '𒒌', '𖦜', '\u{303}',
+    '🤵', '𑯑', '𒃵', '請', '𘡂', '𘳕', '𖄰', '⑱', '𑼌', '𞟠', '𞟥',
+    '𓹯', '🇧', '堲', '𐬛', '\u{302f}', '𓭹', '𝦎', 'Ɡ', '\u{e48}', '𓊡',
+    '𒃬', '𝍮', '𓁤', '𓦿', '𔎹', '𖹳', 'ᭀ', '𓍖', '𢆟', '🏦', '𑯓',
+    'ꐹ', 'ꔃ', '𐅶', '㎦', '🀩', '𛃛', '\u{819}', '꜂', '⇎', '𛱆', '羽',
+    '⦊', '𓘅', '𖥵', 'ꘐ', '𘭵', '𛇙', 'ت', '𒔪', '𓽠', '𒇯', '𜲗',
+    '𑱤', '⠌', '🧜', '␩', '𔁹', '𑏍', '☙', '𓺄', 'ꩋ', '𞠺', '🛹',
+    '𓴫', '🏱', '⢰', '४', '𑒼', '㋼', '⠇', '𝖷', '🝔', '\u{e0128}', '𑪆',
+    '㈦', '𔐆', 'Û', 'ᇽ', '𑊃', '𔄟', 'ޤ', '⠭', '𓈝', '𖣞', '𝘜', '𞗧',
+    '𛋘', '𓪨', '𖨔', '☍', '𑱄', '𞊚', '𝢌', '𔌻', '𓴃', '🈱', '𓕚',
+    '✰', 'ⴜ', '💮', '𓰙', '𔐡', '将', 'ܞ', 'ﺩ', '⍛', '𝦑', 'ᡌ', '𑄈',
+    '🛉', 'ꊮ', '𑩃', '呈', '𑏔', '𔌶', '𞗛', 'ꛭ', '᨟', '𘦘', '㇠', 'ᛛ',
+    'ᇼ', 'Ү', '𓡗', '𓮱', 'ꭀ', 'ᎃ', '⯶', '｠', 'ꡂ', 'ᙙ', '𝙊', '㈷',
+    '𛆘', '𐬺', '𜱂', '𓗦', 'ᜱ', '⣨', '𜽶', 'ꆛ', 'ℨ', '㍽', 'ꉖ', 'ｴ',
+    '𞟶', 'ꑿ', '〕', '\u{7a9}', '𓈗', '𛰟', '𖹨', '🐖', '🐹', '𓴰', '𞸻',
+    '▹', '᠅', '⦚', '𜲻', '𖿣', '挽', '‸', '𐁗', 'ヂ', '𐬪', '⡮', '𘩤',
+    '𐰫', '𔊯', '𓹅', '\u{e012d}', '𔖘', 'ቒ', '𛀯', '𛀫', '⨪', '𝝽', 'ẜ',
+    'ꕿ', 'ȸ', '𑌦', 'ꄗ', '𞴔', 'ꈛ', 'ᗬ', '𓥉', '㉆', '𔔉', '𝒖',
+    '\u{e003b}', '况', 'ꈄ', 'ಪ', 'ឋ', '⎆', 'Ꙕ', '𓂆', '⥯', '🜿',
+    '\u{111b8}', 'ﻳ', '𐃙', '𒁅', 'ໞ', '𚿶', '𐜝', 'ᓩ', '𐿄', '❸', '𔖋',
+    '🥘', '𓏢', 'Ⲗ', '𘲵', '\u{352}', 'ߛ', 'ዚ', '𓧠', 'ϳ', '𒿠', '𔀵',
+    '𑿜', '𐭩', '𓛁', '⋝', '\u{c56}', '𐺑', '𒅄', '🫷', '𐝆', '𐚀', '꯰',
+    'ᖑ', '𓰾', '⸣', '🆒', '𞢕', '𘠺', '𓾏', '𓾋', '༝', '⠷', '𓮎',
+    '\u{16b33}', '𝈤', '📤', '𑁟', '🡒', '⁹', '𜳚', '𓍟', '𜳢', 'ေ', '⼶',
+    '㎽', '𓽿', '𓔺', '🌱', '𒓬', 'ᖗ', '☘', 'ദ', '𝝺', '虩', '𓖄',
+    '𓚶', '蝹', '𖤵', '𐁘', 'ᰆ', '𘧢', '⯐', 'ꪨ', 'झ', '𒄐', 'ರ', '𘣆',
+    '𐜖', 'ᕧ', 'ᝄ', '⌖', 'ꤹ', '𛂇', '𓮽', '𐲝', '𐍞', 'ﴩ', '□', '⽓',
+    '𓁸', '\u{1a6b}', '𝙿', '𑀫', '९', '〰', '𓇭', 'ꤋ', '𥉉', '𔅞', '𝛋',
+    '🕩', '𖵸', '🝑', '\u{ccd}', 'ợ', '𝪁', '𝘄', '𐧹', '⌾', 'ﾯ',
+    '\u{e01dc}', '𐼆', '𝥖', 'ᴐ', '𓮸', '𐃜', '߶', 'ꜞ', '\u{e016e}', '𑙘',
+    '𖡄', 'ﴃ', '🪵', '൛', 'ᦳ', 'ဦ', '㎐', '𐃪', '𐀹', '𔃽', 'ប', '𓗂',
+    'ໝ', '𓤰', 'ᦜ', '᳇', '𐴃', '⾠', 'ᣦ', '꒱', 'ཇ', 'ᯇ', '㈙', 'ಞ',
+    '𒉜', '௮', '𘬹', '𖺗', 'ٸ', '〗', '𜰲', 'ￕ', '⎊', '\u{595}', 'ᇋ',
+    '🩒', 'ꉹ', '\u{fe07}', 'ⰳ', '𔀬', '𝒾', '⪄', 'ꖘ', '𐘗', '𖨏', '𑖨',
+    '𔒚', '㍢', '𖤦', 'ﬥ', '\u{aa2a}', '🦆', '𜴃', '𜾧', '𘤷', '𑲎', '𒒭',
+    '𝄃', '\u{e4c}', '𑫔', '𐘁', 'ཌ', '⒰', '𔈙', '𓊍', 'ў', '𝓸', '⒜',
+    '𖼴', '⒤', 'õ', 'ꗄ', '𓑩', '⚽', '—', '𜹑', '🞢', '𐦴', '𓡧', '𓟧',
+    '流', '虧', '➻', '📨', '𒆢', 'ꮷ', 'ﾠ', '▕', 'ꮳ', 'ꮴ', 'ﯞ', 'ቐ',
+    '𓨔', '𔌷', 'ꆹ', '⤸', '𐤿', '🙏', '𓇰', '𔗶', '𝚊', '𑒪', '𘪘',
+    'ෛ', '𐵋', '𑪀', '⫽', '𓌦', '𐔴', '降', '𔅉', 'ჷ', '𔅊', '\u{e0061}',
+    '𓴬', 'ⱳ', 'ċ', 'ﭵ', '⇤', '⌷', '𖨟', '𝌲', '𓃅', '𞺧', '𓜛', 'ꌕ',
+    '🢥', 'ﬣ', '𓘪', '𞗤', 'ꌴ', '𑵹', 'ꑄ', 'ϣ', '𐘃', '𖥗', '𒐖',
+    '\u{32c}', '𓎜', '𓮞', '\u{32b}', '𑀟', '𔓒', '𝈨', '𛰘', '𒓳', '໑',
+    '𖦿', '꧄', '𘱠', '㊔', '𓰩', 'ꨅ', '≤', 'ꋱ', '⽴', '🁻', '𔀍', 'ﱡ',
+    '᎘', '𘯸', '꧃', '𛉲', '𓂙', '𖦤', '𑈮', '𑁬', '啕', '𓠃', '𓛄',
+    '𝈹', 'ꄉ', '\u{11a5b}', 'ᅆ', '𐰌', '𓸌', '𝡽', '⢽', '⡹', '𔌭', '𜾱',
+    '𜾛', '\u{963}', '🀀', '奄', '𑩫', '𓯂', '𖥥', '〣', '𛰗', '꒖', '𜳇',
+    'ⓤ', '𑛔', '⓽', '𓋅', '8', '🤘', '𝚞', '🩸', 'ë', '𝑑', '𓸇', '𓸃',
+    'Ꜿ', '🥠', '𛀰', 'ܐ', '𛈏', '🕾', '🢹', '𞠮', '𔂂', 'カ', '𓲲', 'ψ',
+    '\u{d41}', '⥡', '𜰸', 'ᜨ', 'ۈ', '𝄾', '\u{1da44}', 'ñ', '𓹥', 'ퟚ',
+    '\u{e0164}', 'ꘌ', '𘱭', '𑖩', '𞲛', '🝊', '🌁', '𓩱', 'ꑮ', 'Ⅴ', '𝟢',
+    'ᾲ', '𑃳', '꧊', '𐵖', '𐢆', '𑧡', '𝣃', 'ꗅ', 'ꡗ', '⨗', '𓜳', '﹨',
+    '𑙤', '𐜢', 'ⵧ', '⯼', 'ቴ', '𐤃', 'അ', '𓴛', '𐝕', '🤰', 'Ὁ', '᭠',
+    '𐒛', '🩄', '𔉷', '𐍒', 'ײ', 'ꈼ', '🁄', '🁀', 'ꘓ', 'ᕪ', 'ㇸ', 'ィ',
+    '\u{331}', 'ァ', 'ᵴ', '䷘', '🀴', '㏙', '𔓓', '𑘗', '𞄐', '𓖋', '𐞰',
+    'ݾ', 'ᆩ', 'ꟴ', 'ㄟ', '𑎂', '\u{e0036}', '𞊠', 'ꠚ', '📂', 'ㅣ', '𝑏',
+    'ঝ', '𝟝', 'ᦑ', 'ꔩ', 'ડ', '⋀', 'ኀ', '𔅕', 'ᵱ', '⸓', '𓛂', '𓘫',
+    '𓘯', 'ꀀ', '𜱤', 'ͻ', '𓁱', 'ᚡ', '🙮', '䑫', 'ף', '⻬', '\u{17cc}',
+    '👣', '𒓮', '⻍', '⌄', '𔓗', '𑤦', '𐴣', 'լ', '🍛', '꜄', '་', '𔅚',
+    '🐑', '𒄼', 'ꑦ', '𝍩', '𞸀', '𜸗', 'Ⳁ', '𓨝', 'ߍ', '⒳', 'ʰ',
+    '\u{e01e6}', '𑵬', 'ﱺ', '𒅱', 'ￗ', '𓝉', '𖢖', '⺰', 'ủ', '𑑎', 'Ἳ',
+    '▘', '𐔍', '𐙚', 'Ⱎ', 'ᙩ', '𐅣', '鍊', '𝖚', 'দ', '𝖒', '💨', '𝖊',
+    'ທ', '\u{11f40}', '🔥', '꣗', '𓼛', '𐰻', '𓪰', '𞲑', 'က', '𝤖', 'ꩉ',
+    '🖬', '⊳', '𒃔', 'Ễ', '𑗊', '⯆', '⥗', '𓢥', '࿐', 'ꃷ', '𝚐', '𓊿',
+    'Ϊ', '⬚', '𓂍', '𐩿', '𓴇', '𐬙', '\u{1ac1}', 'ᱪ', '༸', 'ꔶ', 'ꩇ',
+    'ꕝ', '𘲏', '𘲋', '𓺰', '\u{1d182}', 'ମ', '𓲥', '𐑾', '🔘', '𝖫', '၄',
+    '𓤯', '𝞐', 'ꜣ', '\u{10379}', '暑', 'ꝕ', '𘱻', '𒀎', '頻', 'ꌙ', 'Ꮲ',
+    '𓚌', '𐓠', 'ᄘ', '𐐧', '𒁵', '𞢫', '𖡳', '┝', '𞺎', 'Ꞃ', '𜾻',
+    '㉮', 'ꍯ', 'ꂬ', '\u{193a}', '𝚳', '𓤞', 'ʈ', '␐', '芋', 'ꑌ', '\u{11c9f}',
+    'ᢢ', '𑘩', 'ຓ', '𝇖', 'ᇐ', '▧', '𓍋', '𖮊', '⿹', '𐿧', '𖵔', '𝜚',
+    '𐍤', '🔟', '𓲰', '🭜', '\u{11128}', '揄', '𘣄', 'ᩒ', '␕', 'Ⓖ', '𐘅',
+    '\u{1dcf}', 'ꮓ', '\u{e0191}', '𓭍', 'ᜫ', '𓜧', '𔆮', '\u{d63}', '𝀱', '𘥐',
+    '𐨪', '𑤝', 'ﶄ', '𒍱', '𔌉', '𒊋', '𐠰', '🗢', '🨎', 'Ꚍ', 'ᑛ',
+    '𑛙', '𓨓', '㌬', '𔋴', '𝡩', 'ឨ', 'ຆ', 'ﴛ', '𖡮', '🞻', '⑯', '𖩒',
+    '㿼', 'ꜯ', 'ქ', '𓥃', '⼼', 'ᶂ', '𞓔', '𓛼', '𘣀', '𖫪', '🞌',
+    '𓳯', 'ꅕ', '⿾', '⮀', '𘡭', '𑂋', '\u{bbe}', '𐬄', 'Π', '\u{1344c}', '𓫦',
+    '⭸', '𒀼', 'ळ', '𘪞', '𝜽', '━', '\u{e0134}', '𐎄', '𒈳', '𑘬', '𖦍',
+    '\u{20d6}', '𝪂', '🚊', '❿', '𐎝', '𔔧', '𞣊', '𝣪', '𒂓', '𖄂', '𐌍',
+    '𘲧', '🧏', '𑿧', '𝂰', '𓭜', '叱', '᠐', '∩', '🌵', '𔆓', 'ᬐ',
+    '⚜', '𞡵', '┚', '⠬', '⓷', 'ᅪ', '🡰', '𘣬', '𜵈', 'ﻜ', 'ꖓ', '𓰊',
+    '⩴', '𓰉', '𞗩', '𞄦', '𘥮', '𝜡', 'ᒦ', '𒔟', '𘪗', '㎅', '😂',
+    '𞡈', '𜽮', '🫕', '⥇', '𓞴', '𒁯', '𓞧', '🔭', '🥄', '\u{30b}', '𓨏',
+    '㋚', 'ޏ', '𞺇', '⺥', 'ꄚ', '々', '🔈', 'ꂪ', 'ꔞ', 'ꬪ', 'Ᏹ', '𓲗',
+    '丸', '𝧁', '𑩥', '𘤀', 'ꐞ', '🡤', '𑜉', '𛱐', '𘲩', '\u{16128}',
+    '𞄤', '𝆏', 'ｫ', '𔕢', '͵', '𘮭', '𓯯', '📝', '🅣', '🪅', 'ꓸ', '署',
+    '𘭢', '⦈', '𑣐', '𓱀', '𓍫', 'ᔷ', '𐽷', '𓎶', '𝥧', '✠', '𑛄',
+    '𘧵', '𓀃', '𞠍', '𞗫', '𘱧', '𖧱', '𒈜', '𜰅', '᭷', '𑠨', '𐙢',
+    '臭', '𘲔', '𘲐', '𑩱', 'ꥦ', '𐐒', 'ꠗ', '𓍥', '𑫐', '᱂', '𓎦', '៦',
+    '𛀼', '𑗖', '𝑎', '𝑒', '🁺', '㇊', 'ㆣ', '蘭', 'ኻ', '𐨜', 'ﻤ', 'ې',
+    '𛉐', '𐐯', '𝢛', '➊', '𒑞', '𒅥', 'ﶺ', '㇟', '𘮳', '𝐥', '𔊣',
+    '𒂭', 'ҋ', '𓖫', 'Ⓥ', '📲', 'ⅅ', '\u{113d2}', '\u{1e134}', '𛂷', 'ȃ', 'ř',
+    '\u{308}', '朗', '𘦾', 'Ꮢ', '𓨊', '𓬘', '\u{e0050}', '𖼸', '\u{10376}', '🙆',
+    '𑢪', 'ᅎ', '🠻', '𝚯', '␅', '𜲙', '🃪', '𘡩', '𘡥', '𝡢', 'ﶌ',
+    '𒌲', '𝢚', '\u{e0105}', '𖢘', '𝙑', '𝙍', '𑎑', '\u{e0184}', 'ﲺ', 'ម',
+    '煅', 'ṃ', '𒊤', 'ﯲ', '𔉮', 'ᝑ', '𓬐', '𐨳', '𘢇', 'ꡯ', '𓉞', '￥',
+    'ᦣ', 'ꦣ', 'ᥕ', 'ꣵ', '𝃭', '𝀋', '≝', '𔏹', '𓞇', 'ꛐ', '🜭', '𐡡',
+    '𓂾', '𑜽', '𓠓', '𓛙', '𔂕', '𓽁', '𐺣', '遲', '𓳘', 'ꑏ', '🭺',
+    '🫔', '🩭', '᱀', '𛈚', '𓵿', '𓀺', 'ힿ', '𐽔', '𜷙', '𞸆', '𓷂',
+    '⥣', '𘫞', 'ﺭ', '❭', '𐎐', '🬾', 'ꃞ', 'ꃟ', '𐵰', '𖦝', '𜿃', '𓭧',
+    '🔖', 'ꔤ', '🕳', 'ꨰ', '𓶴', '𓹧', 'Ƙ', '⟞', '🟃', 'Ϥ', '⿔', '𑰌',
+    'ᇌ', '𐫀', '𑇖', '🦞', 'ᲄ', '𜶹', '𛄞', '𘨧', '㌔', '𓸫', '𜸈',
+    '𓷵', '𓨽', '𐣥', '𝣈', 'ᄃ', '𑁤', 'ꁌ', 'ᅟ', '⋜', '𑊧', 'ꘪ', 'ⅳ',
+    '𓱾', 'Ⓒ', '𝼅', '𓝅', 'ﮙ', 'ᔿ', '⾡', '🅠', 'ઌ', '𐔔', 'Щ', '𛲗',
+    '𝌗', 'ꜜ', '𓞯', 'ꠞ', '𓏻', '𔑠', '\u{113c5}', '𖧥', '㏤', '𜾫', '🗼',
+    '🏪', '𔕗', '𒇫', 'ꛀ', 'ј', '\u{1da4e}', '𖠭', '𜵦', '\u{11d33}', 'ꈈ',
+    '𓳅', 'ရ', 'ꨓ', '𓾚', '𐘿', '╌', 'ݚ', 'ꎏ', 'ⵦ', '𑫤', '𐨴', '⯨',
+    '𑒠', '𓰻', '𘰖', '𘧆', 'ᖐ', '༇', '𝀑', 'ӕ', '𐂽', '\u{17bb}', 'ވ',
+    '᠑', '🡣', '𝘦', 'ও', '𓽎', '𖪂', '⚤', '𘮧', '𓎔', '᠓', '℺',
+    '\u{fb1e}', 'ㅄ', 'ႜ', '𓓞', '‣', '𘥿', '🌫', '𝝛', '👧', '⬖', '🧿',
+    '𛉆', '𐵝', '൹', '䷆', '⌯', '𐒱', '磻', '怒', '𛆠', '𑋙', '⯽', '⌌',
+    'ᦥ', '𓈕', '𐊶', 'ꮀ', 'ꮜ', 'Ɦ', '✩', '᠔', '𔎛', '𛆴', '𒾒', '𜽓',
+    '🎩', '𘧄', 'Ჭ', '𒂆', '𔋒', '\u{a4c}', '𔖵', '\u{35a}', 'ೳ', '𛋚', 'Ȃ',
+    'ꖯ', '⤷', '⊾', '🚘', '𔂎', '𒁘', '\u{1e002}', '𓅞', 'Ẃ', '⒒', '襤',
+    'Ꮶ', '𖢵', '𓆞', '𔐩', '𞠉', '𓷎', 'ༀ', 'ꄺ', '㊶', '𓛑', '┷', '𐠑',
+    '𞹹', 'ᦍ', '𐄘', 'ꐻ', '𞡶', '憎', '𓺘', 'Г', '\u{1a7f}', '𜴭', '😧',
+    '🆨', '⹇', '⠊', '𝟔', 'Ί', 'Ĭ', '𔎺', '\u{1ac3}', '𓖖', '䷒', '𓟾',
+    '𘩬', 'ⷉ', '𖪩', '𐑇', 'ᾘ', '㊣', 'ⷈ', '🄥', '𝘤', '北', '𐭦',
+    '\u{8cf}', '🏿', '\u{1d174}', '𘤲', '𓲒', 'ﲽ', 'Ỹ', 'ꄑ', '⛲', '𘳌', '⏃',
+    '𐮎', '淚', '㌳', '\u{20ee}', '𔇘', '⹏', '𝔐', '𐘢', '楂', '𒄖', 'ᓒ',
+    '🖆', '𓑹', '⓲', '🁆', 'ꭦ', '𐐇', '⧦', 'Ĺ', 'ﻹ', '⯳', '𝇂', 'ﮞ',
+    'ܤ', '𓱩', '𔏫', '꩔', '𜰐', '⫭', '㎯', '𑝁', 'ⴭ', '⤬', '⑲', '𝚮',
+    '⍌', '𜲛', '𑣁', '𑑗', '🅪', '𓈭', '\u{16fe4}', 'ꄬ', 'ሦ', '\u{13448}',
+    '𑒒', 'ｉ', 'ꖼ', '🌘', 'ᾬ', '𜶝', '𑖰', '𒊰', '𝄊', '「', '𐾵',
+    '𐓬', '𢯱', '𑿒', '𑈿', '𑇕', '🎯', 'ዧ', '⬏', '𑢱', '𝥇', '𘯩',
+    'ৱ', 'Ꜳ', '瑱', '甤', '꣘', '𝡧', 'ⱥ', 'ꅓ', 'ཥ', '🉐', '\u{32f}',
+    '𑨩', '⺶', '𦰶', '𞓑', '𝢦', '𜾋', '🏥', '𜷼', '⩤', '𐿦', '𖵛',
+    'ⲵ', '𛊃', 'ᛗ', '𖵶', 'Ꮧ', '\u{e0132}', '𝔛', '﴾', '\u{747}', '\u{1ce6}',
+    '⛻', '\u{11836}', '\u{20d4}', '\u{e0033}', '𓫊', '𖤻', '𐅢', '🃎', 'ꞃ',
+    '𐍖', '𘡅', 'ꮌ', '𘭰', '𓬸', 'ꖨ', 'ᑘ', 'ඓ', 'ꔷ', '𘧔', 'ٲ',
+    '\u{1cf28}', '🍫', 'ﱄ', '𔁣', 'ځ', '劣', 'Ŭ', '𖫑', '𐀑', 'ㄞ', '𐁛',
+    '𜵪', '𞄼', '⤗', 'ꋩ', 'ꇔ', 'ꆍ', '⦄', '𒎄', '🨕', 'ಛ', '𝛤',
+    '\u{899}', '𘥲', '糣', '🩡', 'ລ', 'ꛈ', '𒌂', '¯', '≾', '屠', '👶',
+    '𝘯', 'ὃ', 'ӗ', '𓈀', '𖨫', '﬩', 'ὥ', '🠔', 'ꖫ', '𓙜', '𓙴', 'ꕡ',
+    '𐚲', '\u{8fd}', '𞠾', '𑅰', '𝼂', '\u{11100}', 'ﵜ', 'ぷ', '𔅣', 'ᙀ',
+    '𘩰', '𓚧', 'ᨸ', '᎑', 'ꋞ', '\u{5bd}', '߃', '𓱤', '\u{1da56}', '𔘴', 'ҹ',
+    '𓞫', '𔖉', '𖢊', '𑘑', '稜', '\u{113bb}', '˵', 'ᙤ', '𔕘', '𔓷', 'ꕣ',
+    'ᮅ', '𝀔', '⟁', '🏺', 'ᒘ', '𘩾', '𔄑', 'ꓻ', '〤', 'ᚊ', 'ﺰ', '𒾸',
+    '𓗬', 'ᄷ', '亮', 'ᩯ', '⨒', '𘤴', 'ꢺ', '𓲎', '𓴽', '⬄', '\u{e4e}',
+    '𑼫', '𓶹', '𓋶', '𓪮', '㇡', '㼛', '𓶦', '𖽀', '𒆟', '\u{116b7}',
+    '𛁯', '𝋭', 'ﶥ', '𞲔', 'ش', '𐠥', '𞢍', '𓮇', '𑚚', '𐙼', '𓮌',
+    'ዋ', '𐡚', '𑈑', '𖣌', '𘤆', '𐞐', '𝅪', 'ൕ', 'ዄ', '𓏲', '𓏪',
+    '𐧞', '𞗣', '𑋚', '⋉', '𒊒', '𑅠', 'ｶ', 'ᔫ', '𝘸', 'ｸ', 'ⵘ', 'ヴ',
+    'ꇏ', '⋯', '冒', '\u{1bc9d}', 'ꆅ', '࠾', '𜹗', '𓋩', 'ཌྷ', '𔋉', '𛈦',
+    '⌺', '㉩', '𘢊', 'ﳪ', 'ꞔ', '\u{10f4f}', 'ﳽ', '𜹹', '𐎈', 'ꚏ', 'ꄠ',
+    '𝓗', 'ꒉ', '𓆒', '🌑', '𝈺', 'ꪦ', 'ശ', 'Ồ', 'ә', '𓳪', '\u{e0063}',
+    '𜾏', '↳', '⩻', '\u{1cf07}', '\u{ebb}', '𓩡', 'ṏ', '𓳍', '𔈆', '𓚋',
+    '🞺', '🔁', '𘯰', '𔑛', 'ⵂ', 'ꋐ', '𐊥', '𓸥', '🝌', '\u{8e1}', '𝘢',
+    '𒉋', '𝔡', '⬭', '↯', '𓄢', '𖡥', '𒈹', 'Ꚃ', '🇳', '𛱘', 'ꠒ', '⡆',
+    '鈴', '𓇬', '➀', '𓦦', '\u{1cf27}', '𔑟', '𝔖', '⒋', '𝔎', 'ꀷ',
+    '\u{9e2}', '𓮈', '⇅', '\u{111b7}', '𖧻', '🗇', 'ᢜ', 'ꄓ', '~', '𐦏', '🪐',
+    '𞡕', '㠯', '𒄶', 'ꎵ', 'ᴅ', '𒿧', '𓩓', '࠷', 'ꐫ', '𜸫', '𞤉',
+    '𝩳', '𔄌', '᧯', 'ᙥ', '𓮂', '礼', '𘥨', 'ⱙ', '❀', '𜴾', '🢟', '㋦',
+    '𒔓', '\u{e013b}', '𞸖', '𓇴', '𖣰', '꤈', '𖧪', '𐫞', '𔕊', '𔄃',
+    '𔘠', 'ꤔ', 'ᆺ', 'ᾌ', '𓘂', '𛊜', 'ᱧ', 'Ծ', '𑦶', '╓', '𓪛', '𒆿',
+    '\u{e010c}', 'ݎ', '𓊇', '𛆻', 'វ', '𑁌', '𜵲', 'ꅏ', '𝃢', '🐯', '𓁴',
+    '⇐', '𔏞', '𝆒', 'ꐨ', 'ᒵ', '𓟚', '𓡮', 'ڲ', '𞱾', '𓷸', '𓏡', 'ⅵ',
+    'ϔ', 'ퟌ', '𜱡', '𜰴', '😳', '𔔻', '𝓜', '𤾸', '𐞺', '𔓿', '𓮗',
+    'ఱ', 'ﳾ', '𖦻', '𖣝', 'Ӛ', '𞥓', 'Ɵ', '🛢', '𑵮', '\u{e014d}', '㉇',
+    'ퟢ', '𓉰', '𒓐', '𑆠', '⏒', '𖩤', '𓮟', '🀃', 'ঘ', '𐢖', '𘥫',
+    '𞗭', '💌', '𓅰', 'ߢ', '𐧷', 'ᕟ', 'ﱿ', '𘢼', '𑌖', '𜳦', '🝇', 'ᡩ',
+    '⬓', '🮫', '𔐔', '𓈎', '\u{65d}', '🎍', '𑑁', '㈘', '𑛅', 'ퟷ', '⻂',
+    '𖵵', '\u{20d9}', '𐅥', '𐞳', '𑄽', '𒐹', '𝼊', 'Ǒ', '⟰', 'ੳ', 'థ',
+    'ᧄ', '災', '🔧', '𐬽', '𞡺', '𔈋', 'ㆴ', '🡺', '𞸰', 'ᕩ', '𓲈',
+    '᭧', '𐧣', 'ꒁ', '⧽', '䷿', 'ᮼ', '𝘍', '㉚', '𝄙', '\u{947}', '\u{abe5}',
+    '㌋', '𖬢', '𒿪', 'ᕒ', '𔇊', 'ሯ', '🡙', '𓄛', 'ᮯ', '﹢', '𐫅', '🝎',
+    '𜹆', '𓌽', '🆤', '⑦', '𔀟', '㏋', '\u{e0049}', '𓀭', '𘥽', '∢', 'ꌘ',
+    '𛂾', '🅔', '⟐', '𐺂', 'ᓜ', 'ᅒ', '𑲅', '𞣈', '𒃢', '𘫎', '𐲑',
+    'ꒄ', 'ꎮ', '𒌊', '𝐚', '𝐞', '𝐢', '⎦', '𔐯', '𝐎', '流', '𑵲', '┊',
+    'ꈡ', '𑐙', 'ጰ', '𐙫', '𓩅', '𓩁', 'ꊙ', '\u{a8f1}', 'ꄅ', '𖫫', 'ᾀ',
+    'ퟮ', '𓂫', '𖬏', '𐊡', '𒊖', '𘪼', '𖭤', '𚿹', '𒆇', '𐐵', '〸',
+    '𓔆', '𓶡', '𐵗', 'ٳ', '\u{bd7}', '𓩃', 'ꐌ', '𑯞', '劳', '𐼍', '⏴',
+    '᧴', '˴', '👿', '𑘊', '𔖟', '꩜', '𑁡', 'ر', '𖣳', '𔖳', '𓟎', 'ꩰ',
+    'ᇴ', '𓗵', '𓡱', 'ⲛ', '𒄡', '⭞', '𐙹', '൞', '𔘢', '𓚽', '𐭸',
+    '𘨾', 'ﶮ', '𒇑', '𓘐', '𛄖', '꧇', '𑨬', 'Ɀ', '𝦧', '𓇓', '⋎',
+    '🥗', '𐓢', 'យ', '𐙳', '𞣌', '⬒', '𛇈', '𑫕', '\u{13434}', '🝕', 'ꉛ',
+    '𓹨', '𐐨', '\u{e002d}', '⽸', 'ㆆ', '﹤', 'қ', '𝓞', '𑛁', '＋', '𞗲',
+    '社', '🀼', 'ꛯ', '𓀒', '𓝛', 'ꈔ', '𑋉', '🟩', '𒆉', '🅢', '𐡽', 'і',
+    '®', '𘣶', 'ᐓ', 'Ꭽ', '𜾬', 'ﭙ', '⭩', 'ﷺ', '🬙', '𐾷', '直', '𘨕',
+    '\u{17dd}', '🩢', '𐅎', 'ດ', '慌', 'ഛ', '𑊰', '𞹍', '𓕅', '𐡬', '𖼽',
+    '𑆫', '♂', '𖦐', '𘲸', '𓩽', '𘲰', '𐎀', '🐬', '⍕', '𐕼', '𓪫',
+    '𐬡', '𐛤', 'ꈢ', '𜾶', '𑰍', 'Ꮣ', '𖦁', '⫣', 'ᰀ', '𝐭', '𓽑',
+    '\u{ac1}', '𛃶', '𝄰', 'ꀮ', 'ఘ', 'ࡊ', 'ꕵ', '🭩', '𒿦', 'Ȫ', '𓺗',
+    'ࡎ', '﵋', '𑻫', '𞥞', 'ꚾ', '\u{1cf8}', 'ㅹ', 'ﬗ', 'ῴ', '𐩆', '⋾',
+    '𔗗', 'Մ', '⍜', '𓢐', 'ㅩ', '𥾆', 'ড', 'ꃁ', '𜸖', '㎛', '㎂', '📢',
+    '🎉', '𖧰', '꧲', 'ᤂ', 'ᇏ', '𓄐', 'ㄣ', 'ử', '𘤅', '𒀴', '\u{e0024}',
+    '𞅂', '𞀴', '𔇻', '𓙭', '⬂', '𑪅', '𐵱', '𑻬', '\u{dcf}', '﯀', 'ꯐ',
+    '𑘕', '⠴', 'ᑠ', '꩹', '𓃲', '῟', '𐛄', '𒓡', '\u{f35}', '𐳥',
+    '\u{111be}', '𖣈', '⌢', 'ﵒ', '老', '𖼞', 'ᠮ', '𘯎', '𘦇', '😜', '📉',
+    '𜽑', 'ᔧ', '𝂆', '🕲', '𓴱', '𞤎', '𓩹', '㎃', '🬍', '⊪', 'בֿ',
+    '𑱕', '隷', '𖩶', '☜', 'ᵄ', 'ᖕ', '⹘', '𓹑', '׆', '⾬', 'ꮫ', '㋪',
+    'ൽ', 'ఈ', '𘥕', '𓏂', '𝇡', '𑣏', '鶴', '\u{35f}', '𓆮', '⭐', '⛺',
+    '𝛩', '🮗', '⡷', '𛊌', 'ꬅ', '𜲁', '𑓒', '꤁', '𜸁', '𐎳', '⸞', '႞',
+    '𐜧', '⛘', '𐜡', '𜱀', '𐓍', '⑇', 'ᇞ', '⯭', '𐫄', 'ጴ', '๒', '𓔐',
+    '┩', '❖', '𜴂', 'ⴔ', 'ῥ', '⊊', '𑊋', '⒙', '⿄', 'ᇜ', 'ﰊ', 'Ƴ',
+    '།', '𑲉', '🩰', '⋟', '\u{11633}', 'ⳬ', 'ⵃ', '𞸓', '\u{11639}', '𖼌',
+    'Ⱆ', '䷽', 'ᓐ', '𝣿', 'ŝ', '🬞', 'ᆦ', '\u{e01d8}', '🡡', '⭑', '𓐁',
+    'ᒩ', '𑇣', '𖽱', 'ࢭ', 'ޘ', '歔', '꒰', 'ઑ', 'ࠍ', '\u{1d172}', '𔋃',
+    'ⲅ', '𓎺', 'ᯈ', '𓷝', '𓨐', 'ꨐ', '𖣊', 'ꀌ', '\u{8f2}', 'ᔗ', 'ᔡ',
+    'ⅱ', '⿳', '𓔠', '⧵', 'ﰱ', '𘩋', '𐘒', 'Ꮎ', 'ꤰ', '𓥦', '㊒', '𖵏',
+    'Ꞣ', 'ጔ', 'ᆴ', '￪', '𞤿', '㎶', '𘤑', '-', '𑐖', '𖦢', 'ᔲ', '𘤫',
+    '😸', 'ꃅ', '𓋞', '𓊥', '𐃂', '𒔑', '\u{11cb5}', '﹐', '𞸴', '𜽠', '𐗭',
+    '𓽡', '🈵', 'ૹ', 'ꏣ', '’', '𓮍', '⋅', 'ݐ', '㆞', '𖢶', 'ᴆ', 'ױ',
+    '𔆩', 'ᳱ', '䍙', 'و', '𜾼', '𐀍', '𘡙', '🗦', '﮼', '𑶊', '𑄏',
+    '𐬱', '𓛤', '𑝀', '🅍', '𔀂', '𛋏', '𛋋', '𖦂', '㊢', '𔑈', '𔑄',
+    '𖼟', 'ᛊ', '⠰', '𐇤', 'ᚲ', '𖤋', 'ꁴ', '𑱟', '𞺭', '⢬', '⓾', '𓍉',
+    '꤮', '𘱅', 'ₙ', '🐡', '𒃧', '麟', '\u{34b}', '🕠', '𖽸', '﨨', 'ﭲ',
+    '\u{11366}', '𓤋', '𖥁', 'וֹ', '𓙨', '𓄝', '‒', '𔃵', '𑒛', '⨸', '⠚',
+    '🞒', '𘪅', '𓤂', 'ѱ', '𓂜', '𓂖', '𒊫', '𑱪', '憎', '𓯃', '𔌮',
+    '𒈺', '🀿', '𝼕', '⦖', '𓖾', '𓞔', '𘩀', '𐤆', 'ᝤ', '⤂', '₪', 'ꚩ',
+    '𑊨', '𒌙', 'ਲ਼', '𛇫', '\u{e0195}', 'ಢ', 'ꁛ', '𑻱', '𜲜', '\u{11073}',
+    '◦', '𔐄', '𛅕', '𔋺', '𐇞', '𖢼', '𓘰', '𝕹', '𑒩', 'Ꙍ', '\u{16b30}',
+    '𝋥', '▸', 'ഌ', '❉', 'ꙡ', '𘮿', '𓛃', '𔎕', '𘠳', '𓠂', '𒀮',
+    '🀈', '◰', '諭', 'ᣠ', '\u{314}', 'ﬁ', '𓘤', '𖧉', 'ﵤ', '\u{2060}', '𐡭',
+    'ᾠ', '🞃', '🤮', '𑁓', 'ၗ', 'ꑪ', '𓀨', 'ꑡ', '𐎜', 'ԉ', 'ꥒ', '㊓',
+    'ꎯ', '𓫡', '𖮋', '⥷', 'ℱ', '𝀯', 'ೠ', '𛇨', '㋏', 'ᑽ', 'ሄ', '🃄',
+    '꘤', 'ﴕ', '𑱶', '𒀯', 'ꏦ', '𔑇', '𔑃', '𓺆', '𓧸', 'ᢍ', 'Ԓ', '㏉',
+    '𘲕', '𐎪', '𐒧', '\u{aaf6}', '𐀩', 'ڨ', '\u{1dca}', 'Ꝼ', '＿', '𐰭',
+    '💘', '┃', '𐝥', '𓰼', '𐒳', 'ꋍ', '𐩂', '𐧌', '𞄡', 'ℊ', 'ᐏ',
+    '𔘷', '𑵫', '𝈑', '😎', '𛉘', '🬁', '🔓', '䷥', '\u{f81}', '𐹮', '𓆍',
+    '\u{1753}', '⓺', '犀', '🚞', '𘥏', '𓻡', '𐴘', '𑿌', '𐆑', 'Ⴈ', '𛄇',
+    '𐞤', '𐨟', '𜴲', '⦰', 'ﹷ', '⁆', '⟦', '🤩', '𒍒', '𓊴', '𓒨',
+    '🚕', '𝤒', '𓱆', '𓱂', '🬧', '𓺤', '𔉐', '𓥙', '𑦠', 'ꀭ', '\u{f9c}',
+    'ᡙ', '𘣣', 'Ꝟ', 'ꆦ', '𛋭', '🄃', '\u{fe2f}', '𐊄', '𔗧', 'ᑟ', '𐦙',
+    '𖧅', '𔀤', 'ﻰ', '\u{11c9d}', '𖨌', '𜴇', '蔖', '𑖗', '𓾗', 'ꜻ', '𘳍',
+    '𔑗', '𐛁', '𒔍', '𔑓', 'ま', 'ㄮ', '𓐅', '𔓳', '𓧫', '˚', 'ݭ', '𑯈',
+    '𔓁', '𒔼', '\u{1dd0}', '𝇢', '𖄱', 'ﱹ', 'ﲫ', 'ᵈ', '🫨', '﯁', '艹',
+    '𐩑', 'ᠥ', '㎘', '𓙋', 'ޔ', '⛓', '\u{e018d}', '⁾', 'ࡹ', '𘧧', '𝀲',
+    '╈', '𐦇', '𛀬', '🛓', '᥋', '𔆯', '𝔨', 'ٴ', '🪙', '𐲜', 'ᾄ', '½',
+    'ě', '𑈺', '𐚉', '𛈃', '\u{17c9}', '縉', '𓷚', '𘱯', '🉄', 'ᣭ',
+    '\u{111ba}', '🨵', '₍', '\u{8d9}', '💫', '𝜹', '⭳', '🮢', '𞡗', 'ꥧ',
+    'ꄀ', '𐰷', '𑲃', 'ꁭ', '❜', 'ᛵ', '𛁲', '𛁶', '𓜗', '\u{1d178}', 'ꉢ',
+    '\u{619}', '𝙗', '😮', '𝄄', '𐵒', '𝓱', '𖡅', '𐰿', '\u{1ac6}', 'ꓳ',
+    '𛁪', 'Ȼ', '🢲', '🨿', '𐅷', '𓡠', '𝞍', '邔', '⍆', '⛊', '𖾜', 'ᇥ',
+    '𝄯', '\u{fe20}', '𓾌', '𔗈', '𖼚', 'ﱨ', '∑', '𜸘', 'Ƭ', '\u{eb8}', 'ꊘ',
+    'ꓰ', '𓏽', 'ﶰ', '𛄝', '\u{202c}', 'ᜉ', '䀹', '𓂳', '𘣜', '𘣠', 'ꇖ',
+    '\u{1be8}', '𔅂', 'ꇐ', '𓔞', 'ආ', '𔀈', '𜰼', 'ﲂ', '𐬗', '\u{1163d}',
+    'ॱ', '௷', 'ꢞ', 'ꦱ', 'ຑ', '𜾕', '⚏', '𘢐', '𘢔', 'ꁕ', '🚄',
+    '\u{1163a}', '𒋏', 'ⱬ', '𓷦', '𛃬', 'ᨒ', 'ﻉ', '˪', '𔊬', '＾', 'ﾗ',
+    '𓐕', '㌏', 'Ƞ', '➭', '\u{36d}', '𑤧', '🃲', '🄲', 'ᢔ', 'Ҡ', '𓣫',
+    '𝆰', '𔅖', 'ᯓ', '\u{200e}', 'ぶ', '𐣦', '𓉸', '𖡦', 'ڷ', '\u{8f3}', 'ﮉ',
+    '𝛂', '⋶', 'ﲅ', '𔁟', '🅯', '𘥓', '😿', '𝞷', 'チ', 'ꢗ', '𞴒',
+    '𓡆', 'ᡞ', '๗', '𓁍', '𛱍', '𝘒', '𔃦', '𐾲', 'ﵡ', 'ﯶ', '𐖆',
+    '𣻑', 'Ⴒ', '👦', 'ﺘ', '𑒗', 'ᡣ', '🎔', '𒋒', '𖧝', 'ꥴ', '㊍', '⭈',
+    '၌', 'Ⳣ', '𘥪', '|', '𓕾', '𒋙', '%', '𑊊', '※', '𒀓', 'ᮎ', '𖥞',
+    '𓈂', 'Ⴕ', '\u{597}', '🆗', 'Ⓟ', '🬛', '𓣾', '𔀗', 'ኪ', '𑋓', 'ꬆ',
+    'ⷄ', 'ዀ', '𓺙', '\u{1cf9}', 'ꭵ', '🏖', '𖩈', '𓙐', '⫊', '🤽', '𓲯',
+    '𘧭', '𑖥', '𓧐', '冤', '𔀜', '\u{8d3}', '𑄜', '🀁', '\u{1e08f}', '𜶫',
+    '𘫲', 'ᇿ', 'ؤ', '╰', '𔘂', '⥨', '𝢢', '𘧯', 'ட', '𜱶', '⨴', '𝜎',
+    '𔌥', '𝖀', 'ຮ', '𞹩', '𒾜', '𒒥', '⼲', 'ˉ', '\u{e002e}', 'ၵ', '𑚣',
+    '渚', '␓', '𓒖', '𜽻', '💕', '𘮟', 'У', '🭧', '𒃽', 'ק', '𓘼', 'ꁠ',
+    'ꨋ', '𛱈', 'ꁮ', '𞢐', 'ᴷ', '若', '𖺋', '𞸩', '⓫', 'ᰴ', 'ꔰ', 'ⱽ',
+    '𝂸', '𔂞', '𘨵', '⮋', 'ꗜ', '০', 'ꌳ', '\u{e0111}', '𐘡', 'ᙱ', '🙋',
+    'ᙵ', '𞴓', '𝡚', '⫈', 'ꀴ', '𔅐', '𓅵', '𓊂', '𔈹', 'ⴚ', 'ꂞ', '𐤄',
+    '\u{fe2a}', '𜲯', '𘤜', 'ꓠ', '🩶', '𓊅', '🅎', 'ꦤ', 'ﷳ', '𝥳', '𑩭',
+    'ᔜ', 'ꋻ', '𐅲', 'ﲉ', 'ە', '\u{1e003}', '𓠶', 'ㅫ', '\u{1dda}', '𘩧',
+    '\u{366}', 'ᚶ', '\u{1df1}', '＠', 'Ṣ', '𞲣', '𞀹', '✊', '🧀', '▢', '꒺',
+    'ኋ', '𐭆', '𖩃', 'ᵝ', '\u{a8c4}', 'Â', '𑋏', '𒓉', 'ꟓ', '𐄛', 'ﳣ',
+    '﷿', 'Ლ', 'ￏ', '𛊝', 'ힽ', '㏽', '\u{f9a}', '𜴠', '𝞠', '立', '\u{9c2}',
+    '⍊', '𑜘', '𐍨', '𑊗', 'ꕓ', 'ꞙ', '𓼚', '𜷥', '𖢕', '殺', '𐎖', '⭍',
+    '🭃', '\u{11724}', '\u{1dcd}', 'ᖼ', '׀', 'ኊ', 'ę', '𐃲', '🍖', '꒡', '𛊐',
+    '🚭', '𐴊', '📃', '𝃍', '🐆', '\u{1bc9e}', '𔆶', '𐡗', '𛲓', '𑅶',
+    '⑾', '𐕁', '⟶', '𜰗', '𖧨', '🕊', '𐃘', '𔂨', '𐩰', '𝣙', '⼛',
+    '𓈯', 'ர', 'Ɓ', '𓃈', 'ﰔ', 'Ⱡ', '𞴷', 'ﮐ', '🖝', '𓕖', 'ꝼ', '𓕮',
+    'ꝱ', '𛆧', '𜽝', '𑓆', '🜆', '𝀣', '𝙯', 'か', '𐩗', 'ﳯ', '⼙',
+    '🯓', '\u{cd6}', 'Ｖ', '𞓒', 'ｬ', 'Ｊ', '⢄', '𑿤', '𔀇', '𐎬', '𖮀',
+    '╉', 'ꐦ', '𘱱', '𘰋', '⍔', '\u{112e4}', '⳥', '𓮺', '⼘', '𐅡', '⹜',
+    '⿱', '𐿰', '𓁻', '⮦', '𒇍', 'ැ', '𐰗', '\u{e0175}', '𐰵', '簾', 'ꈲ',
+    '⎢', '𛃚', '⇃', '𒊱', 'ꭁ', '\u{aff}', '🩌', 'ꕒ', '₆', 'ᾪ', 'ﺻ', 'ἁ',
+    '𝕳', '𝕷', 'ꌍ', '\u{1e00b}', '➜', 'ꄐ', '𐩔', '𓻕', '𐇛', '𓩨', 'ꐣ',
+    '𝣺', '𐀥', '𘠇', 'ଖ', '𖥬', '𓑫', '⊭', 'ﮌ', '㏠', '⯕', '𑠀', 'ꗇ',
+    'ǔ', '𑜒', 'શ', 'හ', '𓹒', '⇘', '⡤', '𝅖', 'ת', 'ⶱ', '𐅤', '嗢',
+    '🗳', '𓭾', '𐌳', '𝈷', 'ⓠ', '㇤', '𔅫', '𓐭', '𒃨', '𐫲', '𐄹',
+    '𐖳', 'ﳥ', '𓨠', '𖡋', '𑂛', 'ⁱ', '𓚎', 'ꡝ', '𐬞', 'ᑁ', '⫀', '𓣡',
+    'и', 'у', '𔐓', '👕', 'ſ', '\u{a8ef}', '\u{1da10}', '𝂄', 'ᡏ', '𒍙', '🞯',
+    '㎀', '𘢆', '𐦰', '↸', '𝦪', '𞴭', '𖼾', '𐆠', '𖵳', '𝦾', 'ᗮ',
+    'ﯢ', '𓚁', '\u{5b9}', '𖥿', '𑅃', 'ፚ', '𐧚', '⟺', '𜳙', '𓗨', '𔎻',
+    '╨', 'ຉ', '𔎶', '🮾', '𜳭', '𐂘', 'ꩄ', '𘡼', 'ᩋ', '𒐌', '𖦱',
+    '𒎘', '🄋', '𐭅', '𓈓', 'ண', '\u{1885}', '𝕺', '\u{1ce2}', '\u{1143f}',
+    '𑘉', '𖣴', '𑴂', '𖾃', '𑥒', '\u{11a06}', 'ꂃ', '𝍢', '𒍶', 'ᬠ', '𓏝',
+    '\u{1da2b}', 'ᆊ', '𔘞', '𜸓', 'ӿ', '𜲱', 'ᡄ', '𐕳', '𐤶', '🕇', '𝟬',
+    '𝕰', 'ݏ', '𣚣', '🐶', '𝕤', '𓷕', '𔏬', '𔘅', '𘰞', '𑎚', '᧒',
+    '🠦', '𑐭', 'Ṋ', 'ᆋ', '⽲', '𜱣', '𜴯', '⥚', 'ꔎ', '𝟚', '𜴣', '🟙',
+    'ఉ', 'ꑂ', 'ᮘ', '\u{1112e}', 'ꖡ', '𘰅', '𑁠', '𓙸', '𓥚', '🥬', 'ڱ',
+    '𞺯', 'Ã', 'ꆰ', '😅', 'ᅍ', '𐦚', 'ᒖ', '𝖋', '𒈷', '𐒾', '┐', '⬻',
+    '𝚑', '𝚕', '𛆼', '𒊕', '𖡗', '𘬪', 'ꈵ', '駱', 'Ꝏ', '𜾃', 'ᒀ', 'Ⳮ',
+    '𓣕', 'ߥ', '𐗐', '𓝝', '𔙃', 'उ', '\u{fae}', 'ꨈ', '𞟤', '𓠉', '𝥭',
+    '𞤝', '𒅁', '💇', 'ࠋ', '𓫽', '𓁧', '笠', '𔕲', 'ꮐ', 'ࢴ', '🟎', 'ṷ',
+    '⦵', 'ꐟ', 'ᇺ', 'ꜭ', '🬒', '♎', '𘮓', '𔂁', '𓋺', 'ɑ', '🏧', '𛊒',
+    '𛊖', '💻', '𑩝', '𒀑', '𝟟', '\u{a8e3}', '𘲃', '𝒔', '𓉿', '𓰹', 'ᅗ',
+    'ꆞ', '𓦣', '\u{8db}', '𘬛', '𐕺', '𐔱', '؊', '𝑣', '７', '𑎔', 'ᦷ',
+    '🂉', '₹', 'ញ', '\u{11635}', '🌎', '𞟦', '𐤸', '🮵', '𓔋', '🐋', '𓰍',
+    '𓪤', '𓠛', '⎵', '🕶', '൷', '𖾄', '𝗶', '𝚃', '𘭚', '𓑆', '\u{a6f0}',
+    '🟨', '𒊩', '➮', '🭁', '⯏', 'ﺦ', '𓡬', '𔄴', '𝂂', '﮳', '㏲', '𔐙',
+    '\u{a674}', 'Ṡ', 'ଳ', '𝘎', '꩸', 'ḙ', '𓸓', '𔄽', '𒿁', '𒇈', 'ẛ',
+    'ܦ', 'ꭜ', 'ꡅ', '𔓴', 'ꃵ', '𓗀', '┭', '𓐏', '𓗟', 'Ꙓ', '𐊷', '𑝂',
+    '⏬', 'ㆨ', 'ꝁ', '🜊', 'ﾹ', '𛁂', '𖥦', '𐗡', '𓖛', 'ᘬ', '𑒢', '𔖒',
+    'ᘪ', 'ሊ', '𘩽', 'ᥫ', '𔏄', '𓃚', 'ຘ', '☄', '𐧉', '𞤢', '\u{e01a6}',
+    '𓋮', '𖺑', 'ⴥ', 'ሻ', '𝋦', '𝚜', '⚦', '୲', '𐓏', '🎃', 'ᔵ', '𓼹',
+    '𐰬', '𒍧', '㏈', 'ᙔ', 'Ϣ', '𐄐', 'ช', '𓾩', '𞁍', '𑂄', 'ᔟ', '𔊛',
+    'ࠇ', '𐅏', '𓈺', '𓲋', '𓲏', '🎵', '\u{e01cb}', '𖮍', '𘠌', '𝒱',
+    '𞲟', 'ㄜ', '𒃊', '𖢓', '⬾', '𐁀', 'హ', 'ꮵ', '🙝', 'ᨂ', 'པ', '𔊝',
+    '𔗤', 'ꧾ', '𐊽', '🢦', '𝣭', '𔌪', '𓛗', 'ꉱ', 'ᮓ', '𒀿', '𔈸',
+    '𐋹', '𖄴', '𓐊', '⚂', 'ᖧ', 'Ẽ', '𓿒', '⊠', '𓳢', '🡃', 'ነ', 's',
+    '㏢', '𐎹', '𞢟', '𞴨', '🪚', '𘩶', '𓖘', '輸', 'គ', '𝛢', '\u{11831}',
+    '𑄎', '𜹙', '𒃾', 'ꀂ', '┟', 'ꁷ', 'ꊞ', '𓌏', 'ꊜ', '㍾', '𛀭', '𘠗',
+    '𔘵', '𐖠', '𜴎', '⧉', '🫓', '𔕟', '𓠕', '𞲱', 'ጦ', 'ⲧ', '㊎', 'ഽ',
+    '𝚁', '𑠏', 'Ⱏ', '葉', 'ꠜ', '𔑋', '𒋋', 'Ɫ', '᠘', '🛩', '𒎁', '꧕',
+    '𞋋', '🁥', '𓝏', '𖵩', 'ﾆ', '𓋑', '𑽏', 'ﵾ', 'し', '𔃧', '𒌎',
+    'ᱤ', 'ύ', '𞤜', '𔏯', '∻', '𓰃', '⬽', '祥', 'ⓒ', '⇊', '𑩻', 'ⓦ',
+    'ﻁ', '𓛟', '𒋝', 'ゃ', 'ﾈ', '𝄿', '𖵨', '⬱', 'ఫ', '⧁', '𔈟', '⾋',
+    '𖹕', 'ﷻ', '𝈫', '𒃥', '≁', '🄟', 'ᛡ', 'ﭮ', '捐', '𓪁', 'ҷ', '﨑',
+    'ꊇ', '⫻', '𒋽', '𐽘', '𔄂', '𔄆', 'ⱔ', '𐠌', '𑫀', '𑀛', '🦥',
+    '𑿥', '𐐭', '𑥂', '𓳂', '⦓', '𐤙', '╱', '𖦙', 'ૌ', '𒍩', '🭠',
+    '🚯', 'Ⳕ', 'ㅪ', '𐜭', '🚔', '𝤢', 'ᒅ', '߾', '𑢷', '𔓧', '𐓌', '𝣓',
+    'ꈐ', '𔏛', '𔖧', 'ꎪ', 'תּ', '𤘈', 'ꉳ', 'ᓇ', '𝝑', 'パ', '༪', '𑼭',
+    '😪', 'ﮀ', '؇', '\u{a8ff}', '⽚', 'ा', '٠', '🚇', 'ﲑ', '𔔞', '⽣',
+    '\u{11370}', 'ꞗ', '𞗙', '𑘱', '𑘔', '𓍱', 'ࡋ', '𓧼', '𓆝', '𒂾', '𖠘',
+    '῍', '𓫌', '𞢶', '𓞻', '𐃦', '⮅', '𘡐', 'ｌ', '🏠', 'ᮌ', 'ヶ', '𐂙',
+    '𓃜', '\u{1921}', 'ḧ', 'ᤥ', '⺉', '𓃓', '⼈', '‿', '☤', '𐅺', 'ꤍ',
+    '⊱', '𐇜', '՟', '𘯇', '𝅘𝅥𝅰', 'Ⲑ', '𘮹', '𖭫', '𘥢', '𖠓', 'ࡗ', '𝥆',
+    'ฌ', '𖼘', '༐', '𐗈', 'Ῥ', 'ꯓ', '𔁓', 'ꭟ', 'ꭙ', '聾', '𐤩', '⢉',
+    '𓢾', 'ꐧ', '⯲', '𝛣', 'ꙓ', '𓠞', 'ड़', '«', '🡄', '𑎒', '🍜', '𜺠',
+    '𑪲', '⫒', '𝙭', '類', '倫', '𐙉', '𝙝', '𝙡', '𓌹', '開', '𝟅',
+    '嵫', '⌉', '𑇪', 'ꛝ', '𘡘', '𐅙', '𐳚', '𑒞', '\u{16ff0}', '🤨', '⢃',
+    '🀘', 'ᖥ', 'ɭ', '⮿', '𓆎', 'ゟ', '𛱝', '凉', '𐄰', '\u{11ca2}', '🂄',
+    '㍍', '🌌', '𞴤', '🄚', '𐄡', 'ퟜ', '𓀾', '𑣕', 'ᜁ', '𑒕', '🅱',
+    '𝅈', '𛈵', '🅇', '🏜', '⣖', '𜽛', '\u{e006d}', '⫙', '🃊', '𐧬', '𑄿',
+    '𓂑', '𛊼', '𜴐', '𖨵', '𜸍', '\u{1e00a}', '🢔', '𖧖', '𑐱', '𞓱',
+    '𐦘', 'ꓱ', 'ᕯ', 'ﺙ', '𒈩', '𘠟', '⽬', 'ﺌ', '𑢦', '।', '🈮', 'ꃨ',
+    'ⲯ', 'ꦲ', '𘥄', 'म', 'ꢣ', '𐌂', '𞓓', 'ⅎ', '𝢽', '𓗪', '㌎', '𔒠',
+    '𝜋', '\u{a94b}', '℘', 'Ｃ', 'ℋ', 'ℒ', '𔔸', '𘡨', '𔎟', 'ꚛ', '🚜',
+    '𞁌', '𝜺', '𔄔', '𔄐', '⏌', 'ꚑ', '𝠊', '𝧤', '𝠻', '𓍹', '𐮜',
+    '𞟵', '𜳏', 'ﳰ', '㎡', 'ৼ', '𓅴', '𔋈', 'ค', '𜷋', '῾', '𑘄', 'ꡏ',
+    'ꦦ', '\u{a929}', '⣩', '𘱛', '𛰤', '𓜝', '𝐅', '𜲞', '𞠘', '𓜖', '𖧿',
+    '𓪵', 'ஹ', 'ѣ', '⢀', '𑂅', '🟏', '𝧘', '𒀇', 'Ȕ', '𓙙', 'ᣔ', 'Ἂ',
+    '𓜡', '𒉗', 'ꍿ', '𑯋', 'ᴓ', 'Ⴚ', '\u{e003a}', '𛰌', '𓗅', '𓗉', '𓏉',
+    '𝟲', '𓧿', '𓗡', '⬼', '𓏠', 'ꝓ', 'ᴏ', 'ꍙ', '㋱', 'サ', '𖨎', '🫖',
+    'ᾅ', 'ｵ', 'Ⳇ', '\u{e0176}', '𓕋', '𝋧', '𓢷', '𒋔', 'ờ', '\u{e0068}',
+    '𝌷', '𖢬', 'ﲯ', '‷', '𐇐', '𔆇', '𜹁', 'ನ', 'ꃎ', 'G', '𝟏', '𜳲',
+    '𞤲', '𖼖', '🨗', 'ᦛ', '𖠖', 'ṟ', '𛈍', 'ꤛ', 'ᙧ', '𝐁', '𝜜',
+    '🩫', '𐹦', '𖧄', 'ꐩ', '𔇁', '𓿬', '𑗛', '\u{d3b}', '𓣁', '𝂥', 'ለ',
+    'ᆻ', '꘍', 'ᯀ', '𛇧', '𝑗', 'ꬽ', '𘮚', 'ल', '༩', '𘢃', '𔄡', '𞊔',
+    '𓀍', '🖠', '㎻', '罹', '梨', '𐰅', '𝁏', '⻛', '\u{1d16e}', '꒭', '𑌷',
+    'Ꞅ', '𓚷', '𝠫', 'ȫ', '➶', '𝂉', '\u{599}', '𑜐', '𒄍', '緇', '𓝶',
+    '𓯹', '𖮅', 'ᵧ', '𑵥', '😶', '𜹂', '壘', '🚳', 'ă', 'ﶝ', '𝤯', '𑊢',
+    '⏑', '𒇎', '𘥎', '🙫', '𝛠', 'Ó', '⣎', 'ꌥ', '𐑝', 'ච', '𜱿', '⧙',
+    '𔌽', '𐬮', '␋', '𓲬', '直', '廙', 'ﷲ', 'ﴆ', '₼', '𓛷', 'ᜄ', '㋝',
+    '\u{817}', '𐼒', '\u{a8f0}', '𐡣', '𝆤', '\u{e004f}', '\u{1dd1}', '𒁜', '𔌴',
+    '𞓛', 'Ɍ', 'ㅻ', '\u{11a98}', '𐎢', '𒉛', '\u{20df}', 'ꥰ', 'ᾁ', 'ꑽ', '𝣜',
+    '𓅪', '⢴', 'ᔱ', '𖩛', '⩖', '\u{116ad}', '𐼚', '𓊕', 'ﴍ', '𑄉', '𝼥',
+    '𐵚', '⫚', '۷', '𑫳', '𐦆', '💭', '💷', '⹃', 'ꂧ', '𓨀', '𖢍', 'ﰞ',
+    '𓫥', '𔆖', 'ↇ', 'ꇚ', '🛦', 'ⴛ', 'ᝇ', '𐺄', '𓖭', 'ꠁ', '\u{2de3}',
+    '♶', '𓙎', '𔆟', '［', '𒓱', '\u{653}', '𒉅', '𑆎', 'ꖖ', '𓫬', '𐤋',
+    '⍵', '⽖', '⾑', 'ᬎ', '⻀', '𔑆', '𜲀', '𒒹', 'ﾘ', '𓘓', 'ܣ', '𐴐',
+    '𑌅', '\u{9bc}', '𢆃', '𓌀', '\u{1e00c}', 'ᔚ', '𔖞', '⮡', '𝇍', '𩅅',
+    'ฒ', 'ⳓ', '𝑻', '𝑷', '〶', '𝑿', '𓭮', 'ぺ', '𝧪', '🏅', 'ਫ', '🝴',
+    'ⸯ', 'ᦉ', '⺢', 'ꀊ', 'ꭷ', '𜷮', '𓤑', '\u{111bd}', '⪛', '𑼞', '🈖',
+    'ዯ', 'ꋔ', '𖭛', '𐼲', 'ଢ', '𘤺', 'ራ', '𓀇', '𓋹', '✧', '𛰵', '≙',
+    '𔖚', 'ᑊ', 'ꖢ', '𜺥', '𒁹', '䷤', '␤', '𔒣', '𑠅', 'Ḯ', '㊛', '𑴯',
+    'ךּ', '𖡴', '𘬴', '𖧆', 'q', 'ٷ', '𘠼', 'ሙ', '🜬', '𘱒', '㋷', '⦀',
+    'ݒ', '𑤐', '𔗇', '𝟖', '🩆', 'ᄜ', '𐔻', '𐔡', '⧥', '𓨸', '𔃁',
+    '𖭱', '𚿳', '陋', 'ﭒ', '𓿷', '⍄', 'ꕉ', '\u{8f5}', 'Ⰼ', 'ᘩ', '𑠘',
+    '☹', '𓜔', '𓕵', '𔒙', '𔒔', '𛉕', 'ⵠ', '𓃎', '\u{731}', '\u{1da64}',
+    '㎒', '𐚁', '𜵔', '𓇋', 'ᚇ', '⁸', '𑼩', '𑵶', '⍩', '𞥗', '⤤', '▙',
+    '𖼫', '𐨞', 'ᬈ', '⊆', '𘮛', '🯞', '晴', '𒅃', '〞', '玥', '𐌆', 'ⳁ',
+    '⧭', '𒐫', '𘥈', '𛁤', '𛈺', '\u{cc2}', 'ꅉ', '㎇', '㋟', '𓈧', 'ɜ',
+    '⸇', 'ꊔ', '\u{1344b}', '\u{108d}', '𐜫', 'ᨍ', '𐂍', '\u{1d173}', 'བ', '𞹝',
+    '␗', 'ṓ', '‑', '\u{2062}', '𐭙', '𝪆', 'ジ', '𒁛', '⤐', '𔍆', '𒃼',
+    '禮', '𓒳', '\u{328}', 'ᗖ', '𑅞', '𓙚', '𘤬', 'ង', '𓲌', '㋽', 'வ',
+    '𒔬', '𑤳', '𐎻', '𞟸', '𓼄', '᧔', '𞋿', '₀', '𓎋', 'ゝ', '꒢', '❬',
+    '℠', 'ꭿ', 'ᅊ', '𓎙', '\u{f37}', '⑭', '⅃', '𘯻', '𘫿', 'ഷ', '𝁦',
+    '𓫕', 'ઐ', '睊', '𓆈', 'ﰰ', 'ⷓ', '𞢀', '\u{11c92}', '𓂡', '🌞', '🗜',
+    'ᮝ', '𖄷', '𓿽', '\u{e017e}', '\u{11d42}', 'ᕾ', '𐇝', 'ะ', '㈼', '⾂',
+    'ᢑ', '\u{1103e}', '𘣋', 'ﵮ', 'ꓝ', '憲', 'ⱖ', '𜲘', '𐄜', '𒍳',
+    '\u{11a09}', '㉫', '𘦩', '𜲒', 'ຜ', '\u{11a0a}', '\u{a826}', '🂆', '𐗥', 'Ꝡ',
+    '𔎾', 'श', '𓭂', '𔐌', '🩅', '𐿣', '\u{741}', 'ҥ', '𖠛', '𛄄', '𔂳',
+    '𞢧', '\u{1d16f}', '⸑', '𓸙', 'ᗇ', '𔂺', '\u{205f}', 'າ', '𑤎', 'ሩ',
+    '𓶜', 'ꏒ', 'ꑙ', '𐵌', '𖠂', '𒂨', '𑌚', 'ꈾ', 'ꈴ', '⎮', '𔗒', '𝓢',
+    '𘳊', 'ⶴ', '𔎢', 'Ꚙ', '𝝀', 'ሇ', '𔂷', '𖪪', 'ᄟ', '𓮼', '𝞲',
+    '𛂁', '𛆮', '𖵖', '℥', 'ꔜ', '⸪', '᭦', '𞟮', '𑣄', '㌄', '𒇴', 'ꭲ',
+    '𑶥', '㇆', 'អ', '⇙', '𞡦', '𜱧', 'ꊵ', '࿏', '鄛', '𔇐', '頋', 'ᗏ',
+    '𜺩', 'Ꮨ', '𘮍', '𓝒', '꒶', '\u{cd5}', '㈵', '🮥', '𔇝', 'Ṟ', '⇸',
+    '🉈', 'ಜ', 'ᜥ', '𜷠', 'ꔙ', '🍾', '𐎉', 'ᾷ', '𔉇', '𘧁', '㋾', '𛉁',
+    '𑢯', 'ꇌ', '∔', '𣏃', '≡', '𞋴', '𐏕', '⒱', '𛋕', '🅂', '🃨',
+    '𔇥', '𜱠', '蘒', 'ꎈ', '🈭', '𒅢', '𝙣', '👪', '𐔖', '𒾧', '𑝃',
+    'ᱷ', '᠙', '🜉', '︘', '𝅔', '𓍧', '𐁖', 'ゾ', '𝠏', '𐬩', 'ꍏ', '≜',
+    '𐦅', 'Ꝙ', 'ホ', '𐵶', 'ය', 'ⰿ', 'ᝡ', '㏺', '𜹯', '］', 'ʤ', '𒃱',
+    '𔕳', '𐌢', '𛇁', '𓻍', 'ᰥ', '\u{1dc2}', '𖭣', '\u{e01b3}', '𝠕', '𖹻',
+    '᧲', 'ｻ', '𝢁', 'ꧮ', '𐁂', '𐼌', '\u{5ab}', '𓆚', 'ᮗ', '◄', 'ꎜ',
+    '𘢲', '𛇊', 'ꞑ', 'ㇳ', '𝢶', '𓎎', '𐇴', '꧒', '🨐', 'Ƒ', '𛃈', '𝙲',
+    '𐚫', '🤐', '\u{1d18b}', 'ㅊ', 'ၩ', 'ၭ', '𛃌', 'Ḱ', '🤬', '𐇷',
+    '\u{82b}', 'ꖞ', '𑋒', '𒁽', '𐑹', '𒒮', '𝇠', '𒂙', '莭', '𑇨', '𐜏',
+    'ᡅ', '𑑏', '𐌞', '➫', '𝓕', '㌇', '𒄠', '🗤', '𓙼', '𔏵', '𓽏',
+    '𓾜', 'ㇲ', '𝌾', '𑎟', '望', '⮄', '㞁', 'ﮧ', '〺', '𝝊', 'ꢫ', '𝐏',
+    'ꐋ', '𖢅', '𑯳', '𓋾', '𝅚', '𔒈', '𛃯', '𑋜', '🞂', '𒔢', 'ɰ',
+    '᭏', '⾁', '𑆃', '𝢤', '𒊙', '𑈊', '𐅖', '⑫', '🃣', '𑖕', '𐜓',
+    '𒈴', '𓮡', '🀽', 'ؼ', '▷', '𜴱', '🏶', '徭', '🝙', '𔓰', '𔐃',
+    '🔕', 'ፙ', '𓕍', '敖', '\u{1dec}', '𓂼', '⽱', 'ᘉ', 'ᎌ', '𝝍', '𔕀',
+    '𔔼', '\u{8de}', '⛐', '𛆾', '᪄', '𘲈', '𔇯', '📏', 'ឍ', '𝈆', '𓇝',
+    'ퟗ', '𑯕', 'ᛢ', '𓪜', '🍤', '\u{11301}', 'ᓪ', '㊘', 'Ī', '𑤿', '𔀖',
+    '𑴠', '𑘁', '⨲', '𓚃', '𝗸', 'ₒ', 'ᙒ', '𑂷', 'Ӭ', '\u{e01da}', '𝠐',
+    '⟎', 'ᐼ', 'ᐺ', 'ᇃ', '॥', '𓑧', 'छ', 'ᖲ', '𝍨', '🄶', '\u{e002f}',
+    '⠙', '𞁆', '𔓾', '𦇚', '𖪰', '𓾍', '𔑸', '🞨', '𖨄', 'M', '᧸', 'ಝ',
+    '𑶅', 'ꢄ', '杻', 'ḁ', '𖡺', 'ꢻ', '𐏀', 'שּׁ', '𜳬', '⑂', 'ᜊ', 'ꨢ',
+    '𑪞', '▻', 'ᕭ', '𓵳', 'Ą', '𓉣', '𓡐', 'ᖈ', '𔍢', '𓌫', '𜷚', '𓞞',
+    '𓲮', '𒌉', 'ꙙ', '┽', '✝', 'ঢ়', '𒊑', '\u{114c3}', '῏', '𐚖', 'Բ',
+    '𐢛', '⅟', '༃', '𝀼', '\u{1c32}', '䷋', '🥢', '滋', '⢵', '𛈶', '𛈲',
+    'ꂕ', '𓼦', '⧀', '𛈻', '𔐶', '𛇑', 'ꕬ', 'ꘄ', '۩', 'ꍇ', 'ᶖ', '᠗',
+    '꯲', '\u{16af2}', '𒃭', '𝂬', 'ﲿ', 'ꪝ', 'ꅘ', '𘫢', '𓉙', '𔕝', '𒈟',
+    'ꩵ', '᳃', '𝢰', '𔇍', '𓩟', '𝋃', '𐬿', 'ᗆ', '𐰒', '𒇦', 'ࡀ',
+    '𒌮', '\u{11ca5}', '𓅓', 'ᘟ', 'ꮙ', '索', '㆙', '𝦷', '𑖛', 'Ꞛ', '𑥙',
+    '㊗', 'ᐒ', '𑖘', '۽', '╔', '𑋋', '𜰞', '\u{1dd8}', '𑯔', 'ᝰ', 'ﳕ',
+    '𛀢', 'ᯬ', '𘫋', '𓚏', '🭖', 'ὖ', '𓼮', '🈺', '⊓', 'ꔊ', '🐺',
+    '𒐱', '𝃜', '🩓', '௱', '𔂗', 'ߞ', '𓫷', '𜷒', '🪨', 'ل', 'ɓ', '𔔊',
+    '弄', '𒁻', 'Ꝗ', '𘳁', '𝕫', '𐄞', '𔑯', '♹', '🁢', '𓌯', '܆', '𝧅',
+    '𒊨', '𐽺', '𖼷', 'ᒶ', '𜹣', '\u{11a05}', '🙟', 'ꕻ', '\u{10efd}', '𐔳',
+    '௪', 'ꑔ', '🜚', '㤜', 'ᑒ', 'ᴟ', 'ﵖ', '𑘫', '𘠒', '㆐', 'ߋ', '𔋛',
+    'ꐂ', 'ꇅ', '𔗦', '𔗪', '𝝜', '𝪃', 'ȓ', '𝗥', '⎭', '𝗭', '𝗙', '𜴍',
+    '𒍯', '౦', 'ꪺ', '𐳬', '𐛉', '勺', 'ᣮ', '𔃓', '᧐', '𐅅', '😵',
+    '𓩋', '𘳃', '⤒', '\u{e018f}', '꤃', '𓬡', '\u{fffb}', '🝋', 'ㅲ', '\u{1ac2}',
+    'ᵒ', 'ᄒ', '⭔', '𐐑', '𓃢', 'ꠅ', '𒁱', '𖫡', '𖣉', '𓝓', '\u{10eab}',
+    '𓂋', '𒐡', '𜾗', '🂍', '𓪐', '𝀹', '𓪘', '𞺢', '𔆨', '⛧', '𔔥',
+    '𛀅', '𖪌', 'ⱚ', '𓄈', '𖥟', '𝒓', 'k', '𐿃', '𓾖', 'ᶵ', 'ᯜ', 'ᄠ',
+    '𓙑', '𓑼', 'ﲇ', 'ꍘ', '﹂', 'ඞ', '௯', '\u{1da1d}', '𝅓', '᧮', '𝠵',
+    '⾔', '\u{114b3}', '⻅', 'ᑼ', '𘫍', '𓿛', '𝋏', '▚', 'ᾍ', '𒊣', 'ᩃ',
+    '𜾈', 'ힺ', '܄', '𘱂', 'ꃾ', '𐺈', '𑇁', '⤯', '⭭', '፩', '𝙮', '𝃮',
+    '𑙠', '୫', '⛒', 'ḵ', '\u{c81}', '𓌷', '🙇', '𓮮', '🜝', '𒇇', '𒐲',
+    '𐤌', '𓥾', '௨', '𝍴', '𘥠', '𖣻', '\u{73e}', 'ꩀ', 'ୋ', '⨾', '𔁍',
+    'ꬠ', 'ꊆ', '㌥', 'ᓦ', '⣵', '𓪚', '⭾', '𜺙', '\u{11a91}', '𑈂', '𓏬',
+    '𛀐', '𓂰', '派', 'ﴁ', '𑢮', '\u{1715}', 'ਢ', 'ꇳ', '𑨎', '❨', 'ꂫ',
+    'ꀱ', '𝐿', '𖼆', '𝑇', '\u{1cdf}', '𑠖', '🖵', '᭹', '𐴹', '🠡', '𖣑',
+    '\u{1cf1f}', '╃', '𝓙', '𐬥', '𝓑', 'ﲗ', '𞠖', 'ⴕ', '◧', '㈠', '፠',
+    'ⲓ', '⦫', '𝦸', '\u{1072}', '𔊫', '𝙈', 'ﬂ', '𐡩', '𝚲', '𘢻', 'Ⲧ',
+    '𓍭', '𓤳', '𓃵', 'ǵ', '𘫝', '𖤕', '𞢹', '𖹏', 'ෞ', '\u{81f}', '🤀',
+    '\u{e0183}', '\u{1cf21}', 'ఎ', '𜱚', '\u{a8c5}', '\u{fe28}', '𒌫', 'ᰪ', 'ഈ',
+    '𔘯', '𞠨', '🎎', '𐴋', '𓀹', '\u{a82c}', '𒑌', '𝦲', '𜽦', '𐰽',
+    '𓒏', '𜹥', '𐰍', 'ꅿ', '🎑', '𒿍', '𐚩', '䀹', '𓊚', '🞓', '𘠜',
+    '﨎', '◌', '𐙒', '🆏', '𐙓', '𒍾', '𐗊', '𒿬', '𒐰', '𔗰', '𝗴',
+    '𜶵', '𑯏', '𞢌', '𝜷', '𐧴', 'ሬ', 'в', '𐛒', 'ﹲ', '𖥤', '\u{2dec}',
+    '𓪂', '𓪆', '𛇕', 'ڃ', 'ണ', '𑄛', '𐞫', 'Ṇ', 'ꥤ', '𓯒', '\u{652}',
+    '𐑤', '𑐟', '𝐺', '𑽒', '𘣷', '𝛇', '🠪', '𑋅', 'ⵊ', '௲', '\u{1cf34}',
+    '𘠬', 'س', '𓟋', 'ᩱ', '🈧', '𓌴', '𞢠', '𜸚', '撚', '𑒀', '𓛈',
+    '𖥲', '🞖', '咽', '𣴞', 'ᶅ', '𓛨', '𘲽', '፲', '꓂', '꒽', '𓳡', '𒈤',
+    'ꄢ', '𜷸', '\u{2dea}', '𝕾', '𑁭', '𐒌', '🪉', '𛉟', '𝘀', '𝕐', '⺨',
+    '൸', 'ၬ', '𓝾', '𝕄', 'ꡐ', '\u{6d6}', '𓟉', '⩅', '𓟥', '┴', 'ʻ',
+    '𑜰', '𐴶', '🫜', '𐣰', '𔖿', '𔎂', 'ȍ', 'ꋿ', '𝗏', 'ญ', '\u{10a0f}',
+    '𝔷', '🥕', 'ࡼ', 'Ủ', '𜷶', 'జ', '𝔥', '꯷', '𑰏', '𓉈', 'ꘇ', '𒒲',
+    'ٿ', '𓷈', 'ഏ', 'ￆ', 'ࡖ', '🙗', '𔗵', '𓸱', '𒈑', '𓸹', '𓬕', '𓩕',
+    '𝠍', 'ố', 'ṍ', '𒁨', '𝁓', '𦈨', '𑐕', '𝔳', '𓣼', 'ῤ', 'ୈ',
+    '𝥒', '𑖬', 'མ', '𐨫', '𘰸', '🕽', '𑇤', '𘡧', '𖼡', '⓭', '𞢰',
+    '㌿', '𖨕', '頋', '𘲥', '𝘮', '𓋉', 'ࡑ', 'ฑ', '𓁅', 'Ⰾ', '\u{135e}',
+    'ၨ', '𝛧', '𝖥', '𓹺', '𓏰', 'ꐛ', '𓎉', '⽛', 'ޢ', '𝁍', '⌦', '𓉼',
+    'ﶊ', '🈀', '𑠓', '╩', 'ཆ', '𘪊', '䩶', '𒈚', '\u{20dc}', '⎏',
+    '\u{a66f}', '𑣰', 'ꓦ', '㇋', '𓙓', 'ꖥ', 'ﰭ', '𓖅', '\u{17b8}', '𓯙',
+    '𘪌', '𘪐', 'ꙉ', '𑜶', '𓲜', '⣌', '\u{1e8d6}', 'ያ', '𖩆', '🚧', '𓢜',
+    '৮', 'Ǎ', 'Ꚏ', '𝄱', '꠷', 'ꔋ', 'Ậ', 'ꊓ', '𘬺', '𝒏', '👜', '⫓',
+    '𑂃', '\u{20e9}', '\u{6e3}', '𒾙', 'ݼ', '𝞣', '\u{e017b}', '𓥊', '𝈎', '𝡨',
+    '💡', '𨯺', '𑰂', '𘠢', '𓭉', 'ﶒ', '\u{1da43}', '𞸇', 'ᡚ', '⮙', '⟯',
+    '\u{336}', '𖫓', '𖬬', '\u{fad}', 'ݞ', 'ᰋ', '🜠', 'Ⰴ', 'ﺼ', 'ῲ', '👳',
+    '𑰪', '𓅶', '𝝎', '🍙', 'ꝋ', '𑌣', '𛇡', '𐘰', 'ο', 'ꋓ', '𑖆', 'Ϟ',
+    '𓢢', 'ꅂ', '㨮', '𘦪', '⚡', '𑢬', '𜵗', '꒜', 'ꗓ', 'อ', '𓝩', '𓈐',
+    '⌔', '𓝥', '𑏗', 'Ӱ', '𜸤', '𓾃', '⺸', '🖈', '𒂷', 'ឥ', '𞋉', '𐄱',
+    '𑬀', '🙌', '𞲅', '𒅰', '𐎓', '𒓑', 'Ꮳ', '𓭢', '𓙾', '𛰳', 'ᛍ',
+    'ᚵ', 'ᛱ', '𓻐', '𓹜', '\u{13435}', '⬪', '𓩧', '𓜠', '𛋂', 'ꊤ', '⡿',
+    '﵎', '🤏', '郞', '\u{e0079}', '𐠦', '𓺇', '\u{e0075}', '⊮', '\u{1da49}',
+    '𖬨', '𐄚', 'ᑑ', '𘫡', '𛉉', 'ἳ', '𑯡', 'Է', '𒊞', 'ﲒ', '꒯', '𜰭',
+    '\u{1da5d}', '𑤒', '𐬬', 'ổ', '\u{1dc6}', '𑒊', '𓟏', '𑜆', '\u{e0077}',
+    '𝜉', '\u{e006f}', 'ᖵ', '𜲧', 'Ε', '⋿', '࿑', ']', '𝄗', '௴', 'ἲ', '🗾',
+    '𔓏', '𑵠', '𜶛', '𑵢', 'ꖬ', '⊍', '㌗', 'ḹ', '𒅻', '𖬼', '𒇐', 'ᑷ',
+    '𛇞', 'Ҷ', '丹', '⩔', '☷', '\u{1172b}', 'ㆤ', 'ﲡ', 'ꏥ', '𒅣', '🝚',
+    '𐐲', '𓏙', '𐨮', '𐌗', '𛲇', 'ꟹ', 'ⱜ', '🄛', '𔋀', 'ꋈ', '\u{fe0c}',
+    '𘣿', '𘣻', 'ᘦ', '𝤛', '\u{65a}', '🏃', '𓁢', '\u{1daaa}', '𑴟', 'ན',
+    '﷼', '𓛹', '𒋃', '𓪴', 'ᬫ', 'ḳ', '\\', 'ᦄ', 'Ə', '➎', '𐂒', '𜶔',
+    'ᕆ', '𔅗', '𝍰', '𖨲', '⚧', '𑤸', '𘢟', '\u{17b5}', '𑌳', '𐏋', '‧',
+    'Ꞝ', '⍏', '𔃳', '𓯥', 'ए', 'ꋏ', '᱈', '៶', 'ᮛ', '𛋉', '𛁽', '㊤',
+    '𜾰', '↫', '𞋈', '💾', 'ꢝ', '𜴑', 'ၖ', '𒁬', '𑌟', '𝝣', '㍡', 'ঙ',
+    'ﳱ', '𛃕', '♀', 'ꑢ', '𓌒', '🭳', 'ᨶ', '⏣', '𐌛', '╗', '𛅐', '␁',
+    '揤', 'ꈓ', '㊏', '𓹝', '𑒃', '𑍝', '𓦄', 'ㄼ', 'ꐃ', '夢', 'ﶅ', '⻕',
+    '𜳋', '𓏟', 'ᇧ', 'Ⲫ', '鋘', '⺫', 'ꎥ', '⊰', '嘆', '𝁴', '𝄉', 'Ꟃ',
+    'ೄ', '⬜', '⧯', 'ൌ', '\u{1e5ef}', '𔉻', '𐨠', '𝑛', '⼎', '𜸹', '𑢺',
+    '𔙂', '𐌯', '𘭑', '𓟵', '𓾦', '𔀋', '𐘘', 'ᕱ', 'ᑻ', '𒅎', '𞄺',
+    '𓴚', '𓼔', '𓼐', 'ଊ', '𑀏', 'ﶔ', '𞋏', '𑚀', '༂', '፨', '✯', '𓄸',
+    '𖡘', 'ꦬ', '⾸', 'ᳶ', '𒃋', '𛉿', '𓦘', 'ᱭ', 'ʸ', 'ㆋ', '𖧟', '🪳',
+    '𐧝', '𝓾', '𞸙', '𝣠', '↉', '𐚒', 'ゴ', '𐵽', 'ᖢ', 'ኝ', '𐱀',
+    '𓟱', '𐑭', 'ꂏ', '𒁠', '𐖡', 'ﶿ', '𓖴', '⪼', '𑀘', '𓖸', '᰽',
+    '𓂕', 'ꇤ', '⸖', '𓹌', '≴', '\u{a47}', '𓒵', '衣', '𓘱', '𒿈', '𘯴',
+    'ꓙ', '\u{10a39}', '𘥭', '𐢝', '㇈', 'ꮭ', '𖫈', '🠓', '☺', '𝥜', '🯐',
+    'ﱆ', 'ꡘ', '莽', '🀄', '𓒾', 'Ꟑ', 'ᘼ', 'ꪀ', '𑁛', '𐎌', 'ﴏ', '𑰫',
+    '⊹', '𐰙', 'ኜ', 'ΰ', 'ᘷ', 'ᒈ', 'ࢌ', 'ﭛ', 'ࢽ', 'ࡺ', '🕱', '𓫢',
+    'Ŝ', '𓣓', 'ూ', '≀', '𜹰', '𖵧', '\u{304}', '𜲩', '跰', 'ၥ', '𓙏',
+    '𔗱', '𒒚', '𔋷', '𒃉', '\u{fa4}', '𘩘', '𓴝', 'ᒔ', '𐎭', '🭂', '𘩼',
+    '𔏃', '𔏇', 'ꡦ', '𓦛', '᪗', '𝠴', '𐑎', '🗎', '𐒺', '跋', 'ꇾ',
+    '𘣏', '𓓌', '𓧋', '𝧴', 'ꤖ', '㉷', '\u{1cf0d}', 'ꀹ', '𓳽', 'ﶣ', '𑀲',
+    '𓴖', 'പ', '\u{1e009}', 'ߎ', '𝚔', '𝌂', '𔙅', '㆑', '𓡳', '꠲', '𓭺',
+    '🡱', '𑂒', 'ỽ', '𑣬', '𓢎', '𝚥', '𒓎', '𖠏', '𓓨', 'ꐳ', '𝑓',
+    '㋓', 'ꑀ', '𞴢', '𓿡', 'ᴡ', '𝒇', '㆚', '𐆆', '⌽', 'ᓺ', 'ᛳ',
+    '\u{20de}', '𓲽', '𝂱', 'ẞ', 'ગ', 'Є', '𑵖', '🫘', 'ꦌ', '𐚮', '鈸',
+    '؏', '𐃋', '𓎸', '𐑈', '𓲞', '㎞', 'ڳ', 'Ⴖ', '𝓈', '౷', '𔔈', '𝀇',
+    '𓼯', '𝚹', 'ﯤ', 'Ἅ', '𓱈', '🖺', '𓇽', '𝒂', '꜠', 'ʅ', '𑰜', '𓱬',
+    '𖦯', '𔔪', 'טּ', 'ﻯ', '𞠳', '🞈', '㎏', '𓕪', '𔉠', '𞠼', '🌦', 'Ć',
+    '🜮', 'ꪞ', '𓯦', 'ꔝ', '\u{32a}', '\u{1ce5}', 'ዺ', '𝩴', '𝇝', '⾺', '𓞺',
+    '𔕴', '𘨝', '𔄖', '𓵡', '︿', '𒊄', '𞲥', '🯈', 'ǒ', '𘯢', '𔕄',
+    '𘯿', '𓝎', '𖼎', '🚥', '𝐊', 'ꃀ', 'ﶽ', '𘧦', 'ｯ', '𑰮', 'ԗ', '𑼲',
+    '𓼉', '𑣡', '𘯫', 'Ჴ', '𚿺', '⯄', '𐰤', 'ꍓ', '𑌉', '𒅅', 'ㄹ',
+    '𜾷', '🍍', '𐊔', '𓞠', '𛉭', '𘫭', '🀦', '𘫵', 'ᵼ', '𔁭',
+    '\u{13439}', '⪁', '𒁋', '𘫤', '𐨯', '羅', '⛾', '⮑', '𑇞', '\u{16122}',
+    'ｭ', '\u{1da5b}', '𜰥', '𥪧', '𓬈', '𓣒', '𝟽', '💵', '𓼓', '₌', 'ꗰ',
+    '𖥷', '𒆈', '𜺅', 'Ⓕ', '𔉫', '🡧', '᪔', '𓈚', '𠄢', '᧰', '🍚',
+    '𓞀', 'ᵔ', '𒆖', '𝅗', 'ᖂ', 'ꦧ', '𝗁', '𔇡', '𓃿', 'Ќ', '𛊕', 'ꕶ',
+    '𐺥', '𔖹', '𓬔', '☰', '🪮', '𞋔', '𒈭', '𐳻', '𘡤', 'ᖘ', '𞓩',
+    'ꪒ', '𖽷', '⣝', 'ཧ', 'ㅈ', '𐎁', '𐬖', '𞴛', '\u{f86}', '\u{e0193}',
+    '𘦧', 'ꓤ', '𓵢', '🌣', '𓅗', 'Ე', '𓾱', '𘤣', '⓻', '\u{a670}', '🦰',
+    '૪', 'Ⱗ', '𛂛', 'ꁞ', '\u{1e949}', '𓆢', '🏘', '👒', '❾', '𝖯', '祉',
+    '𖤮', '𑜇', '𞟻', '𐑸', '⢒', '\u{1cf40}', '𓳦', '𓓍', 'ꬺ', 'ᄧ', 'ታ',
+    '𓡇', '\u{e018e}', '𒾢', '⸏', '𒀲', '⠵', '𒊪', '\u{e0057}', '⏸', '⮟',
+    '𐔸', '\u{1e4ef}', '⡬', '𓊋', '𓁎', 'ਰ', '𓆃', 'ኘ', '𑈔', '🤧', 'ኙ',
+    '𒊆', '⭒', '𘲤', '🠀', '𔕥', '𓝍', '𔏴', '𓴳', '᠈', '𓹆', '𓬓',
+    'ﺤ', '𞟰', 'ݦ', 'ꨏ', '𓀬', '𓠿', '𝆸', '༛', '𐛗', '🌐', '\u{200f}',
+    '\u{656}', '𝝖', '🙩', '𓋲', 'ﱽ', '𑀝', '𝥾', '𝁉', '𒅤', '⚎', '𒑬',
+    '𓒇', '𘨏', 'э', 'Ɛ', '𐒿', '﹉', '𒋲', 'ⴘ', '𘣟', '🤈', '𔘮', '𝗞',
+    '𓵺', '𔓐', '\u{11a02}', '𓆏', '🧵', '𓨕', '𞺫', '🦫', '‴', '𝢬',
+    '🄳', '℁', 'Ⴠ', '𝥂', 'ф', '𓙛', 'н', 'р', '𓩼', '𐜉', '⟮', '𔍻',
+    '㈛', '᧿', '\u{1da5f}', 'ῗ', 'ṧ', 'ퟪ', '🤓', '𐦌', '𓹣', '🝣', '\u{897}',
+    '𝓁', 'ꭝ', '𝥰', 'ﳦ', '𓆱', '🀒', '⨯', '𝍐', 'ᗓ', 'ᑪ', '𑘒', 'ᑨ',
+    'Ⴆ', '𐫈', '𖡷', 'ꂉ', 'ﰤ', '𝥞', '𖾕', '𒕀', '⟹', '𐒶', 'ऋ', '𓣆',
+    'ጇ', '𝌪', 'ⴳ', '⛢', '⧶', 'ɛ', 'Ｔ', 'Ｐ', 'Ｌ', '𐞔', 'ᓝ', '復',
+    '𒀚', 'ᕰ', 'Ѭ', '𝢍', '𓬒', '𐎾', '\u{1b70}', '𔔄', '𔆉', '𓀴',
+    '\u{e01de}', '𔆅', 'დ', 'ī', '⊖', '㉿', '𝧾', '♘', '🟆', 'ᘏ', '𒂉',
+    '🂼', '🠒', '𐴅', '𞴹', '𝘛', '𘤗', '𛲒', '𝄅', '⋨', '𞴽', 'ꍕ',
+    '𒀔', '⌣', 'ᙛ', '𘮬', '𐲟', '𓃗', 'ꨗ', 'ᙟ', '𒇩', 'ގ', '𒄃', '𒀛',
+    '𑍌', '🛸', '𘭤', '𐂄', '𑆉', '㎊', '\u{7f2}', 'ꩻ', '𞤘', 'ꬫ', '𐞨',
+    '𒁧', 'ﵝ', 'ᓚ', '𜷓', '𓵰', '𐏔', 'ꬦ', '𓿅', '𞢲', '𒒄', 'ቄ', 'ﵱ',
+    'ࡅ', '⨙', '𖣣', '㉳', 'ⱒ', '↛', '𑪂', '∜', '⣋', '𒄢', '➓', '܉',
+    'ᣝ', '𓵶', '𖦮', '謹', '𔂮', '𓡓', 'ᴻ', '\u{11727}', '𑣧', 'ॲ', '𐁑',
+    '𓹸', '𑱗', '𑶃', '𜸡', 'ᄌ', '\u{119d7}', '೧', '𜽥', 'ꦎ', '𛉥', 'ꍖ',
+    '🄡', 'Ｕ', 'ᵻ', 'ഹ', '𑲈', '𝁋', 'Ａ', '𞤫', '𝜵', '𞥒', 'ཨ', '᪣',
+    '碌', '𒋫', '𜶥', '𝢄', 'ꍃ', 'ʹ', '🆉', '𓮿', '𞤨', '𐔓', '🅹', '⨤',
+    '😑', '⢦', '𝦟', '𛰒', '𓲸', '𖩿', '𝅀', 'ⴞ', '𑆮', '🤾', 'ꗬ',
+    'આ', '𘩒', '🦼', '𓒗', '𐔄', 'ꀐ', '𘨃', 'ꭅ', '⨂', '𐙮', 'ﱐ', '𐳋',
+    'ퟟ', 'ꌌ', '🩠', 'ﶁ', 'Ţ', '𝈋', '🨢', '🗙', '𑨏', '𑱡', '𓨉', 'ₑ',
+    '𐿱', 'ȴ', '⡺', '🝥', '𓱲', '⥋', '⌲', '𓯈', '𐄼', 'ᒤ', '𓵅', '𓵉',
+    'װ', 'ꢾ', '𔁔', '◯', 'ග', 'גּ', '᠂', '🝟', '輪', '𓉵', 'ᬆ', '𝅘𝅥𝅱',
+    '𘬬', 'ﾳ', '▤', '\u{e01b6}', '𒔞', '𞋸', '𑎠', '𓺶', '𜴟', '𜰍', '𝞏',
+    '𖺉', 'ث', '𔉈', '𝙦', '≚', '🬅', '𔁖', '杓', '\u{20e2}', '𛆦', '𝥻',
+    '🯕', '𜾑', 'ᐭ', 'ꎽ', '㏿', '🮎', '𒁥', '𐜪', 'ゐ', 'ᱛ', '𒾦', 'Ř',
+    'ơ', '𑒜', '𓒮', '𓼜', 'େ', '𖼢', 'Ͳ', '\u{e0181}', '𞁥', '𔃡', '𜴙',
+    '\u{10f47}', '𔌗', '𓬯', '𓢞', 'Ꙏ', '\u{fe05}', '𔆑', '𑠡', '𝞢', '𓀉',
+    'ඎ', '剷', 'ힳ', '𖠍', '\u{890}', 'ב', '𖫂', '𑆲', 'ⴌ', '𓼗', '𐲫',
+    '𓙽', '𛅵', '\u{e015e}', '⁰', '𝟑', '𝚍', 'ᠱ', '𓊵', '✖', 'ﷹ', '𓕰',
+    '𑑚', '𝡻', '𝗂', '✅', '🠸', '𝇨', '𔕿', 'ꕱ', '𖨃', '𒃞', '𘡷', 'U',
+    '𓅋', 'ⱏ', '𓅔', '⊝', '🏂', '𝄍', '𓕹', '⩂', '𑊼', '𞤈', '𖩌', '¿',
+    '㇗', '𐊀', 'ℐ', 'ᎇ', 'ቧ', '𓠬', 'Љ', '𞁃', '੮', 'Ⴣ', 'ɻ', '𐘑',
+    '᛫', '𑇗', 'ɳ', '𓵄', '▭', '𝝝', 'ፄ', 'Ꮋ', 'Ṱ', '𑙣', '𐰝', '𒇬',
+    '𓠧', '𜰫', 'Ų', '𐃰', '𝗺', '𐦧', '𓹦', 'ﳟ', '𔔏', '༜', '𞲕',
+    '\u{11640}', '𑑖', '٬', '⧑', '𐚦', '𓔉', '𞤮', '𐑔', '𑀯', '࿚', '꒣',
+    'ρ', '𐇺', 'ﭞ', '𖨸', '𛀛', '▴', 'ꍜ', '🙧', '𓯬', '𒄣', 'ꑗ',
+    '\u{e01b4}', '\u{fe02}', 'ኂ', 'ꄨ', '𑥓', '櫛', 'ኬ', '𓬷', 'ဪ', '𔘧',
+    '𓬼', '𓨵', '𒓣', '凵', 'ﮖ', '𛋝', 'ɣ', '𛀄', '⊜', '𞁎', 'ʕ', '𐮄',
+    '🕉', '𐽁', '𒌝', '𓅫', '𘩍', 'ᏸ', '\u{7f1}', '𖮇', '𑈳', '\u{1e01d}',
+    'ꪆ', 'x', '𖩠', '𑌹', '𐤵', 'ꡈ', '𐐆', '瘟', 'ک', '\u{8d0}', '𓵐', '𑆈',
+    '\u{64e}', '𓲱', 'ᗧ', '𑴄', '𓲂', '㇁', '𞤞', 'ᏺ', '◁', '𝞼', 'ե',
+    '𐫁', 'ጱ', '䷩', 'Ή', 'ট', '𑈼', '𘢏', '𝒘', '𓓺', '𘫽', '𜵼', 'ᣤ',
+    'Ꮗ', '␔', '𐠒', '🐛', 'ꋡ', 'ﯠ', '𝛖', '𘥁', '𔏥', 'ᘚ', '⸤', '𑋇',
+    '⚐', '𖼁', '𓈁', 'Ꮬ', '𞥖', '𓁉', '𓻈', '𞓗', '𖥐', 'ⱝ', '𔅶',
+    'ⲙ', 'ﳠ', 'ठ', '𖠮', '㈰', '𐖞', 'ٶ', '⍖', 'ꓺ', 'Ճ', 'Ꮜ', '🈥',
+    '✽', 'ʫ', 'ᙇ', '\u{e0119}', '𑎲', '䬳', '𜷍', '🆦', 'ɧ', '𓃰', '𓃬',
+    'ෙ', '𑚆', '𑚂', 'Ế', '𒇤', '𑦻', '𛰝', 'Ⳃ', '𓳾', '𖹋', 'ᝣ',
+    '𐊳', '˳', '𖄈', '𝀦', 'ᙅ', '𑂱', '🗪', '𝜅', 'ꤟ', 'ᦤ', '𛆉', '𝑖',
+    '\u{11c3c}', '⬥', '🖶', '𓤦', '𑂏', '𜶞', '𔌳', '𔋻', 'ꍽ', 'ℰ', '𑢢',
+    'Ἡ', '𔌺', '𔌾', '𑋡', '𓹏', '\u{1da66}', '\u{10ae5}', '𓅤', '\u{7a6}',
+    '𒃪', '𒊭', '𛱄', '𓵔', '\u{2de1}', '𑻩', '𝄏', '𛰜', '𐰏', 'ࢄ', '𔈠',
+    '𛃻', '𘩝', '\u{ccb}', '\u{1e2ae}', '𜴚', '𘮉', '🂏', '𑐗', '𔏒', 'ါ',
+    '🚤', '𐲖', '꧷', '𐲦', '𐲠', '𘰏', '𜷘', '𖡡', '❫', 'Ͷ', '❱', '℗',
+    '𔌔', '🦵', '画', '盛', '⼝', '⦜', '龎', '𓵸', '⸋', '𑩯', '＇', '𓭃',
+    '⋤', '𝇤', '𐪖', '𓓴', 'ᖽ', '𓁟', '𒐈', 'ǧ', '𐂲', 'ధ', '𐀽', '𒇲',
+    'ढ', '\u{e0140}', 'ᐚ', '𜱟', 'ⴵ', '🞴', 'ꑁ', 'ᛄ', '🪣', '𝑴', '𒁂',
+    'ᗛ', '↵', '𑤮', '𖹠', '𘳐', '𝞧', '𞋰', 'ʿ', '𐂴', '𘬚', '¡', '𒍝',
+    '᧡', '╆', '𑄓', 'ॾ', '𛋶', '◻', '𒅶', '𓢆', 'Ｉ', '𓬖', '𑧢', '🂒',
+    'ꌽ', '𐧭', '\u{1df2}', '𔕻', 'ꍡ', '𖠵', 'ၫ', '𛈑', '\u{1e94a}', '⒮',
+    '❛', '𝄓', '🟍', 'ኃ', '⒪', '\u{c47}', '𘤟', '⛡', '🖓', '𐢂', '㇇',
+    '𓜲', '𝜰', '〾', 'ↀ', '𓢳', '𓺊', '\u{1920}', '𝕲', '\u{e0197}', 'ዃ',
+    'ਅ', 'ᠾ', '𒊹', '\u{1da31}', '᧶', 'ਞ', '𞄚', 'ꀇ', '≓', 'ࢣ', '精',
+    '𐽼', '𔁿', '𐊵', '𛂮', '𜴆', '𐂯', 'ᅸ', '𑤠', '𘦫', '𝂋', '𖩣',
+    'ᾳ', '𐱂', '\u{16ff1}', '𓕤', '⩛', '𔒘', '🄓', '䌴', '♿', '𔁲', '𐩮',
+    '𘣉', '𐅿', 'ᦅ', '⢖', '𑊕', '𐼙', 'ᬪ', '𔌼', '𑿩', '𞋚', 'ꌲ',
+    '🅤', 'ꩭ', '𑱷', '𐔞', 'Ʊ', '🗲', '𝜓', 'ᕺ', '𐊫', '𑘯', '𓁜',
+    '𛁬', '𔖆', '𐂫', '𝁆', '𖹑', 'ⷂ', 'ு', '⽷', '𐳝', '\u{1134d}',
+    '\u{e01cd}', '𝌼', 'ꖗ', '𒄝', 'Ⱇ', '\u{1ab3}', '\u{1da25}', '㉬', '\u{e016f}',
+    '𛉷', 'ো', '𑜻', 'ꤥ', '\u{e01b9}', '𒅳', '𔈛', 'Ꮹ', '𖥣', 'ᶐ', 'ဉ',
+    '𐴜', 'Ǝ', '𜽧', '𑠗', '៧', '𑩐', '\u{1dcb}', '᧕', '\u{7fd}', '𖤸', 'ᓅ',
+    'ﲝ', '▂', '𛈙', '🥔', 'ࡳ', 'ꅫ', '\u{357}', '𞤪', '㋧', 'ꐖ', '𐏒',
+    '᮵', '\u{e01c6}', '⾤', '৩', 'Ⲝ', '𒓻', '𔁱', 'ᩑ', 'è', '𔊩', '𝡷',
+    'ᛤ', '𓃱', '𘫏', 'ꧫ', '𑵰', '𓅟', '𓤶', '𓤲', '\u{110bd}', 'ኲ', '𓟂',
+    '🮣', '𐅫', '𑄚', '㌪', 'זּ', '⒫', '\u{11a34}', '\u{1da2c}', '𜱌', '𒊬',
+    '𛆿', '𛉑', '𝙅', '╢', '₂', '𓆫', '𒂿', '𐠘', 'Ѯ', '𔐊', '𑵔',
+    '𝛐', 'ᶣ', '⚹', 'Ꮍ', 'ऴ', '𐠜', 'Œ', 'ꫢ', '𝅙', '𝔩', '𐵇', '𝦐',
+    '𖧸', '🗌', '𖤑', '𒁩', '𐚈', '𞓸', 'ꥀ', '𝂼', '𓇟', '📾', '🯶',
+    'ร', 'ﷷ', 'ꋣ', '𑇴', 'ꎠ', 'Ẑ', 'ꋠ', 'ꋚ', '𓰽', '😻', 'Ό', '፳',
+    '\u{20dd}', '🙲', '𛂺', '𞄏', '𒔈', '𐤲', 'ꃍ', '𘠏', 'ཫ', '噑', '𒆁',
+    '𓻙', '𞸚', 'Ⲳ', '𞢜', '𐴠', '⣤', '𐝈', '𒍁', 'ᕙ', 'ᕓ', '𛉞', 'ʣ',
+    '𘦽', '𛈖', '🭴', '⦹', '縷', '𐳢', '𑫍', '𜵻', '🍬', 'ஒ', '𐳄',
+    '𐓉', 'ቸ', '𐭯', '𑶄', '⧄', '𐾿', 'ḏ', 'ᒆ', 'ꦫ', '𓒼', '𓯿', '🫅',
+    '謁', 'ﮛ', 'ᛮ', 'ຽ', '\u{1cf20}', '\u{1da60}', '𜱫', '𝛉', 'ꤒ', 'Ӽ', 'ᡰ',
+    '𒈂', '𖥏', 'ﳋ', '𑱃', '𓸖', '𐭜', '𔒏', 'ꡟ', '𔁃', 'ベ', 'ꃺ',
+    '𔂚', 'ᦘ', '𒄵', '⏦', 'ꉅ', 'ꕃ', '𔍷', '𐧋', 'ᯎ', 'ꈺ', '⮈', '𝧖',
+    '𑨑', 'ժ', '摷', '\u{11080}', '𓗎', '𘧡', '\u{5a3}', '❹', 'ᶥ', '多',
+    '𐏊', '庰', '𔃾', 'ඉ', '∄', '\u{a928}', '嗀', '☣', '𔖾', 'ᩉ', '𛄚',
+    '𛰴', '\u{6e2}', '𜱓', 'ࡶ', 'ᬋ', '𝣖', '𖽴', '𑜾', '﹖', '\u{10a0d}',
+    '◴', 'ꖆ', '᪖', 'ꦨ', 'ᇒ', 'ǎ', 'ᒊ', '𛉬', '𓁵', '𒿢', '𝔅',
+    '\u{a8e4}', '⚃', '𔍜', '\u{1ce8}', '𛇛', 'י', '𘨆', '🧯', 'ӹ', '𑙂', 'ἥ',
+    '⥙', '𓅎', '𝛾', 'ﰬ', 'ᡷ', 'ﲛ', '𝅗𝅥', '፵', 'ї', '₭', '䷷', '𐡊',
+    '𝀝', '𘢄', '𔋶', 'ὀ', '\u{e0154}', '𑛝', '𝓯', 'ፓ', '𔏲', '𝔃', '𘤥',
+    '\u{aa43}', '𓡹', '⍥', '\u{e0136}', '𘠍', 'ﺾ', '𝁅', 'ᮄ', 'ᯉ', '𓡯',
+    '𐌻', '܌', '𛱞', '𛄈', '𜹪', '\u{65c}', '\u{dd2}', '𘯍', '𝀻', 'ᨀ', '𐎆',
+    'ʩ', '𘨎', 'ﹼ', '𖪠', '𓇱', '➠', 'ꏑ', 'ꅴ', '𓓔', '𛇄', 'ሉ', '𓡾',
+    'ས', '𓔢', 'ペ', 'ﻦ', '𐗲', '𢡊', '\u{1b81}', 'ꛎ', 'ල', '𝀫', '⌅',
+    '𝚩', '𛃐', '𝂨', 'ᆄ', '▐', '\u{1e00e}', 'ꟈ', '𑈗', 'த', '𓈶', '𓇩',
+    'ꦰ', '㇓', '\u{2006}', 'ⳅ', 'Ꮄ', '\u{1e020}', '𓅘', '𐘀', '𓨖', '\u{1da62}',
+    'ꃚ', 'Ԙ', '𔖽', '𛋇', '𑌮', '𐘱', 'ꡢ', 'ã', '𓾙', '𓾕', '𓾑', '𓏒',
+    '✫', '𝃄', '𖽑', '𐫣', '🄇', '𞢞', '𞸮', '⺙', '𑼔', '🡂', 'ᇬ',
+    '𑱵', '😩', '\u{e002b}', 'ჹ', '𖢙', '🦪', '𒉇', '𝖰', '𑴑', '🩧',
+    '𓽞', 'ꕇ', 'Ⴭ', '𐢍', 'ꤢ', '\u{11c9c}', '𝢸', '𜰇', 'ퟅ', 'ט', '🪫',
+    '🍪', '𞠱', '\u{11d91}', '\u{f76}', '𓥑', '\u{485}', 'ⶺ', '𞲎', '⿁', '扝',
+    '𒑄', '🌗', '🕴', '\u{94d}', '𓓡', 'ꫂ', '𐘛', 'ꑍ', 'ᯟ', 'ᆥ', '🍆',
+    '𖠽', '\u{e0159}', '𐛩', '𓓻', 'ﳫ', '⫝', '𓵠', '🞕', '𒒺', '𒈮', 'ﰙ',
+    '𘦣', '𜳎', 'Ҥ', 'ை', 'ἦ', '𖬚', '\u{e015b}', '\u{e0157}', '滛', 'ￎ',
+    '𒑉', '\u{e015f}', 'ꎹ', '🗋', 'Ἁ', 'ᵞ', '↭', '器', '𑰒', '𖩅', 'ᦙ',
+    '🃆', '\u{1032}', 'ꊹ', '𓦓', 'ᄢ', '🦣', '🫶', '⹗', 'ꆁ', 'B', '୩', 'Z',
+    '🯃', '𒍘', '𝆹𝅥', '⎨', 'ㆂ', '𒆗', '⑿', '⪹', '᧗', '𛈳', 'ழ', '𐤉',
+    '𑧝', '🗖', '𓿌', '𒔸', '𑵵', '𑵗', '𝦔', '𑛠', '🄱', '𜳓',
+    '\u{11caa}', '🚛', '𑐲', 'ⳙ', 'ൔ', '𓌶', '\u{5bb}', 'ꎸ', '𓯨', 'ꇃ',
+    '\u{e01d1}', '𓶀', '𖬝', '⫮', 'ᅰ', '𜲓', 'ꨤ', '𔇏', '𘡺', 'ⱌ', '𑵱',
+    '🖿', 'ᵜ', '福', 'ᱱ', 'ӣ', '\u{e4a}', 'đ', '𒅌', 'ឰ', 'ᆞ', '༭', '𝑦',
+    '𐇳', '𘠕', '𐦄', '𝪅', 'ՠ', '𖩕', '𜾌', '𜹊', '⢔', '𓨧', '𐃠',
+    'ⶹ', '𓻷', '῁', 'ꦙ', 'ੲ', 'ᱦ', '𑐣', 'ⳮ', '𧻓', '𔗺', '\u{955}', 'ポ',
+    'ㆩ', '𓓦', 'ᛲ', 'ꖟ', 'ᤤ', 'ة', '𑈈', '𝀬', '𝞩', '🩪', 'ꟑ', '怜',
+    '𑚹', '㌶', '\u{fa9}', '🥅', '༶', '𓪺', '𝣗', 'ꐿ', 'ᣓ', '\u{1d165}',
+    '免', '𓏆', 'Ὢ', '\u{351}', '𔐨', 'ꞏ', 'ᖆ', 'ᝉ', 'ꋤ', 'ဗ', '𔇮',
+    '𝖝', '𑜕', '\u{20d3}', '𝖑', 'ﳛ', 'ꤕ', '𐢑', 'ᤣ', 'ⱦ', '𓶑', 'ﮒ',
+    '⡪', '⬣', 'ﶖ', '🃵', 'ⅆ', 'ݬ', 'ソ', '\u{5a5}', '⒆', '⨠', '𐘨', '𓶞',
+    '⸚', '𖽡', '\u{1cd5}', '𝝉', '𘮩', '𝟞', '𐃝', '𓺾', '煮', '𐲈', '懲',
+    '👉', '⧢', '𓏎', '曆', '𐓸', '𜱮', 'ԡ', '𛲁', '𔑽', '𒒫', 'ʲ', '𓺹',
+    '𖾗', '𖧕', '🕌', 'ก', '𐦦', '𑆟', 'Ꮿ', 'ᄅ', 'މ', '𖄘', '🍂', 'ꠡ',
+    '𑩬', '𓉓', 'ꑜ', 'ﯫ', '𘲁', '🩲', 'ق', '\u{11834}', '𛃳', '𝑩', '𓐘',
+    '👝', '↢', 'ﲸ', '𝤮', '𝒁', 'త', '⚇', '𞋌', '⚪', '𓊜', '𓑿', 'ꤚ',
+    'ᬟ', '𐚯', '꧶', '𓓗', '🕘', '𝛞', '👌', '𜷧', '𛁹', 'ꂖ', '𑜄',
+    '㇞', 'ﰖ', 'ḉ', '𓢅', '𓢉', 'ꕮ', '𛈡', 'ⳋ', '𔍾', '▎', 'ॉ', '𒇷',
+    'ः', '𑠮', '𓬎', '㉯', '𓕱', '𓒘', '𝂌', '𒄿', 'ᝧ', '࡞', '穏',
+    '𛃉', '\u{1ddc}', '𐇘', '꣺', '┋', '⭚', '𓔪', '𐬔', '⛳', 'ᓸ', '𑌸',
+    '𐇮', '🄕', '𐘄', '⒏', '𖼣', '𔋵', '𝖘', '𒇳', '꤀', '𖪍', 'ᣵ',
+    '𓩖', '𝙄', '𓇢', '𖢞', '𒁒', '𝁷', '🙞', '𓥀', 'Ƅ', '𝠪', '㉣',
+    '⥅', 'ⳏ', '𔉳', 'ᾼ', '𓶛', '𝜯', '𓳹', '𘡰', '𖨁', '𔔕', '𜸉',
+    '𘩓', '\u{1a67}', '𞄜', 'Ⴑ', '𞱼', '𓢒', '𘪟', 'ᎂ', '𓐮', '𔀶', 'Ĩ',
+    'ឯ', '𓂈', '𒿌', '␜', '𑻢', '𔄾', '𖾖', '⡍', '𐀁', '𓶼', '💈',
+    '𖥶', 'ퟭ', '𐅯', '𛄃', 'ป', 'ᦦ', '᭩', 'ǹ', '𖥡', 'ㅦ', 'ᐆ', '🈡',
+    '⎐', '𖭴', '𔂰', '⑹', '𒓸', '𛇭', '𖣄', '𔃼', '⮍', '𐰡', '୨', '穀',
+    'ȭ', '𒊅', '𑀥', '𓰏', 'ꠊ', 'ᣋ', 'ꞟ', '𓰆', 'ꬨ', '𓺻', 'ढ़', '᱓',
+    '𔃖', '𔌎', '㡼', '⅊', '𜹝', 'ᳲ', 'ꆻ', 'ᬅ', 'ࡦ', '𝍌', '𐣪', '🞬',
+    'ⷃ', '\u{81b}', '幩', '𓄰', '\u{13437}', '🅃', '𐕉', '≎', '𛊤', 'ﱅ',
+    '𐜊', '\u{1dd9}', '\u{11a51}', '𘰓', '𓓃', '⊣', '\u{1a59}', '𖺆', '𑚟',
+    '𝧩', '\u{1182f}', '𑃷', '⏮', 'ﻧ', '🕯', '𜶚', 'ﳲ', '▓', 'ﶗ', '🁽',
+    '\u{f9b}', '𓐒', '🦽', '🄽', '\u{a94d}', 'ﯕ', 'ፂ', 'ᣄ', 'ፀ', '\u{1da0f}',
+    '⢡', 'Ῡ', '瑜', '𝌰', 'ꄦ', '🬢', 'ⱷ', '፫', '𛃃', 'ፍ', '琉',
+    '\u{1a7a}', '⚩', '\u{f92}', '𝒆', '𓸭', '𘲙', '𝁰', 'ꎓ', 'య', '፥', '𝥌',
+    'Ჟ', '㊈', '\u{2dfd}', '𞅀', 'ꔂ', 'ﴭ', 'ź', '⽻', 'Ӳ', 'ⶔ', '϶', '❅',
+    '𐀓', 'ꯧ', 'Ъ', '𔓸', 'й', 'ˮ', '𡛪', '︶', 'യ', '𝕃', '🩷', '𔊜',
+    'ដ', '𔑞', '⥉', '࿓', 'ꌈ', '姬', '🡢', 'ᑃ', 'ç', '𓃌', '﹔', '𔎇',
+    'ꟾ', '𞲪', 'Ꮟ', 'ꍼ', '𔋮', '𛊉', '⢊', '𓒛', 'ꖭ', '𓏶', '𔕹', 'ई',
+    '𐒻', '\u{59c}', '𒆝', '𐍓', '𑗓', '𓁰', '𖭪', '⾚', 'ὔ', 'ꢪ', 'ଘ',
+    '𔘄', '𜸾', '\u{fa7}', '𔆢', '🐏', 'ٽ', '滋', '\u{745}', '\u{e01aa}', '𓅜',
+    '𓎄', '🟥', 'ㅌ', '\u{1cf29}', '𐜌', '𝟁', '𓊝', '𓯩', '🂥', 'ო', '𛋵',
+    '𖾞', '𔒇', '𘯐', '𔒐', '𘯘', 'ﳩ', 'ᶁ', '🐉', 'ᮏ', 'ꛖ', '𐑘', 'ᘑ',
+    '𔈻', 'ꈚ', '\u{e0185}', 'ꩆ', '🫀', '𜵞', '༯', '𝀮', '㈶', '𓡔', '𐹩',
+    '𝀅', '\u{11230}', '🬺', '⩄', '𝜧', '𘣥', '𐠣', '𐠤', '𓙞', '𓀗',
+    '𓙘', '𐠧', '⚢', '⁝', '𓷇', '₽', '⮗', '𒋤', '🧚', '𐁄', '𓎲',
+    '𘲻', '䷣', 'ㅳ', '𓧢', '𑚐', '☗', '\u{1cd6}', 'の', '𒌀', '⸝', 'ꩫ',
+    '㌠', '𓱄', '⇇', '⪪', 'ߺ', '\u{16124}', '\u{135f}', '⦽', 'ࡌ', 'า',
+    '\u{11722}', 'ᯛ', '𓋬', '\u{1d17d}', '\u{ddf}', '𛰮', 'ᬭ', '𔑷', '𐔧',
+    '\u{1e010}', 'ᮽ', '𐞌', 'ﭯ', '𑲀', 'ퟖ', 'ꯞ', '𔊭', '𒔕', 'Ⓣ', 'Ⓧ',
+    '𜱁', 'ﵴ', '🤔', 'ꧪ', 'ꚶ', 'ఓ', 'ᠿ', 'ⳇ', '𜴻', '𑈁', 'Ḻ', '⺠',
+    '𐎫', '𐭀', 'ᇪ', '⍡', '𓱡', '𐺊', 'ኢ', '🨾', 'አ', '𒒗', '𜽪',
+    '🭓', '🏯', '𑌥', '𝟘', '🎡', 'ࣃ', '🟑', 'Ⅺ', '𐢎', '𐆔', 'ꬍ',
+    '𓾟', '┣', '𔋂', '\u{e0045}', 'ጊ', '𐞕', '\u{113c8}', '𘤁', '\u{365}', 'ᤍ',
+    '۱', 'ॏ', '🩊', '🁧', '𝄁', '履', 'ⶕ', '𓼋', '⿑', 'ర', '花', 'ꪎ',
+    '\u{20eb}', '🥽', '𐃇', '𔔀', '𞠀', 'ൎ', 'ꐤ', 'ቈ', '〈', '𝛭', '𔖼',
+    '⒘', '𖡃', '⳼', 'ᛪ', 'ᘝ', '𘳑', '𓺜', 'ὼ', '⩚', '🬉', '𐗍', 'ￔ',
+    '⏚', '𑂢', '𜷿', '𞱸', '𔔝', 'ࢩ', '𐁝', '𐳣', '𓪅', '𝘫', '𑜴',
+    '🐄', '𜳸', '𖡽', '𔎓', 'ᣯ', 'ᰤ', '𒔔', '🮴', 'Ӑ', '濆', '𓋵',
+    '𑓓', '\u{11a47}', '𐡁', '\u{11c95}', 'Έ', '礪', '〓', '𒉌', 'ꆀ', '☝',
+    'ꇘ', '𖥑', '⠞', 'ᅞ', '𐤐', '𝝢', '𝂧', '\u{1df7}', '𓨻', 'ꈆ', 'Ꮸ',
+    '𓋌', '𞋣', '𓐫', '🮸', '⽒', 'タ', 'ဃ', 'ᥲ', '𐜵', '𔈜', '꓁', '𐜄',
+    '𜸨', 'ઔ', '\u{16f92}', '𔄍', 'ᶉ', '𜹢', 'ﬆ', '𖭘', 'ﶷ', '𓛠', 'Ů',
+    'ꂮ', '𓃞', 'ꊰ', '𐤊', '𑗈', 'ᚤ', '𔎽', 'ꘀ', '𐶀', '𓖕', '𘩳',
+    '𔄀', '𓖙', 'Ᲊ', '𓹩', '𞁚', '𒓾', 'Ѩ', '𝜥', 'ၧ', 'ᱽ', '𝤹', 'ᑺ',
+    'ᗄ', '𔃝', 'ꦿ', '🪶', '𓘛', '𛈆', '⌭', '\u{11a04}', 'ᮟ', 'ᒢ', '㎨',
+    '𘬢', '𓇺', '🜜', 'ꮧ', '𑊍', '𓈬', '𓱫', '䷐', '\u{655}', '𐋐', '𓼃',
+    '⟓', '𖽖', '𝦘', '𒀄', 'ᙗ', '🏙', '𑆅', '𞄋', '𑠈', 'υ', '𒅓', 'ꉟ',
+    'ꆒ', 'ԇ', 'ᚿ', 'ꎷ', 'ⴒ', '𞄹', 'ᬡ', '🧁', '𐊎', '𘭫', '𧙧', '𜹺',
+    'ﷃ', 'ᑎ', '𒋬', '🕦', '𖦃', '𖹞', '\u{20d5}', '⦑', 'ᘺ', '🛶', '𝃰',
+    '⸒', '𛈮', '𒆅', 'ዲ', '𘢑', '𑆡', '𝦍', '𑋖', '\u{10efe}', '𐃒', 'ĥ',
+    'ᝦ', '𓇨', 'ቑ', '𜳶', 'ﹱ', 'ᦕ', '𞁙', '\u{1da2e}', '𔑬', 'ᖤ', '𔈧',
+    '⌴', '𒿝', '𜾯', '𔘭', 'ꭇ', '𘮈', '🗹', '茶', '𐡅', '度', '⽡', '🧼',
+    '꞊', '𐄳', 'ꀽ', '𐌟', 'ﺄ', 'ꖸ', '\u{1de5}', '𔔷', 'Ư', '𐳜', '\u{315}',
+    '⢋', '𓉊', '𒈄', 'ﲏ', '𖢩', '𖧊', '𐧃', '𐔝', '𝦇', '±', '𓄪',
+    '\u{20ed}', '𓡺', '𝙧', '𛉼', '𖧔', '𓡡', 'ꘋ', '𓍶', '\u{e01a1}', '𓍢',
+    '⢶', 'ᑦ', 'ᗞ', '𘧋', '𘧏', 'ν', '\u{1dd7}', '𓿶', '𛅶', '⽭', 'Ⳅ',
+    'ই', 'ᥘ', 'ຐ', 'ㇼ', 'Ǖ', '𐁜', '𐙜', 'ꜗ', '𝋌', 'ヰ', '𓵜', 'ﳑ',
+    '𓈮', '𘠯', '𐾈', 'ꋃ', 'ꈉ', '𑨌', '𖩞', '෦', '𑼰', '𓳎', '\u{1da0c}',
+    '𑈠', '㣣', '滑', '🫵', '𞠃', '𓬵', '🢂', '🯅', '䕫', 'ꎁ', '\u{20d0}',
+    'ၔ', 'ꚰ', 'ߵ', '🕻', '𓛻', '\u{659}', 'ዟ', 'ﺝ', '𖭐', '︗', '𓀁',
+    '嵮', '𝟦', '𝢿', '－', '𔅢', '𛰞', 'ꀺ', 'Ƶ', 'ڪ', '𒊿', 'ꂊ', 'ᮜ',
+    '𓗢', '\u{11a3e}', '🌥', '𞠣', '\u{746}', 'ὕ', '┤', '🔄', 'Ο', '𞁩',
+    '剆', 'ṙ', 'ꎱ', '𐕰', 'ﰠ', '𓎭', '\u{10a01}', '𓁶', '↷', '𓅱', '📙',
+    '𓫹', '𞸢', '𝄽', '𓁮', '🩻', '𝒚', '\u{358}', 'ㄑ', '🚾', 'ẅ', '𐁅',
+    '⍋', 'ꪁ', '𝥦', '𛁔', 'ม', 'ᔍ', '𐵹', 'ቫ', '𓀽', '𓋷', '⏔', 'ҿ',
+    'ₜ', '😙', '☚', '〛', '𓶬', 'ꨛ', 'Ꝕ', '೯', '𛲅', 'ť', '𓮚', '㍷',
+    '𒑳', '╸', '𝆓', 'ಆ', '㈈', '🩈', '𛀺', '\u{e0042}', '𓦭', '𒂛', '𓫵',
+    'ꌖ', '𓋚', '꩷', 'ꑲ', '𐌎', '𑼙', '🬼', '𔌱', '𝠷', 'බ', '𓈵', '൲',
+    '\u{11caf}', '𑦡', '𑐷', '๏', '𑨣', '⎳', '𐫬', '𓃺', 'ꎫ', '﵃', 'ճ',
+    '𔈑', '𓼫', '𓛏', 'ퟡ', '𔘚', '𔇈', 'ꕟ', '㏅', '𝇑', '１', '\u{1dea}',
+    'ጃ', 'Ꝉ', '𖠎', '\u{34f}', 'ឲ', '𖥼', '𑙦', '🆧', 'ꌱ', '𘥆', '🦉',
+    '𔈓', '\u{114b7}', '𔇂', '⎧', '𑫙', '𝂡', '㉞', '𣽞', '𓡅', '𐤀', '🠝',
+    '𒂲', '┆', '𝈝', 'ᜧ', '𐬯', '𓃶', 'ꁵ', '𘭿', '𜽢', 'ⵈ', 'ㄱ', '𔔒',
+    'Ἧ', '𐦪', '𝘧', '吸', 'ᰃ', '𐠿', '𝦤', '⾥', 'ℚ', '𓮤', '𒃄', 'ﻙ',
+    '𝥛', '𑄌', '𓕌', '𝡴', '庶', '𑀣', '𓽢', '尿', '\u{1da11}', 'ङ', '𓺀',
+    '𝓝', '𝤔', '🝤', 'ꁎ', '𒄊', '𝦿', '𞗵', 'ꉋ', '\u{a949}', '𐴰', '🍸',
+    '䘵', '𒀽', '𝈦', '𞣂', '𐫠', '⮾', 'ꟍ', '߷', 'ﶙ', '🧅', '∊', '𐠩',
+    '🆥', 'Ǥ', '𐍂', '🭾', '𜸆', '𓠏', '𔔓', '𒊜', 'ֈ', 'ᤙ', 'ӂ', 'ﲭ',
+    '\u{34a}', '𔒌', '꣸', '𓰷', '𖧦', '𔅽', 'ᢘ', '⫳', '𒂏', '𘭦', 'ᖣ',
+    '𒔲', 'ヸ', 'â', 'ਓ', '𓉟', '𛊟', '𔄇', '𓡒', '𓯔', '𓲾', '𓪞',
+    '𝚉', 'Կ', 'ᚙ', 'ꢉ', '𔅸', '𔏂', '𝌟', '𓛸', '😄', 'ශ', 'ﲚ', '≃',
+    '𑫄', '𔒀', '\u{1dfb}', 'ﻸ', '里', '୮', '⤰', '⤺', '🔮', '紀',
+    '\u{11373}', '𓷳', '\u{c04}', '𞡏', '𜵏', '⊀', '𐅒', '𖦲', 'ꞓ', 'ⷙ',
+    '𝙎', 'ᆭ', '𔃴', '𓒶', '𐕚', '𞠂', 'ꯋ', '⨐', 'ᱹ', '׃', 'ᐥ', '𒍏',
+    '𐬍', '𑼵', '👓', 'ဌ', '⬤', '\u{73b}', '𐢧', '䷠', '𑻣', 'É', '𔃛',
+    'ӻ', '𓉄', 'ﭝ', 'ꡳ', '𓅯', 'ꬓ', '⤭', 'ꑩ', 'ꓗ', '﵇', 'ꅅ', '𝤻',
+    'Ğ', '྿', '𜹛', '𐳒', 'ᮺ', '𓪙', '𝦳', '𓩐', 'ᔥ', 'ẏ', '𞺺', 'כֿ',
+    'ᢧ', 'Ⓔ', '𛱂', '𝚶', '𑢳', '🅊', '𖦩', 'ﯺ', '𝉁', '㋴', '𖵈',
+    '🏐', '𐤣', '𝃎', 'ㆧ', 'ꜟ', 'ⶇ', '𘥰', 'ѓ', '𝦥', 'ᩇ', 'ᇊ', 'ꢭ',
+    '𓿹', '𐛈', '𞁨', '✾', '🎶', '𖥩', '𝘌', '⺓', '廾', 'ᘤ', '\u{8ef}',
+    '𘨨', '🈑', 'ቨ', '𑖈', '𓒄', '𑂼', '\u{1aca}', '六', 'ᤁ', '😾', '႗',
+    'ꙟ', '𐬓', '𞡅', 'ꆵ', '⪿', '𑿡', '🂀', '🬂', 'ᤌ', '𝞟', '\u{13440}',
+    '𝥅', 'ॠ', '𑉀', 'ᆮ', '𜶱', '𐦭', 'ꘚ', '\u{1dc0}', '🇦', 'ⶍ', '𑁣',
+    'ㆮ', '𒋌', 'Ⳛ', '拉', '\u{1e8d0}', '𓡑', '𓛐', '´', '┬', '𓻛', 'ꏰ',
+    '㈗', '𒋆', '𒁕', '◓', '𝚴', 'ꛋ', '🚵', 'ọ', 'ࠒ', '𐬂', 'ở', 'ㅴ',
+    'ₔ', '⻫', '㏜', '𓃠', '\u{a81}', '刃', '⿷', '𑁉', '𞄔', '𖡛', '⿐',
+    'ꫯ', 'ꋎ', '𐡓', '𛄙', 'Ύ', 'ᙍ', 'ᙊ', '𐗩', '𒊛', '𘪵', '𘪱', '▝',
+    '⌰', 'え', 'ꛤ', 'ᓢ', '𒍢', 'ﰇ', 'ᵽ', '㍒', '𖹢', '𔖮', '🨜', '᪘',
+    'फ़', 'ᄳ', '𛊀', '⾴', '𛊈', 'ᧃ', '𓯠', 'ᨢ', '𓤩', '㌽', '⸼', 'ⴇ',
+    '𓪎', '⤼', '\u{611}', '𝒵', '𓲳', '🚦', '𒍓', '𛀖', '⚘', 'ή', '𓣔',
+    '𖥪', '𐤍', '𓘚', '𓟀', '𓓓', 'ԕ', '𑠒', 'Ჯ', 'ꠄ', '⟤', '𔖜', '蚩',
+    '𒾺', 'ⲳ', '𖧤', '🝻', '𓳲', '𓄊', 'ꛊ', '𐓅', '⮮', '𑩳', '𜶸', 'ʡ',
+    '𝖠', 'ꦞ', '𐘼', 'ꔒ', '𝌅', 'ꭹ', '﵌', '𐫊', '𝝶', '𝂘', '\u{748}',
+    '𔍚', '⪯', '𛃦', 'ʝ', '𐰪', 'ꪍ', '𝥄', '\u{e4d}', '𓇃', '𑒦', '𒉽',
+    '𛀍', 'Ꮚ', '𛈊', '𝈚', '𞹡', '𓧄', '𓁯', '㈳', '𐓤', 'ڀ', '١',
+    '\u{10a3a}', 'ɨ', 'ᓂ', '𩈚', '🥜', '㓟', '𔉺', '⏢', '𔉹', '𘭝', 'ꖄ',
+    'Ꮙ', '𘭘', '𒌞', '𒔁', '၀', '▁', '𓜆', '𐖭', 'ꖠ', '𓽖', '⚲', 'ㆦ',
+    '𐡘', '𓻅', '𐤈', '𓍵', '𖼰', '𜷖', 'ಱ', '㎌', '𑖦', 'ᡃ', '㆜',
+    '𝤩', 'Ꟍ', '𛃑', '𘡔', '\u{1b44}', 'Ḕ', '𝂃', '𓒟', '𓶳', '⭣', '𓈽',
+    'ḱ', '𒊌', '𐚽', '🀤', '𑑈', 'ө', '𐕀', '┘', '𘰰', '𓺌', 'ꗿ', '𖭿',
+    '⣏', 'ﾼ', '𐳐', 'Ⱋ', '𑃱', 'ㄍ', '𑃁', '𓪣', '𔒺', '𒇟', '輻', 'ᰓ',
+    '\u{943}', '𒍋', 'ض', '𐁃', 'Ջ', 'ꛗ', '𐢘', '𓳠', '⩎', 'Ŷ', '↪', '⸵',
+    '🭉', '𔍞', '\u{11042}', '🀋', '𓡞', '🂸', '꛲', '𓛬', '🌬', 'ᥦ', '魯',
+    'ۆ', '鷺', '⫠', '🉥', '𔆭', '\u{fe21}', 'ﾍ', '𐐋', 'ꣾ', '𑅤', 'ꁋ',
+    '𝟈', 'ऐ', '𝦅', '𘫬', '㆗', 'ว', 'Ჿ', '𐰈', 'ꋨ', '𓃻', 'ᄊ', 'Ⲋ',
+    '𓆼', '𞁘', '𜽱', '𝛯', '𓬛', 'ᓉ', '\u{10f4b}', 'ʀ', '𐦈', '𝛅', '🗘',
+    '📗', '⒑', '𓓄', '𘰥', '𞸈', 'ɂ', '≣', '逸', '𒒰', '𛃺', '𑿂', 'ꢵ',
+    'ₕ', '𑿉', '𓨅', '⧒', 'ⱑ', '𐜨', '𐜥', '⾧', '𝩾', 'ꮲ', '⣰', '𝠣',
+    '𑏑', '𑯚', 'ஆ', 'ᘶ', '𑐈', '𛰦', '⍸', '𒎙', '🅟', '⪱', '𐴡', 'ꥺ',
+    '𐾱', '🤇', '┦', '᭸', '𞗹', '꣙', '𔇣', '𔇧', '🐌', '🢵', 'ᥛ', 'ᝌ',
+    '𞡖', 'ꛂ', '𘲘', 'ሮ', 'ऑ', '🕵', '𓒅', '𑢡', '𓜟', 'ꂺ', '\u{c3e}',
+    'ꂼ', 'ꟛ', '꡶', '꣓', 'ᰢ', '𓦶', '𑒖', '⥊', 'ꙮ', '𞸧', 'ᢖ', '𓶃',
+    '𓺨', '𐍧', '\u{302}', 'ୱ', '晴', '𞴩', '\u{e0155}', '𔖡', '≪', '🎌',
+    'ꎋ', '𜱃', 'ꄶ', '𐫳', 'Ⳓ', '㈕', '𑱹', '䷊', '𘩌', '𓴂', 'Ɪ', '⭠',
+    '𖪷', 'ᨁ', 'ᨼ', '⺲', '𒁼', '𑼒', '\u{2008}', 'ዔ', '𝐙', 'ﯱ', '𘤯',
+    '𚿽', '𘯮', 'ۇ', '𓦔', '𓓇', '𓦥', '𐜳', '❧', '𜶽', '𐝇', '🗞',
+    '𝌭', 'ꖺ', '𓺍', 'ꌻ', '𝐉', '𔃊', '𓱗', '⽤', 'ⵋ', '𑊒', '殟',
+    '𐾾', '𐄖', '𖼵', 'ᘱ', '𛋀', '𑓕', '𒆶', 'Ͼ', 'ჯ', '𓉽', '⠶', 'ի',
+    '𓽭', '`', '㋻', '㏧', 'ቲ', '𓠮', 'ㄊ', '۸', 'Î', '\u{1e00f}', 'Ὶ',
+    '\u{1da48}', '😤', '🆙', '⽄', '𜶂', '㌼', '𜷉', '𑈛', '𒾩', 'ꢥ', 'ꄭ',
+    '\u{dd6}', '益', '⼐', '𘱈', '𔓌', '𑥐', '𝌢', '⩏', '\u{1e004}', 'ꖚ',
+    '𓦱', 'ꄏ', '𘤈', '𘨦', 'ꬳ', '𐢭', '𓛳', '𐙿', 'ꤴ', '\u{ac3}', '𝥊',
+    '𒿘', 'ꬃ', '𞹧', 'ï', 'ꋬ', '𞹼', '𐒖', '𐎠', '𐒘', '𖡆', '⸂', '🏊',
+    '𑍢', '𑯶', '🙢', '𔌍', '𑍠', '𐺨', 'ꇂ', '👾', '\u{fe2e}', '㊩',
+    '\u{fb5}', '\u{302a}', 'တ', '𜷩', 'ꋫ', '𖬩', '\u{e01ec}', '྾', '᧺', '𐍴',
+    'ⶨ', 'ល', '𝄒', 'ϥ', 'ങ', '𘧊', '𑎐', 'ܟ', '𓦼', '𓞜', '𒂩', '𐑊',
+    '⼅', '⻏', '𓠘', 'ꊄ', 'ꓘ', '⭋', '𐩡', '𓪾', 'ꂓ', '𖥜', '\u{116b3}',
+    '῝', '㈡', '𒃿', '𐩽', '𞄂', '😌', '𑀖', '𓨑', '\u{16125}', '🯥', 'ㅼ',
+    'ឆ', '🐚', '𐳞', '\u{1b43}', 'O', 'K', '𒉮', '𑁝', '🀗', 'Ⴋ', 'ﮭ', '𓝔',
+    '𖭗', 'ᝀ', '🌆', 'ꃻ', '𞠙', '⪗', '𞁝', '𓘺', '𝦼', 'ﯖ', '𞄓',
+    '𜲹', '⩘', '𝂑', '𐃕', '🫚', '∅', '𓔟', 'ⶠ', '𓍔', '𐇖', '𔊶',
+    '𖠼', 'ￇ', '𔑂', '𞢾', '𜶗', 'ᣞ', '𝘏', '𝘋', '𔋋', '𞲈', 'ῢ',
+    '𓯖', 'ᵑ', '🦷', '𑈆', '𘢕', 'Ḷ', 'ᔩ', '𘢡', 'ᣅ', '𝝅', '𐓑',
+    '𖠫', '𑜷', '⺬', 'ꊴ', '⨈', '𑠦', '𖣒', 'ᐯ', '𑎱', '𓉧', '𓉫', '៱',
+    '𝤬', '㏬', '𞺕', '𘢀', 'ꄱ', 'ꏐ', '🂂', '𑘼', 'Ｆ', '⪾', 'ઓ', 'ꇈ',
+    '≳', 'ഐ', '𔏙', '🃚', '䷞', 'ṩ', 'ꔄ', '🄧', '𑒆', 'ঀ', '𞴯', '🄗',
+    '\u{16af3}', 'ᝠ', '𘬿', 'ꬌ', '⻦', '𜷌', '𓱇', 'ᵓ', '\u{1da4d}', '𐕹',
+    'ℂ', 'ア', '⇴', '\u{1da63}', '𐍐', '𝤆', '𘯚', '𘲣', 'á', '🤄', 'ࣆ',
+    '𔖤', '𒀌', '𐊅', 'Ꞿ', '𐦀', '𐿀', '➛', '㬙', '𑎢', 'ౡ', '㈴',
+    '𖢸', '𔁦', '𝗛', '\u{1058}', '𓒲', '❙', '𐛃', '𑎬', 'ꄜ', '𔇶', 'ꯡ',
+    'エ', '🭇', 'ꢁ', '⢞', '2', '𔒾', '𔓂', '𐺟', '꩐', 'ᵘ', 'ණ', '𑴙',
+    '𐼹', '𓧥', '⥌', '𑛆', '𖹂', 'ニ', '😍', '¶', '┛', 'Ἲ', '㏘', 'ʽ',
+    '🗨', '🤹', '˿', '𑖱', '🅫', '𐒰', '𒔎', '🂈', '✦', 'ꏚ', '𔌒', '㇂',
+    'ႀ', 'ﻑ', '𛁺', '𑤂', '𖪡', '\u{f18}', '𝃌', '𖧜', 'ጝ', 'ꡨ', '㍶',
+    '🌤', '𝖴', '⻐', '𐦎', '𐘚', 'ଡ଼', '䷎', '⒁', 'ș', '🐈', '瀞', '𐧽',
+    'モ', '𑱀', '𓵯', '𓮦', '🥆', '𓅏', '🃞', 'ᐦ', 'ಸ', '𓒡', '჻',
+    '𐞮', 'ꊾ', '⏋', '👁', 'ἱ', '⏀', 'ฅ', 'ꉪ', '𝓬', '𑀨', '𑵙', '𝔀',
+    'ꌦ', '\u{111cf}', '\u{e0029}', 'ᝊ', '𐆚', 'ķ', '𛲀', '🐨', '𒄳', '𐡜',
+    'ᶛ', '𓗌', '𐜠', '𛄉', '𞡆', '𓛕', '𔁈', '\u{e0106}', '𝼘', '🦃',
+    '𑤣', 'ꆩ', '𖄵', '⫍', '𓆩', 'ｒ', 'ﭏ', '𐛷', '𝣋', '𓁽', '𔔃', 'ᡆ',
+    '𔊸', '𔄏', 'ॐ', 'ࣉ', '𜸂', 'ғ', '🎺', '𔅺', '↥', '𞁋', '𐑄', '𓇇',
+    '𓶥', '⹀', '𑒔', '𛰔', 'ꆕ', 'ღ', '⌁', '🛳', '𖢒', '𓈷', '𝜣',
+    '𒅑', '⼾', '𖨀', 'ﴜ', '𝋡', '𒍜', '\u{ec8}', '🙁', '\u{1e008}', '⼠',
+    '𐹱', '𓵫', '𖬭', '𖹘', '𝡦', 'ꁒ', '𑁫', '𞋗', '𐼛', 'ਚ', 'ئ',
+    '\u{13452}', '㎼', '𝆔', '🯫', '𘯅', '𓉱', '𓑄', '\u{1bca2}', '𑥅', '𘭯',
+    '𝩲', '𔊨', '𝢞', 'ᘥ', '➂', 'ꁺ', '𓇈', '⎑', 'ᘒ', '🁶', '𑅓', 'ꂆ',
+    '𓼀', '𜽭', 'ꖍ', 'ද', '𖿡', '𞄻', '𜶭', '᱄', '𒃕', '🪩', '𐔆', '^',
+    '🪇', '𝞕', '⾒', '𡷤', 'ᅛ', 'е', '良', '𔒒', '𖽬', 'ᎋ', '🍺', '𓶫',
+    '𖽃', '𛋑', '🙜', 'ﾡ', 'ኸ', 'ﾒ', '𑩪', '𔋝', '\u{1dd4}', '𓾇',
+    '\u{a802}', '𑗆', 'ऻ', 'ᣉ', '𓼙', '𓰱', 'ꍩ', '𑚗', '𐡲', '𓛖', 'Ɬ',
+    'ᡔ', '⡇', 'ꭰ', '𖪆', '𔔍', 'ꭴ', '𜳮', '𘱨', '🯴', '𑄡', '𓿴',
+    '𓅬', 'ϡ', '𓂇', 'Ꚉ', '🀹', 'ꑬ', 'ɤ', '𘪇', 'ݘ', 'ˀ', '🮝', 'ሕ',
+    'ꏗ', '\u{64f}', '𔀾', '≊', '▰', '🭈', '〡', '🪷', '𐒁', '𓛚', '𑃥',
+    'ោ', '𜲚', 'ଐ', '𑚥', '⟽', '𓋯', '𐊕', 'ഇ', '𞁉', 'ꂩ', '︲', '𐰞',
+    '𞥑', '𓝳', '𓵙', '𖵦', '𐖱', '𖮁', 'ퟬ', '𓁝', '𝤕', '𝞱', '𓉦',
+    'ދ', '𝡏', 'ᚂ', '𓟫', '\u{11c9a}', 'ꣁ', 'ﷸ', '𞲓', '🞤', '𓴋', '₃',
+    '𝗉', '𓓱', '𞡡', '𝗅', 'ᴵ', 'ꤓ', '𐦓', '𝠒', '⏻', '𐰔', 'ㅜ',
+    '𓟦', 'એ', '\u{e0173}', 'Ӕ', '𔅙', '𑋞', '𐳉', '𞢇', '⾍', '㈝', '𓖈',
+    '𑜓', '𐝅', '𜲽', 'ヮ', 'ᢌ', '𐿫', '\u{e01c8}', '𞀾', '𜲤', '\u{1e01b}',
+    'ᝃ', 'ἰ', '🤉', '𞴞', '\u{1123e}', '㏷', '\u{10a38}', 'ࢾ', '𑒙', '𓠷',
+    '𑴁', '𘤝', '𔏳', '\u{11a07}', '𓠼', 'ꄋ', 'ꃲ', '𐙻', '𓃤', 'ㆽ', '⪲',
+    '𓋏', '𓋋', '𐭓', 'උ', '𘱓', '𑌤', '料', '𓵼', '𐀦', '⛨', 'ࢢ',
+    '𑅅', 'ꀒ', 'ꛮ', 'ᬚ', '⤊', '🞵', 'ꊨ', '𛉅', '🢘', '🝿', '𛆷', 'ᐩ',
+    '𓖂', '鱗', '㆟', '\u{cbf}', 'ʖ', '𖢦', '🨏', '𓼟', '\u{a70}', '𓙮', '𛰊',
+    '꧐', '𛆁', '𐑟', '𖠾', '\u{112e8}', 'ద', '𛃫', 'ᒕ', 'ܭ', '𑋠', '𘠰',
+    'て', '\u{2cf1}', '⯺', 'ힷ', '𝓆', '╒', '𝓎', '₰', '𑩂', 'চ', '𔁅',
+    'ﾑ', '🮖', '𓏩', 'ᵹ', '🅏', '\u{e31}', '✑', 'ၾ', '꣏', '⟆', 'ҡ', 'ꩊ',
+    '𔂣', '𞤠', 'ᓊ', '𛇌', '\u{20e8}', '𝝯', 'ࡸ', 'խ', 'ᾨ', '🟔', '𜳞',
+    'å', '𓚘', '憐', '𐌘', 'ﰅ', 'Ɯ', '𑎦', '⼣', '𛇲', 'ꓔ', '🆇', '𔓖',
+    'ϰ', '𐤘', '𒒿', 'ힵ', 'ﾉ', '🄁', '\u{d01}', 'ⴏ', '𒇶', '𛀳', '𐍠',
+    'ꋆ', 'ý', '𝀺', '᭖', '⚰', 'ꫵ', '𔓤', '6', '\u{fb4}', '\u{1085}', '\u{115c0}',
+    '𔇛', '𝋐', '𖤄', '𝡶', '\u{1de9}', '𓝣', 'Ẹ', '𖵙', '𓌢', 'ﮇ', '⏫',
+    '𖫀', '𘨿', 'ⴿ', 'ᘲ', '𒂘', 'ﮰ', '⑻', '🛕', '𝟜', '⡁', '𣫺', '𞴏',
+    '凞', '⾾', '𑊛', '\u{16f8f}', '👗', 'ㆯ', 'ꐸ', '𓅌', '𑝆', '⻁',
+    '\u{11837}', '𒔭', '𐘐', '💴', '𐅴', 'ΐ', '⮆', 'ᯆ', '𐹧', 'ᤕ', '𜲣',
+    'ᗼ', '𝧈', 'ើ', 'ﺞ', '𞠡', '𐭃', '⩿', '𑃵', '▜', 'Ž', '◤', '🯱',
+    'ꃒ', '⬇', '𒓩', 'ꙕ', '𝑔', '𘥷', '🂭', '𝤇', '𑴖', '🚬', '\u{8d2}',
+    '𓂓', '🄬', 'ብ', 'ன', '𓠇', '朗', '⎛', '᰿', '𔄩', '𐙕', '🭕', 'ㆲ',
+    'ꇷ', '𑬉', '𐎯', '𐐞', '🆠', '𞄙', '🁰', '𜷭', '\u{9cd}', '\u{a980}',
+    '𑎳', '𛄆', '𛄂', '▨', '𐕜', '⥩', '𖤝', '𝧟', '𘢉', '𘤩', 'Ⱚ',
+    '𐛺', '𔓫', '𝂾', 'ծ', '𔏷', '𒓼', 'ㆳ', '𓊐', '𑃲', '╁', '𔒨', 'Ὸ',
+    '𛉺', 'ݛ', '\u{1343c}', 'ʱ', '𑰣', 'ߏ', '🜶', '𐼃', '𓂹', '𘧍', '𝋢',
+    '𖢮', '𜵾', '𑦷', 'ᚁ', '🠠', '𒄻', '𝦕', '𜵫', '𓆬', '𓚵', 'ꏍ',
+    '𓆧', '🅭', 'ຠ', '꛶', 'ⴓ', '㋛', '𘠚', '𓼤', '\u{e0125}', '䷌', '𒓴',
+    '𝞺', '⛯', '🗺', 'ꯚ', '𓲓', '𐓵', '𑐨', '𛅼', '𔊡', '𝜐', '𝂖',
+    'ㆱ', '𑋸', 'Ợ', '𘪲', '🀸', '𑲊', '𑰚', '❊', '𐊁', '𐍑', '𐓯',
+    '㊞', 'ᯋ', 'ⲃ', 'ꛏ', 'ᄼ', '𐔜', '𓟗', '𑋄', '𔅾', '𝚿', '\u{fe06}',
+    '𒁍', '⬶', 'ꎾ', '𒁀', 'שׂ', '𑒍', '𞹤', '𘰄', '𔔟', '𒾖', '𖢛',
+    '𓚗', '𘣼', '𖽄', 'ܗ', '\u{1a68}', '😒', '\u{7b0}', '𖽊', '𜾔', '𝛌',
+    '𑒱', 'ꌜ', '𞄧', 'Ỿ', '𝘡', '𐭍', '🅗', 'ᔛ', '𓟞', '🅓', '༊',
+    '\u{1dd5}', 'ꆫ', '𔓲', '𔓶', 'ㄶ', 'ỏ', 'ꁟ', '𛰑', '𔆥', '𑠂', '꠵',
+    'ꑺ', '𞢨', '𖹧', '𜾇', '㊻', '𓾊', '𜹔', '𞗖', '├', '⫾', '𘯜', 'ၑ',
+    '𔕑', '𐢕', '🨈', '𑎓', 'ꥠ', '𓭔', 'ứ', '𓲷', '\u{e005c}', '\u{1e946}',
+    'ﻗ', '\u{110b9}', 'ᕕ', '𛰿', '𞠧', '∂', 'ᐈ', '𓯛', 'ፔ', '𘤸', '⟙',
+    '𓅸', '𒾪', '𘲂', 'ꑯ', 'ퟤ', '𝣬', '🂾', '𐵞', '𐙃', '🕰', '𓩑',
+    '🚖', '𒓿', '🠥', '𓀲', 'څ', 'ᨏ', '𐓆', '𐜍', '𐜑', '𞄩', '𐄎', 'ǩ',
+    '𝒫', '𝠭', 'Ù', '𓹊', 'ϧ', 'Ṃ', '〩', '﮻', '𒿒', '𘥯', '𝔱', '𘯉',
+    '𓟆', '𝗹', '\u{fb9}', '𓉪', '𓚟', '𔎥', '𓂸', '鼅', '𓳰', '⣙', '𓗑',
+    'ﴚ', '𔄵', '⥑', '⩩', 'ꈷ', '𔄮', '𝘙', '𜾭', '𓮄', '㊕', 'ࡤ',
+    '\u{1ba3}', 'ᐐ', 'ꒆ', '🯩', '︾', '𝝂', 'ꕖ', '⏳', 'ਝ', '🀇', '𐺘',
+    '𓜷', '𓋣', '𛈋', 'ꇹ', 'ԁ', 'ᅵ', '🁓', '𝌺', 'ꖛ', 'ꢈ', 'ߝ', '𛂉',
+    'ﲮ', '𝤈', '𝈛', '𖭟', 'ख़', '𐢋', '⯙', '𝚟', '𑜵', '𝍱', '∘', 'य',
+    'ⴀ', '𔘜', '\u{322}', '𑇐', '𜺯', '𘫇', '*', 'ﾚ', '✼', '\u{115dc}', '𓍦',
+    'ಳ', '𒊗', '🍊', 'ዪ', 'Ⓦ', 'ᮿ', '𔈍', '𜰰', '𓼶', '𓭓', '𓷛',
+    '𓲇', '𐕣', '🕝', '𒀻', '𖦛', '𒇚', '𓩂', '🃉', 'ꁡ', '𓮧', '𖤂',
+    '𑰁', '𜵁', 'ꔘ', '𐞭', '᧟', 'ॽ', '𓀄', 'ꎒ', 'Ფ', '🌅', '𒾳', '𐊈',
+    '👏', 'ꑑ', '𑩽', '⩕', '𝖎', '𑶆', 'С', '𓘎', '𜱑', '𜱅', 'ꪔ', '⻌',
+    'Ნ', '𒐇', '𝢾', '‱', 'ᵸ', '𝒲', '𐕌', '𒃻', '\u{11634}', '𝗢', 'ࠆ',
+    '\u{7f0}', 'ṁ', 'Ⅼ', '𛰭', '𒋨', 'ᙦ', '🏔', '𓨋', '🈒', '𓸣', '𐠟',
+    '﹕', 'Ⴜ', '𝀁', '😠', '🍧', '\u{e0137}', 'ﰁ', 'ꇵ', '𜸜', '\u{309a}',
+    '𐐠', 'ԃ', '𔔫', '🟇', '𒍡', '𐦋', '况', '𘤓', '🕕', '𑴫', '𑂖',
+    '泌', '𑿄', '𐬚', 'དྷ', 'ཛྷ', '𑦬', '𔖗', '𘦞', '䷀', '𓨳', '𝁎',
+    '𓰦', '𐒍', '𜱏', '\u{e0160}', 'ᘎ', '𘪰', '𓯝', '⟘', 'ኅ', 'ㆻ', '╤',
+    '𓠭', '𓗘', 'ꒊ', '洴', '𓔲', '🨩', 'ጾ', '𐴇', '𞡸', 'ꦯ', 'Ē', 'ح',
+    '🦑', '𐕓', '\u{6d7}', 'ᛥ', 'ꅼ', 'ꏠ', '𘬁', '𔀛', '𒿜', '𖨆', 'ꔢ',
+    '⩀', 'Ⓓ', '𝀰', '🧺', '℟', '𓎠', 'ḍ', '𓣪', '\u{6e0}', 'ᘛ', 'ᔏ',
+    '𔘦', '\u{10d6c}', '🌄', '⾨', '𐐜', '𖹐', 'ㅠ', '𓘖', '𒿞', '𖧴', '𝠼',
+    '𐬢', '𔓹', '𑫜', '𑩁', '🢤', '𘱍', '𝄬', '𜳃', '𒔶', '𛈈',
+    '\u{e0139}', 'Ź', 'ꚜ', '𓭈', 'ﲍ', '○', '𘱁', '𑅁', '𓒯', '✐', '𘪔',
+    '𐌿', '⻱', '🠹', 'ொ', '𝤤', 'ꭍ', '𑗑', '\u{10a0c}', '🢙', '𐞦', '𔋦',
+    'ᳯ', '𝕶', '𓼷', '⮌', '𝃖', '𘱎', 'ᖌ', '\u{11f42}', '㊊', '蜎', '𓥳',
+    'ⳣ', '螆', 'ﳆ', '𐧙', 'Ἃ', '𒍸', '𐳎', 'ᶇ', '𛃀', '𐐀', '𔒢', '㋁',
+    '𖣃', 'ᡸ', '𘣙', '𑇄', '𔃠', '𓊽', 'Ⲉ', '𑏓', '𐌚', '𖡵', 'ﱠ',
+    '\u{8ec}', '𓥛', '𘬑', 'ꀘ', '𘤘', '₅', 'ʧ', '𝁌', '⧾', 'ዩ', 'ᆑ',
+    '𖫩', '𑨘', 'ꒌ', '𐞹', '𐴏', '𔄣', '𒆛', '𔈫', '𔈯', 'ፒ', '𜹀',
+    '𓮷', '𒉕', '𖧺', '𓢄', '㊖', '𖤠', 'Ⱔ', '𓞾', '𔈢', 'ꊩ', '𒾽',
+    '𘧇', '⧠', '𑚑', '⽉', '꧈', 'ꋪ', '𘰭', 'ⓘ', '𘧰', '🞹', '𑂜', 'ⓨ',
+    'ܨ', '𓷫', '𓎮', '䀘', 'ᇂ', '𛂣', 'ﰑ', '╖', 'ﴒ', 'ﻲ', '𝼎', '𘮸',
+    '𐩷', 'Ᏻ', '𘰤', 'ꁂ', '𓽌', 'ꁅ', 'ク', '𝡂', '︺', '🤁', '🢚', '𝄋',
+    '꜅', '𝙠', '𝙤', '𝙘', 'ᶟ', '𑎆', '𑜱', '𓜼', 'ꇽ', '🤫', '\u{1133b}',
+    '𐽾', 'ᗋ', 'ꥮ', 'ܔ', '雃', '😴', '␎', '𑰃', '𝖕', 'ท', '𐝂',
+    '\u{a9b3}', '𓥓', '🇵', '𒅝', '𔕓', '𑀠', '\u{11cad}', '🅞', '𞸬', 'Ↄ',
+    '\u{11930}', 'л', '𓤬', '𓹪', '𓭸', '⾰', '𐣢', '𔐮', '𖮂', '🬨',
+    '\u{1ddf}', '𛈽', '𓯸', '꧂', '🃕', 'Ḩ', '𒓧', '⤔', '𒑖', '𛊽', '♱',
+    '𖭖', '🬚', '𖼬', '😼', '𑽈', '𝈈', 'ꗺ', '\u{2d7f}', 'ꯝ', 'Ꙩ', '杞',
+    '∥', '𓀔', '𒓇', 'ꖦ', 'ﵼ', '𒐮', '𑦤', '𓺎', 'ⵐ', '𔋯', '衠',
+    '\u{20d7}', '𜴶', '𐴑', '𘲅', '👅', 'ұ', 'Ⱖ', '𖡖', 'ꠐ', 'ꑧ', '𖩁',
+    '𐐙', '𑠊', '𐰼', '𑃴', '⣳', '\u{206a}', '𑠞', 'ӏ', '\u{1cf22}', 'ꌏ',
+    '𑴀', '🧈', '\u{2067}', '𔄙', '𔄞', '𔄚', '䊠', '𐼜', 'ꞧ', '𓏮', '𛂴',
+    '㈉', '𛄢', '𒓯', 'ꋇ', '𘪢', '⹝', '﹟', '⭂', '𛋡', 'ﶃ', 'ㆼ', '𝟐',
+    '➨', '𑿛', 'Ṏ', 'ᶶ', '\u{1d187}', '🩀', '𞤔', 'ﱩ', '⪰', '𓐢', 'ℿ',
+    'Ꙟ', 'ꌿ', '𑐅', '𒉂', '𑨝', '寘', '߀', '𖡩', '⍙', '❐', '𑬆', '🪑',
+    '⠡', '𖧧', 'ﻢ', '\u{e01ef}', '𑣿', '𔋖', '𑆦', '\u{9c1}', '𓏈', '⑸',
+    '𓪸', 'ᐻ', '𓴦', '𞠪', 'ꃡ', '𛁟', '𛰯', 'ᣴ', 'त', '𐡺', '𘦔',
+    '🚠', '᎔', '\u{1cf3e}', '𜴊', '𝝒', 'ￖ', '\u{e0069}', '𑱫', '㏄', '⮹',
+    '𘡴', '\u{fe26}', 'ﺗ', '𒂝', '𑣯', '𑎯', '⧊', 'ᴽ', '₤', 'H', 'ʊ', 'ῌ',
+    'ꖤ', '𘬊', '𑛈', '\u{1772}', '˺', 'Ꭶ', '🪼', '𐩶', '𖢴', '𓃑', 'থ',
+    'ꗧ', 'ệ', '𔈝', '📧', '𝈞', '꡷', '🟗', '⋆', '𜽵', 'Ɖ', 'ﱵ', '旅',
+    '⧴', 'ࠎ', 'ࠤ', '𓞪', '𖪧', 'ꇭ', 'ﯟ', 'ᛘ', 'ᬔ', 'ᑙ', '𖩖', '𓫘',
+    '𓓜', '𖽉', '牐', '𛊥', '🡵', '𐍵', 'ὦ', '𥁄', 'ᱰ', '﹡', '𘣵',
+    'േ', '𝘰', '𓡉', '𜸑', '𒁝', '᳀', '𞴝', 'Ⲕ', '䌁', 'ꈝ', '𒄅',
+    '𓴏', '𓗽', '籠', '𞸃', '𘦰', 'Ş', 'ꬹ', 'ꀝ', '𝔜', '𐳕', '𒉚', '𐲌',
+    'ꀲ', '🨮', '𐲒', 'Ẇ', '🭬', '😘', '奔', '𓮆', '𒁖', '䄯', '𓮋',
+    'ᛞ', '\u{1145e}', '🄘', '𓗸', '🄐', '𓗰', '𓗃', '␊', '𜱯', '𓐞', '❠',
+    '𐦗', '⾵', '𔍒', 'č', '𖽙', 'ﯵ', '𘠖', '\u{11a95}', 'ƪ', 'ନ', 'Ѣ',
+    '\u{11a8d}', '🄢', '⸾', '⭱', '𜺛', '\u{fe0d}', '⅖', 'ꦟ', '👸', 'ധ',
+    '𓊒', '⯠', '🄷', '𐜕', '𝟥', 'ꍚ', '𝍫', '\u{e0052}', '⺼', 'ᮕ', '𔖝',
+    'Ẕ', '𝐘', '\u{59a}', 'ﺧ', '⋞', 'ꁙ', '晉', 'ꁗ', '𔔔', '𐅼', 'ਸ', 'զ',
+    '⮳', 'ጁ', '𓟩', '𝒜', 'ᶲ', '⥟', 'ꐡ', 'ⵖ', '\u{17cf}', '𓔜', '\u{16f90}',
+    '➺', '𓿪', '𓶂', 'ఖ', '𜶜', '؉', '✢', '𔇟', '𑯍', '𓇌', 'ᐵ', '𓿩',
+    '🙺', 'ꐯ', 'ᢻ', '𒍑', '𑱁', '🟂', '慨', '\u{11d3c}', '𝍥', '𑈋', '𝄇',
+    'ԫ', '𞹙', '𓪦', 'ꓓ', '⹈', 'ᶸ', 'ⵁ', '🩉', '𝑮', '𝑪', 'ᗷ', '𝑲',
+    '𘣦', '𝑺', '𘠆', '𓊃', '\u{1343b}', 'ﵠ', 'ޥ', '𑰦', '⬸', '㉵', '⓿',
+    '∪', '𒐣', '\u{355}', '൜', '🧽', '૬', '\u{1a60}', 'Ꭻ', '𑖸', 'ᙋ', '𓭳',
+    'ᖸ', 'ӧ', '𝕦', '⧳', '䷔', '𒁣', '၈', 'Ỳ', '𖩋', '𒒀', 'ᰞ', '𐛱',
+    '𑙐', 'ᑕ', '𝟴', '\u{319}', '𖭔', '𓶢', 'ꇯ', 'Ꜯ', '𘨛', '𓞒', '⚍',
+    '🥹', '⺺', '𓏤', 'ࢯ', '😗', '⸰', 'ჳ', 'Ὥ', '⤖', 'ᦰ', 'ﴪ', '𞤱',
+    '𓽷', '\u{654}', '𔗆', '𖦆', 'ၡ', '䷃', '列', '≉', '𓜵', '𐘧', '！',
+    '𞀳', 'ỻ', '⭏', '𓙗', '𐆀', '𑓗', '\u{f96}', '𐦝', '𑯘', 'ξ', '𘯝',
+    '𓁏', 'ꐷ', 'ퟥ', '𘦼', '𔉖', '𔁠', '𓱸', '𜱍', 'ҝ', '𑚔', '𘣹',
+    '𑰖', 'ᶞ', '䷬', '𑤬', '𓥏', '⊯', '𑴈', '𘩠', 'ꟷ', '𘣒', 'ꭉ', '𑲄',
+    '∾', '𑢵', '𑌵', '𘥝', '𝓟', '𑶤', 'ײַ', '⣑', '༿', 'ꘟ', '𓄆', '⑨',
+    'ꧧ', 'ᓮ', 'ꅬ', '𐆛', '𘯪', '𐛆', '𑰛', '🁂', '𝣼', '𞠩', '⫇',
+    '弢', '𓥫', '𐜮', '⁃', '𝈯', 'ኡ', '癩', '𘥇', '𔌓', '𝨸', 'Φ', '𓨛',
+    '𓼑', '𝜍', 'न', 'Ǣ', '𒑑', 'Ц', '𜵙', '𔐭', '𒒖', '𑅟', '𝣑', '𑆛',
+    '🂐', '𝘟', '瞧', 'ﯬ', '𘲭', '炙', '𐺀', '⎰', '\u{1e013}', '╅', '🁳',
+    '𐕢', '¨', 'ꉇ', '\u{1cf08}', 'ẘ', '𝀩', '𔎪', '🩼', '\u{a9bc}', '成',
+    '𖄕', '𑑟', 'ⶖ', '🧌', '𘪪', '┮', '\u{827}', '\u{13430}', '𒅠', '🞧',
+    'ﴴ', '࿎', 'ዸ', '𝑹', '𑂮', '𓶐', '𓭆', '𔗍', '𓀱', '⛜', 'Ỻ', '♑',
+    '𐼤', 'ꔾ', 'Ὅ', '𑿅', 'ﵨ', '𓦏', 'ⱡ', '𑰈', '\u{1cf1a}', 'յ', '𓫔',
+    '𘣊', '🜼', '🃈', 'ﺠ', '𜺀', '𝆥', '🫸', '⻠', '∭', '𐎼', '𑧓',
+    '㍋', '𓿾', 'Ἆ', '𛊑', '𓦃', '𐤠', '𑱒', '𔒖', '𜶉', '𖠪', 'ᝬ',
+    '䷲', '࿃', 'ꆂ', '𑩠', '𖨛', '𑴐', '𓑴', '𑵧', '˘', '𒆏', '𛊶', '㋺',
+    '🯸', 'ዉ', '𝇒', '\u{11cb2}', '𜱷', '𒇒', '𐫝', '𛃒', '𞓫', '𛅑', 'ꌅ',
+    '𞸐', '𐎷', 'À', '\u{1da55}', '𐫓', 'ⲟ', '𘲠', '𞄛', 'ᕷ', '⪨', '𐚹',
+    '𐺤', '勤', 'ﷰ', 'મ', '𛁾', '𞢄', '𛋟', '𑅔', 'ꊬ', '🞮', '⅂',
+    '𑈐', 'ﭾ', 'ᖿ', '𑖍', 'ᔬ', '霣', '𞡽', 'ꨊ', 'ឧ', '𖡙', '𐐥', '𛆆',
+    '𐐮', '🯜', 'ꌬ', '𚿸', '𖭓', '𜰃', '𞅁', '𑅂', '𓠍', '🐼', '᱔',
+    '𛆟', '𓅃', '𓥸', '𝁺', '𑖻', '𜺝', '📮', '𒁸', '\u{1da30}', 'ᯔ', '꒩',
+    '⼹', '𞱴', '⻡', '⫫', '൚', '𖤌', '𓴵', '𓴹', '𓻿', '𑊻', '𒌺',
+    '𓃽', 'ꨉ', 'ᦴ', 'ᨕ', '🨖', '🏫', '𐨚', '𞴙', '𐛹', 'ꗊ', '㋩', 'ꢘ',
+    '⚭', '𔉞', '𓗩', '𒃎', '𘥙', 'ƶ', '\u{1da9e}', '𐃈', '🮻', '㍫', 'Ⴂ',
+    '𐴛', 'ꎴ', '⩫', '𐭧', 'Õ', 'ဠ', 'ॊ', '𐓹', 'ၚ', '𑯟', '⚗', 'Ƕ',
+    '𓆅', '𘡌', '𓃙', '🈂', '\u{11235}', '𝃵', 'ᕑ', '𑅩', '🀜', '𖄜',
+    '𞣍', 'Ҋ', '𞢋', '⯊', '𞁡', 'ꍫ', '\u{17b7}', '𖹷', '𖩍', 'ㄨ', '𖥀',
+    '𓓀', '🭋', '𛉳', '🡮', 'ᾶ', '꜍', '︒', '𞋒', '𔃤', '㊆', '\u{e017d}',
+    '⥞', '𓃣', '𓞣', '𐳃', '🨘', '🙯', 'ꊋ', 'ꢿ', '\u{e01e9}', '㺸', '𔐽',
+    '𒍮', 'ϕ', 'ឤ', '𝃫', '𑣪', 'ዴ', 'ᮃ', 'ဧ', 'ꆓ', 'ᚪ', '𒄲', 'P',
+    '𘮤', 'ꡩ', '𔀌', '⻳', '\u{16b32}', 'ἣ', '𐜞', '𓜤', '𝧕', 'ક', '𝚺',
+    '\u{fe2c}', '՚', '𓴴', '𐏂', '𑨢', 'Ⴥ', '🢞', 'ኌ', 'ጎ', '𘪶', 'Ԑ',
+    'Ꮾ', '𐹹', '\u{1da0b}', '𖧌', '\u{e0121}', 'ဥ', '充', '𘧮', '𑏘', '\u{2002}',
+    '𖹿', '🔵', '𖹵', '𧢮', '𓩏', '𛇻', '𑣈', '𐢐', '𑆤', '🠕', 'क़',
+    '𑆙', '𘣚', 'ꅮ', '𘯟', '💩', '墳', '≮', '⟚', '𘠽', '𖨪', '𐵉', 'ꦝ',
+    '𓕑', '𐰀', '𔂒', 'ᮆ', 'ꗔ', '≼', 'ಀ', '𝜗', '⯛', '🖄', '𐍘', 'ɖ',
+    '㍄', 'ા', 'ꟲ', 'ꍱ', '\u{112df}', 'Ⴓ', 'ܪ', '𝗲', '𔕃', '⾇', '𝈜',
+    '𓙷', '𓯢', '𝝋', '𒉖', 'ꭤ', 'ᯅ', 'ꓶ', '🞎', '𔈁', 'ᇎ', '𐀮',
+    '\u{1cf3b}', 'ๆ', 'ᒃ', '𒐪', '⋺', '𥘦', '舁', '។', 'ヱ', '𑱼', '🏤',
+    '𝌉', '𓆯', '𐕾', 'ᒂ', 'Ꮥ', '𓶣', '𔁏', '𐆉', '⥦', '\u{602}', '𓟨',
+    'ꅷ', '𓎌', '𓽅', '𐤛', '㊪', '⌻', '▍', 'ᔾ', '𖵘', '\u{fa5}', '𘧌',
+    '𘭹', 'グ', 'へ', '☯', '𓤾', 'ゲ', '𐐘', '\u{1dc3}', '\u{5aa}', '𘤊', 'ꀰ',
+    '𓊢', '𣎓', '𜺲', 'ﲖ', '𝁢', '𜳩', '⪦', '𝞭', '𐇩', '𐓦', '⦬',
+    '𞋇', '⚣', '״', '𓞊', 'ᄮ', '🐭', '𖼋', '祖', '𓞝', '〃', '𝐦', '𓊪',
+    '𐞟', '泥', '𒈙', '𝇘', 'ꤗ', '𔁞', '𐦤', 'ꦆ', '𑼯', 'ᑥ', '𝞳',
+    '𜺤', '𖄛', 'ᔨ', '⣿', '𓃩', '𖢈', 'ꞹ', '⊌', '\u{1e014}', '𐚄', '꡴',
+    '𓊞', 'ꜿ', 'Ӌ', 'ᾗ', '\u{8d1}', 'ᚍ', '𐌊', '𝌙', '\u{33a}', '🡀', '𛲜',
+    '𒐠', '𖨶', 'ꑰ', '𝅘', '𖬮', '𜴜', '𑿱', '𓉜', 'ꋀ', 'ﵸ', '∎', 'ᨧ',
+    'ᓧ', '⯈', '𑈎', '𘤤', '\u{1cd1}', '⇧', '𒋪', '𘩺', '𛃾', '聆', '𞊙',
+    '𐀬', '𓔽', '𓙿', '🚣', '𓯺', 'ħ', '𑃞', '\u{115bc}', '\u{1da42}', '𞺣',
+    '𐰄', 'ᱏ', '𐒡', '➼', '𐖷', 'प', '𛂍', 'ח', '𞺸', 'ﻮ', '⒎', 'ျ',
+    '𑓇', '𓱹', '𓫀', '𖩲', '≨', 'ඵ', '🔗', '𒆴', 'ᵲ', '🝽', '餩', 'հ',
+    '℮', '𜹄', '𝡮', 'Ώ', '৴', '🮯', '炭', '凌', 'ᡁ', '𝄳', '\u{afe}',
+    '⳻', '𝇌', '𓀊', '⣼', '⛗', '\u{1da16}', '🀕', '🖇', '↘', '𜴀', '𔉝',
+    'ꏙ', '𓸊', '𒓊', 'ᆁ', '𐣱', '🖹', 'ڊ', '𞡪', '🧊', '⣣', '📑', '𓌋',
+    '𔖺', '𐠅', '🇸', 'פ', '𜸙', '\u{1da65}', '𐀺', '𐗓', '♩', 'ᶝ', '𖢾',
+    'ꔍ', 'ﻛ', '𑫧', '𝗪', '𝗦', '𔏚', '\u{1a5e}', '𜱎', '𔇅', 'ᔙ', '𓍩',
+    'Ⴍ', '𓘃', 'ࢡ', 'ꨞ', '𓽕', '\u{11367}', '𜹐', '⥮', '𐰰', '𝡄', 'ᕞ',
+    '꜎', '𔖕', 'ﭶ', '𓺃', '𐩘', 'ఽ', '𓰀', 'ᵗ', '𔇇', '𞗞', 'Ꭸ', '𓍰',
+    '𝕮', '𓓢', '⢑', '\u{aa29}', '㌵', '𒑣', 'ဈ', 'ᯡ', 'ᕶ', '𓂐', '𐙖',
+    '𝜱', '𓞋', '𝣯', 'ॿ', '𝤘', 'ꜷ', '呂', '𞢱', 'ꯗ', '𐅛', '╙', '⽞',
+    'ᾧ', 'ŋ', 'n', 'Ҝ', '𓎻', '𘮺', 'Л', '冕', '𛉦', '𓁕', '𐖇', 'ş', '㊿',
+    '𘲫', '⏥', '𝡡', 'ꛟ', 'î', 'ʄ', '🗬', 'ఌ', '㈌', '🃟', 'ﲹ', 'ꁯ',
+    '𒂅', '連', '𝌿', 'ꢎ', '𐧗', '𖡹', 'ꢑ', '⺂', '𐰥', 'ᶭ', 'ᶹ', '𜾒',
+    '𝃝', '𓒤', '𐚕', '𐭨', '𛋍', '𐜶', 'ồ', '𜶩', '𐎴', '🁮', '𑌗',
+    '𞊟', 'ꡀ', 'џ', 'ǟ', '𖦀', 'Ꮽ', '𝁳', 'ꤏ', '𘰷', '𐾺', '𑍡', '🁠',
+    '⬨', '⟾', '𐐐', '次', '⾈', '㋀', 'ꃈ', '齃', '𑐔', '𔈭', 'ꣃ',
+    '\u{11a94}', 'ꑹ', 'ঌ', 'ꝟ', '𘰡', '🈨', '🀳', '𞢅', '𝈏', 'ꖽ', '୬',
+    '🏁', '𪊑', 'ᡫ', '駾', '𝑀', '𐵵', '𒆰', '𝑄', '𔑔', '𝑌', '𓪟',
+    'ĺ', '\u{1dfc}', 'წ', '𛂝', '𛀏', '涅', '⇶', '𝖹', '𓋝', 'ᆶ', '৸',
+    '𜹫', '𖺃', '💳', '𑅐', '𒓤', '𓬬', 'ⵞ', '🀐', '𑫖', '𛱥', '🧻',
+    '\u{17d0}', '⛑', '\u{11a52}', 'ᖃ', '𔀧', '𝢠', '\u{1136b}', '𖹜', '𐎛',
+    '䕡', '\u{fff9}', '𔔣', '𓷯', 'ꀾ', 'स', '⤛', '𑗕', '𘫸', 'Ɏ', '𐿥',
+    '𑶍', '𓾮', '⅔', '𖬗', '⼮', '𓚙', '𞹺', '𓟍', '𑈝', '𝦈', '𞟷',
+    '𜰔', '𓏣', '𒂊', '𔖎', '𜹵', '🚻', '𓢂', '𝋩', '𛱖', '\u{aa36}',
+    '𔉂', '𓕂', '視', '𐀄', '𓰴', '🔏', '\u{2004}', '𘳋', 'ˑ', '𒍖', 'ﺑ',
+    'ꠃ', '𔗯', 'ꍳ', '🕟', '⽯', '𖡊', '𔉘', '𓌎', '𐴎', '०', '𣏕', 'ᰄ',
+    '𞅆', '𝧰', '𓽩', '𝀕', '㍎', '𜱕', '⹛', 'ﰮ', '𓣱', '𓣵', 'ᶽ',
+    '𒿫', '𐖕', '𓽾', '𓈪', 'Ԍ', '\u{e01bb}', '\u{11130}', '㎢', '⚾', '⏰',
+    '‹', 'ฤ', 'Ἷ', '𘠫', '𓽵', 'ꍧ', 'ꆢ', '☞', '↙', 'ဒ', '𓭥', 'ᦡ',
+    '𞣄', '𓓚', 'Γ', '📰', '\u{89d}', '🙘', 'ⅺ', '𔃇', 'ꫩ', 'Ꮅ', '\u{337}',
+    'ᐅ', 'ܛ', 'ᝩ', '🅘', '𐳅', '\u{20e6}', '𐙬', '𐰨', '𓌵', '❞', '僧',
+    '𓧶', '𑤜', '𑱂', '𒇓', '𝤅', '\u{1d186}', '⇝', 'ꂨ', 'ꓖ', '𑻨', 'ﯾ',
+    'ꎕ', '᱿', 'ꤊ', 'ﭿ', '\u{8df}', '𐙐', '⛴', '𓡃', 'ᇖ', '𖤛', '𞥂',
+    'ꏺ', 'ᑐ', '\u{1abd}', '𝖼', '🍯', '𓻩', '𐂆', '𓻪', '👨', '𝔦', '𐋥',
+    '𒇅', '\u{7ab}', '𔂝', '𐡥', '൧', '𝔲', '𓋨', '𑌌', '𑄠', '𒂰', '🡔',
+    '𞤆', '🐴', '𞺖', '𘭽', '𘥗', '𛊪', 'ｔ', '𓕔', '𓕘', 'ộ', 'ﴮ',
+    '𔍑', '𑥀', 'ʬ', 'Ⅎ', '\u{20e0}', '暜', '⪘', '⅋', '𛁰', '𝋋', 'ꜛ',
+    '𘡬', '𐄫', '🝜', '灊', '𪈎', '𓕝', '𔉙', '𝌆', '寿', '⛍', '𓋊',
+    '␌', '𘣲', '熜', '𓣽', 'ꎇ', '𛂢', '𐓣', '𓣴', '𑴣', '𔎭', '𓘝',
+    '灷', '𑅭', '𝓵', '𝙟', '🃅', 'ﲶ', '艹', '᪑', '\u{1a65}', '㏡', 'Ⱈ',
+    '⁅', '䩮', '𐰦', '𘣴', 'ᣘ', '𜺎', '\u{11a36}', '۞', '𓰛', '२', 'ｂ',
+    'ഭ', 'ᒭ', '𘪑', 'ꀜ', '\u{1ac0}', 'ᇘ', '◨', 'Ⱂ', '𐰠', '𓿜', '𝐩',
+    '𘠩', '𘠭', '╹', '𛅲', '᭿', '𝐡', '𐗃', 'ꚷ', 'ꏨ', '\u{1c30}', 'ᥠ',
+    '\u{1143b}', '🌲', '𝼨', '𔅋', 'ퟑ', '𞡼', '𒄸', '\u{1da13}', '𓙀', 'ㄙ',
+    'ꯙ', '𖫘', '𓝀', '𓯽', '⦋', 'ꮾ', '꤂', '\u{1de3}', '⡳', '𞺚', '𝁹',
+    '₩', '𓽺', '🁯', '\u{180f}', '𑨚', 'く', '𒃃', '𞴗', '⾊', '𑎤', '𛃩',
+    '࿂', '𑱸', 'ᣗ', 'ퟄ', '𒓟', '𘭉', 'ਹ', '𒍫', '𓘧', '𒑊', 'ఐ',
+    '\u{114bf}', 'ᑀ', '\u{e01a7}', '⤥', 'ډ', '၂', 'ꨨ', '╽', 'ⵟ', '𓖤',
+    '\u{e01af}', 'ꉯ', '𑑐', '៕', 'ꖪ', '𐍩', '𓨣', '𖣗', '𓝢', '🡻', 'ᕄ',
+    '⬯', 'ꔪ', '𐫖', '🠚', '𓭱', 'ᡡ', '黾', 'ࠈ', '𒅵', '⪴', 'ﶾ', '𜹨',
+    '𔑏', '𖧼', '𑼊', '♺', '𔈅', 'ꪐ', 'ʨ', 'ᘋ', 'ऱ', '𑇩', 'ߕ', '𘫚',
+    '\u{1acd}', '𐕅', '𓝯', '㊁', '𝁣', '𖵴', '𐎕', '𔈊', '𐡂', 'ᄴ', '☡',
+    '諸', '𖼇', '𛱣', '𒄽', '₣', '𓺈', 'ꌶ', '𛂃', '𞠫', '🥣', 'ᩀ',
+    '𝝧', 'વ', '👱', '𑁯', '𓻚', '𓻞', '\u{8e5}', '𘥸', '𔕭', '𔆗', '𐚋',
+    '𓕟', '𒋠', '𜵌', '𓆁', '፷', '🭄', 'ꑎ', '\u{e0066}', '𞁕', '⟇', 'Ĝ',
+    '㉑', '𐩱', '𐖰', '𑆖', '𝠸', '𑄞', '𘱄', 'Ꜫ', '𝕊', '𔍡', '𑰄',
+    '⿵', '𛰹', '𐓎', '𐔕', '𘱬', '𓋸', '\u{1e024}', '𑌐', '\u{fac}', 'ᄚ',
+    '𐩤', '﹙', '\u{16af0}', 'ᛷ', 'ᦇ', '𓉂', 'Ἶ', '⽦', 'ꕕ', 'Ξ', '?', '𑛂',
+    '𞓹', '㛼', '\u{a9b7}', '𒅦', '𔎿', '𐼷', 'դ', '⊇', 'Ỗ', 'ꌗ', '𘩱',
+    '𓏑', '𓁚', '𖮎', '🬓', 'ꟳ', 'ᴮ', 'ぉ', 'ꫀ', '𓺝', '𐺆', 'ⶥ', '𔇎',
+    '𓈩', '𓛥', '𘡹', 'ᴥ', '\u{11101}', '⸥', '𝛑', '𖢆', '𐃢', '𓅍', 'ﰗ',
+    '𓴌', '\u{1d166}', '褐', '𛊮', '〔', '𞠅', '𑌊', '𝖄', '𐽳', '𐒵', '∞',
+    '፡', '𖭬', '𓁫', '𐜯', '👙', 'σ', '࠴', '𝖱', '\u{368}', '𘫊', 'ﶴ',
+    '𝕔', '⭷', '🏳', '⟈', 'ꥂ', '𝜃', '𝟂', '𐮏', '𒒦', '⎈', '\u{17ca}',
+    '𜺁', '𑶣', '»', 'ŀ', '切', 'Ց', '𑓄', '🅌', 'ᨣ', 'Ự', '𓻄', '‥',
+    'ꗯ', '𝈀', 'Ҳ', '𓰶', 'ఁ', '🕒', '🠊', '⊺', '𜸇', '𓺡', '︖', '𝞘',
+    '𐅌', '𝂓', '𝥗', '𜲥', '⧚', 'ᑬ', 'ﾭ', '⸺', '⠟', 'Ի', 'ཝ', '𜲼',
+    '㋃', '⮶', '𜳉', '𓂪', '𛁖', '𓉡', '𔘿', '𜰬', 'ꇁ', 'Ꙑ', '🤂',
+    '𒅕', 'ӳ', '🂤', '𞗥', '𒓵', 'ε', '𓝘', 'ଋ', '𐊙', '੧', '𑶖', '𒇙',
+    'ꍎ', 'េ', '𝛜', '㍣', '🔞', '\u{18a9}', '📺', '䷡', '𓗧', '𞗢', '📅',
+    '\u{891}', '⎥', 'Ʞ', 'Ꝧ', 'ቻ', 'ᦫ', '𓀮', '🎣', '串', 'ꪅ', '⪤', '𝁟',
+    'ᤊ', '\u{f7c}', '𐙄', '𑀬', 'ꜘ', '\u{1ac9}', '𓓟', '𐕟', '㏕', '𐬰', '侮',
+    'ᾕ', '𓿵', '⧋', '𘫖', '𝛎', '𐞖', '𑻦', 'ò', '𓹄', '᱉', 'ꎧ', '𓆭',
+    '⻪', '㋄', 'డ', '⚔', '⪍', '𝙞', '\u{11cb6}', '𓏍', 'ᅿ', '𞓚', '𝅝',
+    '𑐮', 'ﱥ', '屮', '𐋉', '🈚', '✏', '𓁾', '𓅆', '𞲩', '𑱖', '🭸',
+    'ꉩ', '⸩', '𖿢', '𝖃', '⌐', '𑖏', '㈹', '⻆', '𐰃', 'Ɜ', 'ꛙ', '𐜴',
+    'ꇻ', '綠', 'ꚲ', '𑈪', '𔈤', '𝠦', '𐑯', 'ᆨ', '𒒆', '≸', '者',
+    '\u{1612e}', '𧼯', '⨜', '𓧾', '𦉇', '𓙊', '𜹓', '〚', '🁵', '𘢞', '〘',
+    '𘢖', '𠔜', '𐧘', '🀪', '🁙', '𒇻', '㋍', '𔏔', '𓁬', 'ⱞ', '𞡠',
+    '𝠯', '𝜔', '⸅', '𓫖', '𦋙', '𔏁', '𛁈', '𛋆', 'ઋ', '𐼗', '𓡌',
+    '碌', '𑧑', 'ಲ', 'ሴ', '𐕠', 'ᓛ', '𝤲', '𐠳', '\u{1ddb}', '⡯', 'ⰸ',
+    '𑱠', 'ᡤ', '\u{b3e}', '𐔠', 'ᅭ', 'ꚤ', '៤', '𔊔', '𑿀', '\u{1dd2}', '𖡭',
+    '𑴚', '𑂙', '⬘', 'ﮓ', '𝁼', 'ෂ', '𓨡', '𓁑', '𔘑', '𝍊', '🤻',
+    '𐋋', '𓫩', '\u{594}', 'ᄈ', '⠜', '⽨', '𒁭', '⁼', '𑯜', '裞', '🞳',
+    'ꃥ', 'ん', '⦿', '❒', '💑', '𑌏', '𞤕', '꠴', 'Ѡ', 'Ш', '\u{1e001}', '、',
+    '𑃹', '𔔵', '🔹', 'ų', '𝓅', '🞡', '🄭', 'ꃐ', '𓙤', '𓍞', '\u{e0021}',
+    'Ơ', '𓼥', 'ꑃ', '𒑛', 'ピ', '\u{2df7}', '𐧺', '➐', '𐛪', '⹎', 'ᵤ',
+    '𝟫', '੯', '𒑕', '𔅅', '𓶏', '꯵', '𑼥', '㏏', '㉍', '𓿂', '㊋', 'ખ',
+    '𓝂', 'ᅀ', '‘', '\u{f83}', 'ꯅ', '𝁜', '𘯳', 'ൖ', '𞹰', 'ᇄ', '𘨒',
+    '𝁕', '𛁅', '𘲚', '𔕧', '𐲆', '᳁', '💏', '🐻', '𘰊', '𐼊', 'ﴌ',
+    'ٵ', '𖣦', 'Ꝑ', '𒓔', '𝐼', '𓷥', '𓷡', 'ᨩ', 'ﳉ', '𝣕', '𓻱', '𓞑',
+    '𐪛', 'ꨃ', '𝀳', '\u{a825}', '𔆻', '𓃳', '\u{1da5e}', '𞴱', '𑠩', 'ᤵ',
+    'Ꜷ', '𓻾', 'ꂲ', '𓹿', '𓹻', '🍟', 'ㅃ', '𛆂', '𒾕', '\u{1cd8}', '൨',
+    'ᦁ', '῭', 'ⱨ', '\u{11a56}', '𐇡', '𓖩', '𑐒', 'ࠃ', '\u{1cf11}', '𜰩',
+    '𛱰', '⠫', 'ꥨ', '𓄔', 'ꐽ', '⤆', 'ḽ', 'ᖀ', 'Ώ', '𜹧', '𞤗', '𞴺',
+    '𜽾', '𖪴', 'લ', '╞', '你', '丽', '𑠝', 'ﰋ', 'ꦏ', 'ᄆ', '𜰣', '٢',
+    'ꧦ', '꒧', '𓏴', '⮣', '𜺊', 'ꖏ', '🀎', '𐝊', '𛰥', 'Ј', '𥄙', '𘦄',
+    'ꢟ', '\u{2069}', '𑀙', 'ᰜ', '𘢌', 'ᧅ', '🌹', '𓫿', '𑚓', '\u{1e02a}',
+    'ᑤ', 'אַ', '𛅳', '𓚖', '𐰧', '𝙁', '𔍶', '⭘', 'ꛧ', '𓠟', '𔏢', 'ꄲ',
+    'ᴃ', '⫩', '💞', 'ᤛ', '𓦾', '⚒', '♕', '𝄢', '༌', '𖠦', '𓟯', '𑑍',
+    '⸀', 'ۃ', '𓞚', '𓡏', '𝖪', 'ì', '𝖢', '𓛛', 'ꐜ', '𑦦', 'ᱼ', '៲',
+    'ﱶ', 'ᴬ', '𝧲', 'ꀦ', 'ꀥ', 'ꀨ', '𔌈', '𘥼', '\u{fe27}', '۰', '𑫬',
+    '𒒁', '≟', '⇼', '𜸕', '𓲝', '𐃎', '㉝', '𐞍', '𓬪', 'ꡍ', 'ⓑ', 'ⓝ',
+    'ⓙ', 'ⓥ', 'ꍞ', '𐣫', '𓗭', '𑯁', '𝆝', '𑃖', 'ꝯ', '𑑅', '𓛧', 'ᥥ',
+    '📋', '∬', 'ᒛ', '𔓙', 'ࢅ', '𝼪', '🔰', '𖠧', '𘣇', '\u{20ec}', '𒌣',
+    '☂', '🪺', '🉑', '᎗', '\u{e01c1}', 'ᐧ', '℈', '𑬄', '൵', '🛲',
+    '\u{1e01c}', '𘨌', '𘨐', 'Ђ', 'ͽ', '𔐟', 'ꡮ', '𐎗', '𐫐', '𓚡', 'ᅃ',
+    'ᐪ', 'ꂑ', 'ꂔ', '៨', '𑃓', 'ሽ', '𓆉', '𐾀', '🖉', 'ﶵ', '𘲇', '𓒋',
+    '𓋤', '🙦', '𓈢', '㎑', '㊐', 'ꖔ', '㍖', '\u{11040}', 'ꀈ', 'ᠺ', '𘯕',
+    '𖬺', 'Ⅲ', '𓸴', '᧙', '𐖫', '𒿅', 'ﵲ', '˲', 'ౘ', 'Э', 'ꏋ', 'ꦛ',
+    '𝛔', 'ꍆ', '𓪀', '𞡩', '𛇂', '𐂎', '𑣆', 'ꩅ', 'ᇮ', '𔂙', '𘧉',
+    '⺾', 'ဆ', '𑘲', '㉄', 'Ⲛ', '༗', '⍅', '𐭾', 'ᱻ', 'ﾅ', '𓌍', 'ꙧ',
+    '𒊳', '𜰮', '𓱁', '閷', '𒆐', '𐃧', '⬔', '𘯑', 'Ｗ', 'ꈒ', 'Ｏ',
+    '𔂻', 'Ｇ', 'ꘗ', '𓐩', 'ꅐ', '\u{7af}', '🡕', '𓫍', '𔈃', '秫', '𔆈',
+    '𒐳', '吆', '𞄝', 'ꞷ', '𛈪', '卿', '𖼝', 'ꃮ', '🛼', '⍲', '༽', 'ﺲ',
+    '㏚', 'ટ', '㺸', 'ब', '\u{a9e5}', '𓧮', '𒌡', '𝀽', '𞺘', '䃣', 'К',
+    'ꅟ', '؋', 'ƺ', 'Ⳑ', '🀚', '🆣', '🤛', '塞', '♷', '𓂧', '爨', '𐒕',
+    '㈢', '𝕂', '𝅕', '𐑧', '𓬞', '🪀', 'ﳅ', '𜸒', '\u{e003e}', '𐝃', '𔏰',
+    '↻', 'ﱯ', '𓢸', '穊', '𔏨', '๙', '𐄣', '𞺲', 'ɥ', '𓪳', '𓪷', 'ᖮ',
+    'ꗨ', '𝁧', '🄻', 'ꏎ', '𔅿', '𓆌', 'ᐰ', 'ﳬ', '⍼', 'ꌀ', '𐧶', '𐄉',
+    '𘡢', '𐅋', '𝣛', '𖥳', '𔂭', '𓖃', 'ୠ', '𐼇', '\u{aa35}', '𓄄', '𓟘',
+    'ৡ', '𐋮', '⊛', '⸳', '\u{8da}', '𑯐', '𑑀', '🆈', 'ꢋ', '𞊬', '𓫮',
+    '⛖', '⾳', '𖣵', '𘬀', '𓡝', '\u{e007b}', '𑻶', 'Ꜽ', '𛃱', '𘳿', '𓺠',
+    '⍃', '𓣎', '𓣊', '⏄', '🫦', 'ꮩ', '𑫊', '𘲺', '𓒫', '🅲', 'ഗ', '⩠',
+    '𓔍', '\u{e01b2}', '\u{e01b1}', '⾽', '𓆓', '𜷯', '〆', '𒐙', '𑚘', 'ꙗ',
+    '𐿁', 'ﺅ', '\u{1cd4}', '𐵣', '𖥈', '𐙭', '\u{1cd9}', 'ɪ', '𐡱', 'が',
+    '𓚳', '𛉮', '⌋', '\u{1927}', 'ॷ', '⭺', '☕', 'ㅅ', '➩', '⩳', '𓎗',
+    '\u{c63}', '𓚼', '\u{eb5}', '⫉', '𛊁', '⽵', '㊵', '𖮏', '🈹', 'ꖝ', '𔐿',
+    '𒿂', '⾝', '𓜯', '𓜫', '♖', '𘰣', 'Ử', '⩋', '∗', 'ꢔ', '𓬥', 'ʺ',
+    '𑃘', 'მ', '戴', 'ꙋ', 'ἂ', '慎', '𐬻', '🡇', 'ꫤ', '𘰩', '𜵢', '𐪌',
+    '࿊', '𖠃', '𑦺', 'ᒴ', '\u{10f84}', '৪', '⟏', '𔂅', 'Թ', '𐄦', 'λ',
+    '𐛶', '𔒴', '⮱', 'ﮎ', '𝐈', '𒁉', '𒇰', 'Ἰ', '𝘕', '𐆁', '፤',
+    '𒑡', 'ښ', '𐂝', '𜹌', '\u{e0059}', '\u{8ed}', '𒾡', '𘤙', 'Ԁ', 'ﱋ',
+    '\u{e0041}', '\u{7ed}', '⽆', 'Ḥ', '𒔛', 'ⶃ', '𐒄', '𐔛', '𔌐', '𒅭',
+    '𑼉', 'ꚴ', 'Ἀ', 'ⓖ', 'ᧉ', '\u{fe08}', '𐛚', '𜷣', '𔎏', '𝛏', '“',
+    '𓣏', '\u{11d41}', '𑆐', '𝡁', '𞤶', '﹁', 'צ', '𒉍', 'ஓ', 'ﱼ', '𓚴',
+    '𐨨', '\u{a679}', '𝠾', 'Ԣ', '𓫣', 'ඃ', '\u{10eff}', '🃔', '🌪', '𔓼',
+    '𝠧', 'ჿ', '𝖿', '𑎎', '𘪜', '𖡉', '𖩂', '𐹴', 'Ӹ', '𐵴', '𝢱',
+    '\u{327}', '🌒', 'क', '᙮', '𖵷', '⼔', '✙', '𔎋', '𑇟', '𔎖', '𒿱',
+    'ꋛ', '𘮐', '୧', '𓠯', '𐇻', 'ᱡ', '\u{1183a}', 'ڂ', 'ஈ', '💯', '𐗙',
+    '𐋳', '𓤙', '𓸐', '\u{1daa9}', 'ꘜ', 'ꝲ', '𝄷', '\u{11637}', '⠽', '𝠢',
+    '𓠜', 'Ꝯ', '𓠦', '𐼞', 'ퟰ', '𝜨', '𓻯', '\u{900}', 'ꗻ', '⧫', '\u{8f0}',
+    'ꡡ', '𝜕', 'ᣡ', '𒐄', '𑩩', '𔄹', 'ྌ', '𓉭', 'ᅩ', '𐹷', 'ꚅ', '𘡄',
+    '𘡀', '𑴨', '𛀞', '𓉉', 'ⱎ', '𣑭', '𐞁', '𞡐', '🜡', 'Χ', 'ᧀ', '꧹',
+    '𐌃', '⁚', '𖬐', 'ዷ', 'ꞎ', '𝀿', '𜲟', '𓎁', 'ꮛ', 'Ⱀ', 'ᶡ', '𒉧',
+    '㏨', '𓔿', '༥', '𞋦', '\u{1ab8}', '𓛆', 'ù', '𐔿', '⍱', '⧹', 'Ᵽ',
+    '🛻', '𒌦', '𘩔', '𓨫', 'ᕨ', '🯔', 'ᆾ', '𔁗', 'ꉞ', '𓠁', '🔦',
+    '𜺚', '𐕱', '𛂦', '𖄄', '𛆗', '㍵', '𛀃', 'ⶑ', '𓺷', '\u{16123}', '𐇬',
+    '┄', '┒', '𓪻', '𐳨', '淪', 'ﱁ', '⫿', 'ꪤ', '𑙢', '\u{1acc}', 'ⶐ',
+    '⎇', '𑰝', 'ᄍ', 'ﾨ', '🗁', '𐚻', '８', 'ؽ', '𒐃', '𐄭', '👺', '𞺔',
+    '望', '𝒍', '᭕', '⒢', '⁇', 'Ꜧ', 'Ꝍ', '⨮', '𓀙', 'ⅹ', '▶', '𑖮',
+    'ᓀ', '🙪', '𔒤', '𪎒', '🬶', '𐀴', 'ㆭ', '\u{1cf06}', '隸', '𔃟', '𐃸',
+    '𘳄', '𑀍', '𓄧', 'Ǌ', '𓈲', 'ꬋ', '𝧳', '𖫄', '𘰳', '𔖅', '𐖴',
+    '🃱', '雷', '巢', '𓜐', 'ᄥ', 'ꢇ', '⡫', '𝡲', '𐭏', '𝔑', '\u{1714}',
+    '𛈀', '𞗦', '𑵕', '𘪩', '女', '𐌷', '𓝪', '🜖', '፴', '🤤', '🔇',
+    '🩵', '\u{c00}', '𐠎', 'Ꞧ', 'Ě', '𒀥', '𞗸', '菜', '𑬇', 'ꡱ', '𓬹',
+    '𑢸', '𒋕', '𝁐', '𝅘𝅥𝅯', '𐋭', '𔌸', '𑄊', '𔌰', '𒒝', '𜷀', 'ꫥ',
+    '𔒅', '𓀘', 'ခ', 'ᡓ', 'ㇾ', '𐅧', '𓿿', 'Ἑ', '𓍠', '◶', '𔓬', '𖭨',
+    '𛰀', '𐅘', '\u{732}', '𛰈', 't', '𝧷', 'ᆖ', '🀊', '𛃸', '🅙', 'ᘯ',
+    '𛉄', '𐋣', '⍶', '𘫨', 'ԧ', '⯂', 'ꂢ', 'ﭖ', 'ꮃ', '🢀', '🪪', 'ኼ',
+    '⾢', '𞹴', '𓵓', 'ꊲ', '𐴀', 'ᵨ', 'ㄇ', '𜱖', '𛉍', '𛃨', 'ᱩ', 'ￒ',
+    '𓃮', '♋', 'ﰷ', '🯢', 'ꁦ', '𔌊', 'ㅞ', 'ㅟ', '𔄁', '𐓈', '𑒁', 'ﺓ',
+    '𒊂', '什', 'Ι', '𐑶', 'ᓆ', 'ꦩ', '\u{1734}', '𖽼', '𑘰', 'Ý', '𐚝',
+    'Ꮆ', '⯒', '\u{e0038}', 'Ὃ', '𔕋', '𝄫', '𝑳', '𑑘', 'Ⲣ', 'Ꜻ', 'ⵥ',
+    '𓢡', '𒅬', '㋅', '𐵕', '𑋈', '𛆩', '؝', '꙾', '𝝸', '𝂴', '🖭', '㌚',
+    '𐂋', '\u{11074}', 'κ', '𐘆', '𓜂', 'ी', '𓢻', '𒈎', '𖡇', 'ᢗ', '𝡠',
+    '𓬤', 'ᅌ', 'ﭭ', '𓬨', '𓇘', '𝜒', 'ݽ', '𓠑', '𝂚', '\u{735}', 'Ḹ',
+    '⒝', '𒌼', '𓫭', '𑪼', '🔎', '𐋷', '𘰹', 'ꑱ', '𛰪', '𓫨', '𓳧',
+    'ａ', '𘰺', '𓝙', 'ŷ', '𐐩', '𐐪', 'ફ', 'ו', '，', '𛱦', '߇', '𐖊',
+    'ꧤ', '\u{a981}', '𘢂', '𒈪', '𐃌', '𛰙', '𛱁', '𛱇', 'ⅻ', 'ל', '𓎡',
+    '𛱑', '陵', '⓹', '𐺍', '𓑱', '𛱋', '⿒', '\u{e01dd}', '𓬠', '𓝞', 'ꈙ',
+    '𓠀', '🝛', '🌝', 'ﯘ', '𑚢', '𝂗', 'ﷅ', '𐀏', 'İ', '𑃐', 'བྷ', 'ᚐ',
+    '𒋦', '䷙', '𐋧', '𒋓', '𖭵', 'ﯼ', 'Ⲽ', '𛈒', 'ᚺ', '\u{486}', '🨯',
+    'ɵ', '𓏜', '⳽', '\u{e016a}', '𛁩', '𘧑', 'ൈ', '\u{1e2ee}', '𑊸', 'ꩾ',
+    '𜺭', '𘪻', '𜷔', '𛇜', 'ꩺ', 'Ɇ', '꘥', '꜈', '𑋛', 'ᔣ', 'ѿ', '𔋧',
+    'ⵍ', '𝃏', '🫏', 'ꀼ', 'ﯔ', '꒛', '𓢦', '⡂', '𛊄', 'ṝ', '𓀝', '𑎗',
+    'Ǽ', '🆬', '\u{e01c3}', '🧲', 'ĳ', 'ꩤ', '𓲆', '羕', 'ᢸ', '𒈝', '𛈛',
+    '𝢯', '𖹥', '𓌞', 'Ấ', '㒻', '䛇', 'Ệ', '\u{11c99}', '𝙂', '\u{343}',
+    '𐡛', '䷑', '𝂷', 'Ꭱ', '𓖠', 'Ѱ', '𓀿', '𑫉', 'ᖡ', '𑜌', '𑿐', '𜷤',
+    '⒬', 'ᄙ', '\u{cc7}', '𑋶', '⪖', '\u{aab2}', 'ꇼ', '💄', '𓤵', '𓤱', '𝈡',
+    '𑪿', 'ᕍ', 'ㄽ', 'ꐮ', '𓰗', 'ᴰ', 'ꦑ', '㍓', '⟑', '𖼊', '🤎', '𞡒',
+    'Ꞑ', 'Ṿ', '𑯖', '⌙', '㈫', '\u{1732}', '𐂩', '𐂭', '𒀩', '🢷', 'ᐠ',
+    '𐪋', '𓘀', '𓿈', '⪙', 'භ', '⠾', 'ꫲ', '𔒳', '༳', '𓕉', 'ǖ', '⣾',
+    '𒃛', 'ᙼ', '\u{32d}', '𐢇', 'ꠘ', '𑈜', '☴', '𓶵', 'ᚚ', 'ۿ', 'ᑄ',
+    '𔊕', 'ꛢ', '𓾆', '\u{1193d}', '𑢼', '㈃', '\u{1cf0e}', '𛰠', '⫺', 'ﶇ',
+    'ຕ', 'Ȗ', '𑚄', '𒌻', 'ϱ', '㍰', '𓾄', '𘡒', 'ꢂ', '🤠', '⸭', '𐱃',
+    '𝖡', '𜳜', '𜳘', '𜳤', '𓥆', 'ꊎ', '\u{1dc9}', '𖤐', '𓦈', '陼', '𜵺',
+    'Ꙙ', 'ῒ', '𐅈', '\u{11ca4}', '𓾝', '𒒔', 'ຬ', '𒓽', '◢', '𜴓', '㒞',
+    '㊨', 'ᾙ', '𐁉', '⤅', '␒', '𝣆', 'ꬖ', '⭪', 'ʍ', '𓿼', '𝠁', '⪑',
+    '⬰', 'ﵗ', '𑄶', '(', '𔇀', '\u{11241}', '𝙱', 'ᦠ', 'ꆝ', '𝧒', '讀',
+    '𜺒', '\u{11a99}', '🂶', '🎐', '𛁛', '𔒉', '𓸑', '𐧏', '𝒰', 'ᙹ', 'Ȍ',
+    '🫧', '⭕', 'よ', '𓅺', '𐀙', 'ඟ', 'ﶱ', '⭰', '𜶯', '𔍹', '璉', '🁁',
+    '𓄗', '𓋰', 'ꊭ', '◗', 'ল', '𐒼', '𑴕', '𑘎', 'ⷘ', 'ⶶ', 'ḫ', '﵍',
+    '𒿀', '𒀈', '𒈣', '𔃻', '𔍘', '𑆧', '𐲥', '⁞', 'ഢ', '♌', 'ꠢ',
+    '𓵪', 'ꆨ', '𒄪', '𔍋', '䈂', 'ꊪ', 'ᤝ', 'Ἄ', '𔋗', '𒐩', '🭞',
+    '𝣞', 'ᗌ', 'ᗎ', '𖼗', '🚒', '☽', 'ꂹ', '唐', '⭎', '⥭', 'é', '𞴻',
+    '𑀭', '㇯', 'Ꙭ', '\u{1da1b}', '𓧗', '㎗', 'ԥ', '㇎', 'ꏢ', '𓹳', 'ꮼ',
+    '⮨', 'ࡴ', '𑯰', '𓤮', '𔐱', 'Ê', '𑶦', '𝠥', '𘤽', '⍠', '海', '𘯃',
+    '𓫅', '🤥', 'ꭾ', 'ﬢ', '⪋', '𔆌', '\u{1da59}', 'ὠ', '𐺗', 'ꗫ', '𓊎',
+    '\u{1122f}', '🏼', '⍘', '𐺠', 'ꪕ', '𓷺', '𓔱', 'ȕ', 'ꖻ', '𘥞',
+    '\u{1da6b}', '\u{11cae}', 'ꊧ', '𐹳', 'ﯩ', 'ꯍ', '⨋', '\u{11180}', '𝠝', '𖵎',
+    '∃', '﴿', 'ᆧ', '≲', '𞴲', '𖬥', '𘪖', '𛇅', '𓌨', '巡', '𒀣', 'ꞽ',
+    'Ԏ', '𓢈', '𓹐', '🪆', 'ᦏ', '𓏹', '॰', '𑵴', '◖', '⮞', '\u{e0143}',
+    '🁑', '𓱜', '꩞', '․', '⋮', '𒇉', '虐', 'ꔈ', 'ㅍ', '\u{1cf17}', '𝜞',
+    '⡭', 'ꫜ', '𔈵', 'ᙫ', '⚥', '🛋', '𜰌', 'ᆂ', '⅄', '\u{20d1}', '𐎱',
+    '∨', '\u{11044}', '𑣃', '\u{1dce}', '⫞', '﹑', 'Ꚋ', '𜿁', '\u{20e3}',
+    '\u{5ac}', '𖺓', '𖵰', 'ꕰ', '🎫', '𑢿', '𓬅', '𓅙', '𝤝', 'ⴤ', 'ޝ',
+    '🧥', '⋩', 'Ⴐ', '𜴩', '🠺', '⨁', 'ᕚ', '𘫅', '𘫉', '᮸', '𒑱', '𜺂',
+    '𓔼', 'Ⱁ', '𞠽', '➪', '𓹎', 'ᵟ', '𛱪', '𞲜', '𓞼', '𖥌', 'ꪘ',
+    '𒈿', '𐪃', '𐡙', '𑚈', '𝍄', '𐣬', '\u{e01b0}', '𒐚', '𓯪', '𘮻',
+    '⺍', '𒂃', '𑚯', '𓷁', '⇆', '𓯇', '\u{e013e}', '𝌛', 'ﮈ', '𓒆',
+    '\u{e015a}', '𓫻', '𓊑', 'ᙡ', '喙', '🔛', '𝈭', '𔍐', 'ꪯ', 'ꧭ', 'ꃬ',
+    '𔍤', 'ݧ', '𒑚', '🝘', '𐾹', 'ꭳ', 'ꮊ', '嘆', 'ᅕ', '𒄰', '𛆄', 'ᯥ',
+    '₨', '𝐷', '𛉛', 'ﱾ', '𛀷', 'ᴯ', '𝑃', '╧', '𝑋', '\u{e014c}',
+    '\u{1bca1}', '㏁', 'ớ', 'ﶲ', 'ᛣ', 'ஏ', '⫋', '\u{113bc}', '𑒈', '𐝄', 'ꫡ',
+    'ᙐ', '』', '🛗', '𑌂', '蓳', 'ⵀ', '菊', '𦵫', 'ﾢ', '𛋔', '𓾭', 'ﴂ',
+    '🝼', '𓷷', 'ᆰ', '\u{e005b}', '𓪗', '𐢒', '𐀿', '䕝', '𘭐', '𐙽', 'ퟝ',
+    'ㄻ', '𓥞', '𓛒', '𝦱', 'ᴀ', '𓥕', '𐑁', '𖺀', '𐙞', 'ᡵ', '𛈸',
+    '𜷝', 'ἡ', '𓂬', '💐', '𑀞', '𐁓', '𜱰', 'ᇵ', '𜽿', 'ₖ', '𛄁',
+    '𐼶', '👄', '㇣', '𓷣', '𓢇', '𛰷', '𔋹', '𐖪', '🄈', '𝤡', '𔀅',
+    'ꎩ', '𘠘', '𐘮', '\u{11f38}', 'ꄘ', '\u{faf}', '𛃋', '𝛵', 'ᐕ', '𓸏',
+    '𝦋', '˶', '𓢌', '𓕡', '⽏', '𓋖', '𖡪', '𔅳', '꫞', '𓽔', '𘢗', '⋼',
+    '🌂', '𔕁', 'ꓪ', '㋉', '\u{1cf45}', '𓓬', '𐃉', '𓖔', 'ﳏ', '𓉌', '𓗏',
+    'ﳤ', '𓲕', '𓷩', 'ሗ', '𑌭', '𔅯', '\u{e014f}', 'Ỷ', '⣲', '⨔', '🝅',
+    '⼡', '〖', 'ⱁ', '𝍦', '🄣', 'Ղ', '𐵁', '🬳', '؟', '🥰', 'ⷕ', 'Ⱞ',
+    '蓱', 'ꨄ', '𒅈', 'ۺ', '🯣', '𑑊', '𒀤', 'ꘂ', '⢘', '𝦄', '𞢻', '⯔',
+    '𛱻', '𝢋', '𑥗', '𦼬', '\u{1e944}', '⳹', '𜳂', '𐾆', 'ࢺ', '𝦂', '𓅢',
+    '𔕫', '𝝓', '𝁃', '𝐾', '𓞬', '𐛂', '𞹪', '𒉁', '🄹', '✥', 'ힶ',
+    '𘫁', '🠆', 'ⅇ', '˔', '𒉈', '𐠏', '🐵', 'ﱞ', 'ꋶ', 'ꗗ', '☦', '𐁌',
+    '𖩹', '𛱢', '𓠲', '㆕', 'ᒮ', 'ꈍ', '𓞙', '𝇊', 'ᣇ', '𐅾', '𓉗',
+    '𓉒', '🂑', '\u{c4a}', '𐚱', '\u{1b3c}', '樂', '﵉', 'Ⳝ', '𖢤', '𐐣',
+    '𐐛', '𐮬', '𔃩', 'ୌ', 'ꪢ', '\u{113cf}', '𛰱', 'Ꝺ', '㌫', '𒍍', 'ᢼ',
+    'ଲ', 'ᖪ', 'ꬱ', '𘲷', '𞓧', 'ᕦ', '᎓', 'ם', '🪴', '𐺛', '⦧', '𑋂',
+    '𞤅', '𝥏', '韠', '𐚨', 'ꜙ', '𓪃', 'ᄽ', '𑤕', '𓌠', '𖨙', '\u{30e}',
+    'ঔ', '𐒃', 'ﲼ', '𒍵', '\u{307}', '𒋘', '☉', '𓨞', '𞊩', '𓴾', 'מ',
+    'ෲ', '𖦑', 'ꟕ', '𛊳', '㋫', '∖', '🏰', 'ꕴ', '\u{5bc}', '𑩅', '𓬜',
+    '𒀸', 'ἄ', '𖢽', '𛲆', '\u{1b3a}', '⥄', '𘮙', '꯸', 'ꨌ', '⥢', '𞄒',
+    '𞋷', '𑬂', 'ᗪ', 'ᗭ', '𓶓', '𝒢', 'ࢤ', '𑫑', '𓥁', '𖽦', '𔘳',
+    'ᄶ', '𐽲', '𑫝', '🩬', '⪀', '𝘔', '𑀆', '⢾', '𜶤', '𘭋', '⥠', '⭆',
+    '𛱓', '𓥻', 'ᜠ', '𔕏', '𑇘', '𓢓', '𒂕', 'ꅯ', 'ᛟ', '𔀹', '𔂼',
+    '𓏿', '\u{615}', '𒍐', 'ꗸ', '𝥈', 'ⶣ', '㍟', '𑶈', '⬅', '𓶻', '𓶿',
+    '𔐹', 'ꅽ', '𔑁', 'Ƨ', 'Ḽ', '𘲡', 'ꋸ', '𜰝', '⌼', '𝛍', '🉤', 'ꐐ',
+    '𛇩', '𛇥', '🝝', '𐔎', '⋧', '𛇮', 'ो', '𖫙', '𓎛', '𜸻', '𜰆',
+    'ꌆ', '𞋊', '𔆊', '𓰌', '𐦍', 'گ', 'ⴈ', 'ƛ', '⛱', '𒔵', 'ݖ', '𜲑',
+    '𘬼', '𞀰', '𑑔', '𞀸', '✌', '𛉤', '𝅂', 'ὲ', '🯏', 'Ა', 'ᡖ',
+    '𑘾', '꧉', '𘤮', '𝤶', '\u{17bc}', 'ə', 'ȧ', '𒔃', '⽩', '𐰋', 'ঊ',
+    '\u{ae3}', '┏', '⧬', '𞤟', 'ຢ', 'ꗾ', '𓫪', 'ᓏ', 'ᚔ', '🭡', '𐛎',
+    'ꉚ', '🔠', '🌺', 'ｨ', '🛀', '𜵂', '𐠷', '𘦌', '𓂤', '🎿', 'ｩ',
+    '𒓷', '\u{113c7}', '🦲', '𔀦', '𐒦', '𑫓', '𖪅', '𒎌', '🐠', '\u{11a59}',
+    '𛂶', '館', '𞡄', '\u{e0166}', '𑃙', '\u{e0073}', 'ᗐ', '\u{e006b}', '⋲', 'ϖ',
+    '突', '𔇱', '𞣁', '𝒌', '𓸮', '𓸪', '𓆆', '𘲒', '𔎍', '𞄕', '𒊡',
+    'ந', '𒂥', '\u{34d}', '𒋣', 'ﷁ', '𔍰', '𒿃', '𞤵', 'Ϲ', '𐰐', '⍓',
+    '𓥋', '၁', 'ⅽ', '𒅚', '𓝱', '𐨕', '𐙨', 'ퟫ', '𓸢', '✹', '\u{11d95}',
+    'ػ', '𛈤', 'פֿ', 'ත', '𔑙', '𑲇', '\u{5b1}', '娛', '㛮', '𓯑', '⨛',
+    '🯲', '𔖻', '🥋', '𑄼', '㩬', '𝼗', '⍻', '𑀊', 'ᩕ', 'ઊ', '😬',
+    'Ჵ', '\u{9fe}', '𓝇', '\u{1344e}', '𐭑', '𒑇', '\u{e0032}', '𝛸', 'ﲐ', '𐗕',
+    '𔃍', '𑊵', '𝕞', 'ᒏ', '≈', '𔇜', '꯱', 'ꩣ', 'ﶭ', 'ꡎ', '𐦵', 'ᗈ',
+    '𝃣', '𓀶', '𜹱', '𐂸', '𘦜', '𓓾', '𓃄', '𓥹', 'ꮶ', '␇', 'Ł',
+    '𑈽', '⊑', '𐓛', '🍰', '⍗', '𝚡', '𓠳', '𘬄', 'ۮ', '𑪴', '⥖', 'ꇒ',
+    'ﺜ', '𞺥', '\u{1e8d1}', '𐔇', '𑅑', 'ቖ', '𖢏', '𔀡', '∴', '𓬟', 'ᓘ',
+    'ᔞ', 'ݢ', 'ﮫ', '🈴', '𜳅', '🟡', '֊', '⛃', '◘', '‼', '𑩣', 'Ꮦ',
+    '𓭻', '𐰶', '\u{e0060}', '👵', '𛈁', 'Չ', '🝂', '🗿', '𓈅', '𓎃', 'ᅯ',
+    'ụ', '🔱', '𘧽', '⍚', '𑒣', '𔃬', 'ᣌ', '𛰸', '\u{13447}', '𝄸', '𑨪',
+    '𒇵', '𑈙', 'ᄛ', '⧜', '🌊', '𜷽', '𛱼', 'ゞ', 'ꉿ', 'ﶯ', '⎘', '𐰇',
+    '𓱳', '𐧥', '𔌖', '𘨥', '𔃪', '𘨭', 'ﵪ', '𑢾', '⠂', '𝑙', '𝒽',
+    '𝑡', '𓐃', 'ṵ', '𑜿', '\u{119e0}', '𛂼', '\u{1da24}', '▄', 'ꋋ', '𓱼',
+    '𞄉', '᯿', 'ᲆ', '𓺭', '𐮅', '𜸧', '𐶃', 'ﭤ', '🅻', '𛱵', '𑙃',
+    '𖧩', '␂', 'ꊡ', 'ϵ', '𔖃', 'ࡱ', '𒊯', '裺', '⒟', 'ⲉ', '𘫑', '𓖰',
+    '秊', 'Ầ', '𜵊', '♥', '𔗣', '𝅘𝅥𝅮', 'ฯ', '∽', '𛆨', 'ᇹ', '␞',
+    '\u{6d8}', 'ᬛ', '𐪓', 'Ꭿ', '\u{1cf1b}', 'ꫨ', '\u{e007d}', '𝐄', 'ꥃ', 'ﻌ',
+    '⒈', 'ﵯ', '𑇅', 'ך', '𒄭', '𝕌', '壮', '𑨓', '\u{329}', '𝔼', 'ⷞ',
+    '𓂠', '𖢷', '\u{1da01}', 'ᜌ', 'ꡒ', 'ņ', '𒒪', '⒦', '\u{7aa}', '𑒯', '𛱔',
+    '🮹', 'ꊼ', '𑑝', '𔂄', '𓺟', '൘', 'ᄩ', '𓶎', '𓑰', '𝝼', '￫',
+    '𖨭', '𝢴', '𛉈', '𝐃', '\u{f97}', 'ⶳ', 'ー', 'ᴳ', 'ꉄ', '𓣻', '冗',
+    '㒹', '𛂈', '𐚏', '𘱮', '⯢', '\u{1e005}', '㎕', '𛱊', '🞞', '𑜋', 'ꀤ',
+    '𝠬', '٩', '੭', 'ꮸ', 'ெ', 'ﮆ', 'ቜ', '𝣻', '𛃝', '𐨲', '𘯁', '🩨',
+    '꧗', 'ｽ', '\u{a953}', '𝣷', 'ȉ', '❚', 'ᠠ', '𖵚', 'ᠢ', 'ﲬ', 'ꚨ', 'ꊗ',
+    'Ꮵ', 'Ꮴ', 'ﶩ', 'ᗦ', '🌈', 'Ꚗ', '𓥡', '∋', '\u{fe22}', '䔫', 'ڣ', 'ᛆ',
+    '𝠅', '⟩', '🉡', '\u{1112f}', '\u{2dfa}', '𓥮', '≂', '\u{1da9b}', '⥓',
+    '\u{1df9}', 'ꐙ', 'Փ', '𐢌', 'ʾ', '𐅭', '🠄', 'ꓲ', '𓡼', 'ퟶ', 'ꔨ', '（',
+    '𝼁', '𒑙', '𞁪', '𞄆', '𓵋', '𑋐', '𓒴', '𝢪', '禍', '𑂨', '𖠑',
+    '🡿', '𓞛', '\u{e0070}', '𒉃', '𝧜', '▦', '𖺏', '\u{e0064}', '𓫶', '𓖀',
+    '𔁤', '⺎', '𓇹', 'ࡪ', '𝍀', '⟔', 'ꛕ', '摒', '𓛡', 'ꤺ', 'ҕ', 'ਖ਼',
+    '🗏', 'Ⲟ', 'п', '𓖣', '𑫨', '𓽦', 'Ḛ', '𝕡', '\u{bc0}', '⦡', '𖫕',
+    '𓦯', 'ᘠ', 'ꕤ', '𒾷', '𓳱', 'ﰢ', 'ʒ', 'ᢏ', '\u{e39}', 'ᏻ', '𝜼',
+    '輸', '𐫮', '▽', '𘮌', '𓽫', 'ᶋ', '㊚', 'ꗀ', '𛋅', '𖹣', '𓳼',
+    '𛊱', '𔇵', '\u{313}', '𛋌', '𑀩', 'ⶦ', 'ꃙ', '\u{81c}', '𓋿', '𛊘',
+    '𖥍', '🗒', '𓒭', 'ꮑ', '\u{33f}', '𐰣', '𜶶', 'ꏈ', '𓕊', '𓒑', '∤',
+    '𒈸', 'ꀸ', '🫂', '\u{2dfb}', 'ካ', '🗐', '\u{a676}', '\u{e0116}', '᪇', 'ᨄ',
+    'ﲨ', 'Შ', '🬆', 'ߒ', '𑈓', 'ⶌ', '𝜊', 'ꖌ', 'ᥤ', '︱', '\u{206e}',
+    '🄺', '⚨', '𓝕', '𞹇', 'ᅻ', '𓀀', '𓣹', '𓀖', '𓤄', '𝈊', '𐨓',
+    'ꃠ', '𓱊', '꒹', '൩', '✶', '\u{16af4}', 'ཕ', 'ඊ', '𓬏', 'ﭨ', 'ɩ',
+    '𖤤', '𓬆', 'Ⳉ', '𓍬', '𔆼', 'ꌡ', '𘬏', 'ᚈ', '𔎝', 'ﮘ', '𝥣',
+    '𝝟', 'ꣳ', '𘬋', 'ᒺ', '𔒽', '𝼞', '𘬂', '𞹏', '🖨', '𝥉', '𐫎',
+    '𓴠', '𑣦', '𑪶', 'ꥻ', '𓹇', '𐑵', '∇', '\u{116b0}', 'Ұ', 'ᇾ', '🜙',
+    '𔖲', '𓾹', '≞', '𔓝', '𓾵', '\u{8e3}', '𒑝', 'ኣ', 'ꌵ', 'ࢥ', '🤦',
+    '꣒', '🭻', '🮄', '㉕', '𓊏', 'ꚗ', 'ꏧ', '𐠝', '\u{902}', '𓟬', '𝗌',
+    '𖽅', '𓱠', 'ቺ', '𠔥', '˱', '𝚏', '猪', '𘬤', '𔅡', 'ᡇ', '𖼺', '🝨',
+    '𚿰', '⽶', '\u{1d17c}', '⯧', '㶖', 'Ӊ', '𓵭', 'Ᵹ', '𐂥', '𐛅', 'ૉ',
+    '🍿', '𜹿', '↲', 'ℇ', 'ꄙ', '𐼅', '𔅮', 'ቝ', '\u{1ac5}', '⅘', 'ᆟ',
+    '౫', '🁎', '𒆎', 'ퟠ', '𔋲', '𓸛', '🞱', 'ﱒ', 'ꉴ', '𓮖', '𑰨',
+    '𒑐', '𖵮', '🯖', '縂', '🁩', '𝀓', '𜱱', 'ꅃ', '樓', 'ꁆ', '𓇯',
+    'ꃔ', '𓖬', '𑃨', 'ⷔ', '𑨍', '𑵽', '𖫞', '𐽖', '𛲘', '𒾣', '𐬼',
+    '🄸', '𓁩', 'Ѫ', 'ꁾ', '\u{1a5b}', '🛎', 'ꁽ', '⨝', 'ꩯ', '𝟻', '𐠄',
+    '☧', '👞', '𒆻', '𓠤', '𘧈', '𑻠', 'ᚩ', 'ꓭ', '育', '𓤝', '𒂫',
+    '\u{101fd}', '\u{e01d3}', 'ﶠ', 'ꑝ', '𘪍', '𦔣', 'ꐶ', '꤅', 'Ǳ', 'ࢦ', 'ɦ',
+    '𜸽', 'ﳭ', '𒄦', 'ᒯ', '🯪', '𜹭', 'ꔲ', '⇹', '𖭯', '⻯', '𔘙', '𒓭',
+    '\u{113e2}', '𓇞', '𓙅', '𐛘', '𐡼', '⎼', '𒀞', '𘰙', 'ⱓ', '𑱬', '㈅',
+    '𘬣', '฿', 'ꗮ', '𢬌', 'ႛ', '\u{e3a}', '𑚙', '𓳬', '𒐶', '𜵚', '┲',
+    'ὒ', '𒄕', '🧸', 'ᡝ', '፭', '⺘', '𐴢', '\u{11369}', 'û', '𓙫', 'ꐅ',
+    '\u{113d0}', '𐳡', '⹙', '𓧚', '𐌕', '𝥃', '🧩', '𝔧', '便', '𒀕', '𓌚',
+    'ᬗ', '⇵', '🥖', '🇩', '©', '🮲', '𓌆', '㇔', '𖪊', '⡋', '𐊩', '𘦗',
+    '𐩹', '𓍓', '𝆱', 'ꨎ', '\u{9c3}', '𝘪', 'メ', '𖥆', '？', '⪊', '𒄈',
+    'ര', '𐄨', 'ᡳ', '🜧', 'Ҧ', '節', '𑃕', '𔀷', '▉', 'ㅿ', '𖠔', '𒿄',
+    '𓬝', '𜳀', '𒌌', '𓊛', '𘲱', '𓻑', '🁐', '⢠', '𐴲', 'ꐏ', '𓩠',
+    '⡧', '🌧', '𓿉', 'ꤌ', '𓿁', '𐦥', '𝈥', '𝈧', '𑦥', 'ﲵ', '𑫎', 'ۏ',
+    '⦎', '𞤰', 'ᾞ', '𓿎', '𓿊', 'ȁ', '㎙', 'Ἢ', '𓢬', '᭓', '𓵲', '𔇸',
+    '𑶠', '圖', '𒂌', 'ჴ', '𜳟', '⛞', '𐐃', 'ﮤ', '🢛', '𔀄', 'ᡧ', '☀',
+    'ꛉ', '\u{1dd3}', '𓌡', '🐊', '𐱈', '⬫', '܃', '\u{1da75}', '⊕', '⽌', 'ᶺ',
+    '፬', '𑛢', '𑠚', '\u{1da19}', '￦', '旣', 'ኖ', '𔏌', '\u{e015d}', '\u{2066}',
+    '𓝲', 'җ', '🆅', '🭆', 'Ж', '𓝿', '滇', '𒅫', '𒅧', '𒐕', 'ﴔ', '𑐤',
+    '𓈘', 'а', '𓋄', '𘢸', '𓘏', '𝈶', '𓸒', 'ꎐ', 'ԑ', 'ꤳ', '𖢿', '𔘐',
+    'ﺮ', 'ꐁ', '⯜', '\u{ce3}', '𝁾', '𓁡', 'ʉ', '៣', '🆔', '\u{1712}', '𛰕',
+    '𖣼', 'ﰕ', '𓩾', 'ᙚ', '𓬃', '𓶨', '𐪞', '🔢', 'ﯥ', '𝓚', '🔳', 'ҵ',
+    '⫘', '᎕', '𓹛', '𓘻', '⭶', '\u{f84}', 'ᮻ', '🙭', '\u{11a90}', '\u{8ea}',
+    'ႁ', '𐛮', 'ꪟ', '𘤒', '𑩡', 'Ὀ', '𔒲', '\u{1aba}', '𘰮', '梅', 'ⲭ',
+    '𐪚', '⼚', '𝣫', '𔔲', '𑩨', '🧓', '🂽', '‚', '⢸', '𓳓', '𒀢',
+    '𒆥', 'Მ', 'ꮠ', 'స', '⢅', '𐛳', '𓯻', '𐛻', 'Į', '𘨇', '𘨋', '𞋡',
+    '🠋', '𓵥', 'ꁍ', '𐾇', '🞸', 'န', 'ゑ', '𖩚', '𞟢', '𔀝', '𒌾',
+    '\u{b41}', 'ꪬ', '\u{e0043}', '𘥻', '𜵰', '𘦃', '𜾨', 'ꕔ', '𓌳', '𓲠',
+    '𑇍', '𔊈', 'آ', 'ᾢ', 'ܖ', '𑎇', '𝛮', '㍻', '鸞', '𖬃', 'ꄌ', '𖪼',
+    '≗', '𛉠', '𐡞', '𓸶', 'Ṳ', '𐄻', 'Ժ', '𓷹', 'Ŀ', '𒅹', 'ﲥ', '⥺',
+    '𝥙', '𐅄', '⫷', 'ꭧ', '\u{e0163}', '｣', 'ỷ', '𓕴', '⒛', '𓃝', '𔃗',
+    '𝣏', '𘬠', 'ᗕ', '𐪙', 'ꋧ', 'ⱀ', '꧟', '𔖯', '𒄑', 'Ꝛ', '𑩮', '𖮃',
+    '𓠙', 'ᇔ', '𓲺', 'ꝗ', '⠑', '𓝐', '◼', 'ꏌ', '𐓩', 'Ｄ', 'ꢼ', 'ၹ',
+    '𓇒', '⻭', 'ꯎ', '𘫀', '𞓐', '𐭛', '𖵐', '\u{aa2e}', 'Თ', '𜽷', '🡐',
+    '🟠', '𓵀', '𐑠', 'ᶮ', '𐙋', '𓶉', '\u{e01a9}', '𘣔', 'ꕞ', '𑦮', '𛂸',
+    'ⓩ', 'ꢏ', '⮊', 'ꤘ', '🨅', '\u{1dee}', '🄰', '\u{36f}', '✁', '𓍺', '𜳰',
+    'ꐼ', '𐄊', '𑆢', '𑧊', '𒀖', 'ꌎ', 'ጘ', 'ኯ', '𝼓', 'ሔ', '𝃃', 'ﯛ',
+    'ᨰ', '𓈻', 'ᚦ', '𒑋', '𓉀', '૫', '⇖', '𝌚', '⯋', '𘱆', 'ⶒ', 'ວ',
+    '𓕐', '𝼦', '𖥨', '𝡵', '😯', 'ﰾ', '𐺭', '𓼝',];
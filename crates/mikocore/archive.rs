@@ -0,0 +1,578 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{self, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::SystemTime;
+
+use flate2::read::{DeflateDecoder, GzDecoder};
+
+use crate::vfs::{DirEntry, FileMetadata, LocalFs, Vfs};
+
+/// End of central directory record, plus the longest comment it can have
+const MAX_ZIP_TAIL: u64 = 22 + u16::MAX as u64;
+/// Largest file read out of an archive; bigger ones are refused instead of decoded into memory
+const MAX_ENTRY_LEN: u64 = 64 << 20;
+/// Files of a tar.gz kept decoded after the stream passed them on the way to another
+const TAR_GZ_CACHE_LEN: usize = 32 << 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+impl Format {
+    fn of(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+        if name.ends_with(".zip") || name.ends_with(".jar") {
+            Some(Format::Zip)
+        } else if name.ends_with(".tar") {
+            Some(Format::Tar)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Format::TarGz)
+        } else {
+            None
+        }
+    }
+}
+
+/// True if `path` is named like an archive `Archive::open` reads: zip, jar, tar, tar.gz or tgz
+pub fn is_archive(path: &Path) -> bool {
+    Format::of(path).is_some()
+}
+
+/// Where a file's bytes are kept in its archive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Data {
+    /// Offset of the zip local header, which the data follows after a name and extra field of its own
+    Zip { header: u64, compressed: u64, method: u16, encrypted: bool },
+    /// Offset of the data in the uncompressed tar stream
+    Tar { offset: u64 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Entry {
+    len: u64,
+    /// None for folders
+    data: Option<Data>,
+}
+
+/// What reading a tar.gz leaves behind for the next read, as it can only be decoded from the start
+#[derive(Debug, Default)]
+struct TarGzCache {
+    /// Decoder stopped after the last file read, with its offset in the tar stream
+    stream: Option<(u64, GzDecoder<BufReader<File>>)>,
+    /// Files decoded while skipping to others, by offset, up to `TAR_GZ_CACHE_LEN` bytes in all
+    files: HashMap<u64, Vec<u8>>,
+    cached_len: usize,
+}
+
+/// A zip or tar archive listed once when opened, with its files decoded each time they're read
+///
+/// A tar.gz keeps its decoder between reads, so files read in order are decoded in one pass,
+/// and keeps the files it skipped over so reading them later doesn't start over.
+#[derive(Debug)]
+pub struct Archive {
+    path: PathBuf,
+    format: Format,
+    len: u64,
+    modified: Option<SystemTime>,
+    /// By `/`-separated path inside the archive; folders only implied by their files are listed too
+    entries: BTreeMap<String, Entry>,
+    tar_gz: Mutex<TarGzCache>,
+}
+
+impl Archive {
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let format = Format::of(path).ok_or_else(|| invalid("not a zip or tar archive"))?;
+        let mut file = File::open(path)?;
+        let metadata = file.metadata()?;
+        let mut archive = Self {
+            path: path.to_path_buf(),
+            format,
+            len: metadata.len(),
+            modified: metadata.modified().ok(),
+            entries: BTreeMap::new(),
+            tar_gz: Mutex::default(),
+        };
+        match format {
+            Format::Zip => archive.index_zip(&mut file)?,
+            Format::Tar => archive.index_tar(tar::Archive::new(BufReader::new(file)).entries_with_seek()?)?,
+            Format::TarGz => archive.index_tar(tar::Archive::new(GzDecoder::new(BufReader::new(file))).entries()?)?,
+        }
+        Ok(archive)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Entries directly in the folder at `dir` inside the archive, `""` being its root; None if there's no such folder
+    pub fn read_dir(&self, dir: &str) -> Option<Vec<DirEntry>> {
+        let prefix = if dir.is_empty() { String::new() } else { format!("{}/", dir) };
+        if !dir.is_empty() && self.entries.get(dir)?.data.is_some() {
+            return None;
+        }
+        let entries = self
+            .entries
+            .range(prefix.clone()..)
+            .map_while(|(path, entry)| Some((path.strip_prefix(&prefix)?, entry)))
+            .filter(|(name, _)| !name.contains('/'))
+            .map(|(name, entry)| DirEntry { name: name.to_string(), is_dir: entry.data.is_none() })
+            .collect();
+        Some(entries)
+    }
+
+    /// Decode the file at `path` inside the archive
+    ///
+    /// Files declared bigger than `MAX_ENTRY_LEN`, or whose data doesn't decode to their
+    /// declared length, are refused.
+    pub fn read(&self, path: &str) -> io::Result<Vec<u8>> {
+        let entry = self.entries.get(path).ok_or_else(|| not_found(path))?;
+        if entry.len > MAX_ENTRY_LEN {
+            return Err(invalid(&format!("{} is {} bytes, more than the {} read out of archives", path, entry.len, MAX_ENTRY_LEN)));
+        }
+        match entry.data {
+            None => Err(io::Error::new(io::ErrorKind::InvalidInput, format!("{} is a folder", path))),
+            Some(Data::Zip { encrypted: true, .. }) => Err(invalid("encrypted zip entries aren't supported")),
+            Some(Data::Zip { header, compressed, method, .. }) => {
+                let mut file = File::open(&self.path)?;
+                file.seek(SeekFrom::Start(header))?;
+                let mut local = [0; 30];
+                file.read_exact(&mut local)?;
+                if local[..4] != *b"PK\x03\x04" {
+                    return Err(invalid("damaged zip entry"));
+                }
+                let start = header + 30 + le(&local, 26, 2)? + le(&local, 28, 2)?;
+                if start.checked_add(compressed).is_none_or(|end| end > self.len) {
+                    return Err(invalid("damaged zip entry"));
+                }
+                file.seek(SeekFrom::Start(start))?;
+                let data = file.take(compressed);
+                let mut data: Box<dyn Read> = match method {
+                    0 => Box::new(data),
+                    8 => Box::new(DeflateDecoder::new(data)),
+                    method => return Err(invalid(&format!("zip compression method {} isn't supported", method))),
+                };
+                let contents = read_exact_len(&mut data, entry.len)?;
+                // Data that goes on past its declared size is as damaged as data that stops short
+                if data.read(&mut [0])? != 0 {
+                    return Err(size_mismatch());
+                }
+                Ok(contents)
+            }
+            Some(Data::Tar { offset }) if self.format == Format::Tar => {
+                let mut file = File::open(&self.path)?;
+                file.seek(SeekFrom::Start(offset))?;
+                read_exact_len(&mut BufReader::new(file), entry.len)
+            }
+            Some(Data::Tar { offset }) => self.read_tar_gz(offset, entry.len),
+        }
+    }
+
+    /// Decode a file of a tar.gz, going on from the last read when the file comes after it
+    fn read_tar_gz(&self, offset: u64, len: u64) -> io::Result<Vec<u8>> {
+        let mut cache = self.tar_gz.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(contents) = cache.files.get(&offset) {
+            return Ok(contents.clone());
+        }
+        // A failed read leaves no decoder behind, so the next one starts over
+        let (mut position, mut stream) = match cache.stream.take().filter(|(position, _)| *position <= offset) {
+            Some(resumed) => resumed,
+            None => (0, GzDecoder::new(BufReader::new(File::open(&self.path)?))),
+        };
+        let mut skipped: Vec<(u64, u64)> = self
+            .entries
+            .values()
+            .filter_map(|entry| match entry.data {
+                Some(Data::Tar { offset: at }) if (position..offset).contains(&at) && entry.len <= MAX_ENTRY_LEN => Some((at, entry.len)),
+                _ => None,
+            })
+            .collect();
+        skipped.sort_unstable();
+        for (at, len) in skipped {
+            if cache.cached_len + len as usize > TAR_GZ_CACHE_LEN || cache.files.contains_key(&at) {
+                continue;
+            }
+            io::copy(&mut stream.by_ref().take(at - position), &mut io::sink())?;
+            let contents = read_exact_len(&mut stream, len)?;
+            position = at + len;
+            cache.cached_len += contents.len();
+            cache.files.insert(at, contents);
+        }
+        io::copy(&mut stream.by_ref().take(offset - position), &mut io::sink())?;
+        let contents = read_exact_len(&mut stream, len)?;
+        cache.stream = Some((offset + len, stream));
+        Ok(contents)
+    }
+
+    pub fn metadata(&self, path: &str) -> io::Result<FileMetadata> {
+        let (is_dir, len) = match path {
+            "" => (true, self.len),
+            path => {
+                let entry = self.entries.get(path).ok_or_else(|| not_found(path))?;
+                (entry.data.is_none(), entry.len)
+            }
+        };
+        // Entries take the archive's time, so a watcher sees them change only when it does
+        Ok(FileMetadata { is_dir, is_symlink: false, len, modified: self.modified })
+    }
+
+    /// Add an entry and the folders leading to it; names that climb out of the archive are dropped
+    fn insert(&mut self, name: &str, entry: Entry) {
+        let parts: Vec<&str> = name.split(['/', '\\']).filter(|part| !part.is_empty() && *part != ".").collect();
+        if parts.is_empty() || parts.contains(&"..") {
+            return;
+        }
+        for end in 1..parts.len() {
+            self.entries.entry(parts[..end].join("/")).or_insert(Entry { len: 0, data: None });
+        }
+        self.entries.insert(parts.join("/"), entry);
+    }
+
+    /// Read the central directory at the end of a zip file
+    fn index_zip(&mut self, file: &mut File) -> io::Result<()> {
+        let tail_len = self.len.min(MAX_ZIP_TAIL);
+        file.seek(SeekFrom::Start(self.len - tail_len))?;
+        let mut tail = Vec::new();
+        file.read_to_end(&mut tail)?;
+        // The record ends the file after its comment, which may itself hold the signature
+        let end = (0..tail.len().saturating_sub(21))
+            .rev()
+            .filter(|&at| tail[at..].starts_with(b"PK\x05\x06"))
+            .find(|&at| le(&tail, at + 20, 2).is_ok_and(|comment| at + 22 + comment as usize == tail.len()))
+            .ok_or_else(|| invalid("not a zip file"))?;
+        let (count, size, offset) = (le(&tail, end + 10, 2)?, le(&tail, end + 12, 4)?, le(&tail, end + 16, 4)?);
+        if count == 0xFFFF || size == 0xFFFF_FFFF || offset == 0xFFFF_FFFF {
+            return Err(invalid("zip64 archives aren't supported"));
+        }
+        let end_offset = self.len - tail_len + end as u64;
+        if offset + size > end_offset {
+            return Err(invalid("damaged zip directory"));
+        }
+
+        file.seek(SeekFrom::Start(offset))?;
+        let mut directory = Vec::new();
+        file.take(size).read_to_end(&mut directory)?;
+        let mut at = 0;
+        for _ in 0..count {
+            if !directory.get(at..).is_some_and(|rest| rest.starts_with(b"PK\x01\x02")) {
+                return Err(invalid("damaged zip directory"));
+            }
+            let name_len = le(&directory, at + 28, 2)? as usize;
+            let name = directory.get(at + 46..at + 46 + name_len).ok_or_else(|| invalid("damaged zip directory"))?;
+            let name = String::from_utf8_lossy(name).into_owned();
+            let entry = Entry {
+                len: le(&directory, at + 24, 4)?,
+                data: Some(Data::Zip {
+                    header: le(&directory, at + 42, 4)?,
+                    compressed: le(&directory, at + 20, 4)?,
+                    method: le(&directory, at + 10, 2)? as u16,
+                    encrypted: le(&directory, at + 8, 2)? & 1 != 0,
+                }),
+            };
+            if name.ends_with('/') {
+                self.insert(&name, Entry { len: 0, data: None });
+            } else {
+                self.insert(&name, entry);
+            }
+            at += 46 + name_len + (le(&directory, at + 30, 2)? + le(&directory, at + 32, 2)?) as usize;
+        }
+        Ok(())
+    }
+
+    /// List a tar stream's folders and regular files; links and special files are skipped
+    fn index_tar<R: Read>(&mut self, entries: tar::Entries<'_, R>) -> io::Result<()> {
+        for entry in entries {
+            let entry = entry?;
+            let kind = entry.header().entry_type();
+            let name = entry.path()?.to_string_lossy().into_owned();
+            if kind.is_dir() {
+                self.insert(&name, Entry { len: 0, data: None });
+            } else if kind.is_file() || kind.is_contiguous() {
+                let data = Some(Data::Tar { offset: entry.raw_file_position() });
+                self.insert(&name, Entry { len: entry.size(), data });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The disk, where archives opened with `mount` read as read-only folders in place of their files
+#[derive(Debug, Default)]
+pub struct ArchiveFs {
+    mounted: Mutex<Vec<Arc<Archive>>>,
+}
+
+impl ArchiveFs {
+    /// List the archive at `path` so it can be browsed; mounting it again reads it again
+    pub fn mount(&self, path: &Path) -> io::Result<()> {
+        let archive = Arc::new(Archive::open(path)?);
+        let mut mounted = self.mounted.lock().unwrap_or_else(PoisonError::into_inner);
+        mounted.retain(|mounted| mounted.path != path);
+        mounted.push(archive);
+        Ok(())
+    }
+
+    /// Show the archive at `path` as a file again; false if it wasn't mounted
+    pub fn unmount(&self, path: &Path) -> bool {
+        let mut mounted = self.mounted.lock().unwrap_or_else(PoisonError::into_inner);
+        let count = mounted.len();
+        mounted.retain(|mounted| mounted.path != path);
+        mounted.len() != count
+    }
+
+    pub fn is_mounted(&self, path: &Path) -> bool {
+        let mounted = self.mounted.lock().unwrap_or_else(PoisonError::into_inner);
+        mounted.iter().any(|mounted| mounted.path == path)
+    }
+
+    /// The mounted archive `path` is or is in, with the `/`-separated path inside it
+    fn find(&self, path: &Path) -> Option<(Arc<Archive>, String)> {
+        let mounted = self.mounted.lock().unwrap_or_else(PoisonError::into_inner);
+        mounted.iter().find_map(|archive| {
+            let inner = path.strip_prefix(&archive.path).ok()?;
+            let inner: Vec<String> = inner.iter().map(|name| name.to_string_lossy().into_owned()).collect();
+            Some((archive.clone(), inner.join("/")))
+        })
+    }
+}
+
+impl Vfs for ArchiveFs {
+    fn read_dir(&self, dir: &Path) -> io::Result<Vec<DirEntry>> {
+        if let Some((archive, inner)) = self.find(dir) {
+            return archive.read_dir(&inner).ok_or_else(|| not_found(&dir.to_string_lossy()));
+        }
+        let mut entries = LocalFs.read_dir(dir)?;
+        for entry in &mut entries {
+            entry.is_dir = entry.is_dir || self.is_mounted(&dir.join(&entry.name));
+        }
+        Ok(entries)
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        match self.find(path) {
+            Some((archive, inner)) => archive.read(&inner),
+            None => LocalFs.read(path),
+        }
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        match self.find(path) {
+            Some(_) => Err(io::Error::new(io::ErrorKind::PermissionDenied, "files in archives are read-only")),
+            None => LocalFs.write(path, contents),
+        }
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        match self.find(path) {
+            Some((archive, inner)) => archive.metadata(&inner),
+            None => LocalFs.metadata(path),
+        }
+    }
+
+    fn is_read_only(&self, path: &Path) -> bool {
+        self.find(path).is_some()
+    }
+}
+
+/// Little-endian number of `width` bytes at `at`
+fn le(bytes: &[u8], at: usize, width: usize) -> io::Result<u64> {
+    let bytes = bytes.get(at..at + width).ok_or_else(|| invalid("damaged zip file"))?;
+    Ok(bytes.iter().rev().fold(0, |value, &byte| value << 8 | byte as u64))
+}
+
+/// The next `len` bytes of `reader`, failing if it ends before them
+fn read_exact_len(reader: &mut impl Read, len: u64) -> io::Result<Vec<u8>> {
+    let mut contents = Vec::with_capacity(len as usize);
+    reader.take(len).read_to_end(&mut contents)?;
+    if contents.len() as u64 != len {
+        return Err(size_mismatch());
+    }
+    Ok(contents)
+}
+
+fn size_mismatch() -> io::Error {
+    invalid("archive entry doesn't match its declared size")
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn not_found(path: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, format!("{} is not in the archive", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    use flate2::write::{DeflateEncoder, GzEncoder};
+    use flate2::Compression;
+
+    use crate::Walker;
+
+    /// A zip of deflated files, without checksums as nothing here checks them
+    fn zip(files: &[(&str, &str)]) -> Vec<u8> {
+        let (mut bytes, mut directory) = (Vec::new(), Vec::new());
+        for (name, text) in files {
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(text.as_bytes()).unwrap();
+            let data = encoder.finish().unwrap();
+            let sizes = [(data.len() as u32).to_le_bytes(), (text.len() as u32).to_le_bytes()].concat();
+            let name_len = (name.len() as u16).to_le_bytes();
+
+            directory.extend([b"PK\x01\x02".as_slice(), &[0; 6], &8u16.to_le_bytes(), &[0; 8], &sizes, &name_len, &[0; 12]].concat());
+            directory.extend((bytes.len() as u32).to_le_bytes());
+            directory.extend(name.as_bytes());
+            bytes.extend([b"PK\x03\x04".as_slice(), &[0; 4], &8u16.to_le_bytes(), &[0; 8], &sizes, &name_len, &[0; 2]].concat());
+            bytes.extend(name.as_bytes());
+            bytes.extend(data);
+        }
+        let (count, size, offset) = ((files.len() as u16).to_le_bytes(), (directory.len() as u32).to_le_bytes(), (bytes.len() as u32).to_le_bytes());
+        bytes.extend(&directory);
+        bytes.extend([b"PK\x05\x06".as_slice(), &[0; 4], &count, &count, &size, &offset, &[0; 2]].concat());
+        bytes
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("mikocore-archive-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn reads_zip_files() {
+        let dir = temp_dir("zip");
+        let path = dir.join("site.zip");
+        std::fs::write(&path, zip(&[("docs/index.md", "# Hello\n"), ("../escape.txt", "no"), ("app.js", "run()\n")])).unwrap();
+
+        let archive = Archive::open(&path).unwrap();
+        let names: Vec<String> = archive.read_dir("").unwrap().into_iter().map(|entry| entry.name).collect();
+        assert_eq!(names, vec!["app.js", "docs"]);
+        assert_eq!(archive.read("docs/index.md").unwrap(), b"# Hello\n");
+        assert!(archive.metadata("docs").unwrap().is_dir);
+        assert!(archive.read_dir("app.js").is_none());
+        assert!(archive.read("docs").is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Overwrite a field, given as little-endian bytes, at `at`
+    fn patch(bytes: &mut [u8], at: usize, value: &[u8]) {
+        bytes[at..at + value.len()].copy_from_slice(value);
+    }
+
+    #[test]
+    fn refuses_damaged_and_oversized_zips() {
+        let dir = temp_dir("malformed");
+        let path = dir.join("bad.zip");
+        let good = zip(&[("a.txt", "hello, world\n")]);
+        let directory = good.windows(4).position(|window| window == b"PK\x01\x02").unwrap();
+        let eocd = good.len() - 22;
+        let open = |bytes: &[u8]| {
+            std::fs::write(&path, bytes).unwrap();
+            Archive::open(&path)
+        };
+        let error = |result: io::Result<Vec<u8>>| result.unwrap_err().to_string();
+
+        // A comment after the end record, holding a signature of its own
+        let mut commented = good.clone();
+        let comment = b"see PK\x05\x06 and more";
+        patch(&mut commented, eocd + 20, &(comment.len() as u16).to_le_bytes());
+        commented.extend(comment);
+        assert_eq!(open(&commented).unwrap().read("a.txt").unwrap(), b"hello, world\n");
+
+        // A directory past the end of the file, and a file whose header is past it or elsewhere
+        let mut bytes = good.clone();
+        patch(&mut bytes, eocd + 16, &(good.len() as u32).to_le_bytes());
+        assert!(open(&bytes).is_err());
+        let mut bytes = good.clone();
+        patch(&mut bytes, directory + 42, &(good.len() as u32 * 2).to_le_bytes());
+        assert!(open(&bytes).unwrap().read("a.txt").is_err());
+        let mut bytes = good.clone();
+        patch(&mut bytes, directory + 42, &(directory as u32).to_le_bytes());
+        assert_eq!(error(open(&bytes).unwrap().read("a.txt")), "damaged zip entry");
+        let mut bytes = good.clone();
+        patch(&mut bytes, directory + 20, &u32::MAX.to_le_bytes());
+        assert_eq!(error(open(&bytes).unwrap().read("a.txt")), "damaged zip entry");
+
+        // Sizes that don't match the data, or that are too big to decode at all
+        for len in [5u32, 20] {
+            let mut bytes = good.clone();
+            patch(&mut bytes, directory + 24, &len.to_le_bytes());
+            assert!(error(open(&bytes).unwrap().read("a.txt")).contains("declared size"));
+        }
+        let mut bytes = good.clone();
+        patch(&mut bytes, directory + 24, &(MAX_ENTRY_LEN as u32 + 1).to_le_bytes());
+        assert!(error(open(&bytes).unwrap().read("a.txt")).contains("more than the"));
+
+        // Zip64 keeps its real numbers elsewhere
+        let mut bytes = good.clone();
+        patch(&mut bytes, eocd + 16, &u32::MAX.to_le_bytes());
+        assert_eq!(open(&bytes).unwrap_err().to_string(), "zip64 archives aren't supported");
+        assert!(open(b"PK\x05\x06 too short").is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn tar_gz_reads_reuse_what_was_decoded() {
+        let dir = temp_dir("targz");
+        let path = dir.join("files.tgz");
+        let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+        for (name, text) in [("a.txt", "first\n"), ("b.txt", "second\n"), ("c.txt", "third\n"), ("d.txt", "fourth\n")] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(text.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, name, text.as_bytes()).unwrap();
+        }
+        std::fs::write(&path, builder.into_inner().unwrap().finish().unwrap()).unwrap();
+
+        let archive = Archive::open(&path).unwrap();
+        assert_eq!(archive.read("c.txt").unwrap(), b"third\n");
+        assert_eq!(archive.tar_gz.lock().unwrap().files.len(), 2, "a and b were kept on the way to c");
+        assert_eq!(archive.read("a.txt").unwrap(), b"first\n");
+        assert_eq!(archive.read("b.txt").unwrap(), b"second\n");
+        // d comes after c, so the decoder goes on from there
+        let resumed_at = archive.tar_gz.lock().unwrap().stream.as_ref().map(|(position, _)| *position);
+        assert!(resumed_at.is_some());
+        assert_eq!(archive.read("d.txt").unwrap(), b"fourth\n");
+        assert_eq!(archive.read("c.txt").unwrap(), b"third\n");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn mounts_tarballs_as_read_only_folders() {
+        let dir = temp_dir("tar");
+        let path = dir.join("src.tar.gz");
+        let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+        for (name, text) in [("pkg/lib.rs", "pub fn lib() {}\n"), ("pkg/main.rs", "fn main() {}\n")] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(text.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, name, text.as_bytes()).unwrap();
+        }
+        std::fs::write(&path, builder.into_inner().unwrap().finish().unwrap()).unwrap();
+
+        let vfs = Arc::new(ArchiveFs::default());
+        assert!(!vfs.is_dir(&path));
+        vfs.mount(&path).unwrap();
+        assert!(!LocalFs.read_dir(&dir).unwrap()[0].is_dir && vfs.read_dir(&dir).unwrap()[0].is_dir);
+
+        let walker = Walker::new(&dir).vfs(vfs.clone());
+        let paths: Vec<PathBuf> = walker.iter().map(|entry| entry.path).collect();
+        assert_eq!(paths, vec![path.clone(), path.join("pkg"), path.join("pkg/lib.rs"), path.join("pkg/main.rs")]);
+        assert_eq!(vfs.read(&path.join("pkg/main.rs")).unwrap(), b"fn main() {}\n");
+        assert!(vfs.is_read_only(&path.join("pkg/main.rs")) && !vfs.is_read_only(&dir));
+        assert!(vfs.write(&path.join("pkg/main.rs"), b"").is_err());
+
+        assert!(vfs.unmount(&path));
+        assert!(vfs.is_file(&path));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
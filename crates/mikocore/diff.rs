@@ -0,0 +1,97 @@
+/// How a line of a diff relates the old text to the new one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKind {
+    Equal,
+    Removed,
+    Added,
+}
+
+/// Shortest line diff turning `old` into `new`, in order
+/// Uses Myers' algorithm after trimming the common start and end, so the cost grows with the
+/// size of the change rather than the size of the files.
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<(DiffKind, &'a str)> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    let prefix = a.iter().zip(&b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..].iter().rev().zip(b[prefix..].iter().rev()).take_while(|(x, y)| x == y).count();
+
+    let mut lines: Vec<(DiffKind, &str)> = a[..prefix].iter().map(|line| (DiffKind::Equal, *line)).collect();
+    lines.extend(myers(&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]));
+    lines.extend(a[a.len() - suffix..].iter().map(|line| (DiffKind::Equal, *line)));
+    lines
+}
+
+fn myers<'a>(a: &[&'a str], b: &[&'a str]) -> Vec<(DiffKind, &'a str)> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = n + m;
+    let offset = max + 1;
+    // Furthest x reached on each diagonal k = x - y, indexed by k + offset
+    let mut v = vec![0isize; 2 * offset as usize + 1];
+    // v before each round d, kept for diagonals -d..=d only
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    'search: for d in 0..=max {
+        trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let down = k == -d || (k != d && v[(k - 1 + offset) as usize] < v[(k + 1 + offset) as usize]);
+            let mut x = if down { v[(k + 1 + offset) as usize] } else { v[(k - 1 + offset) as usize] + 1 };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[(k + offset) as usize] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let (prev_x, prev_y) = if d == 0 {
+            (0, 0)
+        } else {
+            let at = |k: isize| v[(k + d) as usize];
+            let k = x - y;
+            let prev_k = if k == -d || (k != d && at(k - 1) < at(k + 1)) { k + 1 } else { k - 1 };
+            (at(prev_k), at(prev_k) - prev_k)
+        };
+        while x > prev_x && y > prev_y {
+            lines.push((DiffKind::Equal, a[x as usize - 1]));
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            if x == prev_x {
+                lines.push((DiffKind::Added, b[y as usize - 1]));
+            } else {
+                lines.push((DiffKind::Removed, a[x as usize - 1]));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    lines.reverse();
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diffs_changed_lines() {
+        use DiffKind::*;
+        let old = "a\nb\nc\nd\n";
+        let new = "a\nc\nx\nd\ne\n";
+        assert_eq!(
+            diff_lines(old, new),
+            vec![(Equal, "a"), (Removed, "b"), (Equal, "c"), (Added, "x"), (Equal, "d"), (Added, "e")]
+        );
+        assert_eq!(diff_lines("", "a"), vec![(Added, "a")]);
+        assert_eq!(diff_lines("a\nb", ""), vec![(Removed, "a"), (Removed, "b")]);
+    }
+}
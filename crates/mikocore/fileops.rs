@@ -0,0 +1,268 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::trash::{move_path, move_to_trash, TrashedItem};
+
+/// Operations kept for undo
+const MAX_HISTORY: usize = 100;
+
+/// A change to the workspace files that `FileOperations::undo` can reverse
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileOperation {
+    /// Renames or moves as `(from, to)` pairs, e.g. several rows dragged into a folder
+    Move(Vec<(PathBuf, PathBuf)>),
+    /// Sent to the trash together, e.g. a multi-select delete
+    Trash(Vec<TrashedItem>),
+    /// File contents rewritten together, e.g. Replace in Files
+    Edit(Vec<FileEdit>),
+}
+
+/// New contents for a file, with the old ones kept for undo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEdit {
+    pub path: PathBuf,
+    pub before: String,
+    pub after: String,
+}
+
+/// Renames, moves and deletes made from the UI, with an undo history
+#[derive(Debug, Default)]
+pub struct FileOperations {
+    history: Vec<FileOperation>,
+}
+
+impl FileOperations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rename or move `from`; never replaces an existing file
+    pub fn rename(&mut self, from: &Path, to: &Path) -> io::Result<()> {
+        if fs::symlink_metadata(to).is_ok() {
+            return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", to.display())));
+        }
+        move_path(from, to)?;
+        self.push(FileOperation::Move(vec![(from.to_path_buf(), to.to_path_buf())]));
+        Ok(())
+    }
+
+    /// Move paths into `folder`, keeping their names; a path inside another moved folder goes with it
+    /// Returns the paths that couldn't be moved. The rest is undone as one operation.
+    pub fn move_into(&mut self, paths: &[PathBuf], folder: &Path) -> Vec<(PathBuf, io::Error)> {
+        let mut moved = Vec::new();
+        let mut failed = Vec::new();
+        for path in outermost(paths) {
+            let Some(name) = path.file_name() else {
+                continue;
+            };
+            let to = folder.join(name);
+            if to == *path {
+                continue;
+            }
+            let result = if fs::symlink_metadata(&to).is_ok() {
+                Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", to.display())))
+            } else {
+                move_path(path, &to)
+            };
+            match result {
+                Ok(()) => moved.push((path.clone(), to)),
+                Err(e) => failed.push((path.clone(), e)),
+            }
+        }
+        if !moved.is_empty() {
+            self.push(FileOperation::Move(moved));
+        }
+        failed
+    }
+
+    /// Move paths to the trash; a path inside another selected folder goes with it
+    /// Returns the paths that couldn't be trashed. The rest is undone as one operation.
+    pub fn trash(&mut self, paths: &[PathBuf]) -> Vec<(PathBuf, io::Error)> {
+        let mut trashed = Vec::new();
+        let mut failed = Vec::new();
+        for path in outermost(paths) {
+            match move_to_trash(path) {
+                Ok(item) => trashed.push(item),
+                Err(e) => failed.push((path.clone(), e)),
+            }
+        }
+        if !trashed.is_empty() {
+            self.push(FileOperation::Trash(trashed));
+        }
+        failed
+    }
+
+    /// Rewrite several files at once: either all of them change or none do
+    /// Fails without writing anything when a file no longer holds `before`, e.g. it changed since it was read.
+    pub fn edit(&mut self, edits: Vec<FileEdit>) -> io::Result<()> {
+        let pairs: Vec<(&Path, &str, &str)> = edits.iter().map(|edit| (edit.path.as_path(), edit.before.as_str(), edit.after.as_str())).collect();
+        write_all(&pairs)?;
+        self.push(FileOperation::Edit(edits));
+        Ok(())
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.history.is_empty()
+    }
+
+    /// Reverse the latest operation and return it
+    /// Moves that can't be reversed stay in the history to retry; trashed items are put back as far as possible.
+    pub fn undo(&mut self) -> io::Result<FileOperation> {
+        let operation = self.history.pop().ok_or_else(|| io::Error::other("nothing to undo"))?;
+        match &operation {
+            FileOperation::Move(moves) => {
+                // Newest first, the reverse of how they were made
+                let mut failed = Vec::new();
+                let mut first_error = None;
+                for (from, to) in moves.iter().rev() {
+                    let result = if fs::symlink_metadata(from).is_ok() {
+                        Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", from.display())))
+                    } else {
+                        move_path(to, from)
+                    };
+                    if let Err(e) = result {
+                        failed.insert(0, (from.clone(), to.clone()));
+                        first_error.get_or_insert(e);
+                    }
+                }
+                if let Some(e) = first_error {
+                    self.history.push(FileOperation::Move(failed));
+                    return Err(e);
+                }
+            }
+            FileOperation::Trash(items) => {
+                if !items.iter().any(TrashedItem::can_restore) {
+                    return Err(io::Error::new(io::ErrorKind::Unsupported, "restore the files from the recycle bin"));
+                }
+                let mut first_error = None;
+                for item in items.iter().rev().filter(|item| item.can_restore()) {
+                    if let Err(e) = item.restore() {
+                        first_error.get_or_insert(e);
+                    }
+                }
+                if let Some(e) = first_error {
+                    return Err(e);
+                }
+            }
+            FileOperation::Edit(edits) => {
+                let pairs: Vec<(&Path, &str, &str)> = edits.iter().map(|edit| (edit.path.as_path(), edit.after.as_str(), edit.before.as_str())).collect();
+                if let Err(e) = write_all(&pairs) {
+                    // Files edited since can be put back by hand, then undone again
+                    self.history.push(operation);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(operation)
+    }
+
+    fn push(&mut self, operation: FileOperation) {
+        if self.history.len() == MAX_HISTORY {
+            self.history.remove(0);
+        }
+        self.history.push(operation);
+    }
+}
+
+/// Replace the contents of each `(path, expected, new)` file, all or nothing
+/// New contents go to temporary files next to the originals first, which are then renamed over them.
+fn write_all(files: &[(&Path, &str, &str)]) -> io::Result<()> {
+    for (path, expected, _) in files {
+        if fs::read_to_string(path)? != *expected {
+            return Err(io::Error::other(format!("{} changed since it was read", path.display())));
+        }
+    }
+    let mut temps = Vec::new();
+    let mut written = Ok(());
+    for (path, _, new) in files {
+        let temp = temp_path(path);
+        // Keep the file's permissions, e.g. an executable script stays executable
+        written = fs::write(&temp, new).and_then(|()| fs::set_permissions(&temp, fs::metadata(path)?.permissions()));
+        temps.push(temp);
+        if written.is_err() {
+            break;
+        }
+    }
+    if let Err(e) = written {
+        for temp in &temps {
+            let _ = fs::remove_file(temp);
+        }
+        return Err(e);
+    }
+    for (i, ((path, expected, _), temp)) in files.iter().zip(&temps).enumerate() {
+        if let Err(e) = fs::rename(temp, path) {
+            // Put back the files already replaced and drop the rest
+            for (path, expected, _) in &files[..i] {
+                let _ = fs::write(path, expected);
+            }
+            let _ = fs::write(path, expected);
+            for temp in &temps[i..] {
+                let _ = fs::remove_file(temp);
+            }
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+fn temp_path(path: &Path) -> PathBuf {
+    let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+    path.with_file_name(format!(".{}.rabital-tmp", name))
+}
+
+/// Drop paths inside another listed folder, which go along with it
+fn outermost(paths: &[PathBuf]) -> impl Iterator<Item = &PathBuf> {
+    paths.iter().filter(|path| !paths.iter().any(|other| other != *path && path.starts_with(other)))
+}
+
+#[cfg(all(test, unix, not(target_os = "macos")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rename_and_trash_undo() {
+        let root = std::env::temp_dir().join(format!("mikocore-fileops-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("ws/src")).unwrap();
+        fs::write(root.join("ws/src/a.txt"), "a").unwrap();
+        fs::write(root.join("ws/b.txt"), "b").unwrap();
+        std::env::set_var("XDG_DATA_HOME", root.join("data"));
+
+        let mut operations = FileOperations::new();
+        operations.rename(&root.join("ws/b.txt"), &root.join("ws/c.txt")).unwrap();
+        assert!(operations.rename(&root.join("ws/c.txt"), &root.join("ws/src")).is_err());
+
+        // The file inside the trashed folder isn't trashed separately
+        let failed = operations.trash(&[root.join("ws/src"), root.join("ws/src/a.txt"), root.join("ws/c.txt")]);
+        assert!(failed.is_empty());
+        assert!(!root.join("ws/src").exists());
+        let info = fs::read_to_string(root.join("data/Trash/info/src.trashinfo")).unwrap();
+        assert!(info.contains(&format!("Path={}", root.join("ws/src").display())));
+
+        operations.undo().unwrap();
+        assert_eq!(fs::read_to_string(root.join("ws/src/a.txt")).unwrap(), "a");
+        assert!(root.join("ws/c.txt").exists());
+        assert!(!root.join("data/Trash/info/src.trashinfo").exists());
+
+        operations.undo().unwrap();
+        assert!(root.join("ws/b.txt").exists());
+        assert!(!operations.can_undo());
+
+        let failed = operations.move_into(&[root.join("ws/b.txt"), root.join("ws/src/a.txt")], &root.join("ws/src"));
+        assert!(failed.is_empty());
+        assert!(root.join("ws/src/b.txt").exists());
+        operations.undo().unwrap();
+        assert!(root.join("ws/b.txt").exists() && root.join("ws/src/a.txt").exists());
+
+        let edit = |path: &str, before: &str, after: &str| FileEdit { path: root.join(path), before: before.into(), after: after.into() };
+        // A stale `before` leaves every file alone
+        assert!(operations.edit(vec![edit("ws/b.txt", "b", "B"), edit("ws/src/a.txt", "x", "A")]).is_err());
+        assert_eq!(fs::read_to_string(root.join("ws/b.txt")).unwrap(), "b");
+        operations.edit(vec![edit("ws/b.txt", "b", "B"), edit("ws/src/a.txt", "a", "A")]).unwrap();
+        assert_eq!(fs::read_to_string(root.join("ws/src/a.txt")).unwrap(), "A");
+        operations.undo().unwrap();
+        assert_eq!(fs::read_to_string(root.join("ws/b.txt")).unwrap(), "b");
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
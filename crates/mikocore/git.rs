@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+/// Who last changed a line of a file, as `git blame` tells
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlameLine {
+    /// Full hash; all zeros for changes not committed yet
+    pub commit: String,
+    pub author: String,
+    /// Author date, in seconds since the Unix epoch
+    pub time: i64,
+    /// First line of the commit message
+    pub summary: String,
+}
+
+impl BlameLine {
+    pub fn is_committed(&self) -> bool {
+        !self.commit.bytes().all(|b| b == b'0')
+    }
+}
+
+/// A commit that changed a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileCommit {
+    pub commit: String,
+    pub author: String,
+    /// Author date, in seconds since the Unix epoch
+    pub time: i64,
+    pub summary: String,
+    /// Path of the file in that commit from the repository root; older commits keep names from before renames
+    pub path: String,
+}
+
+/// Branch checked out in a repository and how it compares with its upstream
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BranchStatus {
+    /// Branch name, or the short hash of the commit checked out without one
+    pub head: String,
+    /// Branch the checked out one pulls from and pushes to, e.g. `origin/main`
+    pub upstream: Option<String>,
+    /// Commits not pushed to the upstream yet
+    pub ahead: usize,
+    /// Commits of the upstream not pulled yet
+    pub behind: usize,
+}
+
+/// Who last changed each line of the file as it is on disk, in line order
+pub fn blame(path: &Path) -> io::Result<Vec<BlameLine>> {
+    let output = git_in_parent(path, &["blame", "--porcelain", "--"])?;
+    Ok(parse_blame(&output))
+}
+
+/// Commits that changed a file, newest first, following it through renames
+pub fn file_history(path: &Path) -> io::Result<Vec<FileCommit>> {
+    let output = git_in_parent(path, &["log", "--follow", "--name-only", "--format=%x1e%H%x1f%an%x1f%at%x1f%s", "--"])?;
+    Ok(parse_history(&output))
+}
+
+/// Text of a file in a commit; `path` is from the repository root, as `FileCommit::path`
+///
+/// `repo_file` is any file in the repository, to tell git where to look.
+pub fn show_file(repo_file: &Path, commit: &str, path: &str) -> io::Result<String> {
+    let dir = repo_file.parent().unwrap_or(Path::new("."));
+    git(dir, &["show", &format!("{}:{}", commit, path)])
+}
+
+/// Branch of the repository `dir` is in; fails outside a repository
+pub fn branch_status(dir: &Path) -> io::Result<BranchStatus> {
+    let output = git(dir, &["status", "--porcelain=v2", "--branch", "--untracked-files=no"])?;
+    Ok(parse_branch_status(&output))
+}
+
+/// Local branches of the repository `dir` is in, most recently committed to first
+pub fn branches(dir: &Path) -> io::Result<Vec<String>> {
+    let output = git(dir, &["for-each-ref", "--sort=-committerdate", "--format=%(refname:short)", "refs/heads"])?;
+    Ok(output.lines().map(str::to_string).collect())
+}
+
+/// Run git in `dir` and return what it printed; fails with its error message when it does
+pub fn git(dir: &Path, args: &[&str]) -> io::Result<String> {
+    let output = Command::new("git").args(args).current_dir(dir).output()?;
+    if !output.status.success() {
+        let message = String::from_utf8_lossy(&output.stderr);
+        return Err(io::Error::other(message.trim().to_string()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Run git in the folder of `path` with `args`, followed by the file's name
+fn git_in_parent(path: &Path, args: &[&str]) -> io::Result<String> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let name = path.file_name().ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "not a file"))?;
+    let mut args: Vec<&str> = args.to_vec();
+    let name = name.to_string_lossy();
+    args.push(&name);
+    git(dir, &args)
+}
+
+/// Branch headers of `git status --porcelain=v2 --branch`
+fn parse_branch_status(output: &str) -> BranchStatus {
+    let mut status = BranchStatus::default();
+    let mut commit = "";
+    for header in output.lines().filter_map(|line| line.strip_prefix("# branch.")) {
+        let (name, value) = header.split_once(' ').unwrap_or((header, ""));
+        match name {
+            "oid" => commit = value,
+            "head" => status.head = value.to_string(),
+            "upstream" => status.upstream = Some(value.to_string()),
+            "ab" => {
+                for count in value.split(' ') {
+                    if let Some(ahead) = count.strip_prefix('+') {
+                        status.ahead = ahead.parse().unwrap_or(0);
+                    } else if let Some(behind) = count.strip_prefix('-') {
+                        status.behind = behind.parse().unwrap_or(0);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    if status.head == "(detached)" {
+        status.head = commit.get(..7).unwrap_or(commit).to_string();
+    }
+    status
+}
+
+/// Lines of `git blame --porcelain` output
+///
+/// A commit's author and summary follow its hash only the first time it shows up.
+fn parse_blame(output: &str) -> Vec<BlameLine> {
+    let mut commits: HashMap<String, BlameLine> = HashMap::new();
+    let mut lines: Vec<(usize, BlameLine)> = Vec::new();
+    let mut current: Option<(usize, BlameLine)> = None;
+    for row in output.lines() {
+        if row.starts_with('\t') {
+            // The line's text ends its entry
+            if let Some((line, entry)) = current.take() {
+                commits.insert(entry.commit.clone(), entry.clone());
+                lines.push((line, entry));
+            }
+        } else if let Some((_, entry)) = current.as_mut() {
+            if let Some(author) = row.strip_prefix("author ") {
+                entry.author = author.to_string();
+            } else if let Some(time) = row.strip_prefix("author-time ") {
+                entry.time = time.parse().unwrap_or(0);
+            } else if let Some(summary) = row.strip_prefix("summary ") {
+                entry.summary = summary.to_string();
+            }
+        } else {
+            let mut fields = row.split(' ');
+            let commit = fields.next().unwrap_or_default();
+            let line = fields.nth(1).and_then(|line| line.parse::<usize>().ok()).unwrap_or(1);
+            let entry = commits.get(commit).cloned().unwrap_or_else(|| BlameLine {
+                commit: commit.to_string(),
+                author: String::new(),
+                time: 0,
+                summary: String::new(),
+            });
+            current = Some((line.saturating_sub(1), entry));
+        }
+    }
+    lines.sort_by_key(|(line, _)| *line);
+    lines.into_iter().map(|(_, entry)| entry).collect()
+}
+
+/// Commits of `git log --name-only` with each record starting at \x1e and fields split by \x1f
+fn parse_history(output: &str) -> Vec<FileCommit> {
+    output
+        .split('\x1e')
+        .filter_map(|record| {
+            let mut rows = record.lines();
+            let mut fields = rows.next()?.split('\x1f');
+            let commit = fields.next()?.to_string();
+            let author = fields.next()?.to_string();
+            let time = fields.next()?.parse().unwrap_or(0);
+            let summary = fields.next().unwrap_or_default().to_string();
+            let path = rows.find(|row| !row.is_empty())?.to_string();
+            Some(FileCommit { commit, author, time, summary, path })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_porcelain_blame() {
+        let output = "\
+aaaa 1 1 2
+author Ada
+author-mail <ada@example.com>
+author-time 1700000000
+summary First commit
+filename lib.rs
+\tfn main() {
+aaaa 2 2
+\t}
+0000 3 3 1
+author Not Committed Yet
+author-time 1800000000
+summary Version of lib.rs from lib.rs
+\t
+";
+        let lines = parse_blame(output);
+        assert_eq!(lines.len(), 3);
+        assert_eq!((lines[1].author.as_str(), lines[1].time, lines[1].summary.as_str()), ("Ada", 1700000000, "First commit"));
+        assert!(lines[0].is_committed());
+        assert!(!lines[2].is_committed());
+    }
+
+    #[test]
+    fn parses_branch_headers() {
+        let output = "# branch.oid 1234567890abcdef\n# branch.head main\n# branch.upstream origin/main\n# branch.ab +2 -3\n1 .M N... 100644 100644 100644 aaaa bbbb lib.rs\n";
+        let status = parse_branch_status(output);
+        assert_eq!(status, BranchStatus { head: "main".to_string(), upstream: Some("origin/main".to_string()), ahead: 2, behind: 3 });
+
+        let detached = parse_branch_status("# branch.oid 1234567890abcdef\n# branch.head (detached)\n");
+        assert_eq!((detached.head.as_str(), detached.upstream), ("1234567", None));
+    }
+
+    #[test]
+    fn parses_history_through_renames() {
+        let output = "\x1ebbbb\x1fAda\x1f1800000000\x1fRename\n\nsrc/new.rs\n\x1eaaaa\x1fBob\x1f1700000000\x1fAdd old.rs\n\nold.rs\n";
+        let commits = parse_history(output);
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].path, "src/new.rs");
+        assert_eq!((commits[1].author.as_str(), commits[1].path.as_str()), ("Bob", "old.rs"));
+    }
+}
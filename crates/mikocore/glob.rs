@@ -0,0 +1,69 @@
+/// Match a `/`-separated path against a glob
+///
+/// `*` matches within one path segment, `**` across segments (`**/` also matches no folder at all),
+/// `?` matches one char, `[abc]`, `[a-z]` and `[!abc]` match a char class, and `\` escapes the next char.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let path: Vec<char> = path.chars().collect();
+    matches(&pattern, &path)
+}
+
+fn matches(pattern: &[char], path: &[char]) -> bool {
+    match pattern.first() {
+        None => path.is_empty(),
+        Some('*') if pattern.get(1) == Some(&'*') => {
+            let rest = &pattern[2..];
+            if let Some(after_slash) = rest.strip_prefix(&['/']) {
+                if matches(after_slash, path) {
+                    return true;
+                }
+            }
+            (0..=path.len()).any(|skip| matches(rest, &path[skip..]))
+        }
+        Some('*') => {
+            let rest = &pattern[1..];
+            for skip in 0..=path.len() {
+                if matches(rest, &path[skip..]) {
+                    return true;
+                }
+                if path.get(skip) == Some(&'/') {
+                    break;
+                }
+            }
+            false
+        }
+        Some('?') => path.first().is_some_and(|&c| c != '/') && matches(&pattern[1..], &path[1..]),
+        Some('[') => match (class_match(&pattern[1..], path.first().copied()), path.first()) {
+            (Some((true, len)), Some(_)) => matches(&pattern[1 + len..], &path[1..]),
+            (Some(_), _) => false,
+            // No closing bracket: a literal `[`
+            (None, Some('[')) => matches(&pattern[1..], &path[1..]),
+            (None, _) => false,
+        },
+        Some('\\') if pattern.len() > 1 => path.first() == Some(&pattern[1]) && matches(&pattern[2..], &path[1..]),
+        Some(&c) => path.first() == Some(&c) && matches(&pattern[1..], &path[1..]),
+    }
+}
+
+/// Whether `c` is in the class starting right after `[`, and how many pattern chars the class spans
+fn class_match(class: &[char], c: Option<char>) -> Option<(bool, usize)> {
+    let negated = matches!(class.first(), Some('!' | '^'));
+    let start = usize::from(negated);
+    // A `]` right after the opening bracket is part of the class
+    let end = start + 1 + class.get(start + 1..)?.iter().position(|&ch| ch == ']')?;
+    let members = &class[start..end];
+
+    let mut found = false;
+    let mut i = 0;
+    while i < members.len() {
+        if i + 2 < members.len() && members[i + 1] == '-' {
+            found |= c.is_some_and(|c| (members[i]..=members[i + 2]).contains(&c));
+            i += 3;
+        } else {
+            found |= c == Some(members[i]);
+            i += 1;
+        }
+    }
+    let found = c.is_some_and(|c| c != '/') && found != negated;
+    Some((found, end + 1))
+}
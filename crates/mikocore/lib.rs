@@ -1,6 +1,33 @@
 // MikoCore - Core functionality for Rabital
 // This crate will contain shared core functionality
 
+mod archive;
+mod diff;
+mod fileops;
+mod git;
+mod glob;
+mod remote;
+mod search;
+mod sftp;
+mod trash;
+mod unicode;
+mod vfs;
+mod walker;
+mod watcher;
+
+pub use archive::{is_archive, Archive, ArchiveFs};
+pub use diff::{diff_lines, DiffKind};
+pub use fileops::{FileEdit, FileOperation, FileOperations};
+pub use git::{blame, branch_status, branches, file_history, git, show_file, BlameLine, BranchStatus, FileCommit};
+pub use glob::glob_match;
+pub use remote::{list_dir, read_file, resolve_dir, write_file, RemoteMount, SshHost};
+pub use search::{find_matches, replace_ranges, search_files, FileMatches, SearchQuery, TextMatch};
+pub use trash::{move_to_trash, TrashedItem};
+pub use unicode::{char_category, char_name, code_point_label, named_chars, parse_code_point};
+pub use vfs::{DirEntry, FileMetadata, LocalFs, Stamp, Vfs};
+pub use walker::{Walk, WalkEntry, Walker};
+pub use watcher::FileWatcher;
+
 pub fn version() -> &'static str {
     env!("CARGO_PKG_VERSION")
 }
@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+
+use crate::sftp::SftpSession;
+use crate::vfs::DirEntry;
+
+/// Open SFTP sessions by host, kept for the requests that follow
+static SESSIONS: LazyLock<Mutex<HashMap<SshHost, Arc<Mutex<SftpSession>>>>> = LazyLock::new(Default::default);
+
+/// A machine reached with the system's OpenSSH client; key or agent auth only, as nothing can type a password
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SshHost {
+    pub user: Option<String>,
+    /// Host name, address or `Host` alias from ~/.ssh/config
+    pub host: String,
+    pub port: Option<u16>,
+    /// Private key to log in with instead of the ones ssh tries by itself
+    pub identity_file: Option<PathBuf>,
+}
+
+impl SshHost {
+    /// Parse `[user@]host[:port] [identity file]`, as hosts are saved and typed
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let spec = spec.trim();
+        let (address, identity) = match spec.split_once(char::is_whitespace) {
+            Some((address, identity)) => (address, Some(identity.trim())),
+            None => (spec, None),
+        };
+        let (user, host) = match address.rsplit_once('@') {
+            Some((user, host)) => (Some(user.to_string()).filter(|user| !user.is_empty()), host),
+            None => (None, address),
+        };
+        let (host, port) = match host.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port.parse::<u16>().map_err(|_| format!("{} is not a valid port", port))?)),
+            None => (host, None),
+        };
+        if host.is_empty() || host.starts_with('-') {
+            return Err(format!("{} names no host", spec));
+        }
+        if user.as_deref().is_some_and(|user| user.starts_with('-')) {
+            return Err(format!("{} is not a valid user", spec));
+        }
+        Ok(Self {
+            user,
+            host: host.to_string(),
+            port,
+            identity_file: identity.filter(|identity| !identity.is_empty()).map(PathBuf::from),
+        })
+    }
+
+    /// `user@host`, or just the host
+    pub fn destination(&self) -> String {
+        match self.user {
+            Some(ref user) => format!("{}@{}", user, self.host),
+            None => self.host.clone(),
+        }
+    }
+
+    /// Folder name for the host's files in a local cache
+    pub fn cache_name(&self) -> String {
+        let name = match self.port {
+            Some(port) => format!("{}_{}", self.destination(), port),
+            None => self.destination(),
+        };
+        name.chars().map(|c| if c.is_ascii_alphanumeric() || "._-@".contains(c) { c } else { '_' }).collect()
+    }
+
+    /// Options shared by every connection: no prompts, and one connection reused while it's busy
+    fn ssh_args(&self) -> Vec<String> {
+        let mut args: Vec<String> = ["-o", "BatchMode=yes", "-o", "ConnectTimeout=10"].map(String::from).to_vec();
+        // Windows' OpenSSH has no connection sharing; elsewhere it saves a handshake per request
+        #[cfg(not(windows))]
+        {
+            let control_path = std::env::temp_dir().join("rabital-ssh-%C");
+            args.extend(["-o", "ControlMaster=auto", "-o", "ControlPersist=60", "-o"].map(String::from));
+            args.push(format!("ControlPath={}", control_path.display()));
+        }
+        if let Some(port) = self.port {
+            args.extend(["-p".to_string(), port.to_string()]);
+        }
+        if let Some(ref identity) = self.identity_file {
+            args.extend(["-i".to_string(), identity.display().to_string(), "-o".to_string(), "IdentitiesOnly=yes".to_string()]);
+        }
+        // Ends the options, so a destination like `-oProxyCommand=...@host` can't be read as one
+        args.push("--".to_string());
+        args.push(self.destination());
+        args
+    }
+
+    /// Start the host's SFTP subsystem over ssh
+    fn sftp(&self) -> io::Result<SftpSession> {
+        let mut child = Command::new("ssh")
+            .arg("-s")
+            .args(self.ssh_args())
+            .arg("sftp")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            return Err(io::Error::other("ssh started without pipes"));
+        };
+        SftpSession::start(Box::new(stdout), Box::new(stdin), Some(child))
+    }
+
+    /// Run `request` on this host's session, connecting first if there is none
+    ///
+    /// A kept session may have been dropped by the server since; the request then runs again
+    /// on a new one.
+    fn with_session<T>(&self, mut request: impl FnMut(&mut SftpSession) -> io::Result<T>) -> io::Result<T> {
+        let kept = SESSIONS.lock().unwrap_or_else(PoisonError::into_inner).get(self).cloned();
+        if let Some(session) = kept {
+            let mut session = session.lock().unwrap_or_else(PoisonError::into_inner);
+            if !session.is_broken() {
+                let result = request(&mut session);
+                if !session.is_broken() {
+                    return result;
+                }
+            }
+        }
+        let session = Arc::new(Mutex::new(self.sftp()?));
+        SESSIONS.lock().unwrap_or_else(PoisonError::into_inner).insert(self.clone(), session.clone());
+        let mut session = session.lock().unwrap_or_else(PoisonError::into_inner);
+        request(&mut session)
+    }
+}
+
+impl fmt::Display for SshHost {
+    /// The spec `parse` reads back
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.destination())?;
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+        if let Some(ref identity) = self.identity_file {
+            write!(f, " {}", identity.display())?;
+        }
+        Ok(())
+    }
+}
+
+/// Absolute path of a remote folder; `~` and `~/...` start from the login's home
+pub fn resolve_dir(host: &SshHost, dir: &str) -> io::Result<String> {
+    // Sessions start in the home folder, so `~/...` is just a relative path
+    let dir = match dir.trim() {
+        "" | "~" => ".",
+        dir => dir.strip_prefix("~/").unwrap_or(dir),
+    };
+    host.with_session(|session| {
+        let path = session.realpath(dir)?;
+        if !session.stat(&path)?.is_dir() {
+            return Err(io::Error::new(io::ErrorKind::NotADirectory, format!("{} is not a folder", path)));
+        }
+        Ok(path)
+    })
+}
+
+/// Entries of a remote folder, folders first, then by name
+pub fn list_dir(host: &SshHost, dir: &str) -> io::Result<Vec<DirEntry>> {
+    let mut entries = host.with_session(|session| session.read_dir(dir))?;
+    sort_entries(&mut entries);
+    Ok(entries)
+}
+
+pub fn read_file(host: &SshHost, path: &str) -> io::Result<Vec<u8>> {
+    host.with_session(|session| session.read(path))
+}
+
+/// Replace a remote file's contents, keeping its permissions
+///
+/// The upload lands in a copy next to the file that is moved over it only once complete,
+/// so a connection dropped halfway leaves the original intact.
+pub fn write_file(host: &SshHost, path: &str, contents: &[u8]) -> io::Result<()> {
+    host.with_session(|session| session.write(path, contents))
+}
+
+/// A remote folder opened as the workspace, with its files kept under a local folder
+///
+/// Files are downloaded to the same relative path under `local_root` when opened and
+/// uploaded from there when saved, so the editor works on ordinary local files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteMount {
+    pub host: SshHost,
+    /// Absolute path on the host
+    pub remote_root: String,
+    pub local_root: PathBuf,
+}
+
+impl RemoteMount {
+    /// Mirror `remote_root` of `host` under `cache`, in a folder per host
+    pub fn new(host: SshHost, remote_root: String, cache: &Path) -> Self {
+        let mut local_root = cache.join(host.cache_name());
+        local_root.extend(remote_root.split('/').filter(|part| !part.is_empty()));
+        Self { host, remote_root, local_root }
+    }
+
+    /// Name of the opened folder, e.g. for the window title
+    pub fn name(&self) -> &str {
+        self.remote_root.rsplit('/').find(|part| !part.is_empty()).unwrap_or("/")
+    }
+
+    pub fn contains(&self, local: &Path) -> bool {
+        local.starts_with(&self.local_root)
+    }
+
+    /// Path on the host of a file or folder under `local_root`
+    pub fn remote_path(&self, local: &Path) -> Option<String> {
+        let relative = local.strip_prefix(&self.local_root).ok()?;
+        let mut path = self.remote_root.trim_end_matches('/').to_string();
+        for part in relative.components() {
+            path.push('/');
+            path.push_str(&part.as_os_str().to_string_lossy());
+        }
+        if path.is_empty() {
+            path.push('/');
+        }
+        Some(path)
+    }
+}
+
+fn sort_entries(entries: &mut [DirEntry]) {
+    entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase())));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_host_specs() {
+        let host = SshHost::parse("ada@build.example.com:2222 ~/.ssh/id_build").unwrap();
+        assert_eq!(host.user.as_deref(), Some("ada"));
+        assert_eq!((host.host.as_str(), host.port), ("build.example.com", Some(2222)));
+        assert_eq!(host.identity_file, Some(PathBuf::from("~/.ssh/id_build")));
+        assert_eq!(host.to_string(), "ada@build.example.com:2222 ~/.ssh/id_build");
+        assert_eq!(host.cache_name(), "ada@build.example.com_2222");
+
+        assert_eq!(SshHost::parse("devbox").unwrap().destination(), "devbox");
+        assert!(SshHost::parse("ada@host:ssh").is_err());
+        assert!(SshHost::parse("-oProxyCommand=x").is_err());
+        assert!(SshHost::parse("-oProxyCommand=x@devbox").is_err());
+        assert_eq!(SshHost::parse("devbox").unwrap().ssh_args().last().map(String::as_str), Some("devbox"));
+    }
+
+    #[test]
+    fn lists_folders_first() {
+        let entry = |name: &str, is_dir| DirEntry { name: name.to_string(), is_dir };
+        let mut entries = vec![entry("b.txt", false), entry("src", true), entry("A.md", false), entry(".git", true)];
+        sort_entries(&mut entries);
+        let names: Vec<&str> = entries.iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, vec![".git", "src", "A.md", "b.txt"]);
+        assert!(entries[1].is_dir && !entries[2].is_dir);
+    }
+
+    #[test]
+    fn maps_local_copies_to_remote_paths() {
+        let host = SshHost::parse("ada@devbox").unwrap();
+        let mount = RemoteMount::new(host, "/home/ada/app".to_string(), Path::new("/cache"));
+        assert_eq!(mount.local_root, Path::new("/cache/ada@devbox/home/ada/app"));
+        assert_eq!(mount.name(), "app");
+        let local = mount.local_root.join("src").join("main.rs");
+        assert!(mount.contains(&local));
+        assert_eq!(mount.remote_path(&local).as_deref(), Some("/home/ada/app/src/main.rs"));
+        assert_eq!(mount.remote_path(&mount.local_root).as_deref(), Some("/home/ada/app"));
+        assert_eq!(mount.remote_path(Path::new("/elsewhere")), None);
+
+        let root = RemoteMount::new(SshHost::parse("devbox").unwrap(), "/".to_string(), Path::new("/cache"));
+        assert_eq!(root.remote_path(&root.local_root.join("etc")).as_deref(), Some("/etc"));
+        assert_eq!(root.name(), "/");
+    }
+}
@@ -0,0 +1,181 @@
+use std::ops::Range;
+use std::path::PathBuf;
+
+use crate::walker::Walker;
+
+/// Files larger than this are skipped by workspace searches
+const MAX_FILE_SIZE: u64 = 4 * 1024 * 1024;
+
+/// Text to look for across the workspace
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    pub text: String,
+    pub match_case: bool,
+    /// Only matches not preceded or followed by a letter, digit or `_`
+    pub whole_word: bool,
+}
+
+impl SearchQuery {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn match_case(mut self, match_case: bool) -> Self {
+        self.match_case = match_case;
+        self
+    }
+
+    pub fn whole_word(mut self, whole_word: bool) -> Self {
+        self.whole_word = whole_word;
+        self
+    }
+
+    /// Byte ranges of the non-overlapping matches in `text`
+    pub fn find(&self, text: &str) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+        if self.text.is_empty() {
+            return ranges;
+        }
+        let mut start = 0;
+        while start < text.len() {
+            let end = if self.match_case {
+                text[start..].starts_with(&self.text).then(|| start + self.text.len())
+            } else {
+                match_ignoring_case(&text[start..], &self.text).map(|len| start + len)
+            };
+            match end {
+                Some(end) if !self.whole_word || is_word_boundary(text, start, end) => {
+                    ranges.push(start..end);
+                    start = end;
+                }
+                _ => start += text[start..].chars().next().map_or(1, char::len_utf8),
+            }
+        }
+        ranges
+    }
+}
+
+/// Length in `text` of a case-insensitive match of `pattern` at its start
+/// Compared char by char, since lowercasing can change byte lengths.
+fn match_ignoring_case(text: &str, pattern: &str) -> Option<usize> {
+    let mut chars = text.char_indices();
+    for expected in pattern.chars() {
+        let (_, c) = chars.next()?;
+        if c != expected && !c.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(text.len(), |(i, _)| i))
+}
+
+fn is_word_boundary(text: &str, start: usize, end: usize) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    !text[..start].chars().next_back().is_some_and(is_word) && !text[end..].chars().next().is_some_and(is_word)
+}
+
+/// One match, with the line it's on for showing a preview
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextMatch {
+    /// Bytes of the file
+    pub range: Range<usize>,
+    /// 0-based
+    pub line: usize,
+    /// 0-based, in chars
+    pub column: usize,
+    pub line_text: String,
+    /// Bytes of `line_text`
+    pub line_range: Range<usize>,
+}
+
+/// Matches in one file, in file order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileMatches {
+    pub path: PathBuf,
+    pub matches: Vec<TextMatch>,
+}
+
+/// Every match of `query` in `text`, with line and column
+pub fn find_matches(text: &str, query: &SearchQuery) -> Vec<TextMatch> {
+    let mut matches = Vec::new();
+    let mut line = 0;
+    let mut line_start = 0;
+    let mut counted = 0;
+    for range in query.find(text) {
+        let skipped = &text[counted..range.start];
+        line += skipped.matches('\n').count();
+        if let Some(i) = skipped.rfind('\n') {
+            line_start = counted + i + 1;
+        }
+        counted = range.start;
+        let line_end = text[range.start..].find('\n').map_or(text.len(), |i| range.start + i);
+        let line_text = text[line_start..line_end].trim_end_matches('\r');
+        let end = range.end.min(line_start + line_text.len()).max(range.start);
+        matches.push(TextMatch {
+            column: text[line_start..range.start].chars().count(),
+            line,
+            line_text: line_text.to_string(),
+            line_range: range.start - line_start..end - line_start,
+            range,
+        });
+    }
+    matches
+}
+
+/// Search the files `walker` lists, read from its file system, stopping after `limit` matches
+/// Binary files, files that aren't UTF-8 and very large files are skipped.
+pub fn search_files(walker: &Walker, query: &SearchQuery, limit: usize) -> Vec<FileMatches> {
+    let mut results = Vec::new();
+    let mut found = 0;
+    let vfs = walker.file_system();
+    for entry in walker.iter().filter(|entry| !entry.is_dir) {
+        if found >= limit {
+            break;
+        }
+        if !vfs.metadata(&entry.path).is_ok_and(|meta| meta.len <= MAX_FILE_SIZE) {
+            continue;
+        }
+        let Some(text) = vfs.read(&entry.path).ok().filter(|bytes| !bytes.contains(&0)).and_then(|bytes| String::from_utf8(bytes).ok()) else {
+            continue;
+        };
+        let mut matches = find_matches(&text, query);
+        matches.truncate(limit - found);
+        if !matches.is_empty() {
+            found += matches.len();
+            results.push(FileMatches { path: entry.path, matches });
+        }
+    }
+    results
+}
+
+/// `text` with the given byte ranges replaced; the ranges must be sorted and not overlap
+pub fn replace_ranges(text: &str, ranges: &[Range<usize>], replacement: &str) -> String {
+    let mut replaced = String::with_capacity(text.len());
+    let mut copied = 0;
+    for range in ranges {
+        replaced.push_str(&text[copied..range.start]);
+        replaced.push_str(replacement);
+        copied = range.end;
+    }
+    replaced.push_str(&text[copied..]);
+    replaced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_case_and_word_matches() {
+        let text = "let Foo = foo;\nfoobar(foo)\n";
+        assert_eq!(SearchQuery::new("foo").find(text).len(), 4);
+        assert_eq!(SearchQuery::new("foo").match_case(true).find(text).len(), 3);
+        assert_eq!(SearchQuery::new("foo").whole_word(true).find(text), vec![4..7, 10..13, 22..25]);
+
+        let matches = find_matches(text, &SearchQuery::new("foo").match_case(true).whole_word(true));
+        assert_eq!((matches[1].line, matches[1].column, matches[1].line_text.as_str()), (1, 7, "foobar(foo)"));
+        assert_eq!(replace_ranges(text, &[10..13, 22..25], "bar"), "let Foo = bar;\nfoobar(bar)\n");
+    }
+}
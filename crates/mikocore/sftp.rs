@@ -0,0 +1,709 @@
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, BufReader, Read, Write};
+use std::process::Child;
+
+use crate::vfs::DirEntry;
+
+// Packet types of SFTP version 3, the one every OpenSSH server speaks
+const FXP_INIT: u8 = 1;
+const FXP_VERSION: u8 = 2;
+const FXP_OPEN: u8 = 3;
+const FXP_CLOSE: u8 = 4;
+const FXP_READ: u8 = 5;
+const FXP_WRITE: u8 = 6;
+const FXP_FSETSTAT: u8 = 10;
+const FXP_OPENDIR: u8 = 11;
+const FXP_READDIR: u8 = 12;
+const FXP_REMOVE: u8 = 13;
+const FXP_REALPATH: u8 = 16;
+const FXP_STAT: u8 = 17;
+const FXP_RENAME: u8 = 18;
+const FXP_EXTENDED: u8 = 200;
+const FXP_STATUS: u8 = 101;
+const FXP_HANDLE: u8 = 102;
+const FXP_DATA: u8 = 103;
+const FXP_NAME: u8 = 104;
+const FXP_ATTRS: u8 = 105;
+
+const FX_OK: u32 = 0;
+const FX_EOF: u32 = 1;
+const FX_NO_SUCH_FILE: u32 = 2;
+const FX_PERMISSION_DENIED: u32 = 3;
+
+const OPEN_READ: u32 = 0x01;
+const OPEN_WRITE: u32 = 0x02;
+const OPEN_CREATE: u32 = 0x08;
+const OPEN_TRUNCATE: u32 = 0x10;
+
+const ATTR_SIZE: u32 = 0x01;
+const ATTR_UIDGID: u32 = 0x02;
+const ATTR_PERMISSIONS: u32 = 0x04;
+const ATTR_ACMODTIME: u32 = 0x08;
+const ATTR_EXTENDED: u32 = 0x8000_0000;
+
+const MODE_TYPE: u32 = 0o170000;
+const MODE_DIR: u32 = 0o040000;
+const MODE_SYMLINK: u32 = 0o120000;
+
+/// Bytes per read or write request, which every server accepts
+const CHUNK: usize = 32 * 1024;
+/// Requests kept in flight, so a slow link costs one round trip per file rather than per chunk
+const WINDOW: usize = 16;
+/// Longest packet accepted, well above a full chunk or a page of directory entries
+const MAX_PACKET: u32 = 4 * 1024 * 1024;
+
+/// What a server tells about a file; only the mode is of use here
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Attrs {
+    pub permissions: Option<u32>,
+}
+
+impl Attrs {
+    pub fn is_dir(&self) -> bool {
+        self.permissions.is_some_and(|mode| mode & MODE_TYPE == MODE_DIR)
+    }
+
+    fn is_symlink(&self) -> bool {
+        self.permissions.is_some_and(|mode| mode & MODE_TYPE == MODE_SYMLINK)
+    }
+}
+
+/// A request or reply on the wire, built field by field after the length
+struct Packet(Vec<u8>);
+
+impl Packet {
+    /// Start a packet of `kind`; `id` is the version for `FXP_INIT`/`FXP_VERSION`
+    fn new(kind: u8, id: u32) -> Self {
+        Self(vec![0, 0, 0, 0, kind]).u32(id)
+    }
+
+    fn u32(mut self, value: u32) -> Self {
+        self.0.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    fn u64(mut self, value: u64) -> Self {
+        self.0.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    fn bytes(self, value: &[u8]) -> Self {
+        let mut packet = self.u32(value.len() as u32);
+        packet.0.extend_from_slice(value);
+        packet
+    }
+
+    fn attrs(self, attrs: &Attrs) -> Self {
+        match attrs.permissions {
+            Some(mode) => self.u32(ATTR_PERMISSIONS).u32(mode),
+            None => self.u32(0),
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        let length = (self.0.len() - 4) as u32;
+        self.0[..4].copy_from_slice(&length.to_be_bytes());
+        self.0
+    }
+}
+
+/// Fields read one after another from a packet's body
+struct Fields<'a>(&'a [u8]);
+
+impl Fields<'_> {
+    fn take(&mut self, count: usize) -> io::Result<&[u8]> {
+        if self.0.len() < count {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "SFTP packet is cut short"));
+        }
+        let (taken, rest) = self.0.split_at(count);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn u32(&mut self) -> io::Result<u32> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap_or_default()))
+    }
+
+    fn u64(&mut self) -> io::Result<u64> {
+        Ok(u64::from_be_bytes(self.take(8)?.try_into().unwrap_or_default()))
+    }
+
+    fn bytes(&mut self) -> io::Result<Vec<u8>> {
+        let length = self.u32()? as usize;
+        Ok(self.take(length)?.to_vec())
+    }
+
+    fn string(&mut self) -> io::Result<String> {
+        Ok(String::from_utf8_lossy(&self.bytes()?).into_owned())
+    }
+
+    fn attrs(&mut self) -> io::Result<Attrs> {
+        let flags = self.u32()?;
+        let mut attrs = Attrs::default();
+        if flags & ATTR_SIZE != 0 {
+            self.u64()?;
+        }
+        if flags & ATTR_UIDGID != 0 {
+            self.take(8)?;
+        }
+        if flags & ATTR_PERMISSIONS != 0 {
+            attrs.permissions = Some(self.u32()?);
+        }
+        if flags & ATTR_ACMODTIME != 0 {
+            self.take(8)?;
+        }
+        if flags & ATTR_EXTENDED != 0 {
+            for _ in 0..self.u32()? {
+                self.bytes()?;
+                self.bytes()?;
+            }
+        }
+        Ok(attrs)
+    }
+}
+
+#[derive(Debug)]
+enum Reply {
+    Status(u32, String),
+    Handle(Vec<u8>),
+    Data(Vec<u8>),
+    Name(Vec<(String, Attrs)>),
+    Attrs(Attrs),
+}
+
+/// Error for a reply that isn't the one asked for, or the failure a status reports
+fn unexpected(reply: Reply) -> io::Error {
+    match reply {
+        Reply::Status(FX_NO_SUCH_FILE, message) => io::Error::new(io::ErrorKind::NotFound, message),
+        Reply::Status(FX_PERMISSION_DENIED, message) => io::Error::new(io::ErrorKind::PermissionDenied, message),
+        Reply::Status(_, message) => io::Error::other(message),
+        reply => io::Error::new(io::ErrorKind::InvalidData, format!("unexpected SFTP reply {:?}", reply)),
+    }
+}
+
+fn expect_ok(reply: Reply) -> io::Result<()> {
+    match reply {
+        Reply::Status(FX_OK, _) => Ok(()),
+        reply => Err(unexpected(reply)),
+    }
+}
+
+/// `name` inside the remote folder `dir`
+fn join(dir: &str, name: &str) -> String {
+    format!("{}/{}", dir.trim_end_matches('/'), name)
+}
+
+/// An SFTP conversation with one host, usually over `ssh -s sftp`
+///
+/// Reads and writes keep several requests in flight, and file names travel as
+/// length-prefixed strings, so any byte a name can hold survives a listing.
+pub(crate) struct SftpSession {
+    reader: BufReader<Box<dyn Read + Send>>,
+    writer: Box<dyn Write + Send>,
+    child: Option<Child>,
+    next_id: u32,
+    /// Replies that arrived while waiting for another one
+    early: HashMap<u32, Reply>,
+    /// Whether the server replaces files on rename, as OpenSSH does through this extension
+    posix_rename: bool,
+    /// Set once the connection failed; the session can't be used after that
+    broken: bool,
+}
+
+impl SftpSession {
+    /// Talk SFTP over `reader` and `writer`, ending `child` with the session
+    ///
+    /// When the handshake fails, what `child` wrote to stderr (e.g. why ssh couldn't log in) is the error.
+    pub fn start(reader: Box<dyn Read + Send>, writer: Box<dyn Write + Send>, child: Option<Child>) -> io::Result<Self> {
+        let mut session = Self {
+            reader: BufReader::new(reader),
+            writer,
+            child,
+            next_id: 0,
+            early: HashMap::new(),
+            posix_rename: false,
+            broken: false,
+        };
+        match session.handshake() {
+            Ok(()) => Ok(session),
+            Err(e) => {
+                let Some(mut child) = session.child.take() else {
+                    return Err(e);
+                };
+                let _ = child.kill();
+                let stderr = child.wait_with_output().map(|output| String::from_utf8_lossy(&output.stderr).trim().to_string());
+                match stderr {
+                    Ok(message) if !message.is_empty() => Err(io::Error::other(message)),
+                    _ => Err(e),
+                }
+            }
+        }
+    }
+
+    fn handshake(&mut self) -> io::Result<()> {
+        self.send(Packet::new(FXP_INIT, 3).finish())?;
+        let (kind, body) = self.read_packet()?;
+        if kind != FXP_VERSION {
+            self.broken = true;
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "the server did not start an SFTP session"));
+        }
+        let mut fields = Fields(&body);
+        fields.u32()?;
+        while !fields.0.is_empty() {
+            let name = fields.string()?;
+            fields.bytes()?;
+            self.posix_rename |= name == "posix-rename@openssh.com";
+        }
+        Ok(())
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.broken
+    }
+
+    /// Absolute form of `path`; relative paths start from the login's home
+    pub fn realpath(&mut self, path: &str) -> io::Result<String> {
+        match self.call(Packet::new(FXP_REALPATH, 0).bytes(path.as_bytes()))? {
+            Reply::Name(names) if !names.is_empty() => Ok(names.into_iter().next().map(|(name, _)| name).unwrap_or_default()),
+            reply => Err(unexpected(reply)),
+        }
+    }
+
+    /// Attributes of `path`, following symlinks
+    pub fn stat(&mut self, path: &str) -> io::Result<Attrs> {
+        match self.call(Packet::new(FXP_STAT, 0).bytes(path.as_bytes()))? {
+            Reply::Attrs(attrs) => Ok(attrs),
+            reply => Err(unexpected(reply)),
+        }
+    }
+
+    /// Entries of the folder `path`, without `.` and `..`; symlinks to folders count as folders
+    pub fn read_dir(&mut self, path: &str) -> io::Result<Vec<DirEntry>> {
+        let handle = self.handle(Packet::new(FXP_OPENDIR, 0).bytes(path.as_bytes()))?;
+        let mut names = Vec::new();
+        let listed = loop {
+            match self.call(Packet::new(FXP_READDIR, 0).bytes(&handle)) {
+                Ok(Reply::Name(page)) => names.extend(page),
+                Ok(Reply::Status(FX_EOF, _)) => break Ok(()),
+                Ok(reply) => break Err(unexpected(reply)),
+                Err(e) => break Err(e),
+            }
+        };
+        let closed = self.close(&handle);
+        listed.and(closed)?;
+
+        let mut entries = Vec::with_capacity(names.len());
+        for (name, attrs) in names {
+            if name == "." || name == ".." {
+                continue;
+            }
+            let is_dir = attrs.is_dir() || (attrs.is_symlink() && self.stat(&join(path, &name)).is_ok_and(|target| target.is_dir()));
+            entries.push(DirEntry { name, is_dir });
+        }
+        Ok(entries)
+    }
+
+    pub fn read(&mut self, path: &str) -> io::Result<Vec<u8>> {
+        let handle = self.handle(Packet::new(FXP_OPEN, 0).bytes(path.as_bytes()).u32(OPEN_READ).attrs(&Attrs::default()))?;
+        let read = self.read_handle(&handle);
+        let closed = self.close(&handle);
+        let contents = read?;
+        closed.map(|_| contents)
+    }
+
+    /// Replace `path` with `contents`, keeping its mode
+    ///
+    /// The contents go to a file next to it that is renamed over it once complete, so a
+    /// connection dropped halfway leaves the original as it was.
+    pub fn write(&mut self, path: &str, contents: &[u8]) -> io::Result<()> {
+        let attrs = match self.stat(path) {
+            Ok(attrs) => Attrs { permissions: attrs.permissions.map(|mode| mode & 0o7777) },
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Attrs::default(),
+            Err(e) => return Err(e),
+        };
+        let temp = format!("{}.rabital-{}", path, std::process::id());
+        let handle = self.handle(Packet::new(FXP_OPEN, 0).bytes(temp.as_bytes()).u32(OPEN_WRITE | OPEN_CREATE | OPEN_TRUNCATE).attrs(&attrs))?;
+        let mut written = self.write_handle(&handle, contents);
+        // The mode given on open is reduced by the server's umask
+        if written.is_ok() && attrs.permissions.is_some() {
+            written = self.call(Packet::new(FXP_FSETSTAT, 0).bytes(&handle).attrs(&attrs)).and_then(expect_ok);
+        }
+        let closed = self.close(&handle);
+        let result = written.and(closed).and_then(|_| self.rename(&temp, path));
+        if result.is_err() && !self.broken {
+            let _ = self.call(Packet::new(FXP_REMOVE, 0).bytes(temp.as_bytes()));
+        }
+        result
+    }
+
+    fn read_handle(&mut self, handle: &[u8]) -> io::Result<Vec<u8>> {
+        let mut contents = Vec::new();
+        let mut in_flight = VecDeque::new();
+        let mut next_offset = 0u64;
+        let mut end = false;
+        let mut failure = None;
+        loop {
+            while !end && failure.is_none() && in_flight.len() < WINDOW {
+                let id = self.send_request(Packet::new(FXP_READ, 0).bytes(handle).u64(next_offset).u32(CHUNK as u32))?;
+                in_flight.push_back((id, next_offset));
+                next_offset += CHUNK as u64;
+            }
+            let Some((id, offset)) = in_flight.pop_front() else {
+                break;
+            };
+            let reply = self.reply(id)?;
+            // Replies to chunks past a short read or the end are still read, then dropped
+            if offset != contents.len() as u64 || failure.is_some() {
+                continue;
+            }
+            match reply {
+                Reply::Data(data) if data.is_empty() => end = true,
+                Reply::Data(data) => {
+                    contents.extend_from_slice(&data);
+                    if data.len() < CHUNK {
+                        next_offset = contents.len() as u64;
+                    }
+                }
+                Reply::Status(FX_EOF, _) => end = true,
+                reply => failure = Some(unexpected(reply)),
+            }
+        }
+        match failure {
+            Some(e) => Err(e),
+            None => Ok(contents),
+        }
+    }
+
+    fn write_handle(&mut self, handle: &[u8], contents: &[u8]) -> io::Result<()> {
+        let mut in_flight = VecDeque::new();
+        let mut result = Ok(());
+        for (index, chunk) in contents.chunks(CHUNK).enumerate() {
+            if in_flight.len() == WINDOW {
+                if let Some(id) = in_flight.pop_front() {
+                    result = self.reply(id).and_then(expect_ok);
+                }
+                if result.is_err() {
+                    break;
+                }
+            }
+            let id = self.send_request(Packet::new(FXP_WRITE, 0).bytes(handle).u64((index * CHUNK) as u64).bytes(chunk))?;
+            in_flight.push_back(id);
+        }
+        for id in in_flight {
+            let written = self.reply(id)?;
+            result = result.and(expect_ok(written));
+        }
+        result
+    }
+
+    fn rename(&mut self, from: &str, to: &str) -> io::Result<()> {
+        if self.posix_rename {
+            let request = Packet::new(FXP_EXTENDED, 0).bytes(b"posix-rename@openssh.com").bytes(from.as_bytes()).bytes(to.as_bytes());
+            return self.call(request).and_then(expect_ok);
+        }
+        // Plain renames refuse to replace a file, so the old one goes first
+        let _ = self.call(Packet::new(FXP_REMOVE, 0).bytes(to.as_bytes()));
+        self.call(Packet::new(FXP_RENAME, 0).bytes(from.as_bytes()).bytes(to.as_bytes())).and_then(expect_ok)
+    }
+
+    fn handle(&mut self, request: Packet) -> io::Result<Vec<u8>> {
+        match self.call(request)? {
+            Reply::Handle(handle) => Ok(handle),
+            reply => Err(unexpected(reply)),
+        }
+    }
+
+    fn close(&mut self, handle: &[u8]) -> io::Result<()> {
+        self.call(Packet::new(FXP_CLOSE, 0).bytes(handle)).and_then(expect_ok)
+    }
+
+    /// Send `request` and wait for its reply
+    fn call(&mut self, request: Packet) -> io::Result<Reply> {
+        let id = self.send_request(request)?;
+        self.reply(id)
+    }
+
+    /// Send `request`, built with a placeholder id, under the next free id
+    fn send_request(&mut self, request: Packet) -> io::Result<u32> {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        let mut packet = request.finish();
+        packet[5..9].copy_from_slice(&id.to_be_bytes());
+        self.send(packet)?;
+        Ok(id)
+    }
+
+    fn send(&mut self, packet: Vec<u8>) -> io::Result<()> {
+        let sent = self.writer.write_all(&packet).and_then(|_| self.writer.flush());
+        self.broken |= sent.is_err();
+        sent
+    }
+
+    fn reply(&mut self, id: u32) -> io::Result<Reply> {
+        if let Some(reply) = self.early.remove(&id) {
+            return Ok(reply);
+        }
+        loop {
+            let (kind, body) = self.read_packet()?;
+            let mut fields = Fields(&body);
+            let reply_id = fields.u32()?;
+            let reply = match kind {
+                FXP_STATUS => Reply::Status(fields.u32()?, fields.string().unwrap_or_default()),
+                FXP_HANDLE => Reply::Handle(fields.bytes()?),
+                FXP_DATA => Reply::Data(fields.bytes()?),
+                FXP_NAME => {
+                    let count = fields.u32()?;
+                    let mut names = Vec::new();
+                    for _ in 0..count {
+                        let name = fields.string()?;
+                        fields.bytes()?;
+                        names.push((name, fields.attrs()?));
+                    }
+                    Reply::Name(names)
+                }
+                FXP_ATTRS => Reply::Attrs(fields.attrs()?),
+                kind => {
+                    self.broken = true;
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, format!("unknown SFTP reply type {}", kind)));
+                }
+            };
+            if reply_id == id {
+                return Ok(reply);
+            }
+            self.early.insert(reply_id, reply);
+        }
+    }
+
+    fn read_packet(&mut self) -> io::Result<(u8, Vec<u8>)> {
+        let packet = (|| {
+            let mut length = [0u8; 4];
+            self.reader.read_exact(&mut length)?;
+            let length = u32::from_be_bytes(length);
+            if length == 0 || length > MAX_PACKET {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, format!("SFTP packet of {} bytes", length)));
+            }
+            let mut body = vec![0u8; length as usize];
+            self.reader.read_exact(&mut body)?;
+            let kind = body.remove(0);
+            Ok((kind, body))
+        })();
+        self.broken |= packet.is_err();
+        packet
+    }
+}
+
+impl Drop for SftpSession {
+    fn drop(&mut self) {
+        if let Some(mut child) = self.child.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    /// Just enough of an SFTP server over a local folder to hold a session against
+    fn serve(root: PathBuf, mut input: impl Read, mut output: impl Write) {
+        enum Open {
+            File(fs::File),
+            Dir(Option<Vec<(String, u32)>>),
+        }
+
+        fn mode(path: &Path) -> u32 {
+            use std::os::unix::fs::MetadataExt;
+            fs::symlink_metadata(path).map(|metadata| metadata.mode()).unwrap_or_default()
+        }
+
+        let local = |path: &[u8]| {
+            let path = String::from_utf8_lossy(path).into_owned();
+            match path.strip_prefix('/') {
+                Some(absolute) => root.join(absolute),
+                None => root.join(path),
+            }
+        };
+        let status = |id: u32, code: u32| Packet::new(FXP_STATUS, id).u32(code).bytes(b"").bytes(b"").finish();
+        let mut handles: HashMap<u32, Open> = HashMap::new();
+        loop {
+            let mut length = [0u8; 4];
+            if input.read_exact(&mut length).is_err() {
+                return;
+            }
+            let mut body = vec![0u8; u32::from_be_bytes(length) as usize];
+            input.read_exact(&mut body).unwrap();
+            let mut fields = Fields(&body[1..]);
+            let id = fields.u32().unwrap();
+            let reply = match body[0] {
+                FXP_INIT => Packet::new(FXP_VERSION, 3).bytes(b"posix-rename@openssh.com").bytes(b"1").finish(),
+                FXP_REALPATH => {
+                    let path = local(&fields.bytes().unwrap()).canonicalize().unwrap();
+                    let path = format!("/{}", path.strip_prefix(root.canonicalize().unwrap()).unwrap().display());
+                    Packet::new(FXP_NAME, id).u32(1).bytes(path.as_bytes()).bytes(b"").attrs(&Attrs::default()).finish()
+                }
+                FXP_STAT => match fs::metadata(local(&fields.bytes().unwrap())) {
+                    Ok(metadata) => {
+                        use std::os::unix::fs::MetadataExt;
+                        Packet::new(FXP_ATTRS, id).attrs(&Attrs { permissions: Some(metadata.mode()) }).finish()
+                    }
+                    Err(_) => status(id, FX_NO_SUCH_FILE),
+                },
+                FXP_OPENDIR => {
+                    let dir = local(&fields.bytes().unwrap());
+                    let mut names = vec![(".".to_string(), MODE_DIR), ("..".to_string(), MODE_DIR)];
+                    for entry in fs::read_dir(&dir).unwrap() {
+                        let entry = entry.unwrap();
+                        names.push((entry.file_name().to_string_lossy().into_owned(), mode(&entry.path())));
+                    }
+                    handles.insert(id, Open::Dir(Some(names)));
+                    Packet::new(FXP_HANDLE, id).bytes(&id.to_be_bytes()).finish()
+                }
+                FXP_READDIR => {
+                    let handle = u32::from_be_bytes(fields.bytes().unwrap().try_into().unwrap());
+                    match handles.get_mut(&handle) {
+                        Some(Open::Dir(names)) => match names.take() {
+                            Some(names) => {
+                                let mut packet = Packet::new(FXP_NAME, id).u32(names.len() as u32);
+                                for (name, mode) in names {
+                                    packet = packet.bytes(name.as_bytes()).bytes(b"").attrs(&Attrs { permissions: Some(mode) });
+                                }
+                                packet.finish()
+                            }
+                            None => status(id, FX_EOF),
+                        },
+                        _ => status(id, 4),
+                    }
+                }
+                FXP_OPEN => {
+                    let path = local(&fields.bytes().unwrap());
+                    let flags = fields.u32().unwrap();
+                    let file = fs::OpenOptions::new()
+                        .read(flags & OPEN_READ != 0)
+                        .write(flags & OPEN_WRITE != 0)
+                        .create(flags & OPEN_CREATE != 0)
+                        .truncate(flags & OPEN_TRUNCATE != 0)
+                        .open(path);
+                    match file {
+                        Ok(file) => {
+                            handles.insert(id, Open::File(file));
+                            Packet::new(FXP_HANDLE, id).bytes(&id.to_be_bytes()).finish()
+                        }
+                        Err(_) => status(id, FX_NO_SUCH_FILE),
+                    }
+                }
+                FXP_READ | FXP_WRITE | FXP_FSETSTAT => {
+                    use std::io::{Seek, SeekFrom};
+                    use std::os::unix::fs::PermissionsExt;
+                    let handle = u32::from_be_bytes(fields.bytes().unwrap().try_into().unwrap());
+                    let Some(Open::File(file)) = handles.get_mut(&handle) else {
+                        panic!("no file open as {}", handle);
+                    };
+                    match body[0] {
+                        FXP_READ => {
+                            file.seek(SeekFrom::Start(fields.u64().unwrap())).unwrap();
+                            // Short reads, as a server may give, with the rest asked for again
+                            let mut data = vec![0u8; (fields.u32().unwrap() as usize).min(1000)];
+                            let count = file.read(&mut data).unwrap();
+                            match count {
+                                0 => status(id, FX_EOF),
+                                count => Packet::new(FXP_DATA, id).bytes(&data[..count]).finish(),
+                            }
+                        }
+                        FXP_WRITE => {
+                            file.seek(SeekFrom::Start(fields.u64().unwrap())).unwrap();
+                            file.write_all(&fields.bytes().unwrap()).unwrap();
+                            status(id, FX_OK)
+                        }
+                        _ => {
+                            let mode = fields.attrs().unwrap().permissions.unwrap();
+                            file.set_permissions(fs::Permissions::from_mode(mode)).unwrap();
+                            status(id, FX_OK)
+                        }
+                    }
+                }
+                FXP_CLOSE => {
+                    let handle = u32::from_be_bytes(fields.bytes().unwrap().try_into().unwrap());
+                    handles.remove(&handle);
+                    status(id, FX_OK)
+                }
+                FXP_EXTENDED => {
+                    assert_eq!(fields.bytes().unwrap(), b"posix-rename@openssh.com");
+                    let from = local(&fields.bytes().unwrap());
+                    fs::rename(from, local(&fields.bytes().unwrap())).unwrap();
+                    status(id, FX_OK)
+                }
+                FXP_REMOVE => match fs::remove_file(local(&fields.bytes().unwrap())) {
+                    Ok(()) => status(id, FX_OK),
+                    Err(_) => status(id, FX_NO_SUCH_FILE),
+                },
+                kind => panic!("unexpected request {}", kind),
+            };
+            if output.write_all(&reply).is_err() {
+                return;
+            }
+        }
+    }
+
+    fn session(root: &Path) -> SftpSession {
+        let (from_client, to_server) = io::pipe().unwrap();
+        let (from_server, to_client) = io::pipe().unwrap();
+        let root = root.to_path_buf();
+        std::thread::spawn(move || serve(root, from_client, to_client));
+        SftpSession::start(Box::new(from_server), Box::new(to_server), None).unwrap()
+    }
+
+    fn folder(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rabital-sftp-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn lists_names_with_any_bytes_and_follows_folder_links() {
+        let dir = folder("list");
+        fs::create_dir(dir.join("src")).unwrap();
+        fs::write(dir.join("two\nlines.txt"), "").unwrap();
+        std::os::unix::fs::symlink(dir.join("src"), dir.join("link")).unwrap();
+        let mut session = session(&dir);
+        assert!(session.posix_rename);
+
+        let mut entries = session.read_dir("/").unwrap();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        let listed: Vec<(&str, bool)> = entries.iter().map(|entry| (entry.name.as_str(), entry.is_dir)).collect();
+        assert_eq!(listed, vec![("link", true), ("src", true), ("two\nlines.txt", false)]);
+        assert_eq!(session.realpath("src/../src").unwrap(), "/src");
+        assert!(session.stat("/src").unwrap().is_dir());
+        assert_eq!(session.stat("/missing").unwrap_err().kind(), io::ErrorKind::NotFound);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn reads_and_replaces_files_in_pipelined_chunks() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = folder("files");
+        let big: Vec<u8> = (0..CHUNK * WINDOW + 12345).map(|i| (i % 251) as u8).collect();
+        fs::write(dir.join("big.bin"), &big).unwrap();
+        fs::write(dir.join("run.sh"), "old").unwrap();
+        fs::set_permissions(dir.join("run.sh"), fs::Permissions::from_mode(0o755)).unwrap();
+        let mut session = session(&dir);
+
+        assert_eq!(session.read("/big.bin").unwrap(), big);
+        assert_eq!(session.read("/run.sh").unwrap(), b"old");
+
+        let replacement: Vec<u8> = big.iter().rev().copied().collect();
+        session.write("/run.sh", &replacement).unwrap();
+        assert_eq!(fs::read(dir.join("run.sh")).unwrap(), replacement);
+        assert_eq!(fs::metadata(dir.join("run.sh")).unwrap().permissions().mode() & 0o777, 0o755);
+        session.write("/new.txt", b"fresh").unwrap();
+        assert_eq!(fs::read(dir.join("new.txt")).unwrap(), b"fresh");
+        // Only the files themselves are left, no temporary copies
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 3);
+        assert!(!session.is_broken());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
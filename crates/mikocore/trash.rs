@@ -0,0 +1,222 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A file or folder moved to the trash, with enough to put it back
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashedItem {
+    pub original: PathBuf,
+    /// Where it sits in the trash; None when the platform doesn't say (the Windows recycle bin)
+    trashed: Option<PathBuf>,
+    /// freedesktop `.trashinfo` file, removed on restore
+    info: Option<PathBuf>,
+}
+
+impl TrashedItem {
+    /// False when the item can only be restored from the OS recycle bin
+    pub fn can_restore(&self) -> bool {
+        self.trashed.is_some()
+    }
+
+    /// Move the item back; fails rather than replace something created at its old path since
+    pub fn restore(&self) -> io::Result<()> {
+        let Some(trashed) = &self.trashed else {
+            return Err(io::Error::new(io::ErrorKind::Unsupported, "restore it from the recycle bin"));
+        };
+        if fs::symlink_metadata(&self.original).is_ok() {
+            return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", self.original.display())));
+        }
+        if let Some(parent) = self.original.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        move_path(trashed, &self.original)?;
+        if let Some(info) = &self.info {
+            let _ = fs::remove_file(info);
+        }
+        Ok(())
+    }
+}
+
+/// Move a file or folder to the OS trash or recycle bin
+pub fn move_to_trash(path: &Path) -> io::Result<TrashedItem> {
+    let path = std::path::absolute(path)?;
+    fs::symlink_metadata(&path)?;
+    platform::move_to_trash(path)
+}
+
+/// Rename, or copy and remove when `to` is on another file system
+pub(crate) fn move_path(from: &Path, to: &Path) -> io::Result<()> {
+    match fs::rename(from, to) {
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+            copy_recursive(from, to)?;
+            if fs::symlink_metadata(from)?.is_dir() {
+                fs::remove_dir_all(from)
+            } else {
+                fs::remove_file(from)
+            }
+        }
+        result => result,
+    }
+}
+
+fn copy_recursive(from: &Path, to: &Path) -> io::Result<()> {
+    let metadata = fs::symlink_metadata(from)?;
+    if metadata.is_dir() {
+        fs::create_dir(to)?;
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            copy_recursive(&entry.path(), &to.join(entry.file_name()))?;
+        }
+        Ok(())
+    } else {
+        fs::copy(from, to).map(|_| ())
+    }
+}
+
+/// `name`, then `name 2.ext`, `name 3.ext`, ... for picking a free name in the trash
+#[cfg(not(windows))]
+fn numbered_names(name: &std::ffi::OsStr) -> impl Iterator<Item = std::ffi::OsString> + '_ {
+    let path = Path::new(name);
+    let stem = path.file_stem().unwrap_or(name).to_string_lossy().into_owned();
+    let extension = path.extension().map(|ext| format!(".{}", ext.to_string_lossy())).unwrap_or_default();
+    std::iter::once(name.to_os_string()).chain((2..).map(move |n| format!("{} {}{}", stem, n, extension).into()))
+}
+
+/// freedesktop.org trash: `$XDG_DATA_HOME/Trash/files` with a `.trashinfo` file per item
+#[cfg(all(unix, not(target_os = "macos")))]
+mod platform {
+    use super::{move_path, numbered_names, TrashedItem};
+    use std::fs::{self, OpenOptions};
+    use std::io::{self, Write};
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::{Path, PathBuf};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn trash_dir() -> io::Result<PathBuf> {
+        let data_home = std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute())
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".local").join("share")))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no home folder for the trash"))?;
+        Ok(data_home.join("Trash"))
+    }
+
+    pub fn move_to_trash(path: PathBuf) -> io::Result<TrashedItem> {
+        let trash = trash_dir()?;
+        let files_dir = trash.join("files");
+        let info_dir = trash.join("info");
+        fs::create_dir_all(&files_dir)?;
+        fs::create_dir_all(&info_dir)?;
+        let name = path.file_name().ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "can't trash a root folder"))?.to_os_string();
+
+        // Creating the .trashinfo file first claims the name, as the spec asks
+        for candidate in numbered_names(&name) {
+            let mut info_name = candidate.clone();
+            info_name.push(".trashinfo");
+            let info = info_dir.join(info_name);
+            let mut file = match OpenOptions::new().write(true).create_new(true).open(&info) {
+                Ok(file) => file,
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e),
+            };
+            let trashed = files_dir.join(&candidate);
+            let written = write!(file, "[Trash Info]\nPath={}\nDeletionDate={}\n", encode_path(&path), deletion_date());
+            if let Err(e) = written.and_then(|_| move_path(&path, &trashed)) {
+                let _ = fs::remove_file(&info);
+                return Err(e);
+            }
+            return Ok(TrashedItem {
+                original: path,
+                trashed: Some(trashed),
+                info: Some(info),
+            });
+        }
+        unreachable!("numbered_names never runs out")
+    }
+
+    /// Percent-encode everything but unreserved chars and `/`
+    fn encode_path(path: &Path) -> String {
+        path.as_os_str()
+            .as_bytes()
+            .iter()
+            .map(|&byte| match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => (byte as char).to_string(),
+                _ => format!("%{:02X}", byte),
+            })
+            .collect()
+    }
+
+    /// `YYYY-MM-DDThh:mm:ss`; UTC, as there's no time zone database to ask for local time
+    fn deletion_date() -> String {
+        let secs = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs());
+        let (days, time) = (secs / 86_400, secs % 86_400);
+        // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+        let z = days as i64 + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}", year, month, day, time / 3600, time / 60 % 60, time % 60)
+    }
+}
+
+/// `~/.Trash`; Finder's "Put Back" doesn't know about these, but `TrashedItem::restore` does
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::{move_path, numbered_names, TrashedItem};
+    use std::fs;
+    use std::io;
+    use std::path::{Path, PathBuf};
+
+    pub fn move_to_trash(path: PathBuf) -> io::Result<TrashedItem> {
+        let home = std::env::var_os("HOME").ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no home folder for the trash"))?;
+        let trash = Path::new(&home).join(".Trash");
+        fs::create_dir_all(&trash)?;
+        let name = path.file_name().ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "can't trash a root folder"))?.to_os_string();
+        let trashed = numbered_names(&name)
+            .map(|candidate| trash.join(candidate))
+            .find(|candidate| fs::symlink_metadata(candidate).is_err())
+            .expect("numbered_names never runs out");
+        move_path(&path, &trashed)?;
+        Ok(TrashedItem {
+            original: path,
+            trashed: Some(trashed),
+            info: None,
+        })
+    }
+}
+
+/// The recycle bin, through the shell so Explorer's "Restore" works
+#[cfg(windows)]
+mod platform {
+    use super::TrashedItem;
+    use std::io;
+    use std::os::windows::ffi::OsStrExt;
+    use std::path::PathBuf;
+    use windows::core::PCWSTR;
+    use windows::Win32::UI::Shell::{SHFileOperationW, FOF_ALLOWUNDO, FOF_NOCONFIRMATION, FOF_NOERRORUI, FOF_SILENT, FO_DELETE, SHFILEOPSTRUCTW};
+
+    pub fn move_to_trash(path: PathBuf) -> io::Result<TrashedItem> {
+        // pFrom is a list of paths ended by an empty one
+        let from: Vec<u16> = path.as_os_str().encode_wide().chain([0, 0]).collect();
+        let mut operation = SHFILEOPSTRUCTW {
+            wFunc: FO_DELETE,
+            pFrom: PCWSTR(from.as_ptr()),
+            fFlags: (FOF_ALLOWUNDO | FOF_NOCONFIRMATION | FOF_NOERRORUI | FOF_SILENT).0 as u16,
+            ..Default::default()
+        };
+        let result = unsafe { SHFileOperationW(&mut operation) };
+        if result != 0 || operation.fAnyOperationsAborted.as_bool() {
+            return Err(io::Error::other(format!("couldn't move {} to the recycle bin (code {})", path.display(), result)));
+        }
+        Ok(TrashedItem {
+            original: path,
+            trashed: None,
+            info: None,
+        })
+    }
+}
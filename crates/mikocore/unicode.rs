@@ -0,0 +1,135 @@
+use std::sync::LazyLock;
+
+use unicode_properties::{GeneralCategory, UnicodeGeneralCategory};
+
+/// Walking every code point takes a while, so the list is built on first use and kept
+static NAMED_CHARS: LazyLock<Vec<(char, String)>> = LazyLock::new(|| {
+    (char::MIN..=char::MAX)
+        .filter(|c| !matches!(c.general_category(), GeneralCategory::Unassigned | GeneralCategory::PrivateUse | GeneralCategory::Surrogate))
+        .filter_map(|c| char_name(c).map(|name| (c, name)))
+        .filter(|(c, name)| !is_code_point_name(*c, name))
+        .collect()
+});
+
+/// `U+` notation of a character's code point, with at least four hex digits
+pub fn code_point_label(c: char) -> String {
+    format!("U+{:04X}", c as u32)
+}
+
+/// Name of `c` in the Unicode character database, e.g. `LATIN SMALL LETTER E WITH ACUTE`
+///
+/// Control characters, private use characters and unassigned code points have none.
+pub fn char_name(c: char) -> Option<String> {
+    unicode_names2::name(c).map(|name| name.to_string())
+}
+
+/// General category of `c`, spelled out, e.g. `Lowercase Letter`
+pub fn char_category(c: char) -> &'static str {
+    match c.general_category() {
+        GeneralCategory::UppercaseLetter => "Uppercase Letter",
+        GeneralCategory::LowercaseLetter => "Lowercase Letter",
+        GeneralCategory::TitlecaseLetter => "Titlecase Letter",
+        GeneralCategory::ModifierLetter => "Modifier Letter",
+        GeneralCategory::OtherLetter => "Other Letter",
+        GeneralCategory::NonspacingMark => "Nonspacing Mark",
+        GeneralCategory::SpacingMark => "Spacing Mark",
+        GeneralCategory::EnclosingMark => "Enclosing Mark",
+        GeneralCategory::DecimalNumber => "Decimal Number",
+        GeneralCategory::LetterNumber => "Letter Number",
+        GeneralCategory::OtherNumber => "Other Number",
+        GeneralCategory::ConnectorPunctuation => "Connector Punctuation",
+        GeneralCategory::DashPunctuation => "Dash Punctuation",
+        GeneralCategory::OpenPunctuation => "Open Punctuation",
+        GeneralCategory::ClosePunctuation => "Close Punctuation",
+        GeneralCategory::InitialPunctuation => "Initial Punctuation",
+        GeneralCategory::FinalPunctuation => "Final Punctuation",
+        GeneralCategory::OtherPunctuation => "Other Punctuation",
+        GeneralCategory::MathSymbol => "Math Symbol",
+        GeneralCategory::CurrencySymbol => "Currency Symbol",
+        GeneralCategory::ModifierSymbol => "Modifier Symbol",
+        GeneralCategory::OtherSymbol => "Other Symbol",
+        GeneralCategory::SpaceSeparator => "Space Separator",
+        GeneralCategory::LineSeparator => "Line Separator",
+        GeneralCategory::ParagraphSeparator => "Paragraph Separator",
+        GeneralCategory::Control => "Control",
+        GeneralCategory::Format => "Format",
+        GeneralCategory::Surrogate => "Surrogate",
+        GeneralCategory::PrivateUse => "Private Use",
+        GeneralCategory::Unassigned => "Unassigned",
+    }
+}
+
+/// Character of a typed code point: `U+1F600`, `0x1F600`, `\u{1F600}`, or four to six bare hex digits
+pub fn parse_code_point(text: &str) -> Option<char> {
+    let text = text.trim();
+    let (digits, bare) = if let Some(rest) = text.strip_prefix("U+").or_else(|| text.strip_prefix("u+")) {
+        (rest, false)
+    } else if let Some(rest) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        (rest, false)
+    } else if let Some(rest) = text.strip_prefix("\\u{").and_then(|rest| rest.strip_suffix('}')) {
+        (rest, false)
+    } else {
+        (text, true)
+    };
+    // Short bare words like `face` are more likely part of a name than a code point
+    let min_digits = if bare { 4 } else { 1 };
+    if digits.len() < min_digits || digits.len() > 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(digits, 16).ok().and_then(char::from_u32)
+}
+
+/// Every character with a name of its own, in code point order, for searching by name
+///
+/// Ideographs named after their code point, like `CJK UNIFIED IDEOGRAPH-4E00`, are left out;
+/// `parse_code_point` finds those.
+pub fn named_chars() -> &'static [(char, String)] {
+    &NAMED_CHARS
+}
+
+/// Whether `name` is just a prefix and the code point, as for most ideographs
+fn is_code_point_name(c: char, name: &str) -> bool {
+    name.rsplit_once('-')
+        .is_some_and(|(_, suffix)| u32::from_str_radix(suffix, 16).ok() == Some(c as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn code_points_parse_in_common_notations() {
+        assert_eq!(parse_code_point("U+1F600"), Some('\u{1F600}'));
+        assert_eq!(parse_code_point("u+e9"), Some('é'));
+        assert_eq!(parse_code_point("0x41"), Some('A'));
+        assert_eq!(parse_code_point(" \\u{2014} "), Some('—'));
+        assert_eq!(parse_code_point("4e00"), Some('\u{4E00}'));
+        assert_eq!(parse_code_point("face"), Some('\u{FACE}'));
+        // Too short to be a bare code point, a surrogate, and past the last code point
+        assert_eq!(parse_code_point("abc"), None);
+        assert_eq!(parse_code_point("U+D800"), None);
+        assert_eq!(parse_code_point("U+110000"), None);
+        assert_eq!(parse_code_point("arrow"), None);
+    }
+
+    #[test]
+    fn characters_are_described() {
+        assert_eq!(code_point_label('A'), "U+0041");
+        assert_eq!(code_point_label('\u{1F600}'), "U+1F600");
+        assert_eq!(char_name('é').as_deref(), Some("LATIN SMALL LETTER E WITH ACUTE"));
+        assert_eq!(char_category('é'), "Lowercase Letter");
+        assert_eq!(char_category('\t'), "Control");
+        assert_eq!(char_category('€'), "Currency Symbol");
+
+        assert!(is_code_point_name('\u{4E00}', "CJK UNIFIED IDEOGRAPH-4E00"));
+        assert!(!is_code_point_name('-', "HYPHEN-MINUS"));
+    }
+
+    #[test]
+    fn named_characters_are_listed_once() {
+        let named = named_chars();
+        assert!(std::ptr::eq(named, named_chars()));
+        assert!(named.iter().any(|(c, name)| *c == 'é' && name == "LATIN SMALL LETTER E WITH ACUTE"));
+        assert!(!named.iter().any(|(c, _)| *c == '\u{4E00}'));
+    }
+}
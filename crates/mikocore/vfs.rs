@@ -0,0 +1,169 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::SystemTime;
+
+/// A file or folder directly in a listed folder
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirEntry {
+    pub name: String,
+    pub is_dir: bool,
+}
+
+/// What a file system tells about one path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileMetadata {
+    /// Folders reached through a symlink count as folders
+    pub is_dir: bool,
+    /// The path itself is a symlink
+    pub is_symlink: bool,
+    pub len: u64,
+    pub modified: Option<SystemTime>,
+}
+
+/// What a `FileWatcher` poll compares to notice a file changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stamp {
+    pub modified: Option<SystemTime>,
+    pub len: u64,
+}
+
+/// Where workspace files are listed, read and written, so the explorer, tabs, search and
+/// the file watcher work the same on disk and on anything mounted in its place
+pub trait Vfs: fmt::Debug + Send + Sync {
+    /// Entries directly in `dir`, in no particular order
+    fn read_dir(&self, dir: &Path) -> io::Result<Vec<DirEntry>>;
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+    /// Replace a file's contents, creating it if needed
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()>;
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata>;
+
+    /// How `path` looks to a watcher now; None while it's missing
+    ///
+    /// File systems whose files can't change under the app return the same stamp every time.
+    fn watch(&self, path: &Path) -> Option<Stamp> {
+        let metadata = self.metadata(path).ok()?;
+        Some(Stamp {
+            modified: metadata.modified,
+            len: metadata.len,
+        })
+    }
+
+    /// Writes to `path` always fail, so editors can refuse edits up front
+    fn is_read_only(&self, _path: &Path) -> bool {
+        false
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        self.metadata(path).is_ok_and(|metadata| metadata.is_dir)
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.metadata(path).is_ok_and(|metadata| !metadata.is_dir)
+    }
+}
+
+/// The disk, through `std::fs`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocalFs;
+
+impl Vfs for LocalFs {
+    fn read_dir(&self, dir: &Path) -> io::Result<Vec<DirEntry>> {
+        Ok(fs::read_dir(dir)?
+            .flatten()
+            .map(|entry| DirEntry {
+                name: entry.file_name().to_string_lossy().into_owned(),
+                is_dir: entry.path().is_dir(),
+            })
+            .collect())
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        fs::read(path)
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        fs::write(path, contents)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        let is_symlink = fs::symlink_metadata(path)?.file_type().is_symlink();
+        let metadata = fs::metadata(path)?;
+        Ok(FileMetadata {
+            is_dir: metadata.is_dir(),
+            is_symlink,
+            len: metadata.len(),
+            modified: metadata.modified().ok(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use crate::{search_files, SearchQuery, Walker};
+
+    /// Read-only files kept in memory, folders implied by their paths
+    #[derive(Debug)]
+    struct MemoryFs(BTreeMap<PathBuf, Vec<u8>>);
+
+    impl Vfs for MemoryFs {
+        fn read_dir(&self, dir: &Path) -> io::Result<Vec<DirEntry>> {
+            let mut entries: Vec<DirEntry> = Vec::new();
+            for rest in self.0.keys().filter_map(|path| path.strip_prefix(dir).ok()) {
+                let mut names = rest.iter();
+                let Some(name) = names.next() else {
+                    continue;
+                };
+                let entry = DirEntry { name: name.to_string_lossy().into_owned(), is_dir: names.next().is_some() };
+                if !entries.contains(&entry) {
+                    entries.push(entry);
+                }
+            }
+            Ok(entries)
+        }
+
+        fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+            self.0.get(path).cloned().ok_or_else(|| io::ErrorKind::NotFound.into())
+        }
+
+        fn write(&self, _path: &Path, _contents: &[u8]) -> io::Result<()> {
+            Err(io::ErrorKind::PermissionDenied.into())
+        }
+
+        fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+            let is_dir = match self.0.get(path) {
+                Some(_) => false,
+                None if self.0.keys().any(|file| file.starts_with(path)) => true,
+                None => return Err(io::ErrorKind::NotFound.into()),
+            };
+            let len = self.0.get(path).map_or(0, |contents| contents.len() as u64);
+            Ok(FileMetadata { is_dir, is_symlink: false, len, modified: None })
+        }
+    }
+
+    #[test]
+    fn walks_and_searches_another_file_system() {
+        let files = [("/mem/.gitignore", "*.log\n"), ("/mem/src/main.rs", "fn main() {}\n"), ("/mem/debug.log", "main\n")];
+        let vfs = MemoryFs(files.iter().map(|(path, text)| (PathBuf::from(path), text.as_bytes().to_vec())).collect());
+        let walker = Walker::new("/mem").vfs(Arc::new(vfs));
+
+        let paths: Vec<PathBuf> = walker.iter().map(|entry| entry.path).collect();
+        assert_eq!(paths, vec![PathBuf::from("/mem/src"), PathBuf::from("/mem/src/main.rs")]);
+        assert!(walker.is_ignored(Path::new("/mem/debug.log")));
+
+        let results = search_files(&walker, &SearchQuery::new("main"), 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].path, Path::new("/mem/src/main.rs"));
+        assert!(walker.file_system().watch(Path::new("/mem/src/main.rs")).is_some());
+        assert!(walker.file_system().write(Path::new("/mem/new.rs"), b"").is_err());
+    }
+}
@@ -0,0 +1,361 @@
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+
+use crate::glob::glob_match;
+use crate::vfs::{LocalFs, Vfs};
+
+/// Per-folder ignore files; `.ignore` rules come later and win over `.gitignore`
+const IGNORE_FILES: &[&str] = &[".gitignore", ".ignore"];
+/// Never listed, even with hidden files shown
+const ALWAYS_SKIPPED: &[&str] = &[".git"];
+
+/// One line of an ignore file or an exclude glob
+#[derive(Debug, Clone)]
+struct Rule {
+    pattern: String,
+    /// `!pattern`: re-include what an earlier rule ignored
+    negated: bool,
+    /// `pattern/`: only folders match
+    dir_only: bool,
+    /// Contains a `/`, so it matches the path from the ignore file's folder rather than any name
+    anchored: bool,
+}
+
+impl Rule {
+    /// Parse a line in .gitignore syntax; blank lines and comments give nothing
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line.strip_prefix('\\').filter(|rest| rest.starts_with(['#', '!'])).unwrap_or(line)),
+        };
+        let (dir_only, line) = match line.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let anchored = line.contains('/');
+        let pattern = line.strip_prefix('/').unwrap_or(line).to_string();
+        (!pattern.is_empty()).then_some(Self {
+            pattern,
+            negated,
+            dir_only,
+            anchored,
+        })
+    }
+
+    /// `path` is relative to the folder the rule applies from
+    fn matches(&self, path: &str, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+        if self.anchored {
+            glob_match(&self.pattern, path)
+        } else {
+            glob_match(&self.pattern, path.rsplit('/').next().unwrap_or(path))
+        }
+    }
+}
+
+/// Rules of the ignore files in one folder
+#[derive(Debug)]
+struct IgnoreFile {
+    /// Folder relative to the walk root, `/`-separated; empty for the root
+    base: String,
+    rules: Vec<Rule>,
+}
+
+impl IgnoreFile {
+    fn load(vfs: &dyn Vfs, dir: &Path, base: String, extra: Option<&Path>) -> Option<Arc<Self>> {
+        let rules: Vec<Rule> = extra
+            .into_iter()
+            .map(Path::to_path_buf)
+            .chain(IGNORE_FILES.iter().map(|name| dir.join(name)))
+            .filter_map(|path| vfs.read(&path).ok())
+            .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+            .flat_map(|text| text.lines().filter_map(Rule::parse).collect::<Vec<_>>())
+            .collect();
+        (!rules.is_empty()).then(|| Arc::new(Self { base, rules }))
+    }
+
+    /// Some(ignored) if a rule decides `path`, None if the file has nothing to say
+    fn decide(&self, path: &str, is_dir: bool) -> Option<bool> {
+        let path = if self.base.is_empty() {
+            path
+        } else {
+            path.strip_prefix(self.base.as_str())?.strip_prefix('/')?
+        };
+        self.rules.iter().rev().find(|rule| rule.matches(path, is_dir)).map(|rule| !rule.negated)
+    }
+}
+
+/// Ignore files from the root down to one folder
+type IgnoreChain = Vec<Arc<IgnoreFile>>;
+
+/// A file or folder found by a `Walker`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalkEntry {
+    pub path: PathBuf,
+    pub is_dir: bool,
+    /// 0 for entries directly in the root
+    pub depth: usize,
+}
+
+/// Lists a workspace folder the same way everywhere: hidden entries, ignore files and exclude globs
+#[derive(Debug, Clone)]
+pub struct Walker {
+    root: PathBuf,
+    vfs: Arc<dyn Vfs>,
+    show_hidden: bool,
+    use_ignore_files: bool,
+    excludes: Vec<Rule>,
+}
+
+impl Walker {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            vfs: Arc::new(LocalFs),
+            show_hidden: false,
+            use_ignore_files: true,
+            excludes: Vec::new(),
+        }
+    }
+
+    /// Read the root from `vfs` instead of the disk
+    pub fn vfs(mut self, vfs: Arc<dyn Vfs>) -> Self {
+        self.vfs = vfs;
+        self
+    }
+
+    /// List entries whose name starts with a dot
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+
+    /// Honor .gitignore, .ignore and .git/info/exclude; on by default
+    pub fn use_ignore_files(mut self, use_ignore_files: bool) -> Self {
+        self.use_ignore_files = use_ignore_files;
+        self
+    }
+
+    /// Skip paths matching these globs, relative to the root; `dir/**` skips the folder itself too
+    pub fn exclude<S: AsRef<str>>(mut self, globs: impl IntoIterator<Item = S>) -> Self {
+        self.excludes.extend(globs.into_iter().filter_map(|glob| Rule::parse(glob.as_ref())).map(|mut rule| {
+            rule.anchored = true;
+            rule
+        }));
+        self
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// File system the walk reads, e.g. to read the files it lists
+    pub fn file_system(&self) -> &Arc<dyn Vfs> {
+        &self.vfs
+    }
+
+    /// Every entry under the root, depth first, folders before files in each folder
+    pub fn iter(&self) -> Walk {
+        let chain = self.chain_for(&self.root);
+        let entries = self.list(&self.root, &chain);
+        Walk {
+            walker: self.clone(),
+            stack: vec![Frame { entries: entries.into_iter(), chain }],
+        }
+    }
+
+    /// Walk on a background thread, sending entries as they are found
+    /// The walk stops early once the receiver is dropped.
+    pub fn spawn(&self) -> Receiver<WalkEntry> {
+        let (sender, receiver) = mpsc::channel();
+        let walker = self.clone();
+        std::thread::spawn(move || {
+            for entry in walker.iter() {
+                if sender.send(entry).is_err() {
+                    break;
+                }
+            }
+        });
+        receiver
+    }
+
+    /// Entries directly in `dir`, which must be the root or inside it
+    pub fn read_dir(&self, dir: &Path) -> Vec<WalkEntry> {
+        let chain = self.chain_for(dir);
+        self.list(dir, &chain)
+    }
+
+    /// True if a walk would skip `path`, e.g. to drop watcher events for ignored files
+    /// Paths inside a skipped folder count as skipped too.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        let Some(relative) = self.relative(path) else {
+            return true;
+        };
+        let mut chain = self.chain_for(&self.root);
+        let mut current = self.root.clone();
+        let names: Vec<&str> = relative.split('/').filter(|name| !name.is_empty()).collect();
+        for (i, name) in names.iter().enumerate() {
+            current.push(name);
+            let is_dir = i + 1 < names.len() || self.vfs.is_dir(&current);
+            if ALWAYS_SKIPPED.contains(name) || (!self.show_hidden && name.starts_with('.')) {
+                return true;
+            }
+            if self.skips(&names[..=i].join("/"), is_dir, &chain) {
+                return true;
+            }
+            if is_dir && self.use_ignore_files {
+                chain.extend(IgnoreFile::load(self.vfs.as_ref(), &current, names[..=i].join("/"), None));
+            }
+        }
+        false
+    }
+
+    /// `/`-separated path below the root, None outside of it
+    fn relative(&self, path: &Path) -> Option<String> {
+        let relative = path.strip_prefix(&self.root).ok()?;
+        let names: Vec<String> = relative.iter().map(|name| name.to_string_lossy().into_owned()).collect();
+        Some(names.join("/"))
+    }
+
+    /// Ignore files that apply inside `dir`, from the root down
+    fn chain_for(&self, dir: &Path) -> IgnoreChain {
+        let mut chain = IgnoreChain::new();
+        if !self.use_ignore_files {
+            return chain;
+        }
+        let Some(relative) = self.relative(dir) else {
+            return chain;
+        };
+        let exclude = self.root.join(".git").join("info").join("exclude");
+        chain.extend(IgnoreFile::load(self.vfs.as_ref(), &self.root, String::new(), Some(&exclude)));
+
+        let mut current = self.root.clone();
+        let mut base = String::new();
+        for name in relative.split('/').filter(|name| !name.is_empty()) {
+            current.push(name);
+            base = if base.is_empty() { name.to_string() } else { format!("{}/{}", base, name) };
+            chain.extend(IgnoreFile::load(self.vfs.as_ref(), &current, base.clone(), None));
+        }
+        chain
+    }
+
+    /// Deepest ignore file with a matching rule decides; exclude globs always skip
+    fn skips(&self, relative: &str, is_dir: bool, chain: &IgnoreChain) -> bool {
+        let excluded = self.excludes.iter().any(|rule| {
+            rule.matches(relative, is_dir)
+                || (is_dir && rule.pattern.strip_suffix("/**").is_some_and(|dir| glob_match(dir, relative)))
+        });
+        excluded || chain.iter().rev().find_map(|file| file.decide(relative, is_dir)).unwrap_or(false)
+    }
+
+    fn list(&self, dir: &Path, chain: &IgnoreChain) -> Vec<WalkEntry> {
+        let Some(relative_dir) = self.relative(dir) else {
+            return Vec::new();
+        };
+        let depth = if relative_dir.is_empty() { 0 } else { relative_dir.split('/').count() };
+        let Ok(read) = self.vfs.read_dir(dir) else {
+            return Vec::new();
+        };
+
+        let mut entries: Vec<WalkEntry> = read
+            .into_iter()
+            .filter_map(|entry| {
+                let name = entry.name;
+                if ALWAYS_SKIPPED.contains(&name.as_str()) || (!self.show_hidden && name.starts_with('.')) {
+                    return None;
+                }
+                let path = dir.join(&name);
+                let is_dir = entry.is_dir;
+                let relative = if relative_dir.is_empty() { name } else { format!("{}/{}", relative_dir, name) };
+                (!self.skips(&relative, is_dir, chain)).then_some(WalkEntry { path, is_dir, depth })
+            })
+            .collect();
+        // Folders first, then by name ignoring case
+        entries.sort_by_cached_key(|entry| {
+            let name = entry.path.file_name().map(|name| name.to_string_lossy().to_lowercase());
+            (!entry.is_dir, name)
+        });
+        entries
+    }
+}
+
+struct Frame {
+    entries: std::vec::IntoIter<WalkEntry>,
+    chain: IgnoreChain,
+}
+
+/// Depth-first iterator over a `Walker`'s entries; folders are read as the walk reaches them
+pub struct Walk {
+    walker: Walker,
+    stack: Vec<Frame>,
+}
+
+impl Iterator for Walk {
+    type Item = WalkEntry;
+
+    fn next(&mut self) -> Option<WalkEntry> {
+        loop {
+            let frame = self.stack.last_mut()?;
+            let Some(entry) = frame.entries.next() else {
+                self.stack.pop();
+                continue;
+            };
+            // Symlinked folders are listed but not entered, so links can't loop
+            let vfs = self.walker.vfs.as_ref();
+            if entry.is_dir && !vfs.metadata(&entry.path).is_ok_and(|metadata| metadata.is_symlink) {
+                let mut chain = frame.chain.clone();
+                if self.walker.use_ignore_files {
+                    let base = self.walker.relative(&entry.path).unwrap_or_default();
+                    chain.extend(IgnoreFile::load(vfs, &entry.path, base, None));
+                }
+                let entries = self.walker.list(&entry.path, &chain);
+                self.stack.push(Frame { entries: entries.into_iter(), chain });
+            }
+            return Some(entry);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ignored(lines: &str, path: &str, is_dir: bool) -> bool {
+        let file = IgnoreFile {
+            base: String::new(),
+            rules: lines.lines().filter_map(Rule::parse).collect(),
+        };
+        file.decide(path, is_dir).unwrap_or(false)
+    }
+
+    #[test]
+    fn gitignore_rules() {
+        assert!(ignored("*.log", "logs/today.log", false));
+        assert!(ignored("/build", "build", true));
+        assert!(!ignored("/build", "src/build", true));
+        assert!(ignored("target/", "crates/a/target", true));
+        assert!(!ignored("target/", "target", false));
+        assert!(ignored("docs/**/*.md", "docs/a/b/c.md", false));
+        assert!(ignored("docs/**/*.md", "docs/c.md", false));
+        assert!(!ignored("*.log\n!keep.log", "keep.log", false));
+        assert!(ignored("file[0-9].txt", "file7.txt", false));
+        assert!(!ignored("file[!0-9].txt", "file7.txt", false));
+        assert!(!ignored("# comment\n\\#not", "comment", false));
+        assert!(ignored("\\#not", "#not", false));
+    }
+
+    #[test]
+    fn excludes_skip_the_folder_itself() {
+        let walker = Walker::new("/ws").exclude(["build/**", "**/*.tmp"]);
+        assert!(walker.skips("build", true, &Vec::new()));
+        assert!(walker.skips("src/x.tmp", false, &Vec::new()));
+        assert!(!walker.skips("src/build.rs", false, &Vec::new()));
+    }
+}
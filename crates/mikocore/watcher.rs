@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::vfs::{LocalFs, Stamp, Vfs};
+
+/// Notices files changed on disk by polling their modification time and size
+///
+/// Polling happens on the caller's thread, in `poll`, at most once per interval; `next_poll`
+/// says when to wake up for it.
+#[derive(Debug)]
+pub struct FileWatcher {
+    interval: Duration,
+    vfs: Arc<dyn Vfs>,
+    // None while the file is missing
+    files: HashMap<PathBuf, Option<Stamp>>,
+    last_poll: Instant,
+}
+
+impl FileWatcher {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            vfs: Arc::new(LocalFs),
+            files: HashMap::new(),
+            last_poll: Instant::now(),
+        }
+    }
+
+    /// Poll files through `vfs` instead of the disk
+    pub fn vfs(mut self, vfs: Arc<dyn Vfs>) -> Self {
+        self.vfs = vfs;
+        self
+    }
+
+    /// Watch a file; how it looks now is what later polls compare against
+    pub fn watch(&mut self, path: &Path) {
+        if !self.files.contains_key(path) {
+            self.files.insert(path.to_path_buf(), self.vfs.watch(path));
+        }
+    }
+
+    pub fn unwatch(&mut self, path: &Path) {
+        self.files.remove(path);
+    }
+
+    /// Watch exactly these files, e.g. the ones open in tabs
+    pub fn set_paths<'a>(&mut self, paths: impl IntoIterator<Item = &'a Path>) {
+        let paths: Vec<&Path> = paths.into_iter().collect();
+        self.files.retain(|path, _| paths.contains(&path.as_path()));
+        for path in paths {
+            self.watch(path);
+        }
+    }
+
+    /// Take the file as it is now, e.g. after the app wrote it itself
+    pub fn acknowledge(&mut self, path: &Path) {
+        if let Some(known) = self.files.get_mut(path) {
+            *known = self.vfs.watch(path);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    pub fn next_poll(&self) -> Instant {
+        self.last_poll + self.interval
+    }
+
+    /// Files that changed or reappeared since the last poll; empty until the interval has passed
+    /// Deleted files aren't reported, there being nothing to reload.
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        if Instant::now() < self.next_poll() {
+            return Vec::new();
+        }
+        self.last_poll = Instant::now();
+        let mut changed = Vec::new();
+        for (path, known) in &mut self.files {
+            let current = self.vfs.watch(path);
+            if current != *known {
+                if current.is_some() {
+                    changed.push(path.clone());
+                }
+                *known = current;
+            }
+        }
+        changed.sort();
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_changed_files() {
+        let dir = std::env::temp_dir().join(format!("mikocore-watcher-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (a, b) = (dir.join("a.txt"), dir.join("b.txt"));
+        std::fs::write(&a, "a").unwrap();
+        std::fs::write(&b, "b").unwrap();
+
+        let mut watcher = FileWatcher::new(Duration::ZERO);
+        watcher.set_paths([a.as_path(), b.as_path()]);
+        assert!(watcher.poll().is_empty());
+
+        std::fs::write(&a, "changed").unwrap();
+        std::fs::remove_file(&b).unwrap();
+        assert_eq!(watcher.poll(), vec![a.clone()]);
+
+        // Written by the app itself
+        std::fs::write(&a, "saved").unwrap();
+        watcher.acknowledge(&a);
+        std::fs::write(&b, "back").unwrap();
+        assert_eq!(watcher.poll(), vec![b.clone()]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,142 @@
+use std::ops::Range;
+
+use skia_safe::Color;
+use mikoui::current_theme;
+
+/// What a line annotation marks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnotationKind {
+    SearchMatch,
+    Error,
+    Warning,
+    Info,
+    GitAdded,
+    GitModified,
+    /// Lines removed just above the annotated line
+    GitDeleted,
+}
+
+/// Column of the overview ruler an annotation is drawn in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RulerLane {
+    Left,
+    Center,
+    Right,
+}
+
+impl AnnotationKind {
+    pub fn color(&self) -> Color {
+        match self {
+            AnnotationKind::SearchMatch => Color::from_rgb(234, 179, 8),    // Amber
+            AnnotationKind::Error => current_theme().destructive,
+            AnnotationKind::Warning => Color::from_rgb(245, 158, 11),       // Orange
+            AnnotationKind::Info => Color::from_rgb(59, 130, 246),          // Blue
+            AnnotationKind::GitAdded => Color::from_rgb(34, 197, 94),       // Green
+            AnnotationKind::GitModified => Color::from_rgb(59, 130, 246),   // Blue
+            AnnotationKind::GitDeleted => Color::from_rgb(239, 68, 68),     // Red
+        }
+    }
+
+    /// Git changes on the left, search in the middle, diagnostics on the right
+    pub fn lane(&self) -> RulerLane {
+        match self {
+            AnnotationKind::GitAdded | AnnotationKind::GitModified | AnnotationKind::GitDeleted => RulerLane::Left,
+            AnnotationKind::SearchMatch => RulerLane::Center,
+            AnnotationKind::Error | AnnotationKind::Warning | AnnotationKind::Info => RulerLane::Right,
+        }
+    }
+
+    pub fn is_diagnostic(&self) -> bool {
+        matches!(self, AnnotationKind::Error | AnnotationKind::Warning | AnnotationKind::Info)
+    }
+
+    pub fn is_git(&self) -> bool {
+        self.lane() == RulerLane::Left
+    }
+
+    /// Higher wins when several annotations share a line
+    fn severity(&self) -> u8 {
+        match self {
+            AnnotationKind::Error => 3,
+            AnnotationKind::Warning => 2,
+            AnnotationKind::Info => 1,
+            _ => 0,
+        }
+    }
+}
+
+/// A range of lines marked in the gutter and on the overview ruler
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annotation {
+    /// Zero-based lines, end exclusive
+    pub lines: Range<usize>,
+    pub kind: AnnotationKind,
+    pub message: Option<String>,
+}
+
+impl Annotation {
+    pub fn new(line: usize, kind: AnnotationKind) -> Self {
+        Self {
+            lines: line..line + 1,
+            kind,
+            message: None,
+        }
+    }
+
+    pub fn lines(mut self, lines: Range<usize>) -> Self {
+        self.lines = lines.start..lines.end.max(lines.start + 1);
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+/// Per-tab annotations, one list per source so each can be replaced on its own
+#[derive(Debug, Clone, Default)]
+pub struct Annotations {
+    search: Vec<Annotation>,
+    diagnostics: Vec<Annotation>,
+    git: Vec<Annotation>,
+}
+
+impl Annotations {
+    pub fn set_search_matches(&mut self, lines: impl IntoIterator<Item = usize>) {
+        self.search = lines.into_iter().map(|line| Annotation::new(line, AnnotationKind::SearchMatch)).collect();
+    }
+
+    pub fn set_diagnostics(&mut self, diagnostics: Vec<Annotation>) {
+        self.diagnostics = diagnostics.into_iter().filter(|a| a.kind.is_diagnostic()).collect();
+    }
+
+    pub fn set_git_changes(&mut self, changes: Vec<Annotation>) {
+        self.git = changes.into_iter().filter(|a| a.kind.is_git()).collect();
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.search.is_empty() && self.diagnostics.is_empty() && self.git.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Annotation> {
+        self.git.iter().chain(&self.search).chain(&self.diagnostics)
+    }
+
+    /// Most severe diagnostic touching `line`, for the gutter marker
+    pub fn diagnostic_at(&self, line: usize) -> Option<&Annotation> {
+        self.diagnostics
+            .iter()
+            .filter(|a| a.lines.contains(&line))
+            .max_by_key(|a| a.kind.severity())
+    }
+
+    /// Git change touching `line`, for the gutter bar
+    pub fn git_change_at(&self, line: usize) -> Option<&Annotation> {
+        self.git.iter().find(|a| a.lines.contains(&line))
+    }
+}
@@ -0,0 +1,121 @@
+use std::time::{Duration, Instant};
+
+use crate::tab::TabManager;
+
+/// When edited files are saved without being asked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AutoSaveMode {
+    #[default]
+    Off,
+    /// Once no edit has been made for this long
+    AfterDelay(Duration),
+    /// When the window loses focus or another tab is activated
+    OnFocusChange,
+}
+
+impl AutoSaveMode {
+    /// Parse a setting: `off`, `after_delay` or `on_focus_change`
+    pub fn from_id(id: &str, delay: Duration) -> Option<Self> {
+        match id {
+            "off" => Some(Self::Off),
+            "after_delay" => Some(Self::AfterDelay(delay)),
+            "on_focus_change" => Some(Self::OnFocusChange),
+            _ => None,
+        }
+    }
+}
+
+/// Notices edits to the open files and decides when `AutoSaveMode` wants them saved
+#[derive(Debug, Default)]
+pub struct AutoSave {
+    mode: AutoSaveMode,
+    /// Tab ids and buffer versions at the last `observe`
+    versions: Vec<(usize, u64)>,
+    last_edit: Option<Instant>,
+    active_tab: Option<usize>,
+    focus_lost: bool,
+}
+
+impl AutoSave {
+    pub fn mode(&self) -> AutoSaveMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: AutoSaveMode) {
+        self.mode = mode;
+        self.last_edit = None;
+        self.focus_lost = false;
+    }
+
+    /// Compare the tabs with the last look: an edit restarts the delay, switching tabs counts as a focus change
+    pub fn observe(&mut self, tabs: &TabManager) {
+        let versions: Vec<(usize, u64)> = tabs.tabs().iter().map(|tab| (tab.id, tab.buffer.version())).collect();
+        // New tabs aren't edits, only version changes of tabs seen before
+        let edited = versions.iter().any(|(id, version)| self.versions.iter().any(|(seen, old)| seen == id && old != version));
+        if edited {
+            self.last_edit = Some(Instant::now());
+        }
+        self.versions = versions;
+
+        let active = tabs.get_active_tab().map(|tab| tab.id);
+        if active != self.active_tab {
+            if self.active_tab.is_some() {
+                self.focus_lost = true;
+            }
+            self.active_tab = active;
+        }
+    }
+
+    pub fn window_focus_lost(&mut self) {
+        self.focus_lost = true;
+    }
+
+    /// When the delay after the last edit runs out, for waking up in time
+    pub fn deadline(&self) -> Option<Instant> {
+        match self.mode {
+            AutoSaveMode::AfterDelay(delay) => self.last_edit.map(|edit| edit + delay),
+            _ => None,
+        }
+    }
+
+    /// Whether the modified files should be saved now
+    pub fn is_due(&self) -> bool {
+        match self.mode {
+            AutoSaveMode::Off => false,
+            AutoSaveMode::AfterDelay(_) => self.deadline().is_some_and(|deadline| Instant::now() >= deadline),
+            AutoSaveMode::OnFocusChange => self.focus_lost,
+        }
+    }
+
+    /// The files were saved; wait for the next edit or focus change
+    pub fn saved(&mut self) {
+        self.last_edit = None;
+        self.focus_lost = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn due_after_delay_or_focus_change() {
+        let mut tabs = TabManager::new();
+        tabs.add_tab();
+        let mut auto_save = AutoSave::default();
+        auto_save.set_mode(AutoSaveMode::AfterDelay(Duration::ZERO));
+        auto_save.observe(&tabs);
+        assert!(!auto_save.is_due());
+
+        tabs.get_active_tab_mut().unwrap().buffer.insert(0, "a");
+        auto_save.observe(&tabs);
+        assert!(auto_save.is_due());
+        auto_save.saved();
+        assert!(!auto_save.is_due());
+
+        auto_save.set_mode(AutoSaveMode::OnFocusChange);
+        tabs.add_tab();
+        auto_save.observe(&tabs);
+        assert!(auto_save.is_due());
+    }
+}
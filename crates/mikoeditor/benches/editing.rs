@@ -0,0 +1,100 @@
+//! Text buffer edits and syntax highlighting on large files
+//!
+//! Run with `cargo bench -p mikoeditor`; `scripts/bench.ps1` compares against a saved baseline.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use mikoeditor::{SyntaxHighlighter, TextBuffer};
+
+/// Lines in the generated source files
+const LINES: usize = 20_000;
+
+/// A Rust file of `lines` lines with functions, structs, strings and comments
+fn rust_source(lines: usize) -> String {
+    let mut source = String::new();
+    let mut line = 0;
+    while line < lines {
+        let n = line / 8;
+        source.push_str(&format!("/// Item number {}\n", n));
+        source.push_str(&format!("pub struct Item{} {{ value: u32, name: String }}\n", n));
+        source.push_str(&format!("pub fn compute_{}(input: &str) -> usize {{\n", n));
+        source.push_str("    let mut total = 0;\n");
+        source.push_str("    for (i, c) in input.chars().enumerate() {\n");
+        source.push_str(&format!("        total += i * {} + c as usize; // \"mixed\" content\n", n));
+        source.push_str("    }\n");
+        source.push_str("    total\n}\n");
+        line += 9;
+    }
+    source
+}
+
+fn buffer_edits(c: &mut Criterion) {
+    let source = rust_source(LINES);
+    let mut group = c.benchmark_group("text_buffer");
+    
+    group.bench_function("load_20k_lines", |b| {
+        b.iter(|| TextBuffer::from_str(black_box(&source)))
+    });
+    
+    group.bench_function("type_100_chars_mid_file", |b| {
+        b.iter_batched(
+            || TextBuffer::from_str(&source),
+            |mut buffer| {
+                let mut at = buffer.line_to_char(LINES / 2);
+                for c in "let value = compute(input); ".chars().cycle().take(100) {
+                    buffer.insert(at, c.encode_utf8(&mut [0; 4]));
+                    at += 1;
+                }
+                buffer
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    
+    group.bench_function("delete_100_lines_mid_file", |b| {
+        b.iter_batched(
+            || TextBuffer::from_str(&source),
+            |mut buffer| {
+                let start = buffer.line_to_char(LINES / 2);
+                let end = buffer.line_to_char(LINES / 2 + 100);
+                buffer.remove(start, end);
+                buffer
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    
+    group.bench_function("line_lookup", |b| {
+        let buffer = TextBuffer::from_str(&source);
+        b.iter(|| {
+            for line in (0..buffer.len_lines()).step_by(97) {
+                black_box(buffer.line(line));
+            }
+        })
+    });
+    
+    group.finish();
+}
+
+fn syntax_highlighting(c: &mut Criterion) {
+    let source = rust_source(LINES);
+    let mut group = c.benchmark_group("syntax");
+    group.sample_size(20);
+    
+    group.bench_function("parse_rust_20k_lines", |b| {
+        let mut highlighter = SyntaxHighlighter::new();
+        highlighter.set_language("rust").unwrap();
+        b.iter(|| highlighter.parse(black_box(&source)))
+    });
+    
+    group.bench_function("highlight_rust_20k_lines", |b| {
+        let mut highlighter = SyntaxHighlighter::new();
+        highlighter.set_language("rust").unwrap();
+        highlighter.parse(&source);
+        b.iter(|| highlighter.get_highlights(black_box(&source)))
+    });
+    
+    group.finish();
+}
+
+criterion_group!(benches, buffer_edits, syntax_highlighting);
+criterion_main!(benches);
@@ -0,0 +1,21 @@
+use mikoui::CodiconIcons;
+use skia_safe::Color;
+
+use crate::decorations::Decoration;
+
+/// Key of the decorations a tab marks its bookmarked lines with
+pub(crate) const BOOKMARKS_KEY: &str = "bookmarks";
+/// Tint of the gutter icon and label, a light blue that stays clear of diagnostics' colors
+pub(crate) const BOOKMARK_COLOR: Color = Color::from_rgb(96, 165, 250);
+
+/// The mark for a bookmark on the line starting at char `start`
+///
+/// The range is empty so the bookmark stays with its line as text is typed into it.
+pub(crate) fn bookmark_decoration(start: usize, label: &str) -> Decoration {
+    let decoration = Decoration::new(start..start).gutter_icon(CodiconIcons::BOOKMARK, BOOKMARK_COLOR);
+    if label.is_empty() {
+        decoration
+    } else {
+        decoration.after_line(label, BOOKMARK_COLOR)
+    }
+}
@@ -1,12 +1,71 @@
+use crate::decorations::Decorations;
+use crate::encoding::{Encoding, LineEnding};
+use crate::format::map_offset;
+use crate::language::detect_language;
+use crate::markers::{Bias, MarkerChange, MarkerId, Markers};
+use mikocore::{LocalFs, Vfs};
 use ropey::Rope;
+use std::ops::Range;
 use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Cleanups applied to a buffer right before it is written to disk
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SaveOptions {
+    pub trim_trailing_whitespace: bool,
+    pub insert_final_newline: bool,
+}
+
+/// One edit to a buffer, as delivered to subscribers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeEvent {
+    /// Buffer version after the change; every edit bumps it by one
+    pub version: u64,
+    /// Char range that was replaced, in the text before the change
+    pub range: Range<usize>,
+    /// (line, column) of the range's ends before the change
+    pub start: (usize, usize),
+    pub old_end: (usize, usize),
+    /// Text that took the range's place
+    pub text: String,
+}
+
+impl ChangeEvent {
+    /// (line, column) right after the inserted text
+    pub fn new_end(&self) -> (usize, usize) {
+        match self.text.rfind('\n') {
+            Some(index) => (
+                self.start.0 + self.text.matches('\n').count(),
+                self.text[index + 1..].chars().count(),
+            ),
+            None => (self.start.0, self.start.1 + self.text.chars().count()),
+        }
+    }
+}
+
+/// Handle returned by `TextBuffer::subscribe`, used to unsubscribe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+/// Callback told which markers an edit moved
+type MarkerListener = Box<dyn FnMut(&[MarkerChange])>;
+type ChangeSubscriber = Box<dyn FnMut(&ChangeEvent)>;
 
 /// Text buffer using Rope for efficient text manipulation
 pub struct TextBuffer {
     rope: Rope,
     file_path: Option<PathBuf>,
+    vfs: Arc<dyn Vfs>, // Where the file is read from and saved to
     modified: bool,
     language: Option<String>,
+    encoding: Encoding,
+    line_ending: LineEnding,
+    decorations: Decorations, // Anchored ranges, kept in step with every edit below
+    markers: Markers,
+    marker_listener: Option<MarkerListener>, // Told about markers each edit moved
+    version: u64,
+    subscribers: Vec<(SubscriptionId, ChangeSubscriber)>,
+    next_subscription: u64,
 }
 
 impl TextBuffer {
@@ -14,92 +73,112 @@ impl TextBuffer {
         Self {
             rope: Rope::new(),
             file_path: None,
+            vfs: Arc::new(LocalFs),
             modified: false,
             language: None,
+            encoding: Encoding::default(),
+            line_ending: LineEnding::default(),
+            decorations: Decorations::default(),
+            markers: Markers::default(),
+            marker_listener: None,
+            version: 0,
+            subscribers: Vec::new(),
+            next_subscription: 0,
+        }
+    }
+    
+    /// Empty buffer that will be saved with the given encoding and line ending
+    pub fn with_defaults(encoding: Encoding, line_ending: LineEnding) -> Self {
+        Self {
+            encoding,
+            line_ending,
+            ..Self::new()
         }
     }
     
     pub fn from_str(text: &str) -> Self {
         Self {
-            rope: Rope::from_str(text),
+            rope: Rope::from_str(&text.replace("\r\n", "\n")),
             file_path: None,
+            vfs: Arc::new(LocalFs),
             modified: false,
             language: None,
+            encoding: Encoding::default(),
+            line_ending: LineEnding::detect(text).unwrap_or_default(),
+            decorations: Decorations::default(),
+            markers: Markers::default(),
+            marker_listener: None,
+            version: 0,
+            subscribers: Vec::new(),
+            next_subscription: 0,
         }
     }
     
     pub fn from_file(path: PathBuf) -> std::io::Result<Self> {
-        let text = std::fs::read_to_string(&path)?;
-        let language = Self::detect_language(&path);
+        Self::from_file_with_defaults(path, Arc::new(LocalFs), Encoding::default(), LineEnding::default())
+    }
+    
+    /// Open a file from `vfs`, detecting its encoding and line ending
+    /// The defaults apply when the contents don't decide (pure ASCII, no line breaks)
+    pub fn from_file_with_defaults(
+        path: PathBuf,
+        vfs: Arc<dyn Vfs>,
+        default_encoding: Encoding,
+        default_line_ending: LineEnding,
+    ) -> std::io::Result<Self> {
+        let bytes = vfs.read(&path)?;
+        let encoding = Encoding::detect(&bytes, default_encoding);
+        let text = encoding.decode(&bytes);
+        let line_ending = LineEnding::detect(&text).unwrap_or(default_line_ending);
+        let language = Some(detect_language(Some(&path), &text).to_string());
         
+        // Lines are kept LF-only in memory and converted back on save
         Ok(Self {
-            rope: Rope::from_str(&text),
+            rope: Rope::from_str(&text.replace("\r\n", "\n")),
             file_path: Some(path),
+            vfs,
             modified: false,
             language,
+            encoding,
+            line_ending,
+            decorations: Decorations::default(),
+            markers: Markers::default(),
+            marker_listener: None,
+            version: 0,
+            subscribers: Vec::new(),
+            next_subscription: 0,
         })
     }
     
-    fn detect_language(path: &PathBuf) -> Option<String> {
-        path.extension()
-            .and_then(|ext| ext.to_str())
-            .map(|ext| match ext {
-                // Rust
-                "rs" => "rust",
-                
-                // JavaScript/TypeScript
-                "js" | "mjs" | "cjs" => "javascript",
-                "jsx" => "javascript",
-                "ts" | "mts" | "cts" => "typescript",
-                "tsx" => "tsx",
-                
-                // Python
-                "py" | "pyw" | "pyi" => "python",
-                
-                // Web
-                "html" | "htm" => "html",
-                "css" | "scss" | "sass" | "less" => "css",
-                
-                // Data formats
-                "json" | "jsonc" => "json",
-                "xml" => "xml",
-                "yaml" | "yml" => "yaml",
-                "toml" => "toml",
-                
-                // Markup
-                "md" | "markdown" => "markdown",
-                
-                // C/C++
-                "c" | "h" => "c",
-                "cpp" | "cc" | "cxx" | "hpp" | "hxx" => "cpp",
-                
-                // Java/Kotlin
-                "java" => "java",
-                "kt" | "kts" => "kotlin",
-                
-                // Go
-                "go" => "go",
-                
-                // Ruby
-                "rb" => "ruby",
-                
-                // PHP
-                "php" => "php",
-                
-                // Shell
-                "sh" | "bash" | "zsh" => "bash",
-                
-                // Other
-                "sql" => "sql",
-                "lua" => "lua",
-                "vim" => "vim",
-                "txt" => "text",
-                
-                _ => "text",
-            })
-            .map(String::from)
+    /// Re-read the file from disk using a different encoding
+    pub fn reopen_with_encoding(&mut self, encoding: Encoding) -> std::io::Result<()> {
+        let Some(ref path) = self.file_path else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "No file path set",
+            ));
+        };
+        
+        let bytes = self.vfs.read(path)?;
+        let text = encoding.decode(&bytes);
+        if let Some(line_ending) = LineEnding::detect(&text) {
+            self.line_ending = line_ending;
+        }
+        let text = text.replace("\r\n", "\n");
+        let event = self.change_event(0..self.rope.len_chars(), &text);
+        self.rope = Rope::from_str(&text);
+        self.publish(event);
+        self.decorations.clear();
+        // Markers can't follow a re-decode, so keep them at the same offsets within the new text
+        let len = self.rope.len_chars();
+        let changes = self.markers.remap(|offset| offset.min(len));
+        self.notify_markers(&changes);
+        self.encoding = encoding;
+        self.modified = false;
+        Ok(())
     }
     
+    
     pub fn len_chars(&self) -> usize {
         self.rope.len_chars()
     }
@@ -116,16 +195,224 @@ impl TextBuffer {
         }
     }
     
-    pub fn insert(&mut self, char_idx: usize, text: &str) {
-        self.rope.insert(char_idx, text);
+    /// Char index of the first character of a line
+    pub fn line_to_char(&self, line_idx: usize) -> usize {
+        self.rope.line_to_char(line_idx.min(self.rope.len_lines()))
+    }
+    
+    /// Line containing a char index
+    pub fn char_to_line(&self, char_idx: usize) -> usize {
+        self.rope.char_to_line(char_idx.min(self.rope.len_chars()))
+    }
+    
+    /// Swap in new contents, e.g. a formatter's output; CRLF is normalized to LF like on load
+    pub fn replace_text(&mut self, text: &str) {
+        let old = self.rope.to_string();
+        let new = text.replace("\r\n", "\n");
+        if !self.decorations.is_empty() || !self.markers.is_empty() {
+            let map = |offset| map_offset(&old, offset, &new);
+            self.decorations.remap(map);
+            let changes = self.markers.remap(map);
+            self.notify_markers(&changes);
+        }
+        let event = self.change_event(0..self.rope.len_chars(), &new);
+        self.rope = Rope::from_str(&new);
+        self.publish(event);
         self.modified = true;
     }
     
+    pub fn insert(&mut self, char_idx: usize, text: &str) {
+        self.edit(char_idx..char_idx, text);
+    }
+    
     pub fn remove(&mut self, start: usize, end: usize) {
-        self.rope.remove(start..end);
+        self.edit(start..end, "");
+    }
+    
+    /// Replace a char range; every edit goes through here so anchors and subscribers stay in sync
+    fn edit(&mut self, range: Range<usize>, text: &str) {
+        let event = self.change_event(range.clone(), text);
+        if !range.is_empty() {
+            self.rope.remove(range.clone());
+        }
+        if !text.is_empty() {
+            self.rope.insert(range.start, text);
+        }
+        self.shift_anchors(range.start, range.len(), text.chars().count());
+        self.publish(event);
         self.modified = true;
     }
     
+    /// Remove spaces and tabs at the end of every line
+    /// Returns true if anything was removed
+    pub fn trim_trailing_whitespace(&mut self) -> bool {
+        let mut changed = false;
+        
+        // Walk backwards so earlier char offsets stay valid
+        for line_idx in (0..self.rope.len_lines()).rev() {
+            let line = self.rope.line(line_idx);
+            let content_len = line
+                .chars()
+                .position(|c| c == '\n')
+                .unwrap_or_else(|| line.len_chars());
+            let kept_len = line
+                .chars()
+                .take(content_len)
+                .collect::<String>()
+                .trim_end_matches([' ', '\t'])
+                .chars()
+                .count();
+            
+            if kept_len < content_len {
+                let line_start = self.rope.line_to_char(line_idx);
+                self.edit(line_start + kept_len..line_start + content_len, "");
+                changed = true;
+            }
+        }
+        
+        if changed {
+            self.modified = true;
+        }
+        changed
+    }
+    
+    /// Make sure a non-empty buffer ends with a newline
+    /// Returns true if one was added
+    pub fn ensure_final_newline(&mut self) -> bool {
+        let len = self.rope.len_chars();
+        if len == 0 || self.rope.char(len - 1) == '\n' {
+            return false;
+        }
+        self.edit(len..len, "\n");
+        true
+    }
+    
+    /// Apply save-time cleanups; returns true if the text changed
+    pub fn apply_save_options(&mut self, options: &SaveOptions) -> bool {
+        let mut changed = false;
+        if options.trim_trailing_whitespace {
+            changed |= self.trim_trailing_whitespace();
+        }
+        if options.insert_final_newline {
+            changed |= self.ensure_final_newline();
+        }
+        changed
+    }
+    
+    pub fn decorations(&self) -> &Decorations {
+        &self.decorations
+    }
+    
+    pub fn decorations_mut(&mut self) -> &mut Decorations {
+        &mut self.decorations
+    }
+    
+    /// Marker at a line and column, clamped to the line's text
+    pub fn add_marker(&mut self, line: usize, column: usize, bias: Bias) -> MarkerId {
+        let line = line.min(self.len_lines().saturating_sub(1));
+        let line_len = self.line(line).map_or(0, |text| text.trim_end_matches('\n').chars().count());
+        let offset = self.line_to_char(line) + column.min(line_len);
+        self.markers.add(offset, bias)
+    }
+    
+    /// Marker at a UTF-8 byte offset, e.g. from a tree-sitter node or an external tool
+    pub fn add_marker_at_byte(&mut self, byte: usize, bias: Bias) -> MarkerId {
+        let offset = self.rope.byte_to_char(byte.min(self.rope.len_bytes()));
+        self.markers.add(offset, bias)
+    }
+    
+    pub fn remove_marker(&mut self, id: MarkerId) -> bool {
+        self.markers.remove(id)
+    }
+    
+    pub fn markers(&self) -> &Markers {
+        &self.markers
+    }
+    
+    /// Current (line, column) of a marker
+    pub fn marker_position(&self, id: MarkerId) -> Option<(usize, usize)> {
+        let offset = self.markers.offset(id)?;
+        let line = self.char_to_line(offset);
+        Some((line, offset - self.line_to_char(line)))
+    }
+    
+    /// Current UTF-8 byte offset of a marker
+    pub fn marker_byte(&self, id: MarkerId) -> Option<usize> {
+        self.markers.offset(id).map(|offset| self.rope.char_to_byte(offset))
+    }
+    
+    /// Call `listener` with the markers each edit moves; replaces any previous listener
+    pub fn set_marker_listener(&mut self, listener: impl FnMut(&[MarkerChange]) + 'static) {
+        self.marker_listener = Some(Box::new(listener));
+    }
+    
+    pub fn clear_marker_listener(&mut self) {
+        self.marker_listener = None;
+    }
+    
+    /// Bumped by every edit, so consumers can tell whether their copy is stale
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+    
+    /// Call `subscriber` after every edit with what changed
+    pub fn subscribe(&mut self, subscriber: impl FnMut(&ChangeEvent) + 'static) -> SubscriptionId {
+        let id = SubscriptionId(self.next_subscription);
+        self.next_subscription += 1;
+        self.subscribers.push((id, Box::new(subscriber)));
+        id
+    }
+    
+    /// Returns false if the subscription was already gone
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let before = self.subscribers.len();
+        self.subscribers.retain(|(sub_id, _)| *sub_id != id);
+        self.subscribers.len() != before
+    }
+    
+    /// Event for replacing `range` with `text`, built before the rope changes; None without subscribers
+    fn change_event(&self, range: Range<usize>, text: &str) -> Option<ChangeEvent> {
+        if self.subscribers.is_empty() {
+            return None;
+        }
+        let position = |offset: usize| {
+            let line = self.rope.char_to_line(offset);
+            (line, offset - self.rope.line_to_char(line))
+        };
+        Some(ChangeEvent {
+            version: self.version + 1,
+            start: position(range.start),
+            old_end: position(range.end),
+            range,
+            text: text.to_string(),
+        })
+    }
+    
+    fn publish(&mut self, event: Option<ChangeEvent>) {
+        self.version += 1;
+        if let Some(event) = event {
+            for (_, subscriber) in &mut self.subscribers {
+                subscriber(&event);
+            }
+        }
+    }
+    
+    /// Keep decorations and markers on the same text after `removed` chars at `start` became `inserted` chars
+    fn shift_anchors(&mut self, start: usize, removed: usize, inserted: usize) {
+        self.decorations.apply_edit(start, removed, inserted);
+        let changes = self.markers.apply_edit(start, removed, inserted);
+        self.notify_markers(&changes);
+    }
+    
+    fn notify_markers(&mut self, changes: &[MarkerChange]) {
+        if changes.is_empty() {
+            return;
+        }
+        if let Some(listener) = self.marker_listener.as_mut() {
+            listener(changes);
+        }
+    }
+    
     pub fn is_modified(&self) -> bool {
         self.modified
     }
@@ -134,17 +421,61 @@ impl TextBuffer {
         self.file_path.as_ref()
     }
     
+    /// File system the buffer's file lives on
+    pub fn vfs(&self) -> &Arc<dyn Vfs> {
+        &self.vfs
+    }
+    
+    /// Switch the language id, e.g. from the language mode picker
+    pub fn set_language(&mut self, language: &str) {
+        self.language = Some(language.to_string());
+    }
+    
     pub fn language(&self) -> Option<&str> {
         self.language.as_deref()
     }
     
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+    
+    /// Change the encoding used on the next save
+    pub fn set_encoding(&mut self, encoding: Encoding) {
+        if self.encoding != encoding {
+            self.encoding = encoding;
+            self.modified = true;
+        }
+    }
+    
+    pub fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+    
+    /// Change the line ending used on the next save
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        if self.line_ending != line_ending {
+            self.line_ending = line_ending;
+            self.modified = true;
+        }
+    }
+    
     pub fn to_string(&self) -> String {
         self.rope.to_string()
     }
     
+    /// File contents as they will be written to disk
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let text = self.to_string();
+        let text = match self.line_ending {
+            LineEnding::Lf => text,
+            LineEnding::CrLf => text.replace('\n', "\r\n"),
+        };
+        self.encoding.encode(&text)
+    }
+    
     pub fn save(&mut self) -> std::io::Result<()> {
         if let Some(ref path) = self.file_path {
-            std::fs::write(path, self.to_string())?;
+            self.vfs.write(path, &self.to_bytes())?;
             self.modified = false;
             Ok(())
         } else {
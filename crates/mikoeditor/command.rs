@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+/// An edit to the active document, apart from the key that asked for it
+///
+/// Keys map to these before `Editor::execute` runs them, which is what lets macros record and
+/// replay what was done rather than which keys were pressed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EditorCommand {
+    /// Typed text, with the same auto-indent and bracket handling as typing it
+    Type(String),
+    /// Text inserted as is, e.g. pasted
+    Paste(String),
+    Newline,
+    DeleteLeft,
+    Indent,
+    Outdent,
+    CursorLeft,
+    CursorRight,
+    CursorUp,
+    CursorDown,
+    SelectAll,
+    ClearSelection,
+    /// Grow the column selection by lines and columns
+    ColumnSelect { lines: isize, columns: isize },
+    /// Selected text for the clipboard
+    Copy,
+    /// Selected text for the clipboard, removed from the document
+    Cut,
+    /// Comment out the selected lines with the language's line comment, or uncomment them
+    ToggleLineComment,
+    /// Wrap the selection in the language's block comment, or unwrap it
+    ToggleBlockComment,
+}
+
+impl EditorCommand {
+    /// Whether it changes the document, which read-only editors refuse
+    pub fn modifies_text(&self) -> bool {
+        matches!(
+            self,
+            EditorCommand::Type(_)
+                | EditorCommand::Paste(_)
+                | EditorCommand::Newline
+                | EditorCommand::DeleteLeft
+                | EditorCommand::Indent
+                | EditorCommand::Outdent
+                | EditorCommand::Cut
+                | EditorCommand::ToggleLineComment
+                | EditorCommand::ToggleBlockComment
+        )
+    }
+}
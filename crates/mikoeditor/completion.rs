@@ -0,0 +1,264 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::tab::EditorTab;
+
+/// Most suggestions shown at once, across all providers
+const MAX_ITEMS: usize = 50;
+/// Shortest typed prefix that opens word completion on its own
+const MIN_WORD_PREFIX: usize = 2;
+/// Shortest word worth suggesting
+const MIN_WORD_LENGTH: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionKind {
+    Word,
+    File,
+    Folder,
+}
+
+impl CompletionKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CompletionKind::Word => "word",
+            CompletionKind::File => "file",
+            CompletionKind::Folder => "folder",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletionItem {
+    /// Text shown in the popup and inserted on accept
+    pub label: String,
+    pub kind: CompletionKind,
+    /// Chars before the cursor that the label replaces
+    pub replace_len: usize,
+}
+
+/// What providers see of the editor when asked for suggestions
+pub struct CompletionContext<'a> {
+    /// The cursor's line up to the cursor
+    pub line_before: &'a str,
+    pub document_path: Option<&'a Path>,
+    /// Every open tab, for word harvesting
+    pub tabs: &'a [EditorTab],
+    /// Requested with Ctrl+Space rather than opened by typing
+    pub explicit: bool,
+}
+
+pub fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+impl CompletionContext<'_> {
+    /// Identifier chars right before the cursor
+    pub fn word_prefix(&self) -> &str {
+        let start = self
+            .line_before
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| is_word_char(*c))
+            .last()
+            .map_or(self.line_before.len(), |(index, _)| index);
+        &self.line_before[start..]
+    }
+
+    /// Contents of the string literal the cursor is in, up to the cursor
+    pub fn string_prefix(&self) -> Option<&str> {
+        let mut open: Option<(char, usize)> = None;
+        let mut escaped = false;
+        for (index, c) in self.line_before.char_indices() {
+            if escaped {
+                escaped = false;
+                continue;
+            }
+            match (c, open) {
+                ('\\', Some(_)) => escaped = true,
+                ('"' | '\'' | '`', None) => open = Some((c, index + c.len_utf8())),
+                (c, Some((quote, _))) if c == quote => open = None,
+                _ => {}
+            }
+        }
+        open.map(|(_, start)| &self.line_before[start..])
+    }
+}
+
+/// Source of completion suggestions
+pub trait CompletionProvider {
+    fn name(&self) -> &str;
+
+    /// Suggestions from higher priorities are listed first
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    fn provide(&self, context: &CompletionContext) -> Vec<CompletionItem>;
+}
+
+/// Words from all open text tabs that start with the typed prefix
+pub struct WordProvider;
+
+impl CompletionProvider for WordProvider {
+    fn name(&self) -> &str {
+        "words"
+    }
+
+    fn provide(&self, context: &CompletionContext) -> Vec<CompletionItem> {
+        let prefix = context.word_prefix();
+        let prefix_len = prefix.chars().count();
+        if prefix_len == 0 || (!context.explicit && prefix_len < MIN_WORD_PREFIX) {
+            return Vec::new();
+        }
+        let lower_prefix = prefix.to_lowercase();
+
+        let mut words = HashSet::new();
+        for tab in context.tabs.iter().filter(|tab| !tab.is_hex()) {
+            let text = tab.buffer.to_string();
+            for word in text.split(|c: char| !is_word_char(c)) {
+                if word.chars().count() >= MIN_WORD_LENGTH
+                    && word != prefix
+                    && !word.starts_with(|c: char| c.is_ascii_digit())
+                    && word.to_lowercase().starts_with(&lower_prefix)
+                {
+                    words.insert(word.to_string());
+                }
+            }
+        }
+
+        let mut words: Vec<String> = words.into_iter().collect();
+        // Exact-case matches first, then alphabetical
+        words.sort_by(|a, b| (!a.starts_with(prefix), a).cmp(&(!b.starts_with(prefix), b)));
+        words
+            .into_iter()
+            .map(|label| CompletionItem {
+                label,
+                kind: CompletionKind::Word,
+                replace_len: prefix_len,
+            })
+            .collect()
+    }
+}
+
+/// Files and folders for a path typed inside a string literal
+pub struct PathProvider;
+
+impl PathProvider {
+    /// Folder the typed directory part refers to
+    fn resolve_dir(dir: &str, document_path: Option<&Path>) -> Option<PathBuf> {
+        if let Some(rest) = dir.strip_prefix("~/") {
+            let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+            return Some(PathBuf::from(home).join(rest));
+        }
+        let path = Path::new(dir);
+        if path.is_absolute() || dir.starts_with('/') {
+            return Some(path.to_path_buf());
+        }
+        let base = match document_path.and_then(Path::parent) {
+            Some(parent) => parent.to_path_buf(),
+            None => std::env::current_dir().ok()?,
+        };
+        Some(base.join(path))
+    }
+}
+
+impl CompletionProvider for PathProvider {
+    fn name(&self) -> &str {
+        "paths"
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+
+    fn provide(&self, context: &CompletionContext) -> Vec<CompletionItem> {
+        let Some(typed) = context.string_prefix() else {
+            return Vec::new();
+        };
+        // Plain strings only turn into paths once they contain a separator
+        let looks_like_path = typed.contains('/') || (context.explicit && typed.starts_with('.'));
+        if !looks_like_path {
+            return Vec::new();
+        }
+
+        let (dir, name_prefix) = match typed.rfind('/') {
+            Some(index) => typed.split_at(index + 1),
+            None => ("", typed),
+        };
+        let Some(dir) = Self::resolve_dir(dir, context.document_path) else {
+            return Vec::new();
+        };
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            return Vec::new();
+        };
+
+        let show_hidden = name_prefix.starts_with('.');
+        let mut items: Vec<CompletionItem> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if !name.starts_with(name_prefix) || (name.starts_with('.') && !show_hidden) {
+                    return None;
+                }
+                let is_dir = entry.file_type().is_ok_and(|t| t.is_dir());
+                Some(CompletionItem {
+                    label: if is_dir { format!("{}/", name) } else { name },
+                    kind: if is_dir { CompletionKind::Folder } else { CompletionKind::File },
+                    replace_len: name_prefix.chars().count(),
+                })
+            })
+            .collect();
+        // Folders first
+        items.sort_by(|a, b| (a.kind != CompletionKind::Folder, &a.label).cmp(&(b.kind != CompletionKind::Folder, &b.label)));
+        items
+    }
+}
+
+/// Open suggestion list, tied to the tab it was opened in
+pub(crate) struct CompletionPopup {
+    pub tab_id: usize,
+    pub items: Vec<CompletionItem>,
+    pub selected: usize,
+}
+
+/// Registered providers, queried in priority order
+pub struct CompletionEngine {
+    providers: Vec<Box<dyn CompletionProvider>>,
+}
+
+impl CompletionEngine {
+    /// Engine without any providers
+    pub fn empty() -> Self {
+        Self { providers: Vec::new() }
+    }
+
+    pub fn register(&mut self, provider: Box<dyn CompletionProvider>) {
+        self.providers.push(provider);
+        self.providers.sort_by_key(|provider| std::cmp::Reverse(provider.priority()));
+    }
+
+    pub fn provider_names(&self) -> Vec<&str> {
+        self.providers.iter().map(|provider| provider.name()).collect()
+    }
+
+    /// Suggestions from every provider, highest priority first, without duplicates
+    pub fn complete(&self, context: &CompletionContext) -> Vec<CompletionItem> {
+        let mut seen = HashSet::new();
+        self.providers
+            .iter()
+            .flat_map(|provider| provider.provide(context))
+            .filter(|item| seen.insert(item.label.clone()))
+            .take(MAX_ITEMS)
+            .collect()
+    }
+}
+
+impl Default for CompletionEngine {
+    /// Word and path completion
+    fn default() -> Self {
+        let mut engine = Self::empty();
+        engine.register(Box::new(WordProvider));
+        engine.register(Box::new(PathProvider));
+        engine
+    }
+}
@@ -0,0 +1,186 @@
+use std::cell::Cell;
+use std::ops::Range;
+use std::rc::Rc;
+
+use mikoui::{current_theme, t};
+use skia_safe::{Canvas, Color, Font, Paint, Rect};
+
+use crate::zones::ZoneWidget;
+
+/// Key of the decorations and view zones a tab shows its conflicts with
+pub(crate) const CONFLICTS_KEY: &str = "conflicts";
+/// Height of the row of accept buttons above a conflict
+const LENS_HEIGHT: f32 = 20.0;
+/// Tint of the current side, green like added lines
+pub(crate) const CURRENT_COLOR: Color = Color::from_rgb(34, 197, 94);
+/// Tint of the incoming side, blue like modified lines
+pub(crate) const INCOMING_COLOR: Color = Color::from_rgb(59, 130, 246);
+
+/// A block git left in a file it couldn't merge, by the zero-based lines of its markers
+///
+/// `<<<<<<<` opens the current side, `|||||||` the common ancestor when merged with
+/// `merge.conflictStyle=diff3`, `=======` the incoming side, and `>>>>>>>` closes it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conflict {
+    pub start: usize,
+    pub base: Option<usize>,
+    pub separator: usize,
+    pub end: usize,
+}
+
+/// Which side of a conflict to keep
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    Current,
+    Incoming,
+    /// Current side first, then the incoming one
+    Both,
+}
+
+impl Resolution {
+    const ALL: [Resolution; 3] = [Resolution::Current, Resolution::Incoming, Resolution::Both];
+
+    fn label(&self) -> String {
+        match self {
+            Resolution::Current => t!("git.accept_current"),
+            Resolution::Incoming => t!("git.accept_incoming"),
+            Resolution::Both => t!("git.accept_both"),
+        }
+    }
+}
+
+impl Conflict {
+    /// Lines of the current side, between `<<<<<<<` and the base or `=======`
+    pub fn current(&self) -> Range<usize> {
+        self.start + 1..self.base.unwrap_or(self.separator)
+    }
+
+    /// Lines of the incoming side, between `=======` and `>>>>>>>`
+    pub fn incoming(&self) -> Range<usize> {
+        self.separator + 1..self.end
+    }
+
+    /// Lines that replace the whole block, markers included, in order
+    pub fn kept(&self, resolution: Resolution) -> Vec<Range<usize>> {
+        match resolution {
+            Resolution::Current => vec![self.current()],
+            Resolution::Incoming => vec![self.incoming()],
+            Resolution::Both => vec![self.current(), self.incoming()],
+        }
+    }
+}
+
+/// True if `line` is a conflict marker made of `marker`, alone or followed by a label
+fn is_marker(line: &str, marker: char) -> bool {
+    let line = line.trim_end_matches(['\r', '\n']);
+    let rest = line.trim_start_matches(marker);
+    line.len() - rest.len() == 7 && (rest.is_empty() || rest.starts_with(' '))
+}
+
+/// Complete conflict blocks in `lines`; markers without the rest of their block are ignored
+pub fn find_conflicts<'a>(lines: impl IntoIterator<Item = &'a str>) -> Vec<Conflict> {
+    let mut conflicts = Vec::new();
+    let mut open: Option<(usize, Option<usize>, Option<usize>)> = None;
+    for (index, line) in lines.into_iter().enumerate() {
+        if is_marker(line, '<') {
+            // A second opening marker starts over
+            open = Some((index, None, None));
+        } else if let Some((start, base, separator)) = open.as_mut() {
+            if separator.is_none() && base.is_none() && is_marker(line, '|') {
+                *base = Some(index);
+            } else if separator.is_none() && line.trim_end_matches(['\r', '\n']) == "=======" {
+                *separator = Some(index);
+            } else if let (Some(separator), true) = (*separator, is_marker(line, '>')) {
+                conflicts.push(Conflict { start: *start, base: *base, separator, end: index });
+                open = None;
+            }
+        }
+    }
+    conflicts
+}
+
+/// Conflicts found in a tab and the buffer version they were found in
+#[derive(Default)]
+pub(crate) struct Conflicts {
+    pub version: Option<u64>,
+    pub list: Vec<Conflict>,
+    /// Button clicked in one of the tab's lenses, by conflict index, until the editor applies it
+    pub clicked: Rc<Cell<Option<(usize, Resolution)>>>,
+}
+
+/// Row of accept buttons above a conflict, in the style of a code lens
+pub(crate) struct ConflictLens {
+    index: usize,
+    clicked: Rc<Cell<Option<(usize, Resolution)>>>,
+    /// Left and right edges of each button from the left of the zone, as last drawn
+    buttons: Cell<[(f32, f32); 3]>,
+}
+
+impl ConflictLens {
+    pub fn new(index: usize, clicked: Rc<Cell<Option<(usize, Resolution)>>>) -> Self {
+        Self { index, clicked, buttons: Cell::new([(0.0, 0.0); 3]) }
+    }
+}
+
+impl ZoneWidget for ConflictLens {
+    fn height(&self, _width: f32) -> f32 {
+        LENS_HEIGHT
+    }
+
+    fn draw(&self, canvas: &Canvas, rect: Rect, font: &Font) {
+        let theme = current_theme();
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_color(theme.muted_foreground);
+        let separator = " | ";
+        let separator_width = font.measure_str(separator, None).0;
+        let baseline = rect.top + LENS_HEIGHT / 2.0 + font.size() / 3.0;
+        let mut x = 10.0;
+        let mut buttons = [(0.0, 0.0); 3];
+        for (i, resolution) in Resolution::ALL.iter().enumerate() {
+            if i > 0 {
+                canvas.draw_str(separator, (rect.left + x, baseline), font, &paint);
+                x += separator_width;
+            }
+            let label = resolution.label();
+            let width = font.measure_str(&label, None).0;
+            canvas.draw_str(&label, (rect.left + x, baseline), font, &paint);
+            buttons[i] = (x, x + width);
+            x += width;
+        }
+        self.buttons.set(buttons);
+    }
+
+    fn on_click(&mut self, x: f32, _y: f32, rect: Rect) {
+        let x = x - rect.left;
+        let hit = self.buttons.get().iter().position(|&(left, right)| x >= left && x < right);
+        if let Some(i) = hit {
+            self.clicked.set(Some((self.index, Resolution::ALL[i])));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_complete_blocks() {
+        let text = "a\n<<<<<<< HEAD\nours\n=======\ntheirs\n>>>>>>> feature\nb\n<<<<<<< HEAD\nours\n||||||| base\nold\n=======\n>>>>>>> other\n<<<<<<< dangling\n";
+        let conflicts = find_conflicts(text.lines());
+        assert_eq!(conflicts, vec![
+            Conflict { start: 1, base: None, separator: 3, end: 5 },
+            Conflict { start: 7, base: Some(9), separator: 11, end: 12 },
+        ]);
+        // Seven is exact: longer runs are text, such as a Markdown rule
+        assert!(find_conflicts("<<<<<<<< x\n=======\n>>>>>>>\n".lines()).is_empty());
+    }
+
+    #[test]
+    fn keeps_the_chosen_sides() {
+        let conflict = Conflict { start: 7, base: Some(9), separator: 11, end: 14 };
+        assert_eq!(conflict.kept(Resolution::Current), vec![8..9]);
+        assert_eq!(conflict.kept(Resolution::Incoming), vec![12..14]);
+        assert_eq!(conflict.kept(Resolution::Both), vec![8..9, 12..14]);
+    }
+}
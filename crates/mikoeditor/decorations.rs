@@ -0,0 +1,309 @@
+use std::ops::Range;
+
+use skia_safe::Color;
+
+use crate::markers::Bias;
+
+/// How a decoration underlines its range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnderlineStyle {
+    Solid,
+    /// Squiggle, as used for diagnostics
+    Wavy,
+    Dotted,
+}
+
+/// Text drawn between characters without being part of the document, e.g. an inlay hint
+///
+/// It sits before the character at its decoration's start, pushing the rest of the line right.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InlineText {
+    pub text: String,
+    pub color: Color,
+    /// Pill drawn behind the text
+    pub background: Option<Color>,
+    /// Keep a space's width before the text
+    pub padding_left: bool,
+    /// Keep a space's width after the text
+    pub padding_right: bool,
+}
+
+impl InlineText {
+    pub fn new(text: impl Into<String>, color: Color) -> Self {
+        Self {
+            text: text.into(),
+            color,
+            background: None,
+            padding_left: false,
+            padding_right: false,
+        }
+    }
+
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    pub fn padding(mut self, left: bool, right: bool) -> Self {
+        self.padding_left = left;
+        self.padding_right = right;
+        self
+    }
+}
+
+/// Styling attached to a char range of a document
+///
+/// The range is anchored: it moves with edits before it and grows or shrinks with edits inside it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decoration {
+    /// Char offsets, end exclusive; an empty range marks a single position
+    pub range: Range<usize>,
+    pub background: Option<Color>,
+    /// The background spans the full width of the text area on each line rather than just the text
+    pub whole_line: bool,
+    pub underline: Option<(Color, UnderlineStyle)>,
+    /// Codicon drawn in the gutter next to the range's first line
+    pub gutter_icon: Option<(&'static str, Color)>,
+    /// Text drawn after the end of the range's last line, e.g. an inline hint
+    pub after_line: Option<(String, Color)>,
+    /// Text shown at the start of the range, between the characters
+    pub inline: Option<InlineText>,
+}
+
+impl Decoration {
+    pub fn new(range: Range<usize>) -> Self {
+        Self {
+            range: range.start..range.end.max(range.start),
+            background: None,
+            whole_line: false,
+            underline: None,
+            gutter_icon: None,
+            after_line: None,
+            inline: None,
+        }
+    }
+
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    pub fn whole_line(mut self) -> Self {
+        self.whole_line = true;
+        self
+    }
+
+    pub fn underline(mut self, color: Color, style: UnderlineStyle) -> Self {
+        self.underline = Some((color, style));
+        self
+    }
+
+    pub fn gutter_icon(mut self, icon: &'static str, color: Color) -> Self {
+        self.gutter_icon = Some((icon, color));
+        self
+    }
+
+    pub fn after_line(mut self, text: impl Into<String>, color: Color) -> Self {
+        self.after_line = Some((text.into(), color));
+        self
+    }
+
+    pub fn inline(mut self, text: InlineText) -> Self {
+        self.inline = Some(text);
+        self
+    }
+
+    /// True if the decoration covers any part of `start..end`, or sits inside it when empty
+    pub fn touches(&self, start: usize, end: usize) -> bool {
+        if self.range.is_empty() {
+            (start..=end).contains(&self.range.start)
+        } else {
+            self.range.start < end.max(start + 1) && self.range.end > start
+        }
+    }
+
+    /// Shift the anchors for `removed` chars at `start` replaced by `inserted` chars
+    /// Returns false once a non-empty range has been deleted entirely
+    fn apply_edit(&mut self, start: usize, removed: usize, inserted: usize) -> bool {
+        let was_empty = self.range.is_empty();
+        // Typing right before the range pushes it along; typing right after it doesn't extend it
+        let new_start = Bias::Right.map(self.range.start, start, removed, inserted);
+        let new_end = if was_empty {
+            new_start
+        } else {
+            Bias::Left.map(self.range.end, start, removed, inserted).max(new_start)
+        };
+        self.range = new_start..new_end;
+        was_empty || !self.range.is_empty()
+    }
+}
+
+/// Decorations of one document, grouped by the key of whoever added them
+#[derive(Debug, Clone, Default)]
+pub struct Decorations {
+    entries: Vec<(String, Decoration)>,
+}
+
+impl Decorations {
+    pub fn add(&mut self, key: &str, decoration: Decoration) {
+        self.entries.push((key.to_string(), decoration));
+    }
+
+    /// Replace everything under `key`
+    pub fn set(&mut self, key: &str, decorations: Vec<Decoration>) {
+        self.remove(key);
+        self.entries.extend(decorations.into_iter().map(|d| (key.to_string(), d)));
+    }
+
+    /// Drop everything under `key`; returns true if anything was removed
+    pub fn remove(&mut self, key: &str) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|(k, _)| k != key);
+        self.entries.len() != before
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Decoration> {
+        self.entries.iter().map(|(_, d)| d)
+    }
+
+    /// Decorations added under `key`
+    pub fn get<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a Decoration> {
+        self.entries.iter().filter(move |(k, _)| k == key).map(|(_, d)| d)
+    }
+
+    /// Decorations touching the char range `start..end`, e.g. one line
+    pub fn in_range(&self, start: usize, end: usize) -> impl Iterator<Item = &Decoration> {
+        self.iter().filter(move |d| d.touches(start, end))
+    }
+
+    /// Keep anchors in place after `removed` chars at `start` were replaced by `inserted` chars
+    pub(crate) fn apply_edit(&mut self, start: usize, removed: usize, inserted: usize) {
+        if removed == 0 && inserted == 0 {
+            return;
+        }
+        self.entries.retain_mut(|(_, d)| d.apply_edit(start, removed, inserted));
+    }
+
+    /// Move every anchor through `map` after the whole text was swapped, e.g. by a formatter
+    pub(crate) fn remap(&mut self, map: impl Fn(usize) -> usize) {
+        for (_, d) in &mut self.entries {
+            let start = map(d.range.start);
+            d.range = start..map(d.range.end).max(start);
+        }
+    }
+}
+
+/// Where inline text sits on one line, for mapping columns to x and back
+#[derive(Debug, Clone, Default)]
+pub(crate) struct InlineLayout {
+    /// Column, its x without inline text and the width of the text there, by column
+    spans: Vec<(usize, f32, f32)>,
+}
+
+impl InlineLayout {
+    pub(crate) fn new(mut spans: Vec<(usize, f32, f32)>) -> Self {
+        spans.sort_by_key(|&(column, _, _)| column);
+        Self { spans }
+    }
+
+    pub(crate) fn spans(&self) -> &[(usize, f32, f32)] {
+        &self.spans
+    }
+
+    /// How far right the caret at `column` moves; it stays in front of inline text at its column
+    pub(crate) fn caret_shift(&self, column: usize) -> f32 {
+        self.spans.iter().filter(|&&(at, _, _)| at < column).map(|&(_, _, width)| width).sum()
+    }
+
+    /// How far right the character at `column` moves, behind inline text at its column
+    pub(crate) fn char_shift(&self, column: usize) -> f32 {
+        self.spans.iter().filter(|&&(at, _, _)| at <= column).map(|&(_, _, width)| width).sum()
+    }
+
+    /// X a point in the line would have without the inline text; over inline text it snaps to its column
+    pub(crate) fn unshift(&self, x: f32) -> f32 {
+        let mut shift = 0.0;
+        for &(_, base, width) in &self.spans {
+            let start = base + shift;
+            if x < start {
+                break;
+            }
+            if x < start + width {
+                return base;
+            }
+            shift += width;
+        }
+        x - shift
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(decorations: &Decorations) -> Vec<Range<usize>> {
+        decorations.iter().map(|d| d.range.clone()).collect()
+    }
+
+    #[test]
+    fn anchors_follow_edits() {
+        let mut decorations = Decorations::default();
+        decorations.add("search", Decoration::new(10..15));
+        decorations.add("hint", Decoration::new(20..20));
+
+        // Before the range: both shift
+        decorations.apply_edit(0, 0, 3);
+        assert_eq!(ranges(&decorations), vec![13..18, 23..23]);
+
+        // Typing at either edge doesn't grow the range
+        decorations.apply_edit(13, 0, 1);
+        decorations.apply_edit(19, 0, 1);
+        assert_eq!(ranges(&decorations), vec![14..19, 25..25]);
+
+        // Inside the range: it grows and shrinks
+        decorations.apply_edit(16, 0, 4);
+        decorations.apply_edit(15, 2, 0);
+        assert_eq!(ranges(&decorations), vec![14..21, 27..27]);
+
+        // Deleting across the start trims it
+        decorations.apply_edit(12, 4, 0);
+        assert_eq!(ranges(&decorations), vec![12..17, 23..23]);
+    }
+
+    #[test]
+    fn inline_text_shifts_what_follows() {
+        // 10px wide columns, 30px of text at column 2 and 20px at column 4
+        let layout = InlineLayout::new(vec![(4, 40.0, 20.0), (2, 20.0, 30.0)]);
+        assert_eq!(layout.caret_shift(2), 0.0);
+        assert_eq!(layout.char_shift(2), 30.0);
+        assert_eq!(layout.caret_shift(5), 50.0);
+
+        // Before, on and after the first text
+        assert_eq!(layout.unshift(15.0), 15.0);
+        assert_eq!(layout.unshift(35.0), 20.0);
+        assert_eq!(layout.unshift(55.0), 25.0);
+        assert_eq!(layout.unshift(100.0), 50.0);
+    }
+
+    #[test]
+    fn deleted_ranges_are_dropped_by_key() {
+        let mut decorations = Decorations::default();
+        decorations.set("diagnostics", vec![Decoration::new(2..4), Decoration::new(8..9)]);
+        decorations.add("git", Decoration::new(0..1));
+
+        decorations.apply_edit(1, 4, 0);
+        assert_eq!(ranges(&decorations), vec![4..5, 0..1]);
+
+        assert!(decorations.remove("diagnostics"));
+        assert!(!decorations.remove("diagnostics"));
+        assert_eq!(ranges(&decorations), vec![0..1]);
+    }
+}
@@ -0,0 +1,90 @@
+use mikoui::{current_theme, with_alpha};
+use skia_safe::{Canvas, Font, Paint, PaintStyle, Rect};
+
+/// Pixels the mouse travels from the press before it drags the selection instead of clicking into it
+pub(crate) const DRAG_THRESHOLD: f32 = 4.0;
+/// Longest line of dragged text shown next to the pointer
+const GHOST_MAX_CHARS: usize = 40;
+const GHOST_PADDING: f32 = 6.0;
+
+/// A selection pressed on, which is dragged once the mouse moves
+pub(crate) struct TextDrag {
+    /// Id of the tab the text comes from, and its range there
+    pub tab_id: usize,
+    pub range: ((usize, usize), (usize, usize)),
+    pub text: String,
+    /// Position pressed on, where the cursor goes when the press turns out to be a click
+    pub press_position: (usize, usize),
+    pub press: (f32, f32),
+    pub pointer: (f32, f32),
+    /// The mouse moved far enough to make this a drag
+    pub active: bool,
+    /// Where the text would land in the active tab; `None` while the pointer is outside the text
+    pub drop: Option<(usize, usize)>,
+}
+
+impl TextDrag {
+    pub fn new(tab_id: usize, range: ((usize, usize), (usize, usize)), text: String, press_position: (usize, usize), press: (f32, f32)) -> Self {
+        Self { tab_id, range, text, press_position, press, pointer: press, active: false, drop: None }
+    }
+
+    /// Follow the pointer; becomes active past the threshold
+    pub fn move_to(&mut self, x: f32, y: f32) {
+        self.pointer = (x, y);
+        if !self.active {
+            let (dx, dy) = (x - self.press.0, y - self.press.1);
+            self.active = dx * dx + dy * dy >= DRAG_THRESHOLD * DRAG_THRESHOLD;
+        }
+    }
+}
+
+/// Label of the ghost: the first line, shortened, with an ellipsis when anything was left out
+fn ghost_label(text: &str) -> String {
+    let first = text.lines().next().unwrap_or("").trim();
+    let mut label: String = first.chars().take(GHOST_MAX_CHARS).collect();
+    if first.chars().count() > GHOST_MAX_CHARS || text.trim_end().contains('\n') {
+        label.push('…');
+    }
+    label
+}
+
+/// Translucent copy of dragged text just below and right of the pointer
+pub(crate) fn draw_ghost(canvas: &Canvas, text: &str, (x, y): (f32, f32), font: &Font) {
+    let theme = current_theme();
+    let label = ghost_label(text);
+    let mut text_paint = Paint::default();
+    text_paint.set_anti_alias(true);
+    text_paint.set_color(with_alpha(theme.popover_foreground, 200));
+    let width = font.measure_str(&label, Some(&text_paint)).0;
+    let height = font.size() + GHOST_PADDING * 2.0;
+    let rect = Rect::from_xywh(x + 12.0, y + 12.0, width + GHOST_PADDING * 2.0, height);
+
+    let mut background = Paint::default();
+    background.set_anti_alias(true);
+    background.set_color(with_alpha(theme.popover, 200));
+    canvas.draw_round_rect(rect, 4.0, 4.0, &background);
+    let mut border = Paint::default();
+    border.set_anti_alias(true);
+    border.set_style(PaintStyle::Stroke);
+    border.set_color(with_alpha(theme.border, 200));
+    canvas.draw_round_rect(rect, 4.0, 4.0, &border);
+    canvas.draw_str(&label, (rect.left + GHOST_PADDING, rect.bottom - GHOST_PADDING - 2.0), font, &text_paint);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ghost_shows_the_first_line() {
+        assert_eq!(ghost_label("let x = 1;"), "let x = 1;");
+        assert_eq!(ghost_label("  fn main() {\n}\n"), "fn main() {…");
+        assert_eq!(ghost_label(&"a".repeat(50)), format!("{}…", "a".repeat(40)));
+
+        let mut drag = TextDrag::new(0, ((0, 0), (0, 3)), "one".to_string(), (0, 1), (10.0, 10.0));
+        drag.move_to(12.0, 11.0);
+        assert!(!drag.active);
+        drag.move_to(14.0, 10.0);
+        assert!(drag.active);
+    }
+}
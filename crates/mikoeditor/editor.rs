@@ -1,8 +1,60 @@
-use crate::tab::{EditorTab, TabManager};
+use crate::annotations::{AnnotationKind, Annotations, RulerLane};
+use crate::autosave::{AutoSave, AutoSaveMode};
+use crate::command::EditorCommand;
+use crate::buffer::SaveOptions;
+use crate::completion::{is_word_char, CompletionContext, CompletionEngine, CompletionKind, CompletionPopup};
+use crate::decorations::{Decoration, Decorations, InlineLayout, InlineText, UnderlineStyle};
+use crate::dragtext::{draw_ghost, TextDrag};
+use crate::encoding::{Encoding, LineEnding};
+use crate::format::{map_offset, Formatter};
+use crate::hex::{HexEditor, BYTES_PER_ROW};
+use crate::hover::{parse_blocks, HoverContext, HoverEngine, HoverLayout, HoverPopup, LineKind, RunStyle, Style};
+use crate::indent::IndentStyle;
+use crate::markers::Bias;
+use crate::peek::PeekView;
+use crate::preview::{Preview, PreviewContent};
+use crate::language::detect_language;
+use crate::tab::{BracketPair, EditorTab, TabManager};
 use crate::tabbar::TabBar;
-use crate::syntax::TokenType;
-use skia_safe::{Canvas, Color, Font, Paint, Rect};
-use mikoui::{current_theme, with_alpha};
+use crate::todos::DEFAULT_TODO_KEYWORDS;
+use crate::syntax::{DocumentSymbol, Scope, TokenType};
+use crate::zones::LineLayout;
+use skia_safe::{Canvas, Color, Font, Paint, PaintStyle, Rect};
+use std::time::{Duration, Instant};
+use mikoui::{
+    animation_rate, contains_rtl, current_theme, reveal_offset, surface, with_alpha, BidiLine, HoverCard, HoverCardSide, Icon, IconSize, Orientation, Scrollbar,
+    SmoothScroll, TextDirection, Theme, Widget,
+};
+
+/// Width of the overview ruler along the right edge, shared with the scrollbar track
+const RULER_WIDTH: f32 = 10.0;
+/// How far (px) a click on the ruler may miss a mark and still jump to it
+const RULER_HIT_SLOP: f32 = 3.0;
+/// Width of the gutter's left edge, under the gutter icons, that toggles bookmarks when clicked
+const BOOKMARK_STRIP_WIDTH: f32 = 18.0;
+/// Lines kept visible above and below a line scrolled into view
+const REVEAL_PADDING_LINES: f32 = 3.0;
+/// Space between inline text and the edges of its pill
+const INLINE_TEXT_INSET: f32 = 4.0;
+/// Most enclosing headers sticky scroll pins at once
+const STICKY_SCROLL_MAX_ROWS: usize = 5;
+/// Suggestions visible in the completion popup before it scrolls
+const COMPLETION_ROWS: usize = 8;
+/// Longest pause between the clicks of a double-click that selects a word
+const DOUBLE_CLICK_TIME: Duration = Duration::from_millis(400);
+const COMPLETION_MIN_WIDTH: f32 = 220.0;
+const COMPLETION_PADDING: f32 = 8.0;
+/// Widest a hover card gets before its text wraps
+const HOVER_MAX_WIDTH: f32 = 500.0;
+/// Tallest a hover card gets before its content scrolls
+const HOVER_MAX_HEIGHT: f32 = 300.0;
+const HOVER_PADDING: f32 = 8.0;
+/// Widest the text of a Markdown preview gets, and the least room left around it and around images
+const PREVIEW_MAX_WIDTH: f32 = 860.0;
+const PREVIEW_MARGIN: f32 = 24.0;
+/// Lines of the peeked file a peek view shows, within a third of the editor's height
+const PEEK_MIN_LINES: f32 = 6.0;
+const PEEK_MAX_LINES: f32 = 16.0;
 
 pub struct Editor {
     tab_manager: TabManager,
@@ -16,6 +68,45 @@ pub struct Editor {
     cursor_blink_time: f32,
     show_cursor: bool,
     is_selecting: bool,
+    /// Selection pressed on, and dragged once the mouse moves
+    text_drag: Option<TextDrag>,
+    /// When and where the text was last clicked, to tell a double-click
+    last_click: Option<(Instant, (usize, usize))>,
+    /// Bracket next to the cursor and its partner; bracket matching scans too far to redo every frame
+    bracket_match: Option<BracketPair>,
+    /// Tab id, buffer version and cursor `bracket_match` was found for
+    bracket_match_key: Option<(usize, u64, (usize, usize))>,
+    /// Active tab's id at the last animation frame, to notice switches
+    shown_tab: Option<usize>,
+    /// Opacity of the content while a tab switched to fades in
+    switch_fade: f32,
+    render_whitespace: bool,
+    highlight_trailing_whitespace: bool,
+    /// Pin the headers of the scopes the top line is in
+    sticky_scroll: bool,
+    /// Show inline text decorations such as inlay hints
+    inlay_hints: bool,
+    /// Columns vertical ruler lines are drawn at, unless the document's language has its own
+    rulers: Vec<usize>,
+    language_rulers: std::collections::HashMap<String, Vec<usize>>,
+    /// Words that mark a comment as a to-do, e.g. TODO and FIXME
+    todo_keywords: Vec<String>,
+    /// Mirrors the active tab's `scroll_offset`
+    scrollbar: Scrollbar,
+    /// Glides the active tab's `scroll_offset` to where wheel, reveal and go to line send it
+    smooth_scroll: SmoothScroll,
+    /// Id of the tab `smooth_scroll` moves
+    scrolling_tab: Option<usize>,
+    completion_engine: CompletionEngine,
+    completion: Option<CompletionPopup>,
+    hover_engine: HoverEngine,
+    hover: Option<HoverPopup>,
+    /// Clock from the last `update_animation`, which hover cards are timed by
+    clock: f32,
+    peek: Option<Box<PeekView>>,
+    /// Edits are ignored, as in a read-only peek view
+    read_only: bool,
+    auto_save: AutoSave,
 }
 
 impl Editor {
@@ -34,6 +125,30 @@ impl Editor {
             cursor_blink_time: 0.0,
             show_cursor: true,
             is_selecting: false,
+            text_drag: None,
+            last_click: None,
+            bracket_match: None,
+            bracket_match_key: None,
+            shown_tab: None,
+            switch_fade: 1.0,
+            render_whitespace: false,
+            highlight_trailing_whitespace: true,
+            sticky_scroll: true,
+            inlay_hints: true,
+            rulers: Vec::new(),
+            language_rulers: std::collections::HashMap::new(),
+            todo_keywords: DEFAULT_TODO_KEYWORDS.iter().map(|keyword| keyword.to_string()).collect(),
+            scrollbar: Scrollbar::new(Orientation::Vertical),
+            smooth_scroll: SmoothScroll::default(),
+            scrolling_tab: None,
+            completion_engine: CompletionEngine::default(),
+            completion: None,
+            hover_engine: HoverEngine::default(),
+            hover: None,
+            clock: 0.0,
+            peek: None,
+            read_only: false,
+            auto_save: AutoSave::default(),
         }
     }
     
@@ -50,6 +165,12 @@ impl Editor {
         Ok(())
     }
     
+    /// Open an image or Markdown file rendered, in a read-only tab that follows changes to the file
+    pub fn open_preview(&mut self, path: std::path::PathBuf) -> std::io::Result<()> {
+        self.tab_manager.add_preview_tab(path)?;
+        Ok(())
+    }
+    
     pub fn new_tab(&mut self) {
         self.tab_manager.add_tab();
     }
@@ -58,6 +179,11 @@ impl Editor {
         self.tab_manager.close_active_tab();
     }
     
+    /// Index of the tab under a point in the tab bar
+    pub fn tab_at(&self, x: f32, y: f32) -> Option<usize> {
+        self.tab_bar.get_clicked_tab(x, y, &self.tab_manager)
+    }
+    
     pub fn next_tab(&mut self) {
         self.tab_manager.next_tab();
     }
@@ -78,34 +204,64 @@ impl Editor {
         // Background
         let theme = current_theme();
         let mut bg_paint = Paint::default();
-        bg_paint.set_color(theme.background);
+        bg_paint.set_color(surface(theme.background));
         bg_paint.set_anti_alias(true);
         canvas.draw_rect(
             Rect::from_xywh(self.x, content_y, self.width, content_height),
             &bg_paint,
         );
         
+        // A tab just switched to fades in over the background
+        let fading = self.switch_fade < 1.0;
+        if fading {
+            let bounds = Rect::from_xywh(self.x, content_y, self.width, content_height);
+            canvas.save_layer_alpha_f(bounds, self.switch_fade);
+        }
+        self.draw_content(canvas, ui_font, mono_font, content_y, content_height);
+        if fading {
+            canvas.restore();
+        }
+        self.draw_hover(canvas, mono_font);
+    }
+    
+    /// Text, gutter, cursor and overlays of the active tab
+    fn draw_content(&self, canvas: &Canvas, ui_font: &Font, mono_font: &Font, content_y: f32, content_height: f32) {
+        let theme = current_theme();
+        
+        // Hex editor tabs have their own layout
+        if let Some(hex) = self.tab_manager.get_active_tab().and_then(|tab| tab.hex.as_ref()) {
+            self.draw_hex(canvas, hex, mono_font, content_y);
+            return;
+        }
+        if let Some(preview) = self.tab_manager.get_active_tab().and_then(|tab| tab.preview.as_ref()) {
+            self.draw_preview(canvas, preview, mono_font, content_y, content_height);
+            return;
+        }
+        
         // Get active tab
         if let Some(tab) = self.tab_manager.get_active_tab() {
             // Gutter background
             let mut gutter_paint = Paint::default();
-            gutter_paint.set_color(theme.card);
+            gutter_paint.set_color(surface(theme.card));
             gutter_paint.set_anti_alias(true);
             canvas.draw_rect(
                 Rect::from_xywh(self.x, content_y, self.gutter_width, content_height),
                 &gutter_paint,
             );
             
+            self.draw_rulers(canvas, tab, mono_font, content_y, content_height);
+            
             // Draw line numbers and text
-            let visible_lines = (content_height / self.line_height).ceil() as usize;
-            let start_line = (tab.scroll_offset / self.line_height) as usize;
-            let end_line = (start_line + visible_lines).min(tab.buffer.len_lines());
+            let layout = self.line_layout(tab);
+            let start_line = layout.line_at(tab.scroll_offset);
+            let end_line = (layout.line_at(tab.scroll_offset + content_height) + 1).min(tab.buffer.len_lines());
             
             // Get syntax highlights
             let highlights = tab.highlighter.get_highlights(&tab.buffer.to_string());
             
             for line_idx in start_line..end_line {
-                let y_pos = content_y + (line_idx as f32 * self.line_height) - tab.scroll_offset + 17.0;
+                let y_pos = content_y + layout.line_top(line_idx) - tab.scroll_offset + 17.0;
+                let inline = self.inline_layout(tab, line_idx, mono_font);
                 
                 // Current line highlight
                 if line_idx == tab.cursor_line {
@@ -151,28 +307,69 @@ impl Editor {
                                 (0, line_chars.len())
                             };
                             
-                            // Calculate selection rectangle
-                            let text_before: String = line_chars.iter().take(sel_start_in_line).collect();
-                            let selected_text: String = line_chars.iter()
-                                .skip(sel_start_in_line)
-                                .take(sel_end_in_line - sel_start_in_line)
-                                .collect();
-                            
-                            let start_x = text_x + mono_font.measure_str(&text_before, None).0;
-                            let sel_width = mono_font.measure_str(&selected_text, None).0;
+                            // Calculate selection rectangles; right-to-left runs can split the range on screen
+                            let line_text = line.trim_end_matches('\n');
+                            let spans = if contains_rtl(line_text) {
+                                BidiLine::new(line_text, Some(TextDirection::Ltr))
+                                    .selection_spans(sel_start_in_line, sel_end_in_line, mono_font)
+                            } else {
+                                let text_before: String = line_chars.iter().take(sel_start_in_line).collect();
+                                let selected_text: String = line_chars.iter()
+                                    .skip(sel_start_in_line)
+                                    .take(sel_end_in_line - sel_start_in_line)
+                                    .collect();
+                                let start_shift = inline.caret_shift(sel_start_in_line);
+                                vec![(
+                                    mono_font.measure_str(&text_before, None).0 + start_shift,
+                                    mono_font.measure_str(&selected_text, None).0 + inline.caret_shift(sel_end_in_line) - start_shift,
+                                )]
+                            };
                             
                             // Draw selection background
                             let mut sel_paint = Paint::default();
                             sel_paint.set_color(with_alpha(theme.primary, 80));
                             sel_paint.set_anti_alias(true);
+                            for (start_x, sel_width) in spans {
+                                canvas.draw_rect(
+                                    Rect::from_xywh(text_x + start_x, y_pos - 15.0, sel_width, self.line_height),
+                                    &sel_paint,
+                                );
+                            }
+                        }
+                    }
+                }
+                
+                // Box selection highlight and per-row cursors
+                if let Some((first_line, last_line, start_col, end_col)) = tab.column_selection() {
+                    if line_idx >= first_line && line_idx <= last_line {
+                        let line = tab.buffer.line(line_idx).unwrap_or_default();
+                        let text_x = self.x + self.gutter_width + 10.0;
+                        let start_x = text_x + Self::column_offset(&line, start_col, mono_font) + inline.caret_shift(start_col);
+                        let end_x = text_x + Self::column_offset(&line, end_col, mono_font) + inline.caret_shift(end_col);
+                        
+                        let mut sel_paint = Paint::default();
+                        sel_paint.set_color(with_alpha(theme.primary, 80));
+                        sel_paint.set_anti_alias(true);
+                        canvas.draw_rect(
+                            Rect::from_xywh(start_x, y_pos - 15.0, end_x - start_x, self.line_height),
+                            &sel_paint,
+                        );
+                        
+                        if self.show_cursor && line_idx != tab.cursor_line {
+                            let cursor_x = text_x + Self::column_offset(&line, tab.cursor_column, mono_font) + inline.caret_shift(tab.cursor_column);
+                            let mut cursor_paint = Paint::default();
+                            cursor_paint.set_color(theme.foreground);
+                            cursor_paint.set_anti_alias(true);
                             canvas.draw_rect(
-                                Rect::from_xywh(start_x, y_pos - 15.0, sel_width, self.line_height),
-                                &sel_paint,
+                                Rect::from_xywh(cursor_x, y_pos - 15.0, 2.0, self.line_height - 4.0),
+                                &cursor_paint,
                             );
                         }
                     }
                 }
                 
+                self.draw_decoration_backgrounds(canvas, tab, line_idx, &inline, y_pos, mono_font);
+                
                 // Line number
                 let line_num = format!("{}", line_idx + 1);
                 let line_num_width = mono_font.measure_str(&line_num, None).0;
@@ -186,6 +383,7 @@ impl Editor {
                 });
                 line_num_paint.set_anti_alias(true);
                 canvas.draw_str(&line_num, (line_num_x, y_pos), mono_font, &line_num_paint);
+                self.draw_gutter_annotations(canvas, &tab.annotations, line_idx, y_pos - 15.0);
                 
                 // Line text with syntax highlighting
                 if let Some(mut line_text) = tab.buffer.line(line_idx) {
@@ -193,76 +391,43 @@ impl Editor {
                     line_text = line_text.trim_end_matches('\n').trim_end_matches('\r').to_string();
                     
                     let text_x = self.x + self.gutter_width + 10.0;
+                    let bidi = contains_rtl(&line_text);
+                    self.draw_line_text(canvas, mono_font, tab, line_idx, &highlights, (text_x, y_pos));
                     
-                    // Calculate line start byte offset
-                    let mut line_start_byte = 0;
-                    for i in 0..line_idx {
-                        if let Some(l) = tab.buffer.line(i) {
-                            line_start_byte += l.as_bytes().len();
-                        }
-                    }
-                    let line_end_byte = line_start_byte + line_text.as_bytes().len();
-                    
-                    // Draw text with syntax highlighting
-                    let mut current_x = text_x;
-                    let mut last_pos = 0;
-                    
-                    for (start, end, token_type) in &highlights {
-                        // Check if this highlight is in the current line
-                        if *end <= line_start_byte || *start >= line_end_byte {
-                            continue;
-                        }
-                        
-                        let highlight_start = (*start).saturating_sub(line_start_byte);
-                        let highlight_end = (*end - line_start_byte).min(line_text.len());
-                        
-                        // Draw text before highlight
-                        if last_pos < highlight_start {
-                            let text_before = &line_text[last_pos..highlight_start];
-                            let mut text_paint = Paint::default();
-                            text_paint.set_color(theme.foreground);
-                            text_paint.set_anti_alias(true);
-                            canvas.draw_str(text_before, (current_x, y_pos), mono_font, &text_paint);
-                            current_x += mono_font.measure_str(text_before, None).0;
-                        }
-                        
-                        // Draw highlighted text
-                        if highlight_start < highlight_end && highlight_end <= line_text.len() {
-                            let highlighted_text = &line_text[highlight_start..highlight_end];
-                            let mut highlight_paint = Paint::default();
-                            highlight_paint.set_color(self.get_token_color(*token_type));
-                            highlight_paint.set_anti_alias(true);
-                            canvas.draw_str(highlighted_text, (current_x, y_pos), mono_font, &highlight_paint);
-                            current_x += mono_font.measure_str(highlighted_text, None).0;
-                            last_pos = highlight_end;
-                        }
-                    }
-                    
-                    // Draw remaining text
-                    if last_pos < line_text.len() {
-                        let remaining_text = &line_text[last_pos..];
-                        let mut text_paint = Paint::default();
-                        text_paint.set_color(theme.foreground);
-                        text_paint.set_anti_alias(true);
-                        canvas.draw_str(remaining_text, (current_x, y_pos), mono_font, &text_paint);
+                    // Whitespace markers and trailing whitespace
+                    let trailing_start = line_text.trim_end_matches([' ', '\t']).chars().count();
+                    let typing_at_end = line_idx == tab.cursor_line && tab.cursor_column >= trailing_start;
+                    let highlight_trailing = self.highlight_trailing_whitespace && !typing_at_end;
+                    // Markers follow logical order, which only matches the screen without RTL runs
+                    if !bidi && (self.render_whitespace || highlight_trailing) {
+                        self.draw_whitespace(canvas, mono_font, &line_text, &inline, (text_x, y_pos), highlight_trailing);
                     }
                 }
+                self.draw_decoration_overlays(canvas, tab, line_idx, &inline, y_pos, mono_font);
+            }
+            
+            // Bracket next to the cursor and its partner
+            if let Some((bracket, partner)) = self.bracket_match {
+                let mut bracket_paint = Paint::default();
+                bracket_paint.set_color(with_alpha(theme.foreground, 110));
+                bracket_paint.set_style(PaintStyle::Stroke);
+                bracket_paint.set_anti_alias(true);
+                for (line, column) in [bracket, partner].into_iter().filter(|&(line, _)| line >= start_line && line < end_line) {
+                    let top = content_y + layout.line_top(line) - tab.scroll_offset + 2.0;
+                    let text_x = self.x + self.gutter_width + 10.0;
+                    let left = text_x + self.caret_offset(tab, line, column, mono_font);
+                    let right = text_x + self.caret_offset(tab, line, column + 1, mono_font);
+                    canvas.draw_rect(Rect::from_ltrb(left, top, right, top + self.line_height - 4.0), &bracket_paint);
+                }
             }
             
             // Draw cursor with blink
             if self.show_cursor && tab.cursor_line >= start_line && tab.cursor_line < end_line {
-                let cursor_y = content_y + (tab.cursor_line as f32 * self.line_height) - tab.scroll_offset + 2.0;
+                let cursor_y = content_y + layout.line_top(tab.cursor_line) - tab.scroll_offset + 2.0;
                 
                 // Calculate cursor X position based on actual text width
-                let mut cursor_x = self.x + self.gutter_width + 10.0;
-                if let Some(line) = tab.buffer.line(tab.cursor_line) {
-                    let line_char_count = line.chars().count();
-                    if tab.cursor_column > 0 && tab.cursor_column <= line_char_count {
-                        // Get text before cursor by character count, not byte index
-                        let text_before_cursor: String = line.chars().take(tab.cursor_column).collect();
-                        cursor_x += mono_font.measure_str(&text_before_cursor, None).0;
-                    }
-                }
+                // Box selection columns can lie past the end of the line
+                let cursor_x = self.x + self.gutter_width + 10.0 + self.caret_offset(tab, tab.cursor_line, tab.cursor_column, mono_font);
                 
                 let mut cursor_paint = Paint::default();
                 cursor_paint.set_color(theme.foreground);
@@ -272,36 +437,2109 @@ impl Editor {
                     &cursor_paint,
                 );
             }
+            
+            // Where dragged text would land
+            if let Some((line, column)) = self.text_drag.as_ref().and_then(|drag| drag.drop) {
+                if line >= start_line && line < end_line {
+                    let caret_y = content_y + layout.line_top(line) - tab.scroll_offset + 2.0;
+                    let caret_x = self.x + self.gutter_width + 10.0 + self.caret_offset(tab, line, column, mono_font);
+                    let mut caret_paint = Paint::default();
+                    caret_paint.set_color(theme.primary);
+                    caret_paint.set_anti_alias(true);
+                    canvas.draw_rect(Rect::from_xywh(caret_x, caret_y, 2.0, self.line_height - 4.0), &caret_paint);
+                }
+            }
+            
+            self.draw_zones(canvas, tab, ui_font, content_y);
+            self.draw_peek(canvas, ui_font, mono_font, content_y);
+            self.draw_sticky_scroll(canvas, tab, mono_font, &highlights, content_y);
+            self.draw_overview_ruler(canvas, tab);
+            self.scrollbar.paint(canvas);
+            self.draw_completion(canvas, tab, mono_font, content_y);
         }
     }
     
-    /// Get current editor info for status bar
-    pub fn get_editor_info(&self) -> Option<(String, usize, usize)> {
-        if let Some(tab) = self.tab_manager.get_active_tab() {
-            Some((
-                tab.get_language_display(),
-                tab.cursor_line + 1,
-                tab.cursor_column + 1,
-            ))
-        } else {
-            None
+    /// Faint vertical lines at the configured columns, behind the text
+    fn draw_rulers(&self, canvas: &Canvas, tab: &EditorTab, mono_font: &Font, content_y: f32, content_height: f32) {
+        let rulers = tab.buffer.language()
+            .and_then(|language| self.language_rulers.get(language))
+            .unwrap_or(&self.rulers);
+        if rulers.is_empty() {
+            return;
+        }
+        let theme = current_theme();
+        let char_width = mono_font.measure_str("0", None).0;
+        let text_x = self.x + self.gutter_width + 10.0;
+        
+        let mut paint = Paint::default();
+        paint.set_color(with_alpha(theme.muted_foreground, 40));
+        for &column in rulers {
+            let x = (text_x + column as f32 * char_width).round();
+            if x < self.x + self.width {
+                canvas.draw_rect(Rect::from_xywh(x, content_y, 1.0, content_height), &paint);
+            }
         }
     }
     
-    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
-        self.x = x;
-        self.y = y;
-        self.width = width;
-        self.height = height;
-        self.tab_bar.set_bounds(x, y, width);
+    /// Suggestion list under the cursor, flipped above it near the bottom edge
+    fn draw_completion(&self, canvas: &Canvas, tab: &EditorTab, mono_font: &Font, content_y: f32) {
+        let Some(popup) = self.completion.as_ref().filter(|popup| popup.tab_id == tab.id) else {
+            return;
+        };
+        let theme = current_theme();
+        
+        // Line up the labels with the text they replace
+        let anchor_column = tab.cursor_column.saturating_sub(popup.items[popup.selected].replace_len);
+        let x = self.x + self.gutter_width + 10.0 + self.caret_offset(tab, tab.cursor_line, anchor_column, mono_font) - COMPLETION_PADDING;
+        let line_top = content_y + self.line_layout(tab).line_top(tab.cursor_line) - tab.scroll_offset;
+        
+        let visible = popup.items.len().min(COMPLETION_ROWS);
+        let first = popup.selected.saturating_sub(visible - 1);
+        let label_width = popup.items
+            .iter()
+            .map(|item| mono_font.measure_str(&item.label, None).0)
+            .fold(0.0, f32::max);
+        let kind_width = mono_font.measure_str(CompletionKind::Folder.label(), None).0;
+        let width = (label_width + kind_width + COMPLETION_PADDING * 4.0).max(COMPLETION_MIN_WIDTH);
+        let height = visible as f32 * self.line_height;
+        let below = line_top + self.line_height;
+        let top = if below + height > self.y + self.height { line_top - height } else { below };
+        let rect = Rect::from_xywh(x, top, width, height);
+        
+        let mut bg_paint = Paint::default();
+        bg_paint.set_anti_alias(true);
+        bg_paint.set_color(theme.popover);
+        canvas.draw_round_rect(rect, Theme::RADIUS_SM, Theme::RADIUS_SM, &bg_paint);
+        
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        for (row, item) in popup.items[first..first + visible].iter().enumerate() {
+            let row_top = top + row as f32 * self.line_height;
+            if first + row == popup.selected {
+                let mut selected_paint = Paint::default();
+                selected_paint.set_color(with_alpha(theme.primary, 60));
+                canvas.draw_rect(Rect::from_xywh(x, row_top, width, self.line_height), &selected_paint);
+            }
+            
+            text_paint.set_color(theme.popover_foreground);
+            canvas.draw_str(&item.label, (x + COMPLETION_PADDING, row_top + 15.0), mono_font, &text_paint);
+            
+            let kind = item.kind.label();
+            let kind_x = rect.right - COMPLETION_PADDING - mono_font.measure_str(kind, None).0;
+            text_paint.set_color(theme.muted_foreground);
+            canvas.draw_str(kind, (kind_x, row_top + 15.0), mono_font, &text_paint);
+        }
+        
+        let mut border_paint = Paint::default();
+        border_paint.set_anti_alias(true);
+        border_paint.set_style(skia_safe::PaintStyle::Stroke);
+        border_paint.set_stroke_width(1.0);
+        border_paint.set_color(theme.border);
+        canvas.draw_round_rect(rect.with_inset((0.5, 0.5)), Theme::RADIUS_SM, Theme::RADIUS_SM, &border_paint);
     }
     
-    pub fn update_hover(&mut self, x: f32, y: f32) {
-        self.tab_bar.update_hover(x, y, &self.tab_manager);
+    /// Providers behind the completion popup, for registering more
+    pub fn completion_engine_mut(&mut self) -> &mut CompletionEngine {
+        &mut self.completion_engine
     }
     
-    pub fn update_animation(&mut self, elapsed: f32) {
+    /// Open the completion popup at the cursor, even for a short prefix (Ctrl+Space)
+    pub fn trigger_completion(&mut self) {
+        self.update_completion(true);
+    }
+    
+    /// Re-query providers at the cursor; closes the popup when nothing matches
+    fn update_completion(&mut self, explicit: bool) {
+        let Some(tab) = self.tab_manager
+            .get_active_tab()
+            .filter(|tab| tab.hex.is_none() && tab.column_anchor.is_none())
+        else {
+            self.completion = None;
+            return;
+        };
+        
+        let line = tab.buffer.line(tab.cursor_line).unwrap_or_default();
+        let line_before: String = line.chars().take(tab.cursor_column).collect();
+        let context = CompletionContext {
+            line_before: &line_before,
+            document_path: tab.buffer.file_path().map(|path| path.as_path()),
+            tabs: self.tab_manager.tabs(),
+            explicit,
+        };
+        let items = self.completion_engine.complete(&context);
+        let tab_id = tab.id;
+        self.completion = (!items.is_empty()).then_some(CompletionPopup { tab_id, items, selected: 0 });
+    }
+    
+    pub fn is_completion_visible(&self) -> bool {
+        let active_id = self.tab_manager.get_active_tab().map(|tab| tab.id);
+        self.completion.as_ref().is_some_and(|popup| Some(popup.tab_id) == active_id)
+    }
+    
+    /// Up/Down pick, Enter/Tab accept, Escape closes; returns true if the popup took the key
+    pub fn handle_completion_key(&mut self, key: &str) -> bool {
+        if !self.is_completion_visible() {
+            return false;
+        }
+        let Some(popup) = self.completion.as_mut() else {
+            return false;
+        };
+        
+        let count = popup.items.len();
+        match key {
+            "ArrowDown" => popup.selected = (popup.selected + 1) % count,
+            "ArrowUp" => popup.selected = (popup.selected + count - 1) % count,
+            "Enter" | "Tab" => self.accept_completion(),
+            "Escape" => self.completion = None,
+            _ => return false,
+        }
+        true
+    }
+    
+    /// Replace the typed prefix with the selected suggestion
+    fn accept_completion(&mut self) {
+        let Some(mut popup) = self.completion.take() else {
+            return;
+        };
+        let item = popup.items.swap_remove(popup.selected);
+        
+        if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            let replace_len = item.replace_len.min(tab.cursor_column);
+            let char_idx = tab.buffer.line_to_char(tab.cursor_line) + tab.cursor_column;
+            tab.buffer.remove(char_idx - replace_len, char_idx);
+            tab.buffer.insert(char_idx - replace_len, &item.label);
+            tab.cursor_column = tab.cursor_column - replace_len + item.label.chars().count();
+            tab.highlighter.parse(&tab.buffer.to_string());
+        }
+        
+        // Keep browsing into an accepted folder
+        if item.kind == CompletionKind::Folder {
+            self.update_completion(false);
+        }
+        self.cursor_blink_time = 0.0;
+        self.show_cursor = true;
+    }
+    
+    /// Providers behind hover cards, for registering some
+    pub fn hover_engine_mut(&mut self) -> &mut HoverEngine {
+        &mut self.hover_engine
+    }
+    
+    /// Line, char columns and bounds of the word under a point in the text
+    fn word_at(&self, x: f32, y: f32, mono_font: &Font) -> Option<(usize, usize, usize, Rect)> {
+        let tab = self.tab_manager.get_active_tab().filter(|tab| !tab.is_hex())?;
+        if !self.is_over_editor_content(x, y) || self.sticky_row_at(x, y).is_some() {
+            return None;
+        }
+        let content_y = self.y + self.tab_bar.height();
+        let layout = self.line_layout(tab);
+        let line = layout.line_at(y - content_y + tab.scroll_offset);
+        let top = content_y + layout.line_top(line) - tab.scroll_offset;
+        if y >= top + self.line_height {
+            return None;
+        }
+        let text = tab.buffer.line(line)?;
+        let chars: Vec<char> = text.trim_end_matches(['\n', '\r']).chars().collect();
+        let (_, column) = self.column_position_at(x, y, mono_font)?;
+        let column = column.min(chars.len());
+        let config = tab.language_config();
+        let start = column - chars[..column].iter().rev().take_while(|c| config.is_word_char(**c)).count();
+        let end = column + chars[column..].iter().take_while(|c| config.is_word_char(**c)).count();
+        
+        // The caret nearest the pointer may be at the edge of a word the pointer is beside
+        let inline = self.inline_layout(tab, line, mono_font);
+        let text_x = self.x + self.gutter_width + 10.0;
+        let left = text_x + Self::column_offset(&text, start, mono_font) + inline.char_shift(start);
+        let right = text_x + Self::column_offset(&text, end, mono_font) + inline.caret_shift(end);
+        (start < end && x >= left && x < right).then(|| (line, start, end, Rect::from_ltrb(left, top, right, top + self.line_height)))
+    }
+    
+    /// Ask the providers about the word under the pointer, and open its card after a rest
+    fn update_hover_card(&mut self, x: f32, y: f32, mono_font: &Font) {
+        if self.hover_engine.is_empty() || self.is_selecting {
+            return;
+        }
+        let over_card = self.hover.as_ref().is_some_and(|hover| hover.card.contains(x, y));
+        let word = if over_card { None } else { self.word_at(x, y, mono_font) };
+        let hovered = self.hover.as_ref().map(|hover| (hover.line, hover.start));
+        if let Some((line, start, end, trigger)) = word.filter(|word| Some((word.0, word.1)) != hovered) {
+            if let Some(popup) = self.hover_popup(line, start, end, trigger, mono_font) {
+                self.hover = Some(popup);
+            }
+        }
+        // A word without documentation lets the card close as the pointer leaves it
+        if let Some(hover) = self.hover.as_mut() {
+            hover.card.update_hover(x, y);
+        }
+    }
+    
+    /// Card for a word, laid out from the providers' markdown; `None` when they have nothing to say
+    fn hover_popup(&self, line: usize, start: usize, end: usize, trigger: Rect, mono_font: &Font) -> Option<HoverPopup> {
+        let tab = self.tab_manager.get_active_tab()?;
+        let line_text = tab.buffer.line(line).unwrap_or_default();
+        let line_text = line_text.trim_end_matches(['\n', '\r']);
+        let word: String = line_text.chars().skip(start).take(end - start).collect();
+        let context = HoverContext {
+            word: &word,
+            line_text,
+            line,
+            column: start,
+            document_path: tab.buffer.file_path().map(|path| path.as_path()),
+            language: tab.buffer.language(),
+        };
+        let markdown = self.hover_engine.hover(&context)?;
+        
+        // Bold and slanted text keep the advances of the monospace font
+        let layout = HoverLayout::new(
+            &parse_blocks(&markdown),
+            HOVER_MAX_WIDTH - HOVER_PADDING * 2.0,
+            self.line_height,
+            tab.buffer.language(),
+            |text, _| mono_font.measure_str(text, None).0,
+        );
+        let width = layout.width + HOVER_PADDING * 2.0;
+        let height = (layout.height + HOVER_PADDING * 2.0).min(HOVER_MAX_HEIGHT);
+        // Above the word, unless the editor has no room there
+        let side = if trigger.top - height < self.y + self.tab_bar.height() { HoverCardSide::Bottom } else { HoverCardSide::Top };
+        let card = HoverCard::new(trigger.left, trigger.top, trigger.width(), trigger.height(), width, height).side(side);
+        // Moving from word to word keeps an open card up
+        let mut card = if self.hover.as_ref().is_some_and(|hover| hover.card.is_open()) { card.open_delay(0.0) } else { card };
+        card.update_animation(self.clock);
+        
+        Some(HoverPopup {
+            line,
+            start,
+            card,
+            layout,
+            scroll: 0.0,
+            cursor: (tab.cursor_line, tab.cursor_column),
+        })
+    }
+    
+    /// Close an open hover card (Escape); returns false when none was showing
+    pub fn dismiss_hover(&mut self) -> bool {
+        self.hover.take().is_some_and(|hover| hover.card.is_open())
+    }
+    
+    /// Scroll long hover content under the pointer; returns false when the pointer isn't over a card
+    pub fn scroll_hover(&mut self, x: f32, y: f32, delta: f32) -> bool {
+        let Some(hover) = self.hover.as_mut().filter(|hover| hover.card.contains(x, y)) else {
+            return false;
+        };
+        let max_scroll = (hover.layout.height + HOVER_PADDING * 2.0 - hover.card.card_rect().height()).max(0.0);
+        hover.scroll = (hover.scroll + delta).clamp(0.0, max_scroll);
+        true
+    }
+    
+    /// Open hover card with its markdown; code blocks take the syntax colors
+    fn draw_hover(&self, canvas: &Canvas, mono_font: &Font) {
+        let Some(hover) = self.hover.as_ref().filter(|hover| hover.card.is_open()) else {
+            return;
+        };
+        let theme = current_theme();
+        hover.card.draw_surface(canvas);
+        let card = hover.card.card_rect();
+        canvas.save();
+        canvas.clip_rect(card.with_inset((1.0, 1.0)), None, true);
+        
+        self.draw_markdown(canvas, mono_font, &hover.layout, card, hover.scroll, theme.popover_foreground);
+        
+        // Thumb showing where long content is scrolled to
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        let content_height = hover.layout.height + HOVER_PADDING * 2.0;
+        if content_height > card.height() {
+            let thumb_height = card.height() * card.height() / content_height;
+            let thumb_top = card.top + hover.scroll / content_height * card.height();
+            paint.set_color(with_alpha(theme.muted_foreground, 100));
+            canvas.draw_round_rect(Rect::from_xywh(card.right - 5.0, thumb_top, 3.0, thumb_height), 1.5, 1.5, &paint);
+        }
+        canvas.restore();
+    }
+    
+    /// Markdown rows laid out by `HoverLayout`, inset by the hover padding from the sides of `area`
+    ///
+    /// `scroll` moves the rows up; rows outside `area` are skipped.
+    fn draw_markdown(&self, canvas: &Canvas, mono_font: &Font, layout: &HoverLayout, area: Rect, scroll: f32, foreground: Color) {
+        let theme = current_theme();
+        let mut bold_font = mono_font.clone();
+        bold_font.set_embolden(true);
+        let mut italic_font = mono_font.clone();
+        italic_font.set_skew_x(-0.2);
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        let top = area.top + HOVER_PADDING - scroll;
+        for line in &layout.lines {
+            let y = top + line.top;
+            if y + self.line_height < area.top || y > area.bottom {
+                continue;
+            }
+            match line.kind {
+                LineKind::Rule => {
+                    paint.set_color(theme.border);
+                    canvas.draw_rect(Rect::from_xywh(area.left, y, area.width(), 1.0), &paint);
+                }
+                LineKind::Code => {
+                    paint.set_color(with_alpha(theme.muted, 160));
+                    canvas.draw_rect(Rect::from_xywh(area.left + HOVER_PADDING / 2.0, y, area.width() - HOVER_PADDING, self.line_height), &paint);
+                }
+                LineKind::Text | LineKind::Heading => {}
+            }
+            
+            let mut x = area.left + HOVER_PADDING;
+            for (text, style) in &line.runs {
+                let (font, color) = match style {
+                    RunStyle::Token(token_type) => (mono_font, self.get_token_color(*token_type)),
+                    RunStyle::Text(Style::Bold) => (&bold_font, foreground),
+                    RunStyle::Text(Style::Italic) => (&italic_font, foreground),
+                    RunStyle::Text(Style::Code) => (mono_font, theme.primary),
+                    RunStyle::Text(Style::Plain) => (mono_font, foreground),
+                };
+                let width = font.measure_str(text, None).0;
+                if *style == RunStyle::Text(Style::Code) {
+                    paint.set_color(with_alpha(theme.muted, 160));
+                    let rect = Rect::from_xywh(x - 2.0, y + 2.0, width + 4.0, self.line_height - 4.0);
+                    canvas.draw_round_rect(rect, Theme::RADIUS_SM, Theme::RADIUS_SM, &paint);
+                }
+                paint.set_color(color);
+                canvas.draw_str(text, (x, y + 15.0), font, &paint);
+                x += width;
+            }
+        }
+    }
+    
+    /// Image or Markdown of a preview tab; images shrink to fit, Markdown wraps in a centered column
+    fn draw_preview(&self, canvas: &Canvas, preview: &Preview, mono_font: &Font, content_y: f32, content_height: f32) {
+        let area = Rect::from_xywh(self.x, content_y, self.width, content_height);
+        canvas.save();
+        canvas.clip_rect(area, None, true);
+        match preview.content {
+            PreviewContent::Image(ref image) => {
+                let room = area.with_inset((PREVIEW_MARGIN, PREVIEW_MARGIN));
+                let (width, height) = (image.width() as f32, image.height() as f32);
+                let scale = (room.width() / width).min(room.height() / height).clamp(0.0, 1.0);
+                let dest = Rect::from_xywh(
+                    room.center_x() - width * scale / 2.0,
+                    room.center_y() - height * scale / 2.0,
+                    width * scale,
+                    height * scale,
+                );
+                let mut paint = Paint::default();
+                paint.set_anti_alias(true);
+                canvas.draw_image_rect(image, None, dest, &paint);
+            }
+            PreviewContent::Markdown(ref blocks) => {
+                let column = self.preview_column(content_y, content_height);
+                let mut cached = preview.layout.borrow_mut();
+                if cached.as_ref().is_none_or(|(width, _)| *width != column.width()) {
+                    let layout = HoverLayout::new(
+                        blocks,
+                        column.width() - HOVER_PADDING * 2.0,
+                        self.line_height,
+                        None,
+                        |text, _| mono_font.measure_str(text, None).0,
+                    );
+                    *cached = Some((column.width(), layout));
+                }
+                if let Some((_, ref layout)) = *cached {
+                    self.draw_markdown(canvas, mono_font, layout, column, preview.scroll, current_theme().foreground);
+                }
+            }
+        }
+        canvas.restore();
+    }
+    
+    /// Where a Markdown preview's text goes, at most `PREVIEW_MAX_WIDTH` wide
+    fn preview_column(&self, content_y: f32, content_height: f32) -> Rect {
+        let width = (self.width - PREVIEW_MARGIN * 2.0).clamp(0.0, PREVIEW_MAX_WIDTH);
+        Rect::from_xywh(self.x + (self.width - width) / 2.0, content_y + PREVIEW_MARGIN / 2.0, width, content_height - PREVIEW_MARGIN / 2.0)
+    }
+    
+    /// Where the tab's lines go, below the space of a peek view open in it and of its view zones
+    ///
+    /// The peek view's space comes first, so it sits right under its line; the tab's zones
+    /// follow in the order they were added.
+    fn line_layout(&self, tab: &EditorTab) -> LineLayout {
+        let peek = self.peek
+            .as_ref()
+            .filter(|peek| peek.host_tab == tab.id)
+            .and_then(|peek| tab.buffer.marker_position(peek.anchor))
+            .map(|(line, _)| (line, self.peek_height()));
+        let width = self.zone_width();
+        let zones = tab.zones.iter().filter_map(|zone| {
+            let (line, _) = tab.buffer.marker_position(zone.anchor)?;
+            Some((line, zone.widget.height(width).max(0.0)))
+        });
+        LineLayout::new(self.line_height, peek.into_iter().chain(zones).collect())
+    }
+    
+    /// Width of the text area, which peek views and view zones span
+    fn zone_width(&self) -> f32 {
+        (self.width - self.gutter_width - RULER_WIDTH).max(0.0)
+    }
+    
+    /// Bounds of the active tab's view zones in the order they were added, on screen
+    fn zone_rects(&self, tab: &EditorTab) -> Vec<Rect> {
+        let layout = self.line_layout(tab);
+        let first = usize::from(self.peek.as_ref().is_some_and(|peek| peek.host_tab == tab.id));
+        let (left, width) = (self.x + self.gutter_width, self.zone_width());
+        let top = self.y + self.tab_bar.height() - tab.scroll_offset;
+        tab.zones
+            .iter()
+            .enumerate()
+            .map(|(index, zone)| {
+                Rect::from_xywh(left, top + layout.zone_top(first + index), width, zone.widget.height(width).max(0.0))
+            })
+            .collect()
+    }
+    
+    /// View zones in sight, each clipped to its own space within the content area
+    fn draw_zones(&self, canvas: &Canvas, tab: &EditorTab, ui_font: &Font, content_y: f32) {
+        if tab.zones.is_empty() {
+            return;
+        }
+        let content = Rect::from_ltrb(self.x, content_y, self.x + self.width, self.y + self.height);
+        for (zone, rect) in tab.zones.iter().zip(self.zone_rects(tab)) {
+            if rect.bottom <= content.top || rect.top >= content.bottom || rect.height() <= 0.0 {
+                continue;
+            }
+            canvas.save();
+            canvas.clip_rect(content, None, true);
+            canvas.clip_rect(rect, None, true);
+            zone.widget.draw(canvas, rect, ui_font);
+            canvas.restore();
+        }
+    }
+    
+    fn peek_height(&self) -> f32 {
+        let content_height = self.height - self.tab_bar.height();
+        let lines = (content_height / 3.0 / self.line_height).floor().clamp(PEEK_MIN_LINES, PEEK_MAX_LINES);
+        self.tab_bar.height() + lines * self.line_height
+    }
+    
+    /// Bounds of the peek view open in the active tab, under its line and as wide as the text area
+    fn peek_rect(&self) -> Option<Rect> {
+        let tab = self.tab_manager.get_active_tab()?;
+        let peek = self.peek.as_ref().filter(|peek| peek.host_tab == tab.id)?;
+        let (line, _) = tab.buffer.marker_position(peek.anchor)?;
+        let top = self.y + self.tab_bar.height() + self.line_layout(tab).line_top(line) + self.line_height - tab.scroll_offset;
+        let left = self.x + self.gutter_width;
+        Some(Rect::from_ltrb(left, top, self.x + self.width - RULER_WIDTH, top + self.peek_height()))
+    }
+    
+    /// Whether a point is on the visible part of the active tab's peek view
+    fn peek_contains(&self, x: f32, y: f32) -> bool {
+        let content_y = self.y + self.tab_bar.height();
+        y >= content_y
+            && y < self.y + self.height
+            && self.peek_rect().is_some_and(|rect| x >= rect.left && x < rect.right && y >= rect.top && y < rect.bottom)
+    }
+    
+    /// Embedded editor of the active tab's peek view, while it has focus
+    fn focused_peek_mut(&mut self) -> Option<&mut Editor> {
+        let active = self.tab_manager.get_active_tab().map(|tab| tab.id);
+        self.peek
+            .as_deref_mut()
+            .filter(|peek| peek.focused && Some(peek.host_tab) == active)
+            .map(|peek| &mut peek.editor)
+    }
+    
+    /// Move and size the peek view with its line and the host
+    fn layout_peek(&mut self) {
+        let Some(rect) = self.peek_rect() else {
+            return;
+        };
+        if let Some(peek) = self.peek.as_mut() {
+            peek.editor.set_bounds(rect.left, rect.top, rect.width(), rect.height());
+        }
+    }
+    
+    /// Open a peek view below the cursor line, showing `path` with `line` in view
+    ///
+    /// A file open here with unsaved changes shows its tab's text, read-only so the two copies
+    /// can't drift apart; otherwise it is read from disk and, when `editable`, can be edited and
+    /// saved from the view.
+    pub fn peek_file(&mut self, path: std::path::PathBuf, line: usize, editable: bool) -> std::io::Result<()> {
+        self.close_peek();
+        let Some(host) = self.tab_manager.get_active_tab().filter(|tab| !tab.is_hex()) else {
+            return Ok(());
+        };
+        let mut editor = Editor::new(self.x, self.y, self.width, self.peek_height());
+        match self.tab_manager.tabs().iter().find(|tab| tab.is_modified() && tab.buffer.file_path() == Some(&path)) {
+            Some(open) => {
+                editor.tab_manager.add_tab_with_text(&open.buffer.to_string(), open.title.clone());
+                editor.set_active_language(open.buffer.language());
+                editor.read_only = true;
+            }
+            None => {
+                editor.open_file(path)?;
+                editor.read_only = !editable;
+            }
+        }
+        // Every editor starts with a welcome tab
+        editor.tab_manager.close_tab(0);
+        editor.render_whitespace = self.render_whitespace;
+        editor.highlight_trailing_whitespace = self.highlight_trailing_whitespace;
+        editor.inlay_hints = self.inlay_hints;
+        editor.rulers = self.rulers.clone();
+        editor.language_rulers = self.language_rulers.clone();
+        editor.todo_keywords = self.todo_keywords.clone();
+        editor.sticky_scroll = false;
+        
+        let (host_tab, anchor_line) = (host.id, host.cursor_line);
+        let Some(host) = self.tab_manager.get_active_tab_mut() else {
+            return Ok(());
+        };
+        let anchor = host.buffer.add_marker(anchor_line, 0, Bias::Left);
+        self.peek = Some(Box::new(PeekView { host_tab, anchor, editor, focused: true }));
+        self.layout_peek();
+        if let Some(peek) = self.peek.as_mut() {
+            peek.editor.go_to_position(line, 0);
+            peek.editor.finish_scroll();
+        }
+        
+        // Bring the line and the whole view into sight
+        let top = self.tab_manager.get_active_tab().map_or(0.0, |tab| self.line_layout(tab).line_top(anchor_line));
+        let content_height = self.height - self.tab_bar.height();
+        let scroll = self.scroll_target();
+        let target = reveal_offset(scroll, content_height, top, self.line_height + self.peek_height(), 0.0);
+        if target != scroll {
+            self.scroll_to(target);
+        }
+        Ok(())
+    }
+    
+    /// Close the active tab's peek view (Escape); returns false when it has none
+    pub fn close_peek(&mut self) -> bool {
+        let active = self.tab_manager.get_active_tab().map(|tab| tab.id);
+        if self.peek.as_ref().map(|peek| peek.host_tab) != active {
+            return false;
+        }
+        let Some(peek) = self.peek.take() else {
+            return false;
+        };
+        if let Some(host) = self.tab_manager.get_active_tab_mut() {
+            host.buffer.remove_marker(peek.anchor);
+        }
+        true
+    }
+    
+    pub fn is_peek_open(&self) -> bool {
+        self.peek_rect().is_some()
+    }
+    
+    /// Scroll the peek view under the pointer; returns false when the pointer isn't over one
+    pub fn scroll_peek(&mut self, x: f32, y: f32, delta: f32) -> bool {
+        if !self.peek_contains(x, y) {
+            return false;
+        }
+        if let Some(peek) = self.peek.as_mut() {
+            peek.editor.scroll(delta);
+        }
+        true
+    }
+    
+    /// Peek view open in the active tab, over the space left under its line
+    fn draw_peek(&self, canvas: &Canvas, ui_font: &Font, mono_font: &Font, content_y: f32) {
+        let (Some(peek), Some(rect)) = (self.peek.as_ref(), self.peek_rect()) else {
+            return;
+        };
+        canvas.save();
+        canvas.clip_rect(Rect::from_ltrb(self.x, content_y, self.x + self.width, self.y + self.height), None, true);
+        peek.editor.draw(canvas, ui_font, mono_font);
+        
+        let mut border_paint = Paint::default();
+        border_paint.set_anti_alias(true);
+        border_paint.set_style(PaintStyle::Stroke);
+        border_paint.set_stroke_width(1.0);
+        border_paint.set_color(if peek.focused { current_theme().primary } else { current_theme().border });
+        canvas.draw_rect(rect.with_inset((0.5, 0.5)), &border_paint);
+        canvas.restore();
+    }
+    
+    /// Diagnostic dot at the gutter's left edge and git change bar at its right edge
+    fn draw_gutter_annotations(&self, canvas: &Canvas, annotations: &Annotations, line_idx: usize, top: f32) {
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        
+        if let Some(diagnostic) = annotations.diagnostic_at(line_idx) {
+            paint.set_color(diagnostic.kind.color());
+            canvas.draw_circle((self.x + 8.0, top + self.line_height / 2.0), 3.0, &paint);
+        }
+        
+        if let Some(change) = annotations.git_change_at(line_idx) {
+            paint.set_color(change.kind.color());
+            let bar_x = self.x + self.gutter_width - 4.0;
+            if change.kind == AnnotationKind::GitDeleted {
+                // Deletions sit between lines, so mark the top edge with a wedge
+                let mut path = skia_safe::Path::new();
+                path.move_to((bar_x - 2.0, top - 4.0));
+                path.line_to((bar_x + 3.0, top));
+                path.line_to((bar_x - 2.0, top + 4.0));
+                path.close();
+                canvas.draw_path(&path, &paint);
+            } else {
+                canvas.draw_rect(Rect::from_xywh(bar_x, top, 3.0, self.line_height), &paint);
+            }
+        }
+    }
+    
+    /// Columns of a decoration on a line starting at char `line_start`, clipped to the line's text
+    fn decoration_columns(decoration: &Decoration, line_start: usize, line_len: usize) -> (usize, usize) {
+        let start = decoration.range.start.saturating_sub(line_start).min(line_len);
+        let end = decoration.range.end.saturating_sub(line_start).min(line_len);
+        (start, end)
+    }
+    
+    /// Background fills of the decorations on a line, drawn under the selection and text
+    fn draw_decoration_backgrounds(
+        &self,
+        canvas: &Canvas,
+        tab: &EditorTab,
+        line_idx: usize,
+        inline: &InlineLayout,
+        y_pos: f32,
+        mono_font: &Font,
+    ) {
+        let decorations = tab.decorations();
+        if decorations.is_empty() {
+            return;
+        }
+        let line = tab.buffer.line(line_idx).unwrap_or_default();
+        let line_len = line.trim_end_matches('\n').chars().count();
+        let line_start = tab.buffer.line_to_char(line_idx);
+        let text_x = self.x + self.gutter_width + 10.0;
+        
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        for decoration in decorations.in_range(line_start, line_start + line_len) {
+            let Some(color) = decoration.background else {
+                continue;
+            };
+            paint.set_color(color);
+            if decoration.whole_line {
+                canvas.draw_rect(
+                    Rect::from_xywh(self.x + self.gutter_width, y_pos - 15.0, self.zone_width(), self.line_height),
+                    &paint,
+                );
+                continue;
+            }
+            let (start, end) = Self::decoration_columns(decoration, line_start, line_len);
+            let start_x = Self::column_offset(&line, start, mono_font) + inline.caret_shift(start);
+            let mut end_x = Self::column_offset(&line, end, mono_font) + inline.caret_shift(end);
+            // Ranges that run onto the next line cover the line break too
+            if decoration.range.end > line_start + line_len {
+                end_x += mono_font.measure_str(" ", None).0;
+            }
+            canvas.draw_rect(
+                Rect::from_xywh(text_x + start_x, y_pos - 15.0, end_x - start_x, self.line_height),
+                &paint,
+            );
+        }
+    }
+    
+    /// Underlines, gutter icons and after-line text of the decorations on a line
+    fn draw_decoration_overlays(
+        &self,
+        canvas: &Canvas,
+        tab: &EditorTab,
+        line_idx: usize,
+        inline: &InlineLayout,
+        y_pos: f32,
+        mono_font: &Font,
+    ) {
+        let decorations = tab.decorations();
+        if decorations.is_empty() {
+            return;
+        }
+        let line = tab.buffer.line(line_idx).unwrap_or_default();
+        let line_len = line.trim_end_matches('\n').chars().count();
+        let line_start = tab.buffer.line_to_char(line_idx);
+        let line_end = line_start + line_len;
+        let text_x = self.x + self.gutter_width + 10.0;
+        let space_width = mono_font.measure_str(" ", None).0;
+        
+        let mut hint_x = text_x + Self::column_offset(&line, line_len, mono_font) + inline.char_shift(line_len) + space_width * 2.0;
+        let mut gutter_icon_drawn = false;
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        for decoration in decorations.in_range(line_start, line_end) {
+            if let Some((color, style)) = decoration.underline {
+                let (start, end) = Self::decoration_columns(decoration, line_start, line_len);
+                let start_x = text_x + Self::column_offset(&line, start, mono_font) + inline.char_shift(start);
+                let end_x = text_x + Self::column_offset(&line, end, mono_font) + inline.caret_shift(end);
+                // Empty ranges still mark the char they sit on
+                let end_x = if start == end { start_x + space_width } else { end_x };
+                Self::draw_underline(canvas, (start_x, end_x), y_pos + 3.0, color, style);
+            }
+            
+            // Icons go next to the first line of a range, one per line
+            if let Some((icon, color)) = decoration.gutter_icon {
+                if !gutter_icon_drawn && decoration.range.start >= line_start {
+                    let size = IconSize::Small.as_f32();
+                    Icon::new(self.x + 2.0, y_pos - 15.0 + (self.line_height - size) / 2.0, icon, IconSize::Small, color)
+                        .paint(canvas);
+                    gutter_icon_drawn = true;
+                }
+            }
+            
+            // After-line text follows the last line of a range; a range ending in the line break still ends here
+            if let Some((ref text, color)) = decoration.after_line {
+                if decoration.range.end <= line_end + 1 {
+                    paint.set_color(color);
+                    canvas.draw_str(text, (hint_x, y_pos), mono_font, &paint);
+                    hint_x += mono_font.measure_str(text, None).0 + space_width * 2.0;
+                }
+            }
+        }
+    }
+    
+    fn draw_underline(canvas: &Canvas, (start_x, end_x): (f32, f32), y: f32, color: Color, style: UnderlineStyle) {
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_color(color);
+        match style {
+            UnderlineStyle::Solid => {
+                canvas.draw_rect(Rect::from_xywh(start_x, y, end_x - start_x, 1.0), &paint);
+            }
+            UnderlineStyle::Dotted => {
+                let mut x = start_x;
+                while x < end_x {
+                    canvas.draw_rect(Rect::from_xywh(x, y, 1.0, 1.0), &paint);
+                    x += 3.0;
+                }
+            }
+            UnderlineStyle::Wavy => {
+                // Zigzag with a 4px period
+                let mut path = skia_safe::Path::new();
+                path.move_to((start_x, y + 1.0));
+                let mut x = start_x;
+                let mut up = true;
+                while x < end_x {
+                    x = (x + 2.0).min(end_x);
+                    path.line_to((x, if up { y - 1.0 } else { y + 1.0 }));
+                    up = !up;
+                }
+                paint.set_style(PaintStyle::Stroke);
+                paint.set_stroke_width(1.0);
+                canvas.draw_path(&path, &paint);
+            }
+        }
+    }
+    
+    /// Overview ruler track along the right edge of the text area
+    fn ruler_rect(&self) -> Rect {
+        let tab_bar_height = self.tab_bar.height();
+        Rect::from_xywh(
+            self.x + self.width - RULER_WIDTH,
+            self.y + tab_bar_height,
+            RULER_WIDTH,
+            self.height - tab_bar_height,
+        )
+    }
+    
+    /// Y of a line on the ruler; the whole document maps onto the track
+    fn ruler_line_y(&self, line: usize, total_lines: usize) -> f32 {
+        let ruler = self.ruler_rect();
+        ruler.top + line as f32 / total_lines.max(1) as f32 * ruler.height()
+    }
+    
+    /// Marks for every annotation plus the cursor, under the scrollbar thumb
+    fn draw_overview_ruler(&self, canvas: &Canvas, tab: &EditorTab) {
+        let theme = current_theme();
+        let ruler = self.ruler_rect();
+        let total_lines = tab.buffer.len_lines();
+        let lane_width = ruler.width() / 3.0;
+        
+        let mut track_paint = Paint::default();
+        track_paint.set_color(with_alpha(theme.muted, 20));
+        canvas.draw_rect(ruler, &track_paint);
+        
+        let mut mark_paint = Paint::default();
+        mark_paint.set_anti_alias(true);
+        for annotation in tab.annotations.iter() {
+            let lane_x = match annotation.kind.lane() {
+                RulerLane::Left => ruler.left,
+                RulerLane::Center => ruler.left + lane_width,
+                RulerLane::Right => ruler.left + lane_width * 2.0,
+            };
+            let top = self.ruler_line_y(annotation.lines.start, total_lines);
+            let bottom = self.ruler_line_y(annotation.lines.end, total_lines);
+            mark_paint.set_color(annotation.kind.color());
+            canvas.draw_rect(Rect::from_xywh(lane_x, top, lane_width, (bottom - top).max(2.0)), &mark_paint);
+        }
+        
+        // Cursor position across all lanes
+        let mut cursor_paint = Paint::default();
+        cursor_paint.set_color(with_alpha(theme.foreground, 180));
+        let cursor_y = self.ruler_line_y(tab.cursor_line, total_lines);
+        canvas.draw_rect(Rect::from_xywh(ruler.left, cursor_y, ruler.width(), 2.0), &cursor_paint);
+    }
+    
+    /// First line of the ruler mark under a point, if any
+    fn ruler_annotation_at(&self, x: f32, y: f32) -> Option<usize> {
+        let tab = self.tab_manager.get_active_tab().filter(|tab| tab.hex.is_none())?;
+        if !self.ruler_rect().contains(skia_safe::Point::new(x, y)) {
+            return None;
+        }
+        let total_lines = tab.buffer.len_lines();
+        tab.annotations
+            .iter()
+            .filter(|annotation| {
+                let top = self.ruler_line_y(annotation.lines.start, total_lines);
+                let bottom = self.ruler_line_y(annotation.lines.end, total_lines).max(top + 2.0);
+                y >= top - RULER_HIT_SLOP && y <= bottom + RULER_HIT_SLOP
+            })
+            .min_by(|a, b| {
+                let distance = |line: usize| (self.ruler_line_y(line, total_lines) - y).abs();
+                distance(a.lines.start).total_cmp(&distance(b.lines.start))
+            })
+            .map(|annotation| annotation.lines.start)
+    }
+    
+    /// Annotations of the active text tab, for search, diagnostics and git sources to fill
+    pub fn active_annotations_mut(&mut self) -> Option<&mut Annotations> {
+        self.tab_manager
+            .get_active_tab_mut()
+            .filter(|tab| tab.hex.is_none())
+            .map(|tab| &mut tab.annotations)
+    }
+    
+    /// Decorations of the active text tab; ranges are char offsets that follow later edits
+    pub fn active_decorations_mut(&mut self) -> Option<&mut Decorations> {
+        self.tab_manager
+            .get_active_tab_mut()
+            .filter(|tab| tab.hex.is_none())
+            .map(|tab| tab.buffer.decorations_mut())
+    }
+    
+    /// Digits used for offsets: at least 8, more for files over 4 GiB
+    fn hex_offset_digits(hex: &HexEditor) -> usize {
+        let digits = (64 - hex.buffer.len().leading_zeros() as usize).div_ceil(4);
+        digits.max(8)
+    }
+    
+    /// X positions of the hex and ASCII columns
+    fn hex_columns(&self, hex: &HexEditor, char_width: f32) -> (f32, f32) {
+        let hex_x = self.x + 10.0 + (Self::hex_offset_digits(hex) + 2) as f32 * char_width;
+        let ascii_x = hex_x + (BYTES_PER_ROW as usize * 3 + 2) as f32 * char_width;
+        (hex_x, ascii_x)
+    }
+    
+    /// X offset of a byte within the hex column (extra gap after 8 bytes)
+    fn hex_cell_offset(index: u64, char_width: f32) -> f32 {
+        (index * 3 + (index >= BYTES_PER_ROW / 2) as u64) as f32 * char_width
+    }
+    
+    fn hex_visible_rows(&self) -> u64 {
+        ((self.height - self.tab_bar.height()) / self.line_height).floor().max(1.0) as u64
+    }
+    
+    /// Draw the offset | hex bytes | ASCII view of a hex editor tab
+    fn draw_hex(&self, canvas: &Canvas, hex: &HexEditor, mono_font: &Font, content_y: f32) {
+        let theme = current_theme();
+        let char_width = mono_font.measure_str("0", None).0;
+        let (hex_x, ascii_x) = self.hex_columns(hex, char_width);
+        let digits = Self::hex_offset_digits(hex);
+        let content_height = self.height - self.tab_bar.height();
+        
+        // Offset gutter
+        let mut gutter_paint = Paint::default();
+        gutter_paint.set_color(surface(theme.card));
+        gutter_paint.set_anti_alias(true);
+        canvas.draw_rect(
+            Rect::from_xywh(self.x, content_y, hex_x - self.x - char_width, content_height),
+            &gutter_paint,
+        );
+        
+        let mut muted_paint = Paint::default();
+        muted_paint.set_color(theme.muted_foreground);
+        muted_paint.set_anti_alias(true);
+        let mut text_paint = Paint::default();
+        text_paint.set_color(theme.foreground);
+        text_paint.set_anti_alias(true);
+        let mut edited_paint = Paint::default();
+        edited_paint.set_color(Theme::WARNING);
+        edited_paint.set_anti_alias(true);
+        let mut match_paint = Paint::default();
+        match_paint.set_color(with_alpha(Theme::WARNING, 70));
+        let mut cursor_paint = Paint::default();
+        cursor_paint.set_color(with_alpha(theme.primary, 80));
+        let mut focus_paint = Paint::default();
+        focus_paint.set_color(theme.foreground);
+        
+        let rows = (content_height / self.line_height).ceil() as u64;
+        let end_row = (hex.scroll_row + rows).min(hex.row_count());
+        
+        for row in hex.scroll_row..end_row {
+            let row_top = content_y + (row - hex.scroll_row) as f32 * self.line_height;
+            let baseline = row_top + 17.0;
+            let row_offset = row * BYTES_PER_ROW;
+            
+            let offset_text = format!("{:0width$X}", row_offset, width = digits);
+            let offset_paint = if hex.cursor / BYTES_PER_ROW == row { &text_paint } else { &muted_paint };
+            canvas.draw_str(&offset_text, (self.x + 10.0, baseline), mono_font, offset_paint);
+            
+            let bytes = hex.buffer.read(row_offset, BYTES_PER_ROW as usize);
+            for (i, byte) in bytes.iter().enumerate() {
+                let offset = row_offset + i as u64;
+                let cell_x = hex_x + Self::hex_cell_offset(i as u64, char_width);
+                let char_x = ascii_x + i as f32 * char_width;
+                
+                // Search match and cursor backgrounds in both columns
+                let in_match = hex.search_match
+                    .is_some_and(|(start, len)| offset >= start && offset < start + len as u64);
+                let background = if offset == hex.cursor {
+                    Some(&cursor_paint)
+                } else if in_match {
+                    Some(&match_paint)
+                } else {
+                    None
+                };
+                if let Some(paint) = background {
+                    canvas.draw_rect(Rect::from_xywh(cell_x - 1.0, row_top, char_width * 2.0 + 2.0, self.line_height), paint);
+                    canvas.draw_rect(Rect::from_xywh(char_x, row_top, char_width, self.line_height), paint);
+                }
+                
+                let paint = if hex.buffer.is_edited(offset) {
+                    &edited_paint
+                } else if *byte == 0 {
+                    &muted_paint
+                } else {
+                    &text_paint
+                };
+                canvas.draw_str(format!("{:02X}", byte), (cell_x, baseline), mono_font, paint);
+                
+                let ch = if byte.is_ascii_graphic() || *byte == b' ' { *byte as char } else { '.' };
+                canvas.draw_str(ch.to_string(), (char_x, baseline), mono_font, paint);
+            }
+        }
+        
+        // Caret under the focused column
+        let cursor_row = hex.cursor / BYTES_PER_ROW;
+        if self.show_cursor && cursor_row >= hex.scroll_row && cursor_row < end_row && !hex.buffer.is_empty() {
+            let index = hex.cursor % BYTES_PER_ROW;
+            let caret_y = content_y + (cursor_row - hex.scroll_row) as f32 * self.line_height + self.line_height - 3.0;
+            let caret_x = if hex.ascii_focus {
+                ascii_x + index as f32 * char_width
+            } else {
+                hex_x + Self::hex_cell_offset(index, char_width) + if hex.low_nibble { char_width } else { 0.0 }
+            };
+            canvas.draw_rect(Rect::from_xywh(caret_x, caret_y, char_width, 2.0), &focus_paint);
+        }
+    }
+    
+    /// Hex editor of the active tab, if it was reopened as hex
+    fn active_hex_mut(&mut self) -> Option<&mut HexEditor> {
+        self.tab_manager.get_active_tab_mut().and_then(|tab| tab.hex.as_mut())
+    }
+    
+    /// Whether the active tab shows the hex editor
+    pub fn is_active_hex(&self) -> bool {
+        self.tab_manager.get_active_tab().is_some_and(|tab| tab.is_hex())
+    }
+    
+    /// Move the hex cursor by a number of bytes and keep it on screen
+    fn hex_move(&mut self, delta: i64) {
+        let visible_rows = self.hex_visible_rows();
+        if let Some(hex) = self.active_hex_mut() {
+            hex.move_by(delta);
+            hex.reveal_cursor(visible_rows);
+        }
+        self.cursor_blink_time = 0.0;
+        self.show_cursor = true;
+    }
+    
+    /// Reopen the active file in the hex editor
+    pub fn reopen_active_as_hex(&mut self) -> std::io::Result<()> {
+        let Some(tab) = self.tab_manager.get_active_tab_mut() else {
+            return Ok(());
+        };
+        if tab.is_hex() {
+            return Ok(());
+        }
+        let Some(path) = tab.buffer.file_path().cloned() else {
+            return Err(std::io::Error::new(std::io::ErrorKind::NotFound, "No file path set"));
+        };
+        if tab.buffer.is_modified() {
+            return Err(std::io::Error::other("Save the file before reopening it"));
+        }
+        
+        tab.clear_column_selection();
+        tab.selection_start = None;
+        tab.hex = Some(HexEditor::open(path)?);
+        Ok(())
+    }
+    
+    /// Reopen the active hex editor tab as text, reloading the file from disk
+    pub fn reopen_active_as_text(&mut self) -> std::io::Result<()> {
+        let Some(tab) = self.tab_manager.get_active_tab_mut() else {
+            return Ok(());
+        };
+        let Some(ref hex) = tab.hex else {
+            return Ok(());
+        };
+        if hex.buffer.is_modified() {
+            return Err(std::io::Error::other("Save the file before reopening it"));
+        }
+        
+        let encoding = tab.buffer.encoding();
+        tab.buffer.reopen_with_encoding(encoding)?;
+        tab.highlighter.parse(&tab.buffer.to_string());
+        tab.clamp_cursor();
+        tab.hex = None;
+        Ok(())
+    }
+    
+    /// Find the next occurrence of a byte pattern in the active hex editor
+    pub fn find_bytes(&mut self, pattern: &[u8]) -> Option<u64> {
+        let visible_rows = self.hex_visible_rows();
+        let hex = self.active_hex_mut()?;
+        let found = hex.find_next(pattern);
+        hex.reveal_cursor(visible_rows);
+        found
+    }
+    
+    /// X offset of a column within a line, clamped to the end of the line
+    fn column_offset(line: &str, column: usize, mono_font: &Font) -> f32 {
+        let line = line.trim_end_matches('\n');
+        if contains_rtl(line) {
+            return BidiLine::new(line, Some(TextDirection::Ltr)).caret_x(column, mono_font);
+        }
+        let text: String = line.chars().take(column).collect();
+        mono_font.measure_str(&text, None).0
+    }
+    
+    /// Column of the caret nearest to `relative_x` within a line
+    fn column_at_x(line: &str, relative_x: f32, mono_font: &Font) -> usize {
+        let text = line.trim_end_matches('\n');
+        if contains_rtl(text) {
+            return BidiLine::new(text, Some(TextDirection::Ltr)).index_at_x(relative_x, mono_font);
+        }
+        
+        let mut current_x = 0.0;
+        let mut column = 0;
+        for (i, ch) in line.chars().enumerate() {
+            let char_width = mono_font.measure_str(ch.to_string(), None).0;
+            if current_x + char_width / 2.0 > relative_x {
+                column = i;
+                break;
+            }
+            current_x += char_width;
+            column = i + 1;
+        }
+        column
+    }
+    
+    /// Byte offset in the document where a line starts
+    fn line_start_byte(tab: &EditorTab, line_idx: usize) -> usize {
+        (0..line_idx)
+            .filter_map(|i| tab.buffer.line(i))
+            .map(|line| line.len())
+            .sum()
+    }
+    
+    /// Draw a line's text, without its line break, colored by the document's highlights
+    ///
+    /// Inline text decorations are drawn between the characters, moving the rest of the line right.
+    fn draw_line_text(
+        &self,
+        canvas: &Canvas,
+        mono_font: &Font,
+        tab: &EditorTab,
+        line_idx: usize,
+        highlights: &[(usize, usize, TokenType)],
+        (text_x, y_pos): (f32, f32),
+    ) {
+        let Some(line) = tab.buffer.line(line_idx) else {
+            return;
+        };
+        let line_text = line.trim_end_matches(['\n', '\r']);
+        let line_start_byte = Self::line_start_byte(tab, line_idx);
+        if contains_rtl(line_text) {
+            self.draw_bidi_line(canvas, mono_font, line_text, line_start_byte, highlights, (text_x, y_pos));
+            return;
+        }
+        let inline = self.inline_layout(tab, line_idx, mono_font);
+        
+        let theme = current_theme();
+        let line_end_byte = line_start_byte + line_text.len();
+        
+        // Byte ranges of the line and their colors
+        let mut runs = Vec::new();
+        let mut last_pos = 0;
+        for (start, end, token_type) in highlights {
+            // Check if this highlight is in the current line
+            if *end <= line_start_byte || *start >= line_end_byte {
+                continue;
+            }
+            
+            let highlight_start = (*start).saturating_sub(line_start_byte);
+            let highlight_end = (*end - line_start_byte).min(line_text.len());
+            
+            // Text before highlight
+            if last_pos < highlight_start {
+                runs.push((last_pos, highlight_start, theme.foreground));
+            }
+            
+            // Highlighted text
+            if highlight_start < highlight_end && highlight_end <= line_text.len() {
+                runs.push((highlight_start, highlight_end, self.get_token_color(*token_type)));
+                last_pos = highlight_end;
+            }
+        }
+        
+        // Remaining text
+        if last_pos < line_text.len() {
+            runs.push((last_pos, line_text.len(), theme.foreground));
+        }
+        
+        let mut breaks = inline
+            .spans()
+            .iter()
+            .map(|&(column, _, width)| (line_text.char_indices().nth(column).map_or(line_text.len(), |(byte, _)| byte), width))
+            .peekable();
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        let mut current_x = text_x;
+        for (mut from, to, color) in runs {
+            paint.set_color(color);
+            while from < to {
+                // Inline text in front of the next character moves it along
+                while let Some((_, width)) = breaks.next_if(|&(byte, _)| byte <= from) {
+                    current_x += width;
+                }
+                let until = breaks.peek().map_or(to, |&(byte, _)| byte.min(to));
+                let text = &line_text[from..until];
+                canvas.draw_str(text, (current_x, y_pos), mono_font, &paint);
+                current_x += mono_font.measure_str(text, None).0;
+                from = until;
+            }
+        }
+        self.draw_inline_texts(canvas, tab, line_idx, mono_font, (text_x, y_pos));
+    }
+    
+    /// Inline text decorations starting on a line, by column; none while inlay hints are hidden
+    ///
+    /// Lines with right-to-left text leave their inline text out, as their columns don't run left to right.
+    fn line_inline_texts<'a>(&self, tab: &'a EditorTab, line_idx: usize) -> Vec<(usize, &'a InlineText)> {
+        let decorations = tab.decorations();
+        if !self.inlay_hints || decorations.is_empty() {
+            return Vec::new();
+        }
+        let Some(line) = tab.buffer.line(line_idx) else {
+            return Vec::new();
+        };
+        let line = line.trim_end_matches(['\n', '\r']);
+        if contains_rtl(line) {
+            return Vec::new();
+        }
+        let line_len = line.chars().count();
+        let line_start = tab.buffer.line_to_char(line_idx);
+        
+        let mut texts: Vec<(usize, &InlineText)> = decorations
+            .in_range(line_start, line_start + line_len)
+            .filter(|decoration| decoration.range.start >= line_start)
+            .filter_map(|decoration| Some((decoration.range.start - line_start, decoration.inline.as_ref()?)))
+            .collect();
+        texts.sort_by_key(|&(column, _)| column);
+        texts
+    }
+    
+    /// Width inline text takes in the line, its padding included
+    fn inline_text_width(inline: &InlineText, mono_font: &Font) -> f32 {
+        let space_width = mono_font.measure_str(" ", None).0;
+        let pill_inset = if inline.background.is_some() { INLINE_TEXT_INSET * 2.0 } else { 0.0 };
+        let padding = (inline.padding_left as u8 + inline.padding_right as u8) as f32 * space_width;
+        mono_font.measure_str(&inline.text, None).0 + pill_inset + padding
+    }
+    
+    /// Where a line's inline text goes and how much room it takes
+    fn inline_layout(&self, tab: &EditorTab, line_idx: usize, mono_font: &Font) -> InlineLayout {
+        let texts = self.line_inline_texts(tab, line_idx);
+        if texts.is_empty() {
+            return InlineLayout::default();
+        }
+        let line = tab.buffer.line(line_idx).unwrap_or_default();
+        InlineLayout::new(
+            texts
+                .into_iter()
+                .map(|(column, inline)| {
+                    (column, Self::column_offset(&line, column, mono_font), Self::inline_text_width(inline, mono_font))
+                })
+                .collect(),
+        )
+    }
+    
+    /// X offset of the caret at a column of a line, past the inline text in front of it
+    fn caret_offset(&self, tab: &EditorTab, line_idx: usize, column: usize, mono_font: &Font) -> f32 {
+        let line = tab.buffer.line(line_idx).unwrap_or_default();
+        Self::column_offset(&line, column, mono_font) + self.inline_layout(tab, line_idx, mono_font).caret_shift(column)
+    }
+    
+    /// Pills and text of a line's inline text decorations
+    fn draw_inline_texts(&self, canvas: &Canvas, tab: &EditorTab, line_idx: usize, mono_font: &Font, (text_x, y_pos): (f32, f32)) {
+        let texts = self.line_inline_texts(tab, line_idx);
+        if texts.is_empty() {
+            return;
+        }
+        let line = tab.buffer.line(line_idx).unwrap_or_default();
+        let space_width = mono_font.measure_str(" ", None).0;
+        
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        let mut shift = 0.0;
+        for (column, inline) in texts {
+            let width = Self::inline_text_width(inline, mono_font);
+            let mut x = text_x + Self::column_offset(&line, column, mono_font) + shift;
+            shift += width;
+            if inline.padding_left {
+                x += space_width;
+            }
+            if let Some(background) = inline.background {
+                let text_width = mono_font.measure_str(&inline.text, None).0;
+                paint.set_color(background);
+                canvas.draw_round_rect(
+                    Rect::from_xywh(x, y_pos - 13.0, text_width + INLINE_TEXT_INSET * 2.0, self.line_height - 4.0),
+                    Theme::RADIUS_SM,
+                    Theme::RADIUS_SM,
+                    &paint,
+                );
+                x += INLINE_TEXT_INSET;
+            }
+            paint.set_color(inline.color);
+            canvas.draw_str(&inline.text, (x, y_pos), mono_font, &paint);
+        }
+    }
+    
+    /// Headers of the scopes the top of the view is in, with their row tops below the tab bar
+    fn sticky_rows(&self, tab: &EditorTab) -> Vec<(Scope, f32)> {
+        if !self.sticky_scroll || tab.is_hex() {
+            return Vec::new();
+        }
+        // Leave most of a short view to the text under the headers
+        let content_height = self.height - self.tab_bar.height();
+        let max_rows = STICKY_SCROLL_MAX_ROWS.min((content_height / self.line_height / 3.0) as usize);
+        let scopes = tab.highlighter.get_scopes(&tab.buffer.to_string());
+        let scroll = self.line_layout(tab).uniform_scroll(tab.scroll_offset);
+        Scope::sticky(&scopes, scroll, self.line_height, max_rows)
+    }
+    
+    /// Pinned scope headers over the top lines, above a border
+    fn draw_sticky_scroll(
+        &self,
+        canvas: &Canvas,
+        tab: &EditorTab,
+        mono_font: &Font,
+        highlights: &[(usize, usize, TokenType)],
+        content_y: f32,
+    ) {
+        let rows = self.sticky_rows(tab);
+        let Some(&(_, last_top)) = rows.last() else {
+            return;
+        };
+        let theme = current_theme();
+        let width = self.width - RULER_WIDTH;
+        let text_x = self.x + self.gutter_width + 10.0;
+        
+        canvas.save();
+        canvas.clip_rect(Rect::from_xywh(self.x, content_y, width, self.y + self.height - content_y), None, true);
+        
+        let mut bg_paint = Paint::default();
+        bg_paint.set_color(surface(theme.background));
+        let mut gutter_paint = Paint::default();
+        gutter_paint.set_color(surface(theme.card));
+        let mut line_num_paint = Paint::default();
+        line_num_paint.set_color(theme.muted_foreground);
+        line_num_paint.set_anti_alias(true);
+        
+        // Inner rows first, so outer ones sliding up cover them
+        for &(scope, top) in rows.iter().rev() {
+            let row_top = content_y + top;
+            canvas.draw_rect(Rect::from_xywh(self.x, row_top, width, self.line_height), &bg_paint);
+            canvas.draw_rect(Rect::from_xywh(self.x, row_top, self.gutter_width, self.line_height), &gutter_paint);
+            
+            let y_pos = row_top + 17.0;
+            let line_num = format!("{}", scope.line + 1);
+            let line_num_x = self.x + self.gutter_width - mono_font.measure_str(&line_num, None).0 - 15.0;
+            canvas.draw_str(&line_num, (line_num_x, y_pos), mono_font, &line_num_paint);
+            
+            self.draw_line_text(canvas, mono_font, tab, scope.line, highlights, (text_x, y_pos));
+        }
+        
+        let mut border_paint = Paint::default();
+        border_paint.set_color(theme.border);
+        canvas.draw_rect(Rect::from_xywh(self.x, content_y + last_top + self.line_height - 1.0, width, 1.0), &border_paint);
+        canvas.restore();
+    }
+    
+    /// Pinned header under a point; clicking one scrolls its first line to where the row is
+    fn sticky_row_at(&self, x: f32, y: f32) -> Option<(Scope, f32)> {
+        let content_y = self.y + self.tab_bar.height();
+        if x < self.x || x >= self.x + self.width - RULER_WIDTH || y < content_y {
+            return None;
+        }
+        let tab = self.tab_manager.get_active_tab()?;
+        // Outer rows are drawn over inner ones
+        self.sticky_rows(tab)
+            .into_iter()
+            .find(|(_, top)| (*top..*top + self.line_height).contains(&(y - content_y)))
+    }
+    
+    /// Draw a line containing right-to-left text glyph by glyph in visual order
+    fn draw_bidi_line(
+        &self,
+        canvas: &Canvas,
+        mono_font: &Font,
+        line_text: &str,
+        line_start_byte: usize,
+        highlights: &[(usize, usize, TokenType)],
+        (text_x, y_pos): (f32, f32),
+    ) {
+        let theme = current_theme();
+        let colors: Vec<Color> = line_text
+            .char_indices()
+            .map(|(byte, _)| {
+                let offset = line_start_byte + byte;
+                highlights
+                    .iter()
+                    .find(|(start, end, _)| *start <= offset && offset < *end)
+                    .map_or(theme.foreground, |(_, _, token_type)| self.get_token_color(*token_type))
+            })
+            .collect();
+        
+        let line = BidiLine::new(line_text, Some(TextDirection::Ltr));
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        let mut x = text_x;
+        let mut buf = [0u8; 4];
+        for &index in line.visual_order() {
+            let glyph: &str = line.display_char(index).encode_utf8(&mut buf);
+            paint.set_color(colors[index]);
+            canvas.draw_str(glyph, (x, y_pos), mono_font, &paint);
+            x += mono_font.measure_str(glyph, None).0;
+        }
+    }
+    
+    /// Draw whitespace markers for a line and highlight its trailing whitespace
+    fn draw_whitespace(
+        &self,
+        canvas: &Canvas,
+        mono_font: &Font,
+        line_text: &str,
+        inline: &InlineLayout,
+        (text_x, y_pos): (f32, f32),
+        highlight_trailing: bool,
+    ) {
+        let theme = current_theme();
+        let trailing_start = line_text.trim_end_matches([' ', '\t']).chars().count();
+        
+        let mut marker_paint = Paint::default();
+        marker_paint.set_color(with_alpha(theme.muted_foreground, 110));
+        marker_paint.set_anti_alias(true);
+        
+        let mut trailing_paint = Paint::default();
+        trailing_paint.set_color(with_alpha(theme.destructive, 90));
+        trailing_paint.set_anti_alias(true);
+        
+        let mut line_x = text_x;
+        for (i, ch) in line_text.chars().enumerate() {
+            let mut buf = [0u8; 4];
+            let ch_width = mono_font.measure_str(ch.encode_utf8(&mut buf), None).0;
+            let x = line_x + inline.char_shift(i);
+            
+            if ch == ' ' || ch == '\t' {
+                if highlight_trailing && i >= trailing_start {
+                    canvas.draw_rect(
+                        Rect::from_xywh(x, y_pos - 15.0, ch_width, self.line_height),
+                        &trailing_paint,
+                    );
+                }
+                
+                if self.render_whitespace {
+                    let marker = if ch == ' ' { "·" } else { "→" };
+                    let marker_width = mono_font.measure_str(marker, None).0;
+                    canvas.draw_str(
+                        marker,
+                        (x + (ch_width - marker_width) / 2.0, y_pos),
+                        mono_font,
+                        &marker_paint,
+                    );
+                }
+            }
+            
+            line_x += ch_width;
+        }
+    }
+    
+    /// Get current editor info for status bar
+    pub fn get_editor_info(&self) -> Option<(String, usize, usize)> {
+        if let Some(tab) = self.tab_manager.get_active_tab() {
+            // Hex tabs report the cursor as row and byte within the row
+            if let Some(ref hex) = tab.hex {
+                return Some((
+                    tab.get_language_display(),
+                    (hex.cursor / BYTES_PER_ROW) as usize + 1,
+                    (hex.cursor % BYTES_PER_ROW) as usize + 1,
+                ));
+            }
+            Some((
+                tab.get_language_display(),
+                tab.cursor_line + 1,
+                tab.cursor_column + 1,
+            ))
+        } else {
+            None
+        }
+    }
+    
+    /// Character right of the cursor in the active text tab, for the status bar; `None` at the end of a line
+    pub fn char_at_cursor(&self) -> Option<char> {
+        let tab = self.tab_manager.get_active_tab().filter(|tab| tab.hex.is_none())?;
+        tab.buffer
+            .line(tab.cursor_line)?
+            .chars()
+            .nth(tab.cursor_column)
+            .filter(|c| *c != '\n' && *c != '\r')
+    }
+    
+    /// Get the active document's encoding and line ending for the status bar
+    pub fn get_document_info(&self) -> Option<(Encoding, LineEnding)> {
+        self.tab_manager
+            .get_active_tab()
+            .map(|tab| (tab.buffer.encoding(), tab.buffer.line_ending()))
+    }
+    
+    /// Set the encoding and line ending used for new and ASCII-only files
+    pub fn set_defaults(&mut self, encoding: Encoding, line_ending: LineEnding) {
+        self.tab_manager.set_defaults(encoding, line_ending);
+    }
+    
+    /// Open files from `vfs` from now on, see `TabManager::set_vfs`
+    pub fn set_vfs(&mut self, vfs: std::sync::Arc<dyn mikocore::Vfs>) {
+        self.tab_manager.set_vfs(vfs);
+    }
+    
+    /// Save the active document with a different encoding
+    pub fn set_active_encoding(&mut self, encoding: Encoding) {
+        if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            tab.buffer.set_encoding(encoding);
+        }
+    }
+    
+    /// Change the line ending sequence of the active document
+    pub fn set_active_line_ending(&mut self, line_ending: LineEnding) {
+        if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            tab.buffer.set_line_ending(line_ending);
+        }
+    }
+    
+    /// Re-read the active document from disk with a different encoding
+    pub fn reopen_active_with_encoding(&mut self, encoding: Encoding) -> std::io::Result<()> {
+        if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            let bookmarks = tab.bookmarks();
+            tab.buffer.reopen_with_encoding(encoding)?;
+            tab.set_bookmarks(&bookmarks);
+            tab.highlighter.parse(&tab.buffer.to_string());
+            tab.clamp_cursor();
+            tab.selection_start = None;
+            tab.selection_end = None;
+        }
+        Ok(())
+    }
+    
+    /// Re-read every tab showing or previewing `path` after the file changed on disk
+    ///
+    /// Tabs with unsaved edits are left alone. Returns true when a tab's content changed.
+    pub fn reload_file(&mut self, path: &std::path::Path) -> std::io::Result<bool> {
+        let mut reloaded = false;
+        for index in 0..self.tab_manager.tab_count() {
+            let Some(tab) = self.tab_manager.get_tab_mut(index) else {
+                continue;
+            };
+            if let Some(ref mut preview) = tab.preview {
+                reloaded |= preview.path() == path && preview.reload()?;
+                continue;
+            }
+            if tab.buffer.file_path().map(|p| p.as_path()) != Some(path) || tab.is_modified() {
+                continue;
+            }
+            if let Some(ref mut hex) = tab.hex {
+                let (cursor, scroll_row) = (hex.cursor, hex.scroll_row);
+                let mut fresh = HexEditor::open(path.to_path_buf())?;
+                fresh.set_cursor(cursor);
+                fresh.scroll_row = scroll_row.min(fresh.row_count() - 1);
+                fresh.ascii_focus = hex.ascii_focus;
+                *hex = fresh;
+                reloaded = true;
+                continue;
+            }
+            // Reopening drops decorations, so skip it when only the timestamp moved
+            let encoding = tab.buffer.encoding();
+            let text = encoding.decode(&tab.buffer.vfs().read(path)?).replace("\r\n", "\n");
+            if text == tab.buffer.to_string() {
+                continue;
+            }
+            // Bookmarks stay on their line numbers, as far as the file still reaches
+            let bookmarks = tab.bookmarks();
+            tab.buffer.reopen_with_encoding(encoding)?;
+            tab.set_bookmarks(&bookmarks);
+            tab.highlighter.parse(&tab.buffer.to_string());
+            tab.clamp_cursor();
+            tab.selection_start = None;
+            tab.selection_end = None;
+            reloaded = true;
+        }
+        Ok(reloaded)
+    }
+    
+    /// Open recovered text as an unsaved edit: over its file when that still exists, else in a new tab
+    pub fn restore_text(&mut self, path: Option<&std::path::Path>, title: &str, text: &str) -> std::io::Result<()> {
+        match path.filter(|path| self.tab_manager.vfs().is_file(path)) {
+            Some(path) => {
+                let open = self.tab_manager.tabs().iter().position(|tab| tab.buffer.file_path().map(|p| p.as_path()) == Some(path));
+                match open {
+                    Some(index) => self.tab_manager.set_active_tab(index),
+                    None => self.open_file(path.to_path_buf())?,
+                }
+            }
+            None => {
+                self.new_tab();
+                if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+                    tab.title = title.to_string();
+                }
+            }
+        }
+        let Some(tab) = self.tab_manager.get_active_tab_mut().filter(|tab| tab.hex.is_none()) else {
+            return Ok(());
+        };
+        if tab.buffer.to_string() != text {
+            tab.buffer.replace_text(text);
+            tab.highlighter.parse(&tab.buffer.to_string());
+            tab.clamp_cursor();
+        }
+        Ok(())
+    }
+    
+    pub fn auto_save_mode(&self) -> AutoSaveMode {
+        self.auto_save.mode()
+    }
+    
+    pub fn set_auto_save_mode(&mut self, mode: AutoSaveMode) {
+        self.auto_save.set_mode(mode);
+    }
+    
+    /// Look for edits and tab switches; true when auto save wants the modified files saved now
+    pub fn poll_auto_save(&mut self) -> bool {
+        self.auto_save.observe(&self.tab_manager);
+        self.auto_save.is_due()
+    }
+    
+    /// Auto save on focus change saves when the window loses focus
+    pub fn window_focus_lost(&mut self) {
+        self.auto_save.window_focus_lost();
+    }
+    
+    /// When the auto save delay runs out
+    pub fn auto_save_deadline(&self) -> Option<Instant> {
+        self.auto_save.deadline()
+    }
+    
+    /// Modified files auto save will write; untitled tabs have nowhere to go
+    pub fn pending_auto_saves(&self) -> usize {
+        if self.auto_save.mode() == AutoSaveMode::Off {
+            return 0;
+        }
+        self.tab_manager.tabs().iter().filter(|tab| tab.is_modified() && tab.buffer.file_path().is_some()).count()
+    }
+    
+    /// Save every modified tab that has a file, with the cleanups `options` gives for its language
+    ///
+    /// Returns the files that failed to save.
+    pub fn save_modified_files(&mut self, options: impl Fn(Option<&str>) -> SaveOptions) -> Vec<(std::path::PathBuf, std::io::Error)> {
+        let mut failed = Vec::new();
+        for index in 0..self.tab_manager.tab_count() {
+            let Some(tab) = self.tab_manager.get_tab_mut(index) else {
+                continue;
+            };
+            let Some(path) = tab.buffer.file_path().cloned() else {
+                continue;
+            };
+            if !tab.is_modified() {
+                continue;
+            }
+            let result = match tab.hex {
+                Some(ref mut hex) => hex.buffer.save(),
+                None => {
+                    if tab.buffer.apply_save_options(&options(tab.buffer.language())) {
+                        tab.highlighter.parse(&tab.buffer.to_string());
+                        tab.clamp_cursor();
+                    }
+                    tab.buffer.save()
+                }
+            };
+            if let Err(e) = result {
+                failed.push((path, e));
+            }
+        }
+        self.auto_save.saved();
+        failed
+    }
+    
+    /// Save the active document to its file, applying save-time cleanups first
+    pub fn save_active_file(&mut self, options: &SaveOptions) -> std::io::Result<()> {
+        if let Some(editor) = self.focused_peek_mut() {
+            return editor.save_active_file(options);
+        }
+        if let Some(hex) = self.active_hex_mut() {
+            return hex.buffer.save();
+        }
+        if let Some(tab) = self.tab_manager.get_active_tab_mut().filter(|tab| tab.preview.is_none()) {
+            if tab.buffer.apply_save_options(options) {
+                tab.highlighter.parse(&tab.buffer.to_string());
+                tab.clamp_cursor();
+            }
+            tab.buffer.save()?;
+        }
+        Ok(())
+    }
+    
+    /// Run `formatter` over the active document and replace it, keeping the cursor on the same token
+    ///
+    /// Returns false when the document was already formatted.
+    pub fn format_active(&mut self, formatter: &Formatter) -> std::io::Result<bool> {
+        let Some(tab) = self.tab_manager.get_active_tab_mut().filter(|tab| tab.hex.is_none()) else {
+            return Ok(false);
+        };
+        
+        let text = tab.buffer.to_string();
+        let formatted = formatter.run(&text, tab.buffer.file_path().map(|path| path.as_path()))?.replace("\r\n", "\n");
+        if formatted == text {
+            return Ok(false);
+        }
+        
+        let cursor = tab.buffer.line_to_char(tab.cursor_line) + tab.cursor_column;
+        let new_cursor = map_offset(&text, cursor, &formatted);
+        tab.buffer.replace_text(&formatted);
+        tab.clear_column_selection();
+        tab.selection_start = None;
+        tab.cursor_line = tab.buffer.char_to_line(new_cursor);
+        tab.cursor_column = new_cursor - tab.buffer.line_to_char(tab.cursor_line);
+        tab.clamp_cursor();
+        tab.highlighter.parse(&formatted);
+        self.completion = None;
+        Ok(true)
+    }
+    
+    /// Remove trailing whitespace from every line of the active document
+    pub fn trim_trailing_whitespace(&mut self) {
+        if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            if tab.buffer.trim_trailing_whitespace() {
+                tab.highlighter.parse(&tab.buffer.to_string());
+                tab.clamp_cursor();
+                tab.selection_start = None;
+                tab.selection_end = None;
+            }
+        }
+    }
+    
+    /// Indent: insert one indentation level at the cursor, or indent every selected line
+    pub fn indent(&mut self) {
+        // Tab switches between the hex and ASCII columns
+        if let Some(hex) = self.active_hex_mut() {
+            hex.toggle_focus();
+            return;
+        }
+        if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            let (start, end) = tab.selected_lines();
+            if tab.has_selection() && start != end {
+                let unit = tab.indent.unit();
+                tab.replace_leading_whitespace(start, end, |leading| format!("{}{}", leading, unit));
+            } else {
+                if tab.has_selection() {
+                    tab.delete_selection();
+                }
+                
+                // Pad to the next tab stop when using spaces
+                let text = if tab.indent.use_spaces {
+                    let before_cursor: String = tab.buffer.line(tab.cursor_line)
+                        .map(|line| line.chars().take(tab.cursor_column).collect())
+                        .unwrap_or_default();
+                    let column = tab.indent.columns(&before_cursor);
+                    " ".repeat(tab.indent.width - column % tab.indent.width)
+                } else {
+                    "\t".to_string()
+                };
+                
+                let char_idx = tab.buffer.line_to_char(tab.cursor_line) + tab.cursor_column;
+                tab.buffer.insert(char_idx, &text);
+                tab.cursor_column += text.chars().count();
+                tab.highlighter.parse(&tab.buffer.to_string());
+            }
+            
+            self.cursor_blink_time = 0.0;
+            self.show_cursor = true;
+        }
+    }
+    
+    /// Outdent: remove one indentation level from the selected lines (or the cursor line)
+    pub fn outdent(&mut self) {
+        if let Some(hex) = self.active_hex_mut() {
+            hex.toggle_focus();
+            return;
+        }
+        if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            let (start, end) = tab.selected_lines();
+            let indent = tab.indent;
+            tab.replace_leading_whitespace(start, end, |leading| {
+                let columns = indent.columns(leading);
+                let target = columns.saturating_sub(1) / indent.width * indent.width;
+                indent.whitespace_for(target)
+            });
+        }
+    }
+    
+    /// Convert the indentation of the selected lines (or the whole document) to the active style
+    pub fn reindent_lines(&mut self) {
+        if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            let (start, end) = if tab.has_selection() {
+                tab.selected_lines()
+            } else {
+                (0, tab.buffer.len_lines().saturating_sub(1))
+            };
+            let indent = tab.indent;
+            tab.replace_leading_whitespace(start, end, |leading| {
+                indent.whitespace_for(indent.columns(leading))
+            });
+        }
+    }
+    
+    /// Indentation of the active document
+    pub fn active_indent(&self) -> Option<IndentStyle> {
+        self.tab_manager.get_active_tab().map(|tab| tab.indent)
+    }
+    
+    /// Change the indentation used by the active document (existing text is unchanged)
+    pub fn set_active_indent(&mut self, indent: IndentStyle) {
+        if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            tab.indent = indent;
+        }
+    }
+    
+    /// Re-detect the active document's indentation from its contents
+    pub fn detect_active_indent(&mut self) {
+        let Some(tab) = self.tab_manager.get_active_tab() else {
+            return;
+        };
+        let default = self.tab_manager.indent_for(tab.buffer.language());
+        let detected = IndentStyle::detect(&tab.buffer.to_string(), default);
+        self.set_active_indent(detected);
+    }
+    
+    /// Set the indentation used for new files and per language
+    pub fn set_indent_defaults(
+        &mut self,
+        default: IndentStyle,
+        language_indents: std::collections::HashMap<String, IndentStyle>,
+        detect: bool,
+    ) {
+        self.tab_manager.set_indent_defaults(default, language_indents, detect);
+    }
+    
+    /// Function and type definitions in the active document
+    pub fn document_symbols(&self) -> Vec<DocumentSymbol> {
+        self.tab_manager
+            .get_active_tab()
+            .map(|tab| tab.highlighter.get_symbols(&tab.buffer.to_string()))
+            .unwrap_or_default()
+    }
+    
+    /// Number of lines in the active document
+    pub fn line_count(&self) -> usize {
+        self.tab_manager
+            .get_active_tab()
+            .map(|tab| match tab.hex {
+                Some(ref hex) => hex.row_count() as usize,
+                None => tab.buffer.len_lines(),
+            })
+            .unwrap_or(0)
+    }
+    
+    /// Move the cursor to a zero-based line and column (clamped) and scroll it into view
+    pub fn go_to_position(&mut self, line: usize, column: usize) {
+        let content_height = self.height - self.tab_bar.height();
+        
+        // Hex tabs treat lines as rows and columns as bytes within the row
+        let visible_rows = self.hex_visible_rows();
+        if let Some(hex) = self.active_hex_mut() {
+            hex.set_cursor(line as u64 * BYTES_PER_ROW + (column as u64).min(BYTES_PER_ROW - 1));
+            hex.reveal_cursor(visible_rows);
+            return;
+        }
+        
+        let layout = self.tab_manager.get_active_tab().map(|tab| self.line_layout(tab)).unwrap_or_default();
+        let Some(tab) = self.tab_manager.get_active_tab_mut() else {
+            return;
+        };
+        tab.clear_column_selection();
+        tab.selection_start = None;
+        tab.cursor_line = line;
+        tab.cursor_column = column;
+        tab.clamp_cursor();
+        let cursor_y = layout.line_top(tab.cursor_line);
+        self.cursor_blink_time = 0.0;
+        self.show_cursor = true;
+        
+        // Center the target line when it's off screen
+        let scroll = self.scroll_target();
+        if cursor_y < scroll || cursor_y + self.line_height > scroll + content_height {
+            self.scroll_to(cursor_y - content_height / 2.0);
+        }
+    }
+    
+    /// Move the cursor to the partner of the bracket next to it; false without one
+    pub fn go_to_bracket(&mut self) -> bool {
+        self.refresh_bracket_match();
+        let Some((_, (line, column))) = self.bracket_match else {
+            return false;
+        };
+        self.go_to_position(line, column);
+        true
+    }
+    
+    /// Find the bracket pair at the cursor again if the text, cursor or tab changed since
+    fn refresh_bracket_match(&mut self) {
+        let Some(tab) = self.tab_manager.get_active_tab().filter(|tab| !tab.is_hex()) else {
+            (self.bracket_match, self.bracket_match_key) = (None, None);
+            return;
+        };
+        let key = Some((tab.id, tab.buffer.version(), (tab.cursor_line, tab.cursor_column)));
+        if key != self.bracket_match_key {
+            (self.bracket_match, self.bracket_match_key) = (tab.matching_bracket(), key);
+        }
+    }
+    
+    /// Move to the start of the next merge conflict below the cursor, wrapping around; false without any
+    pub fn next_conflict(&mut self) -> bool {
+        self.go_to_conflict(true)
+    }
+    
+    /// Move to the start of the merge conflict above the cursor, wrapping around; false without any
+    pub fn previous_conflict(&mut self) -> bool {
+        self.go_to_conflict(false)
+    }
+    
+    fn go_to_conflict(&mut self, forward: bool) -> bool {
+        let Some(tab) = self.tab_manager.get_active_tab_mut().filter(|tab| tab.hex.is_none()) else {
+            return false;
+        };
+        tab.refresh_conflicts();
+        let line = tab.cursor_line;
+        let starts: Vec<usize> = tab.conflicts().iter().map(|conflict| conflict.start).collect();
+        let target = if forward {
+            starts.iter().find(|&&start| start > line).or(starts.first())
+        } else {
+            starts.iter().rev().find(|&&start| start < line).or(starts.last())
+        };
+        let Some(&target) = target else {
+            return false;
+        };
+        self.go_to_position(target, 0);
+        true
+    }
+    
+    /// Language id of the active document (e.g. "rust")
+    pub fn active_language(&self) -> Option<String> {
+        self.tab_manager
+            .get_active_tab()
+            .and_then(|tab| tab.buffer.language().map(String::from))
+    }
+    
+    /// Change the active tab's language mode; `None` detects it again from the file name and contents
+    pub fn set_active_language(&mut self, language: Option<&str>) {
+        let Some(tab) = self.tab_manager.get_active_tab_mut().filter(|tab| tab.hex.is_none()) else {
+            return;
+        };
+        let language = match language {
+            Some(language) => language,
+            None => detect_language(tab.buffer.file_path().map(|path| path.as_path()), &tab.buffer.to_string()),
+        };
+        tab.set_language(language);
+        // Brackets differ between languages
+        self.bracket_match_key = None;
+    }
+    
+    pub fn render_whitespace(&self) -> bool {
+        self.render_whitespace
+    }
+    
+    /// Draw middots for spaces and arrows for tabs
+    pub fn set_render_whitespace(&mut self, render: bool) {
+        self.render_whitespace = render;
+    }
+    
+    pub fn toggle_render_whitespace(&mut self) {
+        self.render_whitespace = !self.render_whitespace;
+    }
+    
+    /// Columns to draw ruler lines at, with per-language replacements keyed by language id
+    pub fn set_rulers(&mut self, default: Vec<usize>, language_rulers: std::collections::HashMap<String, Vec<usize>>) {
+        self.rulers = default;
+        self.language_rulers = language_rulers;
+    }
+    
+    pub fn todo_keywords(&self) -> &[String] {
+        &self.todo_keywords
+    }
+    
+    /// Look for to-do comments in the tabs whose text changed since the last look
+    pub fn refresh_todos(&mut self) {
+        for tab in self.tab_manager.tabs_mut() {
+            tab.refresh_todos(&self.todo_keywords);
+        }
+    }
+    
+    /// Words that mark a comment as a to-do; open tabs are searched again with them
+    pub fn set_todo_keywords(&mut self, keywords: Vec<String>) {
+        if keywords == self.todo_keywords {
+            return;
+        }
+        self.todo_keywords = keywords;
+        for tab in self.tab_manager.tabs_mut() {
+            tab.todos.version = None;
+        }
+    }
+    
+    pub fn set_highlight_trailing_whitespace(&mut self, highlight: bool) {
+        self.highlight_trailing_whitespace = highlight;
+    }
+    
+    /// Pin the headers of enclosing functions and blocks at the top while scrolling
+    pub fn set_sticky_scroll(&mut self, sticky: bool) {
+        self.sticky_scroll = sticky;
+    }
+    
+    pub fn inlay_hints(&self) -> bool {
+        self.inlay_hints
+    }
+    
+    /// Show inline text decorations, such as parameter name and type hints
+    pub fn set_inlay_hints(&mut self, show: bool) {
+        self.inlay_hints = show;
+    }
+    
+    pub fn toggle_inlay_hints(&mut self) {
+        self.inlay_hints = !self.inlay_hints;
+    }
+    
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+        self.tab_bar.set_bounds(x, y, width);
+    }
+    
+    pub fn update_hover(&mut self, x: f32, y: f32, mono_font: &Font) {
+        self.tab_bar.update_hover(x, y, &self.tab_manager);
+        self.scrollbar.update_hover(x, y);
+        self.update_hover_card(x, y, mono_font);
+        if let Some(peek) = self.peek.as_mut() {
+            peek.editor.update_hover(x, y, mono_font);
+        }
+    }
+    
+    /// Point the scrollbar at the active text tab; hex tabs scroll by rows and have none
+    fn sync_scrollbar(&mut self) {
+        let tab_bar_height = self.tab_bar.height();
+        let content_height = self.height - tab_bar_height;
+        self.scrollbar.attach_to(Rect::from_xywh(self.x, self.y + tab_bar_height, self.width, content_height));
+        match self.tab_manager.get_active_tab().filter(|tab| tab.hex.is_none()) {
+            Some(tab) => {
+                let total_height = self.line_layout(tab).document_height(tab.buffer.len_lines().max(1));
+                self.scrollbar.set_metrics(total_height, content_height);
+                self.scrollbar.set_offset(tab.scroll_offset);
+            }
+            None => self.scrollbar.set_metrics(0.0, content_height),
+        }
+    }
+    
+    /// Copy a scrollbar drag or track click back to the active tab
+    fn apply_scrollbar_offset(&mut self) {
+        let offset = self.scrollbar.offset();
+        self.hover = None;
+        self.smooth_scroll.jump(offset);
+        if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            tab.scroll_offset = offset;
+        }
+    }
+    
+    /// Whether the scrollbar is fading or being dragged, the text is gliding or a tab is fading in, and needs more frames
+    pub fn is_animating(&self) -> bool {
+        self.scrollbar.is_animating()
+            || self.scrollbar.is_dragging()
+            || self.smooth_scroll.is_animating()
+            || self.switch_fade < 1.0
+            || self.hover.as_ref().is_some_and(|hover| hover.card.is_pending())
+            || self.peek.as_ref().is_some_and(|peek| peek.editor.is_animating())
+    }
+    
+    pub fn update_animation(&mut self, elapsed: f32) {
+        self.refresh_bracket_match();
+        
+        // Each tab keeps its own scroll position and cursor; switching fades the new one in
+        let active = self.tab_manager.get_active_tab().map(|tab| tab.id);
+        if active != self.shown_tab {
+            // Whether the glide moved the tab left behind or the one switched to, e.g. by
+            // opening a file at a line, the new tab shows at once
+            self.finish_scroll();
+            if self.shown_tab.is_some() {
+                self.switch_fade = 0.0;
+            }
+            self.shown_tab = active;
+            self.completion = None;
+            self.hover = None;
+        }
+        // Typing or moving the cursor dismisses the hover card
+        let cursor = self.tab_manager.get_active_tab().map(|tab| (tab.cursor_line, tab.cursor_column));
+        if self.hover.as_ref().is_some_and(|hover| Some(hover.cursor) != cursor) {
+            self.hover = None;
+        }
+        self.clock = elapsed;
+        if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            tab.refresh_conflicts();
+        }
+        self.refresh_todos();
+        if let Some(hover) = self.hover.as_mut() {
+            hover.card.update_animation(elapsed);
+        }
+        if self.smooth_scroll.is_animating() {
+            let offset = self.smooth_scroll.update(elapsed);
+            if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+                tab.scroll_offset = offset;
+            }
+        }
+        
+        // A peek view closes with its host tab, and moves with the host's text
+        if let Some(peek) = self.peek.as_ref() {
+            if !self.tab_manager.tabs().iter().any(|tab| tab.id == peek.host_tab) {
+                self.peek = None;
+            }
+        }
+        self.layout_peek();
+        if let Some(peek) = self.peek.as_mut() {
+            peek.editor.update_animation(elapsed);
+        }
+        
         self.tab_bar.update_animation(self.tab_manager.tab_count());
+        self.sync_scrollbar();
+        self.scrollbar.update_animation(elapsed);
+        if self.switch_fade < 1.0 {
+            self.switch_fade += (1.0 - self.switch_fade) * animation_rate(0.3);
+            if self.switch_fade > 0.98 {
+                self.switch_fade = 1.0;
+            }
+        }
         
         // Cursor blink animation
         self.cursor_blink_time += elapsed;
@@ -312,7 +2550,24 @@ impl Editor {
     }
     
     pub fn insert_char(&mut self, c: char) {
+        let visible_rows = self.hex_visible_rows();
+        if let Some(hex) = self.active_hex_mut() {
+            hex.input_char(c);
+            hex.reveal_cursor(visible_rows);
+            self.cursor_blink_time = 0.0;
+            self.show_cursor = true;
+            return;
+        }
+        
         if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            // Box selection types on every row
+            if tab.column_anchor.is_some() {
+                tab.column_insert(&c.to_string());
+                self.cursor_blink_time = 0.0;
+                self.show_cursor = true;
+                return;
+            }
+            
             // If there's a selection, delete it first
             if tab.has_selection() {
                 tab.delete_selection();
@@ -337,10 +2592,55 @@ impl Editor {
             self.cursor_blink_time = 0.0;
             self.show_cursor = true;
         }
+        
+        // Words and path separators open or narrow the completion popup
+        if is_word_char(c) || matches!(c, '/' | '.' | '-') {
+            self.update_completion(false);
+        } else {
+            self.completion = None;
+        }
+    }
+    
+    /// Type a char as from the keyboard, with the language's auto-close pairs
+    fn type_char(&mut self, c: char) {
+        let paired = !self.is_active_hex() && self.tab_manager.get_active_tab_mut().is_some_and(|tab| tab.type_paired(c));
+        if !paired {
+            self.insert_char(c);
+            return;
+        }
+        self.completion = None;
+        self.cursor_blink_time = 0.0;
+        self.show_cursor = true;
+    }
+    
+    /// Toggle comments on the active tab, e.g. with `EditorTab::toggle_line_comment`
+    fn toggle_comment(&mut self, toggle: fn(&mut EditorTab)) {
+        self.completion = None;
+        if self.is_active_hex() {
+            return;
+        }
+        if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            toggle(tab);
+            self.cursor_blink_time = 0.0;
+            self.show_cursor = true;
+        }
     }
     
     pub fn delete_char(&mut self) {
+        // Bytes are overwritten in place, Backspace just steps back
+        if self.is_active_hex() {
+            self.hex_move(-1);
+            return;
+        }
+        
         if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            if tab.column_anchor.is_some() {
+                tab.column_backspace();
+                self.cursor_blink_time = 0.0;
+                self.show_cursor = true;
+                return;
+            }
+            
             // If there's a selection, delete it instead
             if tab.has_selection() {
                 tab.delete_selection();
@@ -349,6 +2649,13 @@ impl Editor {
                 return;
             }
             
+            // Between an auto-closed pair, e.g. `(|)`, both halves go
+            if tab.delete_pair() {
+                self.cursor_blink_time = 0.0;
+                self.show_cursor = true;
+                return;
+            }
+            
             if tab.cursor_column > 0 {
                 // Calculate character index from cursor position (using char count, not bytes)
                 let mut char_idx = 0;
@@ -403,26 +2710,45 @@ impl Editor {
                 }
             }
         }
+        
+        if self.completion.is_some() {
+            self.update_completion(false);
+        }
     }
     
     pub fn insert_newline(&mut self) {
+        self.completion = None;
+        if self.is_active_hex() {
+            return;
+        }
+        
         if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            tab.clear_column_selection();
+            
             // If there's a selection, delete it first
             if tab.has_selection() {
                 tab.delete_selection();
             }
             
-            let mut char_idx = 0;
-            for line_idx in 0..tab.cursor_line {
-                if let Some(line) = tab.buffer.line(line_idx) {
-                    char_idx += line.len();
-                }
+            let char_idx = tab.buffer.line_to_char(tab.cursor_line) + tab.cursor_column;
+            
+            // Auto-indent: keep the current indentation, one level deeper after an opening bracket
+            let before_cursor: String = tab.buffer.line(tab.cursor_line)
+                .map(|line| line.chars().take(tab.cursor_column).collect())
+                .unwrap_or_default();
+            let mut indent: String = before_cursor.chars().take_while(|c| *c == ' ' || *c == '\t').collect();
+            let opens_block = match before_cursor.trim_end().chars().last() {
+                Some('{') | Some('(') | Some('[') => true,
+                Some(':') => tab.buffer.language() == Some("python"),
+                _ => false,
+            };
+            if opens_block {
+                indent.push_str(&tab.indent.unit());
             }
-            char_idx += tab.cursor_column;
             
-            tab.buffer.insert(char_idx, "\n");
+            tab.buffer.insert(char_idx, &format!("\n{}", indent));
             tab.cursor_line += 1;
-            tab.cursor_column = 0;
+            tab.cursor_column = indent.chars().count();
             
             // Re-parse for syntax highlighting
             tab.highlighter.parse(&tab.buffer.to_string());
@@ -431,11 +2757,30 @@ impl Editor {
             self.cursor_blink_time = 0.0;
             self.show_cursor = true;
         }
+        self.reveal_cursor();
+    }
+    
+    /// Bidi layout of a line, if it contains right-to-left text
+    fn bidi_line(tab: &EditorTab, line_idx: usize) -> Option<BidiLine> {
+        let line = tab.buffer.line(line_idx)?;
+        let text = line.trim_end_matches(['\n', '\r']);
+        contains_rtl(text).then(|| BidiLine::new(text, Some(TextDirection::Ltr)))
     }
     
     pub fn move_cursor_left(&mut self) {
+        self.completion = None;
+        if self.is_active_hex() {
+            self.hex_move(-1);
+            return;
+        }
+        
         if let Some(tab) = self.tab_manager.get_active_tab_mut() {
-            if tab.cursor_column > 0 {
+            tab.clear_column_selection();
+            // Lines with right-to-left runs move on screen, wrapping at the left edge
+            let visual = Self::bidi_line(tab, tab.cursor_line).map(|line| line.move_visual(tab.cursor_column, false));
+            if let Some(Some(column)) = visual {
+                tab.cursor_column = column;
+            } else if visual.is_none() && tab.cursor_column > 0 {
                 tab.cursor_column -= 1;
             } else if tab.cursor_line > 0 {
                 tab.cursor_line -= 1;
@@ -446,13 +2791,24 @@ impl Editor {
             self.cursor_blink_time = 0.0;
             self.show_cursor = true;
         }
+        self.reveal_cursor();
     }
     
     pub fn move_cursor_right(&mut self) {
+        self.completion = None;
+        if self.is_active_hex() {
+            self.hex_move(1);
+            return;
+        }
+        
         if let Some(tab) = self.tab_manager.get_active_tab_mut() {
-            if let Some(line) = tab.buffer.line(tab.cursor_line) {
+            tab.clear_column_selection();
+            let visual = Self::bidi_line(tab, tab.cursor_line).map(|line| line.move_visual(tab.cursor_column, true));
+            if let Some(Some(column)) = visual {
+                tab.cursor_column = column;
+            } else if let Some(line) = tab.buffer.line(tab.cursor_line) {
                 let line_len = line.chars().count();  // Count characters, not bytes
-                if tab.cursor_column < line_len {
+                if visual.is_none() && tab.cursor_column < line_len {
                     tab.cursor_column += 1;
                 } else if tab.cursor_line < tab.buffer.len_lines() - 1 {
                     tab.cursor_line += 1;
@@ -462,10 +2818,17 @@ impl Editor {
             self.cursor_blink_time = 0.0;
             self.show_cursor = true;
         }
+        self.reveal_cursor();
     }
     
     pub fn move_cursor_up(&mut self) {
+        if self.is_active_hex() {
+            self.hex_move(-(BYTES_PER_ROW as i64));
+            return;
+        }
+        
         if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            tab.clear_column_selection();
             if tab.cursor_line > 0 {
                 tab.cursor_line -= 1;
                 if let Some(line) = tab.buffer.line(tab.cursor_line) {
@@ -476,10 +2839,17 @@ impl Editor {
             self.cursor_blink_time = 0.0;
             self.show_cursor = true;
         }
+        self.reveal_cursor();
     }
     
     pub fn move_cursor_down(&mut self) {
+        if self.is_active_hex() {
+            self.hex_move(BYTES_PER_ROW as i64);
+            return;
+        }
+        
         if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            tab.clear_column_selection();
             if tab.cursor_line < tab.buffer.len_lines() - 1 {
                 tab.cursor_line += 1;
                 if let Some(line) = tab.buffer.line(tab.cursor_line) {
@@ -490,9 +2860,15 @@ impl Editor {
             self.cursor_blink_time = 0.0;
             self.show_cursor = true;
         }
+        self.reveal_cursor();
     }
     
     pub fn handle_click(&mut self, x: f32, y: f32, mono_font: &Font) -> bool {
+        self.completion = None;
+        if self.hover.as_ref().is_some_and(|hover| hover.card.contains(x, y)) {
+            return true;
+        }
+        
         // Check if clicking on close button
         if let Some(tab_index) = self.tab_bar.get_close_button_clicked(x, y, &self.tab_manager) {
             self.tab_manager.close_tab(tab_index);
@@ -505,35 +2881,118 @@ impl Editor {
             return true;
         }
         
+        // Ruler marks jump to their line; elsewhere on the track the scrollbar drags or pages
+        if let Some(line) = self.ruler_annotation_at(x, y) {
+            self.go_to_position(line, 0);
+            return true;
+        }
+        
+        // Scrollbar drag or page jump
+        self.sync_scrollbar();
+        if self.scrollbar.handle_mouse_down(x, y) {
+            self.apply_scrollbar_offset();
+            return true;
+        }
+        
+        // Pinned headers jump to their scope's first line
+        if let Some((scope, top)) = self.sticky_row_at(x, y) {
+            let (indent, line_top) = self.tab_manager.get_active_tab().map_or((0, 0.0), |tab| {
+                let indent = tab.buffer.line(scope.line).map_or(0, |line| line.chars().take_while(|c| *c == ' ' || *c == '\t').count());
+                (indent, self.line_layout(tab).line_top(scope.line))
+            });
+            self.go_to_position(scope.line, indent);
+            self.scroll_to(line_top - top.max(0.0));
+            return true;
+        }
+        
+        // Clicks in a peek view focus it, and its tab's close button closes it
+        self.layout_peek();
+        if self.peek_contains(x, y) {
+            let closing = self.peek
+                .as_ref()
+                .is_some_and(|peek| peek.editor.tab_bar.get_close_button_clicked(x, y, &peek.editor.tab_manager).is_some());
+            if closing {
+                self.close_peek();
+            } else if let Some(peek) = self.peek.as_mut() {
+                peek.focused = true;
+                peek.editor.handle_click(x, y, mono_font);
+            }
+            return true;
+        }
+        if let Some(peek) = self.peek.as_mut() {
+            peek.focused = false;
+        }
+        
+        // View zones take their own clicks, and keep them from the text
+        if y >= self.y + self.tab_bar.height() && !self.is_active_hex() {
+            let rects = self.tab_manager.get_active_tab().map(|tab| self.zone_rects(tab)).unwrap_or_default();
+            if let Some(index) = rects.iter().position(|rect| x >= rect.left && x < rect.right && y >= rect.top && y < rect.bottom) {
+                if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+                    if let Some(zone) = tab.zones.iter_mut().nth(index) {
+                        zone.widget.on_click(x, y, rects[index]);
+                    }
+                    // Accept buttons of a merge conflict
+                    if let Some((conflict, resolution)) = tab.conflicts.clicked.take() {
+                        tab.resolve_conflict(conflict, resolution);
+                        self.completion = None;
+                    }
+                }
+                return true;
+            }
+        }
+        
         // Check if clicking in editor content area
         let tab_bar_height = self.tab_bar.height();
         let content_y = self.y + tab_bar_height;
         let content_height = self.height - tab_bar_height;
         let text_x = self.x + self.gutter_width + 10.0;
         
+        if self.is_active_hex() {
+            return self.hex_click(x, y, content_y, mono_font);
+        }
+        
+        // The strip left of the line numbers, where gutter icons sit, toggles a bookmark
+        if x >= self.x && x < self.x + BOOKMARK_STRIP_WIDTH && y >= content_y && y < content_y + content_height {
+            let line = self.tab_manager.get_active_tab().map(|tab| self.line_layout(tab).line_at(y - content_y + tab.scroll_offset));
+            if let (Some(line), Some(tab)) = (line, self.tab_manager.get_active_tab_mut()) {
+                if line < tab.buffer.len_lines() {
+                    tab.toggle_bookmark(line);
+                }
+            }
+            return true;
+        }
+        
         if x >= text_x && x < self.x + self.width && 
            y >= content_y && y < content_y + content_height {
+            // Calculate which line was clicked; inline text moves the columns behind it
+            let (clicked_line, inline) = self.tab_manager.get_active_tab().map_or((0, InlineLayout::default()), |tab| {
+                let line = self.line_layout(tab).line_at(y - content_y + tab.scroll_offset);
+                (line, self.inline_layout(tab, line, mono_font))
+            });
             if let Some(tab) = self.tab_manager.get_active_tab_mut() {
-                // Calculate which line was clicked
-                let relative_y = y - content_y + tab.scroll_offset;
-                let clicked_line = (relative_y / self.line_height) as usize;
+                tab.clear_column_selection();
                 
                 if clicked_line < tab.buffer.len_lines() {
                     // Calculate which column was clicked
                     if let Some(line) = tab.buffer.line(clicked_line) {
                         let relative_x = x - text_x;
-                        let mut current_x = 0.0;
                         let chars: Vec<char> = line.chars().collect();
-                        let mut clicked_col = 0;
+                        let clicked_col = Self::column_at_x(&line, inline.unshift(relative_x), mono_font);
                         
-                        for (i, ch) in chars.iter().enumerate() {
-                            let char_width = mono_font.measure_str(&ch.to_string(), None).0;
-                            if current_x + char_width / 2.0 > relative_x {
-                                clicked_col = i;
-                                break;
-                            }
-                            current_x += char_width;
-                            clicked_col = i + 1;
+                        // A press inside the selection may drag it; it is a plain click if the mouse stays put
+                        let position = (clicked_line, clicked_col.min(chars.len()));
+                        if let Some(range) = tab.selection_range().filter(|&(start, end)| start < position && position < end) {
+                            self.text_drag = Some(TextDrag::new(tab.id, range, tab.get_selected_text(), position, (x, y)));
+                            return true;
+                        }
+                        
+                        // A second click in the same place selects the word there
+                        let now = Instant::now();
+                        let double_click = self.last_click.is_some_and(|(last, at)| at == position && now.duration_since(last) < DOUBLE_CLICK_TIME);
+                        self.last_click = (!double_click).then_some((now, position));
+                        if double_click && tab.select_word(position) {
+                            self.is_selecting = false;
+                            return true;
                         }
                         
                         // Update cursor position
@@ -556,37 +3015,84 @@ impl Editor {
         false
     }
     
+    /// Move the hex cursor to the byte under a click in either column
+    fn hex_click(&mut self, x: f32, y: f32, content_y: f32, mono_font: &Font) -> bool {
+        let char_width = mono_font.measure_str("0", None).0.max(1.0);
+        let Some(hex) = self.tab_manager.get_active_tab().and_then(|tab| tab.hex.as_ref()) else {
+            return false;
+        };
+        let (hex_x, ascii_x) = self.hex_columns(hex, char_width);
+        if !self.contains(x, y) || y < content_y {
+            return false;
+        }
+        
+        let row = hex.scroll_row + ((y - content_y) / self.line_height) as u64;
+        let (index, ascii_focus) = if x >= ascii_x {
+            (((x - ascii_x) / char_width) as u64, true)
+        } else if x >= hex_x {
+            // Each cell is three characters wide, plus one after the eighth byte
+            let column = ((x - hex_x) / char_width) as u64;
+            let column = if column >= (BYTES_PER_ROW / 2) * 3 { column - 1 } else { column };
+            (column / 3, false)
+        } else {
+            return true;
+        };
+        
+        if let Some(hex) = self.active_hex_mut() {
+            hex.set_cursor(row * BYTES_PER_ROW + index.min(BYTES_PER_ROW - 1));
+            hex.ascii_focus = ascii_focus;
+        }
+        self.cursor_blink_time = 0.0;
+        self.show_cursor = true;
+        true
+    }
+    
     pub fn handle_mouse_drag(&mut self, x: f32, y: f32, mono_font: &Font) {
+        if self.scrollbar.is_dragging() {
+            if self.scrollbar.handle_mouse_move(x, y) {
+                self.apply_scrollbar_offset();
+            }
+            return;
+        }
+        if let Some(editor) = self.focused_peek_mut() {
+            editor.handle_mouse_drag(x, y, mono_font);
+            return;
+        }
+        if self.text_drag.is_some() {
+            self.drag_text_to(x, y, mono_font);
+            return;
+        }
         if !self.is_selecting {
             return;
         }
         
+        // Alt+drag extends the box selection
+        if let Some((line, column)) = self.column_position_at(x, y, mono_font) {
+            if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+                if tab.column_anchor.is_some() {
+                    tab.cursor_line = line;
+                    tab.cursor_column = column;
+                    return;
+                }
+            }
+        }
+        
         let tab_bar_height = self.tab_bar.height();
         let content_y = self.y + tab_bar_height;
         let content_height = self.height - tab_bar_height;
         let text_x = self.x + self.gutter_width + 10.0;
         
+        // Calculate which line is being dragged over; inline text moves the columns behind it
+        let (dragged_line, inline) = self.tab_manager.get_active_tab().map_or((0, InlineLayout::default()), |tab| {
+            let line = self.line_layout(tab).line_at(y - content_y + tab.scroll_offset).min(tab.buffer.len_lines() - 1);
+            (line, self.inline_layout(tab, line, mono_font))
+        });
         if let Some(tab) = self.tab_manager.get_active_tab_mut() {
-            // Calculate which line is being dragged over
-            let relative_y = (y - content_y + tab.scroll_offset).max(0.0);
-            let dragged_line = ((relative_y / self.line_height) as usize).min(tab.buffer.len_lines() - 1);
-            
             // Calculate which column is being dragged over
             if let Some(line) = tab.buffer.line(dragged_line) {
                 let relative_x = (x - text_x).max(0.0);
-                let mut current_x = 0.0;
                 let chars: Vec<char> = line.chars().collect();
-                let mut dragged_col = 0;
-                
-                for (i, ch) in chars.iter().enumerate() {
-                    let char_width = mono_font.measure_str(&ch.to_string(), None).0;
-                    if current_x + char_width / 2.0 > relative_x {
-                        dragged_col = i;
-                        break;
-                    }
-                    current_x += char_width;
-                    dragged_col = i + 1;
-                }
+                let dragged_col = Self::column_at_x(&line, inline.unshift(relative_x), mono_font);
                 
                 // Update cursor position (end of selection)
                 tab.cursor_line = dragged_line;
@@ -595,8 +3101,158 @@ impl Editor {
         }
     }
     
+    /// Follow a dragged selection: a tab header under the pointer comes forward, and over the text
+    /// the drop caret follows the pointer
+    fn drag_text_to(&mut self, x: f32, y: f32, mono_font: &Font) {
+        let Some(drag) = self.text_drag.as_mut() else {
+            return;
+        };
+        drag.move_to(x, y);
+        if !drag.active {
+            return;
+        }
+        if let Some(index) = self.tab_bar.get_clicked_tab(x, y, &self.tab_manager) {
+            if index != self.tab_manager.active_index() {
+                self.tab_manager.set_active_tab(index);
+            }
+        }
+        let drop = if self.is_over_editor_content(x, y) && !self.read_only {
+            self.column_position_at(x, y, mono_font).map(|(line, column)| {
+                let length = self.tab_manager.get_active_tab().map_or(0, |tab| tab.line_length(line));
+                (line, column.min(length))
+            })
+        } else {
+            None
+        };
+        if let Some(drag) = self.text_drag.as_mut() {
+            drag.drop = drop;
+        }
+    }
+    
+    /// Whether a selection is being dragged, so the window can show it over other panels
+    pub fn is_dragging_text(&self) -> bool {
+        self.text_drag.as_ref().is_some_and(|drag| drag.active)
+    }
+    
+    /// Drop the dragged selection; `copy` (Ctrl held) leaves the original where it was
+    ///
+    /// Over the text, it is moved or copied there, also between tabs. Anywhere else the text is
+    /// returned for whatever is under the pointer, like the terminal or an input, and the document
+    /// keeps it. A press in the selection that never moved places the cursor like a click.
+    pub fn finish_text_drag(&mut self, copy: bool) -> Option<String> {
+        let drag = self.text_drag.take()?;
+        if !drag.active {
+            if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+                tab.selection_start = None;
+                (tab.cursor_line, tab.cursor_column) = drag.press_position;
+            }
+            return None;
+        }
+        let Some(to) = drag.drop else {
+            return Some(drag.text);
+        };
+        
+        if self.tab_manager.get_active_tab().is_some_and(|tab| tab.id == drag.tab_id) {
+            if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+                tab.move_text(drag.range, to, copy);
+            }
+        } else {
+            if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+                tab.insert_selected(to, &drag.text);
+            }
+            if let Some(source) = self.tab_manager.tabs_mut().iter_mut().find(|tab| tab.id == drag.tab_id).filter(|_| !copy) {
+                source.remove_range(drag.range.0, drag.range.1);
+                source.selection_start = None;
+                (source.cursor_line, source.cursor_column) = drag.range.0;
+            }
+        }
+        self.cursor_blink_time = 0.0;
+        self.show_cursor = true;
+        None
+    }
+    
+    /// Abandon a drag, e.g. on Escape, leaving the selection as it was
+    pub fn cancel_text_drag(&mut self) -> bool {
+        self.text_drag.take().is_some_and(|drag| drag.active)
+    }
+    
+    /// The dragged text next to the pointer, drawn last so it shows over every panel
+    pub fn draw_text_drag(&self, canvas: &Canvas, ui_font: &Font) {
+        if let Some(drag) = self.text_drag.as_ref().filter(|drag| drag.active) {
+            draw_ghost(canvas, &drag.text, drag.pointer, ui_font);
+        }
+    }
+    
+    /// Start a box selection at a point in the text area (Alt+click)
+    pub fn start_column_selection(&mut self, x: f32, y: f32, mono_font: &Font) -> bool {
+        let Some((line, column)) = self.column_position_at(x, y, mono_font) else {
+            return false;
+        };
+        
+        if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            tab.selection_start = None;
+            tab.column_anchor = Some((line, column));
+            tab.cursor_line = line;
+            tab.cursor_column = column;
+            self.is_selecting = true;
+            self.cursor_blink_time = 0.0;
+            self.show_cursor = true;
+        }
+        true
+    }
+    
+    /// Grow or shrink the box selection from the keyboard, starting one at the cursor if needed
+    pub fn extend_column_selection(&mut self, line_delta: isize, column_delta: isize) {
+        if let Some(tab) = self.tab_manager.get_active_tab_mut().filter(|tab| !tab.is_hex()) {
+            if tab.column_anchor.is_none() {
+                tab.selection_start = None;
+                tab.column_anchor = Some((tab.cursor_line, tab.cursor_column));
+            }
+            
+            let last_line = tab.buffer.len_lines().saturating_sub(1);
+            tab.cursor_line = tab.cursor_line.saturating_add_signed(line_delta).min(last_line);
+            tab.cursor_column = tab.cursor_column.saturating_add_signed(column_delta);
+            
+            self.cursor_blink_time = 0.0;
+            self.show_cursor = true;
+        }
+    }
+    
+    /// Line and column under a point, allowing columns past the end of the line
+    fn column_position_at(&self, x: f32, y: f32, mono_font: &Font) -> Option<(usize, usize)> {
+        let tab = self.tab_manager.get_active_tab().filter(|tab| !tab.is_hex())?;
+        let content_y = self.y + self.tab_bar.height();
+        let text_x = self.x + self.gutter_width + 10.0;
+        
+        if x < text_x - 10.0 || x >= self.x + self.width || y < content_y || y >= self.y + self.height {
+            return None;
+        }
+        
+        let line = self.line_layout(tab).line_at(y - content_y + tab.scroll_offset).min(tab.buffer.len_lines().saturating_sub(1));
+        let relative_x = self.inline_layout(tab, line, mono_font).unshift((x - text_x).max(0.0));
+        
+        let mut current_x = 0.0;
+        let mut column = 0;
+        for ch in tab.buffer.line(line).unwrap_or_default().trim_end_matches('\n').chars() {
+            let char_width = mono_font.measure_str(ch.to_string(), None).0;
+            if current_x + char_width / 2.0 > relative_x {
+                return Some((line, column));
+            }
+            current_x += char_width;
+            column += 1;
+        }
+        
+        // Past the end of the line: count virtual columns
+        let space_width = mono_font.measure_str(" ", None).0.max(1.0);
+        Some((line, column + ((relative_x - current_x) / space_width).round() as usize))
+    }
+    
     pub fn handle_mouse_release(&mut self) {
         self.is_selecting = false;
+        self.scrollbar.handle_mouse_up();
+        if let Some(peek) = self.peek.as_mut() {
+            peek.editor.handle_mouse_release();
+        }
     }
     
     pub fn is_over_editor_content(&self, x: f32, y: f32) -> bool {
@@ -604,9 +3260,10 @@ impl Editor {
         let content_y = self.y + tab_bar_height;
         let content_height = self.height - tab_bar_height;
         let text_x = self.x + self.gutter_width + 10.0;
+        let over_ruler = x >= self.ruler_rect().left;
         
         x >= text_x && x < self.x + self.width && 
-        y >= content_y && y < content_y + content_height
+        y >= content_y && y < content_y + content_height && !over_ruler
     }
     
     pub fn contains(&self, x: f32, y: f32) -> bool {
@@ -614,57 +3271,124 @@ impl Editor {
         y >= self.y && y < self.y + self.height
     }
     
+    /// Scroll by a wheel delta in pixels, on top of any scroll still under way
     pub fn scroll(&mut self, delta: f32) {
+        let visible_rows = self.hex_visible_rows();
+        let line_height = self.line_height;
+        if let Some(hex) = self.active_hex_mut() {
+            hex.scroll_rows((delta / line_height).round() as i64, visible_rows);
+            return;
+        }
+        let visible_height = self.preview_column(self.y + self.tab_bar.height(), self.height - self.tab_bar.height()).height();
+        if let Some(preview) = self.tab_manager.get_active_tab_mut().and_then(|tab| tab.preview.as_mut()) {
+            let content_height = preview.layout.borrow().as_ref().map_or(0.0, |(_, layout)| layout.height + HOVER_PADDING * 2.0);
+            preview.scroll = (preview.scroll + delta).clamp(0.0, (content_height - visible_height).max(0.0));
+            return;
+        }
+        self.scroll_to(self.scroll_target() + delta);
+    }
+    
+    /// Where the active tab is scrolling to, or where it rests
+    fn scroll_target(&self) -> f32 {
+        let tab = self.tab_manager.get_active_tab();
+        if self.smooth_scroll.is_animating() && tab.map(|tab| tab.id) == self.scrolling_tab {
+            return self.smooth_scroll.target();
+        }
+        tab.map_or(0.0, |tab| tab.scroll_offset)
+    }
+    
+    /// Put the tab being glided where it was heading, and stop
+    fn finish_scroll(&mut self) {
+        if self.smooth_scroll.is_animating() {
+            let scrolled = self.tab_manager.tabs().iter().position(|tab| Some(tab.id) == self.scrolling_tab);
+            if let Some(tab) = scrolled.and_then(|index| self.tab_manager.get_tab_mut(index)) {
+                tab.scroll_offset = self.smooth_scroll.target();
+            }
+        }
+        self.smooth_scroll = SmoothScroll::default();
+    }
+    
+    /// Glide the active text tab to a scroll offset, clamped to its text
+    fn scroll_to(&mut self, offset: f32) {
+        // The card would be left behind by the word it is about
+        self.hover = None;
+        let content_height = self.height - self.tab_bar.height();
+        if self.scrolling_tab != self.tab_manager.get_active_tab().map(|tab| tab.id) {
+            self.finish_scroll();
+        }
+        let layout = self.tab_manager.get_active_tab().map(|tab| self.line_layout(tab)).unwrap_or_default();
         if let Some(tab) = self.tab_manager.get_active_tab_mut() {
-            let content_height = self.height - self.tab_bar.height();
-            let total_lines = tab.buffer.len_lines().max(1);
-            let total_content_height = total_lines as f32 * self.line_height;
-            let max_scroll = (total_content_height - content_height).max(0.0);
-            
-            // Apply scroll delta with smooth clamping
-            tab.scroll_offset = (tab.scroll_offset + delta).clamp(0.0, max_scroll);
+            let total_height = layout.document_height(tab.buffer.len_lines().max(1));
+            let max_scroll = (total_height - content_height).max(0.0);
+            self.smooth_scroll.scroll_to(tab.scroll_offset, offset.clamp(0.0, max_scroll));
+            self.scrolling_tab = Some(tab.id);
+            tab.scroll_offset = self.smooth_scroll.offset();
+        }
+    }
+    
+    /// Scroll the least needed to show lines `first..=last`, with a few lines around them
+    pub fn reveal_lines(&mut self, first: usize, last: usize) {
+        let content_height = self.height - self.tab_bar.height();
+        let layout = self.tab_manager.get_active_tab().map(|tab| self.line_layout(tab)).unwrap_or_default();
+        let top = layout.line_top(first);
+        let height = layout.line_top(last.max(first)) + self.line_height - top;
+        let scroll = self.scroll_target();
+        let target = reveal_offset(scroll, content_height, top, height, REVEAL_PADDING_LINES * self.line_height);
+        if target != scroll {
+            self.scroll_to(target);
+        }
+    }
+    
+    fn reveal_cursor(&mut self) {
+        if let Some(line) = self.tab_manager.get_active_tab().filter(|tab| !tab.is_hex()).map(|tab| tab.cursor_line) {
+            self.reveal_lines(line, line);
         }
     }
     
     fn get_token_color(&self, token_type: TokenType) -> Color {
+        let theme = current_theme();
+        let syntax = theme.syntax;
         match token_type {
-            TokenType::Keyword => Color::from_rgb(197, 134, 192),      // Purple
-            TokenType::Function => Color::from_rgb(220, 220, 170),     // Yellow
-            TokenType::Type => Color::from_rgb(78, 201, 176),          // Cyan
-            TokenType::String => Color::from_rgb(206, 145, 120),       // Orange
-            TokenType::Number => Color::from_rgb(181, 206, 168),       // Light green
-            TokenType::Comment => Color::from_rgb(106, 153, 85),       // Green
-            TokenType::Operator => Color::from_rgb(180, 180, 180),     // Light gray
-            TokenType::Punctuation => Color::from_rgb(180, 180, 180),  // Light gray
-            TokenType::Variable => Color::from_rgb(156, 220, 254),     // Light blue
-            TokenType::Property => Color::from_rgb(156, 220, 254),     // Light blue
-            TokenType::Parameter => Color::from_rgb(156, 220, 254),    // Light blue
-            TokenType::Constant => Color::from_rgb(79, 193, 255),      // Blue
-            TokenType::Text => Color::from_rgb(220, 220, 220),         // White
+            TokenType::Keyword => syntax.keyword,
+            TokenType::Function => syntax.function,
+            TokenType::Type => syntax.type_name,
+            TokenType::String => syntax.string,
+            TokenType::Number => syntax.number,
+            TokenType::Comment => syntax.comment,
+            TokenType::Operator => syntax.operator,
+            TokenType::Punctuation => syntax.punctuation,
+            TokenType::Variable => syntax.variable,
+            TokenType::Property => syntax.property,
+            TokenType::Parameter => syntax.parameter,
+            TokenType::Constant => syntax.constant,
+            TokenType::Text => theme.foreground,
         }
     }
     
+    /// Insert text as is at the cursor, replacing the selection, e.g. pasted text
+    ///
+    /// Unlike typing, nothing is auto-indented or auto-closed and tabs stay tabs.
     pub fn insert_text(&mut self, text: &str) {
-        // Delete selection if any
+        self.completion = None;
+        if self.is_active_hex() {
+            text.chars().for_each(|c| self.insert_char(c));
+            return;
+        }
+        
         if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            if tab.column_anchor.is_some() && !text.contains('\n') {
+                tab.column_insert(text);
+                return;
+            }
+            tab.clear_column_selection();
             if tab.has_selection() {
                 tab.delete_selection();
             }
+            tab.insert_at_cursor(text);
+            self.cursor_blink_time = 0.0;
+            self.show_cursor = true;
         }
-        
-        // Insert text character by character
-        for c in text.chars() {
-            if c == '\n' || c == '\r' {
-                self.insert_newline();
-            } else if c == '\t' {
-                self.insert_char(' ');
-                self.insert_char(' ');
-                self.insert_char(' ');
-                self.insert_char(' ');
-            } else if !c.is_control() {
-                self.insert_char(c);
-            }
-        }
+        self.reveal_cursor();
     }
     
     // Clipboard operations
@@ -714,41 +3438,13 @@ impl Editor {
     
     /// Paste text from clipboard
     pub fn paste(&mut self, text: &str) {
-        if let Some(tab) = self.tab_manager.get_active_tab_mut() {
-            // Delete selection if any
-            if tab.has_selection() {
-                tab.delete_selection();
-            }
-            
-            // Insert the pasted text
-            let mut char_idx = 0;
-            for line_idx in 0..tab.cursor_line {
-                if let Some(line) = tab.buffer.line(line_idx) {
-                    char_idx += line.chars().count();
-                }
-            }
-            char_idx += tab.cursor_column;
-            
-            tab.buffer.insert(char_idx, text);
-            
-            // Update cursor position
-            let newline_count = text.matches('\n').count();
-            if newline_count > 0 {
-                tab.cursor_line += newline_count;
-                if let Some(last_line) = text.lines().last() {
-                    tab.cursor_column = last_line.chars().count();
-                }
-            } else {
-                tab.cursor_column += text.chars().count();
-            }
-            
-            tab.highlighter.parse(&tab.buffer.to_string());
-        }
+        self.insert_text(text);
     }
     
     /// Select all text in the current buffer
     pub fn select_all(&mut self) {
         if let Some(tab) = self.tab_manager.get_active_tab_mut() {
+            tab.clear_column_selection();
             tab.selection_start = Some((0, 0));
             let last_line = tab.buffer.len_lines().saturating_sub(1);
             let last_column = tab.buffer.line(last_line)
@@ -770,6 +3466,7 @@ impl Editor {
     pub fn clear_selection(&mut self) {
         if let Some(tab) = self.tab_manager.get_active_tab_mut() {
             tab.selection_start = None;
+            tab.clear_column_selection();
         }
     }
     
@@ -779,4 +3476,51 @@ impl Editor {
             .map(|tab| tab.has_selection())
             .unwrap_or(false)
     }
+    
+    /// Run an editing command on the active document
+    ///
+    /// Copy and Cut return the selected text for the clipboard; nothing is returned without a selection.
+    pub fn execute(&mut self, command: &EditorCommand) -> Option<String> {
+        if let Some(editor) = self.focused_peek_mut() {
+            return editor.execute(command);
+        }
+        // Read-only editors and tabs still copy what a cut would take
+        let read_only = self.read_only || self.tab_manager.get_active_tab().is_some_and(|tab| tab.read_only);
+        if read_only && command.modifies_text() {
+            return if *command == EditorCommand::Cut { self.execute(&EditorCommand::Copy) } else { None };
+        }
+        match command {
+            EditorCommand::Type(text) => {
+                for c in text.chars().filter(|c| !c.is_control()) {
+                    self.type_char(c);
+                }
+            }
+            EditorCommand::Paste(text) => self.insert_text(text),
+            EditorCommand::Newline => self.insert_newline(),
+            EditorCommand::DeleteLeft => self.delete_char(),
+            EditorCommand::Indent => self.indent(),
+            EditorCommand::Outdent => self.outdent(),
+            EditorCommand::CursorLeft => self.move_cursor_left(),
+            EditorCommand::CursorRight => self.move_cursor_right(),
+            EditorCommand::CursorUp => self.move_cursor_up(),
+            EditorCommand::CursorDown => self.move_cursor_down(),
+            EditorCommand::SelectAll => self.select_all(),
+            EditorCommand::ClearSelection => self.clear_selection(),
+            EditorCommand::ColumnSelect { lines, columns } => self.extend_column_selection(*lines, *columns),
+            EditorCommand::ToggleLineComment => self.toggle_comment(EditorTab::toggle_line_comment),
+            EditorCommand::ToggleBlockComment => self.toggle_comment(EditorTab::toggle_block_comment),
+            EditorCommand::Copy | EditorCommand::Cut => {
+                let tab = self.tab_manager.get_active_tab_mut()?;
+                let text = tab.get_selected_text();
+                if text.is_empty() {
+                    return None;
+                }
+                if *command == EditorCommand::Cut {
+                    tab.delete_selection();
+                }
+                return Some(text);
+            }
+        }
+        None
+    }
 }
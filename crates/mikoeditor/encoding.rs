@@ -0,0 +1,222 @@
+/// Text encodings supported when reading and writing files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Encoding {
+    #[default]
+    Utf8,
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+    Latin1,
+}
+
+impl Encoding {
+    /// All encodings, in picker order
+    pub const ALL: [Encoding; 5] = [
+        Encoding::Utf8,
+        Encoding::Utf8Bom,
+        Encoding::Utf16Le,
+        Encoding::Utf16Be,
+        Encoding::Latin1,
+    ];
+
+    /// Display name for the status bar
+    pub fn label(&self) -> &'static str {
+        match self {
+            Encoding::Utf8 => "UTF-8",
+            Encoding::Utf8Bom => "UTF-8 with BOM",
+            Encoding::Utf16Le => "UTF-16 LE",
+            Encoding::Utf16Be => "UTF-16 BE",
+            Encoding::Latin1 => "ISO 8859-1",
+        }
+    }
+
+    /// Settings identifier (`files.encoding` style)
+    pub fn id(&self) -> &'static str {
+        match self {
+            Encoding::Utf8 => "utf8",
+            Encoding::Utf8Bom => "utf8bom",
+            Encoding::Utf16Le => "utf16le",
+            Encoding::Utf16Be => "utf16be",
+            Encoding::Latin1 => "iso88591",
+        }
+    }
+
+    /// Parse a settings identifier, accepting common aliases
+    pub fn from_id(id: &str) -> Option<Self> {
+        let normalized: String = id
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+
+        match normalized.as_str() {
+            "utf8" => Some(Encoding::Utf8),
+            "utf8bom" => Some(Encoding::Utf8Bom),
+            "utf16le" | "utf16" => Some(Encoding::Utf16Le),
+            "utf16be" => Some(Encoding::Utf16Be),
+            "iso88591" | "latin1" => Some(Encoding::Latin1),
+            _ => None,
+        }
+    }
+
+    /// Detect the encoding of raw file contents
+    /// BOMs win, then a UTF-16 heuristic, then UTF-8 validity; pure ASCII uses `default`
+    pub fn detect(bytes: &[u8], default: Encoding) -> Encoding {
+        if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+            return Encoding::Utf8Bom;
+        }
+        if bytes.starts_with(&[0xFF, 0xFE]) {
+            return Encoding::Utf16Le;
+        }
+        if bytes.starts_with(&[0xFE, 0xFF]) {
+            return Encoding::Utf16Be;
+        }
+
+        // BOM-less UTF-16: mostly-ASCII text leaves every other byte zero
+        let sample = &bytes[..bytes.len().min(4096)];
+        if sample.len() >= 4 {
+            let pairs = sample.len() / 2;
+            let even_zeros = sample.iter().step_by(2).filter(|b| **b == 0).count();
+            let odd_zeros = sample.iter().skip(1).step_by(2).filter(|b| **b == 0).count();
+            if odd_zeros * 10 > pairs * 3 && even_zeros == 0 {
+                return Encoding::Utf16Le;
+            }
+            if even_zeros * 10 > pairs * 3 && odd_zeros == 0 {
+                return Encoding::Utf16Be;
+            }
+        }
+
+        if bytes.is_ascii() {
+            return match default {
+                Encoding::Utf16Le | Encoding::Utf16Be => Encoding::Utf8,
+                other => other,
+            };
+        }
+
+        if std::str::from_utf8(bytes).is_ok() {
+            Encoding::Utf8
+        } else {
+            Encoding::Latin1
+        }
+    }
+
+    /// Decode raw bytes into a string
+    pub fn decode(&self, bytes: &[u8]) -> String {
+        match self {
+            Encoding::Utf8 | Encoding::Utf8Bom => {
+                let bytes = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(bytes);
+                String::from_utf8_lossy(bytes).into_owned()
+            }
+            Encoding::Utf16Le | Encoding::Utf16Be => {
+                let le = *self == Encoding::Utf16Le;
+                let bom: &[u8] = if le { &[0xFF, 0xFE] } else { &[0xFE, 0xFF] };
+                let bytes = bytes.strip_prefix(bom).unwrap_or(bytes);
+                let units: Vec<u16> = bytes
+                    .chunks_exact(2)
+                    .map(|pair| {
+                        if le {
+                            u16::from_le_bytes([pair[0], pair[1]])
+                        } else {
+                            u16::from_be_bytes([pair[0], pair[1]])
+                        }
+                    })
+                    .collect();
+                String::from_utf16_lossy(&units)
+            }
+            Encoding::Latin1 => bytes.iter().map(|&b| b as char).collect(),
+        }
+    }
+
+    /// Encode a string for writing to disk
+    /// Characters Latin-1 can't represent are written as `?`
+    pub fn encode(&self, text: &str) -> Vec<u8> {
+        match self {
+            Encoding::Utf8 => text.as_bytes().to_vec(),
+            Encoding::Utf8Bom => {
+                let mut bytes = vec![0xEF, 0xBB, 0xBF];
+                bytes.extend_from_slice(text.as_bytes());
+                bytes
+            }
+            Encoding::Utf16Le => {
+                let mut bytes = vec![0xFF, 0xFE];
+                for unit in text.encode_utf16() {
+                    bytes.extend_from_slice(&unit.to_le_bytes());
+                }
+                bytes
+            }
+            Encoding::Utf16Be => {
+                let mut bytes = vec![0xFE, 0xFF];
+                for unit in text.encode_utf16() {
+                    bytes.extend_from_slice(&unit.to_be_bytes());
+                }
+                bytes
+            }
+            Encoding::Latin1 => text
+                .chars()
+                .map(|c| if (c as u32) < 256 { c as u8 } else { b'?' })
+                .collect(),
+        }
+    }
+}
+
+/// Line ending sequence used when saving
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    Lf,
+    CrLf,
+}
+
+impl LineEnding {
+    /// Display name for the status bar
+    pub fn label(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "LF",
+            LineEnding::CrLf => "CRLF",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+
+    /// Parse a settings value ("lf", "crlf"); "auto" and unknown values yield `None`
+    pub fn from_id(id: &str) -> Option<Self> {
+        match id.to_lowercase().as_str() {
+            "lf" | "\n" => Some(LineEnding::Lf),
+            "crlf" | "\r\n" => Some(LineEnding::CrLf),
+            _ => None,
+        }
+    }
+
+    /// Line ending native to the current platform
+    pub fn native() -> Self {
+        if cfg!(target_os = "windows") {
+            LineEnding::CrLf
+        } else {
+            LineEnding::Lf
+        }
+    }
+
+    /// Detect the dominant line ending in `text`
+    pub fn detect(text: &str) -> Option<Self> {
+        let crlf = text.matches("\r\n").count();
+        let lf = text.matches('\n').count() - crlf;
+
+        if crlf == 0 && lf == 0 {
+            None
+        } else if crlf > lf {
+            Some(LineEnding::CrLf)
+        } else {
+            Some(LineEnding::Lf)
+        }
+    }
+}
+
+impl Default for LineEnding {
+    fn default() -> Self {
+        LineEnding::native()
+    }
+}
@@ -0,0 +1,82 @@
+use std::io::{Read, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// External program that reads a document on stdin and writes it formatted to stdout
+#[derive(Debug, Clone, PartialEq)]
+pub struct Formatter {
+    pub command: String,
+    /// `${file}` is replaced with the document's path (e.g. for `prettier --stdin-filepath`)
+    pub args: Vec<String>,
+}
+
+impl Formatter {
+    pub fn new(command: impl Into<String>, args: &[&str]) -> Self {
+        Self {
+            command: command.into(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+
+    /// Built-in formatter for a language id, used when settings don't name one
+    pub fn default_for(language: &str) -> Option<Self> {
+        match language {
+            "rust" => Some(Self::new("rustfmt", &["--edition", "2021", "--emit", "stdout"])),
+            "python" => Some(Self::new("black", &["--quiet", "-"])),
+            "javascript" | "typescript" | "json" => Some(Self::new("prettier", &["--stdin-filepath", "${file}"])),
+            _ => None,
+        }
+    }
+
+    /// Run on `text` from the document's folder; fails with the formatter's stderr on a non-zero exit
+    pub fn run(&self, text: &str, file_path: Option<&Path>) -> std::io::Result<String> {
+        let file = file_path.map(|path| path.to_string_lossy().into_owned()).unwrap_or_default();
+        let mut command = Command::new(&self.command);
+        command
+            .args(self.args.iter().map(|arg| arg.replace("${file}", &file)))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        if let Some(dir) = file_path.and_then(Path::parent).filter(|dir| dir.is_dir()) {
+            command.current_dir(dir);
+        }
+
+        let mut child = command.spawn()?;
+        // Feed stdin from a thread so a formatter writing a large result can't block on a full pipe
+        let mut stdin = child.stdin.take().expect("stdin is piped");
+        let input = text.to_string();
+        let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+
+        let mut stdout = Vec::new();
+        if let Some(mut out) = child.stdout.take() {
+            out.read_to_end(&mut stdout)?;
+        }
+        let output = child.wait_with_output()?;
+        writer.join().map_err(|_| std::io::Error::other("formatter input thread panicked"))??;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(std::io::Error::other(format!("{} failed: {}", self.command, stderr.trim())));
+        }
+        String::from_utf8(stdout).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// Char position in `new` with the same number of non-whitespace chars before it as `offset` has in `old`
+///
+/// Formatters mostly move whitespace around, so this keeps the cursor on the same token:
+/// right after it when the cursor ended a token, else just before the next one.
+pub fn map_offset(old: &str, offset: usize, new: &str) -> usize {
+    let significant = old.chars().take(offset).filter(|c| !c.is_whitespace()).count();
+    let hug_previous = offset > 0 && old.chars().nth(offset - 1).is_some_and(|c| !c.is_whitespace());
+    let mut seen = 0;
+    for (index, c) in new.chars().enumerate() {
+        if seen == significant && (hug_previous || !c.is_whitespace()) {
+            return index;
+        }
+        if !c.is_whitespace() {
+            seen += 1;
+        }
+    }
+    new.chars().count()
+}
@@ -0,0 +1,268 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+/// Bytes shown per row of the hex view
+pub const BYTES_PER_ROW: u64 = 16;
+/// Size of the chunks read from disk
+const PAGE_SIZE: u64 = 64 * 1024;
+/// Pages kept in memory before the cache is reset
+const MAX_CACHED_PAGES: usize = 64;
+
+/// Byte buffer backed by a file that is read page by page
+/// Edits overwrite bytes in place and are kept aside until saved
+pub struct HexBuffer {
+    path: PathBuf,
+    len: u64,
+    pages: RefCell<HashMap<u64, Vec<u8>>>,
+    edits: BTreeMap<u64, u8>,
+}
+
+impl HexBuffer {
+    pub fn open(path: PathBuf) -> std::io::Result<Self> {
+        let len = std::fs::metadata(&path)?.len();
+        Ok(Self {
+            path,
+            len,
+            pages: RefCell::new(HashMap::new()),
+            edits: BTreeMap::new(),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_modified(&self) -> bool {
+        !self.edits.is_empty()
+    }
+
+    pub fn is_edited(&self, offset: u64) -> bool {
+        self.edits.contains_key(&offset)
+    }
+
+    /// Byte at `offset` including unsaved edits; `None` past the end or on read errors
+    pub fn byte(&self, offset: u64) -> Option<u8> {
+        if offset >= self.len {
+            return None;
+        }
+        if let Some(byte) = self.edits.get(&offset) {
+            return Some(*byte);
+        }
+
+        let page = offset / PAGE_SIZE;
+        let mut pages = self.pages.borrow_mut();
+        if !pages.contains_key(&page) {
+            let data = self.read_page(page).ok()?;
+            if pages.len() >= MAX_CACHED_PAGES {
+                pages.clear();
+            }
+            pages.insert(page, data);
+        }
+        pages.get(&page)?.get((offset % PAGE_SIZE) as usize).copied()
+    }
+
+    /// Up to `count` bytes starting at `offset`
+    pub fn read(&self, offset: u64, count: usize) -> Vec<u8> {
+        (offset..offset.saturating_add(count as u64))
+            .map_while(|offset| self.byte(offset))
+            .collect()
+    }
+
+    fn read_page(&self, page: u64) -> std::io::Result<Vec<u8>> {
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start(page * PAGE_SIZE))?;
+        let mut data = Vec::with_capacity(PAGE_SIZE as usize);
+        file.take(PAGE_SIZE).read_to_end(&mut data)?;
+        Ok(data)
+    }
+
+    /// Overwrite a byte; offsets past the end are ignored
+    pub fn set_byte(&mut self, offset: u64, value: u8) {
+        if offset < self.len {
+            self.edits.insert(offset, value);
+        }
+    }
+
+    /// Write the edited bytes back in place
+    pub fn save(&mut self) -> std::io::Result<()> {
+        if self.edits.is_empty() {
+            return Ok(());
+        }
+
+        let mut file = OpenOptions::new().write(true).open(&self.path)?;
+        for (offset, byte) in &self.edits {
+            file.seek(SeekFrom::Start(*offset))?;
+            file.write_all(&[*byte])?;
+        }
+        file.flush()?;
+
+        self.edits.clear();
+        self.pages.borrow_mut().clear();
+        Ok(())
+    }
+
+    /// Find `pattern` starting at `from`, wrapping around to the start of the file
+    pub fn find(&self, pattern: &[u8], from: u64) -> Option<u64> {
+        if pattern.is_empty() || pattern.len() as u64 > self.len {
+            return None;
+        }
+        let from = from.min(self.len);
+        self.find_in(pattern, from, self.len)
+            .or_else(|| self.find_in(pattern, 0, from))
+    }
+
+    /// Search matches starting in `start..end` chunk by chunk
+    fn find_in(&self, pattern: &[u8], start: u64, end: u64) -> Option<u64> {
+        let overlap = pattern.len() - 1;
+        let mut chunk_start = start;
+        while chunk_start < end {
+            let chunk_len = PAGE_SIZE.min(end - chunk_start) as usize;
+            let chunk = self.read(chunk_start, chunk_len + overlap);
+            if let Some(position) = chunk.windows(pattern.len()).position(|window| window == pattern) {
+                if position < chunk_len {
+                    return Some(chunk_start + position as u64);
+                }
+            }
+            chunk_start += chunk_len as u64;
+        }
+        None
+    }
+
+    /// Parse a search pattern: hex bytes ("DE AD be ef") or quoted text ("\"PNG\"")
+    pub fn parse_pattern(text: &str) -> Option<Vec<u8>> {
+        let text = text.trim();
+        if let Some(quoted) = text.strip_prefix('"') {
+            let quoted = quoted.strip_suffix('"').unwrap_or(quoted);
+            return (!quoted.is_empty()).then(|| quoted.as_bytes().to_vec());
+        }
+
+        let digits: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+        if digits.is_empty() || !digits.len().is_multiple_of(2) {
+            return None;
+        }
+        (0..digits.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).ok())
+            .collect()
+    }
+}
+
+/// Hex editor state for a tab opened with "Reopen With... > Hex Editor"
+pub struct HexEditor {
+    pub buffer: HexBuffer,
+    /// Offset of the byte under the cursor
+    pub cursor: u64,
+    /// The next hex digit typed replaces the low nibble
+    pub low_nibble: bool,
+    /// Typing goes to the ASCII column instead of the hex column
+    pub ascii_focus: bool,
+    /// First visible row
+    pub scroll_row: u64,
+    /// Bytes highlighted by the last successful search
+    pub search_match: Option<(u64, usize)>,
+}
+
+impl HexEditor {
+    pub fn open(path: PathBuf) -> std::io::Result<Self> {
+        Ok(Self {
+            buffer: HexBuffer::open(path)?,
+            cursor: 0,
+            low_nibble: false,
+            ascii_focus: false,
+            scroll_row: 0,
+            search_match: None,
+        })
+    }
+
+    pub fn row_count(&self) -> u64 {
+        self.buffer.len().div_ceil(BYTES_PER_ROW).max(1)
+    }
+
+    /// Move the cursor by a number of bytes, clamped to the file
+    pub fn move_by(&mut self, delta: i64) {
+        let last = self.buffer.len().saturating_sub(1);
+        self.cursor = self.cursor.saturating_add_signed(delta).min(last);
+        self.low_nibble = false;
+    }
+
+    pub fn set_cursor(&mut self, offset: u64) {
+        self.cursor = offset.min(self.buffer.len().saturating_sub(1));
+        self.low_nibble = false;
+    }
+
+    /// Switch typing between the hex and ASCII columns
+    pub fn toggle_focus(&mut self) {
+        self.ascii_focus = !self.ascii_focus;
+        self.low_nibble = false;
+    }
+
+    /// Type a character into the focused column; returns false if it was rejected
+    pub fn input_char(&mut self, ch: char) -> bool {
+        let Some(current) = self.buffer.byte(self.cursor) else {
+            return false;
+        };
+
+        if self.ascii_focus {
+            if !ch.is_ascii() || ch.is_ascii_control() {
+                return false;
+            }
+            self.buffer.set_byte(self.cursor, ch as u8);
+            self.move_by(1);
+            return true;
+        }
+
+        let Some(digit) = ch.to_digit(16) else {
+            return false;
+        };
+        let digit = digit as u8;
+        if self.low_nibble {
+            self.buffer.set_byte(self.cursor, (current & 0xF0) | digit);
+            self.move_by(1);
+        } else {
+            self.buffer.set_byte(self.cursor, (digit << 4) | (current & 0x0F));
+            self.low_nibble = true;
+        }
+        true
+    }
+
+    /// Find the next occurrence after the cursor and select it
+    pub fn find_next(&mut self, pattern: &[u8]) -> Option<u64> {
+        let from = match self.search_match {
+            Some((offset, _)) if offset == self.cursor => self.cursor + 1,
+            _ => self.cursor,
+        };
+        let found = self.buffer.find(pattern, from);
+        self.search_match = found.map(|offset| (offset, pattern.len()));
+        if let Some(offset) = found {
+            self.set_cursor(offset);
+        }
+        found
+    }
+
+    /// Scroll so the cursor row is inside a viewport of `visible_rows`
+    pub fn reveal_cursor(&mut self, visible_rows: u64) {
+        let row = self.cursor / BYTES_PER_ROW;
+        let visible_rows = visible_rows.max(1);
+        if row < self.scroll_row {
+            self.scroll_row = row;
+        } else if row >= self.scroll_row + visible_rows {
+            self.scroll_row = row + 1 - visible_rows;
+        }
+    }
+
+    pub fn scroll_rows(&mut self, delta: i64, visible_rows: u64) {
+        let max_row = self.row_count().saturating_sub(visible_rows.max(1));
+        self.scroll_row = self.scroll_row.saturating_add_signed(delta).min(max_row);
+    }
+}
@@ -0,0 +1,449 @@
+use std::path::Path;
+
+use mikoui::HoverCard;
+
+use crate::language::detect_from_path;
+use crate::syntax::{SyntaxHighlighter, TokenType};
+
+/// What providers see of the word under the pointer
+pub struct HoverContext<'a> {
+    /// Identifier under the pointer
+    pub word: &'a str,
+    /// The word's line, without its line break
+    pub line_text: &'a str,
+    pub line: usize,
+    /// Char column where the word starts
+    pub column: usize,
+    pub document_path: Option<&'a Path>,
+    /// Language id of the document (e.g. "rust")
+    pub language: Option<&'a str>,
+}
+
+/// Source of hover documentation
+pub trait HoverProvider {
+    fn name(&self) -> &str;
+
+    /// Sections from higher priorities are shown first
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    /// Markdown about the word, or `None` when there is nothing to say
+    fn provide(&self, context: &HoverContext) -> Option<String>;
+}
+
+/// Registered providers, queried in priority order; none by default
+#[derive(Default)]
+pub struct HoverEngine {
+    providers: Vec<Box<dyn HoverProvider>>,
+}
+
+impl HoverEngine {
+    pub fn register(&mut self, provider: Box<dyn HoverProvider>) {
+        self.providers.push(provider);
+        self.providers.sort_by_key(|provider| std::cmp::Reverse(provider.priority()));
+    }
+
+    pub fn provider_names(&self) -> Vec<&str> {
+        self.providers.iter().map(|provider| provider.name()).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.providers.is_empty()
+    }
+
+    /// Markdown from every provider with something to say, separated by rules
+    pub fn hover(&self, context: &HoverContext) -> Option<String> {
+        let sections: Vec<String> = self.providers
+            .iter()
+            .filter_map(|provider| provider.provide(context))
+            .filter(|markdown| !markdown.trim().is_empty())
+            .collect();
+        (!sections.is_empty()).then(|| sections.join("\n\n---\n\n"))
+    }
+}
+
+/// Block of hover markdown
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Block {
+    Heading(String),
+    Paragraph(String),
+    /// List item, without its marker
+    Item(String),
+    /// Fenced code, with the info string after the opening fence
+    Code { language: String, code: String },
+    Rule,
+}
+
+/// Split markdown into headings, paragraphs, list items, fenced code and rules
+///
+/// Covers what documentation comments use; anything else reads as paragraph text.
+pub(crate) fn parse_blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    // Paragraph or list item still taking lines
+    let mut open: Option<Block> = None;
+    let mut fence: Option<(&str, String, Vec<&str>)> = None;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if let Some((marker, language, lines)) = fence.as_mut() {
+            if trimmed.starts_with(*marker) && trimmed.trim_start_matches(marker.chars().next().unwrap_or('`')).is_empty() {
+                blocks.push(Block::Code { language: std::mem::take(language), code: lines.join("\n") });
+                fence = None;
+            } else {
+                lines.push(line);
+            }
+            continue;
+        }
+
+        let fence_marker = ["```", "~~~"].into_iter().find(|marker| trimmed.starts_with(marker));
+        let heading = trimmed
+            .strip_prefix('#')
+            .map(|rest| rest.trim_start_matches('#'))
+            .filter(|rest| rest.is_empty() || rest.starts_with(' '));
+        let is_rule = trimmed.len() >= 3
+            && ['-', '*', '_'].iter().any(|&c| trimmed.chars().all(|ch| ch == c || ch == ' ') && trimmed.starts_with(c));
+        let item = ["- ", "* ", "+ "]
+            .into_iter()
+            .find_map(|marker| trimmed.strip_prefix(marker))
+            .or_else(|| {
+                let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+                trimmed[digits..].strip_prefix(". ").filter(|_| digits > 0)
+            });
+
+        let starts_block = trimmed.is_empty() || fence_marker.is_some() || heading.is_some() || is_rule || item.is_some();
+        if starts_block {
+            blocks.extend(open.take());
+        }
+        if let Some(marker) = fence_marker {
+            let language = trimmed[marker.len()..].split_whitespace().next().unwrap_or_default().to_string();
+            fence = Some((marker, language, Vec::new()));
+        } else if let Some(text) = heading {
+            blocks.push(Block::Heading(text.trim().to_string()));
+        } else if is_rule {
+            blocks.push(Block::Rule);
+        } else if let Some(text) = item {
+            open = Some(Block::Item(text.trim().to_string()));
+        } else if !trimmed.is_empty() {
+            match open.as_mut() {
+                Some(Block::Paragraph(text) | Block::Item(text)) => {
+                    text.push(' ');
+                    text.push_str(trimmed);
+                }
+                _ => open = Some(Block::Paragraph(trimmed.to_string())),
+            }
+        }
+    }
+
+    blocks.extend(open);
+    // An unclosed fence runs to the end
+    if let Some((_, language, lines)) = fence {
+        blocks.push(Block::Code { language, code: lines.join("\n") });
+    }
+    blocks
+}
+
+/// How a piece of paragraph text is set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Style {
+    Plain,
+    Bold,
+    Italic,
+    Code,
+}
+
+/// Split paragraph text at `code`, **bold** and *italic* spans; links keep their text
+pub(crate) fn parse_inline(text: &str) -> Vec<(String, Style)> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if let Some(escaped) = rest.strip_prefix('\\').and_then(|after| after.chars().next()).filter(char::is_ascii_punctuation) {
+            plain.push(escaped);
+            rest = &rest[1 + escaped.len_utf8()..];
+            continue;
+        }
+
+        let delimited = [("`", Style::Code), ("**", Style::Bold), ("*", Style::Italic)]
+            .into_iter()
+            .find(|(marker, _)| rest.starts_with(marker))
+            .and_then(|(marker, style)| {
+                let inner = &rest[marker.len()..];
+                let end = inner.find(marker).filter(|&end| end > 0)?;
+                Some((style, &inner[..end], end + marker.len() * 2))
+            });
+        if let Some((style, inner, len)) = delimited {
+            if !plain.is_empty() {
+                spans.push((std::mem::take(&mut plain), Style::Plain));
+            }
+            spans.push((inner.to_string(), style));
+            rest = &rest[len..];
+            continue;
+        }
+
+        // [text](target) shows just the text
+        let link = (c == '[')
+            .then(|| rest.find("]("))
+            .flatten()
+            .and_then(|close| Some((close, close + rest[close..].find(')')?)));
+        if let Some((close, end)) = link {
+            plain.push_str(&rest[1..close]);
+            rest = &rest[end + 1..];
+            continue;
+        }
+
+        plain.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    if !plain.is_empty() {
+        spans.push((plain, Style::Plain));
+    }
+    spans
+}
+
+/// Break styled spans into lines no wider than `max_width`, at spaces
+///
+/// A word wider than the line gets a line of its own.
+pub(crate) fn wrap_spans(spans: &[(String, Style)], max_width: f32, measure: impl Fn(&str, Style) -> f32) -> Vec<Vec<(String, Style)>> {
+    let mut lines: Vec<Vec<(String, Style)>> = vec![Vec::new()];
+    let mut width = 0.0;
+    for (text, style) in spans {
+        for piece in text.split_inclusive(' ') {
+            let piece_width = measure(piece.trim_end(), *style);
+            let line = lines.last_mut().expect("at least one line");
+            if !line.is_empty() && width + piece_width > max_width {
+                if let Some((last, _)) = line.last_mut() {
+                    last.truncate(last.trim_end().len());
+                }
+                lines.push(Vec::new());
+                width = 0.0;
+            }
+            let line = lines.last_mut().expect("at least one line");
+            match line.last_mut() {
+                Some((last, last_style)) if last_style == style => last.push_str(piece),
+                _ => line.push((piece.to_string(), *style)),
+            }
+            width += measure(piece, *style);
+        }
+    }
+    lines
+}
+
+/// How a run of laid-out hover text is colored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RunStyle {
+    Text(Style),
+    Token(TokenType),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LineKind {
+    Text,
+    Heading,
+    Code,
+    Rule,
+}
+
+/// Row of laid-out hover content
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct HoverLine {
+    /// Offset from the top of the content
+    pub top: f32,
+    pub kind: LineKind,
+    pub runs: Vec<(String, RunStyle)>,
+}
+
+/// Hover content broken into rows, and the room it needs
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HoverLayout {
+    pub lines: Vec<HoverLine>,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl HoverLayout {
+    /// Lay out blocks in rows `row_height` tall, wrapping text at `max_width`
+    ///
+    /// Code blocks are highlighted as their fence says, or as the document when it doesn't,
+    /// and keep their lines unwrapped.
+    pub fn new(blocks: &[Block], max_width: f32, row_height: f32, language: Option<&str>, measure: impl Fn(&str, Style) -> f32) -> Self {
+        let gap = row_height / 2.0;
+        let mut layout = Self::default();
+        let mut top = 0.0;
+        for (index, block) in blocks.iter().enumerate() {
+            if index > 0 {
+                top += gap;
+            }
+            let mut push = |layout: &mut Self, kind: LineKind, runs: Vec<(String, RunStyle)>, height: f32| {
+                let width: f32 = runs
+                    .iter()
+                    .map(|(text, style)| measure(text, if let RunStyle::Text(style) = style { *style } else { Style::Code }))
+                    .sum();
+                layout.width = layout.width.max(width);
+                layout.lines.push(HoverLine { top, kind, runs });
+                top += height;
+            };
+
+            match block {
+                Block::Heading(text) | Block::Paragraph(text) | Block::Item(text) => {
+                    let mut spans = parse_inline(text);
+                    let kind = if matches!(block, Block::Heading(_)) { LineKind::Heading } else { LineKind::Text };
+                    if kind == LineKind::Heading {
+                        spans.iter_mut().filter(|(_, style)| *style == Style::Plain).for_each(|(_, style)| *style = Style::Bold);
+                    }
+                    if matches!(block, Block::Item(_)) {
+                        spans.insert(0, ("\u{2022} ".to_string(), Style::Plain));
+                    }
+                    for line in wrap_spans(&spans, max_width, &measure) {
+                        let runs = line.into_iter().map(|(text, style)| (text, RunStyle::Text(style))).collect();
+                        push(&mut layout, kind, runs, row_height);
+                    }
+                }
+                Block::Code { language: info, code } => {
+                    for line in highlight_code(code, info, language) {
+                        push(&mut layout, LineKind::Code, line, row_height);
+                    }
+                }
+                Block::Rule => push(&mut layout, LineKind::Rule, Vec::new(), 1.0),
+            }
+        }
+        layout.width = layout.width.min(max_width);
+        layout.height = top;
+        layout
+    }
+}
+
+/// Lines of a code block split into runs by token type
+fn highlight_code(code: &str, info: &str, document_language: Option<&str>) -> Vec<Vec<(String, RunStyle)>> {
+    let mut highlighter = SyntaxHighlighter::new();
+    // Fences name languages by id ("rust") or by extension ("rs")
+    let by_extension = detect_from_path(Path::new(&format!("snippet.{}", info)));
+    let known = [Some(info), by_extension, document_language]
+        .into_iter()
+        .flatten()
+        .any(|language| !language.is_empty() && highlighter.set_language(language).is_ok());
+    let highlights = if known {
+        highlighter.parse(code);
+        highlighter.get_highlights(code)
+    } else {
+        Vec::new()
+    };
+
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    for line in code.split('\n') {
+        let line_end = line_start + line.len();
+        let mut runs = Vec::new();
+        let mut position = line_start;
+        for &(start, end, token_type) in &highlights {
+            let (start, end) = (start.max(position), end.min(line_end));
+            if start >= end {
+                continue;
+            }
+            if position < start {
+                runs.push((code[position..start].to_string(), RunStyle::Token(TokenType::Text)));
+            }
+            runs.push((code[start..end].to_string(), RunStyle::Token(token_type)));
+            position = end;
+        }
+        if position < line_end {
+            runs.push((code[position..line_end].to_string(), RunStyle::Token(TokenType::Text)));
+        }
+        lines.push(runs);
+        line_start = line_end + 1;
+    }
+    lines
+}
+
+/// Open or pending hover card over a word of the active tab
+pub(crate) struct HoverPopup {
+    pub line: usize,
+    /// Char column where the hovered word starts
+    pub start: usize,
+    pub card: HoverCard,
+    pub layout: HoverLayout,
+    /// How far the content is scrolled within the card
+    pub scroll: f32,
+    /// Cursor position when the word was hovered; moving the cursor dismisses the card
+    pub cursor: (usize, usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Docs;
+
+    impl HoverProvider for Docs {
+        fn name(&self) -> &str {
+            "docs"
+        }
+
+        fn provide(&self, context: &HoverContext) -> Option<String> {
+            (context.word == "parse").then(|| "Parses **everything**".to_string())
+        }
+    }
+
+    #[test]
+    fn engine_joins_sections() {
+        let mut engine = HoverEngine::default();
+        let context = |word| HoverContext { word, line_text: word, line: 0, column: 0, document_path: None, language: None };
+        assert_eq!(engine.hover(&context("parse")), None);
+
+        engine.register(Box::new(Docs));
+        engine.register(Box::new(Docs));
+        assert_eq!(engine.provider_names(), vec!["docs", "docs"]);
+        assert_eq!(engine.hover(&context("parse")).as_deref(), Some("Parses **everything**\n\n---\n\nParses **everything**"));
+        assert_eq!(engine.hover(&context("other")), None);
+    }
+
+    #[test]
+    fn markdown_blocks_and_spans() {
+        let markdown = "# `parse`\n\nReads the\ninput.\n\n- one\n- two\n---\n```rs\nfn main() {}\n\n```\n";
+        assert_eq!(
+            parse_blocks(markdown),
+            vec![
+                Block::Heading("`parse`".to_string()),
+                Block::Paragraph("Reads the input.".to_string()),
+                Block::Item("one".to_string()),
+                Block::Item("two".to_string()),
+                Block::Rule,
+                Block::Code { language: "rs".to_string(), code: "fn main() {}\n".to_string() },
+            ]
+        );
+
+        assert_eq!(
+            parse_inline("Use `x` for **bold** [docs](https://x.y) \\*not*"),
+            vec![
+                ("Use ".to_string(), Style::Plain),
+                ("x".to_string(), Style::Code),
+                (" for ".to_string(), Style::Plain),
+                ("bold".to_string(), Style::Bold),
+                (" docs *not*".to_string(), Style::Plain),
+            ]
+        );
+    }
+
+    #[test]
+    fn text_wraps_and_code_is_highlighted() {
+        // Every char is 1 wide
+        let measure = |text: &str, _: Style| text.chars().count() as f32;
+        let lines = wrap_spans(&parse_inline("aaa bbb `ccc` dd"), 8.0, measure);
+        assert_eq!(
+            lines,
+            vec![
+                vec![("aaa bbb".to_string(), Style::Plain)],
+                vec![("ccc".to_string(), Style::Code), (" dd".to_string(), Style::Plain)],
+            ]
+        );
+
+        let blocks = parse_blocks("Long text here\n```rust\nlet x = 1;\n```");
+        let layout = HoverLayout::new(&blocks, 10.0, 20.0, None, measure);
+        assert_eq!(layout.lines.len(), 3);
+        assert_eq!(layout.lines[2].top, 50.0);
+        assert_eq!(layout.height, 70.0);
+        assert_eq!(layout.width, 10.0);
+        assert_eq!(layout.lines[2].kind, LineKind::Code);
+        assert_eq!(layout.lines[2].runs[0], ("let".to_string(), RunStyle::Token(TokenType::Keyword)));
+    }
+}
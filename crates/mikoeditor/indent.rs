@@ -0,0 +1,107 @@
+/// Indentation settings for a document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndentStyle {
+    pub use_spaces: bool,
+    pub width: usize,
+}
+
+impl IndentStyle {
+    pub fn spaces(width: usize) -> Self {
+        Self { use_spaces: true, width: width.max(1) }
+    }
+
+    pub fn tabs(width: usize) -> Self {
+        Self { use_spaces: false, width: width.max(1) }
+    }
+
+    /// Text inserted for one indentation level
+    pub fn unit(&self) -> String {
+        if self.use_spaces {
+            " ".repeat(self.width)
+        } else {
+            "\t".to_string()
+        }
+    }
+
+    /// Display name for the status bar
+    pub fn label(&self) -> String {
+        if self.use_spaces {
+            format!("Spaces: {}", self.width)
+        } else {
+            format!("Tab Size: {}", self.width)
+        }
+    }
+
+    /// Visual width of leading whitespace, expanding tabs to tab stops
+    pub fn columns(&self, whitespace: &str) -> usize {
+        whitespace.chars().fold(0, |col, ch| match ch {
+            '\t' => (col / self.width + 1) * self.width,
+            _ => col + 1,
+        })
+    }
+
+    /// Leading whitespace for a visual column count in this style
+    pub fn whitespace_for(&self, columns: usize) -> String {
+        if self.use_spaces {
+            " ".repeat(columns)
+        } else {
+            let mut result = "\t".repeat(columns / self.width);
+            result.push_str(&" ".repeat(columns % self.width));
+            result
+        }
+    }
+
+    /// Guess the indentation used by `text`
+    /// Falls back to `default` when no line is indented; tabs keep the default width
+    pub fn detect(text: &str, default: IndentStyle) -> Self {
+        let mut tab_lines = 0;
+        let mut space_lines = 0;
+        // Votes for each indent step between consecutive space-indented lines
+        let mut steps = [0usize; 9];
+        let mut previous = 0;
+
+        for line in text.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let leading: String = line.chars().take_while(|c| *c == ' ' || *c == '\t').collect();
+            if leading.starts_with('\t') {
+                tab_lines += 1;
+                continue;
+            }
+
+            let width = leading.len();
+            if width > 0 {
+                space_lines += 1;
+            }
+
+            let step = width.abs_diff(previous);
+            if (2..=8).contains(&step) {
+                steps[step] += 1;
+            }
+            previous = width;
+        }
+
+        if tab_lines == 0 && space_lines == 0 {
+            return default;
+        }
+
+        if tab_lines > space_lines {
+            return Self::tabs(default.width);
+        }
+
+        // Prefer the smaller step on ties (4-space files also show 8-column steps)
+        let width = (2..=8)
+            .max_by_key(|step| (steps[*step], std::cmp::Reverse(*step)))
+            .filter(|step| steps[*step] > 0)
+            .unwrap_or(default.width);
+        Self::spaces(width)
+    }
+}
+
+impl Default for IndentStyle {
+    fn default() -> Self {
+        Self::spaces(4)
+    }
+}
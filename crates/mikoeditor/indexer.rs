@@ -0,0 +1,597 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime};
+
+use mikocore::Walker;
+
+use crate::completion::is_word_char;
+use crate::language::detect_language;
+use crate::syntax::{SyntaxHighlighter, TokenType};
+
+/// Files larger than this are listed but their contents aren't indexed
+const MAX_FILE_SIZE: u64 = 1024 * 1024;
+/// How often the indexer polls the workspace for changes made outside the editor
+const RESCAN_INTERVAL: Duration = Duration::from_secs(5);
+/// Files a background search checks between sending results and looking for cancellation
+const SEARCH_BATCH: usize = 500;
+
+type Trigram = [u8; 3];
+
+/// A definition found somewhere in the workspace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexedSymbol {
+    pub name: String,
+    pub kind: TokenType,
+    pub path: PathBuf,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexedFile {
+    modified: SystemTime,
+    symbols: Vec<IndexedSymbol>,
+    /// Lowercase trigrams of the file's words, sorted
+    trigrams: Vec<Trigram>,
+}
+
+/// Lowercase trigrams of every word in `text`; words shorter than three bytes have none
+fn trigrams_of(text: &str) -> Vec<Trigram> {
+    let mut trigrams: Vec<Trigram> = text
+        .split(|c: char| !is_word_char(c))
+        .filter(|word| word.len() >= 3)
+        .flat_map(|word| {
+            let word = word.to_lowercase().into_bytes();
+            word.windows(3).map(|w| [w[0], w[1], w[2]]).collect::<Vec<_>>()
+        })
+        .collect();
+    trigrams.sort_unstable();
+    trigrams.dedup();
+    trigrams
+}
+
+/// True if all chars of `query` appear in `text` in order
+fn is_subsequence(query: &str, text: &str) -> bool {
+    let mut chars = text.chars();
+    query.chars().all(|q| chars.any(|c| c == q))
+}
+
+/// File paths, symbols and word trigrams of a workspace folder
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ProjectIndex {
+    root: PathBuf,
+    files: HashMap<PathBuf, IndexedFile>,
+    /// Files containing each trigram; rebuilt from `files` after loading
+    #[serde(skip)]
+    postings: HashMap<Trigram, HashSet<PathBuf>>,
+}
+
+impl ProjectIndex {
+    pub fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            ..Self::default()
+        }
+    }
+
+    /// Index saved by `save`, if it was built for the same folder
+    pub fn load(cache: &Path, root: &Path) -> Option<Self> {
+        let bytes = std::fs::read(cache).ok()?;
+        let mut index: Self = bincode::deserialize(&bytes).ok()?;
+        if index.root != root {
+            return None;
+        }
+        for (path, file) in &index.files {
+            for trigram in &file.trigrams {
+                index.postings.entry(*trigram).or_default().insert(path.clone());
+            }
+        }
+        Some(index)
+    }
+
+    pub fn save(&self, cache: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        std::fs::write(cache, bincode::serialize(self)?)?;
+        Ok(())
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn file_count(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Every indexed file, sorted by path
+    pub fn files(&self) -> Vec<&Path> {
+        let mut files: Vec<&Path> = self.files.keys().map(PathBuf::as_path).collect();
+        files.sort();
+        files
+    }
+
+    /// Files whose path relative to the root contains the query's chars in order, file name hits first
+    pub fn find_files(&self, query: &str, limit: usize) -> Vec<&Path> {
+        let query = query.to_lowercase();
+        let mut matches: Vec<(bool, &Path)> = self
+            .files
+            .keys()
+            .filter_map(|path| {
+                let relative = path.strip_prefix(&self.root).unwrap_or(path).to_string_lossy().to_lowercase();
+                if !is_subsequence(&query, &relative) {
+                    return None;
+                }
+                let in_name = path
+                    .file_name()
+                    .is_some_and(|name| name.to_string_lossy().to_lowercase().contains(&query));
+                Some((!in_name, path.as_path()))
+            })
+            .collect();
+        matches.sort_by(|a, b| (a.0, a.1.as_os_str().len(), a.1).cmp(&(b.0, b.1.as_os_str().len(), b.1)));
+        matches.into_iter().take(limit).map(|(_, path)| path).collect()
+    }
+
+    /// Symbols whose name contains `query`, ignoring case; prefix matches first
+    pub fn find_symbols(&self, query: &str, limit: usize) -> Vec<&IndexedSymbol> {
+        let query = query.to_lowercase();
+        let mut matches: Vec<(bool, &IndexedSymbol)> = self
+            .files
+            .values()
+            .flat_map(|file| &file.symbols)
+            .filter_map(|symbol| {
+                let name = symbol.name.to_lowercase();
+                name.contains(&query).then(|| (!name.starts_with(&query), symbol))
+            })
+            .collect();
+        matches.sort_by(|a, b| (a.0, &a.1.name, &a.1.path).cmp(&(b.0, &b.1.name, &b.1.path)));
+        matches.into_iter().take(limit).map(|(_, symbol)| symbol).collect()
+    }
+
+    /// Files among `paths` and the symbols in them matching `query`, which must be lowercase
+    fn search_in(&self, query: &IndexQuery, paths: &[PathBuf]) -> Vec<IndexHit> {
+        let files = paths.iter().filter_map(|path| Some((path, self.files.get(path)?)));
+        match query {
+            IndexQuery::Files(query) => files
+                .filter(|(path, _)| {
+                    let relative = path.strip_prefix(&self.root).unwrap_or(path).to_string_lossy().to_lowercase();
+                    is_subsequence(query, &relative)
+                })
+                .map(|(path, _)| IndexHit::File(path.clone()))
+                .collect(),
+            IndexQuery::Symbols(query) => files
+                .flat_map(|(_, file)| &file.symbols)
+                .filter(|symbol| symbol.name.to_lowercase().contains(query.as_str()))
+                .map(|symbol| IndexHit::Symbol(symbol.clone()))
+                .collect(),
+        }
+    }
+
+    /// Files that may contain `query`, for a text search to read instead of the whole workspace
+    /// Queries without a three-char word can't be narrowed down and get every file.
+    pub fn candidate_files(&self, query: &str) -> Vec<&Path> {
+        let trigrams = trigrams_of(query);
+        if trigrams.is_empty() {
+            return self.files();
+        }
+        let mut candidates: Option<HashSet<&PathBuf>> = None;
+        for trigram in &trigrams {
+            let Some(files) = self.postings.get(trigram) else {
+                return Vec::new();
+            };
+            candidates = Some(match candidates {
+                Some(current) => current.into_iter().filter(|path| files.contains(*path)).collect(),
+                None => files.iter().collect(),
+            });
+        }
+        let mut files: Vec<&Path> = candidates.unwrap_or_default().into_iter().map(PathBuf::as_path).collect();
+        files.sort();
+        files
+    }
+
+    fn is_current(&self, path: &Path, modified: SystemTime) -> bool {
+        self.files.get(path).is_some_and(|file| file.modified == modified)
+    }
+
+    fn insert(&mut self, path: PathBuf, file: IndexedFile) {
+        self.remove(&path);
+        for trigram in &file.trigrams {
+            self.postings.entry(*trigram).or_default().insert(path.clone());
+        }
+        self.files.insert(path, file);
+    }
+
+    fn remove(&mut self, path: &Path) -> bool {
+        let Some(file) = self.files.remove(path) else {
+            return false;
+        };
+        for trigram in &file.trigrams {
+            if let Some(paths) = self.postings.get_mut(trigram) {
+                paths.remove(path);
+                if paths.is_empty() {
+                    self.postings.remove(trigram);
+                }
+            }
+        }
+        true
+    }
+}
+
+/// Read and index one file; binary and oversized files are kept without symbols or trigrams
+fn index_file(path: &Path, modified: SystemTime, highlighter: &mut SyntaxHighlighter) -> IndexedFile {
+    let mut file = IndexedFile {
+        modified,
+        symbols: Vec::new(),
+        trigrams: Vec::new(),
+    };
+    let too_large = std::fs::metadata(path).map_or(true, |meta| meta.len() > MAX_FILE_SIZE);
+    let Some(bytes) = (!too_large).then(|| std::fs::read(path).ok()).flatten() else {
+        return file;
+    };
+    if bytes.iter().take(8000).any(|&b| b == 0) {
+        return file;
+    }
+    let text = String::from_utf8_lossy(&bytes);
+
+    if highlighter.set_language(detect_language(Some(path), &text)).is_ok() {
+        highlighter.parse(&text);
+        file.symbols = highlighter
+            .get_symbols(&text)
+            .into_iter()
+            .map(|symbol| IndexedSymbol {
+                name: symbol.name,
+                kind: symbol.kind,
+                path: path.to_path_buf(),
+                line: symbol.line,
+                column: symbol.column,
+            })
+            .collect();
+    }
+    file.trigrams = trigrams_of(&text);
+    file
+}
+
+/// What `Indexer::search` looks for, with the same matching as `find_files` and `find_symbols`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexQuery {
+    Files(String),
+    Symbols(String),
+}
+
+/// One result of `Indexer::search`
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexHit {
+    File(PathBuf),
+    Symbol(IndexedSymbol),
+}
+
+/// A search of the index running on a background thread; dropping it cancels the search
+///
+/// Results arrive in batches as the files are checked, in path order. The index is only
+/// locked while a batch is checked, so the indexer can keep updating in between.
+pub struct IndexSearch {
+    results: Receiver<Vec<IndexHit>>,
+    cancel: Arc<AtomicBool>,
+    done: bool,
+}
+
+impl IndexSearch {
+    fn spawn(index: Arc<RwLock<ProjectIndex>>, query: IndexQuery, limit: usize) -> Self {
+        let query = match query {
+            IndexQuery::Files(query) => IndexQuery::Files(query.to_lowercase()),
+            IndexQuery::Symbols(query) => IndexQuery::Symbols(query.to_lowercase()),
+        };
+        let cancel = Arc::new(AtomicBool::new(false));
+        let (sender, results) = mpsc::channel();
+        let cancelled = cancel.clone();
+        std::thread::spawn(move || {
+            let read = || index.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let mut paths: Vec<PathBuf> = read().files.keys().cloned().collect();
+            paths.sort();
+            let mut remaining = limit;
+            for batch in paths.chunks(SEARCH_BATCH) {
+                if cancelled.load(Ordering::Relaxed) || remaining == 0 {
+                    return;
+                }
+                let mut hits = read().search_in(&query, batch);
+                hits.truncate(remaining);
+                remaining -= hits.len();
+                if !hits.is_empty() && sender.send(hits).is_err() {
+                    return;
+                }
+            }
+        });
+        Self {
+            results,
+            cancel,
+            done: false,
+        }
+    }
+
+    /// Results found since the last call, without waiting for more
+    pub fn poll(&mut self) -> Vec<IndexHit> {
+        let mut hits = Vec::new();
+        loop {
+            match self.results.try_recv() {
+                Ok(batch) => hits.extend(batch),
+                Err(mpsc::TryRecvError::Empty) => return hits,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    self.done = true;
+                    return hits;
+                }
+            }
+        }
+    }
+
+    /// True once every result was returned by `poll`
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+}
+
+impl Drop for IndexSearch {
+    fn drop(&mut self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+enum Request {
+    Changed(PathBuf),
+    Rescan,
+    Stop,
+}
+
+/// Keeps a `ProjectIndex` of a folder up to date on a background thread
+///
+/// The first scan starts from the on-disk cache when there is one, so only files changed
+/// since the last session are read again. After that the folder is polled for changes, and
+/// files saved in the editor are re-indexed as soon as `file_changed` reports them.
+pub struct Indexer {
+    index: Arc<RwLock<ProjectIndex>>,
+    ready: Arc<AtomicBool>,
+    stop: Arc<AtomicBool>,
+    requests: Sender<Request>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Indexer {
+    /// Start indexing the walker's root, skipping what it skips; `cache` is where the index is kept between sessions
+    pub fn start(walker: Walker, cache: Option<PathBuf>) -> Self {
+        let index = Arc::new(RwLock::new(ProjectIndex::new(walker.root().to_path_buf())));
+        let ready = Arc::new(AtomicBool::new(false));
+        let stop = Arc::new(AtomicBool::new(false));
+        let (requests, receiver) = mpsc::channel();
+
+        let worker = {
+            let index = index.clone();
+            let ready = ready.clone();
+            let stop = stop.clone();
+            std::thread::Builder::new()
+                .name("project-indexer".to_string())
+                .spawn(move || Worker { index, stop, cache, walker, highlighter: SyntaxHighlighter::new() }.run(receiver, &ready))
+                .map_err(|e| eprintln!("Failed to start the project indexer: {}", e))
+                .ok()
+        };
+
+        Self {
+            index,
+            ready,
+            stop,
+            requests,
+            worker,
+        }
+    }
+
+    /// The index as of now; hold the guard briefly since the worker waits for it to update
+    pub fn index(&self) -> RwLockReadGuard<'_, ProjectIndex> {
+        self.index.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// True once the first full scan has finished
+    pub fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::Relaxed)
+    }
+
+    /// Look up files or symbols in the background, stopping after `limit` results
+    pub fn search(&self, query: IndexQuery, limit: usize) -> IndexSearch {
+        IndexSearch::spawn(self.index.clone(), query, limit)
+    }
+
+    /// Re-index a file that was saved, created or deleted
+    pub fn file_changed(&self, path: &Path) {
+        let _ = self.requests.send(Request::Changed(path.to_path_buf()));
+    }
+
+    /// Check the whole folder for changes now instead of at the next poll
+    pub fn rescan(&self) {
+        let _ = self.requests.send(Request::Rescan);
+    }
+}
+
+impl Drop for Indexer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        let _ = self.requests.send(Request::Stop);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+struct Worker {
+    index: Arc<RwLock<ProjectIndex>>,
+    stop: Arc<AtomicBool>,
+    cache: Option<PathBuf>,
+    walker: Walker,
+    highlighter: SyntaxHighlighter,
+}
+
+impl Worker {
+    fn run(mut self, requests: Receiver<Request>, ready: &AtomicBool) {
+        if let Some(cache) = self.cache.as_deref() {
+            let root = self.read().root.clone();
+            if let Some(cached) = ProjectIndex::load(cache, &root) {
+                *self.write() = cached;
+            }
+        }
+        let mut dirty = self.scan();
+        ready.store(true, Ordering::Relaxed);
+        let mut next_scan = Instant::now() + RESCAN_INTERVAL;
+
+        loop {
+            match requests.recv_timeout(next_scan.saturating_duration_since(Instant::now())) {
+                Ok(Request::Changed(path)) => dirty |= self.update(path),
+                Ok(Request::Rescan) => dirty |= self.scan(),
+                Err(RecvTimeoutError::Timeout) => {
+                    dirty |= self.scan();
+                    // Write the cache while idle rather than after every save
+                    if dirty {
+                        self.save_cache();
+                        dirty = false;
+                    }
+                    next_scan = Instant::now() + RESCAN_INTERVAL;
+                }
+                Ok(Request::Stop) | Err(RecvTimeoutError::Disconnected) => break,
+            }
+            if self.stop.load(Ordering::Relaxed) {
+                break;
+            }
+        }
+        if dirty {
+            self.save_cache();
+        }
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, ProjectIndex> {
+        self.index.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, ProjectIndex> {
+        self.index.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Bring the index in line with the folder; returns true if anything changed
+    fn scan(&mut self) -> bool {
+        let found: Vec<(PathBuf, SystemTime)> = self
+            .walker
+            .iter()
+            .filter(|entry| !entry.is_dir)
+            .filter_map(|entry| {
+                let modified = std::fs::metadata(&entry.path).and_then(|meta| meta.modified()).ok()?;
+                Some((entry.path, modified))
+            })
+            .collect();
+
+        let mut changed = false;
+        for (path, modified) in &found {
+            if self.stop.load(Ordering::Relaxed) {
+                return changed;
+            }
+            if self.read().is_current(path, *modified) {
+                continue;
+            }
+            // Read and parse outside the lock so lookups aren't blocked meanwhile
+            let file = index_file(path, *modified, &mut self.highlighter);
+            self.write().insert(path.clone(), file);
+            changed = true;
+        }
+
+        let found: HashSet<&PathBuf> = found.iter().map(|(path, _)| path).collect();
+        let mut index = self.write();
+        let gone: Vec<PathBuf> = index.files.keys().filter(|path| !found.contains(path)).cloned().collect();
+        for path in &gone {
+            index.remove(path);
+        }
+        changed || !gone.is_empty()
+    }
+
+    /// Re-index or drop one file; returns true if the index changed
+    fn update(&mut self, path: PathBuf) -> bool {
+        if self.walker.is_ignored(&path) {
+            // Newly ignored files leave the index; files outside the root were never in it
+            return self.write().remove(&path);
+        }
+        match std::fs::metadata(&path).and_then(|meta| meta.modified()) {
+            Ok(modified) if path.is_file() => {
+                let file = index_file(&path, modified, &mut self.highlighter);
+                self.write().insert(path, file);
+                true
+            }
+            _ => self.write().remove(&path),
+        }
+    }
+
+    fn save_cache(&self) {
+        if let Some(cache) = self.cache.as_deref() {
+            if let Err(e) = self.read().save(cache) {
+                eprintln!("Failed to save the project index: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trigrams_narrow_down_candidates() {
+        let mut index = ProjectIndex::new(PathBuf::from("/ws"));
+        let file = |text: &str| IndexedFile {
+            modified: SystemTime::UNIX_EPOCH,
+            symbols: Vec::new(),
+            trigrams: trigrams_of(text),
+        };
+        index.insert(PathBuf::from("/ws/a.rs"), file("fn render_frame() {}"));
+        index.insert(PathBuf::from("/ws/b.rs"), file("let Frame = 1;"));
+
+        assert_eq!(index.candidate_files("frame"), vec![Path::new("/ws/a.rs"), Path::new("/ws/b.rs")]);
+        assert_eq!(index.candidate_files("render"), vec![Path::new("/ws/a.rs")]);
+        assert!(index.candidate_files("missing").is_empty());
+
+        index.remove(Path::new("/ws/a.rs"));
+        assert!(index.candidate_files("render").is_empty());
+        assert_eq!(index.find_files("b", 10), vec![Path::new("/ws/b.rs")]);
+    }
+
+    #[test]
+    fn background_search_streams_hits_until_done() {
+        let mut index = ProjectIndex::new(PathBuf::from("/ws"));
+        let symbol = |name: &str, path: &str| IndexedSymbol {
+            name: name.to_string(),
+            kind: TokenType::Function,
+            path: PathBuf::from(path),
+            line: 0,
+            column: 0,
+        };
+        for i in 0..SEARCH_BATCH + 10 {
+            let path = format!("/ws/src/file{:04}.rs", i);
+            let file = IndexedFile {
+                modified: SystemTime::UNIX_EPOCH,
+                symbols: vec![symbol(&format!("render{}", i), &path)],
+                trigrams: Vec::new(),
+            };
+            index.insert(PathBuf::from(path), file);
+        }
+        let index = Arc::new(RwLock::new(index));
+        let collect = |mut search: IndexSearch| {
+            let mut hits = Vec::new();
+            while !search.is_done() {
+                hits.extend(search.poll());
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            hits
+        };
+
+        // The only hit is in the second batch
+        let hits = collect(IndexSearch::spawn(index.clone(), IndexQuery::Files("SRC/FILE0509".to_string()), 100));
+        assert_eq!(hits, vec![IndexHit::File(PathBuf::from("/ws/src/file0509.rs"))]);
+
+        let hits = collect(IndexSearch::spawn(index.clone(), IndexQuery::Symbols("render".to_string()), SEARCH_BATCH + 5));
+        assert_eq!(hits.len(), SEARCH_BATCH + 5);
+        assert_eq!(hits[0], IndexHit::Symbol(symbol("render0", "/ws/src/file0000.rs")));
+    }
+}
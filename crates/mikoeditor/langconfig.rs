@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Brackets every built-in language pairs up
+const BRACKETS: [(char, char); 3] = [('(', ')'), ('[', ']'), ('{', '}')];
+const C_BLOCK_COMMENT: Option<(&str, &str)> = Some(("/*", "*/"));
+const MARKUP_COMMENT: Option<(&str, &str)> = Some(("<!--", "-->"));
+
+/// Comment tokens, brackets and word characters of a language
+///
+/// Built-in configs come from `LanguageConfig::builtin`; entries of languages.yml files are merged
+/// over them with `set_language_configs`, field by field.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LanguageConfig {
+    /// Starts a comment running to the end of the line, e.g. `//`
+    pub line_comment: Option<String>,
+    /// Opens and closes a comment that may span lines, e.g. `/*` and `*/`
+    pub block_comment: Option<(String, String)>,
+    /// Pairs Go to Bracket jumps between and the editor highlights at the cursor
+    pub brackets: Vec<(char, char)>,
+    /// Pairs whose closer is typed along with the opener
+    pub auto_close: Vec<(char, char)>,
+    /// Quotes that delimit strings; a quote right after a word isn't auto-closed
+    pub strings: Vec<char>,
+    /// Chars besides letters and digits that are part of a word, e.g. `_$` in JavaScript
+    pub word_chars: String,
+}
+
+impl LanguageConfig {
+    fn new(line_comment: Option<&str>, block_comment: Option<(&str, &str)>, strings: &[char]) -> Self {
+        Self {
+            line_comment: line_comment.map(str::to_string),
+            block_comment: block_comment.map(|(open, close)| (open.to_string(), close.to_string())),
+            brackets: BRACKETS.to_vec(),
+            auto_close: BRACKETS.iter().copied().chain(strings.iter().map(|&quote| (quote, quote))).collect(),
+            strings: strings.to_vec(),
+            word_chars: "_".to_string(),
+        }
+    }
+
+    fn with_word_chars(mut self, word_chars: &str) -> Self {
+        self.word_chars = word_chars.to_string();
+        self
+    }
+
+    /// A pair for bracket matching only, e.g. `<` `>`, which would get in the way when typed as a comparison
+    fn with_bracket(mut self, pair: (char, char)) -> Self {
+        self.brackets.push(pair);
+        self
+    }
+
+    /// Config of a language id from `LANGUAGES`; unknown ids get the plain text one
+    pub fn builtin(language: &str) -> Self {
+        match language {
+            "rust" => Self::new(Some("//"), C_BLOCK_COMMENT, &['"']),
+            "c" | "cpp" | "java" | "kotlin" => Self::new(Some("//"), C_BLOCK_COMMENT, &['"', '\'']),
+            "go" => Self::new(Some("//"), C_BLOCK_COMMENT, &['"', '\'', '`']),
+            "javascript" | "typescript" | "tsx" => Self::new(Some("//"), C_BLOCK_COMMENT, &['"', '\'', '`']).with_word_chars("_$"),
+            "php" => Self::new(Some("//"), C_BLOCK_COMMENT, &['"', '\'']).with_word_chars("_$"),
+            "css" => Self::new(None, C_BLOCK_COMMENT, &['"', '\'']).with_word_chars("_-"),
+            "json" => Self::new(None, None, &['"']),
+            "python" | "ruby" | "toml" | "yaml" | "dockerfile" => Self::new(Some("#"), None, &['"', '\'']),
+            "bash" | "makefile" => Self::new(Some("#"), None, &['"', '\'', '`']),
+            "lua" => Self::new(Some("--"), Some(("--[[", "]]")), &['"', '\'']),
+            "sql" => Self::new(Some("--"), C_BLOCK_COMMENT, &['"', '\'']),
+            "html" | "xml" => Self::new(None, MARKUP_COMMENT, &['"', '\'']).with_bracket(('<', '>')),
+            "markdown" => Self::new(None, MARKUP_COMMENT, &[]),
+            "vim" => Self::new(Some("\""), None, &['\'']),
+            _ => Self::new(None, None, &['"']),
+        }
+    }
+
+    /// Take the fields `other` sets; ones it leaves out or empty keep their value
+    pub fn merge(&mut self, other: LanguageConfig) {
+        if other.line_comment.is_some() {
+            self.line_comment = other.line_comment;
+        }
+        if other.block_comment.is_some() {
+            self.block_comment = other.block_comment;
+        }
+        if !other.brackets.is_empty() {
+            self.brackets = other.brackets;
+        }
+        if !other.auto_close.is_empty() {
+            self.auto_close = other.auto_close;
+        }
+        if !other.strings.is_empty() {
+            self.strings = other.strings;
+        }
+        if !other.word_chars.is_empty() {
+            self.word_chars = other.word_chars;
+        }
+    }
+
+    /// Whether `c` is part of a word, for word selection and the word under the pointer
+    pub fn is_word_char(&self, c: char) -> bool {
+        c.is_alphanumeric() || self.word_chars.contains(c)
+    }
+
+    /// Whether `c` closes one of the auto-close pairs, so typing it in front of itself steps over it
+    pub fn is_closer(&self, c: char) -> bool {
+        self.auto_close.iter().any(|&(_, close)| close == c)
+    }
+
+    /// Closer to type along with `c`, typed between `before` and `after`
+    ///
+    /// Only in front of whitespace, a closer or the line end; quotes also not right after a word,
+    /// where they more likely end a string or are an apostrophe.
+    pub fn auto_close(&self, c: char, before: Option<char>, after: Option<char>) -> Option<char> {
+        let &(_, close) = self.auto_close.iter().find(|&&(open, _)| open == c)?;
+        let free_after = after.is_none_or(|next| next.is_whitespace() || self.is_closer(next));
+        let is_quote = close == c || self.strings.contains(&c);
+        let free_before = !is_quote || before.is_none_or(|prev| !self.is_word_char(prev) && prev != c);
+        (free_after && free_before).then_some(close)
+    }
+
+    /// The other half of bracket `c`, and whether it comes after `c`
+    pub fn bracket_partner(&self, c: char) -> Option<(char, bool)> {
+        self.brackets.iter().find_map(|&(open, close)| {
+            if c == open {
+                Some((close, true))
+            } else if c == close {
+                Some((open, false))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+thread_local! {
+    /// Entries of languages.yml files by language id, merged over the built-in configs
+    static CUSTOM_CONFIGS: RefCell<HashMap<String, LanguageConfig>> = RefCell::new(HashMap::new());
+}
+
+/// Replace the configs read from languages.yml files; each is merged over its language's built-in one
+pub fn set_language_configs(configs: impl IntoIterator<Item = (String, LanguageConfig)>) {
+    let configs = configs.into_iter().collect();
+    CUSTOM_CONFIGS.with(|custom| *custom.borrow_mut() = configs);
+}
+
+/// Config of a language id, with its languages.yml entry merged over the built-in one
+pub fn language_config(language: &str) -> LanguageConfig {
+    let mut config = LanguageConfig::builtin(language);
+    if let Some(custom) = CUSTOM_CONFIGS.with(|custom| custom.borrow().get(language).cloned()) {
+        config.merge(custom);
+    }
+    config
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_entries_merge_over_builtin_configs() {
+        assert_eq!(language_config("python").line_comment.as_deref(), Some("#"));
+        assert!(language_config("javascript").is_word_char('$'));
+        assert!(!language_config("rust").is_word_char('$'));
+
+        set_language_configs([(
+            "rust".to_string(),
+            LanguageConfig { word_chars: "_$".to_string(), ..LanguageConfig::default() },
+        )]);
+        let rust = language_config("rust");
+        assert!(rust.is_word_char('$'));
+        assert_eq!(rust.line_comment.as_deref(), Some("//"));
+        assert_eq!(rust.bracket_partner(']'), Some(('[', false)));
+        set_language_configs([]);
+    }
+
+    #[test]
+    fn openers_close_only_in_front_of_space_or_closers() {
+        let config = LanguageConfig::builtin("javascript");
+        assert_eq!(config.auto_close('(', None, None), Some(')'));
+        assert_eq!(config.auto_close('(', Some('f'), Some(')')), Some(')'));
+        assert_eq!(config.auto_close('(', None, Some('x')), None);
+        assert_eq!(config.auto_close('"', Some(' '), None), Some('"'));
+        // An apostrophe, or the end of a string
+        assert_eq!(config.auto_close('\'', Some('n'), None), None);
+        assert_eq!(config.auto_close('x', None, None), None);
+        assert!(config.is_closer('`'));
+    }
+}
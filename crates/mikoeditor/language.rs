@@ -0,0 +1,182 @@
+use std::path::Path;
+
+/// Language ids with their display names, in picker order
+pub const LANGUAGES: &[(&str, &str)] = &[
+    ("bash", "Shell"),
+    ("c", "C"),
+    ("cpp", "C++"),
+    ("css", "CSS"),
+    ("dockerfile", "Dockerfile"),
+    ("go", "Go"),
+    ("html", "HTML"),
+    ("java", "Java"),
+    ("javascript", "JavaScript"),
+    ("json", "JSON"),
+    ("kotlin", "Kotlin"),
+    ("lua", "Lua"),
+    ("makefile", "Makefile"),
+    ("markdown", "Markdown"),
+    ("php", "PHP"),
+    ("python", "Python"),
+    ("ruby", "Ruby"),
+    ("rust", "Rust"),
+    ("sql", "SQL"),
+    ("text", "Plain Text"),
+    ("toml", "TOML"),
+    ("tsx", "TSX"),
+    ("typescript", "TypeScript"),
+    ("vim", "Vim Script"),
+    ("xml", "XML"),
+    ("yaml", "YAML"),
+];
+
+/// Display name for a language id; unknown ids show as plain text
+pub fn language_display_name(language: &str) -> &'static str {
+    LANGUAGES
+        .iter()
+        .find(|(id, _)| *id == language)
+        .map_or("Plain Text", |(_, name)| name)
+}
+
+/// Language from a file name or extension
+pub fn detect_from_path(path: &Path) -> Option<&'static str> {
+    let file_name = path.file_name()?.to_str()?;
+    let by_name = match file_name {
+        "Makefile" | "makefile" | "GNUmakefile" => Some("makefile"),
+        "Dockerfile" | "Containerfile" => Some("dockerfile"),
+        "Cargo.lock" | "Pipfile" => Some("toml"),
+        ".bashrc" | ".bash_profile" | ".zshrc" | ".profile" => Some("bash"),
+        ".vimrc" => Some("vim"),
+        _ => None,
+    };
+    if by_name.is_some() {
+        return by_name;
+    }
+
+    let language = match path.extension()?.to_str()? {
+        // Rust
+        "rs" => "rust",
+
+        // JavaScript/TypeScript
+        "js" | "mjs" | "cjs" => "javascript",
+        "jsx" => "javascript",
+        "ts" | "mts" | "cts" => "typescript",
+        "tsx" => "tsx",
+
+        // Python
+        "py" | "pyw" | "pyi" => "python",
+
+        // Web
+        "html" | "htm" => "html",
+        "css" | "scss" | "sass" | "less" => "css",
+
+        // Data formats
+        "json" | "jsonc" => "json",
+        "xml" | "svg" => "xml",
+        "yaml" | "yml" => "yaml",
+        "toml" => "toml",
+
+        // Markup
+        "md" | "markdown" => "markdown",
+
+        // C/C++
+        "c" | "h" => "c",
+        "cpp" | "cc" | "cxx" | "hpp" | "hxx" => "cpp",
+
+        // Java/Kotlin
+        "java" => "java",
+        "kt" | "kts" => "kotlin",
+
+        // Go
+        "go" => "go",
+
+        // Ruby
+        "rb" => "ruby",
+
+        // PHP
+        "php" => "php",
+
+        // Shell
+        "sh" | "bash" | "zsh" => "bash",
+
+        // Other
+        "sql" => "sql",
+        "lua" => "lua",
+        "vim" => "vim",
+        "mk" => "makefile",
+        "txt" => "text",
+
+        _ => return None,
+    };
+    Some(language)
+}
+
+/// Language from a shebang or a telltale first line, for files without a known extension
+pub fn detect_from_content(text: &str) -> Option<&'static str> {
+    let first_line = text.lines().next()?.trim();
+
+    if let Some(shebang) = first_line.strip_prefix("#!") {
+        // `#!/usr/bin/env -S python3 -u` names the interpreter after env and its flags
+        let mut words = shebang.split_whitespace();
+        let mut program = words.next()?.rsplit('/').next()?;
+        if program == "env" {
+            program = words.find(|word| !word.starts_with('-'))?;
+        }
+        let program = program.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+        return match program {
+            "sh" | "bash" | "zsh" | "dash" | "ksh" => Some("bash"),
+            "python" | "pypy" => Some("python"),
+            "node" | "deno" | "bun" => Some("javascript"),
+            "ts-node" => Some("typescript"),
+            "ruby" => Some("ruby"),
+            "php" => Some("php"),
+            "lua" => Some("lua"),
+            "make" => Some("makefile"),
+            _ => None,
+        };
+    }
+
+    let lower = first_line.to_ascii_lowercase();
+    if lower.starts_with("<?php") {
+        Some("php")
+    } else if lower.starts_with("<?xml") {
+        Some("xml")
+    } else if lower.starts_with("<!doctype html") || lower.starts_with("<html") {
+        Some("html")
+    } else if first_line == "---" || first_line.starts_with("%YAML") {
+        Some("yaml")
+    } else {
+        let trimmed = text.trim();
+        let json_like = (trimmed.starts_with('{') && trimmed.ends_with('}'))
+            || (trimmed.starts_with('[') && trimmed.ends_with(']') && trimmed.contains(['"', '{']));
+        json_like.then_some("json")
+    }
+}
+
+/// Best guess for a document: its file name first, then its contents
+pub fn detect_language(path: Option<&Path>, text: &str) -> &'static str {
+    path.and_then(detect_from_path)
+        .or_else(|| detect_from_content(text))
+        .unwrap_or("text")
+}
+
+/// Shell command that runs a file of `language` with its usual interpreter, e.g. `python3 "main.py"`
+///
+/// None for languages that need a build step or a project to run.
+pub fn run_command(language: &str, path: &Path) -> Option<String> {
+    let python = if cfg!(windows) { "python" } else { "python3" };
+    let program = match language {
+        "bash" => "bash",
+        "python" => python,
+        "javascript" => "node",
+        "typescript" | "tsx" => "npx tsx",
+        "ruby" => "ruby",
+        "php" => "php",
+        "lua" => "lua",
+        "go" => "go run",
+        // Single-file source launch, Java 11 and up
+        "java" => "java",
+        _ => return None,
+    };
+    Some(format!("{} \"{}\"", program, path.display()))
+}
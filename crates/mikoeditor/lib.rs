@@ -1,11 +1,55 @@
+mod annotations;
+mod autosave;
+mod bookmarks;
 mod buffer;
+mod command;
+mod completion;
+mod conflicts;
+mod decorations;
+mod dragtext;
 mod editor;
+mod encoding;
+mod format;
+mod hex;
+mod hover;
+mod indent;
+mod indexer;
+mod langconfig;
+mod language;
+mod macros;
+mod markers;
+mod peek;
+mod preview;
 mod syntax;
 mod tab;
 mod tabbar;
+mod todos;
+mod tokenizer;
+mod zones;
 
-pub use buffer::TextBuffer;
+pub use annotations::{Annotation, AnnotationKind, Annotations, RulerLane};
+pub use autosave::AutoSaveMode;
+pub use buffer::{ChangeEvent, SaveOptions, SubscriptionId, TextBuffer};
+pub use command::EditorCommand;
+pub use completion::{
+    CompletionContext, CompletionEngine, CompletionItem, CompletionKind, CompletionProvider, PathProvider, WordProvider,
+};
+pub use conflicts::{find_conflicts, Conflict, Resolution};
+pub use decorations::{Decoration, Decorations, InlineText, UnderlineStyle};
 pub use editor::Editor;
-pub use syntax::{Language, SyntaxHighlighter, TokenType};
-pub use tab::{EditorTab, TabManager};
+pub use encoding::{Encoding, LineEnding};
+pub use format::Formatter;
+pub use hex::{HexBuffer, HexEditor};
+pub use hover::{HoverContext, HoverEngine, HoverProvider};
+pub use indent::IndentStyle;
+pub use indexer::{IndexHit, IndexQuery, IndexSearch, IndexedSymbol, Indexer, ProjectIndex};
+pub use langconfig::{language_config, set_language_configs, LanguageConfig};
+pub use language::{detect_from_content, detect_from_path, detect_language, language_display_name, run_command, LANGUAGES};
+pub use macros::{MacroRecorder, MacroStep};
+pub use markers::{Bias, MarkerChange, MarkerId, Markers};
+pub use syntax::{DocumentSymbol, Language, Scope, SyntaxHighlighter, TokenType};
+pub use preview::Preview;
+pub use tab::{BracketPair, EditorTab, TabManager, TabView};
 pub use tabbar::TabBar;
+pub use todos::{find_todos, TodoComment, DEFAULT_TODO_KEYWORDS};
+pub use zones::ZoneWidget;
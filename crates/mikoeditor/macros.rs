@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+use crate::command::EditorCommand;
+
+/// One step of a keyboard macro
+///
+/// Saved macros list steps as `- newline`, `- type: "text"` or `- command: 39`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MacroStep {
+    Edit(EditorCommand),
+    /// An app command, by its command palette id
+    Command { command: u32 },
+}
+
+/// Records editor commands into a macro and keeps the last one for replay
+#[derive(Debug, Default)]
+pub struct MacroRecorder {
+    recording: Option<Vec<MacroStep>>,
+    last: Vec<MacroStep>,
+}
+
+impl MacroRecorder {
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    pub fn start(&mut self) {
+        self.recording = Some(Vec::new());
+    }
+
+    /// Stop recording; a non-empty recording becomes the last macro. Returns its step count.
+    pub fn stop(&mut self) -> usize {
+        let steps = self.recording.take().unwrap_or_default();
+        if !steps.is_empty() {
+            self.last = steps;
+        }
+        self.last.len()
+    }
+
+    /// Add a step while recording; typing in a row becomes one step
+    pub fn record(&mut self, step: MacroStep) {
+        let Some(steps) = self.recording.as_mut() else {
+            return;
+        };
+        if let (Some(MacroStep::Edit(EditorCommand::Type(typed))), MacroStep::Edit(EditorCommand::Type(text))) = (steps.last_mut(), &step) {
+            typed.push_str(text);
+            return;
+        }
+        steps.push(step);
+    }
+
+    /// The last recorded macro; empty before the first recording
+    pub fn last(&self) -> &[MacroStep] {
+        &self.last
+    }
+
+    /// Make a saved macro the one replayed next
+    pub fn set_last(&mut self, steps: Vec<MacroStep>) {
+        self.last = steps;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_typing_as_one_step() {
+        let mut recorder = MacroRecorder::default();
+        recorder.record(MacroStep::Command { command: 39 });
+        assert!(recorder.last().is_empty());
+
+        recorder.start();
+        recorder.record(MacroStep::Edit(EditorCommand::Type("a".to_string())));
+        recorder.record(MacroStep::Edit(EditorCommand::Type("b".to_string())));
+        recorder.record(MacroStep::Edit(EditorCommand::Newline));
+        recorder.record(MacroStep::Command { command: 39 });
+        assert_eq!(recorder.stop(), 3);
+        assert_eq!(recorder.last()[0], MacroStep::Edit(EditorCommand::Type("ab".to_string())));
+
+        // An empty recording keeps the previous macro
+        recorder.start();
+        assert_eq!(recorder.stop(), 3);
+    }
+}
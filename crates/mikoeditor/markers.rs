@@ -0,0 +1,191 @@
+use std::ops::Range;
+
+/// Which way a position leans when text is inserted exactly at it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Bias {
+    /// Stays before the inserted text, e.g. the end of a range
+    #[default]
+    Left,
+    /// Moves after the inserted text, e.g. the start of a range
+    Right,
+}
+
+impl Bias {
+    /// Where char `offset` ends up after `removed` chars at `start` were replaced by `inserted` chars
+    /// Positions inside the removed text collapse onto the side of the insertion they lean to.
+    pub fn map(self, offset: usize, start: usize, removed: usize, inserted: usize) -> usize {
+        if offset < start || (offset == start && self == Bias::Left) {
+            offset
+        } else if offset >= start + removed {
+            offset - removed + inserted
+        } else if self == Bias::Left {
+            start
+        } else {
+            start + inserted
+        }
+    }
+}
+
+/// Handle to a marker, stable for the marker's lifetime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MarkerId(u64);
+
+/// A marker that an edit moved, as reported to the change listener
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarkerChange {
+    pub id: MarkerId,
+    pub old_offset: usize,
+    pub new_offset: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Marker {
+    id: MarkerId,
+    offset: usize,
+    bias: Bias,
+}
+
+impl Marker {
+    /// Sort key; left-leaning markers go first so an insertion never reorders a tie
+    fn key(&self) -> (usize, Bias) {
+        (self.offset, self.bias)
+    }
+}
+
+/// Char positions that follow edits, kept sorted by offset
+///
+/// An edit binary-searches to the first marker it can reach, remaps the ones inside the edited
+/// range and shifts the rest by the same delta, so markers before the edit are never touched.
+#[derive(Debug, Clone, Default)]
+pub struct Markers {
+    markers: Vec<Marker>,
+    next_id: u64,
+}
+
+impl Markers {
+    pub fn add(&mut self, offset: usize, bias: Bias) -> MarkerId {
+        let id = MarkerId(self.next_id);
+        self.next_id += 1;
+        let marker = Marker { id, offset, bias };
+        let index = self.markers.partition_point(|m| m.key() <= marker.key());
+        self.markers.insert(index, marker);
+        id
+    }
+
+    /// Returns false if the marker was already gone
+    pub fn remove(&mut self, id: MarkerId) -> bool {
+        let before = self.markers.len();
+        self.markers.retain(|m| m.id != id);
+        self.markers.len() != before
+    }
+
+    pub fn clear(&mut self) {
+        self.markers.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.markers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.markers.is_empty()
+    }
+
+    /// Current char offset of a marker
+    pub fn offset(&self, id: MarkerId) -> Option<usize> {
+        self.markers.iter().find(|m| m.id == id).map(|m| m.offset)
+    }
+
+    /// Markers with offsets in `range`, in document order
+    pub fn in_range(&self, range: Range<usize>) -> impl Iterator<Item = (MarkerId, usize)> + '_ {
+        let first = self.markers.partition_point(|m| m.offset < range.start);
+        self.markers[first..]
+            .iter()
+            .take_while(move |m| m.offset < range.end)
+            .map(|m| (m.id, m.offset))
+    }
+
+    /// Move markers for `removed` chars at `start` replaced by `inserted` chars
+    pub(crate) fn apply_edit(&mut self, start: usize, removed: usize, inserted: usize) -> Vec<MarkerChange> {
+        let mut changes = Vec::new();
+        if removed == 0 && inserted == 0 {
+            return changes;
+        }
+        let edit_end = start + removed;
+        let first = self.markers.partition_point(|m| m.offset < start);
+        let tail = self.markers.partition_point(|m| m.offset <= edit_end);
+
+        // Markers touching the edit may collapse onto the same offset, so re-sort just those
+        for marker in &mut self.markers[first..tail] {
+            let new_offset = marker.bias.map(marker.offset, start, removed, inserted);
+            if new_offset != marker.offset {
+                changes.push(MarkerChange { id: marker.id, old_offset: marker.offset, new_offset });
+                marker.offset = new_offset;
+            }
+        }
+        self.markers[first..tail].sort_by_key(Marker::key);
+
+        // Everything past the edit shifts by the same amount and keeps its order
+        if removed != inserted {
+            for marker in &mut self.markers[tail..] {
+                let new_offset = marker.offset - removed + inserted;
+                changes.push(MarkerChange { id: marker.id, old_offset: marker.offset, new_offset });
+                marker.offset = new_offset;
+            }
+        }
+        changes
+    }
+
+    /// Move every marker through `map` after the whole text was swapped
+    pub(crate) fn remap(&mut self, map: impl Fn(usize) -> usize) -> Vec<MarkerChange> {
+        let mut changes = Vec::new();
+        for marker in &mut self.markers {
+            let new_offset = map(marker.offset);
+            if new_offset != marker.offset {
+                changes.push(MarkerChange { id: marker.id, old_offset: marker.offset, new_offset });
+                marker.offset = new_offset;
+            }
+        }
+        self.markers.sort_by_key(Marker::key);
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bias_decides_insertions_at_the_marker() {
+        let mut markers = Markers::default();
+        let left = markers.add(5, Bias::Left);
+        let right = markers.add(5, Bias::Right);
+        let after = markers.add(9, Bias::Left);
+
+        let changes = markers.apply_edit(5, 0, 3);
+        assert_eq!(markers.offset(left), Some(5));
+        assert_eq!(markers.offset(right), Some(8));
+        assert_eq!(markers.offset(after), Some(12));
+        assert_eq!(changes.len(), 2);
+        assert_eq!(markers.in_range(6..13).map(|(id, _)| id).collect::<Vec<_>>(), vec![right, after]);
+    }
+
+    #[test]
+    fn removed_text_collapses_markers_in_order() {
+        let mut markers = Markers::default();
+        let inside = markers.add(4, Bias::Right);
+        let end = markers.add(6, Bias::Left);
+        let before = markers.add(1, Bias::Right);
+
+        // Replace 2..6 with two chars
+        markers.apply_edit(2, 4, 2);
+        assert_eq!(markers.offset(before), Some(1));
+        assert_eq!(markers.offset(inside), Some(4));
+        assert_eq!(markers.offset(end), Some(4));
+        // The left-leaning marker sorts first once they share an offset
+        assert_eq!(markers.in_range(0..10).map(|(id, _)| id).collect::<Vec<_>>(), vec![before, end, inside]);
+
+        assert!(markers.remove(inside));
+        assert_eq!(markers.offset(inside), None);
+    }
+}
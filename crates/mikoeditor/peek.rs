@@ -0,0 +1,16 @@
+use crate::editor::Editor;
+use crate::markers::MarkerId;
+
+/// Editor embedded below a line of its host, showing part of another file
+///
+/// Its tab bar names the file, and closing that tab closes the view. The embedded editor
+/// has its own cursor, scrollbar and highlighting; read-only views ignore edits.
+pub(crate) struct PeekView {
+    /// Host tab the view opened in
+    pub host_tab: usize,
+    /// Marker in the host tab's buffer that keeps the view under its line through edits
+    pub anchor: MarkerId,
+    pub editor: Editor,
+    /// Keys and edits go to the view rather than the host, after a click in it
+    pub focused: bool,
+}
@@ -0,0 +1,142 @@
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use mikocore::Vfs;
+use skia_safe::{Data, Image};
+
+use crate::hover::{parse_blocks, Block, HoverLayout};
+
+/// Extensions of pictures skia can decode
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "webp", "bmp", "ico"];
+const MARKDOWN_EXTENSIONS: &[&str] = &["md", "markdown", "mdown", "mkd"];
+
+/// What a preview shows of its file
+pub(crate) enum PreviewContent {
+    Image(Image),
+    Markdown(Vec<Block>),
+}
+
+/// Rendered view of an image or Markdown file, shown in a tab instead of its text
+pub struct Preview {
+    path: PathBuf,
+    vfs: Arc<dyn Vfs>,
+    /// Hash of the bytes last read, so a touched but unchanged file isn't redrawn
+    digest: u64,
+    pub(crate) content: PreviewContent,
+    /// Pixels the Markdown is scrolled down
+    pub(crate) scroll: f32,
+    /// Markdown laid out for the width it was last drawn at
+    pub(crate) layout: RefCell<Option<(f32, HoverLayout)>>,
+}
+
+impl Preview {
+    /// Read and decode a file; fails for files that aren't images or Markdown
+    pub fn open(path: PathBuf, vfs: Arc<dyn Vfs>) -> io::Result<Self> {
+        let bytes = vfs.read(&path)?;
+        let content = Self::decode(&path, &bytes)?;
+        Ok(Self {
+            path,
+            vfs,
+            digest: digest(&bytes),
+            content,
+            scroll: 0.0,
+            layout: RefCell::new(None),
+        })
+    }
+
+    /// Whether a file is an image or Markdown document that can be previewed
+    pub fn supports(path: &Path) -> bool {
+        Self::is_image(path) || has_extension(path, MARKDOWN_EXTENSIONS)
+    }
+
+    /// Whether a file is a picture, which opens as a preview rather than as text
+    pub fn is_image(path: &Path) -> bool {
+        has_extension(path, IMAGE_EXTENSIONS)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn is_markdown(&self) -> bool {
+        matches!(self.content, PreviewContent::Markdown(_))
+    }
+
+    /// Read the file again; returns false when its bytes didn't change
+    ///
+    /// Markdown keeps its scroll position, so an edited document stays where it was being read.
+    pub fn reload(&mut self) -> io::Result<bool> {
+        let bytes = self.vfs.read(&self.path)?;
+        let digest = digest(&bytes);
+        if digest == self.digest {
+            return Ok(false);
+        }
+        self.content = Self::decode(&self.path, &bytes)?;
+        self.digest = digest;
+        self.layout.replace(None);
+        Ok(true)
+    }
+
+    fn decode(path: &Path, bytes: &[u8]) -> io::Result<PreviewContent> {
+        if Self::is_image(path) {
+            return Image::from_encoded(Data::new_copy(bytes))
+                .map(PreviewContent::Image)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("{} is not an image skia can decode", path.display())));
+        }
+        if has_extension(path, MARKDOWN_EXTENSIONS) {
+            return Ok(PreviewContent::Markdown(parse_blocks(&String::from_utf8_lossy(bytes))));
+        }
+        Err(io::Error::new(io::ErrorKind::Unsupported, format!("{} has no preview", path.display())))
+    }
+}
+
+fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| extensions.iter().any(|known| known.eq_ignore_ascii_case(extension)))
+}
+
+fn digest(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mikocore::LocalFs;
+
+    #[test]
+    fn markdown_reloads_only_when_its_text_changes() {
+        let dir = std::env::temp_dir().join(format!("rabital-preview-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("README.md");
+        std::fs::write(&path, "# Title\n\nFirst paragraph.\n").unwrap();
+
+        let mut preview = Preview::open(path.clone(), Arc::new(LocalFs)).unwrap();
+        assert!(preview.is_markdown());
+        preview.scroll = 40.0;
+        assert!(!preview.reload().unwrap(), "rewriting the same text is not a change");
+
+        std::fs::write(&path, "# Title\n\nSecond paragraph.\n\n- item\n").unwrap();
+        assert!(preview.reload().unwrap());
+        let PreviewContent::Markdown(ref blocks) = preview.content else {
+            panic!("a Markdown file stays a Markdown preview");
+        };
+        assert_eq!(blocks[1], Block::Paragraph("Second paragraph.".to_string()));
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(preview.scroll, 40.0);
+
+        assert!(Preview::supports(Path::new("logo.PNG")) && Preview::is_image(Path::new("photo.jpeg")));
+        assert!(Preview::supports(Path::new("notes.markdown")) && !Preview::is_image(Path::new("notes.md")));
+        assert!(!Preview::supports(Path::new("main.rs")));
+        std::fs::write(dir.join("main.rs"), "fn main() {}\n").unwrap();
+        assert!(Preview::open(dir.join("main.rs"), Arc::new(LocalFs)).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
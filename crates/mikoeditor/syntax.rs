@@ -1,8 +1,11 @@
+use serde::{Deserialize, Serialize};
 use tree_sitter::{Parser, Tree};
 
+use crate::tokenizer::TokenRules;
+
 pub use tree_sitter::Language;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TokenType {
     Keyword,
     Function,
@@ -19,10 +22,72 @@ pub enum TokenType {
     Text,
 }
 
+/// A function or type definition found in the syntax tree
+/// Markdown headings are symbols too, with the `Keyword` kind they are highlighted with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentSymbol {
+    pub name: String,
+    pub kind: TokenType,
+    pub line: usize,
+    pub column: usize,
+    pub depth: usize,
+    /// Last line of the definition's body, or of the heading's section
+    pub end_line: usize,
+}
+
+impl DocumentSymbol {
+    /// Index of the innermost symbol whose lines contain `line`
+    pub fn enclosing(symbols: &[DocumentSymbol], line: usize) -> Option<usize> {
+        symbols
+            .iter()
+            .enumerate()
+            .filter(|(_, symbol)| symbol.line <= line && line <= symbol.end_line)
+            .max_by_key(|(_, symbol)| symbol.depth)
+            .map(|(index, _)| index)
+    }
+}
+
+/// Lines of a definition or block that spans more than one line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scope {
+    /// Line the scope opens on, its header
+    pub line: usize,
+    pub end_line: usize,
+}
+
+impl Scope {
+    /// Headers to pin over a view scrolled `scroll` pixels down, with each row's top in the view
+    ///
+    /// `scopes` are in document order, outer before inner, as `get_scopes` returns them. A scope is
+    /// pinned once its header scrolls under the rows above it, for as long as its body shows below
+    /// them; over its last lines the row slides up, pushed out by the lines after it.
+    pub fn sticky(scopes: &[Scope], scroll: f32, line_height: f32, max_rows: usize) -> Vec<(Scope, f32)> {
+        let mut rows: Vec<(Scope, f32)> = Vec::new();
+        for scope in scopes {
+            if rows.len() == max_rows {
+                break;
+            }
+            let slot = rows.last().map_or(0.0, |(_, top)| top + line_height);
+            let header_top = scope.line as f32 * line_height - scroll;
+            if header_top >= slot {
+                // This header and all later ones are still in view
+                break;
+            }
+            let end_bottom = (scope.end_line + 1) as f32 * line_height - scroll;
+            if end_bottom > slot {
+                rows.push((*scope, slot.min(end_bottom - line_height)));
+            }
+        }
+        rows
+    }
+}
+
 pub struct SyntaxHighlighter {
     parser: Parser,
     tree: Option<Tree>,
     language: Option<Language>,
+    /// Used instead of a parse tree for languages without a grammar
+    rules: Option<TokenRules>,
 }
 
 impl SyntaxHighlighter {
@@ -31,10 +96,18 @@ impl SyntaxHighlighter {
             parser: Parser::new(),
             tree: None,
             language: None,
+            rules: None,
         }
     }
     
     pub fn set_language(&mut self, lang_name: &str) -> Result<(), String> {
+        if let Some(rules) = TokenRules::for_language(lang_name) {
+            self.rules = Some(rules);
+            self.language = None;
+            self.tree = None;
+            return Ok(());
+        }
+        
         let language = match lang_name {
             "rust" => tree_sitter_rust::language(),
             "javascript" => tree_sitter_javascript::language(),
@@ -42,6 +115,11 @@ impl SyntaxHighlighter {
             "tsx" => tree_sitter_typescript::language_tsx(),
             "python" => tree_sitter_python::language(),
             "json" => tree_sitter_json::language(),
+            "toml" => tree_sitter_toml::language(),
+            "c" => tree_sitter_c::language(),
+            "cpp" => tree_sitter_cpp::language(),
+            "html" => tree_sitter_html::language(),
+            "css" => tree_sitter_css::language(),
             _ => return Err(format!("Unsupported language: {}", lang_name)),
         };
         
@@ -49,6 +127,7 @@ impl SyntaxHighlighter {
             .set_language(language)
             .map_err(|e| format!("Failed to set language: {:?}", e))?;
         self.language = Some(language);
+        self.rules = None;
         Ok(())
     }
     
@@ -57,19 +136,141 @@ impl SyntaxHighlighter {
     }
     
     pub fn get_highlights(&self, source_code: &str) -> Vec<(usize, usize, TokenType)> {
+        if let Some(rules) = self.rules {
+            return rules.tokenize(source_code);
+        }
+        
         let mut highlights = Vec::new();
         
         if let Some(ref tree) = self.tree {
             let root_node = tree.root_node();
-            self.traverse_node(root_node, source_code, &mut highlights);
+            self.traverse_node(root_node, None, source_code, &mut highlights);
         }
         
         highlights
     }
     
+    /// Collect function and type definitions, in document order
+    pub fn get_symbols(&self, source_code: &str) -> Vec<DocumentSymbol> {
+        if let Some(rules) = self.rules {
+            return rules.symbols(source_code);
+        }
+        
+        let mut symbols = Vec::new();
+        
+        if let Some(ref tree) = self.tree {
+            self.collect_symbols(tree.root_node(), source_code, 0, &mut symbols);
+        }
+        
+        symbols
+    }
+    
+    fn collect_symbols(
+        &self,
+        node: tree_sitter::Node,
+        source_code: &str,
+        depth: usize,
+        symbols: &mut Vec<DocumentSymbol>,
+    ) {
+        let mut child_depth = depth;
+        
+        if let Some(kind) = Self::classify_definition(node.kind()) {
+            if let Some(name_node) = node.child_by_field_name("name") {
+                if let Ok(name) = name_node.utf8_text(source_code.as_bytes()) {
+                    let position = name_node.start_position();
+                    let line_start = name_node.start_byte() - position.column;
+                    let column = source_code[line_start..name_node.start_byte()].chars().count();
+                    
+                    symbols.push(DocumentSymbol {
+                        name: name.to_string(),
+                        kind,
+                        line: position.row,
+                        column,
+                        depth,
+                        end_line: node.end_position().row,
+                    });
+                    child_depth += 1;
+                }
+            }
+        }
+        
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.collect_symbols(child, source_code, child_depth, symbols);
+        }
+    }
+    
+    /// Collect multi-line definitions and blocks, in document order
+    ///
+    /// Of scopes opening on the same line only the outermost is kept.
+    pub fn get_scopes(&self, source_code: &str) -> Vec<Scope> {
+        let mut scopes = Vec::new();
+        
+        if let Some(rules) = self.rules {
+            scopes.extend(rules.symbols(source_code).iter().map(|symbol| Scope {
+                line: symbol.line,
+                end_line: symbol.end_line,
+            }));
+        } else if let Some(ref tree) = self.tree {
+            Self::collect_scopes(tree.root_node(), &mut scopes);
+        }
+        
+        scopes.retain(|scope| scope.end_line > scope.line);
+        scopes.dedup_by_key(|scope| scope.line);
+        scopes
+    }
+    
+    fn collect_scopes(node: tree_sitter::Node, scopes: &mut Vec<Scope>) {
+        if Self::classify_definition(node.kind()).is_some() || Self::is_block(node.kind()) {
+            scopes.push(Scope {
+                line: node.start_position().row,
+                end_line: node.end_position().row,
+            });
+        }
+        
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::collect_scopes(child, scopes);
+        }
+    }
+    
+    /// Nodes besides definitions whose first line heads what follows
+    fn is_block(kind: &str) -> bool {
+        matches!(
+            kind,
+            // Rust
+            "impl_item" | "if_expression" | "match_expression" | "for_expression" |
+            "while_expression" | "loop_expression" | "closure_expression" |
+            // JavaScript/TypeScript, Python, C/C++
+            "if_statement" | "for_statement" | "for_in_statement" | "while_statement" |
+            "do_statement" | "switch_statement" | "try_statement" | "with_statement" |
+            "arrow_function" | "function_expression" | "function" |
+            "struct_specifier" | "class_specifier" | "namespace_definition" |
+            // JSON, TOML, CSS, HTML
+            "pair" | "table" | "rule_set" | "media_statement" | "element"
+        )
+    }
+    
+    fn classify_definition(kind: &str) -> Option<TokenType> {
+        match kind {
+            // Rust, JavaScript/TypeScript, Python
+            "function_item" | "function_signature_item" | "function_declaration" |
+            "generator_function_declaration" | "method_definition" | "function_definition" => {
+                Some(TokenType::Function)
+            }
+            
+            "struct_item" | "enum_item" | "trait_item" | "type_item" | "union_item" | "mod_item" |
+            "class_declaration" | "interface_declaration" | "type_alias_declaration" |
+            "enum_declaration" | "class_definition" => Some(TokenType::Type),
+            
+            _ => None,
+        }
+    }
+    
     fn traverse_node(
         &self,
         node: tree_sitter::Node,
+        context: Option<(&str, Option<&str>)>,
         source_code: &str,
         highlights: &mut Vec<(usize, usize, TokenType)>,
     ) {
@@ -77,19 +278,63 @@ impl SyntaxHighlighter {
         let start = node.start_byte();
         let end = node.end_byte();
         
-        let token_type = self.classify_node(kind);
+        let token_type = context
+            .and_then(|(parent_kind, field)| Self::classify_in_context(kind, parent_kind, field))
+            .unwrap_or_else(|| self.classify_node(kind));
         
-        if token_type != TokenType::Text && !node.is_named() {
-            highlights.push((start, end, token_type));
+        if token_type != TokenType::Text {
+            // Literals and comments are colored whole, quotes and escapes included
+            if Self::is_atomic(kind, token_type) {
+                highlights.push((start, end, token_type));
+                return;
+            }
+            if node.child_count() == 0 {
+                highlights.push((start, end, token_type));
+            }
         }
         
-        // Traverse children
+        // Traverse children, passing along which field of this node each one fills
         let mut cursor = node.walk();
-        for child in node.children(&mut cursor) {
-            self.traverse_node(child, source_code, highlights);
+        if cursor.goto_first_child() {
+            loop {
+                let field = cursor.field_name();
+                self.traverse_node(cursor.node(), Some((kind, field)), source_code, highlights);
+                if !cursor.goto_next_sibling() {
+                    break;
+                }
+            }
         }
     }
     
+    fn is_atomic(kind: &str, token_type: TokenType) -> bool {
+        matches!(
+            token_type,
+            TokenType::String | TokenType::Comment | TokenType::Number | TokenType::Constant
+        ) || matches!(kind, "string" | "predefined_type" | "type")
+    }
+    
+    /// Names whose color depends on where they appear, like a called identifier or a JSON key
+    fn classify_in_context(kind: &str, parent_kind: &str, field: Option<&str>) -> Option<TokenType> {
+        let token_type = match (parent_kind, kind, field) {
+            // Definitions and calls
+            ("function_item" | "function_signature_item" | "function_declaration" |
+             "generator_function_declaration" | "function_definition" | "method_definition",
+             "identifier" | "property_identifier", Some("name")) => TokenType::Function,
+            ("function_declarator", "identifier" | "field_identifier", Some("declarator")) => TokenType::Function,
+            ("call_expression" | "call", "identifier", Some("function")) => TokenType::Function,
+            ("macro_invocation", "identifier", Some("macro")) => TokenType::Function,
+            
+            // JSON object keys
+            ("pair", "string", Some("key")) => TokenType::Property,
+            
+            // TOML table headers
+            ("table" | "table_array_element", "bare_key" | "quoted_key", _) => TokenType::Type,
+            
+            _ => return None,
+        };
+        Some(token_type)
+    }
+    
     fn classify_node(&self, kind: &str) -> TokenType {
         match kind {
             // Keywords - Rust
@@ -110,7 +355,14 @@ impl SyntaxHighlighter {
             
             // Keywords - C/C++
             "sizeof" | "typedef" | "union" | "volatile" | "register" | "goto" |
-            "switch" | "case" | "default" |
+            "switch" | "case" | "default" | "signed" | "unsigned" | "long" | "short" |
+            "inline" | "restrict" | "auto" | "template" | "typename" | "virtual" | "override" |
+            "final" | "using" | "operator" | "friend" | "explicit" | "constexpr" | "noexcept" |
+            "public" | "private" | "protected" | "#include" | "#define" | "#if" | "#ifdef" |
+            "#ifndef" | "#elif" | "#else" | "#endif" | "preproc_directive" |
+            
+            // Keywords - HTML/CSS
+            "tag_name" | "doctype" | "important" |
             
             // Keywords - Java
             "package" | "throws" | "throw" | "catch" | "synchronized" | "native" |
@@ -126,7 +378,8 @@ impl SyntaxHighlighter {
             
             // Types
             "type_identifier" | "primitive_type" | "type" | "type_annotation" |
-            "predefined_type" | "class_name" | "interface_name" => TokenType::Type,
+            "predefined_type" | "class_name" | "interface_name" | "namespace_identifier" |
+            "id_name" | "keyframes_name" => TokenType::Type,
             
             // Functions
             "function_item" | "function_declaration" | "function_definition" |
@@ -135,13 +388,16 @@ impl SyntaxHighlighter {
             
             // Strings
             "string_literal" | "string" | "raw_string_literal" | "char_literal" |
-            "string_content" | "template_string" | "template_literal" => {
+            "string_content" | "template_string" | "template_literal" | "regex" |
+            "system_lib_string" | "attribute_value" | "quoted_attribute_value" | "string_value" => {
                 TokenType::String
             }
             
             // Numbers
             "integer_literal" | "float_literal" | "number" | "numeric_literal" |
-            "decimal_integer_literal" | "hex_integer_literal" | "binary_integer_literal" => {
+            "decimal_integer_literal" | "hex_integer_literal" | "binary_integer_literal" |
+            "number_literal" | "integer" | "float" | "integer_value" | "float_value" |
+            "offset_date_time" | "local_date_time" | "local_date" | "local_time" => {
                 TokenType::Number
             }
             
@@ -159,21 +415,27 @@ impl SyntaxHighlighter {
             
             // Punctuation
             ";" | "," | "." | ":" | "{" | "}" | "[" | "]" | "(" | ")" |
-            "punctuation" | "delimiter" => {
+            "[[" | "]]" | "<!" | "</" | "/>" | "#" | "punctuation" | "delimiter" => {
                 TokenType::Punctuation
             }
             
             // Variables and identifiers
             "identifier" | "variable_name" => TokenType::Variable,
-            "field_identifier" | "property_identifier" | "member_expression" => TokenType::Property,
+            "field_identifier" | "property_identifier" | "shorthand_property_identifier" |
+            "member_expression" | "bare_key" | "quoted_key" | "attribute_name" | "property_name" |
+            "feature_name" => TokenType::Property,
             "parameter" | "parameter_declaration" => TokenType::Parameter,
             
             // Constants
             "boolean_literal" | "true" | "false" | "null" | "None" | "True" | "False" |
-            "nil" | "undefined" | "NULL" | "constant" | "const_identifier" => {
+            "nil" | "undefined" | "NULL" | "constant" | "const_identifier" | "none" | "nullptr" |
+            "boolean" | "color_value" | "plain_value" | "keyword_query" => {
                 TokenType::Constant
             }
             
+            // CSS at-rules
+            at_rule if at_rule.len() > 1 && at_rule.starts_with('@') => TokenType::Keyword,
+            
             _ => TokenType::Text,
         }
     }
@@ -184,3 +446,161 @@ impl Default for SyntaxHighlighter {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Highlighted slices of `source`, in order
+    fn highlight<'a>(language: &str, source: &'a str) -> Vec<(&'a str, TokenType)> {
+        let mut highlighter = SyntaxHighlighter::new();
+        highlighter.set_language(language).unwrap();
+        highlighter.parse(source);
+
+        let highlights = highlighter.get_highlights(source);
+        for pair in highlights.windows(2) {
+            assert!(pair[0].1 <= pair[1].0, "overlapping highlights in {}: {:?}", language, pair);
+        }
+        highlights.into_iter().map(|(start, end, token_type)| (&source[start..end], token_type)).collect()
+    }
+
+    fn assert_token(tokens: &[(&str, TokenType)], text: &str, expected: TokenType) {
+        assert!(
+            tokens.contains(&(text, expected)),
+            "expected {:?} as {:?} in {:?}",
+            text,
+            expected,
+            tokens
+        );
+    }
+
+    #[test]
+    fn test_typescript_and_javascript() {
+        let source = "import { x } from './a';\nexport const n: number = 1.5; // note\nfunction f(a) { return `t${a}`; }\n";
+        for language in ["typescript", "javascript"] {
+            let source = if language == "javascript" { &source.replace(": number", "") } else { source };
+            let tokens = highlight(language, source);
+            assert_token(&tokens, "import", TokenType::Keyword);
+            assert_token(&tokens, "'./a'", TokenType::String);
+            assert_token(&tokens, "1.5", TokenType::Number);
+            assert_token(&tokens, "// note", TokenType::Comment);
+            assert_token(&tokens, "f", TokenType::Function);
+            assert_token(&tokens, "`t${a}`", TokenType::String);
+        }
+        assert_token(&highlight("typescript", source), "number", TokenType::Type);
+    }
+
+    #[test]
+    fn test_python() {
+        let tokens = highlight("python", "def f(a=1):\n    \"\"\"doc\"\"\"\n    return None  # c\n");
+        assert_token(&tokens, "def", TokenType::Keyword);
+        assert_token(&tokens, "f", TokenType::Function);
+        assert_token(&tokens, "1", TokenType::Number);
+        assert_token(&tokens, "\"\"\"doc\"\"\"", TokenType::String);
+        assert_token(&tokens, "None", TokenType::Constant);
+        assert_token(&tokens, "# c", TokenType::Comment);
+    }
+
+    #[test]
+    fn test_json() {
+        let tokens = highlight("json", "{\"a\": [1, true, null], \"b\": \"s\"}");
+        assert_token(&tokens, "\"a\"", TokenType::Property);
+        assert_token(&tokens, "\"s\"", TokenType::String);
+        assert_token(&tokens, "1", TokenType::Number);
+        assert_token(&tokens, "true", TokenType::Constant);
+        assert_token(&tokens, "null", TokenType::Constant);
+    }
+
+    #[test]
+    fn test_toml() {
+        let tokens = highlight("toml", "# c\n[package]\nname = \"x\"\nversion = 1.5\nok = true\n");
+        assert_token(&tokens, "# c", TokenType::Comment);
+        assert_token(&tokens, "package", TokenType::Type);
+        assert_token(&tokens, "name", TokenType::Property);
+        assert_token(&tokens, "\"x\"", TokenType::String);
+        assert_token(&tokens, "1.5", TokenType::Number);
+        assert_token(&tokens, "true", TokenType::Constant);
+    }
+
+    #[test]
+    fn test_html() {
+        let tokens = highlight("html", "<!DOCTYPE html>\n<!-- c -->\n<div class=\"a\" id=x>Hi</div>\n");
+        assert_token(&tokens, "DOCTYPE", TokenType::Keyword);
+        assert_token(&tokens, "<!-- c -->", TokenType::Comment);
+        assert_token(&tokens, "div", TokenType::Keyword);
+        assert_token(&tokens, "class", TokenType::Property);
+        assert_token(&tokens, "\"a\"", TokenType::String);
+        assert_token(&tokens, "x", TokenType::String);
+        assert!(!tokens.iter().any(|(text, _)| *text == "Hi"));
+    }
+
+    #[test]
+    fn test_css() {
+        let tokens = highlight("css", "/* c */\n@media screen { .a > #b { color: #fff; margin: 0 10px; font: \"X\", sans-serif; } }\n");
+        assert_token(&tokens, "/* c */", TokenType::Comment);
+        assert_token(&tokens, "@media", TokenType::Keyword);
+        assert_token(&tokens, "a", TokenType::Type);
+        assert_token(&tokens, "b", TokenType::Type);
+        assert_token(&tokens, "color", TokenType::Property);
+        assert_token(&tokens, "#fff", TokenType::Constant);
+        assert_token(&tokens, "10px", TokenType::Number);
+        assert_token(&tokens, "\"X\"", TokenType::String);
+        assert_token(&tokens, "sans-serif", TokenType::Constant);
+    }
+
+    #[test]
+    fn test_c_and_cpp() {
+        let source = "#include <stdio.h>\nstatic unsigned int count = 0x1F; // c\nint main(void) { printf(\"%d\\n\", 'a'); return 0; }\n";
+        for language in ["c", "cpp"] {
+            let tokens = highlight(language, source);
+            assert_token(&tokens, "#include", TokenType::Keyword);
+            assert_token(&tokens, "<stdio.h>", TokenType::String);
+            assert_token(&tokens, "unsigned", TokenType::Keyword);
+            assert_token(&tokens, "int", TokenType::Type);
+            assert_token(&tokens, "0x1F", TokenType::Number);
+            assert_token(&tokens, "// c", TokenType::Comment);
+            assert_token(&tokens, "main", TokenType::Function);
+            assert_token(&tokens, "printf", TokenType::Function);
+            assert_token(&tokens, "\"%d\\n\"", TokenType::String);
+            assert_token(&tokens, "'a'", TokenType::String);
+        }
+
+        let tokens = highlight("cpp", "namespace app { template <typename T> class Box { public: T* get() { return nullptr; } }; }\n");
+        assert_token(&tokens, "namespace", TokenType::Keyword);
+        assert_token(&tokens, "app", TokenType::Type);
+        assert_token(&tokens, "template", TokenType::Keyword);
+        assert_token(&tokens, "Box", TokenType::Type);
+        assert_token(&tokens, "public", TokenType::Keyword);
+        assert_token(&tokens, "nullptr", TokenType::Constant);
+    }
+
+    #[test]
+    fn test_sticky_scopes() {
+        let source = "mod outer {\n    impl Thing {\n        fn run() {\n            let a = 1;\n            let b = 2;\n        }\n    }\n}\n";
+        let mut highlighter = SyntaxHighlighter::new();
+        highlighter.set_language("rust").unwrap();
+        highlighter.parse(source);
+        let scopes = highlighter.get_scopes(source);
+        let lines: Vec<(usize, usize)> = scopes.iter().map(|scope| (scope.line, scope.end_line)).collect();
+        assert_eq!(lines, vec![(0, 7), (1, 6), (2, 5)]);
+        
+        let tops = |scroll: f32, max_rows: usize| -> Vec<(usize, f32)> {
+            Scope::sticky(&scopes, scroll, 10.0, max_rows).into_iter().map(|(scope, top)| (scope.line, top)).collect()
+        };
+        assert_eq!(tops(0.0, 5), vec![]);
+        // Every header has scrolled under the rows above it
+        assert_eq!(tops(25.0, 5), vec![(0, 0.0), (1, 10.0), (2, 20.0)]);
+        assert_eq!(tops(25.0, 2), vec![(0, 0.0), (1, 10.0)]);
+        // The function's body is gone and the impl's closing line pushes its row up
+        assert_eq!(tops(52.0, 5), vec![(0, 0.0), (1, 8.0)]);
+    }
+
+    #[test]
+    fn test_rule_languages_have_no_grammar() {
+        let mut highlighter = SyntaxHighlighter::new();
+        assert!(highlighter.set_language("yaml").is_ok());
+        highlighter.parse("a: 1\n");
+        assert_eq!(highlighter.get_highlights("a: 1\n").len(), 3);
+        assert!(highlighter.set_language("cobol").is_err());
+    }
+}
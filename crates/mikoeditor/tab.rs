@@ -1,6 +1,54 @@
+use crate::annotations::Annotations;
+use crate::bookmarks::{bookmark_decoration, BOOKMARKS_KEY};
 use crate::buffer::TextBuffer;
+use crate::conflicts::{find_conflicts, Conflict, ConflictLens, Conflicts, Resolution, CONFLICTS_KEY, CURRENT_COLOR, INCOMING_COLOR};
+use crate::decorations::{Decoration, Decorations};
+use crate::encoding::{Encoding, LineEnding};
+use crate::hex::HexEditor;
+use crate::indent::IndentStyle;
+use crate::langconfig::{self, LanguageConfig};
+use crate::language::language_display_name;
+use crate::markers::Bias;
+use crate::preview::Preview;
+use mikocore::{LocalFs, Vfs};
+use mikoui::{current_theme, with_alpha};
 use crate::syntax::SyntaxHighlighter;
-use std::path::PathBuf;
+use crate::todos::{find_todos, TodoComment, Todos, TODOS_KEY, TODO_COLOR};
+use crate::zones::{ViewZone, ViewZones, ZoneWidget};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// How many closed tabs Reopen Closed Tab can bring back
+const CLOSED_TAB_HISTORY: usize = 20;
+/// Lines bracket matching looks through, either way from the cursor, for the partner of a bracket
+const BRACKET_SCAN_LINES: usize = 5000;
+
+/// A bracket and its partner, as (line, column) positions
+pub type BracketPair = ((usize, usize), (usize, usize));
+
+/// Where a tab was scrolled to, and where its cursor and selection were
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TabView {
+    pub scroll_offset: f32,
+    pub cursor_line: usize,
+    pub cursor_column: usize,
+    pub selection_start: Option<(usize, usize)>,
+    pub column_anchor: Option<(usize, usize)>,
+}
+
+/// A closed file tab, with what's needed to reopen it as it was
+#[derive(Debug, Clone)]
+struct ClosedTab {
+    path: PathBuf,
+    /// Position in the tab bar when it was closed
+    index: usize,
+    pinned: bool,
+    hex: bool,
+    encoding: Encoding,
+    language: Option<String>,
+    view: TabView,
+}
 
 /// Represents a single editor tab
 pub struct EditorTab {
@@ -13,6 +61,16 @@ pub struct EditorTab {
     pub title: String,
     pub selection_start: Option<(usize, usize)>, // (line, column)
     pub selection_end: Option<(usize, usize)>,   // (line, column)
+    pub indent: IndentStyle,
+    pub column_anchor: Option<(usize, usize)>, // Box selection anchor (line, column); the cursor is the other corner
+    pub hex: Option<HexEditor>, // Set while the file is open in the hex editor
+    pub preview: Option<Preview>, // Set for a rendered image or Markdown file; the buffer stays empty
+    pub annotations: Annotations, // Search matches, diagnostics and git changes for the gutter and overview ruler
+    pub pinned: bool, // Kept at the front of the tab bar as an icon, and left open by bulk closes
+    pub read_only: bool, // Opened from where it can't be saved, e.g. an archive; edits are ignored
+    pub(crate) zones: ViewZones, // Widgets between the lines, anchored by buffer markers
+    pub(crate) conflicts: Conflicts, // Merge conflict blocks in the text, with their accept buttons
+    pub(crate) todos: Todos, // TODO, FIXME and similar comments, with their keywords highlighted
 }
 
 impl EditorTab {
@@ -27,11 +85,36 @@ impl EditorTab {
             title: "Untitled".to_string(),
             selection_start: None,
             selection_end: None,
+            indent: IndentStyle::default(),
+            column_anchor: None,
+            hex: None,
+            preview: None,
+            annotations: Annotations::default(),
+            pinned: false,
+            read_only: false,
+            zones: ViewZones::default(),
+            conflicts: Conflicts::default(),
+            todos: Todos::default(),
         }
     }
     
     pub fn from_file(id: usize, path: PathBuf) -> std::io::Result<Self> {
-        let buffer = TextBuffer::from_file(path.clone())?;
+        Self::from_file_with_defaults(id, path, Arc::new(LocalFs), Encoding::default(), LineEnding::default())
+    }
+    
+    pub fn from_file_with_defaults(
+        id: usize,
+        path: PathBuf,
+        vfs: Arc<dyn Vfs>,
+        default_encoding: Encoding,
+        default_line_ending: LineEnding,
+    ) -> std::io::Result<Self> {
+        let buffer = TextBuffer::from_file_with_defaults(
+            path.clone(),
+            vfs,
+            default_encoding,
+            default_line_ending,
+        )?;
         let mut highlighter = SyntaxHighlighter::new();
         
         // Set up syntax highlighting
@@ -56,6 +139,16 @@ impl EditorTab {
             title,
             selection_start: None,
             selection_end: None,
+            indent: IndentStyle::default(),
+            column_anchor: None,
+            hex: None,
+            preview: None,
+            annotations: Annotations::default(),
+            pinned: false,
+            read_only: false,
+            zones: ViewZones::default(),
+            conflicts: Conflicts::default(),
+            todos: Todos::default(),
         })
     }
     
@@ -74,11 +167,243 @@ impl EditorTab {
             title,
             selection_start: None,
             selection_end: None,
+            indent: IndentStyle::default(),
+            column_anchor: None,
+            hex: None,
+            preview: None,
+            annotations: Annotations::default(),
+            pinned: false,
+            read_only: false,
+            zones: ViewZones::default(),
+            conflicts: Conflicts::default(),
+            todos: Todos::default(),
+        }
+    }
+    
+    /// Keep the cursor inside the buffer after its text was replaced
+    pub fn clamp_cursor(&mut self) {
+        let line_count = self.buffer.len_lines().max(1);
+        self.cursor_line = self.cursor_line.min(line_count - 1);
+        self.cursor_column = self.cursor_column.min(self.line_length(self.cursor_line));
+    }
+    
+    /// Number of characters in a line, excluding the line break
+    pub fn line_length(&self, line_idx: usize) -> usize {
+        self.buffer
+            .line(line_idx)
+            .map(|line| line.trim_end_matches('\n').chars().count())
+            .unwrap_or(0)
+    }
+    
+    /// Decorate a char range; `key` groups decorations so their owner can remove them together
+    pub fn add_decoration(&mut self, key: &str, decoration: Decoration) {
+        self.buffer.decorations_mut().add(key, decoration);
+    }
+    
+    /// Replace all decorations under `key`, e.g. with fresh search results
+    pub fn set_decorations(&mut self, key: &str, decorations: Vec<Decoration>) {
+        self.buffer.decorations_mut().set(key, decorations);
+    }
+    
+    pub fn remove_decorations(&mut self, key: &str) -> bool {
+        self.buffer.decorations_mut().remove(key)
+    }
+    
+    pub fn decorations(&self) -> &Decorations {
+        self.buffer.decorations()
+    }
+    
+    /// Show a widget in the space it asks for under `line`; `key` groups zones like decorations'
+    ///
+    /// The zone moves with edits above it, and when its line is deleted it stays where the text was.
+    pub fn add_zone(&mut self, key: &str, line: usize, widget: Box<dyn ZoneWidget>) {
+        let anchor = self.buffer.add_marker(line, usize::MAX, Bias::Left);
+        self.zones.push(ViewZone { key: key.to_string(), anchor, widget });
+    }
+    
+    /// Drop every zone under `key`; returns true if anything was removed
+    pub fn remove_zones(&mut self, key: &str) -> bool {
+        let anchors = self.zones.remove(key);
+        for anchor in &anchors {
+            self.buffer.remove_marker(*anchor);
+        }
+        !anchors.is_empty()
+    }
+    
+    /// Lines that have zones under them, in the order the zones were added
+    pub fn zone_lines(&self) -> Vec<usize> {
+        self.zones.iter().filter_map(|zone| self.buffer.marker_position(zone.anchor)).map(|(line, _)| line).collect()
+    }
+    
+    /// To-do comments in the text, as of the last time the editor looked for them
+    pub fn todos(&self) -> &[TodoComment] {
+        &self.todos.list
+    }
+    
+    /// Look for to-do comments again if the text changed, highlighting each keyword
+    pub(crate) fn refresh_todos(&mut self, keywords: &[String]) {
+        let version = self.buffer.version();
+        if self.hex.is_some() || self.todos.version == Some(version) {
+            return;
+        }
+        self.todos.version = Some(version);
+        let text = self.buffer.to_string();
+        let found = find_todos(text.lines(), keywords);
+        if found.is_empty() && self.todos.list.is_empty() {
+            return;
+        }
+        
+        let decorations = found
+            .iter()
+            .map(|todo| {
+                let start = self.buffer.line_to_char(todo.line) + todo.column;
+                Decoration::new(start..start + todo.keyword.chars().count()).background(with_alpha(TODO_COLOR, 70))
+            })
+            .collect();
+        self.set_decorations(TODOS_KEY, decorations);
+        self.todos.list = found;
+    }
+    
+    /// Bookmarked lines and their labels, top to bottom; a label may be empty
+    pub fn bookmarks(&self) -> Vec<(usize, String)> {
+        let mut bookmarks: Vec<(usize, String)> = self
+            .decorations()
+            .get(BOOKMARKS_KEY)
+            .map(|decoration| {
+                let label = decoration.after_line.as_ref().map(|(text, _)| text.clone()).unwrap_or_default();
+                (self.buffer.char_to_line(decoration.range.start), label)
+            })
+            .collect();
+        bookmarks.sort_by_key(|(line, _)| *line);
+        // Deleting the text between two bookmarks leaves both on one line
+        bookmarks.dedup_by_key(|(line, _)| *line);
+        bookmarks
+    }
+    
+    /// Replace the bookmarks, e.g. with the ones saved for the file; lines past the end are dropped
+    pub fn set_bookmarks(&mut self, bookmarks: &[(usize, String)]) {
+        let decorations = bookmarks
+            .iter()
+            .filter(|(line, _)| *line < self.buffer.len_lines())
+            .map(|(line, label)| bookmark_decoration(self.buffer.line_to_char(*line), label))
+            .collect();
+        self.set_decorations(BOOKMARKS_KEY, decorations);
+    }
+    
+    /// Bookmark `line`, or remove its bookmark; returns true if the line is now bookmarked
+    pub fn toggle_bookmark(&mut self, line: usize) -> bool {
+        let mut bookmarks = self.bookmarks();
+        let added = match bookmarks.iter().position(|(bookmarked, _)| *bookmarked == line) {
+            Some(index) => {
+                bookmarks.remove(index);
+                false
+            }
+            None => {
+                bookmarks.push((line, String::new()));
+                true
+            }
+        };
+        self.set_bookmarks(&bookmarks);
+        added
+    }
+    
+    /// Name the bookmark on `line`, bookmarking it first if needed; an empty label clears the name
+    pub fn set_bookmark_label(&mut self, line: usize, label: &str) {
+        let mut bookmarks = self.bookmarks();
+        bookmarks.retain(|(bookmarked, _)| *bookmarked != line);
+        bookmarks.push((line, label.trim().to_string()));
+        self.set_bookmarks(&bookmarks);
+    }
+    
+    /// Merge conflict blocks in the text, as of the last time the editor looked for them
+    pub fn conflicts(&self) -> &[Conflict] {
+        &self.conflicts.list
+    }
+    
+    /// Look for merge conflicts again if the text changed, coloring each side and putting accept buttons above it
+    pub(crate) fn refresh_conflicts(&mut self) {
+        let version = self.buffer.version();
+        if self.hex.is_some() || self.conflicts.version == Some(version) {
+            return;
+        }
+        self.conflicts.version = Some(version);
+        let text = self.buffer.to_string();
+        let found = if text.contains("<<<<<<<") { find_conflicts(text.lines()) } else { Vec::new() };
+        if found.is_empty() && self.conflicts.list.is_empty() {
+            return;
+        }
+        
+        self.remove_decorations(CONFLICTS_KEY);
+        self.remove_zones(CONFLICTS_KEY);
+        let lines = |tab: &Self, range: std::ops::Range<usize>| tab.buffer.line_to_char(range.start)..tab.buffer.line_to_char(range.end);
+        let muted = current_theme().muted_foreground;
+        for (index, conflict) in found.iter().enumerate() {
+            let regions = [
+                (conflict.start..conflict.start + 1, with_alpha(CURRENT_COLOR, 90)),
+                (conflict.current(), with_alpha(CURRENT_COLOR, 40)),
+                (conflict.base.map_or(0..0, |base| base..conflict.separator), with_alpha(muted, 30)),
+                (conflict.incoming(), with_alpha(INCOMING_COLOR, 40)),
+                (conflict.end..conflict.end + 1, with_alpha(INCOMING_COLOR, 90)),
+            ];
+            for (range, color) in regions.into_iter().filter(|(range, _)| !range.is_empty()) {
+                let decoration = Decoration::new(lines(self, range)).background(color).whole_line();
+                self.add_decoration(CONFLICTS_KEY, decoration);
+            }
+            // Above the opening marker, or under it when it is the first line
+            let widget = ConflictLens::new(index, self.conflicts.clicked.clone());
+            self.add_zone(CONFLICTS_KEY, conflict.start.saturating_sub(1), Box::new(widget));
+        }
+        self.conflicts.list = found;
+    }
+    
+    /// Replace the `index`th conflict block, markers and all, with the sides `resolution` keeps
+    pub fn resolve_conflict(&mut self, index: usize, resolution: Resolution) -> bool {
+        self.refresh_conflicts();
+        let Some(conflict) = self.conflicts.list.get(index).copied() else {
+            return false;
+        };
+        let mut text: String = conflict
+            .kept(resolution)
+            .into_iter()
+            .flatten()
+            .filter_map(|line| self.buffer.line(line))
+            .collect();
+        // The block's last line had no line break when it ended the file
+        let end_line = self.buffer.line(conflict.end).unwrap_or_default();
+        if !end_line.ends_with('\n') && text.ends_with('\n') {
+            text.pop();
         }
+        let start = self.buffer.line_to_char(conflict.start);
+        let end = start.max(self.buffer.line_to_char(conflict.end) + end_line.chars().count());
+        self.buffer.remove(start, end);
+        self.buffer.insert(start, &text);
+        self.highlighter.parse(&self.buffer.to_string());
+        self.clear_column_selection();
+        self.selection_start = None;
+        self.selection_end = None;
+        self.cursor_line = conflict.start;
+        self.cursor_column = 0;
+        self.clamp_cursor();
+        true
     }
     
     pub fn is_modified(&self) -> bool {
-        self.buffer.is_modified()
+        match self.hex {
+            Some(ref hex) => hex.buffer.is_modified(),
+            None => self.buffer.is_modified(),
+        }
+    }
+    
+    pub fn is_hex(&self) -> bool {
+        self.hex.is_some()
+    }
+    
+    /// File whose changes on disk the tab follows: the one it edits, or the one it previews
+    pub fn source_path(&self) -> Option<&Path> {
+        match self.preview {
+            Some(ref preview) => Some(preview.path()),
+            None => self.buffer.file_path().map(PathBuf::as_path),
+        }
     }
     
     pub fn get_display_title(&self) -> String {
@@ -90,31 +415,57 @@ impl EditorTab {
     }
     
     pub fn get_language_display(&self) -> String {
-        self.buffer.language()
-            .map(|lang| match lang {
-                "rust" => "Rust",
-                "javascript" => "JavaScript",
-                "typescript" => "TypeScript",
-                "tsx" => "TSX",
-                "python" => "Python",
-                "json" => "JSON",
-                "html" => "HTML",
-                "css" => "CSS",
-                "markdown" => "Markdown",
-                "yaml" => "YAML",
-                "toml" => "TOML",
-                "c" => "C",
-                "cpp" => "C++",
-                "java" => "Java",
-                "go" => "Go",
-                "ruby" => "Ruby",
-                "php" => "PHP",
-                "bash" => "Shell",
-                "sql" => "SQL",
-                _ => "Text",
-            })
-            .unwrap_or("Text")
-            .to_string()
+        if self.is_hex() {
+            return "Hex".to_string();
+        }
+        if let Some(ref preview) = self.preview {
+            return if preview.is_markdown() { "Markdown" } else { "Image" }.to_string();
+        }
+        language_display_name(self.buffer.language().unwrap_or("text")).to_string()
+    }
+    
+    pub fn view(&self) -> TabView {
+        TabView {
+            scroll_offset: self.scroll_offset,
+            cursor_line: self.cursor_line,
+            cursor_column: self.cursor_column,
+            selection_start: self.selection_start,
+            column_anchor: self.column_anchor,
+        }
+    }
+    
+    /// Put back a saved view, kept inside the text in case the file shrank meanwhile
+    pub fn restore_view(&mut self, view: TabView) {
+        let last_line = self.buffer.len_lines().max(1) - 1;
+        let clamp = |(line, column): (usize, usize)| (line.min(last_line), column);
+        self.scroll_offset = view.scroll_offset;
+        self.cursor_line = view.cursor_line;
+        self.cursor_column = view.cursor_column;
+        self.clamp_cursor();
+        self.selection_start = view.selection_start.map(clamp);
+        self.column_anchor = view.column_anchor.map(clamp);
+    }
+    
+    /// What Reopen Closed Tab needs to bring this tab back; `None` for tabs without a file
+    fn closed_at(&self, index: usize) -> Option<ClosedTab> {
+        Some(ClosedTab {
+            path: self.buffer.file_path()?.clone(),
+            index,
+            pinned: self.pinned,
+            hex: self.is_hex(),
+            encoding: self.buffer.encoding(),
+            language: self.buffer.language().map(str::to_string),
+            view: self.view(),
+        })
+    }
+    
+    /// Switch the language mode and re-highlight; languages without a grammar show plain
+    pub fn set_language(&mut self, language: &str) {
+        self.buffer.set_language(language);
+        self.highlighter = SyntaxHighlighter::new();
+        if self.highlighter.set_language(language).is_ok() {
+            self.highlighter.parse(&self.buffer.to_string());
+        }
     }
 }
 
@@ -123,6 +474,13 @@ pub struct TabManager {
     tabs: Vec<EditorTab>,
     active_tab: usize,
     next_id: usize,
+    vfs: Arc<dyn Vfs>, // Where files are opened from
+    default_encoding: Encoding,
+    default_line_ending: LineEnding,
+    default_indent: IndentStyle,
+    language_indents: HashMap<String, IndentStyle>,
+    detect_indentation: bool,
+    closed: Vec<ClosedTab>, // Most recently closed file tabs last
 }
 
 impl TabManager {
@@ -131,6 +489,13 @@ impl TabManager {
             tabs: Vec::new(),
             active_tab: 0,
             next_id: 0,
+            vfs: Arc::new(LocalFs),
+            default_encoding: Encoding::default(),
+            default_line_ending: LineEnding::default(),
+            default_indent: IndentStyle::default(),
+            language_indents: HashMap::new(),
+            detect_indentation: true,
+            closed: Vec::new(),
         };
         
         // Create initial welcome tab
@@ -146,13 +511,36 @@ impl TabManager {
         let id = self.next_id;
         self.next_id += 1;
         
-        let tab = EditorTab::new(id);
+        let mut tab = EditorTab::new(id);
+        tab.buffer = TextBuffer::with_defaults(self.default_encoding, self.default_line_ending);
+        tab.indent = self.default_indent;
         self.tabs.push(tab);
         self.active_tab = self.tabs.len() - 1;
         
         id
     }
     
+    /// Show an image or Markdown file rendered, focusing its preview tab if one is open already
+    pub fn add_preview_tab(&mut self, path: PathBuf) -> std::io::Result<usize> {
+        if let Some(index) = self.tabs.iter().position(|tab| tab.preview.as_ref().is_some_and(|preview| preview.path() == path)) {
+            self.active_tab = index;
+            return Ok(self.tabs[index].id);
+        }
+        let preview = Preview::open(path, self.vfs.clone())?;
+        let id = self.next_id;
+        self.next_id += 1;
+        
+        let mut tab = EditorTab::new(id);
+        let name = preview.path().file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        tab.title = format!("Preview {}", name);
+        tab.read_only = true;
+        tab.preview = Some(preview);
+        self.tabs.push(tab);
+        self.active_tab = self.tabs.len() - 1;
+        
+        Ok(id)
+    }
+    
     pub fn add_tab_with_text(&mut self, text: &str, title: String) -> usize {
         let id = self.next_id;
         self.next_id += 1;
@@ -168,16 +556,65 @@ impl TabManager {
         let id = self.next_id;
         self.next_id += 1;
         
-        let tab = EditorTab::from_file(id, path)?;
+        let read_only = self.vfs.is_read_only(&path);
+        let mut tab = EditorTab::from_file_with_defaults(
+            id,
+            path,
+            self.vfs.clone(),
+            self.default_encoding,
+            self.default_line_ending,
+        )?;
+        tab.read_only = read_only;
+        tab.indent = self.indent_for(tab.buffer.language());
+        if self.detect_indentation {
+            tab.indent = IndentStyle::detect(&tab.buffer.to_string(), tab.indent);
+        }
         self.tabs.push(tab);
         self.active_tab = self.tabs.len() - 1;
         
         Ok(id)
     }
     
+    /// Open files from `vfs` from now on; open tabs keep the file system they came from
+    pub fn set_vfs(&mut self, vfs: Arc<dyn Vfs>) {
+        self.vfs = vfs;
+    }
+    
+    pub fn vfs(&self) -> &Arc<dyn Vfs> {
+        &self.vfs
+    }
+    
+    /// Set the encoding and line ending used for new and ASCII-only files
+    pub fn set_defaults(&mut self, encoding: Encoding, line_ending: LineEnding) {
+        self.default_encoding = encoding;
+        self.default_line_ending = line_ending;
+    }
+    
+    /// Set the indentation used for new files and per language
+    /// With `detect` enabled, opened files use the indentation found in their contents
+    pub fn set_indent_defaults(
+        &mut self,
+        default: IndentStyle,
+        language_indents: HashMap<String, IndentStyle>,
+        detect: bool,
+    ) {
+        self.default_indent = default;
+        self.language_indents = language_indents;
+        self.detect_indentation = detect;
+    }
+    
+    /// Configured indentation for a language
+    pub fn indent_for(&self, language: Option<&str>) -> IndentStyle {
+        language
+            .and_then(|lang| self.language_indents.get(lang))
+            .copied()
+            .unwrap_or(self.default_indent)
+    }
+    
     pub fn close_tab(&mut self, index: usize) -> bool {
         if index < self.tabs.len() {
-            self.tabs.remove(index);
+            let tab = self.tabs.remove(index);
+            self.remember_closed(tab.closed_at(index));
             
             // Adjust active tab
             if self.tabs.is_empty() {
@@ -197,6 +634,115 @@ impl TabManager {
         self.close_tab(self.active_tab);
     }
     
+    fn remember_closed(&mut self, tab: Option<ClosedTab>) {
+        let Some(tab) = tab else {
+            return;
+        };
+        // Reopening the file again later shouldn't bring back a second copy
+        self.closed.retain(|closed| closed.path != tab.path);
+        self.closed.push(tab);
+        if self.closed.len() > CLOSED_TAB_HISTORY {
+            self.closed.remove(0);
+        }
+    }
+    
+    /// Whether Reopen Closed Tab has anything to bring back
+    pub fn has_closed_tabs(&self) -> bool {
+        !self.closed.is_empty()
+    }
+    
+    /// Reopen the most recently closed file tab where it was, with its view, language and pin
+    ///
+    /// A file that's open again in the meantime is only activated. `None` when no tab is left to
+    /// reopen; files that can't be read anymore are dropped from the history with their error.
+    pub fn reopen_closed_tab(&mut self) -> Option<std::io::Result<usize>> {
+        let closed = self.closed.pop()?;
+        if let Some(index) = self.tabs.iter().position(|tab| tab.buffer.file_path() == Some(&closed.path)) {
+            self.active_tab = index;
+            return Some(Ok(self.tabs[index].id));
+        }
+        
+        let id = match self.add_tab_from_file(closed.path.clone()) {
+            Ok(id) => id,
+            Err(e) => return Some(Err(e)),
+        };
+        let mut tab = self.tabs.pop().expect("tab just added");
+        if tab.buffer.encoding() != closed.encoding {
+            match tab.buffer.reopen_with_encoding(closed.encoding) {
+                Ok(()) => tab.highlighter.parse(&tab.buffer.to_string()),
+                Err(e) => eprintln!("Failed to reopen {} as {}: {}", closed.path.display(), closed.encoding.label(), e),
+            }
+        }
+        if let Some(language) = closed.language.filter(|language| tab.buffer.language() != Some(language.as_str())) {
+            tab.set_language(&language);
+        }
+        tab.restore_view(closed.view);
+        if closed.hex {
+            match HexEditor::open(closed.path.clone()) {
+                Ok(hex) => tab.hex = Some(hex),
+                Err(e) => eprintln!("Failed to reopen {} in the hex editor: {}", closed.path.display(), e),
+            }
+        }
+        
+        // Back in its old place, as long as that keeps pinned tabs in front
+        tab.pinned = closed.pinned;
+        let pinned = self.pinned_count();
+        let index = if closed.pinned { closed.index.min(pinned) } else { closed.index.clamp(pinned, self.tabs.len()) };
+        self.tabs.insert(index, tab);
+        self.active_tab = index;
+        Some(Ok(id))
+    }
+    
+    /// Pin or unpin a tab; pinned tabs stay together at the front, in the order they were pinned
+    pub fn set_pinned(&mut self, index: usize, pinned: bool) {
+        if index >= self.tabs.len() || self.tabs[index].pinned == pinned {
+            return;
+        }
+        let active_id = self.tabs[self.active_tab].id;
+        let mut tab = self.tabs.remove(index);
+        tab.pinned = pinned;
+        // Pinning appends to the pinned group, unpinning puts the tab first among the rest
+        let target = self.pinned_count();
+        self.tabs.insert(target, tab);
+        self.active_tab = self.tabs.iter().position(|tab| tab.id == active_id).unwrap_or(0);
+    }
+    
+    pub fn pinned_count(&self) -> usize {
+        self.tabs.iter().filter(|tab| tab.pinned).count()
+    }
+    
+    /// Close every tab `close` picks by index, returning how many closed
+    /// The active tab stays active if it survives, otherwise its nearest remaining neighbour takes over
+    pub fn close_tabs_where(&mut self, mut close: impl FnMut(usize, &EditorTab) -> bool) -> usize {
+        let before = self.tabs.len();
+        let active_tab = self.active_tab;
+        let mut kept_before_active = 0;
+        let mut index = 0;
+        let mut removed = Vec::new();
+        self.tabs.retain(|tab| {
+            let keep = !close(index, tab);
+            if keep && index < active_tab {
+                kept_before_active += 1;
+            }
+            if !keep {
+                removed.push(tab.closed_at(index));
+            }
+            index += 1;
+            keep
+        });
+        let closed = before - self.tabs.len();
+        for tab in removed {
+            self.remember_closed(tab);
+        }
+        
+        if self.tabs.is_empty() {
+            self.add_tab();
+        } else {
+            self.active_tab = kept_before_active.min(self.tabs.len() - 1);
+        }
+        closed
+    }
+    
     pub fn set_active_tab(&mut self, index: usize) {
         if index < self.tabs.len() {
             self.active_tab = index;
@@ -239,6 +785,10 @@ impl TabManager {
         &self.tabs
     }
     
+    pub fn tabs_mut(&mut self) -> &mut [EditorTab] {
+        &mut self.tabs
+    }
+    
     pub fn active_index(&self) -> usize {
         self.active_tab
     }
@@ -258,12 +808,67 @@ impl Default for TabManager {
 impl EditorTab {
     /// Check if there's an active selection
     pub fn has_selection(&self) -> bool {
+        if let Some((_, _, start_col, end_col)) = self.column_selection() {
+            return start_col != end_col;
+        }
         self.selection_start.is_some() && 
         self.selection_start != Some((self.cursor_line, self.cursor_column))
     }
     
+    /// The identifier the cursor is in or right after, e.g. the symbol to rename
+    pub fn word_at_cursor(&self) -> Option<String> {
+        let line = self.buffer.line(self.cursor_line)?;
+        let chars: Vec<char> = line.trim_end_matches(['\n', '\r']).chars().collect();
+        let (start, end) = self.word_bounds(&chars, self.cursor_column);
+        (start < end).then(|| chars[start..end].iter().collect())
+    }
+    
+    /// Columns where the word around `column` of a line starts and ends, by the language's word chars
+    fn word_bounds(&self, chars: &[char], column: usize) -> (usize, usize) {
+        let config = self.language_config();
+        let column = column.min(chars.len());
+        let start = column - chars[..column].iter().rev().take_while(|c| config.is_word_char(**c)).count();
+        let end = column + chars[column..].iter().take_while(|c| config.is_word_char(**c)).count();
+        (start, end)
+    }
+    
+    /// Select the word at a position, e.g. double-clicked; returns false, changing nothing, off a word
+    pub fn select_word(&mut self, (line, column): (usize, usize)) -> bool {
+        let Some(text) = self.buffer.line(line) else {
+            return false;
+        };
+        let chars: Vec<char> = text.trim_end_matches(['\n', '\r']).chars().collect();
+        let (start, end) = self.word_bounds(&chars, column);
+        if start == end {
+            return false;
+        }
+        self.clear_column_selection();
+        self.selection_start = Some((line, start));
+        (self.cursor_line, self.cursor_column) = (line, end);
+        true
+    }
+    
+    /// Comment tokens, brackets and word chars of the tab's language
+    pub fn language_config(&self) -> LanguageConfig {
+        langconfig::language_config(self.buffer.language().unwrap_or("text"))
+    }
+    
     /// Get the selected text
     pub fn get_selected_text(&self) -> String {
+        if let Some((first_line, last_line, start_col, end_col)) = self.column_selection() {
+            return (first_line..=last_line)
+                .map(|line_idx| {
+                    let line = self.buffer.line(line_idx).unwrap_or_default();
+                    let len = self.line_length(line_idx);
+                    line.chars()
+                        .skip(start_col.min(len))
+                        .take(end_col.min(len) - start_col.min(len))
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+        
         if let Some((start_line, start_col)) = self.selection_start {
             let end_line = self.cursor_line;
             let end_col = self.cursor_column;
@@ -316,6 +921,11 @@ impl EditorTab {
     
     /// Delete the selected text
     pub fn delete_selection(&mut self) {
+        if self.column_anchor.is_some() {
+            self.delete_column_selection();
+            return;
+        }
+        
         if let Some((start_line, start_col)) = self.selection_start {
             let end_line = self.cursor_line;
             let end_col = self.cursor_column;
@@ -367,4 +977,646 @@ impl EditorTab {
             self.highlighter.parse(&self.buffer.to_string());
         }
     }
+    
+    /// The selection as start and end positions in document order; `None` without one or for a box selection
+    pub fn selection_range(&self) -> Option<((usize, usize), (usize, usize))> {
+        if self.column_anchor.is_some() {
+            return None;
+        }
+        let anchor = self.selection_start?;
+        let cursor = (self.cursor_line, self.cursor_column);
+        (anchor != cursor).then(|| (anchor.min(cursor), anchor.max(cursor)))
+    }
+    
+    /// Char index of a position, with the column clamped to its line
+    fn char_index(&self, (line, column): (usize, usize)) -> usize {
+        self.buffer.line_to_char(line) + column.min(self.line_length(line))
+    }
+    
+    /// Select the chars `start..end`, leaving the cursor at the end
+    fn select_chars(&mut self, start: usize, end: usize) {
+        let line = self.buffer.char_to_line(start);
+        self.selection_start = Some((line, start - self.buffer.line_to_char(line)));
+        self.cursor_line = self.buffer.char_to_line(end);
+        self.cursor_column = end - self.buffer.line_to_char(self.cursor_line);
+    }
+    
+    /// Put `text` at a position and select it, e.g. where text dragged from another tab was dropped
+    pub fn insert_selected(&mut self, at: (usize, usize), text: &str) {
+        let start = self.char_index(at);
+        self.clear_column_selection();
+        self.buffer.insert(start, text);
+        self.select_chars(start, start + text.chars().count());
+        self.highlighter.parse(&self.buffer.to_string());
+    }
+    
+    /// Put `text` at the cursor as is, e.g. pasted, leaving the cursor after it
+    ///
+    /// `\r\n` and lone `\r` line breaks become `\n`; tabs and indentation are kept.
+    pub fn insert_at_cursor(&mut self, text: &str) {
+        let text: String = text
+            .replace("\r\n", "\n")
+            .replace('\r', "\n")
+            .chars()
+            .filter(|c| !c.is_control() || matches!(c, '\n' | '\t'))
+            .collect();
+        let start = self.char_index((self.cursor_line, self.cursor_column));
+        self.buffer.insert(start, &text);
+        let end = start + text.chars().count();
+        self.cursor_line = self.buffer.char_to_line(end);
+        self.cursor_column = end - self.buffer.line_to_char(self.cursor_line);
+        self.selection_start = None;
+        self.highlighter.parse(&self.buffer.to_string());
+    }
+    
+    /// Remove the text between two positions, e.g. what was dragged into another tab
+    pub fn remove_range(&mut self, start: (usize, usize), end: (usize, usize)) {
+        let (start, end) = (self.char_index(start), self.char_index(end));
+        if start < end {
+            self.buffer.remove(start, end);
+            self.highlighter.parse(&self.buffer.to_string());
+        }
+    }
+    
+    /// Move the text between `start` and `end` to `to`, or copy it there, and select it at its new place
+    ///
+    /// Returns false, changing nothing, when a move would drop the text inside itself.
+    pub fn move_text(&mut self, (start, end): ((usize, usize), (usize, usize)), to: (usize, usize), copy: bool) -> bool {
+        let (start, end) = (self.char_index(start), self.char_index(end));
+        let mut target = self.char_index(to);
+        if !copy && (start..=end).contains(&target) {
+            return false;
+        }
+        let text: String = self.buffer.to_string().chars().skip(start).take(end - start).collect();
+        self.clear_column_selection();
+        if !copy {
+            self.buffer.remove(start, end);
+            if target > end {
+                target -= end - start;
+            }
+        }
+        self.buffer.insert(target, &text);
+        self.select_chars(target, target + (end - start));
+        self.highlighter.parse(&self.buffer.to_string());
+        true
+    }
+    
+    /// Box selection as (first_line, last_line, start_column, end_column)
+    /// Columns may lie past the end of shorter lines
+    pub fn column_selection(&self) -> Option<(usize, usize, usize, usize)> {
+        let (anchor_line, anchor_col) = self.column_anchor?;
+        Some((
+            anchor_line.min(self.cursor_line),
+            anchor_line.max(self.cursor_line),
+            anchor_col.min(self.cursor_column),
+            anchor_col.max(self.cursor_column),
+        ))
+    }
+    
+    /// Leave box selection mode, keeping the primary cursor
+    pub fn clear_column_selection(&mut self) {
+        if self.column_anchor.take().is_some() {
+            self.clamp_cursor();
+        }
+    }
+    
+    /// Remove the selected columns from every row; the box collapses to its left edge
+    fn delete_column_selection(&mut self) {
+        let Some((first_line, last_line, start_col, end_col)) = self.column_selection() else {
+            return;
+        };
+        
+        for line_idx in (first_line..=last_line).rev() {
+            let len = self.line_length(line_idx);
+            let (from, to) = (start_col.min(len), end_col.min(len));
+            if from < to {
+                let line_start = self.buffer.line_to_char(line_idx);
+                self.buffer.remove(line_start + from, line_start + to);
+            }
+        }
+        
+        self.set_column_cursors(start_col);
+        self.highlighter.parse(&self.buffer.to_string());
+    }
+    
+    /// Type `text` on every row of the box selection
+    pub fn column_insert(&mut self, text: &str) {
+        self.delete_column_selection();
+        let Some((first_line, last_line, col, _)) = self.column_selection() else {
+            return;
+        };
+        
+        for line_idx in (first_line..=last_line).rev() {
+            let line_start = self.buffer.line_to_char(line_idx);
+            self.buffer.insert(line_start + col.min(self.line_length(line_idx)), text);
+        }
+        
+        self.set_column_cursors(col + text.chars().count());
+        self.highlighter.parse(&self.buffer.to_string());
+    }
+    
+    /// Backspace on every row of the box selection
+    pub fn column_backspace(&mut self) {
+        let Some((first_line, last_line, start_col, end_col)) = self.column_selection() else {
+            return;
+        };
+        if start_col != end_col {
+            self.delete_column_selection();
+            return;
+        }
+        if start_col == 0 {
+            return;
+        }
+        
+        for line_idx in (first_line..=last_line).rev() {
+            let col = start_col.min(self.line_length(line_idx));
+            if col > 0 {
+                let line_start = self.buffer.line_to_char(line_idx);
+                self.buffer.remove(line_start + col - 1, line_start + col);
+            }
+        }
+        
+        self.set_column_cursors(start_col - 1);
+        self.highlighter.parse(&self.buffer.to_string());
+    }
+    
+    /// Collapse the box to a single column on all of its rows
+    fn set_column_cursors(&mut self, column: usize) {
+        if let Some((anchor_line, _)) = self.column_anchor {
+            self.column_anchor = Some((anchor_line, column));
+        }
+        self.cursor_column = column;
+    }
+    
+    /// Lines touched by the selection, or the cursor line
+    /// A selection ending at column 0 doesn't include that last line
+    pub fn selected_lines(&self) -> (usize, usize) {
+        if let Some((first_line, last_line, _, _)) = self.column_selection() {
+            return (first_line, last_line);
+        }
+        match self.selection_start {
+            Some((anchor_line, anchor_col)) if self.has_selection() => {
+                let ((start, _), (end, end_col)) = if (anchor_line, anchor_col) < (self.cursor_line, self.cursor_column) {
+                    ((anchor_line, anchor_col), (self.cursor_line, self.cursor_column))
+                } else {
+                    ((self.cursor_line, self.cursor_column), (anchor_line, anchor_col))
+                };
+                if end > start && end_col == 0 {
+                    (start, end - 1)
+                } else {
+                    (start, end)
+                }
+            }
+            _ => (self.cursor_line, self.cursor_line),
+        }
+    }
+    
+    /// Rewrite the leading whitespace of lines `start..=end`
+    /// Cursor and selection anchor move with the text on affected lines
+    pub fn replace_leading_whitespace(&mut self, start: usize, end: usize, rewrite: impl Fn(&str) -> String) {
+        let last_line = self.buffer.len_lines().saturating_sub(1);
+        let mut changed = false;
+        
+        for line_idx in start..=end.min(last_line) {
+            let Some(line) = self.buffer.line(line_idx) else {
+                continue;
+            };
+            let leading: String = line.chars().take_while(|c| *c == ' ' || *c == '\t').collect();
+            let replacement = rewrite(&leading);
+            if replacement == leading {
+                continue;
+            }
+            
+            let line_start = self.buffer.line_to_char(line_idx);
+            let old_len = leading.chars().count();
+            let new_len = replacement.chars().count();
+            self.buffer.remove(line_start, line_start + old_len);
+            self.buffer.insert(line_start, &replacement);
+            changed = true;
+            
+            let shift = |col: usize| if col >= old_len { col - old_len + new_len } else { col.min(new_len) };
+            if self.cursor_line == line_idx {
+                self.cursor_column = shift(self.cursor_column);
+            }
+            if let Some((anchor_line, anchor_col)) = self.selection_start {
+                if anchor_line == line_idx {
+                    self.selection_start = Some((anchor_line, shift(anchor_col)));
+                }
+            }
+        }
+        
+        if changed {
+            self.highlighter.parse(&self.buffer.to_string());
+        }
+    }
+    
+    /// Chars of a line without its line break
+    fn line_chars(&self, line_idx: usize) -> Vec<char> {
+        self.buffer.line(line_idx).unwrap_or_default().trim_end_matches(['\n', '\r']).chars().collect()
+    }
+    
+    /// Type `c` with the language's auto-close pairs: a closer steps over the same closer at the cursor,
+    /// an opener brings its closer along or wraps the selection in the pair
+    ///
+    /// Returns false, changing nothing, where neither applies and `c` is typed as is.
+    pub fn type_paired(&mut self, c: char) -> bool {
+        if self.column_anchor.is_some() {
+            return false;
+        }
+        let config = self.language_config();
+        if let Some((start, end)) = self.selection_range() {
+            let Some(&(open, close)) = config.auto_close.iter().find(|&&(open, _)| open == c) else {
+                return false;
+            };
+            let (start, end) = (self.char_index(start), self.char_index(end));
+            self.buffer.insert(end, &close.to_string());
+            self.buffer.insert(start, &open.to_string());
+            self.select_chars(start + 1, end + 1);
+            self.highlighter.parse(&self.buffer.to_string());
+            return true;
+        }
+        
+        let chars = self.line_chars(self.cursor_line);
+        let column = self.cursor_column.min(chars.len());
+        let before = column.checked_sub(1).map(|index| chars[index]);
+        let after = chars.get(column).copied();
+        if after == Some(c) && config.is_closer(c) {
+            self.cursor_column = column + 1;
+            self.selection_start = None;
+            return true;
+        }
+        let Some(close) = config.auto_close(c, before, after) else {
+            return false;
+        };
+        let at = self.char_index((self.cursor_line, column));
+        self.buffer.insert(at, &format!("{c}{close}"));
+        self.cursor_column = column + 1;
+        self.selection_start = None;
+        self.highlighter.parse(&self.buffer.to_string());
+        true
+    }
+    
+    /// Backspace between an auto-close pair, e.g. `(|)`, removes both halves
+    ///
+    /// Returns false, changing nothing, when the cursor isn't between one.
+    pub fn delete_pair(&mut self) -> bool {
+        if self.column_anchor.is_some() || self.has_selection() || self.cursor_column == 0 {
+            return false;
+        }
+        let chars = self.line_chars(self.cursor_line);
+        let column = self.cursor_column.min(chars.len());
+        let (Some(&before), Some(&after)) = (column.checked_sub(1).and_then(|index| chars.get(index)), chars.get(column)) else {
+            return false;
+        };
+        if !self.language_config().auto_close.contains(&(before, after)) {
+            return false;
+        }
+        let at = self.char_index((self.cursor_line, column));
+        self.buffer.remove(at - 1, at + 1);
+        self.cursor_column = column - 1;
+        self.selection_start = None;
+        self.highlighter.parse(&self.buffer.to_string());
+        true
+    }
+    
+    /// Comment out the selected lines with the language's line comment, or uncomment them if all of them are
+    ///
+    /// Tokens line up at the least indented line; blank lines are left alone. Languages without a line
+    /// comment get the lines wrapped in a block comment.
+    pub fn toggle_line_comment(&mut self) {
+        let config = self.language_config();
+        let (first_line, last_line) = self.selected_lines();
+        self.clear_column_selection();
+        let Some(token) = config.line_comment else {
+            let start = (first_line, self.line_chars(first_line).iter().take_while(|c| c.is_whitespace()).count());
+            let end = (last_line, self.line_length(last_line));
+            if let Some((open, close)) = config.block_comment {
+                self.toggle_block_comment_in(start, end, &open, &close);
+            }
+            return;
+        };
+        
+        let lines: Vec<(usize, Vec<char>)> = (first_line..=last_line)
+            .map(|line_idx| (line_idx, self.line_chars(line_idx)))
+            .filter(|(_, chars)| chars.iter().any(|c| !c.is_whitespace()))
+            .collect();
+        if lines.is_empty() {
+            return;
+        }
+        let token_chars: Vec<char> = token.chars().collect();
+        let indent_of = |chars: &[char]| chars.iter().take_while(|c| c.is_whitespace()).count();
+        let commented = lines.iter().all(|(_, chars)| chars[indent_of(chars)..].starts_with(&token_chars));
+        let indent = lines.iter().map(|(_, chars)| indent_of(chars)).min().unwrap_or(0);
+        
+        for (line_idx, chars) in lines {
+            let line_start = self.buffer.line_to_char(line_idx);
+            // Columns past the edit point move with the text
+            let shift: Box<dyn Fn(usize) -> usize> = if commented {
+                let at = indent_of(&chars);
+                let len = token_chars.len() + usize::from(chars.get(at + token_chars.len()) == Some(&' '));
+                self.buffer.remove(line_start + at, line_start + at + len);
+                Box::new(move |col| if col > at { col - len.min(col - at) } else { col })
+            } else {
+                let inserted = format!("{token} ");
+                self.buffer.insert(line_start + indent, &inserted);
+                let len = inserted.chars().count();
+                Box::new(move |col| if col >= indent && col > 0 { col + len } else { col })
+            };
+            if self.cursor_line == line_idx {
+                self.cursor_column = shift(self.cursor_column);
+            }
+            if let Some((anchor_line, anchor_col)) = self.selection_start.filter(|&(anchor_line, _)| anchor_line == line_idx) {
+                self.selection_start = Some((anchor_line, shift(anchor_col)));
+            }
+        }
+        self.highlighter.parse(&self.buffer.to_string());
+    }
+    
+    /// Wrap the selection in the language's block comment, or unwrap it if it is one
+    ///
+    /// Without a selection the cursor line's text is toggled; on a blank line an empty comment is
+    /// inserted with the cursor inside.
+    pub fn toggle_block_comment(&mut self) {
+        let config = self.language_config();
+        let Some((open, close)) = config.block_comment else {
+            return;
+        };
+        self.clear_column_selection();
+        let (start, end) = self.selection_range().unwrap_or_else(|| {
+            let chars = self.line_chars(self.cursor_line);
+            let indent = chars.iter().take_while(|c| c.is_whitespace()).count();
+            let end = chars.len() - chars.iter().rev().take_while(|c| c.is_whitespace()).count();
+            ((self.cursor_line, indent), (self.cursor_line, end.max(indent)))
+        });
+        if start == end {
+            self.buffer.insert(self.char_index(start), &format!("{open}  {close}"));
+            (self.cursor_line, self.cursor_column) = (start.0, start.1 + open.chars().count() + 1);
+            self.selection_start = None;
+            self.highlighter.parse(&self.buffer.to_string());
+            return;
+        }
+        self.toggle_block_comment_in(start, end, &open, &close);
+    }
+    
+    /// Wrap `start..end` in `open` and `close`, or unwrap it if it already starts and ends with them,
+    /// leaving the result selected
+    fn toggle_block_comment_in(&mut self, start: (usize, usize), end: (usize, usize), open: &str, close: &str) {
+        let (start, end) = (self.char_index(start), self.char_index(end));
+        let text: String = self.buffer.to_string().chars().skip(start).take(end - start).collect();
+        let replacement = match text.strip_prefix(open).and_then(|text| text.strip_suffix(close)) {
+            Some(inner) => {
+                let inner = inner.strip_prefix(' ').unwrap_or(inner);
+                inner.strip_suffix(' ').unwrap_or(inner).to_string()
+            }
+            None => format!("{open} {text} {close}"),
+        };
+        self.buffer.remove(start, end);
+        self.buffer.insert(start, &replacement);
+        self.select_chars(start, start + replacement.chars().count());
+        self.highlighter.parse(&self.buffer.to_string());
+    }
+    
+    /// The bracket right after the cursor, or else right before it, and its partner, as (line, column)
+    ///
+    /// Nested pairs of the same brackets are skipped; strings and comments aren't told apart.
+    pub fn matching_bracket(&self) -> Option<BracketPair> {
+        let config = self.language_config();
+        let chars = self.line_chars(self.cursor_line);
+        let column = self.cursor_column.min(chars.len());
+        let (at, (partner, forward)) = [Some(column), column.checked_sub(1)]
+            .into_iter()
+            .flatten()
+            .find_map(|col| Some((col, config.bracket_partner(*chars.get(col)?)?)))?;
+        let bracket = chars[at];
+        
+        let lines: Box<dyn Iterator<Item = usize>> = if forward {
+            let last_line = self.buffer.len_lines().saturating_sub(1).min(self.cursor_line + BRACKET_SCAN_LINES);
+            Box::new(self.cursor_line..=last_line)
+        } else {
+            Box::new((self.cursor_line.saturating_sub(BRACKET_SCAN_LINES)..=self.cursor_line).rev())
+        };
+        let mut depth = 0;
+        for line_idx in lines {
+            let line = if line_idx == self.cursor_line { chars.clone() } else { self.line_chars(line_idx) };
+            let mut columns: Vec<usize> = (0..line.len()).collect();
+            if !forward {
+                columns.reverse();
+            }
+            for col in columns {
+                if line_idx == self.cursor_line && (if forward { col <= at } else { col >= at }) {
+                    continue;
+                }
+                if line[col] == bracket {
+                    depth += 1;
+                } else if line[col] == partner {
+                    if depth == 0 {
+                        return Some(((self.cursor_line, at), (line_idx, col)));
+                    }
+                    depth -= 1;
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reopen_closed_tab_with_its_view() {
+        let dir = std::env::temp_dir().join(format!("rabital-closed-tabs-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (first, second) = (dir.join("first.txt"), dir.join("second.txt"));
+        std::fs::write(&first, "one\ntwo\nthree\n").unwrap();
+        std::fs::write(&second, "alpha\nbeta\n").unwrap();
+
+        let mut tabs = TabManager::new();
+        tabs.add_tab_from_file(first.clone()).unwrap();
+        tabs.add_tab_from_file(second.clone()).unwrap();
+        let tab = tabs.get_tab_mut(1).unwrap();
+        tab.scroll_offset = 22.0;
+        tab.cursor_line = 2;
+        tab.cursor_column = 3;
+        tab.selection_start = Some((1, 0));
+
+        // The welcome tab has no file and isn't remembered
+        tabs.close_tabs_where(|_, tab| tab.buffer.file_path().is_none());
+        tabs.close_tab(0);
+        assert!(tabs.has_closed_tabs());
+        tabs.reopen_closed_tab().unwrap().unwrap();
+        assert_eq!(tabs.active_index(), 0);
+        let tab = tabs.get_active_tab().unwrap();
+        assert_eq!(tab.buffer.file_path(), Some(&first));
+        assert_eq!(tab.view(), TabView { scroll_offset: 22.0, cursor_line: 2, cursor_column: 3, selection_start: Some((1, 0)), column_anchor: None });
+        assert!(tabs.reopen_closed_tab().is_none());
+
+        // A file open again in the meantime is only activated
+        tabs.close_tab(1);
+        tabs.add_tab_from_file(second.clone()).unwrap();
+        tabs.set_active_tab(0);
+        tabs.reopen_closed_tab().unwrap().unwrap();
+        assert_eq!(tabs.tab_count(), 2);
+        assert_eq!(tabs.get_active_tab().unwrap().buffer.file_path(), Some(&second));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    struct Blank;
+
+    impl ZoneWidget for Blank {
+        fn height(&self, _width: f32) -> f32 {
+            40.0
+        }
+
+        fn draw(&self, _canvas: &skia_safe::Canvas, _rect: skia_safe::Rect, _font: &skia_safe::Font) {}
+    }
+
+    #[test]
+    fn zones_move_with_edits() {
+        let mut tab = EditorTab::from_text(0, "one\ntwo\nthree\n", "zones".to_string());
+        tab.add_zone("results", 1, Box::new(Blank));
+        tab.add_zone("blame", 2, Box::new(Blank));
+
+        // Lines inserted above push the zones down; breaking the line at its end keeps the zone under it
+        tab.buffer.insert(0, "zero\n");
+        tab.buffer.insert(tab.buffer.line_to_char(2) + 3, "!\nmore");
+        assert_eq!(tab.zone_lines(), vec![2, 4]);
+
+        // Deleting a zone's line leaves it where the text was
+        tab.buffer.remove(tab.buffer.line_to_char(4), tab.buffer.line_to_char(5));
+        assert_eq!(tab.zone_lines(), vec![2, 4]);
+
+        assert!(tab.remove_zones("results"));
+        assert!(!tab.remove_zones("results"));
+        assert_eq!(tab.zone_lines(), vec![4]);
+    }
+
+    #[test]
+    fn resolving_a_conflict_keeps_the_chosen_side() {
+        let text = "a\n<<<<<<< HEAD\nours\n=======\ntheirs\n>>>>>>> feature\nb\n<<<<<<< HEAD\nx\n=======\ny\n>>>>>>> feature";
+        let mut tab = EditorTab::from_text(0, text, "merge".to_string());
+        tab.refresh_conflicts();
+        assert_eq!(tab.conflicts().len(), 2);
+        // Buttons sit above each opening marker
+        assert_eq!(tab.zone_lines(), vec![0, 6]);
+
+        assert!(tab.resolve_conflict(1, Resolution::Both));
+        assert!(tab.resolve_conflict(0, Resolution::Incoming));
+        assert_eq!(tab.buffer.to_string(), "a\ntheirs\nb\nx\ny");
+        tab.refresh_conflicts();
+        assert!(tab.conflicts().is_empty());
+        assert!(tab.zone_lines().is_empty());
+    }
+
+    #[test]
+    fn bookmarks_follow_their_lines() {
+        let mut tab = EditorTab::from_text(0, "one\ntwo\nthree", "notes".to_string());
+        assert!(tab.toggle_bookmark(1));
+        assert!(tab.toggle_bookmark(2));
+        tab.set_bookmark_label(2, " last ");
+        assert_eq!(tab.bookmarks(), vec![(1, String::new()), (2, "last".to_string())]);
+
+        // A line added above pushes them down
+        tab.buffer.insert(0, "zero\n");
+        assert_eq!(tab.bookmarks(), vec![(2, String::new()), (3, "last".to_string())]);
+        assert!(!tab.toggle_bookmark(2));
+        assert_eq!(tab.bookmarks(), vec![(3, "last".to_string())]);
+    }
+
+    #[test]
+    fn dragged_text_moves_and_stays_selected() {
+        let mut tab = EditorTab::from_text(0, "one two\nthree", "drag".to_string());
+        tab.selection_start = Some((0, 0));
+        (tab.cursor_line, tab.cursor_column) = (0, 4);
+        let range = tab.selection_range().unwrap();
+        assert_eq!(range, ((0, 0), (0, 4)));
+
+        // Dropping inside the dragged text does nothing
+        assert!(!tab.move_text(range, (0, 2), false));
+        // Later in the document, the target shifts back by what was taken out
+        assert!(tab.move_text(range, (1, 5), false));
+        assert_eq!(tab.buffer.to_string(), "two\nthreeone ");
+        assert_eq!(tab.selection_range(), Some(((1, 5), (1, 9))));
+        assert_eq!(tab.get_selected_text(), "one ");
+
+        // A copy leaves the original
+        assert!(tab.move_text(((1, 5), (1, 9)), (0, 0), true));
+        assert_eq!(tab.buffer.to_string(), "one two\nthreeone ");
+        assert_eq!(tab.selection_range(), Some(((0, 0), (0, 4))));
+    }
+
+    #[test]
+    fn inserted_text_keeps_tabs_and_indentation() {
+        let mut tab = EditorTab::from_text(0, "    x\n", "paste".to_string());
+        tab.cursor_column = 4;
+        tab.insert_at_cursor("a:\r\n\tb\r\n        c");
+        assert_eq!(tab.buffer.to_string(), "    a:\n\tb\n        cx\n");
+        assert_eq!((tab.cursor_line, tab.cursor_column), (2, 9));
+    }
+
+    #[test]
+    fn typed_pairs_close_step_over_and_delete_together() {
+        let mut tab = EditorTab::from_text(0, "x", "pairs".to_string());
+        tab.buffer.set_language("rust");
+        tab.cursor_column = 1;
+        assert!(tab.type_paired('('));
+        assert_eq!((tab.buffer.to_string().as_str(), tab.cursor_column), ("x()", 2));
+        assert!(tab.type_paired(')'));
+        assert_eq!((tab.buffer.to_string().as_str(), tab.cursor_column), ("x()", 3));
+        // In front of a word, nothing closes
+        tab.cursor_column = 0;
+        assert!(!tab.type_paired('['));
+
+        tab.cursor_column = 2;
+        assert!(tab.delete_pair());
+        assert_eq!((tab.buffer.to_string().as_str(), tab.cursor_column), ("x", 1));
+
+        // A selection gets wrapped and stays selected
+        tab.selection_start = Some((0, 0));
+        tab.cursor_column = 1;
+        assert!(tab.type_paired('"'));
+        assert_eq!(tab.buffer.to_string(), "\"x\"");
+        assert_eq!(tab.get_selected_text(), "x");
+    }
+
+    #[test]
+    fn line_comments_toggle_at_the_least_indent() {
+        let mut tab = EditorTab::from_text(0, "fn a() {\n    b();\n\n}\n", "comments".to_string());
+        tab.buffer.set_language("rust");
+        tab.selection_start = Some((0, 0));
+        (tab.cursor_line, tab.cursor_column) = (3, 1);
+        tab.toggle_line_comment();
+        assert_eq!(tab.buffer.to_string(), "// fn a() {\n//     b();\n\n// }\n");
+        assert_eq!(tab.cursor_column, 4);
+
+        tab.toggle_line_comment();
+        assert_eq!(tab.buffer.to_string(), "fn a() {\n    b();\n\n}\n");
+        assert_eq!(tab.cursor_column, 1);
+
+        // Without a line comment, the lines become a block comment
+        let mut tab = EditorTab::from_text(0, "  a { b: c; }\n", "css".to_string());
+        tab.buffer.set_language("css");
+        tab.toggle_line_comment();
+        assert_eq!(tab.buffer.to_string(), "  /* a { b: c; } */\n");
+        tab.toggle_block_comment();
+        assert_eq!(tab.buffer.to_string(), "  a { b: c; }\n");
+    }
+
+    #[test]
+    fn brackets_match_across_lines_and_nesting() {
+        let mut tab = EditorTab::from_text(0, "f(a, (b)) {\n    [c]\n}", "brackets".to_string());
+        tab.buffer.set_language("rust");
+        tab.cursor_column = 1;
+        assert_eq!(tab.matching_bracket(), Some(((0, 1), (0, 8))));
+        // Right after a closer
+        tab.cursor_column = 9;
+        assert_eq!(tab.matching_bracket(), Some(((0, 8), (0, 1))));
+        tab.cursor_column = 10;
+        assert_eq!(tab.matching_bracket(), Some(((0, 10), (2, 0))));
+
+        assert!(tab.select_word((1, 5)));
+        assert_eq!(tab.get_selected_text(), "c");
+        assert!(!tab.select_word((1, 0)));
+    }
 }
@@ -1,6 +1,6 @@
-use crate::tab::TabManager;
+use crate::tab::{EditorTab, TabManager};
 use skia_safe::{Canvas, Color, Font, Paint, Rect};
-use mikoui::{current_theme, with_alpha};
+use mikoui::{current_theme, file_icon_theme, surface, truncate_text, with_alpha, CodiconIcons, FileIcon, IconSize, Truncate};
 
 pub struct TabBar {
     x: f32,
@@ -17,6 +17,7 @@ impl TabBar {
     const TAB_MIN_WIDTH: f32 = 120.0;
     const TAB_MAX_WIDTH: f32 = 200.0;
     const CLOSE_BUTTON_SIZE: f32 = 16.0;
+    const PINNED_TAB_WIDTH: f32 = 44.0;
     
     pub fn new(x: f32, y: f32, width: f32) -> Self {
         Self {
@@ -40,34 +41,47 @@ impl TabBar {
         self.width = width;
     }
     
+    /// Left edge and width of every tab; pinned tabs are narrow and the rest share the remaining width
+    fn tab_layout(&self, tab_manager: &TabManager) -> Vec<(f32, f32)> {
+        let pinned_count = tab_manager.pinned_count();
+        let unpinned_count = tab_manager.tab_count() - pinned_count;
+        let available_width = self.width - 40.0 - pinned_count as f32 * Self::PINNED_TAB_WIDTH; // Leave space for new tab button
+        let tab_width = (available_width / unpinned_count.max(1) as f32)
+            .max(Self::TAB_MIN_WIDTH)
+            .min(Self::TAB_MAX_WIDTH);
+        
+        let mut x = self.x;
+        tab_manager
+            .tabs()
+            .iter()
+            .map(|tab| {
+                let width = if tab.pinned { Self::PINNED_TAB_WIDTH } else { tab_width };
+                x += width;
+                (x - width, width)
+            })
+            .collect()
+    }
+    
     pub fn draw(&self, canvas: &Canvas, font: &Font, tab_manager: &TabManager) {
         // Background
         let theme = current_theme();
         let mut bg_paint = Paint::default();
-        bg_paint.set_color(theme.card);
+        bg_paint.set_color(surface(theme.card));
         bg_paint.set_anti_alias(true);
         canvas.draw_rect(
             Rect::from_xywh(self.x, self.y, self.width, self.height),
             &bg_paint,
         );
         
-        let tab_count = tab_manager.tab_count();
-        if tab_count == 0 {
-            return;
-        }
-        
-        // Calculate tab width
-        let available_width = self.width - 40.0; // Leave space for new tab button
-        let tab_width = (available_width / tab_count as f32)
-            .max(Self::TAB_MIN_WIDTH)
-            .min(Self::TAB_MAX_WIDTH);
-        
         // Draw tabs
-        for (i, tab) in tab_manager.tabs().iter().enumerate() {
-            let tab_x = self.x + (i as f32 * tab_width);
+        for (i, (tab, (tab_x, tab_width))) in tab_manager.tabs().iter().zip(self.tab_layout(tab_manager)).enumerate() {
             let is_active = i == tab_manager.active_index();
             let is_hovered = self.hover_tab == Some(i);
             
+            if tab.pinned {
+                self.draw_pinned_tab(canvas, tab_x, tab_width, tab, is_active, i);
+                continue;
+            }
             self.draw_tab(
                 canvas,
                 font,
@@ -145,18 +159,9 @@ impl TabBar {
         });
         text_paint.set_anti_alias(true);
         
-        // Truncate title if too long
+        // Truncate long titles in the middle so the extension stays visible
         let max_text_width = width - 40.0; // Leave space for close button
-        let text_width = font.measure_str(title, None).0;
-        let display_title = if text_width > max_text_width {
-            let mut truncated = title.to_string();
-            while font.measure_str(&truncated, None).0 > max_text_width - 20.0 && !truncated.is_empty() {
-                truncated.pop();
-            }
-            format!("{}...", truncated)
-        } else {
-            title.to_string()
-        };
+        let display_title = truncate_text(title, max_text_width, Truncate::Middle, |text| font.measure_str(text, None).0);
         
         canvas.draw_str(&display_title, (text_x, text_y), font, &text_paint);
         
@@ -212,42 +217,57 @@ impl TabBar {
         }
     }
     
-    pub fn update_hover(&mut self, x: f32, y: f32, tab_manager: &TabManager) {
-        self.hover_tab = None;
-        self.hover_close = None;
-        
-        if y < self.y || y > self.y + self.height {
-            return;
+    /// Pinned tab: the file icon only, with a dot while modified
+    fn draw_pinned_tab(&self, canvas: &Canvas, x: f32, width: f32, tab: &EditorTab, is_active: bool, index: usize) {
+        let theme = current_theme();
+        let is_hovered = self.hover_tab == Some(index);
+        let mut tab_paint = Paint::default();
+        tab_paint.set_anti_alias(true);
+        if is_active {
+            tab_paint.set_color(theme.background);
+        } else if is_hovered {
+            let hover_alpha = self.hover_progress.get(index).map_or(0, |progress| (50.0 * progress) as u8);
+            tab_paint.set_color(with_alpha(theme.foreground, hover_alpha));
         }
+        canvas.draw_rect(Rect::from_xywh(x, self.y, width, self.height), &tab_paint);
         
-        let tab_count = tab_manager.tab_count();
-        if tab_count == 0 {
-            return;
+        if is_active {
+            let mut indicator_paint = Paint::default();
+            indicator_paint.set_color(theme.primary);
+            canvas.draw_rect(Rect::from_xywh(x, self.y, width, 2.0), &indicator_paint);
         }
         
-        let available_width = self.width - 40.0;
-        let tab_width = (available_width / tab_count as f32)
-            .max(Self::TAB_MIN_WIDTH)
-            .min(Self::TAB_MAX_WIDTH);
+        let icon_size = IconSize::Small.as_f32();
+        let color = if is_active { theme.foreground } else { theme.muted_foreground };
+        Self::file_icon(tab)
+            .to_icon(x + (width - icon_size) / 2.0, self.y + (self.height - icon_size) / 2.0, IconSize::Small, color)
+            .paint(canvas);
         
-        for i in 0..tab_count {
-            let tab_x = self.x + (i as f32 * tab_width);
-            
-            if x >= tab_x && x < tab_x + tab_width {
-                self.hover_tab = Some(i);
-                
-                // Check if hovering over close button
-                let close_x = tab_x + tab_width - 24.0;
-                let close_y = self.y + (self.height - Self::CLOSE_BUTTON_SIZE) / 2.0;
-                
-                if x >= close_x && x < close_x + Self::CLOSE_BUTTON_SIZE &&
-                   y >= close_y && y < close_y + Self::CLOSE_BUTTON_SIZE {
-                    self.hover_close = Some(i);
-                }
-                
-                break;
-            }
+        if tab.is_modified() {
+            let mut dot_paint = Paint::default();
+            dot_paint.set_color(theme.foreground);
+            dot_paint.set_anti_alias(true);
+            canvas.draw_circle((x + width - 9.0, self.y + 10.0), 3.0, &dot_paint);
+        }
+        
+        let mut separator_paint = Paint::default();
+        separator_paint.set_color(theme.border);
+        separator_paint.set_stroke_width(1.0);
+        canvas.draw_line((x + width, self.y + 8.0), (x + width, self.y + self.height - 8.0), &separator_paint);
+    }
+    
+    /// Icon standing in for the title of a pinned tab, from the file icon theme
+    fn file_icon(tab: &EditorTab) -> FileIcon {
+        if tab.is_hex() {
+            return FileIcon::codicon(CodiconIcons::FILE_BINARY);
         }
+        let name = tab.buffer.file_path().and_then(|path| path.file_name()).map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        file_icon_theme().file_icon(&name, tab.buffer.language())
+    }
+    
+    pub fn update_hover(&mut self, x: f32, y: f32, tab_manager: &TabManager) {
+        self.hover_tab = self.get_clicked_tab(x, y, tab_manager);
+        self.hover_close = self.get_close_button_clicked(x, y, tab_manager);
     }
     
     pub fn update_animation(&mut self, tab_count: usize) {
@@ -274,25 +294,9 @@ impl TabBar {
             return None;
         }
         
-        let tab_count = tab_manager.tab_count();
-        if tab_count == 0 {
-            return None;
-        }
-        
-        let available_width = self.width - 40.0;
-        let tab_width = (available_width / tab_count as f32)
-            .max(Self::TAB_MIN_WIDTH)
-            .min(Self::TAB_MAX_WIDTH);
-        
-        for i in 0..tab_count {
-            let tab_x = self.x + (i as f32 * tab_width);
-            
-            if x >= tab_x && x < tab_x + tab_width {
-                return Some(i);
-            }
-        }
-        
-        None
+        self.tab_layout(tab_manager)
+            .into_iter()
+            .position(|(tab_x, tab_width)| x >= tab_x && x < tab_x + tab_width)
     }
     
     pub fn get_close_button_clicked(&self, x: f32, y: f32, tab_manager: &TabManager) -> Option<usize> {
@@ -300,18 +304,8 @@ impl TabBar {
             return None;
         }
         
-        let tab_count = tab_manager.tab_count();
-        if tab_count == 0 {
-            return None;
-        }
-        
-        let available_width = self.width - 40.0;
-        let tab_width = (available_width / tab_count as f32)
-            .max(Self::TAB_MIN_WIDTH)
-            .min(Self::TAB_MAX_WIDTH);
-        
-        for i in 0..tab_count {
-            let tab_x = self.x + (i as f32 * tab_width);
+        // Pinned tabs have no close button
+        for (i, (tab_x, tab_width)) in self.tab_layout(tab_manager).into_iter().enumerate().skip(tab_manager.pinned_count()) {
             let close_x = tab_x + tab_width - 24.0;
             let close_y = self.y + (self.height - Self::CLOSE_BUTTON_SIZE) / 2.0;
             
@@ -0,0 +1,95 @@
+use skia_safe::Color;
+
+use crate::completion::is_word_char;
+
+/// Key of the decorations a tab marks its to-do comments with
+pub(crate) const TODOS_KEY: &str = "todos";
+/// Tint of a to-do keyword, amber like warnings
+pub(crate) const TODO_COLOR: Color = Color::from_rgb(234, 179, 8);
+/// Keywords looked for when settings name none
+pub const DEFAULT_TODO_KEYWORDS: [&str; 3] = ["TODO", "FIXME", "HACK"];
+
+/// Text that starts a comment in one of the common languages; `*` continues a block comment
+const COMMENT_MARKERS: [&str; 6] = ["//", "/*", "#", "--", "<!--", ";"];
+
+/// A comment starting with one of the to-do keywords, e.g. `// TODO: handle errors`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TodoComment {
+    /// Zero-based line and char column of the keyword
+    pub line: usize,
+    pub column: usize,
+    pub keyword: String,
+    /// What follows the keyword, without the colon and any comment closer
+    pub text: String,
+}
+
+/// Whether `prefix`, the part of a line before a keyword, opens a comment
+fn in_comment(prefix: &str) -> bool {
+    prefix.trim_start().starts_with('*') || COMMENT_MARKERS.iter().any(|marker| prefix.contains(marker))
+}
+
+/// To-do comments in `lines`, at most one per line
+///
+/// Keywords match whole words and are case-sensitive, so `todo` in prose doesn't count,
+/// and only after something that opens a comment on the same line.
+pub fn find_todos<'a>(lines: impl IntoIterator<Item = &'a str>, keywords: &[String]) -> Vec<TodoComment> {
+    let mut todos = Vec::new();
+    for (line_index, line) in lines.into_iter().enumerate() {
+        let found = keywords
+            .iter()
+            .filter(|keyword| !keyword.is_empty())
+            .filter_map(|keyword| {
+                line.match_indices(keyword.as_str()).find(|&(start, _)| {
+                    let before = line[..start].chars().next_back();
+                    let after = line[start + keyword.len()..].chars().next();
+                    !before.is_some_and(is_word_char) && !after.is_some_and(is_word_char) && in_comment(&line[..start])
+                })
+            })
+            .min_by_key(|&(start, _)| start);
+        let Some((start, keyword)) = found else {
+            continue;
+        };
+        let text = line[start + keyword.len()..]
+            .trim_end()
+            .trim_end_matches("*/")
+            .trim_end_matches("-->")
+            .trim_start_matches(|c: char| c == ':' || c.is_whitespace())
+            .trim_end();
+        todos.push(TodoComment {
+            line: line_index,
+            column: line[..start].chars().count(),
+            keyword: keyword.to_string(),
+            text: text.to_string(),
+        });
+    }
+    todos
+}
+
+/// To-do comments found in a tab and the buffer version they were found in
+#[derive(Default)]
+pub(crate) struct Todos {
+    pub version: Option<u64>,
+    pub list: Vec<TodoComment>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_keywords_in_comments_only() {
+        let text = "let todo = 1; // TODO: rename\n/* FIXME tidy up */\nlet TODO_LIST = [];\n# HACK(ana): works for now\n * TODO\nprintln!(\"TODO\");";
+        let keywords: Vec<String> = DEFAULT_TODO_KEYWORDS.iter().map(|keyword| keyword.to_string()).collect();
+        let todos = find_todos(text.lines(), &keywords);
+        let found: Vec<_> = todos.iter().map(|todo| (todo.line, todo.column, todo.keyword.as_str(), todo.text.as_str())).collect();
+        assert_eq!(
+            found,
+            [
+                (0, 17, "TODO", "rename"),
+                (1, 3, "FIXME", "tidy up"),
+                (3, 2, "HACK", "(ana): works for now"),
+                (4, 3, "TODO", ""),
+            ]
+        );
+    }
+}
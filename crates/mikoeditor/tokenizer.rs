@@ -0,0 +1,614 @@
+use crate::syntax::{DocumentSymbol, TokenType};
+
+/// Line-based token rules for languages without a bundled tree-sitter grammar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TokenRules {
+    Yaml,
+    Markdown,
+}
+
+impl TokenRules {
+    pub fn for_language(language: &str) -> Option<Self> {
+        match language {
+            "yaml" => Some(TokenRules::Yaml),
+            "markdown" => Some(TokenRules::Markdown),
+            _ => None,
+        }
+    }
+
+    /// Byte ranges in document order, without overlaps
+    pub fn tokenize(&self, source: &str) -> Vec<(usize, usize, TokenType)> {
+        let mut tokens = Tokens::default();
+        match self {
+            TokenRules::Yaml => tokenize_yaml(source, &mut tokens),
+            TokenRules::Markdown => tokenize_markdown(source, &mut tokens),
+        }
+        tokens.0
+    }
+
+    /// Outline entries: Markdown headings, nested by level
+    pub fn symbols(&self, source: &str) -> Vec<DocumentSymbol> {
+        match self {
+            TokenRules::Markdown => markdown_headings(source),
+            TokenRules::Yaml => Vec::new(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Tokens(Vec<(usize, usize, TokenType)>);
+
+impl Tokens {
+    fn push(&mut self, start: usize, end: usize, token_type: TokenType) {
+        if start < end {
+            self.0.push((start, end, token_type));
+        }
+    }
+}
+
+/// Lines with their starting byte offset, without the line break
+fn lines_with_offsets(source: &str) -> impl Iterator<Item = (usize, &str)> {
+    source.split_inclusive('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len();
+        Some((start, line.trim_end_matches(['\n', '\r'])))
+    })
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches([' ', '\t']).len()
+}
+
+// YAML
+
+fn tokenize_yaml(source: &str, tokens: &mut Tokens) {
+    // Indent of the line that opened a `|` or `>` block scalar
+    let mut block_scalar: Option<usize> = None;
+
+    for (offset, line) in lines_with_offsets(source) {
+        let indent = indent_of(line);
+        let content = line.trim_end();
+
+        if let Some(parent_indent) = block_scalar {
+            if content.trim().is_empty() {
+                continue;
+            }
+            if indent > parent_indent {
+                tokens.push(offset + indent, offset + content.len(), TokenType::String);
+                continue;
+            }
+            block_scalar = None;
+        }
+
+        let trimmed = &content[indent..];
+        if trimmed.starts_with('#') {
+            tokens.push(offset + indent, offset + content.len(), TokenType::Comment);
+            continue;
+        }
+        if matches!(trimmed, "---" | "...") || trimmed.starts_with("--- ") || trimmed.starts_with('%') {
+            let marker_len = if trimmed.starts_with('%') { trimmed.len() } else { 3 };
+            let token_type = if trimmed.starts_with('%') { TokenType::Keyword } else { TokenType::Punctuation };
+            tokens.push(offset + indent, offset + indent + marker_len, token_type);
+            yaml_value(line, indent + marker_len, offset, tokens);
+            continue;
+        }
+
+        // Sequence entries, possibly nested on one line (`- - a`)
+        let mut pos = indent;
+        while line[pos..].starts_with("- ") || &line[pos..] == "-" {
+            tokens.push(offset + pos, offset + pos + 1, TokenType::Punctuation);
+            pos += 1;
+            pos += indent_of(&line[pos..]);
+        }
+
+        if let Some(colon) = yaml_key_end(&line[pos..]) {
+            let key = line[pos..pos + colon].trim_end();
+            tokens.push(offset + pos, offset + pos + key.len(), TokenType::Property);
+            tokens.push(offset + pos + colon, offset + pos + colon + 1, TokenType::Punctuation);
+            pos += colon + 1;
+        }
+
+        let value = line[pos..].trim();
+        if value.starts_with(['|', '>']) && !value.contains(' ') {
+            tokens.push(offset + pos + indent_of(&line[pos..]), offset + content.len(), TokenType::Operator);
+            block_scalar = Some(indent);
+            continue;
+        }
+        yaml_value(line, pos, offset, tokens);
+    }
+}
+
+/// Byte index of the `:` ending a mapping key, ignoring colons inside quotes or plain values like URLs
+fn yaml_key_end(text: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        match (c, quote) {
+            ('\\', Some('"')) => {
+                chars.next();
+            }
+            (c, Some(q)) if c == q => quote = None,
+            (_, Some(_)) => {}
+            ('"' | '\'', None) if index == 0 => quote = Some(c),
+            ('#', None) if index == 0 || text[..index].ends_with(' ') => return None,
+            ('[' | '{', None) if index == 0 => return None,
+            (':', None) => {
+                let next = chars.peek().map(|(_, next)| *next);
+                if next.is_none() || next.is_some_and(char::is_whitespace) {
+                    return (index > 0).then_some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Scalars, flow collections and trailing comments after a key or sequence marker
+fn yaml_value(line: &str, from: usize, offset: usize, tokens: &mut Tokens) {
+    let bytes = line.as_bytes();
+    let mut flow_depth = 0usize;
+    let mut pos = from;
+
+    while pos < line.len() {
+        let c = line[pos..].chars().next().unwrap_or(' ');
+        match c {
+            ' ' | '\t' => pos += 1,
+            '#' if pos == from || matches!(bytes[pos - 1], b' ' | b'\t') => {
+                tokens.push(offset + pos, offset + line.trim_end().len(), TokenType::Comment);
+                return;
+            }
+            '[' | ']' | '{' | '}' | ',' => {
+                match c {
+                    '[' | '{' => flow_depth += 1,
+                    ']' | '}' => flow_depth = flow_depth.saturating_sub(1),
+                    _ => {}
+                }
+                tokens.push(offset + pos, offset + pos + 1, TokenType::Punctuation);
+                pos += 1;
+            }
+            ':' if flow_depth > 0 => {
+                tokens.push(offset + pos, offset + pos + 1, TokenType::Punctuation);
+                pos += 1;
+            }
+            '"' | '\'' => {
+                let end = quoted_end(line, pos, c);
+                tokens.push(offset + pos, offset + end, TokenType::String);
+                pos = end;
+            }
+            '&' | '*' | '!' => {
+                let end = line[pos..]
+                    .find(|c: char| c.is_whitespace() || (flow_depth > 0 && ",[]{}".contains(c)))
+                    .map_or(line.len(), |len| pos + len);
+                tokens.push(offset + pos, offset + end, TokenType::Type);
+                pos = end;
+            }
+            _ => {
+                let end = plain_scalar_end(line, pos, flow_depth > 0);
+                let scalar = line[pos..end].trim_end();
+                let token_type = if flow_depth > 0 && line[pos + scalar.len()..].trim_start().starts_with(':') {
+                    TokenType::Property
+                } else {
+                    yaml_scalar_type(scalar)
+                };
+                tokens.push(offset + pos, offset + pos + scalar.len(), token_type);
+                pos = end;
+            }
+        }
+    }
+}
+
+/// End of a quoted scalar, just past the closing quote (or the line end if unterminated)
+fn quoted_end(line: &str, start: usize, quote: char) -> usize {
+    let mut chars = line[start + 1..].char_indices();
+    while let Some((index, c)) = chars.next() {
+        if c == '\\' && quote == '"' {
+            chars.next();
+        } else if c == quote {
+            return start + 1 + index + 1;
+        }
+    }
+    line.len()
+}
+
+/// End of an unquoted scalar: a comment, or in flow context a separator or `: `
+fn plain_scalar_end(line: &str, start: usize, in_flow: bool) -> usize {
+    let mut previous = ' ';
+    for (index, c) in line[start..].char_indices() {
+        let at = start + index;
+        if c == '#' && previous.is_whitespace() && index > 0 {
+            return at;
+        }
+        if in_flow && (",[]{}".contains(c) || (c == ':' && line[at + 1..].starts_with([' ', ',', ']', '}']))) {
+            return at;
+        }
+        previous = c;
+    }
+    line.len()
+}
+
+fn yaml_scalar_type(scalar: &str) -> TokenType {
+    match scalar {
+        "true" | "false" | "True" | "False" | "TRUE" | "FALSE" | "yes" | "no" | "on" | "off" | "null" | "Null"
+        | "NULL" | "~" => TokenType::Constant,
+        ".inf" | "-.inf" | "+.inf" | ".nan" | ".NaN" => TokenType::Number,
+        _ if is_yaml_number(scalar) => TokenType::Number,
+        _ => TokenType::String,
+    }
+}
+
+fn is_yaml_number(scalar: &str) -> bool {
+    let digits = scalar.trim_start_matches(['+', '-']);
+    if let Some(hex) = digits.strip_prefix("0x") {
+        return !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+    if let Some(octal) = digits.strip_prefix("0o") {
+        return !octal.is_empty() && octal.chars().all(|c| c.is_digit(8));
+    }
+    digits.starts_with(|c: char| c.is_ascii_digit() || c == '.') && digits.replace('_', "").parse::<f64>().is_ok()
+}
+
+// Markdown
+
+fn tokenize_markdown(source: &str, tokens: &mut Tokens) {
+    // Marker char and length of the open code fence
+    let mut fence: Option<(char, usize)> = None;
+
+    for (offset, line) in lines_with_offsets(source) {
+        let indent = indent_of(line);
+        let trimmed = line[indent..].trim_end();
+
+        if let Some((marker, length)) = fence {
+            let run = trimmed.chars().take_while(|c| *c == marker).count();
+            if indent < 4 && run >= length && trimmed[run..].trim().is_empty() {
+                tokens.push(offset + indent, offset + indent + trimmed.len(), TokenType::Punctuation);
+                fence = None;
+            } else {
+                tokens.push(offset, offset + line.len(), TokenType::String);
+            }
+            continue;
+        }
+
+        if indent < 4 {
+            if let Some(marker) = trimmed.chars().next().filter(|c| *c == '`' || *c == '~') {
+                let run = trimmed.chars().take_while(|c| *c == marker).count();
+                if run >= 3 {
+                    tokens.push(offset + indent, offset + indent + run, TokenType::Punctuation);
+                    let info = trimmed[run..].trim_start();
+                    let info_start = offset + indent + trimmed.len() - info.len();
+                    tokens.push(info_start, info_start + info.len(), TokenType::Type);
+                    fence = Some((marker, run));
+                    continue;
+                }
+            }
+
+            let hashes = trimmed.chars().take_while(|c| *c == '#').count();
+            if (1..=6).contains(&hashes) && (trimmed.len() == hashes || trimmed[hashes..].starts_with([' ', '\t'])) {
+                tokens.push(offset + indent, offset + indent + trimmed.len(), TokenType::Keyword);
+                continue;
+            }
+
+            if is_thematic_break(trimmed) {
+                tokens.push(offset + indent, offset + indent + trimmed.len(), TokenType::Punctuation);
+                continue;
+            }
+        }
+
+        let mut pos = indent;
+        // Block quote markers, then a list marker and task box
+        while line[pos..].starts_with('>') {
+            tokens.push(offset + pos, offset + pos + 1, TokenType::Comment);
+            pos += 1;
+            pos += indent_of(&line[pos..]);
+        }
+        if let Some(marker_len) = list_marker_len(&line[pos..]) {
+            tokens.push(offset + pos, offset + pos + marker_len, TokenType::Punctuation);
+            pos += marker_len;
+            pos += indent_of(&line[pos..]);
+            let rest = &line[pos..];
+            if rest.starts_with("[ ]") || rest.starts_with("[x]") || rest.starts_with("[X]") {
+                tokens.push(offset + pos, offset + pos + 3, TokenType::Constant);
+                pos += 3;
+            }
+        }
+
+        markdown_inline(line, pos, offset, tokens);
+    }
+}
+
+/// ATX headings outside code fences; a heading's section runs until the next heading of the same or a higher level
+fn markdown_headings(source: &str) -> Vec<DocumentSymbol> {
+    let mut headings: Vec<(DocumentSymbol, usize)> = Vec::new();
+    let mut fence: Option<(char, usize)> = None;
+    let mut last_line = 0;
+
+    for (line_index, (_, line)) in lines_with_offsets(source).enumerate() {
+        last_line = line_index;
+        let indent = indent_of(line);
+        let trimmed = line[indent..].trim_end();
+        if indent >= 4 {
+            continue;
+        }
+        if let Some(marker) = trimmed.chars().next().filter(|c| *c == '`' || *c == '~') {
+            let run = trimmed.chars().take_while(|c| *c == marker).count();
+            match fence {
+                Some((open, length)) if open == marker && run >= length && trimmed[run..].trim().is_empty() => fence = None,
+                None if run >= 3 => fence = Some((marker, run)),
+                _ => {}
+            }
+            continue;
+        }
+        if fence.is_some() {
+            continue;
+        }
+
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        if !(1..=6).contains(&level) || !(trimmed.len() == level || trimmed[level..].starts_with([' ', '\t'])) {
+            continue;
+        }
+        let text = trimmed[level..].trim_start();
+        let text_start = indent + trimmed.len() - text.len();
+        // A closing run of `#` after a space isn't part of the title
+        let name = match text.trim_end_matches('#') {
+            rest if rest.is_empty() || rest.ends_with([' ', '\t']) => rest.trim_end(),
+            _ => text,
+        };
+
+        for (open, open_level) in headings.iter_mut().rev() {
+            if *open_level >= level && open.end_line == usize::MAX {
+                open.end_line = line_index.saturating_sub(1);
+            }
+        }
+        let depth = headings.iter().filter(|(open, open_level)| *open_level < level && open.end_line == usize::MAX).count();
+        headings.push((
+            DocumentSymbol {
+                name: name.to_string(),
+                kind: TokenType::Keyword,
+                line: line_index,
+                column: line[..text_start].chars().count(),
+                depth,
+                end_line: usize::MAX,
+            },
+            level,
+        ));
+    }
+
+    headings
+        .into_iter()
+        .map(|(mut heading, _)| {
+            heading.end_line = heading.end_line.min(last_line);
+            heading
+        })
+        .collect()
+}
+
+fn is_thematic_break(trimmed: &str) -> bool {
+    let Some(marker) = trimmed.chars().next().filter(|c| matches!(c, '-' | '*' | '_')) else {
+        return false;
+    };
+    trimmed.chars().all(|c| c == marker || c == ' ') && trimmed.chars().filter(|c| *c == marker).count() >= 3
+}
+
+/// Length of a `-`, `*`, `+` or `1.` / `1)` list marker at the start of `text`
+fn list_marker_len(text: &str) -> Option<usize> {
+    let followed_by_space = |len: usize| text.len() == len || text[len..].starts_with([' ', '\t']);
+    if text.starts_with(['-', '*', '+']) && followed_by_space(1) {
+        return Some(1);
+    }
+    let digits = text.chars().take_while(char::is_ascii_digit).count();
+    if (1..=9).contains(&digits) && text[digits..].starts_with(['.', ')']) && followed_by_space(digits + 1) {
+        return Some(digits + 1);
+    }
+    None
+}
+
+/// Code spans, emphasis, links, autolinks and HTML comments within one line
+fn markdown_inline(line: &str, from: usize, offset: usize, tokens: &mut Tokens) {
+    let mut pos = from;
+    while pos < line.len() {
+        let rest = &line[pos..];
+        let c = rest.chars().next().unwrap_or(' ');
+
+        // Backslash escapes keep the next char literal
+        if c == '\\' {
+            pos += 1 + rest[1..].chars().next().map_or(0, char::len_utf8);
+            continue;
+        }
+
+        if c == '`' {
+            let run = rest.chars().take_while(|c| *c == '`').count();
+            let closing = &rest[..run];
+            if let Some(close) = rest[run..].find(closing) {
+                let end = pos + run + close + run;
+                tokens.push(offset + pos, offset + end, TokenType::String);
+                pos = end;
+                continue;
+            }
+            pos += run;
+            continue;
+        }
+
+        if rest.starts_with("<!--") {
+            let end = rest.find("-->").map_or(line.len(), |close| pos + close + 3);
+            tokens.push(offset + pos, offset + end, TokenType::Comment);
+            pos = end;
+            continue;
+        }
+
+        if c == '<' {
+            if let Some(close) = rest.find('>') {
+                let inner = &rest[1..close];
+                if inner.contains("://") || (inner.contains('@') && !inner.contains(' ')) {
+                    tokens.push(offset + pos, offset + pos + close + 1, TokenType::String);
+                    pos += close + 1;
+                    continue;
+                }
+            }
+        }
+
+        if c == '[' || rest.starts_with("![") {
+            if let Some(len) = markdown_link(line, pos, offset, tokens) {
+                pos += len;
+                continue;
+            }
+        }
+
+        if c == '*' || c == '_' {
+            let run = rest.chars().take_while(|ch| *ch == c).count().min(3);
+            let delimiter = &rest[..run];
+            let opens = rest[run..].starts_with(|ch: char| !ch.is_whitespace());
+            // Underscores inside words are not emphasis
+            let intraword = c == '_' && line[..pos].ends_with(|ch: char| ch.is_alphanumeric());
+            if opens && !intraword {
+                if let Some(close) = rest[run..].find(delimiter).filter(|close| *close > 0) {
+                    let end = pos + run + close + run;
+                    let token_type = if run >= 2 { TokenType::Type } else { TokenType::Parameter };
+                    tokens.push(offset + pos, offset + end, token_type);
+                    pos = end;
+                    continue;
+                }
+            }
+            pos += run;
+            continue;
+        }
+
+        pos += c.len_utf8();
+    }
+}
+
+/// `[text](url)` or `![alt](src)` starting at `pos`; returns its length when complete
+fn markdown_link(line: &str, pos: usize, offset: usize, tokens: &mut Tokens) -> Option<usize> {
+    let rest = &line[pos..];
+    let open = if rest.starts_with('!') { 1 } else { 0 };
+    let text_end = rest[open..].find("](")? + open;
+    let url_end = rest[text_end + 2..].find(')')? + text_end + 2;
+
+    let start = offset + pos;
+    tokens.push(start, start + open + 1, TokenType::Punctuation);
+    tokens.push(start + open + 1, start + text_end, TokenType::Function);
+    tokens.push(start + text_end, start + text_end + 2, TokenType::Punctuation);
+    tokens.push(start + text_end + 2, start + url_end, TokenType::String);
+    tokens.push(start + url_end, start + url_end + 1, TokenType::Punctuation);
+    Some(url_end + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(rules: TokenRules, source: &str) -> Vec<(&str, TokenType)> {
+        let tokens = rules.tokenize(source);
+        for pair in tokens.windows(2) {
+            assert!(pair[0].1 <= pair[1].0, "overlapping tokens: {:?}", pair);
+        }
+        tokens.into_iter().map(|(start, end, token_type)| (&source[start..end], token_type)).collect()
+    }
+
+    #[test]
+    fn test_yaml() {
+        let source = "\
+%YAML 1.2
+---
+# settings
+name: \"app\"  # trailing
+version: 1.5
+debug: true
+url: http://example.com/a:b
+tags: [web, 'api', 3]
+base: &base
+  - key: ~
+script: |
+  echo \"not: a key\"
+  exit 0
+other: *base
+";
+        let tokens = tokens(TokenRules::Yaml, source);
+        let expected = [
+            ("%YAML 1.2", TokenType::Keyword),
+            ("---", TokenType::Punctuation),
+            ("# settings", TokenType::Comment),
+            ("name", TokenType::Property),
+            ("\"app\"", TokenType::String),
+            ("# trailing", TokenType::Comment),
+            ("1.5", TokenType::Number),
+            ("true", TokenType::Constant),
+            ("http://example.com/a:b", TokenType::String),
+            ("[", TokenType::Punctuation),
+            ("web", TokenType::String),
+            ("'api'", TokenType::String),
+            ("3", TokenType::Number),
+            ("&base", TokenType::Type),
+            ("-", TokenType::Punctuation),
+            ("key", TokenType::Property),
+            ("~", TokenType::Constant),
+            ("|", TokenType::Operator),
+            ("echo \"not: a key\"", TokenType::String),
+            ("exit 0", TokenType::String),
+            ("other", TokenType::Property),
+            ("*base", TokenType::Type),
+        ];
+        for token in expected {
+            assert!(tokens.contains(&token), "missing {:?} in {:?}", token, tokens);
+        }
+        assert!(!tokens.contains(&("not", TokenType::Property)));
+    }
+
+    #[test]
+    fn test_markdown() {
+        let source = "\
+# Title
+Some *emphasis*, **strong** and `code` with [a link](https://example.com).
+- [x] done
+1. first
+> quoted
+snake_case_word
+```rust
+let x = \"*not emphasis*\";
+```
+---
+";
+        let tokens = tokens(TokenRules::Markdown, source);
+        let expected = [
+            ("# Title", TokenType::Keyword),
+            ("*emphasis*", TokenType::Parameter),
+            ("**strong**", TokenType::Type),
+            ("`code`", TokenType::String),
+            ("a link", TokenType::Function),
+            ("https://example.com", TokenType::String),
+            ("-", TokenType::Punctuation),
+            ("[x]", TokenType::Constant),
+            ("1.", TokenType::Punctuation),
+            (">", TokenType::Comment),
+            ("```", TokenType::Punctuation),
+            ("rust", TokenType::Type),
+            ("let x = \"*not emphasis*\";", TokenType::String),
+            ("---", TokenType::Punctuation),
+        ];
+        for token in expected {
+            assert!(tokens.contains(&token), "missing {:?} in {:?}", token, tokens);
+        }
+        assert!(!tokens.iter().any(|(text, _)| text.contains("_case_")));
+    }
+
+    #[test]
+    fn test_markdown_headings() {
+        let source = "# Guide\nintro\n## Install ##\n```sh\n# not a heading\n```\n### C#\n## Usage\ntext\n";
+        let headings: Vec<_> = TokenRules::Markdown
+            .symbols(source)
+            .into_iter()
+            .map(|heading| (heading.name, heading.line, heading.depth, heading.end_line))
+            .collect();
+        assert_eq!(
+            headings,
+            [
+                ("Guide".to_string(), 0, 0, 8),
+                ("Install".to_string(), 2, 1, 6),
+                ("C#".to_string(), 6, 2, 6),
+                ("Usage".to_string(), 7, 1, 8),
+            ]
+        );
+        let symbols = TokenRules::Markdown.symbols(source);
+        assert_eq!(DocumentSymbol::enclosing(&symbols, 6), Some(2));
+        assert_eq!(DocumentSymbol::enclosing(&symbols, 1), Some(0));
+    }
+}
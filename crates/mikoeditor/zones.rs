@@ -0,0 +1,160 @@
+use skia_safe::{Canvas, Font, Rect};
+
+use crate::markers::MarkerId;
+
+/// Content of a view zone, drawn in the space it takes under its line
+///
+/// Test results, blame annotations or diff blocks implement this to sit between the lines
+/// of a document without being part of its text.
+pub trait ZoneWidget {
+    /// Height the zone takes when it gets `width`, the width of the text area
+    fn height(&self, width: f32) -> f32;
+
+    /// Draw into `rect`; the canvas is clipped to the editor's content area
+    fn draw(&self, canvas: &Canvas, rect: Rect, font: &Font);
+
+    /// Click at `x`, `y` inside `rect`; clicks never reach the text behind a zone
+    fn on_click(&mut self, _x: f32, _y: f32, _rect: Rect) {}
+}
+
+/// A widget and the marker of the line it sits under
+pub(crate) struct ViewZone {
+    pub key: String,
+    /// End of the line, leaning left so typing there or breaking the line keeps the zone under it
+    pub anchor: MarkerId,
+    pub widget: Box<dyn ZoneWidget>,
+}
+
+/// View zones of one document, grouped by the key of whoever added them
+#[derive(Default)]
+pub(crate) struct ViewZones {
+    entries: Vec<ViewZone>,
+}
+
+impl ViewZones {
+    pub fn push(&mut self, zone: ViewZone) {
+        self.entries.push(zone);
+    }
+
+    /// Drop everything under `key` and return the anchors it held
+    pub fn remove(&mut self, key: &str) -> Vec<MarkerId> {
+        let (removed, kept) = std::mem::take(&mut self.entries).into_iter().partition(|zone| zone.key == key);
+        self.entries = kept;
+        removed.into_iter().map(|zone: ViewZone| zone.anchor).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Zones in the order they were added, which is how zones under the same line stack
+    pub fn iter(&self) -> impl Iterator<Item = &ViewZone> {
+        self.entries.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut ViewZone> {
+        self.entries.iter_mut()
+    }
+}
+
+/// Where lines of equal height sit once the zones between them take up space
+///
+/// Space under a line, such as that of a peek view or a view zone, pushes every later line
+/// down. Offsets are from the top of the document, before scrolling.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LineLayout {
+    line_height: f32,
+    /// Line each space is under and its height, in the order they stack
+    zones: Vec<(usize, f32)>,
+}
+
+impl LineLayout {
+    pub fn new(line_height: f32, zones: Vec<(usize, f32)>) -> Self {
+        Self { line_height, zones }
+    }
+
+    /// Top of a line, below the space under the lines before it
+    pub fn line_top(&self, line: usize) -> f32 {
+        let space: f32 = self.zones.iter().filter(|&&(after, _)| after < line).map(|&(_, height)| height).sum();
+        line as f32 * self.line_height + space
+    }
+
+    /// Top of the `index`th space given to `new`, below its line and the spaces before it there
+    pub fn zone_top(&self, index: usize) -> f32 {
+        let line = self.zones[index].0;
+        let stacked: f32 = self.zones[..index].iter().filter(|&&(after, _)| after == line).map(|&(_, height)| height).sum();
+        self.line_top(line) + self.line_height + stacked
+    }
+
+    /// Line at an offset; the space under a line belongs to that line
+    pub fn line_at(&self, y: f32) -> usize {
+        let mut zones = self.zones.clone();
+        zones.sort_by_key(|&(line, _)| line);
+        let mut y = y.max(0.0);
+        let mut first = 0;
+        for &(after, height) in &zones {
+            let lines_height = (after + 1).saturating_sub(first) as f32 * self.line_height;
+            if y < lines_height {
+                break;
+            }
+            y -= lines_height;
+            if y < height {
+                return after;
+            }
+            y -= height;
+            first = after + 1;
+        }
+        first + (y / self.line_height) as usize
+    }
+
+    /// Height of a document of `lines` lines, with the space under them
+    pub fn document_height(&self, lines: usize) -> f32 {
+        self.line_top(lines)
+    }
+
+    /// Scroll offset of a document without the space that puts the same line, as far into it, at the top
+    ///
+    /// For placing things that assume equal lines, like sticky scroll headers; within a space
+    /// the line above it stays fully scrolled in.
+    pub fn uniform_scroll(&self, scroll: f32) -> f32 {
+        let line = self.line_at(scroll);
+        line as f32 * self.line_height + (scroll - self.line_top(line)).clamp(0.0, self.line_height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn space_pushes_later_lines_down() {
+        let layout = LineLayout::new(20.0, vec![(5, 100.0), (2, 50.0)]);
+        assert_eq!(layout.line_top(2), 40.0);
+        assert_eq!(layout.line_top(3), 110.0);
+        assert_eq!(layout.line_top(6), 270.0);
+        assert_eq!(layout.document_height(10), 350.0);
+
+        assert_eq!(layout.line_at(39.0), 1);
+        // The space under line 2 belongs to it
+        assert_eq!(layout.line_at(75.0), 2);
+        assert_eq!(layout.line_at(110.0), 3);
+        assert_eq!(layout.line_at(269.0), 5);
+        assert_eq!(layout.line_at(290.0), 7);
+
+        assert_eq!(layout.uniform_scroll(30.0), 30.0);
+        assert_eq!(layout.uniform_scroll(90.0), 60.0);
+        assert_eq!(layout.uniform_scroll(115.0), 65.0);
+        assert_eq!(LineLayout::new(20.0, Vec::new()).line_at(45.0), 2);
+    }
+
+    #[test]
+    fn spaces_under_one_line_stack() {
+        let layout = LineLayout::new(20.0, vec![(3, 30.0), (1, 10.0), (3, 40.0)]);
+        assert_eq!(layout.zone_top(1), 40.0);
+        assert_eq!(layout.zone_top(0), 90.0);
+        assert_eq!(layout.zone_top(2), 120.0);
+        assert_eq!(layout.line_top(4), 160.0);
+        assert_eq!(layout.line_at(150.0), 3);
+        assert_eq!(layout.line_at(165.0), 4);
+    }
+}
@@ -4,10 +4,14 @@
 pub mod terminal;
 pub mod pty;
 pub mod renderer;
+pub mod shell_integration;
 
 pub use terminal::Terminal;
-pub use pty::PtySession;
+pub use pty::{MockPtySession, PtyOptions, PtySession};
 pub use renderer::TerminalRenderer;
+pub use shell_integration::CommandMark;
+
+use std::path::PathBuf;
 
 /// Terminal configuration
 #[derive(Debug, Clone)]
@@ -17,6 +21,12 @@ pub struct TerminalConfig {
     pub rows: u16,
     pub cols: u16,
     pub scrollback_limit: usize,
+    /// Working directory for the shell (defaults to the workspace root)
+    pub cwd: Option<PathBuf>,
+    /// Extra environment variables injected into the shell
+    pub env: Vec<(String, String)>,
+    /// Inject OSC 633 shell integration into supported shells
+    pub shell_integration: bool,
 }
 
 impl Default for TerminalConfig {
@@ -27,6 +37,9 @@ impl Default for TerminalConfig {
             rows: 24,
             cols: 80,
             scrollback_limit: 10000,
+            cwd: None,
+            env: Vec::new(),
+            shell_integration: true,
         }
     }
 }
@@ -44,4 +57,12 @@ impl TerminalConfig {
             std::env::var("SHELL").unwrap_or_else(|_| "bash".to_string())
         }
     }
+    
+    /// Resolve the shell working directory, falling back to the workspace root
+    pub fn working_directory(&self) -> PathBuf {
+        self.cwd
+            .clone()
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_else(|| PathBuf::from("."))
+    }
 }
@@ -1,39 +1,608 @@
-/// Simple PTY session wrapper
-/// This is a placeholder implementation for the terminal
-pub struct PtySession {
-    shell: String,
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+use crate::shell_integration;
+use crate::TerminalConfig;
+
+/// A pseudo terminal connected to a child shell process
+pub trait PtySession {
+    /// Write data to the PTY
+    fn write(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error>>;
+
+    /// Read available data from the PTY without blocking
+    fn read(&mut self) -> Result<Vec<u8>, Box<dyn std::error::Error>>;
+
+    /// Resize the PTY
+    fn resize(&mut self, rows: u16, cols: u16) -> Result<(), Box<dyn std::error::Error>>;
+
+    /// Get the shell name
+    fn shell(&self) -> &str;
+}
+
+/// Options used to spawn a PTY backend
+#[derive(Debug, Clone)]
+pub struct PtyOptions {
+    pub shell: String,
+    pub rows: u16,
+    pub cols: u16,
+    pub cwd: PathBuf,
+    pub env: Vec<(String, String)>,
 }
 
-impl PtySession {
-    /// Create a new PTY session with the given shell
-    pub fn new(shell: &str, _rows: u16, _cols: u16) -> Result<Self, Box<dyn std::error::Error>> {
-        println!("Terminal PTY created for shell: {}", shell);
+impl PtyOptions {
+    /// Build spawn options from a terminal config
+    pub fn from_config(config: &TerminalConfig) -> Self {
+        let mut env = if config.shell_integration {
+            shell_integration::injection_env(&config.shell)
+        } else {
+            Vec::new()
+        };
+        env.extend(config.env.iter().cloned());
         
-        Ok(Self {
-            shell: shell.to_string(),
-        })
+        Self {
+            shell: config.shell.clone(),
+            rows: config.rows,
+            cols: config.cols,
+            cwd: config.working_directory(),
+            env,
+        }
     }
-    
-    /// Write data to the PTY
-    pub fn write(&mut self, _data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
-        // Placeholder - would write to actual PTY
+}
+
+/// Spawn the native PTY backend for the current platform
+pub fn spawn(options: &PtyOptions) -> Result<Box<dyn PtySession>, Box<dyn std::error::Error>> {
+    #[cfg(target_os = "windows")]
+    {
+        Ok(Box::new(conpty::ConPtySession::new(options)?))
+    }
+
+    #[cfg(unix)]
+    {
+        Ok(Box::new(unix::UnixPtySession::new(options)?))
+    }
+
+    #[cfg(not(any(target_os = "windows", unix)))]
+    {
+        Ok(Box::new(MockPtySession::new(options)))
+    }
+}
+
+/// In-memory PTY backend for tests
+/// Written input is echoed back and output can be queued with `push_output`
+pub struct MockPtySession {
+    shell: String,
+    rows: u16,
+    cols: u16,
+    echo: bool,
+    written: Vec<u8>,
+    output: VecDeque<u8>,
+}
+
+impl MockPtySession {
+    pub fn new(options: &PtyOptions) -> Self {
+        Self {
+            shell: options.shell.clone(),
+            rows: options.rows,
+            cols: options.cols,
+            echo: true,
+            written: Vec::new(),
+            output: VecDeque::new(),
+        }
+    }
+
+    /// Enable or disable echoing written input back as output
+    pub fn set_echo(&mut self, echo: bool) {
+        self.echo = echo;
+    }
+
+    /// Queue data to be returned by the next read
+    pub fn push_output(&mut self, data: &[u8]) {
+        self.output.extend(data);
+    }
+
+    /// All data written to the session so far
+    pub fn written(&self) -> &[u8] {
+        &self.written
+    }
+
+    /// Current size as (rows, cols)
+    pub fn size(&self) -> (u16, u16) {
+        (self.rows, self.cols)
+    }
+}
+
+impl PtySession for MockPtySession {
+    fn write(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+        self.written.extend_from_slice(data);
+        if self.echo {
+            self.output.extend(data);
+        }
         Ok(())
     }
-    
-    /// Read available data from the PTY
-    pub fn read(&self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-        // Placeholder - would read from actual PTY
-        Ok(Vec::new())
+
+    fn read(&mut self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        Ok(self.output.drain(..).collect())
     }
-    
-    /// Resize the PTY
-    pub fn resize(&mut self, _rows: u16, _cols: u16) -> Result<(), Box<dyn std::error::Error>> {
-        // Placeholder - would resize actual PTY
+
+    fn resize(&mut self, rows: u16, cols: u16) -> Result<(), Box<dyn std::error::Error>> {
+        self.rows = rows;
+        self.cols = cols;
         Ok(())
     }
-    
-    /// Get the shell name
-    pub fn shell(&self) -> &str {
+
+    fn shell(&self) -> &str {
         &self.shell
     }
 }
+
+#[cfg(unix)]
+pub mod unix {
+    use super::{PtyOptions, PtySession};
+    use std::collections::VecDeque;
+    use std::ffi::{CString, OsStr, OsString};
+    use std::os::unix::ffi::{OsStrExt, OsStringExt};
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, Instant};
+
+    /// How long a closed shell gets to exit after SIGHUP before it is killed
+    const HANGUP_GRACE: Duration = Duration::from_secs(2);
+
+    /// PTY backend built on `forkpty`
+    pub struct UnixPtySession {
+        shell: String,
+        master: libc::c_int,
+        pid: libc::pid_t,
+        /// Input the PTY had no room for yet, written as the shell reads
+        pending: VecDeque<u8>,
+    }
+
+    fn winsize(rows: u16, cols: u16) -> libc::winsize {
+        libc::winsize {
+            ws_row: rows,
+            ws_col: cols,
+            ws_xpixel: 0,
+            ws_ypixel: 0,
+        }
+    }
+
+    /// The shell's executable, looked up on `PATH` unless it is a path already
+    fn resolve_shell(shell: &str, path: Option<&OsStr>) -> PathBuf {
+        if shell.contains('/') {
+            return PathBuf::from(shell);
+        }
+        path.into_iter()
+            .flat_map(std::env::split_paths)
+            .map(|dir| dir.join(shell))
+            .find(|candidate| is_executable(candidate))
+            .unwrap_or_else(|| PathBuf::from(shell))
+    }
+
+    fn is_executable(path: &Path) -> bool {
+        let Ok(path) = CString::new(path.as_os_str().as_bytes()) else {
+            return false;
+        };
+        unsafe { libc::access(path.as_ptr(), libc::X_OK) == 0 }
+    }
+
+    /// The child's `KEY=VALUE` environment: ours, with `TERM` and the options' variables over it
+    fn environment(options: &PtyOptions) -> Vec<(OsString, OsString)> {
+        let mut overrides = vec![(OsString::from("TERM"), OsString::from("xterm-256color"))];
+        overrides.extend(options.env.iter().map(|(key, value)| (OsString::from(key), OsString::from(value))));
+        let mut env: Vec<(OsString, OsString)> = std::env::vars_os()
+            .filter(|(key, _)| !overrides.iter().any(|(name, _)| name == key))
+            .collect();
+        env.extend(overrides);
+        env
+    }
+
+    impl UnixPtySession {
+        pub fn new(options: &PtyOptions) -> Result<Self, Box<dyn std::error::Error>> {
+            // Prepare everything the child needs before forking; between fork and exec it
+            // may only make async-signal-safe calls, which rules out allocating or setenv
+            let env = environment(options);
+            let path = env.iter().find(|(key, _)| key == "PATH").map(|(_, value)| value.as_os_str());
+            let program = CString::new(resolve_shell(&options.shell, path).into_os_string().into_vec())?;
+            let shell = CString::new(options.shell.as_str())?;
+            let argv = [shell.as_ptr(), std::ptr::null()];
+            let cwd = CString::new(options.cwd.as_os_str().as_bytes())?;
+            let env = env
+                .into_iter()
+                .map(|(key, value)| {
+                    let mut entry = key.into_vec();
+                    entry.push(b'=');
+                    entry.extend(value.into_vec());
+                    CString::new(entry)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let envp: Vec<*const libc::c_char> = env.iter().map(|entry| entry.as_ptr()).chain(Some(std::ptr::null())).collect();
+
+            let mut size = winsize(options.rows, options.cols);
+            let mut master: libc::c_int = -1;
+
+            let pid = unsafe {
+                libc::forkpty(&mut master, std::ptr::null_mut(), std::ptr::null_mut(), &mut size as *mut libc::winsize)
+            };
+
+            if pid < 0 {
+                return Err(std::io::Error::last_os_error().into());
+            }
+
+            if pid == 0 {
+                // Child: switch directory and exec the shell with its environment
+                unsafe {
+                    libc::chdir(cwd.as_ptr());
+                    libc::execve(program.as_ptr(), argv.as_ptr(), envp.as_ptr());
+                    libc::_exit(127);
+                }
+            }
+
+            // Parent: make the master side non-blocking so reads can be polled every frame
+            unsafe {
+                let flags = libc::fcntl(master, libc::F_GETFL);
+                libc::fcntl(master, libc::F_SETFL, flags | libc::O_NONBLOCK);
+            }
+
+            println!("Terminal PTY created for shell: {} (pid {})", options.shell, pid);
+
+            Ok(Self {
+                shell: options.shell.clone(),
+                master,
+                pid,
+                pending: VecDeque::new(),
+            })
+        }
+
+        /// Write queued input until the PTY is full; the rest waits for the next write or read
+        fn flush_pending(&mut self) -> std::io::Result<()> {
+            while !self.pending.is_empty() {
+                let (chunk, _) = self.pending.as_slices();
+                let result = unsafe { libc::write(self.master, chunk.as_ptr() as *const libc::c_void, chunk.len()) };
+                if result < 0 {
+                    let err = std::io::Error::last_os_error();
+                    if err.kind() == std::io::ErrorKind::WouldBlock {
+                        return Ok(());
+                    }
+                    self.pending.clear();
+                    return Err(err);
+                }
+                self.pending.drain(..result as usize);
+            }
+            Ok(())
+        }
+    }
+
+    impl PtySession for UnixPtySession {
+        fn write(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+            // A full PTY only drains as the shell reads, and a shell echoing input waits for us
+            // to read its output, so what doesn't fit is queued rather than retried here
+            self.pending.extend(data);
+            self.flush_pending()?;
+            Ok(())
+        }
+
+        fn read(&mut self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+            // Reads are polled every frame, which keeps queued input moving; a failed write
+            // shows up here as the read error or EIO too
+            let _ = self.flush_pending();
+            let mut output = Vec::new();
+            let mut buf = [0u8; 4096];
+            loop {
+                let result = unsafe {
+                    libc::read(self.master, buf.as_mut_ptr() as *mut libc::c_void, buf.len())
+                };
+                if result > 0 {
+                    output.extend_from_slice(&buf[..result as usize]);
+                    continue;
+                }
+                if result < 0 {
+                    let err = std::io::Error::last_os_error();
+                    // EIO means the shell exited; report what we have so far
+                    if err.kind() != std::io::ErrorKind::WouldBlock && output.is_empty() && err.raw_os_error() != Some(libc::EIO) {
+                        return Err(err.into());
+                    }
+                }
+                break;
+            }
+            Ok(output)
+        }
+
+        fn resize(&mut self, rows: u16, cols: u16) -> Result<(), Box<dyn std::error::Error>> {
+            let size = winsize(rows, cols);
+            let result = unsafe { libc::ioctl(self.master, libc::TIOCSWINSZ, &size) };
+            if result < 0 {
+                return Err(std::io::Error::last_os_error().into());
+            }
+            Ok(())
+        }
+
+        fn shell(&self) -> &str {
+            &self.shell
+        }
+    }
+
+    impl Drop for UnixPtySession {
+        fn drop(&mut self) {
+            unsafe {
+                libc::close(self.master);
+                libc::kill(self.pid, libc::SIGHUP);
+            }
+            // Reap off the UI thread: the shell gets a grace period to exit, then is killed
+            let pid = self.pid;
+            std::thread::spawn(move || {
+                let deadline = Instant::now() + HANGUP_GRACE;
+                while Instant::now() < deadline {
+                    if unsafe { libc::waitpid(pid, std::ptr::null_mut(), libc::WNOHANG) } != 0 {
+                        return;
+                    }
+                    std::thread::sleep(Duration::from_millis(20));
+                }
+                unsafe {
+                    libc::kill(pid, libc::SIGKILL);
+                    libc::waitpid(pid, std::ptr::null_mut(), 0);
+                }
+            });
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn options(shell: &str, env: Vec<(String, String)>) -> PtyOptions {
+            PtyOptions {
+                shell: shell.to_string(),
+                rows: 24,
+                cols: 80,
+                cwd: std::env::temp_dir(),
+                env,
+            }
+        }
+
+        /// Read until `done` holds for the output so far, or give up after a few seconds
+        fn read_until(session: &mut UnixPtySession, done: impl Fn(&UnixPtySession, &str) -> bool) -> String {
+            let mut output = Vec::new();
+            let deadline = Instant::now() + Duration::from_secs(5);
+            while !done(session, &String::from_utf8_lossy(&output)) && Instant::now() < deadline {
+                output.extend(session.read().unwrap());
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            String::from_utf8_lossy(&output).into_owned()
+        }
+
+        #[test]
+        fn child_gets_injected_environment() {
+            let env = vec![("MIKO_PTY_TEST".to_string(), "injected".to_string())];
+            let mut session = UnixPtySession::new(&options("env", env)).unwrap();
+            let has_vars = |output: &str| output.contains("MIKO_PTY_TEST=injected") && output.contains("TERM=xterm-256color");
+            let output = read_until(&mut session, |_, output| has_vars(output));
+            assert!(has_vars(&output));
+        }
+
+        #[test]
+        fn large_writes_queue_instead_of_blocking() {
+            let mut session = UnixPtySession::new(&options("cat", Vec::new())).unwrap();
+            let data = format!("{}\n", "x".repeat(99)).repeat(2000);
+            session.write(data.as_bytes()).unwrap();
+            // The PTY can't take 200 KB at once; the rest goes out as reads drain cat's output
+            assert!(!session.pending.is_empty());
+            let output = read_until(&mut session, |session, _| session.pending.is_empty());
+            assert!(session.pending.is_empty());
+            assert!(output.contains(&"x".repeat(99)));
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub mod conpty {
+    use super::{PtyOptions, PtySession};
+    use std::os::windows::ffi::OsStrExt;
+    use std::sync::mpsc::{self, Sender};
+    use windows::core::{PCWSTR, PWSTR};
+    use windows::Win32::Foundation::{CloseHandle, HANDLE};
+    use windows::Win32::Storage::FileSystem::{ReadFile, WriteFile};
+    use windows::Win32::System::Console::{
+        ClosePseudoConsole, CreatePseudoConsole, ResizePseudoConsole, COORD, HPCON,
+    };
+    use windows::Win32::System::Pipes::{CreatePipe, PeekNamedPipe};
+    use windows::Win32::System::Threading::{
+        CreateProcessW, DeleteProcThreadAttributeList, InitializeProcThreadAttributeList,
+        UpdateProcThreadAttribute, CREATE_UNICODE_ENVIRONMENT, EXTENDED_STARTUPINFO_PRESENT,
+        LPPROC_THREAD_ATTRIBUTE_LIST, PROCESS_INFORMATION, PROC_THREAD_ATTRIBUTE_PSEUDOCONSOLE,
+        STARTUPINFOEXW,
+    };
+
+    /// PTY backend built on the Windows pseudo console (ConPTY)
+    pub struct ConPtySession {
+        shell: String,
+        hpc: HPCON,
+        /// Input for the writer thread; `None` once dropped, which closes the pipe
+        input: Option<Sender<Vec<u8>>>,
+        output: HANDLE,
+        process: PROCESS_INFORMATION,
+    }
+
+    fn coord(rows: u16, cols: u16) -> COORD {
+        COORD {
+            X: cols as i16,
+            Y: rows as i16,
+        }
+    }
+
+    /// Build a UTF-16 environment block from the current environment plus injected variables
+    fn environment_block(extra: &[(String, String)]) -> Vec<u16> {
+        let mut vars: Vec<(String, String)> = std::env::vars()
+            .filter(|(key, _)| !extra.iter().any(|(k, _)| k.eq_ignore_ascii_case(key)))
+            .collect();
+        vars.extend(extra.iter().cloned());
+        vars.sort_by(|a, b| a.0.to_uppercase().cmp(&b.0.to_uppercase()));
+
+        let mut block = Vec::new();
+        for (key, value) in vars {
+            block.extend(format!("{}={}", key, value).encode_utf16());
+            block.push(0);
+        }
+        block.push(0);
+        block
+    }
+
+    /// Write input on a thread of its own: anonymous pipes can't be non-blocking, and a
+    /// child that stops reading would otherwise freeze the UI thread in `WriteFile`
+    fn spawn_writer(input: HANDLE) -> Sender<Vec<u8>> {
+        struct Pipe(HANDLE);
+        // Only the writer thread touches the handle once it is moved there
+        unsafe impl Send for Pipe {}
+
+        let pipe = Pipe(input);
+        let (sender, receiver) = mpsc::channel::<Vec<u8>>();
+        std::thread::spawn(move || {
+            let pipe = pipe;
+            'chunks: for chunk in receiver {
+                let mut written = 0;
+                while written < chunk.len() {
+                    let mut count = 0u32;
+                    // Fails once the pseudo console is closed, which also ends the thread
+                    if unsafe { WriteFile(pipe.0, Some(&chunk[written..]), Some(&mut count), None) }.is_err() {
+                        break 'chunks;
+                    }
+                    written += count as usize;
+                }
+            }
+            unsafe {
+                let _ = CloseHandle(pipe.0);
+            }
+        });
+        sender
+    }
+
+    impl ConPtySession {
+        pub fn new(options: &PtyOptions) -> Result<Self, Box<dyn std::error::Error>> {
+            unsafe {
+                // Pipes: we write to input_write, the console reads input_read (and vice versa)
+                let mut input_read = HANDLE::default();
+                let mut input_write = HANDLE::default();
+                let mut output_read = HANDLE::default();
+                let mut output_write = HANDLE::default();
+                CreatePipe(&mut input_read, &mut input_write, None, 0)?;
+                CreatePipe(&mut output_read, &mut output_write, None, 0)?;
+
+                let hpc = CreatePseudoConsole(
+                    coord(options.rows, options.cols),
+                    input_read,
+                    output_write,
+                    0,
+                )?;
+
+                // The pseudo console owns duplicates of these now
+                let _ = CloseHandle(input_read);
+                let _ = CloseHandle(output_write);
+
+                // Attach the pseudo console to the child process
+                let mut size = 0usize;
+                let _ = InitializeProcThreadAttributeList(None, 1, None, &mut size);
+                let mut attr_buffer = vec![0u8; size];
+                let attr_list = LPPROC_THREAD_ATTRIBUTE_LIST(attr_buffer.as_mut_ptr() as *mut _);
+                InitializeProcThreadAttributeList(Some(attr_list), 1, None, &mut size)?;
+                UpdateProcThreadAttribute(
+                    attr_list,
+                    0,
+                    PROC_THREAD_ATTRIBUTE_PSEUDOCONSOLE as usize,
+                    Some(hpc.0 as *const std::ffi::c_void),
+                    std::mem::size_of::<HPCON>(),
+                    None,
+                    None,
+                )?;
+
+                let mut startup_info = STARTUPINFOEXW::default();
+                startup_info.StartupInfo.cb = std::mem::size_of::<STARTUPINFOEXW>() as u32;
+                startup_info.lpAttributeList = attr_list;
+
+                let mut command_line: Vec<u16> = options.shell.encode_utf16().chain(Some(0)).collect();
+                let cwd: Vec<u16> = options.cwd.as_os_str().encode_wide().chain(Some(0)).collect();
+                let env = environment_block(&options.env);
+                let mut process = PROCESS_INFORMATION::default();
+
+                let result = CreateProcessW(
+                    PCWSTR::null(),
+                    Some(PWSTR(command_line.as_mut_ptr())),
+                    None,
+                    None,
+                    false,
+                    EXTENDED_STARTUPINFO_PRESENT | CREATE_UNICODE_ENVIRONMENT,
+                    Some(env.as_ptr() as *const std::ffi::c_void),
+                    PCWSTR(cwd.as_ptr()),
+                    &startup_info.StartupInfo,
+                    &mut process,
+                );
+
+                DeleteProcThreadAttributeList(attr_list);
+
+                if let Err(e) = result {
+                    ClosePseudoConsole(hpc);
+                    let _ = CloseHandle(input_write);
+                    let _ = CloseHandle(output_read);
+                    return Err(e.into());
+                }
+
+                println!("Terminal ConPTY created for shell: {} (pid {})", options.shell, process.dwProcessId);
+
+                Ok(Self {
+                    shell: options.shell.clone(),
+                    hpc,
+                    input: Some(spawn_writer(input_write)),
+                    output: output_read,
+                    process,
+                })
+            }
+        }
+    }
+
+    impl PtySession for ConPtySession {
+        fn write(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+            let input = self.input.as_ref().ok_or("terminal input is closed")?;
+            input.send(data.to_vec()).map_err(|_| "terminal input is closed")?;
+            Ok(())
+        }
+
+        fn read(&mut self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+            let mut available = 0u32;
+            unsafe {
+                // Anonymous pipes can't be non-blocking, so only read what is already buffered
+                PeekNamedPipe(self.output, None, 0, None, Some(&mut available), None)?;
+                if available == 0 {
+                    return Ok(Vec::new());
+                }
+
+                let mut buf = vec![0u8; available as usize];
+                let mut count = 0u32;
+                ReadFile(self.output, Some(&mut buf), Some(&mut count), None)?;
+                buf.truncate(count as usize);
+                Ok(buf)
+            }
+        }
+
+        fn resize(&mut self, rows: u16, cols: u16) -> Result<(), Box<dyn std::error::Error>> {
+            unsafe {
+                ResizePseudoConsole(self.hpc, coord(rows, cols))?;
+            }
+            Ok(())
+        }
+
+        fn shell(&self) -> &str {
+            &self.shell
+        }
+    }
+
+    impl Drop for ConPtySession {
+        fn drop(&mut self) {
+            // The writer closes the input pipe after what is queued, or as soon as a write fails
+            self.input = None;
+            unsafe {
+                // Closing the pseudo console terminates the attached shell
+                ClosePseudoConsole(self.hpc);
+                let _ = CloseHandle(self.output);
+                let _ = CloseHandle(self.process.hThread);
+                let _ = CloseHandle(self.process.hProcess);
+            }
+        }
+    }
+}
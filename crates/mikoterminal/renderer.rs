@@ -1,6 +1,11 @@
 use crate::terminal::{Terminal, Cell};
+use crate::shell_integration::CommandMark;
 use skia_safe::{Canvas, Color, Paint, Rect, Font, Typeface, FontStyle, FontMgr};
 
+const SUCCESS_MARK_COLOR: Color = Color::from_rgb(55, 148, 255);
+const FAILED_MARK_COLOR: Color = Color::from_rgb(241, 76, 76);
+const PENDING_MARK_COLOR: Color = Color::from_rgb(128, 128, 128);
+
 /// Terminal renderer
 pub struct TerminalRenderer {
     font_size: f32,
@@ -32,8 +37,13 @@ impl TerminalRenderer {
     
     /// Render terminal to canvas
     pub fn render(&self, terminal: &Terminal, canvas: &Canvas, x: f32, y: f32) {
-        let buffer = terminal.buffer();
-        let (cursor_row, cursor_col) = terminal.cursor_position();
+        let lines = terminal.visible_lines();
+        // The cursor belongs to the live screen, hide it while scrolled back
+        let cursor = if terminal.scroll_offset() == 0 {
+            Some(terminal.cursor_position())
+        } else {
+            None
+        };
         
         // Create font
         let font = if let Some(ref typeface) = self.typeface {
@@ -43,7 +53,7 @@ impl TerminalRenderer {
         };
         
         // Render each cell
-        for (row_idx, row) in buffer.iter().enumerate() {
+        for (row_idx, row) in lines.iter().enumerate() {
             for (col_idx, cell) in row.iter().enumerate() {
                 let cell_x = x + (col_idx as f32 * self.cell_width);
                 let cell_y = y + (row_idx as f32 * self.cell_height);
@@ -85,7 +95,7 @@ impl TerminalRenderer {
                 }
                 
                 // Draw cursor
-                if row_idx == cursor_row && col_idx == cursor_col {
+                if cursor == Some((row_idx, col_idx)) {
                     let mut cursor_paint = Paint::default();
                     cursor_paint.set_color(Color::from_rgb(255, 255, 255));
                     cursor_paint.set_style(skia_safe::PaintStyle::Stroke);
@@ -98,6 +108,89 @@ impl TerminalRenderer {
         }
     }
     
+    /// Render shell integration marks in the gutter left of `x`
+    pub fn render_command_marks(&self, terminal: &Terminal, canvas: &Canvas, x: f32, y: f32) {
+        let first_visible = terminal.first_visible_line();
+        let rows = terminal.visible_lines().len();
+        let selected = terminal.selected_command();
+        
+        for (index, mark) in terminal.commands().iter().enumerate() {
+            if mark.prompt_line < first_visible || mark.prompt_line >= first_visible + rows {
+                continue;
+            }
+            
+            let row_y = y + (mark.prompt_line - first_visible) as f32 * self.cell_height;
+            
+            // Highlight the command selected by navigation
+            if selected == Some(index) {
+                let mut highlight_paint = Paint::default();
+                highlight_paint.set_color(Color::from_argb(40, 255, 255, 255));
+                highlight_paint.set_anti_alias(true);
+                
+                let width = terminal.size().1 as f32 * self.cell_width;
+                canvas.draw_rect(Rect::from_xywh(x, row_y, width, self.cell_height), &highlight_paint);
+            }
+            
+            let mut mark_paint = Paint::default();
+            mark_paint.set_color(Self::mark_color(mark));
+            mark_paint.set_anti_alias(true);
+            
+            canvas.draw_circle((x - 8.0, row_y + self.cell_height / 2.0), 3.0, &mark_paint);
+        }
+    }
+    
+    /// Render the overview ruler (scrollbar) with failed commands in red
+    pub fn render_overview_ruler(&self, terminal: &Terminal, canvas: &Canvas, rect: Rect) {
+        let first_line = terminal.first_line();
+        let total = (terminal.total_lines() - first_line).max(1) as f32;
+        
+        // Track
+        let mut track_paint = Paint::default();
+        track_paint.set_color(Color::from_argb(20, 255, 255, 255));
+        track_paint.set_anti_alias(true);
+        canvas.draw_rect(rect, &track_paint);
+        
+        // Viewport thumb
+        let rows = terminal.visible_lines().len() as f32;
+        let thumb_top = (terminal.first_visible_line() - first_line) as f32 / total * rect.height();
+        let thumb_height = (rows / total * rect.height()).max(8.0);
+        
+        let mut thumb_paint = Paint::default();
+        thumb_paint.set_color(Color::from_argb(60, 255, 255, 255));
+        thumb_paint.set_anti_alias(true);
+        canvas.draw_rect(
+            Rect::from_xywh(rect.left, rect.top + thumb_top, rect.width(), thumb_height),
+            &thumb_paint,
+        );
+        
+        self.render_failed_marks(terminal, canvas, rect);
+    }
+    
+    /// Mark failed commands in red along a scrollbar track
+    pub fn render_failed_marks(&self, terminal: &Terminal, canvas: &Canvas, rect: Rect) {
+        let first_line = terminal.first_line();
+        let total = (terminal.total_lines() - first_line).max(1) as f32;
+        
+        let mut failed_paint = Paint::default();
+        failed_paint.set_color(FAILED_MARK_COLOR);
+        failed_paint.set_anti_alias(true);
+        
+        for mark in terminal.commands().iter().filter(|m| m.is_failed()) {
+            let mark_y = rect.top + (mark.prompt_line - first_line) as f32 / total * rect.height();
+            canvas.draw_rect(Rect::from_xywh(rect.left, mark_y, rect.width(), 2.0), &failed_paint);
+        }
+    }
+    
+    fn mark_color(mark: &CommandMark) -> Color {
+        if mark.is_failed() {
+            FAILED_MARK_COLOR
+        } else if mark.is_finished() {
+            SUCCESS_MARK_COLOR
+        } else {
+            PENDING_MARK_COLOR
+        }
+    }
+    
     /// Get cell dimensions
     pub fn cell_size(&self) -> (f32, f32) {
         (self.cell_width, self.cell_height)
@@ -0,0 +1,144 @@
+// Shell integration (OSC 633 / OSC 133) support
+// Shells emit these sequences around prompts and commands so the terminal
+// knows where each command starts, where its output begins and how it exited
+
+/// A command tracked through shell integration marks
+/// Line numbers are absolute (scrollback lines included)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandMark {
+    pub prompt_line: usize,
+    pub command_line: Option<usize>,
+    pub output_line: Option<usize>,
+    pub end_line: Option<usize>,
+    pub exit_code: Option<i32>,
+    pub command: Option<String>,
+}
+
+impl CommandMark {
+    pub fn new(prompt_line: usize) -> Self {
+        Self {
+            prompt_line,
+            command_line: None,
+            output_line: None,
+            end_line: None,
+            exit_code: None,
+            command: None,
+        }
+    }
+
+    /// Whether the command has finished
+    pub fn is_finished(&self) -> bool {
+        self.end_line.is_some()
+    }
+
+    /// Whether the command finished with a non-zero exit code
+    pub fn is_failed(&self) -> bool {
+        matches!(self.exit_code, Some(code) if code != 0)
+    }
+}
+
+/// Shell integration event decoded from an OSC payload
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShellIntegrationEvent {
+    /// `A` - prompt is about to be drawn
+    PromptStart,
+    /// `B` - prompt ended, user is typing the command
+    CommandStart,
+    /// `C` - command was submitted and output begins
+    CommandExecuted,
+    /// `D[;exit]` - command finished
+    CommandFinished(Option<i32>),
+    /// `E;cmdline` - explicit command line (OSC 633 only)
+    CommandLine(String),
+    /// `P;Key=Value` - shell property such as `Cwd` (OSC 633 only)
+    Property(String, String),
+}
+
+/// Parse the payload of an OSC sequence (without the `ESC ]` prefix and terminator)
+pub fn parse_osc(payload: &str) -> Option<ShellIntegrationEvent> {
+    let mut parts = payload.splitn(3, ';');
+    let code = parts.next()?;
+    if code != "633" && code != "133" {
+        return None;
+    }
+
+    let kind = parts.next()?;
+    let rest = parts.next();
+
+    match kind {
+        "A" => Some(ShellIntegrationEvent::PromptStart),
+        "B" => Some(ShellIntegrationEvent::CommandStart),
+        "C" => Some(ShellIntegrationEvent::CommandExecuted),
+        "D" => {
+            // 133 may append extra `;key=value` params after the exit code
+            let exit_code = rest
+                .and_then(|r| r.split(';').next())
+                .and_then(|c| c.trim().parse::<i32>().ok());
+            Some(ShellIntegrationEvent::CommandFinished(exit_code))
+        }
+        "E" if code == "633" => {
+            let command = rest.unwrap_or("");
+            // Drop the optional nonce after the command line
+            let command = command.split(';').next().unwrap_or("");
+            Some(ShellIntegrationEvent::CommandLine(unescape(command)))
+        }
+        "P" if code == "633" => {
+            let (key, value) = rest?.split_once('=')?;
+            Some(ShellIntegrationEvent::Property(key.to_string(), unescape(value)))
+        }
+        _ => None,
+    }
+}
+
+/// Decode `\xHH` and `\\` escapes used by OSC 633 values
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            result.push(ch);
+            continue;
+        }
+        match chars.peek() {
+            Some('\\') => {
+                chars.next();
+                result.push('\\');
+            }
+            Some('x') => {
+                chars.next();
+                let hex: String = chars.by_ref().take(2).collect();
+                match u8::from_str_radix(&hex, 16) {
+                    Ok(byte) => result.push(byte as char),
+                    Err(_) => {
+                        result.push_str("\\x");
+                        result.push_str(&hex);
+                    }
+                }
+            }
+            _ => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+/// Environment variables that enable shell integration for supported shells
+pub fn injection_env(shell: &str) -> Vec<(String, String)> {
+    let name = std::path::Path::new(shell)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+
+    match name.as_str() {
+        "bash" => vec![
+            (
+                "PROMPT_COMMAND".to_string(),
+                r#"printf '\033]633;D;%s\007\033]633;A\007' "$?""#.to_string(),
+            ),
+            ("PS0".to_string(), r"\e]633;C\a".to_string()),
+        ],
+        _ => Vec::new(),
+    }
+}
@@ -1,5 +1,8 @@
-use crate::{PtySession, TerminalConfig};
+use crate::pty::{self, PtyOptions, PtySession};
+use crate::shell_integration::{self, CommandMark, ShellIntegrationEvent};
+use crate::TerminalConfig;
 use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
 
 /// Terminal cell
 #[derive(Debug, Clone)]
@@ -25,15 +28,34 @@ impl Default for Cell {
     }
 }
 
+/// Maximum length of an OSC payload before it is discarded
+const MAX_OSC_LEN: usize = 4096;
+
+/// Escape sequence parser state
+#[derive(Debug, Clone, PartialEq)]
+enum ParseState {
+    Ground,
+    Escape,
+    Csi,
+    Osc(String),
+    OscEscape(String),
+}
+
 /// Terminal buffer
 pub struct Terminal {
     config: TerminalConfig,
-    pty: Option<PtySession>,
+    pty: Option<Box<dyn PtySession>>,
     buffer: Vec<Vec<Cell>>,
     scrollback: VecDeque<Vec<Cell>>,
     cursor_row: usize,
     cursor_col: usize,
     scroll_offset: usize,
+    /// Lines dropped from the front of the scrollback (keeps absolute line numbers stable)
+    scrollback_dropped: usize,
+    parse_state: ParseState,
+    commands: Vec<CommandMark>,
+    selected_command: Option<usize>,
+    cwd: Option<PathBuf>,
 }
 
 impl Terminal {
@@ -52,32 +74,29 @@ impl Terminal {
             cursor_row: 0,
             cursor_col: 0,
             scroll_offset: 0,
+            scrollback_dropped: 0,
+            parse_state: ParseState::Ground,
+            commands: Vec::new(),
+            selected_command: None,
+            cwd: None,
         }
     }
     
-    /// Start the terminal with PTY
+    /// Start the terminal with the native PTY backend
     pub fn start(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        let pty = PtySession::new(
-            &self.config.shell,
-            self.config.rows,
-            self.config.cols,
-        )?;
-        
-        self.pty = Some(pty);
-        
-        // Show welcome message
-        let welcome = format!(
-            "Rabital Terminal\r\nShell: {}\r\n\r\nTerminal integration coming soon...\r\n\r\n",
-            self.config.shell
-        );
-        self.process_output(welcome.as_bytes());
-        
+        let pty = pty::spawn(&PtyOptions::from_config(&self.config))?;
+        self.start_with(pty);
         Ok(())
     }
     
+    /// Start the terminal with an existing PTY session (e.g. a mock backend)
+    pub fn start_with(&mut self, pty: Box<dyn PtySession>) {
+        self.pty = Some(pty);
+    }
+    
     /// Update terminal - read from PTY and update buffer
     pub fn update(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        if let Some(ref pty) = self.pty {
+        if let Some(ref mut pty) = self.pty {
             let data = pty.read()?;
             if !data.is_empty() {
                 self.process_output(&data);
@@ -88,32 +107,113 @@ impl Terminal {
     
     /// Process output from PTY
     fn process_output(&mut self, data: &[u8]) {
-        // Simple text processing (no ANSI escape codes for now)
+        // Only OSC shell-integration sequences are interpreted, other escapes are skipped
         let text = String::from_utf8_lossy(data);
         
         for ch in text.chars() {
-            match ch {
-                '\n' => {
-                    self.cursor_col = 0;
-                    self.cursor_row += 1;
-                    if self.cursor_row >= self.buffer.len() {
-                        self.scroll_up();
+            match std::mem::replace(&mut self.parse_state, ParseState::Ground) {
+                ParseState::Ground => self.process_char(ch),
+                ParseState::Escape => match ch {
+                    '[' => self.parse_state = ParseState::Csi,
+                    ']' => self.parse_state = ParseState::Osc(String::new()),
+                    _ => {}
+                },
+                ParseState::Csi => {
+                    // Skip parameters until the final byte
+                    if !('\u{40}'..='\u{7e}').contains(&ch) {
+                        self.parse_state = ParseState::Csi;
                     }
                 }
-                '\r' => {
-                    self.cursor_col = 0;
+                ParseState::Osc(mut payload) => match ch {
+                    '\u{07}' => self.dispatch_osc(&payload),
+                    '\u{1b}' => self.parse_state = ParseState::OscEscape(payload),
+                    _ => {
+                        if payload.len() < MAX_OSC_LEN {
+                            payload.push(ch);
+                        }
+                        self.parse_state = ParseState::Osc(payload);
+                    }
+                },
+                ParseState::OscEscape(payload) => {
+                    // ESC \ (string terminator) ends the OSC
+                    self.dispatch_osc(&payload);
                 }
-                '\t' => {
-                    // Tab = 4 spaces
-                    for _ in 0..4 {
-                        self.put_char(' ');
+            }
+        }
+    }
+    
+    /// Handle a character outside of any escape sequence
+    fn process_char(&mut self, ch: char) {
+        match ch {
+            '\u{1b}' => {
+                self.parse_state = ParseState::Escape;
+            }
+            '\n' => {
+                self.cursor_col = 0;
+                self.cursor_row += 1;
+                if self.cursor_row >= self.buffer.len() {
+                    self.scroll_up();
+                }
+            }
+            '\r' => {
+                self.cursor_col = 0;
+            }
+            '\t' => {
+                // Tab = 4 spaces
+                for _ in 0..4 {
+                    self.put_char(' ');
+                }
+            }
+            ch if ch.is_control() => {
+                // Ignore other control characters for now
+            }
+            ch => {
+                self.put_char(ch);
+            }
+        }
+    }
+    
+    /// Apply a shell integration OSC sequence
+    fn dispatch_osc(&mut self, payload: &str) {
+        let Some(event) = shell_integration::parse_osc(payload) else {
+            return;
+        };
+        let line = self.absolute_cursor_line();
+        
+        match event {
+            ShellIntegrationEvent::PromptStart => {
+                // A prompt that never ran a command (empty input) is not worth a mark
+                if self.commands.last().is_some_and(|m| m.output_line.is_none()) {
+                    self.commands.pop();
+                }
+                self.commands.push(CommandMark::new(line));
+            }
+            ShellIntegrationEvent::CommandStart => {
+                if let Some(mark) = self.commands.last_mut() {
+                    mark.command_line = Some(line);
+                }
+            }
+            ShellIntegrationEvent::CommandExecuted => {
+                if let Some(mark) = self.commands.last_mut() {
+                    mark.output_line = Some(line);
+                }
+            }
+            ShellIntegrationEvent::CommandFinished(exit_code) => {
+                if let Some(mark) = self.commands.last_mut() {
+                    if mark.output_line.is_some() && !mark.is_finished() {
+                        mark.end_line = Some(line);
+                        mark.exit_code = exit_code;
                     }
                 }
-                ch if ch.is_control() => {
-                    // Ignore other control characters for now
+            }
+            ShellIntegrationEvent::CommandLine(command) => {
+                if let Some(mark) = self.commands.last_mut() {
+                    mark.command = Some(command);
                 }
-                ch => {
-                    self.put_char(ch);
+            }
+            ShellIntegrationEvent::Property(key, value) => {
+                if key == "Cwd" {
+                    self.cwd = Some(PathBuf::from(value));
                 }
             }
         }
@@ -140,9 +240,24 @@ impl Terminal {
         if let Some(first_line) = self.buffer.first().cloned() {
             self.scrollback.push_back(first_line);
             
+            // Keep the view steady while the user is scrolled back
+            if self.scroll_offset > 0 {
+                self.scroll_offset += 1;
+            }
+            
             // Limit scrollback
             while self.scrollback.len() > self.config.scrollback_limit {
                 self.scrollback.pop_front();
+                self.scrollback_dropped += 1;
+            }
+            self.scroll_offset = self.scroll_offset.min(self.scrollback.len());
+            
+            // Forget commands that scrolled out of the scrollback
+            let first_line = self.scrollback_dropped;
+            let before = self.commands.len();
+            self.commands.retain(|m| m.prompt_line >= first_line);
+            if self.commands.len() != before {
+                self.selected_command = None;
             }
         }
         
@@ -170,6 +285,116 @@ impl Terminal {
         (self.cursor_row, self.cursor_col)
     }
     
+    /// Absolute line number of the cursor (scrollback included)
+    fn absolute_cursor_line(&self) -> usize {
+        self.scrollback_dropped + self.scrollback.len() + self.cursor_row
+    }
+    
+    /// Absolute line number of the oldest line still in scrollback
+    pub fn first_line(&self) -> usize {
+        self.scrollback_dropped
+    }
+    
+    /// Absolute line number one past the last line
+    pub fn total_lines(&self) -> usize {
+        self.scrollback_dropped + self.scrollback.len() + self.buffer.len()
+    }
+    
+    /// Absolute line number of the top visible row
+    pub fn first_visible_line(&self) -> usize {
+        self.scrollback_dropped + self.scrollback.len() - self.scroll_offset
+    }
+    
+    /// Number of lines scrolled back from the bottom
+    pub fn scroll_offset(&self) -> usize {
+        self.scroll_offset
+    }
+    
+    /// Rows currently visible, taking the scroll offset into account
+    pub fn visible_lines(&self) -> Vec<&[Cell]> {
+        let start = self.scrollback.len() - self.scroll_offset;
+        self.scrollback
+            .range(start..)
+            .chain(self.buffer.iter())
+            .take(self.buffer.len())
+            .map(|line| line.as_slice())
+            .collect()
+    }
+    
+    /// Scroll so that the given absolute line is at the top of the view
+    pub fn scroll_to_line(&mut self, line: usize) {
+        let live_start = self.scrollback_dropped + self.scrollback.len();
+        self.scroll_offset = live_start.saturating_sub(line).min(self.scrollback.len());
+    }
+    
+    /// Scroll back to the live screen
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll_offset = 0;
+    }
+    
+    /// Commands tracked through shell integration
+    pub fn commands(&self) -> &[CommandMark] {
+        &self.commands
+    }
+    
+    /// Index of the command selected by command navigation
+    pub fn selected_command(&self) -> Option<usize> {
+        self.selected_command
+    }
+    
+    /// Working directory reported by the shell (OSC 633 ; P ; Cwd=...)
+    pub fn current_directory(&self) -> Option<&Path> {
+        self.cwd.as_deref()
+    }
+    
+    /// Jump to the previous command, returns false if there is none
+    pub fn previous_command(&mut self) -> bool {
+        let index = match self.selected_command {
+            Some(index) => index.checked_sub(1),
+            None => {
+                let cursor_line = self.absolute_cursor_line();
+                self.commands
+                    .iter()
+                    .rposition(|m| m.output_line.is_some() && m.prompt_line < cursor_line)
+            }
+        };
+        
+        match index {
+            Some(index) => {
+                self.select_command(index);
+                true
+            }
+            None => false,
+        }
+    }
+    
+    /// Jump to the next command, returning to the live screen after the last one
+    pub fn next_command(&mut self) -> bool {
+        match self.selected_command {
+            Some(index) if index + 1 < self.commands.len() => {
+                self.select_command(index + 1);
+                true
+            }
+            Some(_) => {
+                self.selected_command = None;
+                self.scroll_to_bottom();
+                true
+            }
+            None => false,
+        }
+    }
+    
+    fn select_command(&mut self, index: usize) {
+        self.selected_command = Some(index);
+        let line = self.commands[index].prompt_line;
+        self.scroll_to_line(line);
+    }
+    
+    /// Get terminal size as (rows, cols)
+    pub fn size(&self) -> (u16, u16) {
+        (self.config.rows, self.config.cols)
+    }
+    
     /// Resize terminal
     pub fn resize(&mut self, rows: u16, cols: u16) -> Result<(), Box<dyn std::error::Error>> {
         self.config.rows = rows;
@@ -180,6 +405,8 @@ impl Terminal {
         for row in &mut self.buffer {
             row.resize(cols as usize, Cell::default());
         }
+        self.cursor_row = self.cursor_row.min((rows as usize).saturating_sub(1));
+        self.cursor_col = self.cursor_col.min((cols as usize).saturating_sub(1));
         
         // Resize PTY
         if let Some(ref mut pty) = self.pty {
@@ -189,3 +416,62 @@ impl Terminal {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MockPtySession;
+
+    fn mock_terminal() -> Terminal {
+        let config = TerminalConfig::default();
+        let mut terminal = Terminal::new(config.clone());
+        terminal.start_with(Box::new(MockPtySession::new(&PtyOptions::from_config(&config))));
+        terminal
+    }
+
+    #[test]
+    fn test_mock_pty_echo() {
+        let mut terminal = mock_terminal();
+        terminal.send_input("ls").unwrap();
+        terminal.update().unwrap();
+
+        let line: String = terminal.buffer()[0].iter().take(2).map(|c| c.ch).collect();
+        assert_eq!(line, "ls");
+        assert_eq!(terminal.cursor_position(), (0, 2));
+    }
+
+    #[test]
+    fn test_resize_clamps_cursor() {
+        let mut terminal = mock_terminal();
+        terminal.send_input("\n\n\nabc").unwrap();
+        terminal.update().unwrap();
+        terminal.resize(2, 2).unwrap();
+
+        assert_eq!(terminal.size(), (2, 2));
+        assert_eq!(terminal.cursor_position(), (1, 1));
+    }
+
+    #[test]
+    fn test_shell_integration_marks() {
+        let mut terminal = mock_terminal();
+        terminal.send_input("\x1b]633;A\x07$ \x1b]633;B\x07false\n\x1b]633;C\x07").unwrap();
+        terminal.send_input("\x1b]133;D;1\x07\x1b]133;A\x07$ ").unwrap();
+        terminal.update().unwrap();
+
+        let commands = terminal.commands();
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0].prompt_line, 0);
+        assert_eq!(commands[0].output_line, Some(1));
+        assert!(commands[0].is_failed());
+        assert!(!commands[1].is_finished());
+
+        let line: String = terminal.buffer()[0].iter().take(7).map(|c| c.ch).collect();
+        assert_eq!(line, "$ false");
+
+        assert!(terminal.previous_command());
+        assert_eq!(terminal.selected_command(), Some(0));
+        assert!(terminal.next_command());
+        assert!(terminal.next_command());
+        assert_eq!(terminal.selected_command(), None);
+    }
+}
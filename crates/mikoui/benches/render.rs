@@ -0,0 +1,131 @@
+//! Widget hit-testing, full-frame paint and the present copies, rendered headless
+//!
+//! Run with `cargo bench -p mikoui`; `scripts/bench.ps1` compares against a saved baseline.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use mikoui::components::{
+    widget_at, Alert, AlertVariant, Badge, Button, Card, Checkbox, Input, ProgressBar, Separator, Slider, Widget,
+};
+use mikoui::{copy_changed_to_buffer, copy_to_buffer, get_theme_color, FontManager, HeadlessFrame, Variant};
+
+const WIDTH: i32 = 1200;
+const HEIGHT: i32 = 800;
+
+/// A dashboard-like screen of cards, each holding the common components
+fn demo_widgets() -> Vec<Box<dyn Widget>> {
+    let mut widgets: Vec<Box<dyn Widget>> = Vec::new();
+    for row in 0..3 {
+        for column in 0..4 {
+            let x = 24.0 + column as f32 * 292.0;
+            let y = 24.0 + row as f32 * 256.0;
+            let variant = [Variant::Default, Variant::Secondary, Variant::Outline, Variant::Ghost][column];
+            let mut progress = ProgressBar::new(x + 16.0, y + 176.0, 236.0);
+            progress.set_progress(0.25 * (column + 1) as f32);
+            widgets.push(Box::new(Card::new(x, y, 268.0, 232.0)));
+            widgets.push(Box::new(Badge::new(x + 16.0, y + 16.0, "New")));
+            widgets.push(Box::new(Input::new(x + 16.0, y + 48.0, 236.0, "Search components...")));
+            widgets.push(Box::new(Checkbox::new(x + 16.0, y + 96.0, "Remember me")));
+            widgets.push(Box::new(Slider::new(x + 16.0, y + 128.0, 236.0, "Volume", 0.5)));
+            widgets.push(Box::new(progress));
+            widgets.push(Box::new(Separator::new(x + 16.0, y + 196.0, 236.0)));
+            widgets.push(Box::new(Button::new(x + 16.0, y + 204.0, 112.0, "Continue").variant(variant)));
+        }
+    }
+    widgets.push(Box::new(
+        Alert::new(24.0, 24.0 + 3.0 * 256.0 - 8.0, 1144.0, "Heads up!")
+            .variant(AlertVariant::Info)
+            .description("Benchmarks draw the same frame the window would, without a window."),
+    ));
+    widgets
+}
+
+/// Points spread over the window, like mouse moves across the screen
+fn sample_points() -> Vec<(f32, f32)> {
+    (0..200)
+        .map(|i| ((i * 37 % WIDTH) as f32, (i * 53 % HEIGHT) as f32))
+        .collect()
+}
+
+fn hit_testing(c: &mut Criterion) {
+    let mut widgets = demo_widgets();
+    let points = sample_points();
+    let mut group = c.benchmark_group("hit_testing");
+    
+    group.bench_function("topmost_widget_at", |b| {
+        b.iter(|| {
+            for &(x, y) in &points {
+                black_box(widget_at(&widgets, x, y));
+            }
+        })
+    });
+    
+    group.bench_function("update_hover_all", |b| {
+        b.iter(|| {
+            for &(x, y) in &points {
+                for widget in widgets.iter_mut() {
+                    widget.update_hover(x, y);
+                }
+            }
+        })
+    });
+    
+    group.finish();
+}
+
+fn paint(c: &mut Criterion) {
+    let widgets = demo_widgets();
+    let mut font_manager = FontManager::new();
+    let mut frame = HeadlessFrame::new(WIDTH, HEIGHT).expect("raster surface");
+    let mut group = c.benchmark_group("paint");
+    group.sample_size(30);
+    
+    group.bench_function("demo_frame_1200x800", |b| {
+        b.iter(|| {
+            let canvas = frame.canvas();
+            canvas.clear(get_theme_color(|t| t.background));
+            for widget in &widgets {
+                widget.draw(canvas, &mut font_manager);
+            }
+        })
+    });
+    
+    group.bench_function("demo_frame_and_present_1200x800", |b| {
+        b.iter(|| {
+            let canvas = frame.canvas();
+            canvas.clear(get_theme_color(|t| t.background));
+            for widget in &widgets {
+                widget.draw(canvas, &mut font_manager);
+            }
+            black_box(frame.present());
+        })
+    });
+    
+    group.finish();
+}
+
+fn present_copy(c: &mut Criterion) {
+    let mut group = c.benchmark_group("present");
+    for (width, height) in [(1200, 800), (1920, 1080), (3840, 2160)] {
+        let src: Vec<u8> = (0..width * height * 4).map(|i| i as u8).collect();
+        let mut dst = vec![0u32; width * height];
+        group.bench_function(format!("copy_to_buffer_{}x{}", width, height), |b| {
+            b.iter(|| copy_to_buffer(black_box(&src), black_box(&mut dst)))
+        });
+        // A caret blink: one small area differs from the frame on screen
+        group.bench_function(format!("copy_changed_to_buffer_{}x{}", width, height), |b| {
+            b.iter_batched_ref(
+                || {
+                    let mut previous = dst.clone();
+                    previous[height / 2 * width + width / 2] ^= 1;
+                    previous
+                },
+                |previous| copy_changed_to_buffer(black_box(&src), previous, width),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, hit_testing, paint, present_copy);
+criterion_main!(benches);
@@ -12,9 +12,8 @@ fn generate_icons(icons_dir: &str, output_file: &str, struct_name: &str, out_dir
     writeln!(f, "").unwrap();
     writeln!(f, "impl {} {{", struct_name).unwrap();
 
+    let mut icons = Vec::new();
     if let Ok(entries) = fs::read_dir(icons_dir) {
-        let mut icons = Vec::new();
-        
         for entry in entries {
             if let Ok(entry) = entry {
                 let path = entry.path();
@@ -29,16 +28,24 @@ fn generate_icons(icons_dir: &str, output_file: &str, struct_name: &str, out_dir
                 }
             }
         }
+    }
 
-        // Sort icons by name
-        icons.sort_by(|a, b| a.0.cmp(&b.0));
+    // Sort icons by name
+    icons.sort_by(|a, b| a.0.cmp(&b.0));
 
-        // Generate constants with full SVG content
-        for (name, svg_content) in icons {
-            let const_name = name.to_uppercase().replace("-", "_");
-            writeln!(f, "    pub const {}: &'static str = r#\"{}\"#;", const_name, svg_content).unwrap();
-        }
+    // Generate constants with full SVG content
+    for (name, svg_content) in &icons {
+        let const_name = name.to_uppercase().replace("-", "_");
+        writeln!(f, "    pub const {}: &'static str = r#\"{}\"#;", const_name, svg_content).unwrap();
+    }
+
+    // Name lookup for icons picked at runtime, e.g. from an icon theme file
+    writeln!(f, "    /// Every icon by its file name, e.g. `(\"file-code\", FILE_CODE)`").unwrap();
+    writeln!(f, "    pub const ALL: &'static [(&'static str, &'static str)] = &[").unwrap();
+    for (name, _) in &icons {
+        writeln!(f, "        (\"{}\", Self::{}),", name, name.to_uppercase().replace("-", "_")).unwrap();
     }
+    writeln!(f, "    ];").unwrap();
 
     writeln!(f, "}}").unwrap();
 }
@@ -0,0 +1,192 @@
+use skia_safe::{Canvas, Color, Font, Paint, Rect};
+
+use crate::components::props::{alert_variant_from, alert_variant_prop};
+use crate::components::{CodiconIcons, Icon, IconSize, Prop, PropValue, Widget};
+use crate::theme::{current_theme, Theme, ThemeColors};
+
+const PADDING: f32 = 16.0;
+const ICON_SPACE: f32 = 28.0;
+const TITLE_HEIGHT: f32 = 20.0;
+const LINE_HEIGHT: f32 = 20.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlertVariant {
+    Info,
+    Warning,
+    Destructive,
+}
+
+impl AlertVariant {
+    fn icon(self) -> &'static str {
+        match self {
+            AlertVariant::Info => CodiconIcons::INFO,
+            AlertVariant::Warning => CodiconIcons::WARNING,
+            AlertVariant::Destructive => CodiconIcons::ERROR,
+        }
+    }
+
+    fn accent(self, colors: &ThemeColors) -> Color {
+        match self {
+            AlertVariant::Info => colors.foreground,
+            AlertVariant::Warning => Theme::WARNING,
+            AlertVariant::Destructive => Theme::ERROR,
+        }
+    }
+}
+
+/// Greedy word wrap of `text` to `max_width`; explicit newlines are kept
+pub(crate) fn wrap_text(font: &Font, text: &str, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", line, word)
+            };
+            if !line.is_empty() && font.measure_str(&candidate, None).0 > max_width {
+                lines.push(std::mem::replace(&mut line, word.to_string()));
+            } else {
+                line = candidate;
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Inline callout with an icon, a title and an optional description
+pub struct Alert {
+    x: f32,
+    y: f32,
+    width: f32,
+    variant: AlertVariant,
+    title: String,
+    description: String,
+}
+
+impl Alert {
+    pub fn new(x: f32, y: f32, width: f32, title: impl Into<String>) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            variant: AlertVariant::Info,
+            title: title.into(),
+            description: String::new(),
+        }
+    }
+
+    pub fn variant(mut self, variant: AlertVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+    }
+
+    fn description_lines(&self, font_manager: &mut crate::core::FontManager) -> Vec<String> {
+        if self.description.is_empty() {
+            return Vec::new();
+        }
+        let font = font_manager.create_font(&self.description, Theme::TEXT_SM, 400);
+        wrap_text(&font, &self.description, self.width - PADDING * 2.0 - ICON_SPACE)
+    }
+
+    /// Height including the wrapped description
+    pub fn height(&self, font_manager: &mut crate::core::FontManager) -> f32 {
+        let lines = self.description_lines(font_manager).len();
+        PADDING * 2.0 + TITLE_HEIGHT + lines as f32 * LINE_HEIGHT
+    }
+}
+
+impl Widget for Alert {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
+        let colors = current_theme();
+        let accent = self.variant.accent(&colors);
+        let lines = self.description_lines(font_manager);
+        let height = PADDING * 2.0 + TITLE_HEIGHT + lines.len() as f32 * LINE_HEIGHT;
+        let rect = Rect::from_xywh(self.x, self.y, self.width, height);
+
+        let mut bg_paint = Paint::default();
+        bg_paint.set_anti_alias(true);
+        bg_paint.set_color(colors.card);
+        canvas.draw_round_rect(rect, Theme::RADIUS_LG, Theme::RADIUS_LG, &bg_paint);
+
+        // Destructive and warning alerts take their border from the accent color
+        let mut border_paint = Paint::default();
+        border_paint.set_anti_alias(true);
+        border_paint.set_style(skia_safe::PaintStyle::Stroke);
+        border_paint.set_stroke_width(1.0);
+        border_paint.set_color(match self.variant {
+            AlertVariant::Info => colors.border,
+            _ => accent,
+        });
+        canvas.draw_round_rect(rect.with_inset((0.5, 0.5)), Theme::RADIUS_LG, Theme::RADIUS_LG, &border_paint);
+
+        Icon::new(self.x + PADDING, self.y + PADDING + 2.0, self.variant.icon(), IconSize::Small, accent)
+            .draw(canvas, font_manager);
+
+        let text_x = self.x + PADDING + ICON_SPACE;
+        let title_font = font_manager.create_font(&self.title, Theme::TEXT_SM, 600);
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        text_paint.set_color(accent);
+        canvas.draw_str(&self.title, (text_x, self.y + PADDING + 14.0), &title_font, &text_paint);
+
+        let description_font = font_manager.create_font(&self.description, Theme::TEXT_SM, 400);
+        text_paint.set_color(match self.variant {
+            AlertVariant::Info => colors.muted_foreground,
+            _ => accent,
+        });
+        for (index, line) in lines.iter().enumerate() {
+            let baseline = self.y + PADDING + TITLE_HEIGHT + index as f32 * LINE_HEIGHT + 14.0;
+            canvas.draw_str(line, (text_x, baseline), &description_font, &text_paint);
+        }
+    }
+
+    fn contains(&self, _x: f32, _y: f32) -> bool {
+        false // Alerts are not interactive
+    }
+
+    fn update_hover(&mut self, _x: f32, _y: f32) {}
+
+    fn update_animation(&mut self, _elapsed: f32) {}
+
+    fn on_click(&mut self) {}
+
+    fn props(&self) -> Vec<Prop> {
+        vec![
+            Prop::text("title", self.title.clone()),
+            Prop::text("description", self.description.clone()),
+            alert_variant_prop(self.variant),
+        ]
+    }
+
+    fn set_prop(&mut self, name: &str, value: &PropValue) -> bool {
+        match name {
+            "title" => value.as_text().map(|title| self.title = title.to_string()),
+            "description" => value.as_text().map(|description| self.description = description.to_string()),
+            "variant" => alert_variant_from(value).map(|variant| self.variant = variant),
+            _ => None,
+        }
+        .is_some()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
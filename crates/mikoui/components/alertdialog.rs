@@ -0,0 +1,210 @@
+use skia_safe::{Canvas, Color, Paint, Rect};
+
+use crate::components::alert::wrap_text;
+use crate::components::{Button, Widget};
+use crate::theme::{current_theme, Size, Theme, Variant};
+
+const WIDTH: f32 = 440.0;
+const PADDING: f32 = 24.0;
+const TITLE_HEIGHT: f32 = 28.0;
+const LINE_HEIGHT: f32 = 20.0;
+const BUTTON_WIDTH: f32 = 120.0;
+
+/// Button chosen in an AlertDialog
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlertDialogAction {
+    Cancel,
+    Confirm,
+}
+
+/// Modal confirmation dialog ("Delete file?") centered over a dimmed window
+/// Only the buttons, Escape and Enter close it; clicks outside are swallowed
+pub struct AlertDialog {
+    screen_width: f32,
+    screen_height: f32,
+    visible: bool,
+    title: String,
+    lines: Vec<String>,
+    cancel_button: Button,
+    confirm_button: Button,
+}
+
+impl AlertDialog {
+    pub fn new(screen_width: f32, screen_height: f32) -> Self {
+        Self {
+            screen_width,
+            screen_height,
+            visible: false,
+            title: String::new(),
+            lines: Vec::new(),
+            cancel_button: Button::new(0.0, 0.0, BUTTON_WIDTH, crate::t!("ui.dialog.cancel"))
+                .size(Size::Sm)
+                .variant(Variant::Outline),
+            confirm_button: Button::new(0.0, 0.0, BUTTON_WIDTH, crate::t!("ui.dialog.continue")).size(Size::Sm),
+        }
+    }
+
+    /// Label of the confirm button; `destructive` paints it in the destructive color
+    pub fn confirm(mut self, label: impl Into<String>, destructive: bool) -> Self {
+        let variant = if destructive { Variant::Destructive } else { Variant::Default };
+        self.confirm_button = Button::new(0.0, 0.0, BUTTON_WIDTH, label)
+            .size(Size::Sm)
+            .variant(variant);
+        self
+    }
+
+    pub fn cancel(mut self, label: impl Into<String>) -> Self {
+        self.cancel_button = Button::new(0.0, 0.0, BUTTON_WIDTH, label)
+            .size(Size::Sm)
+            .variant(Variant::Outline);
+        self
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Open the dialog; the description is wrapped to the dialog width
+    pub fn show(&mut self, title: &str, description: &str, font_manager: &mut crate::core::FontManager) {
+        let font = font_manager.create_font(description, Theme::TEXT_SM, 400);
+        self.title = title.to_string();
+        self.lines = wrap_text(&font, description, WIDTH - PADDING * 2.0);
+        self.visible = true;
+        self.layout();
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn set_screen_size(&mut self, width: f32, height: f32) {
+        self.screen_width = width;
+        self.screen_height = height;
+        self.layout();
+    }
+
+    fn height(&self) -> f32 {
+        PADDING * 2.0 + TITLE_HEIGHT + self.lines.len() as f32 * LINE_HEIGHT + PADDING + self.confirm_button.height()
+    }
+
+    fn rect(&self) -> Rect {
+        let height = self.height();
+        Rect::from_xywh(
+            (self.screen_width - WIDTH) / 2.0,
+            (self.screen_height - height) / 2.0,
+            WIDTH,
+            height,
+        )
+    }
+
+    fn layout(&mut self) {
+        let rect = self.rect();
+        let button_y = rect.bottom - PADDING - self.confirm_button.height();
+        let confirm_x = rect.right - PADDING - BUTTON_WIDTH;
+        self.confirm_button.set_position(confirm_x, button_y);
+        self.cancel_button.set_position(confirm_x - Theme::SPACE_2 - BUTTON_WIDTH, button_y);
+    }
+
+    /// Returns the action when a button was clicked; closes the dialog
+    pub fn handle_click(&mut self, x: f32, y: f32) -> Option<AlertDialogAction> {
+        let action = if self.confirm_button.hit_test(x, y) {
+            self.confirm_button.on_click();
+            AlertDialogAction::Confirm
+        } else if self.cancel_button.hit_test(x, y) {
+            self.cancel_button.on_click();
+            AlertDialogAction::Cancel
+        } else {
+            return None;
+        };
+        self.hide();
+        Some(action)
+    }
+
+    /// Escape cancels and Enter confirms
+    pub fn handle_key_input(&mut self, key: &str) -> Option<AlertDialogAction> {
+        let action = match key {
+            "Escape" => AlertDialogAction::Cancel,
+            "Enter" => AlertDialogAction::Confirm,
+            _ => return None,
+        };
+        self.hide();
+        Some(action)
+    }
+}
+
+impl Widget for AlertDialog {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
+        if !self.visible {
+            return;
+        }
+
+        let colors = current_theme();
+
+        // Dim everything behind the dialog
+        let mut overlay_paint = Paint::default();
+        overlay_paint.set_color(Color::from_argb(128, 0, 0, 0));
+        canvas.draw_rect(Rect::from_xywh(0.0, 0.0, self.screen_width, self.screen_height), &overlay_paint);
+
+        let rect = self.rect();
+        let mut shadow_paint = Paint::default();
+        shadow_paint.set_anti_alias(true);
+        shadow_paint.set_color(Color::from_argb(80, 0, 0, 0));
+        if let Some(blur) = skia_safe::MaskFilter::blur(skia_safe::BlurStyle::Normal, 16.0, false) {
+            shadow_paint.set_mask_filter(blur);
+        }
+        canvas.draw_round_rect(rect.with_offset((0.0, 4.0)), Theme::RADIUS_LG, Theme::RADIUS_LG, &shadow_paint);
+
+        let mut bg_paint = Paint::default();
+        bg_paint.set_anti_alias(true);
+        bg_paint.set_color(colors.background);
+        canvas.draw_round_rect(rect, Theme::RADIUS_LG, Theme::RADIUS_LG, &bg_paint);
+
+        let mut border_paint = Paint::default();
+        border_paint.set_anti_alias(true);
+        border_paint.set_style(skia_safe::PaintStyle::Stroke);
+        border_paint.set_stroke_width(1.0);
+        border_paint.set_color(colors.border);
+        canvas.draw_round_rect(rect.with_inset((0.5, 0.5)), Theme::RADIUS_LG, Theme::RADIUS_LG, &border_paint);
+
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        text_paint.set_color(colors.foreground);
+        let title_font = font_manager.create_font(&self.title, Theme::TEXT_LG, 600);
+        canvas.draw_str(&self.title, (rect.left + PADDING, rect.top + PADDING + 18.0), &title_font, &text_paint);
+
+        text_paint.set_color(colors.muted_foreground);
+        for (index, line) in self.lines.iter().enumerate() {
+            let font = font_manager.create_font(line, Theme::TEXT_SM, 400);
+            let baseline = rect.top + PADDING + TITLE_HEIGHT + index as f32 * LINE_HEIGHT + 14.0;
+            canvas.draw_str(line, (rect.left + PADDING, baseline), &font, &text_paint);
+        }
+
+        self.cancel_button.draw(canvas, font_manager);
+        self.confirm_button.draw(canvas, font_manager);
+    }
+
+    fn contains(&self, _x: f32, _y: f32) -> bool {
+        // Modal: the whole window belongs to the dialog while it is open
+        self.visible
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.cancel_button.update_hover(x, y);
+        self.confirm_button.update_hover(x, y);
+    }
+
+    fn update_animation(&mut self, elapsed: f32) {
+        self.cancel_button.update_animation(elapsed);
+        self.confirm_button.update_animation(elapsed);
+    }
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
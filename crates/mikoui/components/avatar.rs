@@ -0,0 +1,313 @@
+use skia_safe::{Canvas, Data, Image, Paint, RRect, Rect};
+
+use crate::components::props::{avatar_shape_from, avatar_shape_prop, size_from, size_prop};
+use crate::components::{Prop, PropValue, Widget};
+use crate::theme::{current_theme, Size, Theme};
+
+/// Avatar outline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AvatarShape {
+    Circle,
+    Rounded,
+}
+
+/// User picture with initials shown when there is no image (or it fails to decode)
+pub struct Avatar {
+    x: f32,
+    y: f32,
+    size: Size,
+    shape: AvatarShape,
+    image: Option<Image>,
+    initials: String,
+}
+
+impl Avatar {
+    pub fn new(x: f32, y: f32, name: &str) -> Self {
+        Self {
+            x,
+            y,
+            size: Size::Md,
+            shape: AvatarShape::Circle,
+            image: None,
+            initials: Self::initials(name),
+        }
+    }
+
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn shape(mut self, shape: AvatarShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Encoded image data (PNG, JPEG, ...); undecodable data keeps the initials
+    pub fn image(mut self, bytes: &[u8]) -> Self {
+        self.image = Image::from_encoded(Data::new_copy(bytes));
+        self
+    }
+
+    pub fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+    }
+
+    pub fn diameter(&self) -> f32 {
+        self.size.height()
+    }
+
+    /// First letter of the first and last words: "Ada Lovelace" -> "AL"
+    fn initials(name: &str) -> String {
+        let mut words = name.split_whitespace();
+        let first = words.next().and_then(|word| word.chars().next());
+        let last = words.last().and_then(|word| word.chars().next());
+        first.into_iter().chain(last).flat_map(char::to_uppercase).collect()
+    }
+
+    fn rrect(&self) -> RRect {
+        let diameter = self.diameter();
+        let radius = match self.shape {
+            AvatarShape::Circle => diameter / 2.0,
+            AvatarShape::Rounded => Theme::RADIUS_MD,
+        };
+        RRect::new_rect_xy(Rect::from_xywh(self.x, self.y, diameter, diameter), radius, radius)
+    }
+
+    /// Filled outline in `color`, used by AvatarGroup to separate overlapping avatars
+    fn draw_ring(&self, canvas: &Canvas, width: f32, color: skia_safe::Color) {
+        let diameter = self.diameter() + width * 2.0;
+        let radius = match self.shape {
+            AvatarShape::Circle => diameter / 2.0,
+            AvatarShape::Rounded => Theme::RADIUS_MD + width,
+        };
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_color(color);
+        canvas.draw_round_rect(
+            Rect::from_xywh(self.x - width, self.y - width, diameter, diameter),
+            radius,
+            radius,
+            &paint,
+        );
+    }
+}
+
+impl Widget for Avatar {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
+        let colors = current_theme();
+        let diameter = self.diameter();
+        let bounds = Rect::from_xywh(self.x, self.y, diameter, diameter);
+
+        canvas.save();
+        canvas.clip_rrect(self.rrect(), None, true);
+
+        match self.image {
+            Some(ref image) if image.width() > 0 && image.height() > 0 => {
+                // Scale the short side to fit and let the clip crop the rest ("object-cover")
+                let (width, height) = (image.width() as f32, image.height() as f32);
+                let scale = diameter / width.min(height);
+                let (dest_width, dest_height) = (width * scale, height * scale);
+                let dest = Rect::from_xywh(
+                    self.x + (diameter - dest_width) / 2.0,
+                    self.y + (diameter - dest_height) / 2.0,
+                    dest_width,
+                    dest_height,
+                );
+                let mut paint = Paint::default();
+                paint.set_anti_alias(true);
+                canvas.draw_image_rect(image, None, dest, &paint);
+            }
+            _ => {
+                let mut bg_paint = Paint::default();
+                bg_paint.set_anti_alias(true);
+                bg_paint.set_color(colors.muted);
+                canvas.draw_rect(bounds, &bg_paint);
+
+                let font_size = diameter * 0.4;
+                let font = font_manager.create_font(&self.initials, font_size, 500);
+                let mut text_paint = Paint::default();
+                text_paint.set_anti_alias(true);
+                text_paint.set_color(colors.muted_foreground);
+                let (text_width, _) = font.measure_str(&self.initials, Some(&text_paint));
+                canvas.draw_str(
+                    &self.initials,
+                    (self.x + (diameter - text_width) / 2.0, self.y + diameter / 2.0 + font_size * 0.35),
+                    &font,
+                    &text_paint,
+                );
+            }
+        }
+
+        canvas.restore();
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        let diameter = self.diameter();
+        x >= self.x && x <= self.x + diameter && y >= self.y && y <= self.y + diameter
+    }
+
+    fn update_hover(&mut self, _x: f32, _y: f32) {}
+
+    fn update_animation(&mut self, _elapsed: f32) {}
+
+    fn on_click(&mut self) {}
+
+    fn props(&self) -> Vec<Prop> {
+        vec![Prop::text("initials", self.initials.clone()), avatar_shape_prop(self.shape), size_prop(self.size)]
+    }
+
+    fn set_prop(&mut self, name: &str, value: &PropValue) -> bool {
+        match name {
+            "initials" => value.as_text().map(|text| {
+                self.initials = text.chars().filter(|c| c.is_alphanumeric()).take(2).flat_map(char::to_uppercase).collect();
+            }),
+            "shape" => avatar_shape_from(value).map(|shape| self.shape = shape),
+            "size" => size_from(value).map(|size| self.size = size),
+            _ => None,
+        }
+        .is_some()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Overlapping stack of avatars with a "+N" badge for the ones that don't fit
+pub struct AvatarGroup {
+    x: f32,
+    y: f32,
+    size: Size,
+    max_visible: usize,
+    avatars: Vec<Avatar>,
+}
+
+impl AvatarGroup {
+    const RING_WIDTH: f32 = 2.0;
+
+    pub fn new(x: f32, y: f32) -> Self {
+        Self {
+            x,
+            y,
+            size: Size::Md,
+            max_visible: 4,
+            avatars: Vec::new(),
+        }
+    }
+
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = size;
+        self.layout();
+        self
+    }
+
+    /// Number of avatars drawn before collapsing the rest into "+N"
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible.max(1);
+        self.layout();
+        self
+    }
+
+    pub fn with_avatar(mut self, avatar: Avatar) -> Self {
+        self.avatars.push(avatar);
+        self.layout();
+        self
+    }
+
+    pub fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+        self.layout();
+    }
+
+    /// Avatars hidden behind the "+N" badge
+    pub fn overflow(&self) -> usize {
+        self.avatars.len().saturating_sub(self.max_visible)
+    }
+
+    fn step(&self) -> f32 {
+        self.size.height() * 0.75
+    }
+
+    pub fn width(&self) -> f32 {
+        let slots = self.avatars.len().min(self.max_visible) + usize::from(self.overflow() > 0);
+        match slots {
+            0 => 0.0,
+            n => self.step() * (n - 1) as f32 + self.size.height(),
+        }
+    }
+
+    fn layout(&mut self) {
+        let (x, y, step, size) = (self.x, self.y, self.step(), self.size);
+        for (index, avatar) in self.avatars.iter_mut().enumerate() {
+            avatar.size = size;
+            avatar.set_position(x + index as f32 * step, y);
+        }
+    }
+}
+
+impl Widget for AvatarGroup {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
+        let colors = current_theme();
+
+        for avatar in self.avatars.iter().take(self.max_visible) {
+            avatar.draw_ring(canvas, Self::RING_WIDTH, colors.background);
+            avatar.draw(canvas, font_manager);
+        }
+
+        let overflow = self.overflow();
+        if overflow > 0 {
+            let diameter = self.size.height();
+            let x = self.x + self.max_visible as f32 * self.step();
+            let rect = Rect::from_xywh(x, self.y, diameter, diameter);
+
+            let mut ring_paint = Paint::default();
+            ring_paint.set_anti_alias(true);
+            ring_paint.set_color(colors.background);
+            canvas.draw_oval(rect.with_outset((Self::RING_WIDTH, Self::RING_WIDTH)), &ring_paint);
+
+            let mut bg_paint = Paint::default();
+            bg_paint.set_anti_alias(true);
+            bg_paint.set_color(colors.secondary);
+            canvas.draw_oval(rect, &bg_paint);
+
+            let label = format!("+{}", overflow);
+            let font_size = diameter * 0.35;
+            let font = font_manager.create_font(&label, font_size, 500);
+            let mut text_paint = Paint::default();
+            text_paint.set_anti_alias(true);
+            text_paint.set_color(colors.secondary_foreground);
+            let (text_width, _) = font.measure_str(&label, Some(&text_paint));
+            canvas.draw_str(
+                &label,
+                (x + (diameter - text_width) / 2.0, self.y + diameter / 2.0 + font_size * 0.35),
+                &font,
+                &text_paint,
+            );
+        }
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width() && y >= self.y && y <= self.y + self.size.height()
+    }
+
+    fn update_hover(&mut self, _x: f32, _y: f32) {}
+
+    fn update_animation(&mut self, _elapsed: f32) {}
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
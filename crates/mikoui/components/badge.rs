@@ -1,13 +1,16 @@
 use skia_safe::{Canvas, Color, Paint, Rect};
 
-use crate::components::Widget;
-use crate::theme::{current_theme, Theme, Variant};
+use crate::components::props::{variant_from, variant_prop};
+use crate::components::{Prop, PropValue, Widget};
+use crate::theme::{contrast_foreground, current_theme, Style, Theme, Variant};
 
 pub struct Badge {
     x: f32,
     y: f32,
     text: &'static str,
     variant: Variant,
+    color: Option<Color>,
+    style: Style,
     hover: bool,
     hover_progress: f32,
 }
@@ -19,6 +22,8 @@ impl Badge {
             y,
             text,
             variant: Variant::Default,
+            color: None,
+            style: Style::default(),
             hover: false,
             hover_progress: 0.0,
         }
@@ -29,31 +34,50 @@ impl Badge {
         self
     }
     
+    /// Custom fill; the text color is picked for contrast against it
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+    
+    /// Local overrides of the theme colors, border, radius, padding and opacity
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+    
     fn get_width(&self, font_manager: &mut crate::core::FontManager) -> f32 {
         let font = font_manager.create_font(self.text, Theme::TEXT_XS, 500);
         let mut paint = Paint::default();
         paint.set_anti_alias(true);
         let (text_width, _) = font.measure_str(self.text, Some(&paint));
-        text_width + (Theme::SPACE_2 * 2.0)
+        text_width + self.style.padding.unwrap_or(Theme::SPACE_2) * 2.0
     }
 }
 
 impl Widget for Badge {
     fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
-        let border_radius = Theme::RADIUS_SM;
+        let border_radius = self.style.radius.unwrap_or(Theme::RADIUS_SM);
         let height = 22.0;
         let colors = current_theme();
+        let layered = self.style.push_opacity(canvas);
         
         let width = self.get_width(font_manager);
 
         // Colors based on variant
-        let (bg_color, text_color, has_border) = match self.variant {
-            Variant::Default => (colors.primary, colors.primary_foreground, false),
-            Variant::Secondary => (colors.secondary, colors.secondary_foreground, false),
-            Variant::Destructive => (colors.destructive, colors.destructive_foreground, false),
-            Variant::Outline => (Color::TRANSPARENT, colors.foreground, true),
+        let custom = self.variant.custom_colors(&colors);
+        let (bg_color, text_color, has_border) = match (self.color, custom, self.variant) {
+            (Some(color), _, _) => (color, contrast_foreground(color), false),
+            (None, Some(custom), _) => (custom.background, custom.foreground, custom.border.is_some()),
+            (None, None, Variant::Default | Variant::Custom(_)) => (colors.primary, colors.primary_foreground, false),
+            (None, None, Variant::Secondary) => (colors.secondary, colors.secondary_foreground, false),
+            (None, None, Variant::Destructive) => (colors.destructive, colors.destructive_foreground, false),
+            (None, None, Variant::Outline) => (Color::TRANSPARENT, colors.foreground, true),
             _ => (colors.secondary, colors.secondary_foreground, false),
         };
+        let bg_color = self.style.background.unwrap_or(bg_color);
+        let text_color = self.style.foreground.unwrap_or(text_color);
+        let has_border = has_border || self.style.has_border();
 
         // Background
         if bg_color != Color::TRANSPARENT {
@@ -74,15 +98,19 @@ impl Widget for Badge {
             let mut border_paint = Paint::default();
             border_paint.set_anti_alias(true);
             border_paint.set_style(skia_safe::PaintStyle::Stroke);
-            border_paint.set_color(colors.border);
-            border_paint.set_stroke_width(1.0);
+            let border_color = self.style.border_color
+                .or(custom.and_then(|custom| custom.border))
+                .unwrap_or(colors.border);
+            let border_width = self.style.border_width.unwrap_or(1.0);
+            border_paint.set_color(border_color);
+            border_paint.set_stroke_width(border_width);
 
             canvas.draw_round_rect(
                 Rect::from_xywh(
-                    self.x + 0.5,
-                    self.y + 0.5,
-                    width - 1.0,
-                    height - 1.0,
+                    self.x + border_width / 2.0,
+                    self.y + border_width / 2.0,
+                    width - border_width,
+                    height - border_width,
                 ),
                 border_radius,
                 border_radius,
@@ -101,6 +129,10 @@ impl Widget for Badge {
         let text_y = self.y + height / 2.0 + 4.0;
 
         canvas.draw_str(self.text, (text_x, text_y), &font, &text_paint);
+
+        if layered {
+            canvas.restore();
+        }
     }
 
     fn contains(&self, _x: f32, _y: f32) -> bool {
@@ -113,6 +145,18 @@ impl Widget for Badge {
 
     fn on_click(&mut self) {}
 
+    fn props(&self) -> Vec<Prop> {
+        vec![variant_prop(self.variant)]
+    }
+
+    fn set_prop(&mut self, name: &str, value: &PropValue) -> bool {
+        match name {
+            "variant" => variant_from(value).map(|variant| self.variant = variant),
+            _ => None,
+        }
+        .is_some()
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -0,0 +1,175 @@
+use std::cell::RefCell;
+
+use skia_safe::{Canvas, Paint, Rect};
+
+use crate::components::Widget;
+use crate::theme::{current_theme, Theme};
+
+const HEIGHT: f32 = 24.0;
+const CHEVRON_WIDTH: f32 = 16.0;
+const ELLIPSIS: &str = "…";
+
+/// Chevron-separated path of clickable segments
+/// When the path is wider than `max_width` the middle segments collapse into "…"
+pub struct Breadcrumb {
+    x: f32,
+    y: f32,
+    max_width: f32,
+    segments: Vec<String>,
+    hover: Option<usize>,
+    /// Hit areas from the last draw; `None` marks the collapsed "…" slot
+    slots: RefCell<Vec<(Option<usize>, Rect)>>,
+}
+
+impl Breadcrumb {
+    pub fn new(x: f32, y: f32, max_width: f32) -> Self {
+        Self {
+            x,
+            y,
+            max_width,
+            segments: Vec::new(),
+            hover: None,
+            slots: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub fn with_segment(mut self, segment: impl Into<String>) -> Self {
+        self.segments.push(segment.into());
+        self
+    }
+
+    pub fn set_segments(&mut self, segments: Vec<String>) {
+        self.segments = segments;
+        self.hover = None;
+    }
+
+    pub fn segments(&self) -> &[String] {
+        &self.segments
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, max_width: f32) {
+        self.x = x;
+        self.y = y;
+        self.max_width = max_width;
+    }
+
+    /// Index of the clicked segment; the last segment is the current location and not clickable
+    pub fn handle_click(&self, x: f32, y: f32) -> Option<usize> {
+        self.segment_at(x, y)
+            .filter(|&index| index + 1 < self.segments.len())
+    }
+
+    fn segment_at(&self, x: f32, y: f32) -> Option<usize> {
+        self.slots
+            .borrow()
+            .iter()
+            .find(|(_, rect)| rect.contains(skia_safe::Point::new(x, y)))
+            .and_then(|(index, _)| *index)
+    }
+
+    /// Pick which segments to show: all of them, or the first plus as many trailing ones as fit
+    fn visible_slots(&self, widths: &[f32], ellipsis_width: f32) -> Vec<Option<usize>> {
+        let total: f32 = widths.iter().map(|width| width + CHEVRON_WIDTH).sum::<f32>() - CHEVRON_WIDTH;
+        if total <= self.max_width || widths.len() <= 2 {
+            return (0..widths.len()).map(Some).collect();
+        }
+
+        let last = widths.len() - 1;
+        let mut used = widths[0] + CHEVRON_WIDTH + ellipsis_width + CHEVRON_WIDTH + widths[last];
+        let mut first_tail = last;
+        while first_tail > 1 && used + widths[first_tail - 1] + CHEVRON_WIDTH <= self.max_width {
+            first_tail -= 1;
+            used += widths[first_tail] + CHEVRON_WIDTH;
+        }
+
+        let mut slots = vec![Some(0)];
+        if first_tail > 1 {
+            slots.push(None);
+        }
+        slots.extend((first_tail..=last).map(Some));
+        slots
+    }
+
+    fn draw_chevron(canvas: &Canvas, x: f32, center_y: f32, paint: &Paint) {
+        let cx = x + CHEVRON_WIDTH / 2.0;
+        canvas.draw_line((cx - 2.0, center_y - 4.0), (cx + 2.0, center_y), paint);
+        canvas.draw_line((cx + 2.0, center_y), (cx - 2.0, center_y + 4.0), paint);
+    }
+}
+
+impl Widget for Breadcrumb {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
+        let colors = current_theme();
+        let font_size = Theme::TEXT_SM;
+
+        let fonts: Vec<_> = self.segments
+            .iter()
+            .map(|segment| font_manager.create_font(segment, font_size, 400))
+            .collect();
+        let widths: Vec<f32> = self.segments
+            .iter()
+            .zip(&fonts)
+            .map(|(segment, font)| font.measure_str(segment, None).0)
+            .collect();
+        let ellipsis_font = font_manager.create_font(ELLIPSIS, font_size, 400);
+        let ellipsis_width = ellipsis_font.measure_str(ELLIPSIS, None).0;
+
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        let mut chevron_paint = Paint::default();
+        chevron_paint.set_anti_alias(true);
+        chevron_paint.set_style(skia_safe::PaintStyle::Stroke);
+        chevron_paint.set_stroke_width(1.25);
+        chevron_paint.set_color(colors.muted_foreground);
+
+        let center_y = self.y + HEIGHT / 2.0;
+        let baseline = center_y + font_size * 0.35;
+        let last = self.segments.len().saturating_sub(1);
+        let mut slots = Vec::new();
+        let mut x = self.x;
+
+        for (position, slot) in self.visible_slots(&widths, ellipsis_width).into_iter().enumerate() {
+            if position > 0 {
+                Self::draw_chevron(canvas, x, center_y, &chevron_paint);
+                x += CHEVRON_WIDTH;
+            }
+
+            let (text, font, width) = match slot {
+                Some(index) => (self.segments[index].as_str(), &fonts[index], widths[index]),
+                None => (ELLIPSIS, &ellipsis_font, ellipsis_width),
+            };
+            let color = if slot == Some(last) || (slot.is_some() && slot == self.hover) {
+                colors.foreground
+            } else {
+                colors.muted_foreground
+            };
+            text_paint.set_color(color);
+            canvas.draw_str(text, (x, baseline), font, &text_paint);
+
+            slots.push((slot, Rect::from_xywh(x, self.y, width, HEIGHT)));
+            x += width;
+        }
+
+        *self.slots.borrow_mut() = slots;
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.max_width && y >= self.y && y <= self.y + HEIGHT
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hover = self.segment_at(x, y);
+    }
+
+    fn update_animation(&mut self, _elapsed: f32) {}
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -1,38 +1,46 @@
 use skia_safe::{Canvas, Color, Paint, Rect};
 
-use crate::components::Widget;
-use crate::theme::{current_theme, lerp_color, with_alpha, Size, Theme, Variant};
+use crate::components::props::{size_from, size_prop, variant_from, variant_prop};
+use crate::components::{draw_focus_outline, FocusOrigin, Prop, PropValue, Widget};
+use crate::core::animation_rate;
+use crate::theme::{current_theme, lerp_color, with_alpha, ColorShades, Size, Style, Theme, Variant};
 
 pub struct Button {
     x: f32,
     y: f32,
     width: f32,
     height: f32,
-    text: &'static str,
+    text: String,
     variant: Variant,
+    shades: Option<ColorShades>,
+    style: Style,
     size: Size,
     hover: bool,
     active: bool,
     hover_progress: f32,
     active_progress: f32,
     disabled: bool,
+    focus: Option<FocusOrigin>,
 }
 
 impl Button {
-    pub fn new(x: f32, y: f32, width: f32, text: &'static str) -> Self {
+    pub fn new(x: f32, y: f32, width: f32, text: impl Into<String>) -> Self {
         Self {
             x,
             y,
             width,
             height: Size::Md.height(),
-            text,
+            text: text.into(),
             variant: Variant::Default,
+            shades: None,
+            style: Style::default(),
             size: Size::Md,
             hover: false,
             active: false,
             hover_progress: 0.0,
             active_progress: 0.0,
             disabled: false,
+            focus: None,
         }
     }
     
@@ -41,6 +49,18 @@ impl Button {
         self
     }
     
+    /// Custom solid fill; hover/press shades and the text color derive from it
+    pub fn color(mut self, color: Color) -> Self {
+        self.shades = Some(ColorShades::from_base(color));
+        self
+    }
+    
+    /// Local overrides of the theme colors, border, radius and opacity
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+    
     pub fn size(mut self, size: Size) -> Self {
         self.size = size;
         self.height = size.height();
@@ -51,47 +71,79 @@ impl Button {
         self.disabled = disabled;
         self
     }
+    
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+    
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+    
+    pub fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+    }
+    
+    pub fn height(&self) -> f32 {
+        self.height
+    }
 }
 
 impl Widget for Button {
     fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
-        let border_radius = Theme::RADIUS_MD;
+        let border_radius = self.style.radius.unwrap_or(Theme::RADIUS_MD);
         let font_size = self.size.font_size();
         let colors = current_theme();
+        let layered = self.style.push_opacity(canvas);
 
         // Get colors based on variant
-        let (base_bg, hover_bg, text_color, has_border) = match self.variant {
-            Variant::Default => (
+        let custom = self.variant.custom_colors(&colors);
+        let shades = self.style.background.map(ColorShades::from_base).or(self.shades);
+        let (base_bg, hover_bg, text_color, has_border) = match (shades, custom, self.variant) {
+            (Some(shades), _, _) => (
+                shades.base,
+                lerp_color(shades.hover, shades.active, self.active_progress),
+                shades.foreground,
+                false,
+            ),
+            (None, Some(custom), _) => (
+                custom.background,
+                custom.hover,
+                custom.foreground,
+                custom.border.is_some(),
+            ),
+            (None, None, Variant::Default | Variant::Custom(_)) => (
                 colors.primary,
                 with_alpha(colors.primary, 230),
                 colors.primary_foreground,
                 false,
             ),
-            Variant::Destructive => (
+            (None, None, Variant::Destructive) => (
                 colors.destructive,
                 with_alpha(colors.destructive, 230),
                 colors.destructive_foreground,
                 false,
             ),
-            Variant::Outline => (
+            (None, None, Variant::Outline) => (
                 Color::TRANSPARENT,
                 colors.accent,
                 colors.accent_foreground,
                 true,
             ),
-            Variant::Secondary => (
+            (None, None, Variant::Secondary) => (
                 colors.secondary,
                 with_alpha(colors.secondary, 200),
                 colors.secondary_foreground,
                 false,
             ),
-            Variant::Ghost => (
+            (None, None, Variant::Ghost) => (
                 Color::TRANSPARENT,
                 colors.accent,
                 colors.accent_foreground,
                 false,
             ),
-            Variant::Link => (
+            (None, None, Variant::Link) => (
                 Color::TRANSPARENT,
                 Color::TRANSPARENT,
                 colors.primary,
@@ -99,6 +151,9 @@ impl Widget for Button {
             ),
         };
 
+        let text_color = self.style.foreground.unwrap_or(text_color);
+        let has_border = has_border || self.style.has_border();
+
         // Apply disabled state
         let (current_bg, current_text) = if self.disabled {
             (with_alpha(base_bg, 128), with_alpha(text_color, 128))
@@ -136,24 +191,28 @@ impl Widget for Button {
 
         // Draw border for outline variant
         if has_border {
+            let border_color = self.style.border_color
+                .or(custom.and_then(|custom| custom.border))
+                .unwrap_or(colors.border);
             let border_color = if self.disabled {
-                with_alpha(colors.border, 128)
+                with_alpha(border_color, 128)
             } else {
-                colors.border
+                border_color
             };
             
             let mut border_paint = Paint::default();
             border_paint.set_anti_alias(true);
             border_paint.set_style(skia_safe::PaintStyle::Stroke);
+            let border_width = self.style.border_width.unwrap_or(1.0);
             border_paint.set_color(border_color);
-            border_paint.set_stroke_width(1.0);
+            border_paint.set_stroke_width(border_width);
 
             canvas.draw_round_rect(
                 Rect::from_xywh(
-                    scaled_x + 0.5,
-                    scaled_y + 0.5,
-                    scaled_width - 1.0,
-                    scaled_height - 1.0,
+                    scaled_x + border_width / 2.0,
+                    scaled_y + border_width / 2.0,
+                    scaled_width - border_width,
+                    scaled_height - border_width,
                 ),
                 border_radius,
                 border_radius,
@@ -163,10 +222,11 @@ impl Widget for Button {
 
         // Draw text
         let font_weight = match self.variant {
-            Variant::Default | Variant::Destructive => 500,
+            _ if self.shades.is_some() => 500,
+            Variant::Default | Variant::Destructive | Variant::Custom(_) => 500,
             _ => 450,
         };
-        let font = font_manager.create_font(self.text, font_size, font_weight);
+        let font = font_manager.create_font(&self.text, font_size, font_weight);
 
         let mut text_paint = Paint::default();
         text_paint.set_anti_alias(true);
@@ -174,7 +234,7 @@ impl Widget for Button {
 
         // Underline for link variant on hover
         if matches!(self.variant, Variant::Link) && self.hover_progress > 0.5 {
-            let (text_width, _) = font.measure_str(self.text, Some(&text_paint));
+            let text_width = font_manager.measure_text(&self.text, &font);
             let text_x = scaled_x + (scaled_width - text_width) / 2.0;
             let underline_y = scaled_y + scaled_height / 2.0 + 8.0;
             
@@ -190,11 +250,23 @@ impl Widget for Button {
             );
         }
 
-        let (text_width, _) = font.measure_str(self.text, Some(&text_paint));
+        let text_width = font_manager.measure_text(&self.text, &font);
         let text_x = scaled_x + (scaled_width - text_width) / 2.0;
         let text_y = scaled_y + scaled_height / 2.0 + (font_size * 0.3);
 
-        canvas.draw_str(self.text, (text_x, text_y), &font, &text_paint);
+        font_manager.draw_text(canvas, &self.text, (text_x, text_y), &font, &text_paint);
+
+        if self.focus == Some(FocusOrigin::Keyboard) {
+            draw_focus_outline(
+                canvas,
+                Rect::from_xywh(scaled_x, scaled_y, scaled_width, scaled_height),
+                border_radius,
+            );
+        }
+
+        if layered {
+            canvas.restore();
+        }
     }
 
     fn contains(&self, x: f32, y: f32) -> bool {
@@ -202,7 +274,7 @@ impl Widget for Button {
     }
 
     fn update_hover(&mut self, x: f32, y: f32) {
-        self.hover = self.contains(x, y);
+        self.hover = self.hit_test(x, y);
     }
 
     fn update_animation(&mut self, _elapsed: f32) {
@@ -211,7 +283,7 @@ impl Widget for Button {
         // Hover animation
         let target_hover = if self.hover { 1.0 } else { 0.0 };
         if (self.hover_progress - target_hover).abs() > 0.01 {
-            self.hover_progress += (target_hover - self.hover_progress) * animation_speed;
+            self.hover_progress += (target_hover - self.hover_progress) * animation_rate(animation_speed);
         } else {
             self.hover_progress = target_hover;
         }
@@ -219,7 +291,7 @@ impl Widget for Button {
         // Active animation - automatically reset after reaching peak
         let target_active = if self.active { 1.0 } else { 0.0 };
         if (self.active_progress - target_active).abs() > 0.01 {
-            self.active_progress += (target_active - self.active_progress) * (animation_speed * 2.0);
+            self.active_progress += (target_active - self.active_progress) * animation_rate(animation_speed * 2.0);
         } else {
             self.active_progress = target_active;
             // Reset active state after animation completes
@@ -236,6 +308,60 @@ impl Widget for Button {
         }
     }
 
+    fn is_focusable(&self) -> bool {
+        !self.disabled
+    }
+
+    fn set_focus(&mut self, origin: Option<FocusOrigin>) {
+        self.focus = origin;
+    }
+
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+
+    fn content_bounds(&self) -> Option<Rect> {
+        let padding = self.size.padding_x();
+        Some(Rect::from_xywh(self.x + padding, self.y, self.width - padding * 2.0, self.height))
+    }
+
+    fn debug_state(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("text", self.text.clone()),
+            ("hover", self.hover.to_string()),
+            ("active", self.active.to_string()),
+            ("disabled", self.disabled.to_string()),
+            ("focus", format!("{:?}", self.focus)),
+        ]
+    }
+
+    fn props(&self) -> Vec<Prop> {
+        vec![
+            Prop::text("text", self.text.clone()),
+            variant_prop(self.variant),
+            size_prop(self.size),
+            Prop::bool("disabled", self.disabled),
+            Prop::number("radius", self.style.radius.unwrap_or(Theme::RADIUS_MD), 0.0, 20.0),
+            Prop::number("opacity", self.style.opacity.unwrap_or(1.0), 0.0, 1.0),
+        ]
+    }
+
+    fn set_prop(&mut self, name: &str, value: &PropValue) -> bool {
+        match name {
+            "text" => value.as_text().map(|text| self.text = text.to_string()),
+            "variant" => variant_from(value).map(|variant| self.variant = variant),
+            "size" => size_from(value).map(|size| {
+                self.size = size;
+                self.height = size.height();
+            }),
+            "disabled" => value.as_bool().map(|disabled| self.disabled = disabled),
+            "radius" => value.as_number().map(|radius| self.style.radius = Some(radius)),
+            "opacity" => value.as_number().map(|opacity| self.style.opacity = Some(opacity)),
+            _ => None,
+        }
+        .is_some()
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
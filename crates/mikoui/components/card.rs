@@ -1,13 +1,15 @@
 use skia_safe::{Canvas, Paint, Rect};
 
-use crate::components::Widget;
-use crate::theme::{get_theme_color, with_alpha, Theme};
+use crate::components::{Prop, PropValue, Widget};
+use crate::core::animation_rate;
+use crate::theme::{get_theme_color, with_alpha, Style, Theme};
 
 pub struct Card {
     x: f32,
     y: f32,
     width: f32,
     height: f32,
+    style: Style,
     hover: bool,
     hover_progress: f32,
 }
@@ -19,19 +21,28 @@ impl Card {
             y,
             width,
             height,
+            style: Style::default(),
             hover: false,
             hover_progress: 0.0,
         }
     }
+
+    /// Local overrides of the theme colors, border, radius and opacity
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
 }
 
 impl Widget for Card {
     fn draw(&self, canvas: &Canvas, _font_manager: &mut crate::core::FontManager) {
-        let border_radius = Theme::RADIUS_LG;
+        let border_radius = self.style.radius.unwrap_or(Theme::RADIUS_LG);
+        let layered = self.style.push_opacity(canvas);
 
         // Get colors from current theme
-        let card_color = get_theme_color(|t| t.card);
-        let border_color = get_theme_color(|t| t.border);
+        let card_color = self.style.background.unwrap_or_else(|| get_theme_color(|t| t.card));
+        let border_color = self.style.border_color.unwrap_or_else(|| get_theme_color(|t| t.border));
+        let border_width = self.style.border_width.unwrap_or(1.0);
         let bg_color = get_theme_color(|t| t.background);
 
         // Background
@@ -51,19 +62,21 @@ impl Widget for Card {
         border_paint.set_anti_alias(true);
         border_paint.set_style(skia_safe::PaintStyle::Stroke);
         border_paint.set_color(border_color);
-        border_paint.set_stroke_width(1.0);
+        border_paint.set_stroke_width(border_width);
 
-        canvas.draw_round_rect(
-            Rect::from_xywh(
-                self.x + 0.5,
-                self.y + 0.5,
-                self.width - 1.0,
-                self.height - 1.0,
-            ),
-            border_radius,
-            border_radius,
-            &border_paint,
-        );
+        if border_width > 0.0 {
+            canvas.draw_round_rect(
+                Rect::from_xywh(
+                    self.x + border_width / 2.0,
+                    self.y + border_width / 2.0,
+                    self.width - border_width,
+                    self.height - border_width,
+                ),
+                border_radius,
+                border_radius,
+                &border_paint,
+            );
+        }
 
         // Subtle shadow
         if self.hover_progress > 0.0 {
@@ -79,6 +92,10 @@ impl Widget for Card {
                 &shadow_paint,
             );
         }
+
+        if layered {
+            canvas.restore();
+        }
     }
 
     fn contains(&self, x: f32, y: f32) -> bool {
@@ -94,7 +111,7 @@ impl Widget for Card {
         let target_hover = if self.hover { 1.0 } else { 0.0 };
         
         if (self.hover_progress - target_hover).abs() > 0.01 {
-            self.hover_progress += (target_hover - self.hover_progress) * animation_speed;
+            self.hover_progress += (target_hover - self.hover_progress) * animation_rate(animation_speed);
         } else {
             self.hover_progress = target_hover;
         }
@@ -104,6 +121,32 @@ impl Widget for Card {
         println!("Card clicked");
     }
 
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+
+    fn props(&self) -> Vec<Prop> {
+        vec![
+            Prop::number("width", self.width, 40.0, 600.0),
+            Prop::number("height", self.height, 40.0, 400.0),
+            Prop::number("radius", self.style.radius.unwrap_or(Theme::RADIUS_LG), 0.0, 32.0),
+            Prop::number("border_width", self.style.border_width.unwrap_or(1.0), 0.0, 8.0),
+            Prop::number("opacity", self.style.opacity.unwrap_or(1.0), 0.0, 1.0),
+        ]
+    }
+
+    fn set_prop(&mut self, name: &str, value: &PropValue) -> bool {
+        match name {
+            "width" => value.as_number().map(|width| self.width = width),
+            "height" => value.as_number().map(|height| self.height = height),
+            "radius" => value.as_number().map(|radius| self.style.radius = Some(radius)),
+            "border_width" => value.as_number().map(|width| self.style.border_width = Some(width)),
+            "opacity" => value.as_number().map(|opacity| self.style.opacity = Some(opacity)),
+            _ => None,
+        }
+        .is_some()
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -0,0 +1,341 @@
+use std::time::Instant;
+
+use skia_safe::{Canvas, Paint, Point, Rect};
+
+use crate::components::Widget;
+use crate::core::animation_rate;
+use crate::theme::{current_theme, with_alpha};
+
+const BUTTON_SIZE: f32 = 32.0;
+const BUTTON_INSET: f32 = 12.0;
+const DOTS_HEIGHT: f32 = 24.0;
+const DOT_SIZE: f32 = 8.0;
+const DOT_GAP: f32 = 8.0;
+/// Pointer travel below which a press counts as a click on the page
+const CLICK_SLOP: f32 = 4.0;
+/// Release speed in px/s that flings to the neighbouring page
+const FLING_VELOCITY: f32 = 400.0;
+
+/// Horizontally paged viewport with prev/next buttons and dot indicators
+///
+/// Pages are widgets positioned relative to the page's top-left corner. Pages
+/// can be dragged; releasing snaps to the nearest page, and a quick swipe flings
+/// to the next one.
+pub struct Carousel {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    pages: Vec<Box<dyn Widget>>,
+    page: usize,
+    /// Scroll position in pixels; page `i` rests at `i * width`
+    offset: f32,
+    drag: Option<CarouselDrag>,
+    hover_button: Option<bool>,
+}
+
+struct CarouselDrag {
+    start_x: f32,
+    start_offset: f32,
+    moved: bool,
+    last_x: f32,
+    last_time: Instant,
+    velocity: f32,
+}
+
+impl Carousel {
+    /// `height` includes the dot indicator row below the pages
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            pages: Vec::new(),
+            page: 0,
+            offset: 0.0,
+            drag: None,
+            hover_button: None,
+        }
+    }
+
+    pub fn with_page(mut self, page: Box<dyn Widget>) -> Self {
+        self.pages.push(page);
+        self
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+        self.offset = self.page as f32 * width;
+    }
+
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Scroll to `page`; the offset animates there in `update_animation`
+    pub fn set_page(&mut self, page: usize) {
+        self.page = page.min(self.pages.len().saturating_sub(1));
+    }
+
+    pub fn next(&mut self) {
+        self.set_page(self.page + 1);
+    }
+
+    pub fn previous(&mut self) {
+        self.set_page(self.page.saturating_sub(1));
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// True while the pages are still sliding towards the current page
+    pub fn is_animating(&self) -> bool {
+        self.drag.is_none() && (self.offset - self.page as f32 * self.width).abs() > 0.5
+    }
+
+    fn viewport(&self) -> Rect {
+        Rect::from_xywh(self.x, self.y, self.width, self.height - DOTS_HEIGHT)
+    }
+
+    /// Previous (`true`) or next (`false`) button bounds
+    fn button_rect(&self, previous: bool) -> Rect {
+        let viewport = self.viewport();
+        let x = if previous {
+            viewport.left + BUTTON_INSET
+        } else {
+            viewport.right - BUTTON_INSET - BUTTON_SIZE
+        };
+        Rect::from_xywh(x, viewport.center_y() - BUTTON_SIZE / 2.0, BUTTON_SIZE, BUTTON_SIZE)
+    }
+
+    fn button_at(&self, x: f32, y: f32) -> Option<bool> {
+        let point = Point::new(x, y);
+        [true, false].into_iter().find(|&previous| {
+            let enabled = if previous { self.page > 0 } else { self.page + 1 < self.pages.len() };
+            enabled && self.button_rect(previous).contains(point)
+        })
+    }
+
+    fn dot_rect(&self, index: usize) -> Rect {
+        let count = self.pages.len() as f32;
+        let total = count * DOT_SIZE + (count - 1.0) * DOT_GAP;
+        let left = self.x + (self.width - total) / 2.0;
+        Rect::from_xywh(
+            left + index as f32 * (DOT_SIZE + DOT_GAP),
+            self.y + self.height - DOTS_HEIGHT / 2.0 - DOT_SIZE / 2.0,
+            DOT_SIZE,
+            DOT_SIZE,
+        )
+    }
+
+    fn dot_at(&self, x: f32, y: f32) -> Option<usize> {
+        // Hit area is padded to the full dot row height
+        (0..self.pages.len()).find(|&index| {
+            let dot = self.dot_rect(index);
+            x >= dot.left - DOT_GAP / 2.0
+                && x <= dot.right + DOT_GAP / 2.0
+                && y >= self.y + self.height - DOTS_HEIGHT
+                && y <= self.y + self.height
+        })
+    }
+
+    /// Map a window point onto the page under it, in page-local coordinates
+    fn page_point(&self, x: f32, y: f32) -> Option<(usize, f32, f32)> {
+        if !self.viewport().contains(Point::new(x, y)) || self.width <= 0.0 {
+            return None;
+        }
+        let content_x = x - self.x + self.offset;
+        let index = (content_x / self.width).floor();
+        (index >= 0.0 && (index as usize) < self.pages.len())
+            .then_some((index as usize, content_x - index * self.width, y - self.y))
+    }
+
+    /// Buttons and dots navigate; anywhere else on the pages starts a drag
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> bool {
+        if let Some(previous) = self.button_at(x, y) {
+            if previous {
+                self.previous();
+            } else {
+                self.next();
+            }
+            return true;
+        }
+        if let Some(index) = self.dot_at(x, y) {
+            self.set_page(index);
+            return true;
+        }
+        if !self.viewport().contains(Point::new(x, y)) {
+            return false;
+        }
+
+        self.drag = Some(CarouselDrag {
+            start_x: x,
+            start_offset: self.offset,
+            moved: false,
+            last_x: x,
+            last_time: Instant::now(),
+            velocity: 0.0,
+        });
+        true
+    }
+
+    /// Follow the pointer while dragging; returns true if the pages moved
+    pub fn handle_mouse_move(&mut self, x: f32, y: f32) -> bool {
+        let Some(ref mut drag) = self.drag else {
+            self.update_hover(x, y);
+            return false;
+        };
+
+        let now = Instant::now();
+        let dt = now.duration_since(drag.last_time).as_secs_f32();
+        if dt > 0.0 {
+            // Pointer velocity in px/s, smoothed so one jittery sample does not decide a fling
+            drag.velocity = drag.velocity * 0.2 + (x - drag.last_x) / dt * 0.8;
+        }
+        drag.last_x = x;
+        drag.last_time = now;
+        drag.moved |= (x - drag.start_x).abs() > CLICK_SLOP;
+
+        // Rubber-band past the first and last page
+        let max_offset = self.pages.len().saturating_sub(1) as f32 * self.width;
+        let offset = drag.start_offset - (x - drag.start_x);
+        self.offset = if offset < 0.0 {
+            offset / 3.0
+        } else if offset > max_offset {
+            max_offset + (offset - max_offset) / 3.0
+        } else {
+            offset
+        };
+        true
+    }
+
+    /// End a drag: fling or snap to a page, or click the page widget if the pointer barely moved
+    pub fn handle_mouse_up(&mut self, x: f32, y: f32) {
+        let Some(drag) = self.drag.take() else {
+            return;
+        };
+
+        if !drag.moved {
+            if let Some((index, local_x, local_y)) = self.page_point(x, y) {
+                if self.pages[index].contains(local_x, local_y) {
+                    self.pages[index].on_click();
+                }
+            }
+            return;
+        }
+
+        // A stale sample means the pointer stopped before release
+        let velocity = if drag.last_time.elapsed().as_secs_f32() < 0.1 { drag.velocity } else { 0.0 };
+        let nearest = (self.offset / self.width).round().max(0.0) as usize;
+        if velocity <= -FLING_VELOCITY && nearest <= self.page {
+            self.next();
+        } else if velocity >= FLING_VELOCITY && nearest >= self.page {
+            self.previous();
+        } else {
+            self.set_page(nearest);
+        }
+    }
+}
+
+impl Widget for Carousel {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
+        let colors = current_theme();
+        let viewport = self.viewport();
+
+        canvas.save();
+        canvas.clip_rect(viewport, None, true);
+        for (index, page) in self.pages.iter().enumerate() {
+            let page_x = self.x + index as f32 * self.width - self.offset;
+            if page_x + self.width < viewport.left || page_x > viewport.right {
+                continue;
+            }
+            canvas.save();
+            canvas.translate((page_x, self.y));
+            page.draw(canvas, font_manager);
+            canvas.restore();
+        }
+        canvas.restore();
+
+        // Previous/next buttons: outlined circles with a chevron, dimmed at the ends
+        for previous in [true, false] {
+            let rect = self.button_rect(previous);
+            let enabled = if previous { self.page > 0 } else { self.page + 1 < self.pages.len() };
+
+            let mut bg_paint = Paint::default();
+            bg_paint.set_anti_alias(true);
+            bg_paint.set_color(if self.hover_button == Some(previous) { colors.accent } else { colors.background });
+            canvas.draw_circle((rect.center_x(), rect.center_y()), BUTTON_SIZE / 2.0, &bg_paint);
+
+            let mut stroke_paint = Paint::default();
+            stroke_paint.set_anti_alias(true);
+            stroke_paint.set_style(skia_safe::PaintStyle::Stroke);
+            stroke_paint.set_stroke_width(1.0);
+            stroke_paint.set_color(colors.border);
+            canvas.draw_circle((rect.center_x(), rect.center_y()), BUTTON_SIZE / 2.0 - 0.5, &stroke_paint);
+
+            stroke_paint.set_stroke_width(1.5);
+            stroke_paint.set_color(if enabled { colors.foreground } else { with_alpha(colors.muted_foreground, 128) });
+            let (cx, cy) = (rect.center_x(), rect.center_y());
+            let dx = if previous { 2.0 } else { -2.0 };
+            canvas.draw_line((cx + dx, cy - 4.0), (cx - dx, cy), &stroke_paint);
+            canvas.draw_line((cx - dx, cy), (cx + dx, cy + 4.0), &stroke_paint);
+        }
+
+        // Dot indicators; the current page's dot is solid
+        for index in 0..self.pages.len() {
+            let dot = self.dot_rect(index);
+            let mut dot_paint = Paint::default();
+            dot_paint.set_anti_alias(true);
+            dot_paint.set_color(if index == self.page { colors.foreground } else { colors.muted });
+            canvas.draw_round_rect(dot, DOT_SIZE / 2.0, DOT_SIZE / 2.0, &dot_paint);
+        }
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hover_button = self.button_at(x, y);
+        if let Some((index, local_x, local_y)) = self.page_point(x, y) {
+            self.pages[index].update_hover(local_x, local_y);
+        }
+    }
+
+    fn update_animation(&mut self, elapsed: f32) {
+        if self.drag.is_none() {
+            let target = self.page as f32 * self.width;
+            if (self.offset - target).abs() > 0.5 {
+                self.offset += (target - self.offset) * animation_rate(0.2);
+            } else {
+                self.offset = target;
+            }
+        }
+        for page in &mut self.pages {
+            page.update_animation(elapsed);
+        }
+    }
+
+    fn on_click(&mut self) {}
+
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
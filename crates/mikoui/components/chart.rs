@@ -0,0 +1,537 @@
+use std::collections::VecDeque;
+
+use skia_safe::{Canvas, Color, Paint, Path, Point, Rect};
+
+use crate::components::Widget;
+use crate::theme::{current_theme, with_alpha, Theme};
+
+const AXIS_WIDTH: f32 = 44.0;
+const LABEL_HEIGHT: f32 = 20.0;
+const TICK_COUNT: usize = 4;
+const TOOLTIP_PADDING: f32 = 8.0;
+const TOOLTIP_ROW_HEIGHT: f32 = 18.0;
+
+/// Palette cycled through by series without an explicit color
+fn series_color(index: usize) -> Color {
+    [Theme::INFO, Theme::SUCCESS, Theme::WARNING, Theme::ERROR][index % 4]
+}
+
+/// Short axis/tooltip label: `1.2k`, `3.5`, `12`
+pub fn format_value(value: f32) -> String {
+    if value.abs() >= 1000.0 {
+        format!("{:.1}k", value / 1000.0)
+    } else if value.fract().abs() > f32::EPSILON {
+        format!("{:.1}", value)
+    } else {
+        format!("{:.0}", value)
+    }
+}
+
+/// Round a tick step up to 1, 2 or 5 times a power of ten
+fn nice_step(raw: f32) -> f32 {
+    if raw <= 0.0 || !raw.is_finite() {
+        return 1.0;
+    }
+    let magnitude = 10f32.powf(raw.log10().floor());
+    let normalized = raw / magnitude;
+    let nice = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * magnitude
+}
+
+/// One named line or bar group
+pub struct ChartSeries {
+    pub name: String,
+    pub values: Vec<f32>,
+    pub color: Option<Color>,
+}
+
+impl ChartSeries {
+    pub fn new(name: impl Into<String>, values: Vec<f32>) -> Self {
+        Self {
+            name: name.into(),
+            values,
+            color: None,
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChartKind {
+    Line,
+    Bar,
+}
+
+/// Line or bar chart with a value axis, category labels and a hover tooltip
+pub struct Chart {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    kind: ChartKind,
+    labels: Vec<String>,
+    series: Vec<ChartSeries>,
+    y_range: Option<(f32, f32)>,
+    hover: Option<usize>,
+}
+
+impl Chart {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, kind: ChartKind) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            kind,
+            labels: Vec::new(),
+            series: Vec::new(),
+            y_range: None,
+            hover: None,
+        }
+    }
+
+    /// Category labels along the bottom, one per value
+    pub fn labels(mut self, labels: Vec<String>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    pub fn with_series(mut self, series: ChartSeries) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Fixed value range instead of fitting the data
+    pub fn y_range(mut self, min: f32, max: f32) -> Self {
+        self.y_range = Some((min, max));
+        self
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+    }
+
+    pub fn series_mut(&mut self) -> &mut Vec<ChartSeries> {
+        &mut self.series
+    }
+
+    /// Category under the pointer, if any
+    pub fn hovered(&self) -> Option<usize> {
+        self.hover
+    }
+
+    fn point_count(&self) -> usize {
+        self.series
+            .iter()
+            .map(|series| series.values.len())
+            .max()
+            .unwrap_or(0)
+            .max(self.labels.len())
+    }
+
+    fn plot_rect(&self) -> Rect {
+        Rect::from_ltrb(
+            self.x + AXIS_WIDTH,
+            self.y + TOOLTIP_PADDING,
+            self.x + self.width,
+            self.y + self.height - LABEL_HEIGHT,
+        )
+    }
+
+    /// Axis range and tick step; bars always include zero
+    fn scale(&self) -> (f32, f32, f32) {
+        if let Some((min, max)) = self.y_range {
+            return (min, max, nice_step((max - min) / TICK_COUNT as f32));
+        }
+
+        let values = self.series.iter().flat_map(|series| series.values.iter().copied());
+        let (mut min, mut max) = values.fold((f32::MAX, f32::MIN), |(lo, hi), v| (lo.min(v), hi.max(v)));
+        if min > max {
+            (min, max) = (0.0, 1.0);
+        }
+        if self.kind == ChartKind::Bar {
+            min = min.min(0.0);
+            max = max.max(0.0);
+        }
+        if (max - min).abs() < f32::EPSILON {
+            max = min + 1.0;
+        }
+
+        let step = nice_step((max - min) / TICK_COUNT as f32);
+        ((min / step).floor() * step, (max / step).ceil() * step, step)
+    }
+
+    /// Horizontal center of category `index`
+    fn column_x(&self, plot: Rect, index: usize) -> f32 {
+        let count = self.point_count().max(1) as f32;
+        match self.kind {
+            // Bars sit in the middle of equal slots; lines span edge to edge
+            ChartKind::Bar => plot.left + plot.width() * (index as f32 + 0.5) / count,
+            ChartKind::Line if count > 1.0 => plot.left + plot.width() * index as f32 / (count - 1.0),
+            ChartKind::Line => plot.center_x(),
+        }
+    }
+
+    fn column_at(&self, x: f32, y: f32) -> Option<usize> {
+        let plot = self.plot_rect();
+        if !plot.contains(Point::new(x, y)) {
+            return None;
+        }
+        (0..self.point_count()).min_by(|&a, &b| {
+            let da = (self.column_x(plot, a) - x).abs();
+            let db = (self.column_x(plot, b) - x).abs();
+            da.total_cmp(&db)
+        })
+    }
+
+    fn draw_axes(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager, plot: Rect, scale: (f32, f32, f32)) {
+        let colors = current_theme();
+        let (min, max, step) = scale;
+        let value_y = |value: f32| plot.bottom - (value - min) / (max - min) * plot.height();
+
+        let mut grid_paint = Paint::default();
+        grid_paint.set_color(with_alpha(colors.border, 160));
+        grid_paint.set_stroke_width(1.0);
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        text_paint.set_color(colors.muted_foreground);
+
+        let mut value = min;
+        while value <= max + step * 0.5 {
+            let y = value_y(value).round() + 0.5;
+            canvas.draw_line((plot.left, y), (plot.right, y), &grid_paint);
+
+            let label = format_value(value);
+            let font = font_manager.create_font(&label, Theme::TEXT_XS, 400);
+            let (label_width, _) = font.measure_str(&label, None);
+            canvas.draw_str(&label, (plot.left - 8.0 - label_width, y + 4.0), &font, &text_paint);
+            value += step;
+        }
+
+        // Skip labels that would overlap their neighbours
+        let count = self.point_count();
+        let slot = plot.width() / count.max(1) as f32;
+        let every = (48.0 / slot).ceil().max(1.0) as usize;
+        for (index, label) in self.labels.iter().enumerate().filter(|(index, _)| index % every == 0) {
+            let font = font_manager.create_font(label, Theme::TEXT_XS, 400);
+            let (label_width, _) = font.measure_str(label, None);
+            let x = self.column_x(plot, index) - label_width / 2.0;
+            canvas.draw_str(label, (x, plot.bottom + LABEL_HEIGHT - 5.0), &font, &text_paint);
+        }
+    }
+
+    fn draw_tooltip(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager, plot: Rect, index: usize) {
+        let colors = current_theme();
+        let title = self.labels.get(index).cloned().unwrap_or_else(|| format!("#{}", index + 1));
+        let rows: Vec<(Color, String)> = self.series
+            .iter()
+            .enumerate()
+            .filter_map(|(series_index, series)| {
+                let value = series.values.get(index)?;
+                let color = series.color.unwrap_or(series_color(series_index));
+                Some((color, format!("{}: {}", series.name, format_value(*value))))
+            })
+            .collect();
+
+        let title_font = font_manager.create_font(&title, Theme::TEXT_XS, 600);
+        let row_font = font_manager.create_font(&title, Theme::TEXT_XS, 400);
+        let text_width = rows
+            .iter()
+            .map(|(_, text)| row_font.measure_str(text, None).0 + 14.0)
+            .fold(title_font.measure_str(&title, None).0, f32::max);
+        let width = text_width + TOOLTIP_PADDING * 2.0;
+        let height = TOOLTIP_PADDING * 2.0 + TOOLTIP_ROW_HEIGHT * (rows.len() + 1) as f32;
+
+        // Right of the column, flipped left near the edge
+        let column = self.column_x(plot, index);
+        let left = if column + 12.0 + width > plot.right { column - 12.0 - width } else { column + 12.0 };
+        let rect = Rect::from_xywh(left, plot.top, width, height);
+
+        let mut bg_paint = Paint::default();
+        bg_paint.set_anti_alias(true);
+        bg_paint.set_color(colors.popover);
+        canvas.draw_round_rect(rect, Theme::RADIUS_MD, Theme::RADIUS_MD, &bg_paint);
+        let mut border_paint = Paint::default();
+        border_paint.set_anti_alias(true);
+        border_paint.set_style(skia_safe::PaintStyle::Stroke);
+        border_paint.set_stroke_width(1.0);
+        border_paint.set_color(colors.border);
+        canvas.draw_round_rect(rect.with_inset((0.5, 0.5)), Theme::RADIUS_MD, Theme::RADIUS_MD, &border_paint);
+
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        text_paint.set_color(colors.popover_foreground);
+        let mut baseline = rect.top + TOOLTIP_PADDING + 13.0;
+        canvas.draw_str(&title, (rect.left + TOOLTIP_PADDING, baseline), &title_font, &text_paint);
+
+        let mut swatch_paint = Paint::default();
+        swatch_paint.set_anti_alias(true);
+        for (color, text) in rows {
+            baseline += TOOLTIP_ROW_HEIGHT;
+            swatch_paint.set_color(color);
+            canvas.draw_circle((rect.left + TOOLTIP_PADDING + 4.0, baseline - 4.0), 4.0, &swatch_paint);
+            canvas.draw_str(&text, (rect.left + TOOLTIP_PADDING + 14.0, baseline), &row_font, &text_paint);
+        }
+    }
+}
+
+impl Widget for Chart {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
+        let colors = current_theme();
+        let plot = self.plot_rect();
+        let scale = self.scale();
+        let (min, max, _) = scale;
+        let value_y = |value: f32| plot.bottom - (value - min) / (max - min) * plot.height();
+
+        self.draw_axes(canvas, font_manager, plot, scale);
+
+        if let Some(index) = self.hover {
+            let mut guide_paint = Paint::default();
+            match self.kind {
+                ChartKind::Line => {
+                    guide_paint.set_color(colors.muted_foreground);
+                    guide_paint.set_stroke_width(1.0);
+                    let x = self.column_x(plot, index);
+                    canvas.draw_line((x, plot.top), (x, plot.bottom), &guide_paint);
+                }
+                ChartKind::Bar => {
+                    guide_paint.set_color(with_alpha(colors.muted, 160));
+                    let slot = plot.width() / self.point_count().max(1) as f32;
+                    let x = self.column_x(plot, index) - slot / 2.0;
+                    canvas.draw_rect(Rect::from_xywh(x, plot.top, slot, plot.height()), &guide_paint);
+                }
+            }
+        }
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        match self.kind {
+            ChartKind::Line => {
+                paint.set_style(skia_safe::PaintStyle::Stroke);
+                paint.set_stroke_width(2.0);
+                for (series_index, series) in self.series.iter().enumerate() {
+                    paint.set_color(series.color.unwrap_or(series_color(series_index)));
+                    let mut path = Path::new();
+                    for (index, value) in series.values.iter().enumerate() {
+                        let point = (self.column_x(plot, index), value_y(*value));
+                        if index == 0 {
+                            path.move_to(point);
+                        } else {
+                            path.line_to(point);
+                        }
+                    }
+                    canvas.draw_path(&path, &paint);
+
+                    if let Some((index, value)) = self.hover.and_then(|index| Some((index, series.values.get(index)?))) {
+                        let point = (self.column_x(plot, index), value_y(*value));
+                        let mut dot_paint = Paint::default();
+                        dot_paint.set_anti_alias(true);
+                        dot_paint.set_color(paint.color());
+                        canvas.draw_circle(point, 4.0, &dot_paint);
+                    }
+                }
+            }
+            ChartKind::Bar => {
+                let series_count = self.series.len().max(1) as f32;
+                let slot = plot.width() / self.point_count().max(1) as f32;
+                let bar_width = (slot * 0.7 / series_count).max(1.0);
+                let zero_y = value_y(0.0f32.clamp(min, max));
+                for (series_index, series) in self.series.iter().enumerate() {
+                    paint.set_color(series.color.unwrap_or(series_color(series_index)));
+                    for (index, value) in series.values.iter().enumerate() {
+                        let left = self.column_x(plot, index) - bar_width * series_count / 2.0
+                            + bar_width * series_index as f32;
+                        let top = value_y(*value);
+                        let rect = Rect::from_ltrb(left + 1.0, top.min(zero_y), left + bar_width - 1.0, top.max(zero_y));
+                        canvas.draw_round_rect(rect, Theme::RADIUS_SM, Theme::RADIUS_SM, &paint);
+                    }
+                }
+            }
+        }
+
+        if let Some(index) = self.hover {
+            self.draw_tooltip(canvas, font_manager, plot, index);
+        }
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hover = self.column_at(x, y);
+    }
+
+    fn update_animation(&mut self, _elapsed: f32) {}
+
+    fn on_click(&mut self) {}
+
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Tiny axis-less line chart for inline trends, e.g. frame times in a status area
+pub struct Sparkline {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    values: VecDeque<f32>,
+    max_points: usize,
+    color: Option<Color>,
+    fill: bool,
+}
+
+impl Sparkline {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            values: VecDeque::new(),
+            max_points: 60,
+            color: None,
+            fill: true,
+        }
+    }
+
+    /// Oldest values are dropped once more than `max_points` are pushed
+    pub fn max_points(mut self, max_points: usize) -> Self {
+        self.max_points = max_points.max(2);
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Shade the area under the line
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+    }
+
+    pub fn push(&mut self, value: f32) {
+        self.values.push_back(value);
+        while self.values.len() > self.max_points {
+            self.values.pop_front();
+        }
+    }
+
+    pub fn set_values(&mut self, values: &[f32]) {
+        self.values.clear();
+        for value in values {
+            self.push(*value);
+        }
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = f32> + '_ {
+        self.values.iter().copied()
+    }
+}
+
+impl Widget for Sparkline {
+    fn draw(&self, canvas: &Canvas, _font_manager: &mut crate::core::FontManager) {
+        if self.values.len() < 2 {
+            return;
+        }
+
+        let (min, max) = self.values.iter().fold((f32::MAX, f32::MIN), |(lo, hi), v| (lo.min(*v), hi.max(*v)));
+        let range = if max - min > f32::EPSILON { max - min } else { 1.0 };
+        let step = self.width / (self.max_points - 1) as f32;
+        // Newest value sits at the right edge
+        let start_x = self.x + self.width - step * (self.values.len() - 1) as f32;
+        let points: Vec<Point> = self.values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let y = self.y + self.height - 1.0 - (value - min) / range * (self.height - 2.0);
+                Point::new(start_x + step * index as f32, y)
+            })
+            .collect();
+
+        let color = self.color.unwrap_or(Theme::INFO);
+        let mut line = Path::new();
+        line.move_to(points[0]);
+        for point in &points[1..] {
+            line.line_to(*point);
+        }
+
+        if self.fill {
+            let mut area = Path::new();
+            area.move_to((points[0].x, self.y + self.height));
+            for point in &points {
+                area.line_to(*point);
+            }
+            area.line_to((points[points.len() - 1].x, self.y + self.height));
+            area.close();
+
+            let mut fill_paint = Paint::default();
+            fill_paint.set_anti_alias(true);
+            fill_paint.set_color(with_alpha(color, 48));
+            canvas.draw_path(&area, &fill_paint);
+        }
+
+        let mut line_paint = Paint::default();
+        line_paint.set_anti_alias(true);
+        line_paint.set_style(skia_safe::PaintStyle::Stroke);
+        line_paint.set_stroke_width(1.5);
+        line_paint.set_color(color);
+        canvas.draw_path(&line, &line_paint);
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    fn update_hover(&mut self, _x: f32, _y: f32) {}
+
+    fn update_animation(&mut self, _elapsed: f32) {}
+
+    fn on_click(&mut self) {}
+
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -1,6 +1,7 @@
 use skia_safe::{Canvas, Paint, Rect};
 
-use crate::components::Widget;
+use crate::components::{draw_focus_outline, FocusOrigin, Prop, PropValue, Widget};
+use crate::core::animation_rate;
 use crate::theme::{current_theme, lerp_color, with_alpha, Theme};
 
 pub struct Checkbox {
@@ -15,6 +16,7 @@ pub struct Checkbox {
     active: bool,
     active_progress: f32,
     disabled: bool,
+    focus: Option<FocusOrigin>,
 }
 
 impl Checkbox {
@@ -31,6 +33,7 @@ impl Checkbox {
             active: false,
             active_progress: 0.0,
             disabled: false,
+            focus: None,
         }
     }
 
@@ -169,6 +172,10 @@ impl Widget for Checkbox {
             &font,
             &text_paint,
         );
+
+        if self.focus == Some(FocusOrigin::Keyboard) {
+            draw_focus_outline(canvas, Rect::from_xywh(self.x, self.y, self.size, self.size), border_radius);
+        }
     }
 
     fn contains(&self, x: f32, y: f32) -> bool {
@@ -176,7 +183,7 @@ impl Widget for Checkbox {
     }
 
     fn update_hover(&mut self, x: f32, y: f32) {
-        self.hover = self.contains(x, y);
+        self.hover = self.hit_test(x, y);
     }
 
     fn update_animation(&mut self, _elapsed: f32) {
@@ -185,7 +192,7 @@ impl Widget for Checkbox {
         // Hover animation
         let target_hover = if self.hover { 1.0 } else { 0.0 };
         if (self.hover_progress - target_hover).abs() > 0.01 {
-            self.hover_progress += (target_hover - self.hover_progress) * animation_speed;
+            self.hover_progress += (target_hover - self.hover_progress) * animation_rate(animation_speed);
         } else {
             self.hover_progress = target_hover;
         }
@@ -193,7 +200,7 @@ impl Widget for Checkbox {
         // Check animation
         let target_check = if self.checked { 1.0 } else { 0.0 };
         if (self.check_progress - target_check).abs() > 0.01 {
-            self.check_progress += (target_check - self.check_progress) * (animation_speed * 1.5);
+            self.check_progress += (target_check - self.check_progress) * animation_rate(animation_speed * 1.5);
         } else {
             self.check_progress = target_check;
         }
@@ -201,7 +208,7 @@ impl Widget for Checkbox {
         // Active animation
         let target_active = if self.active { 1.0 } else { 0.0 };
         if (self.active_progress - target_active).abs() > 0.01 {
-            self.active_progress += (target_active - self.active_progress) * (animation_speed * 3.0);
+            self.active_progress += (target_active - self.active_progress) * animation_rate(animation_speed * 3.0);
         } else {
             self.active_progress = target_active;
         }
@@ -220,6 +227,41 @@ impl Widget for Checkbox {
         }
     }
 
+    fn is_focusable(&self) -> bool {
+        !self.disabled
+    }
+
+    fn set_focus(&mut self, origin: Option<FocusOrigin>) {
+        self.focus = origin;
+    }
+
+    fn bounds(&self) -> Option<Rect> {
+        // The same area `contains` hit-tests, label included
+        Some(Rect::from_xywh(self.x, self.y, 200.0, self.size))
+    }
+
+    fn debug_state(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("label", self.label.to_string()),
+            ("checked", self.checked.to_string()),
+            ("hover", self.hover.to_string()),
+            ("disabled", self.disabled.to_string()),
+        ]
+    }
+
+    fn props(&self) -> Vec<Prop> {
+        vec![Prop::bool("checked", self.checked), Prop::bool("disabled", self.disabled)]
+    }
+
+    fn set_prop(&mut self, name: &str, value: &PropValue) -> bool {
+        match name {
+            "checked" => value.as_bool().map(|checked| self.checked = checked),
+            "disabled" => value.as_bool().map(|disabled| self.disabled = disabled),
+            _ => None,
+        }
+        .is_some()
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
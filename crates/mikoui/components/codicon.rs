@@ -12,3 +12,10 @@
 
 // Include the generated icon constants from build.rs
 include!(concat!(env!("OUT_DIR"), "/codicon_generated.rs"));
+
+impl CodiconIcons {
+    /// Icon by its file name without `.svg`, e.g. "file-code"
+    pub fn by_name(name: &str) -> Option<&'static str> {
+        Self::ALL.iter().find(|(icon, _)| *icon == name).map(|(_, svg)| *svg)
+    }
+}
@@ -0,0 +1,270 @@
+use skia_safe::{Canvas, Color, Paint, Rect};
+
+use crate::components::{Input, Widget};
+use crate::theme::{color_to_hex, current_theme, parse_hex_color, Size, Theme};
+
+const SWATCH_HEIGHT: f32 = 40.0;
+const CHANNEL_HEIGHT: f32 = 24.0;
+const LABEL_WIDTH: f32 = 18.0;
+const VALUE_WIDTH: f32 = 36.0;
+const CHANNELS: [&str; 4] = ["R", "G", "B", "A"];
+
+/// RGBA color picker: a preview swatch, one slider per channel and a hex input
+pub struct ColorPicker {
+    x: f32,
+    y: f32,
+    width: f32,
+    color: Color,
+    dragging: Option<usize>,
+    hover_channel: Option<usize>,
+    hex_input: Input,
+}
+
+impl ColorPicker {
+    pub fn new(x: f32, y: f32, width: f32, color: Color) -> Self {
+        let hex_input = Input::new(x, 0.0, width, "#RRGGBB")
+            .size(Size::Sm)
+            .with_validator(|text| match parse_hex_color(text) {
+                Some(_) => Ok(()),
+                None => Err(crate::t!("ui.colorpicker.invalid_hex")),
+            });
+        let mut picker = Self {
+            x,
+            y,
+            width,
+            color,
+            dragging: None,
+            hover_channel: None,
+            hex_input,
+        };
+        picker.set_position(x, y);
+        picker.set_color(color);
+        picker
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Replace the color and refresh the hex input
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+        self.hex_input.set_text(color_to_hex(color));
+    }
+
+    pub fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+        let hex_y = self.channel_top(CHANNELS.len()) + Theme::SPACE_2;
+        self.hex_input.set_bounds(x, hex_y, self.width);
+    }
+
+    pub fn height(&self) -> f32 {
+        SWATCH_HEIGHT + CHANNELS.len() as f32 * CHANNEL_HEIGHT + Theme::SPACE_2 * 2.0 + self.hex_input.height()
+    }
+
+    pub fn is_editing_hex(&self) -> bool {
+        self.hex_input.is_focused()
+    }
+
+    fn channel_top(&self, channel: usize) -> f32 {
+        self.y + SWATCH_HEIGHT + Theme::SPACE_2 + channel as f32 * CHANNEL_HEIGHT
+    }
+
+    fn track_rect(&self, channel: usize) -> Rect {
+        let left = self.x + LABEL_WIDTH;
+        let width = self.width - LABEL_WIDTH - VALUE_WIDTH;
+        Rect::from_xywh(left, self.channel_top(channel) + CHANNEL_HEIGHT / 2.0 - 3.0, width, 6.0)
+    }
+
+    fn channel_at(&self, x: f32, y: f32) -> Option<usize> {
+        (0..CHANNELS.len()).find(|&channel| {
+            let top = self.channel_top(channel);
+            y >= top && y < top + CHANNEL_HEIGHT && x >= self.x && x <= self.x + self.width
+        })
+    }
+
+    fn channel_value(&self, channel: usize) -> u8 {
+        match channel {
+            0 => self.color.r(),
+            1 => self.color.g(),
+            2 => self.color.b(),
+            _ => self.color.a(),
+        }
+    }
+
+    fn with_channel(color: Color, channel: usize, value: u8) -> Color {
+        match channel {
+            0 => Color::from_argb(color.a(), value, color.g(), color.b()),
+            1 => Color::from_argb(color.a(), color.r(), value, color.b()),
+            2 => Color::from_argb(color.a(), color.r(), color.g(), value),
+            _ => Color::from_argb(value, color.r(), color.g(), color.b()),
+        }
+    }
+
+    /// Set a channel from a pointer x position; returns true if the color changed
+    fn drag_channel(&mut self, channel: usize, x: f32) -> bool {
+        let track = self.track_rect(channel);
+        let t = ((x - track.left) / track.width()).clamp(0.0, 1.0);
+        let value = (t * 255.0).round() as u8;
+        if value == self.channel_value(channel) {
+            return false;
+        }
+        self.set_color(Self::with_channel(self.color, channel, value));
+        true
+    }
+
+    /// Start dragging a channel or focus the hex input; returns true if the color changed
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> bool {
+        self.hex_input.set_focused(self.hex_input.hit_test(x, y));
+        self.dragging = self.channel_at(x, y);
+        match self.dragging {
+            Some(channel) => self.drag_channel(channel, x),
+            None => false,
+        }
+    }
+
+    /// Continue a channel drag; returns true if the color changed
+    pub fn handle_mouse_move(&mut self, x: f32, y: f32) -> bool {
+        match self.dragging {
+            Some(channel) => self.drag_channel(channel, x),
+            None => {
+                self.update_hover(x, y);
+                false
+            }
+        }
+    }
+
+    pub fn handle_mouse_up(&mut self) {
+        self.dragging = None;
+    }
+
+    /// Type into the hex input; returns true once it holds a new valid color
+    pub fn handle_char(&mut self, c: char) -> bool {
+        if !self.hex_input.is_focused() {
+            return false;
+        }
+        self.hex_input.handle_char(c);
+        self.apply_hex()
+    }
+
+    pub fn handle_backspace(&mut self) -> bool {
+        if !self.hex_input.is_focused() {
+            return false;
+        }
+        self.hex_input.handle_backspace();
+        self.apply_hex()
+    }
+
+    fn apply_hex(&mut self) -> bool {
+        match parse_hex_color(self.hex_input.text()) {
+            Some(color) if color != self.color => {
+                self.color = color;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Widget for ColorPicker {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
+        let colors = current_theme();
+
+        // Swatch over a checkerboard so translucent colors are visible
+        let swatch = Rect::from_xywh(self.x, self.y, self.width, SWATCH_HEIGHT);
+        canvas.save();
+        canvas.clip_rect(swatch, None, true);
+        let mut check_paint = Paint::default();
+        check_paint.set_color(colors.muted);
+        let cell = 8.0;
+        for row in 0..(SWATCH_HEIGHT / cell) as usize {
+            for col in 0..(self.width / cell).ceil() as usize {
+                if (row + col) % 2 == 0 {
+                    canvas.draw_rect(
+                        Rect::from_xywh(self.x + col as f32 * cell, self.y + row as f32 * cell, cell, cell),
+                        &check_paint,
+                    );
+                }
+            }
+        }
+        let mut swatch_paint = Paint::default();
+        swatch_paint.set_color(self.color);
+        canvas.draw_rect(swatch, &swatch_paint);
+        canvas.restore();
+
+        let mut border_paint = Paint::default();
+        border_paint.set_anti_alias(true);
+        border_paint.set_style(skia_safe::PaintStyle::Stroke);
+        border_paint.set_color(colors.border);
+        border_paint.set_stroke_width(1.0);
+        canvas.draw_round_rect(swatch, Theme::RADIUS_SM, Theme::RADIUS_SM, &border_paint);
+
+        // Channel sliders
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        for (channel, label) in CHANNELS.iter().enumerate() {
+            let track = self.track_rect(channel);
+            let value = self.channel_value(channel);
+            let baseline = track.center_y() + 4.0;
+
+            let font = font_manager.create_font(label, Theme::TEXT_XS, 500);
+            text_paint.set_color(colors.muted_foreground);
+            canvas.draw_str(label, (self.x, baseline), &font, &text_paint);
+
+            // Track filled up to the channel value
+            let mut track_paint = Paint::default();
+            track_paint.set_anti_alias(true);
+            track_paint.set_color(colors.secondary);
+            canvas.draw_round_rect(track, 3.0, 3.0, &track_paint);
+            track_paint.set_color(match channel {
+                0 => Color::from_argb(255, 239, 68, 68),
+                1 => Color::from_argb(255, 34, 197, 94),
+                2 => Color::from_argb(255, 59, 130, 246),
+                _ => colors.foreground,
+            });
+            let filled = Rect::from_xywh(track.left, track.top, track.width() * value as f32 / 255.0, track.height());
+            canvas.draw_round_rect(filled, 3.0, 3.0, &track_paint);
+
+            // Thumb
+            let thumb_x = filled.right;
+            let active = self.dragging == Some(channel) || self.hover_channel == Some(channel);
+            let radius = if active { 7.0 } else { 6.0 };
+            let mut thumb_paint = Paint::default();
+            thumb_paint.set_anti_alias(true);
+            thumb_paint.set_color(colors.background);
+            canvas.draw_circle((thumb_x, track.center_y()), radius, &thumb_paint);
+            canvas.draw_circle((thumb_x, track.center_y()), radius - 0.5, &border_paint);
+
+            let value_text = value.to_string();
+            let value_font = font_manager.create_font(&value_text, Theme::TEXT_XS, 400);
+            text_paint.set_color(colors.foreground);
+            canvas.draw_str(&value_text, (track.right + Theme::SPACE_2, baseline), &value_font, &text_paint);
+        }
+
+        self.hex_input.draw(canvas, font_manager);
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height()
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hover_channel = self.channel_at(x, y);
+        self.hex_input.update_hover(x, y);
+    }
+
+    fn update_animation(&mut self, elapsed: f32) {
+        self.hex_input.update_animation(elapsed);
+    }
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -1,6 +1,6 @@
 use skia_safe::{Canvas, Color, Paint, Rect};
 use crate::components::Widget;
-use crate::core::FontManager;
+use crate::core::{animation_rate, FontManager};
 use crate::theme::{current_theme, Theme};
 
 #[derive(Clone)]
@@ -91,6 +91,29 @@ impl ContextMenu {
         self.visible
     }
 
+    /// Replace the items, e.g. before showing the menu for a different target
+    pub fn set_items(&mut self, items: Vec<MenuItem>) {
+        self.hover_progress = vec![0.0; items.len()];
+        self.items = items;
+        self.hover_index = None;
+    }
+
+    /// Width and height of the open menu, for keeping it inside the window
+    pub fn size(&self) -> (f32, f32) {
+        (self.width, self.total_height())
+    }
+
+    /// Handle a click and return the clicked item ID, hiding the menu
+    pub fn handle_click(&mut self) -> Option<usize> {
+        let item = self.hover_index.map(|index| &self.items[index])?;
+        if item.disabled {
+            return None;
+        }
+        let id = item.id;
+        self.hide();
+        Some(id)
+    }
+
     fn item_height(&self) -> f32 {
         32.0
     }
@@ -276,7 +299,7 @@ impl Widget for ContextMenu {
         for i in 0..self.hover_progress.len() {
             let target = if self.hover_index == Some(i) { 1.0 } else { 0.0 };
             if (self.hover_progress[i] - target).abs() > 0.01 {
-                self.hover_progress[i] += (target - self.hover_progress[i]) * animation_speed;
+                self.hover_progress[i] += (target - self.hover_progress[i]) * animation_rate(animation_speed);
             } else {
                 self.hover_progress[i] = target;
             }
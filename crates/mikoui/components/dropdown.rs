@@ -1,6 +1,7 @@
 use skia_safe::{Canvas, Color, Paint, Rect};
-use crate::components::Widget;
-use crate::core::FontManager;
+use crate::components::props::{size_from, size_prop};
+use crate::components::{draw_focus_outline, FocusOrigin, Prop, PropValue, Widget};
+use crate::core::{animation_rate, FontManager};
 use crate::theme::{current_theme, with_alpha, Size, Theme};
 
 pub struct Dropdown {
@@ -16,6 +17,7 @@ pub struct Dropdown {
     hover_progress: f32,
     option_hover_progress: Vec<f32>,
     size: Size,
+    focus: Option<FocusOrigin>,
 }
 
 impl Dropdown {
@@ -34,6 +36,7 @@ impl Dropdown {
             hover_progress: 0.0,
             option_hover_progress,
             size: Size::Md,
+            focus: None,
         }
     }
     
@@ -160,6 +163,8 @@ impl Widget for Dropdown {
                 Theme::RADIUS_MD + 1.5,
                 &ring_paint,
             );
+        } else if self.focus == Some(FocusOrigin::Keyboard) {
+            draw_focus_outline(canvas, button_rect, Theme::RADIUS_MD);
         }
 
         // Draw selected value
@@ -324,7 +329,7 @@ impl Widget for Dropdown {
         // Button hover animation
         let target_hover = if self.hover { 1.0 } else { 0.0 };
         if (self.hover_progress - target_hover).abs() > 0.01 {
-            self.hover_progress += (target_hover - self.hover_progress) * animation_speed;
+            self.hover_progress += (target_hover - self.hover_progress) * animation_rate(animation_speed);
         } else {
             self.hover_progress = target_hover;
         }
@@ -333,7 +338,7 @@ impl Widget for Dropdown {
         for i in 0..self.option_hover_progress.len() {
             let target = if self.hover_option == Some(i) { 1.0 } else { 0.0 };
             if (self.option_hover_progress[i] - target).abs() > 0.01 {
-                self.option_hover_progress[i] += (target - self.option_hover_progress[i]) * animation_speed;
+                self.option_hover_progress[i] += (target - self.option_hover_progress[i]) * animation_rate(animation_speed);
             } else {
                 self.option_hover_progress[i] = target;
             }
@@ -356,6 +361,32 @@ impl Widget for Dropdown {
         }
     }
 
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, origin: Option<FocusOrigin>) {
+        self.focus = origin;
+        if origin.is_none() {
+            self.open = false;
+        }
+    }
+
+    fn props(&self) -> Vec<Prop> {
+        // The options aren't known up front, so the selection is a number rather than a choice
+        let last = self.options.len().saturating_sub(1) as f32;
+        vec![Prop::number("selected", self.selected_index as f32, 0.0, last), size_prop(self.size)]
+    }
+
+    fn set_prop(&mut self, name: &str, value: &PropValue) -> bool {
+        match name {
+            "selected" => value.as_number().map(|index| self.set_selected(index.round() as usize)),
+            "size" => size_from(value).map(|size| self.size = size),
+            _ => None,
+        }
+        .is_some()
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
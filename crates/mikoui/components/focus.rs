@@ -0,0 +1,104 @@
+use skia_safe::{Canvas, Paint, Rect};
+
+use crate::components::Widget;
+use crate::theme::current_theme;
+
+/// Width of the keyboard focus outline
+pub const FOCUS_OUTLINE_WIDTH: f32 = 2.0;
+/// Gap between a widget's edge and its focus outline
+pub const FOCUS_OUTLINE_OFFSET: f32 = 2.0;
+
+/// How a widget received focus; only keyboard focus draws the outline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FocusOrigin {
+    Keyboard,
+    Pointer,
+}
+
+/// Draw the focus-visible outline around `rect` in the theme's ring color
+///
+/// The outline sits outside the widget so it never covers its border or fill.
+pub fn draw_focus_outline(canvas: &Canvas, rect: Rect, radius: f32) {
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_style(skia_safe::PaintStyle::Stroke);
+    paint.set_stroke_width(FOCUS_OUTLINE_WIDTH);
+    paint.set_color(current_theme().ring);
+
+    let inset = -(FOCUS_OUTLINE_OFFSET + FOCUS_OUTLINE_WIDTH / 2.0);
+    let radius = radius + FOCUS_OUTLINE_OFFSET + FOCUS_OUTLINE_WIDTH / 2.0;
+    canvas.draw_round_rect(rect.with_inset((inset, inset)), radius, radius, &paint);
+}
+
+/// Tracks which widget of a list has focus and moves it with Tab / Shift+Tab
+///
+/// Widgets opt in through `Widget::is_focusable` and are told about focus
+/// changes through `Widget::set_focus`.
+#[derive(Debug, Default)]
+pub struct FocusManager {
+    focused: Option<usize>,
+}
+
+impl FocusManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    /// Move focus to `index`, or clear it with `None`
+    pub fn focus(&mut self, widgets: &mut [Box<dyn Widget>], index: Option<usize>, origin: FocusOrigin) {
+        let index = index.filter(|&i| widgets.get(i).is_some_and(|widget| widget.is_focusable()));
+        if let Some(previous) = self.focused.and_then(|i| widgets.get_mut(i)) {
+            previous.set_focus(None);
+        }
+        if let Some(widget) = index.and_then(|i| widgets.get_mut(i)) {
+            widget.set_focus(Some(origin));
+        }
+        self.focused = index;
+    }
+
+    /// Focus the next focusable widget, wrapping around (Tab)
+    pub fn focus_next(&mut self, widgets: &mut [Box<dyn Widget>]) -> Option<usize> {
+        let count = widgets.len();
+        let start = self.focused.map_or(0, |i| i + 1);
+        let next = (0..count)
+            .map(|offset| (start + offset) % count)
+            .find(|&i| widgets[i].is_focusable());
+        self.focus(widgets, next, FocusOrigin::Keyboard);
+        self.focused
+    }
+
+    /// Focus the previous focusable widget, wrapping around (Shift+Tab)
+    pub fn focus_previous(&mut self, widgets: &mut [Box<dyn Widget>]) -> Option<usize> {
+        let count = widgets.len();
+        let start = self.focused.unwrap_or(0) + count;
+        let previous = (1..=count)
+            .map(|offset| (start - offset) % count)
+            .find(|&i| widgets[i].is_focusable());
+        self.focus(widgets, previous, FocusOrigin::Keyboard);
+        self.focused
+    }
+
+    /// Pointer press: focus the focusable widget under the point, or clear focus
+    pub fn focus_at(&mut self, widgets: &mut [Box<dyn Widget>], x: f32, y: f32) -> Option<usize> {
+        let hit = widgets
+            .iter()
+            .position(|widget| widget.is_focusable() && widget.hit_test(x, y));
+        self.focus(widgets, hit, FocusOrigin::Pointer);
+        self.focused
+    }
+
+    /// Activate the focused widget (Enter / Space); returns true if one was clicked
+    pub fn activate(&mut self, widgets: &mut [Box<dyn Widget>]) -> bool {
+        match self.focused.and_then(|i| widgets.get_mut(i)) {
+            Some(widget) => {
+                widget.on_click();
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use skia_safe::Canvas;
+
+use crate::components::input::ERROR_MESSAGE_HEIGHT;
+use crate::components::{Button, FocusOrigin, Input, Widget};
+use crate::theme::Theme;
+
+/// Vertical stack of inputs with a submit button
+/// The button is only enabled while every field passes its validator
+pub struct Form {
+    x: f32,
+    y: f32,
+    width: f32,
+    fields: Vec<Input>,
+    submit: Option<Button>,
+    focused: Option<usize>,
+}
+
+impl Form {
+    pub fn new(x: f32, y: f32, width: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            fields: Vec::new(),
+            submit: None,
+            focused: None,
+        }
+    }
+
+    /// Append a field below the previous one, leaving room for its error message
+    pub fn with_field(mut self, field: Input) -> Self {
+        self.fields.push(field);
+        self.layout();
+        self
+    }
+
+    /// Button placed below the fields; enabled only while the form is valid
+    pub fn with_submit(mut self, button: Button) -> Self {
+        self.submit = Some(button);
+        self.layout();
+        self
+    }
+
+    pub fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+        self.layout();
+    }
+
+    fn layout(&mut self) {
+        let mut y = self.y;
+        for field in &mut self.fields {
+            field.set_bounds(self.x, y, self.width);
+            y += field.height() + ERROR_MESSAGE_HEIGHT + Theme::SPACE_2;
+        }
+        if let Some(ref mut submit) = self.submit {
+            submit.set_position(self.x, y);
+        }
+        self.sync_submit();
+    }
+
+    /// Enable the submit button only when every field is valid
+    fn sync_submit(&mut self) {
+        let valid = self.is_valid();
+        if let Some(ref mut submit) = self.submit {
+            submit.set_disabled(!valid);
+        }
+    }
+
+    pub fn field(&self, index: usize) -> Option<&Input> {
+        self.fields.get(index)
+    }
+
+    pub fn field_mut(&mut self, index: usize) -> Option<&mut Input> {
+        self.fields.get_mut(index)
+    }
+
+    /// Current text of every field, in order
+    pub fn values(&self) -> Vec<&str> {
+        self.fields.iter().map(|field| field.text()).collect()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.fields.iter().all(|field| field.is_valid())
+    }
+
+    /// Validate every field, showing all errors; returns whether the form is valid
+    pub fn validate(&mut self) -> bool {
+        let mut valid = true;
+        for field in &mut self.fields {
+            valid &= field.validate();
+        }
+        self.sync_submit();
+        valid
+    }
+
+    /// Handle a click: focuses the clicked field
+    /// Returns true when the enabled submit button was clicked
+    pub fn handle_click(&mut self, x: f32, y: f32) -> bool {
+        self.focused = self.fields.iter().position(|field| field.hit_test(x, y));
+        for (index, field) in self.fields.iter_mut().enumerate() {
+            field.set_focus((Some(index) == self.focused).then_some(FocusOrigin::Pointer));
+        }
+
+        if let Some(ref mut submit) = self.submit {
+            if submit.hit_test(x, y) && !submit.is_disabled() {
+                submit.on_click();
+                return self.validate();
+            }
+        }
+        false
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        if let Some(field) = self.focused.and_then(|index| self.fields.get_mut(index)) {
+            field.handle_char(c);
+        }
+        self.sync_submit();
+    }
+
+    pub fn handle_backspace(&mut self) {
+        if let Some(field) = self.focused.and_then(|index| self.fields.get_mut(index)) {
+            field.handle_backspace();
+        }
+        self.sync_submit();
+    }
+
+    /// Move focus to the next field (Tab)
+    pub fn focus_next(&mut self) {
+        if self.fields.is_empty() {
+            return;
+        }
+        let next = self.focused.map_or(0, |index| (index + 1) % self.fields.len());
+        self.focused = Some(next);
+        for (index, field) in self.fields.iter_mut().enumerate() {
+            field.set_focus((index == next).then_some(FocusOrigin::Keyboard));
+        }
+    }
+}
+
+impl Widget for Form {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
+        for field in &self.fields {
+            field.draw(canvas, font_manager);
+        }
+        if let Some(ref submit) = self.submit {
+            submit.draw(canvas, font_manager);
+        }
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        self.fields.iter().any(|field| field.hit_test(x, y))
+            || self.submit.as_ref().is_some_and(|submit| submit.hit_test(x, y))
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        for field in &mut self.fields {
+            field.update_hover(x, y);
+        }
+        if let Some(ref mut submit) = self.submit {
+            submit.update_hover(x, y);
+        }
+    }
+
+    fn update_animation(&mut self, elapsed: f32) {
+        for field in &mut self.fields {
+            field.update_animation(elapsed);
+        }
+        if let Some(ref mut submit) = self.submit {
+            submit.update_animation(elapsed);
+        }
+    }
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{set_accessibility_config, AccessibilityConfig};
+
+    #[test]
+    fn clicks_in_the_grown_margin_press_the_submit_button() {
+        let mut form = Form::new(0.0, 0.0, 200.0).with_submit(Button::new(0.0, 0.0, 200.0, "Send"));
+        let bounds = form.submit.as_ref().and_then(|submit| submit.bounds()).unwrap();
+        set_accessibility_config(AccessibilityConfig { min_hit_target: bounds.height() + 16.0, ..AccessibilityConfig::default() });
+
+        // Just below the button: outside what it draws, inside its minimum target
+        let below = bounds.bottom + 4.0;
+        assert!(!form.submit.as_ref().unwrap().contains(100.0, below));
+        assert!(form.contains(100.0, below));
+        assert!(form.handle_click(100.0, below));
+        assert!(!form.handle_click(100.0, bounds.bottom + 12.0));
+        set_accessibility_config(AccessibilityConfig::default());
+    }
+}
@@ -0,0 +1,141 @@
+/// Points for every matched char
+const SCORE_MATCH: i32 = 16;
+/// Extra points for a char right after the previous match
+const BONUS_CONSECUTIVE: i32 = 12;
+/// Extra points for the first char of a word, after a space, `_`, `-`, `.`, `/` or `:`
+const BONUS_WORD_START: i32 = 10;
+/// Extra points for an uppercase char following a lowercase one, as in `camelCase`
+const BONUS_CAMEL_CASE: i32 = 8;
+/// Extra points for matching the very first char of the text
+const BONUS_FIRST_CHAR: i32 = 8;
+/// Points lost for every char skipped between two matches
+const PENALTY_GAP: i32 = 1;
+
+/// How well a query matched a text, and which chars it matched
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub score: i32,
+    /// Indices of the matched chars (not bytes) of the text, ascending
+    pub positions: Vec<usize>,
+}
+
+/// Match the chars of `query` in order anywhere in `text`, ignoring case
+///
+/// Among all the ways the query fits, the one with the highest score wins: runs of
+/// consecutive chars and chars starting a word or a camelCase hump score higher, so
+/// "gtf" ranks "Go to File" above "Toggle Terminal Focus". `None` if the query's chars
+/// don't all appear in order. An empty query matches everything with a score of 0.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query.chars().filter(|c| !c.is_whitespace()).map(fold_case).collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch { score: 0, positions: Vec::new() });
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let folded: Vec<char> = chars.iter().copied().map(fold_case).collect();
+    let (n, m) = (query.len(), chars.len());
+    if n > m {
+        return None;
+    }
+
+    // best[i][j]: score of matching query[..=i] with query[i] on text[j]; from[i][j]: where query[i - 1] went
+    let mut best = vec![vec![None::<i32>; m]; n];
+    let mut from = vec![vec![0usize; m]; n];
+    for (i, &q) in query.iter().enumerate() {
+        // Highest `best[i - 1][k] + k * PENALTY_GAP` over k < j - 1, for a gap ending at j
+        let mut gapped: Option<(i32, usize)> = None;
+        for j in i..m {
+            if i > 0 && j >= 2 {
+                if let Some(score) = best[i - 1][j - 2] {
+                    let candidate = score + (j - 2) as i32 * PENALTY_GAP;
+                    if gapped.is_none_or(|(top, _)| candidate > top) {
+                        gapped = Some((candidate, j - 2));
+                    }
+                }
+            }
+            if folded[j] != q {
+                continue;
+            }
+            let here = SCORE_MATCH + bonus(&chars, j);
+            if i == 0 {
+                best[0][j] = Some(here);
+                continue;
+            }
+            let after_previous = best[i - 1][j - 1].map(|score| (score + BONUS_CONSECUTIVE, j - 1));
+            let after_gap = gapped.map(|(top, k)| (top - (j - 1) as i32 * PENALTY_GAP, k));
+            let previous = match (after_previous, after_gap) {
+                (Some(a), Some(b)) => Some(if a.0 >= b.0 { a } else { b }),
+                (a, b) => a.or(b),
+            };
+            if let Some((score, k)) = previous {
+                best[i][j] = Some(score + here);
+                from[i][j] = k;
+            }
+        }
+    }
+
+    let (mut j, score) = best[n - 1]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, score)| score.map(|score| (j, score)))
+        .max_by_key(|&(j, score)| (score, std::cmp::Reverse(j)))?;
+    let mut positions = vec![0; n];
+    for i in (0..n).rev() {
+        positions[i] = j;
+        j = from[i][j];
+    }
+    Some(FuzzyMatch { score, positions })
+}
+
+fn fold_case(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// Bonus for matching `chars[j]`, from where it sits in its word
+fn bonus(chars: &[char], j: usize) -> i32 {
+    let Some(&previous) = j.checked_sub(1).and_then(|p| chars.get(p)) else {
+        return BONUS_WORD_START + BONUS_FIRST_CHAR;
+    };
+    let current = chars[j];
+    if !previous.is_alphanumeric() && current.is_alphanumeric() {
+        BONUS_WORD_START
+    } else if previous.is_lowercase() && current.is_uppercase() {
+        BONUS_CAMEL_CASE
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsequence_ignoring_case() {
+        let found = fuzzy_match("gtf", "Go to File").unwrap();
+        assert_eq!(found.positions, vec![0, 3, 6]);
+        assert!(fuzzy_match("GTF", "go to file").is_some());
+        assert!(fuzzy_match("fg", "Go to File").is_none());
+        assert_eq!(fuzzy_match("", "anything").unwrap().score, 0);
+        assert!(fuzzy_match("longer", "long").is_none());
+    }
+
+    #[test]
+    fn word_starts_and_runs_score_higher() {
+        let score = |query, text| fuzzy_match(query, text).unwrap().score;
+
+        assert!(score("gtf", "Go to File") > score("gtf", "Toggle Terminal Focus"));
+        assert!(score("save", "File: Save") > score("save", "Show Available Editors"));
+        assert!(score("fi", "findIndex") > score("fi", "toggleFullInspector"));
+        assert!(score("ti", "findTabIndex") > score("ti", "settings"));
+
+        // Runs beat scattered chars even when both start a word
+        let found = fuzzy_match("term", "Toggle Terminal").unwrap();
+        assert_eq!(found.positions, vec![7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn camel_case_humps() {
+        let found = fuzzy_match("ws", "openWorkspaceSettings").unwrap();
+        assert_eq!(found.positions, vec![4, 13]);
+    }
+}
@@ -0,0 +1,274 @@
+use skia_safe::{Canvas, Color, Paint, Point, Rect};
+
+use crate::components::Widget;
+use crate::theme::{current_theme, Theme};
+
+const GAP: f32 = 6.0;
+
+/// Which side of the trigger the card opens on
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HoverCardSide {
+    Top,
+    Bottom,
+}
+
+/// Rich popover shown after hovering a trigger area for `open_delay` seconds
+///
+/// Children are laid out relative to the card's top-left corner. While the
+/// pointer travels from the trigger to the card it stays inside a "safe
+/// polygon" (a triangle from the exit point to the card's near edge), so the
+/// card does not close under it.
+pub struct HoverCard {
+    trigger: Rect,
+    width: f32,
+    height: f32,
+    side: HoverCardSide,
+    open_delay: f32,
+    close_delay: f32,
+    children: Vec<Box<dyn Widget>>,
+    open: bool,
+    /// Time from the last `update_animation`, in seconds since app start
+    now: f32,
+    open_at: Option<f32>,
+    close_at: Option<f32>,
+    in_trigger: bool,
+    last_point: Point,
+    /// Last pointer position inside the trigger before it left while the card was open
+    exit_point: Option<Point>,
+}
+
+impl HoverCard {
+    pub fn new(trigger_x: f32, trigger_y: f32, trigger_width: f32, trigger_height: f32, width: f32, height: f32) -> Self {
+        Self {
+            trigger: Rect::from_xywh(trigger_x, trigger_y, trigger_width, trigger_height),
+            width,
+            height,
+            side: HoverCardSide::Bottom,
+            open_delay: 0.7,
+            close_delay: 0.3,
+            children: Vec::new(),
+            open: false,
+            now: 0.0,
+            open_at: None,
+            close_at: None,
+            in_trigger: false,
+            last_point: Point::new(0.0, 0.0),
+            exit_point: None,
+        }
+    }
+
+    pub fn side(mut self, side: HoverCardSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Seconds the pointer must rest on the trigger before the card opens
+    pub fn open_delay(mut self, seconds: f32) -> Self {
+        self.open_delay = seconds;
+        self
+    }
+
+    /// Seconds after the pointer leaves before the card closes
+    pub fn close_delay(mut self, seconds: f32) -> Self {
+        self.close_delay = seconds;
+        self
+    }
+
+    /// Add a child positioned relative to the card's top-left corner
+    pub fn with_child(mut self, child: Box<dyn Widget>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn children_mut(&mut self) -> &mut Vec<Box<dyn Widget>> {
+        &mut self.children
+    }
+
+    pub fn set_trigger(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.trigger = Rect::from_xywh(x, y, width, height);
+    }
+
+    /// Resize the card, e.g. once content that is laid out late is measured
+    pub fn set_size(&mut self, width: f32, height: f32) {
+        self.width = width;
+        self.height = height;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Whether the card is waiting to open or close, and needs more frames
+    pub fn is_pending(&self) -> bool {
+        self.open_at.is_some() || self.close_at.is_some()
+    }
+
+    /// Close immediately, e.g. when the trigger is clicked or scrolled away
+    pub fn close(&mut self) {
+        self.open = false;
+        self.open_at = None;
+        self.close_at = None;
+        self.exit_point = None;
+    }
+
+    /// Card bounds, horizontally centered on the trigger
+    pub fn card_rect(&self) -> Rect {
+        let x = self.trigger.center_x() - self.width / 2.0;
+        let y = match self.side {
+            HoverCardSide::Bottom => self.trigger.bottom + GAP,
+            HoverCardSide::Top => self.trigger.top - GAP - self.height,
+        };
+        Rect::from_xywh(x.max(0.0), y, self.width, self.height)
+    }
+
+    /// Forward a click to the child under the pointer; returns true if the card took it
+    pub fn handle_click(&mut self, x: f32, y: f32) -> bool {
+        if !self.contains(x, y) {
+            return false;
+        }
+        let card = self.card_rect();
+        let (local_x, local_y) = (x - card.left, y - card.top);
+        if let Some(child) = self.children.iter_mut().find(|child| child.contains(local_x, local_y)) {
+            child.on_click();
+        }
+        true
+    }
+
+    /// Triangle from the exit point to the two corners of the card edge facing the trigger
+    fn in_safe_polygon(&self, point: Point) -> bool {
+        let Some(exit) = self.exit_point else {
+            return false;
+        };
+        let card = self.card_rect();
+        let edge_y = match self.side {
+            HoverCardSide::Bottom => card.top,
+            HoverCardSide::Top => card.bottom,
+        };
+        point_in_triangle(point, exit, Point::new(card.left, edge_y), Point::new(card.right, edge_y))
+    }
+}
+
+fn point_in_triangle(p: Point, a: Point, b: Point, c: Point) -> bool {
+    let sign = |p1: Point, p2: Point, p3: Point| (p1.x - p3.x) * (p2.y - p3.y) - (p2.x - p3.x) * (p1.y - p3.y);
+    let d1 = sign(p, a, b);
+    let d2 = sign(p, b, c);
+    let d3 = sign(p, c, a);
+    let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_negative && has_positive)
+}
+
+impl HoverCard {
+    /// Shadow, background and border of the open card, for owners that draw the content themselves
+    pub fn draw_surface(&self, canvas: &Canvas) {
+        if !self.open {
+            return;
+        }
+
+        let colors = current_theme();
+        let card = self.card_rect();
+
+        let mut shadow_paint = Paint::default();
+        shadow_paint.set_anti_alias(true);
+        shadow_paint.set_color(Color::from_argb(60, 0, 0, 0));
+        if let Some(blur) = skia_safe::MaskFilter::blur(skia_safe::BlurStyle::Normal, 8.0, false) {
+            shadow_paint.set_mask_filter(blur);
+        }
+        canvas.draw_round_rect(card.with_offset((0.0, 2.0)), Theme::RADIUS_MD, Theme::RADIUS_MD, &shadow_paint);
+
+        let mut bg_paint = Paint::default();
+        bg_paint.set_anti_alias(true);
+        bg_paint.set_color(colors.popover);
+        canvas.draw_round_rect(card, Theme::RADIUS_MD, Theme::RADIUS_MD, &bg_paint);
+
+        let mut border_paint = Paint::default();
+        border_paint.set_anti_alias(true);
+        border_paint.set_style(skia_safe::PaintStyle::Stroke);
+        border_paint.set_stroke_width(1.0);
+        border_paint.set_color(colors.border);
+        canvas.draw_round_rect(card.with_inset((0.5, 0.5)), Theme::RADIUS_MD, Theme::RADIUS_MD, &border_paint);
+    }
+}
+
+impl Widget for HoverCard {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
+        if !self.open {
+            return;
+        }
+
+        self.draw_surface(canvas);
+        let card = self.card_rect();
+        canvas.save();
+        canvas.clip_rect(card, None, true);
+        canvas.translate((card.left, card.top));
+        for child in &self.children {
+            child.draw(canvas, font_manager);
+        }
+        canvas.restore();
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        self.open && self.card_rect().contains(Point::new(x, y))
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        let point = Point::new(x, y);
+        let in_trigger = self.trigger.contains(point);
+        let in_card = self.contains(x, y);
+
+        if self.in_trigger && !in_trigger && self.open {
+            self.exit_point = Some(self.last_point);
+        }
+        if in_trigger || in_card {
+            self.exit_point = None;
+        }
+        self.in_trigger = in_trigger;
+        self.last_point = point;
+
+        let keep_open = in_trigger || (self.open && (in_card || self.in_safe_polygon(point)));
+        if keep_open {
+            self.close_at = None;
+            if !self.open && self.open_at.is_none() {
+                self.open_at = Some(self.now + self.open_delay);
+            }
+        } else {
+            self.open_at = None;
+            // Leaving the safe polygon ends the grace path into the card
+            self.exit_point = None;
+            if self.open && self.close_at.is_none() {
+                self.close_at = Some(self.now + self.close_delay);
+            }
+        }
+
+        if self.open {
+            let card = self.card_rect();
+            for child in &mut self.children {
+                child.update_hover(x - card.left, y - card.top);
+            }
+        }
+    }
+
+    fn update_animation(&mut self, elapsed: f32) {
+        self.now = elapsed;
+        if self.open_at.is_some_and(|at| elapsed >= at) {
+            self.open = true;
+            self.open_at = None;
+        }
+        if self.close_at.is_some_and(|at| elapsed >= at) {
+            self.close();
+        }
+        for child in &mut self.children {
+            child.update_animation(elapsed);
+        }
+    }
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
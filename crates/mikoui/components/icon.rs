@@ -3,7 +3,7 @@ use std::sync::Arc;
 use std::cell::RefCell;
 
 use crate::components::Widget;
-use crate::core::FontManager;
+use crate::core::{animation_rate, layout_direction, FontManager};
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum IconSize {
@@ -30,6 +30,8 @@ pub struct Icon {
     hover_progress: f32,
     active: bool,
     active_progress: f32,
+    directional: bool,
+    full_color: bool,
 }
 
 impl Icon {
@@ -45,6 +47,68 @@ impl Icon {
             hover_progress: 0.0,
             active: false,
             active_progress: 0.0,
+            directional: false,
+            full_color: false,
+        }
+    }
+    
+    /// Flip the glyph horizontally in RTL layouts (arrows, chevrons, "back" icons)
+    pub fn directional(mut self, directional: bool) -> Self {
+        self.directional = directional;
+        self
+    }
+    
+    /// Keep the SVG's own colors instead of tinting it with `color`, e.g. for file type icons
+    pub fn full_color(mut self, full_color: bool) -> Self {
+        self.full_color = full_color;
+        self
+    }
+    
+    /// Draw the icon; unlike `Widget::draw` this needs no font manager
+    pub fn paint(&self, canvas: &Canvas) {
+        // Load SVG if not cached
+        if self.cached_image.borrow().is_none() {
+            if let Some(img) = self.load_svg() {
+                *self.cached_image.borrow_mut() = Some(Arc::new(img));
+            }
+        }
+        
+        if let Some(ref image) = *self.cached_image.borrow() {
+            // Animated scale
+            let scale = 1.0 - (self.active_progress * 0.1) + (self.hover_progress * 0.1);
+            let size = self.size.as_f32();
+            let center_x = self.x + size / 2.0;
+            let center_y = self.y + size / 2.0;
+
+            canvas.save();
+            
+            // Apply transformations
+            canvas.translate((center_x, center_y));
+            let flip = if self.directional && layout_direction().is_rtl() { -1.0 } else { 1.0 };
+            canvas.scale((scale * flip, scale));
+            canvas.translate((-size / 2.0, -size / 2.0));
+
+            // Animated alpha
+            let alpha = (1.0 - self.active_progress * 0.3 + self.hover_progress * 0.2).clamp(0.0, 1.0);
+            
+            let mut paint = Paint::default();
+            paint.set_anti_alias(true);
+            paint.set_alpha_f(alpha);
+            
+            // Apply color filter to change icon color
+            if !self.full_color {
+                let color_filter = skia_safe::color_filters::blend(
+                    self.color,
+                    skia_safe::BlendMode::SrcIn,
+                );
+                paint.set_color_filter(color_filter);
+            }
+
+            // Draw the image scaled to the icon size
+            let dest_rect = Rect::from_xywh(0.0, 0.0, size, size);
+            canvas.draw_image_rect(image.as_ref(), None, dest_rect, &paint);
+
+            canvas.restore();
         }
     }
     
@@ -88,47 +152,7 @@ impl Icon {
 
 impl Widget for Icon {
     fn draw(&self, canvas: &Canvas, _font_manager: &mut FontManager) {
-        // Load SVG if not cached
-        if self.cached_image.borrow().is_none() {
-            if let Some(img) = self.load_svg() {
-                *self.cached_image.borrow_mut() = Some(Arc::new(img));
-            }
-        }
-        
-        if let Some(ref image) = *self.cached_image.borrow() {
-            // Animated scale
-            let scale = 1.0 - (self.active_progress * 0.1) + (self.hover_progress * 0.1);
-            let size = self.size.as_f32();
-            let center_x = self.x + size / 2.0;
-            let center_y = self.y + size / 2.0;
-
-            canvas.save();
-            
-            // Apply transformations
-            canvas.translate((center_x, center_y));
-            canvas.scale((scale, scale));
-            canvas.translate((-size / 2.0, -size / 2.0));
-
-            // Animated alpha
-            let alpha = (1.0 - self.active_progress * 0.3 + self.hover_progress * 0.2).clamp(0.0, 1.0);
-            
-            let mut paint = Paint::default();
-            paint.set_anti_alias(true);
-            paint.set_alpha_f(alpha);
-            
-            // Apply color filter to change icon color
-            let color_filter = skia_safe::color_filters::blend(
-                self.color,
-                skia_safe::BlendMode::SrcIn,
-            );
-            paint.set_color_filter(color_filter);
-
-            // Draw the image scaled to the icon size
-            let dest_rect = Rect::from_xywh(0.0, 0.0, size, size);
-            canvas.draw_image_rect(image.as_ref(), None, dest_rect, &paint);
-
-            canvas.restore();
-        }
+        self.paint(canvas);
     }
 
     fn contains(&self, x: f32, y: f32) -> bool {
@@ -146,7 +170,7 @@ impl Widget for Icon {
         // Hover animation
         let target_hover = if self.hover { 1.0 } else { 0.0 };
         if (self.hover_progress - target_hover).abs() > 0.01 {
-            self.hover_progress += (target_hover - self.hover_progress) * animation_speed;
+            self.hover_progress += (target_hover - self.hover_progress) * animation_rate(animation_speed);
         } else {
             self.hover_progress = target_hover;
         }
@@ -154,7 +178,7 @@ impl Widget for Icon {
         // Active animation
         let target_active = if self.active { 1.0 } else { 0.0 };
         if (self.active_progress - target_active).abs() > 0.01 {
-            self.active_progress += (target_active - self.active_progress) * (animation_speed * 2.0);
+            self.active_progress += (target_active - self.active_progress) * animation_rate(animation_speed * 2.0);
         } else {
             self.active_progress = target_active;
         }
@@ -1,16 +1,26 @@
-use skia_safe::{Canvas, Paint, Rect};
+use skia_safe::{Canvas, Font, Paint, Rect};
 
-use crate::components::Widget;
+use crate::components::props::{size_from, size_prop};
+use crate::components::{draw_focus_outline, FocusOrigin, Prop, PropValue, Widget};
+use crate::core::{animation_rate, BidiLine, TextDirection};
 use crate::theme::{current_theme, lerp_color, with_alpha, Size, Theme};
 
+/// Validation callback: `Err(message)` marks the input invalid
+pub type Validator = Box<dyn Fn(&str) -> Result<(), String>>;
+
+/// Space reserved below an input for its error message
+pub const ERROR_MESSAGE_HEIGHT: f32 = 18.0;
+
 pub struct Input {
     x: f32,
     y: f32,
     width: f32,
     height: f32,
-    placeholder: &'static str,
+    placeholder: String,
     text: String,
     focused: bool,
+    /// How focus arrived; keyboard focus adds the solid focus-visible outline
+    focus: Option<FocusOrigin>,
     hover: bool,
     hover_progress: f32,
     focus_progress: f32,
@@ -23,19 +33,24 @@ pub struct Input {
     selection_start: Option<usize>,
     selection_end: Option<usize>,
     clipboard: String, // Simple clipboard storage
+    validator: Option<Validator>,
+    error: Option<String>,
+    touched: bool, // Errors are shown once the user edited the text or validate() was called
+    drop_caret: Option<usize>, // Where text dragged over the field would be inserted
 }
 
 impl Input {
-    pub fn new(x: f32, y: f32, width: f32, placeholder: &'static str) -> Self {
+    pub fn new(x: f32, y: f32, width: f32, placeholder: impl Into<String>) -> Self {
         let size = Size::Md;
         Self {
             x,
             y,
             width,
             height: size.height(),
-            placeholder,
+            placeholder: placeholder.into(),
             text: String::new(),
             focused: false,
+            focus: None,
             hover: false,
             hover_progress: 0.0,
             focus_progress: 0.0,
@@ -48,6 +63,10 @@ impl Input {
             selection_start: None,
             selection_end: None,
             clipboard: String::new(),
+            validator: None,
+            error: None,
+            touched: false,
+            drop_caret: None,
         }
     }
     
@@ -61,6 +80,13 @@ impl Input {
         self.disabled = disabled;
         self
     }
+    
+    /// Validate the text on every change; the error is shown below the field
+    pub fn with_validator(mut self, validator: impl Fn(&str) -> Result<(), String> + 'static) -> Self {
+        self.validator = Some(Box::new(validator));
+        self.revalidate();
+        self
+    }
 
     pub fn text(&self) -> &str {
         &self.text
@@ -68,6 +94,49 @@ impl Input {
 
     pub fn set_text(&mut self, text: String) {
         self.text = text;
+        self.cursor_pos = self.cursor_pos.min(self.char_count());
+        self.revalidate();
+    }
+    
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+    }
+    
+    pub fn height(&self) -> f32 {
+        self.height
+    }
+    
+    /// Whether the current text passes the validator
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+    
+    /// Error message to display, if the field was touched and is invalid
+    pub fn error(&self) -> Option<&str> {
+        if self.touched {
+            self.error.as_deref()
+        } else {
+            None
+        }
+    }
+    
+    /// Run the validator and start showing its error; returns validity
+    pub fn validate(&mut self) -> bool {
+        self.touched = true;
+        self.revalidate();
+        self.is_valid()
+    }
+    
+    fn revalidate(&mut self) {
+        self.error = self.validator.as_ref().and_then(|validator| validator(&self.text).err());
+    }
+    
+    /// Mark the text as edited by the user
+    fn changed(&mut self) {
+        self.touched = true;
+        self.revalidate();
     }
 
     pub fn is_focused(&self) -> bool {
@@ -76,6 +145,9 @@ impl Input {
 
     pub fn set_focused(&mut self, focused: bool) {
         self.focused = focused;
+        if !focused {
+            self.focus = None;
+        }
     }
 
     // Convert character index to byte index safely
@@ -87,13 +159,6 @@ impl Input {
             .unwrap_or(self.text.len())
     }
     
-    // Convert byte index to character index
-    fn byte_to_char_idx(&self, byte_idx: usize) -> usize {
-        self.text[..byte_idx.min(self.text.len())]
-            .chars()
-            .count()
-    }
-    
     // Get character count
     fn char_count(&self) -> usize {
         self.text.chars().count()
@@ -110,6 +175,7 @@ impl Input {
             self.text.insert(byte_pos, c);
             self.cursor_pos += 1;
             self.clear_selection();
+            self.changed();
         }
     }
 
@@ -122,6 +188,7 @@ impl Input {
                 self.text.remove(byte_pos);
                 self.cursor_pos -= 1;
             }
+            self.changed();
         }
     }
 
@@ -129,6 +196,7 @@ impl Input {
         self.text.clear();
         self.cursor_pos = 0;
         self.clear_selection();
+        self.revalidate();
     }
     
     pub fn select_all(&mut self) {
@@ -163,6 +231,7 @@ impl Input {
             self.text.drain(byte_start..byte_end);
             self.cursor_pos = start;
             self.clear_selection();
+            self.changed();
         }
     }
     
@@ -193,52 +262,93 @@ impl Input {
                 self.text.insert(byte_pos, c);
                 self.cursor_pos += 1;
             }
+            self.changed();
             println!("Pasted: {}", self.clipboard);
         }
     }
     
+    /// Show where text dragged over the field would land, if `point` is inside it; clears it otherwise
+    pub fn preview_drop(&mut self, point: Option<(f32, f32)>, font_manager: &mut crate::core::FontManager) {
+        self.drop_caret = point
+            .filter(|&(x, y)| !self.disabled && self.contains(x, y))
+            .map(|(x, _)| self.get_char_index_at_x(x, font_manager));
+    }
+
+    /// Insert dropped text at the character under `(x, y)` and focus the field
+    ///
+    /// Returns false when the point is outside the field. Line breaks and tabs become spaces,
+    /// since the field holds a single line.
+    pub fn drop_text(&mut self, x: f32, y: f32, text: &str, font_manager: &mut crate::core::FontManager) -> bool {
+        self.drop_caret = None;
+        if self.disabled || !self.contains(x, y) {
+            return false;
+        }
+        let index = self.get_char_index_at_x(x, font_manager);
+        let text: String = text
+            .chars()
+            .filter_map(|c| match c {
+                '\n' | '\t' => Some(' '),
+                '\r' => None,
+                c if c.is_control() => None,
+                c => Some(c),
+            })
+            .collect();
+        let byte_pos = self.char_to_byte_idx(index);
+        self.text.insert_str(byte_pos, &text);
+        self.cursor_pos = index + text.chars().count();
+        self.clear_selection();
+        self.set_focused(true);
+        self.changed();
+        true
+    }
+
     // Get character index from mouse x position (for mouse selection)
     pub fn get_char_index_at_x(&self, mouse_x: f32, font_manager: &mut crate::core::FontManager) -> usize {
         if self.text.is_empty() {
             return 0;
         }
         
-        let padding = self.size.padding_x();
         let font_size = self.size.font_size();
-        let text_x = self.x + padding;
-        let relative_x = mouse_x - text_x;
-        
-        if relative_x <= 0.0 {
-            return 0;
-        }
-        
         let font = font_manager.create_font(&self.text, font_size, 400);
-        let mut paint = skia_safe::Paint::default();
-        paint.set_anti_alias(true);
-        
-        let mut closest_idx = 0;
-        let mut closest_dist = f32::MAX;
-        
-        for (char_idx, _) in self.text.char_indices() {
-            let byte_idx = self.char_to_byte_idx(self.byte_to_char_idx(char_idx));
-            let substr = &self.text[..byte_idx];
-            let (width, _) = font.measure_str(substr, Some(&paint));
-            let dist = (width - relative_x).abs();
-            
-            if dist < closest_dist {
-                closest_dist = dist;
-                closest_idx = self.byte_to_char_idx(char_idx);
-            }
+        let line = BidiLine::new(&self.text, None);
+        line.index_at_x(mouse_x - self.text_origin(&line, &font), &font)
+    }
+    
+    /// Left edge of the drawn text; right-to-left text hugs the right padding
+    fn text_origin(&self, line: &BidiLine, font: &Font) -> f32 {
+        let padding = self.size.padding_x();
+        match line.direction() {
+            TextDirection::Ltr => self.x + padding,
+            TextDirection::Rtl => self.x + self.width - padding - line.width(font),
         }
-        
-        // Check end position
-        let (full_width, _) = font.measure_str(&self.text, Some(&paint));
-        let end_dist = (full_width - relative_x).abs();
-        if end_dist < closest_dist {
-            closest_idx = self.char_count();
+    }
+    
+    /// Move the cursor one character left on screen, extending the selection with Shift
+    pub fn move_cursor_left(&mut self, extend: bool) {
+        self.move_cursor_visual(false, extend);
+    }
+    
+    /// Move the cursor one character right on screen, extending the selection with Shift
+    pub fn move_cursor_right(&mut self, extend: bool) {
+        self.move_cursor_visual(true, extend);
+    }
+    
+    // Arrow keys follow the screen, so they walk backwards through right-to-left runs
+    fn move_cursor_visual(&mut self, right: bool, extend: bool) {
+        if !self.focused || self.disabled {
+            return;
         }
-        
-        closest_idx
+        let line = BidiLine::new(&self.text, None);
+        let target = line.move_visual(self.cursor_pos, right).unwrap_or(self.cursor_pos);
+        if extend {
+            if self.selection_start.is_none() {
+                self.selection_start = Some(self.cursor_pos);
+            }
+            self.selection_end = Some(target);
+        } else {
+            self.clear_selection();
+        }
+        self.cursor_pos = target;
     }
     
     pub fn start_selection(&mut self, char_idx: usize) {
@@ -258,7 +368,6 @@ impl Input {
 impl Widget for Input {
     fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
         let border_radius = Theme::RADIUS_MD;
-        let padding = self.size.padding_x();
         let font_size = self.size.font_size();
         let colors = current_theme();
 
@@ -282,9 +391,12 @@ impl Widget for Input {
             &paint,
         );
 
-        // Border color with focus ring
+        // Border color with focus ring (invalid fields use the destructive color)
+        let error = self.error();
         let border_color = if self.disabled {
             with_alpha(colors.input, 128)
+        } else if error.is_some() {
+            colors.destructive
         } else if self.focus_progress > 0.0 {
             lerp_color(colors.input, colors.ring, self.focus_progress)
         } else {
@@ -315,7 +427,8 @@ impl Widget for Input {
             let mut ring_paint = Paint::default();
             ring_paint.set_anti_alias(true);
             ring_paint.set_style(skia_safe::PaintStyle::Stroke);
-            ring_paint.set_color(with_alpha(colors.ring, (ring_opacity * 255.0) as u8));
+            let ring_color = if error.is_some() { colors.destructive } else { colors.ring };
+            ring_paint.set_color(with_alpha(ring_color, (ring_opacity * 255.0) as u8));
             ring_paint.set_stroke_width(3.0);
 
             canvas.draw_round_rect(
@@ -330,10 +443,13 @@ impl Widget for Input {
                 &ring_paint,
             );
         }
+        if self.focused && self.focus == Some(FocusOrigin::Keyboard) && !self.disabled {
+            draw_focus_outline(canvas, Rect::from_xywh(self.x, self.y, self.width, self.height), border_radius);
+        }
 
         // Text or placeholder
         let display_text = if self.text.is_empty() {
-            self.placeholder
+            &self.placeholder
         } else {
             &self.text
         };
@@ -353,22 +469,14 @@ impl Widget for Input {
         text_paint.set_anti_alias(true);
         text_paint.set_color(text_color);
 
-        let text_x = self.x + padding;
+        // Text is laid out in visual (bidi) order, aligned to its own reading direction
+        let line = BidiLine::new(&self.text, None);
+        let text_x = self.text_origin(&line, &font);
         let text_y = self.y + self.height / 2.0 + (font_size * 0.3);
 
-        // Draw selection highlight
+        // Draw selection highlight; one logical range may cover several runs on screen
         if self.has_selection() && !self.text.is_empty() {
             if let Some((start, end)) = self.get_selection() {
-                let byte_start = self.char_to_byte_idx(start);
-                let byte_end = self.char_to_byte_idx(end);
-                
-                let before_text = &self.text[..byte_start];
-                let selected_text = &self.text[byte_start..byte_end];
-                
-                let (before_width, _) = font.measure_str(before_text, Some(&text_paint));
-                let (selected_width, _) = font.measure_str(selected_text, Some(&text_paint));
-                
-                let selection_x = text_x + before_width;
                 let selection_y = self.y + Theme::SPACE_2;
                 let selection_height = self.height - (Theme::SPACE_2 * 2.0);
                 
@@ -377,25 +485,26 @@ impl Widget for Input {
                 selection_paint.set_anti_alias(true);
                 selection_paint.set_color(with_alpha(colors.primary, 80));
                 
-                canvas.draw_rect(
-                    Rect::from_xywh(selection_x, selection_y, selected_width, selection_height),
-                    &selection_paint,
-                );
+                for (span_x, span_width) in line.selection_spans(start, end, &font) {
+                    canvas.draw_rect(
+                        Rect::from_xywh(text_x + span_x, selection_y, span_width, selection_height),
+                        &selection_paint,
+                    );
+                }
             }
         }
 
-        canvas.draw_str(display_text, (text_x, text_y), &font, &text_paint);
+        if self.text.is_empty() {
+            let placeholder = BidiLine::new(display_text, None);
+            let placeholder_x = self.text_origin(&placeholder, &font);
+            canvas.draw_str(placeholder.visual_text(), (placeholder_x, text_y), &font, &text_paint);
+        } else {
+            canvas.draw_str(line.visual_text(), (text_x, text_y), &font, &text_paint);
+        }
 
         // Cursor
         if self.focused && self.cursor_visible && !self.disabled && !self.has_selection() {
-            let cursor_x = if self.text.is_empty() {
-                text_x
-            } else {
-                let byte_pos = self.char_to_byte_idx(self.cursor_pos.min(self.char_count()));
-                let before_cursor = &self.text[..byte_pos];
-                let (before_width, _) = font.measure_str(before_cursor, Some(&text_paint));
-                text_x + before_width
-            };
+            let cursor_x = text_x + line.caret_x(self.cursor_pos.min(self.char_count()), &font);
 
             let mut cursor_paint = Paint::default();
             cursor_paint.set_anti_alias(true);
@@ -409,6 +518,35 @@ impl Widget for Input {
                 &cursor_paint,
             );
         }
+
+        // Insertion point of text being dragged over the field
+        if let Some(index) = self.drop_caret {
+            let caret_x = text_x + line.caret_x(index.min(self.char_count()), &font);
+            let mut caret_paint = Paint::default();
+            caret_paint.set_anti_alias(true);
+            caret_paint.set_color(colors.primary);
+            caret_paint.set_stroke_width(2.0);
+            canvas.draw_line(
+                (caret_x, self.y + Theme::SPACE_2),
+                (caret_x, self.y + self.height - Theme::SPACE_2),
+                &caret_paint,
+            );
+        }
+        
+        // Error message below the field
+        if let Some(error) = error {
+            let error_font_size = font_size - 1.0;
+            let error_font = font_manager.create_font(error, error_font_size, 400);
+            let mut error_paint = Paint::default();
+            error_paint.set_anti_alias(true);
+            error_paint.set_color(colors.destructive);
+            canvas.draw_str(
+                error,
+                (self.x, self.y + self.height + ERROR_MESSAGE_HEIGHT - 4.0),
+                &error_font,
+                &error_paint,
+            );
+        }
     }
 
     fn contains(&self, x: f32, y: f32) -> bool {
@@ -416,7 +554,7 @@ impl Widget for Input {
     }
 
     fn update_hover(&mut self, x: f32, y: f32) {
-        self.hover = self.contains(x, y);
+        self.hover = self.hit_test(x, y);
     }
 
     fn update_animation(&mut self, elapsed: f32) {
@@ -425,7 +563,7 @@ impl Widget for Input {
         // Hover animation
         let target_hover = if self.hover { 1.0 } else { 0.0 };
         if (self.hover_progress - target_hover).abs() > 0.01 {
-            self.hover_progress += (target_hover - self.hover_progress) * animation_speed;
+            self.hover_progress += (target_hover - self.hover_progress) * animation_rate(animation_speed);
         } else {
             self.hover_progress = target_hover;
         }
@@ -433,7 +571,7 @@ impl Widget for Input {
         // Focus animation
         let target_focus = if self.focused { 1.0 } else { 0.0 };
         if (self.focus_progress - target_focus).abs() > 0.01 {
-            self.focus_progress += (target_focus - self.focus_progress) * (animation_speed * 1.5);
+            self.focus_progress += (target_focus - self.focus_progress) * animation_rate(animation_speed * 1.5);
         } else {
             self.focus_progress = target_focus;
         }
@@ -451,6 +589,57 @@ impl Widget for Input {
         }
     }
 
+    fn is_focusable(&self) -> bool {
+        !self.disabled
+    }
+
+    fn set_focus(&mut self, origin: Option<FocusOrigin>) {
+        self.focus = origin;
+        self.focused = origin.is_some();
+    }
+
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+
+    fn content_bounds(&self) -> Option<Rect> {
+        let padding = self.size.padding_x();
+        Some(Rect::from_xywh(self.x + padding, self.y, self.width - padding * 2.0, self.height))
+    }
+
+    fn debug_state(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("text", self.text.clone()),
+            ("cursor", self.cursor_pos.to_string()),
+            ("focused", self.focused.to_string()),
+            ("disabled", self.disabled.to_string()),
+            ("error", self.error.clone().unwrap_or_default()),
+        ]
+    }
+
+    fn props(&self) -> Vec<Prop> {
+        vec![
+            Prop::text("text", self.text.clone()),
+            Prop::text("placeholder", self.placeholder.clone()),
+            size_prop(self.size),
+            Prop::bool("disabled", self.disabled),
+        ]
+    }
+
+    fn set_prop(&mut self, name: &str, value: &PropValue) -> bool {
+        match name {
+            "text" => value.as_text().map(|text| self.set_text(text.to_string())),
+            "placeholder" => value.as_text().map(|placeholder| self.placeholder = placeholder.to_string()),
+            "size" => size_from(value).map(|size| {
+                self.size = size;
+                self.height = size.height();
+            }),
+            "disabled" => value.as_bool().map(|disabled| self.disabled = disabled),
+            _ => None,
+        }
+        .is_some()
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
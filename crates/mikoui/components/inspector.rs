@@ -0,0 +1,235 @@
+use skia_safe::{Canvas, ClipOp, Paint, PaintStyle, Rect};
+
+use crate::components::{Widget, WidgetId};
+use crate::core::FontManager;
+use crate::theme::{current_theme, with_alpha, Theme};
+
+/// One widget of the inspected tree, captured when the inspector last updated
+#[derive(Debug, Clone)]
+pub struct InspectedWidget {
+    pub id: WidgetId,
+    pub type_name: &'static str,
+    /// Nesting level in the tree, 0 for the roots
+    pub depth: usize,
+    pub bounds: Option<Rect>,
+    pub content_bounds: Option<Rect>,
+    pub state: Vec<(&'static str, String)>,
+}
+
+/// Debug overlay that outlines the widget under the cursor and lists the widget tree
+///
+/// While enabled, call `update` with the window's top-level widgets each frame before
+/// `draw`. The hovered widget (the last one in draw order containing the cursor) gets
+/// its bounds and padding drawn, and the tree panel shows its type, id, bounds and state.
+pub struct Inspector {
+    enabled: bool,
+    nodes: Vec<InspectedWidget>,
+    hovered: Option<usize>,
+}
+
+impl Inspector {
+    const WIDTH: f32 = 300.0;
+    const PADDING: f32 = 10.0;
+    const LINE_HEIGHT: f32 = 16.0;
+    const INDENT: f32 = 12.0;
+    /// Tree rows shown at once; the list scrolls to keep the hovered widget in view
+    const MAX_ROWS: usize = 18;
+
+    pub fn new() -> Self {
+        Self {
+            enabled: false,
+            nodes: Vec::new(),
+            hovered: None,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+        self.nodes.clear();
+        self.hovered = None;
+    }
+
+    /// Capture the tree under `roots` and find the widget at the cursor
+    pub fn update(&mut self, roots: &[&dyn Widget], x: f32, y: f32) {
+        self.nodes.clear();
+        for root in roots {
+            self.collect(*root, 0);
+        }
+        // Later widgets draw over earlier ones, and children over their parent
+        self.hovered = self.nodes.iter().rposition(|node| {
+            node.bounds.is_some_and(|bounds| bounds.contains(skia_safe::Point::new(x, y)))
+        });
+    }
+
+    fn collect(&mut self, widget: &dyn Widget, depth: usize) {
+        self.nodes.push(InspectedWidget {
+            id: widget.id(),
+            type_name: widget.type_name(),
+            depth,
+            bounds: widget.bounds(),
+            content_bounds: widget.content_bounds(),
+            state: widget.debug_state(),
+        });
+        for child in widget.children() {
+            self.collect(child, depth + 1);
+        }
+    }
+
+    pub fn hovered(&self) -> Option<&InspectedWidget> {
+        self.hovered.and_then(|index| self.nodes.get(index))
+    }
+
+    /// Debug representation of the hovered widget, for logging on click
+    pub fn describe_hovered(&self) -> Option<String> {
+        self.hovered().map(|node| format!("{:?}", node))
+    }
+
+    pub fn draw(&self, canvas: &Canvas, font_manager: &mut FontManager, screen_height: f32) {
+        if !self.enabled {
+            return;
+        }
+        if let Some(node) = self.hovered() {
+            self.draw_highlight(canvas, font_manager, node);
+        }
+        self.draw_tree(canvas, font_manager, screen_height);
+    }
+
+    /// Padding in yellow, content in blue, then the type and size next to the bounds
+    fn draw_highlight(&self, canvas: &Canvas, font_manager: &mut FontManager, node: &InspectedWidget) {
+        let Some(bounds) = node.bounds else {
+            return;
+        };
+        let content = node.content_bounds.unwrap_or(bounds);
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+
+        canvas.save();
+        canvas.clip_rect(content, ClipOp::Difference, false);
+        paint.set_color(with_alpha(Theme::WARNING, 70));
+        canvas.draw_rect(bounds, &paint);
+        canvas.restore();
+        paint.set_color(with_alpha(Theme::INFO, 60));
+        canvas.draw_rect(content, &paint);
+
+        paint.set_style(PaintStyle::Stroke);
+        paint.set_stroke_width(1.0);
+        paint.set_color(Theme::INFO);
+        canvas.draw_rect(bounds.with_inset((0.5, 0.5)), &paint);
+
+        let tag = format!("{} {:.0} × {:.0}", node.type_name, bounds.width(), bounds.height());
+        let font = font_manager.create_font(&tag, 11.0, 500);
+        let (tag_width, _) = font.measure_str(&tag, None);
+        let tag_y = if bounds.top >= 18.0 { bounds.top - 18.0 } else { bounds.bottom };
+        let tag_rect = Rect::from_xywh(bounds.left, tag_y, tag_width + 8.0, 18.0);
+        paint.set_style(PaintStyle::Fill);
+        canvas.draw_rect(tag_rect, &paint);
+        paint.set_color(Theme::FOREGROUND);
+        canvas.draw_str(&tag, (tag_rect.left + 4.0, tag_rect.top + 13.0), &font, &paint);
+    }
+
+    /// Panel in the bottom-left corner: the tree around the hovered widget, then its details
+    fn draw_tree(&self, canvas: &Canvas, font_manager: &mut FontManager, screen_height: f32) {
+        let colors = current_theme();
+        let (first, rows) = self.visible_rows();
+        let details = self.details();
+        let height = Self::PADDING * 2.0
+            + Self::LINE_HEIGHT * (1 + rows.len() + details.len()) as f32
+            + if details.is_empty() { 0.0 } else { 8.0 };
+        let rect = Rect::from_xywh(12.0, screen_height - height - 12.0, Self::WIDTH, height);
+
+        let mut bg_paint = Paint::default();
+        bg_paint.set_anti_alias(true);
+        bg_paint.set_color(with_alpha(colors.popover, 235));
+        canvas.draw_round_rect(rect, Theme::RADIUS_MD, Theme::RADIUS_MD, &bg_paint);
+
+        let mut border_paint = Paint::default();
+        border_paint.set_anti_alias(true);
+        border_paint.set_style(PaintStyle::Stroke);
+        border_paint.set_stroke_width(1.0);
+        border_paint.set_color(colors.border);
+        canvas.draw_round_rect(rect.with_inset((0.5, 0.5)), Theme::RADIUS_MD, Theme::RADIUS_MD, &border_paint);
+
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        let left = rect.left + Self::PADDING;
+        let right = rect.right - Self::PADDING;
+        let mut baseline = rect.top + Self::PADDING + 12.0;
+
+        let title = format!("Inspector · {} widgets", self.nodes.len());
+        let font = font_manager.create_font(&title, 12.0, 600);
+        text_paint.set_color(colors.foreground);
+        canvas.draw_str(&title, (left, baseline), &font, &text_paint);
+
+        for (offset, node) in rows.iter().enumerate() {
+            baseline += Self::LINE_HEIGHT;
+            if self.hovered == Some(first + offset) {
+                let mut highlight = Paint::default();
+                highlight.set_color(colors.accent);
+                canvas.draw_rect(
+                    Rect::from_xywh(rect.left + 4.0, baseline - 12.0, Self::WIDTH - 8.0, Self::LINE_HEIGHT),
+                    &highlight,
+                );
+            }
+            let name_x = left + node.depth as f32 * Self::INDENT;
+            let font = font_manager.create_font(node.type_name, 12.0, 400);
+            text_paint.set_color(colors.foreground);
+            canvas.draw_str(node.type_name, (name_x, baseline), &font, &text_paint);
+            let id = node.id.to_string();
+            let font = font_manager.create_font(&id, 11.0, 400);
+            let (id_width, _) = font.measure_str(&id, None);
+            text_paint.set_color(colors.muted_foreground);
+            canvas.draw_str(&id, (right - id_width, baseline), &font, &text_paint);
+        }
+
+        if !details.is_empty() {
+            baseline += 8.0;
+        }
+        for (label, value) in details {
+            baseline += Self::LINE_HEIGHT;
+            let font = font_manager.create_font(&label, 12.0, 400);
+            text_paint.set_color(colors.muted_foreground);
+            canvas.draw_str(&label, (left, baseline), &font, &text_paint);
+            let (value_width, _) = font.measure_str(&value, None);
+            text_paint.set_color(colors.foreground);
+            canvas.draw_str(&value, (right - value_width, baseline), &font, &text_paint);
+        }
+    }
+
+    /// Index of the first tree row shown, and the rows themselves
+    fn visible_rows(&self) -> (usize, &[InspectedWidget]) {
+        let len = self.nodes.len();
+        let first = self
+            .hovered
+            .map_or(0, |hovered| hovered.saturating_sub(Self::MAX_ROWS / 2))
+            .min(len.saturating_sub(Self::MAX_ROWS));
+        (first, &self.nodes[first..(first + Self::MAX_ROWS).min(len)])
+    }
+
+    fn details(&self) -> Vec<(String, String)> {
+        let Some(node) = self.hovered() else {
+            return Vec::new();
+        };
+        let mut details = vec![
+            ("type".to_string(), node.type_name.to_string()),
+            ("id".to_string(), node.id.to_string()),
+        ];
+        if let Some(bounds) = node.bounds {
+            details.push((
+                "bounds".to_string(),
+                format!("{:.0}, {:.0}  {:.0} × {:.0}", bounds.left, bounds.top, bounds.width(), bounds.height()),
+            ));
+        }
+        details.extend(node.state.iter().map(|(name, value)| (name.to_string(), value.clone())));
+        details
+    }
+}
+
+impl Default for Inspector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,21 +1,120 @@
-use skia_safe::{Canvas, Color, Font, Paint};
+use std::borrow::Cow;
+use std::cell::Cell;
 
-use crate::components::Widget;
+use skia_safe::{Canvas, Color, Paint, Rect};
+
+use crate::components::{Prop, PropValue, Widget};
+use crate::core::{layout_direction, BidiLine, TextAlign};
+use crate::theme::{current_theme, Theme};
+
+const ELLIPSIS: &str = "…";
+const LINE_SPACING: f32 = 1.3;
+const TOOLTIP_DELAY: f32 = 0.5;
+const TOOLTIP_PADDING: f32 = 6.0;
+
+/// Where text that does not fit is cut
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Truncate {
+    /// `very_long_file_na…`
+    End,
+    /// `very_lon…_name.rs`, keeps file extensions visible
+    Middle,
+}
+
+/// Shorten `text` with "…" so `measure` reports at most `max_width`
+///
+/// Returns `Cow::Borrowed` when the text already fits and `Cow::Owned` when it was cut.
+pub fn truncate_text<'a>(text: &'a str, max_width: f32, mode: Truncate, mut measure: impl FnMut(&str) -> f32) -> Cow<'a, str> {
+    if measure(text) <= max_width {
+        return Cow::Borrowed(text);
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let shorten = |keep: usize| -> String {
+        match mode {
+            Truncate::End => chars[..keep].iter().collect::<String>() + ELLIPSIS,
+            Truncate::Middle => {
+                let head = keep.div_ceil(2);
+                let tail = keep - head;
+                let mut result: String = chars[..head].iter().collect();
+                result.push_str(ELLIPSIS);
+                result.extend(&chars[chars.len() - tail..]);
+                result
+            }
+        }
+    };
+
+    // Largest number of kept chars that still fits
+    let (mut low, mut high) = (0, chars.len());
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+        if measure(&shorten(mid)) <= max_width {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    Cow::Owned(shorten(low))
+}
+
+/// Break `text` into lines no wider than `max_width`, at spaces where possible
+///
+/// Explicit `\n` always starts a new line; words wider than a line are split between chars.
+pub fn wrap_text(text: &str, max_width: f32, mut measure: impl FnMut(&str) -> f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split_inclusive(' ') {
+            let candidate = format!("{}{}", line, word);
+            if measure(candidate.trim_end()) <= max_width {
+                line = candidate;
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(line.trim_end().to_string());
+                line.clear();
+            }
+            for ch in word.chars() {
+                line.push(ch);
+                if line.chars().count() > 1 && measure(line.trim_end()) > max_width {
+                    line.pop();
+                    lines.push(line.trim_end().to_string());
+                    line = ch.to_string();
+                }
+            }
+        }
+        lines.push(line.trim_end().to_string());
+    }
+    lines
+}
 
 pub struct Label {
     x: f32,
     y: f32,
-    text: &'static str,
+    text: String,
     font_size: f32,
     weight: i32,
     color: Color,
+    align: Option<(TextAlign, f32)>,
+    max_width: Option<f32>,
+    truncate: Truncate,
+    wrap: bool,
+    max_lines: Option<usize>,
+    show_tooltip: bool,
+    /// Whether the last draw had to cut the text; drives the tooltip
+    truncated: Cell<bool>,
+    /// Height of the last draw, used as the tooltip's hover area
+    drawn_height: Cell<f32>,
+    hover_point: Option<(f32, f32)>,
+    hover_since: Option<f32>,
+    now: f32,
 }
 
 impl Label {
     pub fn new(
         x: f32,
         y: f32,
-        text: &'static str,
+        text: impl Into<String>,
         font_size: f32,
         weight: i32,
         color: Color,
@@ -23,10 +122,148 @@ impl Label {
         Self {
             x,
             y,
-            text,
+            text: text.into(),
             font_size,
             weight,
             color,
+            align: None,
+            max_width: None,
+            truncate: Truncate::End,
+            wrap: false,
+            max_lines: None,
+            show_tooltip: true,
+            truncated: Cell::new(false),
+            drawn_height: Cell::new(font_size * LINE_SPACING),
+            hover_point: None,
+            hover_since: None,
+            now: 0.0,
+        }
+    }
+
+    /// Align within `width`; `TextAlign::Start` hugs the right edge in RTL layouts
+    pub fn align(mut self, align: TextAlign, width: f32) -> Self {
+        self.align = Some((align, width));
+        self
+    }
+
+    /// Cut (or wrap) text wider than `width`
+    pub fn max_width(mut self, width: f32) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Where single-line text is cut; `Truncate::End` by default
+    pub fn truncate(mut self, truncate: Truncate) -> Self {
+        self.truncate = truncate;
+        self
+    }
+
+    /// Wrap onto several lines instead of cutting; needs `max_width`
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Show at most `lines` wrapped lines, ending the last one with "…"
+    pub fn line_clamp(mut self, lines: usize) -> Self {
+        self.max_lines = Some(lines.max(1));
+        self
+    }
+
+    /// Show the full text on hover when it was truncated (on by default)
+    pub fn tooltip(mut self, show: bool) -> Self {
+        self.show_tooltip = show;
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+    }
+
+    pub fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+    }
+
+    pub fn is_truncated(&self) -> bool {
+        self.truncated.get()
+    }
+
+    /// Lines to draw after wrapping and truncation
+    fn layout_lines(&self, measure: &mut impl FnMut(&str) -> f32) -> (Vec<String>, bool) {
+        let Some(max_width) = self.max_width else {
+            return (self.text.split('\n').map(str::to_string).collect(), false);
+        };
+
+        if !self.wrap {
+            let first = self.text.lines().next().unwrap_or_default();
+            let cut = truncate_text(first, max_width, self.truncate, &mut *measure);
+            let truncated = matches!(cut, Cow::Owned(_)) || first.len() < self.text.len();
+            return (vec![cut.into_owned()], truncated);
+        }
+
+        let mut lines = wrap_text(&self.text, max_width, &mut *measure);
+        let clamp = self.max_lines.unwrap_or(usize::MAX);
+        if lines.len() <= clamp {
+            return (lines, false);
+        }
+        lines.truncate(clamp);
+        // Force the ellipsis onto the last visible line
+        if let Some(last) = lines.last_mut() {
+            let text = format!("{}{}", last, ELLIPSIS);
+            *last = truncate_text(&text, max_width, Truncate::End, &mut *measure).into_owned();
+            if !last.ends_with(ELLIPSIS) {
+                last.push_str(ELLIPSIS);
+            }
+        }
+        (lines, true)
+    }
+
+    fn hover_rect(&self) -> Rect {
+        let width = self.max_width.or(self.align.map(|(_, width)| width)).unwrap_or(0.0);
+        Rect::from_xywh(self.x, self.y, width, self.drawn_height.get())
+    }
+
+    fn draw_tooltip(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager, (x, y): (f32, f32)) {
+        let colors = current_theme();
+        let font = font_manager.create_font(&self.text, Theme::TEXT_XS, 400);
+        let lines: Vec<String> = self.text
+            .split('\n')
+            .map(|line| BidiLine::new(line, None).visual_text())
+            .collect();
+        let line_height = Theme::TEXT_XS * LINE_SPACING;
+        let text_width = lines
+            .iter()
+            .map(|line| font_manager.measure_text(line, &font))
+            .fold(0.0, f32::max);
+        let rect = Rect::from_xywh(
+            x,
+            y + 18.0,
+            text_width + TOOLTIP_PADDING * 2.0,
+            line_height * lines.len() as f32 + TOOLTIP_PADDING * 2.0,
+        );
+
+        let mut bg_paint = Paint::default();
+        bg_paint.set_anti_alias(true);
+        bg_paint.set_color(colors.popover);
+        canvas.draw_round_rect(rect, Theme::RADIUS_SM, Theme::RADIUS_SM, &bg_paint);
+        let mut border_paint = Paint::default();
+        border_paint.set_anti_alias(true);
+        border_paint.set_style(skia_safe::PaintStyle::Stroke);
+        border_paint.set_stroke_width(1.0);
+        border_paint.set_color(colors.border);
+        canvas.draw_round_rect(rect.with_inset((0.5, 0.5)), Theme::RADIUS_SM, Theme::RADIUS_SM, &border_paint);
+
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        text_paint.set_color(colors.popover_foreground);
+        for (index, line) in lines.iter().enumerate() {
+            let baseline = rect.top + TOOLTIP_PADDING + Theme::TEXT_XS + line_height * index as f32;
+            font_manager.draw_text(canvas, line, (rect.left + TOOLTIP_PADDING, baseline), &font, &text_paint);
         }
     }
 }
@@ -34,25 +271,78 @@ impl Label {
 impl Widget for Label {
     fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
         // Pass text for language detection
-        let font = font_manager.create_font(self.text, self.font_size, self.weight);
+        let font = font_manager.create_font(&self.text, self.font_size, self.weight);
 
         let mut paint = Paint::default();
         paint.set_anti_alias(true);
         paint.set_color(self.color);
 
-        canvas.draw_str(self.text, (self.x, self.y + self.font_size), &font, &paint);
+        let (lines, truncated) = self.layout_lines(&mut |text| font_manager.measure_text(text, &font));
+        self.truncated.set(truncated);
+        let line_height = self.font_size * LINE_SPACING;
+        self.drawn_height.set(line_height * lines.len() as f32);
+
+        for (index, text) in lines.iter().enumerate() {
+            // Mixed-direction text is drawn in visual order
+            let visual = BidiLine::new(text, None).visual_text();
+            let offset = match self.align {
+                Some((align, width)) => align.offset(width, font_manager.measure_text(&visual, &font), layout_direction()),
+                None => 0.0,
+            };
+            let baseline = self.y + self.font_size + line_height * index as f32;
+            // Glyphs missing from the primary font (emoji, other scripts) come from fallback fonts
+            font_manager.draw_text(canvas, &visual, (self.x + offset, baseline), &font, &paint);
+        }
+
+        let tooltip_due = self.hover_since.is_some_and(|since| self.now - since >= TOOLTIP_DELAY);
+        if let Some(point) = self.hover_point.filter(|_| tooltip_due && truncated && self.show_tooltip) {
+            self.draw_tooltip(canvas, font_manager, point);
+        }
     }
 
     fn contains(&self, _x: f32, _y: f32) -> bool {
         false // Labels are not interactive
     }
 
-    fn update_hover(&mut self, _x: f32, _y: f32) {}
+    fn update_hover(&mut self, x: f32, y: f32) {
+        if self.hover_rect().contains(skia_safe::Point::new(x, y)) {
+            if self.hover_point.is_none() {
+                self.hover_since = Some(self.now);
+            }
+            self.hover_point = Some((x, y));
+        } else {
+            self.hover_point = None;
+            self.hover_since = None;
+        }
+    }
 
-    fn update_animation(&mut self, _elapsed: f32) {}
+    fn update_animation(&mut self, elapsed: f32) {
+        self.now = elapsed;
+    }
 
     fn on_click(&mut self) {}
 
+    fn props(&self) -> Vec<Prop> {
+        vec![
+            Prop::text("text", self.text.clone()),
+            Prop::number("font_size", self.font_size, 8.0, 48.0),
+            Prop::number("weight", self.weight as f32, 100.0, 900.0),
+            Prop::bool("wrap", self.wrap),
+        ]
+    }
+
+    fn set_prop(&mut self, name: &str, value: &PropValue) -> bool {
+        match name {
+            "text" => value.as_text().map(|text| self.text = text.to_string()),
+            "font_size" => value.as_number().map(|size| self.font_size = size.round()),
+            // Font weights come in steps of 100
+            "weight" => value.as_number().map(|weight| self.weight = (weight / 100.0).round() as i32 * 100),
+            "wrap" => value.as_bool().map(|wrap| self.wrap = wrap),
+            _ => None,
+        }
+        .is_some()
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
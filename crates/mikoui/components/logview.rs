@@ -0,0 +1,597 @@
+use std::cell::Cell;
+use std::collections::VecDeque;
+
+use skia_safe::{Canvas, Color, Paint, Rect};
+
+use crate::components::Widget;
+use crate::core::FontManager;
+use crate::theme::{current_theme, with_alpha};
+
+/// Default cap on stored lines; older lines are dropped first
+const DEFAULT_MAX_LINES: usize = 100_000;
+/// Longer lines are hard-wrapped so a single huge line can't stall rendering
+const MAX_LINE_LENGTH: usize = 16_384;
+/// Tab stops used when expanding tabs
+const TAB_WIDTH: usize = 4;
+const PADDING: f32 = 8.0;
+
+/// Standard and bright ANSI colors (SGR 30-37 / 90-97)
+const ANSI_COLORS: [Color; 16] = [
+    Color::from_rgb(0, 0, 0),
+    Color::from_rgb(205, 49, 49),
+    Color::from_rgb(13, 188, 121),
+    Color::from_rgb(229, 229, 16),
+    Color::from_rgb(36, 114, 200),
+    Color::from_rgb(188, 63, 188),
+    Color::from_rgb(17, 168, 205),
+    Color::from_rgb(229, 229, 229),
+    Color::from_rgb(102, 102, 102),
+    Color::from_rgb(241, 76, 76),
+    Color::from_rgb(35, 209, 139),
+    Color::from_rgb(245, 245, 67),
+    Color::from_rgb(59, 142, 234),
+    Color::from_rgb(214, 112, 214),
+    Color::from_rgb(41, 184, 219),
+    Color::from_rgb(229, 229, 229),
+];
+
+/// Text attributes set by SGR escape sequences
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct SpanStyle {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    bold: bool,
+}
+
+/// Style run starting at a char index and lasting until the next run
+#[derive(Debug, Clone, Copy)]
+struct StyleRun {
+    start: usize,
+    style: SpanStyle,
+}
+
+#[derive(Debug, Clone, Default)]
+struct LogLine {
+    text: String,
+    len: usize,
+    runs: Vec<StyleRun>,
+}
+
+impl LogLine {
+    fn push(&mut self, ch: char, style: SpanStyle) {
+        if self.runs.last().map(|run| run.style) != Some(style) {
+            self.runs.push(StyleRun { start: self.len, style });
+        }
+        self.text.push(ch);
+        self.len += 1;
+    }
+
+    /// Styled `(start, end, style)` char ranges covering the line
+    fn spans(&self) -> impl Iterator<Item = (usize, usize, SpanStyle)> + '_ {
+        self.runs.iter().enumerate().map(|(i, run)| {
+            let end = self.runs.get(i + 1).map_or(self.len, |next| next.start);
+            (run.start, end, run.style)
+        })
+    }
+}
+
+/// Escape sequence parser state, kept between appends
+#[derive(Debug, Clone, PartialEq)]
+enum ParseState {
+    Ground,
+    Escape,
+    Csi(String),
+    Osc,
+    OscEscape,
+}
+
+/// Read-only, append-optimized text view for large logs and process output
+///
+/// Lines live in a ring buffer capped at `max_lines`, only the visible rows are
+/// drawn, ANSI SGR colors are honored and the view follows new output until the
+/// user scrolls up.
+pub struct LogView {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    font_size: f32,
+    line_height: f32,
+    /// Measured advance of the monospace font, updated on draw
+    char_width: Cell<f32>,
+    lines: VecDeque<LogLine>,
+    max_lines: usize,
+    /// Lines dropped from the front (keeps absolute line numbers stable)
+    dropped: usize,
+    /// The last line is still receiving text
+    open_line: bool,
+    /// A `\r` was seen and not yet followed by another character
+    carriage_return: bool,
+    parse_state: ParseState,
+    style: SpanStyle,
+    /// Absolute index of the first visible line
+    scroll_top: usize,
+    /// Stick to the bottom as output arrives
+    follow: bool,
+    selection_anchor: Option<(usize, usize)>,
+    selection_head: Option<(usize, usize)>,
+    selecting: bool,
+}
+
+impl LogView {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        let font_size = 13.0;
+        Self {
+            x,
+            y,
+            width,
+            height,
+            font_size,
+            line_height: font_size * 1.4,
+            char_width: Cell::new(font_size * 0.6),
+            lines: VecDeque::new(),
+            max_lines: DEFAULT_MAX_LINES,
+            dropped: 0,
+            open_line: false,
+            carriage_return: false,
+            parse_state: ParseState::Ground,
+            style: SpanStyle::default(),
+            scroll_top: 0,
+            follow: true,
+            selection_anchor: None,
+            selection_head: None,
+            selecting: false,
+        }
+    }
+
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = max_lines.max(1);
+        self
+    }
+
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self.line_height = font_size * 1.4;
+        self.char_width.set(font_size * 0.6);
+        self
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+        if self.follow {
+            self.scroll_to_bottom();
+        } else {
+            self.scroll_top = self.scroll_top.min(self.max_scroll_top());
+        }
+    }
+
+    /// Number of stored lines
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Text of a stored line (0 is the oldest line still kept)
+    pub fn line(&self, index: usize) -> Option<&str> {
+        self.lines.get(index).map(|line| line.text.as_str())
+    }
+
+    /// Whether the view sticks to the bottom as output arrives
+    pub fn is_following(&self) -> bool {
+        self.follow
+    }
+
+    /// Remove all output
+    pub fn clear(&mut self) {
+        self.dropped += self.lines.len();
+        self.lines.clear();
+        self.open_line = false;
+        self.carriage_return = false;
+        self.scroll_top = self.dropped;
+        self.follow = true;
+        self.clear_selection();
+    }
+
+    /// Append raw output, which may contain ANSI escape sequences
+    /// Sequences and lines may be split across calls
+    pub fn append(&mut self, text: &str) {
+        for ch in text.chars() {
+            match std::mem::replace(&mut self.parse_state, ParseState::Ground) {
+                ParseState::Ground => self.process_char(ch),
+                ParseState::Escape => match ch {
+                    '[' => self.parse_state = ParseState::Csi(String::new()),
+                    ']' => self.parse_state = ParseState::Osc,
+                    _ => {}
+                },
+                ParseState::Csi(mut params) => {
+                    if ('\u{40}'..='\u{7e}').contains(&ch) {
+                        if ch == 'm' {
+                            self.apply_sgr(&params);
+                        }
+                    } else if params.len() < 64 {
+                        params.push(ch);
+                        self.parse_state = ParseState::Csi(params);
+                    }
+                }
+                ParseState::Osc => match ch {
+                    '\u{07}' => {}
+                    '\u{1b}' => self.parse_state = ParseState::OscEscape,
+                    _ => self.parse_state = ParseState::Osc,
+                },
+                ParseState::OscEscape => {
+                    // ESC \ (string terminator) ends the OSC
+                }
+            }
+        }
+
+        if self.follow {
+            self.scroll_to_bottom();
+        }
+    }
+
+    /// Append text followed by a line break
+    pub fn append_line(&mut self, text: &str) {
+        self.append(text);
+        self.append("\n");
+    }
+
+    fn process_char(&mut self, ch: char) {
+        if std::mem::take(&mut self.carriage_return) && ch != '\n' {
+            // A lone carriage return rewrites the current line (progress output)
+            if self.open_line {
+                if let Some(line) = self.lines.back_mut() {
+                    *line = LogLine::default();
+                }
+            }
+        }
+
+        match ch {
+            '\u{1b}' => self.parse_state = ParseState::Escape,
+            '\n' => {
+                if !self.open_line {
+                    self.push_line();
+                }
+                self.open_line = false;
+            }
+            '\r' => self.carriage_return = true,
+            '\t' => {
+                let len = self.current_line().len;
+                for _ in 0..TAB_WIDTH - len % TAB_WIDTH {
+                    self.put_char(' ');
+                }
+            }
+            ch if ch.is_control() => {}
+            ch => self.put_char(ch),
+        }
+    }
+
+    fn put_char(&mut self, ch: char) {
+        if self.current_line().len >= MAX_LINE_LENGTH {
+            self.open_line = false;
+        }
+        let style = self.style;
+        self.current_line().push(ch, style);
+    }
+
+    /// The line receiving text, starting a new one if needed
+    fn current_line(&mut self) -> &mut LogLine {
+        if !self.open_line {
+            self.push_line();
+            self.open_line = true;
+        }
+        self.lines.back_mut().expect("line was just pushed")
+    }
+
+    fn push_line(&mut self) {
+        self.lines.push_back(LogLine::default());
+        while self.lines.len() > self.max_lines {
+            self.lines.pop_front();
+            self.dropped += 1;
+        }
+        self.scroll_top = self.scroll_top.max(self.dropped);
+    }
+
+    /// Apply a Select Graphic Rendition parameter list
+    fn apply_sgr(&mut self, params: &str) {
+        let codes: Vec<u32> = params
+            .split(';')
+            .map(|code| code.parse().unwrap_or(0))
+            .collect();
+
+        let mut i = 0;
+        while i < codes.len() {
+            match codes[i] {
+                0 => self.style = SpanStyle::default(),
+                1 => self.style.bold = true,
+                22 => self.style.bold = false,
+                code @ 30..=37 => self.style.fg = Some(ANSI_COLORS[(code - 30) as usize]),
+                code @ 90..=97 => self.style.fg = Some(ANSI_COLORS[(code - 90 + 8) as usize]),
+                39 => self.style.fg = None,
+                code @ 40..=47 => self.style.bg = Some(ANSI_COLORS[(code - 40) as usize]),
+                code @ 100..=107 => self.style.bg = Some(ANSI_COLORS[(code - 100 + 8) as usize]),
+                49 => self.style.bg = None,
+                code @ (38 | 48) => {
+                    let (color, used) = Self::extended_color(&codes[i + 1..]);
+                    if code == 38 {
+                        self.style.fg = color;
+                    } else {
+                        self.style.bg = color;
+                    }
+                    i += used;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+
+    /// Parse `5;n` (256 colors) or `2;r;g;b` (true color) after 38/48
+    /// Returns the color and how many parameters were consumed
+    fn extended_color(params: &[u32]) -> (Option<Color>, usize) {
+        match params {
+            [5, n, ..] => {
+                let n = *n as usize;
+                let color = match n {
+                    0..=15 => ANSI_COLORS[n],
+                    16..=231 => {
+                        let level = |v: usize| if v == 0 { 0 } else { (v * 40 + 55) as u8 };
+                        let n = n - 16;
+                        Color::from_rgb(level(n / 36), level(n / 6 % 6), level(n % 6))
+                    }
+                    _ => {
+                        let gray = (8 + (n.min(255) - 232) * 10) as u8;
+                        Color::from_rgb(gray, gray, gray)
+                    }
+                };
+                (Some(color), 2)
+            }
+            [2, r, g, b, ..] => (Some(Color::from_rgb(*r as u8, *g as u8, *b as u8)), 4),
+            _ => (None, params.len()),
+        }
+    }
+
+    fn visible_rows(&self) -> usize {
+        (((self.height - PADDING * 2.0) / self.line_height).floor() as usize).max(1)
+    }
+
+    fn max_scroll_top(&self) -> usize {
+        self.dropped + self.lines.len().saturating_sub(self.visible_rows())
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll_top = self.max_scroll_top();
+        self.follow = true;
+    }
+
+    /// Scroll by a pixel delta; scrolling up locks the view, reaching the bottom unlocks it
+    pub fn scroll(&mut self, delta: f32) {
+        let rows = (delta / self.line_height).round() as isize;
+        let rows = if rows == 0 && delta != 0.0 { delta.signum() as isize } else { rows };
+
+        let top = (self.scroll_top as isize + rows)
+            .clamp(self.dropped as isize, self.max_scroll_top() as isize) as usize;
+        self.scroll_top = top;
+        self.follow = top >= self.max_scroll_top();
+    }
+
+    /// Absolute `(line, column)` under a point, clamped to the stored text
+    fn position_at(&self, x: f32, y: f32) -> Option<(usize, usize)> {
+        if self.lines.is_empty() {
+            return None;
+        }
+
+        let row = ((y - self.y - PADDING) / self.line_height).floor().max(0.0) as usize;
+        let line = (self.scroll_top + row).min(self.dropped + self.lines.len() - 1);
+        let len = self.lines[line - self.dropped].len;
+        let column = ((x - self.x - PADDING) / self.char_width.get()).round().max(0.0) as usize;
+        Some((line, column.min(len)))
+    }
+
+    pub fn start_selection(&mut self, x: f32, y: f32) {
+        let position = self.position_at(x, y);
+        self.selection_anchor = position;
+        self.selection_head = position;
+        self.selecting = position.is_some();
+    }
+
+    pub fn extend_selection(&mut self, x: f32, y: f32) {
+        if !self.selecting {
+            return;
+        }
+
+        // Dragging past the edges scrolls the view
+        if y < self.y + PADDING {
+            self.scroll(-self.line_height);
+        } else if y > self.y + self.height - PADDING {
+            self.scroll(self.line_height);
+        }
+        self.selection_head = self.position_at(x, y);
+    }
+
+    pub fn end_selection(&mut self) {
+        self.selecting = false;
+    }
+
+    pub fn is_selecting(&self) -> bool {
+        self.selecting
+    }
+
+    pub fn select_all(&mut self) {
+        if let Some(last) = self.lines.back() {
+            self.selection_anchor = Some((self.dropped, 0));
+            self.selection_head = Some((self.dropped + self.lines.len() - 1, last.len));
+        }
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selection_anchor = None;
+        self.selection_head = None;
+        self.selecting = false;
+    }
+
+    /// Ordered selection bounds, clamped to lines still stored
+    fn selection(&self) -> Option<((usize, usize), (usize, usize))> {
+        let (anchor, head) = (self.selection_anchor?, self.selection_head?);
+        let (start, end) = if anchor <= head { (anchor, head) } else { (head, anchor) };
+        if start == end || end.0 < self.dropped {
+            return None;
+        }
+        let start = if start.0 < self.dropped { (self.dropped, 0) } else { start };
+        Some((start, end))
+    }
+
+    pub fn has_selection(&self) -> bool {
+        self.selection().is_some()
+    }
+
+    /// Selected text with escape sequences stripped
+    pub fn selected_text(&self) -> String {
+        let Some((start, end)) = self.selection() else {
+            return String::new();
+        };
+
+        let mut result = String::new();
+        for line_idx in start.0..=end.0 {
+            let line = &self.lines[line_idx - self.dropped];
+            let from = if line_idx == start.0 { start.1 } else { 0 };
+            let to = if line_idx == end.0 { end.1 } else { line.len };
+            result.extend(line.text.chars().skip(from).take(to.saturating_sub(from)));
+            if line_idx != end.0 {
+                result.push('\n');
+            }
+        }
+        result
+    }
+
+    fn draw_scrollbar(&self, canvas: &Canvas) {
+        let total = self.lines.len();
+        let rows = self.visible_rows();
+        if total <= rows {
+            return;
+        }
+
+        let track_height = self.height - PADDING * 2.0;
+        let thumb_height = (track_height * rows as f32 / total as f32).max(20.0);
+        let progress = ((self.scroll_top - self.dropped) as f32 / (total - rows) as f32).min(1.0);
+        let thumb_y = self.y + PADDING + (track_height - thumb_height) * progress;
+
+        let mut thumb_paint = Paint::default();
+        thumb_paint.set_color(with_alpha(current_theme().muted_foreground, 80));
+        thumb_paint.set_anti_alias(true);
+        canvas.draw_round_rect(
+            Rect::from_xywh(self.x + self.width - 8.0, thumb_y, 6.0, thumb_height),
+            3.0,
+            3.0,
+            &thumb_paint,
+        );
+    }
+}
+
+impl Widget for LogView {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut FontManager) {
+        let theme = current_theme();
+        let font = font_manager.create_mono_font(self.font_size, 400);
+        let char_width = font.measure_str("M", None).0;
+        if char_width > 0.0 {
+            self.char_width.set(char_width);
+        }
+        let char_width = self.char_width.get();
+
+        canvas.save();
+        canvas.clip_rect(Rect::from_xywh(self.x, self.y, self.width, self.height), None, None);
+
+        let selection = self.selection();
+        let mut selection_paint = Paint::default();
+        selection_paint.set_color(with_alpha(theme.primary, 60));
+        selection_paint.set_anti_alias(true);
+
+        let text_x = self.x + PADDING;
+        let first = self.scroll_top - self.dropped;
+        let last = (first + self.visible_rows() + 1).min(self.lines.len());
+
+        for (row, line) in self.lines.range(first..last).enumerate() {
+            let line_idx = self.scroll_top + row;
+            let line_y = self.y + PADDING + row as f32 * self.line_height;
+            let baseline = line_y + (self.line_height + self.font_size) / 2.0 - 2.0;
+
+            // Selection highlight
+            if let Some((start, end)) = selection {
+                if line_idx >= start.0 && line_idx <= end.0 {
+                    let from = if line_idx == start.0 { start.1 } else { 0 };
+                    let to = if line_idx == end.0 { end.1 } else { line.len + 1 };
+                    if to > from {
+                        canvas.draw_rect(
+                            Rect::from_xywh(
+                                text_x + from as f32 * char_width,
+                                line_y,
+                                (to - from) as f32 * char_width,
+                                self.line_height,
+                            ),
+                            &selection_paint,
+                        );
+                    }
+                }
+            }
+
+            for (start, end, style) in line.spans() {
+                let span_x = text_x + start as f32 * char_width;
+                if span_x > self.x + self.width {
+                    break;
+                }
+
+                if let Some(bg) = style.bg {
+                    let mut bg_paint = Paint::default();
+                    bg_paint.set_color(bg);
+                    canvas.draw_rect(
+                        Rect::from_xywh(span_x, line_y, (end - start) as f32 * char_width, self.line_height),
+                        &bg_paint,
+                    );
+                }
+
+                let text: String = line.text.chars().skip(start).take(end - start).collect();
+                let mut text_paint = Paint::default();
+                text_paint.set_color(style.fg.unwrap_or(theme.foreground));
+                text_paint.set_anti_alias(true);
+                if style.bold {
+                    let bold_font = font_manager.create_mono_font(self.font_size, 700);
+                    canvas.draw_str(&text, (span_x, baseline), &bold_font, &text_paint);
+                } else {
+                    canvas.draw_str(&text, (span_x, baseline), &font, &text_paint);
+                }
+            }
+        }
+
+        self.draw_scrollbar(canvas);
+        canvas.restore();
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.extend_selection(x, y);
+    }
+
+    fn update_animation(&mut self, _elapsed: f32) {
+        // No animations
+    }
+
+    fn on_click(&mut self) {
+        // Selection is driven by start_selection / end_selection
+    }
+
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -13,6 +13,28 @@ mod dropdown;
 mod card;
 mod badge;
 mod skeleton;
+mod logview;
+mod form;
+mod colorpicker;
+mod avatar;
+mod breadcrumb;
+mod pagination;
+mod separator;
+mod alert;
+mod alertdialog;
+mod hovercard;
+mod toggle;
+mod splitter;
+mod carousel;
+mod chart;
+mod focus;
+mod scrollbar;
+mod scrollview;
+mod typeahead;
+mod fuzzy;
+mod toast;
+mod inspector;
+mod props;
 
 pub mod lucide;
 pub mod codicon;
@@ -20,17 +42,39 @@ pub mod codicon;
 pub use button::Button;
 pub use checkbox::Checkbox;
 pub use icon::{Icon, IconSize};
-pub use input::Input;
-pub use label::Label;
+pub use input::{Input, Validator, ERROR_MESSAGE_HEIGHT};
+pub use label::{truncate_text, wrap_text, Label, Truncate};
 pub use lucide::LucideIcons;
 pub use codicon::CodiconIcons;
 pub use panel::Panel;
 pub use progress::{ProgressBar, ProgressSize};
 pub use slider::Slider;
-pub use widget::Widget;
+pub use widget::{widget_at, Widget, WidgetId};
 pub use contextmenu::{ContextMenu, MenuItem};
 pub use dropdown::Dropdown;
 // pub use menubar::{MenuBar, MenuBarItem};
 pub use card::Card;
 pub use badge::Badge;
 pub use skeleton::Skeleton;
+pub use logview::LogView;
+pub use form::Form;
+pub use colorpicker::ColorPicker;
+pub use avatar::{Avatar, AvatarGroup, AvatarShape};
+pub use breadcrumb::Breadcrumb;
+pub use pagination::{PageItem, Pagination};
+pub use separator::{Orientation, Separator};
+pub use alert::{Alert, AlertVariant};
+pub use alertdialog::{AlertDialog, AlertDialogAction};
+pub use hovercard::{HoverCard, HoverCardSide};
+pub use carousel::Carousel;
+pub use chart::{format_value, Chart, ChartKind, ChartSeries, Sparkline};
+pub use focus::{draw_focus_outline, FocusManager, FocusOrigin, FOCUS_OUTLINE_OFFSET, FOCUS_OUTLINE_WIDTH};
+pub use scrollbar::{Scrollbar, ScrollbarStyle};
+pub use scrollview::ScrollView;
+pub use splitter::{Splitter, SplitterSide};
+pub use toggle::{Toggle, ToggleCallback, ToggleGroup, ToggleGroupCallback, ToggleGroupMode};
+pub use typeahead::Typeahead;
+pub use fuzzy::{fuzzy_match, FuzzyMatch};
+pub use toast::Toast;
+pub use inspector::{InspectedWidget, Inspector};
+pub use props::{Prop, PropValue};
@@ -0,0 +1,220 @@
+use skia_safe::{Canvas, Paint, Rect};
+
+use crate::components::Widget;
+use crate::theme::{current_theme, Theme};
+
+const ITEM_SIZE: f32 = 32.0;
+const GAP: f32 = 4.0;
+
+/// One slot of the pagination bar
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PageItem {
+    Previous,
+    Page(usize),
+    Ellipsis,
+    Next,
+}
+
+/// Page buttons with previous/next arrows; long ranges collapse into ellipses
+/// Pages are one-based
+pub struct Pagination {
+    x: f32,
+    y: f32,
+    page: usize,
+    page_count: usize,
+    siblings: usize,
+    hover: Option<usize>,
+}
+
+impl Pagination {
+    pub fn new(x: f32, y: f32, page_count: usize) -> Self {
+        Self {
+            x,
+            y,
+            page: 1,
+            page_count: page_count.max(1),
+            siblings: 1,
+            hover: None,
+        }
+    }
+
+    /// Pages shown on each side of the current one
+    pub fn siblings(mut self, siblings: usize) -> Self {
+        self.siblings = siblings;
+        self
+    }
+
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    pub fn set_page(&mut self, page: usize) {
+        self.page = page.clamp(1, self.page_count);
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.page_count
+    }
+
+    pub fn set_page_count(&mut self, page_count: usize) {
+        self.page_count = page_count.max(1);
+        self.set_page(self.page);
+    }
+
+    pub fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+    }
+
+    /// Slots in display order: first and last page are always shown, plus the
+    /// current page and its siblings; gaps of more than one page become ellipses
+    pub fn items(&self) -> Vec<PageItem> {
+        let start = self.page.saturating_sub(self.siblings).max(1);
+        let end = (self.page + self.siblings).min(self.page_count);
+
+        let mut items = vec![PageItem::Previous];
+        if start > 1 {
+            items.push(PageItem::Page(1));
+            match start {
+                2 => {}
+                3 => items.push(PageItem::Page(2)),
+                _ => items.push(PageItem::Ellipsis),
+            }
+        }
+        items.extend((start..=end).map(PageItem::Page));
+        if end < self.page_count {
+            match self.page_count - end {
+                1 => {}
+                2 => items.push(PageItem::Page(self.page_count - 1)),
+                _ => items.push(PageItem::Ellipsis),
+            }
+            items.push(PageItem::Page(self.page_count));
+        }
+        items.push(PageItem::Next);
+        items
+    }
+
+    pub fn width(&self) -> f32 {
+        let count = self.items().len() as f32;
+        count * ITEM_SIZE + (count - 1.0) * GAP
+    }
+
+    fn item_rect(&self, index: usize) -> Rect {
+        Rect::from_xywh(self.x + index as f32 * (ITEM_SIZE + GAP), self.y, ITEM_SIZE, ITEM_SIZE)
+    }
+
+    fn item_index_at(&self, x: f32, y: f32) -> Option<usize> {
+        if y < self.y || y > self.y + ITEM_SIZE || x < self.x {
+            return None;
+        }
+        let index = ((x - self.x) / (ITEM_SIZE + GAP)) as usize;
+        let rect = self.item_rect(index);
+        (index < self.items().len() && x <= rect.right).then_some(index)
+    }
+
+    fn target(&self, item: PageItem) -> Option<usize> {
+        match item {
+            PageItem::Previous if self.page > 1 => Some(self.page - 1),
+            PageItem::Next if self.page < self.page_count => Some(self.page + 1),
+            PageItem::Page(page) if page != self.page => Some(page),
+            _ => None,
+        }
+    }
+
+    /// Go to the clicked page; returns the new page if it changed
+    pub fn handle_click(&mut self, x: f32, y: f32) -> Option<usize> {
+        let item = *self.items().get(self.item_index_at(x, y)?)?;
+        let page = self.target(item)?;
+        self.page = page;
+        Some(page)
+    }
+
+    fn draw_arrow(canvas: &Canvas, rect: Rect, left: bool, paint: &Paint) {
+        let (cx, cy) = (rect.center_x(), rect.center_y());
+        let dx = if left { 2.0 } else { -2.0 };
+        canvas.draw_line((cx + dx, cy - 4.0), (cx - dx, cy), paint);
+        canvas.draw_line((cx - dx, cy), (cx + dx, cy + 4.0), paint);
+    }
+}
+
+impl Widget for Pagination {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
+        let colors = current_theme();
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+
+        for (index, item) in self.items().into_iter().enumerate() {
+            let rect = self.item_rect(index);
+            let enabled = self.target(item).is_some();
+            let current = item == PageItem::Page(self.page);
+
+            // Current page is outlined; other enabled items get a hover background
+            if current {
+                let mut border_paint = Paint::default();
+                border_paint.set_anti_alias(true);
+                border_paint.set_style(skia_safe::PaintStyle::Stroke);
+                border_paint.set_color(colors.border);
+                border_paint.set_stroke_width(1.0);
+                canvas.draw_round_rect(rect.with_inset((0.5, 0.5)), Theme::RADIUS_MD, Theme::RADIUS_MD, &border_paint);
+            } else if enabled && self.hover == Some(index) {
+                let mut hover_paint = Paint::default();
+                hover_paint.set_anti_alias(true);
+                hover_paint.set_color(colors.accent);
+                canvas.draw_round_rect(rect, Theme::RADIUS_MD, Theme::RADIUS_MD, &hover_paint);
+            }
+
+            let color = match item {
+                PageItem::Previous | PageItem::Next if !enabled => colors.muted_foreground,
+                PageItem::Ellipsis => colors.muted_foreground,
+                _ if self.hover == Some(index) && !current => colors.accent_foreground,
+                _ => colors.foreground,
+            };
+
+            match item {
+                PageItem::Previous | PageItem::Next => {
+                    let mut arrow_paint = Paint::default();
+                    arrow_paint.set_anti_alias(true);
+                    arrow_paint.set_style(skia_safe::PaintStyle::Stroke);
+                    arrow_paint.set_stroke_width(1.5);
+                    arrow_paint.set_color(color);
+                    Self::draw_arrow(canvas, rect, item == PageItem::Previous, &arrow_paint);
+                }
+                PageItem::Page(_) | PageItem::Ellipsis => {
+                    let label = match item {
+                        PageItem::Page(page) => page.to_string(),
+                        _ => "…".to_string(),
+                    };
+                    let font = font_manager.create_font(&label, Theme::TEXT_SM, if current { 600 } else { 400 });
+                    text_paint.set_color(color);
+                    let (text_width, _) = font.measure_str(&label, Some(&text_paint));
+                    canvas.draw_str(
+                        &label,
+                        (rect.center_x() - text_width / 2.0, rect.center_y() + Theme::TEXT_SM * 0.35),
+                        &font,
+                        &text_paint,
+                    );
+                }
+            }
+        }
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width() && y >= self.y && y <= self.y + ITEM_SIZE
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hover = self.item_index_at(x, y);
+    }
+
+    fn update_animation(&mut self, _elapsed: f32) {}
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -1,7 +1,8 @@
 use skia_safe::{Canvas, Paint, Rect};
 
 use crate::components::Widget;
-use crate::theme::{current_theme, with_alpha, Theme};
+use crate::core::animation_rate;
+use crate::theme::{current_theme, with_alpha, Style, Theme};
 
 pub struct Panel {
     x: f32,
@@ -9,6 +10,7 @@ pub struct Panel {
     width: f32,
     height: f32,
     title: Option<&'static str>,
+    style: Style,
     hover: bool,
     hover_progress: f32,
 }
@@ -21,6 +23,7 @@ impl Panel {
             width,
             height,
             title: None,
+            style: Style::default(),
             hover: false,
             hover_progress: 0.0,
         }
@@ -30,17 +33,25 @@ impl Panel {
         self.title = Some(title);
         self
     }
+
+    /// Local overrides of the theme colors, border, radius, title padding and opacity
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
 }
 
 impl Widget for Panel {
     fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
-        let border_radius = 8.0;
+        let border_radius = self.style.radius.unwrap_or(8.0);
         let colors = current_theme();
+        let layered = self.style.push_opacity(canvas);
+        let border_width = self.style.border_width.unwrap_or(1.0);
 
         // Background
         let mut bg_paint = Paint::default();
         bg_paint.set_anti_alias(true);
-        bg_paint.set_color(colors.card);
+        bg_paint.set_color(self.style.background.unwrap_or(colors.card));
         canvas.draw_round_rect(
             Rect::from_xywh(self.x, self.y, self.width, self.height),
             border_radius,
@@ -52,19 +63,21 @@ impl Widget for Panel {
         let mut border_paint = Paint::default();
         border_paint.set_anti_alias(true);
         border_paint.set_style(skia_safe::PaintStyle::Stroke);
-        border_paint.set_color(colors.border);
-        border_paint.set_stroke_width(1.0);
-        canvas.draw_round_rect(
-            Rect::from_xywh(
-                self.x + 0.5,
-                self.y + 0.5,
-                self.width - 1.0,
-                self.height - 1.0,
-            ),
-            border_radius,
-            border_radius,
-            &border_paint,
-        );
+        border_paint.set_color(self.style.border_color.unwrap_or(colors.border));
+        border_paint.set_stroke_width(border_width);
+        if border_width > 0.0 {
+            canvas.draw_round_rect(
+                Rect::from_xywh(
+                    self.x + border_width / 2.0,
+                    self.y + border_width / 2.0,
+                    self.width - border_width,
+                    self.height - border_width,
+                ),
+                border_radius,
+                border_radius,
+                &border_paint,
+            );
+        }
 
         // Subtle shadow on hover
         if self.hover_progress > 0.0 {
@@ -86,9 +99,14 @@ impl Widget for Panel {
             let font = font_manager.create_font(title, Theme::TEXT_SM, 600);
             let mut text_paint = Paint::default();
             text_paint.set_anti_alias(true);
-            text_paint.set_color(colors.foreground);
+            text_paint.set_color(self.style.foreground.unwrap_or(colors.foreground));
+
+            let padding = self.style.padding.unwrap_or(Theme::SPACE_4);
+            canvas.draw_str(title, (self.x + padding, self.y + 28.0), &font, &text_paint);
+        }
 
-            canvas.draw_str(title, (self.x + Theme::SPACE_4, self.y + 28.0), &font, &text_paint);
+        if layered {
+            canvas.restore();
         }
     }
 
@@ -104,7 +122,7 @@ impl Widget for Panel {
         let animation_speed = 0.1;
         let target_hover = if self.hover { 1.0 } else { 0.0 };
         if (self.hover_progress - target_hover).abs() > 0.01 {
-            self.hover_progress += (target_hover - self.hover_progress) * animation_speed;
+            self.hover_progress += (target_hover - self.hover_progress) * animation_rate(animation_speed);
         } else {
             self.hover_progress = target_hover;
         }
@@ -112,6 +130,15 @@ impl Widget for Panel {
 
     fn on_click(&mut self) {}
 
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+
+    fn content_bounds(&self) -> Option<Rect> {
+        let padding = self.style.padding.unwrap_or(Theme::SPACE_4);
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height).with_inset((padding, padding)))
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
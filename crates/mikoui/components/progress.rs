@@ -1,6 +1,8 @@
 use skia_safe::{Canvas, Paint, Rect};
 
-use crate::components::Widget;
+use crate::components::props::{progress_size_from, progress_size_prop};
+use crate::components::{Prop, PropValue, Widget};
+use crate::core::{animation_rate, animations_enabled};
 use crate::theme::{current_theme, with_alpha, Theme};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -154,17 +156,39 @@ impl Widget for ProgressBar {
         // Smooth progress animation
         let animation_speed = 0.1;
         if (self.animated_progress - self.progress).abs() > 0.001 {
-            self.animated_progress += (self.progress - self.animated_progress) * animation_speed;
+            self.animated_progress += (self.progress - self.animated_progress) * animation_rate(animation_speed);
         } else {
             self.animated_progress = self.progress;
         }
 
-        // Pulse/shine animation
-        self.pulse_offset = (elapsed * 0.5).fract();
+        // Pulse/shine animation; held still when animations are off
+        if animations_enabled() {
+            self.pulse_offset = (elapsed * 0.5).fract();
+        }
     }
 
     fn on_click(&mut self) {}
 
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+
+    fn props(&self) -> Vec<Prop> {
+        vec![Prop::number("progress", self.progress, 0.0, 1.0), progress_size_prop(self.size)]
+    }
+
+    fn set_prop(&mut self, name: &str, value: &PropValue) -> bool {
+        match name {
+            "progress" => value.as_number().map(|progress| self.set_progress(progress)),
+            "size" => progress_size_from(value).map(|size| {
+                self.size = size;
+                self.height = size.height();
+            }),
+            _ => None,
+        }
+        .is_some()
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
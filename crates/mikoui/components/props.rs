@@ -0,0 +1,170 @@
+use crate::components::{AlertVariant, AvatarShape, Orientation, ProgressSize};
+use crate::theme::{Size, Variant};
+
+/// Current value of a component property, with what an editor for it needs to know
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropValue {
+    Bool(bool),
+    /// A value within `min..=max`
+    Number { value: f32, min: f32, max: f32 },
+    Text(String),
+    /// Index into `options`
+    Choice { selected: usize, options: &'static [&'static str] },
+}
+
+impl PropValue {
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            PropValue::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The number, clamped to its range
+    pub fn as_number(&self) -> Option<f32> {
+        match *self {
+            PropValue::Number { value, min, max } => Some(value.clamp(min, max)),
+            _ => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            PropValue::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// The selected index, if it names one of the options
+    pub fn as_choice(&self) -> Option<usize> {
+        match *self {
+            PropValue::Choice { selected, options } => (selected < options.len()).then_some(selected),
+            _ => None,
+        }
+    }
+}
+
+/// A named property a widget describes about itself through `Widget::props`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Prop {
+    pub name: &'static str,
+    pub value: PropValue,
+}
+
+impl Prop {
+    pub fn bool(name: &'static str, value: bool) -> Self {
+        Self { name, value: PropValue::Bool(value) }
+    }
+
+    pub fn number(name: &'static str, value: f32, min: f32, max: f32) -> Self {
+        Self { name, value: PropValue::Number { value, min, max } }
+    }
+
+    pub fn text(name: &'static str, value: impl Into<String>) -> Self {
+        Self { name, value: PropValue::Text(value.into()) }
+    }
+
+    pub fn choice(name: &'static str, selected: usize, options: &'static [&'static str]) -> Self {
+        Self { name, value: PropValue::Choice { selected, options } }
+    }
+}
+
+/// Built-in variants, in the order `variant_prop` lists them; custom ones aren't offered
+const VARIANTS: [Variant; 6] =
+    [Variant::Default, Variant::Destructive, Variant::Outline, Variant::Secondary, Variant::Ghost, Variant::Link];
+const VARIANT_NAMES: &[&str] = &["default", "destructive", "outline", "secondary", "ghost", "link"];
+const SIZES: [Size; 3] = [Size::Sm, Size::Md, Size::Lg];
+const SIZE_NAMES: &[&str] = &["sm", "md", "lg"];
+const PROGRESS_SIZES: [ProgressSize; 5] = [ProgressSize::Xs, ProgressSize::Sm, ProgressSize::Md, ProgressSize::Lg, ProgressSize::Xl];
+const PROGRESS_SIZE_NAMES: &[&str] = &["xs", "sm", "md", "lg", "xl"];
+const ALERT_VARIANTS: [AlertVariant; 3] = [AlertVariant::Info, AlertVariant::Warning, AlertVariant::Destructive];
+const ALERT_VARIANT_NAMES: &[&str] = &["info", "warning", "destructive"];
+const AVATAR_SHAPES: [AvatarShape; 2] = [AvatarShape::Circle, AvatarShape::Rounded];
+const AVATAR_SHAPE_NAMES: &[&str] = &["circle", "rounded"];
+const ORIENTATIONS: [Orientation; 2] = [Orientation::Horizontal, Orientation::Vertical];
+const ORIENTATION_NAMES: &[&str] = &["horizontal", "vertical"];
+
+fn choice<T: PartialEq + Copy>(name: &'static str, current: T, values: &[T], names: &'static [&'static str]) -> Prop {
+    Prop::choice(name, values.iter().position(|value| *value == current).unwrap_or(0), names)
+}
+
+fn chosen<T: Copy>(value: &PropValue, values: &[T]) -> Option<T> {
+    value.as_choice().and_then(|index| values.get(index).copied())
+}
+
+/// A `variant` choice; a custom variant shows as `default`
+pub(crate) fn variant_prop(current: Variant) -> Prop {
+    choice("variant", current, &VARIANTS, VARIANT_NAMES)
+}
+
+pub(crate) fn variant_from(value: &PropValue) -> Option<Variant> {
+    chosen(value, &VARIANTS)
+}
+
+pub(crate) fn size_prop(current: Size) -> Prop {
+    choice("size", current, &SIZES, SIZE_NAMES)
+}
+
+pub(crate) fn size_from(value: &PropValue) -> Option<Size> {
+    chosen(value, &SIZES)
+}
+
+pub(crate) fn progress_size_prop(current: ProgressSize) -> Prop {
+    choice("size", current, &PROGRESS_SIZES, PROGRESS_SIZE_NAMES)
+}
+
+pub(crate) fn progress_size_from(value: &PropValue) -> Option<ProgressSize> {
+    chosen(value, &PROGRESS_SIZES)
+}
+
+pub(crate) fn alert_variant_prop(current: AlertVariant) -> Prop {
+    choice("variant", current, &ALERT_VARIANTS, ALERT_VARIANT_NAMES)
+}
+
+pub(crate) fn alert_variant_from(value: &PropValue) -> Option<AlertVariant> {
+    chosen(value, &ALERT_VARIANTS)
+}
+
+pub(crate) fn avatar_shape_prop(current: AvatarShape) -> Prop {
+    choice("shape", current, &AVATAR_SHAPES, AVATAR_SHAPE_NAMES)
+}
+
+pub(crate) fn avatar_shape_from(value: &PropValue) -> Option<AvatarShape> {
+    chosen(value, &AVATAR_SHAPES)
+}
+
+pub(crate) fn orientation_prop(current: Orientation) -> Prop {
+    choice("orientation", current, &ORIENTATIONS, ORIENTATION_NAMES)
+}
+
+pub(crate) fn orientation_from(value: &PropValue) -> Option<Orientation> {
+    chosen(value, &ORIENTATIONS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{Button, Widget};
+
+    #[test]
+    fn props_round_trip_through_set_prop() {
+        let mut button = Button::new(0.0, 0.0, 120.0, "Save");
+        let props = button.props();
+        let variant = props.iter().find(|prop| prop.name == "variant").unwrap();
+        assert_eq!(variant.value, PropValue::Choice { selected: 0, options: VARIANT_NAMES });
+
+        assert!(button.set_prop("variant", &PropValue::Choice { selected: 4, options: VARIANT_NAMES }));
+        assert!(button.set_prop("text", &PropValue::Text("Cancel".to_string())));
+        assert!(button.set_prop("radius", &PropValue::Number { value: 40.0, min: 0.0, max: 20.0 }));
+        let props = button.props();
+        let value = |name: &str| props.iter().find(|prop| prop.name == name).map(|prop| prop.value.clone());
+        assert_eq!(value("variant").and_then(|value| value.as_choice()), Some(4));
+        assert_eq!(value("text"), Some(PropValue::Text("Cancel".to_string())));
+        // Numbers are clamped to their range
+        assert_eq!(value("radius").and_then(|value| value.as_number()), Some(20.0));
+
+        // Unknown names and mismatched kinds are refused
+        assert!(!button.set_prop("colour", &PropValue::Bool(true)));
+        assert!(!button.set_prop("disabled", &PropValue::Text("yes".to_string())));
+    }
+}
@@ -0,0 +1,288 @@
+use skia_safe::{Canvas, Paint, Rect};
+
+use crate::components::{Orientation, Widget};
+use crate::core::animation_rate;
+use crate::theme::{current_theme, with_alpha};
+
+const THICKNESS: f32 = 6.0;
+const HOVER_THICKNESS: f32 = 10.0;
+const MIN_THUMB: f32 = 24.0;
+/// Seconds an overlay scrollbar stays visible after the last scroll
+const AUTO_HIDE_DELAY: f32 = 1.0;
+
+/// How the scrollbar sits on top of its content
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScrollbarStyle {
+    /// Thin thumb over the content that fades out when idle
+    Overlay,
+    /// Always visible thumb on a tinted track
+    Classic,
+}
+
+/// Scroll thumb for a viewport onto larger content
+///
+/// `Orientation::Vertical` scrolls along y, `Orientation::Horizontal` along x.
+/// The owner reports its sizes with `set_metrics`, mirrors wheel scrolling with
+/// `set_offset`, and reads `offset()` back after a drag or track click.
+#[derive(Debug, Clone)]
+pub struct Scrollbar {
+    orientation: Orientation,
+    style: ScrollbarStyle,
+    /// Track area; the thumb thickens inside it on hover
+    bounds: Rect,
+    content_size: f32,
+    viewport_size: f32,
+    offset: f32,
+    hover: bool,
+    /// Pointer position and offset when the thumb drag started
+    drag: Option<(f32, f32)>,
+    hover_progress: f32,
+    visibility: f32,
+    now: f32,
+    last_activity: f32,
+}
+
+impl Scrollbar {
+    pub fn new(orientation: Orientation) -> Self {
+        Self {
+            orientation,
+            style: ScrollbarStyle::Overlay,
+            bounds: Rect::default(),
+            content_size: 0.0,
+            viewport_size: 0.0,
+            offset: 0.0,
+            hover: false,
+            drag: None,
+            hover_progress: 0.0,
+            visibility: 0.0,
+            now: 0.0,
+            last_activity: f32::MIN,
+        }
+    }
+
+    pub fn style(mut self, style: ScrollbarStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Track along the right (vertical) or bottom (horizontal) edge of a viewport
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.bounds = Rect::from_xywh(x, y, width, height);
+    }
+
+    /// Place the track `HOVER_THICKNESS` wide along the edge of `viewport`
+    pub fn attach_to(&mut self, viewport: Rect) {
+        self.bounds = match self.orientation {
+            Orientation::Vertical => Rect::from_xywh(viewport.right - HOVER_THICKNESS, viewport.top, HOVER_THICKNESS, viewport.height()),
+            Orientation::Horizontal => Rect::from_xywh(viewport.left, viewport.bottom - HOVER_THICKNESS, viewport.width(), HOVER_THICKNESS),
+        };
+    }
+
+    /// Total content size and visible size along the scroll axis
+    pub fn set_metrics(&mut self, content_size: f32, viewport_size: f32) {
+        self.content_size = content_size.max(0.0);
+        self.viewport_size = viewport_size.max(0.0);
+        self.offset = self.offset.clamp(0.0, self.max_offset());
+    }
+
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Mirror an offset changed elsewhere (wheel, keyboard); briefly reveals an overlay scrollbar
+    pub fn set_offset(&mut self, offset: f32) {
+        let offset = offset.clamp(0.0, self.max_offset());
+        if (offset - self.offset).abs() > f32::EPSILON {
+            self.last_activity = self.now;
+        }
+        self.offset = offset;
+    }
+
+    pub fn max_offset(&self) -> f32 {
+        (self.content_size - self.viewport_size).max(0.0)
+    }
+
+    /// Whether the content overflows the viewport at all
+    pub fn is_scrollable(&self) -> bool {
+        self.max_offset() > 0.0
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    pub fn is_hovered(&self) -> bool {
+        self.hover
+    }
+
+    /// Whether the thumb is still fading or thickening and needs more frames
+    pub fn is_animating(&self) -> bool {
+        let settling = self.hover_progress > 0.0 && self.hover_progress < 1.0;
+        let fading = self.style == ScrollbarStyle::Overlay && self.visibility > 0.0 && !self.hover && !self.is_dragging();
+        settling || fading
+    }
+
+    fn main_axis(&self, x: f32, y: f32) -> f32 {
+        match self.orientation {
+            Orientation::Vertical => y,
+            Orientation::Horizontal => x,
+        }
+    }
+
+    fn track_start(&self) -> f32 {
+        self.main_axis(self.bounds.left, self.bounds.top)
+    }
+
+    fn track_length(&self) -> f32 {
+        self.main_axis(self.bounds.width(), self.bounds.height())
+    }
+
+    fn thumb_length(&self) -> f32 {
+        if self.content_size <= 0.0 {
+            return self.track_length();
+        }
+        (self.track_length() * self.viewport_size / self.content_size)
+            .clamp(MIN_THUMB.min(self.track_length()), self.track_length())
+    }
+
+    /// Thumb position along the track, relative to its start
+    fn thumb_start(&self) -> f32 {
+        let free = self.track_length() - self.thumb_length();
+        match self.max_offset() {
+            max if max > 0.0 => free * self.offset / max,
+            _ => 0.0,
+        }
+    }
+
+    /// Thumb rectangle at the current hover thickness
+    pub fn thumb_rect(&self) -> Rect {
+        let thickness = THICKNESS + (HOVER_THICKNESS - THICKNESS) * self.hover_progress;
+        let start = self.track_start() + self.thumb_start();
+        let length = self.thumb_length();
+        match self.orientation {
+            Orientation::Vertical => Rect::from_xywh(self.bounds.right - thickness - 1.0, start, thickness, length),
+            Orientation::Horizontal => Rect::from_xywh(start, self.bounds.bottom - thickness - 1.0, length, thickness),
+        }
+    }
+
+    /// Grab the thumb, or jump a page towards the pointer on the track; returns true if the bar was hit
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> bool {
+        if !self.is_scrollable() || !self.contains(x, y) {
+            return false;
+        }
+
+        let position = self.main_axis(x, y);
+        let thumb_start = self.track_start() + self.thumb_start();
+        if position < thumb_start {
+            self.set_offset(self.offset - self.viewport_size);
+        } else if position > thumb_start + self.thumb_length() {
+            self.set_offset(self.offset + self.viewport_size);
+        } else {
+            self.drag = Some((position, self.offset));
+        }
+        self.last_activity = self.now;
+        true
+    }
+
+    /// Follow the pointer while dragging; returns true if the offset changed
+    pub fn handle_mouse_move(&mut self, x: f32, y: f32) -> bool {
+        let Some((start_position, start_offset)) = self.drag else {
+            self.update_hover(x, y);
+            return false;
+        };
+
+        let free = self.track_length() - self.thumb_length();
+        if free <= 0.0 {
+            return false;
+        }
+        let delta = (self.main_axis(x, y) - start_position) * self.max_offset() / free;
+        let old_offset = self.offset;
+        self.set_offset(start_offset + delta);
+        self.offset != old_offset
+    }
+
+    pub fn handle_mouse_up(&mut self) {
+        self.drag = None;
+    }
+
+    /// Draw without a font manager, for owners whose own draw does not take one
+    pub fn paint(&self, canvas: &Canvas) {
+        if !self.is_scrollable() || self.visibility <= 0.01 {
+            return;
+        }
+
+        let colors = current_theme();
+        let opacity = self.visibility;
+
+        if self.style == ScrollbarStyle::Classic || self.hover_progress > 0.0 {
+            let track_alpha = match self.style {
+                ScrollbarStyle::Classic => 30.0,
+                ScrollbarStyle::Overlay => 20.0 * self.hover_progress,
+            };
+            let mut track_paint = Paint::default();
+            track_paint.set_color(with_alpha(colors.muted, (track_alpha * opacity) as u8));
+            canvas.draw_rect(self.bounds, &track_paint);
+        }
+
+        let thumb_alpha = if self.is_dragging() {
+            160.0
+        } else {
+            90.0 + 50.0 * self.hover_progress
+        };
+        let mut thumb_paint = Paint::default();
+        thumb_paint.set_anti_alias(true);
+        thumb_paint.set_color(with_alpha(colors.muted_foreground, (thumb_alpha * opacity) as u8));
+        let thumb = self.thumb_rect();
+        let radius = thumb.width().min(thumb.height()) / 2.0;
+        canvas.draw_round_rect(thumb, radius, radius, &thumb_paint);
+    }
+}
+
+impl Widget for Scrollbar {
+    fn draw(&self, canvas: &Canvas, _font_manager: &mut crate::core::FontManager) {
+        self.paint(canvas);
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        self.bounds.contains(skia_safe::Point::new(x, y))
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hover = self.is_scrollable() && self.contains(x, y);
+    }
+
+    fn update_animation(&mut self, elapsed: f32) {
+        self.now = elapsed;
+
+        let active = self.hover || self.is_dragging();
+        let target_hover = if active { 1.0 } else { 0.0 };
+        self.hover_progress += (target_hover - self.hover_progress) * animation_rate(0.2);
+        if (self.hover_progress - target_hover).abs() < 0.01 {
+            self.hover_progress = target_hover;
+        }
+
+        let target_visibility = match self.style {
+            ScrollbarStyle::Classic => 1.0,
+            ScrollbarStyle::Overlay if active || elapsed - self.last_activity < AUTO_HIDE_DELAY => 1.0,
+            ScrollbarStyle::Overlay => 0.0,
+        };
+        self.visibility += (target_visibility - self.visibility) * animation_rate(0.15);
+        if (self.visibility - target_visibility).abs() < 0.01 {
+            self.visibility = target_visibility;
+        }
+    }
+
+    fn on_click(&mut self) {}
+
+    fn bounds(&self) -> Option<Rect> {
+        Some(self.bounds)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,184 @@
+use skia_safe::{Canvas, Point, Rect};
+
+use crate::components::{Orientation, Scrollbar, ScrollbarStyle, Widget};
+use crate::core::{reveal_offset, SmoothScroll};
+
+/// Vertically scrolling viewport over children taller than it
+///
+/// Children are laid out relative to the content's top-left corner; the content
+/// height is set explicitly with `content_height`. Wheel scrolling and
+/// `scroll_into_view` glide per the global animation configuration.
+pub struct ScrollView {
+    bounds: Rect,
+    content_height: f32,
+    children: Vec<Box<dyn Widget>>,
+    scrollbar: Scrollbar,
+    smooth: SmoothScroll,
+}
+
+impl ScrollView {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        let bounds = Rect::from_xywh(x, y, width, height);
+        let mut scrollbar = Scrollbar::new(Orientation::Vertical);
+        scrollbar.attach_to(bounds);
+        Self {
+            bounds,
+            content_height: 0.0,
+            children: Vec::new(),
+            scrollbar,
+            smooth: SmoothScroll::default(),
+        }
+    }
+
+    pub fn content_height(mut self, height: f32) -> Self {
+        self.set_content_height(height);
+        self
+    }
+
+    pub fn scrollbar_style(mut self, style: ScrollbarStyle) -> Self {
+        self.scrollbar = self.scrollbar.style(style);
+        self
+    }
+
+    /// Add a child positioned relative to the content's top-left corner
+    pub fn with_child(mut self, child: Box<dyn Widget>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn children_mut(&mut self) -> &mut Vec<Box<dyn Widget>> {
+        &mut self.children
+    }
+
+    pub fn set_content_height(&mut self, height: f32) {
+        self.content_height = height;
+        self.scrollbar.set_metrics(height, self.bounds.height());
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.bounds = Rect::from_xywh(x, y, width, height);
+        self.scrollbar.attach_to(self.bounds);
+        self.scrollbar.set_metrics(self.content_height, height);
+    }
+
+    pub fn scroll_offset(&self) -> f32 {
+        self.scrollbar.offset()
+    }
+
+    /// Scroll by a wheel delta in pixels, on top of any scroll still under way
+    pub fn scroll(&mut self, delta: f32) {
+        self.scroll_to(self.smooth.target() + delta);
+    }
+
+    /// Scroll just enough to show `top..top + height` of the content, with `padding` around it
+    pub fn scroll_into_view(&mut self, top: f32, height: f32, padding: f32) {
+        let target = reveal_offset(self.smooth.target(), self.bounds.height(), top, height, padding);
+        self.scroll_to(target);
+    }
+
+    fn scroll_to(&mut self, offset: f32) {
+        let offset = offset.clamp(0.0, self.scrollbar.max_offset());
+        self.smooth.scroll_to(self.scrollbar.offset(), offset);
+        self.scrollbar.set_offset(self.smooth.offset());
+    }
+
+    /// Follow the scrollbar after a thumb drag or track click moved it directly
+    fn sync_smooth(&mut self) {
+        self.smooth.jump(self.scrollbar.offset());
+    }
+
+    /// Pointer position in content coordinates
+    fn to_content(&self, x: f32, y: f32) -> (f32, f32) {
+        (x - self.bounds.left, y - self.bounds.top + self.scrollbar.offset())
+    }
+
+    /// Press on the scrollbar or a child; returns true if the view took it
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> bool {
+        if self.scrollbar.handle_mouse_down(x, y) {
+            self.sync_smooth();
+            return true;
+        }
+        if !self.contains(x, y) {
+            return false;
+        }
+        let (local_x, local_y) = self.to_content(x, y);
+        if let Some(child) = self.children.iter_mut().find(|child| child.contains(local_x, local_y)) {
+            child.on_click();
+        }
+        true
+    }
+
+    /// Drag the scrollbar thumb; returns true if the view scrolled
+    pub fn handle_mouse_move(&mut self, x: f32, y: f32) -> bool {
+        if self.scrollbar.is_dragging() {
+            let moved = self.scrollbar.handle_mouse_move(x, y);
+            self.sync_smooth();
+            return moved;
+        }
+        self.update_hover(x, y);
+        false
+    }
+
+    pub fn handle_mouse_up(&mut self) {
+        self.scrollbar.handle_mouse_up();
+    }
+}
+
+impl Widget for ScrollView {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
+        canvas.save();
+        canvas.clip_rect(self.bounds, None, true);
+        canvas.translate((self.bounds.left, self.bounds.top - self.scrollbar.offset()));
+        for child in &self.children {
+            child.draw(canvas, font_manager);
+        }
+        canvas.restore();
+
+        self.scrollbar.draw(canvas, font_manager);
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        self.bounds.contains(Point::new(x, y))
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.scrollbar.update_hover(x, y);
+        // Children under the scrollbar or outside the viewport lose their hover
+        let (local_x, local_y) = if self.contains(x, y) && !self.scrollbar.is_hovered() {
+            self.to_content(x, y)
+        } else {
+            (f32::MIN, f32::MIN)
+        };
+        for child in &mut self.children {
+            child.update_hover(local_x, local_y);
+        }
+    }
+
+    fn update_animation(&mut self, elapsed: f32) {
+        if self.smooth.is_animating() {
+            self.scrollbar.set_offset(self.smooth.update(elapsed));
+        }
+        self.scrollbar.update_animation(elapsed);
+        for child in &mut self.children {
+            child.update_animation(elapsed);
+        }
+    }
+
+    fn on_click(&mut self) {}
+
+    fn bounds(&self) -> Option<Rect> {
+        Some(self.bounds)
+    }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        self.children.iter().map(|child| child.as_ref()).collect()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,87 @@
+use skia_safe::{Canvas, Paint, Rect};
+
+use crate::components::props::{orientation_from, orientation_prop};
+use crate::components::{Prop, PropValue, Widget};
+use crate::theme::current_theme;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+/// One pixel rule in the theme border color
+pub struct Separator {
+    x: f32,
+    y: f32,
+    length: f32,
+    orientation: Orientation,
+}
+
+impl Separator {
+    pub fn new(x: f32, y: f32, length: f32) -> Self {
+        Self {
+            x,
+            y,
+            length,
+            orientation: Orientation::Horizontal,
+        }
+    }
+
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn set_bounds(&mut self, x: f32, y: f32, length: f32) {
+        self.x = x;
+        self.y = y;
+        self.length = length;
+    }
+
+    fn rect(&self) -> Rect {
+        match self.orientation {
+            Orientation::Horizontal => Rect::from_xywh(self.x, self.y, self.length, 1.0),
+            Orientation::Vertical => Rect::from_xywh(self.x, self.y, 1.0, self.length),
+        }
+    }
+}
+
+impl Widget for Separator {
+    fn draw(&self, canvas: &Canvas, _font_manager: &mut crate::core::FontManager) {
+        let mut paint = Paint::default();
+        paint.set_color(current_theme().border);
+        canvas.draw_rect(self.rect(), &paint);
+    }
+
+    fn contains(&self, _x: f32, _y: f32) -> bool {
+        false // Purely decorative
+    }
+
+    fn update_hover(&mut self, _x: f32, _y: f32) {}
+
+    fn update_animation(&mut self, _elapsed: f32) {}
+
+    fn on_click(&mut self) {}
+
+    fn props(&self) -> Vec<Prop> {
+        vec![orientation_prop(self.orientation), Prop::number("length", self.length, 0.0, 400.0)]
+    }
+
+    fn set_prop(&mut self, name: &str, value: &PropValue) -> bool {
+        match name {
+            "orientation" => orientation_from(value).map(|orientation| self.orientation = orientation),
+            "length" => value.as_number().map(|length| self.length = length),
+            _ => None,
+        }
+        .is_some()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
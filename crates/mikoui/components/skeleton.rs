@@ -1,6 +1,7 @@
 use skia_safe::{Canvas, Paint, Rect};
 
-use crate::components::Widget;
+use crate::components::{Prop, PropValue, Widget};
+use crate::core::animation_config;
 use crate::theme::{current_theme, lerp_color, Theme};
 
 /// Lightweight skeleton/loading placeholder with pulse animation
@@ -79,12 +80,41 @@ impl Widget for Skeleton {
     fn update_hover(&mut self, _x: f32, _y: f32) {}
 
     fn update_animation(&mut self, elapsed: f32) {
-        let phase = elapsed * self.pulse_speed;
+        let config = animation_config();
+        if !config.is_active() {
+            self.pulse_value = 0.5;
+            return;
+        }
+        let phase = elapsed * self.pulse_speed * config.speed;
         self.pulse_value = (phase.sin() + 1.0) * 0.5;
     }
 
     fn on_click(&mut self) {}
 
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width, self.height))
+    }
+
+    fn props(&self) -> Vec<Prop> {
+        vec![
+            Prop::number("width", self.width, 8.0, 400.0),
+            Prop::number("height", self.height, 8.0, 200.0),
+            Prop::number("border_radius", self.border_radius, 0.0, 100.0),
+            Prop::number("pulse_speed", self.pulse_speed, 0.2, 5.0),
+        ]
+    }
+
+    fn set_prop(&mut self, name: &str, value: &PropValue) -> bool {
+        match name {
+            "width" => value.as_number().map(|width| self.width = width),
+            "height" => value.as_number().map(|height| self.height = height),
+            "border_radius" => value.as_number().map(|radius| self.border_radius = radius),
+            "pulse_speed" => value.as_number().map(|speed| self.pulse_speed = speed),
+            _ => None,
+        }
+        .is_some()
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1,6 +1,7 @@
 use skia_safe::{Canvas, Paint, Rect};
 
-use crate::components::Widget;
+use crate::components::{draw_focus_outline, FocusOrigin, Prop, PropValue, Widget};
+use crate::core::animation_rate;
 use crate::theme::{current_theme, with_alpha, Theme};
 
 pub struct Slider {
@@ -12,6 +13,7 @@ pub struct Slider {
     hover: bool,
     dragging: bool,
     hover_progress: f32,
+    focus: Option<FocusOrigin>,
 }
 
 impl Slider {
@@ -25,6 +27,7 @@ impl Slider {
             hover: false,
             dragging: false,
             hover_progress: 0.0,
+            focus: None,
         }
     }
 
@@ -113,6 +116,11 @@ impl Widget for Slider {
         border_paint.set_color(colors.border);
         border_paint.set_stroke_width(2.0);
         canvas.draw_circle((thumb_x, thumb_y), thumb_radius - 1.0, &border_paint);
+
+        if self.focus == Some(FocusOrigin::Keyboard) {
+            let thumb = Rect::from_xywh(thumb_x - thumb_radius, thumb_y - thumb_radius, thumb_radius * 2.0, thumb_radius * 2.0);
+            draw_focus_outline(canvas, thumb, thumb_radius);
+        }
     }
 
     fn contains(&self, x: f32, y: f32) -> bool {
@@ -131,7 +139,7 @@ impl Widget for Slider {
         let animation_speed = 0.2;
         let target_hover = if self.hover || self.dragging { 1.0 } else { 0.0 };
         if (self.hover_progress - target_hover).abs() > 0.01 {
-            self.hover_progress += (target_hover - self.hover_progress) * animation_speed;
+            self.hover_progress += (target_hover - self.hover_progress) * animation_rate(animation_speed);
         } else {
             self.hover_progress = target_hover;
         }
@@ -142,6 +150,34 @@ impl Widget for Slider {
         println!("Slider value: {:.2}", self.value);
     }
 
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, origin: Option<FocusOrigin>) {
+        self.focus = origin;
+    }
+
+    fn debug_state(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("label", self.label.to_string()),
+            ("value", format!("{:.2}", self.value)),
+            ("dragging", self.dragging.to_string()),
+        ]
+    }
+
+    fn props(&self) -> Vec<Prop> {
+        vec![Prop::number("value", self.value, 0.0, 1.0)]
+    }
+
+    fn set_prop(&mut self, name: &str, value: &PropValue) -> bool {
+        match name {
+            "value" => value.as_number().map(|value| self.value = value),
+            _ => None,
+        }
+        .is_some()
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
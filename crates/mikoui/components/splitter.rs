@@ -0,0 +1,229 @@
+use std::time::{Duration, Instant};
+
+use skia_safe::{Canvas, Color, Paint, Rect};
+use winit::window::CursorIcon;
+
+use crate::components::{Orientation, Widget};
+
+const HANDLE_THICKNESS: f32 = 4.0;
+const DOUBLE_CLICK_TIME: Duration = Duration::from_millis(400);
+
+/// Which side of the handle the resized panel sits on
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SplitterSide {
+    /// Panel is left of / above the handle and grows towards the end
+    Start,
+    /// Panel is right of / below the handle and grows towards the start
+    End,
+}
+
+/// Draggable handle that owns the size of one panel
+///
+/// `Orientation::Horizontal` lays panels out side by side (a vertical handle
+/// resizing widths); `Orientation::Vertical` stacks them (a horizontal handle
+/// resizing heights). The panel's fixed edge is `origin`: its left/top edge for
+/// `SplitterSide::Start`, its right/bottom edge for `SplitterSide::End`.
+#[derive(Debug, Clone)]
+pub struct Splitter {
+    orientation: Orientation,
+    side: SplitterSide,
+    origin: f32,
+    cross_start: f32,
+    cross_length: f32,
+    size: f32,
+    default_size: f32,
+    min_size: f32,
+    max_size: f32,
+    collapse_threshold: Option<f32>,
+    collapsed: bool,
+    dragging: bool,
+    hover: bool,
+    last_press: Option<Instant>,
+}
+
+impl Splitter {
+    pub fn new(orientation: Orientation, side: SplitterSide, size: f32) -> Self {
+        Self {
+            orientation,
+            side,
+            origin: 0.0,
+            cross_start: 0.0,
+            cross_length: 0.0,
+            size,
+            default_size: size,
+            min_size: 0.0,
+            max_size: f32::MAX,
+            collapse_threshold: None,
+            collapsed: false,
+            dragging: false,
+            hover: false,
+            last_press: None,
+        }
+    }
+
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size;
+        self.size = self.size.clamp(self.min_size, self.max_size);
+        self
+    }
+
+    pub fn max_size(mut self, max_size: f32) -> Self {
+        self.max_size = max_size;
+        self.size = self.size.clamp(self.min_size, self.max_size);
+        self
+    }
+
+    /// Size restored by double-clicking the handle
+    pub fn default_size(mut self, default_size: f32) -> Self {
+        self.default_size = default_size;
+        self
+    }
+
+    /// Dragging the panel smaller than `threshold` collapses it
+    pub fn collapsible(mut self, threshold: f32) -> Self {
+        self.collapse_threshold = Some(threshold);
+        self
+    }
+
+    /// Flip which side of the handle the panel sits on, e.g. for RTL layouts
+    pub fn set_side(&mut self, side: SplitterSide) {
+        self.side = side;
+    }
+
+    /// Place the handle: `origin` is the panel's fixed edge, `cross_*` the handle's extent
+    pub fn set_bounds(&mut self, origin: f32, cross_start: f32, cross_length: f32) {
+        self.origin = origin;
+        self.cross_start = cross_start;
+        self.cross_length = cross_length;
+    }
+
+    /// Current panel size; kept while collapsed so expanding restores it
+    pub fn size(&self) -> f32 {
+        self.size
+    }
+
+    pub fn set_size(&mut self, size: f32) {
+        self.size = size.clamp(self.min_size, self.max_size);
+    }
+
+    pub fn is_collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    pub fn set_collapsed(&mut self, collapsed: bool) {
+        self.collapsed = collapsed;
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    pub fn is_hovered(&self) -> bool {
+        self.hover
+    }
+
+    /// Resize cursor while the handle is hovered or dragged
+    pub fn cursor(&self) -> Option<CursorIcon> {
+        (self.hover || self.dragging).then_some(match self.orientation {
+            Orientation::Horizontal => CursorIcon::ColResize,
+            Orientation::Vertical => CursorIcon::RowResize,
+        })
+    }
+
+    fn handle_position(&self) -> f32 {
+        let size = if self.collapsed { 0.0 } else { self.size };
+        match self.side {
+            SplitterSide::Start => self.origin + size,
+            SplitterSide::End => self.origin - size,
+        }
+    }
+
+    pub fn handle_rect(&self) -> Rect {
+        let position = self.handle_position() - HANDLE_THICKNESS / 2.0;
+        match self.orientation {
+            Orientation::Horizontal => Rect::from_xywh(position, self.cross_start, HANDLE_THICKNESS, self.cross_length),
+            Orientation::Vertical => Rect::from_xywh(self.cross_start, position, self.cross_length, HANDLE_THICKNESS),
+        }
+    }
+
+    /// Start dragging, or reset to the default size on double-click; returns true if the handle was hit
+    pub fn handle_mouse_down(&mut self, x: f32, y: f32) -> bool {
+        if !self.contains(x, y) {
+            return false;
+        }
+
+        let now = Instant::now();
+        if self.last_press.is_some_and(|last| now.duration_since(last) < DOUBLE_CLICK_TIME) {
+            self.last_press = None;
+            self.collapsed = false;
+            self.set_size(self.default_size);
+        } else {
+            self.last_press = Some(now);
+            self.dragging = true;
+        }
+        true
+    }
+
+    /// Follow the pointer while dragging; returns true if size or collapsed state changed
+    pub fn handle_mouse_move(&mut self, x: f32, y: f32) -> bool {
+        if !self.dragging {
+            self.update_hover(x, y);
+            return false;
+        }
+
+        let position = match self.orientation {
+            Orientation::Horizontal => x,
+            Orientation::Vertical => y,
+        };
+        let raw = match self.side {
+            SplitterSide::Start => position - self.origin,
+            SplitterSide::End => self.origin - position,
+        };
+
+        let (old_size, old_collapsed) = (self.size, self.collapsed);
+        if self.collapse_threshold.is_some_and(|threshold| raw < threshold) {
+            self.collapsed = true;
+        } else {
+            self.collapsed = false;
+            self.set_size(raw);
+        }
+        self.size != old_size || self.collapsed != old_collapsed
+    }
+
+    pub fn handle_mouse_up(&mut self) {
+        self.dragging = false;
+    }
+}
+
+impl Widget for Splitter {
+    fn draw(&self, canvas: &Canvas, _font_manager: &mut crate::core::FontManager) {
+        if !self.hover && !self.dragging {
+            return;
+        }
+        let mut paint = Paint::default();
+        let alpha = if self.dragging { 100 } else { 50 };
+        paint.set_color(Color::from_argb(alpha, 100, 150, 255));
+        paint.set_anti_alias(true);
+        canvas.draw_rect(self.handle_rect(), &paint);
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        self.handle_rect().contains(skia_safe::Point::new(x, y))
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hover = self.contains(x, y);
+    }
+
+    fn update_animation(&mut self, _elapsed: f32) {}
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
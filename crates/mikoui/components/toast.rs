@@ -0,0 +1,164 @@
+use skia_safe::{Canvas, Paint, Rect};
+
+use crate::components::{Icon, IconSize, Widget};
+use crate::core::{animations_enabled, FontManager};
+use crate::theme::{current_theme, Theme};
+
+const PADDING: f32 = 12.0;
+const HEIGHT: f32 = 36.0;
+const ICON_SPACE: f32 = 24.0;
+const FADE_IN: f32 = 0.15;
+const FADE_OUT: f32 = 0.3;
+
+/// Short message in a corner of the window that fades out on its own
+pub struct Toast {
+    // Bottom-right corner the toast is drawn against
+    right: f32,
+    bottom: f32,
+    message: String,
+    icon: Option<&'static str>,
+    /// Seconds the message stays fully visible
+    duration: f32,
+    visible: bool,
+    /// Animation clock time the toast appeared; None until the next `update_animation`
+    shown_at: Option<f32>,
+    opacity: f32,
+}
+
+impl Toast {
+    pub fn new() -> Self {
+        Self {
+            right: 0.0,
+            bottom: 0.0,
+            message: String::new(),
+            icon: None,
+            duration: 2.0,
+            visible: false,
+            shown_at: None,
+            opacity: 0.0,
+        }
+    }
+
+    pub fn duration(mut self, seconds: f32) -> Self {
+        self.duration = seconds;
+        self
+    }
+
+    /// Keep the toast's bottom-right corner at this point, e.g. above the status bar
+    pub fn set_anchor(&mut self, right: f32, bottom: f32) {
+        self.right = right;
+        self.bottom = bottom;
+    }
+
+    /// Show a message, replacing the one on screen and restarting the timer
+    pub fn show(&mut self, message: impl Into<String>, icon: Option<&'static str>) {
+        self.message = message.into();
+        self.icon = icon;
+        self.visible = true;
+        self.shown_at = None;
+    }
+
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.opacity = 0.0;
+    }
+
+    /// Visible or fading, so the window should keep redrawing
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn bounds(&self, font_manager: &mut FontManager) -> Rect {
+        let font = font_manager.create_font(&self.message, 13.0, 400);
+        let text_width = font.measure_str(&self.message, None).0;
+        let icon_width = if self.icon.is_some() { ICON_SPACE } else { 0.0 };
+        let width = text_width + icon_width + PADDING * 2.0;
+        Rect::from_xywh(self.right - width, self.bottom - HEIGHT, width, HEIGHT)
+    }
+}
+
+impl Default for Toast {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Widget for Toast {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut FontManager) {
+        if !self.visible || self.opacity <= 0.0 {
+            return;
+        }
+        let theme = current_theme();
+        let rect = self.bounds(font_manager);
+
+        canvas.save_layer_alpha_f(None, self.opacity);
+
+        let mut shadow_paint = Paint::default();
+        shadow_paint.set_anti_alias(true);
+        shadow_paint.set_color(skia_safe::Color::from_argb(40, 0, 0, 0));
+        canvas.draw_round_rect(rect.with_offset((0.0, 2.0)), Theme::RADIUS_MD, Theme::RADIUS_MD, &shadow_paint);
+
+        let mut background = Paint::default();
+        background.set_anti_alias(true);
+        background.set_color(theme.popover);
+        canvas.draw_round_rect(rect, Theme::RADIUS_MD, Theme::RADIUS_MD, &background);
+
+        let mut border = Paint::default();
+        border.set_anti_alias(true);
+        border.set_style(skia_safe::PaintStyle::Stroke);
+        border.set_color(theme.border);
+        canvas.draw_round_rect(rect, Theme::RADIUS_MD, Theme::RADIUS_MD, &border);
+
+        let mut text_x = rect.left + PADDING;
+        if let Some(icon) = self.icon {
+            Icon::new(text_x, rect.top + (HEIGHT - 16.0) / 2.0, icon, IconSize::Small, theme.popover_foreground).draw(canvas, font_manager);
+            text_x += ICON_SPACE;
+        }
+
+        let font = font_manager.create_font(&self.message, 13.0, 400);
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        text_paint.set_color(theme.popover_foreground);
+        canvas.draw_str(&self.message, (text_x, rect.top + HEIGHT / 2.0 + 4.5), &font, &text_paint);
+
+        canvas.restore();
+    }
+
+    fn contains(&self, _x: f32, _y: f32) -> bool {
+        // Never takes clicks away from what's under it
+        false
+    }
+
+    fn update_hover(&mut self, _x: f32, _y: f32) {}
+
+    fn update_animation(&mut self, elapsed: f32) {
+        if !self.visible {
+            return;
+        }
+        let shown_at = *self.shown_at.get_or_insert(elapsed);
+        let age = elapsed - shown_at;
+        if age >= self.duration + FADE_OUT {
+            self.hide();
+            return;
+        }
+        self.opacity = if !animations_enabled() {
+            1.0
+        } else if age < FADE_IN {
+            age / FADE_IN
+        } else if age > self.duration {
+            1.0 - (age - self.duration) / FADE_OUT
+        } else {
+            1.0
+        };
+    }
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
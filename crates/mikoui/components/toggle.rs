@@ -0,0 +1,454 @@
+use skia_safe::{Canvas, Color, Paint, Rect};
+
+use crate::components::props::{size_from, size_prop, variant_from, variant_prop};
+use crate::components::{draw_focus_outline, FocusOrigin, Icon, IconSize, Prop, PropValue, Widget};
+use crate::core::animation_rate;
+use crate::theme::{current_theme, lerp_color, with_alpha, Size, Style, Theme, Variant};
+
+const ICON_LABEL_GAP: f32 = 6.0;
+const GROUP_GAP: f32 = 4.0;
+
+/// Called with the new pressed state
+pub type ToggleCallback = Box<dyn Fn(bool)>;
+/// Called with the indices of the pressed toggles
+pub type ToggleGroupCallback = Box<dyn Fn(&[usize])>;
+
+/// Two-state icon button, e.g. bold/italic in an editor toolbar
+/// Only `Variant::Default` (borderless), `Variant::Outline` and registered `Variant::Custom` styles are
+/// distinct; other variants draw as Default
+pub struct Toggle {
+    x: f32,
+    y: f32,
+    icon: &'static str,
+    label: Option<&'static str>,
+    variant: Variant,
+    style: Style,
+    size: Size,
+    pressed: bool,
+    disabled: bool,
+    hover: bool,
+    hover_progress: f32,
+    focus: Option<FocusOrigin>,
+    on_change: Option<ToggleCallback>,
+}
+
+impl Toggle {
+    pub fn new(x: f32, y: f32, icon: &'static str) -> Self {
+        Self {
+            x,
+            y,
+            icon,
+            label: None,
+            variant: Variant::Default,
+            style: Style::default(),
+            size: Size::Md,
+            pressed: false,
+            disabled: false,
+            hover: false,
+            hover_progress: 0.0,
+            focus: None,
+            on_change: None,
+        }
+    }
+
+    /// Text shown after the icon; without it the toggle is square
+    pub fn label(mut self, label: &'static str) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Local overrides; `background`/`foreground` apply to the pressed state
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn pressed(mut self, pressed: bool) -> Self {
+        self.pressed = pressed;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn on_change(mut self, callback: impl Fn(bool) + 'static) -> Self {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+
+    pub fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+
+    /// Change the state without firing the change callback
+    pub fn set_pressed(&mut self, pressed: bool) {
+        self.pressed = pressed;
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    pub fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+    }
+
+    fn icon_size(&self) -> IconSize {
+        match self.size {
+            Size::Lg => IconSize::Medium,
+            _ => IconSize::Small,
+        }
+    }
+
+    fn label_width(&self) -> f32 {
+        // Estimated from the character count so layout does not need a font manager
+        self.label
+            .map(|label| ICON_LABEL_GAP + label.chars().count() as f32 * self.size.font_size() * 0.55)
+            .unwrap_or(0.0)
+    }
+
+    pub fn width(&self) -> f32 {
+        match self.label {
+            Some(_) => self.padding_x() * 2.0 + self.icon_size().as_f32() + self.label_width(),
+            None => self.height(),
+        }
+    }
+
+    pub fn height(&self) -> f32 {
+        self.size.height()
+    }
+
+    fn padding_x(&self) -> f32 {
+        self.style.padding.unwrap_or(self.size.padding_x())
+    }
+}
+
+impl Widget for Toggle {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
+        let colors = current_theme();
+        let rect = Rect::from_xywh(self.x, self.y, self.width(), self.height());
+        let radius = self.style.radius.unwrap_or(Theme::RADIUS_MD);
+        let layered = self.style.push_opacity(canvas);
+
+        // Pressed toggles get the accent fill; hover fades towards muted.
+        // Custom variants swap in their background/hover/foreground roles.
+        let custom = self.variant.custom_colors(&colors);
+        let (pressed_bg, pressed_fg, hover_bg) = match custom {
+            Some(custom) => (custom.background, custom.foreground, custom.hover),
+            None => (colors.accent, colors.accent_foreground, colors.muted),
+        };
+        let pressed_bg = self.style.background.unwrap_or(pressed_bg);
+        let pressed_fg = self.style.foreground.unwrap_or(pressed_fg);
+        let background = if self.pressed {
+            pressed_bg
+        } else {
+            lerp_color(Color::TRANSPARENT, hover_bg, self.hover_progress)
+        };
+        let foreground = match (self.pressed, self.disabled) {
+            (_, true) => with_alpha(colors.muted_foreground, 128),
+            (true, false) => pressed_fg,
+            (false, false) if self.hover => colors.foreground,
+            (false, false) => colors.muted_foreground,
+        };
+
+        if background != Color::TRANSPARENT && !self.disabled {
+            let mut bg_paint = Paint::default();
+            bg_paint.set_anti_alias(true);
+            bg_paint.set_color(background);
+            canvas.draw_round_rect(rect, radius, radius, &bg_paint);
+        }
+
+        let border = match custom {
+            Some(custom) => custom.border,
+            None => (self.variant == Variant::Outline || self.style.has_border()).then_some(colors.input),
+        };
+        let border = border.map(|border| self.style.border_color.unwrap_or(border));
+        let border_width = self.style.border_width.unwrap_or(1.0);
+        if let Some(border) = border {
+            let mut border_paint = Paint::default();
+            border_paint.set_anti_alias(true);
+            border_paint.set_style(skia_safe::PaintStyle::Stroke);
+            border_paint.set_stroke_width(border_width);
+            border_paint.set_color(border);
+            let inset = border_width / 2.0;
+            canvas.draw_round_rect(rect.with_inset((inset, inset)), radius, radius, &border_paint);
+        }
+
+        let icon_size = self.icon_size().as_f32();
+        let icon_x = match self.label {
+            Some(_) => self.x + self.padding_x(),
+            None => rect.center_x() - icon_size / 2.0,
+        };
+        Icon::new(icon_x, rect.center_y() - icon_size / 2.0, self.icon, self.icon_size(), foreground)
+            .draw(canvas, font_manager);
+
+        if let Some(label) = self.label {
+            let font_size = self.size.font_size();
+            let font = font_manager.create_font(label, font_size, 500);
+            let mut text_paint = Paint::default();
+            text_paint.set_anti_alias(true);
+            text_paint.set_color(foreground);
+            canvas.draw_str(
+                label,
+                (icon_x + icon_size + ICON_LABEL_GAP, rect.center_y() + font_size * 0.3),
+                &font,
+                &text_paint,
+            );
+        }
+
+        if self.focus == Some(FocusOrigin::Keyboard) {
+            draw_focus_outline(canvas, rect, radius);
+        }
+
+        if layered {
+            canvas.restore();
+        }
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width() && y >= self.y && y <= self.y + self.height()
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        self.hover = !self.disabled && self.hit_test(x, y);
+    }
+
+    fn update_animation(&mut self, _elapsed: f32) {
+        let animation_speed = 0.15;
+        let target = if self.hover { 1.0 } else { 0.0 };
+        if (self.hover_progress - target).abs() > 0.01 {
+            self.hover_progress += (target - self.hover_progress) * animation_rate(animation_speed);
+        } else {
+            self.hover_progress = target;
+        }
+    }
+
+    fn on_click(&mut self) {
+        if self.disabled {
+            return;
+        }
+        self.pressed = !self.pressed;
+        if let Some(ref callback) = self.on_change {
+            callback(self.pressed);
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        !self.disabled
+    }
+
+    fn set_focus(&mut self, origin: Option<FocusOrigin>) {
+        self.focus = origin;
+    }
+
+    fn bounds(&self) -> Option<Rect> {
+        Some(Rect::from_xywh(self.x, self.y, self.width(), self.height()))
+    }
+
+    fn props(&self) -> Vec<Prop> {
+        vec![
+            Prop::bool("pressed", self.pressed),
+            Prop::bool("disabled", self.disabled),
+            variant_prop(self.variant),
+            size_prop(self.size),
+        ]
+    }
+
+    fn set_prop(&mut self, name: &str, value: &PropValue) -> bool {
+        match name {
+            "pressed" => value.as_bool().map(|pressed| self.pressed = pressed),
+            "disabled" => value.as_bool().map(|disabled| self.disabled = disabled),
+            "variant" => variant_from(value).map(|variant| self.variant = variant),
+            "size" => size_from(value).map(|size| self.size = size),
+            _ => None,
+        }
+        .is_some()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Whether a ToggleGroup allows one or several pressed toggles
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ToggleGroupMode {
+    Single,
+    Multiple,
+}
+
+/// Row of toggles sharing a variant and size
+/// In `Single` mode pressing one toggle releases the others; the pressed one can be released too
+pub struct ToggleGroup {
+    x: f32,
+    y: f32,
+    mode: ToggleGroupMode,
+    variant: Variant,
+    size: Size,
+    toggles: Vec<Toggle>,
+    on_change: Option<ToggleGroupCallback>,
+}
+
+impl ToggleGroup {
+    pub fn new(x: f32, y: f32, mode: ToggleGroupMode) -> Self {
+        Self {
+            x,
+            y,
+            mode,
+            variant: Variant::Default,
+            size: Size::Md,
+            toggles: Vec::new(),
+            on_change: None,
+        }
+    }
+
+    pub fn variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self.layout();
+        self
+    }
+
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = size;
+        self.layout();
+        self
+    }
+
+    /// Append a toggle; its own callback is not used, the group reports changes instead
+    pub fn with_toggle(mut self, toggle: Toggle) -> Self {
+        self.toggles.push(toggle);
+        self.layout();
+        self
+    }
+
+    pub fn on_change(mut self, callback: impl Fn(&[usize]) + 'static) -> Self {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+
+    pub fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+        self.layout();
+    }
+
+    pub fn toggles(&self) -> &[Toggle] {
+        &self.toggles
+    }
+
+    /// Indices of the pressed toggles, in order
+    pub fn selected(&self) -> Vec<usize> {
+        self.toggles
+            .iter()
+            .enumerate()
+            .filter(|(_, toggle)| toggle.is_pressed())
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Replace the pressed set without firing the change callback
+    pub fn set_selected(&mut self, selected: &[usize]) {
+        for (index, toggle) in self.toggles.iter_mut().enumerate() {
+            toggle.set_pressed(selected.contains(&index));
+        }
+        if self.mode == ToggleGroupMode::Single {
+            let first = self.selected().first().copied();
+            for (index, toggle) in self.toggles.iter_mut().enumerate() {
+                toggle.set_pressed(Some(index) == first);
+            }
+        }
+    }
+
+    pub fn width(&self) -> f32 {
+        let widths: f32 = self.toggles.iter().map(Toggle::width).sum();
+        widths + GROUP_GAP * self.toggles.len().saturating_sub(1) as f32
+    }
+
+    pub fn height(&self) -> f32 {
+        self.size.height()
+    }
+
+    fn layout(&mut self) {
+        let mut x = self.x;
+        for toggle in &mut self.toggles {
+            toggle.variant = self.variant;
+            toggle.size = self.size;
+            toggle.set_position(x, self.y);
+            x += toggle.width() + GROUP_GAP;
+        }
+    }
+
+    /// Toggle the item under the pointer; returns true if the selection changed
+    pub fn handle_click(&mut self, x: f32, y: f32) -> bool {
+        let Some(clicked) = self.toggles
+            .iter()
+            .position(|toggle| toggle.hit_test(x, y) && !toggle.is_disabled())
+        else {
+            return false;
+        };
+
+        let pressed = !self.toggles[clicked].is_pressed();
+        if self.mode == ToggleGroupMode::Single {
+            for toggle in &mut self.toggles {
+                toggle.set_pressed(false);
+            }
+        }
+        self.toggles[clicked].set_pressed(pressed);
+
+        if let Some(ref callback) = self.on_change {
+            callback(&self.selected());
+        }
+        true
+    }
+}
+
+impl Widget for ToggleGroup {
+    fn draw(&self, canvas: &Canvas, font_manager: &mut crate::core::FontManager) {
+        for toggle in &self.toggles {
+            toggle.draw(canvas, font_manager);
+        }
+    }
+
+    fn contains(&self, x: f32, y: f32) -> bool {
+        self.toggles.iter().any(|toggle| toggle.hit_test(x, y))
+    }
+
+    fn update_hover(&mut self, x: f32, y: f32) {
+        for toggle in &mut self.toggles {
+            toggle.update_hover(x, y);
+        }
+    }
+
+    fn update_animation(&mut self, elapsed: f32) {
+        for toggle in &mut self.toggles {
+            toggle.update_animation(elapsed);
+        }
+    }
+
+    fn on_click(&mut self) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,88 @@
+use std::time::{Duration, Instant};
+
+/// Typing pause after which the next char starts a new search
+const TYPEAHEAD_TIMEOUT: Duration = Duration::from_millis(800);
+
+/// Jump to a list or tree row by typing the start of its label
+///
+/// Typed chars build up a prefix until the user pauses. Pressing the same char repeatedly
+/// cycles through the rows starting with it instead.
+#[derive(Debug, Clone, Default)]
+pub struct Typeahead {
+    query: String,
+    last_key: Option<Instant>,
+}
+
+impl Typeahead {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prefix typed so far, lowercase
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn reset(&mut self) {
+        self.query.clear();
+        self.last_key = None;
+    }
+
+    /// Add a typed char and find the row to jump to
+    /// `current` is the selected row; the search wraps around past the last label.
+    pub fn type_char<S: AsRef<str>>(&mut self, c: char, current: Option<usize>, labels: &[S]) -> Option<usize> {
+        self.type_char_at(c, current, labels, Instant::now())
+    }
+
+    fn type_char_at<S: AsRef<str>>(&mut self, c: char, current: Option<usize>, labels: &[S], now: Instant) -> Option<usize> {
+        if self.last_key.is_some_and(|last| now.duration_since(last) > TYPEAHEAD_TIMEOUT) {
+            self.query.clear();
+        }
+        self.last_key = Some(now);
+        self.query.extend(c.to_lowercase());
+
+        // "aaa" means the third row starting with "a", not a row starting with "aaa"
+        let mut chars = self.query.chars();
+        let first = chars.next()?;
+        let repeated = chars.all(|ch| ch == first);
+        let (prefix, skip_current) = if repeated {
+            (first.to_string(), true)
+        } else {
+            (self.query.clone(), false)
+        };
+
+        let count = labels.len();
+        let start = match current {
+            Some(current) if skip_current => current + 1,
+            Some(current) => current,
+            None => 0,
+        };
+        (0..count)
+            .map(|offset| (start + offset) % count)
+            .find(|&index| labels[index].as_ref().to_lowercase().starts_with(&prefix))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefix_then_cycle() {
+        let labels = ["app", "assets", "build", "Cargo.toml", "crates"];
+        let mut typeahead = Typeahead::new();
+        let t0 = Instant::now();
+
+        assert_eq!(typeahead.type_char_at('c', Some(0), &labels, t0), Some(3));
+        assert_eq!(typeahead.type_char_at('r', Some(3), &labels, t0), Some(4));
+
+        // After a pause the search starts over; repeated chars cycle
+        let later = t0 + TYPEAHEAD_TIMEOUT * 2;
+        assert_eq!(typeahead.type_char_at('a', Some(4), &labels, later), Some(0));
+        assert_eq!(typeahead.type_char_at('a', Some(0), &labels, later), Some(1));
+        assert_eq!(typeahead.type_char_at('a', Some(1), &labels, later), Some(0));
+
+        typeahead.reset();
+        assert_eq!(typeahead.type_char_at('z', None, &labels, later), None);
+    }
+}
@@ -1,6 +1,9 @@
-use skia_safe::{Canvas, Font};
+use std::fmt;
 
-use crate::core::FontManager;
+use skia_safe::{Canvas, Point, Rect};
+
+use crate::components::{FocusOrigin, Prop, PropValue};
+use crate::core::{hit_target, FontManager};
 
 /// Base trait for all UI widgets
 pub trait Widget {
@@ -10,6 +13,20 @@ pub trait Widget {
     /// Check if a point is inside the widget bounds
     fn contains(&self, x: f32, y: f32) -> bool;
     
+    /// Whether a press at the point lands on this widget
+    ///
+    /// Interactive widgets that know their `bounds` are hit within the accessibility minimum
+    /// target around them, even when they draw smaller.
+    fn hit_test(&self, x: f32, y: f32) -> bool {
+        if self.contains(x, y) {
+            return true;
+        }
+        match self.bounds() {
+            Some(bounds) if self.is_focusable() => hit_target(bounds).contains(Point::new(x, y)),
+            _ => false,
+        }
+    }
+    
     /// Update hover state based on mouse position
     fn update_hover(&mut self, x: f32, y: f32);
     
@@ -17,11 +34,88 @@ pub trait Widget {
     fn update_animation(&mut self, elapsed: f32);
     
     /// Handle click events
+    ///
+    /// Takes no position, so widgets whose click depends on where it landed leave this
+    /// empty and take presses through their own `handle_click`/`handle_mouse_down`.
     fn on_click(&mut self);
     
+    /// Whether keyboard focus (Tab) can land on this widget
+    fn is_focusable(&self) -> bool {
+        false
+    }
+    
+    /// Focus moved onto (`Some`) or away from (`None`) this widget
+    fn set_focus(&mut self, _origin: Option<FocusOrigin>) {}
+    
+    /// Identifies the widget in the inspector and in logged debug output
+    fn id(&self) -> WidgetId {
+        WidgetId::of(self)
+    }
+    
+    /// Short type name shown by the inspector
+    fn type_name(&self) -> &'static str {
+        let name = std::any::type_name::<Self>();
+        let name = name.split('<').next().unwrap_or(name);
+        name.rsplit("::").next().unwrap_or(name)
+    }
+    
+    /// Area the widget covers, for the inspector; `None` when it can't tell
+    fn bounds(&self) -> Option<Rect> {
+        None
+    }
+    
+    /// Area inside the widget's padding; the inspector shades the gap to `bounds` as padding
+    fn content_bounds(&self) -> Option<Rect> {
+        None
+    }
+    
+    /// Widget-specific state shown by the inspector, as name / value pairs
+    fn debug_state(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+    
+    /// Properties that can be changed while the widget is shown, e.g. from the component gallery
+    fn props(&self) -> Vec<Prop> {
+        Vec::new()
+    }
+    
+    /// Change one of `props`; false for a name the widget doesn't have or a value of the wrong kind
+    fn set_prop(&mut self, _name: &str, _value: &PropValue) -> bool {
+        false
+    }
+    
+    /// Widgets drawn inside this one, for the inspector's tree
+    fn children(&self) -> Vec<&dyn Widget> {
+        Vec::new()
+    }
+    
     /// Downcast to Any for type checking
     fn as_any(&self) -> &dyn std::any::Any;
     
     /// Downcast to Any for mutable access
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
 }
+
+/// Index of the topmost widget hit at the point; later widgets are drawn over earlier ones
+pub fn widget_at(widgets: &[Box<dyn Widget>], x: f32, y: f32) -> Option<usize> {
+    widgets.iter().rposition(|widget| widget.hit_test(x, y))
+}
+
+/// Identifier of a widget, stable for as long as the widget exists
+///
+/// Taken from the widget's address, so a boxed widget keeps its id across frames and
+/// moves of the `Box`; a widget that is rebuilt gets a new one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WidgetId(usize);
+
+impl WidgetId {
+    pub fn of<W: Widget + ?Sized>(widget: &W) -> Self {
+        Self(widget as *const W as *const () as usize)
+    }
+}
+
+impl fmt::Display for WidgetId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:x}", self.0)
+    }
+}
@@ -0,0 +1,80 @@
+use std::cell::Cell;
+
+use skia_safe::Rect;
+
+/// Global accessibility preferences consulted by fonts and hit testing
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AccessibilityConfig {
+    /// Multiplier for UI text sizes, on top of (and independent of) the display scale
+    pub text_scale: f32,
+    /// Smallest width and height, in logical pixels, an interactive widget can be hit within
+    pub min_hit_target: f32,
+}
+
+impl AccessibilityConfig {
+    pub const MIN_TEXT_SCALE: f32 = 0.5;
+    pub const MAX_TEXT_SCALE: f32 = 3.0;
+}
+
+impl Default for AccessibilityConfig {
+    fn default() -> Self {
+        Self {
+            text_scale: 1.0,
+            min_hit_target: 24.0,
+        }
+    }
+}
+
+thread_local! {
+    static ACCESSIBILITY_CONFIG: Cell<AccessibilityConfig> = Cell::new(AccessibilityConfig::default());
+}
+
+/// Set the global accessibility configuration; the text scale is clamped to a usable range
+pub fn set_accessibility_config(config: AccessibilityConfig) {
+    let config = AccessibilityConfig {
+        text_scale: config.text_scale.clamp(AccessibilityConfig::MIN_TEXT_SCALE, AccessibilityConfig::MAX_TEXT_SCALE),
+        min_hit_target: config.min_hit_target.max(0.0),
+    };
+    ACCESSIBILITY_CONFIG.with(|c| c.set(config));
+}
+
+/// Get the current accessibility configuration
+pub fn accessibility_config() -> AccessibilityConfig {
+    ACCESSIBILITY_CONFIG.with(|c| c.get())
+}
+
+/// Multiplier `FontManager::create_font` applies to UI text sizes
+pub fn text_scale() -> f32 {
+    accessibility_config().text_scale
+}
+
+/// `bounds` grown around its center to at least the minimum hit target in each direction
+///
+/// Sides that are already large enough are left alone, so a wide but short button only
+/// gains height.
+pub fn hit_target(bounds: Rect) -> Rect {
+    let min = accessibility_config().min_hit_target;
+    let grow_x = ((min - bounds.width()) / 2.0).max(0.0);
+    let grow_y = ((min - bounds.height()) / 2.0).max(0.0);
+    Rect::from_ltrb(bounds.left - grow_x, bounds.top - grow_y, bounds.right + grow_x, bounds.bottom + grow_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_bounds_grow_to_the_minimum_target() {
+        set_accessibility_config(AccessibilityConfig::default());
+        let checkbox = hit_target(Rect::from_xywh(10.0, 10.0, 16.0, 16.0));
+        assert_eq!(checkbox, Rect::from_xywh(6.0, 6.0, 24.0, 24.0));
+
+        // Only the short side grows
+        let button = hit_target(Rect::from_xywh(0.0, 0.0, 120.0, 20.0));
+        assert_eq!(button, Rect::from_xywh(0.0, -2.0, 120.0, 24.0));
+
+        set_accessibility_config(AccessibilityConfig { text_scale: 10.0, min_hit_target: 0.0 });
+        assert_eq!(text_scale(), AccessibilityConfig::MAX_TEXT_SCALE);
+        assert_eq!(hit_target(Rect::from_xywh(0.0, 0.0, 4.0, 4.0)), Rect::from_xywh(0.0, 0.0, 4.0, 4.0));
+    }
+}
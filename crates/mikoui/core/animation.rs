@@ -0,0 +1,116 @@
+use std::cell::Cell;
+
+use super::scroll::Easing;
+
+/// Global animation preferences consulted by every widget's `update_animation`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimationConfig {
+    /// When false, animated values jump straight to their targets
+    pub enabled: bool,
+    /// Multiplier applied to every animation rate; 2.0 settles twice as fast
+    pub speed: f32,
+    /// Treat the OS "reduce motion" preference as `enabled: false`
+    pub respect_reduced_motion: bool,
+    /// Glide to new scroll positions instead of jumping there
+    pub smooth_scrolling: bool,
+    /// Seconds a smooth scroll takes, before `speed` applies
+    pub scroll_duration: f32,
+    pub scroll_easing: Easing,
+}
+
+impl AnimationConfig {
+    /// Animations switched off entirely, e.g. for deterministic tests
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            ..Self::default()
+        }
+    }
+
+    /// Whether animations should run, after applying the OS preference
+    pub fn is_active(&self) -> bool {
+        self.enabled && !(self.respect_reduced_motion && system_reduced_motion())
+    }
+}
+
+impl Default for AnimationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            speed: 1.0,
+            respect_reduced_motion: true,
+            smooth_scrolling: true,
+            scroll_duration: 0.15,
+            scroll_easing: Easing::EaseOut,
+        }
+    }
+}
+
+thread_local! {
+    static ANIMATION_CONFIG: Cell<AnimationConfig> = Cell::new(AnimationConfig::default());
+    // Queried once per thread; call `refresh_system_reduced_motion` to pick up changes
+    static SYSTEM_REDUCED_MOTION: Cell<bool> = Cell::new(query_reduced_motion());
+}
+
+/// Set the global animation configuration
+pub fn set_animation_config(config: AnimationConfig) {
+    ANIMATION_CONFIG.with(|c| c.set(config));
+}
+
+/// Get the current animation configuration
+pub fn animation_config() -> AnimationConfig {
+    ANIMATION_CONFIG.with(|c| c.get())
+}
+
+/// Whether animations should run right now
+pub fn animations_enabled() -> bool {
+    animation_config().is_active()
+}
+
+/// Per-frame easing rate adjusted by the global configuration
+///
+/// Widgets ease with `value += (target - value) * rate`; the returned rate is
+/// scaled by `speed` and becomes 1.0 (an instant jump) when animations are off.
+pub fn animation_rate(rate: f32) -> f32 {
+    let config = animation_config();
+    if !config.is_active() {
+        return 1.0;
+    }
+    (rate * config.speed).clamp(0.0, 1.0)
+}
+
+/// Cached OS "reduce motion" preference
+pub fn system_reduced_motion() -> bool {
+    SYSTEM_REDUCED_MOTION.with(|c| c.get())
+}
+
+/// Re-read the OS "reduce motion" preference, e.g. after a settings change
+pub fn refresh_system_reduced_motion() {
+    SYSTEM_REDUCED_MOTION.with(|c| c.set(query_reduced_motion()));
+}
+
+/// Windows exposes reduced motion as "Animation effects" (SPI_GETCLIENTAREAANIMATION)
+#[cfg(target_os = "windows")]
+fn query_reduced_motion() -> bool {
+    use windows::Win32::UI::WindowsAndMessaging::{
+        SystemParametersInfoW, SPI_GETCLIENTAREAANIMATION, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS,
+    };
+
+    // Win32 BOOL: non-zero while animation effects are on
+    let mut animations: i32 = 1;
+    let result = unsafe {
+        SystemParametersInfoW(
+            SPI_GETCLIENTAREAANIMATION,
+            0,
+            Some(&mut animations as *mut i32 as *mut std::ffi::c_void),
+            SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+        )
+    };
+    result.is_ok() && animations == 0
+}
+
+/// No portable query elsewhere; rely on `AnimationConfig::enabled`
+#[cfg(not(target_os = "windows"))]
+fn query_reduced_motion() -> bool {
+    false
+}
@@ -0,0 +1,296 @@
+use std::cell::Cell;
+
+use skia_safe::Font;
+use unicode_bidi::{Level, ParagraphBidiInfo};
+
+use crate::core::i18n::locale;
+
+/// Reading direction of a paragraph or of the whole layout
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextDirection {
+    Ltr,
+    Rtl,
+}
+
+impl TextDirection {
+    /// Scripts written right-to-left: Arabic, Hebrew, Persian, Urdu and friends
+    pub fn for_locale(locale: &str) -> Self {
+        const RTL_LANGUAGES: [&str; 10] = ["ar", "he", "fa", "ur", "yi", "ps", "sd", "ug", "dv", "ckb"];
+        let language = locale.split(['-', '_']).next().unwrap_or_default().to_ascii_lowercase();
+        if RTL_LANGUAGES.contains(&language.as_str()) {
+            TextDirection::Rtl
+        } else {
+            TextDirection::Ltr
+        }
+    }
+
+    pub fn is_rtl(self) -> bool {
+        self == TextDirection::Rtl
+    }
+}
+
+/// Horizontal alignment relative to the reading direction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextAlign {
+    /// Left in LTR layouts, right in RTL layouts
+    Start,
+    Center,
+    /// Right in LTR layouts, left in RTL layouts
+    End,
+}
+
+impl TextAlign {
+    /// Offset of content `content_width` wide inside `available` space
+    pub fn offset(self, available: f32, content_width: f32, direction: TextDirection) -> f32 {
+        let free = (available - content_width).max(0.0);
+        match (self, direction) {
+            (TextAlign::Center, _) => free / 2.0,
+            (TextAlign::Start, TextDirection::Ltr) | (TextAlign::End, TextDirection::Rtl) => 0.0,
+            (TextAlign::Start, TextDirection::Rtl) | (TextAlign::End, TextDirection::Ltr) => free,
+        }
+    }
+}
+
+thread_local! {
+    static LAYOUT_DIRECTION: Cell<Option<TextDirection>> = const { Cell::new(None) };
+}
+
+/// Force the layout direction, or follow the active locale with `None`
+pub fn set_layout_direction(direction: Option<TextDirection>) {
+    LAYOUT_DIRECTION.with(|d| d.set(direction));
+}
+
+/// Direction used to mirror layouts and to resolve `TextAlign`
+pub fn layout_direction() -> TextDirection {
+    LAYOUT_DIRECTION
+        .with(|d| d.get())
+        .unwrap_or_else(|| TextDirection::for_locale(&locale()))
+}
+
+/// Mirror a box `width` wide at `x` inside `container_x..container_x + container_width` in RTL layouts
+pub fn mirror_x(x: f32, width: f32, container_x: f32, container_width: f32) -> f32 {
+    match layout_direction() {
+        TextDirection::Ltr => x,
+        TextDirection::Rtl => container_x + container_width - (x - container_x) - width,
+    }
+}
+
+/// Whether `text` contains characters from a right-to-left script
+pub fn contains_rtl(text: &str) -> bool {
+    !ParagraphBidiInfo::new(text, Some(Level::ltr())).is_pure_ltr
+}
+
+/// Bracket glyphs swap in right-to-left runs
+fn mirror_char(ch: char) -> char {
+    match ch {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        '«' => '»',
+        '»' => '«',
+        _ => ch,
+    }
+}
+
+/// One line of text reordered with the Unicode bidi algorithm
+///
+/// Positions come in two flavors: logical char indices (what the text stores,
+/// as used by cursors) and visual slots (caret gaps counted from the left edge).
+/// Where runs of different directions meet, two logical indices can share a
+/// slot while a neighbouring slot has none; carets always use `visual_slot`.
+#[derive(Debug, Clone)]
+pub struct BidiLine {
+    chars: Vec<char>,
+    rtl: Vec<bool>,
+    visual: Vec<usize>,
+    positions: Vec<usize>,
+    direction: TextDirection,
+}
+
+impl BidiLine {
+    /// Resolve `text`; `None` takes the direction from the first strong character,
+    /// falling back to the layout direction for neutral text
+    pub fn new(text: &str, direction: Option<TextDirection>) -> Self {
+        let level = direction.map(|direction| match direction {
+            TextDirection::Ltr => Level::ltr(),
+            TextDirection::Rtl => Level::rtl(),
+        });
+        let info = ParagraphBidiInfo::new(text, level);
+        let direction = match direction {
+            Some(direction) => direction,
+            None if info.is_pure_ltr && !text.chars().any(char::is_alphabetic) => layout_direction(),
+            None if info.paragraph_level.is_rtl() => TextDirection::Rtl,
+            None => TextDirection::Ltr,
+        };
+
+        let mut byte_to_char = vec![0; text.len()];
+        for (char_index, (byte, _)) in text.char_indices().enumerate() {
+            byte_to_char[byte] = char_index;
+        }
+        let chars: Vec<char> = text.chars().collect();
+        let rtl: Vec<bool> = text.char_indices().map(|(byte, _)| info.levels[byte].is_rtl()).collect();
+
+        // unicode-bidi indexes the first level even for an empty line
+        let (levels, runs) = if text.is_empty() {
+            (Vec::new(), Vec::new())
+        } else {
+            info.visual_runs(0..text.len())
+        };
+        let mut visual = Vec::with_capacity(chars.len());
+        for run in runs {
+            let run_chars: Vec<usize> = text[run.clone()]
+                .char_indices()
+                .map(|(offset, _)| byte_to_char[run.start + offset])
+                .collect();
+            if levels[run.start].is_rtl() {
+                visual.extend(run_chars.into_iter().rev());
+            } else {
+                visual.extend(run_chars);
+            }
+        }
+
+        let mut positions = vec![0; chars.len()];
+        for (position, &index) in visual.iter().enumerate() {
+            positions[index] = position;
+        }
+
+        Self {
+            chars,
+            rtl,
+            visual,
+            positions,
+            direction,
+        }
+    }
+
+    /// Paragraph direction, used to align the line to its start edge
+    pub fn direction(&self) -> TextDirection {
+        self.direction
+    }
+
+    pub fn has_rtl(&self) -> bool {
+        self.rtl.iter().any(|&rtl| rtl)
+    }
+
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Logical char indices from left to right
+    pub fn visual_order(&self) -> &[usize] {
+        &self.visual
+    }
+
+    /// Glyph to draw for a logical char: brackets are mirrored inside RTL runs
+    pub fn display_char(&self, index: usize) -> char {
+        if self.rtl[index] {
+            mirror_char(self.chars[index])
+        } else {
+            self.chars[index]
+        }
+    }
+
+    /// The line in left-to-right drawing order
+    pub fn visual_text(&self) -> String {
+        self.visual.iter().map(|&index| self.display_char(index)).collect()
+    }
+
+    /// Caret slot of logical index `index`, attached to the char before it
+    pub fn visual_slot(&self, index: usize) -> usize {
+        if self.chars.is_empty() {
+            return 0;
+        }
+        if index == 0 {
+            let first = self.positions[0];
+            return if self.rtl[0] { first + 1 } else { first };
+        }
+        let before = index.min(self.chars.len()) - 1;
+        let slot = self.positions[before];
+        if self.rtl[before] {
+            slot
+        } else {
+            slot + 1
+        }
+    }
+
+    /// Index of the caret next to `index` on screen; `None` at the line's edge
+    ///
+    /// The slot strictly grows (or shrinks), so repeated moves always reach the edge.
+    pub fn move_visual(&self, index: usize, right: bool) -> Option<usize> {
+        let slot = self.visual_slot(index);
+        let candidates = (0..=self.chars.len())
+            .filter(|&other| other != index)
+            .map(|other| (self.visual_slot(other), other));
+        let next = if right {
+            candidates.filter(|(other_slot, _)| *other_slot > slot).min()
+        } else {
+            candidates.filter(|(other_slot, _)| *other_slot < slot).max()
+        };
+        next.map(|(_, other)| other)
+    }
+
+    /// Advance width of each visual position
+    fn advances(&self, font: &Font) -> Vec<f32> {
+        let mut buf = [0u8; 4];
+        self.visual
+            .iter()
+            .map(|&index| font.measure_str(self.display_char(index).encode_utf8(&mut buf), None).0)
+            .collect()
+    }
+
+    /// Total advance width of the line
+    pub fn width(&self, font: &Font) -> f32 {
+        self.advances(font).iter().sum()
+    }
+
+    /// X offset of the caret for logical index `index`
+    pub fn caret_x(&self, index: usize, font: &Font) -> f32 {
+        let slot = self.visual_slot(index);
+        self.advances(font).iter().take(slot).sum()
+    }
+
+    /// Logical index of the caret nearest to `x` (relative to the line start)
+    pub fn index_at_x(&self, x: f32, font: &Font) -> usize {
+        let mut slot_x = vec![0.0];
+        for advance in self.advances(font) {
+            slot_x.push(slot_x[slot_x.len() - 1] + advance);
+        }
+        (0..=self.chars.len())
+            .min_by(|&a, &b| {
+                let distance = |index: usize| (slot_x[self.visual_slot(index)] - x).abs();
+                distance(a).total_cmp(&distance(b))
+            })
+            .unwrap_or(0)
+    }
+
+    /// Highlight spans `(x, width)` covering logical chars `start..end`
+    ///
+    /// A logical range can be split on screen when it crosses runs of different directions.
+    pub fn selection_spans(&self, start: usize, end: usize, font: &Font) -> Vec<(f32, f32)> {
+        let mut spans: Vec<(f32, f32)> = Vec::new();
+        let mut x = 0.0;
+        let mut extend = false;
+        for (position, advance) in self.advances(font).into_iter().enumerate() {
+            let selected = (start..end).contains(&self.visual[position]);
+            if selected && extend {
+                if let Some(span) = spans.last_mut() {
+                    span.1 += advance;
+                }
+            } else if selected {
+                spans.push((x, advance));
+            }
+            extend = selected;
+            x += advance;
+        }
+        spans
+    }
+}
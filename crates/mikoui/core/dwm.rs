@@ -1,13 +1,42 @@
-/// Windows DWM (Desktop Window Manager) integration for native effects
-/// Provides rounded corners and drop shadows for borderless windows
+//! Windows DWM (Desktop Window Manager) integration for native effects
+//! Provides rounded corners and drop shadows for borderless windows
+
+/// What shows through the translucent parts of the window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WindowMaterial {
+    /// Opaque window
+    #[default]
+    None,
+    /// Whatever is behind the window, unblurred
+    Transparent,
+    /// Blurred desktop (Windows 11 22H2 and later)
+    Acrylic,
+    /// Tinted desktop wallpaper (Windows 11 22H2 and later)
+    Mica,
+}
+
+impl WindowMaterial {
+    /// Parse a settings value: `none`, `transparent`, `acrylic` or `mica`
+    pub fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "none" => Some(Self::None),
+            "transparent" => Some(Self::Transparent),
+            "acrylic" => Some(Self::Acrylic),
+            "mica" => Some(Self::Mica),
+            _ => None,
+        }
+    }
+}
 
 #[cfg(target_os = "windows")]
 pub mod windows {
+    use super::WindowMaterial;
     use windows::Win32::Foundation::{HWND, RECT};
     use windows::Win32::Graphics::Dwm::{
-        DwmSetWindowAttribute, DWMWA_WINDOW_CORNER_PREFERENCE,
+        DwmExtendFrameIntoClientArea, DwmSetWindowAttribute, DWMWA_WINDOW_CORNER_PREFERENCE,
         DWMWINDOWATTRIBUTE,
     };
+    use windows::Win32::UI::Controls::MARGINS;
 
     /// Window corner preference
     #[repr(i32)]
@@ -115,10 +144,63 @@ pub mod windows {
         let resize = enable_borderless_resize(hwnd);
         shadow && corners && resize
     }
+    
+    /// Draw a system backdrop behind the client area
+    /// Fails on Windows versions without DWMWA_SYSTEMBACKDROP_TYPE.
+    pub fn set_window_material(hwnd: isize, material: WindowMaterial) -> bool {
+        unsafe {
+            let hwnd = HWND(hwnd as *mut std::ffi::c_void);
+            
+            // Extend the frame over the whole client area so translucent pixels show the backdrop
+            let inset = if material == WindowMaterial::None { 0 } else { -1 };
+            let margins = MARGINS {
+                cxLeftWidth: inset,
+                cxRightWidth: inset,
+                cyTopHeight: inset,
+                cyBottomHeight: inset,
+            };
+            if DwmExtendFrameIntoClientArea(hwnd, &margins).is_err() {
+                return false;
+            }
+            
+            // DWMWA_SYSTEMBACKDROP_TYPE = 38
+            // DWMSBT_NONE = 1, DWMSBT_MAINWINDOW (Mica) = 2, DWMSBT_TRANSIENTWINDOW (Acrylic) = 3
+            let backdrop: i32 = match material {
+                WindowMaterial::Mica => 2,
+                WindowMaterial::Acrylic => 3,
+                WindowMaterial::None | WindowMaterial::Transparent => 1,
+            };
+            let result = DwmSetWindowAttribute(
+                hwnd,
+                DWMWINDOWATTRIBUTE(38),
+                &backdrop as *const _ as *const _,
+                std::mem::size_of::<i32>() as u32,
+            );
+            result.is_ok()
+        }
+    }
+    
+    /// Tint the backdrop for a dark or light theme
+    pub fn set_dark_mode(hwnd: isize, dark: bool) -> bool {
+        unsafe {
+            let hwnd = HWND(hwnd as *mut std::ffi::c_void);
+            // DWMWA_USE_IMMERSIVE_DARK_MODE = 20
+            let value: i32 = dark as i32;
+            let result = DwmSetWindowAttribute(
+                hwnd,
+                DWMWINDOWATTRIBUTE(20),
+                &value as *const _ as *const _,
+                std::mem::size_of::<i32>() as u32,
+            );
+            result.is_ok()
+        }
+    }
 }
 
 #[cfg(not(target_os = "windows"))]
 pub mod windows {
+    use super::WindowMaterial;
+    
     /// Dummy implementation for non-Windows platforms
     #[derive(Debug, Clone, Copy)]
     pub enum CornerPreference {
@@ -147,4 +229,12 @@ pub mod windows {
     pub fn apply_modern_window_style(_hwnd: isize) -> bool {
         false
     }
+    
+    pub fn set_window_material(_hwnd: isize, _material: WindowMaterial) -> bool {
+        false
+    }
+    
+    pub fn set_dark_mode(_hwnd: isize, _dark: bool) -> bool {
+        false
+    }
 }
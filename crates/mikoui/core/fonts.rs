@@ -1,5 +1,11 @@
-use skia_safe::{Data, Font, FontMgr, FontStyle, Typeface};
+use skia_safe::{Canvas, Data, Font, FontMgr, FontStyle, Paint, Point, Typeface};
 use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use super::accessibility::text_scale;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Language {
@@ -26,6 +32,13 @@ pub enum FontWeight {
     Black = 900,
 }
 
+/// A slice of text (byte range) drawn with a single font
+#[derive(Debug, Clone)]
+pub struct FontRun {
+    pub range: Range<usize>,
+    pub font: Font,
+}
+
 pub struct FontManager {
     // Primary system font
     primary_typeface: Option<Typeface>,
@@ -37,6 +50,7 @@ pub struct FontManager {
     thai_typeface: Option<Typeface>,
     cjk_typeface: Option<Typeface>,
     arabic_typeface: Option<Typeface>,
+    emoji_typeface: Option<Typeface>,
     
     // System font manager
     font_mgr: FontMgr,
@@ -44,6 +58,19 @@ pub struct FontManager {
     // Font cache
     font_cache: HashMap<(Language, i32, i32), Font>,
     mono_font_cache: HashMap<(i32, i32), Font>,
+    
+    // Fallback typeface per character, resolved once through the system font manager
+    fallback_cache: HashMap<char, Option<Typeface>>,
+    
+    // Fonts registered by the app, keyed by lowercase family name
+    registered_fonts: HashMap<String, Vec<Typeface>>,
+    registered_files: HashMap<PathBuf, String>,
+    
+    // Static faces of the UI family; a single variable face leaves this empty
+    primary_faces: Vec<Typeface>,
+    
+    // Bumped whenever the UI or monospace font changes
+    revision: u64,
 }
 
 impl FontManager {
@@ -54,9 +81,15 @@ impl FontManager {
             thai_typeface: None,
             cjk_typeface: None,
             arabic_typeface: None,
+            emoji_typeface: None,
             font_mgr: FontMgr::new(),
             font_cache: HashMap::new(),
             mono_font_cache: HashMap::new(),
+            fallback_cache: HashMap::new(),
+            registered_fonts: HashMap::new(),
+            registered_files: HashMap::new(),
+            primary_faces: Vec::new(),
+            revision: 0,
         };
         
         manager.load_fonts();
@@ -78,6 +111,9 @@ impl FontManager {
         
         // Try to load Arabic fonts from system
         self.load_arabic_fonts();
+        
+        // Try to load a color emoji font from system
+        self.load_emoji_fonts();
     }
     
     fn load_system_font(&mut self) {
@@ -131,7 +167,8 @@ impl FontManager {
         if let Some(typeface) = self.font_mgr.new_from_data(&data, None) {
             println!("✓ Loaded custom primary font ({} bytes)", font_data.len());
             self.primary_typeface = Some(typeface);
-            self.clear_cache(); // Clear cache to use new font
+            self.primary_faces.clear();
+            self.fonts_changed();
             true
         } else {
             println!("✗ Failed to load custom primary font");
@@ -139,6 +176,140 @@ impl FontManager {
         }
     }
     
+    /// Register a font file's bytes under `family`, or under the family name stored in the font
+    ///
+    /// Register every weight of a static family under the same name; a variable
+    /// font is registered once and its `wght` axis covers all weights.
+    /// Returns the family name to pass to `set_ui_font_family` / `set_monospace_font_family`.
+    pub fn register_font(&mut self, font_data: &[u8], family: Option<&str>) -> Result<String, Box<dyn Error>> {
+        let data = Data::new_copy(font_data);
+        let typeface = self.font_mgr
+            .new_from_data(&data, None)
+            .ok_or("not a supported font file")?;
+        
+        let family = match family {
+            Some(family) => family.trim().to_string(),
+            None => typeface.family_name(),
+        };
+        if family.is_empty() {
+            return Err("font has no family name".into());
+        }
+        
+        println!("✓ Registered font: {} ({} bytes)", family, font_data.len());
+        self.registered_fonts.entry(family.to_lowercase()).or_default().push(typeface);
+        Ok(family)
+    }
+    
+    /// Register a `.ttf` / `.otf` file; see `register_font`. Files already registered are skipped
+    pub fn register_font_file(&mut self, path: &Path, family: Option<&str>) -> Result<String, Box<dyn Error>> {
+        if let Some(family) = self.registered_files.get(path) {
+            return Ok(family.clone());
+        }
+        let font_data = fs::read(path)?;
+        let family = self.register_font(&font_data, family)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        self.registered_files.insert(path.to_path_buf(), family.clone());
+        Ok(family)
+    }
+    
+    /// Register every font file in `dir`; returns the families found
+    pub fn register_fonts_from_dir(&mut self, dir: &Path) -> Vec<String> {
+        let mut families = Vec::new();
+        let Ok(entries) = fs::read_dir(dir) else {
+            return families;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            let is_font = path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| matches!(ext.to_ascii_lowercase().as_str(), "ttf" | "otf" | "ttc"));
+            if !is_font {
+                continue;
+            }
+            match self.register_font_file(&path, None) {
+                Ok(family) => families.push(family),
+                Err(e) => eprintln!("Failed to register font {}", e),
+            }
+        }
+        families.sort();
+        families.dedup();
+        families
+    }
+    
+    /// Families added with `register_font`, lowercase
+    pub fn registered_families(&self) -> Vec<String> {
+        let mut families: Vec<String> = self.registered_fonts.keys().cloned().collect();
+        families.sort();
+        families
+    }
+    
+    /// Faces for `family`: registered fonts first, then the system font manager
+    fn resolve_family(&self, family: &str) -> Vec<Typeface> {
+        if let Some(faces) = self.registered_fonts.get(&family.trim().to_lowercase()) {
+            return faces.clone();
+        }
+        self.font_mgr
+            .match_family_style(family.trim(), FontStyle::normal())
+            .into_iter()
+            .collect()
+    }
+    
+    /// Use `family` for UI text, or the platform default with `""`
+    ///
+    /// Returns false and keeps the current font if the family is unknown.
+    pub fn set_ui_font_family(&mut self, family: &str) -> bool {
+        if family.trim().is_empty() {
+            self.load_system_font();
+            self.primary_faces.clear();
+            self.fonts_changed();
+            return true;
+        }
+        let faces = self.resolve_family(family);
+        let Some(regular) = Self::nearest_weight(&faces, FontWeight::Regular as i32).cloned() else {
+            eprintln!("⚠ UI font '{}' not found", family);
+            return false;
+        };
+        
+        println!("✓ UI font: {}", family);
+        self.primary_typeface = Some(regular);
+        self.primary_faces = if faces.len() > 1 { faces } else { Vec::new() };
+        self.fonts_changed();
+        true
+    }
+    
+    /// Use `family` for code and the terminal, or the platform default with `""`
+    pub fn set_monospace_font_family(&mut self, family: &str) -> bool {
+        if family.trim().is_empty() {
+            self.load_monospace_font();
+            self.fonts_changed();
+            return true;
+        }
+        let faces = self.resolve_family(family);
+        let Some(regular) = Self::nearest_weight(&faces, FontWeight::Regular as i32).cloned() else {
+            eprintln!("⚠ Monospace font '{}' not found", family);
+            return false;
+        };
+        
+        println!("✓ Monospace font: {}", family);
+        self.monospace_typeface = Some(regular);
+        self.fonts_changed();
+        true
+    }
+    
+    /// Face whose weight is closest to `weight`
+    fn nearest_weight(faces: &[Typeface], weight: i32) -> Option<&Typeface> {
+        faces.iter().min_by_key(|face| (*face.font_style().weight() - weight).abs())
+    }
+    
+    /// Changes whenever the UI or monospace font changes, so views can re-measure cached text
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+    
+    fn fonts_changed(&mut self) {
+        self.clear_cache();
+        self.revision += 1;
+    }
+    
     fn load_thai_fonts(&mut self) {
         // Try multiple Thai fonts in order of preference
         let thai_fonts = if cfg!(target_os = "windows") {
@@ -202,6 +373,48 @@ impl FontManager {
         println!("⚠ No Arabic font found, using primary font as fallback");
     }
     
+    fn load_emoji_fonts(&mut self) {
+        let emoji_fonts = if cfg!(target_os = "windows") {
+            vec!["Segoe UI Emoji", "Segoe UI Symbol", "Noto Color Emoji"]
+        } else if cfg!(target_os = "macos") {
+            vec!["Apple Color Emoji", "Noto Color Emoji"]
+        } else {
+            vec!["Noto Color Emoji", "Twemoji", "JoyPixels", "Noto Emoji"]
+        };
+        
+        for font_name in emoji_fonts {
+            if let Some(typeface) = self.font_mgr.match_family_style(font_name, FontStyle::normal()) {
+                println!("✓ Loaded emoji font: {}", font_name);
+                self.emoji_typeface = Some(typeface);
+                return;
+            }
+        }
+        
+        println!("⚠ No emoji font found, relying on system fallback");
+    }
+    
+    /// Whether `ch` is drawn from an emoji font (pictographs, flags, symbols)
+    pub fn is_emoji(ch: char) -> bool {
+        matches!(ch,
+            '\u{1F000}'..='\u{1FAFF}'   // Mahjong, cards, emoticons, pictographs, flags
+            | '\u{2600}'..='\u{27BF}'   // Misc symbols and dingbats
+            | '\u{2B00}'..='\u{2BFF}'   // Arrows, stars
+            | '\u{2300}'..='\u{23FF}'   // Watch, hourglass, media controls
+        )
+    }
+    
+    /// Characters that belong to the cluster before them and must share its font
+    fn is_cluster_extender(ch: char) -> bool {
+        matches!(ch,
+            '\u{200D}'                   // Zero-width joiner
+            | '\u{FE00}'..='\u{FE0F}'   // Variation selectors
+            | '\u{1F3FB}'..='\u{1F3FF}' // Skin tone modifiers
+            | '\u{20E3}'                 // Combining keycap
+            | '\u{E0020}'..='\u{E007F}' // Tag sequences (subdivision flags)
+            | '\u{0300}'..='\u{036F}'   // Combining diacritics
+        )
+    }
+    
     /// Detect language from text content
     pub fn detect_language(text: &str) -> Language {
         for ch in text.chars() {
@@ -285,14 +498,123 @@ impl FontManager {
             }
         }
         
+        if let Some(ref emoji_tf) = self.emoji_typeface {
+            if emoji_tf.unichar_to_glyph(ch as i32) != 0 {
+                return Some(emoji_tf.clone());
+            }
+        }
+        
         // Last resort: use primary typeface
         self.primary_typeface.clone()
     }
     
+    /// Typeface for a character `base` cannot draw: script font first, then the system fallback
+    fn fallback_typeface(&mut self, ch: char) -> Option<Typeface> {
+        if let Some(typeface) = self.fallback_cache.get(&ch) {
+            return typeface.clone();
+        }
+        
+        let preferred = if Self::is_emoji(ch) {
+            self.emoji_typeface.as_ref()
+        } else {
+            match Self::detect_language(&ch.to_string()) {
+                Language::Thai => self.thai_typeface.as_ref(),
+                Language::Japanese | Language::Korean | Language::Chinese => self.cjk_typeface.as_ref(),
+                Language::Arabic | Language::Hebrew => self.arabic_typeface.as_ref(),
+                _ => None,
+            }
+        };
+        let typeface = preferred
+            .filter(|typeface| typeface.unichar_to_glyph(ch as i32) != 0)
+            .cloned()
+            .or_else(|| {
+                // Language hints steer Han characters to the right regional glyphs
+                let bcp47: &[&str] = match Self::detect_language(&ch.to_string()) {
+                    Language::Japanese => &["ja"],
+                    Language::Korean => &["ko"],
+                    Language::Chinese => &["zh"],
+                    _ => &[],
+                };
+                self.font_mgr.match_family_style_character("", FontStyle::normal(), bcp47, ch as i32)
+            });
+        
+        self.fallback_cache.insert(ch, typeface.clone());
+        typeface
+    }
+    
+    /// Split `text` into runs that `font` or a fallback font can draw
+    ///
+    /// Characters missing from `font`'s typeface get a per-script or emoji font;
+    /// joiners, variation selectors and modifiers stay in the run of the character
+    /// they extend so emoji sequences are never split across fonts.
+    pub fn segment_runs(&mut self, text: &str, font: &Font) -> Vec<FontRun> {
+        let base = font.typeface();
+        let mut runs: Vec<(Range<usize>, Option<Typeface>)> = Vec::new();
+        
+        for (index, ch) in text.char_indices() {
+            let end = index + ch.len_utf8();
+            if let Some((range, _)) = runs.last_mut().filter(|_| Self::is_cluster_extender(ch)) {
+                range.end = end;
+                continue;
+            }
+            let typeface = if base.unichar_to_glyph(ch as i32) != 0 || ch.is_whitespace() || ch.is_control() {
+                None
+            } else {
+                self.fallback_typeface(ch)
+            };
+            let family = typeface.as_ref().map(Typeface::family_name);
+            match runs.last_mut() {
+                Some((range, current)) if current.as_ref().map(Typeface::family_name) == family => {
+                    range.end = end;
+                }
+                _ => runs.push((index..end, typeface)),
+            }
+        }
+        
+        runs.into_iter()
+            .map(|(range, typeface)| {
+                let font = match typeface {
+                    Some(typeface) => {
+                        let mut fallback = font.clone();
+                        fallback.set_typeface(typeface);
+                        // Color emoji fonts ship their glyphs as embedded bitmaps
+                        fallback.set_embedded_bitmaps(true);
+                        fallback
+                    }
+                    None => font.clone(),
+                };
+                FontRun { range, font }
+            })
+            .collect()
+    }
+    
+    /// Advance width of `text`, measuring fallback runs with their own fonts
+    pub fn measure_text(&mut self, text: &str, font: &Font) -> f32 {
+        self.segment_runs(text, font)
+            .iter()
+            .map(|run| run.font.measure_str(&text[run.range.clone()], None).0)
+            .sum()
+    }
+    
+    /// Draw `text` at `origin`, switching fonts between runs; returns the advance width
+    pub fn draw_text(&mut self, canvas: &Canvas, text: &str, origin: impl Into<Point>, font: &Font, paint: &Paint) -> f32 {
+        let origin = origin.into();
+        let mut x = origin.x;
+        for run in self.segment_runs(text, font) {
+            let slice = &text[run.range];
+            canvas.draw_str(slice, (x, origin.y), &run.font, paint);
+            x += run.font.measure_str(slice, None).0;
+        }
+        x - origin.x
+    }
+    
     /// Create font with Variable Font support and language detection
+    ///
+    /// This is the UI text entry point, so `size` is multiplied by the accessibility text scale;
+    /// editor and terminal text go through the monospace constructors and keep their own size.
     pub fn create_font(&mut self, text: &str, size: f32, weight: i32) -> Font {
         let language = Self::detect_language(text);
-        self.create_font_for_language(language, size, weight)
+        self.create_font_for_language(language, size * text_scale(), weight)
     }
     
     /// Create font for specific language
@@ -303,7 +625,12 @@ impl FontManager {
             return font.clone();
         }
         
-        let typeface = self.get_typeface_for_language(language);
+        // Static UI families pick the registered face closest to the requested weight
+        let static_face = match language {
+            Language::English | Language::Other => Self::nearest_weight(&self.primary_faces, weight),
+            _ => None,
+        };
+        let typeface = static_face.unwrap_or_else(|| self.get_typeface_for_language(language));
         let font = self.create_variable_font(typeface, size, weight);
         
         // Cache the font
@@ -454,7 +781,7 @@ impl FontManager {
         if let Some(typeface) = self.font_mgr.new_from_data(&data, None) {
             println!("✓ Loaded custom monospace font ({} bytes)", font_data.len());
             self.monospace_typeface = Some(typeface);
-            self.mono_font_cache.clear(); // Clear cache to use new font
+            self.fonts_changed();
             true
         } else {
             println!("✗ Failed to load custom monospace font");
@@ -466,6 +793,7 @@ impl FontManager {
     pub fn clear_cache(&mut self) {
         self.font_cache.clear();
         self.mono_font_cache.clear();
+        self.fallback_cache.clear();
     }
     
     /// Get cache size
@@ -0,0 +1,290 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+/// Last locale of every fallback chain
+pub const DEFAULT_LOCALE: &str = "en";
+
+// Strings used by mikoui's own components
+const BUILTIN_EN: &str = include_str!("../locales/en.json");
+
+/// Translated messages of one locale, keyed by dotted ids like `file.open_folder`
+///
+/// Arguments are written as `{name}` and filled in by `translate_with` / `t!`.
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a JSON catalog; nested objects become dotted keys
+    pub fn from_json(source: &str) -> Result<Self, Box<dyn Error>> {
+        fn flatten(prefix: &str, value: &serde_json::Value, catalog: &mut Catalog) -> Result<(), Box<dyn Error>> {
+            match value {
+                serde_json::Value::Object(map) => {
+                    for (key, value) in map {
+                        let key = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                        flatten(&key, value, catalog)?;
+                    }
+                    Ok(())
+                }
+                serde_json::Value::String(text) => {
+                    catalog.insert(prefix, text.as_str());
+                    Ok(())
+                }
+                _ => Err(format!("'{}' must be a string or an object", prefix).into()),
+            }
+        }
+
+        let value: serde_json::Value = serde_json::from_str(source)?;
+        if !value.is_object() {
+            return Err("a catalog must be a JSON object".into());
+        }
+        let mut catalog = Self::new();
+        flatten("", &value, &mut catalog)?;
+        Ok(catalog)
+    }
+
+    /// Parse the Fluent (`.ftl`) subset used for UI strings
+    ///
+    /// Supports `key = value` messages, `#` comments, indented continuation
+    /// lines and `{ $name }` arguments; selectors and terms are not supported.
+    pub fn from_ftl(source: &str) -> Result<Self, Box<dyn Error>> {
+        let mut catalog = Self::new();
+        let mut current: Option<(String, String)> = None;
+
+        for (index, line) in source.lines().enumerate() {
+            if line.starts_with(' ') || line.starts_with('\t') {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match current.as_mut() {
+                    Some((_, value)) => {
+                        if !value.is_empty() {
+                            value.push('\n');
+                        }
+                        value.push_str(line.trim());
+                        continue;
+                    }
+                    None => return Err(format!("line {}: continuation without a message", index + 1).into()),
+                }
+            }
+
+            if let Some((key, value)) = current.take() {
+                catalog.insert(key, ftl_arguments(&value));
+            }
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("line {}: expected `key = value`", index + 1).into());
+            };
+            current = Some((key.trim().to_string(), value.trim().to_string()));
+        }
+        if let Some((key, value)) = current {
+            catalog.insert(key, ftl_arguments(&value));
+        }
+        Ok(catalog)
+    }
+
+    /// Load a `.json` or `.ftl` file
+    pub fn from_file(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let source = fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json(&source),
+            Some("ftl") => Self::from_ftl(&source),
+            _ => Err(format!("unsupported catalog format: {}", path.display()).into()),
+        }
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.messages.insert(key.into(), value.into());
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Add every message of `other`, replacing existing keys
+    pub fn merge(&mut self, other: Catalog) {
+        self.messages.extend(other.messages);
+    }
+}
+
+/// Rewrite Fluent `{ $name }` placeables to the catalog's `{name}` form
+fn ftl_arguments(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        match rest[start..].find('}') {
+            Some(end) => {
+                let inner = rest[start + 1..start + end].trim();
+                result.push('{');
+                result.push_str(inner.trim_start_matches('$'));
+                result.push('}');
+                rest = &rest[start + end + 1..];
+            }
+            None => {
+                result.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+struct Localizer {
+    locale: String,
+    catalogs: HashMap<String, Catalog>,
+}
+
+impl Localizer {
+    fn new() -> Self {
+        let mut catalogs = HashMap::new();
+        catalogs.insert(
+            DEFAULT_LOCALE.to_string(),
+            Catalog::from_json(BUILTIN_EN).expect("built-in catalog is valid JSON"),
+        );
+        Self {
+            locale: DEFAULT_LOCALE.to_string(),
+            catalogs,
+        }
+    }
+
+    fn lookup(&self, key: &str) -> Option<&str> {
+        fallback_chain(&self.locale)
+            .iter()
+            .filter_map(|locale| self.catalogs.get(locale))
+            .find_map(|catalog| catalog.get(key))
+    }
+}
+
+thread_local! {
+    static LOCALIZER: RefCell<Localizer> = RefCell::new(Localizer::new());
+    static REVISION: Cell<u64> = const { Cell::new(0) };
+}
+
+fn bump_revision() {
+    REVISION.with(|r| r.set(r.get() + 1));
+}
+
+/// Normalize `pt_BR` to `pt-BR`
+fn normalize_locale(locale: &str) -> String {
+    locale.trim().replace('_', "-")
+}
+
+/// Locales tried for a lookup: `pt-BR` -> `pt` -> `en`
+pub fn fallback_chain(locale: &str) -> Vec<String> {
+    let mut chain = Vec::new();
+    let mut current = normalize_locale(locale);
+    while !current.is_empty() {
+        chain.push(current.clone());
+        match current.rfind('-') {
+            Some(index) => current.truncate(index),
+            None => break,
+        }
+    }
+    if !chain.iter().any(|locale| locale == DEFAULT_LOCALE) {
+        chain.push(DEFAULT_LOCALE.to_string());
+    }
+    chain
+}
+
+/// Add messages for `locale`, merging with any catalog already registered
+pub fn register_catalog(locale: &str, catalog: Catalog) {
+    LOCALIZER.with(|l| {
+        l.borrow_mut()
+            .catalogs
+            .entry(normalize_locale(locale))
+            .or_default()
+            .merge(catalog)
+    });
+    bump_revision();
+}
+
+/// Load every `<locale>.json` / `<locale>.ftl` in `dir`; returns the locales loaded
+pub fn load_catalogs_from_dir(dir: &Path) -> Vec<String> {
+    let mut loaded = Vec::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return loaded;
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let Some(locale) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        match Catalog::from_file(&path) {
+            Ok(catalog) => {
+                register_catalog(locale, catalog);
+                loaded.push(normalize_locale(locale));
+            }
+            Err(e) => eprintln!("Failed to load catalog {}: {}", path.display(), e),
+        }
+    }
+    loaded.sort();
+    loaded.dedup();
+    loaded
+}
+
+/// Locales with a registered catalog, sorted
+pub fn available_locales() -> Vec<String> {
+    let mut locales: Vec<String> = LOCALIZER.with(|l| l.borrow().catalogs.keys().cloned().collect());
+    locales.sort();
+    locales
+}
+
+/// Switch the active locale; missing keys fall back along `fallback_chain`
+pub fn set_locale(locale: &str) {
+    LOCALIZER.with(|l| l.borrow_mut().locale = normalize_locale(locale));
+    bump_revision();
+}
+
+pub fn locale() -> String {
+    LOCALIZER.with(|l| l.borrow().locale.clone())
+}
+
+/// Changes whenever the locale or a catalog changes, so cached labels can be rebuilt
+pub fn locale_revision() -> u64 {
+    REVISION.with(|r| r.get())
+}
+
+/// Look up `key` in the active locale; unknown keys are returned unchanged
+pub fn translate(key: &str) -> String {
+    LOCALIZER.with(|l| l.borrow().lookup(key).unwrap_or(key).to_string())
+}
+
+/// Look up `key` and replace each `{name}` with its argument
+pub fn translate_with(key: &str, args: &[(&str, String)]) -> String {
+    let mut text = translate(key);
+    for (name, value) in args {
+        text = text.replace(&format!("{{{}}}", name), value);
+    }
+    text
+}
+
+/// Translate a catalog key: `t!("file.open_folder")` or `t!("goto.line", line = 12)`
+#[macro_export]
+macro_rules! t {
+    ($key:expr) => {
+        $crate::core::translate($key)
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::core::translate_with($key, &[$((stringify!($name), $value.to_string())),+])
+    };
+}
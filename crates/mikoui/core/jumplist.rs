@@ -0,0 +1,72 @@
+//! Taskbar jump list with the app's recent folders and files
+//! Each entry starts the executable again with the path as its only argument
+
+#[cfg(target_os = "windows")]
+pub mod windows {
+    use std::path::{Path, PathBuf};
+    use windows::core::{Interface, GUID, HSTRING, PROPVARIANT};
+    use windows::Win32::System::Com::{CoCreateInstance, CoInitializeEx, CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED};
+    use windows::Win32::UI::Shell::PropertiesSystem::{IPropertyStore, PROPERTYKEY};
+    use windows::Win32::UI::Shell::{
+        DestinationList, EnumerableObjectCollection, ICustomDestinationList, IObjectArray, IObjectCollection,
+        IShellLinkW, ShellLink,
+    };
+
+    /// System.Title, shown as the entry's label
+    const PKEY_TITLE: PROPERTYKEY = PROPERTYKEY {
+        fmtid: GUID::from_u128(0xF29F85E0_4FF9_1068_AB91_08002B27B3D9),
+        pid: 2,
+    };
+
+    /// Replace the jump list with one category per `(title, paths)`, most recent first
+    pub fn set_jump_list(categories: &[(String, Vec<PathBuf>)]) -> bool {
+        let Ok(exe) = std::env::current_exe() else {
+            return false;
+        };
+        unsafe {
+            let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+            build_list(&exe, categories).is_ok()
+        }
+    }
+
+    unsafe fn build_list(exe: &Path, categories: &[(String, Vec<PathBuf>)]) -> windows::core::Result<()> {
+        let list: ICustomDestinationList = CoCreateInstance(&DestinationList, None, CLSCTX_INPROC_SERVER)?;
+        let mut max_slots = 0u32;
+        // Entries the user removed from the list; they come back on their next use
+        let _removed: IObjectArray = list.BeginList(&mut max_slots)?;
+
+        for (title, paths) in categories.iter().filter(|(_, paths)| !paths.is_empty()) {
+            let collection: IObjectCollection =
+                CoCreateInstance(&EnumerableObjectCollection, None, CLSCTX_INPROC_SERVER)?;
+            for path in paths.iter().take(max_slots as usize) {
+                collection.AddObject(&shell_link(exe, path)?)?;
+            }
+            let items: IObjectArray = collection.cast()?;
+            list.AppendCategory(&HSTRING::from(title.as_str()), &items)?;
+        }
+
+        list.CommitList()
+    }
+
+    unsafe fn shell_link(exe: &Path, path: &Path) -> windows::core::Result<IShellLinkW> {
+        let link: IShellLinkW = CoCreateInstance(&ShellLink, None, CLSCTX_INPROC_SERVER)?;
+        link.SetPath(&HSTRING::from(exe.as_os_str()))?;
+        link.SetArguments(&HSTRING::from(format!("\"{}\"", path.display())))?;
+        link.SetDescription(&HSTRING::from(path.as_os_str()))?;
+
+        let title = path.file_name().unwrap_or(path.as_os_str()).to_string_lossy();
+        let store: IPropertyStore = link.cast()?;
+        store.SetValue(&PKEY_TITLE, &PROPVARIANT::from(title.as_ref()))?;
+        store.Commit()?;
+        Ok(link)
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub mod windows {
+    use std::path::PathBuf;
+
+    pub fn set_jump_list(_categories: &[(String, Vec<PathBuf>)]) -> bool {
+        false
+    }
+}
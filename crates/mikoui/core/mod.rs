@@ -1,9 +1,37 @@
+pub mod accessibility;
+pub mod animation;
+pub mod bidi;
 pub mod fonts;
 // pub mod titlebar;
 pub mod dwm;
 pub mod file_dialog;
+pub mod i18n;
+pub mod jumplist;
+pub mod present;
+pub mod profiler;
+pub mod protocol;
+pub mod scroll;
 
-pub use fonts::FontManager;
+pub use accessibility::{
+    accessibility_config, hit_target, set_accessibility_config, text_scale, AccessibilityConfig,
+};
+pub use animation::{
+    animation_config, animation_rate, animations_enabled, refresh_system_reduced_motion, set_animation_config,
+    system_reduced_motion, AnimationConfig,
+};
+pub use bidi::{
+    contains_rtl, layout_direction, mirror_x, set_layout_direction, BidiLine, TextAlign, TextDirection,
+};
+pub use fonts::{FontManager, FontRun};
+pub use i18n::{
+    available_locales, fallback_chain, load_catalogs_from_dir, locale, locale_revision, register_catalog, set_locale,
+    translate, translate_with, Catalog, DEFAULT_LOCALE,
+};
+pub use present::{copy_changed_to_buffer, copy_to_buffer, HeadlessFrame};
+pub use profiler::{FrameStats, Profiler, Span};
+pub use scroll::{reveal_offset, Easing, SmoothScroll};
 // pub use titlebar::{TitleBar, WindowControl, WindowControlButton};
-pub use dwm::windows as dwm_windows;
+pub use dwm::{windows as dwm_windows, WindowMaterial};
 pub use file_dialog::windows as file_dialogs;
+pub use jumplist::windows as jump_list;
+pub use protocol::windows as url_scheme;
@@ -0,0 +1,166 @@
+use skia_safe::{surfaces, Canvas, Surface};
+use softbuffer::Rect;
+use std::num::NonZeroU32;
+
+/// Copy a frame of skia N32 pixels (BGRA bytes) into a softbuffer buffer of `0xAARRGGBB` words
+///
+/// `src` holds tightly packed rows, four bytes per pixel; pixels past the end of either slice are
+/// left alone. On little-endian targets BGRA bytes already are those words in memory, so a frame
+/// is one bulk copy; the per-pixel loop only runs for unaligned input or big-endian targets.
+pub fn copy_to_buffer(src: &[u8], dst: &mut [u32]) {
+    #[cfg(target_endian = "little")]
+    {
+        // SAFETY: every bit pattern is a valid u32, and align_to only yields correctly aligned words
+        let (head, words, _) = unsafe { src.align_to::<u32>() };
+        if head.is_empty() {
+            let len = words.len().min(dst.len());
+            dst[..len].copy_from_slice(&words[..len]);
+            return;
+        }
+    }
+    for (pixel, bytes) in dst.iter_mut().zip(src.chunks_exact(4)) {
+        *pixel = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+}
+
+/// Copy a frame like `copy_to_buffer`, but only the pixels that differ from what `dst` holds
+///
+/// `dst` must hold the previously presented frame (softbuffer's buffer age 1). Returns the
+/// changed areas for `present_with_damage`: consecutive changed rows merge into one rectangle
+/// spanning their changed columns. Unaligned input falls back to a full copy and damage.
+pub fn copy_changed_to_buffer(src: &[u8], dst: &mut [u32], width: usize) -> Vec<Rect> {
+    let height = dst.len() / width.max(1);
+    // SAFETY: every bit pattern is a valid u32, and align_to only yields correctly aligned words
+    let (head, words, _) = unsafe { src.align_to::<u32>() };
+    if cfg!(target_endian = "big") || !head.is_empty() || width == 0 || words.len() < width * height {
+        copy_to_buffer(src, dst);
+        return full_damage(width, height);
+    }
+    
+    let mut damage: Vec<Rect> = Vec::new();
+    let mut band_bottom = usize::MAX; // Row after the last rectangle, to extend it
+    for (y, (new, old)) in words.chunks_exact(width).zip(dst.chunks_exact_mut(width)).take(height).enumerate() {
+        if new == old {
+            continue;
+        }
+        let left = new.iter().zip(old.iter()).position(|(a, b)| a != b).unwrap_or(0);
+        let right = width - new.iter().zip(old.iter()).rev().position(|(a, b)| a != b).unwrap_or(0);
+        old[left..right].copy_from_slice(&new[left..right]);
+        
+        match damage.last_mut() {
+            Some(rect) if band_bottom == y => {
+                let rect_right = rect.x as usize + rect.width.get() as usize;
+                let x = (rect.x as usize).min(left);
+                rect.x = x as u32;
+                rect.width = nonzero(rect_right.max(right) - x);
+                rect.height = nonzero(rect.height.get() as usize + 1);
+            }
+            _ => damage.push(Rect {
+                x: left as u32,
+                y: y as u32,
+                width: nonzero(right - left),
+                height: nonzero(1),
+            }),
+        }
+        band_bottom = y + 1;
+    }
+    damage
+}
+
+fn full_damage(width: usize, height: usize) -> Vec<Rect> {
+    if width == 0 || height == 0 {
+        return Vec::new();
+    }
+    vec![Rect {
+        x: 0,
+        y: 0,
+        width: nonzero(width),
+        height: nonzero(height),
+    }]
+}
+
+fn nonzero(value: usize) -> NonZeroU32 {
+    NonZeroU32::new(value as u32).unwrap_or(NonZeroU32::MIN)
+}
+
+/// Offscreen frame that goes through the same draw and present steps as a window, without one
+///
+/// Used by the benchmarks, so rendering can be measured on machines without a display.
+pub struct HeadlessFrame {
+    surface: Surface,
+    buffer: Vec<u32>,
+}
+
+impl HeadlessFrame {
+    pub fn new(width: i32, height: i32) -> Option<Self> {
+        let surface = surfaces::raster_n32_premul((width, height))?;
+        Some(Self {
+            surface,
+            buffer: vec![0; (width.max(0) * height.max(0)) as usize],
+        })
+    }
+    
+    pub fn canvas(&mut self) -> &Canvas {
+        self.surface.canvas()
+    }
+    
+    /// Copy what was drawn into the softbuffer-style buffer, like a window's present does
+    pub fn present(&mut self) -> &[u32] {
+        let image = self.surface.image_snapshot();
+        if let Some(pixels) = image.peek_pixels() {
+            if let Some(bytes) = pixels.bytes() {
+                copy_to_buffer(bytes, &mut self.buffer);
+            }
+        }
+        &self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn bgra_bytes_become_argb_words() {
+        let src = [0x10, 0x20, 0x30, 0x40, 0xFF, 0x00, 0x80, 0xC0];
+        let mut dst = [0u32; 2];
+        copy_to_buffer(&src, &mut dst);
+        assert_eq!(dst, [0x4030_2010, 0xC080_00FF]);
+    }
+    
+    #[test]
+    fn unaligned_and_uneven_input() {
+        // Offset by one byte so the bulk path can't be taken
+        let bytes: Vec<u8> = (0..13).collect();
+        let mut dst = [0u32; 4];
+        copy_to_buffer(&bytes[1..], &mut dst);
+        assert_eq!(dst, [0x0403_0201, 0x0807_0605, 0x0C0B_0A09, 0]);
+        
+        // A short destination takes only what fits
+        let mut short = [0u32; 1];
+        copy_to_buffer(&bytes[..12], &mut short);
+        assert_eq!(short, [0x0302_0100]);
+    }
+    
+    #[test]
+    fn only_changed_rows_are_damaged() {
+        let width = 4;
+        let mut dst = vec![0u32; width * 4];
+        let mut frame = vec![0u32; width * 4];
+        assert!(copy_changed_to_buffer(as_bytes(&frame), &mut dst, width).is_empty());
+        
+        // Row 0 alone; rows 2 and 3 merge into one band spanning their changed columns
+        frame[1] = 7;
+        frame[2 * width + 2] = 8;
+        frame[3 * width] = 9;
+        let damage = copy_changed_to_buffer(as_bytes(&frame), &mut dst, width);
+        let rects: Vec<_> = damage.iter().map(|r| (r.x, r.y, r.width.get(), r.height.get())).collect();
+        assert_eq!(rects, [(1, 0, 1, 1), (0, 2, 3, 2)]);
+        assert_eq!(dst, frame);
+    }
+    
+    fn as_bytes(words: &[u32]) -> &[u8] {
+        // SAFETY: u8 has no alignment or validity requirements
+        unsafe { words.align_to::<u8>().1 }
+    }
+}
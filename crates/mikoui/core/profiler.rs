@@ -0,0 +1,146 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Frames kept for averages and graphs
+const HISTORY_LEN: usize = 120;
+
+/// A running measurement; hand it back to `Profiler::record` when the phase ends
+///
+/// Spans do not borrow the profiler, so a phase can wrap code that needs `&mut self`.
+#[derive(Debug, Clone, Copy)]
+pub struct Span {
+    name: &'static str,
+    start: Instant,
+}
+
+impl Span {
+    pub fn begin(name: &'static str) -> Self {
+        Self {
+            name,
+            start: Instant::now(),
+        }
+    }
+}
+
+/// Timings of one rendered frame
+#[derive(Debug, Clone)]
+pub struct FrameStats {
+    /// Time from `begin_frame` to `end_frame`
+    pub frame_time: Duration,
+    /// Time since the previous frame started
+    pub interval: Duration,
+    /// Named phases in first-recorded order; repeated spans are summed
+    pub spans: Vec<(&'static str, Duration)>,
+}
+
+/// Collects per-frame phase timings for the performance overlay
+///
+/// Spans recorded between frames (e.g. event dispatch) count towards the next frame.
+pub struct Profiler {
+    frame_start: Option<Instant>,
+    last_frame_start: Option<Instant>,
+    pending: Vec<(&'static str, Duration)>,
+    history: VecDeque<FrameStats>,
+}
+
+impl Profiler {
+    pub fn new() -> Self {
+        Self {
+            frame_start: None,
+            last_frame_start: None,
+            pending: Vec::new(),
+            history: VecDeque::with_capacity(HISTORY_LEN),
+        }
+    }
+
+    pub fn begin_frame(&mut self) {
+        self.frame_start = Some(Instant::now());
+    }
+
+    /// Close the frame opened by `begin_frame` and move its spans into the history
+    pub fn end_frame(&mut self) {
+        let Some(start) = self.frame_start.take() else {
+            return;
+        };
+        let interval = self.last_frame_start
+            .map(|last| start.duration_since(last))
+            .unwrap_or_default();
+        self.last_frame_start = Some(start);
+
+        if self.history.len() == HISTORY_LEN {
+            self.history.pop_front();
+        }
+        self.history.push_back(FrameStats {
+            frame_time: start.elapsed(),
+            interval,
+            spans: std::mem::take(&mut self.pending),
+        });
+    }
+
+    pub fn record(&mut self, span: Span) {
+        let elapsed = span.start.elapsed();
+        match self.pending.iter_mut().find(|(name, _)| *name == span.name) {
+            Some((_, total)) => *total += elapsed,
+            None => self.pending.push((span.name, elapsed)),
+        }
+    }
+
+    pub fn history(&self) -> impl Iterator<Item = &FrameStats> {
+        self.history.iter()
+    }
+
+    pub fn last_frame(&self) -> Option<&FrameStats> {
+        self.history.back()
+    }
+
+    /// Frames per second over the recent history; idle gaps over a second are ignored
+    pub fn fps(&self) -> f32 {
+        let intervals: Vec<f32> = self.history
+            .iter()
+            .map(|frame| frame.interval.as_secs_f32())
+            .filter(|interval| *interval > 0.0 && *interval < 1.0)
+            .collect();
+        if intervals.is_empty() {
+            return 0.0;
+        }
+        intervals.len() as f32 / intervals.iter().sum::<f32>()
+    }
+
+    /// Average duration of a phase over the recent history, in milliseconds
+    pub fn average_ms(&self, name: &str) -> f32 {
+        if self.history.is_empty() {
+            return 0.0;
+        }
+        let total: Duration = self.history
+            .iter()
+            .flat_map(|frame| frame.spans.iter())
+            .filter(|(span_name, _)| *span_name == name)
+            .map(|(_, duration)| *duration)
+            .sum();
+        total.as_secs_f32() * 1000.0 / self.history.len() as f32
+    }
+
+    /// Phase names seen in the recent history, in first-recorded order
+    pub fn span_names(&self) -> Vec<&'static str> {
+        let mut names = Vec::new();
+        for (name, _) in self.history.iter().flat_map(|frame| frame.spans.iter()) {
+            if !names.contains(name) {
+                names.push(*name);
+            }
+        }
+        names
+    }
+
+    /// Drop collected frames, e.g. when the overlay is reopened
+    pub fn reset(&mut self) {
+        self.history.clear();
+        self.pending.clear();
+        self.last_frame_start = None;
+    }
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,57 @@
+//! Registration of a custom URL scheme, so links like `rabital://open?file=...` start the app
+//! The link is passed to the executable as its only argument
+
+#[cfg(target_os = "windows")]
+pub mod windows {
+    use windows::core::{HSTRING, PCWSTR};
+    use windows::Win32::System::Registry::{
+        RegCloseKey, RegCreateKeyExW, RegSetValueExW, HKEY, HKEY_CURRENT_USER, KEY_WRITE, REG_OPTION_NON_VOLATILE,
+        REG_SZ,
+    };
+
+    /// Point `scheme` links at the running executable for the current user
+    pub fn register_url_scheme(scheme: &str, description: &str) -> bool {
+        let Ok(exe) = std::env::current_exe() else {
+            return false;
+        };
+        let command = format!("\"{}\" \"%1\"", exe.display());
+        let root = format!("Software\\Classes\\{}", scheme);
+        set_value(&root, None, &format!("URL:{}", description))
+            && set_value(&root, Some("URL Protocol"), "")
+            && set_value(&format!("{}\\shell\\open\\command", root), None, &command)
+    }
+
+    fn set_value(key: &str, name: Option<&str>, value: &str) -> bool {
+        let mut hkey = HKEY::default();
+        let data: Vec<u8> = value.encode_utf16().chain(Some(0)).flat_map(u16::to_le_bytes).collect();
+        let name = name.map(HSTRING::from);
+        unsafe {
+            let created = RegCreateKeyExW(
+                HKEY_CURRENT_USER,
+                &HSTRING::from(key),
+                None,
+                PCWSTR::null(),
+                REG_OPTION_NON_VOLATILE,
+                KEY_WRITE,
+                None,
+                &mut hkey,
+                None,
+            );
+            if created.is_err() {
+                return false;
+            }
+            let name = name.as_ref().map_or(PCWSTR::null(), |name| PCWSTR(name.as_ptr()));
+            let set = RegSetValueExW(hkey, name, None, REG_SZ, Some(&data));
+            let _ = RegCloseKey(hkey);
+            set.is_ok()
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub mod windows {
+    /// Other platforms register schemes through their desktop entry or bundle metadata
+    pub fn register_url_scheme(_scheme: &str, _description: &str) -> bool {
+        false
+    }
+}
@@ -0,0 +1,165 @@
+use super::animation::animation_config;
+
+/// Curve an animation follows from its start to its end
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    Linear,
+    /// Fast start that slows into the target
+    #[default]
+    EaseOut,
+    /// Slow start and end
+    EaseInOut,
+}
+
+impl Easing {
+    /// Easing by its settings name: `linear`, `ease_out` or `ease_in_out`
+    pub fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "linear" => Some(Easing::Linear),
+            "ease_out" => Some(Easing::EaseOut),
+            "ease_in_out" => Some(Easing::EaseInOut),
+            _ => None,
+        }
+    }
+
+    /// Share of the distance covered after `t` of the duration, both in `0.0..=1.0`
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut if t < 0.5 => 4.0 * t * t * t,
+            Easing::EaseInOut => 1.0 - (2.0 - 2.0 * t).powi(3) / 2.0,
+        }
+    }
+}
+
+/// Scroll offset that glides to its target instead of jumping there
+///
+/// Duration and easing come from the global `AnimationConfig`, so every scrolling view
+/// moves alike; with smooth scrolling or animations off, targets are reached at once.
+/// Retargeting mid-way, e.g. on every wheel notch, starts a new glide from where the
+/// view is, and keeps accumulating onto the previous target.
+#[derive(Debug, Clone, Default)]
+pub struct SmoothScroll {
+    from: f32,
+    target: f32,
+    elapsed: f32,
+    animating: bool,
+    /// Clock time of the last `update`, unset until the first frame of a glide
+    last_frame: Option<f32>,
+}
+
+impl SmoothScroll {
+    /// Offset being headed for, or the resting offset
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+
+    pub fn is_animating(&self) -> bool {
+        self.animating
+    }
+
+    /// Glide from the `current` offset to `target`
+    pub fn scroll_to(&mut self, current: f32, target: f32) {
+        let config = animation_config();
+        let smooth = config.is_active() && config.smooth_scrolling && config.scroll_duration > 0.0;
+        if !smooth || (target - current).abs() < 0.5 {
+            self.jump(target);
+            return;
+        }
+        self.from = current;
+        self.target = target;
+        self.elapsed = 0.0;
+        self.animating = true;
+        self.last_frame = None;
+    }
+
+    /// Stop at `offset` right away, e.g. while the scrollbar thumb is dragged
+    pub fn jump(&mut self, offset: f32) {
+        self.from = offset;
+        self.target = offset;
+        self.animating = false;
+    }
+
+    /// Offset to show now, without advancing
+    pub fn offset(&self) -> f32 {
+        if !self.animating {
+            return self.target;
+        }
+        let config = animation_config();
+        let t = self.elapsed / config.scroll_duration;
+        self.from + (self.target - self.from) * config.scroll_easing.apply(t)
+    }
+
+    /// Advance to `now`, the seconds-since-start clock `update_animation` gets, and return the offset to show
+    pub fn update(&mut self, now: f32) -> f32 {
+        if !self.animating {
+            return self.target;
+        }
+        let config = animation_config();
+        // The glide starts counting at its first frame
+        let frame = self.last_frame.map_or(0.0, |last| (now - last).max(0.0));
+        self.last_frame = Some(now);
+        self.elapsed += frame * config.speed.max(0.01);
+        if self.elapsed >= config.scroll_duration || !config.is_active() {
+            self.jump(self.target);
+            return self.target;
+        }
+        self.offset()
+    }
+}
+
+/// Offset that shows `top..top + height` in a view `viewport` tall, keeping `padding` around it
+///
+/// Moves as little as possible from `offset`: not at all when the range is already in view.
+/// The padding shrinks when the view is too short for it, and a range taller than the view
+/// shows its top. Callers clamp the result to their scroll range.
+pub fn reveal_offset(offset: f32, viewport: f32, top: f32, height: f32, padding: f32) -> f32 {
+    let padding = padding.min(((viewport - height) / 2.0).max(0.0));
+    let (start, end) = (top - padding, top + height + padding);
+    if start < offset || end - start > viewport {
+        start
+    } else if end > offset + viewport {
+        end - viewport
+    } else {
+        offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reveal_moves_the_least() {
+        // Already visible with its padding
+        assert_eq!(reveal_offset(100.0, 200.0, 150.0, 20.0, 20.0), 100.0);
+        // Above the view: its padded top lines up with the top edge
+        assert_eq!(reveal_offset(100.0, 200.0, 90.0, 20.0, 20.0), 70.0);
+        // Below the view: its padded bottom lines up with the bottom edge
+        assert_eq!(reveal_offset(100.0, 200.0, 290.0, 20.0, 20.0), 130.0);
+        // Too tall for the view, padding included: show the top
+        assert_eq!(reveal_offset(0.0, 100.0, 300.0, 90.0, 20.0), 295.0);
+        assert_eq!(reveal_offset(0.0, 100.0, 300.0, 150.0, 20.0), 300.0);
+    }
+
+    #[test]
+    fn glides_to_the_target() {
+        let mut scroll = SmoothScroll::default();
+        scroll.scroll_to(0.0, 100.0);
+        assert!(scroll.is_animating());
+        // Frames come with the time since the app started
+        assert_eq!(scroll.update(10.0), 0.0);
+        let halfway = scroll.update(10.0 + animation_config().scroll_duration / 2.0);
+        assert!(halfway > 50.0 && halfway < 100.0, "ease out covers most of the way first: {}", halfway);
+        assert_eq!(scroll.offset(), halfway);
+        assert_eq!(scroll.update(11.0), 100.0);
+        assert!(!scroll.is_animating());
+
+        scroll.scroll_to(100.0, 100.2);
+        assert!(!scroll.is_animating());
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert_eq!(Easing::from_id("linear").map(|easing| easing.apply(0.25)), Some(0.25));
+    }
+}
@@ -0,0 +1,251 @@
+// File icon themes: which icon a file or folder gets, by name, extension or language
+
+use skia_safe::Color;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::components::{CodiconIcons, Icon, IconSize};
+use crate::theme::parse_hex_color;
+
+/// The icon picked for a file or folder
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FileIcon {
+    pub svg: &'static str,
+    /// Tint from the theme; None uses the caller's text color
+    pub color: Option<Color>,
+    /// Draw the SVG's own colors, as icon themes with colored artwork expect
+    pub full_color: bool,
+}
+
+impl FileIcon {
+    /// A monochrome codicon in the caller's text color
+    pub fn codicon(svg: &'static str) -> Self {
+        Self {
+            svg,
+            color: None,
+            full_color: false,
+        }
+    }
+
+    /// Icon widget at `(x, y)`, tinted with `fallback` unless the theme picked a color
+    pub fn to_icon(&self, x: f32, y: f32, size: IconSize, fallback: Color) -> Icon {
+        Icon::new(x, y, self.svg, size, self.color.unwrap_or(fallback)).full_color(self.full_color)
+    }
+}
+
+/// Icon theme file, in the same shape as VS Code icon themes
+///
+/// ```json
+/// {
+///   "iconDefinitions": {
+///     "_rust": { "codicon": "file-code", "fontColor": "#DEA584" },
+///     "_cargo": { "iconPath": "./icons/cargo.svg" }
+///   },
+///   "file": "_file",
+///   "fileExtensions": { "rs": "_rust" },
+///   "fileNames": { "cargo.toml": "_cargo" },
+///   "languageIds": { "rust": "_rust" }
+/// }
+/// ```
+/// `iconPath` SVGs are drawn in their own colors; `codicon` names a built-in icon, tinted with `fontColor`.
+/// Font-glyph definitions (`fontCharacter`) aren't supported and fall back to the default icons.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IconThemeDocument {
+    #[serde(default)]
+    icon_definitions: HashMap<String, IconDefinition>,
+    file: Option<String>,
+    folder: Option<String>,
+    folder_expanded: Option<String>,
+    #[serde(default)]
+    file_extensions: HashMap<String, String>,
+    #[serde(default)]
+    file_names: HashMap<String, String>,
+    #[serde(default)]
+    folder_names: HashMap<String, String>,
+    #[serde(default)]
+    folder_names_expanded: HashMap<String, String>,
+    #[serde(default)]
+    language_ids: HashMap<String, String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IconDefinition {
+    icon_path: Option<String>,
+    codicon: Option<String>,
+    font_color: Option<String>,
+}
+
+/// Maps file names, extensions and language ids to icons
+#[derive(Debug, Clone)]
+pub struct FileIconTheme {
+    file: FileIcon,
+    folder: FileIcon,
+    folder_expanded: FileIcon,
+    // Keys are lowercase; extensions may have several parts, e.g. "d.ts"
+    file_names: HashMap<String, FileIcon>,
+    file_extensions: HashMap<String, FileIcon>,
+    folder_names: HashMap<String, FileIcon>,
+    folder_names_expanded: HashMap<String, FileIcon>,
+    language_ids: HashMap<String, FileIcon>,
+}
+
+impl Default for FileIconTheme {
+    /// Plain file and folder codicons, used until a theme is loaded
+    fn default() -> Self {
+        Self {
+            file: FileIcon::codicon(CodiconIcons::FILE),
+            folder: FileIcon::codicon(CodiconIcons::FOLDER),
+            folder_expanded: FileIcon::codicon(CodiconIcons::FOLDER_OPENED),
+            file_names: HashMap::new(),
+            file_extensions: HashMap::new(),
+            folder_names: HashMap::new(),
+            folder_names_expanded: HashMap::new(),
+            language_ids: HashMap::new(),
+        }
+    }
+}
+
+impl FileIconTheme {
+    /// Read a theme file; `iconPath`s are relative to its folder
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = std::fs::read_to_string(path)?;
+        Self::parse(&content, path.parent().unwrap_or(Path::new("")))
+    }
+
+    /// Parse a theme; SVG files are read once and kept for the rest of the run
+    pub fn parse(content: &str, base_dir: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let document: IconThemeDocument = serde_json::from_str(content)?;
+
+        let mut svgs: HashMap<PathBuf, &'static str> = HashMap::new();
+        let mut definitions: HashMap<&str, FileIcon> = HashMap::new();
+        let mut skipped = 0;
+        for (id, definition) in &document.icon_definitions {
+            let color = definition.font_color.as_deref().and_then(parse_hex_color);
+            let icon = if let Some(name) = definition.codicon.as_deref() {
+                CodiconIcons::by_name(name).map(|svg| FileIcon { svg, color, full_color: false })
+            } else if let Some(icon_path) = definition.icon_path.as_deref() {
+                let path = base_dir.join(icon_path);
+                let svg = match svgs.get(&path) {
+                    Some(svg) => Some(*svg),
+                    None => std::fs::read_to_string(&path).ok().map(|svg| {
+                        let svg: &'static str = Box::leak(svg.into_boxed_str());
+                        svgs.insert(path, svg);
+                        svg
+                    }),
+                };
+                svg.map(|svg| FileIcon { svg, color, full_color: color.is_none() })
+            } else {
+                None
+            };
+            match icon {
+                Some(icon) => {
+                    definitions.insert(id.as_str(), icon);
+                }
+                None => skipped += 1,
+            }
+        }
+        if skipped > 0 {
+            eprintln!("Icon theme: skipped {} unsupported or missing icon(s)", skipped);
+        }
+
+        let resolve = |id: &Option<String>| id.as_deref().and_then(|id| definitions.get(id).copied());
+        let map = |entries: &HashMap<String, String>| -> HashMap<String, FileIcon> {
+            entries
+                .iter()
+                .filter_map(|(key, id)| Some((key.to_lowercase(), *definitions.get(id.as_str())?)))
+                .collect()
+        };
+        let fallback = Self::default();
+        let folder = resolve(&document.folder).unwrap_or(fallback.folder);
+        Ok(Self {
+            file: resolve(&document.file).unwrap_or(fallback.file),
+            folder,
+            folder_expanded: resolve(&document.folder_expanded).unwrap_or(if document.folder.is_some() { folder } else { fallback.folder_expanded }),
+            file_names: map(&document.file_names),
+            file_extensions: map(&document.file_extensions),
+            folder_names: map(&document.folder_names),
+            folder_names_expanded: map(&document.folder_names_expanded),
+            language_ids: map(&document.language_ids),
+        })
+    }
+
+    /// Icon for a file: exact name first, then the longest matching extension, then the language
+    pub fn file_icon(&self, name: &str, language: Option<&str>) -> FileIcon {
+        let name = name.to_lowercase();
+        if let Some(icon) = self.file_names.get(&name) {
+            return *icon;
+        }
+        // "index.d.ts" tries "d.ts" before "ts"
+        let extension = name.match_indices('.').map(|(dot, _)| &name[dot + 1..]).find_map(|extension| self.file_extensions.get(extension));
+        extension
+            .or_else(|| language.and_then(|language| self.language_ids.get(language)))
+            .copied()
+            .unwrap_or(self.file)
+    }
+
+    pub fn folder_icon(&self, name: &str, expanded: bool) -> FileIcon {
+        let name = name.to_lowercase();
+        let by_name = if expanded {
+            self.folder_names_expanded.get(&name).or_else(|| self.folder_names.get(&name))
+        } else {
+            self.folder_names.get(&name)
+        };
+        by_name.copied().unwrap_or(if expanded { self.folder_expanded } else { self.folder })
+    }
+}
+
+thread_local! {
+    static CURRENT_FILE_ICONS: RefCell<Rc<FileIconTheme>> = RefCell::new(Rc::new(FileIconTheme::default()));
+}
+
+/// Set the global file icon theme
+pub fn set_file_icon_theme(theme: FileIconTheme) {
+    CURRENT_FILE_ICONS.with(|icons| {
+        *icons.borrow_mut() = Rc::new(theme);
+    });
+}
+
+/// The active file icon theme
+pub fn file_icon_theme() -> Rc<FileIconTheme> {
+    CURRENT_FILE_ICONS.with(|icons| icons.borrow().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_order() {
+        let theme = FileIconTheme::parse(
+            r##"{
+                "iconDefinitions": {
+                    "_file": { "codicon": "file" },
+                    "_ts": { "codicon": "file-code", "fontColor": "#3178C6" },
+                    "_dts": { "codicon": "file-code", "fontColor": "#235A97" },
+                    "_cargo": { "codicon": "package" },
+                    "_rust": { "codicon": "file-code" },
+                    "_font": { "fontCharacter": "\\E001" }
+                },
+                "file": "_file",
+                "fileExtensions": { "ts": "_ts", "d.ts": "_dts", "woff": "_font" },
+                "fileNames": { "Cargo.toml": "_cargo" },
+                "languageIds": { "rust": "_rust" }
+            }"##,
+            Path::new(""),
+        )
+        .unwrap();
+
+        assert_eq!(theme.file_icon("cargo.TOML", None).svg, CodiconIcons::PACKAGE);
+        assert_eq!(theme.file_icon("index.d.ts", None).color, parse_hex_color("#235A97"));
+        assert_eq!(theme.file_icon("main.ts", None).color, parse_hex_color("#3178C6"));
+        assert_eq!(theme.file_icon("build", Some("rust")).svg, CodiconIcons::FILE_CODE);
+        // Unsupported definitions fall back to the default file icon
+        assert_eq!(theme.file_icon("font.woff", None).svg, CodiconIcons::FILE);
+        assert_eq!(theme.folder_icon("src", true).svg, CodiconIcons::FOLDER_OPENED);
+    }
+}
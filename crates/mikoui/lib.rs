@@ -3,12 +3,17 @@
 
 pub mod components;
 pub mod core;
+pub mod fileicons;
 pub mod theme;
 
 // Re-export commonly used items
 pub use components::*;
 pub use core::*;
+pub use fileicons::{file_icon_theme, set_file_icon_theme, FileIcon, FileIconTheme};
 pub use theme::{
-    current_theme, get_theme_color, lerp_color, set_theme, with_alpha, Size, Theme, ThemeColors,
-    ThemeMode, Variant,
+    best_contrast, color_to_hex, contrast_foreground, contrast_ratio, current_theme, darken, enter_window,
+    get_theme_color, lerp_color, lighten, mix, parse_hex_color, register_variant, relative_luminance,
+    set_surface_opacity, set_theme, set_window_theme, subscribe_theme, surface, surface_opacity, theme_snapshot,
+    unsubscribe_theme, variant_style, with_alpha, ColorRole, ColorShades, Hsl, Oklch, Size, Style, SyntaxColors, Theme,
+    ThemeChange, ThemeColors, ThemeMode, ThemeSubscriptionId, Variant, VariantColors, VariantStyle, WindowThemeGuard,
 };
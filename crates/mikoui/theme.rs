@@ -1,4 +1,4 @@
-use skia_safe::Color;
+use skia_safe::{Canvas, Color};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ThemeMode {
@@ -31,9 +31,77 @@ pub struct ThemeColors {
     pub border: Color,
     pub input: Color,
     pub ring: Color,
+    /// Code highlighting colors, kept apart from the UI tokens above
+    pub syntax: SyntaxColors,
 }
 
 impl ThemeColors {
+    /// Names of every color token, in declaration order
+    pub const FIELD_NAMES: [&'static str; 19] = [
+        "background",
+        "foreground",
+        "card",
+        "card_foreground",
+        "popover",
+        "popover_foreground",
+        "primary",
+        "primary_foreground",
+        "secondary",
+        "secondary_foreground",
+        "muted",
+        "muted_foreground",
+        "accent",
+        "accent_foreground",
+        "destructive",
+        "destructive_foreground",
+        "border",
+        "input",
+        "ring",
+    ];
+    
+    /// Color of the token called `name`
+    pub fn get(&self, name: &str) -> Option<Color> {
+        let mut colors = *self;
+        colors.field_mut(name).map(|color| *color)
+    }
+    
+    /// Replace the token called `name`; returns false for unknown names
+    pub fn set(&mut self, name: &str, color: Color) -> bool {
+        match self.field_mut(name) {
+            Some(field) => {
+                *field = color;
+                true
+            }
+            None => false,
+        }
+    }
+    
+    fn field_mut(&mut self, name: &str) -> Option<&mut Color> {
+        let field = match name {
+            "background" => &mut self.background,
+            "foreground" => &mut self.foreground,
+            "card" => &mut self.card,
+            "card_foreground" => &mut self.card_foreground,
+            "popover" => &mut self.popover,
+            "popover_foreground" => &mut self.popover_foreground,
+            "primary" => &mut self.primary,
+            "primary_foreground" => &mut self.primary_foreground,
+            "secondary" => &mut self.secondary,
+            "secondary_foreground" => &mut self.secondary_foreground,
+            "muted" => &mut self.muted,
+            "muted_foreground" => &mut self.muted_foreground,
+            "accent" => &mut self.accent,
+            "accent_foreground" => &mut self.accent_foreground,
+            "destructive" => &mut self.destructive,
+            "destructive_foreground" => &mut self.destructive_foreground,
+            "border" => &mut self.border,
+            "input" => &mut self.input,
+            "ring" => &mut self.ring,
+            _ => return None,
+        };
+        Some(field)
+    }
+    
     pub fn dark() -> Self {
         Self {
             background: Color::from_argb(255, 9, 9, 11), // zinc-950
@@ -55,6 +123,7 @@ impl ThemeColors {
             border: Color::from_argb(255, 39, 39, 42), // zinc-800
             input: Color::from_argb(255, 39, 39, 42), // zinc-800
             ring: Color::from_argb(255, 212, 212, 216), // zinc-300
+            syntax: SyntaxColors::dark(),
         }
     }
     
@@ -79,6 +148,159 @@ impl ThemeColors {
             border: Color::from_argb(255, 228, 228, 231), // zinc-200
             input: Color::from_argb(255, 228, 228, 231), // zinc-200
             ring: Color::from_argb(255, 24, 24, 27), // zinc-900
+            syntax: SyntaxColors::light(),
+        }
+    }
+    
+    /// Black background with white text and borders; every text pair is at least 7:1 (WCAG AAA)
+    pub fn high_contrast() -> Self {
+        Self {
+            background: Color::from_argb(255, 0, 0, 0), // black
+            foreground: Color::from_argb(255, 255, 255, 255), // white
+            card: Color::from_argb(255, 0, 0, 0), // black
+            card_foreground: Color::from_argb(255, 255, 255, 255), // white
+            popover: Color::from_argb(255, 0, 0, 0), // black
+            popover_foreground: Color::from_argb(255, 255, 255, 255), // white
+            primary: Color::from_argb(255, 255, 255, 0), // yellow
+            primary_foreground: Color::from_argb(255, 0, 0, 0), // black
+            secondary: Color::from_argb(255, 0, 0, 0), // black, set apart by its border
+            secondary_foreground: Color::from_argb(255, 255, 255, 255), // white
+            muted: Color::from_argb(255, 26, 26, 26), // near-black
+            muted_foreground: Color::from_argb(255, 212, 212, 212), // light gray
+            accent: Color::from_argb(255, 26, 235, 255), // cyan
+            accent_foreground: Color::from_argb(255, 0, 0, 0), // black
+            destructive: Color::from_argb(255, 255, 128, 128), // light red
+            destructive_foreground: Color::from_argb(255, 0, 0, 0), // black
+            border: Color::from_argb(255, 255, 255, 255), // white
+            input: Color::from_argb(255, 255, 255, 255), // white
+            ring: Color::from_argb(255, 255, 255, 0), // yellow
+            syntax: SyntaxColors::high_contrast(),
+        }
+    }
+}
+
+/// Colors for each kind of highlighted code token
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SyntaxColors {
+    pub keyword: Color,
+    pub function: Color,
+    /// Called `type` in theme files
+    pub type_name: Color,
+    pub string: Color,
+    pub number: Color,
+    pub comment: Color,
+    pub operator: Color,
+    pub punctuation: Color,
+    pub variable: Color,
+    pub property: Color,
+    pub parameter: Color,
+    pub constant: Color,
+}
+
+impl SyntaxColors {
+    /// Names of every syntax color, in declaration order
+    pub const FIELD_NAMES: [&'static str; 12] = [
+        "keyword",
+        "function",
+        "type",
+        "string",
+        "number",
+        "comment",
+        "operator",
+        "punctuation",
+        "variable",
+        "property",
+        "parameter",
+        "constant",
+    ];
+    
+    /// Color of the syntax token called `name`
+    pub fn get(&self, name: &str) -> Option<Color> {
+        let mut colors = *self;
+        colors.field_mut(name).map(|color| *color)
+    }
+    
+    /// Replace the syntax token called `name`; returns false for unknown names
+    pub fn set(&mut self, name: &str, color: Color) -> bool {
+        match self.field_mut(name) {
+            Some(field) => {
+                *field = color;
+                true
+            }
+            None => false,
+        }
+    }
+    
+    fn field_mut(&mut self, name: &str) -> Option<&mut Color> {
+        let field = match name {
+            "keyword" => &mut self.keyword,
+            "function" => &mut self.function,
+            "type" => &mut self.type_name,
+            "string" => &mut self.string,
+            "number" => &mut self.number,
+            "comment" => &mut self.comment,
+            "operator" => &mut self.operator,
+            "punctuation" => &mut self.punctuation,
+            "variable" => &mut self.variable,
+            "property" => &mut self.property,
+            "parameter" => &mut self.parameter,
+            "constant" => &mut self.constant,
+            _ => return None,
+        };
+        Some(field)
+    }
+    
+    /// VS Code Dark+ colors
+    pub fn dark() -> Self {
+        Self {
+            keyword: Color::from_rgb(197, 134, 192), // purple
+            function: Color::from_rgb(220, 220, 170), // yellow
+            type_name: Color::from_rgb(78, 201, 176), // cyan
+            string: Color::from_rgb(206, 145, 120), // orange
+            number: Color::from_rgb(181, 206, 168), // light green
+            comment: Color::from_rgb(106, 153, 85), // green
+            operator: Color::from_rgb(180, 180, 180), // light gray
+            punctuation: Color::from_rgb(180, 180, 180), // light gray
+            variable: Color::from_rgb(156, 220, 254), // light blue
+            property: Color::from_rgb(156, 220, 254), // light blue
+            parameter: Color::from_rgb(156, 220, 254), // light blue
+            constant: Color::from_rgb(79, 193, 255), // blue
+        }
+    }
+    
+    /// VS Code Light+ colors
+    pub fn light() -> Self {
+        Self {
+            keyword: Color::from_rgb(175, 0, 219), // purple
+            function: Color::from_rgb(121, 94, 38), // brown
+            type_name: Color::from_rgb(38, 127, 153), // teal
+            string: Color::from_rgb(163, 21, 21), // dark red
+            number: Color::from_rgb(9, 134, 88), // green
+            comment: Color::from_rgb(0, 128, 0), // green
+            operator: Color::from_rgb(64, 64, 64), // dark gray
+            punctuation: Color::from_rgb(64, 64, 64), // dark gray
+            variable: Color::from_rgb(0, 16, 128), // navy
+            property: Color::from_rgb(0, 16, 128), // navy
+            parameter: Color::from_rgb(0, 16, 128), // navy
+            constant: Color::from_rgb(0, 112, 193), // blue
+        }
+    }
+    
+    /// Saturated colors that keep at least 7:1 contrast on black
+    pub fn high_contrast() -> Self {
+        Self {
+            keyword: Color::from_rgb(255, 153, 255), // pink
+            function: Color::from_rgb(255, 255, 128), // yellow
+            type_name: Color::from_rgb(128, 255, 234), // aqua
+            string: Color::from_rgb(255, 190, 140), // peach
+            number: Color::from_rgb(190, 255, 160), // lime
+            comment: Color::from_rgb(140, 230, 120), // green
+            operator: Color::from_rgb(255, 255, 255), // white
+            punctuation: Color::from_rgb(255, 255, 255), // white
+            variable: Color::from_rgb(200, 235, 255), // ice blue
+            property: Color::from_rgb(200, 235, 255), // ice blue
+            parameter: Color::from_rgb(200, 235, 255), // ice blue
+            constant: Color::from_rgb(140, 210, 255), // sky blue
         }
     }
 }
@@ -178,20 +400,286 @@ pub enum Variant {
     Secondary,
     Ghost,
     Link,
+    /// Style registered with `register_variant`; unregistered names draw as `Default`
+    Custom(&'static str),
+}
+
+impl Variant {
+    /// Resolved colors of a registered custom variant, `None` for the built-in ones
+    pub fn custom_colors(&self, colors: &ThemeColors) -> Option<VariantColors> {
+        match self {
+            Variant::Custom(name) => variant_style(name).map(|style| style.resolve(colors)),
+            _ => None,
+        }
+    }
+}
+
+/// Where a variant takes one of its colors from
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorRole {
+    /// A token of the active palette by `ThemeColors` field name, so it follows theme switches
+    Token(&'static str),
+    /// A token with its alpha replaced
+    TokenAlpha(&'static str, u8),
+    Fixed(Color),
+}
+
+impl ColorRole {
+    /// Unknown token names resolve to transparent
+    pub fn resolve(&self, colors: &ThemeColors) -> Color {
+        match *self {
+            ColorRole::Token(name) => colors.get(name).unwrap_or(Color::TRANSPARENT),
+            ColorRole::TokenAlpha(name, alpha) => colors.get(name).map_or(Color::TRANSPARENT, |c| with_alpha(c, alpha)),
+            ColorRole::Fixed(color) => color,
+        }
+    }
+}
+
+/// Color roles of a custom variant
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VariantStyle {
+    pub background: ColorRole,
+    pub foreground: ColorRole,
+    pub border: Option<ColorRole>,
+    pub hover: ColorRole,
+}
+
+impl VariantStyle {
+    /// Solid style without a border; hovering keeps the background until `hover` is set
+    pub fn new(background: ColorRole, foreground: ColorRole) -> Self {
+        Self {
+            background,
+            foreground,
+            border: None,
+            hover: background,
+        }
+    }
+
+    pub fn border(mut self, border: ColorRole) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    pub fn hover(mut self, hover: ColorRole) -> Self {
+        self.hover = hover;
+        self
+    }
+
+    pub fn resolve(&self, colors: &ThemeColors) -> VariantColors {
+        VariantColors {
+            background: self.background.resolve(colors),
+            foreground: self.foreground.resolve(colors),
+            border: self.border.map(|border| border.resolve(colors)),
+            hover: self.hover.resolve(colors),
+        }
+    }
 }
 
-/// Global theme state using thread-local storage
-use std::cell::RefCell;
+/// A `VariantStyle` resolved against the active palette
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VariantColors {
+    pub background: Color,
+    pub foreground: Color,
+    pub border: Option<Color>,
+    pub hover: Color,
+}
+
+/// Local overrides of theme defaults, accepted by widgets through `.style(..)`
+///
+/// Unset fields keep the component's own look; components ignore fields that
+/// do not apply to them (e.g. `padding` on a `Card`).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Style {
+    pub background: Option<Color>,
+    pub foreground: Option<Color>,
+    pub border_color: Option<Color>,
+    pub border_width: Option<f32>,
+    pub radius: Option<f32>,
+    /// Horizontal content padding
+    pub padding: Option<f32>,
+    /// Opacity of the whole widget in `0.0..=1.0`
+    pub opacity: Option<f32>,
+}
+
+impl Style {
+    /// Combine two styles; fields set in `other` win
+    pub fn merge(self, other: Style) -> Style {
+        Style {
+            background: other.background.or(self.background),
+            foreground: other.foreground.or(self.foreground),
+            border_color: other.border_color.or(self.border_color),
+            border_width: other.border_width.or(self.border_width),
+            radius: other.radius.or(self.radius),
+            padding: other.padding.or(self.padding),
+            opacity: other.opacity.or(self.opacity),
+        }
+    }
+
+    /// True when a border override is present, so borderless components should draw one
+    pub fn has_border(&self) -> bool {
+        self.border_color.is_some() || self.border_width.is_some_and(|width| width > 0.0)
+    }
+
+    /// Start a translucent layer when `opacity` is below 1.0
+    ///
+    /// Returns true if a layer was pushed; the caller restores the canvas after drawing.
+    pub fn push_opacity(&self, canvas: &Canvas) -> bool {
+        let opacity = self.opacity.unwrap_or(1.0).clamp(0.0, 1.0);
+        if opacity >= 1.0 {
+            return false;
+        }
+        canvas.save_layer_alpha_f(None, opacity);
+        true
+    }
+}
+
+/// Global theme state, shared by every window and thread
+///
+/// The palette lives behind an `Arc`, so a reader on any thread takes a cheap snapshot
+/// instead of copying it. A window can override the palette: while its guard from
+/// `enter_window` is alive, lookups on that thread resolve to the window's theme.
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU8, AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use winit::window::WindowId;
+
+struct ThemeStore {
+    global: Arc<ThemeColors>,
+    windows: HashMap<WindowId, Arc<ThemeColors>>,
+}
+
+/// What `set_theme` or `set_window_theme` changed, passed to theme subscribers
+#[derive(Clone)]
+pub struct ThemeChange {
+    /// The window whose override changed, or `None` for the global theme
+    pub window: Option<WindowId>,
+    /// The palette now in effect there
+    pub colors: Arc<ThemeColors>,
+}
+
+/// Handle returned by `subscribe_theme`, used to unsubscribe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ThemeSubscriptionId(u64);
+
+type ThemeSubscriber = Arc<dyn Fn(&ThemeChange) + Send + Sync>;
+
+static THEME_STORE: LazyLock<RwLock<ThemeStore>> = LazyLock::new(|| {
+    RwLock::new(ThemeStore {
+        global: Arc::new(ThemeColors::dark()),
+        windows: HashMap::new(),
+    })
+});
+static THEME_SUBSCRIBERS: Mutex<Vec<(ThemeSubscriptionId, ThemeSubscriber)>> = Mutex::new(Vec::new());
+static NEXT_SUBSCRIPTION: AtomicU64 = AtomicU64::new(0);
+static SURFACE_OPACITY: AtomicU8 = AtomicU8::new(255);
+static CUSTOM_VARIANTS: LazyLock<RwLock<HashMap<&'static str, VariantStyle>>> = LazyLock::new(Default::default);
 
 thread_local! {
-    static CURRENT_THEME: RefCell<ThemeColors> = RefCell::new(ThemeColors::dark());
+    static ACTIVE_WINDOW: Cell<Option<WindowId>> = const { Cell::new(None) };
+}
+
+// Writers only swap whole `Arc`s, so a lock poisoned by a panicking thread still holds a valid palette
+fn read_store() -> RwLockReadGuard<'static, ThemeStore> {
+    THEME_STORE.read().unwrap_or_else(PoisonError::into_inner)
 }
 
-/// Set the global theme
+fn write_store() -> RwLockWriteGuard<'static, ThemeStore> {
+    THEME_STORE.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// The palette lookups on this thread resolve to: the active window's override, else the global theme
+fn resolved(store: &ThemeStore) -> &Arc<ThemeColors> {
+    ACTIVE_WINDOW
+        .with(Cell::get)
+        .and_then(|window| store.windows.get(&window))
+        .unwrap_or(&store.global)
+}
+
+fn notify_theme_change(change: ThemeChange) {
+    // Call outside the lock, so a subscriber may subscribe, unsubscribe or read the theme
+    let subscribers: Vec<ThemeSubscriber> = THEME_SUBSCRIBERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .map(|(_, subscriber)| subscriber.clone())
+        .collect();
+    for subscriber in subscribers {
+        subscriber(&change);
+    }
+}
+
+/// Register (or replace) the style used by `Variant::Custom(name)`
+pub fn register_variant(name: &'static str, style: VariantStyle) {
+    CUSTOM_VARIANTS.write().unwrap_or_else(PoisonError::into_inner).insert(name, style);
+}
+
+/// Style registered for a custom variant name
+pub fn variant_style(name: &str) -> Option<VariantStyle> {
+    CUSTOM_VARIANTS.read().unwrap_or_else(PoisonError::into_inner).get(name).copied()
+}
+
+/// Set the global theme, used by every window without its own override
 pub fn set_theme(theme: ThemeColors) {
-    CURRENT_THEME.with(|t| {
-        *t.borrow_mut() = theme;
-    });
+    let colors = Arc::new(theme);
+    write_store().global = colors.clone();
+    notify_theme_change(ThemeChange { window: None, colors });
+}
+
+/// Give `window` its own theme, or go back to the global one with `None`
+pub fn set_window_theme(window: WindowId, theme: Option<ThemeColors>) {
+    let mut store = write_store();
+    let colors = match theme {
+        Some(theme) => {
+            let colors = Arc::new(theme);
+            store.windows.insert(window, colors.clone());
+            colors
+        }
+        None => {
+            store.windows.remove(&window);
+            store.global.clone()
+        }
+    };
+    drop(store);
+    notify_theme_change(ThemeChange { window: Some(window), colors });
+}
+
+/// Resolve theme lookups on this thread against `window` until the guard is dropped
+///
+/// Wrap a window's drawing in this, so components pick up that window's override.
+pub fn enter_window(window: WindowId) -> WindowThemeGuard {
+    let previous = ACTIVE_WINDOW.with(|active| active.replace(Some(window)));
+    WindowThemeGuard { previous }
+}
+
+/// Returned by `enter_window`; restores the previously active window when dropped
+#[must_use = "the window's theme only applies while the guard is alive"]
+pub struct WindowThemeGuard {
+    previous: Option<WindowId>,
+}
+
+impl Drop for WindowThemeGuard {
+    fn drop(&mut self) {
+        ACTIVE_WINDOW.with(|active| active.set(self.previous));
+    }
+}
+
+/// Call `subscriber` after every theme change, from the thread that made it
+pub fn subscribe_theme(subscriber: impl Fn(&ThemeChange) + Send + Sync + 'static) -> ThemeSubscriptionId {
+    let id = ThemeSubscriptionId(NEXT_SUBSCRIPTION.fetch_add(1, Ordering::Relaxed));
+    THEME_SUBSCRIBERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push((id, Arc::new(subscriber)));
+    id
+}
+
+/// Stop calling a subscriber; returns false if it was already gone
+pub fn unsubscribe_theme(id: ThemeSubscriptionId) -> bool {
+    let mut subscribers = THEME_SUBSCRIBERS.lock().unwrap_or_else(PoisonError::into_inner);
+    let before = subscribers.len();
+    subscribers.retain(|(subscription, _)| *subscription != id);
+    subscribers.len() != before
 }
 
 /// Get a color from the current theme
@@ -199,12 +687,37 @@ pub fn get_theme_color<F, R>(f: F) -> R
 where
     F: FnOnce(&ThemeColors) -> R,
 {
-    CURRENT_THEME.with(|t| f(&t.borrow()))
+    // Run `f` on a snapshot rather than under the lock, so it may look up the theme itself
+    f(&theme_snapshot())
 }
 
 /// Convenience helper to copy the active theme palette
 pub fn current_theme() -> ThemeColors {
-    CURRENT_THEME.with(|t| *t.borrow())
+    get_theme_color(|theme| *theme)
+}
+
+/// Shared handle to the active palette, for holding on to it (e.g. on a background thread)
+pub fn theme_snapshot() -> Arc<ThemeColors> {
+    resolved(&read_store()).clone()
+}
+
+/// Set how opaque window surfaces are; below 255 while a window material shows through
+pub fn set_surface_opacity(opacity: u8) {
+    SURFACE_OPACITY.store(opacity, Ordering::Relaxed);
+}
+
+/// Opacity of window surfaces, 255 when no window material is active
+pub fn surface_opacity() -> u8 {
+    SURFACE_OPACITY.load(Ordering::Relaxed)
+}
+
+/// `color` for a panel or window background, translucent while a window material is active
+pub fn surface(color: Color) -> Color {
+    let opacity = surface_opacity();
+    if opacity == 255 {
+        return color;
+    }
+    with_alpha(color, (color.a() as u16 * opacity as u16 / 255) as u8)
 }
 
 /// Color interpolation utility
@@ -222,3 +735,304 @@ pub fn lerp_color(color1: Color, color2: Color, t: f32) -> Color {
 pub fn with_alpha(color: Color, alpha: u8) -> Color {
     Color::from_argb(alpha, color.r(), color.g(), color.b())
 }
+
+/// Format a color as `#RRGGBB`, or `#RRGGBBAA` when it is translucent
+pub fn color_to_hex(color: Color) -> String {
+    if color.a() == 255 {
+        format!("#{:02X}{:02X}{:02X}", color.r(), color.g(), color.b())
+    } else {
+        format!("#{:02X}{:02X}{:02X}{:02X}", color.r(), color.g(), color.b(), color.a())
+    }
+}
+
+/// Parse `#RGB`, `#RRGGBB` or `#RRGGBBAA`; the leading `#` is optional
+pub fn parse_hex_color(text: &str) -> Option<Color> {
+    let hex = text.trim();
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    match hex.len() {
+        3 => {
+            let short = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).ok().map(|v| v * 17);
+            Some(Color::from_argb(255, short(0)?, short(1)?, short(2)?))
+        }
+        6 => Some(Color::from_argb(255, channel(0)?, channel(2)?, channel(4)?)),
+        8 => Some(Color::from_argb(channel(6)?, channel(0)?, channel(2)?, channel(4)?)),
+        _ => None,
+    }
+}
+
+/// Hue/saturation/lightness; `h` in degrees, `s` and `l` in `0.0..=1.0`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+    pub alpha: u8,
+}
+
+impl Hsl {
+    pub fn from_color(color: Color) -> Self {
+        let (r, g, b) = (color.r() as f32 / 255.0, color.g() as f32 / 255.0, color.b() as f32 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return Self { h: 0.0, s: 0.0, l, alpha: color.a() };
+        }
+
+        let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        Self { h, s, l, alpha: color.a() }
+    }
+
+    pub fn to_color(self) -> Color {
+        let s = self.s.clamp(0.0, 1.0);
+        let l = self.l.clamp(0.0, 1.0);
+        let h = self.h.rem_euclid(360.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = chroma * (1.0 - ((h / 60.0).rem_euclid(2.0) - 1.0).abs());
+        let (r, g, b) = match (h / 60.0) as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = l - chroma / 2.0;
+        let channel = |v: f32| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Color::from_argb(self.alpha, channel(r), channel(g), channel(b))
+    }
+}
+
+/// Perceptual lightness/chroma/hue (OKLCH); `l` in `0.0..=1.0`, `h` in degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+    pub alpha: u8,
+}
+
+impl Oklch {
+    pub fn from_color(color: Color) -> Self {
+        let (l, a, b) = srgb_to_oklab(color);
+        Self {
+            l,
+            c: (a * a + b * b).sqrt(),
+            h: b.atan2(a).to_degrees().rem_euclid(360.0),
+            alpha: color.a(),
+        }
+    }
+
+    /// Convert back to sRGB; out-of-gamut channels are clipped
+    pub fn to_color(self) -> Color {
+        let hue = self.h.to_radians();
+        let c = self.c.max(0.0);
+        oklab_to_srgb(self.l.clamp(0.0, 1.0), c * hue.cos(), c * hue.sin(), self.alpha)
+    }
+}
+
+fn srgb_to_linear(channel: u8) -> f32 {
+    let c = channel as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(channel: f32) -> u8 {
+    let c = channel.clamp(0.0, 1.0);
+    let c = if c <= 0.0031308 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 };
+    (c * 255.0).round() as u8
+}
+
+fn srgb_to_oklab(color: Color) -> (f32, f32, f32) {
+    let (r, g, b) = (srgb_to_linear(color.r()), srgb_to_linear(color.g()), srgb_to_linear(color.b()));
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+    (
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    )
+}
+
+fn oklab_to_srgb(l: f32, a: f32, b: f32, alpha: u8) -> Color {
+    let l_ = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m_ = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s_ = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+    Color::from_argb(
+        alpha,
+        linear_to_srgb(4.076_741_7 * l_ - 3.307_711_6 * m_ + 0.230_969_94 * s_),
+        linear_to_srgb(-1.268_438 * l_ + 2.609_757_4 * m_ - 0.341_319_38 * s_),
+        linear_to_srgb(-0.004_196_086_3 * l_ - 0.703_418_6 * m_ + 1.707_614_7 * s_),
+    )
+}
+
+/// Raise perceptual (OKLCH) lightness by `amount` in `0.0..=1.0`
+pub fn lighten(color: Color, amount: f32) -> Color {
+    let mut lch = Oklch::from_color(color);
+    lch.l = (lch.l + amount).clamp(0.0, 1.0);
+    lch.to_color()
+}
+
+/// Lower perceptual (OKLCH) lightness by `amount` in `0.0..=1.0`
+pub fn darken(color: Color, amount: f32) -> Color {
+    lighten(color, -amount)
+}
+
+/// Blend two colors in OKLab, which avoids the muddy midpoints of `lerp_color`
+pub fn mix(color1: Color, color2: Color, t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    let (l1, a1, b1) = srgb_to_oklab(color1);
+    let (l2, a2, b2) = srgb_to_oklab(color2);
+    let alpha = color1.a() as f32 + (color2.a() as f32 - color1.a() as f32) * t;
+    oklab_to_srgb(
+        l1 + (l2 - l1) * t,
+        a1 + (a2 - a1) * t,
+        b1 + (b2 - b1) * t,
+        alpha.round() as u8,
+    )
+}
+
+/// WCAG relative luminance in `0.0..=1.0`; alpha is ignored
+pub fn relative_luminance(color: Color) -> f32 {
+    0.2126 * srgb_to_linear(color.r()) + 0.7152 * srgb_to_linear(color.g()) + 0.0722 * srgb_to_linear(color.b())
+}
+
+/// WCAG contrast ratio between two colors, from 1.0 to 21.0
+pub fn contrast_ratio(color1: Color, color2: Color) -> f32 {
+    let l1 = relative_luminance(color1);
+    let l2 = relative_luminance(color2);
+    (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+}
+
+/// The candidate with the highest contrast against `background`
+pub fn best_contrast(background: Color, candidates: &[Color]) -> Option<Color> {
+    candidates.iter().copied().max_by(|a, b| {
+        contrast_ratio(background, *a).total_cmp(&contrast_ratio(background, *b))
+    })
+}
+
+/// Readable text color for an arbitrary background: white or near-black
+pub fn contrast_foreground(background: Color) -> Color {
+    let dark = Color::from_rgb(10, 10, 10);
+    best_contrast(background, &[Color::WHITE, dark]).unwrap_or(dark)
+}
+
+/// Fill colors for a custom-colored control, derived from one base color
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorShades {
+    pub base: Color,
+    pub hover: Color,
+    pub active: Color,
+    pub foreground: Color,
+}
+
+impl ColorShades {
+    /// Light bases darken on interaction and dark bases lighten, so the change stays visible
+    pub fn from_base(base: Color) -> Self {
+        let shift = if Oklch::from_color(base).l > 0.5 { -1.0 } else { 1.0 };
+        Self {
+            base,
+            hover: lighten(base, 0.06 * shift),
+            active: lighten(base, 0.12 * shift),
+            foreground: contrast_foreground(base),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    // The store is global and tests run in parallel, so each test keeps to windows of its own
+    // and only ever sets the global theme to the default dark palette.
+
+    #[test]
+    fn entered_windows_use_their_override_or_fall_back_to_the_global_theme() {
+        let (window, plain) = (WindowId::from(0x7e57_0001), WindowId::from(0x7e57_0002));
+        let global = ThemeColors::dark().background;
+        let light = ThemeColors::light().background;
+        set_window_theme(window, Some(ThemeColors::light()));
+        assert_eq!(current_theme().background, global, "no window entered yet");
+        {
+            let _guard = enter_window(window);
+            assert_eq!(current_theme().background, light);
+            {
+                let _inner = enter_window(plain);
+                assert_eq!(current_theme().background, global, "a window without an override");
+            }
+            assert_eq!(current_theme().background, light, "dropping the inner guard goes back to the outer window");
+            // The active window is per thread
+            assert_eq!(std::thread::spawn(|| current_theme().background).join().unwrap(), global);
+        }
+        assert_eq!(current_theme().background, global);
+
+        set_window_theme(window, None);
+        let _guard = enter_window(window);
+        assert_eq!(current_theme().background, global, "a cleared override");
+    }
+
+    #[test]
+    fn subscribers_hear_each_change_until_they_unsubscribe() {
+        let window = WindowId::from(0x7e57_0003);
+        let (sender, changes) = mpsc::channel();
+        let id = subscribe_theme(move |change| {
+            let _ = sender.send((change.window, change.colors.background));
+        });
+        set_window_theme(window, Some(ThemeColors::high_contrast()));
+        set_theme(ThemeColors::dark());
+        set_window_theme(window, None);
+        assert!(unsubscribe_theme(id));
+        assert!(!unsubscribe_theme(id));
+        set_theme(ThemeColors::dark());
+
+        let (contrast, dark) = (ThemeColors::high_contrast().background, ThemeColors::dark().background);
+        let heard: Vec<_> = changes.try_iter().filter(|(changed, _)| changed.is_none_or(|changed| changed == window)).collect();
+        assert_eq!(heard, vec![(Some(window), contrast), (None, dark), (Some(window), dark)]);
+    }
+
+    #[test]
+    fn other_threads_read_whole_palettes_while_they_change() {
+        let window = WindowId::from(0x7e57_0004);
+        set_window_theme(window, Some(ThemeColors::light()));
+        let held = {
+            let _guard = enter_window(window);
+            theme_snapshot()
+        };
+        let palettes = [ThemeColors::light(), ThemeColors::high_contrast()].map(|theme| (theme.background, theme.foreground));
+
+        let reader = std::thread::spawn(move || {
+            let _guard = enter_window(window);
+            (0..2000).all(|_| {
+                let pair = get_theme_color(|theme| {
+                    // A lookup inside a lookup doesn't deadlock on the store's lock
+                    let _ = current_theme();
+                    (theme.background, theme.foreground)
+                });
+                palettes.contains(&pair)
+            })
+        });
+        for i in 0..200 {
+            set_window_theme(window, Some(if i % 2 == 0 { ThemeColors::high_contrast() } else { ThemeColors::light() }));
+        }
+        assert!(reader.join().unwrap(), "every read saw one palette or the other");
+        assert_eq!(held.background, ThemeColors::light().background, "a snapshot keeps the palette it was taken from");
+        set_window_theme(window, None);
+    }
+}
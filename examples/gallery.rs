@@ -0,0 +1,457 @@
+//! Component gallery: every mikoui component next to editors for its properties
+//!
+//! Pick a component on the left; the controls on the right are built from its `Widget::props`
+//! and change it live through `Widget::set_prop`. Run with `cargo run -p mikoui --example gallery`.
+
+use std::num::NonZeroU32;
+use std::rc::Rc;
+use std::time::Instant;
+
+use mikoui::components::{
+    Alert, Avatar, Badge, Button, Card, Checkbox, CodiconIcons, Dropdown, Input, Label, ProgressBar, PropValue,
+    Separator, Skeleton, Slider, Toggle, Widget,
+};
+use mikoui::{copy_to_buffer, current_theme, FontManager, Theme};
+use skia_safe::{Canvas, Paint, Rect};
+use softbuffer::{Context, Surface};
+use winit::application::ApplicationHandler;
+use winit::event::{ElementState, MouseButton, WindowEvent};
+use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
+use winit::keyboard::{Key, NamedKey};
+use winit::window::{Window, WindowId};
+
+const WINDOW_WIDTH: f32 = 1040.0;
+const WINDOW_HEIGHT: f32 = 680.0;
+const LIST_WIDTH: f32 = 180.0;
+const LIST_ROW_HEIGHT: f32 = 28.0;
+const PADDING: f32 = 24.0;
+const PREVIEW_X: f32 = LIST_WIDTH + PADDING;
+const PREVIEW_Y: f32 = 64.0;
+/// The property editors start here, right of the preview
+const EDITORS_X: f32 = 660.0;
+const EDITOR_NAME_WIDTH: f32 = 110.0;
+const EDITOR_WIDTH: f32 = 220.0;
+const EDITOR_ROW_HEIGHT: f32 = 52.0;
+
+/// Control that edits one property, picked by the kind of its value
+enum EditorControl {
+    Bool(Checkbox),
+    /// The slider runs 0..1 over `min..=max`
+    Number { slider: Slider, min: f32, max: f32 },
+    Choice { dropdown: Dropdown, options: &'static [&'static str] },
+    Text(Input),
+}
+
+struct Editor {
+    name: &'static str,
+    control: EditorControl,
+}
+
+impl Editor {
+    fn new(name: &'static str, value: &PropValue, x: f32, y: f32) -> Self {
+        let control = match *value {
+            PropValue::Bool(checked) => {
+                let mut checkbox = Checkbox::new(x, y + 14.0, "");
+                checkbox.set_checked(checked);
+                EditorControl::Bool(checkbox)
+            }
+            PropValue::Number { value, min, max } => {
+                let fraction = if max > min { (value - min) / (max - min) } else { 0.0 };
+                EditorControl::Number { slider: Slider::new(x, y + 2.0, EDITOR_WIDTH, "", fraction), min, max }
+            }
+            PropValue::Choice { selected, options } => {
+                let labels = options.iter().map(|option| option.to_string()).collect();
+                let mut dropdown = Dropdown::new(x, y + 8.0, EDITOR_WIDTH, name, labels);
+                dropdown.set_selected(selected);
+                EditorControl::Choice { dropdown, options }
+            }
+            PropValue::Text(ref text) => {
+                let mut input = Input::new(x, y + 8.0, EDITOR_WIDTH, name);
+                input.set_text(text.clone());
+                EditorControl::Text(input)
+            }
+        };
+        Self { name, control }
+    }
+
+    /// The value the control currently shows
+    fn value(&self) -> PropValue {
+        match &self.control {
+            EditorControl::Bool(checkbox) => PropValue::Bool(checkbox.is_checked()),
+            EditorControl::Number { slider, min, max } => {
+                PropValue::Number { value: min + slider.value() * (max - min), min: *min, max: *max }
+            }
+            EditorControl::Choice { dropdown, options } => {
+                PropValue::Choice { selected: dropdown.selected_index(), options }
+            }
+            EditorControl::Text(input) => PropValue::Text(input.text().to_string()),
+        }
+    }
+
+    /// Show `value`, after the widget changed it itself or clamped what was set
+    fn show(&mut self, value: &PropValue) {
+        match (&mut self.control, value) {
+            (EditorControl::Bool(checkbox), PropValue::Bool(checked)) => checkbox.set_checked(*checked),
+            (EditorControl::Number { slider, min, max }, PropValue::Number { value, .. }) => {
+                if *max > *min {
+                    slider.set_value((value - *min) / (*max - *min));
+                }
+            }
+            (EditorControl::Choice { dropdown, .. }, PropValue::Choice { selected, .. }) => dropdown.set_selected(*selected),
+            // Typing stays where it is; the widget may normalize the text, e.g. avatar initials
+            (EditorControl::Text(input), PropValue::Text(text)) if !input.is_focused() => input.set_text(text.clone()),
+            _ => {}
+        }
+    }
+
+    fn widget(&self) -> &dyn Widget {
+        match &self.control {
+            EditorControl::Bool(checkbox) => checkbox,
+            EditorControl::Number { slider, .. } => slider,
+            EditorControl::Choice { dropdown, .. } => dropdown,
+            EditorControl::Text(input) => input,
+        }
+    }
+
+    fn widget_mut(&mut self) -> &mut dyn Widget {
+        match &mut self.control {
+            EditorControl::Bool(checkbox) => checkbox,
+            EditorControl::Number { slider, .. } => slider,
+            EditorControl::Choice { dropdown, .. } => dropdown,
+            EditorControl::Text(input) => input,
+        }
+    }
+}
+
+/// Area of the `index`th editor, its name and control
+fn editor_row(index: usize) -> Rect {
+    let y = PREVIEW_Y + index as f32 * EDITOR_ROW_HEIGHT;
+    Rect::from_xywh(EDITORS_X, y, EDITOR_NAME_WIDTH + EDITOR_WIDTH, EDITOR_ROW_HEIGHT)
+}
+
+struct Entry {
+    name: &'static str,
+    widget: Box<dyn Widget>,
+}
+
+/// One of each component, placed in the preview area
+fn entries() -> Vec<Entry> {
+    let (x, y) = (PREVIEW_X, PREVIEW_Y);
+    let foreground = current_theme().foreground;
+    let mut progress = ProgressBar::new(x, y, 320.0);
+    progress.set_progress(0.6);
+    let options = ["Rust", "Zig", "Go"].iter().map(|option| option.to_string()).collect();
+    let entries: Vec<(&'static str, Box<dyn Widget>)> = vec![
+        ("Button", Box::new(Button::new(x, y, 140.0, "Continue"))),
+        ("Checkbox", Box::new(Checkbox::new(x, y, "Remember me"))),
+        ("Slider", Box::new(Slider::new(x, y, 320.0, "Volume", 0.5))),
+        ("Progress", Box::new(progress)),
+        ("Badge", Box::new(Badge::new(x, y, "New"))),
+        ("Label", Box::new(Label::new(x, y + 20.0, "The quick brown fox", Theme::TEXT_BASE, 400, foreground))),
+        (
+            "Alert",
+            Box::new(Alert::new(x, y, 400.0, "Heads up!").description("Change the props on the right to restyle me.")),
+        ),
+        ("Avatar", Box::new(Avatar::new(x, y, "Miko UI"))),
+        ("Separator", Box::new(Separator::new(x, y, 320.0))),
+        ("Skeleton", Box::new(Skeleton::new(x, y, 240.0, 20.0))),
+        ("Card", Box::new(Card::new(x, y, 320.0, 200.0))),
+        ("Input", Box::new(Input::new(x, y, 320.0, "Search components..."))),
+        ("Toggle", Box::new(Toggle::new(x, y, CodiconIcons::BOOKMARK))),
+        ("Dropdown", Box::new(Dropdown::new(x, y, 240.0, "Language", options))),
+    ];
+    entries.into_iter().map(|(name, widget)| Entry { name, widget }).collect()
+}
+
+struct Gallery {
+    window: Option<Rc<Window>>,
+    surface: Option<Surface<Rc<Window>, Rc<Window>>>,
+    font_manager: FontManager,
+    start_time: Instant,
+    entries: Vec<Entry>,
+    selected: usize,
+    editors: Vec<Editor>,
+    hover_entry: Option<usize>,
+    // Index of the editor whose slider follows the mouse
+    dragging: Option<usize>,
+    cursor: (f32, f32),
+}
+
+impl Gallery {
+    fn new() -> Self {
+        let mut gallery = Self {
+            window: None,
+            surface: None,
+            font_manager: FontManager::new(),
+            start_time: Instant::now(),
+            entries: entries(),
+            selected: 0,
+            editors: Vec::new(),
+            hover_entry: None,
+            dragging: None,
+            cursor: (0.0, 0.0),
+        };
+        gallery.select(0);
+        gallery
+    }
+
+    /// Show a component and build editors for its properties
+    fn select(&mut self, index: usize) {
+        self.selected = index;
+        self.dragging = None;
+        let props = self.entries[index].widget.props();
+        self.editors = props
+            .iter()
+            .enumerate()
+            .map(|(row, prop)| {
+                Editor::new(prop.name, &prop.value, EDITORS_X + EDITOR_NAME_WIDTH, editor_row(row).top)
+            })
+            .collect();
+    }
+
+    /// Hand an editor's value to the component, then show what the component made of it
+    fn apply(&mut self, index: usize) {
+        let editor = &self.editors[index];
+        let widget = &mut self.entries[self.selected].widget;
+        if !widget.set_prop(editor.name, &editor.value()) {
+            eprintln!("{} refused {}", self.entries[self.selected].name, editor.name);
+        }
+        self.refresh_editors();
+    }
+
+    fn refresh_editors(&mut self) {
+        for prop in self.entries[self.selected].widget.props() {
+            if let Some(editor) = self.editors.iter_mut().find(|editor| editor.name == prop.name) {
+                editor.show(&prop.value);
+            }
+        }
+    }
+
+    fn drag_slider(&mut self, index: usize, x: f32) {
+        if let EditorControl::Number { slider, .. } = &mut self.editors[index].control {
+            slider.set_value(((x - slider.x()) / slider.width()).clamp(0.0, 1.0));
+            self.apply(index);
+        }
+    }
+
+    fn mouse_down(&mut self, x: f32, y: f32) {
+        // An open dropdown lies over the rows below it, so it sees the press first
+        if let Some(index) = self.editors.iter().position(|editor| {
+            matches!(&editor.control, EditorControl::Choice { dropdown, .. } if dropdown.is_open())
+        }) {
+            let dropdown = self.editors[index].widget_mut();
+            dropdown.update_hover(x, y);
+            if dropdown.contains(x, y) {
+                dropdown.on_click();
+                self.apply(index);
+                return;
+            }
+            if let EditorControl::Choice { dropdown, .. } = &mut self.editors[index].control {
+                dropdown.close();
+            }
+        }
+
+        if x < LIST_WIDTH {
+            let row = ((y - PADDING) / LIST_ROW_HEIGHT).floor();
+            if row >= 0.0 && (row as usize) < self.entries.len() {
+                self.select(row as usize);
+            }
+            return;
+        }
+
+        for editor in &mut self.editors {
+            if let EditorControl::Text(input) = &mut editor.control {
+                input.set_focused(input.contains(x, y));
+            }
+        }
+        if let Some(index) = (0..self.editors.len()).find(|&index| editor_row(index).contains((x, y))) {
+            match self.editors[index].control {
+                EditorControl::Number { .. } => {
+                    self.dragging = Some(index);
+                    self.drag_slider(index, x);
+                }
+                EditorControl::Text(_) => {}
+                _ => {
+                    let widget = self.editors[index].widget_mut();
+                    if widget.hit_test(x, y) {
+                        widget.on_click();
+                        self.apply(index);
+                    }
+                }
+            }
+            return;
+        }
+
+        // The preview reacts to clicks like it would anywhere else
+        let widget = &mut self.entries[self.selected].widget;
+        if widget.hit_test(x, y) {
+            widget.on_click();
+            self.refresh_editors();
+        }
+    }
+
+    fn mouse_moved(&mut self, x: f32, y: f32) {
+        self.cursor = (x, y);
+        if let Some(index) = self.dragging {
+            self.drag_slider(index, x);
+        }
+        let row = ((y - PADDING) / LIST_ROW_HEIGHT).floor();
+        self.hover_entry = (x < LIST_WIDTH && row >= 0.0 && (row as usize) < self.entries.len()).then(|| row as usize);
+        for editor in &mut self.editors {
+            editor.widget_mut().update_hover(x, y);
+        }
+        self.entries[self.selected].widget.update_hover(x, y);
+    }
+
+    /// Typing into the focused text editor
+    fn key_pressed(&mut self, key: &Key, text: Option<&str>) {
+        let Some(index) = self.editors.iter().position(|editor| {
+            matches!(&editor.control, EditorControl::Text(input) if input.is_focused())
+        }) else {
+            return;
+        };
+        let EditorControl::Text(input) = &mut self.editors[index].control else {
+            return;
+        };
+        match key {
+            Key::Named(NamedKey::Backspace) => input.handle_backspace(),
+            Key::Named(NamedKey::Escape | NamedKey::Enter) => {
+                input.set_focused(false);
+                return;
+            }
+            _ => match text {
+                Some(text) => text.chars().filter(|c| !c.is_control()).for_each(|c| input.handle_char(c)),
+                None => return,
+            },
+        }
+        self.apply(index);
+    }
+
+    fn draw(&mut self, canvas: &Canvas, elapsed: f32) {
+        let theme = current_theme();
+        canvas.clear(theme.background);
+        let mut text_paint = Paint::default();
+        text_paint.set_anti_alias(true);
+        text_paint.set_color(theme.foreground);
+        let mut muted_paint = Paint::default();
+        muted_paint.set_anti_alias(true);
+        muted_paint.set_color(theme.muted_foreground);
+
+        // Component list
+        let mut list_paint = Paint::default();
+        list_paint.set_color(theme.card);
+        canvas.draw_rect(Rect::from_xywh(0.0, 0.0, LIST_WIDTH, WINDOW_HEIGHT * 4.0), &list_paint);
+        for (index, entry) in self.entries.iter().enumerate() {
+            let top = PADDING + index as f32 * LIST_ROW_HEIGHT;
+            if index == self.selected || self.hover_entry == Some(index) {
+                let mut row_paint = Paint::default();
+                row_paint.set_anti_alias(true);
+                row_paint.set_color(if index == self.selected { theme.accent } else { theme.muted });
+                canvas.draw_round_rect(Rect::from_xywh(8.0, top, LIST_WIDTH - 16.0, LIST_ROW_HEIGHT - 2.0), Theme::RADIUS_MD, Theme::RADIUS_MD, &row_paint);
+            }
+            let font = self.font_manager.create_font(entry.name, Theme::TEXT_SM, 500);
+            canvas.draw_str(entry.name, (20.0, top + 18.0), &font, &text_paint);
+        }
+
+        let entry = &mut self.entries[self.selected];
+        let title_font = self.font_manager.create_font(entry.name, Theme::TEXT_XL, 600);
+        canvas.draw_str(entry.name, (PREVIEW_X, PADDING + 16.0), &title_font, &text_paint);
+        entry.widget.update_animation(elapsed);
+        entry.widget.draw(canvas, &mut self.font_manager);
+
+        if self.editors.is_empty() {
+            let note = "No editable properties";
+            let font = self.font_manager.create_font(note, Theme::TEXT_SM, 400);
+            canvas.draw_str(note, (EDITORS_X, PREVIEW_Y + 20.0), &font, &muted_paint);
+        }
+        for (index, editor) in self.editors.iter_mut().enumerate() {
+            let row = editor_row(index);
+            let name_font = self.font_manager.create_font(editor.name, Theme::TEXT_SM, 500);
+            canvas.draw_str(editor.name, (row.left, row.top + 26.0), &name_font, &text_paint);
+            if let PropValue::Number { value, .. } = editor.value() {
+                let shown = format!("{:.2}", value);
+                let font = self.font_manager.create_font(&shown, Theme::TEXT_XS, 400);
+                canvas.draw_str(&shown, (row.left + EDITOR_NAME_WIDTH, row.top + 12.0), &font, &muted_paint);
+            }
+            editor.widget_mut().update_animation(elapsed);
+        }
+        // Open dropdowns last, so their option list covers the rows below
+        let open = |editor: &Editor| matches!(&editor.control, EditorControl::Choice { dropdown, .. } if dropdown.is_open());
+        for editor in self.editors.iter().filter(|editor| !open(editor)) {
+            editor.widget().draw(canvas, &mut self.font_manager);
+        }
+        for editor in self.editors.iter().filter(|editor| open(editor)) {
+            editor.widget().draw(canvas, &mut self.font_manager);
+        }
+    }
+
+    fn render(&mut self) {
+        let Some(window) = self.window.clone() else {
+            return;
+        };
+        let size = window.inner_size();
+        let (Some(width), Some(height)) = (NonZeroU32::new(size.width), NonZeroU32::new(size.height)) else {
+            return;
+        };
+        let Some(surface) = self.surface.as_mut() else {
+            return;
+        };
+        surface.resize(width, height).unwrap();
+        let mut skia_surface = skia_safe::surfaces::raster_n32_premul((size.width as i32, size.height as i32)).unwrap();
+        let elapsed = self.start_time.elapsed().as_secs_f32();
+        self.draw(skia_surface.canvas(), elapsed);
+
+        let image = skia_surface.image_snapshot();
+        if let Some(pixels) = image.peek_pixels() {
+            if let Some(src) = pixels.bytes() {
+                let surface = self.surface.as_mut().unwrap();
+                let mut buffer = surface.buffer_mut().unwrap();
+                copy_to_buffer(src, &mut buffer);
+                buffer.present().unwrap();
+            }
+        }
+        // Keep animations moving
+        window.request_redraw();
+    }
+}
+
+impl ApplicationHandler for Gallery {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        if self.window.is_some() {
+            return;
+        }
+        let attributes = Window::default_attributes()
+            .with_title("mikoui gallery")
+            .with_inner_size(winit::dpi::LogicalSize::new(WINDOW_WIDTH, WINDOW_HEIGHT));
+        let window = Rc::new(event_loop.create_window(attributes).unwrap());
+        let context = Context::new(window.clone()).unwrap();
+        self.surface = Some(Surface::new(&context, window.clone()).unwrap());
+        self.window = Some(window);
+    }
+
+    fn window_event(&mut self, event_loop: &ActiveEventLoop, _window_id: WindowId, event: WindowEvent) {
+        match event {
+            WindowEvent::CloseRequested => event_loop.exit(),
+            WindowEvent::RedrawRequested => self.render(),
+            WindowEvent::CursorMoved { position, .. } => self.mouse_moved(position.x as f32, position.y as f32),
+            WindowEvent::MouseInput { state, button: MouseButton::Left, .. } => {
+                let (x, y) = self.cursor;
+                match state {
+                    ElementState::Pressed => self.mouse_down(x, y),
+                    ElementState::Released => self.dragging = None,
+                }
+            }
+            WindowEvent::KeyboardInput { event, .. } if event.state == ElementState::Pressed => {
+                self.key_pressed(&event.logical_key, event.text.as_deref());
+            }
+            _ => {}
+        }
+    }
+}
+
+fn main() {
+    let event_loop = EventLoop::new().unwrap();
+    event_loop.set_control_flow(ControlFlow::Wait);
+    let mut gallery = Gallery::new();
+    event_loop.run_app(&mut gallery).unwrap();
+}
@@ -0,0 +1,40 @@
+#!/usr/bin/env pwsh
+# Run the benchmarks and save or compare a baseline
+#
+#   .\scripts\bench.ps1 -Save main        # record a baseline, e.g. on the main branch
+#   .\scripts\bench.ps1 -Compare main     # compare against it; fails when a benchmark regressed
+param(
+    [string]$Save = "",
+    [string]$Compare = ""
+)
+
+$ErrorActionPreference = "Stop"
+
+$criterionArgs = @()
+if ($Save -ne "") {
+    $criterionArgs += @("--save-baseline", $Save)
+}
+if ($Compare -ne "") {
+    $criterionArgs += @("--baseline", $Compare)
+}
+
+Write-Host "Running benchmarks..." -ForegroundColor Cyan
+cargo bench -p mikoui -p mikoeditor -- @criterionArgs 2>&1 | Tee-Object -Variable output
+
+if ($LASTEXITCODE -ne 0) {
+    Write-Host "[ERROR] Benchmarks failed to run!" -ForegroundColor Red
+    exit 1
+}
+
+if ($Compare -ne "") {
+    # Criterion prints this under every benchmark that got slower beyond its noise threshold
+    $regressions = $output | Select-String -Pattern "Performance has regressed" -Context 3, 0
+    if ($regressions) {
+        Write-Host ""
+        Write-Host "[REGRESSION] Slower than baseline '$Compare':" -ForegroundColor Red
+        $regressions | ForEach-Object { Write-Host $_.Context.PreContext[0] -ForegroundColor Yellow }
+        exit 1
+    }
+    Write-Host ""
+    Write-Host "[SUCCESS] No regressions against baseline '$Compare'" -ForegroundColor Green
+}